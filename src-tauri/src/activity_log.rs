@@ -0,0 +1,256 @@
+// 本地使用统计：记录启动了哪个提供者 / 结果类型，以及查询耗时
+// 数据只保存在本地数据库中，按设置的保留天数清理，可随时清空
+
+use crate::db;
+use crate::event_bus::{self, BusEvent};
+use crate::settings;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast;
+
+const DAY_SECS: i64 = 24 * 60 * 60;
+const TOP_RESULTS_LIMIT: usize = 10;
+
+// 设置中的统计开关，启动时读取并随 settings-changed 更新，记录事件时不再读数据库
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityEvent {
+    pub event_type: String,          // "launch" | "query"
+    pub provider: String,            // 结果来源，如 "app", "file", "everything", "plugin", "url"
+    pub result_type: Option<String>, // 结果类型，如 "folder", "exe", "lnk"
+    pub result_id: Option<String>,   // 被启动结果的唯一标识（通常是路径或插件 id）
+    pub result_name: Option<String>,
+    pub query: Option<String>,
+    pub duration_ms: Option<i64>,    // 查询耗时（仅 query 事件）
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CountEntry {
+    pub key: String,
+    pub name: Option<String>,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderLatency {
+    pub provider: String,
+    pub avg_ms: f64,
+    pub max_ms: i64,
+    pub query_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyCount {
+    pub day: String, // YYYY-MM-DD（本地时间）
+    pub launches: u64,
+    pub queries: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityStats {
+    pub days: u32,
+    pub total_launches: u64,
+    pub total_queries: u64,
+    pub launches_by_provider: Vec<CountEntry>,
+    pub launches_by_result_type: Vec<CountEntry>,
+    pub top_results: Vec<CountEntry>,
+    pub query_latency: Vec<ProviderLatency>,
+    pub daily: Vec<DailyCount>,
+}
+
+fn now_ts() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// 订阅 settings-changed，统计开关变化时热更新
+pub fn start_settings_listener() {
+    let mut receiver = event_bus::subscribe();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(BusEvent::SettingsChanged(settings)) => set_enabled(settings.analytics_enabled),
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    eprintln!("[ActivityLog] Listener lagged, skipped {} events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+/// 记录一条事件（设置中关闭统计时直接忽略）
+pub fn record_event(event: &ActivityEvent, app_data_dir: &Path) -> Result<(), String> {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+
+    let conn = db::get_connection(app_data_dir)?;
+    conn.execute(
+        "INSERT INTO activity_log (event_type, provider, result_type, result_id, result_name, query, duration_ms, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        params![
+            event.event_type,
+            event.provider,
+            event.result_type,
+            event.result_id,
+            event.result_name,
+            event.query.as_ref().map(|q| q.trim().to_string()),
+            event.duration_ms,
+            now_ts()
+        ],
+    )
+    .map_err(|e| format!("Failed to record activity: {}", e))?;
+
+    Ok(())
+}
+
+fn query_counts(
+    conn: &rusqlite::Connection,
+    sql: &str,
+    since: i64,
+) -> Result<Vec<CountEntry>, String> {
+    let mut stmt = conn
+        .prepare(sql)
+        .map_err(|e| format!("Failed to prepare activity stats query: {}", e))?;
+    let rows = stmt
+        .query_map(params![since], |row| {
+            Ok(CountEntry {
+                key: row.get(0)?,
+                name: row.get(1)?,
+                count: row.get::<_, i64>(2)? as u64,
+            })
+        })
+        .map_err(|e| format!("Failed to iterate activity stats: {}", e))?;
+
+    let mut results = Vec::new();
+    for row in rows {
+        results.push(row.map_err(|e| format!("Failed to read activity stats row: {}", e))?);
+    }
+    Ok(results)
+}
+
+/// 汇总最近 days 天的统计数据
+pub fn get_stats(days: u32, app_data_dir: &Path) -> Result<ActivityStats, String> {
+    let conn = db::get_connection(app_data_dir)?;
+    let since = now_ts() - days as i64 * DAY_SECS;
+
+    let (total_launches, total_queries): (i64, i64) = conn
+        .query_row(
+            "SELECT
+                COALESCE(SUM(CASE WHEN event_type = 'launch' THEN 1 ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN event_type = 'query' THEN 1 ELSE 0 END), 0)
+             FROM activity_log WHERE created_at >= ?1",
+            params![since],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .map_err(|e| format!("Failed to count activity: {}", e))?;
+
+    let launches_by_provider = query_counts(
+        &conn,
+        "SELECT provider, NULL, COUNT(*) AS c FROM activity_log
+         WHERE event_type = 'launch' AND created_at >= ?1
+         GROUP BY provider ORDER BY c DESC",
+        since,
+    )?;
+    let launches_by_result_type = query_counts(
+        &conn,
+        "SELECT COALESCE(result_type, 'unknown'), NULL, COUNT(*) AS c FROM activity_log
+         WHERE event_type = 'launch' AND created_at >= ?1
+         GROUP BY COALESCE(result_type, 'unknown') ORDER BY c DESC",
+        since,
+    )?;
+    let top_results = query_counts(
+        &conn,
+        &format!(
+            "SELECT result_id, MAX(result_name), COUNT(*) AS c FROM activity_log
+             WHERE event_type = 'launch' AND result_id IS NOT NULL AND created_at >= ?1
+             GROUP BY result_id ORDER BY c DESC LIMIT {}",
+            TOP_RESULTS_LIMIT
+        ),
+        since,
+    )?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT provider, AVG(duration_ms), MAX(duration_ms), COUNT(*) FROM activity_log
+             WHERE event_type = 'query' AND duration_ms IS NOT NULL AND created_at >= ?1
+             GROUP BY provider ORDER BY AVG(duration_ms) DESC",
+        )
+        .map_err(|e| format!("Failed to prepare latency query: {}", e))?;
+    let query_latency = stmt
+        .query_map(params![since], |row| {
+            Ok(ProviderLatency {
+                provider: row.get(0)?,
+                avg_ms: row.get(1)?,
+                max_ms: row.get(2)?,
+                query_count: row.get::<_, i64>(3)? as u64,
+            })
+        })
+        .map_err(|e| format!("Failed to iterate latency stats: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read latency stats row: {}", e))?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT date(created_at, 'unixepoch', 'localtime') AS day,
+                    SUM(CASE WHEN event_type = 'launch' THEN 1 ELSE 0 END),
+                    SUM(CASE WHEN event_type = 'query' THEN 1 ELSE 0 END)
+             FROM activity_log WHERE created_at >= ?1
+             GROUP BY day ORDER BY day",
+        )
+        .map_err(|e| format!("Failed to prepare daily stats query: {}", e))?;
+    let daily = stmt
+        .query_map(params![since], |row| {
+            Ok(DailyCount {
+                day: row.get(0)?,
+                launches: row.get::<_, i64>(1)? as u64,
+                queries: row.get::<_, i64>(2)? as u64,
+            })
+        })
+        .map_err(|e| format!("Failed to iterate daily stats: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read daily stats row: {}", e))?;
+
+    Ok(ActivityStats {
+        days,
+        total_launches: total_launches as u64,
+        total_queries: total_queries as u64,
+        launches_by_provider,
+        launches_by_result_type,
+        top_results,
+        query_latency,
+        daily,
+    })
+}
+
+/// 清空所有统计数据，返回删除的条数
+pub fn purge(app_data_dir: &Path) -> Result<usize, String> {
+    let conn = db::get_connection(app_data_dir)?;
+    conn.execute("DELETE FROM activity_log", [])
+        .map_err(|e| format!("Failed to purge activity log: {}", e))
+}
+
+/// 删除超过保留天数的记录（保留天数为 0 表示永久保留）
+pub fn apply_retention(app_data_dir: &Path) -> Result<usize, String> {
+    let settings = settings::load_settings(app_data_dir)?;
+    if settings.analytics_retention_days == 0 {
+        return Ok(0);
+    }
+
+    let cutoff = now_ts() - settings.analytics_retention_days as i64 * DAY_SECS;
+    let conn = db::get_connection(app_data_dir)?;
+    conn.execute("DELETE FROM activity_log WHERE created_at < ?1", params![cutoff])
+        .map_err(|e| format!("Failed to apply activity retention: {}", e))
+}
@@ -0,0 +1,427 @@
+// 外部 API 调用策略：按服务商（域名）限流、失败后指数退避重试，连续失败时熔断一段时间
+// 错误按类型区分（离线、网络错误、限流、额度用尽、认证失败、熔断等），前端据此给出不同提示
+
+use crate::network;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiErrorKind {
+    Offline,
+    Network,
+    Timeout,
+    RateLimited,
+    QuotaExceeded,
+    Unauthorized,
+    CircuitOpen,
+    Http,
+    Invalid,
+}
+
+/// 结构化的外部 API 错误，序列化后交给前端
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiError {
+    pub kind: ApiErrorKind,
+    pub message: String,
+    pub status: Option<u16>,
+    pub retry_after_secs: Option<u64>,
+}
+
+impl ApiError {
+    pub fn new(kind: ApiErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            status: None,
+            retry_after_secs: None,
+        }
+    }
+
+    fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    fn with_retry_after(mut self, retry_after: Option<Duration>) -> Self {
+        self.retry_after_secs = retry_after.map(|d| d.as_secs().max(1));
+        self
+    }
+
+    /// 是否值得重试（网络抖动、超时、限流、服务端错误）
+    fn is_retryable(&self) -> bool {
+        match self.kind {
+            ApiErrorKind::Network | ApiErrorKind::Timeout | ApiErrorKind::RateLimited => true,
+            ApiErrorKind::Http => self.status.map(|s| s >= 500).unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    /// 是否计入熔断的失败次数（客户端问题和额度问题不说明服务不可用）
+    fn counts_as_failure(&self) -> bool {
+        match self.kind {
+            ApiErrorKind::Network | ApiErrorKind::Timeout => true,
+            ApiErrorKind::Http => self.status.map(|s| s >= 500).unwrap_or(false),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<ApiError> for String {
+    fn from(error: ApiError) -> Self {
+        error.to_string()
+    }
+}
+
+impl From<reqwest::Error> for ApiError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            ApiError::new(ApiErrorKind::Timeout, format!("请求超时: {}", error))
+        } else if error.is_decode() || error.is_builder() {
+            ApiError::new(ApiErrorKind::Invalid, format!("请求失败: {}", error))
+        } else {
+            ApiError::new(ApiErrorKind::Network, format!("网络请求失败: {}", error))
+        }
+    }
+}
+
+/// 调用策略：重试次数、退避时间、每分钟请求上限、熔断阈值
+#[derive(Debug, Clone, Copy)]
+pub struct Policy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub requests_per_minute: usize,
+    pub failure_threshold: u32,
+    pub open_duration: Duration,
+}
+
+impl Policy {
+    pub const LLM: Policy = Policy {
+        max_retries: 2,
+        base_delay: Duration::from_millis(800),
+        max_delay: Duration::from_secs(10),
+        requests_per_minute: 30,
+        failure_threshold: 5,
+        open_duration: Duration::from_secs(60),
+    };
+
+    pub const UPDATE: Policy = Policy {
+        max_retries: 2,
+        base_delay: Duration::from_secs(1),
+        max_delay: Duration::from_secs(8),
+        requests_per_minute: 10,
+        failure_threshold: 3,
+        open_duration: Duration::from_secs(300),
+    };
+
+    // 联想词随输入频繁请求，失败不重试，过期的结果没有意义
+    pub const SUGGESTIONS: Policy = Policy {
+        max_retries: 0,
+        base_delay: Duration::from_millis(200),
+        max_delay: Duration::from_millis(200),
+        requests_per_minute: 120,
+        failure_threshold: 5,
+        open_duration: Duration::from_secs(30),
+    };
+
+    // 行情按刷新间隔请求，失败时有缓存兜底，只重试一次
+    pub const QUOTES: Policy = Policy {
+        max_retries: 1,
+        base_delay: Duration::from_millis(500),
+        max_delay: Duration::from_secs(2),
+        requests_per_minute: 60,
+        failure_threshold: 5,
+        open_duration: Duration::from_secs(60),
+    };
+
+    // 订阅后台定时抓取，不急于重试；源站持续失败时暂停较长时间
+    pub const FEEDS: Policy = Policy {
+        max_retries: 1,
+        base_delay: Duration::from_secs(2),
+        max_delay: Duration::from_secs(10),
+        requests_per_minute: 30,
+        failure_threshold: 3,
+        open_duration: Duration::from_secs(600),
+    };
+
+    // 云同步每次同步会连续读取多条日志，请求上限放宽；失败时下次同步再试
+    pub const SYNC: Policy = Policy {
+        max_retries: 2,
+        base_delay: Duration::from_secs(1),
+        max_delay: Duration::from_secs(8),
+        requests_per_minute: 120,
+        failure_threshold: 3,
+        open_duration: Duration::from_secs(300),
+    };
+
+    // 上传粘贴不重试：请求超时时服务端可能已经创建，重试会产生重复的链接
+    pub const PASTE: Policy = Policy {
+        max_retries: 0,
+        base_delay: Duration::from_millis(500),
+        max_delay: Duration::from_millis(500),
+        requests_per_minute: 20,
+        failure_threshold: 3,
+        open_duration: Duration::from_secs(120),
+    };
+
+    // 自动化脚本的请求不自动重试（POST 可能有副作用），由脚本自行处理失败；限制频率避免脚本在循环中请求
+    pub const SCRIPTS: Policy = Policy {
+        max_retries: 0,
+        base_delay: Duration::from_millis(500),
+        max_delay: Duration::from_millis(500),
+        requests_per_minute: 60,
+        failure_threshold: 5,
+        open_duration: Duration::from_secs(60),
+    };
+}
+
+#[derive(Default)]
+struct ProviderState {
+    recent: VecDeque<Instant>,
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+static PROVIDERS: LazyLock<Mutex<HashMap<String, ProviderState>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn with_provider<T>(provider: &str, f: impl FnOnce(&mut ProviderState) -> T) -> T {
+    let mut providers = PROVIDERS.lock().unwrap_or_else(|e| e.into_inner());
+    f(providers.entry(provider.to_string()).or_default())
+}
+
+/// 熔断中返回剩余时间
+fn circuit_remaining(provider: &str) -> Option<Duration> {
+    with_provider(provider, |state| {
+        let now = Instant::now();
+        match state.open_until {
+            Some(until) if until > now => Some(until - now),
+            Some(_) => {
+                // 熔断结束，放行一次试探请求，失败会立即再次熔断
+                state.open_until = None;
+                state.consecutive_failures = state.consecutive_failures.saturating_sub(1);
+                None
+            }
+            None => None,
+        }
+    })
+}
+
+/// 占用一个请求名额；名额已满时返回需要等待的时间
+fn try_acquire(provider: &str, policy: &Policy) -> Option<Duration> {
+    with_provider(provider, |state| {
+        let now = Instant::now();
+        while state.recent.front().map(|t| now.duration_since(*t) >= RATE_WINDOW).unwrap_or(false) {
+            state.recent.pop_front();
+        }
+        if state.recent.len() >= policy.requests_per_minute.max(1) {
+            return state.recent.front().map(|t| RATE_WINDOW - now.duration_since(*t));
+        }
+        state.recent.push_back(now);
+        None
+    })
+}
+
+fn record_result(provider: &str, policy: &Policy, failed: bool) {
+    with_provider(provider, |state| {
+        if !failed {
+            state.consecutive_failures = 0;
+            return;
+        }
+        state.consecutive_failures += 1;
+        if state.consecutive_failures >= policy.failure_threshold {
+            eprintln!("[ApiPolicy] Circuit opened for {} after {} failures", provider, state.consecutive_failures);
+            state.open_until = Some(Instant::now() + policy.open_duration);
+        }
+    });
+}
+
+fn backoff_delay(policy: &Policy, attempt: u32) -> Duration {
+    let exp = policy.base_delay.saturating_mul(1u32 << attempt.min(16));
+    // 加入最多 25% 的随机抖动，避免多个请求同时重试
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().subsec_nanos();
+    let jitter = exp.mul_f64((nanos % 250) as f64 / 1000.0);
+    (exp + jitter).min(policy.max_delay)
+}
+
+fn parse_retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// 把非 2xx 响应转换为结构化错误
+async fn error_from_response(response: reqwest::Response) -> ApiError {
+    let status = response.status();
+    let retry_after = parse_retry_after(&response);
+    let body = response.text().await.unwrap_or_default();
+    let detail = if body.trim().is_empty() {
+        status.canonical_reason().unwrap_or_default().to_string()
+    } else {
+        body
+    };
+    let message = format!("API 请求失败 ({}): {}", status.as_u16(), detail);
+    let lower = detail.to_lowercase();
+
+    let kind = match status.as_u16() {
+        // OpenAI 等服务额度用尽时也返回 429，通过错误内容区分
+        402 => ApiErrorKind::QuotaExceeded,
+        429 if lower.contains("quota") || lower.contains("insufficient") || lower.contains("billing") => {
+            ApiErrorKind::QuotaExceeded
+        }
+        429 => ApiErrorKind::RateLimited,
+        401 | 403 => ApiErrorKind::Unauthorized,
+        _ => ApiErrorKind::Http,
+    };
+    ApiError::new(kind, message)
+        .with_status(status.as_u16())
+        .with_retry_after(retry_after)
+}
+
+/// 服务商标识：请求地址的域名
+pub fn provider_of(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(|h| h.to_lowercase()))
+        .unwrap_or_else(|| url.to_string())
+}
+
+/// 地址是否指向本机或局域网（回环、私有网段、链路本地地址及 localhost、.local 域名）
+fn is_local_url(url: &reqwest::Url) -> bool {
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+    match host.trim_start_matches('[').trim_end_matches(']').parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => ip.is_loopback() || ip.is_private() || ip.is_link_local(),
+        Ok(IpAddr::V6(ip)) => {
+            let first = ip.segments()[0];
+            // fc00::/7 唯一本地地址，fe80::/10 链路本地地址
+            ip.is_loopback() || (first & 0xfe00) == 0xfc00 || (first & 0xffc0) == 0xfe80
+        }
+        Err(_) => {
+            let host = host.to_ascii_lowercase();
+            host == "localhost" || host.ends_with(".localhost") || host.ends_with(".local")
+        }
+    }
+}
+
+/// 按策略发送请求；build 每次重试都会重新调用以构建请求
+pub async fn send<F>(provider: &str, policy: &Policy, build: F) -> Result<reqwest::Response, ApiError>
+where
+    F: Fn() -> reqwest::RequestBuilder,
+{
+    if let Some(remaining) = circuit_remaining(provider) {
+        return Err(ApiError::new(
+            ApiErrorKind::CircuitOpen,
+            format!("{} 连续请求失败，已暂停访问，请 {} 秒后重试", provider, remaining.as_secs().max(1)),
+        )
+        .with_retry_after(Some(remaining)));
+    }
+    // 本机和局域网的服务（如本地运行的 Ollama、LM Studio）离线时仍然可用
+    let local = build().build().is_ok_and(|request| is_local_url(request.url()));
+    if !local && !network::is_online() {
+        return Err(ApiError::new(ApiErrorKind::Offline, "网络不可用，请检查网络连接"));
+    }
+
+    let mut attempt = 0;
+    loop {
+        while let Some(wait) = try_acquire(provider, policy) {
+            tokio::time::sleep(wait).await;
+        }
+
+        let error = match build().send().await {
+            Ok(response) if response.status().is_success() => {
+                network::report_success();
+                record_result(provider, policy, false);
+                return Ok(response);
+            }
+            Ok(response) => {
+                network::report_success();
+                error_from_response(response).await
+            }
+            Err(e) => {
+                if let Some(url) = e.url().filter(|_| e.is_connect() && !local) {
+                    network::report_unreachable(url.as_str());
+                }
+                ApiError::from(e)
+            }
+        };
+
+        if attempt >= policy.max_retries || !error.is_retryable() {
+            record_result(provider, policy, error.counts_as_failure());
+            return Err(error);
+        }
+        let delay = error
+            .retry_after_secs
+            .map(Duration::from_secs)
+            .unwrap_or_else(|| backoff_delay(policy, attempt))
+            .min(policy.max_delay);
+        eprintln!(
+            "[ApiPolicy] {} attempt {} failed ({}), retrying in {:?}",
+            provider,
+            attempt + 1,
+            error.message,
+            delay
+        );
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opens_circuit_after_repeated_failures() {
+        let policy = Policy {
+            failure_threshold: 2,
+            open_duration: Duration::from_secs(60),
+            ..Policy::LLM
+        };
+        let provider = "test.circuit";
+        record_result(provider, &policy, true);
+        assert!(circuit_remaining(provider).is_none());
+        record_result(provider, &policy, true);
+        assert!(circuit_remaining(provider).is_some());
+    }
+
+    #[test]
+    fn detects_local_endpoints() {
+        let local = |url: &str| is_local_url(&reqwest::Url::parse(url).unwrap());
+        assert!(local("http://localhost:11434/api/chat"));
+        assert!(local("http://127.0.0.1:1234/v1/chat/completions"));
+        assert!(local("http://192.168.1.20:8080/v1"));
+        assert!(local("http://[::1]:11434/"));
+        assert!(local("http://nas.local/"));
+        assert!(!local("https://api.openai.com/v1"));
+        assert!(!local("http://8.8.8.8/"));
+    }
+
+    #[test]
+    fn limits_requests_per_window() {
+        let policy = Policy {
+            requests_per_minute: 2,
+            ..Policy::LLM
+        };
+        let provider = "test.rate";
+        assert!(try_acquire(provider, &policy).is_none());
+        assert!(try_acquire(provider, &policy).is_none());
+        assert!(try_acquire(provider, &policy).is_some());
+    }
+}
@@ -0,0 +1,180 @@
+// 应用搜索索引：预先计算名称、描述、路径的小写形式，搜索时不再逐个转换大小写
+// 小写形式与原文相同时不额外存储，重复的描述共享同一份字符串，几万个应用时内存占用也可控
+
+use crate::app_search::AppInfo;
+use crate::scoring;
+use std::collections::HashSet;
+use std::sync::{Arc, LazyLock, Mutex, Weak};
+
+const MAX_SEARCH_RESULTS: usize = 20;
+const MAX_PERFECT_MATCHES: usize = 3;
+
+struct IndexEntry {
+    name_lower: Option<Box<str>>, // None 表示与原名称相同
+    description_lower: Option<Arc<str>>,
+    path_lower: Option<Box<str>>,
+}
+
+pub struct AppIndex {
+    entries: Vec<IndexEntry>,
+}
+
+/// 小写形式与原文不同时才返回
+fn lower_if_changed(text: &str) -> Option<String> {
+    let lower = text.to_lowercase();
+    (lower != text).then_some(lower)
+}
+
+impl AppIndex {
+    /// 为应用列表建立索引，entries 与 apps 按下标一一对应
+    pub fn build(apps: &[AppInfo]) -> Self {
+        let mut interned: HashSet<Arc<str>> = HashSet::new();
+        let entries = apps
+            .iter()
+            .map(|app| IndexEntry {
+                name_lower: lower_if_changed(&app.name).map(String::into_boxed_str),
+                description_lower: app.description.as_ref().map(|description| {
+                    let lower = description.to_lowercase();
+                    match interned.get(lower.as_str()) {
+                        Some(existing) => existing.clone(),
+                        None => {
+                            let value: Arc<str> = Arc::from(lower);
+                            interned.insert(value.clone());
+                            value
+                        }
+                    }
+                }),
+                path_lower: lower_if_changed(&app.path).map(String::into_boxed_str),
+            })
+            .collect();
+        AppIndex { entries }
+    }
+
+    /// 搜索应用；apps 必须是建立索引时使用的同一个列表
+    pub fn search(&self, query: &str, apps: &[AppInfo]) -> Vec<AppInfo> {
+        if query.is_empty() {
+            return apps.iter().take(10).cloned().collect();
+        }
+
+        let query = scoring::Query::for_provider(query, "apps");
+        let mut results: Vec<(usize, i32)> = Vec::with_capacity(MAX_SEARCH_RESULTS);
+        let mut perfect_matches = 0;
+
+        for (idx, (app, entry)) in apps.iter().zip(&self.entries).enumerate() {
+            // Direct text match (highest priority)
+            let name_lower = entry.name_lower.as_deref().unwrap_or(&app.name);
+            let mut score = scoring::text_score(&query, name_lower);
+            if !name_lower.is_empty() && name_lower == query.lower {
+                perfect_matches += 1;
+                results.push((idx, score));
+                if perfect_matches >= MAX_PERFECT_MATCHES {
+                    break;
+                }
+                continue;
+            }
+
+            // Pinyin matching - use cached pinyin if available
+            if let (Some(name_pinyin), Some(name_pinyin_initials)) = (&app.name_pinyin, &app.name_pinyin_initials) {
+                score += scoring::pinyin_score(&query, name_pinyin, name_pinyin_initials);
+                if query.is_pinyin && name_pinyin.as_str() == query.lower {
+                    perfect_matches += 1;
+                    if perfect_matches >= MAX_PERFECT_MATCHES {
+                        results.push((idx, score));
+                        break;
+                    }
+                }
+            }
+
+            // Fuzzy subsequence match (e.g. "vsc" -> "Visual Studio Code") when nothing matched directly
+            if score == 0 {
+                score = scoring::weighted_fuzzy_score(&query, name_lower);
+            }
+
+            // Description match (e.g. "系统设置" matches "Windows 系统设置")
+            if score == 0 {
+                if let (Some(description), Some(description_lower)) = (&app.description, &entry.description_lower) {
+                    score += scoring::secondary_score_lower(&query, description, description_lower);
+                }
+            }
+
+            // Path match gets lower score (only check if no name or description match)
+            if score == 0 && app.path.len() >= query.lower.len() {
+                score += scoring::path_score_lower(&query, entry.path_lower.as_deref().unwrap_or(&app.path));
+            }
+
+            if score > 0 {
+                results.push((idx, score));
+            }
+        }
+
+        // 提前结束时只有完全匹配的结果，无需排序
+        if !(perfect_matches >= MAX_PERFECT_MATCHES && results.len() <= MAX_PERFECT_MATCHES) {
+            results.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+        }
+        results
+            .into_iter()
+            .take(MAX_SEARCH_RESULTS)
+            .map(|(idx, _)| apps[idx].clone())
+            .collect()
+    }
+}
+
+// 最近一次建立的索引及其对应的应用列表；应用缓存整体替换后按需重建
+// 使用 Weak 判断是否为同一个列表，不会延长旧列表的生命周期
+static CACHED_INDEX: LazyLock<Mutex<Option<(Weak<Vec<AppInfo>>, Arc<AppIndex>)>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+/// 获取应用列表的索引，列表变化时重新建立
+pub fn index_for(apps: &Arc<Vec<AppInfo>>) -> Arc<AppIndex> {
+    let mut cached = CACHED_INDEX.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some((list, index)) = cached.as_ref() {
+        if list.ptr_eq(&Arc::downgrade(apps)) {
+            return index.clone();
+        }
+    }
+    let index = Arc::new(AppIndex::build(apps));
+    *cached = Some((Arc::downgrade(apps), index.clone()));
+    index
+}
+
+/// 使用缓存的索引搜索应用
+pub fn search(query: &str, apps: &Arc<Vec<AppInfo>>) -> Vec<AppInfo> {
+    index_for(apps).search(query, apps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(name: &str, path: &str, description: Option<&str>) -> AppInfo {
+        AppInfo {
+            name: name.to_string(),
+            path: path.to_string(),
+            icon: None,
+            description: description.map(str::to_string),
+            name_pinyin: None,
+            name_pinyin_initials: None,
+        }
+    }
+
+    #[test]
+    fn searches_precomputed_keys() {
+        let apps = Arc::new(vec![
+            app("notepad", "c:\\notepad.exe", Some("文本编辑器")),
+            app("Paint", "C:\\Tools\\MSPaint.exe", Some("文本编辑器")),
+            app("Visual Studio Code", "C:\\Code.exe", None),
+        ]);
+        let index = index_for(&apps);
+        assert!(index.entries[0].name_lower.is_none());
+        assert!(Arc::ptr_eq(
+            index.entries[0].description_lower.as_ref().unwrap(),
+            index.entries[1].description_lower.as_ref().unwrap()
+        ));
+        assert!(Arc::ptr_eq(&index, &index_for(&apps)));
+
+        assert_eq!(search("PAINT", &apps)[0].name, "Paint");
+        assert_eq!(search("vsc", &apps)[0].name, "Visual Studio Code");
+        assert_eq!(search("编辑器", &apps).len(), 2);
+        assert_eq!(search("tools", &apps)[0].name, "Paint");
+    }
+}
@@ -0,0 +1,217 @@
+// 按前台程序限定作用范围：热字串和全局快捷键可以只在指定程序中生效，或在指定程序中不生效。
+// 规则写法为进程名（如 code.exe，可省略 .exe）或 class:窗口类名，前面加 ! 表示排除；
+// 在键盘钩子中按当前前台窗口判断，空规则表示所有程序
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ForegroundApp {
+    /// 小写的进程文件名，如 code.exe
+    pub exe: String,
+    pub class: String,
+}
+
+#[derive(Debug, PartialEq)]
+enum Target<'a> {
+    Exe(&'a str),
+    Class(&'a str),
+}
+
+struct Rule<'a> {
+    exclude: bool,
+    target: Target<'a>,
+}
+
+fn parse_rule(rule: &str) -> Option<Rule<'_>> {
+    let rule = rule.trim();
+    let (exclude, rule) = match rule.strip_prefix('!') {
+        Some(rest) => (true, rest.trim()),
+        None => (false, rule),
+    };
+    let target = match rule.get(..6) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("class:") => Target::Class(rule[6..].trim()),
+        _ => Target::Exe(rule),
+    };
+    match target {
+        Target::Exe("") | Target::Class("") => None,
+        target => Some(Rule { exclude, target }),
+    }
+}
+
+fn exe_matches(rule: &str, exe: &str) -> bool {
+    exe.eq_ignore_ascii_case(rule)
+        || exe.strip_suffix(".exe").is_some_and(|stem| stem.eq_ignore_ascii_case(rule))
+}
+
+impl Rule<'_> {
+    fn matches(&self, app: &ForegroundApp) -> bool {
+        match self.target {
+            Target::Exe(exe) => exe_matches(exe, &app.exe),
+            Target::Class(class) => app.class.eq_ignore_ascii_case(class),
+        }
+    }
+}
+
+/// 整理用户输入的规则：去掉空白和空项，检查格式
+pub fn normalize(rules: &[String]) -> Result<Vec<String>, String> {
+    let mut normalized = Vec::new();
+    for rule in rules.iter().map(|r| r.trim()).filter(|r| !r.is_empty()) {
+        if parse_rule(rule).is_none() {
+            return Err(format!("无效的程序规则: {}", rule));
+        }
+        if !normalized.iter().any(|r: &String| r.eq_ignore_ascii_case(rule)) {
+            normalized.push(rule.to_string());
+        }
+    }
+    Ok(normalized)
+}
+
+/// 前台程序是否在作用范围内：命中任一排除规则时不生效；有包含规则时需要命中其中之一
+pub fn matches(rules: &[String], app: Option<&ForegroundApp>) -> bool {
+    let rules: Vec<Rule> = rules.iter().filter_map(|r| parse_rule(r)).collect();
+    if rules.is_empty() {
+        return true;
+    }
+    let Some(app) = app else {
+        // 取不到前台程序时只有纯排除规则生效
+        return rules.iter().all(|rule| rule.exclude);
+    };
+    if rules.iter().any(|rule| rule.exclude && rule.matches(app)) {
+        return false;
+    }
+    let mut includes = rules.iter().filter(|rule| !rule.exclude).peekable();
+    includes.peek().is_none() || includes.any(|rule| rule.matches(app))
+}
+
+/// 两个作用范围是否可能同时生效，用于检查快捷键冲突；包含规则完全不同时才认为不冲突
+pub fn overlaps(a: &[String], b: &[String]) -> bool {
+    let includes = |rules: &[String]| -> Vec<String> {
+        rules
+            .iter()
+            .filter_map(|r| parse_rule(r))
+            .filter(|rule| !rule.exclude)
+            .map(|rule| match rule.target {
+                Target::Exe(exe) => {
+                    let exe = exe.to_lowercase();
+                    exe.strip_suffix(".exe").map(str::to_string).unwrap_or(exe)
+                }
+                Target::Class(class) => format!("class:{}", class.to_lowercase()),
+            })
+            .collect()
+    };
+    let (a, b) = (includes(a), includes(b));
+    a.is_empty() || b.is_empty() || a.iter().any(|rule| b.contains(rule))
+}
+
+/// 按当前前台窗口判断；规则为空时不查询窗口
+pub fn foreground_matches(rules: &[String]) -> bool {
+    if rules.is_empty() {
+        return true;
+    }
+    matches(rules, platform::foreground_app().as_ref())
+}
+
+#[cfg(target_os = "windows")]
+pub mod platform {
+    use super::ForegroundApp;
+    use std::sync::{LazyLock, Mutex};
+    use windows_sys::Win32::Foundation::{CloseHandle, HWND};
+    use windows_sys::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows_sys::Win32::UI::WindowsAndMessaging::{GetClassNameW, GetForegroundWindow, GetWindowThreadProcessId};
+
+    // 钩子中每次按键都会判断，按窗口句柄缓存上一次查到的程序
+    static CACHE: LazyLock<Mutex<Option<(HWND, ForegroundApp)>>> = LazyLock::new(|| Mutex::new(None));
+
+    fn query(hwnd: HWND) -> ForegroundApp {
+        let mut class = [0u16; 256];
+        let class_len = unsafe { GetClassNameW(hwnd, class.as_mut_ptr(), class.len() as i32) };
+        let class = String::from_utf16_lossy(&class[..class_len.max(0) as usize]);
+
+        let mut exe = String::new();
+        let mut pid = 0u32;
+        unsafe { GetWindowThreadProcessId(hwnd, &mut pid) };
+        if pid != 0 {
+            unsafe {
+                let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+                if process != 0 {
+                    let mut path = [0u16; 1024];
+                    let mut len = path.len() as u32;
+                    if QueryFullProcessImageNameW(process, PROCESS_NAME_WIN32, path.as_mut_ptr(), &mut len) != 0 {
+                        let path = String::from_utf16_lossy(&path[..len as usize]);
+                        exe = path.rsplit(['\\', '/']).next().unwrap_or_default().to_lowercase();
+                    }
+                    CloseHandle(process);
+                }
+            }
+        }
+        ForegroundApp { exe, class }
+    }
+
+    pub fn foreground_app() -> Option<ForegroundApp> {
+        let hwnd = unsafe { GetForegroundWindow() };
+        if hwnd == 0 {
+            return None;
+        }
+        let mut cache = CACHE.lock().ok()?;
+        if let Some((cached, app)) = cache.as_ref() {
+            if *cached == hwnd {
+                return Some(app.clone());
+            }
+        }
+        let app = query(hwnd);
+        *cache = Some((hwnd, app.clone()));
+        Some(app)
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub mod platform {
+    use super::ForegroundApp;
+
+    pub fn foreground_app() -> Option<ForegroundApp> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(list: &[&str]) -> Vec<String> {
+        list.iter().map(|r| r.to_string()).collect()
+    }
+
+    fn app(exe: &str, class: &str) -> ForegroundApp {
+        ForegroundApp { exe: exe.to_string(), class: class.to_string() }
+    }
+
+    #[test]
+    fn matches_by_exe_class_and_exclusion() {
+        let code = app("code.exe", "Chrome_WidgetWin_1");
+        let notepad = app("notepad.exe", "Notepad");
+        assert!(matches(&[], Some(&code)));
+        assert!(matches(&rules(&["Code"]), Some(&code)));
+        assert!(!matches(&rules(&["Code.exe"]), Some(&notepad)));
+        assert!(matches(&rules(&["class:notepad", "code"]), Some(&notepad)));
+        assert!(!matches(&rules(&["!notepad.exe"]), Some(&notepad)));
+        assert!(matches(&rules(&["!notepad.exe"]), Some(&code)));
+        assert!(!matches(&rules(&["class:Chrome_WidgetWin_1", "!code"]), Some(&code)));
+        assert!(!matches(&rules(&["code"]), None));
+        assert!(matches(&rules(&["!code"]), None));
+    }
+
+    #[test]
+    fn normalizes_and_detects_overlap() {
+        assert_eq!(
+            normalize(&rules(&[" code.exe ", "", "CODE.EXE", "class: Notepad"])).unwrap(),
+            rules(&["code.exe", "class: Notepad"])
+        );
+        assert!(normalize(&rules(&["class:"])).is_err());
+        assert!(normalize(&rules(&["!"])).is_err());
+
+        assert!(overlaps(&[], &rules(&["code"])));
+        assert!(overlaps(&rules(&["code.exe"]), &rules(&["Code", "word"])));
+        assert!(!overlaps(&rules(&["code"]), &rules(&["class:Notepad"])));
+        assert!(overlaps(&rules(&["!code"]), &rules(&["code"])));
+    }
+}
@@ -15,11 +15,93 @@ pub struct AppInfo {
     pub name_pinyin_initials: Option<String>, // Cached pinyin initials for faster search
 }
 
+// 应用缓存文件格式：魔数 + 版本号（u32 小端）+ 数据的 SHA-256 + bincode 编码的应用列表
+// 版本号不一致、校验和不匹配或解码失败都视为缓存损坏
+const CACHE_MAGIC: &[u8; 4] = b"IMAC";
+const CACHE_VERSION: u32 = 1;
+const CACHE_HEADER_LEN: usize = 4 + 4 + 32;
+
+/// 缓存中的应用记录；AppInfo 的可选字段在 JSON 中会被省略，bincode 需要字段完整，因此单独定义
+#[derive(Serialize, Deserialize)]
+struct CachedApp {
+    name: String,
+    path: String,
+    icon: Option<String>,
+    description: Option<String>,
+    name_pinyin: Option<String>,
+    name_pinyin_initials: Option<String>,
+}
+
+/// 把应用列表编码为二进制缓存
+pub fn encode_cache(apps: &[AppInfo]) -> Result<Vec<u8>, String> {
+    use sha2::{Digest, Sha256};
+    let entries: Vec<CachedApp> = apps
+        .iter()
+        .map(|app| CachedApp {
+            name: app.name.clone(),
+            path: app.path.clone(),
+            icon: app.icon.clone(),
+            description: app.description.clone(),
+            name_pinyin: app.name_pinyin.clone(),
+            name_pinyin_initials: app.name_pinyin_initials.clone(),
+        })
+        .collect();
+    let payload = bincode::serialize(&entries).map_err(|e| format!("Failed to encode cache: {}", e))?;
+
+    let mut data = Vec::with_capacity(CACHE_HEADER_LEN + payload.len());
+    data.extend_from_slice(CACHE_MAGIC);
+    data.extend_from_slice(&CACHE_VERSION.to_le_bytes());
+    data.extend_from_slice(&Sha256::digest(&payload));
+    data.extend_from_slice(&payload);
+    Ok(data)
+}
+
+/// 解码二进制缓存，格式、版本或校验和不对时返回错误
+pub fn decode_cache(data: &[u8]) -> Result<Vec<AppInfo>, String> {
+    use sha2::{Digest, Sha256};
+    if data.len() < CACHE_HEADER_LEN || &data[..4] != CACHE_MAGIC {
+        return Err("Invalid cache file header".to_string());
+    }
+    let version = u32::from_le_bytes([data[4], data[5], data[6], data[7]]);
+    if version != CACHE_VERSION {
+        return Err(format!("Unsupported cache version: {}", version));
+    }
+    let payload = &data[CACHE_HEADER_LEN..];
+    if Sha256::digest(payload).as_slice() != &data[8..CACHE_HEADER_LEN] {
+        return Err("Cache checksum mismatch".to_string());
+    }
+    let entries: Vec<CachedApp> =
+        bincode::deserialize(payload).map_err(|e| format!("Failed to decode cache: {}", e))?;
+    Ok(entries
+        .into_iter()
+        .map(|entry| AppInfo {
+            name: entry.name,
+            path: entry.path,
+            icon: entry.icon,
+            description: entry.description,
+            name_pinyin: entry.name_pinyin,
+            name_pinyin_initials: entry.name_pinyin_initials,
+        })
+        .collect())
+}
+
+/// 比较新旧应用索引，返回（新增数, 移除数），按路径（忽略大小写）判断是否为同一应用
+pub fn diff_apps(old: &[AppInfo], new: &[AppInfo]) -> (usize, usize) {
+    use std::collections::HashSet;
+    let old_paths: HashSet<String> = old.iter().map(|a| a.path.to_lowercase()).collect();
+    let new_paths: HashSet<String> = new.iter().map(|a| a.path.to_lowercase()).collect();
+    (
+        new_paths.difference(&old_paths).count(),
+        old_paths.difference(&new_paths).count(),
+    )
+}
+
 #[cfg(target_os = "windows")]
 pub mod windows {
     use super::*;
     use base64::Engine;
-    use pinyin::ToPinyin;
+    use crate::pinyin_utils::{contains_chinese, to_pinyin, to_pinyin_initials};
+    use crate::i18n;
     use std::env;
     use std::io::Write;
     use std::os::windows::ffi::OsStringExt;
@@ -72,15 +154,19 @@ pub mod windows {
     
     // Cache file name
     pub fn get_cache_file_path(app_data_dir: &Path) -> PathBuf {
+        app_data_dir.join("app_cache.bin")
+    }
+
+    // 旧版本使用的 JSON 缓存，首次读取时迁移为二进制格式
+    fn get_legacy_cache_file_path(app_data_dir: &Path) -> PathBuf {
         app_data_dir.join("app_cache.json")
     }
 
     // Constants
-    const MAX_SEARCH_RESULTS: usize = 20;
-    const MAX_PERFECT_MATCHES: usize = 3;
     const ICON_SIZE: u32 = 32;
     const MAX_SCAN_DEPTH: usize = 3;
-    const MAX_APPS: usize = 2000;
+    // 扫描数量的安全上限（防止异常目录结构导致无限增长），正常安装远达不到
+    const MAX_APPS: usize = 50_000;
     const WINDOWSAPPS_PATH: &str = "windowsapps";
     const RECENT_PATH: &str = "recent";
 
@@ -329,18 +415,24 @@ pub mod windows {
     }
 
     // Load cached apps from disk
+    // 缓存损坏时删除缓存文件并返回错误，由调用方重新扫描
     pub fn load_cache(app_data_dir: &Path) -> Result<Vec<AppInfo>, String> {
         let cache_file = get_cache_file_path(app_data_dir);
 
         if !cache_file.exists() {
-            return Ok(Vec::new());
+            return load_legacy_cache(app_data_dir);
         }
 
-        let content = fs::read_to_string(&cache_file)
+        let data = fs::read(&cache_file)
             .map_err(|e| format!("Failed to read cache file: {}", e))?;
 
-        let mut apps: Vec<AppInfo> = serde_json::from_str(&content)
-            .map_err(|e| format!("Failed to parse cache file: {}", e))?;
+        let mut apps = match decode_cache(&data) {
+            Ok(apps) => apps,
+            Err(e) => {
+                let _ = fs::remove_file(&cache_file);
+                return Err(format!("App cache is corrupted: {}", e));
+            }
+        };
 
         // Filter out WindowsApps paths from cache (in case old cache contains them)
         filter_windowsapps_paths(&mut apps);
@@ -348,6 +440,25 @@ pub mod windows {
         Ok(apps)
     }
 
+    // 读取旧版 JSON 缓存并转存为二进制格式
+    fn load_legacy_cache(app_data_dir: &Path) -> Result<Vec<AppInfo>, String> {
+        let legacy_file = get_legacy_cache_file_path(app_data_dir);
+        if !legacy_file.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&legacy_file)
+            .map_err(|e| format!("Failed to read cache file: {}", e))?;
+        let mut apps: Vec<AppInfo> = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse cache file: {}", e))?;
+        filter_windowsapps_paths(&mut apps);
+
+        if save_cache(app_data_dir, &apps).is_ok() {
+            let _ = fs::remove_file(&legacy_file);
+        }
+        Ok(apps)
+    }
+
     // Save apps cache to disk
     pub fn save_cache(app_data_dir: &Path, apps: &[AppInfo]) -> Result<(), String> {
         // Create directory if it doesn't exist
@@ -361,11 +472,14 @@ pub mod windows {
         filter_windowsapps_paths(&mut filtered_apps);
 
         let cache_file = get_cache_file_path(app_data_dir);
-        let json_string = serde_json::to_string_pretty(&filtered_apps)
-            .map_err(|e| format!("Failed to serialize cache: {}", e))?;
+        let data = encode_cache(&filtered_apps)?;
 
-        fs::write(&cache_file, json_string)
+        // 先写临时文件再替换，避免写入中断留下不完整的缓存
+        let tmp_file = cache_file.with_extension("bin.tmp");
+        fs::write(&tmp_file, data)
             .map_err(|e| format!("Failed to write cache file: {}", e))?;
+        fs::rename(&tmp_file, &cache_file)
+            .map_err(|e| format!("Failed to replace cache file: {}", e))?;
 
         Ok(())
     }
@@ -413,7 +527,7 @@ pub mod windows {
         crate::log!("AppScan", "  桌面路径: {:?}", desktop_paths_str);
 
         if let Some(ref tx) = tx {
-            let _ = tx.send((5, "开始扫描应用...".to_string()));
+            let _ = tx.send((5, i18n::t("app_scan.start").to_string()));
         }
 
         // Scan start menu paths
@@ -430,7 +544,7 @@ pub mod windows {
                         .and_then(|n| n.to_str())
                         .unwrap_or("开始菜单")
                         .to_string();
-                    let _ = tx.send((10 + (idx as u8 * 15), format!("正在扫描: {}", path_name)));
+                    let _ = tx.send((10 + (idx as u8 * 15), i18n::tf("app_scan.path", &[&path_name])));
                 }
                 
                 // Start scanning from depth 0, limit to 3 levels for better coverage
@@ -449,7 +563,7 @@ pub mod windows {
         // Scan desktop paths (only scan depth 0 for desktop, no recursion)
         let desktop_scan_start = std::time::Instant::now();
         if let Some(ref tx) = tx {
-            let _ = tx.send((60, "正在扫描桌面...".to_string()));
+            let _ = tx.send((60, i18n::t("app_scan.desktop").to_string()));
         }
         let apps_before_desktop = apps.len();
         for desktop_path in desktop_paths.into_iter().flatten() {
@@ -466,7 +580,7 @@ pub mod windows {
         // Scan Microsoft Store / UWP apps via shell:AppsFolder enumeration
         let uwp_scan_start = std::time::Instant::now();
         if let Some(ref tx) = tx {
-            let _ = tx.send((70, "正在扫描 Microsoft Store 应用...".to_string()));
+            let _ = tx.send((70, i18n::t("app_scan.store").to_string()));
         }
         crate::log!("AppScan", "开始扫描 UWP/Microsoft Store 应用...");
         
@@ -485,7 +599,7 @@ pub mod windows {
 
         let apps_before_dedup = apps.len();
         if let Some(ref tx) = tx {
-            let _ = tx.send((80, format!("找到 {} 个应用，正在去重...", apps.len())));
+            let _ = tx.send((80, i18n::tf("app_scan.dedup", &[&apps.len()])));
         }
         crate::log!("AppScan", "开始去重处理 - 原始应用数: {}", apps_before_dedup);
         
@@ -498,12 +612,12 @@ pub mod windows {
             apps_before_dedup, apps_after_dedup, removed_count, dedup_duration.as_millis());
         
         if let Some(ref tx) = tx {
-            let _ = tx.send((95, format!("去重完成，共 {} 个应用", apps.len())));
+            let _ = tx.send((95, i18n::tf("app_scan.dedup_done", &[&apps.len()])));
         }
         
 
         if let Some(ref tx) = tx {
-            let _ = tx.send((100, "扫描完成".to_string()));
+            let _ = tx.send((100, i18n::t("app_scan.done").to_string()));
         }
 
         let total_duration = scan_start_time.elapsed();
@@ -938,8 +1052,8 @@ pub mod windows {
             // 优化：复用前面的 has_chinese 判断，避免重复调用 contains_chinese
             let (name_pinyin, name_pinyin_initials) = if has_chinese {
                 let pinyin_start = std::time::Instant::now();
-                let pinyin = Some(to_pinyin(name).to_lowercase());
-                let pinyin_initials = Some(to_pinyin_initials(name).to_lowercase());
+                let pinyin = Some(to_pinyin(name));
+                let pinyin_initials = Some(to_pinyin_initials(name));
                 let pinyin_duration = pinyin_start.elapsed();
                 
                 // 如果拼音转换超过50ms，记录警告
@@ -977,8 +1091,9 @@ pub mod windows {
             return Ok(());
         }
 
-        // Limit total number of apps to avoid memory issues (increased to 2000)
+        // 达到安全上限时记录日志，不再静默截断
         if apps.len() >= MAX_APPS {
+            crate::log!("AppScan", "应用数量达到上限 {}，跳过目录: {}", MAX_APPS, dir.display());
             return Ok(());
         }
 
@@ -1028,14 +1143,7 @@ pub mod windows {
                     if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
                         let name_str = name.to_string();
                         // Pre-compute pinyin for faster search (only for Chinese names)
-                        let (name_pinyin, name_pinyin_initials) = if contains_chinese(&name_str) {
-                            (
-                                Some(to_pinyin(&name_str).to_lowercase()),
-                                Some(to_pinyin_initials(&name_str).to_lowercase()),
-                            )
-                        } else {
-                            (None, None)
-                        };
+                        let (name_pinyin, name_pinyin_initials) = crate::pinyin_utils::precompute(&name_str);
                         apps.push(AppInfo {
                             name: name_str,
                             path: path.to_string_lossy().to_string(),
@@ -3373,14 +3481,7 @@ public class IconExtractor {
             .to_string();
 
         // Pre-compute pinyin for faster search (only for Chinese names)
-        let (name_pinyin, name_pinyin_initials) = if contains_chinese(&name) {
-            (
-                Some(to_pinyin(&name).to_lowercase()),
-                Some(to_pinyin_initials(&name).to_lowercase()),
-            )
-        } else {
-            (None, None)
-        };
+        let (name_pinyin, name_pinyin_initials) = crate::pinyin_utils::precompute(&name);
 
         Ok(AppInfo {
             name,
@@ -3392,146 +3493,10 @@ public class IconExtractor {
         })
     }
 
-    // Convert Chinese characters to pinyin (full pinyin)
-    fn to_pinyin(text: &str) -> String {
-        text.to_pinyin()
-            .filter_map(|p| p.map(|p| p.plain()))
-            .collect::<Vec<_>>()
-            .join("")
-    }
-
-    // Convert Chinese characters to pinyin initials (first letter of each pinyin)
-    fn to_pinyin_initials(text: &str) -> String {
-        text.to_pinyin()
-            .filter_map(|p| p.map(|p| p.plain().chars().next()))
-            .flatten()
-            .collect::<String>()
-    }
-
-    // Check if text contains Chinese characters
-    fn contains_chinese(text: &str) -> bool {
-        text.chars().any(|c| {
-            matches!(c as u32,
-                0x4E00..=0x9FFF |  // CJK Unified Ideographs
-                0x3400..=0x4DBF |  // CJK Extension A
-                0x20000..=0x2A6DF | // CJK Extension B
-                0x2A700..=0x2B73F | // CJK Extension C
-                0x2B740..=0x2B81F | // CJK Extension D
-                0xF900..=0xFAFF |  // CJK Compatibility Ideographs
-                0x2F800..=0x2FA1F   // CJK Compatibility Ideographs Supplement
-            )
-        })
-    }
-
+    // 一次性搜索（会临时建立索引）；启动器搜索使用 app_index 中缓存的索引
+    #[allow(dead_code)]
     pub fn search_apps(query: &str, apps: &[AppInfo]) -> Vec<AppInfo> {
-        if query.is_empty() {
-            return apps.iter().take(10).cloned().collect();
-        }
-
-        let query_lower = query.to_lowercase();
-        let query_is_pinyin = !contains_chinese(&query_lower);
-
-        // Pre-allocate with capacity estimate to reduce allocations
-        let mut results: Vec<(usize, i32)> = Vec::with_capacity(MAX_SEARCH_RESULTS);
-        
-        // Track perfect matches for early exit optimization
-        let mut perfect_matches = 0;
-        
-        // Use indices instead of cloning to avoid expensive clones
-        for (idx, app) in apps.iter().enumerate() {
-            let mut score = 0;
-
-            // Direct text match (highest priority) - use case-insensitive comparison
-            // Optimize: compute to_lowercase once per app name
-            let name_lower = app.name.to_lowercase();
-            
-            if name_lower == query_lower {
-                score += 1000;
-                perfect_matches += 1;
-                results.push((idx, score));
-                if perfect_matches >= MAX_PERFECT_MATCHES {
-                    break;
-                }
-            } else if name_lower.starts_with(&query_lower) {
-                score += 500;
-            } else if name_lower.contains(&query_lower) {
-                score += 100;
-            }
-
-            // Pinyin matching (if query is pinyin) - use cached pinyin if available
-            if query_is_pinyin {
-                // Use cached pinyin if available (much faster than computing on the fly)
-                if let (Some(ref name_pinyin), Some(ref name_pinyin_initials)) =
-                    (&app.name_pinyin, &app.name_pinyin_initials)
-                {
-                    // Full pinyin match
-                    if name_pinyin.as_str() == query_lower {
-                        score += 800; // High score for full pinyin match
-                        perfect_matches += 1;
-                        // Early exit if we have enough perfect matches
-                        if perfect_matches >= MAX_PERFECT_MATCHES {
-                            results.push((idx, score));
-                            break;
-                        }
-                    } else if name_pinyin.starts_with(&query_lower) {
-                        score += 400;
-                    } else if name_pinyin.contains(&query_lower) {
-                        score += 150;
-                    }
-
-                    // Pinyin initials match
-                    if name_pinyin_initials.as_str() == query_lower {
-                        score += 600; // High score for initials match
-                    } else if name_pinyin_initials.starts_with(&query_lower) {
-                        score += 300;
-                    } else if name_pinyin_initials.contains(&query_lower) {
-                        score += 120;
-                    }
-                }
-            }
-
-            // Description match (check if query matches description, e.g., "系统设置" matches "Windows 系统设置")
-            if score == 0 {
-                if let Some(ref description) = app.description {
-                    let desc_lower = description.to_lowercase();
-                    if desc_lower.contains(&query_lower) {
-                        score += 150; // Description match gets higher score than path match
-                    }
-                }
-            }
-            
-            // Path match gets lower score (only check if no name or description match to save time)
-            // Use case-insensitive comparison without allocating new string
-            if score == 0 && app.path.len() >= query.len() {
-                if app.path.to_lowercase().contains(&query_lower) {
-                    score += 10;
-                }
-            }
-
-            if score > 0 {
-                results.push((idx, score));
-            }
-        }
-
-        // If we have perfect matches and early exited, return them immediately without sorting
-        let final_results: Vec<AppInfo> = if perfect_matches >= MAX_PERFECT_MATCHES && results.len() <= MAX_PERFECT_MATCHES {
-            results
-                .into_iter()
-                .map(|(idx, _)| apps[idx].clone())
-                .collect()
-        } else {
-            // Sort by score (descending) only if we need to
-            results.sort_by(|a, b| b.1.cmp(&a.1));
-
-            // Limit to top results for performance, clone only the selected apps
-            results
-                .into_iter()
-                .take(MAX_SEARCH_RESULTS)
-                .map(|(idx, _)| apps[idx].clone())
-                .collect()
-        };
-        
-        final_results
+        crate::app_index::AppIndex::build(apps).search(query, apps)
     }
 
     pub fn launch_app(app: &AppInfo) -> Result<(), String> {
@@ -3830,4 +3795,43 @@ mod tests {
         // Exact match should be prioritized
         assert_eq!(results[0].name, "Chrome");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_diff_apps() {
+        let app = |path: &str| AppInfo {
+            name: path.to_string(),
+            path: path.to_string(),
+            icon: None,
+            description: None,
+            name_pinyin: None,
+            name_pinyin_initials: None,
+        };
+        let old = vec![app("C:\\A.exe"), app("C:\\B.exe")];
+        let new = vec![app("c:\\a.exe"), app("C:\\C.exe"), app("C:\\D.exe")];
+        assert_eq!(diff_apps(&old, &new), (2, 1));
+        assert_eq!(diff_apps(&new, &new), (0, 0));
+    }
+
+    #[test]
+    fn test_binary_cache_roundtrip_and_corruption() {
+        let apps = vec![AppInfo {
+            name: "微信".to_string(),
+            path: "C:\\WeChat.exe".to_string(),
+            icon: None,
+            description: Some("WeChat".to_string()),
+            name_pinyin: Some("weixin".to_string()),
+            name_pinyin_initials: None,
+        }];
+        let mut data = encode_cache(&apps).unwrap();
+        let decoded = decode_cache(&data).unwrap();
+        assert_eq!(decoded.len(), 1);
+        assert_eq!(decoded[0].name, "微信");
+        assert_eq!(decoded[0].name_pinyin.as_deref(), Some("weixin"));
+        assert_eq!(decoded[0].name_pinyin_initials, None);
+
+        let last = data.len() - 1;
+        data[last] ^= 0xFF;
+        assert!(decode_cache(&data).is_err());
+        assert!(decode_cache(b"[]").is_err());
+    }
+}
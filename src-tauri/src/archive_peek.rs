@@ -0,0 +1,175 @@
+// 压缩包内容预览：列出 zip 压缩包中的条目，并把选中的单个条目解压到临时目录后打开，无需解压整个压缩包
+// 7z 等其他格式暂不支持，列出时返回明确的错误提示
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+const MAX_LIST_ENTRIES: usize = 5000;
+const TEMP_DIR_NAME: &str = "IMiss-archive-peek";
+const STALE_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ArchiveEntry {
+    pub name: String,
+    pub size: u64,
+    pub compressed_size: u64,
+    pub is_dir: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ArchiveListing {
+    pub entries: Vec<ArchiveEntry>,
+    pub total: usize, // 条目总数，超过 MAX_LIST_ENTRIES 时 entries 只包含前面一部分
+}
+
+fn open_zip(path: &Path) -> Result<zip::ZipArchive<fs::File>, String> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_default();
+    if extension != "zip" {
+        return Err(format!("暂不支持 .{} 格式的压缩包，目前仅支持 zip", extension));
+    }
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open archive: {}", e))?;
+    zip::ZipArchive::new(file).map_err(|e| format!("Failed to read archive: {}", e))
+}
+
+/// 列出压缩包中的条目（按压缩包内的顺序）
+pub fn list_entries(path: &Path) -> Result<ArchiveListing, String> {
+    let mut archive = open_zip(path)?;
+    let total = archive.len();
+    let mut entries = Vec::with_capacity(total.min(MAX_LIST_ENTRIES));
+    for index in 0..total.min(MAX_LIST_ENTRIES) {
+        // 只读取元数据，加密条目不需要密码也能列出
+        let entry = archive
+            .by_index_raw(index)
+            .map_err(|e| format!("Failed to read archive entry: {}", e))?;
+        entries.push(ArchiveEntry {
+            name: entry.name().to_string(),
+            size: entry.size(),
+            compressed_size: entry.compressed_size(),
+            is_dir: entry.is_dir(),
+        });
+    }
+    Ok(ArchiveListing { entries, total })
+}
+
+/// 同一个压缩包（路径 + 修改时间）的条目解压到同一个子目录，压缩包更新后不会打开旧内容
+fn extraction_dir(archive_path: &Path, temp_root: &Path) -> PathBuf {
+    let modified = fs::metadata(archive_path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let mut hasher = Sha256::new();
+    hasher.update(archive_path.to_string_lossy().as_bytes());
+    hasher.update(modified.to_le_bytes());
+    let digest = hasher.finalize();
+    let key: String = digest.iter().take(8).map(|b| format!("{:02x}", b)).collect();
+    temp_root.join(key)
+}
+
+/// 删除超过一天未使用的解压目录
+fn remove_stale_dirs(temp_root: &Path) {
+    let Ok(entries) = fs::read_dir(temp_root) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let is_stale = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.elapsed().ok())
+            .is_some_and(|age| age > STALE_AFTER);
+        if is_stale {
+            let _ = fs::remove_dir_all(entry.path());
+        }
+    }
+}
+
+/// 把单个条目解压到 temp_root 下的临时目录，返回解压后的文件路径
+pub fn extract_entry(archive_path: &Path, entry_name: &str, temp_root: &Path) -> Result<PathBuf, String> {
+    let mut archive = open_zip(archive_path)?;
+    let mut entry = archive
+        .by_name(entry_name)
+        .map_err(|e| format!("Failed to find archive entry {}: {}", entry_name, e))?;
+    if entry.is_dir() {
+        return Err("不能打开压缩包中的文件夹".to_string());
+    }
+    // enclosed_name 会拒绝 "../" 和绝对路径，防止写到临时目录之外
+    let relative = entry
+        .enclosed_name()
+        .map(Path::to_path_buf)
+        .ok_or_else(|| format!("压缩包条目路径不安全: {}", entry_name))?;
+
+    remove_stale_dirs(temp_root);
+    let target = extraction_dir(archive_path, temp_root).join(relative);
+    if fs::metadata(&target).map(|m| m.len() == entry.size()).unwrap_or(false) {
+        return Ok(target);
+    }
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create temp directory: {}", e))?;
+    }
+
+    // 先写入临时文件再重命名，避免解压中断留下不完整的文件被下次直接打开
+    let partial = target.with_extension("partial");
+    let mut output = fs::File::create(&partial).map_err(|e| format!("Failed to create temp file: {}", e))?;
+    if let Err(e) = io::copy(&mut entry, &mut output) {
+        drop(output);
+        let _ = fs::remove_file(&partial);
+        return Err(format!("Failed to extract archive entry: {}", e));
+    }
+    drop(output);
+    fs::rename(&partial, &target).map_err(|e| format!("Failed to move extracted file: {}", e))?;
+    Ok(target)
+}
+
+/// 默认的解压根目录（系统临时目录下）
+pub fn default_temp_root() -> PathBuf {
+    std::env::temp_dir().join(TEMP_DIR_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_zip(path: &Path, files: &[(&str, &[u8])]) {
+        let mut zip = zip::ZipWriter::new(fs::File::create(path).unwrap());
+        let options = zip::write::FileOptions::default();
+        for (name, data) in files {
+            zip.start_file(*name, options).unwrap();
+            zip.write_all(data).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    #[test]
+    fn lists_and_extracts_single_entry() {
+        let dir = std::env::temp_dir().join(format!("imiss-archive-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let archive = dir.join("sample.zip");
+        write_zip(&archive, &[("docs/readme.txt", b"hello"), ("../evil.txt", b"x")]);
+
+        let listing = list_entries(&archive).unwrap();
+        assert_eq!(listing.total, 2);
+        assert_eq!(listing.entries[0].name, "docs/readme.txt");
+        assert_eq!(listing.entries[0].size, 5);
+
+        let temp_root = dir.join("extract");
+        let extracted = extract_entry(&archive, "docs/readme.txt", &temp_root).unwrap();
+        assert!(extracted.starts_with(&temp_root));
+        assert_eq!(fs::read(&extracted).unwrap(), b"hello");
+        assert!(extract_entry(&archive, "../evil.txt", &temp_root).is_err());
+
+        assert!(list_entries(&dir.join("sample.7z")).is_err());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
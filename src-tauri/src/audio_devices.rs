@@ -0,0 +1,254 @@
+// 音频设备：列出输出设备、切换默认输出设备、设置音量与静音（Windows Core Audio）
+// 切换默认设备使用系统"声音"设置同样调用的 IPolicyConfig 接口（未公开文档，Windows 7 起可用）
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AudioDevice {
+    pub id: String,
+    pub name: String,
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct VolumeState {
+    pub volume: u32, // 0..=100
+    pub muted: bool,
+}
+
+/// 按名称查找设备：优先完全匹配，其次名称包含关键字（不区分大小写）
+pub fn find_device<'a>(devices: &'a [AudioDevice], keyword: &str) -> Option<&'a AudioDevice> {
+    let keyword = keyword.trim().to_lowercase();
+    if keyword.is_empty() {
+        return None;
+    }
+    devices
+        .iter()
+        .find(|d| d.name.to_lowercase() == keyword)
+        .or_else(|| devices.iter().find(|d| d.name.to_lowercase().contains(&keyword)))
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::{AudioDevice, VolumeState};
+    use ::windows::core::{GUID, PCWSTR};
+    use ::windows::Win32::Devices::FunctionDiscovery::PKEY_Device_FriendlyName;
+    use ::windows::Win32::Media::Audio::Endpoints::IAudioEndpointVolume;
+    use ::windows::Win32::Media::Audio::{
+        eCommunications, eConsole, eMultimedia, eRender, IMMDevice, IMMDeviceEnumerator,
+        MMDeviceEnumerator, DEVICE_STATE_ACTIVE,
+    };
+    use ::windows::Win32::System::Com::StructuredStorage::{PropVariantClear, PropVariantToStringAlloc};
+    use ::windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, CoTaskMemFree, CoUninitialize, CLSCTX_ALL, COINIT_MULTITHREADED, STGM_READ,
+    };
+    use std::ffi::c_void;
+
+    const CLSID_POLICY_CONFIG: GUID = GUID::from_u128(0x870af99c_171d_4f9e_af0d_e63df40c2bc9);
+
+    // 只调用 SetDefaultEndpoint，前面的方法仅用于保持虚表顺序
+    #[allow(non_snake_case)]
+    mod policy_config {
+        use ::windows::core::{interface, IUnknown, IUnknown_Vtbl, HRESULT, PCWSTR};
+        use ::windows::Win32::Media::Audio::ERole;
+        use std::ffi::c_void;
+
+        #[interface("f8679f50-850a-41cf-9c72-430f290290c8")]
+        pub unsafe trait IPolicyConfig: IUnknown {
+            fn GetMixFormat(&self, device: PCWSTR, format: *mut *mut c_void) -> HRESULT;
+            fn GetDeviceFormat(&self, device: PCWSTR, default: i32, format: *mut *mut c_void) -> HRESULT;
+            fn ResetDeviceFormat(&self, device: PCWSTR) -> HRESULT;
+            fn SetDeviceFormat(&self, device: PCWSTR, endpoint: *mut c_void, mix: *mut c_void) -> HRESULT;
+            fn GetProcessingPeriod(&self, device: PCWSTR, default: i32, period: *mut i64, min: *mut i64) -> HRESULT;
+            fn SetProcessingPeriod(&self, device: PCWSTR, period: *mut i64) -> HRESULT;
+            fn GetShareMode(&self, device: PCWSTR, mode: *mut c_void) -> HRESULT;
+            fn SetShareMode(&self, device: PCWSTR, mode: *mut c_void) -> HRESULT;
+            fn GetPropertyValue(&self, device: PCWSTR, key: *const c_void, value: *mut c_void) -> HRESULT;
+            fn SetPropertyValue(&self, device: PCWSTR, key: *const c_void, value: *mut c_void) -> HRESULT;
+            fn SetDefaultEndpoint(&self, device: PCWSTR, role: ERole) -> HRESULT;
+            fn SetEndpointVisibility(&self, device: PCWSTR, visible: i32) -> HRESULT;
+        }
+
+        pub fn set_default_endpoint(policy: &IPolicyConfig, device: PCWSTR, role: ERole) -> ::windows::core::Result<()> {
+            unsafe { policy.SetDefaultEndpoint(device, role) }.ok()
+        }
+    }
+    use policy_config::IPolicyConfig;
+
+    /// 在当前线程初始化 COM，离开作用域时释放
+    struct ComGuard(bool);
+
+    impl ComGuard {
+        fn new() -> Self {
+            // 线程已按其他模式初始化时返回 RPC_E_CHANGED_MODE，此时不需要（也不能）释放
+            ComGuard(unsafe { CoInitializeEx(None, COINIT_MULTITHREADED) }.is_ok())
+        }
+    }
+
+    impl Drop for ComGuard {
+        fn drop(&mut self) {
+            if self.0 {
+                unsafe { CoUninitialize() };
+            }
+        }
+    }
+
+    fn enumerator() -> Result<IMMDeviceEnumerator, String> {
+        unsafe { CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL) }
+            .map_err(|e| format!("Failed to create device enumerator: {}", e))
+    }
+
+    fn device_id(device: &IMMDevice) -> Result<String, String> {
+        unsafe {
+            let id = device.GetId().map_err(|e| format!("Failed to get device id: {}", e))?;
+            let result = id.to_string().map_err(|e| format!("Invalid device id: {}", e));
+            CoTaskMemFree(Some(id.0 as *const c_void));
+            result
+        }
+    }
+
+    fn device_name(device: &IMMDevice) -> Result<String, String> {
+        unsafe {
+            let store = device
+                .OpenPropertyStore(STGM_READ)
+                .map_err(|e| format!("Failed to open property store: {}", e))?;
+            let mut value = store
+                .GetValue(&PKEY_Device_FriendlyName)
+                .map_err(|e| format!("Failed to read device name: {}", e))?;
+            let name = PropVariantToStringAlloc(&value).map_err(|e| format!("Failed to read device name: {}", e));
+            let _ = PropVariantClear(&mut value);
+            let name = name?;
+            let result = name.to_string().map_err(|e| format!("Invalid device name: {}", e));
+            CoTaskMemFree(Some(name.0 as *const c_void));
+            result
+        }
+    }
+
+    fn default_device(enumerator: &IMMDeviceEnumerator) -> Result<IMMDevice, String> {
+        unsafe { enumerator.GetDefaultAudioEndpoint(eRender, eConsole) }
+            .map_err(|e| format!("没有可用的音频输出设备: {}", e))
+    }
+
+    fn endpoint_volume() -> Result<IAudioEndpointVolume, String> {
+        let device = default_device(&enumerator()?)?;
+        unsafe { device.Activate(CLSCTX_ALL, None) }.map_err(|e| format!("Failed to activate endpoint volume: {}", e))
+    }
+
+    pub fn list_output_devices() -> Result<Vec<AudioDevice>, String> {
+        let _com = ComGuard::new();
+        let enumerator = enumerator()?;
+        let default_id = default_device(&enumerator).and_then(|d| device_id(&d)).ok();
+        let collection = unsafe { enumerator.EnumAudioEndpoints(eRender, DEVICE_STATE_ACTIVE) }
+            .map_err(|e| format!("Failed to enumerate audio devices: {}", e))?;
+        let count = unsafe { collection.GetCount() }.map_err(|e| format!("Failed to count audio devices: {}", e))?;
+        let mut devices = Vec::with_capacity(count as usize);
+        for index in 0..count {
+            let device = unsafe { collection.Item(index) }.map_err(|e| format!("Failed to get audio device: {}", e))?;
+            let id = device_id(&device)?;
+            devices.push(AudioDevice {
+                name: device_name(&device).unwrap_or_else(|_| id.clone()),
+                is_default: default_id.as_deref() == Some(id.as_str()),
+                id,
+            });
+        }
+        Ok(devices)
+    }
+
+    pub fn set_default_output_device(id: &str) -> Result<(), String> {
+        let _com = ComGuard::new();
+        let policy: IPolicyConfig = unsafe { CoCreateInstance(&CLSID_POLICY_CONFIG, None, CLSCTX_ALL) }
+            .map_err(|e| format!("Failed to create policy config: {}", e))?;
+        let wide: Vec<u16> = id.encode_utf16().chain(Some(0)).collect();
+        // 与系统设置一致，同时设置为默认设备和默认通信设备
+        for role in [eConsole, eMultimedia, eCommunications] {
+            policy_config::set_default_endpoint(&policy, PCWSTR(wide.as_ptr()), role)
+                .map_err(|e| format!("切换默认音频设备失败: {}", e))?;
+        }
+        Ok(())
+    }
+
+    pub fn volume() -> Result<VolumeState, String> {
+        let _com = ComGuard::new();
+        let endpoint = endpoint_volume()?;
+        unsafe {
+            let level = endpoint
+                .GetMasterVolumeLevelScalar()
+                .map_err(|e| format!("Failed to get volume: {}", e))?;
+            let muted = endpoint.GetMute().map_err(|e| format!("Failed to get mute state: {}", e))?;
+            Ok(VolumeState { volume: (level * 100.0).round() as u32, muted: muted.as_bool() })
+        }
+    }
+
+    pub fn set_volume(volume: u32) -> Result<(), String> {
+        let _com = ComGuard::new();
+        let endpoint = endpoint_volume()?;
+        let level = volume.min(100) as f32 / 100.0;
+        unsafe { endpoint.SetMasterVolumeLevelScalar(level, std::ptr::null()) }
+            .map_err(|e| format!("设置音量失败: {}", e))
+    }
+
+    pub fn set_mute(muted: bool) -> Result<(), String> {
+        let _com = ComGuard::new();
+        let endpoint = endpoint_volume()?;
+        unsafe { endpoint.SetMute(muted, std::ptr::null()) }.map_err(|e| format!("设置静音失败: {}", e))
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    use super::{AudioDevice, VolumeState};
+
+    const UNSUPPORTED: &str = "当前平台不支持音频设备管理";
+
+    pub fn list_output_devices() -> Result<Vec<AudioDevice>, String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn set_default_output_device(_id: &str) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn volume() -> Result<VolumeState, String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn set_volume(_volume: u32) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn set_mute(_muted: bool) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+}
+
+pub use platform::{list_output_devices, set_mute, set_volume, volume};
+
+/// 切换默认输出设备，target 可以是设备 id 或名称关键字；返回切换到的设备
+pub fn switch_output_device(target: &str) -> Result<AudioDevice, String> {
+    let devices = list_output_devices()?;
+    let device = devices
+        .iter()
+        .find(|d| d.id == target)
+        .or_else(|| find_device(&devices, target))
+        .ok_or_else(|| format!("未找到音频设备: {}", target))?;
+    platform::set_default_output_device(&device.id)?;
+    Ok(AudioDevice { is_default: true, ..device.clone() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(name: &str) -> AudioDevice {
+        AudioDevice { id: name.to_string(), name: name.to_string(), is_default: false }
+    }
+
+    #[test]
+    fn finds_device_by_keyword() {
+        let devices = vec![device("扬声器 (Realtek Audio)"), device("Headphones (WH-1000XM4)"), device("Headphones")];
+        assert_eq!(find_device(&devices, "headphones").unwrap().name, "Headphones");
+        assert_eq!(find_device(&devices, "realtek").unwrap().name, "扬声器 (Realtek Audio)");
+        assert!(find_device(&devices, "hdmi").is_none());
+        assert!(find_device(&devices, " ").is_none());
+    }
+}
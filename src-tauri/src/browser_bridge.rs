@@ -0,0 +1,626 @@
+// 浏览器扩展桥接：扩展通过 native messaging 启动本程序作为宿主，宿主再经本机回环端口把消息转给正在运行的 IMiss
+// 支持把当前页面链接或选中文本加入剪切板历史/单词本、搜索本地数据和唤起启动器；宿主清单写入应用数据目录并在注册表中登记
+
+use crate::{clipboard, memos, shutdown, word_records};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+pub const HOST_NAME: &str = "com.re_fast.browser_bridge";
+pub const SET_QUERY_EVENT: &str = "launcher://set-query";
+const BRIDGE_TAG: &str = "浏览器";
+const MAX_MESSAGE_BYTES: usize = 8 * 1024 * 1024;
+const DEFAULT_SEARCH_LIMIT: usize = 10;
+const MAX_SEARCH_LIMIT: usize = 50;
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(30);
+
+// 已监听的端口，0 表示未启动
+static PORT: AtomicU16 = AtomicU16::new(0);
+
+/// 扩展所属的浏览器类型：Chromium 系（Chrome、Edge）用 allowed_origins，Firefox 用 allowed_extensions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserFamily {
+    Chromium,
+    Firefox,
+}
+
+impl BrowserFamily {
+    fn manifest_file(self) -> String {
+        match self {
+            BrowserFamily::Chromium => format!("{}.json", HOST_NAME),
+            BrowserFamily::Firefox => format!("{}.firefox.json", HOST_NAME),
+        }
+    }
+
+    /// (浏览器名, 注册表中 NativeMessagingHosts 所在的键)
+    fn registry_roots(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            BrowserFamily::Chromium => &[
+                ("chrome", "Software\\Google\\Chrome\\NativeMessagingHosts"),
+                ("edge", "Software\\Microsoft\\Edge\\NativeMessagingHosts"),
+            ],
+            BrowserFamily::Firefox => &[("firefox", "Software\\Mozilla\\NativeMessagingHosts")],
+        }
+    }
+}
+
+/// 判断扩展 ID 的类型：Chromium 扩展 ID 为 32 位 a-p 字母，Firefox 为 name@domain 或 {GUID}
+pub fn classify_extension_id(id: &str) -> Option<BrowserFamily> {
+    if id.len() == 32 && id.bytes().all(|b| (b'a'..=b'p').contains(&b)) {
+        return Some(BrowserFamily::Chromium);
+    }
+    let is_guid = id.starts_with('{')
+        && id.ends_with('}')
+        && id.len() > 2
+        && id[1..id.len() - 1].chars().all(|c| c.is_ascii_hexdigit() || c == '-');
+    let is_email_like = id.split_once('@').is_some_and(|(name, domain)| {
+        !domain.is_empty() && !name.contains(char::is_whitespace) && !domain.contains(char::is_whitespace)
+    });
+    (is_guid || is_email_like).then_some(BrowserFamily::Firefox)
+}
+
+/// 生成宿主清单
+pub fn build_manifest(family: BrowserFamily, exe_path: &str, ids: &[String]) -> Value {
+    let mut manifest = json!({
+        "name": HOST_NAME,
+        "description": "IMiss browser bridge",
+        "path": exe_path,
+        "type": "stdio",
+    });
+    match family {
+        BrowserFamily::Chromium => {
+            manifest["allowed_origins"] = json!(ids.iter().map(|id| format!("chrome-extension://{}/", id)).collect::<Vec<_>>());
+        }
+        BrowserFamily::Firefox => manifest["allowed_extensions"] = json!(ids),
+    }
+    manifest
+}
+
+/// 从清单中读出已授权的扩展 ID
+fn manifest_ids(manifest: &Value) -> Vec<String> {
+    let origins = manifest["allowed_origins"].as_array().into_iter().flatten().filter_map(|v| {
+        v.as_str()?.strip_prefix("chrome-extension://").map(|id| id.trim_end_matches('/').to_string())
+    });
+    let extensions = manifest["allowed_extensions"].as_array().into_iter().flatten().filter_map(|v| v.as_str().map(str::to_string));
+    origins.chain(extensions).collect()
+}
+
+/// 是否由浏览器作为 native messaging 宿主启动：
+/// Chrome/Edge 传入 chrome-extension://<id>/，Firefox 的第一个参数为宿主清单路径
+pub fn is_native_host_invocation(args: &[String]) -> bool {
+    if args.iter().skip(1).any(|arg| arg.starts_with("chrome-extension://")) {
+        return true;
+    }
+    args.get(1)
+        .and_then(|arg| arg.rsplit(['/', '\\']).next())
+        .is_some_and(|name| name.starts_with(HOST_NAME) && name.ends_with(".json"))
+}
+
+/// 读取一条 native messaging 消息（4 字节本机字节序长度 + UTF-8 JSON），流结束时返回 None
+pub fn read_message(reader: &mut impl Read) -> Result<Option<Value>, String> {
+    let mut len_buf = [0u8; 4];
+    match reader.read_exact(&mut len_buf) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(format!("Failed to read message length: {}", e)),
+    }
+    let len = u32::from_ne_bytes(len_buf) as usize;
+    if len > MAX_MESSAGE_BYTES {
+        return Err(format!("Message too large: {} bytes", len));
+    }
+    let mut body = vec![0u8; len];
+    reader
+        .read_exact(&mut body)
+        .map_err(|e| format!("Failed to read message body: {}", e))?;
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse message: {}", e))
+}
+
+/// 写入一条 native messaging 消息
+pub fn write_message(writer: &mut impl Write, message: &Value) -> Result<(), String> {
+    let body = serde_json::to_vec(message).map_err(|e| format!("Failed to serialize message: {}", e))?;
+    writer
+        .write_all(&(body.len() as u32).to_ne_bytes())
+        .and_then(|_| writer.write_all(&body))
+        .and_then(|_| writer.flush())
+        .map_err(|e| format!("Failed to write message: {}", e))
+}
+
+fn error_response(error: impl Into<String>) -> Value {
+    json!({ "ok": false, "error": error.into() })
+}
+
+// ---------- 运行中的应用：本机回环端口 ----------
+
+/// 宿主进程与运行中的应用之间的连接信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BridgeInfo {
+    port: u16,
+    token: String,
+    pid: u32,
+}
+
+/// 连接信息文件，与单实例锁文件放在同一目录，宿主进程无需知道应用数据目录
+fn bridge_info_path() -> PathBuf {
+    #[cfg(target_os = "windows")]
+    {
+        if let Ok(appdata) = std::env::var("APPDATA") {
+            return PathBuf::from(appdata).join("ReFast").join("browser-bridge.json");
+        }
+    }
+    std::env::temp_dir().join("re-fast-browser-bridge.json")
+}
+
+/// 启动桥接服务：监听 127.0.0.1 的随机端口，并把端口和口令写入连接信息文件
+pub fn start_server(app: AppHandle, app_data_dir: PathBuf) -> Result<(), String> {
+    let listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)))
+        .map_err(|e| format!("Failed to bind browser bridge: {}", e))?;
+    listener
+        .set_nonblocking(true)
+        .map_err(|e| format!("Failed to configure browser bridge: {}", e))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| format!("Failed to get browser bridge address: {}", e))?
+        .port();
+    let token: String = (0..32).map(|_| format!("{:x}", rand::thread_rng().gen_range(0..16u8))).collect();
+
+    let info_path = bridge_info_path();
+    if let Some(parent) = info_path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let info = BridgeInfo { port, token: token.clone(), pid: std::process::id() };
+    let info_json = serde_json::to_string(&info).map_err(|e| format!("Failed to serialize bridge info: {}", e))?;
+    std::fs::write(&info_path, info_json).map_err(|e| format!("Failed to write bridge info: {}", e))?;
+    PORT.store(port, Ordering::SeqCst);
+    shutdown::on_shutdown("browser-bridge", move || {
+        PORT.store(0, Ordering::SeqCst);
+        let _ = std::fs::remove_file(&info_path);
+    });
+
+    shutdown::spawn("browser-bridge", move || loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let app = app.clone();
+                let app_data_dir = app_data_dir.clone();
+                let token = token.clone();
+                std::thread::spawn(move || {
+                    if let Err(e) = serve_connection(stream, &token, &app, &app_data_dir) {
+                        eprintln!("[BrowserBridge] Connection error: {}", e);
+                    }
+                });
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                if shutdown::wait_timeout(Duration::from_millis(200)) {
+                    break;
+                }
+            }
+            Err(e) => {
+                eprintln!("[BrowserBridge] Failed to accept connection: {}", e);
+                if shutdown::wait_timeout(Duration::from_secs(1)) {
+                    break;
+                }
+            }
+        }
+    })
+}
+
+pub fn is_running() -> bool {
+    PORT.load(Ordering::SeqCst) != 0
+}
+
+/// 一个连接上可以连续发送多条请求，每行一个 {"token": ..., "message": ...}，每行返回一个响应
+fn serve_connection(stream: TcpStream, token: &str, app: &AppHandle, app_data_dir: &PathBuf) -> Result<(), String> {
+    stream
+        .set_nonblocking(false)
+        .and_then(|_| stream.set_read_timeout(Some(CONNECTION_TIMEOUT)))
+        .map_err(|e| format!("Failed to configure connection: {}", e))?;
+    let mut writer = stream.try_clone().map_err(|e| format!("Failed to clone connection: {}", e))?;
+    let mut reader = BufReader::new(stream).take(MAX_MESSAGE_BYTES as u64 * 2);
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).map_err(|e| format!("Failed to read request: {}", e))? == 0 {
+            return Ok(());
+        }
+        let response = match serde_json::from_str::<Value>(&line) {
+            Ok(request) if request["token"].as_str() == Some(token) => handle_message(&request["message"], app, app_data_dir),
+            Ok(_) => error_response("口令无效"),
+            Err(e) => error_response(format!("请求格式错误: {}", e)),
+        };
+        let mut out = serde_json::to_string(&response).map_err(|e| format!("Failed to serialize response: {}", e))?;
+        out.push('\n');
+        writer
+            .write_all(out.as_bytes())
+            .map_err(|e| format!("Failed to write response: {}", e))?;
+    }
+}
+
+fn str_field<'a>(message: &'a Value, key: &str) -> &'a str {
+    message[key].as_str().map(str::trim).unwrap_or_default()
+}
+
+fn optional_field(message: &Value, key: &str) -> Option<String> {
+    Some(str_field(message, key)).filter(|s| !s.is_empty()).map(str::to_string)
+}
+
+/// 处理扩展发来的消息，返回 {"ok": true, "data": ...} 或 {"ok": false, "error": ...}
+fn handle_message(message: &Value, app: &AppHandle, app_data_dir: &PathBuf) -> Value {
+    let result = match str_field(message, "type") {
+        "ping" => Ok(json!({ "version": app.package_info().version.to_string() })),
+        "clip" => add_clip(message, app, app_data_dir),
+        "word" => add_word(message, app, app_data_dir),
+        "search" => search(message, app_data_dir),
+        "open_launcher" => open_launcher(message, app),
+        "" => Err("缺少消息类型".to_string()),
+        other => Err(format!("不支持的消息类型: {}", other)),
+    };
+    match result {
+        Ok(data) => json!({ "ok": true, "data": data }),
+        Err(e) => error_response(e),
+    }
+}
+
+/// 选中文本优先，没有选中时保存页面链接；统一打上「浏览器」标签便于筛选
+fn add_clip(message: &Value, app: &AppHandle, app_data_dir: &PathBuf) -> Result<Value, String> {
+    let content = optional_field(message, "text")
+        .or_else(|| optional_field(message, "url"))
+        .ok_or_else(|| "没有可保存的内容".to_string())?;
+    let mut item = clipboard::add_clipboard_item(content, "text".to_string(), app_data_dir)?;
+    clipboard::tag_clipboard_items(&[item.id.clone()], &[BRIDGE_TAG.to_string()], false, app_data_dir)?;
+    item.tags.push(BRIDGE_TAG.to_string());
+    let item = clipboard::mask_item(item);
+    #[cfg(target_os = "windows")]
+    if let Err(e) = app.emit(clipboard::monitor::NEW_ITEM_EVENT, &item) {
+        eprintln!("[BrowserBridge] Failed to emit clipboard event: {}", e);
+    }
+    #[cfg(not(target_os = "windows"))]
+    let _ = app;
+    serde_json::to_value(&item).map_err(|e| format!("Failed to serialize clipboard item: {}", e))
+}
+
+/// 加入单词本；未提供上下文时用页面标题和链接作为来源
+fn add_word(message: &Value, app: &AppHandle, app_data_dir: &Path) -> Result<Value, String> {
+    let word = optional_field(message, "word").ok_or_else(|| "单词不能为空".to_string())?;
+    let context = optional_field(message, "context").or_else(|| {
+        let source = [optional_field(message, "title"), optional_field(message, "url")]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!source.is_empty()).then_some(source)
+    });
+    let record = word_records::add_word_record(
+        word,
+        optional_field(message, "translation").unwrap_or_default(),
+        context,
+        None,
+        None,
+        vec![BRIDGE_TAG.to_string()],
+        app_data_dir,
+    )?;
+    word_records::notify_change(app, word_records::CREATED_EVENT, &record);
+    serde_json::to_value(&record).map_err(|e| format!("Failed to serialize word record: {}", e))
+}
+
+fn search(message: &Value, app_data_dir: &PathBuf) -> Result<Value, String> {
+    let query = str_field(message, "query");
+    if query.is_empty() {
+        return Err("搜索内容不能为空".to_string());
+    }
+    let limit = message["limit"]
+        .as_u64()
+        .map(|n| (n as usize).clamp(1, MAX_SEARCH_LIMIT))
+        .unwrap_or(DEFAULT_SEARCH_LIMIT);
+    let memos: Vec<_> = memos::search_memos(query, app_data_dir)?.into_iter().take(limit).collect();
+    let words: Vec<_> = word_records::search_word_records(query, app_data_dir)?.into_iter().take(limit).collect();
+    let clipboard: Vec<_> = clipboard::search_clipboard_items(query, app_data_dir)?.into_iter().take(limit).collect();
+    let clipboard = clipboard::mask_items(clipboard);
+    Ok(json!({ "memos": memos, "words": words, "clipboard": clipboard }))
+}
+
+/// 显示启动器并填入查询内容
+fn open_launcher(message: &Value, app: &AppHandle) -> Result<Value, String> {
+    let window = app
+        .get_webview_window("launcher")
+        .ok_or_else(|| "Launcher window not found".to_string())?;
+    let _ = window.show();
+    let _ = window.set_focus();
+    if let Some(query) = optional_field(message, "query") {
+        window
+            .emit(SET_QUERY_EVENT, query)
+            .map_err(|e| format!("Failed to emit {} event: {}", SET_QUERY_EVENT, e))?;
+    }
+    Ok(Value::Null)
+}
+
+// ---------- 宿主进程：stdio 与回环端口之间转发 ----------
+
+fn forward(info: &BridgeInfo, message: Value) -> Result<Value, String> {
+    let mut stream = TcpStream::connect_timeout(&SocketAddr::from((Ipv4Addr::LOCALHOST, info.port)), Duration::from_secs(2))
+        .map_err(|_| "IMiss 未运行".to_string())?;
+    stream
+        .set_read_timeout(Some(CONNECTION_TIMEOUT))
+        .map_err(|e| format!("Failed to configure connection: {}", e))?;
+    let mut request = serde_json::to_string(&json!({ "token": info.token, "message": message }))
+        .map_err(|e| format!("Failed to serialize request: {}", e))?;
+    request.push('\n');
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+    let mut line = String::new();
+    BufReader::new(stream)
+        .read_line(&mut line)
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+    serde_json::from_str(&line).map_err(|e| format!("Failed to parse response: {}", e))
+}
+
+/// 作为 native messaging 宿主运行，直到浏览器关闭标准输入；返回进程退出码
+pub fn run_native_host() -> i32 {
+    let mut stdin = std::io::stdin().lock();
+    let mut stdout = std::io::stdout().lock();
+    loop {
+        let message = match read_message(&mut stdin) {
+            Ok(Some(message)) => message,
+            Ok(None) => return 0,
+            Err(e) => {
+                let _ = write_message(&mut stdout, &error_response(e));
+                return 1;
+            }
+        };
+        // 每条消息重新读取连接信息，应用重启后无需重新打开扩展
+        let response = std::fs::read_to_string(bridge_info_path())
+            .ok()
+            .and_then(|text| serde_json::from_str::<BridgeInfo>(&text).ok())
+            .ok_or_else(|| "IMiss 未运行".to_string())
+            .and_then(|info| forward(&info, message))
+            .unwrap_or_else(error_response);
+        if write_message(&mut stdout, &response).is_err() {
+            return 1;
+        }
+    }
+}
+
+// ---------- 宿主注册 ----------
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BridgeStatus {
+    pub running: bool,
+    pub extension_ids: Vec<String>,
+    pub registered_browsers: Vec<String>,
+    pub manifest_dir: String,
+    pub exe_matches: bool, // 清单中的程序路径是否为当前程序，程序移动后需要重新注册
+}
+
+fn manifest_dir(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("browser-bridge")
+}
+
+fn exe_path() -> Result<String, String> {
+    std::env::current_exe()
+        .map_err(|e| format!("Failed to get current exe path: {}", e))?
+        .to_str()
+        .ok_or_else(|| "Invalid exe path encoding".to_string())
+        .map(str::to_string)
+}
+
+fn read_manifest(path: &Path) -> Option<Value> {
+    serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()
+}
+
+/// 为给定的扩展 ID 写入宿主清单并在注册表中登记；没有对应 ID 的浏览器会被取消登记
+pub fn register(extension_ids: &[String], app_data_dir: &Path) -> Result<BridgeStatus, String> {
+    let mut chromium = Vec::new();
+    let mut firefox = Vec::new();
+    for id in extension_ids.iter().map(|id| id.trim()).filter(|id| !id.is_empty()) {
+        match classify_extension_id(id) {
+            Some(BrowserFamily::Chromium) => chromium.push(id.to_string()),
+            Some(BrowserFamily::Firefox) => firefox.push(id.to_string()),
+            None => return Err(format!("无法识别的扩展 ID: {}", id)),
+        }
+    }
+    if chromium.is_empty() && firefox.is_empty() {
+        return Err("请至少填写一个扩展 ID".to_string());
+    }
+    for ids in [&mut chromium, &mut firefox] {
+        ids.sort();
+        ids.dedup();
+    }
+
+    let exe = exe_path()?;
+    let dir = manifest_dir(app_data_dir);
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create manifest dir: {}", e))?;
+    for (family, ids) in [(BrowserFamily::Chromium, &chromium), (BrowserFamily::Firefox, &firefox)] {
+        let path = dir.join(family.manifest_file());
+        if ids.is_empty() {
+            unregister_family(family, &path)?;
+            continue;
+        }
+        let manifest = serde_json::to_string_pretty(&build_manifest(family, &exe, ids))
+            .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+        std::fs::write(&path, manifest).map_err(|e| format!("Failed to write manifest: {}", e))?;
+        let path_str = path.to_str().ok_or_else(|| "Invalid manifest path encoding".to_string())?;
+        for (_, root) in family.registry_roots() {
+            registry::set_default_value(&format!("{}\\{}", root, HOST_NAME), path_str)?;
+        }
+    }
+    get_status(app_data_dir)
+}
+
+fn unregister_family(family: BrowserFamily, manifest_path: &Path) -> Result<(), String> {
+    for (_, root) in family.registry_roots() {
+        registry::delete_key(&format!("{}\\{}", root, HOST_NAME))?;
+    }
+    match std::fs::remove_file(manifest_path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(format!("Failed to remove manifest: {}", e)),
+        _ => Ok(()),
+    }
+}
+
+/// 删除所有注册表登记和宿主清单
+pub fn unregister(app_data_dir: &Path) -> Result<BridgeStatus, String> {
+    let dir = manifest_dir(app_data_dir);
+    for family in [BrowserFamily::Chromium, BrowserFamily::Firefox] {
+        unregister_family(family, &dir.join(family.manifest_file()))?;
+    }
+    get_status(app_data_dir)
+}
+
+pub fn get_status(app_data_dir: &Path) -> Result<BridgeStatus, String> {
+    let dir = manifest_dir(app_data_dir);
+    let exe = exe_path().ok();
+    let mut status = BridgeStatus {
+        running: is_running(),
+        extension_ids: Vec::new(),
+        registered_browsers: Vec::new(),
+        manifest_dir: dir.to_string_lossy().to_string(),
+        exe_matches: true,
+    };
+    for family in [BrowserFamily::Chromium, BrowserFamily::Firefox] {
+        let path = dir.join(family.manifest_file());
+        let Some(manifest) = read_manifest(&path) else { continue };
+        status.extension_ids.extend(manifest_ids(&manifest));
+        if manifest["path"].as_str().map(|p| p.to_lowercase()) != exe.as_ref().map(|e| e.to_lowercase()) {
+            status.exe_matches = false;
+        }
+        let path_str = path.to_string_lossy().to_lowercase();
+        for (browser, root) in family.registry_roots() {
+            if registry::get_default_value(&format!("{}\\{}", root, HOST_NAME)).is_some_and(|v| v.to_lowercase() == path_str) {
+                status.registered_browsers.push(browser.to_string());
+            }
+        }
+    }
+    Ok(status)
+}
+
+#[cfg(target_os = "windows")]
+mod registry {
+    use std::ffi::OsStr;
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::System::Registry::{
+        RegDeleteKeyW, RegGetValueW, RegSetKeyValueW, HKEY_CURRENT_USER, REG_SZ, RRF_RT_REG_SZ,
+    };
+
+    const ERROR_FILE_NOT_FOUND: u32 = 2;
+
+    fn to_wide_string(s: &str) -> Vec<u16> {
+        OsStr::new(s).encode_wide().chain(std::iter::once(0)).collect()
+    }
+
+    /// 设置键的默认值（键不存在时自动创建）
+    pub fn set_default_value(sub_key: &str, value: &str) -> Result<(), String> {
+        let sub_key_wide = to_wide_string(sub_key);
+        let value_wide = to_wide_string(value);
+        let result = unsafe {
+            RegSetKeyValueW(
+                HKEY_CURRENT_USER,
+                sub_key_wide.as_ptr(),
+                std::ptr::null(),
+                REG_SZ,
+                value_wide.as_ptr() as *const _,
+                (value_wide.len() * std::mem::size_of::<u16>()) as u32,
+            )
+        };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(format!("Failed to set registry value: error code {}", result))
+        }
+    }
+
+    pub fn get_default_value(sub_key: &str) -> Option<String> {
+        let sub_key_wide = to_wide_string(sub_key);
+        let mut buffer = vec![0u16; 1024];
+        let mut size = (buffer.len() * 2) as u32;
+        let result = unsafe {
+            RegGetValueW(
+                HKEY_CURRENT_USER,
+                sub_key_wide.as_ptr(),
+                std::ptr::null(),
+                RRF_RT_REG_SZ,
+                std::ptr::null_mut(),
+                buffer.as_mut_ptr() as *mut _,
+                &mut size,
+            )
+        };
+        if result != 0 {
+            return None;
+        }
+        let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+        Some(String::from_utf16_lossy(&buffer[..len]))
+    }
+
+    /// 删除键，不存在时视为成功
+    pub fn delete_key(sub_key: &str) -> Result<(), String> {
+        let sub_key_wide = to_wide_string(sub_key);
+        let result = unsafe { RegDeleteKeyW(HKEY_CURRENT_USER, sub_key_wide.as_ptr()) };
+        if result == 0 || result == ERROR_FILE_NOT_FOUND {
+            Ok(())
+        } else {
+            Err(format!("Failed to delete registry key: error code {}", result))
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod registry {
+    pub fn set_default_value(_sub_key: &str, _value: &str) -> Result<(), String> {
+        Err("Not implemented for this platform".to_string())
+    }
+
+    pub fn get_default_value(_sub_key: &str) -> Option<String> {
+        None
+    }
+
+    pub fn delete_key(_sub_key: &str) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_native_messages() {
+        let message = json!({ "type": "clip", "text": "你好" });
+        let mut buf = Vec::new();
+        write_message(&mut buf, &message).unwrap();
+        assert_eq!(u32::from_ne_bytes(buf[..4].try_into().unwrap()) as usize, buf.len() - 4);
+
+        let mut reader = std::io::Cursor::new(buf);
+        assert_eq!(read_message(&mut reader).unwrap(), Some(message));
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+
+        let mut oversized = std::io::Cursor::new(u32::MAX.to_ne_bytes().to_vec());
+        assert!(read_message(&mut oversized).is_err());
+    }
+
+    #[test]
+    fn detects_host_invocation_and_extension_ids() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert!(is_native_host_invocation(&args(&["imiss.exe", "chrome-extension://abcdefghijklmnopabcdefghijklmnop/", "--parent-window=0"])));
+        assert!(is_native_host_invocation(&args(&["imiss.exe", "C:\\data\\browser-bridge\\com.re_fast.browser_bridge.firefox.json", "bridge@imiss"])));
+        assert!(!is_native_host_invocation(&args(&["imiss.exe"])));
+        assert!(!is_native_host_invocation(&args(&["imiss.exe", "settings.json"])));
+
+        assert_eq!(classify_extension_id("abcdefghijklmnopabcdefghijklmnop"), Some(BrowserFamily::Chromium));
+        assert_eq!(classify_extension_id("bridge@imiss.app"), Some(BrowserFamily::Firefox));
+        assert_eq!(classify_extension_id("{d3b07384-d9a0-4c9b-8f5e-123456789abc}"), Some(BrowserFamily::Firefox));
+        assert_eq!(classify_extension_id("abcdefghijklmnopabcdefghijklmnoz"), None);
+        assert_eq!(classify_extension_id("not an id"), None);
+
+        let ids = vec!["abcdefghijklmnopabcdefghijklmnop".to_string()];
+        let manifest = build_manifest(BrowserFamily::Chromium, "C:\\IMiss\\imiss.exe", &ids);
+        assert_eq!(manifest["allowed_origins"][0], "chrome-extension://abcdefghijklmnopabcdefghijklmnop/");
+        assert_eq!(manifest_ids(&manifest), ids);
+        let ids = vec!["bridge@imiss.app".to_string()];
+        assert_eq!(manifest_ids(&build_manifest(BrowserFamily::Firefox, "imiss.exe", &ids)), ids);
+    }
+}
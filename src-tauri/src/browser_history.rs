@@ -0,0 +1,225 @@
+// 浏览器历史搜索：读取 Chrome / Edge / Brave 各配置文件的 History 数据库，按访问次数与最近访问排序
+// 浏览器运行时数据库被锁定，先复制到临时目录再查询；副本缓存一段时间，避免每次输入都复制
+
+use rusqlite::{params, Connection, OpenFlags};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+// 副本刷新间隔
+const SNAPSHOT_TTL: Duration = Duration::from_secs(60);
+// Chromium 时间戳（1601-01-01 起的微秒）与 Unix 时间戳的秒数差
+const CHROMIUM_EPOCH_OFFSET_SECS: i64 = 11_644_473_600;
+
+// (浏览器名称, 相对 %LOCALAPPDATA% 的 User Data 目录)
+const CHROMIUM_BROWSERS: &[(&str, &str)] = &[
+    ("Chrome", r"Google\Chrome\User Data"),
+    ("Edge", r"Microsoft\Edge\User Data"),
+    ("Brave", r"BraveSoftware\Brave-Browser\User Data"),
+];
+
+// 最近一次复制的副本：(复制时间, [(浏览器名称, 副本路径)])
+type Snapshots = Option<(Instant, Vec<(String, PathBuf)>)>;
+static SNAPSHOTS: LazyLock<Mutex<Snapshots>> = LazyLock::new(|| Mutex::new(None));
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BrowserHistoryItem {
+    pub url: String,
+    pub title: String,
+    pub browser: String,
+    pub visit_count: i64,
+    pub last_visit: i64, // Unix 时间戳（秒）
+}
+
+pub fn chromium_time_to_unix(micros: i64) -> i64 {
+    if micros <= 0 {
+        return 0;
+    }
+    micros / 1_000_000 - CHROMIUM_EPOCH_OFFSET_SECS
+}
+
+/// 所有 Chromium 系浏览器配置文件（Default、Profile N）中的 History 文件
+fn history_files() -> Vec<(String, PathBuf)> {
+    let Some(local) = env::var_os("LOCALAPPDATA").map(PathBuf::from) else {
+        return Vec::new();
+    };
+    let mut files = Vec::new();
+    for (browser, user_data) in CHROMIUM_BROWSERS {
+        let Ok(entries) = fs::read_dir(local.join(user_data)) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name != "Default" && !name.starts_with("Profile ") {
+                continue;
+            }
+            let history = entry.path().join("History");
+            if history.is_file() {
+                files.push((browser.to_string(), history));
+            }
+        }
+    }
+    files
+}
+
+/// 复制 History 文件到临时目录（超过缓存时间才重新复制）
+fn snapshots() -> Vec<(String, PathBuf)> {
+    let Ok(mut cache) = SNAPSHOTS.lock() else {
+        return Vec::new();
+    };
+    if let Some((copied_at, files)) = cache.as_ref() {
+        if copied_at.elapsed() < SNAPSHOT_TTL {
+            return files.clone();
+        }
+    }
+
+    let dir = env::temp_dir().join("imiss-browser-history");
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("[BrowserHistory] Failed to create snapshot dir: {}", e);
+        return Vec::new();
+    }
+    let files: Vec<(String, PathBuf)> = history_files()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, (browser, source))| {
+            let target = dir.join(format!("History-{}", i));
+            match fs::copy(&source, &target) {
+                Ok(_) => Some((browser, target)),
+                Err(e) => {
+                    eprintln!("[BrowserHistory] Failed to copy {}: {}", source.display(), e);
+                    None
+                }
+            }
+        })
+        .collect();
+    *cache = Some((Instant::now(), files.clone()));
+    files
+}
+
+/// 在单个 History 数据库中按网址或标题搜索
+pub fn search_database(path: &Path, browser: &str, query: &str, limit: usize) -> Result<Vec<BrowserHistoryItem>, String> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| format!("Failed to open browser history: {}", e))?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT url, title, visit_count, last_visit_time FROM urls
+             WHERE hidden = 0 AND (instr(lower(url), ?1) > 0 OR instr(lower(title), ?1) > 0)
+             ORDER BY visit_count DESC, last_visit_time DESC LIMIT ?2",
+        )
+        .map_err(|e| format!("Failed to prepare browser history query: {}", e))?;
+    let rows = stmt
+        .query_map(params![query.to_lowercase(), limit as i64], |row| {
+            Ok(BrowserHistoryItem {
+                url: row.get(0)?,
+                title: row.get(1)?,
+                browser: browser.to_string(),
+                visit_count: row.get(2)?,
+                last_visit: chromium_time_to_unix(row.get(3)?),
+            })
+        })
+        .map_err(|e| format!("Failed to query browser history: {}", e))?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read browser history row: {}", e))
+}
+
+/// 合并多个浏览器的结果：同一网址累加访问次数、保留最近访问时间
+pub fn merge_results(results: Vec<BrowserHistoryItem>, limit: usize) -> Vec<BrowserHistoryItem> {
+    let mut merged: Vec<BrowserHistoryItem> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for item in results {
+        match index.get(&item.url) {
+            Some(&i) => {
+                let existing = &mut merged[i];
+                existing.visit_count += item.visit_count;
+                if item.last_visit > existing.last_visit {
+                    existing.last_visit = item.last_visit;
+                    existing.browser = item.browser;
+                }
+                if existing.title.is_empty() {
+                    existing.title = item.title;
+                }
+            }
+            None => {
+                index.insert(item.url.clone(), merged.len());
+                merged.push(item);
+            }
+        }
+    }
+    merged.sort_by(|a, b| b.visit_count.cmp(&a.visit_count).then_with(|| b.last_visit.cmp(&a.last_visit)));
+    merged.truncate(limit);
+    merged
+}
+
+/// 搜索所有浏览器的历史记录
+pub fn search(query: &str, limit: usize) -> Vec<BrowserHistoryItem> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut results = Vec::new();
+    for (browser, path) in snapshots() {
+        match search_database(&path, &browser, query, limit) {
+            Ok(items) => results.extend(items),
+            Err(e) => eprintln!("[BrowserHistory] {}: {}", browser, e),
+        }
+    }
+    merge_results(results, limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(url: &str, browser: &str, visit_count: i64, last_visit: i64) -> BrowserHistoryItem {
+        BrowserHistoryItem {
+            url: url.to_string(),
+            title: String::new(),
+            browser: browser.to_string(),
+            visit_count,
+            last_visit,
+        }
+    }
+
+    #[test]
+    fn converts_chromium_timestamps() {
+        assert_eq!(chromium_time_to_unix(13_300_000_000_000_000), 1_655_526_400);
+        assert_eq!(chromium_time_to_unix(0), 0);
+    }
+
+    #[test]
+    fn searches_history_database() {
+        let path = env::temp_dir().join(format!("imiss-history-test-{}.db", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE urls (id INTEGER PRIMARY KEY, url TEXT, title TEXT, visit_count INTEGER, last_visit_time INTEGER, hidden INTEGER);
+             INSERT INTO urls VALUES (1, 'https://docs.rs/tauri', 'Tauri docs', 3, 13300000000000000, 0);
+             INSERT INTO urls VALUES (2, 'https://tauri.app/', 'Tauri', 10, 13300000000000000, 0);
+             INSERT INTO urls VALUES (3, 'https://example.com/', 'Hidden Tauri', 50, 13300000000000000, 1);
+             INSERT INTO urls VALUES (4, 'https://example.org/', 'Other', 99, 13300000000000000, 0);",
+        )
+        .unwrap();
+        drop(conn);
+
+        let items = search_database(&path, "Chrome", "TAURI", 10).unwrap();
+        let urls: Vec<&str> = items.iter().map(|i| i.url.as_str()).collect();
+        assert_eq!(urls, vec!["https://tauri.app/", "https://docs.rs/tauri"]);
+        assert_eq!(items[0].browser, "Chrome");
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn merges_results_across_browsers() {
+        let merged = merge_results(
+            vec![item("https://a/", "Chrome", 2, 10), item("https://b/", "Chrome", 3, 5), item("https://a/", "Edge", 4, 20)],
+            10,
+        );
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0], BrowserHistoryItem { visit_count: 6, ..item("https://a/", "Edge", 6, 20) });
+        assert_eq!(merge_results(merged, 1).len(), 1);
+    }
+}
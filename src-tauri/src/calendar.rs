@@ -0,0 +1,915 @@
+// 日历订阅：订阅 ICS 地址（http/https/webcal）或本地 .ics 文件，后台定时刷新并展开近期的日程（支持常见的重复规则与例外）
+// 启动器输入 "today"/"今天日程" 查看今天的日程，"next meeting"/"下个会议" 查看接下来的会议，识别到会议链接时可一键加入
+
+use crate::api_policy::{self, Policy};
+use crate::{db, network, shutdown};
+use chrono::{Datelike, Duration as ChronoDuration, Local, Months, NaiveDate, NaiveDateTime, TimeZone, Weekday};
+use chrono_tz::Tz;
+use regex::Regex;
+use rusqlite::params;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+const FIRST_REFRESH_DELAY: Duration = Duration::from_secs(30);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+// 展开日程的时间范围：过去 1 天到未来 60 天
+const WINDOW_PAST_DAYS: i64 = 1;
+const WINDOW_FUTURE_DAYS: i64 = 60;
+// 展开重复规则时最多遍历的周期数，防止异常规则无限循环
+const MAX_PERIODS: i64 = 40_000;
+
+// Outlook/Exchange 导出的 ICS 使用 Windows 时区名
+const WINDOWS_ZONES: &[(&str, &str)] = &[
+    ("China Standard Time", "Asia/Shanghai"),
+    ("Taipei Standard Time", "Asia/Taipei"),
+    ("Tokyo Standard Time", "Asia/Tokyo"),
+    ("Korea Standard Time", "Asia/Seoul"),
+    ("Singapore Standard Time", "Asia/Singapore"),
+    ("India Standard Time", "Asia/Kolkata"),
+    ("AUS Eastern Standard Time", "Australia/Sydney"),
+    ("GMT Standard Time", "Europe/London"),
+    ("W. Europe Standard Time", "Europe/Berlin"),
+    ("Romance Standard Time", "Europe/Paris"),
+    ("Russian Standard Time", "Europe/Moscow"),
+    ("Eastern Standard Time", "America/New_York"),
+    ("Central Standard Time", "America/Chicago"),
+    ("Mountain Standard Time", "America/Denver"),
+    ("Pacific Standard Time", "America/Los_Angeles"),
+    ("UTC", "UTC"),
+];
+
+// 会议软件的域名，描述里有多个链接时优先取这些
+const MEETING_HOSTS: &[&str] = &[
+    "zoom.us",
+    "zoom.com.cn",
+    "teams.microsoft.com",
+    "teams.live.com",
+    "meet.google.com",
+    "meeting.tencent.com",
+    "voovmeeting.com",
+    "vc.feishu.cn",
+    "meetings.feishu.cn",
+    "vc.larksuite.com",
+    "webex.com",
+    "meeting.dingtalk.com",
+    "whereby.com",
+];
+
+static URL_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"https?://[^\s<>"'\\]+"#).unwrap());
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Calendar {
+    pub id: i64,
+    pub source: String, // ICS 地址或本地文件路径
+    pub name: String,
+    pub last_fetched: Option<i64>,
+    pub last_error: Option<String>, // 最近一次刷新失败的原因，成功后清空
+    pub upcoming: u32,              // 尚未结束的日程数
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CalendarEvent {
+    pub id: i64,
+    pub calendar_id: i64,
+    pub calendar_name: String,
+    pub title: String,
+    pub location: Option<String>,
+    pub start_at: i64, // Unix 秒；全天日程为本地当天零点
+    pub end_at: i64,
+    pub all_day: bool,
+    pub join_url: Option<String>, // 识别到的会议链接
+}
+
+/// 展开后的一次日程
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventInstance {
+    pub uid: String,
+    pub title: String,
+    pub location: Option<String>,
+    pub start_at: i64,
+    pub end_at: i64,
+    pub all_day: bool,
+    pub join_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedCalendar {
+    pub name: Option<String>,
+    pub events: Vec<EventInstance>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Zone {
+    Utc,
+    Named(Tz),
+    Local, // 浮动时间与全天日程按本地时区
+}
+
+/// ICS 中的时间：本地钟面时间加时区，重复规则按钟面时间展开，夏令时前后保持同一时刻
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Stamp {
+    time: NaiveDateTime,
+    zone: Zone,
+    all_day: bool,
+}
+
+impl Stamp {
+    fn timestamp(&self) -> Option<i64> {
+        // 重复的时刻取较早的一个，不存在的时刻顺延一小时
+        fn resolve<T: TimeZone>(tz: &T, time: NaiveDateTime) -> Option<i64> {
+            tz.from_local_datetime(&time)
+                .earliest()
+                .or_else(|| tz.from_local_datetime(&(time + ChronoDuration::hours(1))).earliest())
+                .map(|t| t.timestamp())
+        }
+        match self.zone {
+            Zone::Utc => Some(self.time.and_utc().timestamp()),
+            Zone::Named(tz) => resolve(&tz, self.time),
+            Zone::Local => resolve(&Local, self.time),
+        }
+    }
+}
+
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    fn param(&self, key: &str) -> Option<&str> {
+        self.params.iter().find(|(k, _)| k.eq_ignore_ascii_case(key)).map(|(_, v)| v.as_str())
+    }
+}
+
+#[derive(Default)]
+struct RawEvent {
+    uid: String,
+    summary: String,
+    location: Option<String>,
+    description: Option<String>,
+    url: Option<String>,
+    start: Option<Stamp>,
+    end: Option<Stamp>,
+    duration: Option<i64>,
+    rrule: Option<String>,
+    exdates: Vec<i64>,
+    recurrence_id: Option<i64>,
+    cancelled: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    freq: Freq,
+    interval: u32,
+    count: Option<usize>,
+    until: Option<i64>,
+    by_day: Vec<(i32, Weekday)>, // (序号, 星期)，序号 0 表示每个，负数从月末倒数
+    by_month_day: Vec<i32>,
+    by_month: Vec<u32>,
+}
+
+fn now_ts() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+/// 展开折行：以空格或制表符开头的行接在上一行后面
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in text.split('\n') {
+        let raw = raw.strip_suffix('\r').unwrap_or(raw);
+        match lines.last_mut() {
+            Some(last) if raw.starts_with([' ', '\t']) => last.push_str(&raw[1..]),
+            _ if !raw.is_empty() => lines.push(raw.to_string()),
+            _ => {}
+        }
+    }
+    lines
+}
+
+/// 拆分 "NAME;PARAM=V:value"，引号内的冒号和分号不作分隔
+fn parse_property(line: &str) -> Option<Property> {
+    let mut in_quotes = false;
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut value_at = None;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => {
+                parts.push(&line[start..i]);
+                start = i + 1;
+            }
+            ':' if !in_quotes => {
+                parts.push(&line[start..i]);
+                value_at = Some(i + 1);
+                break;
+            }
+            _ => {}
+        }
+    }
+    let value = line[value_at?..].to_string();
+    let name = parts.first()?.to_ascii_uppercase();
+    let params = parts[1..]
+        .iter()
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.to_ascii_uppercase(), v.trim_matches('"').to_string()))
+        .collect();
+    Some(Property { name, params, value })
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result.trim().to_string()
+}
+
+fn resolve_zone(tzid: &str) -> Zone {
+    let tzid = tzid.trim_matches(|c| c == '"' || c == '/');
+    if let Ok(tz) = tzid.parse::<Tz>() {
+        return Zone::Named(tz);
+    }
+    WINDOWS_ZONES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(tzid))
+        .and_then(|(_, iana)| iana.parse::<Tz>().ok())
+        .map(Zone::Named)
+        .unwrap_or(Zone::Local)
+}
+
+/// 解析 "20250610"、"20250610T100000Z" 或带 TZID 的 "20250610T100000"
+fn parse_stamp(value: &str, prop: &Property) -> Option<Stamp> {
+    let value = value.trim();
+    if prop.param("VALUE").is_some_and(|v| v.eq_ignore_ascii_case("DATE")) || value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some(Stamp { time: date.and_hms_opt(0, 0, 0)?, zone: Zone::Local, all_day: true });
+    }
+    let (text, utc) = match value.strip_suffix(['Z', 'z']) {
+        Some(text) => (text, true),
+        None => (value, false),
+    };
+    let time = NaiveDateTime::parse_from_str(text, "%Y%m%dT%H%M%S").ok()?;
+    let zone = if utc { Zone::Utc } else { prop.param("TZID").map(resolve_zone).unwrap_or(Zone::Local) };
+    Some(Stamp { time, zone, all_day: false })
+}
+
+/// 解析 "PT1H30M"、"P1D"、"P1W" 等时长，返回秒数
+fn parse_duration(value: &str) -> Option<i64> {
+    let (sign, rest) = match value.trim().strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.trim().trim_start_matches('+')),
+    };
+    let mut total = 0;
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in rest.strip_prefix('P')?.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            unit => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += n * match (unit, in_time) {
+                    ('W', false) => 7 * 86400,
+                    ('D', false) => 86400,
+                    ('H', true) => 3600,
+                    ('M', true) => 60,
+                    ('S', true) => 1,
+                    _ => return None,
+                };
+            }
+        }
+    }
+    Some(sign * total)
+}
+
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    Some(match code {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+fn parse_rule(value: &str, start: &Stamp) -> Option<Rule> {
+    let mut rule = Rule {
+        freq: Freq::Daily,
+        interval: 1,
+        count: None,
+        until: None,
+        by_day: Vec::new(),
+        by_month_day: Vec::new(),
+        by_month: Vec::new(),
+    };
+    let mut freq = None;
+    for part in value.split(';') {
+        let Some((key, val)) = part.split_once('=') else { continue };
+        let val = val.trim().to_ascii_uppercase();
+        match key.trim().to_ascii_uppercase().as_str() {
+            "FREQ" => {
+                freq = Some(match val.as_str() {
+                    "DAILY" => Freq::Daily,
+                    "WEEKLY" => Freq::Weekly,
+                    "MONTHLY" => Freq::Monthly,
+                    "YEARLY" => Freq::Yearly,
+                    // 更细的频率（每小时等）不展开
+                    _ => return None,
+                })
+            }
+            "INTERVAL" => rule.interval = val.parse().ok().filter(|n| *n > 0)?,
+            "COUNT" => rule.count = val.parse().ok(),
+            "UNTIL" => {
+                let until = parse_stamp(&val, &Property { name: String::new(), params: Vec::new(), value: val.clone() })?;
+                // 只写日期的 UNTIL 包含当天；浮动时间按日程自己的时区
+                let until = if until.all_day {
+                    Stamp { time: until.time.date().and_hms_opt(23, 59, 59)?, zone: start.zone, all_day: false }
+                } else if until.zone == Zone::Local {
+                    Stamp { zone: start.zone, ..until }
+                } else {
+                    until
+                };
+                rule.until = until.timestamp();
+            }
+            "BYDAY" => {
+                rule.by_day = val
+                    .split(',')
+                    .filter_map(|d| {
+                        let d = d.trim();
+                        let (ordinal, code) = d.split_at(d.len().checked_sub(2)?);
+                        Some((if ordinal.is_empty() { 0 } else { ordinal.parse().ok()? }, parse_weekday(code)?))
+                    })
+                    .collect()
+            }
+            "BYMONTHDAY" => rule.by_month_day = val.split(',').filter_map(|d| d.trim().parse().ok()).collect(),
+            "BYMONTH" => rule.by_month = val.split(',').filter_map(|m| m.trim().parse().ok()).collect(),
+            _ => {}
+        }
+    }
+    rule.freq = freq?;
+    Some(rule)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1).and_then(|d| d.pred_opt()).map(|d| d.day()).unwrap_or(28)
+}
+
+/// 某月第 n 个星期几，n 为负数时从月末倒数
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: i32) -> Option<NaiveDate> {
+    if n > 0 {
+        return NaiveDate::from_weekday_of_month_opt(year, month, weekday, u8::try_from(n).ok()?);
+    }
+    let last = NaiveDate::from_ymd_opt(year, month, days_in_month(year, month))?;
+    let back = (last.weekday().num_days_from_monday() as i64 - weekday.num_days_from_monday() as i64).rem_euclid(7);
+    let date = last - ChronoDuration::days(back + 7 * (-n as i64 - 1));
+    (date.month() == month).then_some(date)
+}
+
+/// 某月中符合 BYDAY / BYMONTHDAY 的日期，都没有时取 default_day
+fn month_dates(rule: &Rule, year: i32, month: u32, default_day: u32) -> Vec<NaiveDate> {
+    let days = days_in_month(year, month);
+    if !rule.by_day.is_empty() {
+        rule.by_day
+            .iter()
+            .flat_map(|&(n, weekday)| match n {
+                0 => (1..=days)
+                    .filter_map(|d| NaiveDate::from_ymd_opt(year, month, d))
+                    .filter(|d| d.weekday() == weekday)
+                    .collect::<Vec<_>>(),
+                n => nth_weekday(year, month, weekday, n).into_iter().collect(),
+            })
+            .collect()
+    } else if !rule.by_month_day.is_empty() {
+        rule.by_month_day
+            .iter()
+            .filter_map(|&d| {
+                let day = if d < 0 { days as i32 + d + 1 } else { d };
+                NaiveDate::from_ymd_opt(year, month, u32::try_from(day).ok()?)
+            })
+            .collect()
+    } else {
+        NaiveDate::from_ymd_opt(year, month, default_day).into_iter().collect()
+    }
+}
+
+/// 按重复规则展开，返回开始时间不晚于 window_end 的各次（含 DTSTART 本身）
+fn expand(rule: &Rule, start: Stamp, window_end: i64) -> Vec<Stamp> {
+    let start_date = start.time.date();
+    let time = start.time.time();
+    let interval = rule.interval as i64;
+    let mut result = Vec::new();
+    for period in 0..MAX_PERIODS {
+        let step = period * interval;
+        let mut dates: Vec<NaiveDate> = match rule.freq {
+            Freq::Daily => vec![start_date + ChronoDuration::days(step)],
+            Freq::Weekly => {
+                let monday = start_date - ChronoDuration::days(start_date.weekday().num_days_from_monday() as i64)
+                    + ChronoDuration::weeks(step);
+                let weekdays: Vec<Weekday> = if rule.by_day.is_empty() {
+                    vec![start_date.weekday()]
+                } else {
+                    rule.by_day.iter().map(|(_, w)| *w).collect()
+                };
+                weekdays.iter().map(|w| monday + ChronoDuration::days(w.num_days_from_monday() as i64)).collect()
+            }
+            Freq::Monthly => {
+                let Some(month) = start_date.with_day(1).and_then(|d| d.checked_add_months(Months::new(step as u32))) else {
+                    break;
+                };
+                month_dates(rule, month.year(), month.month(), start_date.day())
+            }
+            Freq::Yearly => {
+                let year = start_date.year() + step as i32;
+                let months = if rule.by_month.is_empty() { vec![start_date.month()] } else { rule.by_month.clone() };
+                let by_day_only = Rule { by_day: Vec::new(), ..rule.clone() };
+                months
+                    .iter()
+                    .flat_map(|&m| month_dates(&by_day_only, year, m, start_date.day()))
+                    .collect()
+            }
+        };
+        if rule.freq == Freq::Daily && !rule.by_day.is_empty() {
+            dates.retain(|d| rule.by_day.iter().any(|(_, w)| d.weekday() == *w));
+        }
+        if rule.freq != Freq::Yearly && !rule.by_month.is_empty() {
+            dates.retain(|d| rule.by_month.contains(&d.month()));
+        }
+        dates.sort();
+        dates.dedup();
+        for date in dates {
+            if date < start_date {
+                continue;
+            }
+            let stamp = Stamp { time: date.and_time(time), ..start };
+            let Some(ts) = stamp.timestamp() else { continue };
+            if rule.until.is_some_and(|until| ts > until)
+                || ts > window_end
+                || rule.count.is_some_and(|count| result.len() >= count)
+            {
+                return result;
+            }
+            result.push(stamp);
+        }
+    }
+    result
+}
+
+fn host_of(url: &str) -> Option<&str> {
+    url.split_once("://")?.1.split(['/', '?', '#', ':']).next()
+}
+
+/// 从 URL、地点和描述中找会议链接，优先会议软件的域名
+fn find_join_url(event: &RawEvent) -> Option<String> {
+    let urls: Vec<String> = [&event.url, &event.location, &event.description]
+        .into_iter()
+        .flatten()
+        .flat_map(|field| URL_RE.find_iter(field).map(|m| m.as_str().trim_end_matches([')', '.', ',', ';', '>', ']']).to_string()))
+        .collect();
+    let is_meeting = |url: &&String| {
+        host_of(url).is_some_and(|host| {
+            let host = host.to_ascii_lowercase();
+            MEETING_HOSTS.iter().any(|h| host == *h || host.ends_with(&format!(".{}", h)))
+        })
+    };
+    urls.iter()
+        .find(is_meeting)
+        .or_else(|| event.location.as_ref().and_then(|l| urls.iter().find(|u| l.trim() == u.as_str())))
+        .or(event.url.as_ref())
+        .cloned()
+}
+
+impl RawEvent {
+    fn apply(&mut self, prop: &Property) {
+        match prop.name.as_str() {
+            "UID" => self.uid = prop.value.trim().to_string(),
+            "SUMMARY" => self.summary = unescape(&prop.value),
+            "LOCATION" => self.location = Some(unescape(&prop.value)).filter(|l| !l.is_empty()),
+            "DESCRIPTION" => self.description = Some(unescape(&prop.value)),
+            "URL" => self.url = Some(prop.value.trim().to_string()).filter(|u| !u.is_empty()),
+            "DTSTART" => self.start = parse_stamp(&prop.value, prop),
+            "DTEND" => self.end = parse_stamp(&prop.value, prop),
+            "DURATION" => self.duration = parse_duration(&prop.value),
+            "RRULE" => self.rrule = Some(prop.value.clone()),
+            "EXDATE" => self.exdates.extend(
+                prop.value.split(',').filter_map(|v| parse_stamp(v, prop)).filter_map(|s| s.timestamp()),
+            ),
+            "RECURRENCE-ID" => self.recurrence_id = parse_stamp(&prop.value, prop).and_then(|s| s.timestamp()),
+            "STATUS" => self.cancelled = prop.value.trim().eq_ignore_ascii_case("CANCELLED"),
+            _ => {}
+        }
+    }
+}
+
+/// 解析 ICS 文本，展开与 [window_start, window_end) 有交集的日程，按开始时间排序
+pub fn parse_ics(text: &str, window_start: i64, window_end: i64) -> Result<ParsedCalendar, String> {
+    let lines = unfold(text.trim_start_matches('\u{feff}'));
+    if !lines.iter().any(|l| l.trim().eq_ignore_ascii_case("BEGIN:VCALENDAR")) {
+        return Err("不是有效的 ICS 日历".to_string());
+    }
+
+    let mut name = None;
+    let mut raw_events = Vec::new();
+    let mut current: Option<RawEvent> = None;
+    let mut nested = 0; // VEVENT 内嵌的组件（如 VALARM）
+    for line in &lines {
+        let Some(prop) = parse_property(line) else { continue };
+        let value = prop.value.trim().to_ascii_uppercase();
+        match prop.name.as_str() {
+            "BEGIN" if value == "VEVENT" && current.is_none() => current = Some(RawEvent::default()),
+            "BEGIN" if current.is_some() => nested += 1,
+            "END" if nested > 0 => nested -= 1,
+            "END" if value == "VEVENT" => raw_events.extend(current.take()),
+            "X-WR-CALNAME" if current.is_none() => name = Some(unescape(&prop.value)).filter(|n| !n.is_empty()),
+            _ if nested == 0 => {
+                if let Some(event) = current.as_mut() {
+                    event.apply(&prop);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // 单独修改过的某一次（RECURRENCE-ID）替换重复规则展开出的那一次
+    let overridden: HashSet<(String, i64)> =
+        raw_events.iter().filter_map(|e| Some((e.uid.clone(), e.recurrence_id?))).collect();
+    let mut events = Vec::new();
+    for event in &raw_events {
+        // 取消的单次修改同样会把展开出的那一次去掉
+        if event.cancelled {
+            continue;
+        }
+        let Some(start) = event.start else { continue };
+        let Some(start_ts) = start.timestamp() else { continue };
+        let length = match (event.end.and_then(|e| e.timestamp()), event.duration) {
+            (Some(end), _) => end - start_ts,
+            (None, Some(duration)) => duration,
+            (None, None) if start.all_day => 86400,
+            (None, None) => 0,
+        }
+        .max(0);
+        let starts = match (&event.rrule, event.recurrence_id) {
+            (Some(rule), None) => match parse_rule(rule, &start) {
+                Some(rule) => expand(&rule, start, window_end),
+                None => vec![start],
+            },
+            _ => vec![start],
+        };
+        let join_url = find_join_url(event);
+        for stamp in starts {
+            let Some(ts) = stamp.timestamp() else { continue };
+            let replaced = event.recurrence_id.is_none()
+                && (event.exdates.contains(&ts) || overridden.contains(&(event.uid.clone(), ts)));
+            if replaced || ts >= window_end || ts + length.max(1) <= window_start {
+                continue;
+            }
+            events.push(EventInstance {
+                uid: event.uid.clone(),
+                title: if event.summary.is_empty() { "（无标题）".to_string() } else { event.summary.clone() },
+                location: event.location.clone(),
+                start_at: ts,
+                end_at: ts + length,
+                all_day: start.all_day,
+                join_url: join_url.clone(),
+            });
+        }
+    }
+    events.sort_by_key(|e| (e.start_at, !e.all_day));
+    Ok(ParsedCalendar { name, events })
+}
+
+fn is_remote(source: &str) -> bool {
+    ["http://", "https://", "webcal://"].iter().any(|p| source.to_ascii_lowercase().starts_with(p))
+}
+
+/// 读取日历内容：远程地址通过网络获取（webcal 按 https），其余视为本地文件
+async fn load_source(source: &str) -> Result<String, String> {
+    if !is_remote(source) {
+        let bytes = std::fs::read(source).map_err(|e| format!("读取日历文件失败: {}", e))?;
+        return Ok(String::from_utf8_lossy(&bytes).into_owned());
+    }
+    let url = match source.get(..9) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("webcal://") => format!("https://{}", &source[9..]),
+        _ => source.to_string(),
+    };
+    let client = crate::http_client::builder()?
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("创建HTTP客户端失败: {}", e))?;
+    api_policy::send(&api_policy::provider_of(&url), &Policy::FEEDS, || client.get(&url))
+        .await
+        .map_err(|e| format!("日历请求失败: {}", e))?
+        .text()
+        .await
+        .map_err(|e| format!("读取日历内容失败: {}", e))
+}
+
+fn window() -> (i64, i64) {
+    let now = now_ts();
+    (now - WINDOW_PAST_DAYS * 86400, now + WINDOW_FUTURE_DAYS * 86400)
+}
+
+async fn fetch_calendar(source: &str) -> Result<ParsedCalendar, String> {
+    let text = load_source(source).await?;
+    let (start, end) = window();
+    parse_ics(&text, start, end)
+}
+
+/// 用新展开的日程替换该日历原有的日程
+fn store_events(app_data_dir: &Path, calendar_id: i64, parsed: &ParsedCalendar) -> Result<(), String> {
+    let mut conn = db::get_connection(app_data_dir)?;
+    let tx = conn.transaction().map_err(|e| format!("Failed to start calendar transaction: {}", e))?;
+    tx.execute("DELETE FROM calendar_events WHERE calendar_id = ?1", params![calendar_id])
+        .map_err(|e| format!("Failed to clear calendar events: {}", e))?;
+    {
+        let mut stmt = tx
+            .prepare(
+                "INSERT INTO calendar_events (calendar_id, uid, title, location, start_at, end_at, all_day, join_url)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            )
+            .map_err(|e| format!("Failed to prepare calendar insert: {}", e))?;
+        for event in &parsed.events {
+            stmt.execute(params![
+                calendar_id,
+                event.uid,
+                event.title,
+                event.location,
+                event.start_at,
+                event.end_at,
+                event.all_day,
+                event.join_url
+            ])
+            .map_err(|e| format!("Failed to insert calendar event: {}", e))?;
+        }
+    }
+    tx.execute(
+        "UPDATE calendars SET name = COALESCE(?2, name), last_fetched = ?3, last_error = NULL WHERE id = ?1",
+        params![calendar_id, parsed.name, now_ts()],
+    )
+    .map_err(|e| format!("Failed to update calendar: {}", e))?;
+    tx.commit().map_err(|e| format!("Failed to commit calendar events: {}", e))
+}
+
+fn read_calendar(row: &rusqlite::Row) -> rusqlite::Result<Calendar> {
+    Ok(Calendar {
+        id: row.get(0)?,
+        source: row.get(1)?,
+        name: row.get(2)?,
+        last_fetched: row.get(3)?,
+        last_error: row.get(4)?,
+        upcoming: row.get(5)?,
+    })
+}
+
+/// 获取所有日历订阅及尚未结束的日程数
+pub fn list_calendars(app_data_dir: &Path) -> Result<Vec<Calendar>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT c.id, c.source, c.name, c.last_fetched, c.last_error,
+                    (SELECT COUNT(*) FROM calendar_events e WHERE e.calendar_id = c.id AND e.end_at > ?1)
+                 FROM calendars c ORDER BY c.name COLLATE NOCASE",
+            )
+            .map_err(|e| format!("Failed to prepare calendars query: {}", e))?;
+        let calendars = stmt
+            .query_map(params![now_ts()], read_calendar)
+            .map_err(|e| format!("Failed to query calendars: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read calendars: {}", e))?;
+        Ok(calendars)
+    })
+}
+
+/// 添加日历订阅：先读取一次验证内容有效，同时保存日程
+pub async fn add_calendar(app_data_dir: &Path, source: &str) -> Result<Calendar, String> {
+    // 资源管理器 "复制为路径" 会带上引号
+    let source = source.trim().trim_matches('"');
+    if !is_remote(source) {
+        let path = Path::new(source);
+        if !path.is_file() {
+            return Err(format!("日历文件不存在: {}", source));
+        }
+        if !path.extension().is_some_and(|e| e.eq_ignore_ascii_case("ics")) {
+            return Err("只支持 .ics 日历文件".to_string());
+        }
+    }
+    let parsed = fetch_calendar(source).await?;
+    let default_name = if is_remote(source) {
+        api_policy::provider_of(source)
+    } else {
+        Path::new(source).file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default()
+    };
+    let calendar_id = db::with_connection(app_data_dir, |conn| {
+        conn.execute(
+            "INSERT INTO calendars (source, name, created_at) VALUES (?1, ?2, ?3)",
+            params![source, parsed.name.as_deref().unwrap_or(&default_name), now_ts()],
+        )
+        .map_err(|e| match e {
+            rusqlite::Error::SqliteFailure(err, _) if err.code == rusqlite::ErrorCode::ConstraintViolation => {
+                "已经订阅过这个日历".to_string()
+            }
+            e => format!("Failed to insert calendar: {}", e),
+        })?;
+        Ok(conn.last_insert_rowid())
+    })?;
+    store_events(app_data_dir, calendar_id, &parsed)?;
+    list_calendars(app_data_dir)?
+        .into_iter()
+        .find(|c| c.id == calendar_id)
+        .ok_or_else(|| "日历保存失败".to_string())
+}
+
+/// 删除日历订阅及其日程
+pub fn remove_calendar(app_data_dir: &Path, id: i64) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute("DELETE FROM calendar_events WHERE calendar_id = ?1", params![id])
+            .and_then(|_| conn.execute("DELETE FROM calendars WHERE id = ?1", params![id]))
+            .map_err(|e| format!("Failed to delete calendar: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 与 [from, to) 有交集的日程，全天日程排在同一天的前面
+fn events_between(app_data_dir: &Path, from: i64, to: i64, include_all_day: bool, limit: usize) -> Result<Vec<CalendarEvent>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT e.id, e.calendar_id, c.name, e.title, e.location, e.start_at, e.end_at, e.all_day, e.join_url
+                 FROM calendar_events e JOIN calendars c ON c.id = e.calendar_id
+                 WHERE e.end_at > ?1 AND e.start_at < ?2 AND (?3 = 1 OR e.all_day = 0)
+                 ORDER BY e.start_at, e.all_day DESC, e.id
+                 LIMIT ?4",
+            )
+            .map_err(|e| format!("Failed to prepare calendar events query: {}", e))?;
+        let events = stmt
+            .query_map(params![from, to, include_all_day, limit as i64], |row| {
+                Ok(CalendarEvent {
+                    id: row.get(0)?,
+                    calendar_id: row.get(1)?,
+                    calendar_name: row.get(2)?,
+                    title: row.get(3)?,
+                    location: row.get(4)?,
+                    start_at: row.get(5)?,
+                    end_at: row.get(6)?,
+                    all_day: row.get(7)?,
+                    join_url: row.get(8)?,
+                })
+            })
+            .map_err(|e| format!("Failed to query calendar events: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read calendar events: {}", e))?;
+        Ok(events)
+    })
+}
+
+/// 今天（本地时间）的全部日程，包括已经结束的
+pub fn today_events(app_data_dir: &Path) -> Result<Vec<CalendarEvent>, String> {
+    let today = Local::now().date_naive();
+    let midnight = |date: NaiveDate| Stamp { time: date.and_hms_opt(0, 0, 0).unwrap(), zone: Zone::Local, all_day: true }.timestamp();
+    let from = midnight(today).ok_or("无法确定今天的起始时间")?;
+    let to = midnight(today + ChronoDuration::days(1)).ok_or("无法确定今天的结束时间")?;
+    events_between(app_data_dir, from, to, true, 100)
+}
+
+/// 接下来的会议（不含全天日程），正在进行的也包括在内
+pub fn upcoming_events(app_data_dir: &Path, limit: usize) -> Result<Vec<CalendarEvent>, String> {
+    let (_, end) = window();
+    events_between(app_data_dir, now_ts(), end, false, limit)
+}
+
+/// 刷新所有日历，单个失败时记录错误并继续；离线时跳过远程日历；返回刷新成功的数量
+pub async fn refresh_all(app_data_dir: &Path) -> Result<usize, String> {
+    let online = network::is_online();
+    let mut refreshed = 0;
+    for calendar in list_calendars(app_data_dir)? {
+        if is_remote(&calendar.source) && !online {
+            continue;
+        }
+        match fetch_calendar(&calendar.source).await {
+            Ok(parsed) => {
+                store_events(app_data_dir, calendar.id, &parsed)?;
+                refreshed += 1;
+            }
+            Err(e) => {
+                eprintln!("[Calendar] Failed to refresh {}: {}", calendar.source, e);
+                db::with_connection(app_data_dir, |conn| {
+                    conn.execute(
+                        "UPDATE calendars SET last_fetched = ?2, last_error = ?3 WHERE id = ?1",
+                        params![calendar.id, now_ts(), e],
+                    )
+                    .map_err(|e| format!("Failed to update calendar: {}", e))
+                })?;
+            }
+        }
+    }
+    Ok(refreshed)
+}
+
+/// 后台定时刷新，有日历刷新成功时发送 calendar-updated 事件
+pub fn start_refresher(app_handle: AppHandle, app_data_dir: PathBuf) -> Result<(), String> {
+    shutdown::spawn("calendar-refresher", move || {
+        let mut interval = FIRST_REFRESH_DELAY;
+        while !shutdown::wait_timeout(interval) {
+            interval = REFRESH_INTERVAL;
+            match tauri::async_runtime::block_on(refresh_all(&app_data_dir)) {
+                Ok(0) => {}
+                Ok(refreshed) => {
+                    let _ = app_handle.emit("calendar-updated", refreshed);
+                }
+                Err(e) => eprintln!("[Calendar] Refresh failed: {}", e),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(text: &str) -> i64 {
+        NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M").unwrap().and_utc().timestamp()
+    }
+
+    #[test]
+    fn parses_events_and_join_links() {
+        let ics = "BEGIN:VCALENDAR\r\nX-WR-CALNAME:Work\r\nBEGIN:VEVENT\r\nUID:a\r\nSUMMARY:Design review\\, v2\r\n\
+            DTSTART:20250610T020000Z\r\nDTEND:20250610T030000Z\r\nLOCATION:Room 1\r\n\
+            DESCRIPTION:Agenda https://example.com/doc\\nJoin: https://us02web.zoom.us/j/123\r\n 45?pwd=x\r\n\
+            BEGIN:VALARM\r\nDESCRIPTION:ignored\r\nEND:VALARM\r\nEND:VEVENT\r\n\
+            BEGIN:VEVENT\r\nUID:b\r\nSUMMARY:Holiday\r\nDTSTART;VALUE=DATE:20250611\r\nEND:VEVENT\r\n\
+            BEGIN:VEVENT\r\nUID:c\r\nSUMMARY:Standup\r\nDTSTART;TZID=Asia/Shanghai:20250610T093000\r\nDURATION:PT15M\r\n\
+            STATUS:CANCELLED\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        let parsed = parse_ics(ics, ts("2025-06-09 00:00"), ts("2025-06-20 00:00")).unwrap();
+        assert_eq!(parsed.name.as_deref(), Some("Work"));
+        assert_eq!(parsed.events.len(), 2);
+        let review = &parsed.events[0];
+        assert_eq!(review.title, "Design review, v2");
+        assert_eq!((review.start_at, review.end_at), (ts("2025-06-10 02:00"), ts("2025-06-10 03:00")));
+        assert_eq!(review.join_url.as_deref(), Some("https://us02web.zoom.us/j/12345?pwd=x"));
+        assert!(parsed.events[1].all_day);
+        assert!(parse_ics("hello", 0, 1).is_err());
+    }
+
+    #[test]
+    fn expands_recurring_events() {
+        // 每周一、三上海时间 10:00，共 5 次，排除第二次，第三次改到 11:00
+        let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:w\nSUMMARY:Sync\n\
+            DTSTART;TZID=China Standard Time:20250602T100000\nDTEND;TZID=China Standard Time:20250602T103000\n\
+            RRULE:FREQ=WEEKLY;BYDAY=MO,WE;COUNT=5\nEXDATE;TZID=China Standard Time:20250604T100000\nEND:VEVENT\n\
+            BEGIN:VEVENT\nUID:w\nSUMMARY:Sync (moved)\nRECURRENCE-ID;TZID=Asia/Shanghai:20250609T100000\n\
+            DTSTART;TZID=Asia/Shanghai:20250609T110000\nDTEND;TZID=Asia/Shanghai:20250609T113000\nEND:VEVENT\nEND:VCALENDAR\n";
+        let parsed = parse_ics(ics, ts("2025-06-01 00:00"), ts("2025-07-01 00:00")).unwrap();
+        let starts: Vec<(i64, &str)> = parsed.events.iter().map(|e| (e.start_at, e.title.as_str())).collect();
+        assert_eq!(
+            starts,
+            vec![
+                (ts("2025-06-02 02:00"), "Sync"),
+                (ts("2025-06-09 03:00"), "Sync (moved)"),
+                (ts("2025-06-11 02:00"), "Sync"),
+                (ts("2025-06-16 02:00"), "Sync"),
+            ]
+        );
+
+        // 每月第二个星期二，直到 8 月底
+        let start = Stamp {
+            time: NaiveDateTime::parse_from_str("2025-01-14 09:00", "%Y-%m-%d %H:%M").unwrap(),
+            zone: Zone::Utc,
+            all_day: false,
+        };
+        let rule = parse_rule("FREQ=MONTHLY;BYDAY=2TU;UNTIL=20250831", &start).unwrap();
+        let dates: Vec<String> = expand(&rule, start, i64::MAX).iter().map(|s| s.time.format("%m-%d").to_string()).collect();
+        assert_eq!(dates, ["01-14", "02-11", "03-11", "04-08", "05-13", "06-10", "07-08", "08-12"]);
+        let rule = parse_rule("FREQ=MONTHLY;BYMONTHDAY=-1;COUNT=3", &start).unwrap();
+        let dates: Vec<String> = expand(&rule, start, i64::MAX).iter().map(|s| s.time.format("%m-%d").to_string()).collect();
+        assert_eq!(dates, ["01-31", "02-28", "03-31"]);
+        assert_eq!(parse_duration("P1DT2H30M"), Some(95400));
+    }
+}
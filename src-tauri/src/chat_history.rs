@@ -0,0 +1,338 @@
+// AI 对话记录：保存启动器 AI 问答等对话，支持置顶、按内容搜索消息、导出为 Markdown
+// 超过保留天数的对话在空闲时自动删除，置顶的对话始终保留
+
+use crate::db;
+use crate::llm::ChatMessage;
+use chrono::{Local, TimeZone};
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DAY_SECS: i64 = 24 * 60 * 60;
+const TITLE_CHARS: usize = 40;
+const SNIPPET_CHARS: usize = 80;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conversation {
+    pub id: String,
+    pub title: String,
+    pub feature: String, // 发起对话的功能，如 "launcher_ask"
+    pub model: String,
+    pub pinned: bool,
+    pub message_count: u32,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredMessage {
+    pub role: String,
+    pub content: String,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageMatch {
+    pub conversation_id: String,
+    pub title: String,
+    pub role: String,
+    pub snippet: String, // 匹配位置附近的片段
+    pub created_at: i64,
+}
+
+fn now_ts() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+/// 对话标题：第一条用户消息的第一行，过长时截断
+fn make_title(messages: &[ChatMessage]) -> String {
+    let first = messages
+        .iter()
+        .find(|m| m.role == "user")
+        .and_then(|m| m.content.lines().map(str::trim).find(|l| !l.is_empty()))
+        .unwrap_or("新对话");
+    if first.chars().count() > TITLE_CHARS {
+        format!("{}…", first.chars().take(TITLE_CHARS).collect::<String>())
+    } else {
+        first.to_string()
+    }
+}
+
+/// 截取 content 中 query 附近的片段（不区分大小写），找不到时取开头
+fn make_snippet(content: &str, query: &str) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let lower: Vec<char> = content.to_lowercase().chars().collect();
+    let needle: Vec<char> = query.to_lowercase().chars().collect();
+    // 小写后字符数不变时才能按位置对应回原文
+    let position = if lower.len() == chars.len() && !needle.is_empty() {
+        lower.windows(needle.len()).position(|w| w == needle.as_slice())
+    } else {
+        None
+    };
+    let start = position.map(|p| p.saturating_sub(SNIPPET_CHARS / 4)).unwrap_or(0);
+    let end = (start + SNIPPET_CHARS).min(chars.len());
+    let mut snippet: String = chars[start..end].iter().collect();
+    snippet = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+    if start > 0 {
+        snippet.insert(0, '…');
+    }
+    if end < chars.len() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+/// 保存一次对话（系统消息不保存），返回对话 id
+pub fn save_conversation(
+    app_data_dir: &Path,
+    feature: &str,
+    model: &str,
+    messages: &[ChatMessage],
+    answer: &str,
+) -> Result<String, String> {
+    let title = make_title(messages);
+    let messages: Vec<&ChatMessage> = messages.iter().filter(|m| m.role != "system").collect();
+    let id = format!(
+        "chat-{}",
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos()
+    );
+    let now = now_ts();
+
+    db::with_connection(app_data_dir, |conn| {
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| format!("Failed to start chat history transaction: {}", e))?;
+        tx.execute(
+            "INSERT INTO chat_conversations (id, title, feature, model, pinned, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, 0, ?5, ?5)",
+            params![id, title, feature, model, now],
+        )
+        .map_err(|e| format!("Failed to save chat conversation: {}", e))?;
+        {
+            let mut stmt = tx
+                .prepare_cached(
+                    "INSERT INTO chat_messages (conversation_id, role, content, created_at)
+                     VALUES (?1, ?2, ?3, ?4)",
+                )
+                .map_err(|e| format!("Failed to prepare chat message insert: {}", e))?;
+            for message in &messages {
+                stmt.execute(params![id, message.role, message.content, now])
+                    .map_err(|e| format!("Failed to save chat message: {}", e))?;
+            }
+            stmt.execute(params![id, "assistant", answer, now])
+                .map_err(|e| format!("Failed to save chat message: {}", e))?;
+        }
+        tx.commit()
+            .map_err(|e| format!("Failed to commit chat history: {}", e))
+    })?;
+    Ok(id)
+}
+
+fn conversation_from_row(row: &rusqlite::Row) -> rusqlite::Result<Conversation> {
+    Ok(Conversation {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        feature: row.get(2)?,
+        model: row.get(3)?,
+        pinned: row.get(4)?,
+        message_count: row.get(5)?,
+        created_at: row.get(6)?,
+        updated_at: row.get(7)?,
+    })
+}
+
+const CONVERSATION_COLUMNS: &str = "c.id, c.title, c.feature, c.model, c.pinned,
+    (SELECT COUNT(*) FROM chat_messages m WHERE m.conversation_id = c.id), c.created_at, c.updated_at";
+
+/// 获取所有对话（置顶在前，其余按更新时间倒序）
+pub fn list_conversations(app_data_dir: &Path) -> Result<Vec<Conversation>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(&format!(
+                "SELECT {} FROM chat_conversations c ORDER BY c.pinned DESC, c.updated_at DESC",
+                CONVERSATION_COLUMNS
+            ))
+            .map_err(|e| format!("Failed to prepare chat conversations query: {}", e))?;
+        let rows = stmt
+            .query_map([], conversation_from_row)
+            .map_err(|e| format!("Failed to query chat conversations: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read chat conversations: {}", e))?;
+        Ok(rows)
+    })
+}
+
+fn get_conversation(app_data_dir: &Path, id: &str) -> Result<Option<Conversation>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.query_row(
+            &format!("SELECT {} FROM chat_conversations c WHERE c.id = ?1", CONVERSATION_COLUMNS),
+            params![id],
+            conversation_from_row,
+        )
+        .optional()
+        .map_err(|e| format!("Failed to get chat conversation: {}", e))
+    })
+}
+
+/// 获取对话中的消息（按时间顺序）
+pub fn get_messages(app_data_dir: &Path, id: &str) -> Result<Vec<StoredMessage>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT role, content, created_at FROM chat_messages
+                 WHERE conversation_id = ?1 ORDER BY id",
+            )
+            .map_err(|e| format!("Failed to prepare chat messages query: {}", e))?;
+        let rows = stmt
+            .query_map(params![id], |row| {
+                Ok(StoredMessage {
+                    role: row.get(0)?,
+                    content: row.get(1)?,
+                    created_at: row.get(2)?,
+                })
+            })
+            .map_err(|e| format!("Failed to query chat messages: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read chat messages: {}", e))?;
+        Ok(rows)
+    })
+}
+
+/// 置顶或取消置顶对话，置顶的对话不会被自动删除
+pub fn set_pinned(app_data_dir: &Path, id: &str, pinned: bool) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached("UPDATE chat_conversations SET pinned = ?2 WHERE id = ?1")
+            .and_then(|mut stmt| stmt.execute(params![id, pinned]))
+            .map_err(|e| format!("Failed to pin chat conversation: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 删除对话及其消息
+pub fn delete_conversation(app_data_dir: &Path, id: &str) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| format!("Failed to start chat history transaction: {}", e))?;
+        tx.execute("DELETE FROM chat_messages WHERE conversation_id = ?1", params![id])
+            .and_then(|_| tx.execute("DELETE FROM chat_conversations WHERE id = ?1", params![id]))
+            .map_err(|e| format!("Failed to delete chat conversation: {}", e))?;
+        tx.commit()
+            .map_err(|e| format!("Failed to commit chat history: {}", e))
+    })
+}
+
+/// 在所有消息中搜索（不区分大小写），按时间倒序返回
+pub fn search_messages(app_data_dir: &Path, query: &str, limit: usize) -> Result<Vec<MessageMatch>, String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let like = format!("%{}%", query.to_lowercase());
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT m.conversation_id, c.title, m.role, m.content, m.created_at
+                 FROM chat_messages m JOIN chat_conversations c ON c.id = m.conversation_id
+                 WHERE lower(m.content) LIKE ?1
+                 ORDER BY m.created_at DESC, m.id DESC LIMIT ?2",
+            )
+            .map_err(|e| format!("Failed to prepare chat search: {}", e))?;
+        let rows = stmt
+            .query_map(params![like, limit as i64], |row| {
+                let content: String = row.get(3)?;
+                Ok(MessageMatch {
+                    conversation_id: row.get(0)?,
+                    title: row.get(1)?,
+                    role: row.get(2)?,
+                    snippet: make_snippet(&content, query),
+                    created_at: row.get(4)?,
+                })
+            })
+            .map_err(|e| format!("Failed to search chat messages: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read chat search results: {}", e))?;
+        Ok(rows)
+    })
+}
+
+fn format_time(ts: i64) -> String {
+    Local
+        .timestamp_opt(ts, 0)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// 把对话转换为 Markdown 文本
+pub fn to_markdown(conversation: &Conversation, messages: &[StoredMessage]) -> String {
+    let mut output = format!(
+        "# {}\n\n- 模型：{}\n- 时间：{}\n",
+        conversation.title,
+        conversation.model,
+        format_time(conversation.created_at)
+    );
+    for message in messages {
+        let speaker = match message.role.as_str() {
+            "user" => "我",
+            "assistant" => "AI",
+            other => other,
+        };
+        output.push_str(&format!("\n## {}\n\n{}\n", speaker, message.content.trim_end()));
+    }
+    output
+}
+
+/// 导出对话为 Markdown
+pub fn export_markdown(app_data_dir: &Path, id: &str) -> Result<String, String> {
+    let conversation = get_conversation(app_data_dir, id)?
+        .ok_or_else(|| format!("对话 {} 不存在", id))?;
+    let messages = get_messages(app_data_dir, id)?;
+    Ok(to_markdown(&conversation, &messages))
+}
+
+/// 删除超过保留天数且未置顶的对话（保留天数为 0 表示永久保留），返回删除的对话数
+pub fn apply_retention(app_data_dir: &Path, retention_days: u32) -> Result<usize, String> {
+    if retention_days == 0 {
+        return Ok(0);
+    }
+    let cutoff = now_ts() - retention_days as i64 * DAY_SECS;
+    db::with_connection(app_data_dir, |conn| {
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| format!("Failed to start chat history transaction: {}", e))?;
+        tx.execute(
+            "DELETE FROM chat_messages WHERE conversation_id IN
+                (SELECT id FROM chat_conversations WHERE pinned = 0 AND updated_at < ?1)",
+            params![cutoff],
+        )
+        .map_err(|e| format!("Failed to apply chat history retention: {}", e))?;
+        let deleted = tx
+            .execute(
+                "DELETE FROM chat_conversations WHERE pinned = 0 AND updated_at < ?1",
+                params![cutoff],
+            )
+            .map_err(|e| format!("Failed to apply chat history retention: {}", e))?;
+        tx.commit()
+            .map_err(|e| format!("Failed to commit chat history: {}", e))?;
+        Ok(deleted)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_snippet_around_match() {
+        let content = format!("{}关键字 Rust 所有权{}", "前".repeat(50), "后".repeat(100));
+        let snippet = make_snippet(&content, "rust");
+        assert!(snippet.starts_with('…') && snippet.ends_with('…'));
+        assert!(snippet.contains("关键字 Rust 所有权"));
+        assert_eq!(make_snippet("short  text", "missing"), "short text");
+    }
+}
@@ -0,0 +1,217 @@
+// 以另一种形式复制剪切板中已有的项：图片复制为 base64 data URI 或图片文件，路径复制为 file:// URL，
+// 文本复制为 Markdown 代码块，表格文本（制表符分隔、CSV 或 Markdown 表格）转换为 CSV 或 Markdown 表格
+
+use crate::clipboard::{self, ClipboardContent, ClipboardItem};
+use crate::text_variables::percent_encode;
+use base64::{engine::general_purpose, Engine as _};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CopyFormat {
+    DataUri,
+    ImageFile,
+    FileUrl,
+    CodeBlock,
+    Csv,
+    MarkdownTable,
+}
+
+/// 按格式生成要写入剪贴板的内容
+pub fn convert(item: &ClipboardItem, format: CopyFormat) -> Result<ClipboardContent, String> {
+    let text = match (format, item.content_type.as_str()) {
+        (CopyFormat::DataUri, "image") => {
+            let data = std::fs::read(&item.content).map_err(|e| format!("Failed to read image: {}", e))?;
+            format!("data:image/png;base64,{}", general_purpose::STANDARD.encode(data))
+        }
+        (CopyFormat::ImageFile, "image") => {
+            if !std::path::Path::new(&item.content).exists() {
+                return Err("图片文件已不存在".to_string());
+            }
+            return Ok(ClipboardContent { files: vec![item.content.clone()], ..Default::default() });
+        }
+        (CopyFormat::FileUrl, _) => clipboard::file_paths(&item.content)
+            .iter()
+            .map(|path| file_url(path))
+            .collect::<Option<Vec<_>>>()
+            .ok_or("不是文件路径")?
+            .join("\n"),
+        (CopyFormat::CodeBlock, "text") => code_block(&item.content),
+        (CopyFormat::Csv, "text") => to_csv(&parse_table(&item.content).ok_or("不是表格文本")?),
+        (CopyFormat::MarkdownTable, "text") => to_markdown_table(&parse_table(&item.content).ok_or("不是表格文本")?),
+        _ => return Err("该类型的内容不支持这种复制格式".to_string()),
+    };
+    Ok(ClipboardContent { text: Some(text), ..Default::default() })
+}
+
+/// 绝对路径转换为 file:// URL，各段按 UTF-8 百分号编码；UNC 路径的服务器名作为主机名
+pub fn file_url(path: &str) -> Option<String> {
+    let normalized = path.replace('\\', "/");
+    let (prefix, rest) = if let Some(unc) = normalized.strip_prefix("//") {
+        let (host, rest) = unc.split_once('/')?;
+        (format!("file://{}/", host), rest.to_string())
+    } else if normalized.as_bytes().get(1) == Some(&b':') && normalized.as_bytes()[0].is_ascii_alphabetic() {
+        (format!("file:///{}/", &normalized[..2]), normalized[2..].trim_start_matches('/').to_string())
+    } else if let Some(rest) = normalized.strip_prefix('/') {
+        ("file:///".to_string(), rest.to_string())
+    } else {
+        return None;
+    };
+    let segments: Vec<String> = rest.split('/').map(percent_encode).collect();
+    Some(prefix + segments.join("/").as_str())
+}
+
+/// 用比内容中最长的连续反引号多一个的围栏包起来，内容里有 ``` 时也不会提前结束
+pub fn code_block(text: &str) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+    for c in text.chars() {
+        run = if c == '`' { run + 1 } else { 0 };
+        longest = longest.max(run);
+    }
+    let fence = "`".repeat((longest + 1).max(3));
+    format!("{}\n{}\n{}", fence, text.trim_end_matches(['\r', '\n']), fence)
+}
+
+/// 解析表格文本：Markdown 表格、制表符分隔（从 Excel 等复制）或 CSV；少于两列时不算表格
+pub fn parse_table(text: &str) -> Option<Vec<Vec<String>>> {
+    let lines: Vec<&str> = text.lines().map(|line| line.trim_end_matches('\r')).filter(|l| !l.trim().is_empty()).collect();
+    if lines.is_empty() {
+        return None;
+    }
+    let rows: Vec<Vec<String>> = if lines.iter().all(|line| line.trim_start().starts_with('|')) {
+        lines.iter().map(|line| split_markdown_row(line)).filter(|row| !is_separator_row(row)).collect()
+    } else if lines.iter().any(|line| line.contains('\t')) {
+        lines.iter().map(|line| line.split('\t').map(|cell| cell.trim().to_string()).collect()).collect()
+    } else if text.contains(',') {
+        parse_csv(text)
+    } else {
+        return None;
+    };
+    (rows.iter().map(Vec::len).max()? >= 2).then_some(rows)
+}
+
+fn split_markdown_row(line: &str) -> Vec<String> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = if line.ends_with('|') && !line.ends_with("\\|") { &line[..line.len() - 1] } else { line };
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => cell.push(chars.next().unwrap_or('|')),
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            c => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+fn is_separator_row(row: &[String]) -> bool {
+    row.iter().all(|cell| {
+        let dashes = cell.trim_start_matches(':').trim_end_matches(':');
+        !dashes.is_empty() && dashes.chars().all(|c| c == '-')
+    })
+}
+
+/// 按 RFC 4180 解析，引号内可以包含逗号和换行，"" 表示一个引号
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            '"' if quoted => quoted = false,
+            '"' if cell.trim().is_empty() => {
+                cell.clear();
+                quoted = true;
+            }
+            ',' if !quoted => row.push(std::mem::take(&mut cell)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut cell));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => cell.push(c),
+        }
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+    rows.retain(|row| row.iter().any(|cell| !cell.trim().is_empty()));
+    rows
+}
+
+pub fn to_csv(rows: &[Vec<String>]) -> String {
+    let quote = |cell: &String| {
+        if cell.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", cell.replace('"', "\"\""))
+        } else {
+            cell.clone()
+        }
+    };
+    rows.iter().map(|row| row.iter().map(quote).collect::<Vec<_>>().join(",")).collect::<Vec<_>>().join("\r\n")
+}
+
+/// 第一行作为表头，列数不足的行补空单元格
+pub fn to_markdown_table(rows: &[Vec<String>]) -> String {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let line = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+    // 单元格中的 | 需要转义，换行用 <br> 表示
+    let escape = |cell: &String| cell.replace('|', "\\|").replace("\r\n", "<br>").replace('\n', "<br>");
+    let row_line = |row: &Vec<String>| line((0..columns).map(|i| row.get(i).map(escape).unwrap_or_default()).collect());
+    let mut lines: Vec<String> = rows.iter().take(1).map(row_line).collect();
+    lines.push(line(vec!["---".to_string(); columns]));
+    lines.extend(rows.iter().skip(1).map(row_line));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter().map(|row| row.iter().map(|cell| cell.to_string()).collect()).collect()
+    }
+
+    #[test]
+    fn converts_paths_to_file_urls() {
+        assert_eq!(file_url(r"C:\Users\me\My Docs\报告.txt").unwrap(), "file:///C:/Users/me/My%20Docs/%E6%8A%A5%E5%91%8A.txt");
+        assert_eq!(file_url(r"\\server\share\a#b.png").unwrap(), "file://server/share/a%23b.png");
+        assert_eq!(file_url("/tmp/x y").unwrap(), "file:///tmp/x%20y");
+        assert_eq!(file_url("relative/path"), None);
+    }
+
+    #[test]
+    fn fences_code_longer_than_content() {
+        assert_eq!(code_block("let a = 1;\n"), "```\nlet a = 1;\n```");
+        assert_eq!(code_block("```rust\nx\n```"), "````\n```rust\nx\n```\n````");
+    }
+
+    #[test]
+    fn parses_tables_in_common_formats() {
+        let expected = table(&[&["name", "value"], &["a", "1"]]);
+        assert_eq!(parse_table("name\tvalue\r\na\t1\r\n").unwrap(), expected);
+        assert_eq!(parse_table("| name | value |\n|:---|---:|\n| a | 1 |").unwrap(), expected);
+        assert_eq!(parse_table("name,value\na,1").unwrap(), expected);
+        assert_eq!(parse_table("\"x, y\",\"say \"\"hi\"\"\"\n1,2").unwrap(), table(&[&["x, y", "say \"hi\""], &["1", "2"]]));
+        assert_eq!(parse_table("| a \\| b | c |").unwrap(), table(&[&["a | b", "c"]]));
+        assert_eq!(parse_table("just some text"), None);
+    }
+
+    #[test]
+    fn renders_csv_and_markdown_tables() {
+        let rows = table(&[&["name", "note"], &["a|b", "x, \"y\""], &["c"]]);
+        assert_eq!(to_csv(&rows), "name,note\r\na|b,\"x, \"\"y\"\"\"\r\nc");
+        assert_eq!(to_markdown_table(&rows), "| name | note |\n| --- | --- |\n| a\\|b | x, \"y\" |\n| c |  |");
+    }
+}
@@ -0,0 +1,199 @@
+// 剪切板图片文字识别：后台线程用 Windows OCR（Windows.Media.Ocr，按系统语言选择识别语言）处理新的图片项，
+// 识别结果保存在 clipboard_history.ocr_text 并进入全文索引，使截图也能被搜索到
+
+use crate::clipboard;
+use crate::event_bus::{self, BusEvent};
+use crate::shutdown;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+const BATCH_SIZE: usize = 10;
+
+// 启动时先处理一次，补上以前保存的图片；之后剪切板有写入时再检查
+static PENDING: AtomicBool = AtomicBool::new(true);
+
+/// 把 OCR 得到的单词拼成一行：中日韩文字之间不加空格，其余单词以空格分隔
+pub fn join_words<S: AsRef<str>>(words: &[S]) -> String {
+    let mut line = String::new();
+    for word in words.iter().map(AsRef::as_ref).filter(|w| !w.is_empty()) {
+        let joins_cjk = line.chars().last().is_some_and(is_cjk) && word.chars().next().is_some_and(is_cjk);
+        if !line.is_empty() && !joins_cjk {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    line
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{2E80}'..='\u{9FFF}' | '\u{AC00}'..='\u{D7AF}' | '\u{F900}'..='\u{FAFF}' | '\u{FF00}'..='\u{FFEF}')
+}
+
+/// 识别一批待处理的图片，返回处理的数量；单张图片识别失败时记为没有文字，避免反复重试
+fn process_batch(recognizer: &platform::Recognizer, app_data_dir: &PathBuf) -> Result<usize, String> {
+    let pending = clipboard::pending_ocr_images(BATCH_SIZE, app_data_dir)?;
+    for (id, path) in &pending {
+        if shutdown::is_requested() {
+            break;
+        }
+        let text = recognizer.recognize(path).unwrap_or_else(|e| {
+            eprintln!("[ClipboardOcr] Failed to recognize {}: {}", path, e);
+            String::new()
+        });
+        clipboard::set_ocr_text(id, &text, app_data_dir)?;
+    }
+    Ok(pending.len())
+}
+
+/// 启动后台识别线程；当前系统没有可用的 OCR 语言时线程记录原因后退出
+pub fn start(app_data_dir: PathBuf) -> Result<(), String> {
+    let mut receiver = event_bus::subscribe();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(BusEvent::DbWrite { table: "clipboard_items" }) => PENDING.store(true, Ordering::Relaxed),
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(_)) => PENDING.store(true, Ordering::Relaxed),
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+
+    shutdown::spawn("clipboard-ocr", move || {
+        let recognizer = match platform::Recognizer::new() {
+            Ok(recognizer) => recognizer,
+            Err(e) => {
+                eprintln!("[ClipboardOcr] {}", e);
+                return;
+            }
+        };
+        while !shutdown::wait_timeout(POLL_INTERVAL) {
+            if !PENDING.swap(false, Ordering::Relaxed) {
+                continue;
+            }
+            match process_batch(&recognizer, &app_data_dir) {
+                // 一批处理满了说明可能还有剩余，下一轮继续
+                Ok(count) if count >= BATCH_SIZE => PENDING.store(true, Ordering::Relaxed),
+                Ok(_) => {}
+                Err(e) => eprintln!("[ClipboardOcr] {}", e),
+            }
+        }
+    })
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::join_words;
+    use ::windows::core::HSTRING;
+    use ::windows::Graphics::Imaging::{
+        BitmapAlphaMode, BitmapDecoder, BitmapPixelFormat, BitmapTransform, ColorManagementMode, ExifOrientationMode,
+    };
+    use ::windows::Media::Ocr::OcrEngine;
+    use ::windows::Storage::{FileAccessMode, StorageFile};
+    use ::windows::Win32::System::Com::{CoInitializeEx, CoUninitialize, COINIT_MULTITHREADED};
+
+    pub struct Recognizer {
+        engine: OcrEngine,
+        max_dimension: u32,
+        com_initialized: bool,
+    }
+
+    impl Recognizer {
+        /// 在当前线程初始化 COM 并创建识别引擎，识别器只在创建它的线程中使用
+        pub fn new() -> Result<Self, String> {
+            let com_initialized = unsafe { CoInitializeEx(None, COINIT_MULTITHREADED).is_ok() };
+            let created = OcrEngine::TryCreateFromUserProfileLanguages()
+                .map_err(|e| format!("没有可用的 OCR 识别语言，请在系统设置中安装语言的 OCR 组件: {}", e))
+                .and_then(|engine| {
+                    let max_dimension = OcrEngine::MaxImageDimension().map_err(|e| e.to_string())?;
+                    Ok((engine, max_dimension))
+                });
+            match created {
+                Ok((engine, max_dimension)) => Ok(Self { engine, max_dimension, com_initialized }),
+                Err(e) => {
+                    if com_initialized {
+                        unsafe { CoUninitialize() };
+                    }
+                    Err(e)
+                }
+            }
+        }
+
+        /// 识别图片文件中的文字，每行一段；超过 OCR 支持的最大尺寸时按比例缩小
+        pub fn recognize(&self, path: &str) -> Result<String, String> {
+            let run = || -> ::windows::core::Result<String> {
+                let file = StorageFile::GetFileFromPathAsync(&HSTRING::from(path))?.get()?;
+                let stream = file.OpenAsync(FileAccessMode::Read)?.get()?;
+                let decoder = BitmapDecoder::CreateAsync(&stream)?.get()?;
+                let (width, height) = (decoder.PixelWidth()?, decoder.PixelHeight()?);
+                let transform = BitmapTransform::new()?;
+                let longest = width.max(height);
+                if longest > self.max_dimension {
+                    let scale = self.max_dimension as f64 / longest as f64;
+                    transform.SetScaledWidth(((width as f64 * scale) as u32).max(1))?;
+                    transform.SetScaledHeight(((height as f64 * scale) as u32).max(1))?;
+                }
+                let bitmap = decoder
+                    .GetSoftwareBitmapTransformedAsync(
+                        BitmapPixelFormat::Bgra8,
+                        BitmapAlphaMode::Premultiplied,
+                        &transform,
+                        ExifOrientationMode::IgnoreExifOrientation,
+                        ColorManagementMode::DoNotColorManage,
+                    )?
+                    .get()?;
+                let result = self.engine.RecognizeAsync(&bitmap)?.get()?;
+                let mut lines = Vec::new();
+                for line in result.Lines()? {
+                    let words = line
+                        .Words()?
+                        .into_iter()
+                        .map(|word| word.Text().map(|text| text.to_string()))
+                        .collect::<::windows::core::Result<Vec<_>>>()?;
+                    lines.push(join_words(&words));
+                }
+                Ok(lines.join("\n"))
+            };
+            run().map_err(|e| e.to_string())
+        }
+    }
+
+    impl Drop for Recognizer {
+        fn drop(&mut self) {
+            if self.com_initialized {
+                unsafe { CoUninitialize() };
+            }
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    pub struct Recognizer;
+
+    impl Recognizer {
+        pub fn new() -> Result<Self, String> {
+            Err("当前平台不支持图片文字识别".to_string())
+        }
+
+        pub fn recognize(&self, _path: &str) -> Result<String, String> {
+            Err("当前平台不支持图片文字识别".to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joins_cjk_words_without_spaces() {
+        assert_eq!(join_words(&["剪", "切", "板", "IMiss", "v1.0"]), "剪切板 IMiss v1.0");
+        assert_eq!(join_words(&["Hello", "", "世界", "！"]), "Hello 世界！");
+        assert_eq!(join_words::<&str>(&[]), "");
+    }
+}
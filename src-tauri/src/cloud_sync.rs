@@ -0,0 +1,826 @@
+// 云同步（默认关闭）：把设置、片段（备忘录）和单词本同步到用户自己的 WebDAV 或 S3 存储
+// 远端是只追加的变更日志 journal/<序号>.bin，内容用同步口令派生的密钥（PBKDF2-SHA256 + AES-256-GCM）端到端加密；
+// 每次同步先拉取其他设备的新日志，再把本地自上次同步以来的变化写成一条新日志；双方都改过的记录后写者胜出，并记入冲突日志
+
+use crate::db;
+use crate::memos::{self, MemoItem};
+use crate::secrets;
+use crate::settings::{self, Settings};
+use crate::shutdown;
+use crate::sync_backend::{Backend, BackendConfig, BackendKind, PutResult};
+use crate::word_records::{self, WordRecord};
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::Engine;
+use rand::Rng;
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+const CONFIG_KEY: &str = "cloud_sync"; // settings 表中保存配置的键
+const STATUS_KEY: &str = "cloud_sync_state";
+const REMOTE_ROOT: &str = "imiss-sync";
+const KEY_INFO: &str = "imiss-sync/keyinfo.json";
+const JOURNAL_DIR: &str = "imiss-sync/journal";
+const KDF_ITERATIONS: u32 = 210_000;
+const KEY_CHECK: &[u8] = b"imiss-sync-key-check";
+const NONCE_LEN: usize = 12;
+const MAX_PUSH_ATTEMPTS: usize = 3;
+const SCHEDULER_TICK: Duration = Duration::from_secs(60);
+const DEFAULT_INTERVAL_MINUTES: u32 = 30;
+const MAX_CONFLICTS: i64 = 500;
+
+pub const COMPLETED_EVENT: &str = "cloud-sync-completed";
+
+/// 可同步的数据集：(id, 名称)
+pub const DATASETS: &[(&str, &str)] = &[("settings", "设置"), ("snippets", "片段（备忘录）"), ("wordbook", "单词本")];
+
+// 敏感或与本机相关的设置项不参与同步，应用远端设置时保留本机的值
+const LOCAL_ONLY_SETTINGS: &[&str] = &[
+    "/llm/api_key",
+    "/proxy",
+    "/startup_enabled",
+    "/last_update_check_time",
+    "/ignored_update_version",
+    "/git_repo_roots",
+    "/hot_folders",
+    "/display_presets",
+];
+
+static SYNCING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncConfig {
+    pub enabled: bool,
+    pub backend: BackendKind,
+    pub endpoint: String,
+    pub username: String, // WebDAV 用户名或 S3 Access Key
+    pub region: String,
+    pub bucket: String,
+    pub datasets: Vec<String>,
+    pub interval_minutes: u32, // 自动同步间隔，0 表示只手动同步
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        SyncConfig {
+            enabled: false,
+            backend: BackendKind::WebDav,
+            endpoint: String::new(),
+            username: String::new(),
+            region: String::new(),
+            bucket: String::new(),
+            datasets: DATASETS.iter().map(|(id, _)| id.to_string()).collect(),
+            interval_minutes: DEFAULT_INTERVAL_MINUTES,
+        }
+    }
+}
+
+// 密码与同步口令以 DPAPI 加密后保存
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct StoredConfig {
+    #[serde(flatten)]
+    config: SyncConfig,
+    secret: Option<String>,
+    passphrase: Option<String>,
+}
+
+/// 返回给前端的配置，不含密码与口令
+#[derive(Debug, Serialize)]
+pub struct SyncConfigView {
+    #[serde(flatten)]
+    pub config: SyncConfig,
+    pub has_secret: bool,
+    pub has_passphrase: bool,
+}
+
+/// 前端保存的配置；secret / passphrase 为 None 时保留原值，为空字符串时清除
+#[derive(Debug, Deserialize)]
+pub struct SyncConfigInput {
+    #[serde(flatten)]
+    pub config: SyncConfig,
+    pub secret: Option<String>,
+    pub passphrase: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncSummary {
+    pub pulled: usize,
+    pub pushed: usize,
+    pub conflicts: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncStatus {
+    pub device_id: String,
+    pub last_seq: u64, // 已应用的远端日志序号
+    pub last_sync_at: Option<i64>,
+    pub last_attempt_at: Option<i64>,
+    pub last_error: Option<String>,
+    pub last_summary: Option<SyncSummary>,
+    pub running: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncConflict {
+    pub id: i64,
+    pub dataset: String,
+    pub record_id: String,
+    pub winner: String, // "local" | "remote"
+    pub local_data: Option<Value>,
+    pub remote_data: Option<Value>,
+    pub local_updated_at: i64,
+    pub remote_updated_at: i64,
+    pub remote_device: String,
+    pub created_at: i64,
+}
+
+/// 一条记录的变化；data 为 None 表示删除，updated_at 为毫秒时间戳
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Change {
+    pub dataset: String,
+    pub id: String,
+    pub updated_at: i64,
+    #[serde(default)]
+    pub data: Option<Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct JournalEntry {
+    device_id: String,
+    created_at: i64,
+    changes: Vec<Change>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeyInfo {
+    version: u32,
+    kdf: String,
+    iterations: u32,
+    salt: String,
+    check: String, // 用派生密钥加密的固定内容，用于识别口令错误
+}
+
+type RecordKey = (String, String); // (数据集, 记录 id)
+
+/// 本地数据：记录内容及其自带的修改时间（毫秒），没有时为 None
+type Snapshot = BTreeMap<RecordKey, (Value, Option<i64>)>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    ApplyRemote,
+    KeepLocal,
+    Identical, // 双方修改后的内容相同，本地无需再推送
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+fn b64() -> base64::engine::GeneralPurpose {
+    base64::engine::general_purpose::STANDARD
+}
+
+fn journal_key(seq: u64) -> String {
+    format!("{}/{:010}.bin", JOURNAL_DIR, seq)
+}
+
+fn record_hash(value: &Value) -> String {
+    Sha256::digest(value.to_string().as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// ---------- 加密 ----------
+
+struct Cipher(Aes256Gcm);
+
+impl Cipher {
+    fn derive(passphrase: &str, salt: &[u8], iterations: u32) -> Self {
+        let mut key = [0u8; 32];
+        pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, iterations, &mut key);
+        Cipher(Aes256Gcm::new(&key.into()))
+    }
+
+    /// 输出为 nonce ‖ 密文
+    fn encrypt(&self, plain: &[u8]) -> Result<Vec<u8>, String> {
+        let nonce: [u8; NONCE_LEN] = rand::thread_rng().gen();
+        let cipher = self.0.encrypt(Nonce::from_slice(&nonce), plain).map_err(|_| "加密同步数据失败".to_string())?;
+        Ok([nonce.as_slice(), &cipher].concat())
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, String> {
+        if data.len() < NONCE_LEN {
+            return Err("同步数据已损坏".to_string());
+        }
+        let (nonce, cipher) = data.split_at(NONCE_LEN);
+        self.0
+            .decrypt(Nonce::from_slice(nonce), cipher)
+            .map_err(|_| "无法解密同步数据，请检查同步口令".to_string())
+    }
+}
+
+/// 读取远端的密钥参数并派生密钥；首次同步时生成随机盐并写入
+async fn open_cipher(backend: &Backend, passphrase: &str) -> Result<Cipher, String> {
+    for _ in 0..2 {
+        if let Some(bytes) = backend.get(KEY_INFO).await? {
+            let info: KeyInfo = serde_json::from_slice(&bytes).map_err(|e| format!("远端密钥信息无效: {}", e))?;
+            let salt = b64().decode(&info.salt).map_err(|e| format!("远端密钥信息无效: {}", e))?;
+            let check = b64().decode(&info.check).map_err(|e| format!("远端密钥信息无效: {}", e))?;
+            let passphrase = passphrase.to_string();
+            let cipher = tauri::async_runtime::spawn_blocking(move || Cipher::derive(&passphrase, &salt, info.iterations))
+                .await
+                .map_err(|e| format!("派生密钥失败: {}", e))?;
+            return match cipher.decrypt(&check) {
+                Ok(plain) if plain == KEY_CHECK => Ok(cipher),
+                _ => Err("同步口令与远端数据不一致".to_string()),
+            };
+        }
+
+        let salt: [u8; 16] = rand::thread_rng().gen();
+        let cipher = Cipher::derive(passphrase, &salt, KDF_ITERATIONS);
+        let info = KeyInfo {
+            version: 1,
+            kdf: "pbkdf2-sha256".to_string(),
+            iterations: KDF_ITERATIONS,
+            salt: b64().encode(salt),
+            check: b64().encode(cipher.encrypt(KEY_CHECK)?),
+        };
+        let body = serde_json::to_vec_pretty(&info).map_err(|e| format!("Failed to serialize key info: {}", e))?;
+        // 另一台设备同时初始化时以先写入的为准
+        if backend.put(KEY_INFO, &body, true).await? == PutResult::Written {
+            return Ok(cipher);
+        }
+    }
+    Err("无法初始化远端密钥信息".to_string())
+}
+
+// ---------- 配置与状态 ----------
+
+fn read_kv(app_data_dir: &Path, key: &str) -> Result<Option<String>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached("SELECT value FROM settings WHERE key = ?1")
+            .and_then(|mut stmt| stmt.query_row(params![key], |row| row.get(0)).optional())
+            .map_err(|e| format!("Failed to read {}: {}", key, e))
+    })
+}
+
+fn write_kv(app_data_dir: &Path, key: &str, value: &impl Serialize) -> Result<(), String> {
+    let value = serde_json::to_string(value).map_err(|e| format!("Failed to serialize {}: {}", key, e))?;
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "INSERT INTO settings (key, value) VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        )
+        .and_then(|mut stmt| stmt.execute(params![key, value]))
+        .map_err(|e| format!("Failed to save {}: {}", key, e))
+    })?;
+    Ok(())
+}
+
+fn load_stored(app_data_dir: &Path) -> Result<StoredConfig, String> {
+    Ok(read_kv(app_data_dir, CONFIG_KEY)?
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default())
+}
+
+pub fn get_config(app_data_dir: &Path) -> Result<SyncConfigView, String> {
+    let stored = load_stored(app_data_dir)?;
+    Ok(SyncConfigView {
+        has_secret: stored.secret.is_some(),
+        has_passphrase: stored.passphrase.is_some(),
+        config: stored.config,
+    })
+}
+
+pub fn save_config(app_data_dir: &Path, input: SyncConfigInput) -> Result<SyncConfigView, String> {
+    let mut stored = load_stored(app_data_dir)?;
+    let mut config = input.config;
+    config.datasets.retain(|d| DATASETS.iter().any(|(id, _)| id == d));
+    config.datasets.dedup();
+    if config.enabled && config.endpoint.trim().is_empty() {
+        return Err("请填写同步服务地址".to_string());
+    }
+    let protect = |value: Option<String>, current: Option<String>| -> Result<Option<String>, String> {
+        match value {
+            None => Ok(current),
+            Some(v) if v.is_empty() => Ok(None),
+            Some(v) => secrets::encrypt(&v).map(Some),
+        }
+    };
+    stored.secret = protect(input.secret, stored.secret.take())?;
+    stored.passphrase = protect(input.passphrase, stored.passphrase.take())?;
+    if config.enabled && stored.passphrase.is_none() {
+        return Err("请设置同步口令，所有设备需使用相同的口令".to_string());
+    }
+    stored.config = config;
+    write_kv(app_data_dir, CONFIG_KEY, &stored)?;
+    get_config(app_data_dir)
+}
+
+fn load_status(app_data_dir: &Path) -> Result<SyncStatus, String> {
+    let mut status: SyncStatus = read_kv(app_data_dir, STATUS_KEY)?
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    if status.device_id.is_empty() {
+        let bytes: [u8; 8] = rand::thread_rng().gen();
+        status.device_id = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        write_kv(app_data_dir, STATUS_KEY, &status)?;
+    }
+    Ok(status)
+}
+
+pub fn get_status(app_data_dir: &Path) -> Result<SyncStatus, String> {
+    let mut status = load_status(app_data_dir)?;
+    status.running = SYNCING.load(Ordering::SeqCst);
+    Ok(status)
+}
+
+// ---------- 本地数据 ----------
+
+pub fn strip_local_only(value: &mut Value) {
+    for pointer in LOCAL_ONLY_SETTINGS {
+        let (parent, key) = pointer.rsplit_once('/').unwrap_or_default();
+        if let Some(object) = value.pointer_mut(parent).and_then(Value::as_object_mut) {
+            object.remove(key);
+        }
+    }
+}
+
+/// 用本机的值覆盖远端设置中的本机专属项
+pub fn merge_local_only(mut remote: Value, local: &Value) -> Value {
+    for pointer in LOCAL_ONLY_SETTINGS {
+        let (parent, key) = pointer.rsplit_once('/').unwrap_or_default();
+        let (Some(local_value), Some(object)) =
+            (local.pointer(pointer), remote.pointer_mut(parent).and_then(Value::as_object_mut))
+        else {
+            continue;
+        };
+        object.insert(key.to_string(), local_value.clone());
+    }
+    remote
+}
+
+fn to_value(value: &impl Serialize) -> Result<Value, String> {
+    serde_json::to_value(value).map_err(|e| format!("Failed to serialize sync record: {}", e))
+}
+
+fn snapshot(app_data_dir: &Path, datasets: &[String]) -> Result<Snapshot, String> {
+    let mut records = Snapshot::new();
+    let dir = app_data_dir.to_path_buf();
+    for dataset in datasets {
+        let mut insert = |id: String, data: Value, updated_at: Option<i64>| {
+            records.insert((dataset.clone(), id), (data, updated_at));
+        };
+        match dataset.as_str() {
+            "settings" => {
+                let mut value = to_value(&settings::load_settings(app_data_dir)?)?;
+                strip_local_only(&mut value);
+                insert("settings".to_string(), value, None);
+            }
+            "snippets" => {
+                for memo in memos::get_all_memos(&dir)? {
+                    let updated_at = memo.updated_at as i64 * 1000;
+                    insert(memo.id.clone(), to_value(&memo)?, Some(updated_at));
+                }
+            }
+            "wordbook" => {
+                for record in word_records::get_all_word_records(app_data_dir)? {
+                    let updated_at = record.updated_at as i64 * 1000;
+                    insert(record.id.clone(), to_value(&record)?, Some(updated_at));
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(records)
+}
+
+/// 写入攒下的单词记录（单个事务）
+fn flush_words(app_data_dir: &Path, words: &mut Vec<WordRecord>) -> Result<(), String> {
+    if !words.is_empty() {
+        word_records::insert_word_records(words, db::ConflictMode::Replace, app_data_dir)?;
+        words.clear();
+    }
+    Ok(())
+}
+
+/// 应用一条远端修改；单词记录先攒到 words 中，由调用方批量写入
+fn apply_change(app_data_dir: &Path, change: &Change, words: &mut Vec<WordRecord>) -> Result<(), String> {
+    let dir = app_data_dir.to_path_buf();
+    let invalid = |e: serde_json::Error| format!("远端 {} 记录无效: {}", change.dataset, e);
+    match (change.dataset.as_str(), &change.data) {
+        ("settings", Some(data)) => {
+            let local = to_value(&settings::load_settings(app_data_dir)?)?;
+            let merged: Settings = serde_json::from_value(merge_local_only(data.clone(), &local)).map_err(invalid)?;
+            settings::save_settings(app_data_dir, &merged)
+        }
+        ("snippets", Some(data)) => {
+            let memo: MemoItem = serde_json::from_value(data.clone()).map_err(invalid)?;
+            memos::upsert_memo(&memo, &dir)
+        }
+        ("snippets", None) => match memos::delete_memo(change.id.clone(), &dir) {
+            Err(e) if e != "Memo not found" => Err(e),
+            _ => Ok(()),
+        },
+        ("wordbook", Some(data)) => {
+            words.push(serde_json::from_value(data.clone()).map_err(invalid)?);
+            Ok(())
+        }
+        ("wordbook", None) => {
+            // 先写入之前攒下的记录，保证删除在它们之后生效
+            flush_words(app_data_dir, words)?;
+            match word_records::delete_word_record(change.id.clone(), app_data_dir) {
+                Err(e) if e != "WordRecord not found" => Err(e),
+                _ => Ok(()),
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 上次同步后记录的各条数据的哈希
+fn load_hashes(app_data_dir: &Path) -> Result<HashMap<RecordKey, String>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached("SELECT dataset, record_id, hash FROM sync_state")
+            .map_err(|e| format!("Failed to prepare sync_state query: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| Ok(((row.get(0)?, row.get(1)?), row.get(2)?)))
+            .map_err(|e| format!("Failed to query sync_state: {}", e))?;
+        rows.collect::<Result<_, _>>().map_err(|e| format!("Failed to read sync_state: {}", e))
+    })
+}
+
+/// 同步完成后刷新哈希。只有内容等于同步开始时的快照或刚应用的远端数据的记录才更新，
+/// 同步过程中用户新做的修改保留旧哈希，下次同步时推送
+fn save_hashes(
+    app_data_dir: &Path,
+    datasets: &[String],
+    previous: &HashMap<RecordKey, String>,
+    initial: &Snapshot,
+    applied: &HashMap<RecordKey, Option<Value>>,
+    current: &Snapshot,
+) -> Result<(), String> {
+    let keys: BTreeSet<&RecordKey> = previous
+        .keys()
+        .filter(|key| datasets.contains(&key.0))
+        .chain(initial.keys())
+        .chain(current.keys())
+        .collect();
+    db::with_connection(app_data_dir, |conn| {
+        let tx = conn.unchecked_transaction().map_err(|e| format!("Failed to begin sync_state update: {}", e))?;
+        for key in keys {
+            let now = current.get(key).map(|(data, _)| data);
+            let settled = now == initial.get(key).map(|(data, _)| data)
+                || applied.get(key).is_some_and(|data| data.as_ref() == now);
+            if !settled {
+                continue;
+            }
+            let result = match now {
+                Some(data) => tx
+                    .prepare_cached("INSERT OR REPLACE INTO sync_state (dataset, record_id, hash) VALUES (?1, ?2, ?3)")
+                    .and_then(|mut stmt| stmt.execute(params![key.0, key.1, record_hash(data)])),
+                None => tx
+                    .prepare_cached("DELETE FROM sync_state WHERE dataset = ?1 AND record_id = ?2")
+                    .and_then(|mut stmt| stmt.execute(params![key.0, key.1])),
+            };
+            result.map_err(|e| format!("Failed to update sync_state: {}", e))?;
+        }
+        tx.commit().map_err(|e| format!("Failed to commit sync_state: {}", e))
+    })
+}
+
+/// 对比上次同步的哈希找出本地的修改；没有自带修改时间的记录用 now，首次同步（无哈希）时用 0 让远端优先
+pub fn diff_local(current: &Snapshot, hashes: &HashMap<RecordKey, String>, datasets: &[String], now: i64) -> BTreeMap<RecordKey, Change> {
+    let mut changes = BTreeMap::new();
+    for (key, (data, updated_at)) in current {
+        let previous = hashes.get(key);
+        if previous == Some(&record_hash(data)) {
+            continue;
+        }
+        let updated_at = updated_at.unwrap_or(if previous.is_some() { now } else { 0 });
+        changes.insert(key.clone(), Change { dataset: key.0.clone(), id: key.1.clone(), updated_at, data: Some(data.clone()) });
+    }
+    for key in hashes.keys() {
+        if datasets.contains(&key.0) && !current.contains_key(key) {
+            changes.insert(key.clone(), Change { dataset: key.0.clone(), id: key.1.clone(), updated_at: now, data: None });
+        }
+    }
+    changes
+}
+
+/// 后写者胜出：修改时间相同时按设备 id 决定，保证各设备得到相同结果
+pub fn resolve(local: Option<&Change>, remote: &Change, local_device: &str, remote_device: &str) -> Resolution {
+    let Some(local) = local else {
+        return Resolution::ApplyRemote;
+    };
+    if local.data == remote.data {
+        return Resolution::Identical;
+    }
+    if (remote.updated_at, remote_device) > (local.updated_at, local_device) {
+        Resolution::ApplyRemote
+    } else {
+        Resolution::KeepLocal
+    }
+}
+
+fn log_conflict(app_data_dir: &Path, local: &Change, remote: &Change, winner: &str, remote_device: &str) -> Result<(), String> {
+    let json = |data: &Option<Value>| data.as_ref().map(Value::to_string);
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "INSERT INTO sync_conflicts (dataset, record_id, winner, local_data, remote_data,
+                local_updated_at, remote_updated_at, remote_device, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        )
+        .and_then(|mut stmt| {
+            stmt.execute(params![
+                local.dataset,
+                local.id,
+                winner,
+                json(&local.data),
+                json(&remote.data),
+                local.updated_at,
+                remote.updated_at,
+                remote_device,
+                chrono::Utc::now().timestamp()
+            ])
+        })
+        .and_then(|_| {
+            conn.execute(
+                "DELETE FROM sync_conflicts WHERE id NOT IN (SELECT id FROM sync_conflicts ORDER BY id DESC LIMIT ?1)",
+                params![MAX_CONFLICTS],
+            )
+        })
+        .map_err(|e| format!("Failed to log sync conflict: {}", e))
+    })?;
+    Ok(())
+}
+
+pub fn list_conflicts(app_data_dir: &Path, limit: u32) -> Result<Vec<SyncConflict>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT id, dataset, record_id, winner, local_data, remote_data, local_updated_at,
+                    remote_updated_at, remote_device, created_at
+                 FROM sync_conflicts ORDER BY id DESC LIMIT ?1",
+            )
+            .map_err(|e| format!("Failed to prepare sync_conflicts query: {}", e))?;
+        let parse = |text: Option<String>| text.and_then(|t| serde_json::from_str(&t).ok());
+        let rows = stmt
+            .query_map(params![limit], |row| {
+                Ok(SyncConflict {
+                    id: row.get(0)?,
+                    dataset: row.get(1)?,
+                    record_id: row.get(2)?,
+                    winner: row.get(3)?,
+                    local_data: parse(row.get(4)?),
+                    remote_data: parse(row.get(5)?),
+                    local_updated_at: row.get(6)?,
+                    remote_updated_at: row.get(7)?,
+                    remote_device: row.get(8)?,
+                    created_at: row.get(9)?,
+                })
+            })
+            .map_err(|e| format!("Failed to query sync_conflicts: {}", e))?;
+        rows.collect::<Result<_, _>>().map_err(|e| format!("Failed to read sync_conflicts: {}", e))
+    })
+}
+
+pub fn clear_conflicts(app_data_dir: &Path) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute("DELETE FROM sync_conflicts", [])
+            .map_err(|e| format!("Failed to clear sync_conflicts: {}", e))
+    })?;
+    Ok(())
+}
+
+// ---------- 同步 ----------
+
+struct SyncGuard;
+
+impl SyncGuard {
+    fn acquire() -> Result<Self, String> {
+        SYNCING
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .map(|_| SyncGuard)
+            .map_err(|_| "正在同步，请稍候".to_string())
+    }
+}
+
+impl Drop for SyncGuard {
+    fn drop(&mut self) {
+        SYNCING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// 一次同步过程中的进度
+#[derive(Default)]
+struct Round {
+    pending: BTreeMap<RecordKey, Change>,         // 待推送的本地修改
+    applied: HashMap<RecordKey, Option<Value>>, // 已应用到本地的远端数据
+    summary: SyncSummary,
+}
+
+/// 拉取并应用其他设备从 last_seq 之后写入的日志，被远端覆盖的本地修改不再推送
+async fn pull(
+    app_data_dir: &Path,
+    backend: &Backend,
+    cipher: &Cipher,
+    status: &mut SyncStatus,
+    datasets: &[String],
+    round: &mut Round,
+) -> Result<(), String> {
+    let Round { pending, applied, summary } = round;
+    let mut words = Vec::new();
+    while let Some(bytes) = backend.get(&journal_key(status.last_seq + 1)).await? {
+        let plain = cipher.decrypt(&bytes)?;
+        let entry: JournalEntry = serde_json::from_slice(&plain).map_err(|e| format!("远端日志无效: {}", e))?;
+        if entry.device_id != status.device_id {
+            for remote in entry.changes.iter().filter(|c| datasets.contains(&c.dataset)) {
+                let key = (remote.dataset.clone(), remote.id.clone());
+                match resolve(pending.get(&key), remote, &status.device_id, &entry.device_id) {
+                    Resolution::Identical => {
+                        pending.remove(&key);
+                    }
+                    Resolution::ApplyRemote => {
+                        if let Some(local) = pending.remove(&key) {
+                            log_conflict(app_data_dir, &local, remote, "remote", &entry.device_id)?;
+                            summary.conflicts += 1;
+                        }
+                        apply_change(app_data_dir, remote, &mut words)?;
+                        applied.insert(key, remote.data.clone());
+                        summary.pulled += 1;
+                    }
+                    Resolution::KeepLocal => {
+                        log_conflict(app_data_dir, &pending[&key], remote, "local", &entry.device_id)?;
+                        summary.conflicts += 1;
+                    }
+                }
+            }
+        }
+        status.last_seq += 1;
+    }
+    flush_words(app_data_dir, &mut words)
+}
+
+async fn run_sync(app_data_dir: &Path, status: &mut SyncStatus) -> Result<SyncSummary, String> {
+    let stored = load_stored(app_data_dir)?;
+    let config = stored.config;
+    let passphrase = stored
+        .passphrase
+        .as_deref()
+        .map(secrets::decrypt)
+        .transpose()?
+        .ok_or_else(|| "请先设置同步口令".to_string())?;
+    let secret = stored.secret.as_deref().map(secrets::decrypt).transpose()?.unwrap_or_default();
+    let backend = Backend::new(BackendConfig {
+        kind: config.backend,
+        endpoint: config.endpoint.clone(),
+        username: config.username.clone(),
+        secret,
+        region: config.region.clone(),
+        bucket: config.bucket.clone(),
+    })?;
+    backend.ensure_dirs(&[REMOTE_ROOT, JOURNAL_DIR]).await?;
+    let cipher = open_cipher(&backend, &passphrase).await?;
+
+    let datasets = config.datasets;
+    let previous = load_hashes(app_data_dir)?;
+    let initial = snapshot(app_data_dir, &datasets)?;
+    let mut round = Round { pending: diff_local(&initial, &previous, &datasets, now_ms()), ..Default::default() };
+
+    for attempt in 0..MAX_PUSH_ATTEMPTS {
+        pull(app_data_dir, &backend, &cipher, status, &datasets, &mut round).await?;
+        if round.pending.is_empty() {
+            break;
+        }
+        let entry = JournalEntry {
+            device_id: status.device_id.clone(),
+            created_at: now_ms(),
+            changes: round.pending.values().cloned().collect(),
+        };
+        let plain = serde_json::to_vec(&entry).map_err(|e| format!("Failed to serialize journal entry: {}", e))?;
+        match backend.put(&journal_key(status.last_seq + 1), &cipher.encrypt(&plain)?, true).await? {
+            PutResult::Written => {
+                status.last_seq += 1;
+                round.summary.pushed += entry.changes.len();
+                round.pending.clear();
+                break;
+            }
+            // 其他设备刚写入了同一序号，重新拉取后再试
+            PutResult::AlreadyExists if attempt + 1 < MAX_PUSH_ATTEMPTS => {}
+            PutResult::AlreadyExists => return Err("远端日志写入冲突，请稍后重试".to_string()),
+        }
+    }
+
+    // 应用远端修改后重新读取本地数据作为下次对比的基准
+    let current = snapshot(app_data_dir, &datasets)?;
+    save_hashes(app_data_dir, &datasets, &previous, &initial, &round.applied, &current)?;
+    Ok(round.summary)
+}
+
+/// 立即同步一次，结果写入同步状态
+pub async fn sync_now(app_data_dir: &Path) -> Result<SyncSummary, String> {
+    let _guard = SyncGuard::acquire()?;
+    let mut status = load_status(app_data_dir)?;
+    status.last_attempt_at = Some(chrono::Utc::now().timestamp());
+    let result = run_sync(app_data_dir, &mut status).await;
+    match &result {
+        Ok(summary) => {
+            status.last_sync_at = status.last_attempt_at;
+            status.last_error = None;
+            status.last_summary = Some(summary.clone());
+        }
+        Err(e) => status.last_error = Some(e.clone()),
+    }
+    write_kv(app_data_dir, STATUS_KEY, &status)?;
+    result
+}
+
+/// 按配置的间隔自动同步；出错后同样等待一个间隔再重试
+pub fn start_scheduler(app_handle: AppHandle, app_data_dir: PathBuf) -> Result<(), String> {
+    shutdown::spawn("cloud-sync", move || {
+        while !shutdown::wait_timeout(SCHEDULER_TICK) {
+            let Ok(stored) = load_stored(&app_data_dir) else { continue };
+            let config = stored.config;
+            if !config.enabled || config.interval_minutes == 0 {
+                continue;
+            }
+            let Ok(status) = load_status(&app_data_dir) else { continue };
+            let interval = config.interval_minutes as i64 * 60;
+            if status.last_attempt_at.is_some_and(|t| chrono::Utc::now().timestamp() - t < interval) {
+                continue;
+            }
+            match tauri::async_runtime::block_on(sync_now(&app_data_dir)) {
+                Ok(summary) => {
+                    let _ = app_handle.emit(COMPLETED_EVENT, summary);
+                }
+                Err(e) => eprintln!("[CloudSync] Sync failed: {}", e),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn change(id: &str, updated_at: i64, data: Option<Value>) -> Change {
+        Change { dataset: "snippets".to_string(), id: id.to_string(), updated_at, data }
+    }
+
+    #[test]
+    fn encrypts_and_detects_wrong_passphrase() {
+        let cipher = Cipher::derive("correct horse", b"salt", 1000);
+        let sealed = cipher.encrypt(b"hello").unwrap();
+        assert_eq!(cipher.decrypt(&sealed).unwrap(), b"hello");
+        assert_ne!(cipher.encrypt(b"hello").unwrap(), sealed);
+        assert!(Cipher::derive("wrong", b"salt", 1000).decrypt(&sealed).is_err());
+    }
+
+    #[test]
+    fn diffs_local_changes_and_resolves_last_writer_wins() {
+        let key = |id: &str| ("snippets".to_string(), id.to_string());
+        let a = json!({"id": "a", "content": "new"});
+        let current = BTreeMap::from([(key("a"), (a.clone(), Some(5000))), (key("b"), (json!({"id": "b"}), None))]);
+        let hashes = HashMap::from([
+            (key("a"), record_hash(&json!({"id": "a", "content": "old"}))),
+            (key("c"), record_hash(&json!({"id": "c"}))),
+        ]);
+        let changes = diff_local(&current, &hashes, &["snippets".to_string()], 9000);
+        assert_eq!(changes[&key("a")], change("a", 5000, Some(a.clone())));
+        assert_eq!(changes[&key("b")].updated_at, 0); // 从未同步过的记录让远端优先
+        assert_eq!(changes[&key("c")], change("c", 9000, None));
+
+        let local = change("a", 5000, Some(a.clone()));
+        assert_eq!(resolve(None, &change("a", 1, None), "d1", "d2"), Resolution::ApplyRemote);
+        assert_eq!(resolve(Some(&local), &change("a", 1, Some(a)), "d1", "d2"), Resolution::Identical);
+        assert_eq!(resolve(Some(&local), &change("a", 6000, None), "d1", "d2"), Resolution::ApplyRemote);
+        assert_eq!(resolve(Some(&local), &change("a", 4000, None), "d1", "d2"), Resolution::KeepLocal);
+        // 时间相同时设备 id 大的一方胜出，两端结论一致
+        assert_eq!(resolve(Some(&local), &change("a", 5000, None), "d1", "d2"), Resolution::ApplyRemote);
+        assert_eq!(resolve(Some(&local), &change("a", 5000, None), "d2", "d1"), Resolution::KeepLocal);
+    }
+
+    #[test]
+    fn keeps_local_only_settings() {
+        let mut local = json!({"theme": "dark", "llm": {"model": "m", "api_key": "secret"}, "proxy": {"mode": "system"}});
+        let remote = json!({"theme": "light", "llm": {"model": "r"}});
+        let merged = merge_local_only(remote, &local);
+        assert_eq!(
+            merged,
+            json!({"theme": "light", "llm": {"model": "r", "api_key": "secret"}, "proxy": {"mode": "system"}})
+        );
+        strip_local_only(&mut local);
+        assert_eq!(local, json!({"theme": "dark", "llm": {"model": "m"}}));
+    }
+}
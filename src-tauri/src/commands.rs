@@ -1,18 +1,131 @@
 pub mod recording;
 pub use recording::*;
+pub mod activity_log;
+pub mod archive_peek;
+pub mod audio_devices;
+pub mod browser_bridge;
+pub mod browser_history;
+pub mod calendar;
+pub mod chat_history;
+pub mod cloud_sync;
 pub mod color_picker;
+pub mod crash_reports;
+pub mod display_control;
+pub mod favicon;
+pub mod focus_mode;
+pub mod folder_sizes;
+pub mod games;
+pub mod git_repos;
+pub mod git_sync;
+pub mod habits;
+pub mod hot_folders;
+pub mod importer;
+pub mod keep_awake;
+pub mod lan_share;
+pub mod llm;
 pub mod memos;
+pub mod net_tools;
+pub mod network;
+pub mod open_with;
+pub mod password_gen;
+pub mod paste_share;
+pub mod prompt_templates;
+pub mod proxy;
+pub mod query_history;
+pub mod quotes;
+pub mod radios;
+pub mod recent_projects;
+pub mod reminders;
+pub mod result_preview;
+pub mod rss;
+pub mod screen_ruler;
+pub mod scripts;
+pub mod ssh_hosts;
+pub mod system_monitor;
+pub mod text_transform;
+pub mod text_variables;
+pub mod themes;
+pub mod timers;
+pub mod totp;
+pub mod virtual_desktops;
+pub mod web_suggestions;
+pub mod window_manager;
 pub mod word_records;
+pub mod workflows;
+pub mod world_clock;
 
 // 重新导出子模块中的所有命令
+pub use activity_log::{record_activity, get_activity_stats, purge_activity_log, get_ranking_boosts};
+pub use archive_peek::{list_archive_entries, open_archive_entry};
+pub use chat_history::{
+    list_chat_conversations, get_chat_messages, search_chat_messages, set_chat_conversation_pinned,
+    delete_chat_conversation, export_chat_conversation,
+};
 pub use color_picker::{show_color_picker_window, pick_color_from_screen};
-pub use memos::{get_all_memos, add_memo, update_memo, delete_memo, search_memos};
+pub use crash_reports::{list_crash_reports, create_bug_report};
+pub use favicon::{get_favicon, clear_favicon_cache};
+pub use focus_mode::{get_focus_mode_status, set_focus_mode};
+pub use folder_sizes::{scan_folder_sizes, cancel_folder_scan};
+pub use games::{search_games, refresh_games, launch_game};
+pub use git_repos::{get_git_repos, search_git_repos, refresh_git_repos};
+pub use hot_folders::get_hot_folder_recent_files;
+pub use importer::{preview_launcher_import, apply_launcher_import};
+pub use memos::{get_all_memos, add_memo, update_memo, set_memo_hotstring, delete_memo, search_memos, semantic_search_memos};
+pub use net_tools::{get_network_info, get_public_ip, ping_host, check_port};
+pub use network::{get_network_status, check_network_status};
+pub use llm::{llm_chat, clipboard_ai_action, ask_notes, clear_llm_cache, get_llm_usage_summary};
+pub use proxy::get_system_proxy;
+pub use prompt_templates::{
+    list_prompt_templates, save_prompt_template, delete_prompt_template, set_active_prompt_template,
+};
+pub use open_with::{get_open_with_actions, save_open_with_actions, open_folder_with};
+pub use query_history::{
+    add_query_history,
+    get_query_history,
+    navigate_query_history,
+    suggest_query_completions,
+    delete_query_history,
+    clear_query_history,
+};
+pub use recent_projects::{get_recent_projects, search_recent_projects, open_recent_project};
+pub use result_preview::get_result_preview;
+pub use ssh_hosts::{get_ssh_hosts, search_ssh_hosts, connect_ssh_host};
+pub use system_monitor::get_system_stats;
+pub use audio_devices::{list_audio_devices, switch_audio_device, get_audio_volume, set_audio_volume, set_audio_mute};
+pub use display_control::{list_monitors, set_monitor_brightness, list_display_modes, set_display_mode, apply_display_preset, get_night_light, set_night_light};
+pub use radios::{get_radio_status, set_radio_state, set_airplane_mode};
+pub use window_manager::{run_window_action, get_window_hotkeys, save_window_hotkey};
+pub use virtual_desktops::{list_virtual_desktops, switch_virtual_desktop, move_window_to_virtual_desktop};
+pub use keep_awake::{get_keep_awake_status, start_keep_awake, stop_keep_awake};
+pub use text_transform::transform_text;
+pub use password_gen::{generate_password, copy_password};
+pub use totp::{list_totp_accounts, add_totp_account, remove_totp_account, get_totp_codes, copy_totp_code};
+pub use world_clock::{get_world_clocks, convert_world_time};
+pub use quotes::get_quotes;
+pub use rss::{list_rss_feeds, add_rss_feed, remove_rss_feed, get_rss_items, mark_rss_items_read, refresh_rss_feeds};
+pub use timers::{list_timers, create_timer, cancel_timer};
+pub use reminders::{preview_reminder, create_reminder};
+pub use calendar::{list_calendars, add_calendar, remove_calendar, get_today_events, get_upcoming_events, refresh_calendars};
+pub use habits::{list_habits, add_habit, remove_habit, check_in_habit, undo_habit_check_in};
+pub use screen_ruler::{get_cursor_info, measure_screen_distance, start_cursor_inspector, stop_cursor_inspector};
+pub use text_variables::{render_text_variables, list_text_variables};
+pub use cloud_sync::{get_sync_config, save_sync_config, sync_now, get_sync_status, list_sync_conflicts, clear_sync_conflicts};
+pub use git_sync::{get_git_sync_status, save_git_sync_config, git_sync_commit, git_sync_pull};
+pub use browser_bridge::{get_browser_bridge_status, register_browser_bridge, unregister_browser_bridge};
+pub use browser_history::search_browser_history;
+pub use lan_share::{create_phone_share, list_phone_shares, revoke_phone_share};
+pub use paste_share::{clear_paste_links, create_paste_link, get_paste_config, list_paste_links, revoke_paste_link, save_paste_config};
+pub use scripts::{create_script, get_script_http_allow, list_scripts, open_scripts_folder, run_script, set_script_http_allow};
+pub use workflows::{delete_workflow, export_workflows, import_workflows, list_workflows, run_workflow, save_workflow};
+pub use themes::{list_themes, get_theme, set_theme, save_custom_theme, delete_custom_theme};
+pub use web_suggestions::get_web_suggestions;
 pub use word_records::{
     get_all_word_records,
     add_word_record,
     update_word_record,
     delete_word_record,
     search_word_records,
+    import_word_records,
 };
 
 use crate::app_search;
@@ -21,6 +134,7 @@ use crate::everything_search;
 use crate::everything_filters;
 use crate::file_history;
 use crate::open_history;
+use crate::path_expand;
 use crate::plugin_usage;
 
 use crate::settings;
@@ -256,6 +370,95 @@ pub async fn test_uwp_apps_scan() -> Result<Vec<app_search::AppInfo>, String> {
     .map_err(|e| format!("test_uwp_apps_scan join error: {}", e))?
 }
 
+/// 用新的扫描结果整体替换应用索引并写入磁盘缓存，返回（新增数, 移除数）
+/// 替换在锁内一次完成，搜索只会看到替换前或替换后的完整列表
+fn swap_app_index(app_data_dir: &Path, apps_vec: Vec<app_search::AppInfo>) -> Result<(usize, usize), String> {
+    let new_index = Arc::new(apps_vec);
+    let previous = {
+        let cache = get_app_cache();
+        let mut cache_guard = lock_app_cache_safe(&cache);
+        cache_guard.replace(new_index.clone())
+    };
+    // 内存中还没有索引时与磁盘缓存比较
+    let previous = match previous {
+        Some(previous) => previous,
+        None => Arc::new(app_search::windows::load_cache(app_data_dir).unwrap_or_default()),
+    };
+    let diff = app_search::diff_apps(&previous, &new_index);
+    // 预先建立搜索索引，替换后的第一次搜索无需等待
+    crate::app_index::index_for(&new_index);
+    app_search::windows::save_cache(app_data_dir, &new_index)?;
+    Ok(diff)
+}
+
+/// 空闲时静默刷新应用缓存（不发送进度事件），扫描期间继续使用旧缓存
+#[cfg(target_os = "windows")]
+pub fn refresh_app_cache(app_data_dir: &Path) -> Result<(), String> {
+    let apps_vec = app_search::windows::scan_start_menu(None)?;
+    let (added, removed) = swap_app_index(app_data_dir, apps_vec)?;
+    if added > 0 || removed > 0 {
+        crate::log!("AppScan", "应用缓存已刷新：新增 {} 个，移除 {} 个", added, removed);
+    }
+    Ok(())
+}
+
+static APP_INDEX_REFRESHING: AtomicBool = AtomicBool::new(false);
+
+/// 后台重新扫描应用并与当前索引比较，完成后整体替换索引
+/// 扫描期间搜索继续使用旧索引；进度通过 app-index-progress 事件发送，
+/// 完成后发送 app-index-refreshed（新增、移除数量），失败发送 app-index-error
+#[tauri::command]
+pub async fn refresh_app_index(app: tauri::AppHandle) -> Result<(), String> {
+    if APP_INDEX_REFRESHING.swap(true, Ordering::SeqCst) {
+        return Err("应用索引正在刷新".to_string());
+    }
+    let app_data_dir = match get_app_data_dir(&app) {
+        Ok(dir) => dir,
+        Err(e) => {
+            APP_INDEX_REFRESHING.store(false, Ordering::SeqCst);
+            return Err(e);
+        }
+    };
+
+    async_runtime::spawn(async move {
+        let (tx, rx) = std::sync::mpsc::channel::<(u8, String)>();
+        // 扫描结束时发送端被释放，转发任务随之结束
+        let progress_app = app.clone();
+        async_runtime::spawn_blocking(move || {
+            for (progress, message) in rx {
+                let _ = progress_app.emit(
+                    "app-index-progress",
+                    serde_json::json!({ "progress": progress, "message": message }),
+                );
+            }
+        });
+
+        let result = async_runtime::spawn_blocking(move || {
+            let apps_vec = app_search::windows::scan_start_menu(Some(tx))?;
+            let total = apps_vec.len();
+            let (added, removed) = swap_app_index(&app_data_dir, apps_vec)?;
+            Ok::<_, String>((added, removed, total))
+        })
+        .await
+        .unwrap_or_else(|e| Err(format!("扫描任务失败: {}", e)));
+        APP_INDEX_REFRESHING.store(false, Ordering::SeqCst);
+
+        match result {
+            Ok((added, removed, total)) => {
+                let _ = app.emit(
+                    "app-index-refreshed",
+                    serde_json::json!({ "added": added, "removed": removed, "total": total }),
+                );
+            }
+            Err(error) => {
+                let _ = app.emit("app-index-error", serde_json::json!({ "error": error }));
+            }
+        }
+    });
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn rescan_applications(app: tauri::AppHandle) -> Result<(), String> {
     // 获取所有可能的窗口，应用中心可能在启动器窗口或独立窗口中
@@ -380,6 +583,9 @@ pub async fn search_applications(
     app: tauri::AppHandle,
 ) -> Result<Vec<app_search::AppInfo>, String> {
     eprintln!("[搜索应用] 函数被调用: query={}", query);
+    if !crate::search_providers::is_enabled("apps") {
+        return Ok(Vec::new());
+    }
     let cache = get_app_cache();
     let app_handle_clone = app.clone();
     let query_clone = query.clone();
@@ -434,7 +640,7 @@ pub async fn search_applications(
         
         // 步骤2: 先执行搜索（避免预先检查计算器，节省时间）
         let search_start = std::time::Instant::now();
-        let mut results = app_search::windows::search_apps(&query_clone, apps.as_slice());
+        let mut results = crate::app_index::search(&query_clone, &apps);
         let search_time = search_start.elapsed();
         
         // #region agent log
@@ -1164,44 +1370,7 @@ pub async fn extract_icon_from_path(file_path: String, app: tauri::AppHandle) ->
             };
             
             // 计算拼音（如果需要）
-            let contains_chinese = |text: &str| -> bool {
-                text.chars().any(|c| {
-                    matches!(c as u32,
-                        0x4E00..=0x9FFF |  // CJK Unified Ideographs
-                        0x3400..=0x4DBF |  // CJK Extension A
-                        0x20000..=0x2A6DF | // CJK Extension B
-                        0x2A700..=0x2B73F | // CJK Extension C
-                        0x2B740..=0x2B81F | // CJK Extension D
-                        0xF900..=0xFAFF |  // CJK Compatibility Ideographs
-                        0x2F800..=0x2FA1F   // CJK Compatibility Ideographs Supplement
-                    )
-                })
-            };
-            
-            let to_pinyin = |text: &str| -> String {
-                use pinyin::ToPinyin;
-                text.to_pinyin()
-                    .filter_map(|p| p.map(|p| p.plain()))
-                    .collect::<Vec<_>>()
-                    .join("")
-            };
-            
-            let to_pinyin_initials = |text: &str| -> String {
-                use pinyin::ToPinyin;
-                text.to_pinyin()
-                    .filter_map(|p| p.map(|p| p.plain().chars().next()))
-                    .flatten()
-                    .collect::<String>()
-            };
-            
-            let (name_pinyin, name_pinyin_initials) = if contains_chinese(&name) {
-                (
-                    Some(to_pinyin(&name).to_lowercase()),
-                    Some(to_pinyin_initials(&name).to_lowercase()),
-                )
-            } else {
-                (None, None)
-            };
+            let (name_pinyin, name_pinyin_initials) = crate::pinyin_utils::precompute(&name);
             
             // 创建新的 AppInfo（成功时添加图标，失败时标记为失败）
             let icon_value = if let Some(icon_data) = &icon_result_clone {
@@ -1424,6 +1593,9 @@ pub async fn search_file_history(
         let _ = writeln!(file, r#"{{"location":"commands.rs:1155","message":"search_file_history API入口","data":{{"query":"{}"}},"timestamp":{},"sessionId":"debug-session","runId":"run1","hypothesisId":"D"}}"#, query, std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis());
     }
     // #endregion
+    if !crate::search_providers::is_enabled("file_history") {
+        return Ok(Vec::new());
+    }
     
     // 性能优化：在后台线程执行，避免阻塞 Everything 搜索
     let app_data_dir = get_app_data_dir(&app)?;
@@ -2280,21 +2452,27 @@ pub fn get_everything_log_file_path() -> Result<Option<String>, String> {
     }
 }
 
+/// 清理过期历史（异步，大量记录删除时避免阻塞）
 #[tauri::command]
-pub fn purge_file_history(days: Option<u64>, app: tauri::AppHandle) -> Result<usize, String> {
+pub async fn purge_file_history(days: Option<u64>, app: tauri::AppHandle) -> Result<usize, String> {
     let app_data_dir = get_app_data_dir(&app)?;
     let days = days.unwrap_or(30).max(1);
-    open_history::purge_history_older_than(days, &app_data_dir)
+    async_runtime::spawn_blocking(move || open_history::purge_history_older_than(days, &app_data_dir))
+        .await
+        .map_err(|e| format!("purge_file_history join error: {}", e))?
 }
 
+/// 按时间范围删除历史（异步，大量记录删除时避免阻塞）
 #[tauri::command]
-pub fn delete_file_history_by_range(
+pub async fn delete_file_history_by_range(
     start_ts: Option<u64>,
     end_ts: Option<u64>,
     app: tauri::AppHandle,
 ) -> Result<usize, String> {
     let app_data_dir = get_app_data_dir(&app)?;
-    open_history::delete_by_range(start_ts, end_ts, &app_data_dir)
+    async_runtime::spawn_blocking(move || open_history::delete_by_range(start_ts, end_ts, &app_data_dir))
+        .await
+        .map_err(|e| format!("delete_file_history_by_range join error: {}", e))?
 }
 
 #[derive(Serialize)]
@@ -2319,7 +2497,7 @@ pub struct FilePreview {
     pub error: Option<String>,
 }
 
-fn guess_mime_from_extension(ext: Option<&str>) -> Option<&'static str> {
+pub(crate) fn guess_mime_from_extension(ext: Option<&str>) -> Option<&'static str> {
     match ext {
         Some("png") => Some("image/png"),
         Some("jpg") | Some("jpeg") => Some("image/jpeg"),
@@ -2407,9 +2585,16 @@ fn is_probably_binary(buffer: &[u8]) -> bool {
     buffer.iter().take(2048).any(|&b| b == 0)
 }
 
+/// 获取文件预览信息（异步，读取媒体元数据可能较慢）
 #[tauri::command]
-pub fn get_file_preview(path: String) -> Result<FilePreview, String> {
-    let path_ref = Path::new(&path);
+pub async fn get_file_preview(path: String) -> Result<FilePreview, String> {
+    async_runtime::spawn_blocking(move || build_file_preview(&path))
+        .await
+        .map_err(|e| format!("get_file_preview join error: {}", e))?
+}
+
+fn build_file_preview(path: &str) -> Result<FilePreview, String> {
+    let path_ref = Path::new(path);
     let metadata =
         fs::metadata(path_ref).map_err(|e| format!("无法读取文件信息: {}", e.to_string()))?;
 
@@ -2608,28 +2793,61 @@ fn ensure_backup_path(path: &str, app_data_dir: &Path) -> Result<std::path::Path
     Ok(target)
 }
 
+// 自动备份文件名前缀，只清理这类备份，手动备份不受影响
+const AUTO_BACKUP_PREFIX: &str = "re-fast-autobackup_";
+const AUTO_BACKUP_KEEP: usize = 5;
+
+/// 复制数据库到 app_data_dir/backups/<prefix>yyyyMMdd_HHmmss.db
+fn copy_database_backup(app_data_dir: &Path, prefix: &str) -> Result<std::path::PathBuf, String> {
+    let db_path = db::get_db_path(app_data_dir);
+    if !db_path.exists() {
+        return Err("Database file not found".to_string());
+    }
+
+    let backup_dir = app_data_dir.join("backups");
+    fs::create_dir_all(&backup_dir)
+        .map_err(|e| format!("Failed to create backup directory: {}", e))?;
+
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let backup_name = format!("{}{}.db", prefix, timestamp);
+    let backup_path = backup_dir.join(backup_name);
+
+    fs::copy(&db_path, &backup_path)
+        .map_err(|e| format!("Failed to copy database: {}", e))?;
+    Ok(backup_path)
+}
+
+/// 空闲时自动备份数据库，只保留最近 AUTO_BACKUP_KEEP 份自动备份
+pub fn create_auto_backup(app_data_dir: &Path) -> Result<(), String> {
+    copy_database_backup(app_data_dir, AUTO_BACKUP_PREFIX)?;
+
+    let backup_dir = app_data_dir.join("backups");
+    let mut auto_backups: Vec<std::path::PathBuf> = fs::read_dir(&backup_dir)
+        .map_err(|e| format!("Failed to read backup directory: {}", e))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().starts_with(AUTO_BACKUP_PREFIX))
+                .unwrap_or(false)
+        })
+        .collect();
+    // 文件名包含时间戳，按名称降序即为时间降序
+    auto_backups.sort_by(|a, b| b.cmp(a));
+    for path in auto_backups.iter().skip(AUTO_BACKUP_KEEP) {
+        let _ = fs::remove_file(path);
+    }
+    Ok(())
+}
+
 /// 备份数据库到 app_data_dir/backups/re-fast-backup_yyyyMMdd_HHmmss.db
 /// 异步执行，避免大文件复制时阻塞主线程
 #[tauri::command]
 pub async fn backup_database(app: tauri::AppHandle) -> Result<String, String> {
     async_runtime::spawn_blocking(move || {
         let app_data_dir = get_app_data_dir(&app)?;
-        let db_path = db::get_db_path(&app_data_dir);
-        if !db_path.exists() {
-            return Err("Database file not found".to_string());
-        }
-
-        let backup_dir = app_data_dir.join("backups");
-        fs::create_dir_all(&backup_dir)
-            .map_err(|e| format!("Failed to create backup directory: {}", e))?;
-
-        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
-        let backup_name = format!("re-fast-backup_{}.db", timestamp);
-        let backup_path = backup_dir.join(backup_name);
-
         // 文件复制操作可能很慢（特别是大数据库），使用 spawn_blocking 避免阻塞
-        fs::copy(&db_path, &backup_path)
-            .map_err(|e| format!("Failed to copy database: {}", e))?;
+        let backup_path = copy_database_backup(&app_data_dir, "re-fast-backup_")?;
 
         Ok(backup_path
             .to_string_lossy()
@@ -2673,9 +2891,13 @@ pub async fn restore_backup(app: tauri::AppHandle, path: String) -> Result<Strin
                 .map_err(|e| format!("Failed to create database directory: {}", e))?;
         }
 
+        // 先关闭共享连接，避免覆盖数据库文件时仍有连接持有旧文件
+        db::close_shared_connection();
+
         // 文件复制操作可能很慢，使用 spawn_blocking 避免阻塞
         fs::copy(&target, &db_path)
             .map_err(|e| format!("Failed to restore database: {}", e))?;
+        crate::clipboard::invalidate_cache();
 
         Ok(db_path
             .to_string_lossy()
@@ -2745,6 +2967,12 @@ pub async fn list_backups(app: tauri::AppHandle) -> Result<DatabaseBackupList, S
     .map_err(|e| format!("list_backups join error: {}", e))?
 }
 
+/// 获取启动预热状态（各数据提供者是否已就绪及耗时）
+#[tauri::command]
+pub fn get_startup_status() -> crate::warmup::StartupStatus {
+    crate::warmup::get_status()
+}
+
 /// 聚合索引状态，便于前端一次性获取
 #[tauri::command]
 pub async fn get_index_status(app: tauri::AppHandle) -> Result<IndexStatus, String> {
@@ -2905,7 +3133,7 @@ pub async fn check_database_health(app: tauri::AppHandle) -> Result<DatabaseHeal
             Err(e) => {
                 return Ok(DatabaseHealthStatus {
                     accessible: false,
-                    error_message: Some(format!("无法获取应用数据目录: {}", e)),
+                    error_message: Some(crate::i18n::tf("db.app_data_dir_error", &[&e])),
                     db_path: None,
                     db_size_bytes: None,
                     file_history_count: None,
@@ -2968,7 +3196,7 @@ pub async fn check_database_health(app: tauri::AppHandle) -> Result<DatabaseHeal
             Ok(Err(e)) => {
                 Ok(DatabaseHealthStatus {
                     accessible: false,
-                    error_message: Some(format!("数据库访问错误: {}", e)),
+                    error_message: Some(crate::i18n::tf("db.access_error", &[&e])),
                     db_path: Some(db_path_str),
                     db_size_bytes: db_size,
                     file_history_count: None,
@@ -3119,8 +3347,10 @@ pub async fn download_everything(app: tauri::AppHandle) -> Result<String, String
         // 可以后续统一进度事件格式
         use std::io::Write;
         use std::time::Instant;
+
+        crate::network::ensure_online()?;
         
-        let client = reqwest::Client::builder()
+        let client = crate::http_client::builder()?
             .timeout(Duration::from_secs(300))
             .build()
             .map_err(|e| format!("创建HTTP客户端失败: {}", e))?;
@@ -3182,8 +3412,9 @@ pub fn check_path_exists(path: String, app: tauri::AppHandle) -> Result<Option<f
     let app_data_dir = get_app_data_dir(&app)?;
 
     // Normalize path: trim whitespace and remove trailing backslashes/slashes
-    let trimmed = path.trim();
-    let trimmed = trimmed.trim_end_matches(|c| c == '\\' || c == '/');
+    // 先展开 %VAR%、$VAR 和 ~
+    let expanded = path_expand::expand_path(&path);
+    let trimmed = expanded.trim_end_matches(|c| c == '\\' || c == '/');
 
     // Normalize path (convert to absolute if relative)
     let path_buf = PathBuf::from(trimmed);
@@ -3678,12 +3909,19 @@ pub fn get_downloads_folder() -> Result<String, String> {
     }
 }
 
+/// 复制文件到下载目录（异步，大文件复制时避免阻塞）
 #[tauri::command]
-pub fn copy_file_to_downloads(source_path: String) -> Result<String, String> {
+pub async fn copy_file_to_downloads(source_path: String) -> Result<String, String> {
+    async_runtime::spawn_blocking(move || copy_to_downloads(&source_path))
+        .await
+        .map_err(|e| format!("copy_file_to_downloads join error: {}", e))?
+}
+
+fn copy_to_downloads(source_path: &str) -> Result<String, String> {
     use std::fs;
     use std::path::Path;
     
-    let source = Path::new(&source_path);
+    let source = Path::new(source_path);
     if !source.exists() {
         return Err("Source file does not exist".to_string());
     }
@@ -3733,11 +3971,23 @@ pub fn launch_file(path: String, app: tauri::AppHandle) -> Result<(), String> {
     file_history::launch_file(&path)
 }
 
+/// 为部分输入的路径提供补全候选（支持环境变量与 ~）
+#[tauri::command]
+pub fn complete_path(partial: String) -> Result<Vec<path_expand::PathCompletion>, String> {
+    Ok(path_expand::complete_path(&partial))
+}
+
+/// 展开路径中的环境变量与 ~，供前端预览实际路径
+#[tauri::command]
+pub fn expand_path(path: String) -> Result<String, String> {
+    Ok(path_expand::expand_path(&path))
+}
+
 #[tauri::command]
 pub fn get_all_shortcuts(app: tauri::AppHandle) -> Result<Vec<shortcuts::ShortcutItem>, String> {
     let app_data_dir = get_app_data_dir(&app)?;
     shortcuts::load_shortcuts(&app_data_dir)?;
-    Ok(shortcuts::get_all_shortcuts())
+    shortcuts::get_all_shortcuts(&app_data_dir)
 }
 
 #[tauri::command]
@@ -5077,13 +5327,47 @@ pub fn remove_markdown_recent_file(app: tauri::AppHandle, file_path: String) ->
 #[tauri::command]
 pub fn get_settings(app: tauri::AppHandle) -> Result<settings::Settings, String> {
     let app_data_dir = get_app_data_dir(&app)?;
-    settings::load_settings(&app_data_dir)
+    let mut settings = settings::load_settings(&app_data_dir)?;
+    settings.proxy = settings.proxy.redacted();
+    Ok(settings)
 }
 
+/// 获取所有搜索数据源的生效配置（开关、优先级、结果数量上限）
 #[tauri::command]
-pub fn save_settings(app: tauri::AppHandle, settings: settings::Settings) -> Result<(), String> {
+pub fn get_search_providers() -> std::collections::HashMap<String, crate::search_providers::ProviderSettings> {
+    crate::search_providers::effective()
+}
+
+#[tauri::command]
+pub fn save_settings(app: tauri::AppHandle, mut settings: settings::Settings) -> Result<(), String> {
     let app_data_dir = get_app_data_dir(&app)?;
-    settings::save_settings(&app_data_dir, &settings)
+    crate::scoring::validate_weights(&settings.scoring_weights)?;
+    crate::search_providers::validate(&settings.providers)?;
+    let previous = settings::load_settings(&app_data_dir).unwrap_or_default();
+    settings.proxy.seal(&previous.proxy)?;
+    settings::save_settings(&app_data_dir, &settings)?;
+    crate::http_client::configure(&settings.proxy);
+    crate::scoring::set_weights(&settings.scoring_weights);
+    // 重新计算去重键前先同步更新剪切板设置，不等 settings-changed 监听
+    crate::clipboard::apply_settings(&settings);
+    // 去重选项变化时重新计算剪切板去重键
+    if previous.clipboard_dedup_collapse_whitespace != settings.clipboard_dedup_collapse_whitespace {
+        crate::clipboard::refresh_dedup_keys(&app_data_dir, false)?;
+    }
+    // 保留天数变化时立即清理，不等下一次定期任务
+    if previous.clipboard_max_age_days != settings.clipboard_max_age_days {
+        crate::clipboard::delete_expired_items(&app_data_dir)?;
+    }
+    // 开机启动设置变化时同步注册表
+    #[cfg(target_os = "windows")]
+    if previous.startup_enabled != settings.startup_enabled {
+        sync_startup_setting(settings.startup_enabled)?;
+    }
+    crate::focus_mode::set_auto_detect(&app, settings.dnd_auto_detect);
+    if crate::i18n::set_locale(&settings.locale) {
+        crate::tray::refresh_menu(&app)?;
+    }
+    Ok(())
 }
 
 // ===== Everything Filters commands =====
@@ -5230,12 +5514,15 @@ pub fn save_plugin_hotkeys(
 pub fn save_plugin_hotkey(
     app: tauri::AppHandle,
     plugin_id: String,
-    config: Option<settings::HotkeyConfig>,
+    mut config: Option<settings::HotkeyConfig>,
 ) -> Result<(), String> {
     let app_data_dir = get_app_data_dir(&app)?;
     let mut settings = settings::load_settings(&app_data_dir)?;
+    if let Some(hotkey) = config.as_mut() {
+        hotkey.apps = crate::app_scope::normalize(&hotkey.apps)?;
+    }
     
-    // 如果设置了快捷键，检查冲突
+    // 如果设置了快捷键，检查冲突（限定在不同程序中的快捷键互不冲突）
     if let Some(ref new_hotkey) = config {
         // 检查与其他插件快捷键的冲突
         for (other_plugin_id, other_hotkey) in settings.plugin_hotkeys.iter() {
@@ -5246,7 +5533,10 @@ pub fn save_plugin_hotkey(
                 let mut other_mods = other_hotkey.modifiers.clone();
                 other_mods.sort();
                 
-                if new_mods == other_mods && new_hotkey.key == other_hotkey.key {
+                if new_mods == other_mods
+                    && new_hotkey.key == other_hotkey.key
+                    && crate::app_scope::overlaps(&new_hotkey.apps, &other_hotkey.apps)
+                {
                     return Err(format!(
                         "快捷键冲突：与插件 \"{}\" 的快捷键相同，请修改后再保存",
                         other_plugin_id
@@ -5286,7 +5576,10 @@ pub fn save_plugin_hotkey(
             let mut app_mods = app_hotkey.modifiers.clone();
             app_mods.sort();
             
-            if new_mods == app_mods && new_hotkey.key == app_hotkey.key {
+            if new_mods == app_mods
+                && new_hotkey.key == app_hotkey.key
+                && crate::app_scope::overlaps(&new_hotkey.apps, &app_hotkey.apps)
+            {
                 let app_name = app_path.split('/').last()
                     .or_else(|| app_path.split('\\').last())
                     .unwrap_or(app_path);
@@ -5344,10 +5637,13 @@ pub fn get_app_hotkeys(app: tauri::AppHandle) -> Result<std::collections::HashMa
 pub fn save_app_hotkey(
     app: tauri::AppHandle,
     app_path: String,
-    config: Option<settings::HotkeyConfig>,
+    mut config: Option<settings::HotkeyConfig>,
 ) -> Result<(), String> {
     let app_data_dir = get_app_data_dir(&app)?;
     let mut settings = settings::load_settings(&app_data_dir)?;
+    if let Some(hotkey) = config.as_mut() {
+        hotkey.apps = crate::app_scope::normalize(&hotkey.apps)?;
+    }
     
     // 先克隆 config 用于后端注册
     let config_clone = config.clone();
@@ -5516,44 +5812,63 @@ mod startup {
             .map(|s| s.to_string())
     }
 
-    /// 检查是否已设置开机启动
-    pub fn is_startup_enabled() -> Result<bool, String> {
-        let hkey = match open_registry_key(HKEY_CURRENT_USER, REGISTRY_PATH, KEY_QUERY_VALUE) {
-            Ok(key) => key,
-            Err(_) => return Ok(false), // 如果注册表键不存在，说明未启用
-        };
+    /// 写入 Run 键的启动命令（路径加引号，避免路径含空格时无法启动）
+    fn startup_command() -> Result<String, String> {
+        Ok(format!("\"{}\"", get_exe_path()?))
+    }
 
+    /// 读取 Run 键中已注册的启动命令，未注册时返回 None
+    fn registered_command() -> Option<String> {
+        // 如果注册表键不存在，说明未启用
+        let hkey = open_registry_key(HKEY_CURRENT_USER, REGISTRY_PATH, KEY_QUERY_VALUE).ok()?;
         let value_name_wide = to_wide_string(APP_NAME);
 
         unsafe {
-            // 尝试读取注册表值来检查是否存在
             let mut value_type: u32 = 0;
-            let mut value_data: Vec<u8> = vec![0; 520]; // 足够大的缓冲区
-            let mut value_size: u32 = value_data.len() as u32;
+            let mut value_data: Vec<u16> = vec![0; 1024]; // 足够大的缓冲区（MAX_PATH 加引号和参数）
+            let mut value_size: u32 = (value_data.len() * std::mem::size_of::<u16>()) as u32;
 
             let result = RegQueryValueExW(
                 hkey,
                 value_name_wide.as_ptr(),
                 std::ptr::null_mut(),
                 &mut value_type,
-                value_data.as_mut_ptr(),
+                value_data.as_mut_ptr() as *mut u8,
                 &mut value_size,
             );
 
             RegCloseKey(hkey);
 
-            Ok(result == 0 && value_type == REG_SZ)
+            if result != 0 || value_type != REG_SZ {
+                return None;
+            }
+            let len = value_size as usize / std::mem::size_of::<u16>();
+            let command = String::from_utf16_lossy(&value_data[..len.min(value_data.len())]);
+            Some(command.trim_end_matches('\0').to_string())
+        }
+    }
+
+    /// 检查是否已设置开机启动
+    pub fn is_startup_enabled() -> Result<bool, String> {
+        Ok(registered_command().is_some())
+    }
+
+    /// 已注册的启动命令是否指向当前可执行文件（程序移动或更新到新目录后需要重新注册）
+    pub fn is_registration_current() -> bool {
+        match (registered_command(), startup_command()) {
+            (Some(registered), Ok(expected)) => registered.eq_ignore_ascii_case(&expected),
+            _ => false,
         }
     }
 
     /// 设置开机启动
     pub fn enable_startup() -> Result<(), String> {
-        let exe_path = get_exe_path()?;
+        let command = startup_command()?;
         // Run 键应该总是存在的，使用 KEY_ALL_ACCESS 以确保可以写入
         let hkey = open_registry_key(HKEY_CURRENT_USER, REGISTRY_PATH, KEY_ALL_ACCESS)?;
 
         let value_name_wide = to_wide_string(APP_NAME);
-        let value_data_wide = to_wide_string(&exe_path);
+        let value_data_wide = to_wide_string(&command);
 
         unsafe {
             let result = RegSetValueExW(
@@ -5612,6 +5927,10 @@ mod startup {
     pub fn disable_startup() -> Result<(), String> {
         Err("Startup is only supported on Windows".to_string())
     }
+
+    pub fn is_registration_current() -> bool {
+        false
+    }
 }
 
 /// 检查是否已设置开机启动
@@ -5620,25 +5939,33 @@ pub fn is_startup_enabled() -> Result<bool, String> {
     startup::is_startup_enabled()
 }
 
-/// 设置开机启动
+/// 设置开机启动，并同步保存到 settings.startup_enabled
 #[tauri::command]
-pub fn set_startup_enabled(enabled: bool) -> Result<(), String> {
+pub fn set_startup_enabled(enabled: bool, app: tauri::AppHandle) -> Result<(), String> {
     if enabled {
-        startup::enable_startup()
+        startup::enable_startup()?;
     } else {
-        startup::disable_startup()
+        startup::disable_startup()?;
     }
+
+    let app_data_dir = get_app_data_dir(&app)?;
+    let mut settings = settings::load_settings(&app_data_dir)?;
+    if settings.startup_enabled != enabled {
+        settings.startup_enabled = enabled;
+        settings::save_settings(&app_data_dir, &settings)?;
+    }
+    Ok(())
 }
 
-/// 同步开机启动设置（内部使用）
+/// 同步开机启动设置（内部使用）：注册表与设置不一致，或已注册的路径不是当前程序时重新写入
 pub fn sync_startup_setting(startup_enabled: bool) -> Result<(), String> {
     let current = startup::is_startup_enabled().unwrap_or(false);
-    if current != startup_enabled {
-        if startup_enabled {
+    if startup_enabled {
+        if !current || !startup::is_registration_current() {
             startup::enable_startup()?;
-        } else {
-            startup::disable_startup()?;
         }
+    } else if current {
+        startup::disable_startup()?;
     }
     Ok(())
 }
@@ -5710,32 +6037,24 @@ fn compare_versions(version1: &str, version2: &str) -> i32 {
 /// 检查更新
 #[tauri::command]
 pub async fn check_update() -> Result<UpdateCheckResult, String> {
+    crate::network::ensure_online()?;
     let current_version = env!("CARGO_PKG_VERSION");
     
     // GitHub API URL
     let api_url = "https://api.github.com/repos/b3nguang/ReFast/releases/latest";
     
     // 创建 HTTP 客户端，设置 User-Agent（GitHub API 要求）
-    let client = reqwest::Client::builder()
+    let client = crate::http_client::builder()?
         .user_agent("ReFast-Updater/1.0")
         .timeout(Duration::from_secs(10))
         .build()
         .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
     
-    // 发送请求
-    let response = client
-        .get(api_url)
-        .send()
+    // 发送请求（失败时按策略退避重试，非 2xx 响应转换为错误）
+    let response = crate::api_policy::send("api.github.com", &crate::api_policy::Policy::UPDATE, || client.get(api_url))
         .await
         .map_err(|e| format!("请求 GitHub API 失败: {}", e))?;
     
-    if !response.status().is_success() {
-        return Err(format!(
-            "GitHub API 返回错误: {}",
-            response.status()
-        ));
-    }
-    
     // 解析 JSON
     let release: GitHubRelease = response
         .json()
@@ -5794,9 +6113,11 @@ async fn download_file(
 ) -> Result<(), String> {
     use std::io::Write;
     use std::time::Instant;
+
+    crate::network::ensure_online()?;
     
     // 创建 HTTP 客户端
-    let client = reqwest::Client::builder()
+    let client = crate::http_client::builder()?
         .user_agent(user_agent.unwrap_or("ReFast-Downloader/1.0"))
         .timeout(Duration::from_secs(300)) // 5分钟超时
         .build()
@@ -5994,7 +6315,18 @@ pub fn quit_app(app_handle: tauri::AppHandle) -> Result<(), String> {
 #[tauri::command]
 pub async fn get_all_clipboard_items(app_handle: tauri::AppHandle) -> Result<Vec<crate::clipboard::ClipboardItem>, String> {
     let app_data_dir = get_app_data_dir(&app_handle)?;
-    crate::clipboard::get_all_clipboard_items(&app_data_dir)
+    crate::clipboard::get_all_clipboard_items(&app_data_dir).map(crate::clipboard::mask_items)
+}
+
+/// 读取剪切板项的原文（列表接口中敏感内容已打码），用于复制、查看和编辑
+#[tauri::command]
+pub async fn reveal_clipboard_item(
+    id: String,
+    app_handle: tauri::AppHandle,
+) -> Result<crate::clipboard::ClipboardItem, String> {
+    let app_data_dir = get_app_data_dir(&app_handle)?;
+    crate::clipboard::get_clipboard_item(&id, &app_data_dir)?
+        .ok_or_else(|| format!("Clipboard item {} not found", id))
 }
 
 #[tauri::command]
@@ -6004,7 +6336,7 @@ pub async fn add_clipboard_item(
     app_handle: tauri::AppHandle,
 ) -> Result<crate::clipboard::ClipboardItem, String> {
     let app_data_dir = get_app_data_dir(&app_handle)?;
-    crate::clipboard::add_clipboard_item(content, content_type, &app_data_dir)
+    crate::clipboard::add_clipboard_item(content, content_type, &app_data_dir).map(crate::clipboard::mask_item)
 }
 
 #[tauri::command]
@@ -6014,7 +6346,7 @@ pub async fn update_clipboard_item(
     app_handle: tauri::AppHandle,
 ) -> Result<crate::clipboard::ClipboardItem, String> {
     let app_data_dir = get_app_data_dir(&app_handle)?;
-    crate::clipboard::update_clipboard_item(id, content, &app_data_dir)
+    crate::clipboard::update_clipboard_item(id, content, &app_data_dir).map(crate::clipboard::mask_item)
 }
 
 #[tauri::command]
@@ -6023,7 +6355,100 @@ pub async fn toggle_favorite_clipboard_item(
     app_handle: tauri::AppHandle,
 ) -> Result<crate::clipboard::ClipboardItem, String> {
     let app_data_dir = get_app_data_dir(&app_handle)?;
-    crate::clipboard::toggle_favorite_clipboard_item(id, &app_data_dir)
+    crate::clipboard::toggle_favorite_clipboard_item(id, &app_data_dir).map(crate::clipboard::mask_item)
+}
+
+/// 获取收藏的剪切板项（按手动排序）
+#[tauri::command]
+pub async fn get_favorite_clipboard_items(
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<crate::clipboard::ClipboardItem>, String> {
+    let app_data_dir = get_app_data_dir(&app_handle)?;
+    crate::clipboard::get_favorite_clipboard_items(&app_data_dir).map(crate::clipboard::mask_items)
+}
+
+/// 按 ids 的顺序调整收藏项排序，返回排序后的收藏列表
+#[tauri::command]
+pub async fn reorder_favorite_clipboard_items(
+    ids: Vec<String>,
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<crate::clipboard::ClipboardItem>, String> {
+    let app_data_dir = get_app_data_dir(&app_handle)?;
+    crate::clipboard::reorder_favorite_clipboard_items(&ids, &app_data_dir).map(crate::clipboard::mask_items)
+}
+
+/// 置顶或取消置顶剪切板项
+#[tauri::command]
+pub async fn pin_clipboard_item(
+    id: String,
+    pinned: bool,
+    app_handle: tauri::AppHandle,
+) -> Result<crate::clipboard::ClipboardItem, String> {
+    let app_data_dir = get_app_data_dir(&app_handle)?;
+    crate::clipboard::pin_clipboard_item(&id, pinned, &app_data_dir).map(crate::clipboard::mask_item)
+}
+
+/// 按 ids 的顺序调整置顶项排序，返回排序后的置顶列表
+#[tauri::command]
+pub async fn reorder_pinned_clipboard_items(
+    ids: Vec<String>,
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<crate::clipboard::ClipboardItem>, String> {
+    let app_data_dir = get_app_data_dir(&app_handle)?;
+    crate::clipboard::reorder_pinned_items(&ids, &app_data_dir).map(crate::clipboard::mask_items)
+}
+
+/// 批量删除剪切板项，返回删除的条数
+#[tauri::command]
+pub async fn delete_clipboard_items(
+    ids: Vec<String>,
+    app_handle: tauri::AppHandle,
+) -> Result<usize, String> {
+    let app_data_dir = get_app_data_dir(&app_handle)?;
+    crate::clipboard::delete_clipboard_items(&ids, &app_data_dir)
+}
+
+/// 批量收藏或取消收藏剪切板项，返回状态有变化的条数
+#[tauri::command]
+pub async fn set_clipboard_items_favorite(
+    ids: Vec<String>,
+    favorite: bool,
+    app_handle: tauri::AppHandle,
+) -> Result<usize, String> {
+    let app_data_dir = get_app_data_dir(&app_handle)?;
+    crate::clipboard::set_clipboard_items_favorite(&ids, favorite, &app_data_dir)
+}
+
+/// 批量为剪切板项添加标签（replace 为 true 时替换原有标签），返回更新的条数
+#[tauri::command]
+pub async fn tag_clipboard_items(
+    ids: Vec<String>,
+    tags: Vec<String>,
+    replace: Option<bool>,
+    app_handle: tauri::AppHandle,
+) -> Result<usize, String> {
+    let app_data_dir = get_app_data_dir(&app_handle)?;
+    crate::clipboard::tag_clipboard_items(&ids, &tags, replace.unwrap_or(false), &app_data_dir)
+}
+
+/// 设置单个剪切板项的标签（替换原有标签），返回更新后的项
+#[tauri::command]
+pub async fn set_clipboard_item_tags(
+    id: String,
+    tags: Vec<String>,
+    app_handle: tauri::AppHandle,
+) -> Result<crate::clipboard::ClipboardItem, String> {
+    let app_data_dir = get_app_data_dir(&app_handle)?;
+    crate::clipboard::set_clipboard_item_tags(&id, &tags, &app_data_dir).map(crate::clipboard::mask_item)
+}
+
+/// 列出剪切板中用到的所有标签及其数量
+#[tauri::command]
+pub async fn list_clipboard_tags(
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<crate::clipboard::ClipboardTag>, String> {
+    let app_data_dir = get_app_data_dir(&app_handle)?;
+    crate::clipboard::list_clipboard_tags(&app_data_dir)
 }
 
 #[tauri::command]
@@ -6035,10 +6460,24 @@ pub async fn delete_clipboard_item(
     crate::clipboard::delete_clipboard_item(id, &app_data_dir)
 }
 
+/// 清空剪切板历史（收藏除外），删除图片文件时通过 clipboard-clear-progress 事件报告进度
 #[tauri::command]
 pub async fn clear_clipboard_history(app_handle: tauri::AppHandle) -> Result<(), String> {
     let app_data_dir = get_app_data_dir(&app_handle)?;
-    crate::clipboard::clear_clipboard_history(&app_data_dir)
+    async_runtime::spawn_blocking(move || {
+        crate::clipboard::clear_clipboard_history(&app_data_dir, |done, total| {
+            let progress = if total == 0 { 100 } else { (done * 100 / total) as u8 };
+            let _ = app_handle.emit(
+                "clipboard-clear-progress",
+                serde_json::json!({
+                    "progress": progress,
+                    "message": crate::i18n::tf("clipboard.clear_progress", &[&done, &total]),
+                }),
+            );
+        })
+    })
+    .await
+    .map_err(|e| format!("clear_clipboard_history join error: {}", e))?
 }
 
 #[tauri::command]
@@ -6047,7 +6486,55 @@ pub async fn search_clipboard_items(
     app_handle: tauri::AppHandle,
 ) -> Result<Vec<crate::clipboard::ClipboardItem>, String> {
     let app_data_dir = get_app_data_dir(&app_handle)?;
-    crate::clipboard::search_clipboard_items(&query, &app_data_dir)
+    crate::clipboard::search_clipboard_items(&query, &app_data_dir).map(crate::clipboard::mask_items)
+}
+
+/// 语义搜索剪切板历史：设置了向量模型时按语义相似度排序并合并关键词结果，否则只返回关键词结果
+#[tauri::command]
+pub async fn semantic_search_clipboard(
+    query: String,
+    limit: Option<usize>,
+    app_handle: tauri::AppHandle,
+) -> Result<Vec<crate::embeddings::SemanticMatch<crate::clipboard::ClipboardItem>>, crate::api_policy::ApiError> {
+    let invalid = |e: String| crate::api_policy::ApiError::new(crate::api_policy::ApiErrorKind::Invalid, e);
+    let app_data_dir = get_app_data_dir(&app_handle).map_err(invalid)?;
+    let llm_settings = settings::load_settings(&app_data_dir).map_err(invalid)?.llm;
+    let matches =
+        crate::embeddings::semantic_search_clipboard(&app_data_dir, &llm_settings, &query, limit.unwrap_or(50)).await?;
+    let (items, scores): (Vec<_>, Vec<_>) = matches.into_iter().map(|m| (m.item, (m.score, m.keyword))).unzip();
+    let items = crate::clipboard::mask_items(items);
+    Ok(items
+        .into_iter()
+        .zip(scores)
+        .map(|(item, (score, keyword))| crate::embeddings::SemanticMatch { item, score, keyword })
+        .collect())
+}
+
+/// 批量导入剪切板项（单个事务，mode 默认跳过已存在的内容）
+#[tauri::command]
+pub async fn import_clipboard_items(
+    items: Vec<crate::clipboard::ClipboardItem>,
+    mode: Option<db::ConflictMode>,
+    app_handle: tauri::AppHandle,
+) -> Result<db::BatchInsertResult, String> {
+    let app_data_dir = get_app_data_dir(&app_handle)?;
+    async_runtime::spawn_blocking(move || {
+        crate::clipboard::insert_clipboard_items(&items, mode.unwrap_or_default(), &app_data_dir)
+    })
+    .await
+    .map_err(|e| format!("import_clipboard_items join error: {}", e))?
+}
+
+/// 获取剪切板监控状态（是否运行、重启次数、最近错误等）
+#[tauri::command]
+pub fn get_monitor_status() -> crate::clipboard::MonitorStatus {
+    crate::clipboard::get_monitor_status()
+}
+
+/// 手动重启剪切板监控
+#[tauri::command]
+pub fn restart_monitor() -> Result<(), String> {
+    crate::clipboard::restart_monitor()
 }
 
 #[tauri::command]
@@ -6079,102 +6566,67 @@ pub async fn get_clipboard_image_data(image_path: String) -> Result<Vec<u8>, Str
 
 #[tauri::command]
 pub async fn copy_image_to_clipboard(image_path: String) -> Result<(), String> {
-    #[cfg(target_os = "windows")]
-    {
-        use std::fs;
-        use windows_sys::Win32::System::DataExchange::{
-            SetClipboardData, OpenClipboard, EmptyClipboard, CloseClipboard,
-        };
-        use windows_sys::Win32::System::Memory::{GlobalAlloc, GlobalLock, GlobalUnlock, GMEM_MOVEABLE};
-        use windows_sys::Win32::Foundation::HWND;
-        use windows_sys::Win32::Graphics::Gdi::BITMAPINFOHEADER;
-
-        // 读取图片文件
-        let image_data = fs::read(&image_path)
-            .map_err(|e| format!("Failed to read image: {}", e))?;
-
-        // 解码 PNG
-        let decoder = png::Decoder::new(&image_data[..]);
-        let mut reader = decoder.read_info()
-            .map_err(|e| format!("Failed to decode PNG: {}", e))?;
-        let mut buf = vec![0; reader.output_buffer_size()];
-        let info = reader.next_frame(&mut buf)
-            .map_err(|e| format!("Failed to read PNG frame: {}", e))?;
-
-        let width = info.width as i32;
-        let height = info.height as i32;
-        let bit_count = 32u16; // RGBA
-
-        unsafe {
-            if OpenClipboard(0 as HWND) == 0 {
-                return Err("Failed to open clipboard".to_string());
-            }
-
-            EmptyClipboard();
+    crate::clipboard::write_multi(&crate::clipboard::ClipboardContent {
+        image_path: Some(image_path),
+        ..Default::default()
+    })
+}
 
-            // 计算 DIB 大小
-            let header_size = std::mem::size_of::<BITMAPINFOHEADER>();
-            let row_size = ((width * bit_count as i32 + 31) / 32 * 4) as usize;
-            let image_size = row_size * height as usize;
-            let total_size = header_size + image_size;
+/// 同时写入多种剪贴板格式（纯文本、HTML、图片、文件列表），如 Markdown 原文与渲染后的 HTML
+#[tauri::command]
+pub async fn set_clipboard_multi(content: crate::clipboard::ClipboardContent) -> Result<(), String> {
+    crate::clipboard::write_multi(&content)
+}
 
-            // 分配全局内存
-            let h_mem = GlobalAlloc(GMEM_MOVEABLE, total_size);
-            if h_mem.is_null() {
-                CloseClipboard();
-                return Err("Failed to allocate memory".to_string());
-            }
+/// 以另一种形式复制剪切板中的项，如图片复制为 data URI、表格文本复制为 CSV 或 Markdown 表格
+#[tauri::command]
+pub async fn copy_clipboard_item_as(
+    app: tauri::AppHandle,
+    item_id: String,
+    format: crate::clipboard_copy_as::CopyFormat,
+) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let item = crate::clipboard::get_clipboard_item(&item_id, &app_data_dir)?
+        .ok_or_else(|| format!("Clipboard item {} not found", item_id))?;
+    crate::clipboard::write_multi(&crate::clipboard_copy_as::convert(&item, format)?)
+}
 
-            let p_mem = GlobalLock(h_mem);
-            if p_mem.is_null() {
-                CloseClipboard();
-                return Err("Failed to lock memory".to_string());
-            }
+/// 把文件项中的路径重新复制到剪切板，已不存在的文件会被跳过
+#[tauri::command]
+pub async fn copy_files_to_clipboard(paths: Vec<String>) -> Result<(), String> {
+    let existing: Vec<String> = paths.into_iter().filter(|path| std::path::Path::new(path).exists()).collect();
+    if existing.is_empty() {
+        return Err("文件已不存在".to_string());
+    }
 
-            // 填充 BITMAPINFOHEADER
-            let bmi = p_mem as *mut BITMAPINFOHEADER;
-            (*bmi).biSize = header_size as u32;
-            (*bmi).biWidth = width;
-            (*bmi).biHeight = height;
-            (*bmi).biPlanes = 1;
-            (*bmi).biBitCount = bit_count;
-            (*bmi).biCompression = 0; // BI_RGB
-            (*bmi).biSizeImage = 0;
-            (*bmi).biXPelsPerMeter = 0;
-            (*bmi).biYPelsPerMeter = 0;
-            (*bmi).biClrUsed = 0;
-            (*bmi).biClrImportant = 0;
-
-            // 填充图片数据（转换 RGBA 到 BGRA，并上下翻转）
-            let image_data_ptr = (p_mem as *mut u8).add(header_size);
-            for y in 0..height {
-                for x in 0..width {
-                    let src_offset = ((height - 1 - y) * width + x) as usize * 4;
-                    let dst_offset = (y * width + x) as usize * 4;
-                    if src_offset + 3 < buf.len() {
-                        *image_data_ptr.add(dst_offset) = buf[src_offset + 2]; // B
-                        *image_data_ptr.add(dst_offset + 1) = buf[src_offset + 1]; // G
-                        *image_data_ptr.add(dst_offset + 2) = buf[src_offset]; // R
-                        *image_data_ptr.add(dst_offset + 3) = buf[src_offset + 3]; // A
-                    }
-                }
-            }
+    #[cfg(target_os = "windows")]
+    {
+        crate::clipboard::monitor::write_files(&existing)
+    }
 
-            GlobalUnlock(h_mem);
+    #[cfg(not(target_os = "windows"))]
+    {
+        Err("Not implemented for this platform".to_string())
+    }
+}
 
-            // 设置到剪切板 (CF_DIB = 8)
-            if SetClipboardData(8, h_mem as isize) == 0 {
-                CloseClipboard();
-                return Err("Failed to set clipboard data".to_string());
-            }
+/// 把剪切板项以纯文本与 HTML 两种格式重新复制，粘贴到支持富文本的程序时保留格式
+#[tauri::command]
+pub async fn copy_rich_text_to_clipboard(app: tauri::AppHandle, item_id: String) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let item = crate::clipboard::get_clipboard_item(&item_id, &app_data_dir)?
+        .ok_or_else(|| format!("Clipboard item {} not found", item_id))?;
+    let html = crate::clipboard::get_clipboard_item_html(&item_id, &app_data_dir)?
+        .ok_or_else(|| "该记录没有保存富文本格式".to_string())?;
 
-            CloseClipboard();
-            Ok(())
-        }
+    #[cfg(target_os = "windows")]
+    {
+        crate::clipboard::monitor::write_html(&item.content, &html)
     }
 
     #[cfg(not(target_os = "windows"))]
     {
+        let _ = (item, html);
         Err("Not implemented for this platform".to_string())
     }
 }
\ No newline at end of file
@@ -0,0 +1,36 @@
+//! 使用统计相关命令模块
+//! 
+//! 提供本地使用统计的记录、汇总与清空（数据不会离开本机），以及基于统计的个性化排序
+
+use crate::activity_log;
+use crate::ranking;
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 记录一次启动或查询事件
+#[tauri::command]
+pub fn record_activity(event: activity_log::ActivityEvent, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    activity_log::record_event(&event, &app_data_dir)
+}
+
+/// 获取最近 days 天的统计数据
+#[tauri::command]
+pub fn get_activity_stats(days: Option<u32>, app: AppHandle) -> Result<activity_log::ActivityStats, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    activity_log::get_stats(days.unwrap_or(30), &app_data_dir)
+}
+
+/// 清空所有统计数据
+#[tauri::command]
+pub fn purge_activity_log(app: AppHandle) -> Result<usize, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    activity_log::purge(&app_data_dir)
+}
+
+/// 获取当前输入对应的个性化排序加权（基于历史启动记录）
+#[tauri::command]
+pub fn get_ranking_boosts(query: String, app: AppHandle) -> Result<Vec<ranking::RankingBoost>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    ranking::get_ranking_boosts(&query, &app_data_dir)
+}
@@ -0,0 +1,29 @@
+//! 压缩包内容预览相关命令模块
+//! 
+//! 提供 zip 压缩包条目列表，以及单个条目的解压并打开
+
+use crate::archive_peek;
+use crate::file_history;
+use std::path::PathBuf;
+use tauri::async_runtime;
+
+/// 列出压缩包中的条目
+#[tauri::command]
+pub async fn list_archive_entries(path: String) -> Result<archive_peek::ArchiveListing, String> {
+    async_runtime::spawn_blocking(move || archive_peek::list_entries(&PathBuf::from(path)))
+        .await
+        .map_err(|e| format!("Failed to join archive task: {}", e))?
+}
+
+/// 把压缩包中的单个条目解压到临时目录并用默认程序打开，返回解压后的路径
+#[tauri::command]
+pub async fn open_archive_entry(path: String, entry: String) -> Result<String, String> {
+    let extracted = async_runtime::spawn_blocking(move || {
+        archive_peek::extract_entry(&PathBuf::from(path), &entry, &archive_peek::default_temp_root())
+    })
+    .await
+    .map_err(|e| format!("Failed to join archive task: {}", e))??;
+    let extracted = extracted.to_string_lossy().to_string();
+    file_history::launch_file(&extracted)?;
+    Ok(extracted)
+}
@@ -0,0 +1,46 @@
+//! 音频设备相关命令模块
+//! 
+//! 提供音频输出设备列表、默认设备切换以及音量和静音设置
+
+use crate::audio_devices;
+use tauri::async_runtime;
+
+/// 获取音频输出设备列表
+#[tauri::command]
+pub async fn list_audio_devices() -> Result<Vec<audio_devices::AudioDevice>, String> {
+    async_runtime::spawn_blocking(audio_devices::list_output_devices)
+        .await
+        .map_err(|e| format!("list_audio_devices join error: {}", e))?
+}
+
+/// 切换默认音频输出设备（target 为设备 id 或名称关键字，如 "headphones"）
+#[tauri::command]
+pub async fn switch_audio_device(target: String) -> Result<audio_devices::AudioDevice, String> {
+    async_runtime::spawn_blocking(move || audio_devices::switch_output_device(&target))
+        .await
+        .map_err(|e| format!("switch_audio_device join error: {}", e))?
+}
+
+/// 获取默认输出设备的音量与静音状态
+#[tauri::command]
+pub async fn get_audio_volume() -> Result<audio_devices::VolumeState, String> {
+    async_runtime::spawn_blocking(audio_devices::volume)
+        .await
+        .map_err(|e| format!("get_audio_volume join error: {}", e))?
+}
+
+/// 设置默认输出设备的音量（0-100）
+#[tauri::command]
+pub async fn set_audio_volume(volume: u32) -> Result<(), String> {
+    async_runtime::spawn_blocking(move || audio_devices::set_volume(volume))
+        .await
+        .map_err(|e| format!("set_audio_volume join error: {}", e))?
+}
+
+/// 设置默认输出设备是否静音
+#[tauri::command]
+pub async fn set_audio_mute(muted: bool) -> Result<(), String> {
+    async_runtime::spawn_blocking(move || audio_devices::set_mute(muted))
+        .await
+        .map_err(|e| format!("set_audio_mute join error: {}", e))?
+}
@@ -0,0 +1,28 @@
+//! 浏览器扩展桥接相关命令模块
+//! 
+//! 提供 native messaging 宿主的注册、取消注册与状态查询
+
+use crate::browser_bridge::{self, BridgeStatus};
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 获取桥接状态：服务是否运行、已授权的扩展和已登记的浏览器
+#[tauri::command]
+pub fn get_browser_bridge_status(app: AppHandle) -> Result<BridgeStatus, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    browser_bridge::get_status(&app_data_dir)
+}
+
+/// 为扩展 ID 写入宿主清单并在 Chrome、Edge、Firefox 中登记
+#[tauri::command]
+pub fn register_browser_bridge(app: AppHandle, extension_ids: Vec<String>) -> Result<BridgeStatus, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    browser_bridge::register(&extension_ids, &app_data_dir)
+}
+
+/// 删除宿主清单和注册表登记
+#[tauri::command]
+pub fn unregister_browser_bridge(app: AppHandle) -> Result<BridgeStatus, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    browser_bridge::unregister(&app_data_dir)
+}
@@ -0,0 +1,20 @@
+//! 浏览器历史相关命令模块
+//! 
+//! 提供 Chrome / Edge / Brave 浏览历史的搜索
+
+use crate::browser_history;
+use tauri::async_runtime;
+
+const DEFAULT_LIMIT: usize = 20;
+
+/// 搜索浏览器历史（启动器数据源停用时返回空列表）
+#[tauri::command]
+pub async fn search_browser_history(query: String, limit: Option<usize>) -> Result<Vec<browser_history::BrowserHistoryItem>, String> {
+    if !crate::search_providers::is_enabled("browser_history") {
+        return Ok(Vec::new());
+    }
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    async_runtime::spawn_blocking(move || browser_history::search(&query, limit))
+        .await
+        .map_err(|e| format!("search_browser_history join error: {}", e))
+}
@@ -0,0 +1,55 @@
+//! 日历相关命令模块
+//! 
+//! 提供 ICS 日历订阅的添加、删除、列出与手动刷新，以及今天的日程和接下来的会议查询
+
+use crate::calendar::{self, Calendar, CalendarEvent};
+use super::get_app_data_dir;
+use tauri::{AppHandle, Emitter};
+
+const DEFAULT_UPCOMING_LIMIT: usize = 5;
+
+/// 获取所有日历订阅
+#[tauri::command]
+pub fn list_calendars(app: AppHandle) -> Result<Vec<Calendar>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    calendar::list_calendars(&app_data_dir)
+}
+
+/// 添加日历订阅：ICS 地址（http/https/webcal）或本地 .ics 文件路径
+#[tauri::command]
+pub async fn add_calendar(app: AppHandle, source: String) -> Result<Calendar, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    calendar::add_calendar(&app_data_dir, &source).await
+}
+
+/// 删除日历订阅及其日程
+#[tauri::command]
+pub fn remove_calendar(app: AppHandle, id: i64) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    calendar::remove_calendar(&app_data_dir, id)
+}
+
+/// 今天的全部日程
+#[tauri::command]
+pub fn get_today_events(app: AppHandle) -> Result<Vec<CalendarEvent>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    calendar::today_events(&app_data_dir)
+}
+
+/// 接下来的会议（含正在进行的），不含全天日程
+#[tauri::command]
+pub fn get_upcoming_events(app: AppHandle, limit: Option<usize>) -> Result<Vec<CalendarEvent>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    calendar::upcoming_events(&app_data_dir, limit.unwrap_or(DEFAULT_UPCOMING_LIMIT))
+}
+
+/// 立即刷新所有日历，返回刷新成功的数量
+#[tauri::command]
+pub async fn refresh_calendars(app: AppHandle) -> Result<usize, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let refreshed = calendar::refresh_all(&app_data_dir).await?;
+    if refreshed > 0 {
+        let _ = app.emit("calendar-updated", refreshed);
+    }
+    Ok(refreshed)
+}
@@ -0,0 +1,51 @@
+//! 对话记录相关命令模块
+//! 
+//! 提供 AI 对话记录的查询、搜索、置顶、删除与导出为 Markdown
+
+use crate::chat_history::{self, Conversation, MessageMatch, StoredMessage};
+use super::get_app_data_dir;
+use std::fs;
+use tauri::AppHandle;
+
+/// 获取所有对话（置顶在前）
+#[tauri::command]
+pub fn list_chat_conversations(app: AppHandle) -> Result<Vec<Conversation>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    chat_history::list_conversations(&app_data_dir)
+}
+
+/// 获取对话中的消息
+#[tauri::command]
+pub fn get_chat_messages(id: String, app: AppHandle) -> Result<Vec<StoredMessage>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    chat_history::get_messages(&app_data_dir, &id)
+}
+
+/// 在所有对话消息中搜索
+#[tauri::command]
+pub fn search_chat_messages(query: String, limit: Option<usize>, app: AppHandle) -> Result<Vec<MessageMatch>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    chat_history::search_messages(&app_data_dir, &query, limit.unwrap_or(50))
+}
+
+/// 置顶或取消置顶对话
+#[tauri::command]
+pub fn set_chat_conversation_pinned(id: String, pinned: bool, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    chat_history::set_pinned(&app_data_dir, &id, pinned)
+}
+
+/// 删除对话
+#[tauri::command]
+pub fn delete_chat_conversation(id: String, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    chat_history::delete_conversation(&app_data_dir, &id)
+}
+
+/// 把对话导出为 Markdown 文件
+#[tauri::command]
+pub fn export_chat_conversation(id: String, path: String, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let markdown = chat_history::export_markdown(&app_data_dir, &id)?;
+    fs::write(&path, markdown).map_err(|e| format!("Failed to write {}: {}", path, e))
+}
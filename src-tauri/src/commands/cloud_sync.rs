@@ -0,0 +1,53 @@
+//! 云同步相关命令模块
+//! 
+//! 提供 WebDAV / S3 同步配置的读取与保存、立即同步、同步状态以及冲突日志的查看与清空
+
+use crate::cloud_sync::{self, SyncConfigInput, SyncConfigView, SyncConflict, SyncStatus, SyncSummary};
+use super::get_app_data_dir;
+use tauri::{AppHandle, Emitter};
+
+const DEFAULT_CONFLICT_LIMIT: u32 = 100;
+
+/// 获取同步配置（不含密码与同步口令）
+#[tauri::command]
+pub fn get_sync_config(app: AppHandle) -> Result<SyncConfigView, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    cloud_sync::get_config(&app_data_dir)
+}
+
+/// 保存同步配置；密码与口令留空（null）时保留原值
+#[tauri::command]
+pub fn save_sync_config(app: AppHandle, config: SyncConfigInput) -> Result<SyncConfigView, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    cloud_sync::save_config(&app_data_dir, config)
+}
+
+/// 立即同步一次
+#[tauri::command]
+pub async fn sync_now(app: AppHandle) -> Result<SyncSummary, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let summary = cloud_sync::sync_now(&app_data_dir).await?;
+    let _ = app.emit(cloud_sync::COMPLETED_EVENT, &summary);
+    Ok(summary)
+}
+
+/// 获取同步状态：上次同步时间、结果与错误
+#[tauri::command]
+pub fn get_sync_status(app: AppHandle) -> Result<SyncStatus, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    cloud_sync::get_status(&app_data_dir)
+}
+
+/// 最近的同步冲突，最新的在前
+#[tauri::command]
+pub fn list_sync_conflicts(app: AppHandle, limit: Option<u32>) -> Result<Vec<SyncConflict>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    cloud_sync::list_conflicts(&app_data_dir, limit.unwrap_or(DEFAULT_CONFLICT_LIMIT))
+}
+
+/// 清空冲突日志
+#[tauri::command]
+pub fn clear_sync_conflicts(app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    cloud_sync::clear_conflicts(&app_data_dir)
+}
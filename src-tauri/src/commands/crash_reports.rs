@@ -0,0 +1,24 @@
+//! 崩溃报告相关命令模块
+//! 
+//! 提供崩溃报告列表与问题报告 zip 打包
+
+use crate::crash;
+use super::get_app_data_dir;
+use tauri::{async_runtime, AppHandle};
+
+/// 获取已保存的崩溃报告（最新在前）
+#[tauri::command]
+pub fn list_crash_reports(app: AppHandle) -> Result<Vec<crash::CrashReportInfo>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    crash::list_crash_reports(&app_data_dir)
+}
+
+/// 打包崩溃报告、minidump 和最近日志，返回 zip 路径
+#[tauri::command]
+pub async fn create_bug_report(app: AppHandle) -> Result<String, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    async_runtime::spawn_blocking(move || crash::create_bug_report(&app_data_dir))
+        .await
+        .map_err(|e| format!("create_bug_report join error: {}", e))?
+        .map(|path| path.to_string_lossy().to_string())
+}
@@ -0,0 +1,75 @@
+//! 显示器控制相关命令模块
+//! 
+//! 提供显示器亮度调节、分辨率/刷新率切换、显示预设以及夜间模式开关
+
+use crate::display_control;
+use crate::settings;
+use super::get_app_data_dir;
+use tauri::{async_runtime, AppHandle};
+
+/// 获取显示器列表（含当前分辨率与亮度）
+#[tauri::command]
+pub async fn list_monitors() -> Result<Vec<display_control::Monitor>, String> {
+    async_runtime::spawn_blocking(display_control::list_monitors)
+        .await
+        .map_err(|e| format!("list_monitors join error: {}", e))?
+}
+
+/// 设置显示器亮度（0-100）
+#[tauri::command]
+pub async fn set_monitor_brightness(monitor: String, brightness: u32) -> Result<(), String> {
+    async_runtime::spawn_blocking(move || display_control::set_brightness(&monitor, brightness))
+        .await
+        .map_err(|e| format!("set_monitor_brightness join error: {}", e))?
+}
+
+/// 获取显示器支持的分辨率与刷新率
+#[tauri::command]
+pub async fn list_display_modes(monitor: String) -> Result<Vec<display_control::DisplayMode>, String> {
+    async_runtime::spawn_blocking(move || display_control::list_modes(&monitor))
+        .await
+        .map_err(|e| format!("list_display_modes join error: {}", e))?
+}
+
+/// 切换显示器分辨率与刷新率（未指定刷新率时尽量保持当前刷新率）
+#[tauri::command]
+pub async fn set_display_mode(
+    monitor: String,
+    width: u32,
+    height: u32,
+    frequency: Option<u32>,
+) -> Result<display_control::DisplayMode, String> {
+    async_runtime::spawn_blocking(move || display_control::set_display_mode(&monitor, width, height, frequency))
+        .await
+        .map_err(|e| format!("set_display_mode join error: {}", e))?
+}
+
+/// 按名称应用设置中保存的显示预设
+#[tauri::command]
+pub async fn apply_display_preset(app: AppHandle, name: String) -> Result<display_control::DisplayMode, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let preset = settings::load_settings(&app_data_dir)?
+        .display_presets
+        .into_iter()
+        .find(|p| p.name == name)
+        .ok_or_else(|| format!("未找到显示预设: {}", name))?;
+    async_runtime::spawn_blocking(move || display_control::apply_preset(&preset))
+        .await
+        .map_err(|e| format!("apply_display_preset join error: {}", e))?
+}
+
+/// 获取夜间模式是否开启
+#[tauri::command]
+pub async fn get_night_light() -> Result<bool, String> {
+    async_runtime::spawn_blocking(display_control::night_light_enabled)
+        .await
+        .map_err(|e| format!("get_night_light join error: {}", e))?
+}
+
+/// 开关夜间模式，返回切换后的状态
+#[tauri::command]
+pub async fn set_night_light(enabled: bool) -> Result<bool, String> {
+    async_runtime::spawn_blocking(move || display_control::set_night_light(enabled))
+        .await
+        .map_err(|e| format!("set_night_light join error: {}", e))?
+}
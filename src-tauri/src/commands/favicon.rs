@@ -0,0 +1,21 @@
+//! 网站图标相关命令模块
+//! 
+//! 提供网址结果的站点图标获取（带磁盘缓存）与缓存清理
+
+use crate::favicon;
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 获取网址对应站点的图标（data URL），获取不到时返回 None
+#[tauri::command]
+pub async fn get_favicon(url: String, app: AppHandle) -> Result<Option<String>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    favicon::get_favicon(&url, &app_data_dir).await
+}
+
+/// 清空网站图标缓存
+#[tauri::command]
+pub fn clear_favicon_cache(app: AppHandle) -> Result<usize, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    favicon::clear_cache(&app_data_dir)
+}
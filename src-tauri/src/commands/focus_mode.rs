@@ -0,0 +1,18 @@
+//! 勿扰模式相关命令模块
+//! 
+//! 提供勿扰模式状态查询与手动开关
+
+use crate::focus_mode;
+use tauri::AppHandle;
+
+/// 获取勿扰模式状态
+#[tauri::command]
+pub fn get_focus_mode_status() -> focus_mode::FocusModeStatus {
+    focus_mode::status()
+}
+
+/// 手动开启 / 关闭勿扰模式
+#[tauri::command]
+pub fn set_focus_mode(enabled: bool, app: AppHandle) -> focus_mode::FocusModeStatus {
+    focus_mode::set_manual(&app, enabled)
+}
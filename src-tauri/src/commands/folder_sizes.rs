@@ -0,0 +1,29 @@
+//! 文件夹大小分析相关命令模块
+//! 
+//! 扫描目录占用空间，扫描期间通过 folder-scan://progress 事件推送进度，可随时取消
+
+use crate::folder_sizes;
+use std::path::PathBuf;
+use tauri::{async_runtime, AppHandle, Emitter};
+
+pub const PROGRESS_EVENT: &str = "folder-scan://progress";
+
+/// 扫描文件夹，返回按大小排序的目录树；取消时返回错误
+#[tauri::command]
+pub async fn scan_folder_sizes(path: String, app: AppHandle) -> Result<folder_sizes::FolderSizeNode, String> {
+    let guard = folder_sizes::ScanGuard::acquire()?;
+    async_runtime::spawn_blocking(move || {
+        let _guard = guard;
+        folder_sizes::scan(&PathBuf::from(path), |progress| {
+            let _ = app.emit(PROGRESS_EVENT, progress);
+        })
+    })
+    .await
+    .map_err(|e| format!("Failed to join folder scan task: {}", e))?
+}
+
+/// 取消正在进行的文件夹扫描
+#[tauri::command]
+pub fn cancel_folder_scan() -> bool {
+    folder_sizes::cancel_scan()
+}
@@ -0,0 +1,41 @@
+//! 游戏相关命令模块
+//! 
+//! 提供 Steam / Epic 已安装游戏的搜索与启动（可选提供者，需在设置中启用）
+
+use crate::games;
+use crate::settings;
+use super::{get_app_data_dir, open_url};
+use tauri::{async_runtime, AppHandle};
+
+fn is_enabled(app: &AppHandle) -> Result<bool, String> {
+    let app_data_dir = get_app_data_dir(app)?;
+    Ok(settings::load_settings(&app_data_dir)?.games_provider_enabled)
+}
+
+/// 搜索已安装的游戏（未启用或启动器数据源停用时返回空列表）
+#[tauri::command]
+pub async fn search_games(query: String, app: AppHandle) -> Result<Vec<games::GameInfo>, String> {
+    if !crate::search_providers::is_enabled("games") || !is_enabled(&app)? {
+        return Ok(Vec::new());
+    }
+    async_runtime::spawn_blocking(move || games::search_games(&query))
+        .await
+        .map_err(|e| format!("search_games join error: {}", e))
+}
+
+/// 重新扫描已安装的游戏
+#[tauri::command]
+pub async fn refresh_games() -> Result<Vec<games::GameInfo>, String> {
+    async_runtime::spawn_blocking(games::refresh_games)
+        .await
+        .map_err(|e| format!("refresh_games join error: {}", e))
+}
+
+/// 通过平台协议启动游戏
+#[tauri::command]
+pub fn launch_game(launch_uri: String) -> Result<(), String> {
+    if !launch_uri.starts_with("steam://") && !launch_uri.starts_with("com.epicgames.launcher://") {
+        return Err(format!("Unsupported game launch uri: {}", launch_uri));
+    }
+    open_url(launch_uri)
+}
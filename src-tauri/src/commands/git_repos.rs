@@ -0,0 +1,37 @@
+//! Git 仓库相关命令模块
+//! 
+//! 提供配置根目录下 git 仓库的索引刷新与搜索
+
+use crate::git_repos;
+use crate::settings;
+use super::get_app_data_dir;
+use tauri::{async_runtime, AppHandle};
+
+/// 获取已索引的 git 仓库
+#[tauri::command]
+pub fn get_git_repos(app: AppHandle) -> Result<Vec<git_repos::GitRepo>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    git_repos::get_git_repos(&app_data_dir)
+}
+
+/// 搜索 git 仓库（启动器数据源停用时返回空列表）
+#[tauri::command]
+pub fn search_git_repos(query: String, app: AppHandle) -> Result<Vec<git_repos::GitRepo>, String> {
+    if !crate::search_providers::is_enabled("git_repos") {
+        return Ok(Vec::new());
+    }
+    let app_data_dir = get_app_data_dir(&app)?;
+    git_repos::search_git_repos(&query, &app_data_dir)
+}
+
+/// 扫描设置中的根目录并增量刷新仓库索引（异步，避免阻塞主线程）
+#[tauri::command]
+pub async fn refresh_git_repos(app: AppHandle) -> Result<git_repos::GitRepoRefreshResult, String> {
+    async_runtime::spawn_blocking(move || {
+        let app_data_dir = get_app_data_dir(&app)?;
+        let settings = settings::load_settings(&app_data_dir)?;
+        git_repos::refresh_git_repos(&settings.git_repo_roots, &app_data_dir)
+    })
+    .await
+    .map_err(|e| format!("refresh_git_repos join error: {}", e))?
+}
@@ -0,0 +1,39 @@
+//! Git 配置同步相关命令模块
+//! 
+//! 提供同步仓库的设置与状态查询，以及导出提交（可推送）和拉取合并后导入
+
+use crate::git_sync::{self, GitCommitResult, GitPullResult, GitSyncConfig, GitSyncStatus};
+use super::get_app_data_dir;
+use tauri::{async_runtime, AppHandle};
+
+/// 获取 git 同步状态：仓库、分支、远端、最近提交以及本机是否有未提交的配置修改
+#[tauri::command]
+pub fn get_git_sync_status(app: AppHandle) -> Result<GitSyncStatus, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    git_sync::get_status(&app_data_dir)
+}
+
+/// 设置同步仓库目录与子目录，目录不是 git 仓库时自动初始化
+#[tauri::command]
+pub fn save_git_sync_config(app: AppHandle, config: GitSyncConfig) -> Result<GitSyncStatus, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    git_sync::save_config(&app_data_dir, config)
+}
+
+/// 导出设置、片段和快捷链接并提交，push 为 true 时推送到远端
+#[tauri::command]
+pub async fn git_sync_commit(app: AppHandle, message: Option<String>, push: bool) -> Result<GitCommitResult, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    async_runtime::spawn_blocking(move || git_sync::commit(&app_data_dir, message.as_deref(), push))
+        .await
+        .map_err(|e| format!("Failed to join git sync task: {}", e))?
+}
+
+/// 拉取远端并把合并后的配置导入本机
+#[tauri::command]
+pub async fn git_sync_pull(app: AppHandle) -> Result<GitPullResult, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    async_runtime::spawn_blocking(move || git_sync::pull(&app_data_dir))
+        .await
+        .map_err(|e| format!("Failed to join git sync task: {}", e))?
+}
@@ -0,0 +1,42 @@
+//! 习惯打卡相关命令模块
+//! 
+//! 提供习惯的添加、删除、今日打卡与撤销，以及连续天数和完成率等统计
+
+use crate::habits::{self, HabitStatus};
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 获取所有习惯及统计，今天待打卡的在前
+#[tauri::command]
+pub fn list_habits(app: AppHandle) -> Result<Vec<HabitStatus>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    habits::list_habits(&app_data_dir)
+}
+
+/// 添加习惯，input 为 "名称 [计划]"，如 "跑步 每周3次"、"阅读 工作日"
+#[tauri::command]
+pub fn add_habit(app: AppHandle, input: String) -> Result<HabitStatus, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    habits::add_habit(&app_data_dir, &input)
+}
+
+/// 删除习惯及其打卡记录
+#[tauri::command]
+pub fn remove_habit(app: AppHandle, id: i64) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    habits::remove_habit(&app_data_dir, id)
+}
+
+/// 今天打卡
+#[tauri::command]
+pub fn check_in_habit(app: AppHandle, id: i64) -> Result<HabitStatus, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    habits::check_in(&app_data_dir, id)
+}
+
+/// 撤销今天的打卡
+#[tauri::command]
+pub fn undo_habit_check_in(app: AppHandle, id: i64) -> Result<HabitStatus, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    habits::undo_check_in(&app_data_dir, id)
+}
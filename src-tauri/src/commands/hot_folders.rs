@@ -0,0 +1,16 @@
+//! 热点文件夹相关命令模块
+//! 
+//! 提供热点文件夹中最近新建/修改文件的查询，供启动器空查询时展示
+
+use crate::hot_folders;
+
+const DEFAULT_LIMIT: usize = 8;
+
+/// 获取热点文件夹中最近新建/修改的文件（按修改时间倒序）
+#[tauri::command]
+pub fn get_hot_folder_recent_files(limit: Option<usize>) -> Result<Vec<hot_folders::RecentFile>, String> {
+    if !crate::search_providers::is_enabled("hot_folders") {
+        return Ok(Vec::new());
+    }
+    Ok(hot_folders::recent_files(limit.unwrap_or(DEFAULT_LIMIT)))
+}
@@ -0,0 +1,26 @@
+//! 配置导入相关命令模块
+//! 
+//! 提供从 Wox / PowerToys Run / uTools 导入网页搜索、快速链接与别名
+
+use crate::importer;
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 解析其他启动器的配置并返回预览（source: "wox" | "powertoys" | "utools"）
+#[tauri::command]
+pub fn preview_launcher_import(
+    source: String,
+    path: Option<String>,
+) -> Result<importer::ImportPreview, String> {
+    importer::preview_import(&source, path.as_deref())
+}
+
+/// 应用导入预览
+#[tauri::command]
+pub fn apply_launcher_import(
+    preview: importer::ImportPreview,
+    app: AppHandle,
+) -> Result<importer::ImportResult, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    importer::apply_import(&preview, &app_data_dir)
+}
@@ -0,0 +1,28 @@
+//! 保持唤醒相关命令模块
+//! 
+//! 提供保持唤醒（阻止睡眠与息屏）的状态查询、开启与关闭
+
+use crate::keep_awake::{self, KeepAwakeStatus};
+use tauri::AppHandle;
+
+/// 获取保持唤醒状态
+#[tauri::command]
+pub fn get_keep_awake_status() -> KeepAwakeStatus {
+    keep_awake::status()
+}
+
+/// 开启保持唤醒；minutes 为空时一直保持，keep_display 默认同时阻止息屏
+#[tauri::command]
+pub fn start_keep_awake(
+    app: AppHandle,
+    minutes: Option<u64>,
+    keep_display: Option<bool>,
+) -> Result<KeepAwakeStatus, String> {
+    keep_awake::start(&app, minutes, keep_display.unwrap_or(true))
+}
+
+/// 关闭保持唤醒
+#[tauri::command]
+pub fn stop_keep_awake(app: AppHandle) -> KeepAwakeStatus {
+    keep_awake::stop(&app)
+}
@@ -0,0 +1,26 @@
+//! 发送到手机相关命令模块
+//! 
+//! 提供剪切板项局域网分享链接的创建、列表与停止
+
+use crate::lan_share::{self, PhoneShare};
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 为剪切板项创建局域网临时链接，返回链接与二维码
+#[tauri::command]
+pub fn create_phone_share(app: AppHandle, item_id: String, expire_minutes: Option<u64>) -> Result<PhoneShare, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    lan_share::create_share(&item_id, expire_minutes, &app_data_dir)
+}
+
+/// 列出未过期的分享
+#[tauri::command]
+pub fn list_phone_shares() -> Result<Vec<PhoneShare>, String> {
+    lan_share::list_shares()
+}
+
+/// 停止分享，链接立即失效
+#[tauri::command]
+pub fn revoke_phone_share(id: String) -> Result<(), String> {
+    lan_share::revoke_share(&id)
+}
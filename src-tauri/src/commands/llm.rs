@@ -0,0 +1,304 @@
+//! AI 对话相关命令模块
+//! 
+//! 提供经由后端（应用代理设置）发送的 OpenAI 兼容对话请求，流式内容通过 Channel 回传，可选使用回答缓存；
+//! 剪切板文本的总结 / 改写 / 语法修正 / 翻译（长文本分段并行翻译）；基于备忘录和单词记录的问答（问笔记）；以及 token 用量的月度汇总
+
+use super::get_app_data_dir;
+use crate::api_policy::{ApiError, ApiErrorKind};
+use crate::llm::{self, CacheMode};
+use crate::chat_history;
+use crate::llm_cache;
+use crate::llm_usage;
+use crate::prompt_templates;
+use crate::settings;
+use crate::text_chunks;
+use crate::embeddings::{self, NoteSource};
+use futures_util::{stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use tauri::ipc::Channel;
+use tauri::{AppHandle, Emitter};
+
+/// 剪切板文本的 AI 操作，对应同名提示词功能
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipboardAiAction {
+    Summarize,
+    RewriteFormal,
+    FixGrammar,
+    Translate,
+}
+
+impl ClipboardAiAction {
+    fn prompt_feature(self) -> &'static str {
+        match self {
+            ClipboardAiAction::Summarize => "summarize",
+            ClipboardAiAction::RewriteFormal => "rewrite_formal",
+            ClipboardAiAction::FixGrammar => "fix_grammar",
+            ClipboardAiAction::Translate => "translate",
+        }
+    }
+}
+
+/// 发送对话请求，流式内容逐段发送到 on_delta，返回完整回答；失败时返回结构化错误（kind 区分限流、额度用尽、网络错误等）
+/// request.prompt 不为空时先用对应功能当前的提示词模板生成消息；
+/// request.cache 为 use 时命中缓存直接返回（整段内容作为一次 delta 发送）
+#[tauri::command]
+pub async fn llm_chat(request: llm::ChatRequest, on_delta: Channel<String>, app: AppHandle) -> Result<String, ApiError> {
+    let app_data_dir = get_app_data_dir(&app).map_err(|e| ApiError::new(ApiErrorKind::Invalid, e))?;
+    run_chat(&app, &app_data_dir, request, &|delta| {
+        let _ = on_delta.send(delta.to_string());
+    })
+    .await
+}
+
+/// 对话请求的完整流程：渲染提示词模板、读写回答缓存、记录用量
+async fn run_chat(
+    app: &AppHandle,
+    app_data_dir: &Path,
+    mut request: llm::ChatRequest,
+    on_delta: &(dyn Fn(&str) + Sync),
+) -> Result<String, ApiError> {
+    if let Some(prompt) = &request.prompt {
+        let template = prompt_templates::active_template(app_data_dir, &prompt.feature)
+            .map_err(|e| ApiError::new(ApiErrorKind::Invalid, e))?;
+        request.apply_template(&template);
+    }
+    let cache_key = (request.cache != CacheMode::None).then(|| llm_cache::cache_key(&request));
+
+    if let (Some(key), CacheMode::Use) = (&cache_key, request.cache) {
+        match llm_cache::get(app_data_dir, key) {
+            Ok(Some(cached)) => {
+                on_delta(&cached);
+                save_history(app_data_dir, &request, &cached);
+                return Ok(cached);
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!("[LLM] {}", e),
+        }
+    }
+
+    let result = llm::chat_completion(&request, on_delta).await?;
+    let answer = result.content;
+
+    let feature = request.feature.as_deref().unwrap_or("other");
+    if let Err(e) = llm_usage::record(app_data_dir, feature, &request.model, &result.usage) {
+        eprintln!("[LLM] {}", e);
+    }
+    notify_budget(app, app_data_dir);
+
+    // 中断的流式回答不完整，不写入缓存
+    if let (Some(key), true) = (&cache_key, result.complete) {
+        if let Err(e) = llm_cache::put(app_data_dir, key, &answer) {
+            eprintln!("[LLM] {}", e);
+        }
+    } else if cache_key.is_some() {
+        eprintln!("[LLM] Stream ended before completion, skipping cache");
+    }
+    save_history(app_data_dir, &request, &answer);
+    Ok(answer)
+}
+
+/// request.save_history 为 true 时把对话保存到对话记录
+fn save_history(app_data_dir: &Path, request: &llm::ChatRequest, answer: &str) {
+    if !request.save_history {
+        return;
+    }
+    let feature = request.feature.as_deref().unwrap_or("other");
+    if let Err(e) = chat_history::save_conversation(app_data_dir, feature, &request.model, &request.messages, answer) {
+        eprintln!("[LLM] {}", e);
+    }
+}
+
+/// 对剪切板文本执行 AI 操作（总结、正式改写、语法修正、翻译），结果保存为新的剪切板项并返回；
+/// item_id 不为空时处理对应的剪切板项，否则处理 text；输出流式发送到 on_delta；
+/// 翻译超过 TRANSLATE_CHUNK_CHARS 个字符的文本时分段翻译
+#[tauri::command]
+pub async fn clipboard_ai_action(
+    action: ClipboardAiAction,
+    item_id: Option<String>,
+    text: Option<String>,
+    target_lang: Option<String>,
+    on_delta: Channel<String>,
+    app: AppHandle,
+) -> Result<crate::clipboard::ClipboardItem, ApiError> {
+    let invalid = |e: String| ApiError::new(ApiErrorKind::Invalid, e);
+    let app_data_dir = get_app_data_dir(&app).map_err(invalid)?;
+    let text = match item_id {
+        Some(id) => {
+            let item = crate::clipboard::get_clipboard_item(&id, &app_data_dir)
+                .map_err(invalid)?
+                .ok_or_else(|| invalid(format!("剪切板项 {} 不存在", id)))?;
+            if item.content_type != "text" {
+                return Err(invalid("只能处理文本类型的剪切板项".to_string()));
+            }
+            item.content
+        }
+        None => text.unwrap_or_default(),
+    };
+    if text.trim().is_empty() {
+        return Err(invalid("没有需要处理的文本".to_string()));
+    }
+
+    let llm_settings = settings::load_settings(&app_data_dir).map_err(invalid)?.llm;
+    let feature = action.prompt_feature();
+    let target_lang = target_lang.filter(|l| !l.trim().is_empty()).unwrap_or_else(|| "中文".to_string());
+    let build_request = |text: String| llm::ChatRequest {
+        base_url: llm_settings.base_url.clone(),
+        api_key: llm_settings.api_key.clone(),
+        model: llm_settings.model.clone(),
+        messages: Vec::new(),
+        stream: true,
+        max_tokens: None,
+        cache: CacheMode::Use,
+        feature: Some(format!("clipboard_{}", feature)),
+        prompt: Some(llm::PromptInput {
+            feature: feature.to_string(),
+            variables: HashMap::from([
+                ("text".to_string(), text),
+                ("source_lang".to_string(), "原文语言".to_string()),
+                ("target_lang".to_string(), target_lang.clone()),
+            ]),
+        }),
+        save_history: false,
+    };
+    let send_delta = |delta: &str| {
+        let _ = on_delta.send(delta.to_string());
+    };
+    let answer = if matches!(action, ClipboardAiAction::Translate) && text.chars().count() > TRANSLATE_CHUNK_CHARS {
+        let chunks = text_chunks::split_chunks(&text, TRANSLATE_CHUNK_CHARS);
+        translate_chunks(&app, &app_data_dir, chunks, &build_request, &send_delta).await?
+    } else {
+        run_chat(&app, &app_data_dir, build_request(text), &send_delta).await?
+    };
+    let answer = answer.trim();
+    if answer.is_empty() {
+        return Err(ApiError::new(ApiErrorKind::Invalid, "AI 未返回内容"));
+    }
+    crate::clipboard::add_clipboard_item(answer.to_string(), "text".to_string(), &app_data_dir).map_err(invalid)
+}
+
+// 长文本分段翻译：每段的字符数上限和同时进行的请求数
+const TRANSLATE_CHUNK_CHARS: usize = 1500;
+const TRANSLATE_CONCURRENCY: usize = 3;
+
+fn ignore_delta(_: &str) {}
+
+/// 分段翻译长文本：最多 TRANSLATE_CONCURRENCY 段同时请求，译文按原文顺序拼接；
+/// 某段完成且前面的段落都已输出时整段发送到 on_delta，避免整篇一次请求超时
+async fn translate_chunks(
+    app: &AppHandle,
+    app_data_dir: &Path,
+    chunks: Vec<String>,
+    build_request: &(dyn Fn(String) -> llm::ChatRequest + Sync),
+    on_delta: &(dyn Fn(&str) + Sync),
+) -> Result<String, ApiError> {
+    let mut results = stream::iter(chunks)
+        .map(|chunk| run_chat(app, app_data_dir, build_request(chunk), &ignore_delta))
+        .buffered(TRANSLATE_CONCURRENCY);
+    let mut translated: Vec<String> = Vec::new();
+    while let Some(result) = results.next().await {
+        let part = result?;
+        if !translated.is_empty() {
+            on_delta("\n\n");
+        }
+        on_delta(part.trim());
+        translated.push(part.trim().to_string());
+    }
+    Ok(translated.join("\n\n"))
+}
+
+// 问笔记时检索的资料条数
+const NOTES_CONTEXT_LIMIT: usize = 6;
+
+/// 问笔记的回答，sources 按回答中 [编号] 的顺序排列
+#[derive(Debug, Clone, Serialize)]
+pub struct NotesAnswer {
+    pub answer: String,
+    pub sources: Vec<NoteSource>,
+}
+
+/// 问笔记：从备忘录和单词记录中检索相关内容交给 AI 回答，回答流式发送到 on_delta，并返回引用的资料
+#[tauri::command]
+pub async fn ask_notes(question: String, on_delta: Channel<String>, app: AppHandle) -> Result<NotesAnswer, ApiError> {
+    let invalid = |e: String| ApiError::new(ApiErrorKind::Invalid, e);
+    let question = question.trim().to_string();
+    if question.is_empty() {
+        return Err(invalid("问题不能为空".to_string()));
+    }
+    let app_data_dir = get_app_data_dir(&app).map_err(invalid)?;
+    let llm_settings = settings::load_settings(&app_data_dir).map_err(invalid)?.llm;
+
+    let sources = embeddings::retrieve_notes(&app_data_dir, &llm_settings, &question, NOTES_CONTEXT_LIMIT).await?;
+    if sources.is_empty() {
+        let answer = "没有找到与问题相关的备忘录或单词记录。".to_string();
+        let _ = on_delta.send(answer.clone());
+        return Ok(NotesAnswer { answer, sources });
+    }
+    let context = sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let kind = if source.kind == "word" { "单词" } else { "备忘录" };
+            format!("[{}]（{}）{}\n{}", i + 1, kind, source.title, source.content)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let request = llm::ChatRequest {
+        base_url: llm_settings.base_url,
+        api_key: llm_settings.api_key,
+        model: llm_settings.model,
+        messages: Vec::new(),
+        stream: true,
+        max_tokens: None,
+        cache: CacheMode::None,
+        feature: Some("ask_notes".to_string()),
+        prompt: Some(llm::PromptInput {
+            feature: "ask_notes".to_string(),
+            variables: HashMap::from([
+                ("question".to_string(), question),
+                ("context".to_string(), context),
+            ]),
+        }),
+        save_history: false,
+    };
+    let answer = run_chat(&app, &app_data_dir, request, &|delta| {
+        let _ = on_delta.send(delta.to_string());
+    })
+    .await?;
+    Ok(NotesAnswer { answer, sources })
+}
+
+/// 清空 AI 回答缓存，返回删除的条目数
+#[tauri::command]
+pub fn clear_llm_cache(app: AppHandle) -> Result<usize, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    llm_cache::clear(&app_data_dir)
+}
+
+/// 用量越过月度预算提醒阈值时发送 llm-budget-warning 事件
+fn notify_budget(app: &AppHandle, app_data_dir: &Path) {
+    let budget = settings::load_settings(app_data_dir)
+        .ok()
+        .and_then(|s| s.llm.monthly_budget);
+    if budget.is_none() {
+        return;
+    }
+    match llm_usage::summary(app_data_dir, None, budget) {
+        Ok(summary) if llm_usage::should_warn(&summary) => {
+            let _ = app.emit("llm-budget-warning", &summary);
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("[LLM] {}", e),
+    }
+}
+
+/// 获取 AI 用量的月度汇总（month 为 "YYYY-MM"，为空时为当月）
+#[tauri::command]
+pub fn get_llm_usage_summary(month: Option<String>, app: AppHandle) -> Result<llm_usage::UsageSummary, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let budget = settings::load_settings(&app_data_dir)?.llm.monthly_budget;
+    llm_usage::summary(&app_data_dir, month.as_deref(), budget)
+}
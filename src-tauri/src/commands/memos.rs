@@ -1,8 +1,12 @@
 //! 备忘录相关命令模块
 //! 
-//! 提供备忘录的 CRUD 操作
+//! 提供备忘录的 CRUD 操作及语义搜索
 
+use crate::api_policy::{ApiError, ApiErrorKind};
+use crate::embeddings::{self, SemanticMatch};
+use crate::key_injection::InjectionMode;
 use crate::memos;
+use crate::settings;
 use super::get_app_data_dir;
 use tauri::AppHandle;
 
@@ -36,6 +40,27 @@ pub fn update_memo(
     memos::update_memo(id, title, content, &app_data_dir)
 }
 
+/// 设置备忘录的热字串缩写（为空时取消）、是否区分大小写、生效的程序及输入方式
+#[tauri::command]
+pub fn set_memo_hotstring(
+    id: String,
+    hotstring: Option<String>,
+    case_sensitive: bool,
+    apps: Option<Vec<String>>,
+    injection: Option<InjectionMode>,
+    app: AppHandle,
+) -> Result<memos::MemoItem, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    memos::set_memo_hotstring(
+        id,
+        hotstring,
+        case_sensitive,
+        apps.unwrap_or_default(),
+        injection.unwrap_or_default(),
+        &app_data_dir,
+    )
+}
+
 /// 删除备忘录
 #[tauri::command]
 pub fn delete_memo(id: String, app: AppHandle) -> Result<(), String> {
@@ -49,3 +74,16 @@ pub fn search_memos(query: String, app: AppHandle) -> Result<Vec<memos::MemoItem
     let app_data_dir = get_app_data_dir(&app)?;
     memos::search_memos(&query, &app_data_dir)
 }
+
+/// 语义搜索备忘录：设置了向量模型时按语义相似度排序并合并关键词结果，否则只返回关键词结果
+#[tauri::command]
+pub async fn semantic_search_memos(
+    query: String,
+    limit: Option<usize>,
+    app: AppHandle,
+) -> Result<Vec<SemanticMatch<memos::MemoItem>>, ApiError> {
+    let invalid = |e: String| ApiError::new(ApiErrorKind::Invalid, e);
+    let app_data_dir = get_app_data_dir(&app).map_err(invalid)?;
+    let llm_settings = settings::load_settings(&app_data_dir).map_err(invalid)?.llm;
+    embeddings::semantic_search_memos(&app_data_dir, &llm_settings, &query, limit.unwrap_or(50)).await
+}
@@ -0,0 +1,36 @@
+//! 网络工具相关命令模块
+//! 
+//! 提供本机/公网 IP、Wi-Fi 与网卡信息查询，以及 ping 和端口检测
+
+use crate::net_tools;
+use tauri::async_runtime;
+
+/// 获取本机网络信息（PowerShell 查询网卡较慢，在阻塞线程中执行）
+#[tauri::command]
+pub async fn get_network_info() -> Result<net_tools::NetworkInfo, String> {
+    async_runtime::spawn_blocking(net_tools::network_info)
+        .await
+        .map_err(|e| format!("get_network_info join error: {}", e))?
+}
+
+/// 获取公网 IP
+#[tauri::command]
+pub async fn get_public_ip() -> Result<String, String> {
+    net_tools::public_ip().await
+}
+
+/// ping 主机
+#[tauri::command]
+pub async fn ping_host(host: String) -> Result<net_tools::PingResult, String> {
+    async_runtime::spawn_blocking(move || net_tools::ping(&host))
+        .await
+        .map_err(|e| format!("ping_host join error: {}", e))?
+}
+
+/// 检测主机的 TCP 端口是否可连接
+#[tauri::command]
+pub async fn check_port(host: String, port: u16) -> Result<net_tools::PortCheckResult, String> {
+    async_runtime::spawn_blocking(move || net_tools::check_port(&host, port))
+        .await
+        .map_err(|e| format!("check_port join error: {}", e))?
+}
@@ -0,0 +1,20 @@
+//! 网络状态相关命令模块
+//! 
+//! 提供网络连通性查询与立即重新检测
+
+use crate::network;
+use tauri::async_runtime;
+
+/// 获取最近一次检测到的网络状态
+#[tauri::command]
+pub fn get_network_status() -> network::NetworkStatus {
+    network::status()
+}
+
+/// 离线时立即重新检测网络状态（探测可能耗时数秒，在阻塞线程中执行）
+#[tauri::command]
+pub async fn check_network_status() -> Result<network::NetworkStatus, String> {
+    async_runtime::spawn_blocking(network::refresh)
+        .await
+        .map_err(|e| format!("check_network_status join error: {}", e))
+}
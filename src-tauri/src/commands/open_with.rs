@@ -0,0 +1,49 @@
+//! 打开方式相关命令模块
+//! 
+//! 提供在终端 / VS Code / 自定义命令中打开文件夹的操作
+
+use crate::open_with;
+use crate::settings;
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 获取已配置的打开方式
+#[tauri::command]
+pub fn get_open_with_actions(app: AppHandle) -> Result<Vec<settings::OpenWithAction>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let settings = settings::load_settings(&app_data_dir)?;
+    Ok(settings.open_with_actions)
+}
+
+/// 保存打开方式配置
+#[tauri::command]
+pub fn save_open_with_actions(
+    actions: Vec<settings::OpenWithAction>,
+    app: AppHandle,
+) -> Result<(), String> {
+    for action in &actions {
+        if action.id.trim().is_empty() {
+            return Err("打开方式的 id 不能为空".to_string());
+        }
+        open_with::build_command_args(&action.command, "")?;
+    }
+
+    let app_data_dir = get_app_data_dir(&app)?;
+    let mut settings = settings::load_settings(&app_data_dir)?;
+    settings.open_with_actions = actions;
+    settings::save_settings(&app_data_dir, &settings)
+}
+
+/// 使用指定的打开方式打开文件夹（传入文件时打开其所在文件夹）
+#[tauri::command]
+pub fn open_folder_with(action_id: String, path: String, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let settings = settings::load_settings(&app_data_dir)?;
+    let action = settings
+        .open_with_actions
+        .iter()
+        .find(|a| a.id == action_id)
+        .ok_or_else(|| format!("Open-with action not found: {}", action_id))?;
+
+    open_with::run_action(action, &path)
+}
@@ -0,0 +1,22 @@
+//! 密码生成相关命令模块
+//! 
+//! 提供随机密码、单词口令与易读密码的生成，以及以敏感内容复制到剪贴板
+
+use crate::clipboard;
+use crate::password_gen::{self, GeneratedPassword, PasswordOptions};
+
+/// 按选项生成密码（不写入剪贴板）
+#[tauri::command]
+pub fn generate_password(options: PasswordOptions) -> Result<GeneratedPassword, String> {
+    password_gen::generate(&options)
+}
+
+/// 把密码以敏感内容复制到剪贴板：不记入剪切板历史，返回自动清除前的秒数
+#[tauri::command]
+pub fn copy_password(password: String) -> Result<u64, String> {
+    if password.is_empty() {
+        return Err("没有可复制的密码".to_string());
+    }
+    clipboard::write_sensitive_text(&password, password_gen::CLIPBOARD_EXPIRE)?;
+    Ok(password_gen::CLIPBOARD_EXPIRE.as_secs())
+}
@@ -0,0 +1,49 @@
+//! 粘贴链接分享相关命令模块
+//! 
+//! 提供粘贴服务配置，以及剪切板文本链接的创建、列表与撤销
+
+use crate::paste_share::{self, PasteConfigInput, PasteConfigView, PasteLink};
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 获取粘贴服务配置（不返回令牌明文）
+#[tauri::command]
+pub fn get_paste_config(app: AppHandle) -> Result<PasteConfigView, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    paste_share::get_config(&app_data_dir)
+}
+
+/// 保存粘贴服务配置
+#[tauri::command]
+pub fn save_paste_config(app: AppHandle, input: PasteConfigInput) -> Result<PasteConfigView, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    paste_share::save_config(&app_data_dir, input)
+}
+
+/// 上传剪切板文本项并返回链接，expiry 为空时使用默认有效期
+#[tauri::command]
+pub async fn create_paste_link(app: AppHandle, item_id: String, expiry: Option<String>) -> Result<PasteLink, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    paste_share::create_link(&app_data_dir, &item_id, expiry.as_deref()).await
+}
+
+/// 列出已创建的链接
+#[tauri::command]
+pub fn list_paste_links(app: AppHandle) -> Result<Vec<PasteLink>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    paste_share::list_links(&app_data_dir)
+}
+
+/// 撤销链接
+#[tauri::command]
+pub async fn revoke_paste_link(app: AppHandle, id: String) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    paste_share::revoke_link(&app_data_dir, &id).await
+}
+
+/// 清除已撤销和已过期的记录，返回清除数量
+#[tauri::command]
+pub fn clear_paste_links(app: AppHandle) -> Result<usize, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    paste_share::clear_inactive_links(&app_data_dir)
+}
@@ -0,0 +1,35 @@
+//! 提示词模板相关命令模块
+//! 
+//! 提供 AI 功能提示词模板的查询、保存、删除与切换
+
+use crate::prompt_templates::{self, PromptTemplate};
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 获取所有提示词模板（内置 + 自定义）
+#[tauri::command]
+pub fn list_prompt_templates(app: AppHandle) -> Result<Vec<PromptTemplate>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    prompt_templates::list_templates(&app_data_dir)
+}
+
+/// 保存自定义提示词模板（id 为空时新建）
+#[tauri::command]
+pub fn save_prompt_template(template: PromptTemplate, app: AppHandle) -> Result<PromptTemplate, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    prompt_templates::save_template(template, &app_data_dir)
+}
+
+/// 删除自定义提示词模板
+#[tauri::command]
+pub fn delete_prompt_template(id: String, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    prompt_templates::delete_template(&id, &app_data_dir)
+}
+
+/// 设置功能当前使用的提示词模板
+#[tauri::command]
+pub fn set_active_prompt_template(feature: String, id: String, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    prompt_templates::set_active_template(&feature, &id, &app_data_dir)
+}
@@ -0,0 +1,11 @@
+//! 代理相关命令模块
+//! 
+//! 提供系统代理检测
+
+use crate::http_client;
+
+/// 检测系统代理，未启用时返回 None
+#[tauri::command]
+pub fn get_system_proxy() -> Option<http_client::SystemProxy> {
+    http_client::detect_system_proxy()
+}
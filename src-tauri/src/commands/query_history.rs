@@ -0,0 +1,60 @@
+//! 查询历史相关命令模块
+//! 
+//! 提供搜索框查询历史的记录、上下键回溯、输入补全与清空
+
+use crate::query_history;
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 记录一次查询
+#[tauri::command]
+pub fn add_query_history(query: String, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    query_history::add_query(&query, &app_data_dir)
+}
+
+/// 获取最近的查询历史
+#[tauri::command]
+pub fn get_query_history(
+    limit: Option<u32>,
+    app: AppHandle,
+) -> Result<Vec<query_history::QueryHistoryEntry>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    query_history::get_history(limit.unwrap_or(50), &app_data_dir)
+}
+
+/// 上下键回溯：获取以 prefix 开头的第 offset 条历史
+#[tauri::command]
+pub fn navigate_query_history(
+    prefix: String,
+    offset: u32,
+    app: AppHandle,
+) -> Result<Option<String>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    query_history::navigate(&prefix, offset, &app_data_dir)
+}
+
+/// 根据当前输入获取历史补全建议
+#[tauri::command]
+pub fn suggest_query_completions(
+    prefix: String,
+    limit: Option<u32>,
+    app: AppHandle,
+) -> Result<Vec<query_history::QueryHistoryEntry>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    query_history::suggest(&prefix, limit.unwrap_or(5), &app_data_dir)
+}
+
+/// 删除单条查询历史
+#[tauri::command]
+pub fn delete_query_history(query: String, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    query_history::delete_query(&query, &app_data_dir)
+}
+
+/// 清空查询历史
+#[tauri::command]
+pub fn clear_query_history(app: AppHandle) -> Result<usize, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    query_history::clear(&app_data_dir)
+}
@@ -0,0 +1,21 @@
+//! 行情相关命令模块
+//! 
+//! 提供股票、外汇与加密货币报价查询，未指定代码时返回设置中的关注列表
+
+use crate::quotes::{self, QuoteResult};
+use crate::settings;
+use super::get_app_data_dir;
+use std::time::Duration;
+use tauri::AppHandle;
+
+/// 获取报价；symbols 为空时使用关注列表，force 为 true 时忽略缓存重新获取
+#[tauri::command]
+pub async fn get_quotes(
+    app: AppHandle,
+    symbols: Option<Vec<String>>,
+    force: Option<bool>,
+) -> Result<Vec<QuoteResult>, String> {
+    let settings = settings::load_settings(&get_app_data_dir(&app)?)?;
+    let symbols = symbols.unwrap_or(settings.quote_watchlist);
+    Ok(quotes::get_quotes(&symbols, Duration::from_secs(settings.quote_refresh_secs), force.unwrap_or(false)).await)
+}
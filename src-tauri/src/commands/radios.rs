@@ -0,0 +1,30 @@
+//! 无线开关相关命令模块
+//! 
+//! 提供 Wi-Fi、蓝牙与飞行模式的状态查询和开关
+
+use crate::radios;
+use tauri::async_runtime;
+
+/// 获取各无线设备与飞行模式的状态
+#[tauri::command]
+pub async fn get_radio_status() -> Result<radios::RadioStatus, String> {
+    async_runtime::spawn_blocking(radios::status)
+        .await
+        .map_err(|e| format!("get_radio_status join error: {}", e))?
+}
+
+/// 打开或关闭某一类无线设备（kind 为 "wifi" 或 "bluetooth"）
+#[tauri::command]
+pub async fn set_radio_state(kind: String, enabled: bool) -> Result<(), String> {
+    async_runtime::spawn_blocking(move || radios::set_radio(&kind, enabled))
+        .await
+        .map_err(|e| format!("set_radio_state join error: {}", e))?
+}
+
+/// 开关飞行模式
+#[tauri::command]
+pub async fn set_airplane_mode(enabled: bool) -> Result<(), String> {
+    async_runtime::spawn_blocking(move || radios::set_airplane_mode(enabled))
+        .await
+        .map_err(|e| format!("set_airplane_mode join error: {}", e))?
+}
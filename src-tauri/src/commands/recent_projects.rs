@@ -0,0 +1,32 @@
+//! 最近项目相关命令模块
+//! 
+//! 提供 VS Code / JetBrains 最近打开项目的搜索与打开
+
+use crate::recent_projects;
+use super::{get_app_cache, lock_app_cache_safe};
+
+/// 获取所有最近项目
+#[tauri::command]
+pub fn get_recent_projects() -> Result<Vec<recent_projects::RecentProject>, String> {
+    Ok(recent_projects::get_recent_projects())
+}
+
+/// 搜索最近项目（启动器数据源停用时返回空列表）
+#[tauri::command]
+pub fn search_recent_projects(query: String) -> Result<Vec<recent_projects::RecentProject>, String> {
+    if !crate::search_providers::is_enabled("recent_projects") {
+        return Ok(Vec::new());
+    }
+    Ok(recent_projects::search_recent_projects(&query))
+}
+
+/// 使用对应 IDE 打开最近项目（可执行文件从应用索引中查找）
+#[tauri::command]
+pub fn open_recent_project(project: recent_projects::RecentProject) -> Result<(), String> {
+    let apps = {
+        let cache = get_app_cache();
+        let guard = lock_app_cache_safe(&cache);
+        guard.clone().unwrap_or_default()
+    };
+    recent_projects::open_recent_project(&project, &apps)
+}
@@ -1,12 +1,48 @@
 use crate::commands::get_app_data_dir;
 use crate::hooks;
+use crate::key_injection::{self, InjectionMode, UnicodeKeys};
 use crate::recording::{RecordingMeta, RecordingState};
 use crate::replay::ReplayState;
 use std::fs;
 use std::path::Path;
 use std::sync::{Arc, LazyLock, Mutex};
+use serde::Serialize;
 use std::time::Duration;
-use tauri::Manager;
+use tauri::{Emitter, Manager};
+
+// 录制、回放状态事件，前端监听这些事件而不是轮询 get_playback_progress
+pub const RECORDING_STARTED_EVENT: &str = "recording://started";
+pub const RECORDING_STOPPED_EVENT: &str = "recording://stopped";
+pub const REPLAY_PROGRESS_EVENT: &str = "replay://progress";
+pub const REPLAY_COMPLETED_EVENT: &str = "replay://completed";
+pub const REPLAY_ERROR_EVENT: &str = "replay://error";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingStoppedPayload {
+    pub file_path: String,
+    pub event_count: usize,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayProgressPayload {
+    pub progress: f32, // 0-100
+    pub current: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayCompletedPayload {
+    pub reason: String, // "finished" | "stopped" | "escape" | "limit"
+    pub executed: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayErrorPayload {
+    pub index: usize,
+    pub message: String,
+}
 
 static RECORDING_STATE: LazyLock<Arc<Mutex<RecordingState>>> =
     LazyLock::new(|| Arc::new(Mutex::new(RecordingState::new())));
@@ -22,7 +58,7 @@ pub fn get_recording_status() -> Result<bool, String> {
 }
 
 #[tauri::command]
-pub fn start_recording() -> Result<(), String> {
+pub fn start_recording(app: tauri::AppHandle) -> Result<(), String> {
     #[cfg(not(target_os = "windows"))]
     {
         return Err("Recording is only supported on Windows".to_string());
@@ -51,6 +87,7 @@ pub fn start_recording() -> Result<(), String> {
         // Install Windows hooks with shared state (clone Arc to avoid move)
         hooks::windows::install_hooks(state.clone())?;
 
+        let _ = app.emit(RECORDING_STARTED_EVENT, ());
         Ok(())
     }
 }
@@ -108,7 +145,16 @@ pub fn stop_recording(app: tauri::AppHandle) -> Result<String, String> {
             .map_err(|e| format!("Failed to write recording file: {}", e))?;
 
         // Return relative path for display
-        Ok(format!("recordings/{}", filename))
+        let relative_path = format!("recordings/{}", filename);
+        let _ = app.emit(
+            RECORDING_STOPPED_EVENT,
+            RecordingStoppedPayload {
+                file_path: relative_path.clone(),
+                event_count: events.len(),
+                duration_ms,
+            },
+        );
+        Ok(relative_path)
     }
 }
 
@@ -226,8 +272,14 @@ fn extract_recording_meta(
     })
 }
 
+/// 回放录制；injection 为按键的输入方式（见 key_injection），中文输入法下可选择 Unicode 或切换英文布局
 #[tauri::command]
-pub fn play_recording(app: tauri::AppHandle, path: String, speed: f32) -> Result<(), String> {
+pub fn play_recording(
+    app: tauri::AppHandle,
+    path: String,
+    speed: f32,
+    injection: Option<InjectionMode>,
+) -> Result<(), String> {
     #[cfg(not(target_os = "windows"))]
     {
         return Err("Replay is only supported on Windows".to_string());
@@ -276,13 +328,30 @@ pub fn play_recording(app: tauri::AppHandle, path: String, speed: f32) -> Result
         }
 
         state.start(speed);
+        let total = state.current_events.len();
 
         // Start replay task in a separate thread (not async) since Windows API calls
         // should be done in a blocking context
         let replay_state = Arc::clone(&REPLAY_STATE);
         let speed_multiplier = speed.max(0.1).min(10.0); // Ensure speed is between 0.1 and 10.0
 
+        // 回放开始时已处于勿扰模式（如回放目标本身就是全屏程序）则不暂停
+        let focus_mode_at_start = crate::focus_mode::is_active();
+
+        let injection = injection.unwrap_or_default();
+
         std::thread::spawn(move || {
+            // 英文布局在整个回放期间保持，线程结束时恢复
+            let _layout = match injection {
+                InjectionMode::EnglishLayout => key_injection::platform::LayoutGuard::english()
+                    .map_err(|e| eprintln!("Failed to switch keyboard layout: {}", e))
+                    .ok(),
+                _ => None,
+            };
+            let mut unicode_keys = (injection == InjectionMode::Unicode).then(UnicodeKeys::new);
+            let mut reason = "finished";
+            let mut current = 0usize;
+            let mut last_percent = 0u32;
             let mut last_time = 0u64;
             let mut last_mouse_move_time = 0u64;
             let mut event_count = 0u64;
@@ -306,6 +375,7 @@ pub fn play_recording(app: tauri::AppHandle, path: String, speed: f32) -> Result
                             if let Ok(mut state) = replay_state.lock() {
                                 state.stop();
                             }
+                            reason = "escape";
                             break;
                         }
                     }
@@ -318,6 +388,7 @@ pub fn play_recording(app: tauri::AppHandle, path: String, speed: f32) -> Result
                     if let Ok(mut state) = replay_state.lock() {
                         state.stop();
                     }
+                    reason = "limit";
                     break;
                 }
 
@@ -325,22 +396,42 @@ pub fn play_recording(app: tauri::AppHandle, path: String, speed: f32) -> Result
                 let (event_opt, is_playing) = {
                     let mut state = match replay_state.lock() {
                         Ok(s) => s,
-                        Err(_) => break,
+                        Err(_) => {
+                            reason = "stopped";
+                            break;
+                        }
                     };
 
                     if !state.is_playing {
+                        reason = "stopped";
                         break;
                     }
 
                     let event = state.get_next_event();
+                    current = state.current_index;
                     let is_playing = state.is_playing;
                     (event, is_playing)
                 };
 
                 if !is_playing {
+                    reason = "stopped";
                     break;
                 }
 
+                // 进度按整数百分比节流，避免每个事件都发送一次
+                let percent = (current * 100 / total) as u32;
+                if percent != last_percent {
+                    last_percent = percent;
+                    let _ = app.emit(
+                        REPLAY_PROGRESS_EVENT,
+                        ReplayProgressPayload {
+                            progress: (current as f32 / total as f32) * 100.0,
+                            current,
+                            total,
+                        },
+                    );
+                }
+
                 if let Some(event) = event_opt {
                     // For mouse move events, only skip if the time difference from last mouse move
                     // is too small (based on recorded event times, not system time)
@@ -372,11 +463,37 @@ pub fn play_recording(app: tauri::AppHandle, path: String, speed: f32) -> Result
                         std::thread::sleep(Duration::from_millis(delay_ms));
                     }
 
+                    // 回放过程中进入勿扰模式（如开始全屏演示）时暂停，避免模拟输入打断前台程序
+                    while !focus_mode_at_start && crate::focus_mode::is_active() {
+                        let still_playing = replay_state.lock().map(|s| s.is_playing).unwrap_or(false);
+                        if !still_playing {
+                            break;
+                        }
+                        std::thread::sleep(Duration::from_millis(200));
+                    }
+
                     // Execute the event with error handling
-                    match crate::replay::ReplayState::execute_event(&event) {
+                    // Unicode 方式下按键交给 UnicodeKeys 转换，鼠标事件照常回放
+                    let result = match (unicode_keys.as_mut(), &event.event_type) {
+                        (Some(keys), crate::recording::EventType::KeyDown { vk_code }) if *vk_code <= 255 => {
+                            key_injection::platform::send_injected(&keys.on_key(*vk_code as u16, true))
+                        }
+                        (Some(keys), crate::recording::EventType::KeyUp { vk_code }) if *vk_code <= 255 => {
+                            key_injection::platform::send_injected(&keys.on_key(*vk_code as u16, false))
+                        }
+                        _ => crate::replay::ReplayState::execute_event(&event),
+                    };
+                    match result {
                         Ok(_) => {}
                         Err(e) => {
                             eprintln!("Failed to execute event: {}", e);
+                            let _ = app.emit(
+                                REPLAY_ERROR_EVENT,
+                                ReplayErrorPayload {
+                                    index: current.saturating_sub(1),
+                                    message: e,
+                                },
+                            );
                             // Continue with next event instead of crashing
                         }
                     }
@@ -390,6 +507,15 @@ pub fn play_recording(app: tauri::AppHandle, path: String, speed: f32) -> Result
                     break;
                 }
             }
+
+            let _ = app.emit(
+                REPLAY_COMPLETED_EVENT,
+                ReplayCompletedPayload {
+                    reason: reason.to_string(),
+                    executed: current,
+                    total,
+                },
+            );
         });
 
         Ok(())
@@ -0,0 +1,21 @@
+//! 提醒相关命令模块
+//! 
+//! 提供自然语言提醒的解析预览与创建，创建后的提醒与倒计时一起由计时调度触发
+
+use crate::reminders::{self, ReminderPreview};
+use crate::timers::{self, Timer};
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 解析提醒输入，返回内容、到期时间和循环规则，不创建
+#[tauri::command]
+pub fn preview_reminder(text: String) -> Result<ReminderPreview, String> {
+    reminders::preview(&text)
+}
+
+/// 按自然语言输入创建提醒，如 "明天上午9点提醒我交报告"；sound 默认开启
+#[tauri::command]
+pub fn create_reminder(app: AppHandle, text: String, sound: Option<bool>) -> Result<Timer, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    timers::create_reminder(&app, &app_data_dir, &text, sound.unwrap_or(true))
+}
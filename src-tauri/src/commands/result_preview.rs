@@ -0,0 +1,36 @@
+//! 搜索结果预览相关命令模块
+//! 
+//! 选中搜索结果时按需获取文件、图片、单词的预览信息
+
+use crate::result_preview::{self, ResultPreview};
+use super::get_app_data_dir;
+use std::path::PathBuf;
+use tauri::{async_runtime, AppHandle};
+
+/// 获取结果预览；kind 为 "path"（文件、文件夹、图片）或 "word"（单词本），没有可预览内容时返回 None
+#[tauri::command]
+pub async fn get_result_preview(
+    kind: String,
+    target: String,
+    app: AppHandle,
+) -> Result<Option<ResultPreview>, String> {
+    match kind.as_str() {
+        "path" => {
+            let path = PathBuf::from(target);
+            async_runtime::spawn_blocking(move || {
+                if path.exists() {
+                    result_preview::preview_path(&path).map(Some)
+                } else {
+                    Ok(None)
+                }
+            })
+            .await
+            .map_err(|e| format!("Failed to join preview task: {}", e))?
+        }
+        "word" => {
+            let app_data_dir = get_app_data_dir(&app)?;
+            result_preview::preview_word(&target, &app_data_dir)
+        }
+        _ => Err(format!("Unknown preview kind: {}", kind)),
+    }
+}
@@ -0,0 +1,65 @@
+//! RSS 订阅相关命令模块
+//! 
+//! 提供订阅的添加、删除、列出，条目查询与标记已读，以及手动刷新
+
+use crate::rss::{self, Feed, FeedItem};
+use super::get_app_data_dir;
+use tauri::{AppHandle, Emitter};
+
+const DEFAULT_ITEM_LIMIT: usize = 50;
+
+/// 获取所有订阅及未读数
+#[tauri::command]
+pub fn list_rss_feeds(app: AppHandle) -> Result<Vec<Feed>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    rss::list_feeds(&app_data_dir)
+}
+
+/// 添加订阅（会先抓取一次验证地址）
+#[tauri::command]
+pub async fn add_rss_feed(app: AppHandle, url: String) -> Result<Feed, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    rss::add_feed(&app_data_dir, &url).await
+}
+
+/// 删除订阅及其条目
+#[tauri::command]
+pub fn remove_rss_feed(app: AppHandle, id: i64) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    rss::remove_feed(&app_data_dir, id)
+}
+
+/// 按标题或订阅名筛选条目，默认只返回未读
+#[tauri::command]
+pub fn get_rss_items(
+    app: AppHandle,
+    query: Option<String>,
+    unread_only: Option<bool>,
+    limit: Option<usize>,
+) -> Result<Vec<FeedItem>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    rss::list_items(
+        &app_data_dir,
+        query.as_deref().unwrap_or(""),
+        unread_only.unwrap_or(true),
+        limit.unwrap_or(DEFAULT_ITEM_LIMIT),
+    )
+}
+
+/// 标记条目为已读
+#[tauri::command]
+pub fn mark_rss_items_read(app: AppHandle, ids: Vec<i64>) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    rss::mark_read(&app_data_dir, &ids)
+}
+
+/// 立即抓取所有订阅，返回新增条目数
+#[tauri::command]
+pub async fn refresh_rss_feeds(app: AppHandle) -> Result<usize, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let inserted = rss::refresh_all(&app_data_dir).await?;
+    if inserted > 0 {
+        let _ = app.emit("rss-updated", inserted);
+    }
+    Ok(inserted)
+}
@@ -0,0 +1,30 @@
+//! 屏幕标尺相关命令模块
+//! 
+//! 提供鼠标坐标、所在窗口与显示器 DPI 的查询，两点测距，以及实时坐标检查的开启与停止
+
+use crate::screen_ruler::{self, CursorInfo, Measurement};
+use tauri::AppHandle;
+
+/// 获取当前鼠标位置、所在窗口与显示器信息
+#[tauri::command]
+pub fn get_cursor_info() -> Result<CursorInfo, String> {
+    screen_ruler::cursor_info()
+}
+
+/// 测量两点间距离（屏幕物理像素坐标），同时给出按起点显示器缩放换算的逻辑尺寸
+#[tauri::command]
+pub fn measure_screen_distance(from_x: i32, from_y: i32, to_x: i32, to_y: i32) -> Result<Measurement, String> {
+    screen_ruler::measure((from_x, from_y), (to_x, to_y))
+}
+
+/// 开启实时坐标检查，按间隔推送 cursor-inspector 事件
+#[tauri::command]
+pub fn start_cursor_inspector(app: AppHandle, interval_ms: Option<u64>) -> Result<(), String> {
+    screen_ruler::start_inspector(app, interval_ms)
+}
+
+/// 停止实时坐标检查
+#[tauri::command]
+pub fn stop_cursor_inspector() -> bool {
+    screen_ruler::stop_inspector()
+}
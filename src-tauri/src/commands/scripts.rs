@@ -0,0 +1,61 @@
+//! 自动化脚本相关命令模块
+//! 
+//! 提供脚本列表、运行、新建、打开脚本文件夹与确认网络白名单
+
+use crate::scripts::{self, ScriptInfo, ScriptRunResult};
+use crate::settings;
+use super::get_app_data_dir;
+use std::collections::HashMap;
+use tauri::AppHandle;
+
+/// 列出脚本文件夹中的脚本（含语法错误信息）
+#[tauri::command]
+pub fn list_scripts() -> Result<Vec<ScriptInfo>, String> {
+    Ok(scripts::list_scripts())
+}
+
+/// 运行脚本，input 为启动器中关键字之后的文本
+#[tauri::command]
+pub async fn run_script(id: String, input: Option<String>) -> Result<ScriptRunResult, String> {
+    tauri::async_runtime::spawn_blocking(move || scripts::run_script(&id, input.as_deref().unwrap_or_default()))
+        .await
+        .map_err(|e| format!("run_script join error: {}", e))?
+}
+
+/// 新建脚本模板并返回文件路径
+#[tauri::command]
+pub fn create_script(app: AppHandle, name: String, keyword: Option<String>) -> Result<String, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let path = scripts::create_script(&app_data_dir, &name, keyword.as_deref())?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// 获取用户已确认的脚本网络白名单（脚本 id -> 主机）
+#[tauri::command]
+pub fn get_script_http_allow(app: AppHandle) -> Result<HashMap<String, Vec<String>>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    Ok(settings::load_settings(&app_data_dir)?.script_http_allow)
+}
+
+/// 确认脚本可以访问的主机，传空列表表示撤销
+#[tauri::command]
+pub fn set_script_http_allow(app: AppHandle, id: String, hosts: Vec<String>) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let mut settings = settings::load_settings(&app_data_dir)?;
+    let hosts = scripts::normalize_hosts(hosts.iter().map(String::as_str));
+    if hosts.is_empty() {
+        settings.script_http_allow.remove(&id);
+    } else {
+        settings.script_http_allow.insert(id, hosts);
+    }
+    settings::save_settings(&app_data_dir, &settings)
+}
+
+/// 在资源管理器中打开脚本文件夹
+#[tauri::command]
+pub fn open_scripts_folder(app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let dir = scripts::scripts_dir(&app_data_dir);
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create scripts dir: {}", e))?;
+    crate::file_history::launch_file(&dir.to_string_lossy())
+}
@@ -0,0 +1,31 @@
+//! SSH 主机相关命令模块
+//! 
+//! 提供 ~/.ssh/config 与 known_hosts 中主机的搜索与连接
+
+use crate::ssh_hosts;
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 获取所有 SSH 主机
+#[tauri::command]
+pub fn get_ssh_hosts(app: AppHandle) -> Result<Vec<ssh_hosts::SshHost>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    ssh_hosts::get_ssh_hosts(&app_data_dir)
+}
+
+/// 搜索 SSH 主机（启动器数据源停用时返回空列表）
+#[tauri::command]
+pub fn search_ssh_hosts(query: String, app: AppHandle) -> Result<Vec<ssh_hosts::SshHost>, String> {
+    if !crate::search_providers::is_enabled("ssh_hosts") {
+        return Ok(Vec::new());
+    }
+    let app_data_dir = get_app_data_dir(&app)?;
+    ssh_hosts::search_ssh_hosts(&query, &app_data_dir)
+}
+
+/// 在终端中连接 SSH 主机
+#[tauri::command]
+pub fn connect_ssh_host(host: String, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    ssh_hosts::connect_ssh_host(&host, &app_data_dir)
+}
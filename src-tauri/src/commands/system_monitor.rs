@@ -0,0 +1,14 @@
+//! 系统监控相关命令模块
+//! 
+//! 提供 CPU、内存、磁盘与电池状态查询
+
+use crate::system_monitor;
+use tauri::async_runtime;
+
+/// 获取当前系统状态与最近的 CPU/内存历史（前端展示期间定期调用）
+#[tauri::command]
+pub async fn get_system_stats() -> Result<system_monitor::SystemStats, String> {
+    async_runtime::spawn_blocking(system_monitor::stats)
+        .await
+        .map_err(|e| format!("get_system_stats join error: {}", e))
+}
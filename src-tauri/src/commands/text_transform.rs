@@ -0,0 +1,31 @@
+//! 文本转换相关命令模块
+//! 
+//! 提供对剪贴板文本或前台窗口选中文本的大小写、命名风格、JSON 与按行处理等转换
+
+use crate::text_transform;
+use super::hide_launcher;
+use std::time::Duration;
+use tauri::{async_runtime, AppHandle};
+
+/// 隐藏启动器后等待系统把焦点还给之前的窗口
+const FOCUS_RESTORE_DELAY: Duration = Duration::from_millis(150);
+
+/// 执行文本转换并返回结果；source 为 "selection" 时先隐藏启动器，转换前台窗口中选中的文本并粘贴替换，
+/// 其他情况转换剪贴板文本并写回剪贴板
+#[tauri::command]
+pub async fn transform_text(app: AppHandle, action: String, source: String) -> Result<String, String> {
+    let from_selection = source == "selection";
+    if from_selection {
+        hide_launcher(app)?;
+    }
+    async_runtime::spawn_blocking(move || {
+        if from_selection {
+            std::thread::sleep(FOCUS_RESTORE_DELAY);
+            text_transform::transform_selection(&action)
+        } else {
+            text_transform::transform_clipboard(&action)
+        }
+    })
+    .await
+    .map_err(|e| format!("transform_text join error: {}", e))?
+}
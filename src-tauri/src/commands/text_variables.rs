@@ -0,0 +1,27 @@
+//! 文本扩展变量相关命令模块
+//! 
+//! 提供模板变量的展开（片段、快捷链接共用）以及可用变量列表
+
+use crate::text_variables::{self, RenderOptions, TextVariable};
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 展开文本中的变量；url_encode 为 true 时对替换的值做 URL 编码
+#[tauri::command]
+pub fn render_text_variables(
+    app: AppHandle,
+    text: String,
+    query: Option<String>,
+    url_encode: Option<bool>,
+) -> Result<String, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let options = RenderOptions { query, url_encode: url_encode.unwrap_or(false) };
+    text_variables::render(&app_data_dir, &text, &options)
+}
+
+/// 获取所有可用变量（内置与自定义）
+#[tauri::command]
+pub fn list_text_variables(app: AppHandle) -> Result<Vec<TextVariable>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    text_variables::list_variables(&app_data_dir)
+}
@@ -0,0 +1,48 @@
+//! 主题相关命令模块
+//! 
+//! 提供内置 / 自定义主题的查询、切换、保存与删除
+
+use crate::themes;
+use super::get_app_data_dir;
+use tauri::{AppHandle, Emitter};
+
+/// 获取所有主题（内置 + 自定义）
+#[tauri::command]
+pub fn list_themes(app: AppHandle) -> Result<Vec<themes::Theme>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    themes::list_themes(&app_data_dir)
+}
+
+/// 获取当前生效的主题及系统深色模式状态
+#[tauri::command]
+pub fn get_theme(app: AppHandle) -> Result<themes::ThemeState, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    themes::get_theme(&app_data_dir)
+}
+
+/// 切换主题（"system" 表示跟随系统），并通知所有窗口
+#[tauri::command]
+pub fn set_theme(id: String, app: AppHandle) -> Result<themes::ThemeState, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let state = themes::set_theme(&id, &app_data_dir)?;
+    let _ = app.emit("theme-changed", &state);
+    Ok(state)
+}
+
+/// 保存自定义主题（JSON）
+#[tauri::command]
+pub fn save_custom_theme(json: String, app: AppHandle) -> Result<themes::Theme, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    themes::save_custom_theme(&json, &app_data_dir)
+}
+
+/// 删除自定义主题，删除的是当前主题时通知窗口切回跟随系统
+#[tauri::command]
+pub fn delete_custom_theme(id: String, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    themes::delete_custom_theme(&id, &app_data_dir)?;
+    if let Ok(state) = themes::get_theme(&app_data_dir) {
+        let _ = app.emit("theme-changed", &state);
+    }
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! 计时器相关命令模块
+//! 
+//! 提供倒计时与闹钟的创建、列出和取消，到期提醒由后台调度线程负责
+
+use crate::timers::{self, Timer, TimerKind};
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 列出所有待触发的倒计时和闹钟
+#[tauri::command]
+pub fn list_timers(app: AppHandle) -> Result<Vec<Timer>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    timers::list_timers(&app_data_dir)
+}
+
+/// 创建倒计时（spec 为时长，如 "10m"）或闹钟（spec 为时刻，如 "7:30"）；sound 默认开启
+#[tauri::command]
+pub fn create_timer(
+    app: AppHandle,
+    kind: TimerKind,
+    spec: String,
+    label: Option<String>,
+    sound: Option<bool>,
+) -> Result<Timer, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    timers::create_timer(&app, &app_data_dir, kind, &spec, label.as_deref(), sound.unwrap_or(true))
+}
+
+/// 取消倒计时或闹钟
+#[tauri::command]
+pub fn cancel_timer(app: AppHandle, id: i64) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    timers::cancel_timer(&app, &app_data_dir, id)
+}
@@ -0,0 +1,46 @@
+//! TOTP 验证码相关命令模块
+//! 
+//! 提供两步验证账户的添加、删除、列出，以及当前验证码的查询与复制
+
+use crate::clipboard;
+use crate::totp::{self, NewTotpAccount, TotpAccount, TotpCode};
+use super::get_app_data_dir;
+use std::time::Duration;
+use tauri::AppHandle;
+
+/// 获取所有 TOTP 账户（不含密钥）
+#[tauri::command]
+pub fn list_totp_accounts(app: AppHandle) -> Result<Vec<TotpAccount>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    totp::list_accounts(&app_data_dir)
+}
+
+/// 添加 TOTP 账户，密钥加密后保存
+#[tauri::command]
+pub fn add_totp_account(account: NewTotpAccount, app: AppHandle) -> Result<TotpAccount, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    totp::add_account(&app_data_dir, account)
+}
+
+/// 删除 TOTP 账户
+#[tauri::command]
+pub fn remove_totp_account(id: String, app: AppHandle) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    totp::remove_account(&app_data_dir, &id)
+}
+
+/// 按账户名筛选并获取当前验证码及剩余秒数
+#[tauri::command]
+pub fn get_totp_codes(query: Option<String>, app: AppHandle) -> Result<Vec<TotpCode>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    totp::current_codes(&app_data_dir, query.as_deref().unwrap_or(""))
+}
+
+/// 复制账户的当前验证码：以敏感内容写入剪贴板，验证码过期时自动清除
+#[tauri::command]
+pub fn copy_totp_code(id: String, app: AppHandle) -> Result<TotpCode, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let code = totp::code_for(&app_data_dir, &id)?;
+    clipboard::write_sensitive_text(&code.code, Duration::from_secs(code.remaining))?;
+    Ok(code)
+}
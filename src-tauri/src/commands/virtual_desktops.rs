@@ -0,0 +1,43 @@
+//! 虚拟桌面相关命令模块
+//! 
+//! 提供虚拟桌面的列出、切换，以及把前台窗口移动到指定桌面
+
+use crate::virtual_desktops::{self, VirtualDesktop};
+use super::hide_launcher;
+use std::time::Duration;
+use tauri::{async_runtime, AppHandle};
+
+/// 隐藏启动器后等待系统把焦点还给之前的窗口
+const FOCUS_RESTORE_DELAY: Duration = Duration::from_millis(150);
+
+/// 按顺序列出虚拟桌面，并标记当前桌面
+#[tauri::command]
+pub async fn list_virtual_desktops() -> Result<Vec<VirtualDesktop>, String> {
+    async_runtime::spawn_blocking(virtual_desktops::list)
+        .await
+        .map_err(|e| format!("list_virtual_desktops join error: {}", e))
+}
+
+/// 切换到第 index 个虚拟桌面（从 0 开始），切换前隐藏启动器
+#[tauri::command]
+pub async fn switch_virtual_desktop(app: AppHandle, index: usize) -> Result<(), String> {
+    hide_launcher(app)?;
+    async_runtime::spawn_blocking(move || {
+        std::thread::sleep(FOCUS_RESTORE_DELAY);
+        virtual_desktops::switch_to(index)
+    })
+    .await
+    .map_err(|e| format!("switch_virtual_desktop join error: {}", e))?
+}
+
+/// 把启动器打开前的前台窗口移到第 index 个虚拟桌面，并切换过去
+#[tauri::command]
+pub async fn move_window_to_virtual_desktop(app: AppHandle, index: usize) -> Result<(), String> {
+    hide_launcher(app)?;
+    async_runtime::spawn_blocking(move || {
+        std::thread::sleep(FOCUS_RESTORE_DELAY);
+        virtual_desktops::move_foreground_window(index)
+    })
+    .await
+    .map_err(|e| format!("move_window_to_virtual_desktop join error: {}", e))?
+}
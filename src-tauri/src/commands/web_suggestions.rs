@@ -0,0 +1,28 @@
+//! 网页搜索联想相关命令模块
+//! 
+//! 提供搜索引擎前缀查询的联想词（Google / 百度 / 必应）
+
+use crate::settings;
+use crate::web_suggestions;
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 获取指定搜索引擎前缀下关键词的联想词
+#[tauri::command]
+pub async fn get_web_suggestions(
+    prefix: String,
+    keyword: String,
+    app: AppHandle,
+) -> Result<Vec<String>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let settings = settings::load_settings(&app_data_dir)?;
+    if !settings.web_suggestions_enabled {
+        return Ok(Vec::new());
+    }
+
+    let engine = match settings.search_engines.iter().find(|e| e.prefix == prefix) {
+        Some(engine) => engine,
+        None => return Ok(Vec::new()),
+    };
+    web_suggestions::fetch_suggestions(engine, &keyword).await
+}
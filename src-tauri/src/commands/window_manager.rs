@@ -0,0 +1,95 @@
+//! 窗口管理相关命令模块
+//! 
+//! 提供对前台窗口的贴靠、跨显示器移动、最大化切换、居中与最小化全部，以及窗口管理快捷键的读取和保存
+
+use crate::settings::{self, HotkeyConfig};
+use crate::window_manager;
+use super::{get_app_data_dir, hide_launcher};
+use std::collections::HashMap;
+use std::time::Duration;
+use tauri::{async_runtime, AppHandle};
+
+#[cfg(target_os = "windows")]
+use tauri::Emitter;
+
+/// 隐藏启动器后等待系统把焦点还给之前的窗口
+const FOCUS_RESTORE_DELAY: Duration = Duration::from_millis(150);
+
+fn same_hotkey(a: &HotkeyConfig, b: &HotkeyConfig) -> bool {
+    let mut a_mods = a.modifiers.clone();
+    a_mods.sort();
+    let mut b_mods = b.modifiers.clone();
+    b_mods.sort();
+    a_mods == b_mods && a.key == b.key
+}
+
+/// 在启动器中执行窗口管理动作：先隐藏启动器，再作用于焦点回到的窗口
+#[tauri::command]
+pub async fn run_window_action(app: AppHandle, action: String) -> Result<(), String> {
+    hide_launcher(app)?;
+    async_runtime::spawn_blocking(move || {
+        std::thread::sleep(FOCUS_RESTORE_DELAY);
+        window_manager::perform(&action)
+    })
+    .await
+    .map_err(|e| format!("run_window_action join error: {}", e))?
+}
+
+/// 获取窗口管理快捷键（动作 id -> 快捷键）
+#[tauri::command]
+pub fn get_window_hotkeys(app: AppHandle) -> Result<HashMap<String, HotkeyConfig>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    Ok(settings::load_settings(&app_data_dir)?.window_hotkeys)
+}
+
+/// 设置或清除某个窗口管理动作的快捷键
+#[tauri::command]
+pub fn save_window_hotkey(app: AppHandle, action: String, config: Option<HotkeyConfig>) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let mut settings = settings::load_settings(&app_data_dir)?;
+
+    if let Some(ref new_hotkey) = config {
+        let conflict = if settings.hotkey.as_ref().is_some_and(|h| same_hotkey(h, new_hotkey)) {
+            Some("启动器快捷键".to_string())
+        } else if settings.app_center_hotkey.as_ref().is_some_and(|h| same_hotkey(h, new_hotkey)) {
+            Some("应用中心快捷键".to_string())
+        } else if let Some((id, _)) = settings.plugin_hotkeys.iter().find(|(_, h)| same_hotkey(h, new_hotkey)) {
+            Some(format!("插件 \"{}\" 的快捷键", id))
+        } else if let Some((path, _)) = settings.app_hotkeys.iter().find(|(_, h)| same_hotkey(h, new_hotkey)) {
+            Some(format!("应用 \"{}\" 的快捷键", path.rsplit(['/', '\\']).next().unwrap_or(path)))
+        } else {
+            settings
+                .window_hotkeys
+                .iter()
+                .find(|(other, h)| **other != action && same_hotkey(h, new_hotkey))
+                .map(|(other, _)| format!("窗口动作 \"{}\" 的快捷键", other))
+        };
+        if let Some(conflict) = conflict {
+            return Err(format!("快捷键冲突：与{}相同，请修改后再保存", conflict));
+        }
+    }
+
+    match config.clone() {
+        Some(hotkey) => settings.window_hotkeys.insert(action.clone(), hotkey),
+        None => settings.window_hotkeys.remove(&action),
+    };
+    settings::save_settings(&app_data_dir, &settings)?;
+
+    // 更新后端快捷键注册（使用 "window:" 前缀来区分窗口管理快捷键）
+    #[cfg(target_os = "windows")]
+    {
+        let hotkey_id = format!("window:{}", action);
+        let result = match config {
+            Some(hotkey) => crate::hotkey_handler::windows::register_plugin_hotkey(hotkey_id, hotkey),
+            None => crate::hotkey_handler::windows::unregister_plugin_hotkey(&hotkey_id),
+        };
+        if let Err(e) = result {
+            eprintln!("Failed to update window hotkey: {}", e);
+        }
+        if let Err(e) = app.emit("window-hotkeys-updated", settings.window_hotkeys.clone()) {
+            eprintln!("Failed to emit window-hotkeys-updated event: {}", e);
+        }
+    }
+
+    Ok(())
+}
@@ -2,9 +2,10 @@
 //! 
 //! 提供单词记录的 CRUD 操作
 
+use crate::db;
 use crate::word_records;
 use super::get_app_data_dir;
-use tauri::AppHandle;
+use tauri::{async_runtime, AppHandle};
 
 /// 获取所有单词记录
 #[tauri::command]
@@ -25,7 +26,7 @@ pub fn add_word_record(
     app: AppHandle,
 ) -> Result<word_records::WordRecord, String> {
     let app_data_dir = get_app_data_dir(&app)?;
-    word_records::add_word_record(
+    let record = word_records::add_word_record(
         word,
         translation,
         context,
@@ -33,7 +34,9 @@ pub fn add_word_record(
         example_sentence,
         tags,
         &app_data_dir,
-    )
+    )?;
+    word_records::notify_change(&app, word_records::CREATED_EVENT, &record);
+    Ok(record)
 }
 
 /// 更新单词记录
@@ -53,7 +56,7 @@ pub fn update_word_record(
     app: AppHandle,
 ) -> Result<word_records::WordRecord, String> {
     let app_data_dir = get_app_data_dir(&app)?;
-    word_records::update_word_record(
+    let record = word_records::update_word_record(
         id,
         word,
         translation,
@@ -66,14 +69,18 @@ pub fn update_word_record(
         is_favorite,
         is_mastered,
         &app_data_dir,
-    )
+    )?;
+    word_records::notify_change(&app, word_records::UPDATED_EVENT, &record);
+    Ok(record)
 }
 
 /// 删除单词记录
 #[tauri::command]
 pub fn delete_word_record(id: String, app: AppHandle) -> Result<(), String> {
     let app_data_dir = get_app_data_dir(&app)?;
-    word_records::delete_word_record(id, &app_data_dir)
+    let record = word_records::delete_word_record(id, &app_data_dir)?;
+    word_records::notify_change(&app, word_records::DELETED_EVENT, &record);
+    Ok(())
 }
 
 /// 搜索单词记录
@@ -82,3 +89,18 @@ pub fn search_word_records(query: String, app: AppHandle) -> Result<Vec<word_rec
     let app_data_dir = get_app_data_dir(&app)?;
     word_records::search_word_records(&query, &app_data_dir)
 }
+
+/// 批量导入单词记录（单个事务，mode 默认跳过已存在的单词）
+#[tauri::command]
+pub async fn import_word_records(
+    records: Vec<word_records::WordRecord>,
+    mode: Option<db::ConflictMode>,
+    app: AppHandle,
+) -> Result<db::BatchInsertResult, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    async_runtime::spawn_blocking(move || {
+        word_records::insert_word_records(&records, mode.unwrap_or_default(), &app_data_dir)
+    })
+    .await
+    .map_err(|e| format!("import_word_records join error: {}", e))?
+}
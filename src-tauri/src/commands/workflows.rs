@@ -0,0 +1,72 @@
+//! 工作流相关命令模块
+//!
+//! 提供工作流的增删改查、运行与导入导出，变更后通知启动器刷新关键字
+
+use crate::workflows::{self, Workflow, WorkflowImportResult, WorkflowRunResult};
+use super::get_app_data_dir;
+use std::fs;
+use tauri::{AppHandle, Emitter};
+
+fn notify_changed(app: &AppHandle) {
+    let Ok(app_data_dir) = get_app_data_dir(app) else {
+        return;
+    };
+    match workflows::list_workflows(&app_data_dir) {
+        Ok(list) => {
+            let _ = app.emit(workflows::CHANGED_EVENT, list);
+        }
+        Err(e) => eprintln!("[Workflows] Failed to list workflows: {}", e),
+    }
+}
+
+#[tauri::command]
+pub fn list_workflows(app: AppHandle) -> Result<Vec<Workflow>, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    workflows::list_workflows(&app_data_dir)
+}
+
+/// 保存工作流（id 为空时新建）
+#[tauri::command]
+pub fn save_workflow(app: AppHandle, workflow: Workflow) -> Result<Workflow, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let saved = workflows::save_workflow(&app_data_dir, workflow)?;
+    notify_changed(&app);
+    Ok(saved)
+}
+
+#[tauri::command]
+pub fn delete_workflow(app: AppHandle, id: String) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    workflows::delete_workflow(&app_data_dir, &id)?;
+    notify_changed(&app);
+    Ok(())
+}
+
+/// 运行工作流，input 为启动器中关键字之后的文本
+#[tauri::command]
+pub async fn run_workflow(app: AppHandle, id: String, input: Option<String>) -> Result<WorkflowRunResult, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        workflows::run_workflow(&app, &app_data_dir, &id, input.as_deref().unwrap_or_default())
+    })
+    .await
+    .map_err(|e| format!("run_workflow join error: {}", e))?
+}
+
+/// 把工作流导出为 JSON 文件；ids 为空时导出全部
+#[tauri::command]
+pub fn export_workflows(app: AppHandle, path: String, ids: Option<Vec<String>>) -> Result<(), String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let json = workflows::export_workflows(&app_data_dir, &ids.unwrap_or_default())?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write {}: {}", path, e))
+}
+
+/// 从 JSON 文件导入工作流
+#[tauri::command]
+pub fn import_workflows(app: AppHandle, path: String) -> Result<WorkflowImportResult, String> {
+    let app_data_dir = get_app_data_dir(&app)?;
+    let json = fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let result = workflows::import_workflows(&app_data_dir, &json)?;
+    notify_changed(&app);
+    Ok(result)
+}
@@ -0,0 +1,24 @@
+//! 世界时钟相关命令模块
+//! 
+//! 提供城市当前时间查询与跨时区时间换算，未指定城市时返回设置中的常用城市
+
+use crate::settings;
+use crate::world_clock::{self, ClockEntry, TimeConversion};
+use super::get_app_data_dir;
+use tauri::AppHandle;
+
+/// 获取城市的当前时间；places 为空时返回设置中的常用城市
+#[tauri::command]
+pub fn get_world_clocks(app: AppHandle, places: Option<Vec<String>>) -> Result<Vec<ClockEntry>, String> {
+    let places = match places {
+        Some(places) => places,
+        None => settings::load_settings(&get_app_data_dir(&app)?)?.world_clock_cities,
+    };
+    world_clock::world_clocks(&places)
+}
+
+/// 把源时区今天的某个时间换算到一个或多个目标时区
+#[tauri::command]
+pub fn convert_world_time(time: String, from: String, to: Vec<String>) -> Result<TimeConversion, String> {
+    world_clock::convert(&time, &from, &to)
+}
@@ -0,0 +1,350 @@
+// 崩溃处理：panic hook + Windows 未处理异常过滤器，崩溃时把报告（堆栈、minidump、最近日志、版本）写入应用数据目录
+// 用户可以一键把崩溃报告和日志打包成 zip，附加到 issue 中
+
+use crate::logger;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+// 报告中附带的日志尾部大小
+const RECENT_LOG_BYTES: u64 = 64 * 1024;
+// 打包时附带的最近日志文件个数
+const BUNDLED_LOG_FILES: usize = 3;
+// 保留的崩溃报告个数（报告 + dump 按时间戳成对保存）
+const MAX_CRASH_REPORTS: usize = 10;
+
+static CRASH_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReportInfo {
+    pub name: String,
+    pub path: String,
+    pub created_at: i64,
+    pub has_minidump: bool,
+}
+
+pub fn get_crash_dir(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("crashes")
+}
+
+/// 安装 panic hook 和未处理异常过滤器（应尽早调用）
+pub fn install(app_data_dir: &Path) {
+    let dir = get_crash_dir(app_data_dir);
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("[Crash] Failed to create crash directory: {}", e);
+    }
+    if CRASH_DIR.set(dir).is_err() {
+        return; // 已安装
+    }
+    if let Err(e) = prune_crash_reports(app_data_dir) {
+        eprintln!("[Crash] Failed to prune crash reports: {}", e);
+    }
+
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let message = if let Some(s) = info.payload().downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = info.payload().downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "unknown panic payload".to_string()
+        };
+        let location = info
+            .location()
+            .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+            .unwrap_or_else(|| "unknown".to_string());
+        let thread = std::thread::current();
+        let summary = format!(
+            "Panic in thread '{}' at {}\n{}",
+            thread.name().unwrap_or("<unnamed>"),
+            location,
+            message
+        );
+        let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+
+        let stem = report_stem();
+        if let Some(path) = write_report(&stem, "panic", &summary, &backtrace) {
+            eprintln!("[Crash] Crash report written to {}", path.display());
+        }
+        #[cfg(target_os = "windows")]
+        windows::write_minidump(&stem, std::ptr::null());
+
+        previous(info);
+    }));
+
+    #[cfg(target_os = "windows")]
+    windows::install_exception_filter();
+}
+
+fn report_stem() -> String {
+    chrono::Local::now().format("crash-%Y%m%d-%H%M%S").to_string()
+}
+
+/// 读取最新日志文件的尾部；崩溃时可能持有日志锁，所以直接读日志目录而不经过 logger 的状态
+fn recent_log_tail() -> String {
+    let Some(path) = recent_log_files(1).into_iter().next() else {
+        return "(no log file)".to_string();
+    };
+    let read = || -> std::io::Result<String> {
+        let mut file = fs::File::open(&path)?;
+        let len = file.metadata()?.len();
+        file.seek(SeekFrom::Start(len.saturating_sub(RECENT_LOG_BYTES)))?;
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    };
+    match read() {
+        Ok(tail) => format!("--- {} ---\n{}", path.display(), tail),
+        Err(e) => format!("(failed to read {}: {})", path.display(), e),
+    }
+}
+
+fn recent_log_files(limit: usize) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(logger::get_log_dir()) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = entries
+        .flatten()
+        .filter(|e| e.path().extension().map(|ext| ext == "log").unwrap_or(false))
+        .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+        .collect();
+    files.sort_by_key(|f| std::cmp::Reverse(f.0));
+    files.into_iter().take(limit).map(|(_, p)| p).collect()
+}
+
+fn system_info() -> String {
+    format!(
+        "App: {} {}\nOS: {} {}\nTime: {}\n",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        chrono::Local::now().to_rfc3339()
+    )
+}
+
+/// 写入崩溃报告文本，返回报告路径
+fn write_report(stem: &str, kind: &str, summary: &str, stack: &str) -> Option<PathBuf> {
+    write_report_in(CRASH_DIR.get()?, stem, kind, summary, stack)
+}
+
+fn write_report_in(dir: &Path, stem: &str, kind: &str, summary: &str, stack: &str) -> Option<PathBuf> {
+    let path = dir.join(format!("{}.txt", stem));
+    let content = format!(
+        "Kind: {}\n{}\n{}\n\n--- Stack ---\n{}\n\n--- Recent log ---\n{}\n",
+        kind,
+        system_info(),
+        summary,
+        stack,
+        recent_log_tail()
+    );
+    let mut file = fs::File::create(&path).ok()?;
+    file.write_all(content.as_bytes()).ok()?;
+    Some(path)
+}
+
+/// 列出已保存的崩溃报告（最新在前）
+pub fn list_crash_reports(app_data_dir: &Path) -> Result<Vec<CrashReportInfo>, String> {
+    let dir = get_crash_dir(app_data_dir);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let entries = fs::read_dir(&dir).map_err(|e| format!("Failed to read crash directory: {}", e))?;
+    let mut reports: Vec<CrashReportInfo> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().map(|ext| ext == "txt").unwrap_or(false))
+        .map(|path| {
+            let created_at = fs::metadata(&path)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or(0);
+            CrashReportInfo {
+                name: path.file_stem().unwrap_or_default().to_string_lossy().to_string(),
+                has_minidump: path.with_extension("dmp").exists(),
+                path: path.to_string_lossy().to_string(),
+                created_at,
+            }
+        })
+        .collect();
+    reports.sort_by_key(|r| std::cmp::Reverse(r.created_at));
+    Ok(reports)
+}
+
+/// 只保留最近的 MAX_CRASH_REPORTS 份报告
+pub fn prune_crash_reports(app_data_dir: &Path) -> Result<usize, String> {
+    let reports = list_crash_reports(app_data_dir)?;
+    let mut removed = 0;
+    for report in reports.iter().skip(MAX_CRASH_REPORTS) {
+        let path = PathBuf::from(&report.path);
+        let _ = fs::remove_file(path.with_extension("dmp"));
+        if fs::remove_file(&path).is_ok() {
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// 把崩溃报告、minidump、最近日志和系统信息打包成 zip，返回 zip 路径
+pub fn create_bug_report(app_data_dir: &Path) -> Result<PathBuf, String> {
+    let out_dir = app_data_dir.join("bug-reports");
+    fs::create_dir_all(&out_dir).map_err(|e| format!("Failed to create bug report directory: {}", e))?;
+    let zip_path = out_dir.join(format!(
+        "bug-report-{}.zip",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+
+    let file = fs::File::create(&zip_path).map_err(|e| format!("Failed to create bug report: {}", e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let add_entry = |zip: &mut zip::ZipWriter<fs::File>, name: String, data: &[u8]| -> Result<(), String> {
+        zip.start_file(name, options)
+            .and_then(|_| zip.write_all(data).map_err(Into::into))
+            .map_err(|e| format!("Failed to write bug report: {}", e))
+    };
+    let add_file = |zip: &mut zip::ZipWriter<fs::File>, name: String, path: &Path| -> Result<(), String> {
+        let data = fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        add_entry(zip, name, &data)
+    };
+
+    add_entry(&mut zip, "system-info.txt".to_string(), system_info().as_bytes())?;
+
+    for report in list_crash_reports(app_data_dir)?.iter().take(MAX_CRASH_REPORTS) {
+        let path = PathBuf::from(&report.path);
+        add_file(&mut zip, format!("crashes/{}.txt", report.name), &path)?;
+        if report.has_minidump {
+            add_file(&mut zip, format!("crashes/{}.dmp", report.name), &path.with_extension("dmp"))?;
+        }
+    }
+
+    for path in recent_log_files(BUNDLED_LOG_FILES) {
+        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        add_file(&mut zip, format!("logs/{}", name), &path)?;
+    }
+
+    zip.finish().map_err(|e| format!("Failed to finish bug report: {}", e))?;
+    Ok(zip_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn temp_app_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("imiss-crash-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(get_crash_dir(&dir)).unwrap();
+        dir
+    }
+
+    #[test]
+    fn writes_report_with_summary_and_stack() {
+        let app_dir = temp_app_dir("write");
+        let crash_dir = get_crash_dir(&app_dir);
+        let path = write_report_in(&crash_dir, "crash-20240101-000000", "panic", "Panic in thread 'main'", "frame 0").unwrap();
+
+        assert_eq!(path, crash_dir.join("crash-20240101-000000.txt"));
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("Kind: panic\n"));
+        assert!(content.contains(&format!("App: {} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))));
+        assert!(content.contains("Panic in thread 'main'"));
+        assert!(content.contains("--- Stack ---\nframe 0"));
+        assert!(content.contains("--- Recent log ---"));
+
+        let reports = list_crash_reports(&app_dir).unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].name, "crash-20240101-000000");
+        assert!(!reports[0].has_minidump);
+        let _ = fs::remove_dir_all(&app_dir);
+    }
+
+    #[test]
+    fn prunes_oldest_reports_with_their_minidumps() {
+        let app_dir = temp_app_dir("prune");
+        let crash_dir = get_crash_dir(&app_dir);
+        let total = MAX_CRASH_REPORTS + 2;
+        for i in 0..total {
+            let path = write_report_in(&crash_dir, &format!("crash-{:02}", i), "panic", "summary", "stack").unwrap();
+            fs::write(path.with_extension("dmp"), b"dump").unwrap();
+            let modified = UNIX_EPOCH + Duration::from_secs(1_700_000_000 + i as u64);
+            fs::File::options().write(true).open(&path).unwrap().set_modified(modified).unwrap();
+        }
+
+        assert_eq!(prune_crash_reports(&app_dir).unwrap(), 2);
+        let reports = list_crash_reports(&app_dir).unwrap();
+        assert_eq!(reports.len(), MAX_CRASH_REPORTS);
+        assert_eq!(reports[0].name, format!("crash-{:02}", total - 1));
+        assert!(reports.iter().all(|r| r.has_minidump));
+        assert!(!crash_dir.join("crash-00.txt").exists());
+        assert!(!crash_dir.join("crash-00.dmp").exists());
+        assert!(!crash_dir.join("crash-01.dmp").exists());
+        assert_eq!(prune_crash_reports(&app_dir).unwrap(), 0);
+        let _ = fs::remove_dir_all(&app_dir);
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    use super::{report_stem, write_report, CRASH_DIR};
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::System::Diagnostics::Debug::{
+        MiniDumpWithThreadInfo, MiniDumpWriteDump, SetUnhandledExceptionFilter, EXCEPTION_POINTERS,
+        MINIDUMP_EXCEPTION_INFORMATION,
+    };
+    use windows_sys::Win32::System::Threading::{GetCurrentProcess, GetCurrentProcessId, GetCurrentThreadId};
+
+    // EXCEPTION_CONTINUE_SEARCH：交给系统默认处理（WER）
+    const EXCEPTION_CONTINUE_SEARCH: i32 = 0;
+
+    pub fn install_exception_filter() {
+        unsafe {
+            SetUnhandledExceptionFilter(Some(exception_filter));
+        }
+    }
+
+    unsafe extern "system" fn exception_filter(info: *const EXCEPTION_POINTERS) -> i32 {
+        let code = if !info.is_null() && !(*info).ExceptionRecord.is_null() {
+            (*(*info).ExceptionRecord).ExceptionCode
+        } else {
+            0
+        };
+        let summary = format!("Unhandled exception 0x{:08X}", code as u32);
+        let stack = std::backtrace::Backtrace::force_capture().to_string();
+        let stem = report_stem();
+        write_report(&stem, "exception", &summary, &stack);
+        write_minidump(&stem, info);
+        EXCEPTION_CONTINUE_SEARCH
+    }
+
+    /// 写入当前进程的 minidump；info 为空时（panic）不附带异常信息
+    pub fn write_minidump(stem: &str, info: *const EXCEPTION_POINTERS) {
+        let Some(dir) = CRASH_DIR.get() else {
+            return;
+        };
+        let Ok(file) = std::fs::File::create(dir.join(format!("{}.dmp", stem))) else {
+            return;
+        };
+        unsafe {
+            let exception = MINIDUMP_EXCEPTION_INFORMATION {
+                ThreadId: GetCurrentThreadId(),
+                ExceptionPointers: info as *mut _,
+                ClientPointers: 0,
+            };
+            MiniDumpWriteDump(
+                GetCurrentProcess(),
+                GetCurrentProcessId(),
+                file.as_raw_handle() as _,
+                MiniDumpWithThreadInfo,
+                if info.is_null() { std::ptr::null() } else { &exception },
+                std::ptr::null(),
+                std::ptr::null(),
+            );
+        }
+    }
+}
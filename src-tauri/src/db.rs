@@ -1,9 +1,34 @@
 use rusqlite::{Connection, OpenFlags};
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
 
 const DB_NAME: &str = "re-fast.db";
 const LEGACY_DB_NAME: &str = "data.db";
+const STATEMENT_CACHE_CAPACITY: usize = 64;
+
+/// How bulk inserts resolve rows that already exist.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictMode {
+    #[default]
+    Skip,      // 保留现有记录
+    Replace,   // 用导入的记录覆盖
+    KeepNewer, // 保留时间较新的一方
+}
+
+/// Result of a bulk insert.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchInsertResult {
+    pub inserted: usize,
+    pub updated: usize,
+    pub skipped: usize,
+}
+
+// 共享的读写连接：prepare_cached 的语句缓存挂在连接上，只有复用连接才能生效
+static SHARED_CONNECTION: LazyLock<Mutex<Option<(PathBuf, Connection)>>> =
+    LazyLock::new(|| Mutex::new(None));
 
 /// Database file path under the app data directory (new name).
 pub fn get_db_path(app_data_dir: &Path) -> PathBuf {
@@ -61,6 +86,70 @@ pub fn get_connection(app_data_dir: &Path) -> Result<Connection, String> {
     Ok(conn)
 }
 
+/// Run `f` on the shared long-lived connection (opened on first use).
+/// Hot queries should use `conn.prepare_cached` inside `f` so the SQL is only compiled once.
+/// 注意：f 内不要再调用 with_connection，也不要做耗时的文件操作，否则会阻塞其他数据库访问
+pub fn with_connection<T>(
+    app_data_dir: &Path,
+    f: impl FnOnce(&Connection) -> Result<T, String>,
+) -> Result<T, String> {
+    let mut shared = SHARED_CONNECTION
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if shared.as_ref().map(|(dir, _)| dir.as_path()) != Some(app_data_dir) {
+        let conn = get_connection(app_data_dir)?;
+        conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+        *shared = Some((app_data_dir.to_path_buf(), conn));
+    }
+
+    match shared.as_ref() {
+        Some((_, conn)) => f(conn),
+        None => Err("Failed to open shared database connection".to_string()),
+    }
+}
+
+/// Close the shared connection (e.g. before the database file is replaced by a backup restore).
+pub fn close_shared_connection() {
+    let mut shared = SHARED_CONNECTION
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *shared = None;
+}
+
+/// 剪切板全文索引：外部内容 FTS5 表（trigram 分词，支持中文与任意子串，忽略大小写），
+/// 以 rowid 关联 clipboard_history，由触发器保持同步
+const CLIPBOARD_FTS_SCHEMA: &str = r#"
+    CREATE VIRTUAL TABLE IF NOT EXISTS clipboard_fts USING fts5(
+        content, tags, ocr_text,
+        content = 'clipboard_history', content_rowid = 'rowid', tokenize = 'trigram'
+    );
+    CREATE TRIGGER IF NOT EXISTS clipboard_fts_ai AFTER INSERT ON clipboard_history BEGIN
+        INSERT INTO clipboard_fts(rowid, content, tags, ocr_text) VALUES (new.rowid, new.content, new.tags, new.ocr_text);
+    END;
+    CREATE TRIGGER IF NOT EXISTS clipboard_fts_ad AFTER DELETE ON clipboard_history BEGIN
+        INSERT INTO clipboard_fts(clipboard_fts, rowid, content, tags, ocr_text)
+            VALUES ('delete', old.rowid, old.content, old.tags, old.ocr_text);
+    END;
+    CREATE TRIGGER IF NOT EXISTS clipboard_fts_au AFTER UPDATE OF content, tags, ocr_text ON clipboard_history BEGIN
+        INSERT INTO clipboard_fts(clipboard_fts, rowid, content, tags, ocr_text)
+            VALUES ('delete', old.rowid, old.content, old.tags, old.ocr_text);
+        INSERT INTO clipboard_fts(rowid, content, tags, ocr_text) VALUES (new.rowid, new.content, new.tags, new.ocr_text);
+    END;
+"#;
+
+/// 数据库维护：更新查询统计、截断 WAL 并整理碎片（由空闲任务调用，VACUUM 在大库上可能耗时数秒）
+/// VACUUM 可能重新分配没有 INTEGER PRIMARY KEY 的表的 rowid，随后重建剪切板全文索引
+pub fn run_maintenance(app_data_dir: &Path) -> Result<(), String> {
+    with_connection(app_data_dir, |conn| {
+        conn.execute_batch(
+            "PRAGMA optimize; PRAGMA wal_checkpoint(TRUNCATE); VACUUM;
+             INSERT INTO clipboard_fts(clipboard_fts) VALUES('rebuild');",
+        )
+        .map_err(|e| format!("Failed to run database maintenance: {}", e))
+    })
+}
+
 /// Open a read-only SQLite connection for search operations.
 /// This reduces file lock contention compared to read-write connections.
 pub fn get_readonly_connection(app_data_dir: &Path) -> Result<Connection, String> {
@@ -158,7 +247,11 @@ fn run_migrations(conn: &Connection) -> Result<(), String> {
             content TEXT NOT NULL,
             content_type TEXT NOT NULL,
             created_at INTEGER NOT NULL,
-            is_favorite INTEGER NOT NULL DEFAULT 0
+            is_favorite INTEGER NOT NULL DEFAULT 0,
+            dedup_key TEXT,
+            sort_order INTEGER,
+            tags TEXT,
+            html TEXT
         );
         CREATE INDEX IF NOT EXISTS idx_clipboard_history_created_at ON clipboard_history(created_at);
         CREATE INDEX IF NOT EXISTS idx_clipboard_history_is_favorite ON clipboard_history(is_favorite);
@@ -184,6 +277,233 @@ fn run_migrations(conn: &Connection) -> Result<(), String> {
         CREATE INDEX IF NOT EXISTS idx_word_records_created_at ON word_records(created_at);
         CREATE INDEX IF NOT EXISTS idx_word_records_mastery_level ON word_records(mastery_level);
         CREATE INDEX IF NOT EXISTS idx_word_records_is_favorite ON word_records(is_favorite);
+
+        CREATE TABLE IF NOT EXISTS ssh_host_usage (
+            host TEXT PRIMARY KEY,
+            use_count INTEGER NOT NULL,
+            last_used INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS git_repos (
+            path TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            branch TEXT,
+            last_commit_time INTEGER,
+            head_mtime INTEGER NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_git_repos_last_commit_time ON git_repos(last_commit_time);
+
+        CREATE TABLE IF NOT EXISTS activity_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            event_type TEXT NOT NULL,
+            provider TEXT NOT NULL,
+            result_type TEXT,
+            result_id TEXT,
+            result_name TEXT,
+            query TEXT,
+            duration_ms INTEGER,
+            created_at INTEGER NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_activity_log_created_at ON activity_log(created_at);
+        CREATE INDEX IF NOT EXISTS idx_activity_log_event_type ON activity_log(event_type);
+
+        CREATE TABLE IF NOT EXISTS query_history (
+            query TEXT PRIMARY KEY,
+            use_count INTEGER NOT NULL DEFAULT 1,
+            last_used INTEGER NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_query_history_last_used ON query_history(last_used);
+
+        CREATE TABLE IF NOT EXISTS themes (
+            id TEXT PRIMARY KEY,
+            data TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS llm_cache (
+            key TEXT PRIMARY KEY,
+            response TEXT NOT NULL,
+            size INTEGER NOT NULL,
+            created_at INTEGER NOT NULL,
+            last_used INTEGER NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_llm_cache_last_used ON llm_cache(last_used);
+
+        CREATE TABLE IF NOT EXISTS llm_usage (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            created_at INTEGER NOT NULL,
+            feature TEXT NOT NULL,
+            model TEXT NOT NULL,
+            prompt_tokens INTEGER NOT NULL,
+            completion_tokens INTEGER NOT NULL,
+            estimated INTEGER NOT NULL DEFAULT 0,
+            cost REAL NOT NULL DEFAULT 0
+        );
+        CREATE INDEX IF NOT EXISTS idx_llm_usage_created_at ON llm_usage(created_at);
+
+        CREATE TABLE IF NOT EXISTS vector_entries (
+            collection TEXT NOT NULL,
+            key TEXT NOT NULL,
+            slot INTEGER NOT NULL,
+            model TEXT NOT NULL,
+            content_hash TEXT NOT NULL,
+            updated_at INTEGER NOT NULL,
+            PRIMARY KEY (collection, key)
+        );
+
+        CREATE TABLE IF NOT EXISTS prompt_templates (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            feature TEXT NOT NULL,
+            system_prompt TEXT,
+            template TEXT NOT NULL,
+            active INTEGER NOT NULL DEFAULT 0,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS chat_conversations (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            feature TEXT NOT NULL,
+            model TEXT NOT NULL,
+            pinned INTEGER NOT NULL DEFAULT 0,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS chat_messages (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            conversation_id TEXT NOT NULL,
+            role TEXT NOT NULL,
+            content TEXT NOT NULL,
+            created_at INTEGER NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_chat_messages_conversation ON chat_messages(conversation_id);
+
+        CREATE TABLE IF NOT EXISTS totp_accounts (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            issuer TEXT NOT NULL DEFAULT '',
+            secret TEXT NOT NULL,
+            algorithm TEXT NOT NULL DEFAULT 'SHA1',
+            digits INTEGER NOT NULL DEFAULT 6,
+            period INTEGER NOT NULL DEFAULT 30,
+            created_at INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS rss_feeds (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            url TEXT NOT NULL UNIQUE,
+            title TEXT NOT NULL,
+            site_url TEXT,
+            last_fetched INTEGER,
+            last_error TEXT,
+            created_at INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS rss_items (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            feed_id INTEGER NOT NULL,
+            guid TEXT NOT NULL,
+            title TEXT NOT NULL,
+            link TEXT NOT NULL,
+            summary TEXT,
+            published_at INTEGER,
+            fetched_at INTEGER NOT NULL,
+            read INTEGER NOT NULL DEFAULT 0,
+            UNIQUE(feed_id, guid)
+        );
+        CREATE INDEX IF NOT EXISTS idx_rss_items_read ON rss_items(read, published_at);
+
+        CREATE TABLE IF NOT EXISTS timers (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            kind TEXT NOT NULL,
+            label TEXT NOT NULL,
+            fire_at INTEGER NOT NULL,
+            duration_secs INTEGER NOT NULL,
+            sound INTEGER NOT NULL DEFAULT 1,
+            created_at INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS calendars (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            source TEXT NOT NULL UNIQUE,
+            name TEXT NOT NULL,
+            last_fetched INTEGER,
+            last_error TEXT,
+            created_at INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS calendar_events (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            calendar_id INTEGER NOT NULL,
+            uid TEXT NOT NULL,
+            title TEXT NOT NULL,
+            location TEXT,
+            start_at INTEGER NOT NULL,
+            end_at INTEGER NOT NULL,
+            all_day INTEGER NOT NULL DEFAULT 0,
+            join_url TEXT
+        );
+        CREATE INDEX IF NOT EXISTS idx_calendar_events_start ON calendar_events(start_at);
+
+        CREATE TABLE IF NOT EXISTS habits (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            schedule TEXT NOT NULL,
+            created_at INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS habit_checkins (
+            habit_id INTEGER NOT NULL,
+            day TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            PRIMARY KEY (habit_id, day)
+        );
+
+        CREATE TABLE IF NOT EXISTS sync_state (
+            dataset TEXT NOT NULL,
+            record_id TEXT NOT NULL,
+            hash TEXT NOT NULL,
+            PRIMARY KEY (dataset, record_id)
+        );
+
+        CREATE TABLE IF NOT EXISTS sync_conflicts (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            dataset TEXT NOT NULL,
+            record_id TEXT NOT NULL,
+            winner TEXT NOT NULL,
+            local_data TEXT,
+            remote_data TEXT,
+            local_updated_at INTEGER NOT NULL,
+            remote_updated_at INTEGER NOT NULL,
+            remote_device TEXT NOT NULL,
+            created_at INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS paste_links (
+            id TEXT PRIMARY KEY,
+            clipboard_item_id TEXT,
+            provider TEXT NOT NULL,
+            url TEXT NOT NULL,
+            delete_url TEXT,
+            preview TEXT NOT NULL DEFAULT '',
+            created_at INTEGER NOT NULL,
+            expires_at INTEGER,
+            revoked_at INTEGER
+        );
+
+        CREATE TABLE IF NOT EXISTS workflows (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            keyword TEXT NOT NULL,
+            description TEXT NOT NULL DEFAULT '',
+            steps TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );
         
         -- Migration: Add ai_explanation column if it doesn't exist
         -- SQLite doesn't support IF NOT EXISTS for ALTER TABLE ADD COLUMN
@@ -214,6 +534,124 @@ fn run_migrations(conn: &Connection) -> Result<(), String> {
         .map_err(|e| format!("Failed to add ai_explanation column: {}", e))?;
     }
 
+    // Migration: Add clipboard dedup_key column (existing rows are filled in by clipboard::refresh_dedup_keys)
+    let dedup_key_exists = conn
+        .prepare("SELECT dedup_key FROM clipboard_history LIMIT 1")
+        .is_ok();
+    if !dedup_key_exists {
+        conn.execute("ALTER TABLE clipboard_history ADD COLUMN dedup_key TEXT", [])
+            .map_err(|e| format!("Failed to add dedup_key column: {}", e))?;
+    }
+    // Migration: Add clipboard sort_order column (manual ordering of favorites)
+    let sort_order_exists = conn
+        .prepare("SELECT sort_order FROM clipboard_history LIMIT 1")
+        .is_ok();
+    if !sort_order_exists {
+        conn.execute("ALTER TABLE clipboard_history ADD COLUMN sort_order INTEGER", [])
+            .map_err(|e| format!("Failed to add sort_order column: {}", e))?;
+    }
+    // Migration: Add clipboard tags column (JSON array)
+    let clipboard_tags_exists = conn
+        .prepare("SELECT tags FROM clipboard_history LIMIT 1")
+        .is_ok();
+    if !clipboard_tags_exists {
+        conn.execute("ALTER TABLE clipboard_history ADD COLUMN tags TEXT", [])
+            .map_err(|e| format!("Failed to add clipboard tags column: {}", e))?;
+    }
+    // Migration: Add clipboard html column (CF_HTML fragment copied alongside the plain text)
+    let clipboard_html_exists = conn
+        .prepare("SELECT html FROM clipboard_history LIMIT 1")
+        .is_ok();
+    if !clipboard_html_exists {
+        conn.execute("ALTER TABLE clipboard_history ADD COLUMN html TEXT", [])
+            .map_err(|e| format!("Failed to add clipboard html column: {}", e))?;
+    }
+    // Migration: Add clipboard ocr_text column (text recognized from image items; NULL means not processed yet)
+    let clipboard_ocr_exists = conn
+        .prepare("SELECT ocr_text FROM clipboard_history LIMIT 1")
+        .is_ok();
+    if !clipboard_ocr_exists {
+        conn.execute("ALTER TABLE clipboard_history ADD COLUMN ocr_text TEXT", [])
+            .map_err(|e| format!("Failed to add clipboard ocr_text column: {}", e))?;
+    }
+    // Migration: Add clipboard pinned/pin_order columns (pinned items listed first in manual order, independent of favorites)
+    let clipboard_pinned_exists = conn
+        .prepare("SELECT pinned FROM clipboard_history LIMIT 1")
+        .is_ok();
+    if !clipboard_pinned_exists {
+        conn.execute("ALTER TABLE clipboard_history ADD COLUMN pinned INTEGER NOT NULL DEFAULT 0", [])
+            .map_err(|e| format!("Failed to add clipboard pinned column: {}", e))?;
+        conn.execute("ALTER TABLE clipboard_history ADD COLUMN pin_order INTEGER", [])
+            .map_err(|e| format!("Failed to add clipboard pin_order column: {}", e))?;
+    }
+    // Migration: Add clipboard is_sensitive column (looks like a password/card number/API key; NULL means not checked yet)
+    let clipboard_sensitive_exists = conn
+        .prepare("SELECT is_sensitive FROM clipboard_history LIMIT 1")
+        .is_ok();
+    if !clipboard_sensitive_exists {
+        conn.execute("ALTER TABLE clipboard_history ADD COLUMN is_sensitive INTEGER", [])
+            .map_err(|e| format!("Failed to add clipboard is_sensitive column: {}", e))?;
+    }
+    // Migration: 剪切板全文索引（依赖 tags、ocr_text 列，放在其迁移之后）；新建时从现有记录建立索引
+    // 旧版索引没有 ocr_text 列，删除后按新结构重建
+    let clipboard_fts_exists = conn
+        .prepare("SELECT ocr_text FROM clipboard_fts LIMIT 1")
+        .is_ok();
+    if !clipboard_fts_exists {
+        conn.execute_batch(
+            "DROP TRIGGER IF EXISTS clipboard_fts_ai;
+             DROP TRIGGER IF EXISTS clipboard_fts_ad;
+             DROP TRIGGER IF EXISTS clipboard_fts_au;
+             DROP TABLE IF EXISTS clipboard_fts;",
+        )
+        .map_err(|e| format!("Failed to drop old clipboard full-text index: {}", e))?;
+    }
+    conn.execute_batch(CLIPBOARD_FTS_SCHEMA)
+        .map_err(|e| format!("Failed to create clipboard full-text index: {}", e))?;
+    if !clipboard_fts_exists {
+        conn.execute("INSERT INTO clipboard_fts(clipboard_fts) VALUES('rebuild')", [])
+            .map_err(|e| format!("Failed to build clipboard full-text index: {}", e))?;
+    }
+    // Migration: Add timers recurrence column (encoded rule of recurring reminders)
+    let timer_recurrence_exists = conn
+        .prepare("SELECT recurrence FROM timers LIMIT 1")
+        .is_ok();
+    if !timer_recurrence_exists {
+        conn.execute("ALTER TABLE timers ADD COLUMN recurrence TEXT", [])
+            .map_err(|e| format!("Failed to add timers recurrence column: {}", e))?;
+    }
+    // Migration: Add memos hotstring columns (abbreviation expanded after a trigger key, and its case sensitivity)
+    let memo_hotstring_exists = conn
+        .prepare("SELECT hotstring FROM memos LIMIT 1")
+        .is_ok();
+    if !memo_hotstring_exists {
+        conn.execute("ALTER TABLE memos ADD COLUMN hotstring TEXT", [])
+            .map_err(|e| format!("Failed to add memos hotstring column: {}", e))?;
+        conn.execute("ALTER TABLE memos ADD COLUMN case_sensitive INTEGER NOT NULL DEFAULT 0", [])
+            .map_err(|e| format!("Failed to add memos case_sensitive column: {}", e))?;
+    }
+    // Migration: Add memos apps column (JSON array of app rules the hotstring is limited to)
+    let memo_apps_exists = conn
+        .prepare("SELECT apps FROM memos LIMIT 1")
+        .is_ok();
+    if !memo_apps_exists {
+        conn.execute("ALTER TABLE memos ADD COLUMN apps TEXT", [])
+            .map_err(|e| format!("Failed to add memos apps column: {}", e))?;
+    }
+    // Migration: Add memos injection column (how the expanded hotstring is typed, NULL = default)
+    let memo_injection_exists = conn
+        .prepare("SELECT injection FROM memos LIMIT 1")
+        .is_ok();
+    if !memo_injection_exists {
+        conn.execute("ALTER TABLE memos ADD COLUMN injection TEXT", [])
+            .map_err(|e| format!("Failed to add memos injection column: {}", e))?;
+    }
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_clipboard_history_dedup_key ON clipboard_history(dedup_key)",
+        [],
+    )
+    .map_err(|e| format!("Failed to create dedup_key index: {}", e))?;
+
     // Migration: Remove source_lang and target_lang columns if they exist
     // SQLite doesn't support DROP COLUMN, so we need to recreate the table
     let old_columns_exist = conn
@@ -0,0 +1,540 @@
+// 显示器控制：亮度（外接显示器走 DDC/CI，笔记本内置屏走 WMI）、分辨率/刷新率预设与夜间模式
+// 供启动器的 "亮度"、"display"、"夜间模式" 等查询使用；预设保存在设置的 display_presets 中
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BrightnessMethod {
+    Ddc,
+    Wmi,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Monitor {
+    pub id: String, // 设备名，如 \\.\DISPLAY1
+    pub name: String,
+    pub primary: bool,
+    pub width: u32,
+    pub height: u32,
+    pub frequency: u32,
+    pub brightness: Option<u32>, // 0..=100，不支持调节时为 None
+    pub brightness_method: Option<BrightnessMethod>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct DisplayMode {
+    pub width: u32,
+    pub height: u32,
+    pub frequency: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisplayPreset {
+    pub name: String,
+    #[serde(default)]
+    pub monitor: Option<String>, // 为空时作用于主显示器
+    pub width: u32,
+    pub height: u32,
+    #[serde(default)]
+    pub frequency: Option<u32>, // 为空时保持当前刷新率
+}
+
+// 夜间模式状态保存在 CloudStore 的二进制值中：第 18 字节为 0x15 表示开启、0x13 表示关闭，
+// 开启时第 23 字节处多出 0x10 0x00 两个字节；第 10..15 字节是 LEB128 编码的修改时间（秒）
+const NIGHT_LIGHT_FLAG_INDEX: usize = 18;
+const NIGHT_LIGHT_ON: u8 = 0x15;
+const NIGHT_LIGHT_OFF: u8 = 0x13;
+const NIGHT_LIGHT_EXTRA_INDEX: usize = 23;
+const NIGHT_LIGHT_EXTRA: [u8; 2] = [0x10, 0x00];
+const NIGHT_LIGHT_TIMESTAMP: std::ops::Range<usize> = 10..15;
+
+pub fn is_night_light_on(data: &[u8]) -> bool {
+    data.get(NIGHT_LIGHT_FLAG_INDEX) == Some(&NIGHT_LIGHT_ON)
+}
+
+fn leb128(mut value: u64) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+/// 生成切换夜间模式后的注册表数据；格式无法识别或已是目标状态时返回 None
+pub fn set_night_light_data(data: &[u8], enable: bool, now_secs: u64) -> Option<Vec<u8>> {
+    let flag = *data.get(NIGHT_LIGHT_FLAG_INDEX)?;
+    if flag != NIGHT_LIGHT_ON && flag != NIGHT_LIGHT_OFF {
+        return None;
+    }
+    if (flag == NIGHT_LIGHT_ON) == enable || data.len() < NIGHT_LIGHT_EXTRA_INDEX + NIGHT_LIGHT_EXTRA.len() {
+        return None;
+    }
+    let mut result = data.to_vec();
+    if enable {
+        result[NIGHT_LIGHT_FLAG_INDEX] = NIGHT_LIGHT_ON;
+        result.splice(NIGHT_LIGHT_EXTRA_INDEX..NIGHT_LIGHT_EXTRA_INDEX, NIGHT_LIGHT_EXTRA);
+    } else {
+        if result[NIGHT_LIGHT_EXTRA_INDEX..NIGHT_LIGHT_EXTRA_INDEX + 2] != NIGHT_LIGHT_EXTRA {
+            return None;
+        }
+        result[NIGHT_LIGHT_FLAG_INDEX] = NIGHT_LIGHT_OFF;
+        result.drain(NIGHT_LIGHT_EXTRA_INDEX..NIGHT_LIGHT_EXTRA_INDEX + 2);
+    }
+    // 系统只在时间戳变化时才应用新状态
+    let timestamp = leb128(now_secs);
+    if timestamp.len() == NIGHT_LIGHT_TIMESTAMP.len() {
+        result.splice(NIGHT_LIGHT_TIMESTAMP, timestamp);
+    }
+    Some(result)
+}
+
+/// 在模式列表中查找与预设匹配的模式；预设未指定刷新率时优先当前刷新率，其次最高刷新率
+pub fn match_mode(modes: &[DisplayMode], width: u32, height: u32, frequency: Option<u32>, current: u32) -> Option<DisplayMode> {
+    let candidates = modes.iter().filter(|m| m.width == width && m.height == height);
+    match frequency {
+        Some(frequency) => candidates.clone().find(|m| m.frequency == frequency),
+        None => candidates
+            .clone()
+            .find(|m| m.frequency == current)
+            .or_else(|| candidates.max_by_key(|m| m.frequency)),
+    }
+    .copied()
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::{BrightnessMethod, DisplayMode, Monitor};
+    use std::ffi::OsStr;
+    use std::os::windows::ffi::OsStrExt;
+    use std::os::windows::process::CommandExt;
+    use std::process::Command;
+    use windows_sys::Win32::Devices::Display::{
+        DestroyPhysicalMonitors, GetNumberOfPhysicalMonitorsFromHMONITOR, GetPhysicalMonitorsFromHMONITOR,
+        GetVCPFeatureAndVCPFeatureReply, SetVCPFeature, PHYSICAL_MONITOR,
+    };
+    use windows_sys::Win32::Foundation::{BOOL, LPARAM, RECT};
+    use windows_sys::Win32::Graphics::Gdi::{
+        ChangeDisplaySettingsExW, EnumDisplayMonitors, EnumDisplaySettingsW, GetMonitorInfoW, CDS_UPDATEREGISTRY,
+        DEVMODEW, DISP_CHANGE_SUCCESSFUL, DM_DISPLAYFREQUENCY, DM_PELSHEIGHT, DM_PELSWIDTH, ENUM_CURRENT_SETTINGS,
+        HDC, HMONITOR, MONITORINFO, MONITORINFOEXW,
+    };
+    use windows_sys::Win32::System::Registry::{
+        RegGetValueW, RegSetKeyValueW, HKEY_CURRENT_USER, REG_BINARY, RRF_RT_REG_BINARY,
+    };
+    use windows_sys::Win32::UI::WindowsAndMessaging::MONITORINFOF_PRIMARY;
+
+    const VCP_BRIGHTNESS: u8 = 0x10;
+    const NIGHT_LIGHT_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\CloudStore\\Store\\DefaultAccount\\Current\\default$windows.data.bluelightreduction.bluelightreductionstate\\windows.data.bluelightreduction.bluelightreductionstate";
+
+    fn to_wide(s: &str) -> Vec<u16> {
+        OsStr::new(s).encode_wide().chain(Some(0)).collect()
+    }
+
+    fn from_wide(buf: &[u16]) -> String {
+        let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+        String::from_utf16_lossy(&buf[..len])
+    }
+
+    fn powershell(script: &str) -> Result<String, String> {
+        let output = Command::new("powershell")
+            .args(["-NoProfile", "-NonInteractive", "-Command", script])
+            .creation_flags(0x08000000) // CREATE_NO_WINDOW
+            .output()
+            .map_err(|e| format!("执行 PowerShell 失败: {}", e))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    unsafe extern "system" fn collect_monitor(monitor: HMONITOR, _: HDC, _: *mut RECT, data: LPARAM) -> BOOL {
+        let monitors = &mut *(data as *mut Vec<HMONITOR>);
+        monitors.push(monitor);
+        1
+    }
+
+    fn monitor_handles() -> Vec<HMONITOR> {
+        let mut monitors: Vec<HMONITOR> = Vec::new();
+        unsafe {
+            EnumDisplayMonitors(0, std::ptr::null(), Some(collect_monitor), &mut monitors as *mut _ as LPARAM);
+        }
+        monitors
+    }
+
+    fn monitor_info(monitor: HMONITOR) -> Option<MONITORINFOEXW> {
+        let mut info: MONITORINFOEXW = unsafe { std::mem::zeroed() };
+        info.monitorInfo.cbSize = std::mem::size_of::<MONITORINFOEXW>() as u32;
+        let ok = unsafe { GetMonitorInfoW(monitor, &mut info as *mut MONITORINFOEXW as *mut MONITORINFO) };
+        (ok != 0).then_some(info)
+    }
+
+    fn empty_devmode() -> DEVMODEW {
+        let mut mode: DEVMODEW = unsafe { std::mem::zeroed() };
+        mode.dmSize = std::mem::size_of::<DEVMODEW>() as u16;
+        mode
+    }
+
+    fn current_mode(device: &[u16]) -> Option<DEVMODEW> {
+        let mut mode = empty_devmode();
+        let ok = unsafe { EnumDisplaySettingsW(device.as_ptr(), ENUM_CURRENT_SETTINGS, &mut mode) };
+        (ok != 0).then_some(mode)
+    }
+
+    /// 打开显示器对应的物理显示器句柄，离开作用域时释放
+    struct PhysicalMonitors(Vec<PHYSICAL_MONITOR>);
+
+    impl PhysicalMonitors {
+        fn open(monitor: HMONITOR) -> Self {
+            let mut count = 0u32;
+            if unsafe { GetNumberOfPhysicalMonitorsFromHMONITOR(monitor, &mut count) } == 0 || count == 0 {
+                return PhysicalMonitors(Vec::new());
+            }
+            let mut handles: Vec<PHYSICAL_MONITOR> = vec![unsafe { std::mem::zeroed() }; count as usize];
+            if unsafe { GetPhysicalMonitorsFromHMONITOR(monitor, count, handles.as_mut_ptr()) } == 0 {
+                return PhysicalMonitors(Vec::new());
+            }
+            PhysicalMonitors(handles)
+        }
+
+        fn name(&self) -> Option<String> {
+            self.0
+                .first()
+                // PHYSICAL_MONITOR 是 packed 结构体，先复制字段再取引用
+                .map(|m| {
+                    let description = m.szPhysicalMonitorDescription;
+                    from_wide(&description)
+                })
+                .filter(|name| !name.is_empty())
+        }
+
+        /// 通过 DDC/CI 读取亮度（VCP 0x10），按显示器的最大值换算为百分比
+        fn brightness(&self) -> Option<u32> {
+            self.0.iter().find_map(|m| {
+                let (mut current, mut max) = (0u32, 0u32);
+                let ok = unsafe {
+                    GetVCPFeatureAndVCPFeatureReply(m.hPhysicalMonitor, VCP_BRIGHTNESS, std::ptr::null_mut(), &mut current, &mut max)
+                };
+                (ok != 0 && max > 0).then(|| (current * 100 + max / 2) / max)
+            })
+        }
+
+        fn set_brightness(&self, percent: u32) -> bool {
+            let mut changed = false;
+            for m in &self.0 {
+                let (mut current, mut max) = (0u32, 0u32);
+                let ok = unsafe {
+                    GetVCPFeatureAndVCPFeatureReply(m.hPhysicalMonitor, VCP_BRIGHTNESS, std::ptr::null_mut(), &mut current, &mut max)
+                };
+                if ok == 0 || max == 0 {
+                    continue;
+                }
+                let value = (percent * max + 50) / 100;
+                changed |= unsafe { SetVCPFeature(m.hPhysicalMonitor, VCP_BRIGHTNESS, value) } != 0;
+            }
+            changed
+        }
+    }
+
+    impl Drop for PhysicalMonitors {
+        fn drop(&mut self) {
+            if !self.0.is_empty() {
+                unsafe { DestroyPhysicalMonitors(self.0.len() as u32, self.0.as_ptr()) };
+            }
+        }
+    }
+
+    fn wmi_brightness() -> Option<u32> {
+        powershell("(Get-CimInstance -Namespace root/WMI -ClassName WmiMonitorBrightness -ErrorAction Stop | Select-Object -First 1).CurrentBrightness")
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    fn set_wmi_brightness(percent: u32) -> Result<(), String> {
+        let script = format!(
+            "Get-CimInstance -Namespace root/WMI -ClassName WmiMonitorBrightnessMethods -ErrorAction Stop | Invoke-CimMethod -MethodName WmiSetBrightness -Arguments @{{Timeout=0;Brightness=[byte]{}}} | Out-Null",
+            percent
+        );
+        powershell(&script).map(|_| ()).map_err(|e| format!("设置亮度失败: {}", e))
+    }
+
+    fn find_monitor(id: &str) -> Result<(HMONITOR, MONITORINFOEXW), String> {
+        monitor_handles()
+            .into_iter()
+            .filter_map(|h| monitor_info(h).map(|info| (h, info)))
+            .find(|(_, info)| from_wide(&info.szDevice) == id)
+            .ok_or_else(|| format!("未找到显示器: {}", id))
+    }
+
+    pub fn list_monitors() -> Result<Vec<Monitor>, String> {
+        let mut monitors = Vec::new();
+        for handle in monitor_handles() {
+            let Some(info) = monitor_info(handle) else {
+                continue;
+            };
+            let id = from_wide(&info.szDevice);
+            let mode = current_mode(&info.szDevice);
+            let physical = PhysicalMonitors::open(handle);
+            let brightness = physical.brightness();
+            monitors.push(Monitor {
+                name: physical.name().unwrap_or_else(|| id.trim_start_matches("\\\\.\\").to_string()),
+                primary: info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY != 0,
+                width: mode.map(|m| m.dmPelsWidth).unwrap_or(0),
+                height: mode.map(|m| m.dmPelsHeight).unwrap_or(0),
+                frequency: mode.map(|m| m.dmDisplayFrequency).unwrap_or(0),
+                brightness_method: brightness.map(|_| BrightnessMethod::Ddc),
+                brightness,
+                id,
+            });
+        }
+        // 笔记本内置屏不支持 DDC/CI，亮度由 WMI 提供；WMI 只对应内置屏，优先分配给主显示器
+        if monitors.iter().any(|m| m.brightness.is_none()) {
+            if let Some(brightness) = wmi_brightness() {
+                let target = monitors
+                    .iter()
+                    .position(|m| m.brightness.is_none() && m.primary)
+                    .or_else(|| monitors.iter().position(|m| m.brightness.is_none()));
+                if let Some(monitor) = target.map(|i| &mut monitors[i]) {
+                    monitor.brightness = Some(brightness.min(100));
+                    monitor.brightness_method = Some(BrightnessMethod::Wmi);
+                }
+            }
+        }
+        monitors.sort_by(|a, b| b.primary.cmp(&a.primary).then_with(|| a.id.cmp(&b.id)));
+        Ok(monitors)
+    }
+
+    pub fn set_brightness(id: &str, percent: u32, method: Option<BrightnessMethod>) -> Result<(), String> {
+        let percent = percent.min(100);
+        if method == Some(BrightnessMethod::Wmi) {
+            return set_wmi_brightness(percent);
+        }
+        let (handle, _) = find_monitor(id)?;
+        if PhysicalMonitors::open(handle).set_brightness(percent) {
+            Ok(())
+        } else {
+            // DDC/CI 不可用时尝试 WMI（内置屏）
+            set_wmi_brightness(percent)
+        }
+    }
+
+    pub fn list_modes(id: &str) -> Result<Vec<DisplayMode>, String> {
+        let device = to_wide(id);
+        let mut modes = Vec::new();
+        let mut mode = empty_devmode();
+        let mut index = 0;
+        while unsafe { EnumDisplaySettingsW(device.as_ptr(), index, &mut mode) } != 0 {
+            modes.push(DisplayMode { width: mode.dmPelsWidth, height: mode.dmPelsHeight, frequency: mode.dmDisplayFrequency });
+            index += 1;
+        }
+        if modes.is_empty() {
+            return Err(format!("未找到显示器: {}", id));
+        }
+        // 同一分辨率和刷新率会因色深、缩放方式不同重复出现
+        modes.sort_by(|a, b| b.cmp(a));
+        modes.dedup();
+        Ok(modes)
+    }
+
+    pub fn current(id: &str) -> Option<DisplayMode> {
+        current_mode(&to_wide(id)).map(|m| DisplayMode { width: m.dmPelsWidth, height: m.dmPelsHeight, frequency: m.dmDisplayFrequency })
+    }
+
+    pub fn set_mode(id: &str, mode: DisplayMode) -> Result<(), String> {
+        let device = to_wide(id);
+        let mut devmode = empty_devmode();
+        devmode.dmPelsWidth = mode.width;
+        devmode.dmPelsHeight = mode.height;
+        devmode.dmDisplayFrequency = mode.frequency;
+        devmode.dmFields = DM_PELSWIDTH | DM_PELSHEIGHT | DM_DISPLAYFREQUENCY;
+        let result = unsafe { ChangeDisplaySettingsExW(device.as_ptr(), &devmode, 0, CDS_UPDATEREGISTRY, std::ptr::null()) };
+        if result == DISP_CHANGE_SUCCESSFUL {
+            Ok(())
+        } else {
+            Err(format!("切换显示模式失败 (code {})", result))
+        }
+    }
+
+    pub fn night_light_data() -> Result<Vec<u8>, String> {
+        let sub_key = to_wide(NIGHT_LIGHT_KEY);
+        let value_name = to_wide("Data");
+        let mut size = 0u32;
+        let result = unsafe {
+            RegGetValueW(HKEY_CURRENT_USER, sub_key.as_ptr(), value_name.as_ptr(), RRF_RT_REG_BINARY, std::ptr::null_mut(), std::ptr::null_mut(), &mut size)
+        };
+        if result != 0 || size == 0 {
+            return Err("未找到夜间模式设置，请先在系统设置中打开一次夜间模式".to_string());
+        }
+        let mut data = vec![0u8; size as usize];
+        let result = unsafe {
+            RegGetValueW(
+                HKEY_CURRENT_USER,
+                sub_key.as_ptr(),
+                value_name.as_ptr(),
+                RRF_RT_REG_BINARY,
+                std::ptr::null_mut(),
+                data.as_mut_ptr() as *mut _,
+                &mut size,
+            )
+        };
+        if result != 0 {
+            return Err(format!("读取夜间模式设置失败 (code {})", result));
+        }
+        data.truncate(size as usize);
+        Ok(data)
+    }
+
+    pub fn write_night_light_data(data: &[u8]) -> Result<(), String> {
+        let sub_key = to_wide(NIGHT_LIGHT_KEY);
+        let value_name = to_wide("Data");
+        let result = unsafe {
+            RegSetKeyValueW(HKEY_CURRENT_USER, sub_key.as_ptr(), value_name.as_ptr(), REG_BINARY, data.as_ptr() as *const _, data.len() as u32)
+        };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(format!("写入夜间模式设置失败 (code {})", result))
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    use super::{BrightnessMethod, DisplayMode, Monitor};
+
+    const UNSUPPORTED: &str = "当前平台不支持显示器控制";
+
+    pub fn list_monitors() -> Result<Vec<Monitor>, String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn set_brightness(_id: &str, _percent: u32, _method: Option<BrightnessMethod>) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn list_modes(_id: &str) -> Result<Vec<DisplayMode>, String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn current(_id: &str) -> Option<DisplayMode> {
+        None
+    }
+
+    pub fn set_mode(_id: &str, _mode: DisplayMode) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn night_light_data() -> Result<Vec<u8>, String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn write_night_light_data(_data: &[u8]) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+}
+
+pub use platform::{list_modes, list_monitors};
+
+/// 设置显示器亮度（0-100）
+pub fn set_brightness(monitor: &str, percent: u32) -> Result<(), String> {
+    let method = list_monitors()?
+        .into_iter()
+        .find(|m| m.id == monitor)
+        .ok_or_else(|| format!("未找到显示器: {}", monitor))?
+        .brightness_method;
+    platform::set_brightness(monitor, percent, method)
+}
+
+fn primary_monitor() -> Result<String, String> {
+    list_monitors()?
+        .into_iter()
+        .find(|m| m.primary)
+        .map(|m| m.id)
+        .ok_or_else(|| "未找到主显示器".to_string())
+}
+
+/// 切换分辨率与刷新率；只接受显示器支持的模式
+pub fn set_display_mode(monitor: &str, width: u32, height: u32, frequency: Option<u32>) -> Result<DisplayMode, String> {
+    let modes = list_modes(monitor)?;
+    let current = platform::current(monitor).map(|m| m.frequency).unwrap_or(0);
+    let mode = match_mode(&modes, width, height, frequency, current).ok_or_else(|| match frequency {
+        Some(frequency) => format!("显示器不支持 {}x{} @ {}Hz", width, height, frequency),
+        None => format!("显示器不支持 {}x{}", width, height),
+    })?;
+    platform::set_mode(monitor, mode)?;
+    Ok(mode)
+}
+
+/// 应用显示预设
+pub fn apply_preset(preset: &DisplayPreset) -> Result<DisplayMode, String> {
+    let monitor = match preset.monitor.as_deref().filter(|m| !m.is_empty()) {
+        Some(monitor) => monitor.to_string(),
+        None => primary_monitor()?,
+    };
+    set_display_mode(&monitor, preset.width, preset.height, preset.frequency)
+}
+
+pub fn night_light_enabled() -> Result<bool, String> {
+    platform::night_light_data().map(|data| is_night_light_on(&data))
+}
+
+/// 开关夜间模式，返回切换后的状态
+pub fn set_night_light(enable: bool) -> Result<bool, String> {
+    let data = platform::night_light_data()?;
+    if is_night_light_on(&data) == enable {
+        return Ok(enable);
+    }
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let updated = set_night_light_data(&data, enable, now).ok_or_else(|| "无法识别夜间模式设置的格式".to_string())?;
+    platform::write_night_light_data(&updated)?;
+    Ok(enable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 系统设置中关闭夜间模式后的注册表数据
+    const OFF: [u8; 41] = [
+        0x43, 0x42, 0x01, 0x00, 0x0A, 0x02, 0x01, 0x00, 0x2A, 0x06, 0x9B, 0xD5, 0xB9, 0xC6, 0x06, 0x2A, 0x2B, 0x0E,
+        0x13, 0x43, 0x42, 0x01, 0x00, 0xD0, 0x0A, 0x02, 0xC6, 0x14, 0xF3, 0xCF, 0xB2, 0xB4, 0x82, 0xA1, 0xE5, 0xEC,
+        0x01, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn toggles_night_light_data() {
+        assert!(!is_night_light_on(&OFF));
+        let on = set_night_light_data(&OFF, true, 1_700_000_000).unwrap();
+        assert!(is_night_light_on(&on));
+        assert_eq!(on.len(), OFF.len() + 2);
+        assert_eq!(on[23..25], [0x10, 0x00]);
+        assert_eq!(on[10..15], leb128(1_700_000_000)[..]);
+
+        let off = set_night_light_data(&on, false, 1_700_000_100).unwrap();
+        assert!(!is_night_light_on(&off));
+        assert_eq!(off.len(), OFF.len());
+        assert_eq!(off[15..], OFF[15..]);
+
+        assert!(set_night_light_data(&OFF, false, 0).is_none());
+        assert!(set_night_light_data(&[0u8; 10], true, 0).is_none());
+    }
+
+    #[test]
+    fn matches_preset_modes() {
+        let mode = |width, height, frequency| DisplayMode { width, height, frequency };
+        let modes = vec![mode(2560, 1440, 144), mode(2560, 1440, 60), mode(1920, 1080, 60)];
+        assert_eq!(match_mode(&modes, 2560, 1440, Some(60), 144), Some(mode(2560, 1440, 60)));
+        assert_eq!(match_mode(&modes, 2560, 1440, None, 60), Some(mode(2560, 1440, 60)));
+        assert_eq!(match_mode(&modes, 2560, 1440, None, 75), Some(mode(2560, 1440, 144)));
+        assert_eq!(match_mode(&modes, 1920, 1080, Some(144), 60), None);
+    }
+}
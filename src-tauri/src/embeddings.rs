@@ -0,0 +1,420 @@
+// 文本向量：通过 OpenAI 兼容的 /embeddings 接口（可用本地 Ollama 或在线服务）计算向量，保存到本地向量库
+// 设置中填写向量模型后启用；提供按集合增量索引和语义搜索，剪切板和备忘录的语义搜索、「问笔记」的资料检索都基于这里
+
+use crate::api_policy::{self, ApiError, ApiErrorKind, Policy};
+use crate::clipboard::{self, ClipboardItem};
+use crate::db;
+use crate::http_client;
+use crate::llm::{self, TokenUsage};
+use crate::llm_usage;
+use crate::memos::{self, MemoItem};
+use crate::settings::LlmSettings;
+use crate::vector_store::VectorStore;
+use crate::word_records::{self, WordRecord};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+// 单条文本参与计算的最大字符数，超长内容只取开头
+const MAX_INPUT_CHARS: usize = 2000;
+const BATCH_SIZE: usize = 32;
+// 相似度低于该值的结果视为不相关
+const MIN_SCORE: f32 = 0.3;
+// 关键词命中的结果额外加分，保证精确匹配排在前面
+const KEYWORD_BOOST: f32 = 0.5;
+
+const CLIPBOARD_COLLECTION: &str = "clipboard";
+const MEMOS_COLLECTION: &str = "memos";
+const WORDS_COLLECTION: &str = "word_records";
+// 问笔记时每条资料放进提示词的最大字符数
+const NOTE_SNIPPET_CHARS: usize = 800;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SemanticMatch<T> {
+    pub item: T,
+    pub score: f32,
+    pub keyword: bool, // 是否同时命中关键词
+}
+
+/// 问笔记检索到的资料
+#[derive(Debug, Clone, Serialize)]
+pub struct NoteSource {
+    pub kind: String, // "memo" | "word"
+    pub id: String,
+    pub title: String,
+    pub content: String,
+    pub score: f32,
+}
+
+/// 设置中的向量模型，未填写时不启用语义搜索
+pub fn embedding_model(settings: &LlmSettings) -> Option<&str> {
+    settings
+        .embedding_model
+        .as_deref()
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+}
+
+fn truncate_input(text: &str) -> String {
+    text.chars().take(MAX_INPUT_CHARS).collect()
+}
+
+fn content_hash(text: &str) -> String {
+    format!("{:x}", Sha256::digest(text.as_bytes()))
+}
+
+/// 调用 /embeddings 接口计算向量，返回顺序与 inputs 一致
+async fn embed(
+    app_data_dir: &Path,
+    settings: &LlmSettings,
+    model: &str,
+    inputs: &[String],
+) -> Result<Vec<Vec<f32>>, ApiError> {
+    let base_url = settings.base_url.trim().trim_end_matches('/');
+    let base_url = if base_url.is_empty() { "https://api.openai.com/v1" } else { base_url };
+    let url = format!("{}/embeddings", base_url);
+    let body = serde_json::json!({ "model": model, "input": inputs });
+    let client = http_client::client().map_err(|e| ApiError::new(ApiErrorKind::Invalid, e))?;
+
+    let response = api_policy::send(&api_policy::provider_of(&url), &Policy::LLM, || {
+        let builder = client.post(&url).json(&body);
+        match settings.api_key.as_deref().filter(|k| !k.is_empty()) {
+            Some(api_key) => builder.bearer_auth(api_key),
+            None => builder,
+        }
+    })
+    .await?;
+    let value: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| ApiError::new(ApiErrorKind::Invalid, format!("解析响应失败: {}", e)))?;
+
+    let mut vectors: Vec<Option<Vec<f32>>> = vec![None; inputs.len()];
+    for (position, entry) in value
+        .get("data")
+        .and_then(|d| d.as_array())
+        .into_iter()
+        .flatten()
+        .enumerate()
+    {
+        let index = entry
+            .get("index")
+            .and_then(|i| i.as_u64())
+            .map(|i| i as usize)
+            .unwrap_or(position);
+        let vector = entry.get("embedding").and_then(|e| e.as_array()).map(|values| {
+            values
+                .iter()
+                .filter_map(|v| v.as_f64())
+                .map(|v| v as f32)
+                .collect::<Vec<_>>()
+        });
+        if let (Some(slot), Some(vector)) = (vectors.get_mut(index), vector) {
+            *slot = Some(vector);
+        }
+    }
+    let vectors = vectors
+        .into_iter()
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| ApiError::new(ApiErrorKind::Invalid, "向量接口返回的数据不完整"))?;
+
+    let prompt_tokens = value
+        .pointer("/usage/prompt_tokens")
+        .and_then(|v| v.as_u64());
+    let usage = TokenUsage {
+        prompt_tokens: prompt_tokens.unwrap_or_else(|| inputs.iter().map(|i| llm::estimate_tokens(i)).sum()),
+        completion_tokens: 0,
+        estimated: prompt_tokens.is_none(),
+    };
+    if let Err(e) = llm_usage::record(app_data_dir, "clipboard_embedding", model, &usage) {
+        eprintln!("[Embeddings] {}", e);
+    }
+    Ok(vectors)
+}
+
+/// 增量索引一个集合：items 为 (键, 文本) 的完整列表，只为新增或内容已修改的条目计算向量，
+/// 不在列表中的键会从向量库删除；最多处理 max_batches 批，返回处理的条目数
+pub async fn index_collection(
+    app_data_dir: &Path,
+    settings: &LlmSettings,
+    collection: &str,
+    items: Vec<(String, String)>,
+    max_batches: usize,
+) -> Result<usize, ApiError> {
+    let Some(model) = embedding_model(settings) else {
+        return Ok(0);
+    };
+    let invalid = |e: String| ApiError::new(ApiErrorKind::Invalid, e);
+    let store = VectorStore::open(app_data_dir, collection).map_err(invalid)?;
+    let indexed: HashMap<String, (String, String)> = store
+        .entries()
+        .map_err(invalid)?
+        .into_iter()
+        .map(|e| (e.key, (e.model, e.content_hash)))
+        .collect();
+
+    let keys: HashSet<&str> = items.iter().map(|(key, _)| key.as_str()).collect();
+    let stale: Vec<String> = indexed.keys().filter(|k| !keys.contains(k.as_str())).cloned().collect();
+    store.delete(&stale).map_err(invalid)?;
+
+    let pending: Vec<(String, String, String)> = items
+        .into_iter()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .filter_map(|(key, text)| {
+            let input = truncate_input(&text);
+            let hash = content_hash(&input);
+            let up_to_date = indexed
+                .get(&key)
+                .map(|(m, h)| m == model && *h == hash)
+                .unwrap_or(false);
+            (!up_to_date).then_some((key, input, hash))
+        })
+        .collect();
+
+    let mut count = 0;
+    for batch in pending.chunks(BATCH_SIZE).take(max_batches) {
+        let inputs: Vec<String> = batch.iter().map(|(_, input, _)| input.clone()).collect();
+        let vectors = embed(app_data_dir, settings, model, &inputs).await?;
+        let entries: Vec<(String, String, Vec<f32>)> = batch
+            .iter()
+            .zip(vectors)
+            .map(|((key, _, hash), vector)| (key.clone(), hash.clone(), vector))
+            .collect();
+        store.upsert(model, &entries).map_err(invalid)?;
+        count += batch.len();
+    }
+    Ok(count)
+}
+
+/// 在集合中查询与 query 语义相近的条目，返回 (键, 相似度)
+pub async fn search_collection(
+    app_data_dir: &Path,
+    settings: &LlmSettings,
+    collection: &str,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<(String, f32)>, ApiError> {
+    let Some(model) = embedding_model(settings) else {
+        return Ok(Vec::new());
+    };
+    let query_vector = embed(app_data_dir, settings, model, &[truncate_input(query)])
+        .await?
+        .pop()
+        .unwrap_or_default();
+    VectorStore::open(app_data_dir, collection)
+        .and_then(|store| store.query(&query_vector, limit, MIN_SCORE))
+        .map_err(|e| ApiError::new(ApiErrorKind::Invalid, e))
+}
+
+/// 合并关键词结果和语义结果：关键词命中的条目加分，其余语义结果通过 load 按键加载
+fn merge_results<T>(
+    keyword_items: Vec<T>,
+    key_of: impl Fn(&T) -> &str,
+    semantic: Vec<(String, f32)>,
+    load: impl Fn(&str) -> Option<T>,
+    limit: usize,
+) -> Vec<SemanticMatch<T>> {
+    let mut scores: HashMap<String, f32> = semantic.into_iter().collect();
+    let mut matches: Vec<SemanticMatch<T>> = keyword_items
+        .into_iter()
+        .map(|item| {
+            let score = scores.remove(key_of(&item)).unwrap_or(0.0) + KEYWORD_BOOST;
+            SemanticMatch { item, score, keyword: true }
+        })
+        .collect();
+    for (key, score) in scores {
+        if let Some(item) = load(&key) {
+            matches.push(SemanticMatch { item, score, keyword: false });
+        }
+    }
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    matches.truncate(limit);
+    matches
+}
+
+fn clipboard_texts(app_data_dir: &Path) -> Result<Vec<(String, String)>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT id, content FROM clipboard_history
+                 WHERE content_type = 'text' AND is_sensitive = 0 ORDER BY created_at DESC",
+            )
+            .map_err(|e| format!("Failed to prepare clipboard texts query: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+            .map_err(|e| format!("Failed to query clipboard texts: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read clipboard texts: {}", e))?;
+        Ok(rows)
+    })
+}
+
+fn memo_texts(app_data_dir: &Path) -> Result<Vec<(String, String)>, String> {
+    Ok(memos::get_all_memos(&app_data_dir.to_path_buf())?
+        .into_iter()
+        .map(|memo| (memo.id, format!("{}\n{}", memo.title, memo.content)))
+        .collect())
+}
+
+fn word_text(record: &WordRecord) -> String {
+    let mut parts = vec![format!("{} {}", record.word, record.translation)];
+    parts.extend(
+        [&record.context, &record.example_sentence, &record.ai_explanation]
+            .into_iter()
+            .flatten()
+            .filter(|s| !s.trim().is_empty())
+            .cloned(),
+    );
+    parts.join("\n")
+}
+
+fn word_texts(app_data_dir: &Path) -> Result<Vec<(String, String)>, String> {
+    Ok(word_records::get_all_word_records(app_data_dir)?
+        .iter()
+        .map(|record| (record.id.clone(), word_text(record)))
+        .collect())
+}
+
+/// 索引剪切板文本、备忘录和单词记录，每个集合最多处理 max_batches 批（空闲时的后台任务调用）；
+/// 关闭语义搜索（清空向量模型）后删除已有的向量
+pub async fn index_all(app_data_dir: &Path, settings: &LlmSettings, max_batches: usize) -> Result<usize, ApiError> {
+    let invalid = |e: String| ApiError::new(ApiErrorKind::Invalid, e);
+    if embedding_model(settings).is_none() {
+        for collection in [CLIPBOARD_COLLECTION, MEMOS_COLLECTION, WORDS_COLLECTION] {
+            VectorStore::open(app_data_dir, collection)
+                .and_then(|store| store.clear())
+                .map_err(invalid)?;
+        }
+        return Ok(0);
+    }
+    let clipboard_items = clipboard_texts(app_data_dir).map_err(invalid)?;
+    let memo_items = memo_texts(app_data_dir).map_err(invalid)?;
+    let word_items = word_texts(app_data_dir).map_err(invalid)?;
+    Ok(
+        index_collection(app_data_dir, settings, CLIPBOARD_COLLECTION, clipboard_items, max_batches).await?
+            + index_collection(app_data_dir, settings, MEMOS_COLLECTION, memo_items, max_batches).await?
+            + index_collection(app_data_dir, settings, WORDS_COLLECTION, word_items, max_batches).await?,
+    )
+}
+
+/// 语义搜索剪切板历史；未启用向量模型时只返回关键词结果
+pub async fn semantic_search_clipboard(
+    app_data_dir: &Path,
+    settings: &LlmSettings,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<SemanticMatch<ClipboardItem>>, ApiError> {
+    let invalid = |e: String| ApiError::new(ApiErrorKind::Invalid, e);
+    let dir = app_data_dir.to_path_buf();
+    let keyword_items = clipboard::search_clipboard_items(query, &dir).map_err(invalid)?;
+    let semantic = if embedding_model(settings).is_some() && !query.trim().is_empty() {
+        // 先补齐最近的少量条目，其余由空闲时的后台任务处理
+        match clipboard_texts(app_data_dir) {
+            Ok(items) => {
+                if let Err(e) = index_collection(app_data_dir, settings, CLIPBOARD_COLLECTION, items, 1).await {
+                    eprintln!("[Embeddings] Failed to index clipboard items: {}", e);
+                }
+            }
+            Err(e) => eprintln!("[Embeddings] {}", e),
+        }
+        search_collection(app_data_dir, settings, CLIPBOARD_COLLECTION, query, limit).await?
+    } else {
+        Vec::new()
+    };
+    Ok(merge_results(
+        keyword_items,
+        |item| item.id.as_str(),
+        semantic,
+        |id| clipboard::get_clipboard_item(id, &dir).ok().flatten(),
+        limit,
+    ))
+}
+
+/// 语义搜索备忘录；未启用向量模型时只返回关键词结果
+pub async fn semantic_search_memos(
+    app_data_dir: &Path,
+    settings: &LlmSettings,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<SemanticMatch<MemoItem>>, ApiError> {
+    let invalid = |e: String| ApiError::new(ApiErrorKind::Invalid, e);
+    let dir = app_data_dir.to_path_buf();
+    let keyword_items = memos::search_memos(query, &dir).map_err(invalid)?;
+    let semantic = if embedding_model(settings).is_some() && !query.trim().is_empty() {
+        match memo_texts(app_data_dir) {
+            Ok(items) => {
+                if let Err(e) = index_collection(app_data_dir, settings, MEMOS_COLLECTION, items, 1).await {
+                    eprintln!("[Embeddings] Failed to index memos: {}", e);
+                }
+            }
+            Err(e) => eprintln!("[Embeddings] {}", e),
+        }
+        search_collection(app_data_dir, settings, MEMOS_COLLECTION, query, limit).await?
+    } else {
+        Vec::new()
+    };
+    let all_memos = memos::get_all_memos(&dir).unwrap_or_default();
+    Ok(merge_results(
+        keyword_items,
+        |memo| memo.id.as_str(),
+        semantic,
+        |id| all_memos.iter().find(|m| m.id == id).cloned(),
+        limit,
+    ))
+}
+
+/// 为问题检索相关的备忘录和单词记录（按相似度降序，最多 limit 条）；需要设置向量模型
+pub async fn retrieve_notes(
+    app_data_dir: &Path,
+    settings: &LlmSettings,
+    question: &str,
+    limit: usize,
+) -> Result<Vec<NoteSource>, ApiError> {
+    let invalid = |e: String| ApiError::new(ApiErrorKind::Invalid, e);
+    if embedding_model(settings).is_none() {
+        return Err(invalid("请先在 AI 设置中填写向量模型".to_string()));
+    }
+    let memo_list = memos::get_all_memos(&app_data_dir.to_path_buf()).map_err(invalid)?;
+    let word_list = word_records::get_all_word_records(app_data_dir).map_err(invalid)?;
+
+    // 先补齐最近的少量条目，其余由空闲时的后台任务处理
+    let memo_items = memo_list
+        .iter()
+        .map(|memo| (memo.id.clone(), format!("{}\n{}", memo.title, memo.content)))
+        .collect();
+    let word_items = word_list.iter().map(|record| (record.id.clone(), word_text(record))).collect();
+    for (collection, items) in [(MEMOS_COLLECTION, memo_items), (WORDS_COLLECTION, word_items)] {
+        if let Err(e) = index_collection(app_data_dir, settings, collection, items, 1).await {
+            eprintln!("[Embeddings] Failed to index {}: {}", collection, e);
+        }
+    }
+
+    let snippet = |text: &str| text.chars().take(NOTE_SNIPPET_CHARS).collect::<String>();
+    let mut sources: Vec<NoteSource> = Vec::new();
+    for (id, score) in search_collection(app_data_dir, settings, MEMOS_COLLECTION, question, limit).await? {
+        if let Some(memo) = memo_list.iter().find(|m| m.id == id) {
+            sources.push(NoteSource {
+                kind: "memo".to_string(),
+                id,
+                title: memo.title.clone(),
+                content: snippet(&memo.content),
+                score,
+            });
+        }
+    }
+    for (id, score) in search_collection(app_data_dir, settings, WORDS_COLLECTION, question, limit).await? {
+        if let Some(record) = word_list.iter().find(|r| r.id == id) {
+            sources.push(NoteSource {
+                kind: "word".to_string(),
+                id,
+                title: record.word.clone(),
+                content: snippet(&word_text(record)),
+                score,
+            });
+        }
+    }
+    sources.sort_by(|a, b| b.score.total_cmp(&a.score));
+    sources.truncate(limit);
+    Ok(sources)
+}
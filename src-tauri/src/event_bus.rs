@@ -0,0 +1,72 @@
+// 内部事件总线：后端各子系统（设置、数据库写入、监控状态等）通过广播通道发布和订阅事件，互不直接依赖
+// 桥接任务把选定的主题转发为 Tauri 事件（bus://<主题>），供前端窗口监听
+
+use crate::clipboard::MonitorStatus;
+use crate::settings::Settings;
+use serde::Serialize;
+use std::sync::LazyLock;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast;
+
+const CHANNEL_CAPACITY: usize = 256;
+
+/// 转发给前端的主题；settings-changed 含 API Key 等敏感字段，只在后端内部流转
+const FORWARDED_TOPICS: &[&str] = &["db-write", "monitor-status"];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum BusEvent {
+    /// 设置已保存
+    SettingsChanged(Settings),
+    /// 数据表有写入（新增、更新、删除）
+    DbWrite { table: &'static str },
+    /// 剪切板监控状态变化
+    MonitorStatus(MonitorStatus),
+}
+
+impl BusEvent {
+    pub fn topic(&self) -> &'static str {
+        match self {
+            BusEvent::SettingsChanged(_) => "settings-changed",
+            BusEvent::DbWrite { .. } => "db-write",
+            BusEvent::MonitorStatus(_) => "monitor-status",
+        }
+    }
+}
+
+static SENDER: LazyLock<broadcast::Sender<BusEvent>> =
+    LazyLock::new(|| broadcast::channel(CHANNEL_CAPACITY).0);
+
+/// 发布事件（没有订阅者时直接丢弃）
+pub fn publish(event: BusEvent) {
+    let _ = SENDER.send(event);
+}
+
+/// 订阅所有事件；处理过慢时会丢失最早的事件（recv 返回 Lagged）
+pub fn subscribe() -> broadcast::Receiver<BusEvent> {
+    SENDER.subscribe()
+}
+
+/// 启动桥接任务，把 FORWARDED_TOPICS 中的事件转发为 Tauri 事件
+pub fn start_tauri_bridge(app: AppHandle) {
+    let mut receiver = subscribe();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    let topic = event.topic();
+                    if !FORWARDED_TOPICS.contains(&topic) {
+                        continue;
+                    }
+                    if let Err(e) = app.emit(&format!("bus://{}", topic), &event) {
+                        eprintln!("[EventBus] Failed to forward {}: {}", topic, e);
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    eprintln!("[EventBus] Bridge lagged, skipped {} events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
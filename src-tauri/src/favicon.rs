@@ -0,0 +1,179 @@
+// 网站图标：为「打开网址」结果获取站点的 favicon，并缓存到应用数据目录的 favicons 子目录
+// 先尝试 /favicon.ico，失败时解析首页 HTML 中的 <link rel="icon">
+
+use base64::{engine::general_purpose, Engine as _};
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(4);
+const MAX_ICON_BYTES: usize = 256 * 1024;
+// 获取失败的站点在这段时间内不再重试
+const NEGATIVE_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+static ICON_LINK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?is)<link\b[^>]*>"#).unwrap());
+static REL_ICON_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)\brel\s*=\s*["']?[^"'>]*\bicon\b"#).unwrap());
+static HREF_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)\bhref\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s>]+))"#).unwrap());
+
+fn favicons_dir(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("favicons")
+}
+
+fn cache_file(app_data_dir: &Path, host: &str) -> PathBuf {
+    let safe_host: String = host
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+    favicons_dir(app_data_dir).join(format!("{}.icon", safe_host))
+}
+
+/// 根据文件头判断图片类型，不是图片时返回 None
+pub fn sniff_mime(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG") {
+        Some("image/png")
+    } else if bytes.starts_with(&[0, 0, 1, 0]) {
+        Some("image/x-icon")
+    } else if bytes.starts_with(b"GIF8") {
+        Some("image/gif")
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        Some("image/jpeg")
+    } else if bytes.len() > 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else {
+        let head = String::from_utf8_lossy(&bytes[..bytes.len().min(256)]).to_lowercase();
+        (head.trim_start().starts_with("<svg") || (head.contains("<?xml") && head.contains("<svg")))
+            .then_some("image/svg+xml")
+    }
+}
+
+fn to_data_url(bytes: &[u8]) -> Option<String> {
+    sniff_mime(bytes).map(|mime| format!("data:{};base64,{}", mime, general_purpose::STANDARD.encode(bytes)))
+}
+
+/// 从 HTML 中提取第一个 rel 包含 icon 的 <link> 的 href
+pub fn find_icon_href(html: &str) -> Option<String> {
+    ICON_LINK_RE
+        .find_iter(html)
+        .map(|m| m.as_str())
+        .filter(|tag| REL_ICON_RE.is_match(tag))
+        .find_map(|tag| {
+            HREF_RE.captures(tag).and_then(|caps| {
+                caps.get(1)
+                    .or_else(|| caps.get(2))
+                    .or_else(|| caps.get(3))
+                    .map(|m| m.as_str().trim().to_string())
+            })
+        })
+        .filter(|href| !href.is_empty())
+}
+
+async fn fetch_bytes(client: &reqwest::Client, url: reqwest::Url) -> Option<Vec<u8>> {
+    let response = client.get(url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    let bytes = response.bytes().await.ok()?;
+    (!bytes.is_empty() && bytes.len() <= MAX_ICON_BYTES).then(|| bytes.to_vec())
+}
+
+async fn download_favicon(site: &reqwest::Url) -> Option<Vec<u8>> {
+    let client = crate::http_client::builder()
+        .ok()?
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .ok()?;
+
+    if let Ok(icon_url) = site.join("/favicon.ico") {
+        if let Some(bytes) = fetch_bytes(&client, icon_url).await {
+            if sniff_mime(&bytes).is_some() {
+                return Some(bytes);
+            }
+        }
+    }
+
+    let html = client.get(site.clone()).send().await.ok()?.text().await.ok()?;
+    let href = find_icon_href(&html)?;
+    let bytes = fetch_bytes(&client, site.join(&href).ok()?).await?;
+    sniff_mime(&bytes).is_some().then_some(bytes)
+}
+
+/// 获取网址对应站点的图标（data URL），优先读取磁盘缓存；获取不到时返回 None
+pub async fn get_favicon(url: &str, app_data_dir: &Path) -> Result<Option<String>, String> {
+    let site = reqwest::Url::parse(url).map_err(|e| format!("Invalid url {}: {}", url, e))?;
+    if site.scheme() != "http" && site.scheme() != "https" {
+        return Ok(None);
+    }
+    let host = match site.host_str() {
+        Some(host) => host.to_lowercase(),
+        None => return Ok(None),
+    };
+
+    let path = cache_file(app_data_dir, &host);
+    if let Ok(metadata) = fs::metadata(&path) {
+        if metadata.len() > 0 {
+            return Ok(fs::read(&path).ok().and_then(|bytes| to_data_url(&bytes)));
+        }
+        // 空文件表示上次获取失败
+        let age = metadata
+            .modified()
+            .ok()
+            .and_then(|t| SystemTime::now().duration_since(t).ok())
+            .unwrap_or_default();
+        if age < NEGATIVE_CACHE_TTL {
+            return Ok(None);
+        }
+    }
+
+    // 离线时不下载，也不写入失败缓存，联网后可以重新获取
+    if !crate::network::is_online() {
+        return Ok(None);
+    }
+    let bytes = download_favicon(&site).await;
+    fs::create_dir_all(favicons_dir(app_data_dir))
+        .map_err(|e| format!("Failed to create favicons directory: {}", e))?;
+    fs::write(&path, bytes.as_deref().unwrap_or_default())
+        .map_err(|e| format!("Failed to write favicon cache: {}", e))?;
+
+    Ok(bytes.and_then(|bytes| to_data_url(&bytes)))
+}
+
+/// 清空图标缓存，返回删除的文件数
+pub fn clear_cache(app_data_dir: &Path) -> Result<usize, String> {
+    let dir = favicons_dir(app_data_dir);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(0),
+    };
+    let mut removed = 0;
+    for entry in entries.filter_map(|e| e.ok()) {
+        if fs::remove_file(entry.path()).is_ok() {
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_icon_link_href() {
+        let html = r#"<head><link rel="stylesheet" href="a.css"><link rel="shortcut icon" href='/static/fav.png'></head>"#;
+        assert_eq!(find_icon_href(html).as_deref(), Some("/static/fav.png"));
+        assert_eq!(find_icon_href(r#"<link rel=stylesheet href=a.css>"#), None);
+    }
+
+    #[test]
+    fn sniffs_image_types() {
+        assert_eq!(sniff_mime(b"\x89PNG\r\n"), Some("image/png"));
+        assert_eq!(sniff_mime(&[0, 0, 1, 0, 1]), Some("image/x-icon"));
+        assert_eq!(sniff_mime(b"<svg xmlns='http://www.w3.org/2000/svg'/>"), Some("image/svg+xml"));
+        assert_eq!(sniff_mime(b"<!DOCTYPE html>"), None);
+    }
+}
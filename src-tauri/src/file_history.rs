@@ -1,6 +1,6 @@
 use crate::db;
-#[cfg(target_os = "windows")]
-use pinyin::ToPinyin;
+use crate::path_expand;
+use crate::scoring;
 use rusqlite::params;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -226,8 +226,9 @@ pub fn add_file_path(path: String, app_data_dir: &Path) -> Result<(), String> {
         
         (url, false, name)
     } else {
-        // Handle file system path
-        let trimmed = trimmed.trim_end_matches(|c| c == '\\' || c == '/');
+        // Handle file system path (expand %VAR%, $VAR and ~ first)
+        let expanded = path_expand::expand_path(trimmed);
+        let trimmed = expanded.trim_end_matches(|c| c == '\\' || c == '/');
         
         // Normalize path (convert to absolute if relative)
         let path_buf = PathBuf::from(trimmed);
@@ -297,40 +298,6 @@ pub fn add_file_path(path: String, app_data_dir: &Path) -> Result<(), String> {
     Ok(())
 }
 
-// Convert Chinese characters to pinyin (full pinyin)
-#[cfg(target_os = "windows")]
-fn to_pinyin(text: &str) -> String {
-    text.to_pinyin()
-        .filter_map(|p| p.map(|p| p.plain()))
-        .collect::<Vec<_>>()
-        .join("")
-}
-
-// Convert Chinese characters to pinyin initials (first letter of each pinyin)
-#[cfg(target_os = "windows")]
-fn to_pinyin_initials(text: &str) -> String {
-    text.to_pinyin()
-        .filter_map(|p| p.map(|p| p.plain().chars().next()))
-        .flatten()
-        .collect::<String>()
-}
-
-// Check if text contains Chinese characters
-#[cfg(target_os = "windows")]
-fn contains_chinese(text: &str) -> bool {
-    text.chars().any(|c| {
-        matches!(c as u32,
-            0x4E00..=0x9FFF |  // CJK Unified Ideographs
-            0x3400..=0x4DBF |  // CJK Extension A
-            0x20000..=0x2A6DF | // CJK Extension B
-            0x2A700..=0x2B73F | // CJK Extension C
-            0x2B740..=0x2B81F | // CJK Extension D
-            0xF900..=0xFAFF |  // CJK Compatibility Ideographs
-            0x2F800..=0x2FA1F   // CJK Compatibility Ideographs Supplement
-        )
-    })
-}
-
 // Get a lock guard - caller must ensure no nested locking
 // 临时改为使用读锁（RwLock），读操作不需要阻塞，提升性能
 pub fn lock_history(
@@ -374,61 +341,15 @@ pub fn search_in_history(
         return items;
     }
 
-    let query_lower = query.to_lowercase();
-    #[cfg(target_os = "windows")]
-    let query_is_pinyin = !contains_chinese(&query_lower);
+    let query = scoring::Query::for_provider(query, "file_history");
 
     let mut results: Vec<(FileHistoryItem, i32)> = state
         .values()
         .filter_map(|item| {
-            let name_lower = item.name.to_lowercase();
-            let path_lower = item.path.to_lowercase();
-
-            let mut score = 0;
-
-            // Direct text match (highest priority)
-            if name_lower == query_lower {
-                score += 1000;
-            } else if name_lower.starts_with(&query_lower) {
-                score += 500;
-            } else if name_lower.contains(&query_lower) {
-                score += 100;
-            }
-
-            // Pinyin matching (if query is pinyin, Windows only)
-            #[cfg(target_os = "windows")]
-            if query_is_pinyin {
-                let name_pinyin = to_pinyin(&item.name).to_lowercase();
-                let name_pinyin_initials = to_pinyin_initials(&item.name).to_lowercase();
-
-                // Full pinyin match
-                if name_pinyin == query_lower {
-                    score += 800;
-                } else if name_pinyin.starts_with(&query_lower) {
-                    score += 400;
-                } else if name_pinyin.contains(&query_lower) {
-                    score += 150;
-                }
-
-                // Pinyin initials match
-                if name_pinyin_initials == query_lower {
-                    score += 600;
-                } else if name_pinyin_initials.starts_with(&query_lower) {
-                    score += 300;
-                } else if name_pinyin_initials.contains(&query_lower) {
-                    score += 120;
-                }
-            }
-
-            // Path match gets lower score
-            if path_lower.contains(&query_lower) {
-                score += 10;
-            }
-
+            let score = scoring::name_score(&query, &item.name) + scoring::path_score(&query, &item.path);
             if score > 0 {
-                // Boost score by use_count and recency
-                score += (item.use_count as i32).min(100); // Max 100 bonus points
-                Some((item.clone(), score))
+                // Boost score by use_count
+                Some((item.clone(), score + scoring::frecency_bonus(&query, item.use_count)))
             } else {
                 None
             }
@@ -681,8 +602,29 @@ fn maybe_migrate_from_json(
     Ok(())
 }
 
+/// 带 scheme:// 前缀的地址（http、file、vscode 等）
+fn has_url_scheme(input: &str) -> bool {
+    input.split_once("://").is_some_and(|(scheme, _)| {
+        let mut chars = scheme.chars();
+        chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+/// 文件系统路径展开 ~ 与环境变量；网址原样保留，避免其中的 $name、%NAME% 被替换
+fn expand_launch_target(input: &str) -> String {
+    let trimmed = input.trim();
+    if has_url_scheme(trimmed) {
+        trimmed.to_string()
+    } else {
+        path_expand::expand_path(trimmed)
+    }
+}
+
 pub fn launch_file(path: &str) -> Result<(), String> {
-    let trimmed = path.trim();
+    // 支持 %USERPROFILE%\Desktop、~/docs 这类输入
+    let expanded = expand_launch_target(path);
+    let trimmed = expanded.as_str();
     
     #[cfg(target_os = "windows")]
     {
@@ -793,7 +735,7 @@ pub fn launch_file(path: &str) -> Result<(), String> {
         use std::process::Command;
         // On Unix-like systems, use xdg-open
         Command::new("xdg-open")
-            .arg(path)
+            .arg(trimmed)
             .spawn()
             .map_err(|e| format!("Failed to launch file: {}", e))?;
     }
@@ -864,4 +806,14 @@ mod tests {
         assert_eq!(file_item.is_folder, Some(false));
         assert_eq!(folder_item.is_folder, Some(true));
     }
+
+    #[test]
+    fn test_launch_target_expands_paths_but_not_urls() {
+        std::env::set_var("IMISS_LAUNCH_TEST", "expanded");
+        let url = "https://example.com/search?q=$IMISS_LAUNCH_TEST&v=%IMISS_LAUNCH_TEST%";
+        assert_eq!(expand_launch_target(url), url);
+        assert_eq!(expand_launch_target(" vscode://file/$IMISS_LAUNCH_TEST "), "vscode://file/$IMISS_LAUNCH_TEST");
+        assert_eq!(expand_launch_target("$IMISS_LAUNCH_TEST/docs"), "expanded/docs");
+        assert_eq!(expand_launch_target("%IMISS_LAUNCH_TEST%\\a://b"), "expanded\\a://b");
+    }
 }
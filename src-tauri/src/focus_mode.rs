@@ -0,0 +1,144 @@
+// 勿扰模式（演示模式）：手动开启，或在前台窗口真正全屏（无标题栏，如游戏、演示、全屏视频）时自动进入
+// 勿扰期间全局快捷键不再弹出启动器抢占焦点，回放等自动执行的任务暂停，系统通知统一经 show_notification 发送并在勿扰期间跳过
+
+use crate::shutdown;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_notification::NotificationExt;
+
+const DETECT_INTERVAL: Duration = Duration::from_secs(2);
+
+static MANUAL: AtomicBool = AtomicBool::new(false);
+static AUTO_DETECT: AtomicBool = AtomicBool::new(false);
+static FULLSCREEN: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusModeStatus {
+    pub active: bool,
+    pub manual: bool,
+    pub auto_detect: bool,
+    pub fullscreen: bool, // 当前前台窗口是否全屏
+}
+
+pub fn status() -> FocusModeStatus {
+    let manual = MANUAL.load(Ordering::Relaxed);
+    let auto_detect = AUTO_DETECT.load(Ordering::Relaxed);
+    let fullscreen = FULLSCREEN.load(Ordering::Relaxed);
+    FocusModeStatus {
+        active: manual || (auto_detect && fullscreen),
+        manual,
+        auto_detect,
+        fullscreen,
+    }
+}
+
+/// 当前是否处于勿扰模式
+pub fn is_active() -> bool {
+    status().active
+}
+
+/// 发送系统通知；勿扰期间不弹出，直接返回成功
+pub fn show_notification(app_handle: &AppHandle, title: &str, body: &str, sound: Option<&str>) -> Result<(), String> {
+    if is_active() {
+        eprintln!("[FocusMode] Notification suppressed: {}", title);
+        return Ok(());
+    }
+    let mut builder = app_handle.notification().builder().title(title).body(body);
+    if let Some(sound) = sound {
+        builder = builder.sound(sound);
+    }
+    builder.show().map_err(|e| format!("发送通知失败: {}", e))
+}
+
+fn notify(app_handle: &AppHandle) {
+    let _ = app_handle.emit("focus-mode-changed", status());
+}
+
+/// 手动开启 / 关闭勿扰模式
+pub fn set_manual(app_handle: &AppHandle, enabled: bool) -> FocusModeStatus {
+    if MANUAL.swap(enabled, Ordering::Relaxed) != enabled {
+        notify(app_handle);
+    }
+    status()
+}
+
+/// 是否在前台全屏时自动进入勿扰模式（来自设置 dnd_auto_detect）
+pub fn set_auto_detect(app_handle: &AppHandle, enabled: bool) {
+    if AUTO_DETECT.swap(enabled, Ordering::Relaxed) != enabled {
+        notify(app_handle);
+    }
+}
+
+/// 后台轮询前台窗口是否全屏，状态变化时发送 focus-mode-changed 事件
+pub fn start_fullscreen_watcher(app_handle: AppHandle) -> Result<(), String> {
+    shutdown::spawn("focus-mode-watcher", move || {
+        while !shutdown::wait_timeout(DETECT_INTERVAL) {
+            let fullscreen = is_foreground_fullscreen();
+            if FULLSCREEN.swap(fullscreen, Ordering::Relaxed) != fullscreen {
+                notify(&app_handle);
+            }
+        }
+    })
+}
+
+/// 前台窗口是否真正全屏：覆盖整个显示器且没有标题栏、不是最大化的普通窗口（排除桌面、任务栏和本程序自身的窗口）
+#[cfg(target_os = "windows")]
+fn is_foreground_fullscreen() -> bool {
+    use windows_sys::Win32::Foundation::RECT;
+    use windows_sys::Win32::Graphics::Gdi::{GetMonitorInfoW, MonitorFromWindow, MONITORINFO, MONITOR_DEFAULTTONEAREST};
+    use windows_sys::Win32::System::Threading::GetCurrentProcessId;
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        GetClassNameW, GetDesktopWindow, GetForegroundWindow, GetShellWindow, GetWindowLongW, GetWindowRect,
+        GetWindowThreadProcessId, IsWindowVisible, IsZoomed, GWL_STYLE, WS_CAPTION,
+    };
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        if hwnd == 0 || hwnd == GetDesktopWindow() || hwnd == GetShellWindow() || IsWindowVisible(hwnd) == 0 {
+            return false;
+        }
+
+        let mut pid: u32 = 0;
+        GetWindowThreadProcessId(hwnd, &mut pid);
+        if pid == GetCurrentProcessId() {
+            return false;
+        }
+
+        // 桌面（Progman / WorkerW）在点击后也会成为前台窗口
+        let mut class_name = [0u16; 64];
+        let len = GetClassNameW(hwnd, class_name.as_mut_ptr(), class_name.len() as i32);
+        let class_name = String::from_utf16_lossy(&class_name[..len.max(0) as usize]);
+        if matches!(class_name.as_str(), "Progman" | "WorkerW" | "Shell_TrayWnd") {
+            return false;
+        }
+
+        // 最大化或带标题栏的窗口（如任务栏自动隐藏时的最大化窗口）不算全屏
+        if IsZoomed(hwnd) != 0 || (GetWindowLongW(hwnd, GWL_STYLE) as u32 & WS_CAPTION) == WS_CAPTION {
+            return false;
+        }
+
+        let mut window_rect: RECT = std::mem::zeroed();
+        if GetWindowRect(hwnd, &mut window_rect) == 0 {
+            return false;
+        }
+        let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+        let mut info: MONITORINFO = std::mem::zeroed();
+        info.cbSize = std::mem::size_of::<MONITORINFO>() as u32;
+        if GetMonitorInfoW(monitor, &mut info) == 0 {
+            return false;
+        }
+
+        let screen = info.rcMonitor;
+        window_rect.left <= screen.left
+            && window_rect.top <= screen.top
+            && window_rect.right >= screen.right
+            && window_rect.bottom >= screen.bottom
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn is_foreground_fullscreen() -> bool {
+    false
+}
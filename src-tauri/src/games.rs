@@ -0,0 +1,324 @@
+// 游戏搜索：扫描 Steam（libraryfolders.vdf / appmanifest_*.acf）与 Epic（Manifests/*.item）已安装的游戏
+// 通过 steam:// 或 com.epicgames.launcher:// 协议启动
+
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+// 扫描结果缓存（图标提取较慢，避免每次搜索都重新扫描）
+static GAMES_CACHE: LazyLock<Mutex<Option<Vec<GameInfo>>>> = LazyLock::new(|| Mutex::new(None));
+
+// Steam 中不是游戏的常见条目（运行库、工具）
+const STEAM_IGNORED_APP_IDS: &[&str] = &["228980", "1070560", "1391110", "1628350"];
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GameInfo {
+    pub id: String,              // "steam:<appid>" | "epic:<AppName>"
+    pub name: String,
+    pub platform: String,        // "steam" | "epic"
+    pub install_dir: Option<String>,
+    pub launch_uri: String,
+    pub icon: Option<String>,    // base64 图片（不带 data: 前缀）
+}
+
+/// 极简 VDF（Valve KeyValues）解析：返回扁平化的 "a/b/c" -> value 映射
+pub fn parse_vdf(content: &str) -> HashMap<String, String> {
+    let mut result = HashMap::new();
+    let mut stack: Vec<String> = Vec::new();
+    let mut pending_key: Option<String> = None;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let mut token = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                token.push(escaped);
+                            }
+                        }
+                        '"' => break,
+                        c => token.push(c),
+                    }
+                }
+                match pending_key.take() {
+                    Some(key) => {
+                        let mut full = stack.clone();
+                        full.push(key.to_lowercase());
+                        result.insert(full.join("/"), token);
+                    }
+                    None => pending_key = Some(token),
+                }
+            }
+            '{' => {
+                if let Some(key) = pending_key.take() {
+                    stack.push(key.to_lowercase());
+                }
+            }
+            '}' => {
+                stack.pop();
+                pending_key = None;
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                // 行注释
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    result
+}
+
+#[cfg(target_os = "windows")]
+fn read_registry_string(sub_key: &str, value_name: &str) -> Option<String> {
+    use std::ffi::OsStr;
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_SZ};
+
+    let to_wide = |s: &str| -> Vec<u16> { OsStr::new(s).encode_wide().chain(Some(0)).collect() };
+    let sub_key_wide = to_wide(sub_key);
+    let value_wide = to_wide(value_name);
+    let mut buffer = vec![0u16; 1024];
+    let mut size = (buffer.len() * 2) as u32;
+
+    let result = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            sub_key_wide.as_ptr(),
+            value_wide.as_ptr(),
+            RRF_RT_REG_SZ,
+            std::ptr::null_mut(),
+            buffer.as_mut_ptr() as *mut _,
+            &mut size,
+        )
+    };
+    if result != 0 {
+        return None;
+    }
+    let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    Some(String::from_utf16_lossy(&buffer[..len]))
+}
+
+fn steam_root() -> Option<PathBuf> {
+    #[cfg(target_os = "windows")]
+    if let Some(path) = read_registry_string("Software\\Valve\\Steam", "SteamPath") {
+        let path = PathBuf::from(path.replace('/', "\\"));
+        if path.exists() {
+            return Some(path);
+        }
+    }
+
+    let default = PathBuf::from("C:\\Program Files (x86)\\Steam");
+    default.exists().then_some(default)
+}
+
+fn encode_image_file(path: &Path) -> Option<String> {
+    fs::read(path)
+        .ok()
+        .map(|bytes| general_purpose::STANDARD.encode(bytes))
+}
+
+/// Steam 图标：旧版为 librarycache/<appid>_icon.jpg，新版为 librarycache/<appid>/<sha1>.jpg
+fn steam_icon(steam_root: &Path, app_id: &str) -> Option<String> {
+    let cache_dir = steam_root.join("appcache").join("librarycache");
+    let legacy = cache_dir.join(format!("{}_icon.jpg", app_id));
+    if legacy.exists() {
+        return encode_image_file(&legacy);
+    }
+
+    fs::read_dir(cache_dir.join(app_id))
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .find(|p| {
+            p.extension().map(|e| e == "jpg").unwrap_or(false)
+                && p.file_stem()
+                    .map(|s| {
+                        let s = s.to_string_lossy();
+                        s.len() == 40 && s.chars().all(|c| c.is_ascii_hexdigit())
+                    })
+                    .unwrap_or(false)
+        })
+        .and_then(|p| encode_image_file(&p))
+}
+
+fn scan_steam_games() -> Vec<GameInfo> {
+    let root = match steam_root() {
+        Some(root) => root,
+        None => return Vec::new(),
+    };
+
+    let mut libraries = vec![root.clone()];
+    if let Ok(content) = fs::read_to_string(root.join("steamapps").join("libraryfolders.vdf")) {
+        for (key, value) in parse_vdf(&content) {
+            // libraryfolders/<n>/path（新格式）或 libraryfolders/<n>（旧格式直接是路径）
+            let parts: Vec<&str> = key.split('/').collect();
+            let is_path = matches!(parts.as_slice(), ["libraryfolders", _, "path"])
+                || matches!(parts.as_slice(), ["libraryfolders", n] if n.chars().all(|c| c.is_ascii_digit()));
+            if is_path {
+                let library = PathBuf::from(value);
+                if !libraries.contains(&library) {
+                    libraries.push(library);
+                }
+            }
+        }
+    }
+
+    let mut games = Vec::new();
+    for library in libraries {
+        let steamapps = library.join("steamapps");
+        let entries = match fs::read_dir(&steamapps) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if !file_name.starts_with("appmanifest_") || !file_name.ends_with(".acf") {
+                continue;
+            }
+            let manifest = match fs::read_to_string(entry.path()) {
+                Ok(content) => parse_vdf(&content),
+                Err(_) => continue,
+            };
+            let (app_id, name) = match (manifest.get("appstate/appid"), manifest.get("appstate/name")) {
+                (Some(id), Some(name)) => (id.clone(), name.clone()),
+                _ => continue,
+            };
+            if STEAM_IGNORED_APP_IDS.contains(&app_id.as_str()) {
+                continue;
+            }
+            let install_dir = manifest
+                .get("appstate/installdir")
+                .map(|dir| steamapps.join("common").join(dir).to_string_lossy().to_string());
+
+            games.push(GameInfo {
+                id: format!("steam:{}", app_id),
+                icon: steam_icon(&root, &app_id),
+                launch_uri: format!("steam://rungameid/{}", app_id),
+                name,
+                platform: "steam".to_string(),
+                install_dir,
+            });
+        }
+    }
+    games
+}
+
+fn epic_manifests_dir() -> PathBuf {
+    let program_data = std::env::var("ProgramData").unwrap_or_else(|_| "C:\\ProgramData".to_string());
+    PathBuf::from(program_data)
+        .join("Epic")
+        .join("EpicGamesLauncher")
+        .join("Data")
+        .join("Manifests")
+}
+
+#[cfg(target_os = "windows")]
+fn extract_exe_icon(exe_path: &Path) -> Option<String> {
+    crate::app_search::windows::extract_icon_base64(exe_path)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn extract_exe_icon(_exe_path: &Path) -> Option<String> {
+    None
+}
+
+fn scan_epic_games() -> Vec<GameInfo> {
+    let entries = match fs::read_dir(epic_manifests_dir()) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut games = Vec::new();
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if path.extension().map(|e| e != "item").unwrap_or(true) {
+            continue;
+        }
+        let manifest: serde_json::Value = match fs::read_to_string(&path)
+            .ok()
+            .and_then(|c| serde_json::from_str(&c).ok())
+        {
+            Some(manifest) => manifest,
+            None => continue,
+        };
+        let field = |key: &str| manifest.get(key).and_then(|v| v.as_str()).unwrap_or("").to_string();
+
+        let (name, app_name) = (field("DisplayName"), field("AppName"));
+        let (namespace, item_id) = (field("CatalogNamespace"), field("CatalogItemId"));
+        if name.is_empty() || app_name.is_empty() || namespace.is_empty() || item_id.is_empty() {
+            continue;
+        }
+        // 插件 / DLC 的 AppName 与主游戏相同，MainGameAppName 不同时跳过
+        let main_game = field("MainGameAppName");
+        if !main_game.is_empty() && main_game != app_name {
+            continue;
+        }
+
+        let install_location = field("InstallLocation");
+        let launch_exe = field("LaunchExecutable");
+        let icon = if install_location.is_empty() || launch_exe.is_empty() {
+            None
+        } else {
+            extract_exe_icon(&Path::new(&install_location).join(&launch_exe))
+        };
+
+        games.push(GameInfo {
+            id: format!("epic:{}", app_name),
+            name,
+            platform: "epic".to_string(),
+            install_dir: (!install_location.is_empty()).then_some(install_location),
+            launch_uri: format!(
+                "com.epicgames.launcher://apps/{}%3A{}%3A{}?action=launch&silent=true",
+                namespace, item_id, app_name
+            ),
+            icon,
+        });
+    }
+    games
+}
+
+/// 重新扫描已安装的游戏并更新缓存
+pub fn refresh_games() -> Vec<GameInfo> {
+    let mut games = scan_steam_games();
+    games.extend(scan_epic_games());
+    games.sort_by_cached_key(|g| g.name.to_lowercase());
+
+    if let Ok(mut cache) = GAMES_CACHE.lock() {
+        *cache = Some(games.clone());
+    }
+    games
+}
+
+/// 获取已安装的游戏（优先使用缓存）
+pub fn get_games() -> Vec<GameInfo> {
+    if let Ok(cache) = GAMES_CACHE.lock() {
+        if let Some(ref games) = *cache {
+            return games.clone();
+        }
+    }
+    refresh_games()
+}
+
+/// 按名称搜索游戏
+pub fn search_games(query: &str) -> Vec<GameInfo> {
+    let query_lower = query.trim().to_lowercase();
+    let games = get_games();
+    if query_lower.is_empty() {
+        return games;
+    }
+    games
+        .into_iter()
+        .filter(|g| g.name.to_lowercase().contains(&query_lower))
+        .collect()
+}
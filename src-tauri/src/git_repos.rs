@@ -0,0 +1,310 @@
+// Git 仓库搜索：扫描配置的根目录，索引其中的 git 仓库（名称、路径、当前分支、最后提交时间）
+// 刷新为增量方式：已索引的仓库仅在 .git/HEAD 或 .git/logs/HEAD 变化时重新读取
+
+use crate::db;
+use crate::path_expand;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+// 根目录下的最大扫描深度
+const MAX_SCAN_DEPTH: usize = 4;
+
+// 扫描时跳过的目录（体积大且不可能包含需要索引的仓库）
+const SKIP_DIRS: &[&str] = &["node_modules", "target", "vendor", "dist", "build", "$RECYCLE.BIN"];
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GitRepo {
+    pub path: String,
+    pub name: String,
+    pub branch: Option<String>,           // 当前分支；分离 HEAD 时为提交哈希前 7 位
+    pub last_commit_time: Option<i64>,    // 最后一次提交 / 切换的时间（秒）
+    pub head_mtime: i64,                  // 用于增量刷新的 HEAD 修改时间签名
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GitRepoRefreshResult {
+    pub total: usize,
+    pub added: usize,
+    pub updated: usize,
+    pub removed: usize,
+}
+
+/// 解析仓库的 git 目录（支持 worktree / submodule 中 .git 为文件的情况）
+fn resolve_git_dir(repo_path: &Path) -> Option<PathBuf> {
+    let dot_git = repo_path.join(".git");
+    if dot_git.is_dir() {
+        return Some(dot_git);
+    }
+    if dot_git.is_file() {
+        let content = fs::read_to_string(&dot_git).ok()?;
+        let gitdir = content.trim().strip_prefix("gitdir:")?.trim();
+        let gitdir = PathBuf::from(gitdir);
+        let resolved = if gitdir.is_absolute() {
+            gitdir
+        } else {
+            repo_path.join(gitdir)
+        };
+        return resolved.is_dir().then_some(resolved);
+    }
+    None
+}
+
+fn mtime_secs(path: &Path) -> Option<i64> {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs() as i64)
+}
+
+fn head_signature(git_dir: &Path) -> i64 {
+    let head = mtime_secs(&git_dir.join("HEAD")).unwrap_or(0);
+    let logs = mtime_secs(&git_dir.join("logs").join("HEAD")).unwrap_or(0);
+    head.max(logs)
+}
+
+/// 从 HEAD 文件内容解析当前分支
+pub fn parse_head(content: &str) -> Option<String> {
+    let content = content.trim();
+    if let Some(reference) = content.strip_prefix("ref:") {
+        let reference = reference.trim();
+        return Some(
+            reference
+                .strip_prefix("refs/heads/")
+                .unwrap_or(reference)
+                .to_string(),
+        );
+    }
+    if content.len() >= 7 && content.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Some(content[..7].to_string());
+    }
+    None
+}
+
+/// 从 logs/HEAD 的最后一行解析时间戳
+/// 行格式：<old> <new> Name <email> <timestamp> <tz>\t<message>
+pub fn parse_reflog_time(content: &str) -> Option<i64> {
+    let line = content.lines().rev().find(|l| !l.trim().is_empty())?;
+    let header = line.split('\t').next()?;
+    let after_email = &header[header.rfind('>')? + 1..];
+    after_email.split_whitespace().next()?.parse().ok()
+}
+
+fn read_repo(repo_path: &Path, git_dir: &Path) -> GitRepo {
+    let branch = fs::read_to_string(git_dir.join("HEAD"))
+        .ok()
+        .and_then(|c| parse_head(&c));
+    let last_commit_time = fs::read_to_string(git_dir.join("logs").join("HEAD"))
+        .ok()
+        .and_then(|c| parse_reflog_time(&c))
+        .or_else(|| mtime_secs(&git_dir.join("HEAD")));
+    let name = repo_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| repo_path.to_string_lossy().to_string());
+
+    GitRepo {
+        path: repo_path.to_string_lossy().to_string(),
+        name,
+        branch,
+        last_commit_time,
+        head_mtime: head_signature(git_dir),
+    }
+}
+
+fn find_repos(dir: &Path, depth: usize, found: &mut Vec<(PathBuf, PathBuf)>) {
+    if let Some(git_dir) = resolve_git_dir(dir) {
+        found.push((dir.to_path_buf(), git_dir));
+        return;
+    }
+    if depth >= MAX_SCAN_DEPTH {
+        return;
+    }
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.filter_map(|e| e.ok()) {
+        let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+        if !is_dir {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') || SKIP_DIRS.iter().any(|s| s.eq_ignore_ascii_case(&name)) {
+            continue;
+        }
+        find_repos(&entry.path(), depth + 1, found);
+    }
+}
+
+fn load_repos(conn: &rusqlite::Connection) -> Result<Vec<GitRepo>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT path, name, branch, last_commit_time, head_mtime FROM git_repos
+             ORDER BY last_commit_time DESC",
+        )
+        .map_err(|e| format!("Failed to prepare git repos query: {}", e))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(GitRepo {
+                path: row.get(0)?,
+                name: row.get(1)?,
+                branch: row.get(2)?,
+                last_commit_time: row.get(3)?,
+                head_mtime: row.get(4)?,
+            })
+        })
+        .map_err(|e| format!("Failed to iterate git repos rows: {}", e))?;
+
+    let mut repos = Vec::new();
+    for row in rows {
+        repos.push(row.map_err(|e| format!("Failed to read git repos row: {}", e))?);
+    }
+    Ok(repos)
+}
+
+/// 获取已索引的仓库（按最后提交时间倒序）
+pub fn get_git_repos(app_data_dir: &Path) -> Result<Vec<GitRepo>, String> {
+    let conn = db::get_connection(app_data_dir)?;
+    load_repos(&conn)
+}
+
+/// 扫描根目录并增量更新仓库索引
+pub fn refresh_git_repos(roots: &[String], app_data_dir: &Path) -> Result<GitRepoRefreshResult, String> {
+    let mut found = Vec::new();
+    for root in roots {
+        let root = PathBuf::from(path_expand::expand_path(root));
+        if root.is_dir() {
+            find_repos(&root, 0, &mut found);
+        }
+    }
+
+    let mut conn = db::get_connection(app_data_dir)?;
+    let existing: HashMap<String, i64> = load_repos(&conn)?
+        .into_iter()
+        .map(|r| (r.path, r.head_mtime))
+        .collect();
+
+    let tx = conn
+        .transaction()
+        .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+    let mut result = GitRepoRefreshResult {
+        total: 0,
+        added: 0,
+        updated: 0,
+        removed: 0,
+    };
+    let mut seen = HashSet::new();
+
+    for (repo_path, git_dir) in &found {
+        let path_str = repo_path.to_string_lossy().to_string();
+        if !seen.insert(path_str.clone()) {
+            continue;
+        }
+        match existing.get(&path_str) {
+            Some(&mtime) if mtime == head_signature(git_dir) => continue,
+            Some(_) => result.updated += 1,
+            None => result.added += 1,
+        }
+
+        let repo = read_repo(repo_path, git_dir);
+        tx.execute(
+            "INSERT INTO git_repos (path, name, branch, last_commit_time, head_mtime)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(path) DO UPDATE SET
+                name = excluded.name,
+                branch = excluded.branch,
+                last_commit_time = excluded.last_commit_time,
+                head_mtime = excluded.head_mtime",
+            params![
+                repo.path,
+                repo.name,
+                repo.branch,
+                repo.last_commit_time,
+                repo.head_mtime
+            ],
+        )
+        .map_err(|e| format!("Failed to save git repo: {}", e))?;
+    }
+
+    // 移除已不存在或不在根目录下的仓库
+    for path in existing.keys().filter(|p| !seen.contains(*p)) {
+        tx.execute("DELETE FROM git_repos WHERE path = ?1", params![path])
+            .map_err(|e| format!("Failed to delete git repo: {}", e))?;
+        result.removed += 1;
+    }
+
+    tx.commit()
+        .map_err(|e| format!("Failed to commit git repos: {}", e))?;
+
+    result.total = seen.len();
+    Ok(result)
+}
+
+/// 按名称或路径搜索已索引的仓库
+pub fn search_git_repos(query: &str, app_data_dir: &Path) -> Result<Vec<GitRepo>, String> {
+    let repos = get_git_repos(app_data_dir)?;
+    let query_lower = query.trim().to_lowercase();
+    if query_lower.is_empty() {
+        return Ok(repos);
+    }
+
+    let mut matched: Vec<(u8, GitRepo)> = repos
+        .into_iter()
+        .filter_map(|repo| {
+            let name_lower = repo.name.to_lowercase();
+            let score = if name_lower == query_lower {
+                3
+            } else if name_lower.starts_with(&query_lower) {
+                2
+            } else if name_lower.contains(&query_lower) {
+                1
+            } else if repo.path.to_lowercase().contains(&query_lower) {
+                0
+            } else {
+                return None;
+            };
+            Some((score, repo))
+        })
+        .collect();
+
+    // 稳定排序：同分时保持最后提交时间倒序
+    matched.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    Ok(matched.into_iter().map(|(_, repo)| repo).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_head() {
+        assert_eq!(parse_head("ref: refs/heads/main\n").as_deref(), Some("main"));
+        assert_eq!(
+            parse_head("ref: refs/heads/feature/login").as_deref(),
+            Some("feature/login")
+        );
+        assert_eq!(
+            parse_head("0123456789abcdef0123456789abcdef01234567").as_deref(),
+            Some("0123456")
+        );
+        assert_eq!(parse_head("garbage"), None);
+    }
+
+    #[test]
+    fn test_parse_reflog_time() {
+        let content = "\
+0000 1111 A <a@example.com> 1700000000 +0800\tcommit (initial): init
+1111 2222 B <b@example.com> 1700000500 +0000\tcheckout: moving from main to dev
+";
+        assert_eq!(parse_reflog_time(content), Some(1700000500));
+        assert_eq!(parse_reflog_time(""), None);
+    }
+}
@@ -0,0 +1,443 @@
+// Git 配置同步：把设置、片段（备忘录）和快捷链接导出为用户指定 git 仓库中的 JSON 文件，便于版本管理和审阅
+// 拉取时先提交本机的修改再与远端合并，冲突交给用户在仓库中处理；合并后的文件再导入本机
+
+use crate::cloud_sync;
+use crate::db;
+use crate::memos::{self, MemoItem};
+use crate::settings::{self, Settings};
+use crate::shortcuts::{self, ShortcutItem};
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+const CONFIG_KEY: &str = "git_sync"; // settings 表中保存配置的键
+const DEFAULT_SUBDIR: &str = "imiss";
+const SETTINGS_FILE: &str = "settings.json";
+const SNIPPETS_FILE: &str = "snippets.json";
+const QUICKLINKS_FILE: &str = "quicklinks.json";
+const DEFAULT_COMMIT_MESSAGE: &str = "Update IMiss configuration";
+// 仓库未配置提交身份时使用
+const FALLBACK_IDENTITY: [&str; 4] = ["-c", "user.name=IMiss", "-c", "user.email=imiss@localhost"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitSyncConfig {
+    pub repo_path: String,
+    pub subdir: String, // 仓库中存放配置的子目录，为空时使用 imiss
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GitCommitInfo {
+    pub hash: String,
+    pub message: String,
+    pub author: String,
+    pub time: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GitSyncStatus {
+    pub repo_path: String,
+    pub subdir: String,
+    pub is_repo: bool,
+    pub branch: Option<String>,
+    pub remote: Option<String>,
+    pub has_changes: bool, // 本机配置与仓库中已提交的版本不同
+    pub last_commit: Option<GitCommitInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GitCommitResult {
+    pub committed: bool,
+    pub commit: Option<GitCommitInfo>,
+    pub pushed: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitPullResult {
+    pub merged: bool, // 远端有新提交并已合并
+    pub settings_updated: bool,
+    pub snippets_updated: usize,
+    pub snippets_removed: usize,
+    pub quicklinks_updated: usize,
+    pub quicklinks_removed: usize,
+}
+
+/// 导出的快捷链接；图标由本机按网址获取，不写入仓库
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Quicklink {
+    id: String,
+    name: String,
+    url: String,
+    created_at: u64,
+    updated_at: u64,
+}
+
+fn hidden_command(program: &str) -> Command {
+    #[allow(unused_mut)]
+    let mut command = Command::new(program);
+    #[cfg(target_os = "windows")]
+    command.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    command
+}
+
+/// 在仓库中执行 git 命令，返回标准输出
+fn git(repo: &Path, args: &[&str]) -> Result<String, String> {
+    let output = hidden_command("git")
+        .arg("-C")
+        .arg(repo)
+        .args(["-c", "core.quotepath=false"])
+        .args(args)
+        .output()
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => "未找到 git，请先安装 Git 并加入 PATH".to_string(),
+            _ => format!("Failed to run git: {}", e),
+        })?;
+    if output.status.success() {
+        return Ok(String::from_utf8_lossy(&output.stdout).trim_end().to_string());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let detail = if stderr.trim().is_empty() { stdout.trim() } else { stderr.trim() };
+    let command = args.iter().find(|arg| !arg.starts_with('-') && !arg.contains('=')).unwrap_or(&"");
+    Err(format!("git {} 失败: {}", command, detail))
+}
+
+/// 提交与合并时使用的身份参数：仓库或全局已配置时为空
+fn identity_args(repo: &Path) -> Vec<&'static str> {
+    match git(repo, &["config", "user.email"]) {
+        Ok(email) if !email.trim().is_empty() => Vec::new(),
+        _ => FALLBACK_IDENTITY.to_vec(),
+    }
+}
+
+fn git_with_identity(repo: &Path, args: &[&str]) -> Result<String, String> {
+    let mut full = identity_args(repo);
+    full.extend_from_slice(args);
+    git(repo, &full)
+}
+
+fn is_repo(repo: &Path) -> bool {
+    git(repo, &["rev-parse", "--is-inside-work-tree"]).is_ok_and(|out| out == "true")
+}
+
+fn first_remote(repo: &Path) -> Option<String> {
+    git(repo, &["remote"]).ok()?.lines().next().map(str::to_string)
+}
+
+fn head(repo: &Path) -> Option<String> {
+    git(repo, &["rev-parse", "HEAD"]).ok()
+}
+
+/// 解析 `git log --format=%h%x1f%s%x1f%an%x1f%ct` 的一行
+fn parse_commit_line(line: &str) -> Option<GitCommitInfo> {
+    let mut parts = line.split('\u{1f}');
+    Some(GitCommitInfo {
+        hash: parts.next()?.to_string(),
+        message: parts.next()?.to_string(),
+        author: parts.next()?.to_string(),
+        time: parts.next()?.trim().parse().ok()?,
+    })
+}
+
+fn last_commit(repo: &Path, subdir: &str) -> Option<GitCommitInfo> {
+    let line = git(repo, &["log", "-1", "--format=%h%x1f%s%x1f%an%x1f%ct", "--", subdir]).ok()?;
+    parse_commit_line(&line)
+}
+
+// ---------- 配置 ----------
+
+pub fn get_config(app_data_dir: &Path) -> Result<GitSyncConfig, String> {
+    let value: Option<String> = db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached("SELECT value FROM settings WHERE key = ?1")
+            .and_then(|mut stmt| stmt.query_row(params![CONFIG_KEY], |row| row.get(0)).optional())
+            .map_err(|e| format!("Failed to read git sync config: {}", e))
+    })?;
+    let mut config: GitSyncConfig = value.and_then(|json| serde_json::from_str(&json).ok()).unwrap_or_default();
+    if config.subdir.trim().is_empty() {
+        config.subdir = DEFAULT_SUBDIR.to_string();
+    }
+    Ok(config)
+}
+
+/// 保存仓库设置；目录还不是 git 仓库时执行 git init
+pub fn save_config(app_data_dir: &Path, mut config: GitSyncConfig) -> Result<GitSyncStatus, String> {
+    config.repo_path = config.repo_path.trim().to_string();
+    config.subdir = config.subdir.trim().trim_matches(|c| c == '/' || c == '\\').to_string();
+    if config.subdir.is_empty() {
+        config.subdir = DEFAULT_SUBDIR.to_string();
+    }
+    if !Path::new(&config.subdir).components().all(|c| matches!(c, Component::Normal(_))) {
+        return Err("子目录必须是仓库内的相对路径".to_string());
+    }
+    if !config.repo_path.is_empty() {
+        let repo = Path::new(&config.repo_path);
+        if !repo.is_dir() {
+            return Err(format!("目录不存在: {}", config.repo_path));
+        }
+        if !is_repo(repo) {
+            git(repo, &["init"])?;
+        }
+    }
+    let value = serde_json::to_string(&config).map_err(|e| format!("Failed to serialize git sync config: {}", e))?;
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "INSERT INTO settings (key, value) VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        )
+        .and_then(|mut stmt| stmt.execute(params![CONFIG_KEY, value]))
+        .map_err(|e| format!("Failed to save git sync config: {}", e))
+    })?;
+    get_status(app_data_dir)
+}
+
+fn repo_of(config: &GitSyncConfig) -> Result<PathBuf, String> {
+    if config.repo_path.is_empty() {
+        return Err("请先设置用于同步的 git 仓库目录".to_string());
+    }
+    let repo = PathBuf::from(&config.repo_path);
+    if !is_repo(&repo) {
+        return Err(format!("{} 不是 git 仓库", config.repo_path));
+    }
+    Ok(repo)
+}
+
+// ---------- 导出与导入 ----------
+
+fn is_quicklink(shortcut: &ShortcutItem) -> bool {
+    let path = shortcut.path.trim().to_lowercase();
+    path.starts_with("http://") || path.starts_with("https://")
+}
+
+fn to_json(value: &impl Serialize) -> Result<String, String> {
+    serde_json::to_string_pretty(value)
+        .map(|json| json + "\n")
+        .map_err(|e| format!("Failed to serialize configuration: {}", e))
+}
+
+fn shareable_settings(app_data_dir: &Path) -> Result<Value, String> {
+    let mut value = serde_json::to_value(settings::load_settings(app_data_dir)?)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    cloud_sync::strip_local_only(&mut value);
+    Ok(value)
+}
+
+/// 本机配置对应的文件内容：(文件名, 内容)；记录按 id 排序，保证导出结果稳定、差异可读
+fn render_files(app_data_dir: &Path) -> Result<Vec<(&'static str, String)>, String> {
+    let mut snippets = memos::get_all_memos(&app_data_dir.to_path_buf())?;
+    snippets.sort_by(|a, b| a.id.cmp(&b.id));
+    let mut quicklinks: Vec<Quicklink> = shortcuts::get_all_shortcuts(app_data_dir)?
+        .into_iter()
+        .filter(is_quicklink)
+        .map(|s| Quicklink { id: s.id, name: s.name, url: s.path, created_at: s.created_at, updated_at: s.updated_at })
+        .collect();
+    quicklinks.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(vec![
+        (SETTINGS_FILE, to_json(&shareable_settings(app_data_dir)?)?),
+        (SNIPPETS_FILE, to_json(&snippets)?),
+        (QUICKLINKS_FILE, to_json(&quicklinks)?),
+    ])
+}
+
+/// 把本机配置写入仓库目录，返回是否有文件变化
+fn export_files(app_data_dir: &Path, dir: &Path) -> Result<bool, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let mut changed = false;
+    for (name, content) in render_files(app_data_dir)? {
+        let path = dir.join(name);
+        if fs::read_to_string(&path).is_ok_and(|existing| existing == content) {
+            continue;
+        }
+        fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        changed = true;
+    }
+    Ok(changed)
+}
+
+fn read_file<T: serde::de::DeserializeOwned>(dir: &Path, name: &str) -> Result<Option<T>, String> {
+    let path = dir.join(name);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    serde_json::from_str(&content).map(Some).map_err(|e| format!("{} 格式无效: {}", name, e))
+}
+
+/// 对比本机与文件中的记录（id -> 内容），返回需要写入的 id 和需要删除的 id
+fn plan_changes(local: &BTreeMap<String, Value>, incoming: &BTreeMap<String, Value>) -> (Vec<String>, Vec<String>) {
+    let upserts = incoming.iter().filter(|(id, value)| local.get(*id) != Some(value)).map(|(id, _)| id.clone()).collect();
+    let removals = local.keys().filter(|id| !incoming.contains_key(*id)).cloned().collect();
+    (upserts, removals)
+}
+
+fn by_id<T: Serialize>(items: &[T], id: impl Fn(&T) -> &str) -> Result<BTreeMap<String, Value>, String> {
+    items
+        .iter()
+        .map(|item| Ok((id(item).to_string(), serde_json::to_value(item).map_err(|e| e.to_string())?)))
+        .collect()
+}
+
+/// 用仓库中的文件更新本机配置；文件中没有的片段和快捷链接会被删除
+fn import_files(app_data_dir: &Path, dir: &Path, result: &mut GitPullResult) -> Result<(), String> {
+    if let Some(incoming) = read_file::<Value>(dir, SETTINGS_FILE)? {
+        if incoming != shareable_settings(app_data_dir)? {
+            let local = serde_json::to_value(settings::load_settings(app_data_dir)?)
+                .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+            let merged: Settings = serde_json::from_value(cloud_sync::merge_local_only(incoming, &local))
+                .map_err(|e| format!("{} 格式无效: {}", SETTINGS_FILE, e))?;
+            settings::save_settings(app_data_dir, &merged)?;
+            result.settings_updated = true;
+        }
+    }
+
+    let memo_dir = app_data_dir.to_path_buf();
+    if let Some(incoming) = read_file::<Vec<MemoItem>>(dir, SNIPPETS_FILE)? {
+        let local = by_id(&memos::get_all_memos(&memo_dir)?, |m| &m.id)?;
+        let (upserts, removals) = plan_changes(&local, &by_id(&incoming, |m| &m.id)?);
+        for memo in incoming.iter().filter(|m| upserts.contains(&m.id)) {
+            memos::upsert_memo(memo, &memo_dir)?;
+        }
+        for id in &removals {
+            memos::delete_memo(id.clone(), &memo_dir)?;
+        }
+        result.snippets_updated = upserts.len();
+        result.snippets_removed = removals.len();
+    }
+
+    if let Some(incoming) = read_file::<Vec<Quicklink>>(dir, QUICKLINKS_FILE)? {
+        let existing: Vec<ShortcutItem> = shortcuts::get_all_shortcuts(app_data_dir)?.into_iter().filter(is_quicklink).collect();
+        let local: Vec<Quicklink> = existing
+            .iter()
+            .map(|s| Quicklink { id: s.id.clone(), name: s.name.clone(), url: s.path.clone(), created_at: s.created_at, updated_at: s.updated_at })
+            .collect();
+        let (upserts, removals) = plan_changes(&by_id(&local, |q| &q.id)?, &by_id(&incoming, |q| &q.id)?);
+        let items: Vec<ShortcutItem> = incoming
+            .into_iter()
+            .filter(|q| upserts.contains(&q.id))
+            .map(|q| ShortcutItem {
+                icon: existing.iter().find(|s| s.id == q.id).and_then(|s| s.icon.clone()),
+                id: q.id,
+                name: q.name,
+                path: q.url,
+                created_at: q.created_at,
+                updated_at: q.updated_at,
+            })
+            .collect();
+        shortcuts::upsert_shortcuts(items, app_data_dir)?;
+        for id in &removals {
+            shortcuts::delete_shortcut(id.clone(), app_data_dir)?;
+        }
+        result.quicklinks_updated = upserts.len();
+        result.quicklinks_removed = removals.len();
+    }
+    Ok(())
+}
+
+// ---------- 提交与拉取 ----------
+
+pub fn get_status(app_data_dir: &Path) -> Result<GitSyncStatus, String> {
+    let config = get_config(app_data_dir)?;
+    let repo = PathBuf::from(&config.repo_path);
+    let is_repo = !config.repo_path.is_empty() && is_repo(&repo);
+    let mut status = GitSyncStatus {
+        repo_path: config.repo_path.clone(),
+        subdir: config.subdir.clone(),
+        is_repo,
+        branch: None,
+        remote: None,
+        has_changes: false,
+        last_commit: None,
+    };
+    if !is_repo {
+        return Ok(status);
+    }
+    status.branch = git(&repo, &["symbolic-ref", "--short", "HEAD"]).ok();
+    status.remote = first_remote(&repo);
+    status.last_commit = last_commit(&repo, &config.subdir);
+    // 本机配置与工作区文件不同，或工作区文件尚未提交
+    let dir = repo.join(&config.subdir);
+    let differs = render_files(app_data_dir)?
+        .iter()
+        .any(|(name, content)| fs::read_to_string(dir.join(name)).ok().as_ref() != Some(content));
+    let uncommitted = git(&repo, &["status", "--porcelain", "--", &config.subdir]).is_ok_and(|out| !out.is_empty());
+    status.has_changes = differs || uncommitted;
+    Ok(status)
+}
+
+fn commit_in(app_data_dir: &Path, repo: &Path, subdir: &str, message: &str) -> Result<Option<GitCommitInfo>, String> {
+    export_files(app_data_dir, &repo.join(subdir))?;
+    git(repo, &["add", "-A", "--", subdir])?;
+    if git(repo, &["status", "--porcelain", "--", subdir])?.is_empty() {
+        return Ok(None);
+    }
+    git_with_identity(repo, &["commit", "-m", message, "--", subdir])?;
+    Ok(last_commit(repo, subdir))
+}
+
+/// 导出本机配置并提交；push 时推送到第一个远端
+pub fn commit(app_data_dir: &Path, message: Option<&str>, push: bool) -> Result<GitCommitResult, String> {
+    let config = get_config(app_data_dir)?;
+    let repo = repo_of(&config)?;
+    let message = message.map(str::trim).filter(|m| !m.is_empty()).unwrap_or(DEFAULT_COMMIT_MESSAGE);
+    let commit = commit_in(app_data_dir, &repo, &config.subdir, message)?;
+    let mut pushed = false;
+    if push {
+        let remote = first_remote(&repo).ok_or_else(|| "仓库没有配置远端，无法推送".to_string())?;
+        git(&repo, &["push", "-u", &remote, "HEAD"])?;
+        pushed = true;
+    }
+    Ok(GitCommitResult { committed: commit.is_some(), commit, pushed })
+}
+
+/// 提交本机的修改后拉取并合并远端，再把合并结果导入本机；合并冲突时放弃合并并提示手动处理
+pub fn pull(app_data_dir: &Path) -> Result<GitPullResult, String> {
+    let config = get_config(app_data_dir)?;
+    let repo = repo_of(&config)?;
+    let remote = first_remote(&repo).ok_or_else(|| "仓库没有配置远端，无法拉取".to_string())?;
+    commit_in(app_data_dir, &repo, &config.subdir, DEFAULT_COMMIT_MESSAGE)?;
+
+    let before = head(&repo);
+    let branch = git(&repo, &["symbolic-ref", "--short", "HEAD"])?;
+    if let Err(e) = git_with_identity(&repo, &["pull", "--no-rebase", "--no-edit", &remote, &branch]) {
+        if git(&repo, &["ls-files", "-u"]).is_ok_and(|out| !out.is_empty()) {
+            let _ = git(&repo, &["merge", "--abort"]);
+            return Err(format!("与远端合并时发生冲突，请在 {} 中手动合并后再拉取", config.repo_path));
+        }
+        return Err(e);
+    }
+
+    let mut result = GitPullResult { merged: head(&repo) != before, ..Default::default() };
+    import_files(app_data_dir, &repo.join(&config.subdir), &mut result)?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn plans_upserts_and_removals() {
+        let local = BTreeMap::from([
+            ("a".to_string(), json!({"title": "same"})),
+            ("b".to_string(), json!({"title": "old"})),
+            ("c".to_string(), json!({"title": "gone"})),
+        ]);
+        let incoming = BTreeMap::from([
+            ("a".to_string(), json!({"title": "same"})),
+            ("b".to_string(), json!({"title": "new"})),
+            ("d".to_string(), json!({"title": "added"})),
+        ]);
+        assert_eq!(plan_changes(&local, &incoming), (vec!["b".to_string(), "d".to_string()], vec!["c".to_string()]));
+
+        let commit = parse_commit_line("1a2b3c4\u{1f}Update config\u{1f}Alice\u{1f}1700000000").unwrap();
+        assert_eq!((commit.hash.as_str(), commit.author.as_str(), commit.time), ("1a2b3c4", "Alice", 1700000000));
+        assert!(parse_commit_line("").is_none());
+    }
+}
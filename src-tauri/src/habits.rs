@@ -0,0 +1,446 @@
+// 习惯打卡：习惯按计划（每天、工作日、每周固定几天或每周几次）打卡，统计连续天数、最长记录与近 30 天完成率
+// 启动器输入 "habit" 列出今天的习惯并回车打卡，"habit add 跑步 每周3次" 添加习惯；打卡按本地日期记录
+
+use crate::db;
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use rusqlite::{params, OptionalExtension};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const RATE_DAYS: i64 = 30;
+const RECENT_DAYS: i64 = 7;
+const WEEKDAY_CN: [&str; 7] = ["一", "二", "三", "四", "五", "六", "日"];
+
+/// 打卡计划
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HabitSchedule {
+    Daily,
+    Weekdays,
+    Days(Vec<Weekday>), // 每周固定的几天
+    Weekly(u32),        // 每周任意几天，共 n 次
+}
+
+impl HabitSchedule {
+    fn is_scheduled(&self, date: NaiveDate) -> bool {
+        match self {
+            HabitSchedule::Daily | HabitSchedule::Weekly(_) => true,
+            HabitSchedule::Weekdays => date.weekday().num_days_from_monday() < 5,
+            HabitSchedule::Days(days) => days.contains(&date.weekday()),
+        }
+    }
+
+    /// 保存到数据库的形式
+    pub fn encode(&self) -> String {
+        match self {
+            HabitSchedule::Daily => "daily".to_string(),
+            HabitSchedule::Weekdays => "weekdays".to_string(),
+            HabitSchedule::Days(days) => format!(
+                "days:{}",
+                days.iter().map(|d| d.num_days_from_monday().to_string()).collect::<Vec<_>>().join(",")
+            ),
+            HabitSchedule::Weekly(times) => format!("weekly:{}", times),
+        }
+    }
+
+    pub fn decode(value: &str) -> Self {
+        match value.split_once(':') {
+            Some(("days", list)) => {
+                let days: Vec<Weekday> =
+                    list.split(',').filter_map(|d| d.parse::<u8>().ok()).filter_map(|d| Weekday::try_from(d).ok()).collect();
+                if days.is_empty() { HabitSchedule::Daily } else { HabitSchedule::Days(days) }
+            }
+            Some(("weekly", n)) => n.parse().ok().filter(|n| (1..=7).contains(n)).map(HabitSchedule::Weekly).unwrap_or(HabitSchedule::Daily),
+            _ if value == "weekdays" => HabitSchedule::Weekdays,
+            _ => HabitSchedule::Daily,
+        }
+    }
+
+    /// 中文描述，如 "每天"、"每周一、三、五"、"每周 3 次"
+    pub fn describe(&self) -> String {
+        match self {
+            HabitSchedule::Daily => "每天".to_string(),
+            HabitSchedule::Weekdays => "工作日".to_string(),
+            HabitSchedule::Days(days) => format!(
+                "每周{}",
+                days.iter().map(|d| WEEKDAY_CN[d.num_days_from_monday() as usize]).collect::<Vec<_>>().join("、")
+            ),
+            HabitSchedule::Weekly(times) => format!("每周 {} 次", times),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HabitStatus {
+    pub id: i64,
+    pub name: String,
+    pub schedule: String, // 计划描述
+    pub due_today: bool,  // 今天是否需要打卡（每周几次的习惯在本周次数未满时都算）
+    pub done_today: bool,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub streak_unit: &'static str, // "day" 或 "week"（每周几次的习惯按周计）
+    pub total_checkins: u32,
+    pub completion_rate: f64, // 近 30 天的完成率（0~1）
+    pub week_progress: Option<(u32, u32)>, // 每周几次的习惯本周已完成/目标次数
+    pub recent: Vec<bool>,    // 最近 7 天（含今天，从早到晚）是否打卡
+}
+
+fn parse_weekday(text: &str) -> Option<Weekday> {
+    let text = text.to_lowercase();
+    let index = match text.as_str() {
+        "一" | "1" => 0,
+        "二" | "2" => 1,
+        "三" | "3" => 2,
+        "四" | "4" => 3,
+        "五" | "5" => 4,
+        "六" | "6" => 5,
+        "日" | "天" | "7" => 6,
+        _ if text.len() >= 3 => ["mon", "tue", "wed", "thu", "fri", "sat", "sun"].iter().position(|p| text.starts_with(p))?,
+        _ => return None,
+    };
+    Weekday::try_from(index as u8).ok()
+}
+
+fn chinese_count(text: &str) -> Option<u32> {
+    text.parse().ok().or_else(|| "一二三四五六七".chars().position(|c| text == c.to_string()).map(|n| n as u32 + 1))
+        .or((text == "两").then_some(2))
+}
+
+/// 解析计划：每天/daily、工作日/weekdays、每周3次/3x/week/3 times a week、周一三五/mon,wed,fri
+pub fn parse_schedule(input: &str) -> Option<HabitSchedule> {
+    let text = input.trim().to_lowercase();
+    match text.as_str() {
+        "" | "daily" | "every day" | "everyday" | "每天" | "每日" => return Some(HabitSchedule::Daily),
+        "weekdays" | "workdays" | "工作日" | "每个工作日" => return Some(HabitSchedule::Weekdays),
+        _ => {}
+    }
+
+    // 每周几次
+    let times = text
+        .strip_suffix("x/week")
+        .or_else(|| text.strip_suffix("/week"))
+        .or_else(|| text.strip_suffix(" times a week"))
+        .or_else(|| text.strip_suffix(" times per week"))
+        .map(str::trim)
+        .or_else(|| {
+            ["每周", "一周", "每星期"]
+                .iter()
+                .find_map(|p| text.strip_prefix(p))
+                .and_then(|rest| rest.strip_suffix('次'))
+                .map(str::trim)
+        })
+        .and_then(chinese_count);
+    if let Some(times) = times {
+        return (1..=7).contains(&times).then_some(if times == 7 { HabitSchedule::Daily } else { HabitSchedule::Weekly(times) });
+    }
+
+    // 固定的几天："周一三五"、"每周二、四"、"mon,wed,fri"
+    let list = ["每周", "每星期", "周", "星期"].iter().find_map(|p| text.strip_prefix(p));
+    let mut days: Vec<Weekday> = match list {
+        Some(rest) => rest
+            .chars()
+            .filter(|c| !matches!(c, '、' | ',' | '，' | ' ' | '周'))
+            .map(|c| parse_weekday(&c.to_string()))
+            .collect::<Option<_>>()?,
+        None => text
+            .split([',', '，', '、', ' ', '/'])
+            .filter(|s| !s.is_empty())
+            .map(parse_weekday)
+            .collect::<Option<_>>()?,
+    };
+    days.sort_by_key(|d| d.num_days_from_monday());
+    days.dedup();
+    match days.len() {
+        0 => None,
+        7 => Some(HabitSchedule::Daily),
+        _ => Some(HabitSchedule::Days(days)),
+    }
+}
+
+/// 拆分 "跑步 每周3次"、"read 3 times a week" 这样的输入：末尾能解析为计划的部分作为计划，其余为名称；没有计划时为每天
+pub fn split_name_and_schedule(input: &str) -> (String, HabitSchedule) {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    for take in (1..=words.len().saturating_sub(1).min(4)).rev() {
+        let (name, schedule) = words.split_at(words.len() - take);
+        if let Some(schedule) = parse_schedule(&schedule.join(" ")) {
+            return (name.join(" "), schedule);
+        }
+    }
+    (words.join(" "), HabitSchedule::Daily)
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// 当前与最长连续记录：按天计划时为连续完成的计划日数（今天尚未打卡不算中断），每周几次时为连续达标的周数
+fn streaks(schedule: &HabitSchedule, checkins: &BTreeSet<NaiveDate>, today: NaiveDate) -> (u32, u32) {
+    let Some(&first) = checkins.first() else { return (0, 0) };
+    match schedule {
+        HabitSchedule::Weekly(times) => {
+            let mut weeks: BTreeMap<NaiveDate, u32> = BTreeMap::new();
+            for day in checkins.iter().filter(|d| **d <= today) {
+                *weeks.entry(week_start(*day)).or_default() += 1;
+            }
+            let met = |week: NaiveDate| weeks.get(&week).is_some_and(|n| n >= times);
+            let (mut best, mut run) = (0, 0);
+            let mut week = week_start(first);
+            while week <= today {
+                run = if met(week) { run + 1 } else if week == week_start(today) { run } else { 0 };
+                best = best.max(run);
+                week += Duration::weeks(1);
+            }
+            let mut current = 0;
+            let mut week = week_start(today);
+            if !met(week) {
+                week -= Duration::weeks(1);
+            }
+            while met(week) {
+                current += 1;
+                week -= Duration::weeks(1);
+            }
+            (current, best)
+        }
+        _ => {
+            let (mut best, mut run) = (0, 0);
+            let mut day = first;
+            while day <= today {
+                if schedule.is_scheduled(day) {
+                    if checkins.contains(&day) {
+                        run += 1;
+                    } else if day != today {
+                        run = 0;
+                    }
+                    best = best.max(run);
+                }
+                day += Duration::days(1);
+            }
+            let mut current = 0;
+            let mut day = today;
+            if !checkins.contains(&today) {
+                day -= Duration::days(1);
+            }
+            while day >= first {
+                if schedule.is_scheduled(day) {
+                    if !checkins.contains(&day) {
+                        break;
+                    }
+                    current += 1;
+                }
+                day -= Duration::days(1);
+            }
+            (current, best)
+        }
+    }
+}
+
+/// 近 30 天完成率，只统计习惯创建之后的日子；今天未打卡时不计入
+fn completion_rate(schedule: &HabitSchedule, checkins: &BTreeSet<NaiveDate>, created: NaiveDate, today: NaiveDate) -> f64 {
+    let start = created.max(today - Duration::days(RATE_DAYS - 1));
+    if let HabitSchedule::Weekly(times) = schedule {
+        // 按完整的周计算，本周只在已达标时计入
+        let (mut done, mut due) = (0, 0);
+        let mut week = week_start(start);
+        while week <= today {
+            let count = checkins.range(week..week + Duration::days(7)).count() as u32;
+            if week != week_start(today) || count >= *times {
+                done += count.min(*times);
+                due += times;
+            }
+            week += Duration::weeks(1);
+        }
+        return if due == 0 { 0.0 } else { done as f64 / due as f64 };
+    }
+    let (mut done, mut due) = (0, 0);
+    let mut day = start;
+    while day <= today {
+        if schedule.is_scheduled(day) && (day != today || checkins.contains(&day)) {
+            due += 1;
+            done += u32::from(checkins.contains(&day));
+        }
+        day += Duration::days(1);
+    }
+    if due == 0 { 0.0 } else { done as f64 / due as f64 }
+}
+
+fn build_status(id: i64, name: String, schedule: &HabitSchedule, created: NaiveDate, checkins: &BTreeSet<NaiveDate>, today: NaiveDate) -> HabitStatus {
+    let (current_streak, best_streak) = streaks(schedule, checkins, today);
+    let done_today = checkins.contains(&today);
+    let week_progress = match schedule {
+        HabitSchedule::Weekly(times) => {
+            Some((checkins.range(week_start(today)..=today).count() as u32, *times))
+        }
+        _ => None,
+    };
+    HabitStatus {
+        id,
+        name,
+        schedule: schedule.describe(),
+        due_today: done_today || week_progress.map_or(schedule.is_scheduled(today), |(done, target)| done < target),
+        done_today,
+        current_streak,
+        best_streak,
+        streak_unit: if week_progress.is_some() { "week" } else { "day" },
+        total_checkins: checkins.len() as u32,
+        completion_rate: completion_rate(schedule, checkins, created, today),
+        week_progress,
+        recent: (0..RECENT_DAYS).rev().map(|n| checkins.contains(&(today - Duration::days(n)))).collect(),
+    }
+}
+
+fn local_today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// 所有习惯及统计，按今天是否需要打卡、是否已完成排序（待打卡的在前）
+pub fn list_habits(app_data_dir: &Path) -> Result<Vec<HabitStatus>, String> {
+    let today = local_today();
+    let mut habits = db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached("SELECT id, name, schedule, created_at FROM habits ORDER BY created_at")
+            .map_err(|e| format!("Failed to prepare habits query: {}", e))?;
+        let habits = stmt
+            .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?, row.get::<_, i64>(3)?)))
+            .map_err(|e| format!("Failed to query habits: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read habits: {}", e))?;
+
+        let mut checkin_stmt = conn
+            .prepare_cached("SELECT day FROM habit_checkins WHERE habit_id = ?1")
+            .map_err(|e| format!("Failed to prepare habit checkins query: {}", e))?;
+        let mut result = Vec::with_capacity(habits.len());
+        for (id, name, schedule, created_at) in habits {
+            let checkins: BTreeSet<NaiveDate> = checkin_stmt
+                .query_map(params![id], |row| row.get::<_, String>(0))
+                .map_err(|e| format!("Failed to query habit checkins: {}", e))?
+                .filter_map(|day| day.ok().and_then(|d| NaiveDate::parse_from_str(&d, DATE_FORMAT).ok()))
+                .collect();
+            let created = chrono::DateTime::from_timestamp(created_at, 0)
+                .map(|t| t.with_timezone(&Local).date_naive())
+                .unwrap_or(today);
+            result.push(build_status(id, name, &HabitSchedule::decode(&schedule), created, &checkins, today));
+        }
+        Ok(result)
+    })?;
+    habits.sort_by_key(|h| (!h.due_today || h.done_today, h.done_today));
+    Ok(habits)
+}
+
+fn find_status(app_data_dir: &Path, id: i64) -> Result<HabitStatus, String> {
+    list_habits(app_data_dir)?.into_iter().find(|h| h.id == id).ok_or_else(|| "习惯不存在".to_string())
+}
+
+/// 添加习惯，input 为 "名称 [计划]"，如 "跑步 每周3次"、"read weekdays"
+pub fn add_habit(app_data_dir: &Path, input: &str) -> Result<HabitStatus, String> {
+    let (name, schedule) = split_name_and_schedule(input);
+    if name.is_empty() {
+        return Err("习惯名称不能为空".to_string());
+    }
+    let id = db::with_connection(app_data_dir, |conn| {
+        let existing: Option<i64> = conn
+            .query_row("SELECT id FROM habits WHERE name = ?1", params![name], |row| row.get(0))
+            .optional()
+            .map_err(|e| format!("Failed to query habit: {}", e))?;
+        if existing.is_some() {
+            return Err(format!("已经有名为 {} 的习惯", name));
+        }
+        conn.execute(
+            "INSERT INTO habits (name, schedule, created_at) VALUES (?1, ?2, ?3)",
+            params![name, schedule.encode(), chrono::Utc::now().timestamp()],
+        )
+        .map_err(|e| format!("Failed to insert habit: {}", e))?;
+        Ok(conn.last_insert_rowid())
+    })?;
+    find_status(app_data_dir, id)
+}
+
+/// 删除习惯及其打卡记录
+pub fn remove_habit(app_data_dir: &Path, id: i64) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute("DELETE FROM habit_checkins WHERE habit_id = ?1", params![id])
+            .and_then(|_| conn.execute("DELETE FROM habits WHERE id = ?1", params![id]))
+            .map_err(|e| format!("Failed to delete habit: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 今天打卡（重复打卡不变），返回更新后的统计
+pub fn check_in(app_data_dir: &Path, id: i64) -> Result<HabitStatus, String> {
+    let today = local_today().format(DATE_FORMAT).to_string();
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute(
+            "INSERT OR IGNORE INTO habit_checkins (habit_id, day, created_at) VALUES (?1, ?2, ?3)",
+            params![id, today, chrono::Utc::now().timestamp()],
+        )
+        .map_err(|e| format!("Failed to insert habit checkin: {}", e))
+    })?;
+    find_status(app_data_dir, id)
+}
+
+/// 撤销今天的打卡
+pub fn undo_check_in(app_data_dir: &Path, id: i64) -> Result<HabitStatus, String> {
+    let today = local_today().format(DATE_FORMAT).to_string();
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute("DELETE FROM habit_checkins WHERE habit_id = ?1 AND day = ?2", params![id, today])
+            .map_err(|e| format!("Failed to delete habit checkin: {}", e))
+    })?;
+    find_status(app_data_dir, id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(text: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(text, DATE_FORMAT).unwrap()
+    }
+
+    fn days(list: &[&str]) -> BTreeSet<NaiveDate> {
+        list.iter().map(|d| date(d)).collect()
+    }
+
+    #[test]
+    fn parses_schedules() {
+        assert_eq!(parse_schedule("每天"), Some(HabitSchedule::Daily));
+        assert_eq!(parse_schedule("Weekdays"), Some(HabitSchedule::Weekdays));
+        assert_eq!(parse_schedule("3x/week"), Some(HabitSchedule::Weekly(3)));
+        assert_eq!(parse_schedule("每周两次"), Some(HabitSchedule::Weekly(2)));
+        assert_eq!(parse_schedule("周一三五"), Some(HabitSchedule::Days(vec![Weekday::Mon, Weekday::Wed, Weekday::Fri])));
+        assert_eq!(parse_schedule("每周二、四"), Some(HabitSchedule::Days(vec![Weekday::Tue, Weekday::Thu])));
+        assert_eq!(parse_schedule("sat,sun"), Some(HabitSchedule::Days(vec![Weekday::Sat, Weekday::Sun])));
+        assert_eq!(parse_schedule("跑步"), None);
+        assert_eq!(split_name_and_schedule("跑步 每周3次"), ("跑步".to_string(), HabitSchedule::Weekly(3)));
+        assert_eq!(split_name_and_schedule("read 3 times a week"), ("read".to_string(), HabitSchedule::Weekly(3)));
+        assert_eq!(split_name_and_schedule("drink water"), ("drink water".to_string(), HabitSchedule::Daily));
+        for schedule in [HabitSchedule::Weekdays, HabitSchedule::Days(vec![Weekday::Sun]), HabitSchedule::Weekly(4)] {
+            assert_eq!(HabitSchedule::decode(&schedule.encode()), schedule);
+        }
+    }
+
+    #[test]
+    fn computes_streaks() {
+        // 2025-06-10 是星期二
+        let today = date("2025-06-10");
+        let daily = days(&["2025-06-05", "2025-06-07", "2025-06-08", "2025-06-09"]);
+        // 今天尚未打卡不算中断
+        assert_eq!(streaks(&HabitSchedule::Daily, &daily, today), (3, 3));
+        let mut done = daily.clone();
+        done.insert(today);
+        assert_eq!(streaks(&HabitSchedule::Daily, &done, today), (4, 4));
+
+        // 工作日计划跳过周末：6/6(五)、6/9(一)、6/10(二) 连续
+        let weekdays = days(&["2025-06-04", "2025-06-06", "2025-06-09", "2025-06-10"]);
+        assert_eq!(streaks(&HabitSchedule::Weekdays, &weekdays, today), (3, 3));
+
+        // 每周 2 次：5/26 周、6/2 周达标，本周未满不中断
+        let weekly = days(&["2025-05-26", "2025-05-28", "2025-06-02", "2025-06-06", "2025-06-10"]);
+        assert_eq!(streaks(&HabitSchedule::Weekly(2), &weekly, today), (2, 2));
+        let status = build_status(1, "x".into(), &HabitSchedule::Weekly(2), date("2025-05-26"), &weekly, today);
+        assert_eq!(status.week_progress, Some((1, 2)));
+        assert!(status.due_today && status.done_today);
+        assert_eq!(status.recent, vec![false, false, true, false, false, false, true]);
+        assert_eq!(status.completion_rate, 1.0);
+    }
+}
@@ -0,0 +1,325 @@
+// 热点文件夹：监听用户登记的文件夹（下载、桌面、项目目录等），维护最近新建/修改文件的滚动索引
+// 启动器查询为空时展示"刚刚落到下载文件夹里的文件"；设置保存后通过 settings-changed 重新监听
+
+use crate::event_bus::{self, BusEvent};
+use crate::path_expand;
+use crate::shutdown;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast;
+
+pub const UPDATED_EVENT: &str = "hot-folders://updated";
+const MAX_ENTRIES: usize = 200;
+const MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+/// 文件变化频繁（如下载中）时合并通知，最多每秒通知前端一次
+const NOTIFY_INTERVAL: Duration = Duration::from_secs(1);
+/// 监听时跳过的目录（依赖、构建产物、版本库）
+const IGNORED_DIRS: &[&str] = &["node_modules", ".git", ".svn", "target", "__pycache__", ".idea", ".vs"];
+/// 下载中或编辑器生成的临时文件
+const TEMP_SUFFIXES: &[&str] = &[".crdownload", ".part", ".partial", ".download", ".tmp"];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RecentFile {
+    pub path: String,
+    pub name: String,
+    pub folder: String, // 所属的热点文件夹
+    pub size: u64,
+    pub modified: u64, // 毫秒时间戳
+    pub created: bool, // 监听期间新建的文件，启动时已存在的文件为 false
+}
+
+/// 最近文件的滚动索引：按路径去重，只保留 MAX_AGE 内的最近 MAX_ENTRIES 个
+#[derive(Default)]
+pub struct RecentIndex {
+    entries: HashMap<String, RecentFile>,
+}
+
+impl RecentIndex {
+    pub fn upsert(&mut self, mut file: RecentFile) {
+        if let Some(existing) = self.entries.get(&file.path) {
+            file.created |= existing.created;
+        }
+        self.entries.insert(file.path.clone(), file);
+        if self.entries.len() > MAX_ENTRIES {
+            let mut modified: Vec<u64> = self.entries.values().map(|f| f.modified).collect();
+            modified.sort_unstable_by(|a, b| b.cmp(a));
+            let cutoff = modified[MAX_ENTRIES - 1];
+            self.entries.retain(|_, f| f.modified >= cutoff);
+        }
+    }
+
+    pub fn remove(&mut self, path: &str) -> bool {
+        // 删除文件夹时一并移除其中的文件
+        let prefix = format!("{}{}", path.trim_end_matches(['\\', '/']), std::path::MAIN_SEPARATOR);
+        let before = self.entries.len();
+        self.entries.retain(|p, _| p != path && !p.starts_with(&prefix));
+        self.entries.len() != before
+    }
+
+    /// 按修改时间倒序返回，过期的条目不返回
+    pub fn recent(&self, now_ms: u64, limit: usize) -> Vec<RecentFile> {
+        let min_modified = now_ms.saturating_sub(MAX_AGE.as_millis() as u64);
+        let mut files: Vec<RecentFile> = self
+            .entries
+            .values()
+            .filter(|f| f.modified >= min_modified)
+            .cloned()
+            .collect();
+        files.sort_by_key(|f| std::cmp::Reverse(f.modified));
+        files.truncate(limit);
+        files
+    }
+}
+
+struct HotFolderState {
+    folders: Vec<PathBuf>,
+    watcher: Option<RecommendedWatcher>,
+    index: RecentIndex,
+}
+
+static STATE: LazyLock<Mutex<HotFolderState>> = LazyLock::new(|| {
+    Mutex::new(HotFolderState { folders: Vec::new(), watcher: None, index: RecentIndex::default() })
+});
+static DIRTY: AtomicBool = AtomicBool::new(false);
+
+fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+fn is_ignored(path: &Path, root: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(root) else {
+        return true;
+    };
+    if relative.components().any(|c| IGNORED_DIRS.contains(&c.as_os_str().to_string_lossy().as_ref())) {
+        return true;
+    }
+    let name = path.file_name().map(|n| n.to_string_lossy().to_lowercase()).unwrap_or_default();
+    // ~$ 开头的是 Office 的锁文件
+    name.starts_with("~$") || name.starts_with('.') || TEMP_SUFFIXES.iter().any(|s| name.ends_with(s))
+}
+
+fn recent_file(path: &Path, root: &Path, created: bool) -> Option<RecentFile> {
+    if is_ignored(path, root) {
+        return None;
+    }
+    let metadata = fs::metadata(path).ok()?;
+    if !metadata.is_file() {
+        return None;
+    }
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)?;
+    Some(RecentFile {
+        path: path.to_string_lossy().to_string(),
+        name: path.file_name()?.to_string_lossy().to_string(),
+        folder: root.to_string_lossy().to_string(),
+        size: metadata.len(),
+        modified,
+        created,
+    })
+}
+
+/// 启动时只扫描热点文件夹的第一层，避免大型项目目录拖慢启动
+fn seed(index: &mut RecentIndex, root: &Path) {
+    let Ok(entries) = fs::read_dir(root) else {
+        return;
+    };
+    let min_modified = now_ms().saturating_sub(MAX_AGE.as_millis() as u64);
+    for entry in entries.flatten() {
+        if let Some(file) = recent_file(&entry.path(), root, false) {
+            if file.modified >= min_modified {
+                index.upsert(file);
+            }
+        }
+    }
+}
+
+fn handle_event(event: Event) {
+    let Ok(mut state) = STATE.lock() else {
+        return;
+    };
+    let created = event.kind.is_create();
+    let mut changed = false;
+    for path in &event.paths {
+        let Some(root) = state.folders.iter().find(|root| path.starts_with(root)).cloned() else {
+            continue;
+        };
+        if path.exists() {
+            if let Some(file) = recent_file(path, &root, created) {
+                state.index.upsert(file);
+                changed = true;
+            }
+        } else {
+            // 删除或重命名前的旧路径
+            changed |= state.index.remove(&path.to_string_lossy());
+        }
+    }
+    if changed {
+        DIRTY.store(true, Ordering::Relaxed);
+    }
+}
+
+/// 重新设置要监听的文件夹；列表没有变化时不做任何事
+pub fn configure(folders: &[String]) {
+    let folders: Vec<PathBuf> = folders
+        .iter()
+        .map(|f| PathBuf::from(path_expand::expand_path(f)))
+        .filter(|f| !f.as_os_str().is_empty())
+        .collect();
+    let old_watcher = {
+        let Ok(mut state) = STATE.lock() else {
+            return;
+        };
+        if state.folders == folders {
+            return;
+        }
+        state.index = RecentIndex::default();
+        state.folders = folders.clone();
+        state.watcher.take()
+    };
+    // 在锁外停止旧的监听：监听线程可能正等待这把锁处理事件
+    drop(old_watcher);
+    DIRTY.store(true, Ordering::Relaxed);
+    if folders.is_empty() {
+        return;
+    }
+
+    let mut watcher = match RecommendedWatcher::new(
+        |result: Result<Event, notify::Error>| match result {
+            Ok(event) => handle_event(event),
+            Err(e) => eprintln!("[HotFolders] Watch error: {}", e),
+        },
+        notify::Config::default(),
+    ) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            eprintln!("[HotFolders] Failed to create watcher: {}", e);
+            return;
+        }
+    };
+    let mut seeded = RecentIndex::default();
+    for folder in &folders {
+        if !folder.is_dir() {
+            eprintln!("[HotFolders] Skipping missing folder: {}", folder.display());
+            continue;
+        }
+        if let Err(e) = watcher.watch(folder, RecursiveMode::Recursive) {
+            eprintln!("[HotFolders] Failed to watch {}: {}", folder.display(), e);
+            continue;
+        }
+        seed(&mut seeded, folder);
+    }
+
+    let Ok(mut state) = STATE.lock() else {
+        return;
+    };
+    // 扫描期间设置又被修改时，以后一次配置为准
+    if state.folders != folders {
+        return;
+    }
+    for (_, file) in seeded.entries {
+        state.index.upsert(file);
+    }
+    state.watcher = Some(watcher);
+    DIRTY.store(true, Ordering::Relaxed);
+}
+
+/// 最近新建/修改的文件，按修改时间倒序
+pub fn recent_files(limit: usize) -> Vec<RecentFile> {
+    STATE.lock().map(|state| state.index.recent(now_ms(), limit)).unwrap_or_default()
+}
+
+/// 按设置开始监听，并订阅 settings-changed 以便热更新文件夹列表；索引变化时通知前端
+pub fn start(app: AppHandle, folders: Vec<String>) {
+    if let Err(e) = shutdown::spawn("hot-folders-scan", move || configure(&folders)) {
+        eprintln!("[HotFolders] {}", e);
+    }
+
+    let emitter = app.clone();
+    let notifier = shutdown::spawn("hot-folders-notify", move || {
+        while !shutdown::wait_timeout(NOTIFY_INTERVAL) {
+            if DIRTY.swap(false, Ordering::Relaxed) {
+                if let Err(e) = emitter.emit(UPDATED_EVENT, ()) {
+                    eprintln!("[HotFolders] Failed to emit {}: {}", UPDATED_EVENT, e);
+                }
+            }
+        }
+    });
+    if let Err(e) = notifier {
+        eprintln!("[HotFolders] {}", e);
+    }
+
+    let mut receiver = event_bus::subscribe();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(BusEvent::SettingsChanged(settings)) => {
+                    // 首次扫描可能较慢，放到阻塞线程池中执行
+                    let folders = settings.hot_folders.clone();
+                    let _ = tauri::async_runtime::spawn_blocking(move || configure(&folders)).await;
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    eprintln!("[HotFolders] Listener lagged, skipped {} events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str, modified: u64) -> RecentFile {
+        RecentFile {
+            path: path.to_string(),
+            name: path.to_string(),
+            folder: String::new(),
+            size: 0,
+            modified,
+            created: false,
+        }
+    }
+
+    #[test]
+    fn keeps_rolling_recent_index() {
+        let now = MAX_AGE.as_millis() as u64 * 2;
+        let mut index = RecentIndex::default();
+        index.upsert(RecentFile { created: true, ..file("a", now - 10) });
+        index.upsert(file("a", now - 5)); // 再次修改保留"新建"标记
+        index.upsert(file("b", now - 1));
+        index.upsert(file("old", 1));
+
+        let recent = index.recent(now, 10);
+        assert_eq!(recent.iter().map(|f| f.path.as_str()).collect::<Vec<_>>(), vec!["b", "a"]);
+        assert!(recent[1].created);
+
+        for i in 0..MAX_ENTRIES as u64 + 10 {
+            index.upsert(file(&format!("f{}", i), now - 1000 + i));
+        }
+        assert!(index.entries.len() <= MAX_ENTRIES);
+        assert!(index.remove("b"));
+        assert!(!index.remove("b"));
+    }
+
+    #[test]
+    fn ignores_temp_and_dependency_files() {
+        let root = Path::new("/data/hot");
+        assert!(is_ignored(&root.join("movie.mkv.crdownload"), root));
+        assert!(is_ignored(&root.join("~$report.docx"), root));
+        assert!(is_ignored(&root.join("proj/node_modules/x.js"), root));
+        assert!(!is_ignored(&root.join("proj/src/main.rs"), root));
+        assert!(is_ignored(Path::new("/elsewhere/file.txt"), root));
+    }
+}
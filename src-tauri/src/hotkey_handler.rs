@@ -966,6 +966,18 @@ pub mod windows {
             return CallNextHookEx(windows_sys::Win32::UI::WindowsAndMessaging::HHOOK::default(), nCode, wParam, lParam);
         }
         
+        // 热字串需要看到所有普通按键（包括启动器窗口中的输入），放在快捷键处理之前；忽略模拟输入的按键
+        if wParam == WM_KEYDOWN as WPARAM || wParam == WM_SYSKEYDOWN as WPARAM {
+            use windows_sys::Win32::UI::WindowsAndMessaging::LLKHF_INJECTED;
+            let hook_struct = &*(lParam as *const KBDLLHOOKSTRUCT);
+            if hook_struct.flags & LLKHF_INJECTED == 0 {
+                let key = crate::hotstrings::platform::translate_key(hook_struct.vkCode, hook_struct.scanCode);
+                if crate::hotstrings::handle_key(key) {
+                    return 1;
+                }
+            }
+        }
+
         // 只处理 WM_KEYDOWN，忽略 WM_SYSKEYDOWN，避免重复触发
         // WM_SYSKEYDOWN 通常用于系统快捷键（如 Alt+Tab），我们只处理普通按键
         let is_keydown = wParam == WM_KEYDOWN as WPARAM;
@@ -1086,7 +1098,11 @@ pub mod windows {
                 let mut pressed_modifiers = modifiers.clone();
                 pressed_modifiers.sort();
                 
-                if config_modifiers == pressed_modifiers && config.key == key_name {
+                // 限定了程序的快捷键只在前台窗口匹配时生效，同一组合键可以在不同程序中绑定不同动作
+                if config_modifiers == pressed_modifiers
+                    && config.key == key_name
+                    && crate::app_scope::foreground_matches(&config.apps)
+                {
                     matched_ids.push(id.clone());
                 }
             }
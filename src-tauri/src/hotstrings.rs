@@ -0,0 +1,462 @@
+// 热字串：给备忘录设置缩写后，在任意程序中输入缩写并按空格/回车/Tab，缩写会被替换为备忘录内容（支持文本变量）；
+// 展开后紧接着按退格会撤销展开，恢复原来输入的缩写和触发键。按键由 hotkey_handler 的全局键盘钩子转交过来
+
+use crate::app_scope;
+use crate::event_bus::{self, BusEvent};
+use crate::key_injection::{self, InjectionMode};
+use crate::memos;
+use crate::shutdown;
+use crate::text_variables::{self, RenderOptions};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{LazyLock, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+pub const MAX_ABBREVIATION_LEN: usize = 32;
+// 记录最近输入的字符数，超过缩写长度上限即可
+const BUFFER_LEN: usize = MAX_ABBREVIATION_LEN * 2;
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hotstring {
+    pub memo_id: String,
+    pub abbreviation: String,
+    pub case_sensitive: bool,
+    pub apps: Vec<String>,
+    pub injection: InjectionMode,
+}
+
+/// 钩子识别出的一次按键
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyInput {
+    Char(char),
+    /// 触发键：空格、回车（'\n'）、Tab
+    Trigger(char),
+    Backspace,
+    /// 方向键、快捷键组合等会让输入位置变化的按键，清空已记录的输入
+    Reset,
+    /// 单独的修饰键等，不影响输入
+    Ignore,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// 删除已输入的缩写，输入展开内容和触发键
+    Expand { hotstring: Hotstring, typed: String, trigger: char },
+    /// 删除展开的内容（erase 个字符），恢复原来的输入
+    Undo { erase: usize, restore: String },
+}
+
+struct LastExpansion {
+    typed: String,
+    trigger: char,
+    inserted: usize,
+}
+
+/// 根据输入的字符匹配热字串；只做匹配，不涉及按键模拟
+#[derive(Default)]
+pub struct Matcher {
+    hotstrings: Vec<Hotstring>,
+    buffer: Vec<char>,
+    last: Option<LastExpansion>,
+}
+
+impl Matcher {
+    pub fn set_hotstrings(&mut self, hotstrings: Vec<Hotstring>) {
+        self.hotstrings = hotstrings;
+        self.reset();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hotstrings.is_empty()
+    }
+
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.last = None;
+    }
+
+    /// in_scope 判断热字串限定的程序是否是当前前台程序，只在触发键匹配到缩写时调用
+    pub fn on_key(&mut self, key: KeyInput, in_scope: impl Fn(&[String]) -> bool) -> Option<Action> {
+        match key {
+            KeyInput::Char(c) => {
+                self.last = None;
+                self.buffer.push(c);
+                if self.buffer.len() > BUFFER_LEN {
+                    self.buffer.drain(..self.buffer.len() - BUFFER_LEN);
+                }
+                None
+            }
+            KeyInput::Trigger(trigger) => {
+                self.last = None;
+                let action = self.find_match(in_scope).map(|(hotstring, typed)| Action::Expand { hotstring, typed, trigger });
+                // 触发键结束一个单词，之后重新开始记录
+                self.buffer.clear();
+                action
+            }
+            KeyInput::Backspace => match self.last.take() {
+                Some(last) => Some(Action::Undo {
+                    erase: last.inserted + 1,
+                    restore: format!("{}{}", last.typed, last.trigger),
+                }),
+                None => {
+                    self.buffer.pop();
+                    None
+                }
+            },
+            KeyInput::Reset => {
+                self.reset();
+                None
+            }
+            KeyInput::Ignore => None,
+        }
+    }
+
+    /// 展开完成后记录下来，供紧接着的退格撤销；inserted 为输入的展开内容字符数（不含触发键）
+    pub fn expanded(&mut self, typed: String, trigger: char, inserted: usize) {
+        self.buffer.clear();
+        self.last = Some(LastExpansion { typed, trigger, inserted });
+    }
+
+    /// 找出以缓冲区结尾、且前面不是字母数字的最长缩写，返回热字串和实际输入的缩写
+    fn find_match(&self, in_scope: impl Fn(&[String]) -> bool) -> Option<(Hotstring, String)> {
+        self.hotstrings
+            .iter()
+            .filter_map(|hotstring| {
+                let abbr: Vec<char> = hotstring.abbreviation.chars().collect();
+                if abbr.is_empty() || abbr.len() > self.buffer.len() {
+                    return None;
+                }
+                let start = self.buffer.len() - abbr.len();
+                let typed = &self.buffer[start..];
+                let matched = if hotstring.case_sensitive {
+                    typed == abbr.as_slice()
+                } else {
+                    typed.iter().zip(&abbr).all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()))
+                };
+                let at_word_start = start == 0 || !self.buffer[start - 1].is_alphanumeric();
+                (matched && at_word_start && in_scope(&hotstring.apps))
+                    .then(|| (hotstring.clone(), typed.iter().collect::<String>()))
+            })
+            .max_by_key(|(hotstring, _)| hotstring.abbreviation.chars().count())
+    }
+}
+
+/// 不区分大小写的热字串按输入调整展开内容：缩写全部大写时展开为大写，首字母大写时展开内容首字母大写
+pub fn match_case(typed: &str, replacement: &str) -> String {
+    let letters: Vec<char> = typed.chars().filter(|c| c.is_alphabetic()).collect();
+    let Some(first) = letters.first() else {
+        return replacement.to_string();
+    };
+    if letters.len() > 1 && letters.iter().all(|c| c.is_uppercase()) {
+        return replacement.to_uppercase();
+    }
+    if first.is_uppercase() {
+        let mut chars = replacement.chars();
+        if let Some(c) = chars.next() {
+            return c.to_uppercase().chain(chars).collect();
+        }
+    }
+    replacement.to_string()
+}
+
+/// 输入文本时删除一个字符需要的退格数；\r\n 作为一个换行
+fn typed_len(text: &str) -> usize {
+    text.replace("\r\n", "\n").chars().count()
+}
+
+enum Job {
+    Reload,
+    Run(Action),
+}
+
+static MATCHER: LazyLock<Mutex<Matcher>> = LazyLock::new(|| Mutex::new(Matcher::default()));
+static JOBS: OnceLock<Sender<Job>> = OnceLock::new();
+
+/// 全局键盘钩子中调用；返回 true 表示拦截这次按键（由后台线程完成展开或撤销）
+pub fn handle_key(key: KeyInput) -> bool {
+    let Ok(mut matcher) = MATCHER.lock() else {
+        return false;
+    };
+    if matcher.is_empty() {
+        return false;
+    }
+    let Some(action) = matcher.on_key(key, app_scope::foreground_matches) else {
+        return false;
+    };
+    drop(matcher);
+    JOBS.get().is_some_and(|jobs| jobs.send(Job::Run(action)).is_ok())
+}
+
+fn load_hotstrings(app_data_dir: &PathBuf) -> Result<Vec<Hotstring>, String> {
+    Ok(memos::get_all_memos(app_data_dir)?
+        .into_iter()
+        .filter_map(|memo| {
+            memo.hotstring.map(|abbreviation| Hotstring {
+                memo_id: memo.id,
+                abbreviation,
+                case_sensitive: memo.case_sensitive,
+                apps: memo.apps,
+                injection: memo.injection,
+            })
+        })
+        .collect())
+}
+
+fn run(action: Action, app_data_dir: &PathBuf) -> Result<(), String> {
+    match action {
+        Action::Expand { hotstring, typed, trigger } => {
+            let rendered = memos::get_all_memos(app_data_dir)
+                .and_then(|memos| {
+                    memos
+                        .into_iter()
+                        .find(|memo| memo.id == hotstring.memo_id)
+                        .ok_or_else(|| format!("Memo {} not found", hotstring.memo_id))
+                })
+                .and_then(|memo| text_variables::render(app_data_dir, &memo.content, &RenderOptions::default()));
+            let rendered = match rendered {
+                Ok(rendered) => rendered,
+                Err(e) => {
+                    // 触发键已被拦截，展开失败时补上
+                    key_injection::platform::type_text(&trigger.to_string())?;
+                    return Err(e);
+                }
+            };
+            let text = if hotstring.case_sensitive { rendered } else { match_case(&typed, &rendered) };
+            key_injection::with_mode(hotstring.injection, || {
+                key_injection::platform::send_backspaces(typed.chars().count())?;
+                key_injection::platform::type_text(&format!("{}{}", text, trigger))
+            })?;
+            if let Ok(mut matcher) = MATCHER.lock() {
+                matcher.expanded(typed, trigger, typed_len(&text));
+            }
+            Ok(())
+        }
+        Action::Undo { erase, restore } => {
+            key_injection::platform::send_backspaces(erase)?;
+            key_injection::platform::type_text(&restore)
+        }
+    }
+}
+
+/// 加载热字串并启动展开线程；备忘录有写入时重新加载
+pub fn start(app_data_dir: PathBuf) -> Result<(), String> {
+    let (sender, receiver) = mpsc::channel();
+    let _ = sender.send(Job::Reload);
+    let bus_sender = sender.clone();
+    JOBS.set(sender).map_err(|_| "Hotstrings already started".to_string())?;
+
+    let mut bus = event_bus::subscribe();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let reload = match bus.recv().await {
+                Ok(BusEvent::DbWrite { table: "memos" }) => true,
+                Ok(_) => false,
+                Err(broadcast::error::RecvError::Lagged(_)) => true,
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            if reload && bus_sender.send(Job::Reload).is_err() {
+                break;
+            }
+        }
+    });
+
+    shutdown::spawn("hotstrings", move || {
+        while !shutdown::is_requested() {
+            let job = match receiver.recv_timeout(POLL_INTERVAL) {
+                Ok(job) => job,
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => break,
+            };
+            let result = match job {
+                Job::Reload => load_hotstrings(&app_data_dir).map(|hotstrings| {
+                    if let Ok(mut matcher) = MATCHER.lock() {
+                        matcher.set_hotstrings(hotstrings);
+                    }
+                }),
+                Job::Run(action) => run(action, &app_data_dir),
+            };
+            if let Err(e) = result {
+                eprintln!("[Hotstrings] {}", e);
+            }
+        }
+    })
+}
+
+#[cfg(target_os = "windows")]
+pub mod platform {
+    use super::KeyInput;
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
+        GetAsyncKeyState, GetKeyState, GetKeyboardLayout, ToUnicodeEx, VK_BACK, VK_CAPITAL, VK_CONTROL, VK_LCONTROL,
+        VK_LMENU, VK_LSHIFT, VK_LWIN, VK_MENU, VK_PROCESSKEY, VK_RCONTROL, VK_RETURN, VK_RMENU, VK_RSHIFT, VK_RWIN,
+        VK_SHIFT, VK_SPACE, VK_TAB,
+    };
+    use windows_sys::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
+
+    const KEY_PRESSED: i16 = 0x8000u16 as i16;
+    // ToUnicodeEx 不修改键盘状态（不影响死键），Windows 10 1607 起支持
+    const TO_UNICODE_NO_STATE_CHANGE: u32 = 0x4;
+
+    fn pressed(vk: u16) -> bool {
+        unsafe { GetAsyncKeyState(vk as i32) & KEY_PRESSED != 0 }
+    }
+
+    /// 把钩子收到的按键转换为输入的字符；按当前前台窗口的键盘布局计算
+    pub fn translate_key(vk: u32, scan_code: u32) -> KeyInput {
+        let vk16 = vk as u16;
+        match vk16 {
+            VK_SHIFT | VK_LSHIFT | VK_RSHIFT | VK_CONTROL | VK_LCONTROL | VK_RCONTROL | VK_MENU | VK_LMENU
+            | VK_RMENU | VK_LWIN | VK_RWIN | VK_CAPITAL => return KeyInput::Ignore,
+            // 输入法正在组字，输入位置无法确定
+            VK_PROCESSKEY => return KeyInput::Reset,
+            _ => {}
+        }
+        let ctrl = pressed(VK_CONTROL);
+        let alt = pressed(VK_MENU);
+        // AltGr 表现为 Ctrl+Alt，仍然可能输入字符
+        if (ctrl || alt) && !(ctrl && alt) {
+            return KeyInput::Reset;
+        }
+        match vk16 {
+            VK_SPACE => return KeyInput::Trigger(' '),
+            VK_RETURN => return KeyInput::Trigger('\n'),
+            VK_TAB => return KeyInput::Trigger('\t'),
+            VK_BACK => return KeyInput::Backspace,
+            _ => {}
+        }
+
+        let mut state = [0u8; 256];
+        for key in [VK_SHIFT, VK_CONTROL, VK_MENU] {
+            if pressed(key) {
+                state[key as usize] = 0x80;
+            }
+        }
+        if unsafe { GetKeyState(VK_CAPITAL as i32) } & 1 != 0 {
+            state[VK_CAPITAL as usize] = 0x01;
+        }
+        let mut buffer = [0u16; 8];
+        let written = unsafe {
+            let thread_id = GetWindowThreadProcessId(GetForegroundWindow(), std::ptr::null_mut());
+            let layout = GetKeyboardLayout(thread_id);
+            ToUnicodeEx(
+                vk,
+                scan_code,
+                state.as_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.len() as i32,
+                TO_UNICODE_NO_STATE_CHANGE,
+                layout,
+            )
+        };
+        if written <= 0 {
+            return KeyInput::Reset;
+        }
+        match String::from_utf16_lossy(&buffer[..written as usize]).chars().next() {
+            Some(c) if !c.is_control() => KeyInput::Char(c),
+            _ => KeyInput::Reset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher(hotstrings: &[(&str, bool)]) -> Matcher {
+        let mut matcher = Matcher::default();
+        matcher.set_hotstrings(
+            hotstrings
+                .iter()
+                .map(|(abbr, case_sensitive)| Hotstring {
+                    memo_id: format!("memo-{}", abbr),
+                    abbreviation: abbr.to_string(),
+                    case_sensitive: *case_sensitive,
+                    apps: Vec::new(),
+                    injection: InjectionMode::Default,
+                })
+                .collect(),
+        );
+        matcher
+    }
+
+    fn key(matcher: &mut Matcher, input: KeyInput) -> Option<Action> {
+        matcher.on_key(input, |_| true)
+    }
+
+    fn type_str(matcher: &mut Matcher, text: &str) {
+        for c in text.chars() {
+            assert_eq!(key(matcher, KeyInput::Char(c)), None);
+        }
+    }
+
+    fn expanded_abbr(action: Option<Action>) -> Option<(String, String, char)> {
+        match action {
+            Some(Action::Expand { hotstring, typed, trigger }) => Some((hotstring.abbreviation, typed, trigger)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn expands_only_on_trigger_at_word_start() {
+        let mut m = matcher(&[("btw", false), ("@@", false), ("@@x", false)]);
+        type_str(&mut m, "btw");
+        assert_eq!(expanded_abbr(key(&mut m, KeyInput::Trigger(' '))), Some(("btw".into(), "btw".into(), ' ')));
+
+        // 缩写前是字母数字时不展开
+        type_str(&mut m, "abtw");
+        assert_eq!(key(&mut m, KeyInput::Trigger('\n')), None);
+
+        // 取最长匹配；退格修正后的输入同样可以触发
+        type_str(&mut m, "a.@@y");
+        key(&mut m, KeyInput::Backspace);
+        type_str(&mut m, "x");
+        assert_eq!(expanded_abbr(key(&mut m, KeyInput::Trigger('\t'))), Some(("@@x".into(), "@@x".into(), '\t')));
+
+        // 光标移动等按键清空记录
+        type_str(&mut m, "bt");
+        key(&mut m, KeyInput::Reset);
+        type_str(&mut m, "w");
+        assert_eq!(key(&mut m, KeyInput::Trigger(' ')), None);
+    }
+
+    #[test]
+    fn respects_case_sensitivity() {
+        let mut m = matcher(&[("Sig", true), ("addr", false)]);
+        type_str(&mut m, "sig");
+        assert_eq!(key(&mut m, KeyInput::Trigger(' ')), None);
+        type_str(&mut m, "Sig");
+        assert!(key(&mut m, KeyInput::Trigger(' ')).is_some());
+        type_str(&mut m, "ADDR");
+        assert_eq!(expanded_abbr(key(&mut m, KeyInput::Trigger(' '))), Some(("addr".into(), "ADDR".into(), ' ')));
+
+        assert_eq!(match_case("ADDR", "by the way"), "BY THE WAY");
+        assert_eq!(match_case("Addr", "by the way"), "By the way");
+        assert_eq!(match_case("addr", "by the way"), "by the way");
+        assert_eq!(match_case("@@", "by the way"), "by the way");
+    }
+
+    #[test]
+    fn only_expands_in_scoped_apps() {
+        let mut m = matcher(&[("sig", false)]);
+        m.hotstrings[0].apps = vec!["outlook.exe".to_string()];
+        type_str(&mut m, "sig");
+        assert_eq!(m.on_key(KeyInput::Trigger(' '), |apps| apps.is_empty()), None);
+        type_str(&mut m, "sig");
+        assert!(m.on_key(KeyInput::Trigger(' '), |apps| apps.iter().any(|app| app == "outlook.exe")).is_some());
+    }
+
+    #[test]
+    fn backspace_right_after_expansion_undoes_it() {
+        let mut m = matcher(&[("btw", false)]);
+        m.expanded("btw".into(), ' ', typed_len("by the\r\nway"));
+        assert_eq!(
+            key(&mut m, KeyInput::Backspace),
+            Some(Action::Undo { erase: 11, restore: "btw ".into() })
+        );
+        // 只有紧接着的退格才撤销
+        m.expanded("btw".into(), ' ', 10);
+        type_str(&mut m, "x");
+        assert_eq!(key(&mut m, KeyInput::Backspace), None);
+    }
+}
@@ -0,0 +1,218 @@
+// 出站 HTTP 代理：按设置中的代理配置创建 reqwest 客户端，更新检查、下载、联想词、网站图标和 AI 对话请求都经由这里发出
+// system 使用系统代理（Windows 读取 Internet Settings，其他平台读取环境变量），none 直连，manual 使用手动填写的 HTTP / SOCKS5 代理
+
+use crate::settings::ProxySettings;
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, Mutex, RwLock};
+use std::time::Duration;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+static PROXY: LazyLock<RwLock<ProxySettings>> = LazyLock::new(|| RwLock::new(ProxySettings::default()));
+// 共享客户端，代理设置变化时重建
+static CLIENT: LazyLock<Mutex<Option<reqwest::Client>>> = LazyLock::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemProxy {
+    pub server: String,         // 代理地址，如 http://127.0.0.1:7890
+    pub bypass: Option<String>, // 不走代理的地址列表（逗号分隔）
+}
+
+/// 更新代理配置（启动时和保存设置时调用）
+pub fn configure(proxy: &ProxySettings) {
+    if let Ok(mut current) = PROXY.write() {
+        *current = proxy.clone();
+    }
+    if let Ok(mut client) = CLIENT.lock() {
+        *client = None;
+    }
+}
+
+/// 解析系统代理的 ProxyServer 值："host:port" 或 "http=host:port;https=host:port;socks=host:port"
+fn parse_proxy_server(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if !value.contains('=') {
+        return Some(if value.contains("://") {
+            value.to_string()
+        } else {
+            format!("http://{}", value)
+        });
+    }
+
+    let entries: Vec<(&str, &str)> = value
+        .split(';')
+        .filter_map(|entry| entry.split_once('='))
+        .map(|(scheme, addr)| (scheme.trim(), addr.trim()))
+        .filter(|(_, addr)| !addr.is_empty())
+        .collect();
+    let find = |scheme: &str| entries.iter().find(|(s, _)| s.eq_ignore_ascii_case(scheme)).map(|(_, a)| *a);
+    if let Some(addr) = find("https").or_else(|| find("http")) {
+        return Some(format!("http://{}", addr));
+    }
+    find("socks").map(|addr| format!("socks5://{}", addr))
+}
+
+/// 解析系统代理的 ProxyOverride 值：分号分隔，"<local>" 表示本地地址
+fn parse_proxy_bypass(value: &str) -> Option<String> {
+    let hosts: Vec<&str> = value
+        .split(';')
+        .map(str::trim)
+        .filter(|host| !host.is_empty())
+        .flat_map(|host| {
+            if host.eq_ignore_ascii_case("<local>") {
+                vec!["localhost", "127.0.0.1", "::1"]
+            } else {
+                vec![host]
+            }
+        })
+        .collect();
+    (!hosts.is_empty()).then(|| hosts.join(","))
+}
+
+/// 检测系统代理，未启用时返回 None
+#[cfg(target_os = "windows")]
+pub fn detect_system_proxy() -> Option<SystemProxy> {
+    use std::ffi::OsStr;
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD, RRF_RT_REG_SZ};
+
+    let to_wide = |s: &str| -> Vec<u16> { OsStr::new(s).encode_wide().chain(Some(0)).collect() };
+    let sub_key = to_wide("Software\\Microsoft\\Windows\\CurrentVersion\\Internet Settings");
+
+    let read_dword = |name: &str| -> Option<u32> {
+        let name = to_wide(name);
+        let mut value: u32 = 0;
+        let mut size = std::mem::size_of::<u32>() as u32;
+        let result = unsafe {
+            RegGetValueW(
+                HKEY_CURRENT_USER,
+                sub_key.as_ptr(),
+                name.as_ptr(),
+                RRF_RT_REG_DWORD,
+                std::ptr::null_mut(),
+                &mut value as *mut u32 as *mut _,
+                &mut size,
+            )
+        };
+        (result == 0).then_some(value)
+    };
+    let read_string = |name: &str| -> Option<String> {
+        let name = to_wide(name);
+        let mut buffer = vec![0u16; 2048];
+        let mut size = (buffer.len() * 2) as u32;
+        let result = unsafe {
+            RegGetValueW(
+                HKEY_CURRENT_USER,
+                sub_key.as_ptr(),
+                name.as_ptr(),
+                RRF_RT_REG_SZ,
+                std::ptr::null_mut(),
+                buffer.as_mut_ptr() as *mut _,
+                &mut size,
+            )
+        };
+        if result != 0 {
+            return None;
+        }
+        let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+        Some(String::from_utf16_lossy(&buffer[..len]))
+    };
+
+    if read_dword("ProxyEnable").unwrap_or(0) == 0 {
+        return None;
+    }
+    Some(SystemProxy {
+        server: parse_proxy_server(&read_string("ProxyServer")?)?,
+        bypass: read_string("ProxyOverride").and_then(|v| parse_proxy_bypass(&v.replace(',', ";"))),
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn detect_system_proxy() -> Option<SystemProxy> {
+    let server = ["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy", "ALL_PROXY", "all_proxy"]
+        .iter()
+        .find_map(|name| std::env::var(name).ok().filter(|v| !v.trim().is_empty()))?;
+    Some(SystemProxy {
+        server: parse_proxy_server(&server)?,
+        bypass: std::env::var("NO_PROXY")
+            .or_else(|_| std::env::var("no_proxy"))
+            .ok()
+            .and_then(|v| parse_proxy_bypass(&v.replace(',', ";"))),
+    })
+}
+
+fn manual_proxy(settings: &ProxySettings) -> Result<reqwest::Proxy, String> {
+    let url = settings.url.trim();
+    if url.is_empty() {
+        return Err("未填写代理地址".to_string());
+    }
+    let url = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("http://{}", url)
+    };
+    let mut url = reqwest::Url::parse(&url).map_err(|e| format!("Invalid proxy url {}: {}", url, e))?;
+    // 认证信息写入代理地址，HTTP 代理和 SOCKS5 代理都从这里读取
+    if let Some(username) = settings.username.as_deref().filter(|u| !u.is_empty()) {
+        url.set_username(username)
+            .and_then(|_| url.set_password(settings.decrypted_password().as_deref()))
+            .map_err(|_| format!("Invalid proxy url {}", url))?;
+    }
+    reqwest::Proxy::all(url.as_str()).map_err(|e| format!("Invalid proxy url {}: {}", url, e))
+}
+
+/// 按当前代理配置创建 ClientBuilder，调用方可以继续设置超时、User-Agent 等
+pub fn builder() -> Result<reqwest::ClientBuilder, String> {
+    let settings = PROXY.read().map(|s| s.clone()).unwrap_or_default();
+    let builder = reqwest::Client::builder().connect_timeout(CONNECT_TIMEOUT);
+    let builder = match settings.mode.as_str() {
+        "none" => builder.no_proxy(),
+        "manual" => builder.proxy(manual_proxy(&settings)?),
+        _ => match detect_system_proxy() {
+            Some(system) => {
+                let proxy = reqwest::Proxy::all(&system.server)
+                    .map_err(|e| format!("Invalid system proxy {}: {}", system.server, e))?
+                    .no_proxy(system.bypass.as_deref().and_then(reqwest::NoProxy::from_string));
+                builder.proxy(proxy)
+            }
+            None => builder.no_proxy(),
+        },
+    };
+    Ok(builder)
+}
+
+/// 共享客户端（无整体超时，适合流式请求；需要超时的请求在 RequestBuilder 上设置）
+pub fn client() -> Result<reqwest::Client, String> {
+    let mut cached = CLIENT.lock().map_err(|e| format!("Failed to lock http client: {}", e))?;
+    if let Some(client) = cached.as_ref() {
+        return Ok(client.clone());
+    }
+    let client = builder()?
+        .build()
+        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
+    *cached = Some(client.clone());
+    Ok(client)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_system_proxy_values() {
+        assert_eq!(parse_proxy_server("127.0.0.1:7890").as_deref(), Some("http://127.0.0.1:7890"));
+        assert_eq!(
+            parse_proxy_server("http=proxy:80;https=proxy:443;socks=proxy:1080").as_deref(),
+            Some("http://proxy:443")
+        );
+        assert_eq!(parse_proxy_server("socks=proxy:1080").as_deref(), Some("socks5://proxy:1080"));
+        assert_eq!(parse_proxy_server(""), None);
+        assert_eq!(
+            parse_proxy_bypass("*.corp.com;<local>").as_deref(),
+            Some("*.corp.com,localhost,127.0.0.1,::1")
+        );
+    }
+}
@@ -0,0 +1,112 @@
+// 后端文案多语言：托盘菜单、特殊文件夹名称、进度通知等由后端提供的显示文本按设置中的语言输出
+// 当前语言保存在全局变量中，启动时和保存设置时根据 settings.locale 更新
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    ZhCn,
+    EnUs,
+}
+
+impl Locale {
+    /// 解析语言代码，未知语言回退到简体中文
+    pub fn parse(code: &str) -> Self {
+        if code.trim().to_lowercase().starts_with("en") {
+            Locale::EnUs
+        } else {
+            Locale::ZhCn
+        }
+    }
+}
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+// (key, 简体中文, English)
+const CATALOG: &[(&str, &str, &str)] = &[
+    ("tray.app_center", "应用中心", "App Center"),
+    ("tray.keep_awake", "保持唤醒", "Keep Awake"),
+    ("tray.keep_awake_active", "保持唤醒中", "Keeping awake"),
+    ("tray.keep_awake_until", "保持唤醒至 {}", "Keeping awake until {}"),
+    ("tray.open_logs", "打开日志文件夹", "Open Logs Folder"),
+    ("tray.restart", "重启程序", "Restart"),
+    ("tray.quit", "退出", "Quit"),
+    ("folder.recycle_bin", "回收站", "Recycle Bin"),
+    ("folder.settings", "设置", "Settings"),
+    ("folder.downloads", "下载", "Downloads"),
+    ("app_scan.start", "开始扫描应用...", "Scanning applications..."),
+    ("app_scan.path", "正在扫描: {}", "Scanning: {}"),
+    ("app_scan.desktop", "正在扫描桌面...", "Scanning desktop..."),
+    ("app_scan.store", "正在扫描 Microsoft Store 应用...", "Scanning Microsoft Store apps..."),
+    ("app_scan.dedup", "找到 {} 个应用，正在去重...", "Found {} apps, removing duplicates..."),
+    ("app_scan.dedup_done", "去重完成，共 {} 个应用", "Deduplicated, {} apps in total"),
+    ("app_scan.done", "扫描完成", "Scan complete"),
+    ("clipboard.clear_progress", "正在删除图片文件 {}/{}", "Deleting image files {}/{}"),
+    ("db.app_data_dir_error", "无法获取应用数据目录: {}", "Cannot access app data directory: {}"),
+    ("db.access_error", "数据库访问错误: {}", "Database access error: {}"),
+];
+
+pub fn current() -> Locale {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Locale::EnUs,
+        _ => Locale::ZhCn,
+    }
+}
+
+/// 设置当前语言，返回语言是否发生变化
+pub fn set_locale(code: &str) -> bool {
+    let value = match Locale::parse(code) {
+        Locale::ZhCn => 0,
+        Locale::EnUs => 1,
+    };
+    CURRENT.swap(value, Ordering::Relaxed) != value
+}
+
+/// 按指定语言查找文案，缺失的 key 原样返回
+pub fn lookup(locale: Locale, key: &'static str) -> &'static str {
+    CATALOG
+        .iter()
+        .find(|(k, _, _)| *k == key)
+        .map(|(_, zh, en)| match locale {
+            Locale::ZhCn => *zh,
+            Locale::EnUs => *en,
+        })
+        .unwrap_or(key)
+}
+
+/// 按当前语言获取文案
+pub fn t(key: &'static str) -> &'static str {
+    lookup(current(), key)
+}
+
+/// 按当前语言获取文案，并依次替换其中的 {} 占位符
+pub fn tf(key: &'static str, args: &[&dyn Display]) -> String {
+    let mut result = String::new();
+    let mut args = args.iter();
+    let mut parts = t(key).split("{}").peekable();
+    while let Some(part) = parts.next() {
+        result.push_str(part);
+        if parts.peek().is_some() {
+            if let Some(arg) = args.next() {
+                result.push_str(&arg.to_string());
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looks_up_and_formats_messages() {
+        assert_eq!(Locale::parse("en-US"), Locale::EnUs);
+        assert_eq!(Locale::parse("fr"), Locale::ZhCn);
+        assert_eq!(lookup(Locale::EnUs, "folder.downloads"), "Downloads");
+        assert_eq!(lookup(Locale::ZhCn, "folder.downloads"), "下载");
+        assert_eq!(lookup(Locale::EnUs, "missing.key"), "missing.key");
+        assert_eq!(tf("clipboard.clear_progress", &[&3, &10]), "正在删除图片文件 3/10");
+    }
+}
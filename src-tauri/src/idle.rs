@@ -0,0 +1,111 @@
+// 空闲检测：通过 GetLastInputInfo 获取用户最后一次键鼠输入距今的时间
+// 数据库备份、应用缓存刷新、图片清理、数据库维护等重任务通过 schedule 注册，只在用户空闲一段时间后执行
+
+use crate::db;
+use crate::shutdown;
+use rusqlite::{params, OptionalExtension};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// 检查任务是否到期的间隔
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 用户最后一次输入距今的时间
+#[cfg(target_os = "windows")]
+pub fn idle_duration() -> Duration {
+    use windows_sys::Win32::System::SystemInformation::GetTickCount;
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO};
+
+    let mut info = LASTINPUTINFO {
+        cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32,
+        dwTime: 0,
+    };
+    unsafe {
+        if GetLastInputInfo(&mut info) == 0 {
+            return Duration::ZERO;
+        }
+        // 两者都是 32 位毫秒计数，约 49.7 天回绕一次，用 wrapping_sub 处理
+        Duration::from_millis(GetTickCount().wrapping_sub(info.dwTime) as u64)
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn idle_duration() -> Duration {
+    Duration::ZERO
+}
+
+/// 用户是否已空闲至少 min_idle
+pub fn is_idle(min_idle: Duration) -> bool {
+    idle_duration() >= min_idle
+}
+
+fn now_ts() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+fn state_key(name: &str) -> String {
+    format!("idle_task.{}", name)
+}
+
+// 上次执行时间保存在 settings 表中，重启后不会立刻重复执行
+fn last_run(app_data_dir: &Path, name: &str) -> Result<i64, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let value: Option<String> = conn
+            .prepare_cached("SELECT value FROM settings WHERE key = ?1")
+            .and_then(|mut stmt| stmt.query_row(params![state_key(name)], |row| row.get(0)).optional())
+            .map_err(|e| format!("Failed to read idle task state: {}", e))?;
+        Ok(value.and_then(|v| v.parse().ok()).unwrap_or(0))
+    })
+}
+
+fn set_last_run(app_data_dir: &Path, name: &str, ts: i64) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "INSERT INTO settings (key, value) VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        )
+        .and_then(|mut stmt| stmt.execute(params![state_key(name), ts.to_string()]))
+        .map_err(|e| format!("Failed to save idle task state: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 注册后台任务：距上次执行超过 interval 且用户空闲至少 min_idle 时执行
+pub fn schedule<F>(
+    name: &'static str,
+    app_data_dir: PathBuf,
+    interval: Duration,
+    min_idle: Duration,
+    task: F,
+) -> Result<(), String>
+where
+    F: Fn(&Path) -> Result<(), String> + Send + 'static,
+{
+    shutdown::spawn(&format!("idle-{}", name), move || {
+        while !shutdown::wait_timeout(CHECK_INTERVAL) {
+            let due = match last_run(&app_data_dir, name) {
+                Ok(last) => now_ts() - last >= interval.as_secs() as i64,
+                Err(e) => {
+                    eprintln!("[Idle] {}: {}", name, e);
+                    false
+                }
+            };
+            if !due || !is_idle(min_idle) {
+                continue;
+            }
+
+            let started = std::time::Instant::now();
+            match task(&app_data_dir) {
+                Ok(()) => eprintln!("[Idle] {} finished in {:?}", name, started.elapsed()),
+                Err(e) => eprintln!("[Idle] {} failed: {}", name, e),
+            }
+            // 失败也记录时间，避免每分钟重试
+            if let Err(e) = set_last_run(&app_data_dir, name, now_ts()) {
+                eprintln!("[Idle] {}: {}", name, e);
+            }
+        }
+    })
+}
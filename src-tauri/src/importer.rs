@@ -0,0 +1,360 @@
+// 从其他启动器导入配置：Wox / Flow Launcher、PowerToys Run、uTools
+// 自定义网页搜索 -> search_engines，书签 / 快速链接 -> 快捷方式，别名 -> aliases
+
+use crate::settings::{self, AliasConfig, SearchEngineConfig};
+use crate::shortcuts::{self, ShortcutItem};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportedQuicklink {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ImportPreview {
+    pub source: String,
+    pub search_engines: Vec<SearchEngineConfig>,
+    pub quicklinks: Vec<ImportedQuicklink>,
+    pub aliases: Vec<AliasConfig>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportResult {
+    pub search_engines_added: usize,
+    pub quicklinks_added: usize,
+    pub aliases_added: usize,
+    pub skipped: usize, // 与现有配置重复而跳过的条目数
+}
+
+/// 将各启动器的查询占位符统一为 {query}
+pub fn normalize_query_placeholder(url: &str) -> String {
+    url.replace("{{query}}", "{query}")
+        .replace("{q}", "{query}")
+        .replace("{searchTerms}", "{query}")
+        .replace("%s", "{query}")
+}
+
+fn is_search_url(url: &str) -> bool {
+    normalize_query_placeholder(url).contains("{query}")
+}
+
+fn to_prefix(keyword: &str) -> String {
+    format!("{} ", keyword.trim())
+}
+
+fn read_json(path: &Path) -> Result<serde_json::Value, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    // 部分 .NET 程序写入的 JSON 带 BOM
+    serde_json::from_str(content.trim_start_matches('\u{feff}'))
+        .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+}
+
+fn str_field<'a>(value: &'a serde_json::Value, keys: &[&str]) -> Option<&'a str> {
+    keys.iter()
+        .find_map(|key| value.get(*key).and_then(|v| v.as_str()))
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+/// Wox / Flow Launcher 的 WebSearch 插件设置
+fn default_wox_path() -> Option<PathBuf> {
+    let appdata = PathBuf::from(env::var("APPDATA").ok()?);
+    [
+        appdata.join("Wox").join("Settings").join("Plugins").join("Wox.Plugin.WebSearch").join("Settings.json"),
+        appdata
+            .join("FlowLauncher")
+            .join("Settings")
+            .join("Plugins")
+            .join("Flow.Launcher.Plugin.WebSearch")
+            .join("Settings.json"),
+    ]
+    .into_iter()
+    .find(|p| p.exists())
+}
+
+fn parse_wox(value: &serde_json::Value, preview: &mut ImportPreview) {
+    let searches = match value
+        .get("WebSearches")
+        .or_else(|| value.get("SearchSources"))
+        .and_then(|v| v.as_array())
+    {
+        Some(searches) => searches,
+        None => return,
+    };
+
+    for search in searches {
+        if search.get("Enabled").and_then(|v| v.as_bool()) == Some(false) {
+            continue;
+        }
+        let (title, keyword, url) = match (
+            str_field(search, &["Title"]),
+            str_field(search, &["ActionKeyword"]),
+            str_field(search, &["Url"]),
+        ) {
+            (Some(t), Some(k), Some(u)) => (t, k, u),
+            _ => continue,
+        };
+        // "*" 表示全局触发，IMiss 需要明确的前缀
+        if keyword == "*" {
+            continue;
+        }
+        preview.search_engines.push(SearchEngineConfig {
+            prefix: to_prefix(keyword),
+            url: normalize_query_placeholder(url),
+            name: title.to_string(),
+            suggest_source: None,
+        });
+    }
+}
+
+/// PowerToys Run 社区插件 WebSearchShortcut 的存储文件
+fn default_powertoys_path() -> Option<PathBuf> {
+    let local = PathBuf::from(env::var("LOCALAPPDATA").ok()?);
+    let path = local
+        .join("Microsoft")
+        .join("PowerToys")
+        .join("PowerToys Run")
+        .join("Settings")
+        .join("Plugins")
+        .join("Community.PowerToys.Run.Plugin.WebSearchShortcut")
+        .join("WebSearchShortcutStorage.json");
+    path.exists().then_some(path)
+}
+
+fn parse_powertoys(value: &serde_json::Value, preview: &mut ImportPreview) {
+    // 格式：{ "<名称>": { "Url": "...%s...", "Keyword": "g", ... }, ... }
+    let map = match value.as_object() {
+        Some(map) => map,
+        None => return,
+    };
+    for (name, item) in map {
+        let url = match str_field(item, &["Url", "url"]) {
+            Some(url) => url,
+            None => continue,
+        };
+        let name = str_field(item, &["Name", "name"]).unwrap_or(name).to_string();
+        match str_field(item, &["Keyword", "keyword"]) {
+            Some(keyword) if is_search_url(url) => preview.search_engines.push(SearchEngineConfig {
+                prefix: to_prefix(keyword),
+                url: normalize_query_placeholder(url),
+                name,
+                suggest_source: None,
+            }),
+            _ => preview.quicklinks.push(ImportedQuicklink {
+                name,
+                url: url.to_string(),
+            }),
+        }
+    }
+}
+
+/// uTools 的数据保存在其内部数据库中，这里解析用户从「网页快开」等插件导出的 JSON 数组：
+/// [{ "name"/"title", "url", "keyword"/"cmds" }]
+/// 含查询占位符的条目作为搜索引擎；指向本地路径且带关键字的条目作为别名；其余作为快速链接
+fn parse_utools(value: &serde_json::Value, preview: &mut ImportPreview) {
+    let items = match value.as_array().or_else(|| value.get("data").and_then(|v| v.as_array())) {
+        Some(items) => items,
+        None => return,
+    };
+
+    for item in items {
+        let url = match str_field(item, &["url", "link", "path"]) {
+            Some(url) => url,
+            None => continue,
+        };
+        let name = str_field(item, &["name", "title", "explain"]).unwrap_or(url).to_string();
+        let keyword = str_field(item, &["keyword"]).map(str::to_string).or_else(|| {
+            item.get("cmds")
+                .and_then(|v| v.as_array())
+                .and_then(|cmds| cmds.iter().find_map(|c| c.as_str()))
+                .map(str::to_string)
+        });
+        let is_web = url.starts_with("http://") || url.starts_with("https://");
+
+        match keyword {
+            Some(keyword) if is_search_url(url) => preview.search_engines.push(SearchEngineConfig {
+                prefix: to_prefix(&keyword),
+                url: normalize_query_placeholder(url),
+                name,
+                suggest_source: None,
+            }),
+            Some(keyword) if !is_web => preview.aliases.push(AliasConfig {
+                alias: keyword,
+                target: url.to_string(),
+            }),
+            _ => preview.quicklinks.push(ImportedQuicklink {
+                name,
+                url: url.to_string(),
+            }),
+        }
+    }
+}
+
+/// 解析指定来源的配置（path 为空时使用该启动器的默认位置）
+pub fn preview_import(source: &str, path: Option<&str>) -> Result<ImportPreview, String> {
+    let path = match path.map(str::trim).filter(|p| !p.is_empty()) {
+        Some(path) => PathBuf::from(path),
+        None => match source {
+            "wox" => default_wox_path(),
+            "powertoys" => default_powertoys_path(),
+            _ => None,
+        }
+        .ok_or_else(|| format!("未找到 {} 的配置文件，请手动选择", source))?,
+    };
+
+    let value = read_json(&path)?;
+    let mut preview = ImportPreview {
+        source: source.to_string(),
+        ..Default::default()
+    };
+    match source {
+        "wox" => parse_wox(&value, &mut preview),
+        "powertoys" => parse_powertoys(&value, &mut preview),
+        "utools" => parse_utools(&value, &mut preview),
+        _ => return Err(format!("Unsupported import source: {}", source)),
+    }
+    Ok(preview)
+}
+
+/// 将预览结果合并到现有配置，前缀 / 链接 / 别名重复的条目跳过
+pub fn apply_import(preview: &ImportPreview, app_data_dir: &Path) -> Result<ImportResult, String> {
+    let mut result = ImportResult {
+        search_engines_added: 0,
+        quicklinks_added: 0,
+        aliases_added: 0,
+        skipped: 0,
+    };
+
+    let mut settings = settings::load_settings(app_data_dir)?;
+    for engine in &preview.search_engines {
+        if settings.search_engines.iter().any(|e| e.prefix == engine.prefix) {
+            result.skipped += 1;
+        } else {
+            settings.search_engines.push(engine.clone());
+            result.search_engines_added += 1;
+        }
+    }
+    for alias in &preview.aliases {
+        if settings.aliases.iter().any(|a| a.alias.eq_ignore_ascii_case(&alias.alias)) {
+            result.skipped += 1;
+        } else {
+            settings.aliases.push(alias.clone());
+            result.aliases_added += 1;
+        }
+    }
+    settings::save_settings(app_data_dir, &settings)?;
+
+    let mut existing_urls: HashSet<String> = shortcuts::get_all_shortcuts(app_data_dir)?
+        .into_iter()
+        .map(|s| s.path)
+        .collect();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("Failed to get timestamp: {}", e))?
+        .as_secs();
+    let mut links = Vec::new();
+    for link in &preview.quicklinks {
+        if !existing_urls.insert(link.url.clone()) {
+            result.skipped += 1;
+            continue;
+        }
+        // 同一秒内导入多条，id 追加序号避免重复
+        links.push(ShortcutItem {
+            id: format!("{}_{}_{}", link.name.replace(' ', "_"), timestamp, links.len()),
+            name: link.name.clone(),
+            path: link.url.clone(),
+            icon: None,
+            created_at: timestamp,
+            updated_at: timestamp,
+        });
+    }
+    result.quicklinks_added = links.len();
+    shortcuts::upsert_shortcuts(links, app_data_dir)?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn parse(parser: fn(&serde_json::Value, &mut ImportPreview), value: serde_json::Value) -> ImportPreview {
+        let mut preview = ImportPreview::default();
+        parser(&value, &mut preview);
+        preview
+    }
+
+    #[test]
+    fn parses_wox_web_searches() {
+        let preview = parse(
+            parse_wox,
+            json!({
+                "WebSearches": [
+                    { "Title": "Google", "ActionKeyword": "g", "Url": "https://www.google.com/search?q={q}", "Enabled": true },
+                    { "Title": "Disabled", "ActionKeyword": "d", "Url": "https://d.example.com/?q={q}", "Enabled": false },
+                    { "Title": "Global", "ActionKeyword": "*", "Url": "https://x.example.com/?q={q}" },
+                    { "Title": "No url", "ActionKeyword": "n" }
+                ]
+            }),
+        );
+        assert_eq!(preview.search_engines.len(), 1);
+        assert_eq!(preview.search_engines[0].prefix, "g ");
+        assert_eq!(preview.search_engines[0].url, "https://www.google.com/search?q={query}");
+        assert_eq!(preview.search_engines[0].name, "Google");
+
+        // Flow Launcher 使用 SearchSources
+        let flow = parse(
+            parse_wox,
+            json!({ "SearchSources": [{ "Title": "Bing", "ActionKeyword": "b", "Url": "https://bing.com/search?q={q}" }] }),
+        );
+        assert_eq!(flow.search_engines[0].prefix, "b ");
+    }
+
+    #[test]
+    fn parses_powertoys_shortcuts() {
+        let preview = parse(
+            parse_powertoys,
+            json!({
+                "GitHub": { "Url": "https://github.com/search?q=%s", "Keyword": "gh" },
+                "Docs": { "Name": "Rust Docs", "Url": "https://doc.rust-lang.org/" }
+            }),
+        );
+        assert_eq!(preview.search_engines.len(), 1);
+        assert_eq!(preview.search_engines[0].prefix, "gh ");
+        assert_eq!(preview.search_engines[0].url, "https://github.com/search?q={query}");
+        assert_eq!(preview.quicklinks.len(), 1);
+        assert_eq!(preview.quicklinks[0].name, "Rust Docs");
+        assert_eq!(preview.quicklinks[0].url, "https://doc.rust-lang.org/");
+    }
+
+    #[test]
+    fn parses_utools_export() {
+        let preview = parse(
+            parse_utools,
+            json!({
+                "data": [
+                    { "name": "百度", "url": "https://www.baidu.com/s?wd={{query}}", "cmds": ["bd"] },
+                    { "title": "文档", "path": "D:\\Docs", "keyword": "docs" },
+                    { "name": "掘金", "url": "https://juejin.cn/" },
+                    { "name": "missing" }
+                ]
+            }),
+        );
+        assert_eq!(preview.search_engines.len(), 1);
+        assert_eq!(preview.search_engines[0].prefix, "bd ");
+        assert_eq!(preview.search_engines[0].url, "https://www.baidu.com/s?wd={query}");
+        assert_eq!(preview.aliases.len(), 1);
+        assert_eq!(preview.aliases[0].alias, "docs");
+        assert_eq!(preview.aliases[0].target, "D:\\Docs");
+        assert_eq!(preview.quicklinks.len(), 1);
+        assert_eq!(preview.quicklinks[0].url, "https://juejin.cn/");
+    }
+}
@@ -0,0 +1,119 @@
+// 保持唤醒（咖啡因模式）：周期性调用 SetThreadExecutionState 重置系统空闲计时，阻止睡眠与息屏
+// 可设定时长（到期自动结束）或一直保持；开启期间托盘菜单勾选"保持唤醒"并在托盘提示中标注
+
+use crate::{shutdown, tray};
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+/// 系统空闲超时最短为 1 分钟，刷新间隔需小于它
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy)]
+struct Session {
+    until: Option<i64>, // 结束时间（Unix 毫秒），None 表示一直保持
+    keep_display: bool,
+}
+
+static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+// 每次开启或关闭递增，旧的刷新线程据此退出
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KeepAwakeStatus {
+    pub active: bool,
+    pub keep_display: bool, // 是否同时阻止关闭显示器
+    pub until: Option<i64>,
+}
+
+fn now_millis() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+pub fn status() -> KeepAwakeStatus {
+    let session = SESSION.lock().ok().and_then(|s| *s);
+    KeepAwakeStatus {
+        active: session.is_some(),
+        keep_display: session.map(|s| s.keep_display).unwrap_or(false),
+        until: session.and_then(|s| s.until),
+    }
+}
+
+/// 当前是否处于保持唤醒状态
+pub fn is_active() -> bool {
+    status().active
+}
+
+fn notify(app_handle: &AppHandle) {
+    let _ = tray::refresh_menu(app_handle);
+    let _ = tray::refresh_tooltip(app_handle);
+    let _ = app_handle.emit("keep-awake-changed", status());
+}
+
+#[cfg(target_os = "windows")]
+fn reset_idle_timer(keep_display: bool) {
+    use windows_sys::Win32::System::Power::{SetThreadExecutionState, ES_DISPLAY_REQUIRED, ES_SYSTEM_REQUIRED};
+
+    let flags = if keep_display { ES_SYSTEM_REQUIRED | ES_DISPLAY_REQUIRED } else { ES_SYSTEM_REQUIRED };
+    unsafe { SetThreadExecutionState(flags) };
+}
+
+#[cfg(not(target_os = "windows"))]
+fn reset_idle_timer(_keep_display: bool) {}
+
+/// 开启保持唤醒；minutes 为空时一直保持，重复调用会以新的时长重新计时
+pub fn start(app_handle: &AppHandle, minutes: Option<u64>, keep_display: bool) -> Result<KeepAwakeStatus, String> {
+    if minutes == Some(0) {
+        return Err("时长必须大于 0 分钟".to_string());
+    }
+    let session = Session {
+        until: minutes.map(|m| now_millis() + m as i64 * 60_000),
+        keep_display,
+    };
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    if let Ok(mut current) = SESSION.lock() {
+        *current = Some(session);
+    }
+
+    let app = app_handle.clone();
+    shutdown::spawn("keep-awake", move || {
+        loop {
+            if GENERATION.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            let remaining = session.until.map(|until| until - now_millis());
+            if remaining.is_some_and(|ms| ms <= 0) {
+                break;
+            }
+            reset_idle_timer(session.keep_display);
+            let wait = remaining
+                .map(|ms| REFRESH_INTERVAL.min(Duration::from_millis(ms as u64)))
+                .unwrap_or(REFRESH_INTERVAL);
+            if shutdown::wait_timeout(wait) {
+                return;
+            }
+        }
+        // 到期自动结束（期间没有重新开启或关闭）
+        if GENERATION.compare_exchange(generation, generation + 1, Ordering::SeqCst, Ordering::SeqCst).is_ok() {
+            if let Ok(mut current) = SESSION.lock() {
+                *current = None;
+            }
+            notify(&app);
+        }
+    })?;
+
+    notify(app_handle);
+    Ok(status())
+}
+
+/// 关闭保持唤醒，系统恢复按电源设置睡眠和息屏
+pub fn stop(app_handle: &AppHandle) -> KeepAwakeStatus {
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    let was_active = SESSION.lock().map(|mut s| s.take().is_some()).unwrap_or(false);
+    if was_active {
+        notify(app_handle);
+    }
+    status()
+}
@@ -0,0 +1,412 @@
+// 模拟输入的注入方式：中文输入法处于中文状态时，直接发送的虚拟键码会被输入法拦截组字，输出乱码。
+// 可以按备忘录热字串/宏分别选择：把字符键转换为 KEYEVENTF_UNICODE 直接输入（不经过输入法），
+// 或在输入期间把前台窗口临时切换到英文键盘布局，结束后恢复
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InjectionMode {
+    /// 文本用 KEYEVENTF_UNICODE 输入，宏按录制的虚拟键码回放
+    #[default]
+    Default,
+    /// 宏中不带 Ctrl/Alt/Win 的字符键也转换为 KEYEVENTF_UNICODE 输入
+    Unicode,
+    /// 输入期间切换到英文键盘布局
+    EnglishLayout,
+}
+
+impl InjectionMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InjectionMode::Default => "default",
+            InjectionMode::Unicode => "unicode",
+            InjectionMode::EnglishLayout => "english_layout",
+        }
+    }
+
+    /// 从数据库中保存的字符串解析，未知或为空时按默认方式
+    pub fn parse(value: Option<&str>) -> Self {
+        match value {
+            Some("unicode") => InjectionMode::Unicode,
+            Some("english_layout") => InjectionMode::EnglishLayout,
+            _ => InjectionMode::Default,
+        }
+    }
+}
+
+/// 按注入方式执行一次输入；英文布局模式下 f 执行期间前台窗口使用英文布局
+pub fn with_mode<T>(mode: InjectionMode, f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    let _layout = match mode {
+        InjectionMode::EnglishLayout => Some(platform::LayoutGuard::english()?),
+        _ => None,
+    };
+    f()
+}
+
+/// 转换后实际发送的输入
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Injected {
+    Key { vk: u16, up: bool },
+    Char(char),
+}
+
+const VK_SHIFT: u16 = 0x10;
+const VK_CONTROL: u16 = 0x11;
+const VK_MENU: u16 = 0x12;
+const VK_CAPITAL: u16 = 0x14;
+const VK_LWIN: u16 = 0x5B;
+const VK_RWIN: u16 = 0x5C;
+const VK_LSHIFT: u16 = 0xA0;
+const VK_RSHIFT: u16 = 0xA1;
+
+fn is_shift(vk: u16) -> bool {
+    matches!(vk, VK_SHIFT | VK_LSHIFT | VK_RSHIFT)
+}
+
+fn is_modifier(vk: u16) -> bool {
+    matches!(vk, VK_CONTROL | VK_MENU | VK_LWIN | VK_RWIN | 0xA2..=0xA5)
+}
+
+/// 美式键盘布局下按键对应的字符（录制时的键码按美式布局理解）
+pub fn us_char(vk: u16, shift: bool, caps: bool) -> Option<char> {
+    const DIGITS: &[u8; 10] = b"0123456789";
+    const SHIFTED_DIGITS: &[u8; 10] = b")!@#$%^&*(";
+    let c = match vk {
+        0x20 => ' ',
+        0x30..=0x39 => {
+            let table = if shift { SHIFTED_DIGITS } else { DIGITS };
+            table[(vk - 0x30) as usize] as char
+        }
+        0x41..=0x5A => {
+            let c = (b'a' + (vk - 0x41) as u8) as char;
+            if shift != caps {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        }
+        // 小键盘
+        0x60..=0x69 => DIGITS[(vk - 0x60) as usize] as char,
+        0x6A => '*',
+        0x6B => '+',
+        0x6D => '-',
+        0x6E => '.',
+        0x6F => '/',
+        _ => {
+            let (plain, shifted) = match vk {
+                0xBA => (';', ':'),
+                0xBB => ('=', '+'),
+                0xBC => (',', '<'),
+                0xBD => ('-', '_'),
+                0xBE => ('.', '>'),
+                0xBF => ('/', '?'),
+                0xC0 => ('`', '~'),
+                0xDB => ('[', '{'),
+                0xDC => ('\\', '|'),
+                0xDD => (']', '}'),
+                0xDE => ('\'', '"'),
+                _ => return None,
+            };
+            if shift {
+                shifted
+            } else {
+                plain
+            }
+        }
+    };
+    Some(c)
+}
+
+/// 回放宏时把字符键转换为 Unicode 字符。Shift 只用于决定大小写和符号，不单独发送
+/// （单独按下再松开 Shift 会切换输入法的中英文状态），需要组合其他按键（如 Shift+方向键）时才补发
+#[derive(Debug, Default)]
+pub struct UnicodeKeys {
+    shift: Option<u16>,
+    shift_sent: bool,
+    caps: bool,
+    /// 按住的 Ctrl/Alt/Win，按住时按键按原样发送，保证快捷键可用
+    held: Vec<u16>,
+    /// 已转换为字符的按键，松开时不再发送
+    converted: Vec<u16>,
+}
+
+impl UnicodeKeys {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn on_key(&mut self, vk: u16, down: bool) -> Vec<Injected> {
+        if is_shift(vk) {
+            if down {
+                self.shift.get_or_insert(vk);
+                return Vec::new();
+            }
+            let pressed = self.shift.take();
+            return match pressed {
+                Some(pressed) if std::mem::take(&mut self.shift_sent) => vec![Injected::Key { vk: pressed, up: true }],
+                _ => Vec::new(),
+            };
+        }
+
+        if !down {
+            if let Some(index) = self.converted.iter().position(|&k| k == vk) {
+                self.converted.remove(index);
+                return Vec::new();
+            }
+            self.held.retain(|&k| k != vk);
+            return vec![Injected::Key { vk, up: true }];
+        }
+
+        if self.held.is_empty() {
+            if let Some(c) = us_char(vk, self.shift.is_some(), self.caps) {
+                if !self.converted.contains(&vk) {
+                    self.converted.push(vk);
+                }
+                return vec![Injected::Char(c)];
+            }
+        }
+
+        let mut injected = Vec::new();
+        if let Some(shift) = self.shift {
+            if !self.shift_sent {
+                self.shift_sent = true;
+                injected.push(Injected::Key { vk: shift, up: false });
+            }
+        }
+        if is_modifier(vk) && !self.held.contains(&vk) {
+            self.held.push(vk);
+        }
+        if vk == VK_CAPITAL {
+            self.caps = !self.caps;
+        }
+        injected.push(Injected::Key { vk, up: false });
+        injected
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub mod platform {
+    use super::Injected;
+    use std::time::{Duration, Instant};
+    use windows_sys::Win32::Foundation::HWND;
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
+        GetKeyboardLayout, GetKeyboardLayoutList, LoadKeyboardLayoutW, SendInput, UnloadKeyboardLayout, INPUT,
+        INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, KEYEVENTF_UNICODE, KLF_NOTELLSHELL, VK_BACK, VK_RETURN,
+        VK_TAB,
+    };
+    use windows_sys::Win32::UI::TextServices::HKL;
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        GetForegroundWindow, GetWindowThreadProcessId, PostMessageW, WM_INPUTLANGCHANGEREQUEST,
+    };
+
+    const LANG_EN_US: isize = 0x0409;
+    const LAYOUT_SWITCH_TIMEOUT: Duration = Duration::from_millis(300);
+
+    fn key_input(vk: u16, scan: u16, flags: u32) -> INPUT {
+        INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT { wVk: vk, wScan: scan, dwFlags: flags, time: 0, dwExtraInfo: 0 },
+            },
+        }
+    }
+
+    fn char_inputs(c: char, inputs: &mut Vec<INPUT>) {
+        let mut units = [0u16; 2];
+        for unit in c.encode_utf16(&mut units) {
+            inputs.push(key_input(0, *unit, KEYEVENTF_UNICODE));
+            inputs.push(key_input(0, *unit, KEYEVENTF_UNICODE | KEYEVENTF_KEYUP));
+        }
+    }
+
+    fn send(inputs: &[INPUT]) -> Result<(), String> {
+        if inputs.is_empty() {
+            return Ok(());
+        }
+        let sent = unsafe { SendInput(inputs.len() as u32, inputs.as_ptr(), std::mem::size_of::<INPUT>() as i32) };
+        if sent != inputs.len() as u32 {
+            return Err("模拟按键失败".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn send_backspaces(count: usize) -> Result<(), String> {
+        let inputs: Vec<INPUT> = (0..count)
+            .flat_map(|_| [key_input(VK_BACK, 0, 0), key_input(VK_BACK, 0, KEYEVENTF_KEYUP)])
+            .collect();
+        send(&inputs)
+    }
+
+    /// 逐字输入文本：换行和 Tab 用对应的按键，其余字符用 KEYEVENTF_UNICODE 直接输入
+    pub fn type_text(text: &str) -> Result<(), String> {
+        let mut inputs = Vec::new();
+        for c in text.replace("\r\n", "\n").chars() {
+            match c {
+                '\n' | '\r' => inputs.extend([key_input(VK_RETURN, 0, 0), key_input(VK_RETURN, 0, KEYEVENTF_KEYUP)]),
+                '\t' => inputs.extend([key_input(VK_TAB, 0, 0), key_input(VK_TAB, 0, KEYEVENTF_KEYUP)]),
+                _ => char_inputs(c, &mut inputs),
+            }
+        }
+        send(&inputs)
+    }
+
+    pub fn send_injected(injected: &[Injected]) -> Result<(), String> {
+        let mut inputs = Vec::new();
+        for item in injected {
+            match *item {
+                Injected::Key { vk, up } => inputs.push(key_input(vk, 0, if up { KEYEVENTF_KEYUP } else { 0 })),
+                Injected::Char(c) => char_inputs(c, &mut inputs),
+            }
+        }
+        send(&inputs)
+    }
+
+    fn foreground_layout(hwnd: HWND) -> HKL {
+        unsafe { GetKeyboardLayout(GetWindowThreadProcessId(hwnd, std::ptr::null_mut())) }
+    }
+
+    fn is_english(layout: HKL) -> bool {
+        layout & 0xFFFF == LANG_EN_US
+    }
+
+    // 切换请求是异步处理的，等待前台窗口的布局实际变化后再输入
+    fn request_layout(hwnd: HWND, layout: HKL) {
+        unsafe { PostMessageW(hwnd, WM_INPUTLANGCHANGEREQUEST, 0, layout) };
+        let started = Instant::now();
+        while foreground_layout(hwnd) != layout && started.elapsed() < LAYOUT_SWITCH_TIMEOUT {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    /// 把前台窗口临时切换到英文布局，离开作用域时恢复；系统中没有英文布局时临时加载，恢复后卸载
+    pub struct LayoutGuard {
+        hwnd: HWND,
+        previous: HKL,
+        loaded: Option<HKL>,
+    }
+
+    impl LayoutGuard {
+        pub fn english() -> Result<Self, String> {
+            let hwnd = unsafe { GetForegroundWindow() };
+            let previous = foreground_layout(hwnd);
+            if hwnd == 0 || is_english(previous) {
+                return Ok(Self { hwnd: 0, previous, loaded: None });
+            }
+
+            let mut layouts: [HKL; 32] = [0; 32];
+            let count = unsafe { GetKeyboardLayoutList(layouts.len() as i32, layouts.as_mut_ptr()) };
+            let installed = layouts[..count.max(0) as usize].iter().copied().find(|&layout| is_english(layout));
+            let (english, loaded) = match installed {
+                Some(layout) => (layout, None),
+                None => {
+                    let id: Vec<u16> = "00000409".encode_utf16().chain(Some(0)).collect();
+                    let layout = unsafe { LoadKeyboardLayoutW(id.as_ptr(), KLF_NOTELLSHELL) };
+                    if layout == 0 {
+                        return Err("无法加载英文键盘布局".to_string());
+                    }
+                    (layout, Some(layout))
+                }
+            };
+            request_layout(hwnd, english);
+            Ok(Self { hwnd, previous, loaded })
+        }
+    }
+
+    impl Drop for LayoutGuard {
+        fn drop(&mut self) {
+            if self.hwnd == 0 {
+                return;
+            }
+            request_layout(self.hwnd, self.previous);
+            if let Some(layout) = self.loaded {
+                unsafe { UnloadKeyboardLayout(layout) };
+            }
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub mod platform {
+    use super::Injected;
+
+    pub fn send_backspaces(_count: usize) -> Result<(), String> {
+        Err("当前平台不支持模拟输入".to_string())
+    }
+
+    pub fn type_text(_text: &str) -> Result<(), String> {
+        Err("当前平台不支持模拟输入".to_string())
+    }
+
+    pub fn send_injected(_injected: &[Injected]) -> Result<(), String> {
+        Err("当前平台不支持模拟输入".to_string())
+    }
+
+    pub struct LayoutGuard;
+
+    impl LayoutGuard {
+        pub fn english() -> Result<Self, String> {
+            Err("当前平台不支持切换键盘布局".to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(mapper: &mut UnicodeKeys, events: &[(u16, bool)]) -> Vec<Injected> {
+        events.iter().flat_map(|&(vk, down)| mapper.on_key(vk, down)).collect()
+    }
+
+    #[test]
+    fn maps_us_layout_characters() {
+        assert_eq!(us_char(0x41, false, false), Some('a'));
+        assert_eq!(us_char(0x41, true, false), Some('A'));
+        assert_eq!(us_char(0x41, true, true), Some('a'));
+        assert_eq!(us_char(0x32, true, false), Some('@'));
+        assert_eq!(us_char(0x32, true, true), Some('@'));
+        assert_eq!(us_char(0xDE, true, false), Some('"'));
+        assert_eq!(us_char(0x63, true, false), Some('3'));
+        assert_eq!(us_char(0x25, false, false), None); // 方向键
+        assert_eq!(InjectionMode::parse(Some(InjectionMode::EnglishLayout.as_str())), InjectionMode::EnglishLayout);
+        assert_eq!(InjectionMode::parse(None), InjectionMode::Default);
+    }
+
+    #[test]
+    fn converts_typing_without_sending_shift() {
+        let mut mapper = UnicodeKeys::new();
+        let injected = keys(
+            &mut mapper,
+            &[(VK_LSHIFT, true), (0x48, true), (0x48, false), (VK_LSHIFT, false), (0x49, true), (0x49, false)],
+        );
+        assert_eq!(injected, vec![Injected::Char('H'), Injected::Char('i')]);
+    }
+
+    #[test]
+    fn keeps_shortcuts_and_shift_combinations_as_keys() {
+        let mut mapper = UnicodeKeys::new();
+        let injected = keys(&mut mapper, &[(0xA2, true), (0x43, true), (0x43, false), (0xA2, false)]);
+        assert_eq!(
+            injected,
+            vec![
+                Injected::Key { vk: 0xA2, up: false },
+                Injected::Key { vk: 0x43, up: false },
+                Injected::Key { vk: 0x43, up: true },
+                Injected::Key { vk: 0xA2, up: true },
+            ]
+        );
+
+        // Shift+方向键选择文本时补发 Shift
+        let injected = keys(&mut mapper, &[(VK_SHIFT, true), (0x25, true), (0x25, false), (VK_SHIFT, false)]);
+        assert_eq!(
+            injected,
+            vec![
+                Injected::Key { vk: VK_SHIFT, up: false },
+                Injected::Key { vk: 0x25, up: false },
+                Injected::Key { vk: 0x25, up: true },
+                Injected::Key { vk: VK_SHIFT, up: true },
+            ]
+        );
+    }
+}
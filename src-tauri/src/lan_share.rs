@@ -0,0 +1,358 @@
+// 发送到手机：为剪切板项（文本、图片、文件）开启一个局域网内的临时下载链接，并生成二维码供手机扫码打开
+// 链接路径带随机口令，到期自动失效；所有分享失效后停止监听，平时不在局域网中开放端口
+
+use crate::commands::guess_mime_from_extension;
+use crate::text_variables::percent_encode;
+use crate::{clipboard, net_tools, shutdown};
+use qrcode::render::svg;
+use qrcode::QrCode;
+use rand::Rng;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_EXPIRE_MINUTES: u64 = 10;
+pub const MAX_EXPIRE_MINUTES: u64 = 60;
+pub const MAX_SHARE_BYTES: u64 = 200 * 1024 * 1024;
+const MAX_ACTIVE_SHARES: usize = 20;
+const MAX_REQUEST_HEAD_BYTES: u64 = 8 * 1024;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PhoneShare {
+    pub id: String,
+    pub kind: String, // "text" / "image" / "file"
+    pub name: String,
+    pub size: u64,
+    pub url: String,
+    pub qr_svg: String,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub downloads: u32,
+}
+
+#[derive(Debug, Clone)]
+enum Payload {
+    Text(String),
+    File(PathBuf),
+}
+
+struct Share {
+    info: PhoneShare,
+    payload: Payload,
+}
+
+#[derive(Default)]
+struct State {
+    port: Option<u16>, // 正在监听的端口，None 表示服务未运行
+    shares: HashMap<String, Share>,
+}
+
+static STATE: LazyLock<Mutex<State>> = LazyLock::new(|| Mutex::new(State::default()));
+
+fn now_ts() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn lock_state() -> Result<std::sync::MutexGuard<'static, State>, String> {
+    STATE.lock().map_err(|e| format!("Failed to lock share state: {}", e))
+}
+
+/// 生成二维码 SVG
+pub fn qr_svg(text: &str) -> Result<String, String> {
+    let code = QrCode::new(text.as_bytes()).map_err(|e| format!("Failed to encode QR code: {}", e))?;
+    Ok(code
+        .render::<svg::Color>()
+        .min_dimensions(200, 200)
+        .quiet_zone(true)
+        .build())
+}
+
+fn random_token() -> String {
+    let mut rng = rand::thread_rng();
+    (0..32).map(|_| format!("{:x}", rng.gen_range(0..16u8))).collect()
+}
+
+/// 为剪切板项创建分享链接；expire_minutes 为空时使用默认有效期
+pub fn create_share(item_id: &str, expire_minutes: Option<u64>, app_data_dir: &PathBuf) -> Result<PhoneShare, String> {
+    let item = clipboard::get_clipboard_item(item_id, app_data_dir)?.ok_or_else(|| "剪切板记录不存在".to_string())?;
+    let (payload, name, size) = match item.content_type.as_str() {
+        "text" => {
+            let name = item.content.chars().take(20).collect::<String>().trim().to_string();
+            let size = item.content.len() as u64;
+            (Payload::Text(item.content), name, size)
+        }
+        _ => {
+            // 图片为缓存的 PNG 文件路径；文件项取第一个路径
+            let path = PathBuf::from(clipboard::file_paths(&item.content).into_iter().next().unwrap_or_default());
+            let metadata = std::fs::metadata(&path).map_err(|_| format!("文件不存在: {}", path.display()))?;
+            if !metadata.is_file() {
+                return Err("只能发送单个文件".to_string());
+            }
+            let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            (Payload::File(path), name, metadata.len())
+        }
+    };
+    if size > MAX_SHARE_BYTES {
+        return Err(format!("内容超过 {} MB，无法发送", MAX_SHARE_BYTES / 1024 / 1024));
+    }
+    let ip = net_tools::local_ip()
+        .filter(|ip| !ip.is_loopback() && !ip.is_unspecified())
+        .ok_or_else(|| "未连接到局域网".to_string())?;
+
+    let mut state = lock_state()?;
+    let now = now_ts();
+    state.shares.retain(|_, share| share.info.expires_at > now);
+    if state.shares.len() >= MAX_ACTIVE_SHARES {
+        return Err(format!("最多同时分享 {} 项，请先停止不需要的分享", MAX_ACTIVE_SHARES));
+    }
+    let port = match state.port {
+        Some(port) => port,
+        None => {
+            let port = start_server()?;
+            state.port = Some(port);
+            port
+        }
+    };
+
+    let id = random_token();
+    let url = format!("http://{}/s/{}", SocketAddr::new(ip, port), id);
+    let minutes = expire_minutes.unwrap_or(DEFAULT_EXPIRE_MINUTES).clamp(1, MAX_EXPIRE_MINUTES);
+    let info = PhoneShare {
+        id: id.clone(),
+        kind: item.content_type,
+        name,
+        size,
+        qr_svg: qr_svg(&url)?,
+        url,
+        created_at: now,
+        expires_at: now + minutes * 60,
+        downloads: 0,
+    };
+    state.shares.insert(id, Share { info: info.clone(), payload });
+    Ok(info)
+}
+
+/// 未过期的分享，按创建时间从新到旧
+pub fn list_shares() -> Result<Vec<PhoneShare>, String> {
+    let state = lock_state()?;
+    let now = now_ts();
+    let mut shares: Vec<PhoneShare> = state
+        .shares
+        .values()
+        .filter(|share| share.info.expires_at > now)
+        .map(|share| share.info.clone())
+        .collect();
+    shares.sort_by_key(|s| std::cmp::Reverse(s.created_at));
+    Ok(shares)
+}
+
+/// 提前停止分享
+pub fn revoke_share(id: &str) -> Result<(), String> {
+    lock_state()?.shares.remove(id);
+    Ok(())
+}
+
+/// 监听所有网卡的随机端口（首次监听时 Windows 防火墙可能会询问是否允许）
+fn start_server() -> Result<u16, String> {
+    let listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)))
+        .map_err(|e| format!("Failed to bind share server: {}", e))?;
+    listener
+        .set_nonblocking(true)
+        .map_err(|e| format!("Failed to configure share server: {}", e))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| format!("Failed to get share server address: {}", e))?
+        .port();
+
+    shutdown::spawn("lan-share", move || loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                std::thread::spawn(move || {
+                    if let Err(e) = serve_connection(stream) {
+                        eprintln!("[LanShare] Connection error: {}", e);
+                    }
+                });
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                if shutdown::wait_timeout(Duration::from_millis(200)) {
+                    break;
+                }
+                // 所有分享都已失效时停止监听
+                if let Ok(mut state) = STATE.lock() {
+                    let now = now_ts();
+                    state.shares.retain(|_, share| share.info.expires_at > now);
+                    if state.shares.is_empty() {
+                        state.port = None;
+                        break;
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("[LanShare] Failed to accept connection: {}", e);
+                if shutdown::wait_timeout(Duration::from_secs(1)) {
+                    break;
+                }
+            }
+        }
+    })?;
+    Ok(port)
+}
+
+/// 解析请求行，返回 (方法, 路径)
+pub fn parse_request_line(line: &str) -> Option<(&str, &str)> {
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?;
+    let path = parts.next()?;
+    parts.next().filter(|version| version.starts_with("HTTP/"))?;
+    Some((method, path))
+}
+
+/// 从 /s/<口令> 路径中取出分享 ID
+pub fn share_id_from_path(path: &str) -> Option<&str> {
+    let id = path.strip_prefix("/s/")?.split(['?', '#']).next()?;
+    (!id.is_empty() && id.bytes().all(|b| b.is_ascii_hexdigit())).then_some(id)
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 文本在手机上以网页显示，便于长按全选复制
+pub fn text_page(text: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+         <title>IMiss</title></head><body style=\"margin:0;padding:16px;font-family:sans-serif\">\
+         <textarea readonly style=\"width:100%;height:80vh;box-sizing:border-box;font-size:16px\">{}</textarea></body></html>",
+        escape_html(text)
+    )
+}
+
+fn write_head(stream: &mut TcpStream, status: &str, headers: &[(&str, String)]) -> std::io::Result<()> {
+    let mut head = format!("HTTP/1.1 {}\r\nConnection: close\r\nCache-Control: no-store\r\n", status);
+    for (name, value) in headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes())
+}
+
+fn write_page(stream: &mut TcpStream, status: &str, body: &str, send_body: bool) -> std::io::Result<()> {
+    write_head(
+        stream,
+        status,
+        &[
+            ("Content-Type", "text/html; charset=utf-8".to_string()),
+            ("Content-Length", body.len().to_string()),
+        ],
+    )?;
+    if send_body {
+        stream.write_all(body.as_bytes())?;
+    }
+    Ok(())
+}
+
+fn serve_connection(mut stream: TcpStream) -> Result<(), String> {
+    stream
+        .set_nonblocking(false)
+        .and_then(|_| stream.set_read_timeout(Some(REQUEST_TIMEOUT)))
+        .map_err(|e| format!("Failed to configure connection: {}", e))?;
+    let mut reader = BufReader::new(stream.try_clone().map_err(|e| format!("Failed to clone connection: {}", e))?)
+        .take(MAX_REQUEST_HEAD_BYTES);
+    let mut request_line = String::new();
+    reader
+        .read_line(&mut request_line)
+        .map_err(|e| format!("Failed to read request: {}", e))?;
+    // 读完请求头，之后直接关闭连接
+    let mut header = String::new();
+    while reader.read_line(&mut header).map_err(|e| format!("Failed to read request: {}", e))? > 0 && header.trim() != "" {
+        header.clear();
+    }
+
+    let result = match parse_request_line(&request_line) {
+        Some((method @ ("GET" | "HEAD"), path)) => serve_share(&mut stream, path, method == "GET"),
+        Some(_) => write_page(&mut stream, "405 Method Not Allowed", "", false),
+        None => write_page(&mut stream, "400 Bad Request", "", false),
+    };
+    result.map_err(|e| format!("Failed to write response: {}", e))
+}
+
+fn serve_share(stream: &mut TcpStream, path: &str, send_body: bool) -> std::io::Result<()> {
+    let share = share_id_from_path(path).and_then(|id| {
+        let mut state = STATE.lock().ok()?;
+        let share = state.shares.get_mut(id).filter(|share| share.info.expires_at > now_ts())?;
+        if send_body {
+            share.info.downloads += 1;
+        }
+        Some((share.info.name.clone(), share.payload.clone()))
+    });
+    let Some((name, payload)) = share else {
+        return write_page(stream, "404 Not Found", &text_page("链接已失效"), send_body);
+    };
+
+    match payload {
+        Payload::Text(text) => write_page(stream, "200 OK", &text_page(&text), send_body),
+        Payload::File(path) => {
+            let mut file = match std::fs::File::open(&path) {
+                Ok(file) => file,
+                Err(_) => return write_page(stream, "404 Not Found", &text_page("文件已不存在"), send_body),
+            };
+            let size = file.metadata()?.len();
+            // 文件在分享后可能被替换，发送前再检查一次大小
+            if size > MAX_SHARE_BYTES {
+                return write_page(stream, "413 Payload Too Large", &text_page("文件过大"), send_body);
+            }
+            let extension = path.extension().map(|ext| ext.to_string_lossy().to_lowercase());
+            let mime = guess_mime_from_extension(extension.as_deref()).unwrap_or("application/octet-stream");
+            let disposition = if mime.starts_with("image/") { "inline" } else { "attachment" };
+            write_head(
+                stream,
+                "200 OK",
+                &[
+                    ("Content-Type", mime.to_string()),
+                    ("Content-Length", size.to_string()),
+                    ("Content-Disposition", format!("{}; filename*=UTF-8''{}", disposition, percent_encode(&name))),
+                ],
+            )?;
+            if send_body {
+                std::io::copy(&mut file, stream)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_share_requests() {
+        assert_eq!(parse_request_line("GET /s/abc123 HTTP/1.1\r\n"), Some(("GET", "/s/abc123")));
+        assert_eq!(parse_request_line("GET /s/abc123"), None);
+        assert_eq!(share_id_from_path("/s/0f9a?x=1"), Some("0f9a"));
+        assert_eq!(share_id_from_path("/s/"), None);
+        assert_eq!(share_id_from_path("/s/../etc"), None);
+        assert_eq!(share_id_from_path("/favicon.ico"), None);
+        assert!(text_page("<a & \"b\">").contains("&lt;a &amp; &quot;b&quot;&gt;"));
+        assert!(qr_svg("http://192.168.1.2:5000/s/0123456789abcdef0123456789abcdef").unwrap().starts_with("<?xml"));
+    }
+}
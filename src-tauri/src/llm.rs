@@ -0,0 +1,243 @@
+// AI 对话请求：通过共享 HTTP 客户端（应用代理设置）调用 OpenAI 兼容的 /chat/completions 接口
+// 流式请求按 SSE 逐行解析，每段增量内容通过回调交给调用方，最终返回完整回答和 token 用量
+
+use crate::api_policy::{self, ApiError, ApiErrorKind, Policy};
+use crate::http_client;
+use crate::prompt_templates::{self, PromptTemplate};
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: String,
+    pub content: String,
+}
+
+/// 回答缓存模式：none 不使用缓存；use 优先返回缓存；refresh 忽略已有缓存重新请求并更新缓存
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheMode {
+    #[default]
+    None,
+    Use,
+    Refresh,
+}
+
+/// 使用提示词模板生成消息：按 feature 取当前使用的模板，用 variables 渲染
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptInput {
+    pub feature: String,
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatRequest {
+    pub base_url: String,
+    #[serde(default)]
+    pub api_key: Option<String>,
+    pub model: String,
+    pub messages: Vec<ChatMessage>,
+    #[serde(default)]
+    pub stream: bool,
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+    #[serde(default)]
+    pub cache: CacheMode,
+    #[serde(default)]
+    pub feature: Option<String>, // 发起请求的功能，用于用量统计，如 "launcher_ask"、"wordbook_explain"
+    #[serde(default)]
+    pub prompt: Option<PromptInput>,
+    #[serde(default)]
+    pub save_history: bool, // 保存到对话记录
+}
+
+impl ChatRequest {
+    /// 渲染提示词模板，生成的系统消息和用户消息放在已有消息之前
+    pub fn apply_template(&mut self, template: &PromptTemplate) {
+        let Some(prompt) = self.prompt.take() else {
+            return;
+        };
+        let mut messages = Vec::with_capacity(self.messages.len() + 2);
+        if let Some(system) = &template.system {
+            messages.push(ChatMessage {
+                role: "system".to_string(),
+                content: prompt_templates::render(system, &prompt.variables),
+            });
+        }
+        messages.push(ChatMessage {
+            role: "user".to_string(),
+            content: prompt_templates::render(&template.template, &prompt.variables),
+        });
+        messages.append(&mut self.messages);
+        self.messages = messages;
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct TokenUsage {
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub estimated: bool, // 服务未返回 usage 时按字符数估算
+}
+
+// 支持 stream_options 的服务；其他 OpenAI 兼容服务可能因未知参数拒绝请求，流式用量改为按字符数估算
+const STREAM_USAGE_HOSTS: &[&str] = &["api.openai.com", "api.deepseek.com", "dashscope.aliyuncs.com", "api.groq.com"];
+
+/// 服务是否支持在流式响应中返回 token 用量
+fn supports_stream_usage(base_url: &str) -> bool {
+    let host = api_policy::provider_of(base_url);
+    STREAM_USAGE_HOSTS
+        .iter()
+        .any(|h| host == *h || host.ends_with(&format!(".{}", h)))
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatResult {
+    pub content: String,
+    pub usage: TokenUsage,
+    // 流式响应在收到 [DONE] 或 finish_reason 之前中断时为 false，此时内容不完整
+    pub complete: bool,
+}
+
+fn delta_content(value: &serde_json::Value) -> Option<&str> {
+    value.pointer("/choices/0/delta/content").and_then(|c| c.as_str())
+}
+
+fn parse_usage(value: &serde_json::Value) -> Option<TokenUsage> {
+    let usage = value.get("usage").filter(|u| u.is_object())?;
+    Some(TokenUsage {
+        prompt_tokens: usage.get("prompt_tokens").and_then(|v| v.as_u64()).unwrap_or(0),
+        completion_tokens: usage.get("completion_tokens").and_then(|v| v.as_u64()).unwrap_or(0),
+        estimated: false,
+    })
+}
+
+/// 粗略估算 token 数：中日韩字符约 1 token / 字，其他字符约 4 字符 / token
+pub fn estimate_tokens(text: &str) -> u64 {
+    let (cjk, other) = text.chars().fold((0u64, 0u64), |(cjk, other), c| {
+        if matches!(c as u32, 0x3000..=0x9FFF | 0xAC00..=0xD7AF | 0xF900..=0xFAFF | 0xFF00..=0xFFEF) {
+            (cjk + 1, other)
+        } else {
+            (cjk, other + 1)
+        }
+    });
+    cjk + other.div_ceil(4)
+}
+
+fn estimate_usage(request: &ChatRequest, answer: &str) -> TokenUsage {
+    TokenUsage {
+        // 每条消息约有 4 个 token 的格式开销
+        prompt_tokens: request
+            .messages
+            .iter()
+            .map(|m| estimate_tokens(&m.content) + 4)
+            .sum(),
+        completion_tokens: estimate_tokens(answer),
+        estimated: true,
+    }
+}
+
+/// 发送对话请求；stream 为 true 时每收到一段内容调用一次 on_delta
+pub async fn chat_completion<F>(request: &ChatRequest, mut on_delta: F) -> Result<ChatResult, ApiError>
+where
+    F: FnMut(&str),
+{
+    let base_url = if request.base_url.trim().is_empty() {
+        "https://api.openai.com/v1"
+    } else {
+        request.base_url.trim().trim_end_matches('/')
+    };
+    let mut body = serde_json::json!({
+        "model": request.model,
+        "messages": request.messages,
+        "stream": request.stream,
+    });
+    if request.stream && supports_stream_usage(base_url) {
+        // 让服务在最后一个数据块中返回 token 用量
+        body["stream_options"] = serde_json::json!({ "include_usage": true });
+    }
+    if let Some(max_tokens) = request.max_tokens {
+        body["max_tokens"] = max_tokens.into();
+    }
+
+    let client = http_client::client().map_err(|e| ApiError::new(ApiErrorKind::Invalid, e))?;
+    let url = format!("{}/chat/completions", base_url);
+    let response = api_policy::send(&api_policy::provider_of(&url), &Policy::LLM, || {
+        let builder = client.post(&url).json(&body);
+        match request.api_key.as_deref().filter(|k| !k.is_empty()) {
+            Some(api_key) => builder.bearer_auth(api_key),
+            None => builder,
+        }
+    })
+    .await?;
+
+    if !request.stream {
+        let value: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| ApiError::new(ApiErrorKind::Invalid, format!("解析响应失败: {}", e)))?;
+        let content = value
+            .pointer("/choices/0/message/content")
+            .and_then(|c| c.as_str())
+            .unwrap_or_default()
+            .to_string();
+        on_delta(&content);
+        let usage = parse_usage(&value).unwrap_or_else(|| estimate_usage(request, &content));
+        return Ok(ChatResult { content, usage, complete: true });
+    }
+
+    let mut answer = String::new();
+    let mut usage = None;
+    let mut complete = false;
+    // 按字节缓存，避免多字节字符被拆到两个数据块中
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut stream = response.bytes_stream();
+    'outer: while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| ApiError::new(ApiErrorKind::Network, format!("读取响应流失败: {}", e)))?;
+        buffer.extend_from_slice(&chunk);
+        while let Some(pos) = buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            let data = line.trim();
+            let data = data.strip_prefix("data:").map(str::trim).unwrap_or(data);
+            if data.is_empty() {
+                continue;
+            }
+            if data == "[DONE]" {
+                complete = true;
+                break 'outer;
+            }
+            let Ok(value) = serde_json::from_str::<serde_json::Value>(data) else {
+                continue;
+            };
+            if let Some(content) = delta_content(&value) {
+                answer.push_str(content);
+                on_delta(content);
+            }
+            if let Some(chunk_usage) = parse_usage(&value) {
+                usage = Some(chunk_usage);
+            }
+            if value.pointer("/choices/0/finish_reason").is_some_and(|r| r.is_string()) {
+                complete = true;
+            }
+        }
+    }
+    let usage = usage.unwrap_or_else(|| estimate_usage(request, &answer));
+    Ok(ChatResult { content: answer, usage, complete })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_stream_usage_only_from_supported_hosts() {
+        assert!(supports_stream_usage("https://api.openai.com/v1"));
+        assert!(supports_stream_usage("https://api.deepseek.com"));
+        assert!(!supports_stream_usage("http://localhost:11434/v1"));
+        assert!(!supports_stream_usage("https://proxy.example.com/openai/v1"));
+        assert!(!supports_stream_usage("https://api.openai.com.evil.example/v1"));
+    }
+}
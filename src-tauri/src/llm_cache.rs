@@ -0,0 +1,154 @@
+// AI 回答缓存：以请求内容（地址、模型、消息、参数）的哈希为 key，相同的解释 / 查词请求直接返回缓存结果
+// 条目超过有效期后失效；总大小超过上限时按最近使用时间淘汰
+
+use crate::db;
+use crate::llm::ChatRequest;
+use rusqlite::{params, Connection, OptionalExtension};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// 缓存有效期：30 天
+const CACHE_TTL_SECS: i64 = 30 * 24 * 60 * 60;
+// 缓存总大小上限：8 MB
+const MAX_CACHE_BYTES: i64 = 8 * 1024 * 1024;
+
+fn now_ts() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+/// 请求内容的哈希（不包含 API Key 和缓存模式）
+pub fn cache_key(request: &ChatRequest) -> String {
+    let content = serde_json::json!({
+        "base_url": request.base_url.trim().trim_end_matches('/'),
+        "model": request.model,
+        "messages": request.messages,
+        "max_tokens": request.max_tokens,
+    });
+    let mut hasher = Sha256::new();
+    hasher.update(content.to_string().as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+fn get_at(conn: &Connection, key: &str, now: i64) -> Result<Option<String>, String> {
+    let response: Option<String> = conn
+        .prepare_cached("SELECT response FROM llm_cache WHERE key = ?1 AND created_at > ?2")
+        .and_then(|mut stmt| stmt.query_row(params![key, now - CACHE_TTL_SECS], |row| row.get(0)).optional())
+        .map_err(|e| format!("Failed to read llm cache: {}", e))?;
+    if response.is_some() {
+        conn.prepare_cached("UPDATE llm_cache SET last_used = ?2 WHERE key = ?1")
+            .and_then(|mut stmt| stmt.execute(params![key, now]))
+            .map_err(|e| format!("Failed to update llm cache: {}", e))?;
+    }
+    Ok(response)
+}
+
+fn put_at(conn: &Connection, key: &str, response: &str, now: i64) -> Result<(), String> {
+    conn.prepare_cached(
+        "INSERT INTO llm_cache (key, response, size, created_at, last_used) VALUES (?1, ?2, ?3, ?4, ?4)
+         ON CONFLICT(key) DO UPDATE SET response = excluded.response, size = excluded.size,
+             created_at = excluded.created_at, last_used = excluded.last_used",
+    )
+    .and_then(|mut stmt| stmt.execute(params![key, response, response.len() as i64, now]))
+    .map_err(|e| format!("Failed to write llm cache: {}", e))?;
+
+    conn.prepare_cached("DELETE FROM llm_cache WHERE created_at <= ?1")
+        .and_then(|mut stmt| stmt.execute(params![now - CACHE_TTL_SECS]))
+        .map_err(|e| format!("Failed to prune llm cache: {}", e))?;
+    // 按最近使用时间从新到旧累加大小，超出上限的部分删除
+    conn.prepare_cached(
+        "DELETE FROM llm_cache WHERE key IN (
+            SELECT key FROM (
+                SELECT key, SUM(size) OVER (ORDER BY last_used DESC, key) AS total FROM llm_cache
+            ) WHERE total > ?1
+        )",
+    )
+    .and_then(|mut stmt| stmt.execute(params![MAX_CACHE_BYTES]))
+    .map_err(|e| format!("Failed to prune llm cache: {}", e))?;
+    Ok(())
+}
+
+/// 读取未过期的缓存，并更新最近使用时间
+pub fn get(app_data_dir: &Path, key: &str) -> Result<Option<String>, String> {
+    let now = now_ts();
+    db::with_connection(app_data_dir, |conn| get_at(conn, key, now))
+}
+
+/// 写入缓存，并清理过期和超出大小上限的条目
+pub fn put(app_data_dir: &Path, key: &str, response: &str) -> Result<(), String> {
+    if response.is_empty() {
+        return Ok(());
+    }
+    let now = now_ts();
+    db::with_connection(app_data_dir, |conn| put_at(conn, key, response, now))
+}
+
+/// 清空缓存，返回删除的条目数
+pub fn clear(app_data_dir: &Path) -> Result<usize, String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute("DELETE FROM llm_cache", [])
+            .map_err(|e| format!("Failed to clear llm cache: {}", e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE llm_cache (key TEXT PRIMARY KEY, response TEXT NOT NULL, size INTEGER NOT NULL,
+                 created_at INTEGER NOT NULL, last_used INTEGER NOT NULL);",
+        )
+        .unwrap();
+        conn
+    }
+
+    fn count(conn: &Connection) -> i64 {
+        conn.query_row("SELECT COUNT(*) FROM llm_cache", [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn expires_entries_after_ttl() {
+        let conn = open();
+        put_at(&conn, "a", "answer", 1_000).unwrap();
+        assert_eq!(get_at(&conn, "a", 1_000 + CACHE_TTL_SECS - 1).unwrap().as_deref(), Some("answer"));
+        assert_eq!(get_at(&conn, "a", 1_000 + CACHE_TTL_SECS).unwrap(), None);
+        assert_eq!(get_at(&conn, "missing", 1_000).unwrap(), None);
+    }
+
+    #[test]
+    fn reading_refreshes_last_used_but_not_expiry() {
+        let conn = open();
+        put_at(&conn, "a", "answer", 1_000).unwrap();
+        get_at(&conn, "a", 5_000).unwrap();
+        let (created_at, last_used): (i64, i64) = conn
+            .query_row("SELECT created_at, last_used FROM llm_cache WHERE key = 'a'", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!((created_at, last_used), (1_000, 5_000));
+    }
+
+    #[test]
+    fn writing_prunes_expired_entries() {
+        let conn = open();
+        put_at(&conn, "old", "stale", 1_000).unwrap();
+        put_at(&conn, "new", "fresh", 1_000 + CACHE_TTL_SECS).unwrap();
+        assert_eq!(count(&conn), 1);
+        assert_eq!(get_at(&conn, "new", 1_000 + CACHE_TTL_SECS).unwrap().as_deref(), Some("fresh"));
+    }
+
+    #[test]
+    fn rewriting_restarts_ttl() {
+        let conn = open();
+        put_at(&conn, "a", "first", 1_000).unwrap();
+        put_at(&conn, "a", "second", 2_000).unwrap();
+        assert_eq!(get_at(&conn, "a", 1_000 + CACHE_TTL_SECS).unwrap().as_deref(), Some("second"));
+        assert_eq!(count(&conn), 1);
+    }
+}
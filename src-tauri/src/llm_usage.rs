@@ -0,0 +1,211 @@
+// AI 用量统计：记录每次对话请求的 prompt / completion token 数和按模型单价估算的费用
+// 提供按月汇总（含各功能明细），设置了月度预算时在用量超过 80% 和 100% 时提醒
+
+use crate::db;
+use crate::llm::TokenUsage;
+use chrono::{Datelike, Local, NaiveDate, TimeZone};
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Mutex;
+
+// (模型名前缀, 输入单价, 输出单价)，单位：美元 / 百万 token；更具体的前缀排在前面
+const MODEL_PRICES: &[(&str, f64, f64)] = &[
+    ("gpt-4o-mini", 0.15, 0.6),
+    ("gpt-4o", 2.5, 10.0),
+    ("gpt-4.1-nano", 0.1, 0.4),
+    ("gpt-4.1-mini", 0.4, 1.6),
+    ("gpt-4.1", 2.0, 8.0),
+    ("gpt-4-turbo", 10.0, 30.0),
+    ("gpt-4", 30.0, 60.0),
+    ("gpt-3.5-turbo", 0.5, 1.5),
+    ("o3-mini", 1.1, 4.4),
+    ("o4-mini", 1.1, 4.4),
+    ("deepseek-reasoner", 0.55, 2.19),
+    ("deepseek-chat", 0.27, 1.1),
+    ("claude-3-5-haiku", 0.8, 4.0),
+    ("claude-3-5-sonnet", 3.0, 15.0),
+    ("qwen-turbo", 0.05, 0.2),
+    ("qwen-plus", 0.4, 1.2),
+    ("qwen-max", 1.6, 6.4),
+    ("text-embedding-3-small", 0.02, 0.0),
+    ("text-embedding-3-large", 0.13, 0.0),
+    ("text-embedding-ada-002", 0.1, 0.0),
+];
+
+// 预算提醒阈值（占预算的比例）
+const BUDGET_WARNING_LEVELS: &[f64] = &[0.8, 1.0];
+
+// 本月已提醒过的最高阈值，避免每次请求都重复提醒
+static WARNED: Mutex<Option<(String, usize)>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureUsage {
+    pub feature: String,
+    pub calls: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub estimated_cost: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageSummary {
+    pub month: String, // "YYYY-MM"
+    pub calls: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub estimated_cost: f64,
+    pub has_estimated_tokens: bool, // 部分请求的 token 数为估算值
+    pub by_feature: Vec<FeatureUsage>,
+    pub budget: Option<f64>,
+    pub budget_used_ratio: Option<f64>,
+}
+
+/// 按模型单价估算费用（美元）；本地模型和未知模型返回 0
+pub fn estimate_cost(model: &str, usage: &TokenUsage) -> f64 {
+    let model = model.to_lowercase();
+    let model = model.rsplit('/').next().unwrap_or(&model);
+    MODEL_PRICES
+        .iter()
+        .find(|(prefix, _, _)| model.starts_with(prefix))
+        .map(|(_, input, output)| {
+            (usage.prompt_tokens as f64 * input + usage.completion_tokens as f64 * output) / 1_000_000.0
+        })
+        .unwrap_or(0.0)
+}
+
+fn current_month() -> String {
+    Local::now().format("%Y-%m").to_string()
+}
+
+/// 月份 "YYYY-MM" 对应的时间戳范围 [start, end)
+fn month_range(month: &str) -> Result<(i64, i64), String> {
+    let first = NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d")
+        .map_err(|e| format!("Invalid month {}: {}", month, e))?;
+    let next = if first.month() == 12 {
+        NaiveDate::from_ymd_opt(first.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(first.year(), first.month() + 1, 1)
+    }
+    .ok_or_else(|| format!("Invalid month {}", month))?;
+    let to_ts = |date: NaiveDate| {
+        Local
+            .from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+            .earliest()
+            .map(|dt| dt.timestamp())
+            .ok_or_else(|| format!("Invalid month {}", month))
+    };
+    Ok((to_ts(first)?, to_ts(next)?))
+}
+
+/// 记录一次请求的用量
+pub fn record(app_data_dir: &Path, feature: &str, model: &str, usage: &TokenUsage) -> Result<(), String> {
+    let cost = estimate_cost(model, usage);
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "INSERT INTO llm_usage (created_at, feature, model, prompt_tokens, completion_tokens, estimated, cost)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        )
+        .and_then(|mut stmt| {
+            stmt.execute(params![
+                Local::now().timestamp(),
+                feature,
+                model,
+                usage.prompt_tokens as i64,
+                usage.completion_tokens as i64,
+                usage.estimated,
+                cost
+            ])
+        })
+        .map_err(|e| format!("Failed to record llm usage: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 按月汇总用量，month 为空时汇总当月
+pub fn summary(app_data_dir: &Path, month: Option<&str>, budget: Option<f64>) -> Result<UsageSummary, String> {
+    let month = month.map(str::to_string).unwrap_or_else(current_month);
+    let (start, end) = month_range(&month)?;
+
+    let rows = db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT feature, COUNT(*), SUM(prompt_tokens), SUM(completion_tokens), SUM(cost), MAX(estimated)
+                 FROM llm_usage WHERE created_at >= ?1 AND created_at < ?2
+                 GROUP BY feature ORDER BY SUM(cost) DESC, SUM(prompt_tokens + completion_tokens) DESC",
+            )
+            .map_err(|e| format!("Failed to prepare llm usage query: {}", e))?;
+        let rows = stmt
+            .query_map(params![start, end], |row| {
+                Ok((
+                    FeatureUsage {
+                        feature: row.get(0)?,
+                        calls: row.get::<_, i64>(1)? as u64,
+                        prompt_tokens: row.get::<_, i64>(2)? as u64,
+                        completion_tokens: row.get::<_, i64>(3)? as u64,
+                        estimated_cost: row.get(4)?,
+                    },
+                    row.get::<_, bool>(5)?,
+                ))
+            })
+            .map_err(|e| format!("Failed to query llm usage: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read llm usage: {}", e))?;
+        Ok(rows)
+    })?;
+
+    let has_estimated_tokens = rows.iter().any(|(_, estimated)| *estimated);
+    let by_feature: Vec<FeatureUsage> = rows.into_iter().map(|(usage, _)| usage).collect();
+    let estimated_cost = by_feature.iter().map(|f| f.estimated_cost).sum();
+    let budget = budget.filter(|b| *b > 0.0);
+    Ok(UsageSummary {
+        month,
+        calls: by_feature.iter().map(|f| f.calls).sum(),
+        prompt_tokens: by_feature.iter().map(|f| f.prompt_tokens).sum(),
+        completion_tokens: by_feature.iter().map(|f| f.completion_tokens).sum(),
+        estimated_cost,
+        has_estimated_tokens,
+        budget,
+        budget_used_ratio: budget.map(|b| estimated_cost / b),
+        by_feature,
+    })
+}
+
+/// 当月用量首次越过预算提醒阈值时返回 true（每个阈值每月只提醒一次）
+pub fn should_warn(summary: &UsageSummary) -> bool {
+    let Some(ratio) = summary.budget_used_ratio else {
+        return false;
+    };
+    let level = BUDGET_WARNING_LEVELS.iter().filter(|l| ratio >= **l).count();
+    if level == 0 {
+        return false;
+    }
+    let mut warned = WARNED.lock().unwrap_or_else(|e| e.into_inner());
+    let previous = match warned.as_ref() {
+        Some((month, level)) if *month == summary.month => *level,
+        _ => 0,
+    };
+    if level > previous {
+        *warned = Some((summary.month.clone(), level));
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimates_cost_by_model_prefix() {
+        let usage = TokenUsage {
+            prompt_tokens: 1_000_000,
+            completion_tokens: 1_000_000,
+            estimated: false,
+        };
+        assert!((estimate_cost("gpt-4o-mini-2024-07-18", &usage) - 0.75).abs() < 1e-9);
+        assert!((estimate_cost("openai/gpt-4o", &usage) - 12.5).abs() < 1e-9);
+        assert_eq!(estimate_cost("llama3", &usage), 0.0);
+    }
+}
@@ -1,12 +1,25 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod activity_log;
+mod api_policy;
+mod app_index;
 mod app_search;
+mod audio_devices;
+mod browser_bridge;
+mod browser_history;
+mod calendar;
+mod archive_peek;
+mod chat_history;
+mod cloud_sync;
 mod commands;
+mod display_control;
 mod error;
+mod event_bus;
 mod everything_search;
 mod everything_filters;
 mod file_history;
+mod habits;
 mod hooks;
 mod hotkey;
 mod hotkey_handler;
@@ -14,31 +27,111 @@ mod hotkey_handler;
 mod db;
 mod logger;
 mod plugin_usage;
+mod llm;
+mod llm_cache;
+mod llm_usage;
 mod memos;
+mod hotstrings;
+mod key_injection;
+mod selection;
+mod app_scope;
+mod net_tools;
+mod network;
+mod onboarding;
 mod open_history;
+mod radios;
 mod recording;
 mod replay;
+mod result_preview;
+mod rss;
+mod screen_ruler;
+mod scripts;
+mod text_variables;
 mod settings;
+mod shutdown;
 mod shortcuts;
+mod sync_backend;
 mod system_folders_search;
+mod system_monitor;
+mod virtual_desktops;
 mod window_config;
+mod window_manager;
 mod clipboard;
+mod clipboard_copy_as;
+mod clipboard_ocr;
+mod crash;
 mod word_records;
 mod file_watcher;
+mod focus_mode;
+mod folder_sizes;
 mod markdown_recent_files;
+mod favicon;
+mod games;
+mod git_repos;
+mod git_sync;
+mod hot_folders;
+mod http_client;
+mod i18n;
+mod embeddings;
+mod idle;
+mod importer;
+mod keep_awake;
+mod lan_share;
+mod open_with;
+mod paste_share;
+mod password_gen;
+mod path_expand;
+mod pinyin_utils;
+mod prompt_templates;
+mod query_history;
+mod quotes;
+mod ranking;
+mod scoring;
+mod search_providers;
+mod secrets;
+mod recent_projects;
+mod reminders;
+mod ssh_hosts;
+mod text_chunks;
+mod text_transform;
+mod themes;
+mod timers;
+mod totp;
+mod tray;
+mod vector_store;
+mod warmup;
+mod web_suggestions;
+mod workflows;
+mod world_clock;
 
 use crate::commands::get_app_data_dir;
 use commands::*;
 use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
-use tauri::{
-    menu::{Menu, MenuItem},
-    Manager,
-    Emitter,
-};
+use tauri::Manager;
+#[cfg(target_os = "windows")]
+use tauri::Emitter;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
 // 全局锁文件句柄，确保文件在程序运行期间保持打开
 static LOCK_FILE: Mutex<Option<Arc<std::fs::File>>> = Mutex::new(None);
+// 启动器页面第一次加载完成时显示窗口（之后页面重新加载不再弹出）
+static LAUNCHER_SHOWN: AtomicBool = AtomicBool::new(false);
+
+fn show_launcher_on_startup(app_handle: &tauri::AppHandle) {
+    let Some(window) = app_handle.get_webview_window("launcher") else {
+        return;
+    };
+    if let Ok(app_data_dir) = get_app_data_dir(app_handle) {
+        set_launcher_window_position(&window, &app_data_dir);
+    }
+    if let Err(e) = window.show() {
+        eprintln!("Failed to show launcher window on startup: {}", e);
+    }
+    if let Err(e) = window.set_focus() {
+        eprintln!("Failed to focus launcher window on startup: {}", e);
+    }
+}
 
 /// 检查是否已经有实例在运行
 /// 返回 true 表示这是第一个实例，可以继续运行
@@ -147,13 +240,12 @@ fn check_single_instance() -> bool {
 
 fn get_lock_file_path() -> std::path::PathBuf {
     use std::env;
-    use std::path::PathBuf;
     
     // 使用临时目录或应用数据目录
     #[cfg(target_os = "windows")]
     {
         if let Ok(appdata) = env::var("APPDATA") {
-            return PathBuf::from(appdata).join("ReFast").join("re-fast.lock");
+            return std::path::PathBuf::from(appdata).join("ReFast").join("re-fast.lock");
         }
     }
     
@@ -226,6 +318,11 @@ fn set_launcher_window_position(window: &tauri::WebviewWindow, app_data_dir: &st
 }
 
 fn main() {
+    // 被浏览器作为 native messaging 宿主启动时只转发消息，不参与单实例检查
+    if browser_bridge::is_native_host_invocation(&std::env::args().collect::<Vec<_>>()) {
+        std::process::exit(browser_bridge::run_native_host());
+    }
+
     // 检查单实例
     if !check_single_instance() {
         // 已有实例在运行，退出
@@ -235,20 +332,42 @@ fn main() {
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_notification::init())
+        .on_page_load(|webview, payload| {
+            // 前端加载完成后再显示启动器，不再依赖固定延时
+            if webview.label() == "launcher"
+                && payload.event() == tauri::webview::PageLoadEvent::Finished
+                && !LAUNCHER_SHOWN.swap(true, Ordering::SeqCst)
+            {
+                show_launcher_on_startup(webview.app_handle());
+            }
+        })
         .setup(|app| {
-            // Create system tray menu
-            let app_center = MenuItem::with_id(app, "app_center", "应用中心", true, None::<&str>)?;
-            let open_logs = MenuItem::with_id(app, "open_logs", "打开日志文件夹", true, None::<&str>)?;
-            let restart = MenuItem::with_id(app, "restart", "重启程序", true, None::<&str>)?;
-            let quit = MenuItem::with_id(app, "quit", "退出", true, None::<&str>)?;
+            // Get app_data_dir early for use in closures
+            let app_data_dir = get_app_data_dir(app.handle())?;
+
+            // 崩溃时在应用数据目录写入崩溃报告
+            crash::install(&app_data_dir);
 
-            let menu = Menu::with_items(app, &[&app_center, &open_logs, &restart, &quit])?;
+            // 按设置中的语言生成后端文案（托盘菜单等）
+            if let Ok(settings) = settings::load_settings(&app_data_dir) {
+                i18n::set_locale(&settings.locale);
+                focus_mode::set_auto_detect(app.handle(), settings.dnd_auto_detect);
+                http_client::configure(&settings.proxy);
+                scoring::set_weights(&settings.scoring_weights);
+                search_providers::apply(&settings.providers);
+                activity_log::set_enabled(settings.analytics_enabled);
+                clipboard::apply_settings(&settings);
+            }
+
+            // Create system tray menu
+            let menu = tray::build_menu(app)?;
 
             // Create tray icon - use default window icon (which loads from tauri.conf.json)
             // 禁用左键点击显示菜单，左键只用于切换启动器窗口
-            let mut tray_builder = TrayIconBuilder::new()
+            let mut tray_builder = TrayIconBuilder::with_id(tray::TRAY_ID)
                 .menu(&menu)
-                .tooltip("ReFast")
+                .tooltip(tray::TOOLTIP)
                 .show_menu_on_left_click(false);
 
             // Use default window icon (loaded from tauri.conf.json icons/icon.ico)
@@ -268,9 +387,6 @@ fn main() {
                 tray_builder = tray_builder.icon(fallback_icon);
             }
 
-            // Get app_data_dir early for use in closures
-            let app_data_dir = get_app_data_dir(app.handle())?;
-
             let app_data_dir_clone1 = app_data_dir.clone();
 
             let _tray = tray_builder
@@ -305,6 +421,18 @@ fn main() {
                             }
                         });
                     }
+                    "keep_awake" => {
+                        // 勾选状态由 keep_awake 重建菜单时同步
+                        let result = if keep_awake::is_active() {
+                            keep_awake::stop(app);
+                            Ok(())
+                        } else {
+                            keep_awake::start(app, None, true).map(|_| ())
+                        };
+                        if let Err(e) = result {
+                            eprintln!("Failed to toggle keep awake: {}", e);
+                        }
+                    }
                     "open_logs" => {
                         #[cfg(target_os = "windows")]
                         {
@@ -327,7 +455,6 @@ fn main() {
                         #[cfg(not(target_os = "windows"))]
                         {
                             // 其他平台：使用临时目录下的日志文件夹
-                            use std::path::PathBuf;
                             let log_dir = std::env::temp_dir().join("re-fast-logs");
                             
                             // 确保日志目录存在
@@ -357,6 +484,8 @@ fn main() {
                         {
                             hotkey_handler::windows::cleanup_hotkeys();
                         }
+                        // 重启不会触发 RunEvent::Exit，这里先停止后台任务
+                        shutdown::shutdown(std::time::Duration::from_secs(3));
                         // 清理锁文件，以便重启后新实例可以正常启动
                         cleanup_lock_file();
                         app.restart();
@@ -417,6 +546,9 @@ fn main() {
                                     let _ = window.is_visible().map(|visible| {
                                         if visible {
                                             let _ = window.hide();
+                                        } else if focus_mode::is_active() {
+                                            // 勿扰模式下不弹出启动器抢占全屏程序的焦点
+                                            eprintln!("[Main] Focus mode active, ignoring launcher hotkey");
                                         } else {
                                             set_launcher_window_position(&window, &app_data_dir_hotkey);
                                             let _ = window.show();
@@ -521,6 +653,14 @@ fn main() {
                                             }
                                         }
                                     }
+                                } else if let Some(action) = hotkey_id.strip_prefix("window:") {
+                                    // 窗口管理快捷键，直接作用于当前前台窗口
+                                    if let Err(e) = window_manager::perform(action) {
+                                        eprintln!("[Main] Failed to run window action {}: {}", action, e);
+                                    }
+                                } else if let Some(script_id) = hotkey_id.strip_prefix(scripts::HOTKEY_PREFIX) {
+                                    // 自动化脚本快捷键，在后台运行，结果以通知显示
+                                    scripts::run_from_hotkey(script_id);
                                 } else {
                                     // 插件快捷键，发送事件到前端
                                     if let Err(e) = app_handle_plugin.emit("plugin-hotkey-triggered", hotkey_id) {
@@ -582,6 +722,13 @@ fn main() {
                                         eprintln!("[Main] Registered {} app hotkeys", app_hotkey_count);
                                     }
                                 }
+
+                                // 注册窗口管理快捷键（使用 "window:" 前缀）
+                                for (action, hotkey) in settings.window_hotkeys.iter() {
+                                    if let Err(e) = hotkey_handler::windows::register_plugin_hotkey(format!("window:{}", action), hotkey.clone()) {
+                                        eprintln!("[Main] Failed to register window hotkey {}: {}", action, e);
+                                    }
+                                }
                             }
                         });
                     }
@@ -591,26 +738,160 @@ fn main() {
                 }
             }
 
-            // 性能优化：异步预加载文件历史缓存，避免首次搜索时访问 SQLite
-            // 不阻塞应用启动，在后台加载
-            let app_data_dir_clone = app_data_dir.clone();
-            std::thread::spawn(move || {
-                open_history::load_history(&app_data_dir_clone).ok(); // Ignore errors if file doesn't exist
-                markdown_recent_files::load_recent_files(&app_data_dir_clone).ok(); // Ignore errors if file doesn't exist
+            // 退出时卸载快捷键钩子（托盘退出等路径也会直接调用，重复清理无副作用）
+            shutdown::on_shutdown("hotkeys", hotkey_handler::windows::cleanup_hotkeys);
+            // 退出时取消通过窗口管理置顶的窗口，避免它们一直停留在最上层
+            shutdown::on_shutdown("window-topmost", window_manager::clear_topmost);
+
+            // 各数据提供者在后台并行初始化，不阻塞窗口显示；快捷方式、打开历史和应用缓存在首次使用时若未就绪会同步加载，
+            // 其余提供者就绪前搜索结果可能不完整，前端收到 startup-progress（ready）后重新搜索
+            warmup::begin(app.handle().clone());
+            event_bus::start_tauri_bridge(app.handle().clone());
+            search_providers::start_settings_listener(app.handle().clone());
+            activity_log::start_settings_listener();
+            clipboard::start_settings_listener();
+            let hot_folders = settings::load_settings(&app_data_dir).map(|s| s.hot_folders).unwrap_or_default();
+            hot_folders::start(app.handle().clone(), hot_folders);
+            let dir = app_data_dir.clone();
+            warmup::provide("database", move || db::with_connection(&dir, |_| Ok(())));
+            let dir = app_data_dir.clone();
+            warmup::provide("file-history", move || {
+                open_history::load_history(&dir).ok(); // Ignore errors if file doesn't exist
+                markdown_recent_files::load_recent_files(&dir).ok(); // Ignore errors if file doesn't exist
+                Ok(())
             });
-            open_history::load_history(&app_data_dir).ok(); // Ignore errors if file doesn't exist
-            markdown_recent_files::load_recent_files(&app_data_dir).ok(); // Ignore errors if file doesn't exist
-            shortcuts::load_shortcuts(&app_data_dir).ok(); // Ignore errors if file doesn't exist
+            let dir = app_data_dir.clone();
+            warmup::provide("shortcuts", move || {
+                shortcuts::load_shortcuts(&dir).ok(); // Ignore errors if file doesn't exist
+                Ok(())
+            });
+            let dir = app_data_dir.clone();
+            warmup::provide("app-cache", move || {
+                use crate::commands::APP_CACHE;
+                // Load from disk cache first (fast)
+                let disk_cache = match app_search::windows::load_cache(&dir) {
+                    Ok(disk_cache) => disk_cache,
+                    Err(e) => {
+                        // 缓存损坏（已被删除）时重新扫描
+                        eprintln!("[AppCache] {}, rescanning", e);
+                        return commands::refresh_app_cache(&dir);
+                    }
+                };
+                if !disk_cache.is_empty() {
+                    if let Ok(mut cache_guard) = APP_CACHE.lock() {
+                        *cache_guard = Some(std::sync::Arc::new(disk_cache));
+                    }
+                }
+                // No background icon extraction on startup - icons will be extracted on-demand during search
+                Ok(())
+            });
+            let dir = app_data_dir.clone();
+            let handle = app.handle().clone();
+            warmup::provide("onboarding", move || onboarding::run(&handle, &dir));
+            let dir = app_data_dir.clone();
+            warmup::provide("activity-retention", move || activity_log::apply_retention(&dir).map(|_| ()));
+            let dir = app_data_dir.clone();
+            warmup::provide("clipboard-dedup-keys", move || clipboard::refresh_dedup_keys(&dir, true).map(|_| ()));
+            let dir = app_data_dir.clone();
+            warmup::provide("clipboard-sensitive-flags", move || clipboard::refresh_sensitive_flags(&dir).map(|_| ()));
+
+            // 前台全屏时自动进入勿扰模式
+            if let Err(e) = focus_mode::start_fullscreen_watcher(app.handle().clone()) {
+                eprintln!("[Main] Failed to start focus mode watcher: {}", e);
+            }
+
+            // 网络连通性检测，离线时在线功能直接失败
+            if let Err(e) = network::start_watcher(app.handle().clone()) {
+                eprintln!("[Main] Failed to start network watcher: {}", e);
+            }
+
+            // RSS 订阅后台定时抓取
+            if let Err(e) = rss::start_fetcher(app.handle().clone(), app_data_dir.clone()) {
+                eprintln!("[Main] Failed to start rss fetcher: {}", e);
+            }
+
+            // 日历订阅后台定时刷新
+            if let Err(e) = calendar::start_refresher(app.handle().clone(), app_data_dir.clone()) {
+                eprintln!("[Main] Failed to start calendar refresher: {}", e);
+            }
+
+            // 云同步定时任务，未启用时只做轻量检查
+            if let Err(e) = cloud_sync::start_scheduler(app.handle().clone(), app_data_dir.clone()) {
+                eprintln!("[Main] Failed to start cloud sync scheduler: {}", e);
+            }
+
+            // 浏览器扩展桥接，供 native messaging 宿主转发消息
+            if let Err(e) = browser_bridge::start_server(app.handle().clone(), app_data_dir.clone()) {
+                eprintln!("[Main] Failed to start browser bridge: {}", e);
+            }
+
+            // 自动化脚本：加载 scripts 文件夹并监听变化
+            if let Err(e) = scripts::start(app.handle().clone(), app_data_dir.clone()) {
+                eprintln!("[Main] Failed to start scripts: {}", e);
+            }
+
+            // 剪切板历史按保留天数定期清理
+            if let Err(e) = clipboard::start_retention_task(app_data_dir.clone()) {
+                eprintln!("[Main] Failed to start clipboard retention task: {}", e);
+            }
+
+            // 粘贴链接到期删除
+            if let Err(e) = paste_share::start_expiry_task(app_data_dir.clone()) {
+                eprintln!("[Main] Failed to start paste expiry task: {}", e);
+            }
+
+            // 倒计时与闹钟调度，补发应用关闭期间到期的提醒
+            if let Err(e) = timers::start_scheduler(app.handle().clone(), app_data_dir.clone()) {
+                eprintln!("[Main] Failed to start timer scheduler: {}", e);
+            }
+
+            // 跟随系统深色模式变化
+            if let Err(e) = themes::start_system_theme_watcher(app.handle().clone(), app_data_dir.clone()) {
+                eprintln!("[Main] Failed to start system theme watcher: {}", e);
+            }
+
+            // 重任务只在用户空闲时执行
+            {
+                use std::time::Duration;
+                const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+                const MINUTE: Duration = Duration::from_secs(60);
+                let idle_tasks = [
+                    idle::schedule("db-maintenance", app_data_dir.clone(), DAY, 5 * MINUTE, db::run_maintenance),
+                    idle::schedule("clipboard-image-gc", app_data_dir.clone(), DAY, 5 * MINUTE, |dir| {
+                        clipboard::collect_orphan_images(&dir.to_path_buf()).map(|_| ())
+                    }),
+                    idle::schedule("embeddings-index", app_data_dir.clone(), 60 * MINUTE, 2 * MINUTE, |dir| {
+                        let llm_settings = settings::load_settings(dir)?.llm;
+                        tauri::async_runtime::block_on(embeddings::index_all(dir, &llm_settings, 20))
+                            .map(|_| ())
+                            .map_err(String::from)
+                    }),
+                    idle::schedule("chat-history-retention", app_data_dir.clone(), DAY, 5 * MINUTE, |dir| {
+                        let retention_days = settings::load_settings(dir)?.llm.chat_retention_days;
+                        chat_history::apply_retention(dir, retention_days).map(|_| ())
+                    }),
+                    idle::schedule("database-backup", app_data_dir.clone(), 7 * DAY, 10 * MINUTE, commands::create_auto_backup),
+                    #[cfg(target_os = "windows")]
+                    idle::schedule("app-cache-refresh", app_data_dir.clone(), DAY, 10 * MINUTE, commands::refresh_app_cache),
+                    #[cfg(not(target_os = "windows"))]
+                    Ok(()),
+                ];
+                for result in idle_tasks {
+                    if let Err(e) = result {
+                        eprintln!("[Main] Failed to schedule idle task: {}", e);
+                    }
+                }
+            }
 
             // Sync startup setting on Windows
             #[cfg(target_os = "windows")]
             {
-                use crate::commands;
-                use crate::settings;
-                // Load settings and sync startup state
-                if let Ok(settings) = settings::load_settings(&app_data_dir) {
-                    commands::sync_startup_setting(settings.startup_enabled).ok();
-                }
+                let dir = app_data_dir.clone();
+                warmup::provide("autostart", move || {
+                    // Load settings and sync startup state
+                    let settings = settings::load_settings(&dir)?;
+                    commands::sync_startup_setting(settings.startup_enabled)
+                });
             }
 
             // Initialize Everything log file on startup to ensure path is displayed
@@ -629,51 +910,27 @@ fn main() {
                 logger::init_log_file_early();
             }
 
-            // Load app cache on startup and start background scan
-            let app_data_dir_clone = app_data_dir.clone();
-            std::thread::spawn(move || {
-                use crate::commands::APP_CACHE;
-                // Load from disk cache first (fast)
-                if let Ok(disk_cache) = app_search::windows::load_cache(&app_data_dir_clone) {
-                    if !disk_cache.is_empty() {
-                        if let Ok(mut cache_guard) = APP_CACHE.lock() {
-                            *cache_guard = Some(std::sync::Arc::new(disk_cache));
-                        }
-                    }
-                }
-                // No background icon extraction on startup - icons will be extracted on-demand during search
-            });
-
-            // Show launcher window on startup after a short delay to ensure frontend is loaded
-            let app_handle = app.handle().clone();
-            let app_data_dir_startup = app_data_dir.clone();
-            std::thread::spawn(move || {
-                use std::time::Duration;
-                // Wait for frontend to load (500ms should be enough)
-                std::thread::sleep(Duration::from_millis(500));
-                
-                if let Some(window) = app_handle.get_webview_window("launcher") {
-                    set_launcher_window_position(&window, &app_data_dir_startup);
-                    if let Err(e) = window.show() {
-                        eprintln!("Failed to show launcher window on startup: {}", e);
-                    }
-                    if let Err(e) = window.set_focus() {
-                        eprintln!("Failed to focus launcher window on startup: {}", e);
-                    }
-                }
-            });
-
             // Start clipboard monitor on Windows
             #[cfg(target_os = "windows")]
             {
                 let app_data_dir_clipboard = app_data_dir.clone();
-                if let Err(e) = clipboard::monitor::start_clipboard_monitor(app_data_dir_clipboard) {
+                if let Err(e) = clipboard::monitor::start_clipboard_monitor(app.handle().clone(), app_data_dir_clipboard) {
                     eprintln!("[Main] Failed to start clipboard monitor: {}", e);
                 } else {
                     eprintln!("[Main] Clipboard monitor started");
                 }
             }
 
+            // 备忘录热字串，按键由全局键盘钩子转交
+            if let Err(e) = hotstrings::start(app_data_dir.clone()) {
+                eprintln!("[Main] Failed to start hotstrings: {}", e);
+            }
+
+            // 剪切板图片文字识别，结果用于搜索（当前系统不支持 OCR 时后台线程直接退出）
+            if let Err(e) = clipboard_ocr::start(app_data_dir.clone()) {
+                eprintln!("[Main] Failed to start clipboard OCR: {}", e);
+            }
+
             // 预热拾色器资源（后台线程，避免阻塞启动）
             // commands::color_picker::warmup_color_picker();  // 暂时屏蔽，待优化
 
@@ -691,6 +948,7 @@ fn main() {
             get_playback_progress,
             scan_applications,
             rescan_applications,
+            refresh_app_index,
             test_uwp_apps_scan,
             search_applications,
             search_system_folders,
@@ -721,6 +979,136 @@ fn main() {
             delete_backup,
             restore_backup,
             list_backups,
+            get_startup_status,
+            get_focus_mode_status,
+            set_focus_mode,
+            get_network_status,
+            check_network_status,
+            get_network_info,
+            get_public_ip,
+            ping_host,
+            check_port,
+            get_system_stats,
+            list_audio_devices,
+            switch_audio_device,
+            get_audio_volume,
+            set_audio_volume,
+            set_audio_mute,
+            list_monitors,
+            set_monitor_brightness,
+            list_display_modes,
+            set_display_mode,
+            apply_display_preset,
+            get_night_light,
+            set_night_light,
+            get_radio_status,
+            set_radio_state,
+            set_airplane_mode,
+            run_window_action,
+            get_window_hotkeys,
+            save_window_hotkey,
+            list_virtual_desktops,
+            switch_virtual_desktop,
+            move_window_to_virtual_desktop,
+            get_keep_awake_status,
+            start_keep_awake,
+            stop_keep_awake,
+            transform_text,
+            generate_password,
+            copy_password,
+            list_totp_accounts,
+            add_totp_account,
+            remove_totp_account,
+            get_totp_codes,
+            copy_totp_code,
+            get_world_clocks,
+            convert_world_time,
+            get_quotes,
+            list_rss_feeds,
+            add_rss_feed,
+            remove_rss_feed,
+            get_rss_items,
+            mark_rss_items_read,
+            refresh_rss_feeds,
+            list_timers,
+            create_timer,
+            cancel_timer,
+            preview_reminder,
+            create_reminder,
+            list_calendars,
+            add_calendar,
+            remove_calendar,
+            get_today_events,
+            get_upcoming_events,
+            refresh_calendars,
+            list_habits,
+            add_habit,
+            remove_habit,
+            check_in_habit,
+            undo_habit_check_in,
+            get_cursor_info,
+            measure_screen_distance,
+            start_cursor_inspector,
+            stop_cursor_inspector,
+            render_text_variables,
+            list_text_variables,
+            get_sync_config,
+            save_sync_config,
+            sync_now,
+            get_sync_status,
+            list_sync_conflicts,
+            clear_sync_conflicts,
+            get_git_sync_status,
+            save_git_sync_config,
+            git_sync_commit,
+            git_sync_pull,
+            get_browser_bridge_status,
+            register_browser_bridge,
+            unregister_browser_bridge,
+            create_phone_share,
+            list_phone_shares,
+            revoke_phone_share,
+            get_paste_config,
+            save_paste_config,
+            create_paste_link,
+            list_paste_links,
+            revoke_paste_link,
+            clear_paste_links,
+            list_scripts,
+            run_script,
+            create_script,
+            open_scripts_folder,
+            get_script_http_allow,
+            set_script_http_allow,
+            list_workflows,
+            save_workflow,
+            delete_workflow,
+            run_workflow,
+            export_workflows,
+            import_workflows,
+            llm_chat,
+            clipboard_ai_action,
+            ask_notes,
+            clear_llm_cache,
+            get_llm_usage_summary,
+            list_prompt_templates,
+            save_prompt_template,
+            delete_prompt_template,
+            set_active_prompt_template,
+            list_chat_conversations,
+            get_chat_messages,
+            search_chat_messages,
+            set_chat_conversation_pinned,
+            delete_chat_conversation,
+            export_chat_conversation,
+            get_system_proxy,
+            list_crash_reports,
+            create_bug_report,
+            list_themes,
+            get_theme,
+            set_theme,
+            save_custom_theme,
+            delete_custom_theme,
             get_index_status,
             check_database_health,
             start_everything,
@@ -728,6 +1116,45 @@ fn main() {
             download_everything,
             launch_file,
             check_path_exists,
+            get_open_with_actions,
+            save_open_with_actions,
+            open_folder_with,
+            get_ssh_hosts,
+            search_ssh_hosts,
+            connect_ssh_host,
+            get_git_repos,
+            search_git_repos,
+            refresh_git_repos,
+            get_hot_folder_recent_files,
+            get_recent_projects,
+            search_recent_projects,
+            open_recent_project,
+            get_result_preview,
+            list_archive_entries,
+            open_archive_entry,
+            scan_folder_sizes,
+            cancel_folder_scan,
+            search_games,
+            search_browser_history,
+            refresh_games,
+            launch_game,
+            preview_launcher_import,
+            apply_launcher_import,
+            record_activity,
+            get_activity_stats,
+            purge_activity_log,
+            get_ranking_boosts,
+            add_query_history,
+            get_query_history,
+            navigate_query_history,
+            suggest_query_completions,
+            delete_query_history,
+            clear_query_history,
+            get_web_suggestions,
+            get_favicon,
+            clear_favicon_cache,
+            complete_path,
+            expand_path,
             get_clipboard_file_path,
             get_clipboard_text,
             save_clipboard_image,
@@ -747,13 +1174,16 @@ fn main() {
             get_all_memos,
             add_memo,
             update_memo,
+            set_memo_hotstring,
             delete_memo,
             search_memos,
+            semantic_search_memos,
             get_all_word_records,
             add_word_record,
             update_word_record,
             delete_word_record,
             search_word_records,
+            import_word_records,
             show_shortcuts_config,
             show_main_window,
             open_url,
@@ -790,6 +1220,7 @@ fn main() {
             remove_markdown_recent_file,
             get_settings,
             save_settings,
+            get_search_providers,
             get_everything_custom_filters,
             save_everything_custom_filters,
             is_startup_enabled,
@@ -811,16 +1242,40 @@ fn main() {
             install_update,
             quit_app,
             get_all_clipboard_items,
+            reveal_clipboard_item,
             add_clipboard_item,
             update_clipboard_item,
             toggle_favorite_clipboard_item,
+            get_favorite_clipboard_items,
+            reorder_favorite_clipboard_items,
+            pin_clipboard_item,
+            reorder_pinned_clipboard_items,
+            delete_clipboard_items,
+            set_clipboard_items_favorite,
+            tag_clipboard_items,
+            set_clipboard_item_tags,
+            list_clipboard_tags,
             delete_clipboard_item,
             clear_clipboard_history,
             search_clipboard_items,
+            semantic_search_clipboard,
+            import_clipboard_items,
+            get_monitor_status,
+            restart_monitor,
             show_clipboard_window,
             get_clipboard_image_data,
             copy_image_to_clipboard,
+            set_clipboard_multi,
+            copy_clipboard_item_as,
+            copy_files_to_clipboard,
+            copy_rich_text_to_clipboard,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            // 退出前通知后台线程结束并等待正在进行的写入完成
+            if let tauri::RunEvent::Exit = event {
+                shutdown::shutdown(std::time::Duration::from_secs(3));
+            }
+        });
 }
@@ -1,4 +1,6 @@
 use crate::db;
+use crate::event_bus::{self, BusEvent};
+use crate::key_injection::InjectionMode;
 use rusqlite::{params, OptionalExtension};
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -12,6 +14,70 @@ pub struct MemoItem {
     pub content: String,
     pub created_at: u64,
     pub updated_at: u64,
+    /// 热字串缩写：输入缩写后再按空格/回车/Tab 时展开为备忘录内容
+    #[serde(default)]
+    pub hotstring: Option<String>,
+    /// 热字串是否区分大小写；不区分时展开结果跟随输入的大小写
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// 热字串只在这些程序中展开（规则见 app_scope），为空时所有程序
+    #[serde(default)]
+    pub apps: Vec<String>,
+    /// 热字串展开时的输入方式，见 key_injection
+    #[serde(default)]
+    pub injection: InjectionMode,
+}
+
+const MEMO_COLUMNS: &str = "id, title, content, created_at, updated_at, hotstring, case_sensitive, apps, injection";
+
+fn row_to_memo(row: &rusqlite::Row) -> rusqlite::Result<MemoItem> {
+    Ok(MemoItem {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        content: row.get(2)?,
+        created_at: row.get::<_, i64>(3)? as u64,
+        updated_at: row.get::<_, i64>(4)? as u64,
+        hotstring: row.get::<_, Option<String>>(5)?.filter(|s| !s.is_empty()),
+        case_sensitive: row.get::<_, i64>(6)? != 0,
+        apps: row
+            .get::<_, Option<String>>(7)?
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default(),
+        injection: InjectionMode::parse(row.get::<_, Option<String>>(8)?.as_deref()),
+    })
+}
+
+fn insert_memo(conn: &rusqlite::Connection, verb: &str, m: &MemoItem) -> rusqlite::Result<usize> {
+    conn.execute(
+        &format!(
+            "{} INTO memos ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            verb, MEMO_COLUMNS
+        ),
+        params![
+            m.id,
+            m.title,
+            m.content,
+            m.created_at as i64,
+            m.updated_at as i64,
+            m.hotstring,
+            m.case_sensitive as i64,
+            apps_json(&m.apps),
+            injection_value(m.injection)
+        ],
+    )
+}
+
+fn apps_json(apps: &[String]) -> Option<String> {
+    (!apps.is_empty()).then(|| serde_json::to_string(apps).unwrap_or_default())
+}
+
+fn injection_value(mode: InjectionMode) -> Option<&'static str> {
+    (mode != InjectionMode::Default).then(|| mode.as_str())
+}
+
+/// 备忘录有写入时通知订阅者（热字串表据此重新加载）
+fn notify_changed() {
+    event_bus::publish(BusEvent::DbWrite { table: "memos" });
 }
 
 fn now_ts() -> u64 {
@@ -42,16 +108,12 @@ fn save_memos(app_data_dir: &PathBuf, items: &[MemoItem]) -> Result<(), String>
         .map_err(|e| format!("Failed to clear memos table: {}", e))?;
 
     for m in items {
-        tx.execute(
-            "INSERT INTO memos (id, title, content, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5)",
-            params![m.id, m.title, m.content, m.created_at as i64, m.updated_at as i64],
-        )
-        .map_err(|e| format!("Failed to insert memo {}: {}", m.id, e))?;
+        insert_memo(&tx, "INSERT", m).map_err(|e| format!("Failed to insert memo {}: {}", m.id, e))?;
     }
 
     tx.commit()
         .map_err(|e| format!("Failed to commit memos: {}", e))?;
+    notify_changed();
     Ok(())
 }
 
@@ -60,19 +122,11 @@ pub fn get_all_memos(app_data_dir: &PathBuf) -> Result<Vec<MemoItem>, String> {
     maybe_migrate_from_json(&mut conn, app_data_dir)?;
 
     let mut stmt = conn
-        .prepare("SELECT id, title, content, created_at, updated_at FROM memos ORDER BY updated_at DESC")
+        .prepare(&format!("SELECT {} FROM memos ORDER BY updated_at DESC", MEMO_COLUMNS))
         .map_err(|e| format!("Failed to prepare memos query: {}", e))?;
 
     let rows = stmt
-        .query_map([], |row| {
-            Ok(MemoItem {
-                id: row.get(0)?,
-                title: row.get(1)?,
-                content: row.get(2)?,
-                created_at: row.get::<_, i64>(3)? as u64,
-                updated_at: row.get::<_, i64>(4)? as u64,
-            })
-        })
+        .query_map([], row_to_memo)
         .map_err(|e| format!("Failed to iterate memos: {}", e))?;
 
     let mut items = Vec::new();
@@ -88,7 +142,11 @@ pub fn add_memo(
     app_data_dir: &PathBuf,
 ) -> Result<MemoItem, String> {
     let now = now_ts();
-    let id = format!("memo-{}", now_ts());
+    // 使用纳秒时间戳，避免同一秒内连续添加时 id 冲突
+    let id = format!(
+        "memo-{}",
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos()
+    );
 
     let item = MemoItem {
         id,
@@ -96,16 +154,16 @@ pub fn add_memo(
         content,
         created_at: now,
         updated_at: now,
+        hotstring: None,
+        case_sensitive: false,
+        apps: Vec::new(),
+        injection: InjectionMode::Default,
     };
 
     let mut conn = db::get_connection(app_data_dir)?;
     maybe_migrate_from_json(&mut conn, app_data_dir)?;
-    conn.execute(
-        "INSERT INTO memos (id, title, content, created_at, updated_at)
-         VALUES (?1, ?2, ?3, ?4, ?5)",
-        params![item.id, item.title, item.content, item.created_at as i64, item.updated_at as i64],
-    )
-    .map_err(|e| format!("Failed to insert memo: {}", e))?;
+    insert_memo(&conn, "INSERT", &item).map_err(|e| format!("Failed to insert memo: {}", e))?;
+    notify_changed();
 
     Ok(item)
 }
@@ -119,24 +177,7 @@ pub fn update_memo(
     let mut conn = db::get_connection(app_data_dir)?;
     maybe_migrate_from_json(&mut conn, app_data_dir)?;
 
-    let existing: Option<MemoItem> = conn
-        .query_row(
-            "SELECT id, title, content, created_at, updated_at FROM memos WHERE id = ?1",
-            params![id],
-            |row| {
-                Ok(MemoItem {
-                    id: row.get(0)?,
-                    title: row.get(1)?,
-                    content: row.get(2)?,
-                    created_at: row.get::<_, i64>(3)? as u64,
-                    updated_at: row.get::<_, i64>(4)? as u64,
-                })
-            },
-        )
-        .optional()
-        .map_err(|e| format!("Failed to load memo: {}", e))?;
-
-    let mut memo = existing.ok_or_else(|| format!("Memo {} not found", id))?;
+    let mut memo = load_memo(&conn, &id)?;
     if let Some(t) = title {
         memo.title = t;
     }
@@ -150,10 +191,102 @@ pub fn update_memo(
         params![memo.title, memo.content, memo.updated_at as i64, memo.id],
     )
     .map_err(|e| format!("Failed to update memo: {}", e))?;
+    notify_changed();
+
+    Ok(memo)
+}
+
+fn load_memo(conn: &rusqlite::Connection, id: &str) -> Result<MemoItem, String> {
+    conn.query_row(
+        &format!("SELECT {} FROM memos WHERE id = ?1", MEMO_COLUMNS),
+        params![id],
+        row_to_memo,
+    )
+    .optional()
+    .map_err(|e| format!("Failed to load memo: {}", e))?
+    .ok_or_else(|| format!("Memo {} not found", id))
+}
+
+/// 设置备忘录的热字串缩写、生效的程序及输入方式；缩写为空时取消。缩写不能包含空白字符，也不能与其他备忘录重复
+pub fn set_memo_hotstring(
+    id: String,
+    hotstring: Option<String>,
+    case_sensitive: bool,
+    apps: Vec<String>,
+    injection: InjectionMode,
+    app_data_dir: &PathBuf,
+) -> Result<MemoItem, String> {
+    let hotstring = hotstring.map(|h| h.trim().to_string()).filter(|h| !h.is_empty());
+    let apps = crate::app_scope::normalize(&apps)?;
+    if let Some(abbr) = &hotstring {
+        if abbr.chars().any(char::is_whitespace) {
+            return Err("热字串缩写不能包含空白字符".to_string());
+        }
+        if abbr.chars().count() > crate::hotstrings::MAX_ABBREVIATION_LEN {
+            return Err(format!(
+                "热字串缩写不能超过 {} 个字符",
+                crate::hotstrings::MAX_ABBREVIATION_LEN
+            ));
+        }
+    }
+
+    let mut conn = db::get_connection(app_data_dir)?;
+    maybe_migrate_from_json(&mut conn, app_data_dir)?;
+    let mut memo = load_memo(&conn, &id)?;
+
+    if let Some(abbr) = &hotstring {
+        // 同一缩写限定在不同程序中时可以共存
+        let mut stmt = conn
+            .prepare("SELECT title, apps FROM memos WHERE id <> ?1 AND lower(hotstring) = lower(?2)")
+            .map_err(|e| format!("Failed to check hotstring: {}", e))?;
+        let others = stmt
+            .query_map(params![id, abbr], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?))
+            })
+            .map_err(|e| format!("Failed to check hotstring: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to check hotstring: {}", e))?;
+        let conflict = others.into_iter().find_map(|(title, other_apps)| {
+            let other_apps: Vec<String> =
+                other_apps.and_then(|json| serde_json::from_str(&json).ok()).unwrap_or_default();
+            crate::app_scope::overlaps(&apps, &other_apps).then_some(title)
+        });
+        if let Some(title) = conflict {
+            return Err(format!("热字串 \"{}\" 已被备忘录 \"{}\" 使用", abbr, title));
+        }
+    }
+
+    memo.hotstring = hotstring;
+    memo.case_sensitive = case_sensitive;
+    memo.apps = apps;
+    memo.injection = injection;
+    memo.updated_at = now_ts();
+    conn.execute(
+        "UPDATE memos SET hotstring = ?1, case_sensitive = ?2, apps = ?3, injection = ?4, updated_at = ?5 WHERE id = ?6",
+        params![
+            memo.hotstring,
+            memo.case_sensitive as i64,
+            apps_json(&memo.apps),
+            injection_value(memo.injection),
+            memo.updated_at as i64,
+            memo.id
+        ],
+    )
+    .map_err(|e| format!("Failed to update memo hotstring: {}", e))?;
+    notify_changed();
 
     Ok(memo)
 }
 
+/// 按原样写入备忘录（保留 id 与时间戳），已存在时覆盖；用于云同步应用远端的修改
+pub fn upsert_memo(item: &MemoItem, app_data_dir: &PathBuf) -> Result<(), String> {
+    let mut conn = db::get_connection(app_data_dir)?;
+    maybe_migrate_from_json(&mut conn, app_data_dir)?;
+    insert_memo(&conn, "INSERT OR REPLACE", item).map_err(|e| format!("Failed to upsert memo: {}", e))?;
+    notify_changed();
+    Ok(())
+}
+
 pub fn delete_memo(id: String, app_data_dir: &PathBuf) -> Result<(), String> {
     let mut conn = db::get_connection(app_data_dir)?;
     maybe_migrate_from_json(&mut conn, app_data_dir)?;
@@ -163,6 +296,7 @@ pub fn delete_memo(id: String, app_data_dir: &PathBuf) -> Result<(), String> {
     if affected == 0 {
         return Err("Memo not found".to_string());
     }
+    notify_changed();
     Ok(())
 }
 
@@ -172,24 +306,17 @@ pub fn search_memos(query: &str, app_data_dir: &PathBuf) -> Result<Vec<MemoItem>
 
     let like = format!("%{}%", query.to_lowercase());
     let mut stmt = conn
-        .prepare(
-            "SELECT id, title, content, created_at, updated_at
+        .prepare(&format!(
+            "SELECT {}
              FROM memos
-             WHERE lower(title) LIKE ?1 OR lower(content) LIKE ?1
+             WHERE lower(title) LIKE ?1 OR lower(content) LIKE ?1 OR lower(hotstring) LIKE ?1
              ORDER BY updated_at DESC",
-        )
+            MEMO_COLUMNS
+        ))
         .map_err(|e| format!("Failed to prepare memo search: {}", e))?;
 
     let rows = stmt
-        .query_map(params![like], |row| {
-            Ok(MemoItem {
-                id: row.get(0)?,
-                title: row.get(1)?,
-                content: row.get(2)?,
-                created_at: row.get::<_, i64>(3)? as u64,
-                updated_at: row.get::<_, i64>(4)? as u64,
-            })
-        })
+        .query_map(params![like], row_to_memo)
         .map_err(|e| format!("Failed to iterate memo search: {}", e))?;
 
     let mut items = Vec::new();
@@ -218,18 +345,8 @@ fn maybe_migrate_from_json(
                     tx.execute("DELETE FROM memos", [])
                         .map_err(|e| format!("Failed to clear memos table: {}", e))?;
                     for m in items {
-                        tx.execute(
-                            "INSERT INTO memos (id, title, content, created_at, updated_at)
-                             VALUES (?1, ?2, ?3, ?4, ?5)",
-                            params![
-                                m.id,
-                                m.title,
-                                m.content,
-                                m.created_at as i64,
-                                m.updated_at as i64
-                            ],
-                        )
-                        .map_err(|e| format!("Failed to migrate memo {}: {}", m.id, e))?;
+                        insert_memo(&tx, "INSERT", &m)
+                            .map_err(|e| format!("Failed to migrate memo {}: {}", m.id, e))?;
                     }
                     let _ = tx.commit();
                 }
@@ -0,0 +1,293 @@
+// 网络信息与诊断工具：本机/公网 IP、当前 Wi-Fi、网卡列表，以及 ping 与 TCP 端口检测
+// 供启动器的 "ip"、"ping 主机" 查询使用；网卡与 Wi-Fi 信息来自 PowerShell / netsh，ping 调用系统 ping 命令
+
+use crate::http_client;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr, TcpStream, ToSocketAddrs, UdpSocket};
+use std::process::Command;
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+#[cfg(target_os = "windows")]
+use std::os::windows::process::CommandExt;
+
+const PUBLIC_IP_TIMEOUT: Duration = Duration::from_secs(5);
+// 返回纯文本 IP 的服务，依次尝试
+const PUBLIC_IP_SERVICES: &[&str] = &["https://4.ipw.cn", "https://api.ipify.org"];
+const PING_COUNT: u32 = 4;
+const PING_TIMEOUT_MS: u32 = 1000;
+const PORT_TIMEOUT: Duration = Duration::from_secs(3);
+
+// 匹配 "time=12ms"、"时间=12ms"、"time<1ms" 等各语言的 ping 输出
+static PING_TIME_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[=<]\s*(\d+(?:\.\d+)?)\s*ms").unwrap());
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkAdapter {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub status: String, // "Up" | "Disconnected" 等
+    #[serde(default)]
+    pub mac: String,
+    #[serde(default)]
+    pub link_speed: String,
+    #[serde(default)]
+    pub addresses: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WifiInfo {
+    pub ssid: String,
+    pub signal: Option<u32>, // 信号强度百分比
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkInfo {
+    pub local_ip: Option<String>, // 默认路由使用的本机地址
+    pub wifi: Option<WifiInfo>,
+    pub adapters: Vec<NetworkAdapter>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PingResult {
+    pub host: String,
+    pub address: Option<String>,
+    pub sent: u32,
+    pub received: u32,
+    pub times_ms: Vec<f64>,
+    pub avg_ms: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PortCheckResult {
+    pub host: String,
+    pub port: u16,
+    pub address: Option<String>,
+    pub open: bool,
+    pub elapsed_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+fn hidden_command(program: &str) -> Command {
+    #[allow(unused_mut)]
+    let mut command = Command::new(program);
+    #[cfg(target_os = "windows")]
+    command.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    command
+}
+
+/// 默认路由使用的本机地址（UDP connect 只选择路由，不会发送数据包）
+pub fn local_ip() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("223.5.5.5:53").ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}
+
+/// 解析 `netsh wlan show interfaces` 的输出
+/// SSID 字段名在各语言中相同；信号强度取第一个以 % 结尾的值（中文系统中字段名为"信号"）
+pub fn parse_netsh_wlan(output: &str) -> Option<WifiInfo> {
+    let mut ssid = None;
+    let mut signal = None;
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+        if key == "SSID" && ssid.is_none() && !value.is_empty() {
+            ssid = Some(value.to_string());
+        } else if signal.is_none() {
+            if let Some(percent) = value.strip_suffix('%') {
+                signal = percent.trim().parse().ok();
+            }
+        }
+    }
+    ssid.map(|ssid| WifiInfo { ssid, signal })
+}
+
+fn wifi_info() -> Option<WifiInfo> {
+    if !cfg!(target_os = "windows") {
+        return None;
+    }
+    let output = hidden_command("netsh").args(["wlan", "show", "interfaces"]).output().ok()?;
+    parse_netsh_wlan(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// PowerShell 的 ConvertTo-Json 在只有一个元素时输出对象而不是数组
+pub fn parse_adapters_json(json: &str) -> Result<Vec<NetworkAdapter>, String> {
+    let json = json.trim();
+    if json.is_empty() {
+        return Ok(Vec::new());
+    }
+    if json.starts_with('[') {
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse adapters: {}", e))
+    } else {
+        serde_json::from_str(json)
+            .map(|adapter| vec![adapter])
+            .map_err(|e| format!("Failed to parse adapters: {}", e))
+    }
+}
+
+fn adapters() -> Result<Vec<NetworkAdapter>, String> {
+    if !cfg!(target_os = "windows") {
+        return Ok(Vec::new());
+    }
+    let script = r#"
+        [Console]::OutputEncoding = [System.Text.Encoding]::UTF8
+        $adapters = Get-NetAdapter | Sort-Object Status, Name | ForEach-Object {
+            $a = $_
+            [pscustomobject]@{
+                name = $a.Name
+                description = $a.InterfaceDescription
+                status = [string]$a.Status
+                mac = $a.MacAddress
+                link_speed = $a.LinkSpeed
+                addresses = @(Get-NetIPAddress -InterfaceIndex $a.ifIndex -ErrorAction SilentlyContinue | ForEach-Object { $_.IPAddress })
+            }
+        }
+        ConvertTo-Json -InputObject @($adapters) -Compress
+    "#;
+    let output = hidden_command("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", script])
+        .output()
+        .map_err(|e| format!("执行 PowerShell 失败: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("获取网卡列表失败: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    parse_adapters_json(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// 本机网络信息：本机地址、当前 Wi-Fi 与网卡列表
+pub fn network_info() -> Result<NetworkInfo, String> {
+    Ok(NetworkInfo {
+        local_ip: local_ip().map(|ip| ip.to_string()),
+        wifi: wifi_info(),
+        adapters: adapters()?,
+    })
+}
+
+/// 公网 IP（配置了代理时为代理出口的地址）
+pub async fn public_ip() -> Result<String, String> {
+    crate::network::ensure_online()?;
+    let client = http_client::client()?;
+    let mut last_error = String::new();
+    for service in PUBLIC_IP_SERVICES {
+        let response = match client.get(*service).timeout(PUBLIC_IP_TIMEOUT).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                last_error = e.to_string();
+                continue;
+            }
+        };
+        match response.text().await {
+            Ok(text) if text.trim().parse::<IpAddr>().is_ok() => return Ok(text.trim().to_string()),
+            Ok(text) => last_error = format!("unexpected response: {}", text.chars().take(50).collect::<String>()),
+            Err(e) => last_error = e.to_string(),
+        }
+    }
+    Err(format!("获取公网 IP 失败: {}", last_error))
+}
+
+/// 主机名只允许字母、数字和 . - : []（IPv6），避免被当作 ping 的参数
+fn validate_host(host: &str) -> Result<&str, String> {
+    let host = host.trim();
+    let valid = !host.is_empty()
+        && !host.starts_with('-')
+        && host.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':' | '[' | ']'));
+    if valid {
+        Ok(host)
+    } else {
+        Err(format!("无效的主机名: {}", host))
+    }
+}
+
+fn resolve(host: &str, port: u16) -> Option<SocketAddr> {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    (host, port).to_socket_addrs().ok()?.next()
+}
+
+/// 从 ping 输出中提取每次回复的耗时（毫秒）
+pub fn parse_ping_times(output: &str) -> Vec<f64> {
+    output
+        .lines()
+        .filter(|line| line.contains("TTL") || line.contains("ttl"))
+        .filter_map(|line| PING_TIME_RE.captures(line))
+        .filter_map(|caps| caps[1].parse().ok())
+        .collect()
+}
+
+/// 调用系统 ping 命令
+pub fn ping(host: &str) -> Result<PingResult, String> {
+    let host = validate_host(host)?;
+    let address = resolve(host, 0).map(|addr| addr.ip().to_string());
+    if address.is_none() {
+        return Err(format!("无法解析主机: {}", host));
+    }
+    let mut command = hidden_command("ping");
+    if cfg!(target_os = "windows") {
+        command.args(["-n", &PING_COUNT.to_string(), "-w", &PING_TIMEOUT_MS.to_string()]);
+    } else {
+        command.args(["-c", &PING_COUNT.to_string(), "-W", &(PING_TIMEOUT_MS / 1000).max(1).to_string()]);
+    }
+    let output = command.arg(host).output().map_err(|e| format!("执行 ping 失败: {}", e))?;
+    let times_ms = parse_ping_times(&String::from_utf8_lossy(&output.stdout));
+    let avg_ms = (!times_ms.is_empty()).then(|| times_ms.iter().sum::<f64>() / times_ms.len() as f64);
+    Ok(PingResult {
+        host: host.to_string(),
+        address,
+        sent: PING_COUNT,
+        received: times_ms.len() as u32,
+        times_ms,
+        avg_ms,
+    })
+}
+
+/// 检测 TCP 端口是否可连接
+pub fn check_port(host: &str, port: u16) -> Result<PortCheckResult, String> {
+    let host = validate_host(host)?;
+    let Some(addr) = resolve(host, port) else {
+        return Err(format!("无法解析主机: {}", host));
+    };
+    let start = Instant::now();
+    let (open, elapsed_ms, error) = match TcpStream::connect_timeout(&addr, PORT_TIMEOUT) {
+        Ok(_) => (true, Some(start.elapsed().as_millis() as u64), None),
+        Err(e) => (false, None, Some(e.to_string())),
+    };
+    Ok(PortCheckResult {
+        host: host.to_string(),
+        port,
+        address: Some(addr.ip().to_string()),
+        open,
+        elapsed_ms,
+        error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_netsh_and_ping_output() {
+        let netsh = "    名称                   : WLAN\n    SSID                   : Office-5G\n    BSSID                  : aa:bb:cc:dd:ee:ff\n    信号                   : 87%\n";
+        assert_eq!(parse_netsh_wlan(netsh), Some(WifiInfo { ssid: "Office-5G".to_string(), signal: Some(87) }));
+        assert_eq!(parse_netsh_wlan("There is no wireless interface on the system."), None);
+
+        let ping = "来自 1.1.1.1 的回复: 字节=32 时间=12ms TTL=57\nReply from 1.1.1.1: bytes=32 time<1ms TTL=57\n请求超时。\n数据包: 已发送 = 4，已接收 = 2";
+        assert_eq!(parse_ping_times(ping), vec![12.0, 1.0]);
+    }
+
+    #[test]
+    fn parses_adapters_and_validates_hosts() {
+        let single = r#"{"name":"以太网","description":"Intel","status":"Up","mac":"00-11","link_speed":"1 Gbps","addresses":["192.168.1.2"]}"#;
+        assert_eq!(parse_adapters_json(single).unwrap()[0].addresses, vec!["192.168.1.2"]);
+        assert_eq!(parse_adapters_json(&format!("[{}]", single)).unwrap().len(), 1);
+        assert!(parse_adapters_json("").unwrap().is_empty());
+
+        assert!(validate_host("example.com").is_ok());
+        assert!(validate_host("[::1]").is_ok());
+        assert!(validate_host("-t example.com").is_err());
+        assert!(validate_host("a.com & calc").is_err());
+    }
+}
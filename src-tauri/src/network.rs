@@ -0,0 +1,109 @@
+// 网络状态检测：默认视为在线，只有真实请求连接失败后才标记离线；离线时在线功能（更新检查、联想词、网站图标、下载等）直接失败，不再等待超时。
+// 离线期间后台经由代理感知的 HTTP 客户端定期重试上次失败的地址，连通后恢复在线；在线时不发出任何探测请求。
+// 状态变化时发送 network-status-changed 事件；从离线恢复时额外发送 network-online，前端据此执行排队的任务（如待生成的 AI 解释）
+
+use crate::{http_client, shutdown};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+// 离线时重试的间隔
+const OFFLINE_INTERVAL: Duration = Duration::from_secs(5);
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+static ONLINE: AtomicBool = AtomicBool::new(true);
+static LAST_CHECKED: AtomicI64 = AtomicI64::new(0);
+// 最近一次连接失败的请求地址，离线期间用它判断是否恢复
+static UNREACHABLE_URL: Mutex<Option<String>> = Mutex::new(None);
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkStatus {
+    pub online: bool,
+    pub last_checked: i64,
+}
+
+pub fn status() -> NetworkStatus {
+    NetworkStatus {
+        online: ONLINE.load(Ordering::Relaxed),
+        last_checked: LAST_CHECKED.load(Ordering::Relaxed),
+    }
+}
+
+pub fn is_online() -> bool {
+    ONLINE.load(Ordering::Relaxed)
+}
+
+/// 离线时立即返回错误，供在线功能在发起请求前调用
+pub fn ensure_online() -> Result<(), String> {
+    if is_online() {
+        Ok(())
+    } else {
+        Err("网络不可用，请检查网络连接".to_string())
+    }
+}
+
+fn set_online(online: bool) {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+    LAST_CHECKED.store(now, Ordering::Relaxed);
+
+    let was_online = ONLINE.swap(online, Ordering::Relaxed);
+    if was_online == online {
+        return;
+    }
+    eprintln!("[Network] Connectivity changed: {}", if online { "online" } else { "offline" });
+    if let Some(app_handle) = APP_HANDLE.get() {
+        let current = status();
+        let _ = app_handle.emit("network-status-changed", &current);
+        if online {
+            let _ = app_handle.emit("network-online", &current);
+        }
+    }
+}
+
+/// 请求成功收到响应，说明网络可用
+pub fn report_success() {
+    set_online(true);
+}
+
+/// 请求无法建立连接（经由代理时包括代理不可达），标记离线并记下地址供后续重试
+pub fn report_unreachable(url: &str) {
+    if let Ok(mut unreachable) = UNREACHABLE_URL.lock() {
+        *unreachable = Some(url.to_string());
+    }
+    set_online(false);
+}
+
+/// 经由当前代理配置重试上次失败的地址，收到任何 HTTP 响应即视为在线；没有记录的地址时视为在线
+async fn probe() -> bool {
+    let Some(url) = UNREACHABLE_URL.lock().ok().and_then(|url| url.clone()) else {
+        return true;
+    };
+    let Ok(client) = http_client::client() else {
+        return false;
+    };
+    client.head(&url).timeout(PROBE_TIMEOUT).send().await.is_ok()
+}
+
+/// 离线时立即重试一次并更新状态；在线时直接返回当前状态，不发出请求
+pub fn refresh() -> NetworkStatus {
+    if !is_online() {
+        set_online(tauri::async_runtime::block_on(probe()));
+    }
+    status()
+}
+
+/// 离线期间后台定期重试，恢复后发送事件
+pub fn start_watcher(app_handle: AppHandle) -> Result<(), String> {
+    let _ = APP_HANDLE.set(app_handle);
+    shutdown::spawn("network-watcher", move || {
+        while !shutdown::wait_timeout(OFFLINE_INTERVAL) {
+            refresh();
+        }
+    })
+}
@@ -0,0 +1,147 @@
+// 首次运行引导：检测全新安装，写入示例片段（备忘录）、示例快速链接和常用文件夹书签，并触发首次应用扫描
+// 完成后在 settings 表写入 onboarding 标记，之后不再执行；已有数据的老用户只写标记，不写入示例
+
+use crate::db;
+use crate::importer::{self, ImportPreview, ImportedQuicklink};
+use crate::memos;
+use crate::settings;
+use crate::shortcuts;
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+const ONBOARDING_KEY: &str = "onboarding";
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OnboardingResult {
+    pub snippets_added: usize,
+    pub quicklinks_added: usize,
+    pub folders_added: usize,
+}
+
+// (标题, 内容)
+const SAMPLE_SNIPPETS: &[(&str, &str)] = &[
+    (
+        "欢迎使用 ReFast",
+        "按下快捷键呼出启动器，输入应用名、拼音或首字母即可搜索；输入 \"s 关键词\" 用 Google 搜索，\"bd 关键词\" 用百度搜索。",
+    ),
+    (
+        "示例片段：邮件签名",
+        "此致\n敬礼\n\n——\n发送自 ReFast",
+    ),
+];
+
+// (名称, 链接)
+const SAMPLE_QUICKLINKS: &[(&str, &str)] = &[
+    ("ReFast GitHub", "https://github.com/b3nguang/ReFast"),
+    ("GitHub", "https://github.com"),
+    ("必应翻译", "https://www.bing.com/translator"),
+];
+
+// (名称, 相对用户目录的路径)
+const SAMPLE_FOLDERS: &[(&str, &str)] = &[
+    ("桌面", "Desktop"),
+    ("文档", "Documents"),
+    ("图片", "Pictures"),
+];
+
+fn is_completed(app_data_dir: &Path) -> Result<bool, String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached("SELECT 1 FROM settings WHERE key = ?1")
+            .and_then(|mut stmt| stmt.query_row(params![ONBOARDING_KEY], |_| Ok(())).optional())
+            .map(|row| row.is_some())
+            .map_err(|e| format!("Failed to read onboarding state: {}", e))
+    })
+}
+
+fn mark_completed(app_data_dir: &Path, result: &OnboardingResult) -> Result<(), String> {
+    let value = serde_json::to_string(result).map_err(|e| format!("Failed to serialize onboarding state: {}", e))?;
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "INSERT INTO settings (key, value) VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        )
+        .and_then(|mut stmt| stmt.execute(params![ONBOARDING_KEY, value]))
+        .map_err(|e| format!("Failed to save onboarding state: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 是否为全新安装：没有快捷方式、备忘录和打开历史
+fn has_user_data(app_data_dir: &Path) -> Result<bool, String> {
+    let history_count: i64 = db::with_connection(app_data_dir, |conn| {
+        conn.query_row("SELECT COUNT(*) FROM open_history", [], |row| row.get(0))
+            .map_err(|e| format!("Failed to count open history: {}", e))
+    })?;
+    Ok(history_count > 0
+        || !shortcuts::get_all_shortcuts(app_data_dir)?.is_empty()
+        || !memos::get_all_memos(&app_data_dir.to_path_buf())?.is_empty())
+}
+
+fn seed(app_data_dir: &Path) -> Result<OnboardingResult, String> {
+    let mut result = OnboardingResult::default();
+    let dir = app_data_dir.to_path_buf();
+
+    for (title, content) in SAMPLE_SNIPPETS {
+        memos::add_memo(title.to_string(), content.to_string(), &dir)?;
+        result.snippets_added += 1;
+    }
+
+    let preview = ImportPreview {
+        source: "onboarding".to_string(),
+        quicklinks: SAMPLE_QUICKLINKS
+            .iter()
+            .map(|(name, url)| ImportedQuicklink {
+                name: name.to_string(),
+                url: url.to_string(),
+            })
+            .collect(),
+        ..Default::default()
+    };
+    result.quicklinks_added = importer::apply_import(&preview, app_data_dir)?.quicklinks_added;
+
+    if let Ok(profile) = std::env::var("USERPROFILE") {
+        for (name, relative) in SAMPLE_FOLDERS {
+            let path = PathBuf::from(&profile).join(relative);
+            if path.is_dir() {
+                shortcuts::add_shortcut(name.to_string(), path.to_string_lossy().to_string(), None, app_data_dir)?;
+                result.folders_added += 1;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// 首次运行时写入示例数据并触发首次应用扫描（进度通过 app-rescan-progress 事件报告）
+/// 完成后发送 onboarding-completed 事件；已完成引导或已有用户数据时不写入示例
+pub fn run(app_handle: &AppHandle, app_data_dir: &Path) -> Result<(), String> {
+    if is_completed(app_data_dir)? {
+        return Ok(());
+    }
+    // 旧版 settings.json 只在 settings 表为空时导入，写入标记前先完成迁移
+    settings::load_settings(app_data_dir)?;
+    // 与启动时的快捷方式加载并行执行，这里先确保已加载，避免误判为空
+    shortcuts::load_shortcuts(app_data_dir)?;
+
+    if has_user_data(app_data_dir)? {
+        return mark_completed(app_data_dir, &OnboardingResult::default());
+    }
+
+    let result = seed(app_data_dir)?;
+    mark_completed(app_data_dir, &result)?;
+    eprintln!(
+        "[Onboarding] Seeded {} snippets, {} quicklinks, {} folders",
+        result.snippets_added, result.quicklinks_added, result.folders_added
+    );
+
+    let handle = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = crate::commands::rescan_applications(handle).await {
+            eprintln!("[Onboarding] Initial app scan failed: {}", e);
+        }
+    });
+    let _ = app_handle.emit("onboarding-completed", &result);
+    Ok(())
+}
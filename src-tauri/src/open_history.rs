@@ -1,4 +1,6 @@
 use crate::db;
+use crate::path_expand;
+use crate::scoring;
 use rusqlite::params;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -187,8 +189,9 @@ pub fn add_item(path: String, app_data_dir: &Path) -> Result<(), String> {
         
         (url, false, Some(name))
     } else {
-        // Handle file system path
-        let trimmed = trimmed.trim_end_matches(|c| c == '\\' || c == '/');
+        // Handle file system path (expand %VAR%, $VAR and ~ first)
+        let expanded = path_expand::expand_path(trimmed);
+        let trimmed = expanded.trim_end_matches(|c| c == '\\' || c == '/');
         
         // Normalize path (convert to absolute if relative)
         let path_buf = PathBuf::from(trimmed);
@@ -371,44 +374,6 @@ fn maybe_migrate_from_json(
     Ok(())
 }
 
-// Convert Chinese characters to pinyin (full pinyin)
-#[cfg(target_os = "windows")]
-fn to_pinyin(text: &str) -> String {
-    #[cfg(target_os = "windows")]
-    use pinyin::ToPinyin;
-    text.to_pinyin()
-        .filter_map(|p| p.map(|p| p.plain()))
-        .collect::<Vec<_>>()
-        .join("")
-}
-
-// Convert Chinese characters to pinyin initials (first letter of each pinyin)
-#[cfg(target_os = "windows")]
-fn to_pinyin_initials(text: &str) -> String {
-    #[cfg(target_os = "windows")]
-    use pinyin::ToPinyin;
-    text.to_pinyin()
-        .filter_map(|p| p.map(|p| p.plain().chars().next()))
-        .flatten()
-        .collect::<String>()
-}
-
-// Check if text contains Chinese characters
-#[cfg(target_os = "windows")]
-fn contains_chinese(text: &str) -> bool {
-    text.chars().any(|c| {
-        matches!(c as u32,
-            0x4E00..=0x9FFF |  // CJK Unified Ideographs
-            0x3400..=0x4DBF |  // CJK Extension A
-            0x20000..=0x2A6DF | // CJK Extension B
-            0x2A700..=0x2B73F | // CJK Extension C
-            0x2B740..=0x2B81F | // CJK Extension D
-            0xF900..=0xFAFF |  // CJK Compatibility Ideographs
-            0x2F800..=0x2FA1F   // CJK Compatibility Ideographs Supplement
-        )
-    })
-}
-
 // Search within already-locked history (no additional locking)
 pub fn search_in_history(
     state: &HashMap<String, OpenHistoryItem>,
@@ -421,63 +386,20 @@ pub fn search_in_history(
         return items;
     }
 
-    let query_lower = query.to_lowercase();
-    #[cfg(target_os = "windows")]
-    let query_is_pinyin = !contains_chinese(&query_lower);
+    let query = scoring::Query::for_provider(query, "open_history");
 
     let mut results: Vec<(OpenHistoryItem, i32)> = state
         .values()
         .filter_map(|item| {
-            let name = item.name.as_ref().map(|n| n.to_lowercase()).unwrap_or_default();
-            let key_lower = item.key.to_lowercase();
-
-            let mut score = 0;
-
-            // Direct text match (highest priority)
-            if !name.is_empty() {
-                if name == query_lower {
-                    score += 1000;
-                } else if name.starts_with(&query_lower) {
-                    score += 500;
-                } else if name.contains(&query_lower) {
-                    score += 100;
-                }
-            }
-
-            // Pinyin matching (if query is pinyin, Windows only)
-            #[cfg(target_os = "windows")]
-            if query_is_pinyin && !name.is_empty() {
-                let name_pinyin = to_pinyin(&name).to_lowercase();
-                let name_pinyin_initials = to_pinyin_initials(&name).to_lowercase();
-
-                // Full pinyin match
-                if name_pinyin == query_lower {
-                    score += 800;
-                } else if name_pinyin.starts_with(&query_lower) {
-                    score += 400;
-                } else if name_pinyin.contains(&query_lower) {
-                    score += 150;
-                }
-
-                // Pinyin initials match
-                if name_pinyin_initials == query_lower {
-                    score += 600;
-                } else if name_pinyin_initials.starts_with(&query_lower) {
-                    score += 300;
-                } else if name_pinyin_initials.contains(&query_lower) {
-                    score += 120;
-                }
-            }
-
-            // Path match gets lower score
-            if key_lower.contains(&query_lower) {
-                score += 10;
-            }
-
+            let name_score = item
+                .name
+                .as_deref()
+                .map(|name| scoring::name_score(&query, name))
+                .unwrap_or(0);
+            let score = name_score + scoring::path_score(&query, &item.key);
             if score > 0 {
-                // Boost score by use_count and recency
-                score += (item.use_count as i32).min(100); // Max 100 bonus points
-                Some((item.clone(), score))
+                // Boost score by use_count
+                Some((item.clone(), score + scoring::frecency_bonus(&query, item.use_count)))
             } else {
                 None
             }
@@ -0,0 +1,211 @@
+// "打开方式" 动作：在终端 / VS Code / 自定义命令中打开文件夹
+// 命令模板中的 {path} 会被替换为目标文件夹路径；写在单引号中（'{path}'，如 PowerShell 命令）时路径中的 ' 转义为 ''。
+// 程序直接启动，不经过 cmd /c，避免路径中的 & | ^ 等字符被当作命令解析
+
+use crate::settings::OpenWithAction;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// 将命令模板拆分为参数列表（支持双引号包裹含空格的参数），并替换 {path}
+pub fn build_command_args(template: &str, path: &str) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut has_token = false;
+
+    for c in template.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                has_token = true;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if has_token {
+                    args.push(std::mem::take(&mut current));
+                    has_token = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_token = true;
+            }
+        }
+    }
+
+    if in_quotes {
+        return Err(format!("命令模板中的引号未闭合: {}", template));
+    }
+    if has_token {
+        args.push(current);
+    }
+    if args.is_empty() {
+        return Err("命令模板为空".to_string());
+    }
+
+    let quoted = format!("'{}'", path.replace('\'', "''"));
+    Ok(args
+        .into_iter()
+        .map(|arg| arg.replace("'{path}'", &quoted).replace("{path}", path))
+        .collect())
+}
+
+/// 按 PATH 与 PATHEXT 查找程序，用于 code（code.cmd）、wt（App Execution Alias）等无法按名称直接启动的程序；
+/// 带路径的程序名不查找
+fn find_in_path(program: &str, path_var: &std::ffi::OsStr, path_ext: &str) -> Option<PathBuf> {
+    if program.contains(['\\', '/']) {
+        return None;
+    }
+    let has_ext = Path::new(program).extension().is_some();
+    let exts: Vec<&str> = path_ext.split(';').map(str::trim).filter(|ext| !ext.is_empty()).collect();
+    std::env::split_paths(path_var).find_map(|dir| {
+        let mut candidates = has_ext
+            .then(|| dir.join(program))
+            .into_iter()
+            .chain(exts.iter().map(|ext| dir.join(format!("{}{}", program, ext.to_lowercase()))));
+        // App Execution Alias 是重解析点，exists() 会跟随链接而失败，用 symlink_metadata 判断
+        candidates.find(|candidate| std::fs::symlink_metadata(candidate).is_ok_and(|m| !m.is_dir()))
+    })
+}
+
+fn resolve_program(program: &str) -> Option<PathBuf> {
+    let path_var = std::env::var_os("PATH")?;
+    let path_ext = std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string());
+    find_in_path(program, &path_var, &path_ext)
+}
+
+/// 规范化目标路径：文件则使用其所在文件夹
+fn resolve_folder(path: &str) -> Result<PathBuf, String> {
+    let trimmed = path.trim().trim_end_matches(|c| c == '\\' || c == '/');
+    let path_buf = PathBuf::from(trimmed);
+    if !path_buf.exists() {
+        return Err(format!("Path not found: {}", trimmed));
+    }
+    if path_buf.is_dir() {
+        Ok(path_buf)
+    } else {
+        path_buf
+            .parent()
+            .map(Path::to_path_buf)
+            .ok_or_else(|| format!("无法获取文件所在目录: {}", trimmed))
+    }
+}
+
+/// 按模板执行打开方式
+pub fn run_action(action: &OpenWithAction, path: &str) -> Result<(), String> {
+    let folder = resolve_folder(path)?;
+    let folder_str = folder.to_string_lossy().to_string();
+    let args = build_command_args(&action.command, &folder_str)?;
+
+    spawn_args(&args, Some(&folder))
+        .map_err(|e| format!("Failed to run \"{}\": {}", action.name, e))
+}
+
+/// 启动命令（不隐藏窗口，终端类程序需要可见窗口）
+pub fn spawn_args(args: &[String], working_dir: Option<&Path>) -> Result<(), String> {
+    let (program, rest) = args
+        .split_first()
+        .ok_or_else(|| "命令为空".to_string())?;
+
+    let mut command = Command::new(program);
+    command.args(rest);
+    if let Some(dir) = working_dir {
+        command.current_dir(dir);
+    }
+
+    match command.spawn() {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            // code / wt 等通过 .cmd 或 App Execution Alias 提供，按名称直接启动可能失败，按 PATH 解析出完整路径后再启动；
+            // .cmd / .bat 的参数由标准库按批处理规则转义，无法安全转义时拒绝启动
+            let resolved = resolve_program(program).ok_or_else(|| e.to_string())?;
+            let mut fallback = Command::new(&resolved);
+            fallback.args(rest);
+            if let Some(dir) = working_dir {
+                fallback.current_dir(dir);
+            }
+            fallback
+                .spawn()
+                .map(|_| ())
+                .map_err(|fallback_err| format!("{} ({}: {})", e, resolved.display(), fallback_err))
+        }
+    }
+}
+
+/// 在新的终端窗口中运行命令（优先 Windows Terminal，缺失时回退到 cmd 窗口）
+pub fn spawn_in_terminal(args: &[String]) -> Result<(), String> {
+    if args.is_empty() {
+        return Err("命令为空".to_string());
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        if let Some(wt) = resolve_program("wt.exe") {
+            if Command::new(wt).args(args).spawn().is_ok() {
+                return Ok(());
+            }
+        }
+        // 没有 Windows Terminal 时直接在新的控制台窗口中启动程序，不经过 cmd 解析参数
+        let program = resolve_program(&args[0]).unwrap_or_else(|| PathBuf::from(&args[0]));
+        Command::new(program)
+            .args(&args[1..])
+            .creation_flags(0x00000010) // CREATE_NEW_CONSOLE
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to open terminal: {}", e))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Command::new("x-terminal-emulator")
+            .arg("-e")
+            .args(args)
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to open terminal: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_command_args_replaces_path() {
+        let args = build_command_args("wt.exe -d \"{path}\"", "C:\\My Projects").unwrap();
+        assert_eq!(args, vec!["wt.exe", "-d", "C:\\My Projects"]);
+    }
+
+    #[test]
+    fn test_build_command_args_keeps_empty_quoted_arg() {
+        let args = build_command_args("cmd /c start \"\" \"{path}\"", "D:\\a").unwrap();
+        assert_eq!(args, vec!["cmd", "/c", "start", "", "D:\\a"]);
+    }
+
+    #[test]
+    fn test_build_command_args_escapes_single_quoted_path() {
+        let template = "powershell.exe -NoExit -Command \"Set-Location -LiteralPath '{path}'\"";
+        let args = build_command_args(template, "C:\\it's & more").unwrap();
+        assert_eq!(args[3], "Set-Location -LiteralPath 'C:\\it''s & more'");
+    }
+
+    #[test]
+    fn test_find_in_path_uses_pathext() {
+        let dir = std::env::temp_dir().join(format!("imiss-open-with-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("code.cmd"), "").unwrap();
+        let path_var = std::env::join_paths([dir.clone()]).unwrap();
+
+        assert_eq!(find_in_path("code", &path_var, ".EXE;.CMD"), Some(dir.join("code.cmd")));
+        assert_eq!(find_in_path("code.cmd", &path_var, ".EXE"), Some(dir.join("code.cmd")));
+        assert_eq!(find_in_path("missing", &path_var, ".EXE;.CMD"), None);
+        assert_eq!(find_in_path("C:\\tools\\code", &path_var, ".CMD"), None);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_build_command_args_rejects_invalid_templates() {
+        assert!(build_command_args("   ", "C:\\").is_err());
+        assert!(build_command_args("code \"{path}", "C:\\").is_err());
+    }
+}
@@ -0,0 +1,245 @@
+// 密码生成：随机字符密码、diceware 式单词口令，以及由辅音与元音交替组成的易读密码
+// 随机数取自系统安全随机源；复制时以敏感内容写入剪贴板（不记入剪切板历史，到期自动清除）
+
+use rand::rngs::OsRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+/// 复制的密码在剪贴板中保留的时间
+pub const CLIPBOARD_EXPIRE: Duration = Duration::from_secs(30);
+
+const MIN_LENGTH: usize = 4;
+const MAX_LENGTH: usize = 128;
+const MIN_WORDS: usize = 3;
+const MAX_WORDS: usize = 16;
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!@#$%^&*()-_=+[]{};:,.?/";
+// 容易看错的字符，可选择排除
+const AMBIGUOUS: &str = "Il1O0o";
+const CONSONANTS: &str = "bcdfghjklmnprstvwz";
+const VOWELS: &str = "aeiou";
+
+static WORDS: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
+    include_str!("password_words.txt").lines().map(str::trim).filter(|w| !w.is_empty()).collect()
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasswordMode {
+    #[default]
+    Random,
+    Passphrase,
+    Pronounceable,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PasswordOptions {
+    pub mode: PasswordMode,
+    pub length: usize, // 随机与易读模式的字符数
+    pub lowercase: bool,
+    pub uppercase: bool,
+    pub digits: bool,
+    pub symbols: bool,
+    pub exclude_ambiguous: bool,
+    pub words: usize, // 口令模式的单词数
+    pub separator: String,
+    pub capitalize: bool,     // 口令的每个单词首字母大写
+    pub include_number: bool, // 口令中随机一个单词后追加一位数字
+}
+
+impl Default for PasswordOptions {
+    fn default() -> Self {
+        PasswordOptions {
+            mode: PasswordMode::Random,
+            length: 20,
+            lowercase: true,
+            uppercase: true,
+            digits: true,
+            symbols: true,
+            exclude_ambiguous: false,
+            words: 6,
+            separator: "-".to_string(),
+            capitalize: false,
+            include_number: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GeneratedPassword {
+    pub password: String,
+    pub entropy_bits: f64, // 估算的熵（位），用于前端显示强度
+}
+
+fn pick<R: Rng>(rng: &mut R, chars: &[char]) -> char {
+    chars[rng.gen_range(0..chars.len())]
+}
+
+fn charset(source: &str, exclude_ambiguous: bool) -> Vec<char> {
+    source.chars().filter(|c| !exclude_ambiguous || !AMBIGUOUS.contains(*c)).collect()
+}
+
+/// 随机字符密码：每种选中的字符类型至少出现一次
+fn random_password<R: Rng>(rng: &mut R, options: &PasswordOptions) -> Result<GeneratedPassword, String> {
+    let classes: Vec<Vec<char>> = [
+        (options.lowercase, LOWERCASE),
+        (options.uppercase, UPPERCASE),
+        (options.digits, DIGITS),
+        (options.symbols, SYMBOLS),
+    ]
+    .into_iter()
+    .filter(|(enabled, _)| *enabled)
+    .map(|(_, source)| charset(source, options.exclude_ambiguous))
+    .collect();
+    if classes.is_empty() {
+        return Err("至少选择一种字符类型".to_string());
+    }
+    if options.length < classes.len() {
+        return Err(format!("长度至少为 {} 才能包含所有选中的字符类型", classes.len()));
+    }
+    let pool: Vec<char> = classes.concat();
+    let mut chars: Vec<char> = classes.iter().map(|class| pick(rng, class)).collect();
+    while chars.len() < options.length {
+        chars.push(pick(rng, &pool));
+    }
+    chars.shuffle(rng);
+    Ok(GeneratedPassword {
+        password: chars.into_iter().collect(),
+        entropy_bits: options.length as f64 * (pool.len() as f64).log2(),
+    })
+}
+
+/// 单词口令：从词表中均匀抽取单词
+fn passphrase<R: Rng>(rng: &mut R, options: &PasswordOptions) -> Result<GeneratedPassword, String> {
+    if !(MIN_WORDS..=MAX_WORDS).contains(&options.words) {
+        return Err(format!("单词数需在 {} 到 {} 之间", MIN_WORDS, MAX_WORDS));
+    }
+    let mut words: Vec<String> = (0..options.words)
+        .map(|_| {
+            let word = WORDS[rng.gen_range(0..WORDS.len())];
+            if options.capitalize {
+                let mut chars = word.chars();
+                chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+            } else {
+                word.to_string()
+            }
+        })
+        .collect();
+    let mut entropy_bits = options.words as f64 * (WORDS.len() as f64).log2();
+    if options.include_number {
+        let index = rng.gen_range(0..words.len());
+        words[index].push(char::from(b'0' + rng.gen_range(0..10u8)));
+        entropy_bits += 10f64.log2() + (options.words as f64).log2();
+    }
+    Ok(GeneratedPassword { password: words.join(&options.separator), entropy_bits })
+}
+
+/// 易读密码：辅音与元音交替，按选项把首字母大写并在末尾追加两位数字和一个符号
+fn pronounceable<R: Rng>(rng: &mut R, options: &PasswordOptions) -> Result<GeneratedPassword, String> {
+    let consonants: Vec<char> = CONSONANTS.chars().collect();
+    let vowels: Vec<char> = VOWELS.chars().collect();
+    let suffix_len = if options.digits { 2 } else { 0 } + usize::from(options.symbols);
+    let letters = options.length.saturating_sub(suffix_len).max(MIN_LENGTH);
+
+    let mut password = String::with_capacity(options.length);
+    let mut entropy_bits = 1.0; // 以辅音还是元音开头
+    let mut use_vowel = rng.gen_bool(0.5);
+    for i in 0..letters {
+        let set = if use_vowel { &vowels } else { &consonants };
+        let c = pick(rng, set);
+        password.push(if i == 0 && options.uppercase { c.to_ascii_uppercase() } else { c });
+        entropy_bits += (set.len() as f64).log2();
+        use_vowel = !use_vowel;
+    }
+    if options.digits {
+        let digits = charset(DIGITS, false);
+        for _ in 0..2 {
+            password.push(pick(rng, &digits));
+        }
+        entropy_bits += 2.0 * 10f64.log2();
+    }
+    if options.symbols {
+        let symbols = charset(SYMBOLS, false);
+        password.push(pick(rng, &symbols));
+        entropy_bits += (symbols.len() as f64).log2();
+    }
+    Ok(GeneratedPassword { password, entropy_bits })
+}
+
+fn generate_with<R: Rng>(rng: &mut R, options: &PasswordOptions) -> Result<GeneratedPassword, String> {
+    if options.mode != PasswordMode::Passphrase && !(MIN_LENGTH..=MAX_LENGTH).contains(&options.length) {
+        return Err(format!("长度需在 {} 到 {} 之间", MIN_LENGTH, MAX_LENGTH));
+    }
+    match options.mode {
+        PasswordMode::Random => random_password(rng, options),
+        PasswordMode::Passphrase => passphrase(rng, options),
+        PasswordMode::Pronounceable => pronounceable(rng, options),
+    }
+}
+
+/// 按选项生成密码
+pub fn generate(options: &PasswordOptions) -> Result<GeneratedPassword, String> {
+    generate_with(&mut OsRng, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_password_covers_selected_classes() {
+        let options = PasswordOptions { length: 8, exclude_ambiguous: true, ..Default::default() };
+        for _ in 0..50 {
+            let result = generate(&options).unwrap();
+            let password = &result.password;
+            assert_eq!(password.chars().count(), 8);
+            assert!(password.chars().any(|c| c.is_ascii_lowercase()));
+            assert!(password.chars().any(|c| c.is_ascii_uppercase()));
+            assert!(password.chars().any(|c| c.is_ascii_digit()));
+            assert!(password.chars().any(|c| SYMBOLS.contains(c)));
+            assert!(!password.chars().any(|c| AMBIGUOUS.contains(c)));
+        }
+        let digits_only = PasswordOptions { lowercase: false, uppercase: false, symbols: false, ..Default::default() };
+        assert!(generate(&digits_only).unwrap().password.chars().all(|c| c.is_ascii_digit()));
+        let none = PasswordOptions { digits: false, ..digits_only };
+        assert!(generate(&none).is_err());
+        assert!(generate(&PasswordOptions { length: 2, ..Default::default() }).is_err());
+    }
+
+    #[test]
+    fn passphrase_uses_word_list() {
+        assert!(WORDS.len() >= 1024);
+        let options = PasswordOptions {
+            mode: PasswordMode::Passphrase,
+            words: 5,
+            separator: ".".to_string(),
+            capitalize: true,
+            include_number: true,
+            ..Default::default()
+        };
+        let result = generate(&options).unwrap();
+        let parts: Vec<&str> = result.password.split('.').collect();
+        assert_eq!(parts.len(), 5);
+        assert!(parts.iter().all(|p| p.starts_with(|c: char| c.is_ascii_uppercase())));
+        assert_eq!(parts.iter().filter(|p| p.ends_with(|c: char| c.is_ascii_digit())).count(), 1);
+        assert!(result.entropy_bits > 50.0);
+        assert!(generate(&PasswordOptions { mode: PasswordMode::Passphrase, words: 2, ..Default::default() }).is_err());
+    }
+
+    #[test]
+    fn pronounceable_alternates_vowels() {
+        let options = PasswordOptions { mode: PasswordMode::Pronounceable, length: 12, ..Default::default() };
+        let password = generate(&options).unwrap().password;
+        assert_eq!(password.chars().count(), 12);
+        let letters: Vec<bool> = password[..9].to_lowercase().chars().map(|c| VOWELS.contains(c)).collect();
+        assert!(letters.windows(2).all(|w| w[0] != w[1]));
+        assert!(password[9..11].chars().all(|c| c.is_ascii_digit()));
+    }
+}
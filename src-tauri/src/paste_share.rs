@@ -0,0 +1,434 @@
+// 把剪切板文本分享为临时粘贴链接（默认关闭）：上传到 paste.rs、dpaste.com 或自建服务，返回链接供复制
+// 创建的链接记录在 paste_links 表中，可随时撤销；服务端不支持过期但支持删除时，由后台任务在到期后删除
+
+use crate::api_policy::{self, Policy};
+use crate::{clipboard, db, http_client, secrets, shutdown};
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const CONFIG_KEY: &str = "paste_service"; // settings 表中保存配置的键
+const PASTE_RS_URL: &str = "https://paste.rs/";
+const DPASTE_URL: &str = "https://dpaste.com/api/v2/";
+const DPASTE_MAX_DAYS: u64 = 365;
+const MAX_PASTE_BYTES: usize = 512 * 1024;
+const PREVIEW_CHARS: usize = 60;
+const EXPIRY_TICK: Duration = Duration::from_secs(300);
+
+/// 有效期选项：(id, 秒数)，None 表示不过期
+pub const EXPIRY_OPTIONS: &[(&str, Option<u64>)] = &[
+    ("1h", Some(3600)),
+    ("1d", Some(86400)),
+    ("7d", Some(7 * 86400)),
+    ("30d", Some(30 * 86400)),
+    ("never", None),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PasteProvider {
+    #[default]
+    PasteRs,
+    Dpaste,
+    Custom,
+}
+
+impl PasteProvider {
+    fn as_str(self) -> &'static str {
+        match self {
+            PasteProvider::PasteRs => "paste_rs",
+            PasteProvider::Dpaste => "dpaste",
+            PasteProvider::Custom => "custom",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PasteConfig {
+    pub enabled: bool,
+    pub provider: PasteProvider,
+    pub endpoint: String, // 自建服务的上传地址
+    pub default_expiry: String,
+}
+
+impl Default for PasteConfig {
+    fn default() -> Self {
+        PasteConfig {
+            enabled: false,
+            provider: PasteProvider::default(),
+            endpoint: String::new(),
+            default_expiry: "1d".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct StoredConfig {
+    #[serde(flatten)]
+    config: PasteConfig,
+    token: Option<String>, // DPAPI 加密的访问令牌（dpaste 或自建服务）
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PasteConfigView {
+    #[serde(flatten)]
+    pub config: PasteConfig,
+    pub has_token: bool,
+}
+
+/// token 为 None 时保留原值，为空字符串时清除
+#[derive(Debug, Clone, Deserialize)]
+pub struct PasteConfigInput {
+    #[serde(flatten)]
+    pub config: PasteConfig,
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PasteLink {
+    pub id: String,
+    pub clipboard_item_id: Option<String>,
+    pub provider: String,
+    pub url: String,
+    pub preview: String,
+    pub created_at: u64,
+    pub expires_at: Option<u64>,
+    pub revoked_at: Option<u64>,
+    pub can_delete: bool, // 是否可以在服务端删除
+}
+
+/// 上传结果：链接与删除地址
+#[derive(Debug, Clone, PartialEq)]
+pub struct Uploaded {
+    pub url: String,
+    pub delete_url: Option<String>,
+}
+
+fn now_ts() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+pub fn expiry_seconds(expiry: &str) -> Result<Option<u64>, String> {
+    EXPIRY_OPTIONS
+        .iter()
+        .find(|(id, _)| *id == expiry)
+        .map(|(_, seconds)| *seconds)
+        .ok_or_else(|| format!("无效的有效期: {}", expiry))
+}
+
+// ---------- 配置 ----------
+
+fn load_stored(app_data_dir: &Path) -> Result<StoredConfig, String> {
+    let json: Option<String> = db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached("SELECT value FROM settings WHERE key = ?1")
+            .and_then(|mut stmt| stmt.query_row(params![CONFIG_KEY], |row| row.get(0)).optional())
+            .map_err(|e| format!("Failed to read {}: {}", CONFIG_KEY, e))
+    })?;
+    Ok(json.and_then(|json| serde_json::from_str(&json).ok()).unwrap_or_default())
+}
+
+pub fn get_config(app_data_dir: &Path) -> Result<PasteConfigView, String> {
+    let stored = load_stored(app_data_dir)?;
+    Ok(PasteConfigView { has_token: stored.token.is_some(), config: stored.config })
+}
+
+pub fn save_config(app_data_dir: &Path, input: PasteConfigInput) -> Result<PasteConfigView, String> {
+    let mut stored = load_stored(app_data_dir)?;
+    let mut config = input.config;
+    config.endpoint = config.endpoint.trim().to_string();
+    expiry_seconds(&config.default_expiry)?;
+    if config.enabled && config.provider == PasteProvider::Custom {
+        let url = reqwest::Url::parse(&config.endpoint).map_err(|e| format!("服务地址无效: {}", e))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err("服务地址必须以 http:// 或 https:// 开头".to_string());
+        }
+    }
+    stored.token = match input.token {
+        None => stored.token,
+        Some(token) if token.trim().is_empty() => None,
+        Some(token) => Some(secrets::encrypt(token.trim())?),
+    };
+    stored.config = config;
+
+    let value = serde_json::to_string(&stored).map_err(|e| format!("Failed to serialize {}: {}", CONFIG_KEY, e))?;
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "INSERT INTO settings (key, value) VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        )
+        .and_then(|mut stmt| stmt.execute(params![CONFIG_KEY, value]))
+        .map_err(|e| format!("Failed to save {}: {}", CONFIG_KEY, e))
+    })?;
+    get_config(app_data_dir)
+}
+
+// ---------- 上传与删除 ----------
+
+/// 解析上传响应：JSON 中的 url / link 字段（可带 delete_url），或纯文本链接
+pub fn parse_upload_response(body: &str) -> Result<Uploaded, String> {
+    let body = body.trim();
+    if let Ok(value) = serde_json::from_str::<Value>(body) {
+        let url = ["url", "link"].iter().find_map(|key| value[*key].as_str());
+        if let Some(url) = url {
+            return Ok(Uploaded {
+                url: url.to_string(),
+                delete_url: value["delete_url"].as_str().map(str::to_string),
+            });
+        }
+        return Err("服务返回的内容中没有链接".to_string());
+    }
+    let url = body.lines().next().unwrap_or_default().trim();
+    if url.starts_with("http://") || url.starts_with("https://") {
+        Ok(Uploaded { url: url.to_string(), delete_url: None })
+    } else {
+        Err(format!("服务返回的内容无法识别: {}", body.chars().take(100).collect::<String>()))
+    }
+}
+
+async fn upload(config: &PasteConfig, token: Option<&str>, content: &str, expires_in: Option<u64>) -> Result<Uploaded, String> {
+    let client = http_client::client()?;
+    let provider = config.provider;
+    let endpoint = match provider {
+        PasteProvider::PasteRs => PASTE_RS_URL,
+        PasteProvider::Dpaste => DPASTE_URL,
+        PasteProvider::Custom => config.endpoint.as_str(),
+    };
+    let host = api_policy::provider_of(endpoint);
+    let response = api_policy::send(&host, &Policy::PASTE, || {
+        let request = client.post(endpoint);
+        let request = match token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        };
+        match provider {
+            PasteProvider::PasteRs => request.body(content.to_string()),
+            PasteProvider::Dpaste => {
+                // dpaste 按天过期，不足一天按一天计，不支持永久保存
+                let days = expires_in.map_or(DPASTE_MAX_DAYS, |s| s.div_ceil(86400).clamp(1, DPASTE_MAX_DAYS));
+                request.form(&[("content", content.to_string()), ("expiry_days", days.to_string())])
+            }
+            PasteProvider::Custom => request.json(&json!({ "content": content, "expires_in": expires_in })),
+        }
+    })
+    .await
+    .map_err(|e| format!("上传失败: {}", e))?;
+    // paste.rs 内容过大时只保存前一部分并返回 206
+    if response.status().as_u16() == 206 {
+        return Err("内容超过服务的大小限制".to_string());
+    }
+    let body = response.text().await.map_err(|e| format!("读取上传结果失败: {}", e))?;
+    let mut uploaded = parse_upload_response(&body)?;
+    // paste.rs 用同一地址删除
+    if provider == PasteProvider::PasteRs {
+        uploaded.delete_url = Some(uploaded.url.clone());
+    }
+    Ok(uploaded)
+}
+
+async fn delete_remote(delete_url: &str, token: Option<&str>) -> Result<(), String> {
+    let client = http_client::client()?;
+    let host = api_policy::provider_of(delete_url);
+    match api_policy::send(&host, &Policy::PASTE, || {
+        let request = client.delete(delete_url);
+        match token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    })
+    .await
+    {
+        Ok(_) => Ok(()),
+        // 已被服务端清理
+        Err(e) if matches!(e.status, Some(404) | Some(410)) => Ok(()),
+        Err(e) => Err(format!("删除失败: {}", e)),
+    }
+}
+
+fn load_token(stored: &StoredConfig) -> Result<Option<String>, String> {
+    stored.token.as_deref().map(secrets::decrypt).transpose()
+}
+
+// ---------- 链接记录 ----------
+
+fn row_to_link(row: &rusqlite::Row) -> rusqlite::Result<PasteLink> {
+    let delete_url: Option<String> = row.get(4)?;
+    Ok(PasteLink {
+        id: row.get(0)?,
+        clipboard_item_id: row.get(1)?,
+        provider: row.get(2)?,
+        url: row.get(3)?,
+        can_delete: delete_url.is_some(),
+        preview: row.get(5)?,
+        created_at: row.get::<_, i64>(6)? as u64,
+        expires_at: row.get::<_, Option<i64>>(7)?.map(|v| v as u64),
+        revoked_at: row.get::<_, Option<i64>>(8)?.map(|v| v as u64),
+    })
+}
+
+const LINK_COLUMNS: &str = "id, clipboard_item_id, provider, url, delete_url, preview, created_at, expires_at, revoked_at";
+
+/// 上传剪切板文本项，expiry 为空时使用设置中的默认有效期
+pub async fn create_link(app_data_dir: &PathBuf, item_id: &str, expiry: Option<&str>) -> Result<PasteLink, String> {
+    let stored = load_stored(app_data_dir)?;
+    if !stored.config.enabled {
+        return Err("未启用粘贴链接分享，请先在设置中开启".to_string());
+    }
+    let item = clipboard::get_clipboard_item(item_id, app_data_dir)?.ok_or_else(|| "剪切板记录不存在".to_string())?;
+    if item.content_type != "text" {
+        return Err("只能分享文本内容".to_string());
+    }
+    if item.content.trim().is_empty() {
+        return Err("内容为空".to_string());
+    }
+    if item.content.len() > MAX_PASTE_BYTES {
+        return Err(format!("内容超过 {} KB，无法分享", MAX_PASTE_BYTES / 1024));
+    }
+    let expires_in = expiry_seconds(expiry.unwrap_or(&stored.config.default_expiry))?;
+    let token = load_token(&stored)?;
+    let uploaded = upload(&stored.config, token.as_deref(), &item.content, expires_in).await?;
+
+    let now = now_ts();
+    let id = format!("paste-{}-{:04x}", now, rand::random::<u16>());
+    let preview: String = item.content.split_whitespace().collect::<Vec<_>>().join(" ").chars().take(PREVIEW_CHARS).collect();
+    let provider = stored.config.provider.as_str();
+    let expires_at = expires_in.map(|seconds| now + seconds);
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute(
+            "INSERT INTO paste_links (id, clipboard_item_id, provider, url, delete_url, preview, created_at, expires_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![id, item_id, provider, uploaded.url, uploaded.delete_url, preview, now as i64, expires_at.map(|v| v as i64)],
+        )
+        .map_err(|e| format!("Failed to save paste link: {}", e))
+    })?;
+    Ok(PasteLink {
+        id,
+        clipboard_item_id: Some(item_id.to_string()),
+        provider: provider.to_string(),
+        can_delete: uploaded.delete_url.is_some(),
+        url: uploaded.url,
+        preview,
+        created_at: now,
+        expires_at,
+        revoked_at: None,
+    })
+}
+
+pub fn list_links(app_data_dir: &Path) -> Result<Vec<PasteLink>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(&format!("SELECT {} FROM paste_links ORDER BY created_at DESC", LINK_COLUMNS))
+            .map_err(|e| format!("Failed to prepare paste links query: {}", e))?;
+        let rows = stmt
+            .query_map([], row_to_link)
+            .map_err(|e| format!("Failed to query paste links: {}", e))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read paste link: {}", e))
+    })
+}
+
+fn mark_revoked(app_data_dir: &Path, id: &str) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute(
+            "UPDATE paste_links SET revoked_at = ?2 WHERE id = ?1 AND revoked_at IS NULL",
+            params![id, now_ts() as i64],
+        )
+        .map_err(|e| format!("Failed to update paste link: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 撤销链接：服务支持删除时在服务端删除，否则只在本地标记（链接到期后由服务端清理）
+pub async fn revoke_link(app_data_dir: &PathBuf, id: &str) -> Result<(), String> {
+    let delete_url: Option<String> = db::with_connection(app_data_dir, |conn| {
+        conn.query_row("SELECT delete_url FROM paste_links WHERE id = ?1", params![id], |row| row.get(0))
+            .optional()
+            .map_err(|e| format!("Failed to read paste link: {}", e))
+    })?
+    .ok_or_else(|| "链接记录不存在".to_string())?;
+    if let Some(delete_url) = delete_url {
+        let token = load_token(&load_stored(app_data_dir)?)?;
+        delete_remote(&delete_url, token.as_deref()).await?;
+    }
+    mark_revoked(app_data_dir, id)
+}
+
+/// 删除已撤销或已过期的记录
+pub fn clear_inactive_links(app_data_dir: &Path) -> Result<usize, String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute(
+            "DELETE FROM paste_links WHERE revoked_at IS NOT NULL OR (expires_at IS NOT NULL AND expires_at <= ?1)",
+            params![now_ts() as i64],
+        )
+        .map_err(|e| format!("Failed to clear paste links: {}", e))
+    })
+}
+
+/// 到期且可删除的链接在服务端删除；失败时保留，下次再试
+async fn delete_expired(app_data_dir: &PathBuf) -> Result<(), String> {
+    let due: Vec<(String, String)> = db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT id, delete_url FROM paste_links
+                 WHERE revoked_at IS NULL AND delete_url IS NOT NULL AND expires_at IS NOT NULL AND expires_at <= ?1",
+            )
+            .map_err(|e| format!("Failed to prepare expired paste query: {}", e))?;
+        let rows = stmt
+            .query_map(params![now_ts() as i64], |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(|e| format!("Failed to query expired pastes: {}", e))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read expired paste: {}", e))
+    })?;
+    if due.is_empty() {
+        return Ok(());
+    }
+    let token = load_token(&load_stored(app_data_dir)?)?;
+    for (id, delete_url) in due {
+        match delete_remote(&delete_url, token.as_deref()).await {
+            Ok(()) => mark_revoked(app_data_dir, &id)?,
+            Err(e) => eprintln!("[PasteShare] Failed to delete expired paste {}: {}", id, e),
+        }
+    }
+    Ok(())
+}
+
+/// 后台定时删除到期的链接
+pub fn start_expiry_task(app_data_dir: PathBuf) -> Result<(), String> {
+    shutdown::spawn("paste-expiry", move || loop {
+        if let Err(e) = tauri::async_runtime::block_on(delete_expired(&app_data_dir)) {
+            eprintln!("[PasteShare] Expiry check failed: {}", e);
+        }
+        if shutdown::wait_timeout(EXPIRY_TICK) {
+            break;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_upload_responses() {
+        assert_eq!(
+            parse_upload_response("https://paste.rs/abc\n").unwrap(),
+            Uploaded { url: "https://paste.rs/abc".to_string(), delete_url: None }
+        );
+        assert_eq!(
+            parse_upload_response(r#"{"url":"https://p.example/x","delete_url":"https://p.example/x?key=1"}"#).unwrap(),
+            Uploaded { url: "https://p.example/x".to_string(), delete_url: Some("https://p.example/x?key=1".to_string()) }
+        );
+        assert!(parse_upload_response(r#"{"error":"too large"}"#).is_err());
+        assert!(parse_upload_response("<html>error</html>").is_err());
+
+        assert_eq!(expiry_seconds("1h").unwrap(), Some(3600));
+        assert_eq!(expiry_seconds("never").unwrap(), None);
+        assert!(expiry_seconds("2h").is_err());
+    }
+}
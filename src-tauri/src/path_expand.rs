@@ -0,0 +1,228 @@
+// 路径输入展开与补全
+// 支持 %VAR% / $VAR / ${VAR} 环境变量与 ~ 家目录简写，并为部分输入的路径提供补全候选
+
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MAX_COMPLETIONS: usize = 50;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PathCompletion {
+    pub path: String,     // 补全后的完整路径（文件夹以分隔符结尾，便于继续输入）
+    pub name: String,     // 条目名称
+    pub is_folder: bool,
+}
+
+/// 获取用户家目录（Windows 优先 USERPROFILE，其次 HOME）
+pub fn home_dir() -> Option<PathBuf> {
+    let candidates = if cfg!(target_os = "windows") {
+        ["USERPROFILE", "HOME"]
+    } else {
+        ["HOME", "USERPROFILE"]
+    };
+    candidates
+        .iter()
+        .filter_map(|key| env::var(key).ok())
+        .find(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// 展开路径中的 ~ 与环境变量
+/// 未定义的变量保持原样，避免把用户输入悄悄改成空字符串
+pub fn expand_path(input: &str) -> String {
+    let expanded = expand_env_vars(input.trim());
+    expand_tilde(&expanded)
+}
+
+fn expand_tilde(input: &str) -> String {
+    if input == "~" {
+        return home_dir()
+            .map(|h| h.to_string_lossy().to_string())
+            .unwrap_or_else(|| input.to_string());
+    }
+    if let Some(rest) = input.strip_prefix("~/").or_else(|| input.strip_prefix("~\\")) {
+        if let Some(home) = home_dir() {
+            return home.join(rest).to_string_lossy().to_string();
+        }
+    }
+    input.to_string()
+}
+
+fn expand_env_vars(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut result = String::with_capacity(input.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            // %VAR%（Windows 风格），不区分大小写
+            '%' => {
+                if let Some(end) = chars[i + 1..].iter().position(|&c| c == '%') {
+                    let name: String = chars[i + 1..i + 1 + end].iter().collect();
+                    if is_valid_var_name(&name, true) {
+                        if let Some(value) = lookup_var(&name) {
+                            result.push_str(&value);
+                            i += end + 2;
+                            continue;
+                        }
+                    }
+                }
+                result.push('%');
+                i += 1;
+            }
+            // ${VAR}
+            '$' if chars.get(i + 1) == Some(&'{') => {
+                if let Some(end) = chars[i + 2..].iter().position(|&c| c == '}') {
+                    let name: String = chars[i + 2..i + 2 + end].iter().collect();
+                    if is_valid_var_name(&name, false) {
+                        if let Some(value) = lookup_var(&name) {
+                            result.push_str(&value);
+                            i += end + 3;
+                            continue;
+                        }
+                    }
+                }
+                result.push('$');
+                i += 1;
+            }
+            // $VAR
+            '$' => {
+                let len = chars[i + 1..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_alphanumeric() || **c == '_')
+                    .count();
+                if len > 0 {
+                    let name: String = chars[i + 1..i + 1 + len].iter().collect();
+                    if let Some(value) = lookup_var(&name) {
+                        result.push_str(&value);
+                        i += len + 1;
+                        continue;
+                    }
+                }
+                result.push('$');
+                i += 1;
+            }
+            c => {
+                result.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    result
+}
+
+fn is_valid_var_name(name: &str, allow_parens: bool) -> bool {
+    // 允许 ProgramFiles(x86) 这类带括号的 Windows 变量名
+    !name.is_empty()
+        && name.chars().all(|c| {
+            c.is_ascii_alphanumeric() || c == '_' || (allow_parens && (c == '(' || c == ')'))
+        })
+}
+
+fn lookup_var(name: &str) -> Option<String> {
+    if let Ok(value) = env::var(name) {
+        return Some(value);
+    }
+    // Windows 环境变量不区分大小写
+    env::vars()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value)
+}
+
+/// 为部分输入的路径生成补全候选
+/// 输入以分隔符结尾时列出该目录内容，否则按最后一段的前缀过滤父目录中的条目
+pub fn complete_path(partial: &str) -> Vec<PathCompletion> {
+    let expanded = expand_path(partial);
+    if expanded.is_empty() {
+        return Vec::new();
+    }
+
+    let ends_with_separator = expanded.ends_with('\\') || expanded.ends_with('/');
+    let (dir, prefix) = if ends_with_separator {
+        (PathBuf::from(&expanded), String::new())
+    } else {
+        let path = Path::new(&expanded);
+        let prefix = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => (parent.to_path_buf(), prefix),
+            _ => return Vec::new(),
+        }
+    };
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let prefix_lower = prefix.to_lowercase();
+    let show_hidden = prefix.starts_with('.');
+    let mut completions: Vec<PathCompletion> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !name.to_lowercase().starts_with(&prefix_lower) {
+                return None;
+            }
+            if name.starts_with('.') && !show_hidden {
+                return None;
+            }
+            let is_folder = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+            let mut path = dir.join(&name).to_string_lossy().to_string();
+            if is_folder {
+                path.push(std::path::MAIN_SEPARATOR);
+            }
+            Some(PathCompletion {
+                path,
+                name,
+                is_folder,
+            })
+        })
+        .collect();
+
+    // 文件夹优先，其次按名称排序
+    completions.sort_by(|a, b| {
+        b.is_folder
+            .cmp(&a.is_folder)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    completions.truncate(MAX_COMPLETIONS);
+    completions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_percent_and_dollar_vars() {
+        env::set_var("IMISS_TEST_DIR", "C:\\Data");
+        assert_eq!(expand_path("%IMISS_TEST_DIR%\\docs"), "C:\\Data\\docs");
+        assert_eq!(expand_path("%imiss_test_dir%\\docs"), "C:\\Data\\docs");
+        assert_eq!(expand_path("$IMISS_TEST_DIR/docs"), "C:\\Data/docs");
+        assert_eq!(expand_path("${IMISS_TEST_DIR}/docs"), "C:\\Data/docs");
+    }
+
+    #[test]
+    fn test_unknown_vars_are_kept() {
+        assert_eq!(expand_path("%IMISS_NOT_SET_VAR%\\a"), "%IMISS_NOT_SET_VAR%\\a");
+        assert_eq!(expand_path("100% done"), "100% done");
+    }
+
+    #[test]
+    fn test_expand_tilde() {
+        if let Some(home) = home_dir() {
+            assert_eq!(expand_path("~"), home.to_string_lossy());
+            assert_eq!(
+                expand_path("~/docs"),
+                home.join("docs").to_string_lossy().to_string()
+            );
+        }
+        assert_eq!(expand_path("a~b"), "a~b");
+    }
+}
@@ -0,0 +1,101 @@
+// 拼音工具：中文检测、全拼 / 首字母转换，并缓存已计算过的名称
+// 应用、历史记录、系统文件夹等搜索都通过这里获取拼音，避免每次查询对每个条目重复转换
+
+use pinyin::ToPinyin;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+
+// 缓存条目上限，超过后整体清空（索引名称数量有限，正常不会触发）
+const CACHE_CAPACITY: usize = 20_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PinyinNames {
+    pub full: String,     // 全拼，小写，如 "weixin"
+    pub initials: String, // 首字母，小写，如 "wx"
+}
+
+static CACHE: LazyLock<RwLock<HashMap<String, Arc<PinyinNames>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// 是否包含中文字符
+pub fn contains_chinese(text: &str) -> bool {
+    text.chars().any(|c| {
+        matches!(c as u32,
+            0x4E00..=0x9FFF |  // CJK Unified Ideographs
+            0x3400..=0x4DBF |  // CJK Extension A
+            0x20000..=0x2A6DF | // CJK Extension B
+            0x2A700..=0x2B73F | // CJK Extension C
+            0x2B740..=0x2B81F | // CJK Extension D
+            0xF900..=0xFAFF |  // CJK Compatibility Ideographs
+            0x2F800..=0x2FA1F   // CJK Compatibility Ideographs Supplement
+        )
+    })
+}
+
+/// 中文转全拼（非中文字符被忽略），结果为小写
+pub fn to_pinyin(text: &str) -> String {
+    text.to_pinyin()
+        .filter_map(|p| p.map(|p| p.plain()))
+        .collect::<Vec<_>>()
+        .join("")
+        .to_lowercase()
+}
+
+/// 中文转拼音首字母（非中文字符被忽略），结果为小写
+pub fn to_pinyin_initials(text: &str) -> String {
+    text.to_pinyin()
+        .filter_map(|p| p.map(|p| p.plain().chars().next()))
+        .flatten()
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// 获取名称的拼音（带缓存）；不含中文时返回 None
+pub fn names(text: &str) -> Option<Arc<PinyinNames>> {
+    if !contains_chinese(text) {
+        return None;
+    }
+
+    if let Ok(cache) = CACHE.read() {
+        if let Some(names) = cache.get(text) {
+            return Some(names.clone());
+        }
+    }
+
+    let names = Arc::new(PinyinNames {
+        full: to_pinyin(text),
+        initials: to_pinyin_initials(text),
+    });
+    if let Ok(mut cache) = CACHE.write() {
+        if cache.len() >= CACHE_CAPACITY {
+            cache.clear();
+        }
+        cache.insert(text.to_string(), names.clone());
+    }
+    Some(names)
+}
+
+/// 预先计算名称的拼音，返回 (全拼, 首字母)，用于写入索引条目
+pub fn precompute(text: &str) -> (Option<String>, Option<String>) {
+    match names(text) {
+        Some(names) => (Some(names.full.clone()), Some(names.initials.clone())),
+        None => (None, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_and_caches_chinese_names() {
+        assert_eq!(to_pinyin("微信"), "weixin");
+        assert_eq!(to_pinyin_initials("微信"), "wx");
+        assert_eq!(
+            precompute("微信 PC"),
+            (Some("weixin".to_string()), Some("wx".to_string()))
+        );
+        assert!(Arc::ptr_eq(&names("微信").unwrap(), &names("微信").unwrap()));
+        assert_eq!(names("WeChat"), None);
+    }
+}
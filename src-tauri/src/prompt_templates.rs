@@ -0,0 +1,359 @@
+// 提示词模板：翻译、单词解释、查词、总结、问笔记等 AI 功能使用的提示词，支持 {{变量}} 占位符
+// 每个功能内置一个默认模板，用户可以新建模板并设为当前使用，自定义模板保存在数据库
+
+use crate::db;
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const BUILTIN_PREFIX: &str = "builtin-";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptTemplate {
+    #[serde(default)]
+    pub id: String, // 新建时为空
+    pub name: String,
+    pub feature: String, // FEATURES 中的功能 id，如 "word_explain"、"translate"
+    #[serde(default)]
+    pub system: Option<String>, // 系统提示词，同样支持变量
+    pub template: String,
+    #[serde(default)]
+    pub variables: Vec<String>, // 模板中用到的变量，保存时重新提取
+    #[serde(default)]
+    pub builtin: bool,
+    #[serde(default)]
+    pub active: bool,
+}
+
+/// 功能 id 及其可用变量
+pub const FEATURES: &[(&str, &[&str])] = &[
+    ("word_explain", &["word", "translation"]),
+    ("word_lookup", &["word"]),
+    ("translate", &["text", "source_lang", "target_lang"]),
+    ("summarize", &["text"]),
+    ("rewrite_formal", &["text"]),
+    ("fix_grammar", &["text"]),
+    ("ask_notes", &["question", "context"]),
+];
+
+fn now_ts() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+fn builtin(feature: &str, name: &str, system: Option<&str>, template: &str) -> PromptTemplate {
+    PromptTemplate {
+        id: format!("{}{}", BUILTIN_PREFIX, feature),
+        name: name.to_string(),
+        feature: feature.to_string(),
+        system: system.map(str::to_string),
+        template: template.to_string(),
+        variables: extract_variables(template),
+        builtin: true,
+        active: false,
+    }
+}
+
+/// 内置默认模板
+pub fn builtin_templates() -> Vec<PromptTemplate> {
+    vec![
+        builtin(
+            "word_explain",
+            "单词详解（默认）",
+            None,
+            "请详细解释英语单词 \"{{word}}\"（中文翻译：{{translation}}）。请提供：
+1. 单词的详细含义和用法
+2. 词性（如果是动词，说明及物/不及物）
+3. 常见搭配和短语
+4. 2-3个实用的例句（中英文对照）
+5. 记忆技巧或词根词缀分析（如果有）
+请用中文回答，内容要详细且实用。",
+        ),
+        builtin(
+            "word_lookup",
+            "AI 查词（默认）",
+            None,
+            "请详细解释英语单词 \"{{word}}\"。请提供：
+1. 单词的详细含义和用法
+2. 词性（如果是动词，说明及物/不及物）
+3. 常见搭配和短语
+4. 2-3个实用的例句（中英文对照）
+5. 记忆技巧或词根词缀分析（如果有）
+请用中文回答，内容要详细且实用。",
+        ),
+        builtin(
+            "translate",
+            "翻译（默认）",
+            Some("你是一名专业译者，只输出译文，不要添加解释。"),
+            "请把以下内容从{{source_lang}}翻译成{{target_lang}}：\n\n{{text}}",
+        ),
+        builtin(
+            "summarize",
+            "总结（默认）",
+            None,
+            "请用中文简要总结以下内容，列出要点：\n\n{{text}}",
+        ),
+        builtin(
+            "rewrite_formal",
+            "正式改写（默认）",
+            Some("只输出改写后的文本，不要添加解释。"),
+            "请把以下内容改写得更正式、书面，保持原意和原文语言：\n\n{{text}}",
+        ),
+        builtin(
+            "fix_grammar",
+            "语法修正（默认）",
+            Some("只输出修正后的文本，不要添加解释。"),
+            "请修正以下内容中的语法、拼写和标点错误，保持原意、语气和原文语言：\n\n{{text}}",
+        ),
+        builtin(
+            "ask_notes",
+            "问笔记（默认）",
+            Some("你是用户的笔记助手，只根据提供的笔记回答问题。引用笔记时在句末用 [编号] 标注来源；笔记中没有相关信息时直接说明。"),
+            "笔记：\n{{context}}\n\n问题：{{question}}",
+        ),
+    ]
+}
+
+/// 提取模板中的变量名（按首次出现的顺序去重）
+pub fn extract_variables(template: &str) -> Vec<String> {
+    let mut variables: Vec<String> = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let name = after[..end].trim();
+        if !name.is_empty() && !variables.iter().any(|v| v == name) {
+            variables.push(name.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+    variables
+}
+
+/// 渲染模板：{{name}} 替换为变量值，未提供的变量替换为空字符串
+pub fn render(template: &str, variables: &HashMap<String, String>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        if let Some(value) = variables.get(after[..end].trim()) {
+            output.push_str(value);
+        }
+        rest = &after[end + 2..];
+    }
+    output.push_str(rest);
+    output
+}
+
+fn load_custom_templates(app_data_dir: &Path) -> Result<Vec<PromptTemplate>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT id, name, feature, system_prompt, template, active
+                 FROM prompt_templates ORDER BY created_at",
+            )
+            .map_err(|e| format!("Failed to prepare prompt templates query: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| {
+                let template: String = row.get(4)?;
+                Ok(PromptTemplate {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    feature: row.get(2)?,
+                    system: row.get(3)?,
+                    variables: extract_variables(&template),
+                    template,
+                    builtin: false,
+                    active: row.get(5)?,
+                })
+            })
+            .map_err(|e| format!("Failed to query prompt templates: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read prompt templates: {}", e))?;
+        Ok(rows)
+    })
+}
+
+/// 获取所有模板（内置在前）；功能没有启用的自定义模板时内置模板标记为当前使用
+pub fn list_templates(app_data_dir: &Path) -> Result<Vec<PromptTemplate>, String> {
+    let custom = load_custom_templates(app_data_dir)?;
+    let mut templates = builtin_templates();
+    for template in templates.iter_mut() {
+        template.active = !custom.iter().any(|t| t.active && t.feature == template.feature);
+    }
+    templates.extend(custom);
+    Ok(templates)
+}
+
+/// 获取功能当前使用的模板
+pub fn active_template(app_data_dir: &Path, feature: &str) -> Result<PromptTemplate, String> {
+    list_templates(app_data_dir)?
+        .into_iter()
+        .find(|t| t.active && t.feature == feature)
+        .ok_or_else(|| format!("未知的提示词功能: {}", feature))
+}
+
+/// 保存自定义模板（id 为空时新建）
+pub fn save_template(mut template: PromptTemplate, app_data_dir: &Path) -> Result<PromptTemplate, String> {
+    template.name = template.name.trim().to_string();
+    if template.name.is_empty() {
+        return Err("模板名称不能为空".to_string());
+    }
+    if template.template.trim().is_empty() {
+        return Err("模板内容不能为空".to_string());
+    }
+    let Some((_, allowed)) = FEATURES.iter().find(|(feature, _)| *feature == template.feature) else {
+        return Err(format!("未知的提示词功能: {}", template.feature));
+    };
+    if template.id.starts_with(BUILTIN_PREFIX) {
+        return Err("不能修改内置模板，请另存为新模板".to_string());
+    }
+    template.system = template.system.filter(|s| !s.trim().is_empty());
+    let mut variables = extract_variables(&template.template);
+    if let Some(system) = &template.system {
+        for name in extract_variables(system) {
+            if !variables.contains(&name) {
+                variables.push(name);
+            }
+        }
+    }
+    if let Some(unknown) = variables.iter().find(|v| !allowed.contains(&v.as_str())) {
+        return Err(format!("未知的变量 {{{{{}}}}}，可用变量: {}", unknown, allowed.join(", ")));
+    }
+    template.variables = variables;
+    template.builtin = false;
+    if template.id.is_empty() {
+        // 使用纳秒时间戳，避免同一秒内连续新建时 id 冲突
+        template.id = format!(
+            "prompt-{}",
+            SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos()
+        );
+    }
+
+    let now = now_ts();
+    let active = db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "INSERT INTO prompt_templates (id, name, feature, system_prompt, template, active, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, 0, ?6, ?6)
+             ON CONFLICT(id) DO UPDATE SET name = excluded.name, feature = excluded.feature,
+                active = CASE WHEN feature = excluded.feature THEN active ELSE 0 END,
+                system_prompt = excluded.system_prompt, template = excluded.template, updated_at = excluded.updated_at",
+        )
+        .and_then(|mut stmt| {
+            stmt.execute(params![
+                template.id,
+                template.name,
+                template.feature,
+                template.system,
+                template.template,
+                now
+            ])
+        })
+        .and_then(|_| {
+            conn.query_row(
+                "SELECT active FROM prompt_templates WHERE id = ?1",
+                params![template.id],
+                |row| row.get::<_, bool>(0),
+            )
+        })
+        .map_err(|e| format!("Failed to save prompt template: {}", e))
+    })?;
+    template.active = active;
+    Ok(template)
+}
+
+/// 删除自定义模板；删除的是当前使用的模板时该功能回到内置模板
+pub fn delete_template(id: &str, app_data_dir: &Path) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached("DELETE FROM prompt_templates WHERE id = ?1")
+            .and_then(|mut stmt| stmt.execute(params![id]))
+            .map_err(|e| format!("Failed to delete prompt template: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 设置功能当前使用的模板（内置模板 id 表示恢复默认）
+pub fn set_active_template(feature: &str, id: &str, app_data_dir: &Path) -> Result<(), String> {
+    if !FEATURES.iter().any(|(f, _)| *f == feature) {
+        return Err(format!("未知的提示词功能: {}", feature));
+    }
+    db::with_connection(app_data_dir, |conn| activate(conn, feature, id))
+}
+
+/// 在同一事务中取消该功能的其他模板并启用指定模板；模板不属于该功能时不做修改
+fn activate(conn: &Connection, feature: &str, id: &str) -> Result<(), String> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Failed to start prompt template transaction: {}", e))?;
+    tx.execute("UPDATE prompt_templates SET active = 0 WHERE feature = ?1 AND active", params![feature])
+        .map_err(|e| format!("Failed to set active prompt template: {}", e))?;
+    if !id.starts_with(BUILTIN_PREFIX) {
+        let updated = tx
+            .execute(
+                "UPDATE prompt_templates SET active = 1 WHERE id = ?1 AND feature = ?2",
+                params![id, feature],
+            )
+            .map_err(|e| format!("Failed to set active prompt template: {}", e))?;
+        if updated == 0 {
+            return Err(format!("模板不存在或不属于该功能: {}", id));
+        }
+    }
+    tx.commit()
+        .map_err(|e| format!("Failed to commit prompt template: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_template_variables() {
+        let template = "把 {{ text }} 翻译成{{target_lang}}，{{missing}}{{text}}";
+        assert_eq!(extract_variables(template), vec!["text", "target_lang", "missing"]);
+
+        let variables = HashMap::from([
+            ("text".to_string(), "hello".to_string()),
+            ("target_lang".to_string(), "中文".to_string()),
+        ]);
+        assert_eq!(render(template, &variables), "把 hello 翻译成中文，hello");
+        assert_eq!(render("未闭合 {{text", &variables), "未闭合 {{text");
+    }
+
+    #[test]
+    fn activating_template_deactivates_others() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE prompt_templates (id TEXT PRIMARY KEY, name TEXT NOT NULL, feature TEXT NOT NULL,
+                 system_prompt TEXT, template TEXT NOT NULL, active INTEGER NOT NULL DEFAULT 0,
+                 created_at INTEGER NOT NULL, updated_at INTEGER NOT NULL);
+             INSERT INTO prompt_templates VALUES ('a', 'A', 'translate', NULL, 't', 1, 0, 0);
+             INSERT INTO prompt_templates VALUES ('b', 'B', 'translate', NULL, 't', 1, 0, 0);
+             INSERT INTO prompt_templates VALUES ('c', 'C', 'summarize', NULL, 't', 1, 0, 0);",
+        )
+        .unwrap();
+        let active = |conn: &Connection| -> Vec<String> {
+            let mut stmt = conn.prepare("SELECT id FROM prompt_templates WHERE active ORDER BY id").unwrap();
+            stmt.query_map([], |row| row.get(0)).unwrap().collect::<Result<_, _>>().unwrap()
+        };
+
+        activate(&conn, "translate", "b").unwrap();
+        assert_eq!(active(&conn), vec!["b", "c"]);
+
+        // 模板属于其他功能时整体回滚
+        assert!(activate(&conn, "translate", "c").is_err());
+        assert_eq!(active(&conn), vec!["b", "c"]);
+
+        activate(&conn, "translate", "builtin-translate").unwrap();
+        assert_eq!(active(&conn), vec!["c"]);
+    }
+}
@@ -0,0 +1,130 @@
+// 搜索框查询历史：启动结果时记录输入的查询，支持类似 shell 的上下键回溯和输入补全
+
+use crate::db;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// 最多保留的历史条数，超出后删除最久未使用的记录
+const MAX_ENTRIES: i64 = 500;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryHistoryEntry {
+    pub query: String,
+    pub use_count: u32,
+    pub last_used: i64,
+}
+
+fn now_ts() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+fn read_entries(
+    stmt: &mut rusqlite::Statement,
+    params: impl rusqlite::Params,
+) -> Result<Vec<QueryHistoryEntry>, String> {
+    stmt.query_map(params, |row| {
+        Ok(QueryHistoryEntry {
+            query: row.get(0)?,
+            use_count: row.get::<_, i64>(1)? as u32,
+            last_used: row.get(2)?,
+        })
+    })
+    .map_err(|e| format!("Failed to iterate query history: {}", e))?
+    .collect::<Result<Vec<_>, _>>()
+    .map_err(|e| format!("Failed to read query history row: {}", e))
+}
+
+/// 记录一次查询（已存在则更新使用次数和时间）
+pub fn add_query(query: &str, app_data_dir: &Path) -> Result<(), String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(());
+    }
+
+    let conn = db::get_connection(app_data_dir)?;
+    conn.execute(
+        "INSERT INTO query_history (query, use_count, last_used) VALUES (?1, 1, ?2)
+         ON CONFLICT(query) DO UPDATE SET use_count = use_count + 1, last_used = excluded.last_used",
+        params![query, now_ts()],
+    )
+    .map_err(|e| format!("Failed to record query history: {}", e))?;
+
+    conn.execute(
+        "DELETE FROM query_history WHERE query NOT IN
+            (SELECT query FROM query_history ORDER BY last_used DESC LIMIT ?1)",
+        params![MAX_ENTRIES],
+    )
+    .map_err(|e| format!("Failed to trim query history: {}", e))?;
+
+    Ok(())
+}
+
+/// 获取最近的查询历史（按最近使用时间倒序）
+pub fn get_history(limit: u32, app_data_dir: &Path) -> Result<Vec<QueryHistoryEntry>, String> {
+    let conn = db::get_connection(app_data_dir)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT query, use_count, last_used FROM query_history
+             ORDER BY last_used DESC LIMIT ?1",
+        )
+        .map_err(|e| format!("Failed to prepare query history: {}", e))?;
+    read_entries(&mut stmt, params![limit])
+}
+
+/// 上下键回溯：返回以 prefix 开头的第 offset 条历史（0 为最近一条），没有更多时返回 None
+/// prefix 为空时在全部历史中回溯
+pub fn navigate(prefix: &str, offset: u32, app_data_dir: &Path) -> Result<Option<String>, String> {
+    let prefix_lower = prefix.trim_start().to_lowercase();
+    let conn = db::get_connection(app_data_dir)?;
+    let result = conn.query_row(
+        "SELECT query FROM query_history
+         WHERE substr(lower(query), 1, length(?1)) = ?1
+         ORDER BY last_used DESC LIMIT 1 OFFSET ?2",
+        params![prefix_lower, offset],
+        |row| row.get::<_, String>(0),
+    );
+
+    match result {
+        Ok(query) => Ok(Some(query)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(format!("Failed to navigate query history: {}", e)),
+    }
+}
+
+/// 输入补全：返回以 prefix 开头且比 prefix 更长的历史查询，常用的排在前面
+pub fn suggest(prefix: &str, limit: u32, app_data_dir: &Path) -> Result<Vec<QueryHistoryEntry>, String> {
+    let prefix_lower = prefix.trim_start().to_lowercase();
+    if prefix_lower.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let conn = db::get_connection(app_data_dir)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT query, use_count, last_used FROM query_history
+             WHERE substr(lower(query), 1, length(?1)) = ?1 AND length(query) > length(?1)
+             ORDER BY use_count DESC, last_used DESC LIMIT ?2",
+        )
+        .map_err(|e| format!("Failed to prepare query suggestions: {}", e))?;
+    read_entries(&mut stmt, params![prefix_lower, limit])
+}
+
+/// 删除单条查询历史
+pub fn delete_query(query: &str, app_data_dir: &Path) -> Result<(), String> {
+    let conn = db::get_connection(app_data_dir)?;
+    conn.execute("DELETE FROM query_history WHERE query = ?1", params![query])
+        .map_err(|e| format!("Failed to delete query history: {}", e))?;
+    Ok(())
+}
+
+/// 清空查询历史，返回删除的条数
+pub fn clear(app_data_dir: &Path) -> Result<usize, String> {
+    let conn = db::get_connection(app_data_dir)?;
+    conn.execute("DELETE FROM query_history", [])
+        .map_err(|e| format!("Failed to clear query history: {}", e))
+}
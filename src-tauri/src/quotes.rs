@@ -0,0 +1,250 @@
+// 行情查询：股票、外汇与加密货币的最新价和当日涨跌，数据来自 Yahoo Finance 图表接口（无需 API Key）
+// 报价按设置中的刷新间隔缓存，请求失败时返回上次的缓存并标记为过期；关注列表保存在设置 quote_watchlist 中
+
+use crate::api_policy::{self, Policy};
+use futures_util::future::join_all;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const PROVIDER: &str = "query1.finance.yahoo.com";
+const CHART_URL: &str = "https://query1.finance.yahoo.com/v8/finance/chart/";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(8);
+/// 刷新间隔下限，避免过于频繁地请求
+const MIN_REFRESH: Duration = Duration::from_secs(15);
+
+const CURRENCIES: &[&str] = &[
+    "USD", "EUR", "JPY", "GBP", "CNY", "CNH", "HKD", "TWD", "KRW", "SGD", "AUD", "NZD", "CAD", "CHF", "SEK", "NOK",
+    "DKK", "RUB", "INR", "THB", "MYR", "IDR", "PHP", "VND", "BRL", "MXN", "ZAR", "TRY", "AED", "SAR",
+];
+const CRYPTOS: &[&str] = &[
+    "BTC", "ETH", "SOL", "BNB", "XRP", "DOGE", "ADA", "TRX", "TON", "AVAX", "DOT", "LINK", "LTC", "BCH", "SHIB", "USDT", "USDC",
+];
+
+// 以数据源代码为键的报价缓存
+static QUOTE_CACHE: LazyLock<Mutex<HashMap<String, (Instant, Quote)>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteKind {
+    Stock,
+    Forex,
+    Crypto,
+}
+
+/// 规范化后的代码：display 用于显示，source 为数据源使用的代码
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuoteSymbol {
+    pub display: String,
+    pub source: String,
+    pub kind: QuoteKind,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Quote {
+    pub symbol: String,
+    pub source_symbol: String,
+    pub kind: QuoteKind,
+    pub name: Option<String>,
+    pub price: f64,
+    pub previous_close: Option<f64>,
+    pub change: Option<f64>,
+    pub change_percent: Option<f64>,
+    pub currency: Option<String>,
+    pub market_time: Option<i64>, // 行情时间（Unix 秒）
+    pub fetched_at: i64,          // 获取时间（Unix 秒）
+}
+
+/// 单个代码的查询结果：失败时 quote 为上次缓存（stale 为 true）或为空
+#[derive(Debug, Clone, Serialize)]
+pub struct QuoteResult {
+    pub input: String,
+    pub quote: Option<Quote>,
+    pub stale: bool,
+    pub error: Option<String>,
+}
+
+/// 把用户输入规范化为数据源代码：
+/// - "usd/jpy"、"USDJPY" → 外汇 USDJPY=X
+/// - "btc"、"eth/eur" → 加密货币 BTC-USD、ETH-EUR
+/// - 6 位 A 股代码补全交易所后缀，5 位港股代码转为 0700.HK 形式
+/// - 其他按股票代码原样使用（如 AAPL、BRK-B、^GSPC）
+pub fn normalize(input: &str) -> Result<QuoteSymbol, String> {
+    let upper = input.trim().trim_start_matches('$').to_ascii_uppercase();
+    let symbol = |display: String, source: String, kind| Ok(QuoteSymbol { display, source, kind });
+
+    if let Some((base, quote)) = upper.split_once(['/', '-']) {
+        if CRYPTOS.contains(&base) && (CURRENCIES.contains(&quote) || CRYPTOS.contains(&quote)) {
+            // 稳定币报价按美元处理
+            let quote = if quote.starts_with("USD") { "USD" } else { quote };
+            return symbol(format!("{}/{}", base, quote), format!("{}-{}", base, quote), QuoteKind::Crypto);
+        }
+        if CURRENCIES.contains(&base) && CURRENCIES.contains(&quote) {
+            return symbol(format!("{}/{}", base, quote), format!("{}{}=X", base, quote), QuoteKind::Forex);
+        }
+    }
+    if upper.len() == 6 && CURRENCIES.contains(&&upper[..3]) && CURRENCIES.contains(&&upper[3..]) {
+        return symbol(format!("{}/{}", &upper[..3], &upper[3..]), format!("{}=X", upper), QuoteKind::Forex);
+    }
+    if CRYPTOS.contains(&upper.as_str()) {
+        return symbol(upper.clone(), format!("{}-USD", upper), QuoteKind::Crypto);
+    }
+    if upper.chars().all(|c| c.is_ascii_digit()) {
+        let suffix = match (upper.len(), upper.as_bytes().first()) {
+            (6, Some(b'6' | b'9')) => Some("SS"),
+            (6, Some(b'0' | b'2' | b'3')) => Some("SZ"),
+            (6, Some(b'4' | b'8')) => Some("BJ"),
+            _ => None,
+        };
+        if let Some(suffix) = suffix {
+            return symbol(upper.clone(), format!("{}.{}", upper, suffix), QuoteKind::Stock);
+        }
+        if (4..=5).contains(&upper.len()) {
+            let code: u32 = upper.parse().map_err(|_| format!("无法识别的代码: {}", input.trim()))?;
+            return symbol(upper.clone(), format!("{:04}.HK", code), QuoteKind::Stock);
+        }
+    }
+    let valid = (1..=15).contains(&upper.len())
+        && upper.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '^' | '=' | '-'));
+    if valid {
+        return symbol(upper.clone(), upper, QuoteKind::Stock);
+    }
+    Err(format!("无法识别的代码: {}", input.trim()))
+}
+
+fn now_secs() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs() as i64
+}
+
+/// 解析图表接口返回的 meta 信息
+pub fn parse_chart(body: &str, symbol: &QuoteSymbol) -> Result<Quote, String> {
+    let value: serde_json::Value = serde_json::from_str(body).map_err(|e| format!("行情数据格式错误: {}", e))?;
+    let chart = &value["chart"];
+    if let Some(description) = chart["error"]["description"].as_str() {
+        return Err(format!("{}: {}", symbol.display, description));
+    }
+    let meta = &chart["result"][0]["meta"];
+    let price = meta["regularMarketPrice"]
+        .as_f64()
+        .ok_or_else(|| format!("{}: 没有行情数据", symbol.display))?;
+    let previous_close = meta["previousClose"].as_f64().or_else(|| meta["chartPreviousClose"].as_f64());
+    let change = previous_close.map(|prev| price - prev);
+    let text = |key: &str| meta[key].as_str().map(str::to_string).filter(|s| !s.is_empty());
+    Ok(Quote {
+        symbol: symbol.display.clone(),
+        source_symbol: symbol.source.clone(),
+        kind: symbol.kind,
+        name: text("shortName").or_else(|| text("longName")),
+        price,
+        previous_close,
+        change,
+        change_percent: previous_close.filter(|prev| *prev != 0.0).map(|prev| (price - prev) / prev * 100.0),
+        currency: text("currency"),
+        market_time: meta["regularMarketTime"].as_i64(),
+        fetched_at: now_secs(),
+    })
+}
+
+fn cached(source: &str, max_age: Option<Duration>) -> Option<Quote> {
+    let cache = QUOTE_CACHE.lock().ok()?;
+    cache
+        .get(source)
+        .filter(|(cached_at, _)| max_age.map_or(true, |max_age| cached_at.elapsed() < max_age))
+        .map(|(_, quote)| quote.clone())
+}
+
+async fn fetch(client: &reqwest::Client, symbol: &QuoteSymbol) -> Result<Quote, String> {
+    let mut url = reqwest::Url::parse(CHART_URL).map_err(|e| format!("Failed to build quote url: {}", e))?;
+    url.path_segments_mut()
+        .map_err(|_| "Failed to build quote url".to_string())?
+        .pop_if_empty()
+        .push(&symbol.source);
+    url.query_pairs_mut().append_pair("range", "1d").append_pair("interval", "1d");
+    let body = api_policy::send(PROVIDER, &Policy::QUOTES, || {
+        client.get(url.clone()).header(reqwest::header::USER_AGENT, "Mozilla/5.0")
+    })
+    .await
+    .map_err(|e| format!("行情请求失败: {}", e))?
+    .text()
+    .await
+    .map_err(|e| format!("读取行情失败: {}", e))?;
+    let quote = parse_chart(&body, symbol)?;
+    if let Ok(mut cache) = QUOTE_CACHE.lock() {
+        cache.insert(symbol.source.clone(), (Instant::now(), quote.clone()));
+    }
+    Ok(quote)
+}
+
+async fn quote_for(client: Option<&reqwest::Client>, input: &str, refresh: Duration, force: bool) -> QuoteResult {
+    let failed = |error: String, stale: Option<Quote>| QuoteResult {
+        input: input.to_string(),
+        stale: stale.is_some(),
+        quote: stale,
+        error: Some(error),
+    };
+    let symbol = match normalize(input) {
+        Ok(symbol) => symbol,
+        Err(e) => return failed(e, None),
+    };
+    if !force {
+        if let Some(quote) = cached(&symbol.source, Some(refresh)) {
+            return QuoteResult { input: input.to_string(), quote: Some(quote), stale: false, error: None };
+        }
+    }
+    let result = match client {
+        Some(client) => fetch(client, &symbol).await,
+        None => Err("当前处于离线状态".to_string()),
+    };
+    match result {
+        Ok(quote) => QuoteResult { input: input.to_string(), quote: Some(quote), stale: false, error: None },
+        Err(e) => failed(e, cached(&symbol.source, None)),
+    }
+}
+
+/// 批量获取报价；refresh 内已获取过的代码直接使用缓存，force 时忽略缓存
+pub async fn get_quotes(inputs: &[String], refresh: Duration, force: bool) -> Vec<QuoteResult> {
+    let refresh = refresh.max(MIN_REFRESH);
+    let client = if crate::network::is_online() {
+        crate::http_client::builder().and_then(|b| b.timeout(REQUEST_TIMEOUT).build().map_err(|e| e.to_string())).ok()
+    } else {
+        None
+    };
+    join_all(inputs.iter().map(|input| quote_for(client.as_ref(), input, refresh, force))).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_symbols() {
+        let source = |input: &str| normalize(input).unwrap().source;
+        assert_eq!(source("usd/jpy"), "USDJPY=X");
+        assert_eq!(source("EURCNY"), "EURCNY=X");
+        assert_eq!(source("btc"), "BTC-USD");
+        assert_eq!(source("eth/usdt"), "ETH-USD");
+        assert_eq!(source("$aapl"), "AAPL");
+        assert_eq!(source("brk-b"), "BRK-B");
+        assert_eq!(source("600519"), "600519.SS");
+        assert_eq!(source("000001"), "000001.SZ");
+        assert_eq!(source("00700"), "0700.HK");
+        assert_eq!(normalize("usd/jpy").unwrap().display, "USD/JPY");
+        assert!(normalize("not a symbol").is_err());
+    }
+
+    #[test]
+    fn parses_chart_meta() {
+        let symbol = normalize("aapl").unwrap();
+        let body = r#"{"chart":{"result":[{"meta":{"currency":"USD","symbol":"AAPL","regularMarketPrice":210.5,
+            "chartPreviousClose":200.0,"regularMarketTime":1760000000,"shortName":"Apple Inc."}}],"error":null}}"#;
+        let quote = parse_chart(body, &symbol).unwrap();
+        assert_eq!(quote.price, 210.5);
+        assert_eq!(quote.change, Some(10.5));
+        assert!((quote.change_percent.unwrap() - 5.25).abs() < 1e-9);
+        assert_eq!(quote.name.as_deref(), Some("Apple Inc."));
+
+        let error = r#"{"chart":{"result":null,"error":{"code":"Not Found","description":"No data found, symbol may be delisted"}}}"#;
+        assert!(parse_chart(error, &symbol).unwrap_err().contains("delisted"));
+    }
+}
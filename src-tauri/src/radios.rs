@@ -0,0 +1,226 @@
+// 无线开关：Wi-Fi、蓝牙与飞行模式，供启动器的 "wifi"、"蓝牙"、"飞行模式" 查询使用
+// Wi-Fi 与蓝牙通过 Windows.Devices.Radios 切换；飞行模式使用系统设置同样调用的 IRadioManager 接口（未公开文档）
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RadioKind {
+    Wifi,
+    Bluetooth,
+    MobileBroadband,
+    Other,
+}
+
+impl RadioKind {
+    /// 解析前端或查询中的类型名称
+    pub fn parse(name: &str) -> Option<RadioKind> {
+        match name.trim().to_lowercase().replace(['-', ' '], "").as_str() {
+            "wifi" | "wlan" | "无线" => Some(RadioKind::Wifi),
+            "bluetooth" | "bt" | "蓝牙" => Some(RadioKind::Bluetooth),
+            "mobilebroadband" | "cellular" | "移动网络" => Some(RadioKind::MobileBroadband),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RadioInfo {
+    pub kind: RadioKind,
+    pub name: String,
+    pub on: bool,
+    pub disabled: bool, // 被硬件开关或驱动禁用，无法通过软件打开
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RadioStatus {
+    pub radios: Vec<RadioInfo>,
+    pub airplane_mode: Option<bool>, // 系统不支持飞行模式时为 None
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::{RadioInfo, RadioKind};
+    use ::windows::core::GUID;
+    use ::windows::Devices::Radios::{Radio, RadioAccessStatus, RadioKind as WinRadioKind, RadioState};
+    use ::windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_ALL, COINIT_MULTITHREADED};
+
+    const CLSID_RADIO_MANAGEMENT_API: GUID = GUID::from_u128(0x581333f6_28db_41be_bc7a_ff201f12f3f6);
+
+    // 只调用 GetSystemRadioState / SetSystemRadioState，其余方法仅用于保持虚表顺序
+    #[allow(non_snake_case)]
+    mod radio_manager {
+        use ::windows::core::{interface, IUnknown, IUnknown_Vtbl, HRESULT};
+        use std::ffi::c_void;
+
+        #[interface("db3afbfb-08e6-46c6-aa70-bf9a34c30ab7")]
+        pub unsafe trait IRadioManager: IUnknown {
+            fn IsRMSupported(&self, supported: *mut u32) -> HRESULT;
+            fn GetUIRadioInstances(&self, instances: *mut *mut c_void) -> HRESULT;
+            fn GetSystemRadioState(&self, enabled: *mut i32, policy_enabled: *mut i32, reason: *mut i32) -> HRESULT;
+            fn SetSystemRadioState(&self, enabled: i32) -> HRESULT;
+            fn Refresh(&self) -> HRESULT;
+            fn OnHardwareSliderChange(&self, state: i32, reserved: i32) -> HRESULT;
+        }
+
+        /// 系统无线是否启用（即飞行模式是否关闭）
+        pub fn system_radio_enabled(manager: &IRadioManager) -> ::windows::core::Result<bool> {
+            let (mut enabled, mut policy_enabled, mut reason) = (0i32, 0i32, 0i32);
+            unsafe { manager.GetSystemRadioState(&mut enabled, &mut policy_enabled, &mut reason) }.ok()?;
+            Ok(enabled != 0)
+        }
+
+        pub fn set_system_radio_enabled(manager: &IRadioManager, enabled: bool) -> ::windows::core::Result<()> {
+            unsafe { manager.SetSystemRadioState(enabled as i32) }.ok()
+        }
+    }
+    use radio_manager::IRadioManager;
+
+    /// 在当前线程初始化 COM（WinRT 也需要），离开作用域时释放
+    struct ComGuard(bool);
+
+    impl ComGuard {
+        fn new() -> Self {
+            ComGuard(unsafe { CoInitializeEx(None, COINIT_MULTITHREADED) }.is_ok())
+        }
+    }
+
+    impl Drop for ComGuard {
+        fn drop(&mut self) {
+            if self.0 {
+                unsafe { CoUninitialize() };
+            }
+        }
+    }
+
+    fn kind_of(kind: WinRadioKind) -> RadioKind {
+        match kind {
+            WinRadioKind::WiFi => RadioKind::Wifi,
+            WinRadioKind::Bluetooth => RadioKind::Bluetooth,
+            WinRadioKind::MobileBroadband => RadioKind::MobileBroadband,
+            _ => RadioKind::Other,
+        }
+    }
+
+    fn radios() -> Result<Vec<Radio>, String> {
+        let radios = Radio::GetRadiosAsync()
+            .and_then(|op| op.get())
+            .map_err(|e| format!("Failed to enumerate radios: {}", e))?;
+        Ok(radios.into_iter().collect())
+    }
+
+    fn radio_manager() -> Result<IRadioManager, String> {
+        unsafe { CoCreateInstance(&CLSID_RADIO_MANAGEMENT_API, None, CLSCTX_ALL) }
+            .map_err(|e| format!("Failed to create radio manager: {}", e))
+    }
+
+    pub fn list_radios() -> Result<Vec<RadioInfo>, String> {
+        let _com = ComGuard::new();
+        let mut result = Vec::new();
+        for radio in radios()? {
+            let state = radio.State().unwrap_or(RadioState::Unknown);
+            result.push(RadioInfo {
+                kind: kind_of(radio.Kind().unwrap_or(WinRadioKind::Other)),
+                name: radio.Name().map(|n| n.to_string()).unwrap_or_default(),
+                on: state == RadioState::On,
+                disabled: state == RadioState::Disabled,
+            });
+        }
+        Ok(result)
+    }
+
+    pub fn set_radio(kind: RadioKind, on: bool) -> Result<usize, String> {
+        let _com = ComGuard::new();
+        let access = Radio::RequestAccessAsync()
+            .and_then(|op| op.get())
+            .map_err(|e| format!("Failed to request radio access: {}", e))?;
+        if access != RadioAccessStatus::Allowed {
+            return Err("系统拒绝了无线开关的访问权限，请在隐私设置中允许应用控制无线设备".to_string());
+        }
+        let target = if on { RadioState::On } else { RadioState::Off };
+        let mut changed = 0;
+        for radio in radios()? {
+            if radio.Kind().map(kind_of).ok() != Some(kind) {
+                continue;
+            }
+            let status = radio
+                .SetStateAsync(target)
+                .and_then(|op| op.get())
+                .map_err(|e| format!("切换无线状态失败: {}", e))?;
+            if status != RadioAccessStatus::Allowed {
+                return Err("系统拒绝切换无线状态（可能处于飞行模式或被硬件开关禁用）".to_string());
+            }
+            changed += 1;
+        }
+        Ok(changed)
+    }
+
+    pub fn airplane_mode() -> Option<bool> {
+        let _com = ComGuard::new();
+        let manager = radio_manager().ok()?;
+        radio_manager::system_radio_enabled(&manager).ok().map(|enabled| !enabled)
+    }
+
+    pub fn set_airplane_mode(on: bool) -> Result<(), String> {
+        let _com = ComGuard::new();
+        let manager = radio_manager()?;
+        radio_manager::set_system_radio_enabled(&manager, !on).map_err(|e| format!("切换飞行模式失败: {}", e))
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    use super::{RadioInfo, RadioKind};
+
+    const UNSUPPORTED: &str = "当前平台不支持无线开关";
+
+    pub fn list_radios() -> Result<Vec<RadioInfo>, String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn set_radio(_kind: RadioKind, _on: bool) -> Result<usize, String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn airplane_mode() -> Option<bool> {
+        None
+    }
+
+    pub fn set_airplane_mode(_on: bool) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+}
+
+pub use platform::set_airplane_mode;
+
+/// 当前各无线设备与飞行模式的状态
+pub fn status() -> Result<RadioStatus, String> {
+    Ok(RadioStatus { radios: platform::list_radios()?, airplane_mode: platform::airplane_mode() })
+}
+
+/// 打开或关闭某一类无线设备（如全部 Wi-Fi 网卡）
+pub fn set_radio(kind: &str, on: bool) -> Result<(), String> {
+    let kind = RadioKind::parse(kind).ok_or_else(|| format!("未知的无线类型: {}", kind))?;
+    if platform::set_radio(kind, on)? == 0 {
+        return Err(match kind {
+            RadioKind::Wifi => "未找到 Wi-Fi 设备".to_string(),
+            RadioKind::Bluetooth => "未找到蓝牙设备".to_string(),
+            _ => "未找到对应的无线设备".to_string(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_radio_kind_names() {
+        assert_eq!(RadioKind::parse("Wi-Fi"), Some(RadioKind::Wifi));
+        assert_eq!(RadioKind::parse("wifi"), Some(RadioKind::Wifi));
+        assert_eq!(RadioKind::parse("蓝牙"), Some(RadioKind::Bluetooth));
+        assert_eq!(RadioKind::parse("mobile broadband"), Some(RadioKind::MobileBroadband));
+        assert_eq!(RadioKind::parse("fm"), None);
+    }
+}
@@ -0,0 +1,165 @@
+// 个性化排序：基于使用统计中的启动记录，学习「输入前缀 -> 选择的结果」
+// 简单的频率 + 时间衰减模型：越常在该前缀下选择、越近期选择的结果，加权越高
+
+use crate::db;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DAY_SECS: f64 = 24.0 * 60.0 * 60.0;
+// 时间衰减半衰期（天）
+const HALF_LIFE_DAYS: f64 = 30.0;
+// 只考虑最近一段时间的记录
+const LOOKBACK_DAYS: i64 = 180;
+// 历史查询比当前输入更长（当前输入是其前缀）时的权重
+const EXTENDED_QUERY_WEIGHT: f64 = 0.6;
+// 低于该分值的结果不返回，避免偶然的一次选择影响排序
+const MIN_SCORE: f64 = 0.5;
+const MAX_BOOSTS: usize = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RankingBoost {
+    pub result_id: String,
+    pub score: f64,       // 原始分值（衰减后的加权次数）
+    pub boost: f64,       // 归一化到 0~1，供前端排序使用
+    pub launch_count: u32,
+}
+
+fn decay(age_secs: i64) -> f64 {
+    let age_days = age_secs.max(0) as f64 / DAY_SECS;
+    0.5_f64.powf(age_days / HALF_LIFE_DAYS)
+}
+
+/// 根据 (历史查询, 结果 id, 启动时间) 计算当前输入的加权分值
+pub fn score_launches(
+    query: &str,
+    launches: &[(String, String, i64)],
+    now: i64,
+) -> Vec<RankingBoost> {
+    let query_lower = query.trim().to_lowercase();
+    if query_lower.is_empty() {
+        return Vec::new();
+    }
+
+    let mut scores: HashMap<&str, (f64, u32)> = HashMap::new();
+    for (past_query, result_id, created_at) in launches {
+        let past_lower = past_query.trim().to_lowercase();
+        let weight = if past_lower == query_lower {
+            1.0
+        } else if past_lower.starts_with(&query_lower) {
+            EXTENDED_QUERY_WEIGHT
+        } else {
+            continue;
+        };
+        let entry = scores.entry(result_id.as_str()).or_insert((0.0, 0));
+        entry.0 += weight * decay(now - created_at);
+        entry.1 += 1;
+    }
+
+    let max_score = scores.values().map(|(s, _)| *s).fold(0.0, f64::max);
+    let mut boosts: Vec<RankingBoost> = scores
+        .into_iter()
+        .filter(|(_, (score, _))| *score >= MIN_SCORE)
+        .map(|(result_id, (score, launch_count))| RankingBoost {
+            result_id: result_id.to_string(),
+            score,
+            boost: if max_score > 0.0 { score / max_score } else { 0.0 },
+            launch_count,
+        })
+        .collect();
+
+    boosts.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    boosts.truncate(MAX_BOOSTS);
+    boosts
+}
+
+/// 获取当前输入对应的个性化加权结果
+pub fn get_ranking_boosts(query: &str, app_data_dir: &Path) -> Result<Vec<RankingBoost>, String> {
+    let query_lower = query.trim().to_lowercase();
+    if query_lower.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+
+    let conn = db::get_connection(app_data_dir)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT query, result_id, created_at FROM activity_log
+             WHERE event_type = 'launch'
+               AND result_id IS NOT NULL
+               AND query IS NOT NULL
+               AND created_at >= ?1
+               AND substr(lower(query), 1, length(?2)) = ?2",
+        )
+        .map_err(|e| format!("Failed to prepare ranking query: {}", e))?;
+
+    let launches = stmt
+        .query_map(params![now - LOOKBACK_DAYS * 24 * 60 * 60, query_lower], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+            ))
+        })
+        .map_err(|e| format!("Failed to iterate ranking rows: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read ranking row: {}", e))?;
+
+    Ok(score_launches(&query_lower, &launches, now))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn launch(query: &str, result_id: &str, days_ago: i64) -> (String, String, i64) {
+        (query.to_string(), result_id.to_string(), NOW - days_ago * DAY)
+    }
+
+    #[test]
+    fn decays_by_half_life() {
+        assert_eq!(decay(0), 1.0);
+        assert!((decay(30 * DAY) - 0.5).abs() < 1e-9);
+        assert!((decay(60 * DAY) - 0.25).abs() < 1e-9);
+        // 时间在未来（时钟回拨）时不放大分值
+        assert_eq!(decay(-DAY), 1.0);
+    }
+
+    #[test]
+    fn orders_by_frequency_and_recency() {
+        let launches = vec![
+            launch("co", "code", 0),
+            launch("co", "code", 1),
+            launch("co", "chrome", 0),
+            launch("CO ", "old", 90),
+            launch("co", "old", 90),
+            launch("co", "old", 90),
+        ];
+        let boosts = score_launches("co", &launches, NOW);
+        let ids: Vec<&str> = boosts.iter().map(|b| b.result_id.as_str()).collect();
+        // old 选了三次，但 90 天前的每次只剩 1/8，低于阈值被过滤
+        assert_eq!(ids, vec!["code", "chrome"]);
+        assert_eq!(boosts[0].boost, 1.0);
+        assert_eq!(boosts[0].launch_count, 2);
+        assert!(boosts[1].boost > 0.0 && boosts[1].boost < 1.0);
+    }
+
+    #[test]
+    fn weights_longer_past_queries_lower() {
+        let launches = vec![launch("vs", "exact", 0), launch("vscode", "extended", 0), launch("v", "shorter", 0)];
+        let boosts = score_launches("vs", &launches, NOW);
+        let ids: Vec<&str> = boosts.iter().map(|b| b.result_id.as_str()).collect();
+        assert_eq!(ids, vec!["exact", "extended"]);
+        assert!((boosts[1].score - EXTENDED_QUERY_WEIGHT).abs() < 1e-9);
+        assert!(score_launches("  ", &launches, NOW).is_empty());
+    }
+}
@@ -0,0 +1,401 @@
+// 最近项目：读取 VS Code（storage.json / state.vscdb）与 JetBrains（recentProjects.xml）的最近打开记录
+// 打开时根据应用索引查找对应 IDE 的可执行文件
+
+use crate::app_search::AppInfo;
+use crate::open_with;
+use crate::path_expand;
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RecentProject {
+    pub name: String,
+    pub path: String,
+    pub kind: String,              // "folder" | "workspace" | "file"
+    pub ide: String,               // "vscode" | "jetbrains"
+    pub ide_name: String,          // 显示名称，如 "Visual Studio Code", "PyCharm"
+    pub product: String,           // 产品标识（配置目录名前缀），打开时用于查找可执行文件
+    pub last_opened: Option<i64>,  // 毫秒时间戳（VS Code 不记录时间，按列表顺序即最近优先）
+    pub exists: bool,
+}
+
+// VS Code 系列：配置目录名, 显示名称, 可执行文件名, 命令行工具
+const VSCODE_FLAVORS: &[(&str, &str, &str, &str)] = &[
+    ("Code", "Visual Studio Code", "Code.exe", "code"),
+    ("Code - Insiders", "Visual Studio Code - Insiders", "Code - Insiders.exe", "code-insiders"),
+    ("VSCodium", "VSCodium", "VSCodium.exe", "codium"),
+    ("Cursor", "Cursor", "Cursor.exe", "cursor"),
+];
+
+// JetBrains 系列：配置目录名前缀, 显示名称, 可执行文件名
+const JETBRAINS_PRODUCTS: &[(&str, &str, &str)] = &[
+    ("IntelliJIdea", "IntelliJ IDEA", "idea64.exe"),
+    ("IdeaIC", "IntelliJ IDEA Community", "idea64.exe"),
+    ("PyCharm", "PyCharm", "pycharm64.exe"),
+    ("PyCharmCE", "PyCharm Community", "pycharm64.exe"),
+    ("WebStorm", "WebStorm", "webstorm64.exe"),
+    ("GoLand", "GoLand", "goland64.exe"),
+    ("CLion", "CLion", "clion64.exe"),
+    ("PhpStorm", "PhpStorm", "phpstorm64.exe"),
+    ("Rider", "Rider", "rider64.exe"),
+    ("RubyMine", "RubyMine", "rubymine64.exe"),
+    ("DataGrip", "DataGrip", "datagrip64.exe"),
+    ("RustRover", "RustRover", "rustrover64.exe"),
+];
+
+fn appdata_dir() -> Option<PathBuf> {
+    env::var("APPDATA").ok().map(PathBuf::from)
+}
+
+/// 百分号解码（file URI 中的路径）
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+/// file:///c%3A/Users/a/proj -> c:\Users\a\proj；非本地 URI（如 vscode-remote://）返回 None
+pub fn file_uri_to_path(uri: &str) -> Option<String> {
+    let rest = uri.strip_prefix("file://")?;
+    let decoded = percent_decode(rest);
+    // 去掉盘符前的 /（/c:/...）
+    let trimmed = match decoded.as_bytes() {
+        [b'/', drive, b':', ..] if drive.is_ascii_alphabetic() => &decoded[1..],
+        _ => decoded.as_str(),
+    };
+    if cfg!(target_os = "windows") {
+        if trimmed.starts_with('/') {
+            // file://server/share 形式的 UNC 路径
+            return Some(format!("\\\\{}", trimmed.trim_start_matches('/').replace('/', "\\")));
+        }
+        Some(trimmed.replace('/', "\\"))
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+fn display_name(path: &str) -> String {
+    Path::new(path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string())
+}
+
+/// 解析 VS Code 最近打开列表（history.recentlyOpenedPathsList / openedPathsList 的 JSON 值）
+pub fn parse_vscode_entries(value: &serde_json::Value) -> Vec<(String, String)> {
+    let entries = match value.get("entries").and_then(|e| e.as_array()) {
+        Some(entries) => entries,
+        None => return Vec::new(),
+    };
+
+    entries
+        .iter()
+        .filter_map(|entry| {
+            if let Some(uri) = entry.get("folderUri").and_then(|v| v.as_str()) {
+                return file_uri_to_path(uri).map(|p| (p, "folder".to_string()));
+            }
+            if let Some(uri) = entry
+                .get("workspace")
+                .and_then(|w| w.get("configPath"))
+                .and_then(|v| v.as_str())
+            {
+                return file_uri_to_path(uri).map(|p| (p, "workspace".to_string()));
+            }
+            if let Some(uri) = entry.get("fileUri").and_then(|v| v.as_str()) {
+                return file_uri_to_path(uri).map(|p| (p, "file".to_string()));
+            }
+            None
+        })
+        .collect()
+}
+
+fn read_vscode_history(user_dir: &Path) -> Option<serde_json::Value> {
+    // 新版本存储在 state.vscdb（SQLite），旧版本在 storage.json
+    let db_path = user_dir.join("globalStorage").join("state.vscdb");
+    if db_path.exists() {
+        let from_db = Connection::open_with_flags(&db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .ok()
+            .and_then(|conn| {
+                conn.query_row(
+                    "SELECT value FROM ItemTable WHERE key = 'history.recentlyOpenedPathsList'",
+                    [],
+                    |row| row.get::<_, String>(0),
+                )
+                .optional()
+                .ok()
+                .flatten()
+            })
+            .and_then(|json| serde_json::from_str(&json).ok());
+        if from_db.is_some() {
+            return from_db;
+        }
+    }
+
+    let storage_path = user_dir.join("globalStorage").join("storage.json");
+    let content = fs::read_to_string(storage_path).ok()?;
+    let storage: serde_json::Value = serde_json::from_str(&content).ok()?;
+    storage.get("openedPathsList").cloned()
+}
+
+fn vscode_projects() -> Vec<RecentProject> {
+    let appdata = match appdata_dir() {
+        Some(dir) => dir,
+        None => return Vec::new(),
+    };
+
+    let mut projects = Vec::new();
+    for (dir_name, ide_name, _, _) in VSCODE_FLAVORS {
+        let user_dir = appdata.join(dir_name).join("User");
+        let history = match read_vscode_history(&user_dir) {
+            Some(history) => history,
+            None => continue,
+        };
+        for (path, kind) in parse_vscode_entries(&history) {
+            projects.push(RecentProject {
+                name: display_name(&path),
+                exists: Path::new(&path).exists(),
+                path,
+                kind,
+                ide: "vscode".to_string(),
+                ide_name: ide_name.to_string(),
+                product: dir_name.to_string(),
+                last_opened: None,
+            });
+        }
+    }
+    projects
+}
+
+fn xml_unescape(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// 解析 recentProjects.xml，返回 (路径, 打开时间毫秒)
+pub fn parse_jetbrains_recent(content: &str, user_home: &str) -> Vec<(String, Option<i64>)> {
+    let mut results = Vec::new();
+
+    // 新格式：<entry key="$USER_HOME$/proj"><value><RecentProjectMetaInfo ...>
+    for segment in content.split("<entry key=\"").skip(1) {
+        let key = match segment.split('"').next() {
+            Some(key) => key,
+            None => continue,
+        };
+        let block = segment.split("</entry>").next().unwrap_or(segment);
+        if !block.contains("RecentProjectMetaInfo") {
+            continue;
+        }
+        let timestamp = block
+            .split("name=\"projectOpenTimestamp\" value=\"")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .and_then(|v| v.parse::<i64>().ok());
+        results.push((key.to_string(), timestamp));
+    }
+
+    // 旧格式：<option name="recentPaths"><list><option value="..." /></list></option>
+    if results.is_empty() {
+        if let Some(section) = content.split("name=\"recentPaths\"").nth(1) {
+            let section = section.split("</list>").next().unwrap_or(section);
+            for part in section.split("<option value=\"").skip(1) {
+                if let Some(value) = part.split('"').next() {
+                    results.push((value.to_string(), None));
+                }
+            }
+        }
+    }
+
+    results
+        .into_iter()
+        .map(|(path, ts)| {
+            let path = xml_unescape(&path).replace("$USER_HOME$", user_home);
+            let path = if cfg!(target_os = "windows") {
+                path.replace('/', "\\")
+            } else {
+                path
+            };
+            (path, ts)
+        })
+        .collect()
+}
+
+/// IntelliJIdea2024.1 -> IntelliJIdea
+fn jetbrains_product_prefix(dir_name: &str) -> &str {
+    dir_name.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.')
+}
+
+fn jetbrains_projects() -> Vec<RecentProject> {
+    let jetbrains_dir = match appdata_dir() {
+        Some(dir) => dir.join("JetBrains"),
+        None => return Vec::new(),
+    };
+    let user_home = path_expand::home_dir()
+        .map(|h| h.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let entries = match fs::read_dir(&jetbrains_dir) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut projects = Vec::new();
+    for entry in entries.filter_map(|e| e.ok()) {
+        let dir_name = entry.file_name().to_string_lossy().to_string();
+        let prefix = jetbrains_product_prefix(&dir_name);
+        let (product, ide_name, _) = match JETBRAINS_PRODUCTS.iter().find(|(p, _, _)| *p == prefix) {
+            Some(found) => found,
+            None => continue,
+        };
+
+        let options_dir = entry.path().join("options");
+        // Rider 使用 recentSolutions.xml
+        for file_name in ["recentProjects.xml", "recentSolutions.xml"] {
+            let content = match fs::read_to_string(options_dir.join(file_name)) {
+                Ok(content) => content,
+                Err(_) => continue,
+            };
+            for (path, last_opened) in parse_jetbrains_recent(&content, &user_home) {
+                projects.push(RecentProject {
+                    name: display_name(&path),
+                    exists: Path::new(&path).exists(),
+                    path,
+                    kind: "folder".to_string(),
+                    ide: "jetbrains".to_string(),
+                    ide_name: ide_name.to_string(),
+                    product: product.to_string(),
+                    last_opened,
+                });
+            }
+        }
+    }
+
+    // 同一产品多个版本目录会包含重复项目，按打开时间倒序后保留最新的
+    projects.sort_by_key(|p| std::cmp::Reverse(p.last_opened));
+    projects
+}
+
+/// 获取所有最近项目（VS Code 在前保持其最近顺序，JetBrains 按打开时间倒序）
+pub fn get_recent_projects() -> Vec<RecentProject> {
+    let mut projects = vscode_projects();
+    projects.extend(jetbrains_projects());
+
+    let mut seen = HashSet::new();
+    projects.retain(|p| seen.insert((p.product.clone(), p.path.to_lowercase())));
+    projects
+}
+
+/// 按名称或路径搜索最近项目
+pub fn search_recent_projects(query: &str) -> Vec<RecentProject> {
+    let query_lower = query.trim().to_lowercase();
+    let projects = get_recent_projects();
+    if query_lower.is_empty() {
+        return projects;
+    }
+    projects
+        .into_iter()
+        .filter(|p| {
+            p.name.to_lowercase().contains(&query_lower)
+                || p.path.to_lowercase().contains(&query_lower)
+        })
+        .collect()
+}
+
+fn file_name_eq(path: &str, exe_name: &str) -> bool {
+    Path::new(path)
+        .file_name()
+        .map(|n| n.to_string_lossy().eq_ignore_ascii_case(exe_name))
+        .unwrap_or(false)
+}
+
+/// 在应用索引中查找 IDE：优先可执行文件名匹配，其次按显示名称匹配快捷方式
+fn find_in_app_index(apps: &[AppInfo], ide_name: &str, exe_name: &str) -> Option<String> {
+    apps.iter()
+        .find(|app| file_name_eq(&app.path, exe_name))
+        .or_else(|| {
+            apps.iter()
+                .find(|app| app.name.eq_ignore_ascii_case(ide_name))
+        })
+        .or_else(|| {
+            let ide_lower = ide_name.to_lowercase();
+            apps.iter().find(|app| {
+                app.path.to_lowercase().ends_with(".lnk")
+                    && app.name.to_lowercase().starts_with(&ide_lower)
+            })
+        })
+        .map(|app| app.path.clone())
+}
+
+/// JetBrains 默认安装目录：%ProgramFiles%\JetBrains\<显示名称> <版本>\bin\<exe>
+fn find_jetbrains_install(ide_name: &str, exe_name: &str) -> Option<String> {
+    let program_files = env::var("ProgramFiles").ok()?;
+    let mut candidates: Vec<PathBuf> = fs::read_dir(Path::new(&program_files).join("JetBrains"))
+        .ok()?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_string_lossy().starts_with(ide_name))
+        .map(|e| e.path().join("bin").join(exe_name))
+        .filter(|p| p.exists())
+        .collect();
+    // 目录名中版本号越大越新
+    candidates.sort();
+    candidates.pop().map(|p| p.to_string_lossy().to_string())
+}
+
+/// 查找项目对应 IDE 的可执行文件（或快捷方式 / 命令行工具）
+pub fn resolve_executable(project: &RecentProject, apps: &[AppInfo]) -> Option<String> {
+    if project.ide == "vscode" {
+        let (_, ide_name, exe_name, cli) = VSCODE_FLAVORS
+            .iter()
+            .find(|(dir, _, _, _)| *dir == project.product)?;
+        return Some(
+            find_in_app_index(apps, ide_name, exe_name).unwrap_or_else(|| cli.to_string()),
+        );
+    }
+
+    let (_, ide_name, exe_name) = JETBRAINS_PRODUCTS
+        .iter()
+        .find(|(p, _, _)| *p == project.product)?;
+    find_in_app_index(apps, ide_name, exe_name)
+        .or_else(|| find_jetbrains_install(ide_name, exe_name))
+}
+
+/// 使用对应 IDE 打开最近项目
+pub fn open_recent_project(project: &RecentProject, apps: &[AppInfo]) -> Result<(), String> {
+    let executable = resolve_executable(project, apps)
+        .ok_or_else(|| format!("未找到 {} 的可执行文件", project.ide_name))?;
+
+    #[cfg(target_os = "windows")]
+    if executable.to_lowercase().ends_with(".lnk") {
+        use std::os::windows::process::CommandExt;
+        // 快捷方式不能直接带参数启动，交给 start（ShellExecute）处理
+        return std::process::Command::new("cmd")
+            .args(["/c", "start", "", &executable, &project.path])
+            .creation_flags(0x08000000) // CREATE_NO_WINDOW
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to open project: {}", e));
+    }
+
+    open_with::spawn_args(&[executable, project.path.clone()], None)
+        .map_err(|e| format!("Failed to open project: {}", e))
+}
@@ -0,0 +1,506 @@
+// 自然语言提醒：解析 "remind me to submit report tomorrow 9am"、"明天上午9点提醒我交报告"、"每周一10点开周会" 等输入
+// 识别相对时间、日期、星期、时刻与循环规则，其余文字作为提醒内容；到期提醒由计时调度（timers）负责，循环提醒触发后自动排到下一次
+
+use crate::timers;
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use regex::{Captures, Regex};
+use serde::Serialize;
+use std::sync::LazyLock;
+
+const WEEKDAY_EN: &str = "monday|mon|tuesday|tues|tue|wednesday|wed|thursday|thurs|thur|thu|friday|fri|saturday|sat|sunday|sun";
+const CN_NUMBER: &str = "[零一二两三四五六七八九十]{1,3}";
+
+static WEEKDAYS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\bevery\s+weekdays?\b|\bweekdays\b|每个?工作日|工作日").unwrap());
+static WEEKLY_DAY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?i)\bevery\s+({})\b|每个?(?:周|星期|礼拜)([一二三四五六日天])", WEEKDAY_EN)).unwrap()
+});
+static DAILY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bevery\s+(?:day|morning|afternoon|evening|night)\b|\bdaily\b|每天|每日|每晚|每早").unwrap()
+});
+static MONTHLY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bevery\s+month(?:\s+on\s+the\s+(\d{1,2})(?:st|nd|rd|th)?)?\b|\bmonthly\b|每个?月(?:\s*(\d{1,2})\s*[号日])?")
+        .unwrap()
+});
+static WEEKLY_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\bevery\s+week\b|\bweekly\b|每个?(?:周|星期|礼拜)").unwrap());
+
+static RELATIVE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\bin\s+(\d+(?:\.\d+)?|an?|half\s+an?)\s*(minutes?|mins?|m|hours?|hrs?|h|days?|d)\b|(\d+(?:\.\d+)?|半|{})\s*个?\s*(分钟|小时|钟头|天)以?后",
+        CN_NUMBER
+    ))
+    .unwrap()
+});
+static DATE_WORD_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bday\s+after\s+tomorrow\b|\btomorrow\b|\btonight\b|\btoday\b|大后天|后天|明天|明早|明晚|今天|今晚|今早").unwrap()
+});
+static WEEKDAY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?i)(?:\bon\s+)?\b(next\s+)?({})\b|(下个?)?(?:周|星期|礼拜)([一二三四五六日天])", WEEKDAY_EN)).unwrap()
+});
+static EXPLICIT_DATE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(\d{4})-(\d{1,2})-(\d{1,2})|(\d{1,2})\s*月\s*(\d{1,2})\s*[日号]?|(\d{1,2})\s*号").unwrap()
+});
+
+static TIME_MERIDIEM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(?:\bat\s+)?\b(\d{1,2})(?::(\d{2}))?\s*(am|pm)\b").unwrap());
+static TIME_COLON_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(?:\bat\s+)?(\d{1,2})[:：](\d{2})").unwrap());
+static TIME_CN_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(\d{{1,2}}|{0})\s*[点點]钟?\s*(半|一刻|三刻|(\d{{1,2}}|{0})\s*分?)?", CN_NUMBER)).unwrap()
+});
+static TIME_AT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\bat\s+(\d{1,2})\b").unwrap());
+static PERIOD_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:in\s+the\s+)?(morning|afternoon|evening|night|noon|midnight)\b|凌晨|早上|早晨|上午|中午|下午|傍晚|晚上|夜里").unwrap()
+});
+
+static PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?:please\s+)?(?:remind\s+me\s+(?:to|about|that)?|remind(?:er)?\s*:?)|^请?提醒(?:我|一下)?(?:要|去|该)?|提醒(?:我|一下)?$").unwrap()
+});
+static DANGLING_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(?:to|at|on|in|by|the)\s+|\s+(?:at|on|in|by|the)$").unwrap());
+
+/// 循环规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recurrence {
+    Daily,
+    Weekdays,
+    Weekly(Weekday),
+    Monthly(u32), // 每月几号，超过当月天数时取月末
+}
+
+impl Recurrence {
+    fn matches(self, date: NaiveDate) -> bool {
+        match self {
+            Recurrence::Daily => true,
+            Recurrence::Weekdays => date.weekday().num_days_from_monday() < 5,
+            Recurrence::Weekly(weekday) => date.weekday() == weekday,
+            Recurrence::Monthly(day) => date.day() == day.min(days_in_month(date)),
+        }
+    }
+
+    /// 从 start 当天起第一个时刻晚于 now 的匹配日期
+    fn first_from(self, start: NaiveDate, time: NaiveTime, now: NaiveDateTime) -> NaiveDateTime {
+        let mut date = start;
+        // 每月规则最多相隔 62 天，其余不超过 7 天
+        for _ in 0..400 {
+            if self.matches(date) && date.and_time(time) > now {
+                break;
+            }
+            date += Duration::days(1);
+        }
+        date.and_time(time)
+    }
+
+    /// 触发后下一次的时间：保持原来的时刻，从上一次的次日起找第一个晚于 now 的匹配日期
+    pub fn next_after(self, previous: NaiveDateTime, now: NaiveDateTime) -> NaiveDateTime {
+        self.first_from(previous.date() + Duration::days(1), previous.time(), now)
+    }
+
+    /// 保存到数据库的形式
+    pub fn encode(self) -> String {
+        match self {
+            Recurrence::Daily => "daily".to_string(),
+            Recurrence::Weekdays => "weekdays".to_string(),
+            Recurrence::Weekly(weekday) => format!("weekly:{}", weekday.num_days_from_monday()),
+            Recurrence::Monthly(day) => format!("monthly:{}", day),
+        }
+    }
+
+    pub fn decode(value: &str) -> Option<Self> {
+        match value.split_once(':') {
+            None if value == "daily" => Some(Recurrence::Daily),
+            None if value == "weekdays" => Some(Recurrence::Weekdays),
+            Some(("weekly", n)) => n.parse::<u8>().ok().and_then(|n| Weekday::try_from(n).ok()).map(Recurrence::Weekly),
+            Some(("monthly", n)) => n.parse().ok().filter(|d| (1..=31).contains(d)).map(Recurrence::Monthly),
+            _ => None,
+        }
+    }
+
+    /// 中文描述，如 "每周一"、"每月 15 日"
+    pub fn describe(self) -> String {
+        match self {
+            Recurrence::Daily => "每天".to_string(),
+            Recurrence::Weekdays => "每个工作日".to_string(),
+            Recurrence::Weekly(weekday) => format!("每周{}", WEEKDAY_CN[weekday.num_days_from_monday() as usize]),
+            Recurrence::Monthly(day) => format!("每月 {} 日", day),
+        }
+    }
+}
+
+const WEEKDAY_CN: [&str; 7] = ["一", "二", "三", "四", "五", "六", "日"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedReminder {
+    pub text: String,
+    pub at: NaiveDateTime,
+    pub recurrence: Option<Recurrence>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReminderPreview {
+    pub text: String,
+    pub fire_at: i64,
+    pub recurrence: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Period {
+    Early,
+    Morning,
+    Noon,
+    Afternoon,
+    Evening,
+    Midnight,
+}
+
+impl Period {
+    fn from_word(word: &str) -> Self {
+        match word.to_lowercase().as_str() {
+            "凌晨" => Period::Early,
+            "noon" | "中午" => Period::Noon,
+            "afternoon" | "下午" => Period::Afternoon,
+            "evening" | "night" | "tonight" | "傍晚" | "晚上" | "夜里" | "今晚" | "明晚" | "每晚" => Period::Evening,
+            "midnight" => Period::Midnight,
+            _ => Period::Morning,
+        }
+    }
+
+    fn default_time(self) -> NaiveTime {
+        let hour = match self {
+            Period::Early => 6,
+            Period::Morning => 9,
+            Period::Noon => 12,
+            Period::Afternoon => 15,
+            Period::Evening => 20,
+            Period::Midnight => 0,
+        };
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    /// 按时段把 12 小时制的钟点换成 24 小时制，如 "下午3点" → 15
+    fn adjust_hour(self, hour: u32) -> u32 {
+        match self {
+            Period::Afternoon | Period::Evening if hour < 12 => hour + 12,
+            Period::Noon if (1..6).contains(&hour) => hour + 12,
+            Period::Early | Period::Midnight if hour == 12 => 0,
+            _ => hour,
+        }
+    }
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = if date.month() == 12 { (date.year() + 1, 1) } else { (date.year(), date.month() + 1) };
+    NaiveDate::from_ymd_opt(year, month, 1).and_then(|d| d.pred_opt()).map(|d| d.day()).unwrap_or(28)
+}
+
+/// 阿拉伯数字或不超过九十九的中文数字，如 "9"、"十一"、"两"、"三十"
+fn parse_number(text: &str) -> Option<u32> {
+    if let Ok(n) = text.parse() {
+        return Some(n);
+    }
+    let digit = |c: char| "零一二三四五六七八九".chars().position(|d| d == c).map(|n| n as u32).or((c == '两').then_some(2));
+    let chars: Vec<char> = text.chars().collect();
+    match chars.as_slice() {
+        [c] if *c == '十' => Some(10),
+        [c] => digit(*c),
+        ['十', ones] => Some(10 + digit(*ones)?),
+        [tens, '十'] => Some(digit(*tens)? * 10),
+        [tens, '十', ones] => Some(digit(*tens)? * 10 + digit(*ones)?),
+        _ => None,
+    }
+}
+
+fn parse_weekday(name: &str) -> Option<Weekday> {
+    let name = name.to_lowercase();
+    let index = match name.as_str() {
+        "一" => 0,
+        "二" => 1,
+        "三" => 2,
+        "四" => 3,
+        "五" => 4,
+        "六" => 5,
+        "日" | "天" => 6,
+        _ => ["mon", "tue", "wed", "thu", "fri", "sat", "sun"].iter().position(|p| name.starts_with(p))?,
+    };
+    Weekday::try_from(index as u8).ok()
+}
+
+/// 找到第一个匹配并从文本中去掉，返回各分组的内容
+fn take(re: &Regex, text: &mut String) -> Option<Vec<Option<String>>> {
+    let (range, groups) = {
+        let caps: Captures = re.captures(text)?;
+        let groups = caps.iter().map(|m| m.map(|m| m.as_str().to_string())).collect();
+        (caps.get(0)?.range(), groups)
+    };
+    text.replace_range(range, " ");
+    Some(groups)
+}
+
+fn group(groups: &[Option<String>], index: usize) -> Option<&str> {
+    groups.get(index).and_then(|g| g.as_deref())
+}
+
+/// 解析提醒输入；now 为当前本地时间
+pub fn parse_reminder(input: &str, now: NaiveDateTime) -> Result<ParsedReminder, String> {
+    let today = now.date();
+    let mut text = input.trim().to_string();
+    let mut period: Option<Period> = None;
+
+    // 循环规则
+    let mut recurrence = None;
+    if take(&WEEKDAYS_RE, &mut text).is_some() {
+        recurrence = Some(Recurrence::Weekdays);
+    } else if let Some(groups) = take(&WEEKLY_DAY_RE, &mut text) {
+        recurrence = group(&groups, 1).or(group(&groups, 2)).and_then(parse_weekday).map(Recurrence::Weekly);
+    } else if let Some(groups) = take(&DAILY_RE, &mut text) {
+        let word = group(&groups, 0).unwrap_or_default().to_lowercase();
+        if let Some(suffix) = word.strip_prefix("every").map(str::trim).or_else(|| word.strip_prefix('每')) {
+            if suffix != "day" && suffix != "天" && suffix != "日" {
+                period = Some(Period::from_word(if suffix == "早" { "早上" } else if suffix == "晚" { "晚上" } else { suffix }));
+            }
+        }
+        recurrence = Some(Recurrence::Daily);
+    } else if let Some(groups) = take(&MONTHLY_RE, &mut text) {
+        // 没有指定几号时在确定首次日期后补上
+        let day = group(&groups, 1).or(group(&groups, 2)).and_then(|d| d.parse().ok()).unwrap_or(0);
+        if day > 31 {
+            return Err(format!("无效的日期: 每月 {} 日", day));
+        }
+        recurrence = Some(Recurrence::Monthly(day));
+    } else if take(&WEEKLY_RE, &mut text).is_some() {
+        recurrence = Some(Recurrence::Weekly(today.weekday()));
+    }
+
+    // 相对时间："in 10 minutes"、"半小时后"、"3天后"
+    let mut relative_secs = None;
+    let mut date = None;
+    if let Some(groups) = take(&RELATIVE_RE, &mut text) {
+        let (amount, unit) = match (group(&groups, 1), group(&groups, 3)) {
+            (Some(amount), _) => (amount.to_lowercase(), group(&groups, 2).unwrap_or_default().to_lowercase()),
+            (_, Some(amount)) => (amount.to_string(), group(&groups, 4).unwrap_or_default().to_string()),
+            _ => unreachable!(),
+        };
+        let amount = match amount.as_str() {
+            "a" | "an" => 1.0,
+            "半" => 0.5,
+            a if a.starts_with("half") => 0.5,
+            a => a.parse::<f64>().ok().or_else(|| parse_number(a).map(f64::from)).ok_or("无法识别的时长")?,
+        };
+        if unit.starts_with('d') || unit == "天" {
+            date = Some(today + Duration::days(amount.round() as i64));
+        } else {
+            let unit_secs = if unit.starts_with('h') || unit == "小时" || unit == "钟头" { 3600.0 } else { 60.0 };
+            relative_secs = Some((amount * unit_secs).round() as i64);
+        }
+    }
+
+    // 日期
+    let mut weekday_only = false;
+    if let Some(groups) = take(&DATE_WORD_RE, &mut text) {
+        let word = group(&groups, 0).unwrap_or_default().to_lowercase();
+        let offset = match word.as_str() {
+            "大后天" => 3,
+            "后天" => 2,
+            w if w.starts_with("day") => 2,
+            "tomorrow" | "明天" | "明早" | "明晚" => 1,
+            _ => 0,
+        };
+        date = Some(today + Duration::days(offset));
+        if matches!(word.as_str(), "tonight" | "今晚" | "明晚" | "今早" | "明早") {
+            period = Some(Period::from_word(&word));
+        }
+    } else if let Some(groups) = take(&WEEKDAY_RE, &mut text) {
+        let next = group(&groups, 1).is_some() || group(&groups, 3).is_some();
+        let target = group(&groups, 2).or(group(&groups, 4)).and_then(parse_weekday).ok_or("无法识别的星期")?;
+        let from_monday = today.weekday().num_days_from_monday() as i64;
+        let days = if next {
+            // 下周的那一天
+            7 - from_monday + target.num_days_from_monday() as i64
+        } else {
+            weekday_only = true;
+            (target.num_days_from_monday() as i64 - from_monday).rem_euclid(7)
+        };
+        date = Some(today + Duration::days(days));
+    } else if let Some(groups) = take(&EXPLICIT_DATE_RE, &mut text) {
+        let number = |i: usize| group(&groups, i).and_then(|n| n.parse::<u32>().ok());
+        let parsed = if let (Some(y), Some(m), Some(d)) = (number(1), number(2), number(3)) {
+            NaiveDate::from_ymd_opt(y as i32, m, d)
+        } else if let (Some(m), Some(d)) = (number(4), number(5)) {
+            // 今年已过的日期顺延到明年
+            NaiveDate::from_ymd_opt(today.year(), m, d)
+                .filter(|d| *d >= today)
+                .or_else(|| NaiveDate::from_ymd_opt(today.year() + 1, m, d))
+        } else {
+            // 只写几号：本月已过则为下月
+            let d = number(6).unwrap_or(0);
+            NaiveDate::from_ymd_opt(today.year(), today.month(), d)
+                .filter(|d| *d >= today)
+                .or_else(|| (1..=2).find_map(|n| today.checked_add_months(chrono::Months::new(n))?.with_day(d)))
+        };
+        date = Some(parsed.ok_or("无效的日期")?);
+    }
+
+    // 时刻
+    let mut time = None;
+    let mut explicit_meridiem = false;
+    if let Some(groups) = take(&TIME_MERIDIEM_RE, &mut text) {
+        let hour: u32 = group(&groups, 1).and_then(|h| h.parse().ok()).unwrap_or(0);
+        let minute: u32 = group(&groups, 2).and_then(|m| m.parse().ok()).unwrap_or(0);
+        if !(1..=12).contains(&hour) {
+            return Err(format!("无效的时间: {}", group(&groups, 0).unwrap_or_default().trim()));
+        }
+        let pm = group(&groups, 3).is_some_and(|m| m.eq_ignore_ascii_case("pm"));
+        time = Some((hour % 12 + if pm { 12 } else { 0 }, minute));
+        explicit_meridiem = true;
+    } else if let Some(groups) = take(&TIME_COLON_RE, &mut text) {
+        let hour = group(&groups, 1).and_then(|h| h.parse().ok()).unwrap_or(0);
+        let minute = group(&groups, 2).and_then(|m| m.parse().ok()).unwrap_or(0);
+        time = Some((hour, minute));
+    } else if let Some(groups) = take(&TIME_CN_RE, &mut text) {
+        let hour = group(&groups, 1).and_then(parse_number).ok_or("无法识别的时间")?;
+        let minute = match group(&groups, 2) {
+            Some("半") => 30,
+            Some("一刻") => 15,
+            Some("三刻") => 45,
+            Some(_) => group(&groups, 3).and_then(parse_number).ok_or("无法识别的时间")?,
+            None => 0,
+        };
+        time = Some((hour, minute));
+    } else if let Some(groups) = take(&TIME_AT_RE, &mut text) {
+        time = group(&groups, 1).and_then(|h| h.parse().ok()).map(|h| (h, 0));
+    }
+    if let Some(groups) = take(&PERIOD_RE, &mut text) {
+        period = Some(Period::from_word(group(&groups, 1).or(group(&groups, 0)).unwrap_or_default().trim()));
+    }
+    let time = match time {
+        Some((hour, minute)) => {
+            let hour = match period {
+                Some(period) if !explicit_meridiem => period.adjust_hour(hour),
+                _ => hour,
+            };
+            Some(NaiveTime::from_hms_opt(hour, minute, 0).ok_or_else(|| format!("无效的时间: {}:{:02}", hour, minute))?)
+        }
+        None => period.map(Period::default_time),
+    };
+
+    let at = if let Some(rule) = recurrence {
+        if relative_secs.is_some() {
+            return Err("循环提醒需要指定时刻，例如 \"每天 9点\"".to_string());
+        }
+        let start = date.unwrap_or(today);
+        let rule = match rule {
+            Recurrence::Monthly(0) => Recurrence::Monthly(start.day()),
+            rule => rule,
+        };
+        recurrence = Some(rule);
+        rule.first_from(start, time.unwrap_or(Period::Morning.default_time()), now)
+    } else if let Some(secs) = relative_secs {
+        now + Duration::seconds(secs)
+    } else if let Some(date) = date {
+        // 只给日期时默认上午 9 点；只写星期且今天的时刻已过时顺延一周
+        let mut at = date.and_time(time.unwrap_or(Period::Morning.default_time()));
+        if at <= now && weekday_only {
+            at += Duration::days(7);
+        }
+        if at <= now {
+            return Err("提醒时间已经过去".to_string());
+        }
+        at
+    } else if let Some(time) = time {
+        let at = today.and_time(time);
+        if at > now { at } else { at + Duration::days(1) }
+    } else {
+        return Err("没有识别到提醒时间，例如 \"明天上午9点\"、\"in 10 minutes\"、\"every monday 9am\"".to_string());
+    };
+
+    // 去掉 "remind me to"、"提醒我" 等前缀和残留的介词、标点
+    let mut text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    loop {
+        let trimmed = text.trim_matches(|c: char| c.is_whitespace() || ",，。.!！;；:：、".contains(c)).to_string();
+        let stripped = DANGLING_RE.replace_all(&PREFIX_RE.replace_all(&trimmed, ""), "").trim().to_string();
+        if stripped == text {
+            break;
+        }
+        text = stripped;
+    }
+    let text = if text.is_empty() { "提醒".to_string() } else { text };
+
+    Ok(ParsedReminder { text, at, recurrence })
+}
+
+/// 按当前本地时间解析，供启动器预览
+pub fn preview(input: &str) -> Result<ReminderPreview, String> {
+    let parsed = parse_reminder(input, Local::now().naive_local())?;
+    Ok(ReminderPreview {
+        fire_at: timers::local_timestamp(parsed.at).ok_or("提醒时间在本地时区不存在")?,
+        recurrence: parsed.recurrence.map(Recurrence::describe),
+        text: parsed.text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2025-06-10 是星期二
+    fn now() -> NaiveDateTime {
+        NaiveDateTime::parse_from_str("2025-06-10 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn parse(input: &str) -> (String, String, Option<Recurrence>) {
+        let parsed = parse_reminder(input, now()).unwrap();
+        (parsed.text, parsed.at.format("%Y-%m-%d %H:%M").to_string(), parsed.recurrence)
+    }
+
+    fn check(input: &str, text: &str, at: &str) {
+        assert_eq!(parse(input), (text.to_string(), at.to_string(), None), "{}", input);
+    }
+
+    #[test]
+    fn parses_english() {
+        check("remind me to submit report tomorrow 9am", "submit report", "2025-06-11 09:00");
+        check("remind me to call mom in 10 minutes", "call mom", "2025-06-10 10:10");
+        check("remind me in half an hour to stretch", "stretch", "2025-06-10 10:30");
+        check("pay rent on friday at 6pm", "pay rent", "2025-06-13 18:00");
+        check("standup next monday at 9:30", "standup", "2025-06-16 09:30");
+        check("water plants tuesday 8am", "water plants", "2025-06-17 08:00");
+        check("take out trash tonight", "take out trash", "2025-06-10 20:00");
+        check("remind me to stretch at 11", "stretch", "2025-06-10 11:00");
+        check("dentist 2025-07-01 14:00", "dentist", "2025-07-01 14:00");
+        assert!(parse_reminder("remind me to buy milk", now()).is_err());
+        assert!(parse_reminder("meeting today 8am", now()).is_err());
+    }
+
+    #[test]
+    fn parses_chinese() {
+        check("明天上午9点提醒我交报告", "交报告", "2025-06-11 09:00");
+        check("提醒我下午3点半开会", "开会", "2025-06-10 15:30");
+        check("半小时后提醒我关火", "关火", "2025-06-10 10:30");
+        check("2小时后 取快递", "取快递", "2025-06-10 12:00");
+        check("后天晚上八点 看电影", "看电影", "2025-06-12 20:00");
+        check("下周三 10:00 复诊", "复诊", "2025-06-18 10:00");
+        check("6月20号 交房租", "交房租", "2025-06-20 09:00");
+        check("提醒我 3天后 续费", "续费", "2025-06-13 09:00");
+    }
+
+    #[test]
+    fn parses_recurrence() {
+        assert_eq!(
+            parse("every monday 9am team sync"),
+            ("team sync".to_string(), "2025-06-16 09:00".to_string(), Some(Recurrence::Weekly(Weekday::Mon)))
+        );
+        assert_eq!(parse("每天早上8点 吃药"), ("吃药".to_string(), "2025-06-11 08:00".to_string(), Some(Recurrence::Daily)));
+        assert_eq!(parse("每个工作日 18:00 写日报").2, Some(Recurrence::Weekdays));
+        assert_eq!(parse("每月15号 还信用卡"), ("还信用卡".to_string(), "2025-06-15 09:00".to_string(), Some(Recurrence::Monthly(15))));
+        assert_eq!(parse("daily standup at 10:30").1, "2025-06-10 10:30");
+
+        let friday = NaiveDateTime::parse_from_str("2025-06-13 18:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        assert_eq!(Recurrence::Weekdays.next_after(friday, friday).to_string(), "2025-06-16 18:00:00");
+        let jan31 = NaiveDateTime::parse_from_str("2025-01-31 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        assert_eq!(Recurrence::Monthly(31).next_after(jan31, jan31).to_string(), "2025-02-28 09:00:00");
+        for rule in [Recurrence::Daily, Recurrence::Weekdays, Recurrence::Weekly(Weekday::Sun), Recurrence::Monthly(31)] {
+            assert_eq!(Recurrence::decode(&rule.encode()), Some(rule));
+        }
+    }
+}
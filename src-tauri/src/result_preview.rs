@@ -0,0 +1,270 @@
+// 搜索结果预览：文件大小与修改时间、文本文件的前几行、图片尺寸与缩略图、单词本释义
+// 前端选中结果时按需获取，只读取文件开头的少量字节，不会因大文件阻塞
+
+use crate::word_records;
+use base64::{engine::general_purpose, Engine as _};
+use serde::Serialize;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+const MAX_TEXT_LINES: usize = 8;
+const MAX_LINE_CHARS: usize = 200;
+const TEXT_SNIFF_BYTES: u64 = 4096;
+const IMAGE_HEADER_BYTES: u64 = 64 * 1024; // JPEG 的尺寸信息可能在 EXIF 之后
+const MAX_THUMBNAIL_BYTES: u64 = 512 * 1024; // 较小的图片直接作为缩略图返回
+const MAX_FOLDER_ITEMS: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ResultPreview {
+    File {
+        size: u64,
+        modified: Option<u64>,
+        lines: Vec<String>, // 文本文件的前几行，二进制文件为空
+    },
+    Folder {
+        modified: Option<u64>,
+        item_count: Option<usize>, // 超过 MAX_FOLDER_ITEMS 或无法读取时为 None
+    },
+    Image {
+        size: u64,
+        modified: Option<u64>,
+        format: &'static str,
+        width: u32,
+        height: u32,
+        thumbnail: Option<String>, // data URL，图片过大时为 None
+    },
+    Word {
+        word: String,
+        phonetic: Option<String>,
+        translation: String,
+        example_sentence: Option<String>,
+        mastery_level: i32,
+    },
+}
+
+fn read_head(path: &Path, limit: u64) -> Result<Vec<u8>, String> {
+    let file = fs::File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let mut head = Vec::new();
+    file.take(limit)
+        .read_to_end(&mut head)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+    Ok(head)
+}
+
+/// 文件或文件夹的预览
+pub fn preview_path(path: &Path) -> Result<ResultPreview, String> {
+    let metadata = fs::metadata(path).map_err(|e| format!("Failed to read metadata: {}", e))?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs());
+
+    if metadata.is_dir() {
+        let item_count = fs::read_dir(path).ok().and_then(|entries| {
+            let count = entries.take(MAX_FOLDER_ITEMS + 1).count();
+            (count <= MAX_FOLDER_ITEMS).then_some(count)
+        });
+        return Ok(ResultPreview::Folder { modified, item_count });
+    }
+
+    let size = metadata.len();
+    let head = read_head(path, IMAGE_HEADER_BYTES)?;
+    if let Some((format, width, height)) = image_dimensions(&head) {
+        let thumbnail = if size <= MAX_THUMBNAIL_BYTES {
+            fs::read(path).ok().map(|bytes| {
+                format!("data:image/{};base64,{}", format, general_purpose::STANDARD.encode(bytes))
+            })
+        } else {
+            None
+        };
+        return Ok(ResultPreview::Image { size, modified, format, width, height, thumbnail });
+    }
+
+    let sniff = &head[..head.len().min(TEXT_SNIFF_BYTES as usize)];
+    let lines = text_lines(sniff).unwrap_or_default();
+    Ok(ResultPreview::File { size, modified, lines })
+}
+
+/// 单词本中与 word 对应的记录（优先完全匹配）
+pub fn preview_word(word: &str, app_data_dir: &Path) -> Result<Option<ResultPreview>, String> {
+    let word = word.trim();
+    if word.is_empty() {
+        return Ok(None);
+    }
+    let mut records = word_records::search_word_records(word, app_data_dir)?;
+    let exact = records.iter().position(|r| r.word.eq_ignore_ascii_case(word));
+    let record = match exact {
+        Some(index) => records.swap_remove(index),
+        None if !records.is_empty() => records.swap_remove(0),
+        None => return Ok(None),
+    };
+    Ok(Some(ResultPreview::Word {
+        word: record.word,
+        phonetic: record.phonetic,
+        translation: record.translation,
+        example_sentence: record.example_sentence,
+        mastery_level: record.mastery_level,
+    }))
+}
+
+/// 文本内容的前几行；含 NUL 字节或不是 UTF-8 时视为二进制文件返回 None
+fn text_lines(bytes: &[u8]) -> Option<Vec<String>> {
+    if bytes.contains(&0) {
+        return None;
+    }
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    let text = match std::str::from_utf8(bytes) {
+        Ok(text) => text,
+        // 截断位置可能落在多字节字符中间，只要错误出现在末尾就接受
+        Err(e) if e.error_len().is_none() => std::str::from_utf8(&bytes[..e.valid_up_to()]).ok()?,
+        Err(_) => return None,
+    };
+    Some(
+        text.lines()
+            .take(MAX_TEXT_LINES)
+            .map(|line| line.trim_end().chars().take(MAX_LINE_CHARS).collect())
+            .collect(),
+    )
+}
+
+fn be_u16(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u16::from_be_bytes(bytes.get(at..at + 2)?.try_into().ok()?) as u32)
+}
+
+fn le_u16(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u16::from_le_bytes(bytes.get(at..at + 2)?.try_into().ok()?) as u32)
+}
+
+fn le_u24(bytes: &[u8], at: usize) -> Option<u32> {
+    let b = bytes.get(at..at + 3)?;
+    Some(b[0] as u32 | (b[1] as u32) << 8 | (b[2] as u32) << 16)
+}
+
+fn le_u32(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// 从文件头解析图片格式和尺寸，支持 PNG、JPEG、GIF、BMP、WebP
+pub fn image_dimensions(header: &[u8]) -> Option<(&'static str, u32, u32)> {
+    if header.starts_with(b"\x89PNG\r\n\x1a\n") {
+        let width = u32::from_be_bytes(header.get(16..20)?.try_into().ok()?);
+        let height = u32::from_be_bytes(header.get(20..24)?.try_into().ok()?);
+        return Some(("png", width, height));
+    }
+    if header.starts_with(b"GIF87a") || header.starts_with(b"GIF89a") {
+        return Some(("gif", le_u16(header, 6)?, le_u16(header, 8)?));
+    }
+    if header.starts_with(b"BM") {
+        // BITMAPFILEHEADER：文件大小在 2，像素数据偏移在 10，其后是 DIB 头（第一个字段为头大小）
+        // 以 BM 开头的文本等文件这几个字段通常对不上，不当作图片
+        let file_size = le_u32(header, 2)?;
+        let data_offset = le_u32(header, 10)?;
+        let dib_size = le_u32(header, 14)?;
+        if ![12, 40, 52, 56, 108, 124].contains(&dib_size) || data_offset < 14 + dib_size || data_offset > file_size {
+            return None;
+        }
+        if dib_size == 12 {
+            // BITMAPCOREHEADER 的宽高是 16 位
+            return Some(("bmp", le_u16(header, 18)?, le_u16(header, 20)?));
+        }
+        let width = le_u32(header, 18)? as i32;
+        let height = le_u32(header, 22)? as i32;
+        return Some(("bmp", width.unsigned_abs(), height.unsigned_abs()));
+    }
+    if header.starts_with(b"RIFF") && header.get(8..12) == Some(b"WEBP") {
+        return match header.get(12..16)? {
+            b"VP8 " => Some(("webp", le_u16(header, 26)? & 0x3FFF, le_u16(header, 28)? & 0x3FFF)),
+            b"VP8L" => {
+                let bits = le_u32(header, 21)?;
+                Some(("webp", (bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1))
+            }
+            b"VP8X" => Some(("webp", le_u24(header, 24)? + 1, le_u24(header, 27)? + 1)),
+            _ => None,
+        };
+    }
+    if header.starts_with(b"\xFF\xD8") {
+        // 逐段查找 SOF 段（0xC0..=0xCF，排除 DHT/JPG/DAC）
+        let mut pos = 2;
+        while pos + 4 <= header.len() {
+            if header[pos] != 0xFF {
+                return None;
+            }
+            let marker = header[pos + 1];
+            if marker == 0xFF {
+                pos += 1;
+                continue;
+            }
+            let length = be_u16(header, pos + 2)? as usize;
+            if (0xC0..=0xCF).contains(&marker) && ![0xC4, 0xC8, 0xCC].contains(&marker) {
+                return Some(("jpeg", be_u16(header, pos + 7)?, be_u16(header, pos + 5)?));
+            }
+            pos += 2 + length;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_image_headers() {
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        png.extend_from_slice(&640u32.to_be_bytes());
+        png.extend_from_slice(&480u32.to_be_bytes());
+        assert_eq!(image_dimensions(&png), Some(("png", 640, 480)));
+
+        let gif = b"GIF89a\x20\x03\x58\x02";
+        assert_eq!(image_dimensions(gif), Some(("gif", 800, 600)));
+
+        // APP0 段之后是 SOF0：高 0x01E0，宽 0x0280
+        let jpeg = b"\xFF\xD8\xFF\xE0\x00\x04\x00\x00\xFF\xC0\x00\x11\x08\x01\xE0\x02\x80";
+        assert_eq!(image_dimensions(jpeg), Some(("jpeg", 640, 480)));
+
+        assert_eq!(image_dimensions(b"plain text"), None);
+    }
+
+    fn bmp_header(file_size: u32, data_offset: u32, dib_size: u32, width: i32, height: i32) -> Vec<u8> {
+        let mut bmp = b"BM".to_vec();
+        bmp.extend_from_slice(&file_size.to_le_bytes());
+        bmp.extend_from_slice(&[0; 4]);
+        bmp.extend_from_slice(&data_offset.to_le_bytes());
+        bmp.extend_from_slice(&dib_size.to_le_bytes());
+        bmp.extend_from_slice(&width.to_le_bytes());
+        bmp.extend_from_slice(&height.to_le_bytes());
+        bmp
+    }
+
+    #[test]
+    fn validates_bmp_file_header() {
+        // 自下而上存储的位图高度为负数
+        assert_eq!(image_dimensions(&bmp_header(1_000_054, 54, 40, 500, -500)), Some(("bmp", 500, 500)));
+        let mut core = b"BM".to_vec();
+        core.extend_from_slice(&(26u32 + 12).to_le_bytes());
+        core.extend_from_slice(&[0; 4]);
+        core.extend_from_slice(&26u32.to_le_bytes());
+        core.extend_from_slice(&12u32.to_le_bytes());
+        core.extend_from_slice(&[0x20, 0x03, 0x58, 0x02]);
+        assert_eq!(image_dimensions(&core), Some(("bmp", 800, 600)));
+
+        // 未知的 DIB 头大小、像素偏移落在头部之内或超出文件大小
+        assert_eq!(image_dimensions(&bmp_header(1_000, 54, 7, 10, 10)), None);
+        assert_eq!(image_dimensions(&bmp_header(1_000, 20, 40, 10, 10)), None);
+        assert_eq!(image_dimensions(&bmp_header(40, 54, 40, 10, 10)), None);
+        assert_eq!(image_dimensions(b"BMW owners manual, chapter one"), None);
+    }
+
+    #[test]
+    fn extracts_text_lines() {
+        let lines = text_lines("\u{FEFF}第一行\r\nsecond\n".as_bytes()).unwrap();
+        assert_eq!(lines, vec!["第一行", "second"]);
+        // 截断在多字节字符中间
+        assert_eq!(text_lines(&"中文".as_bytes()[..4]).unwrap(), vec!["中"]);
+        assert!(text_lines(b"\x00\x01binary").is_none());
+    }
+}
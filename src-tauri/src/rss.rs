@@ -0,0 +1,502 @@
+// RSS 阅读：订阅管理、后台定时抓取（按订阅 + guid 去重）和未读条目，启动器输入 "rss" 查看最新标题
+// 支持 RSS 2.0、RSS 1.0 (RDF) 与 Atom；已读条目保留 30 天后清理
+
+use crate::api_policy::{self, Policy};
+use crate::{db, network, shutdown};
+use chrono::DateTime;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use rusqlite::{params, params_from_iter, OptionalExtension};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+const FETCH_INTERVAL: Duration = Duration::from_secs(30 * 60);
+const FIRST_FETCH_DELAY: Duration = Duration::from_secs(2 * 60);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+const READ_RETENTION_SECS: i64 = 30 * 24 * 60 * 60;
+const SUMMARY_MAX_CHARS: usize = 200;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Feed {
+    pub id: i64,
+    pub url: String,
+    pub title: String,
+    pub site_url: Option<String>,
+    pub last_fetched: Option<i64>,
+    pub last_error: Option<String>, // 最近一次抓取失败的原因，成功后清空
+    pub unread: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedItem {
+    pub id: i64,
+    pub feed_id: i64,
+    pub feed_title: String,
+    pub title: String,
+    pub link: String,
+    pub summary: Option<String>,
+    pub published_at: Option<i64>, // 发布时间（Unix 秒），订阅未提供时为空
+    pub read: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedItem {
+    pub guid: String,
+    pub title: String,
+    pub link: String,
+    pub summary: Option<String>,
+    pub published_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedFeed {
+    pub title: String,
+    pub site_url: Option<String>,
+    pub items: Vec<ParsedItem>,
+}
+
+#[derive(Default)]
+struct RawItem {
+    title: String,
+    link: String,
+    guid: String,
+    summary: String,
+    content: String,
+    date: String,
+}
+
+fn now_ts() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs() as i64
+}
+
+/// 去掉 HTML 标签和常见实体，合并空白并截断，用作条目摘要
+fn plain_summary(html: &str) -> Option<String> {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return None;
+    }
+    Some(match text.char_indices().nth(SUMMARY_MAX_CHARS) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text,
+    })
+}
+
+fn parse_date(value: &str) -> Option<i64> {
+    let value = value.trim();
+    DateTime::parse_from_rfc2822(value)
+        .or_else(|_| DateTime::parse_from_rfc3339(value))
+        .ok()
+        .map(|d| d.timestamp())
+}
+
+impl RawItem {
+    fn finish(self) -> Option<ParsedItem> {
+        let title = self.title.trim().to_string();
+        let link = self.link.trim().to_string();
+        if title.is_empty() && link.is_empty() {
+            return None;
+        }
+        // guid 缺失时依次用链接、标题去重
+        let guid = [self.guid.trim(), link.as_str(), title.as_str()]
+            .into_iter()
+            .find(|s| !s.is_empty())
+            .unwrap_or_default()
+            .to_string();
+        let summary = if self.summary.trim().is_empty() { self.content } else { self.summary };
+        Some(ParsedItem {
+            guid,
+            title: if title.is_empty() { link.clone() } else { title },
+            link,
+            summary: plain_summary(&summary),
+            published_at: parse_date(&self.date),
+        })
+    }
+}
+
+fn local_name(e: &BytesStart) -> String {
+    String::from_utf8_lossy(e.local_name().as_ref()).to_ascii_lowercase()
+}
+
+/// Atom 的 <link href="..." rel="alternate"/>，rel 缺省视为 alternate
+fn alternate_href(e: &BytesStart) -> Option<String> {
+    let attr = |name: &str| {
+        e.try_get_attribute(name)
+            .ok()
+            .flatten()
+            .and_then(|a| a.unescape_value().ok().map(|v| v.into_owned()))
+    };
+    let rel = attr("rel");
+    if rel.as_deref().is_some_and(|rel| rel != "alternate") {
+        return None;
+    }
+    attr("href")
+}
+
+/// 解析 RSS 2.0 / RSS 1.0 / Atom 订阅内容；遇到格式错误时保留已解析的条目
+pub fn parse_feed(xml: &str) -> Result<ParsedFeed, String> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+    let mut path: Vec<String> = Vec::new();
+    let mut feed = ParsedFeed::default();
+    let mut item: Option<RawItem> = None;
+    let mut recognized = false;
+
+    loop {
+        let event = match reader.read_event() {
+            Ok(event) => event,
+            Err(e) if feed.items.is_empty() => return Err(format!("订阅内容解析失败: {}", e)),
+            Err(_) => break,
+        };
+        let text = match event {
+            Event::Start(e) => {
+                let name = local_name(&e);
+                match name.as_str() {
+                    "rss" | "rdf" | "feed" => recognized = true,
+                    "item" | "entry" => item = Some(RawItem::default()),
+                    "link" => {
+                        if let Some(href) = alternate_href(&e) {
+                            match item.as_mut() {
+                                Some(item) if item.link.is_empty() => item.link = href,
+                                None if feed.site_url.is_none() => feed.site_url = Some(href),
+                                _ => {}
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                path.push(name);
+                continue;
+            }
+            Event::Empty(e) => {
+                if local_name(&e) == "link" {
+                    if let Some(href) = alternate_href(&e) {
+                        match item.as_mut() {
+                            Some(item) if item.link.is_empty() => item.link = href,
+                            None if feed.site_url.is_none() => feed.site_url = Some(href),
+                            _ => {}
+                        }
+                    }
+                }
+                continue;
+            }
+            Event::End(_) => {
+                if let Some(name) = path.pop() {
+                    if name == "item" || name == "entry" {
+                        if let Some(parsed) = item.take().and_then(RawItem::finish) {
+                            feed.items.push(parsed);
+                        }
+                    }
+                }
+                continue;
+            }
+            // 描述中常含 &nbsp; 等 HTML 实体，XML 反转义失败时保留原文，交给摘要处理
+            Event::Text(t) => t.unescape().map(|s| s.into_owned()).unwrap_or_else(|_| String::from_utf8_lossy(&t).into_owned()),
+            Event::CData(c) => String::from_utf8_lossy(&c.into_inner()).into_owned(),
+            Event::Eof => break,
+            _ => continue,
+        };
+
+        let (Some(current), Some(parent)) = (path.last(), path.len().checked_sub(2).and_then(|i| path.get(i))) else {
+            continue;
+        };
+        match item.as_mut() {
+            Some(item) if parent == "item" || parent == "entry" => {
+                let field = match current.as_str() {
+                    "title" => &mut item.title,
+                    "link" => &mut item.link,
+                    "guid" | "id" => &mut item.guid,
+                    "description" | "summary" => &mut item.summary,
+                    "encoded" | "content" => &mut item.content,
+                    "pubdate" | "published" | "date" => &mut item.date,
+                    "updated" if item.date.is_empty() => &mut item.date,
+                    _ => continue,
+                };
+                field.push_str(&text);
+            }
+            None if parent == "channel" || parent == "feed" => match current.as_str() {
+                "title" => feed.title.push_str(&text),
+                "link" if feed.site_url.is_none() => feed.site_url = Some(text.trim().to_string()),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    if !recognized {
+        return Err("不是有效的 RSS 或 Atom 订阅".to_string());
+    }
+    feed.title = feed.title.trim().to_string();
+    Ok(feed)
+}
+
+async fn fetch_feed(url: &str) -> Result<ParsedFeed, String> {
+    let client = crate::http_client::builder()?
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("创建HTTP客户端失败: {}", e))?;
+    let body = api_policy::send(&api_policy::provider_of(url), &Policy::FEEDS, || client.get(url))
+        .await
+        .map_err(|e| format!("订阅请求失败: {}", e))?
+        .text()
+        .await
+        .map_err(|e| format!("读取订阅内容失败: {}", e))?;
+    parse_feed(&body)
+}
+
+/// 保存抓取到的条目，已存在的（同一订阅下 guid 相同）跳过；返回新增条数
+fn store_items(app_data_dir: &Path, feed_id: i64, parsed: &ParsedFeed) -> Result<usize, String> {
+    let now = now_ts();
+    let mut conn = db::get_connection(app_data_dir)?;
+    let tx = conn.transaction().map_err(|e| format!("Failed to start rss transaction: {}", e))?;
+    let mut inserted = 0;
+    {
+        let mut stmt = tx
+            .prepare(
+                "INSERT OR IGNORE INTO rss_items (feed_id, guid, title, link, summary, published_at, fetched_at, read)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 0)",
+            )
+            .map_err(|e| format!("Failed to prepare rss insert: {}", e))?;
+        for item in &parsed.items {
+            inserted += stmt
+                .execute(params![feed_id, item.guid, item.title, item.link, item.summary, item.published_at, now])
+                .map_err(|e| format!("Failed to insert rss item: {}", e))?;
+        }
+    }
+    tx.execute(
+        "UPDATE rss_feeds SET title = CASE WHEN ?2 = '' THEN title ELSE ?2 END, site_url = COALESCE(?3, site_url),
+            last_fetched = ?4, last_error = NULL WHERE id = ?1",
+        params![feed_id, parsed.title, parsed.site_url, now],
+    )
+    .map_err(|e| format!("Failed to update rss feed: {}", e))?;
+    tx.execute(
+        "DELETE FROM rss_items WHERE feed_id = ?1 AND read = 1 AND fetched_at < ?2",
+        params![feed_id, now - READ_RETENTION_SECS],
+    )
+    .map_err(|e| format!("Failed to prune rss items: {}", e))?;
+    tx.commit().map_err(|e| format!("Failed to commit rss items: {}", e))?;
+    Ok(inserted)
+}
+
+fn read_feed(row: &rusqlite::Row) -> rusqlite::Result<Feed> {
+    Ok(Feed {
+        id: row.get(0)?,
+        url: row.get(1)?,
+        title: row.get(2)?,
+        site_url: row.get(3)?,
+        last_fetched: row.get(4)?,
+        last_error: row.get(5)?,
+        unread: row.get(6)?,
+    })
+}
+
+const FEED_COLUMNS: &str = "f.id, f.url, f.title, f.site_url, f.last_fetched, f.last_error,
+    (SELECT COUNT(*) FROM rss_items i WHERE i.feed_id = f.id AND i.read = 0)";
+
+/// 获取所有订阅及未读数
+pub fn list_feeds(app_data_dir: &Path) -> Result<Vec<Feed>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(&format!("SELECT {} FROM rss_feeds f ORDER BY f.title COLLATE NOCASE", FEED_COLUMNS))
+            .map_err(|e| format!("Failed to prepare rss feeds query: {}", e))?;
+        let feeds = stmt
+            .query_map([], read_feed)
+            .map_err(|e| format!("Failed to query rss feeds: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read rss feeds: {}", e))?;
+        Ok(feeds)
+    })
+}
+
+/// 添加订阅：先抓取一次验证地址有效，同时保存现有条目
+pub async fn add_feed(app_data_dir: &Path, url: &str) -> Result<Feed, String> {
+    let url = url.trim();
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err("订阅地址必须以 http:// 或 https:// 开头".to_string());
+    }
+    let parsed = fetch_feed(url).await?;
+    let title = if parsed.title.is_empty() { api_policy::provider_of(url) } else { parsed.title.clone() };
+    let feed_id = db::with_connection(app_data_dir, |conn| {
+        let existing: Option<i64> = conn
+            .query_row("SELECT id FROM rss_feeds WHERE url = ?1", params![url], |row| row.get(0))
+            .optional()
+            .map_err(|e| format!("Failed to query rss feed: {}", e))?;
+        if existing.is_some() {
+            return Err("已经订阅过这个地址".to_string());
+        }
+        conn.execute(
+            "INSERT INTO rss_feeds (url, title, site_url, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params![url, title, parsed.site_url, now_ts()],
+        )
+        .map_err(|e| format!("Failed to insert rss feed: {}", e))?;
+        Ok(conn.last_insert_rowid())
+    })?;
+    store_items(app_data_dir, feed_id, &parsed)?;
+    list_feeds(app_data_dir)?
+        .into_iter()
+        .find(|f| f.id == feed_id)
+        .ok_or_else(|| "订阅保存失败".to_string())
+}
+
+/// 删除订阅及其条目
+pub fn remove_feed(app_data_dir: &Path, id: i64) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute("DELETE FROM rss_items WHERE feed_id = ?1", params![id])
+            .and_then(|_| conn.execute("DELETE FROM rss_feeds WHERE id = ?1", params![id]))
+            .map_err(|e| format!("Failed to delete rss feed: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 按标题或订阅名筛选条目，按发布时间倒序（未提供发布时间的按抓取时间）
+pub fn list_items(app_data_dir: &Path, query: &str, unread_only: bool, limit: usize) -> Result<Vec<FeedItem>, String> {
+    let pattern = format!("%{}%", query.trim().replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"));
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT i.id, i.feed_id, f.title, i.title, i.link, i.summary, i.published_at, i.read
+                 FROM rss_items i JOIN rss_feeds f ON f.id = i.feed_id
+                 WHERE (?1 = 0 OR i.read = 0)
+                   AND (i.title LIKE ?2 ESCAPE '\\' OR f.title LIKE ?2 ESCAPE '\\')
+                 ORDER BY COALESCE(i.published_at, i.fetched_at) DESC, i.id DESC
+                 LIMIT ?3",
+            )
+            .map_err(|e| format!("Failed to prepare rss items query: {}", e))?;
+        let items = stmt
+            .query_map(params![unread_only, pattern, limit as i64], |row| {
+                Ok(FeedItem {
+                    id: row.get(0)?,
+                    feed_id: row.get(1)?,
+                    feed_title: row.get(2)?,
+                    title: row.get(3)?,
+                    link: row.get(4)?,
+                    summary: row.get(5)?,
+                    published_at: row.get(6)?,
+                    read: row.get(7)?,
+                })
+            })
+            .map_err(|e| format!("Failed to query rss items: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read rss items: {}", e))?;
+        Ok(items)
+    })
+}
+
+/// 标记条目为已读
+pub fn mark_read(app_data_dir: &Path, ids: &[i64]) -> Result<(), String> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+    let placeholders = vec!["?"; ids.len()].join(", ");
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute(&format!("UPDATE rss_items SET read = 1 WHERE id IN ({})", placeholders), params_from_iter(ids))
+            .map_err(|e| format!("Failed to mark rss items read: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 抓取所有订阅，单个订阅失败时记录错误并继续；返回新增条目数
+pub async fn refresh_all(app_data_dir: &Path) -> Result<usize, String> {
+    network::ensure_online()?;
+    let mut inserted = 0;
+    for feed in list_feeds(app_data_dir)? {
+        match fetch_feed(&feed.url).await {
+            Ok(parsed) => inserted += store_items(app_data_dir, feed.id, &parsed)?,
+            Err(e) => {
+                eprintln!("[RSS] Failed to fetch {}: {}", feed.url, e);
+                db::with_connection(app_data_dir, |conn| {
+                    conn.execute(
+                        "UPDATE rss_feeds SET last_fetched = ?2, last_error = ?3 WHERE id = ?1",
+                        params![feed.id, now_ts(), e],
+                    )
+                    .map_err(|e| format!("Failed to update rss feed: {}", e))
+                })?;
+            }
+        }
+    }
+    Ok(inserted)
+}
+
+/// 后台定时抓取，有新条目时发送 rss-updated 事件（负载为新增条数）
+pub fn start_fetcher(app_handle: AppHandle, app_data_dir: PathBuf) -> Result<(), String> {
+    shutdown::spawn("rss-fetcher", move || {
+        let mut interval = FIRST_FETCH_DELAY;
+        while !shutdown::wait_timeout(interval) {
+            interval = FETCH_INTERVAL;
+            if !network::is_online() {
+                continue;
+            }
+            match tauri::async_runtime::block_on(refresh_all(&app_data_dir)) {
+                Ok(0) => {}
+                Ok(inserted) => {
+                    let _ = app_handle.emit("rss-updated", inserted);
+                }
+                Err(e) => eprintln!("[RSS] Refresh failed: {}", e),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rss2() {
+        let xml = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>Example &amp; Co</title>
+            <link>https://example.com/</link>
+            <item><title>First</title><link>https://example.com/1</link><guid>id-1</guid>
+              <description><![CDATA[<p>Hello&nbsp;<b>world</b></p>]]></description>
+              <pubDate>Tue, 10 Jun 2025 04:00:00 GMT</pubDate></item>
+            <item><title>Second</title><link>https://example.com/2</link><description>a &lt;i&gt;b&lt;/i&gt;</description></item>
+            </channel></rss>"#;
+        let feed = parse_feed(xml).unwrap();
+        assert_eq!(feed.title, "Example & Co");
+        assert_eq!(feed.site_url.as_deref(), Some("https://example.com/"));
+        assert_eq!(feed.items.len(), 2);
+        assert_eq!(feed.items[0].guid, "id-1");
+        assert_eq!(feed.items[0].summary.as_deref(), Some("Hello world"));
+        assert_eq!(feed.items[0].published_at, Some(1749528000));
+        assert_eq!(feed.items[1].guid, "https://example.com/2");
+        assert_eq!(feed.items[1].summary.as_deref(), Some("a b"));
+    }
+
+    #[test]
+    fn parses_atom() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom"><title>Atom Blog</title>
+            <link rel="self" href="https://blog.example/feed.xml"/><link href="https://blog.example/"/>
+            <entry><title type="html">Post</title><id>urn:post:1</id>
+              <link rel="alternate" href="https://blog.example/post"/>
+              <updated>2025-06-10T04:00:00Z</updated><content type="html">&lt;p&gt;Body&lt;/p&gt;</content>
+              <author><name>Someone</name></author></entry></feed>"#;
+        let feed = parse_feed(xml).unwrap();
+        assert_eq!(feed.title, "Atom Blog");
+        assert_eq!(feed.site_url.as_deref(), Some("https://blog.example/"));
+        let item = &feed.items[0];
+        assert_eq!((item.guid.as_str(), item.link.as_str()), ("urn:post:1", "https://blog.example/post"));
+        assert_eq!(item.summary.as_deref(), Some("Body"));
+        assert_eq!(item.published_at, Some(1749528000));
+        assert!(parse_feed("<html><body>not a feed</body></html>").is_err());
+    }
+}
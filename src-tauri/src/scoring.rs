@@ -0,0 +1,270 @@
+// 统一的搜索打分：名称精确 / 前缀 / 包含匹配、拼音全拼 / 首字母匹配、模糊子序列匹配，以及使用次数加成
+// 应用、系统文件夹、文件历史、打开历史的搜索共用同一套权重，调整排序只需修改这里
+// 高级用户可以在设置中按数据源调整各类得分的倍率（名称、拼音、次要文本、路径、使用频率）
+
+use crate::pinyin_utils;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+
+pub const EXACT: i32 = 1000;
+pub const PREFIX: i32 = 500;
+pub const CONTAINS: i32 = 100;
+pub const PINYIN_EXACT: i32 = 800;
+pub const PINYIN_PREFIX: i32 = 400;
+pub const PINYIN_CONTAINS: i32 = 150;
+pub const INITIALS_EXACT: i32 = 600;
+pub const INITIALS_PREFIX: i32 = 300;
+pub const INITIALS_CONTAINS: i32 = 120;
+// 描述、显示名等次要文本命中
+pub const SECONDARY: i32 = 100;
+pub const PATH: i32 = 10;
+// 模糊子序列匹配的最高分，低于 CONTAINS，保证不会压过直接命中
+pub const FUZZY_MAX: i32 = 90;
+pub const USE_COUNT_MAX: i32 = 100;
+
+/// 支持单独调整权重的数据源
+pub const PROVIDERS: &[&str] = &["apps", "system_folders", "file_history", "open_history"];
+const MAX_WEIGHT: f32 = 10.0;
+
+/// 各类得分的倍率，1.0 为默认权重，0 表示忽略该类匹配
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoringWeights {
+    pub name: f32,      // 名称直接匹配及模糊匹配
+    pub pinyin: f32,    // 拼音全拼 / 首字母匹配
+    pub secondary: f32, // 描述、显示名
+    pub path: f32,
+    pub frecency: f32,  // 使用频率加成
+}
+
+impl Default for ScoringWeights {
+    fn default() -> Self {
+        ScoringWeights { name: 1.0, pinyin: 1.0, secondary: 1.0, path: 1.0, frecency: 1.0 }
+    }
+}
+
+static PROVIDER_WEIGHTS: LazyLock<RwLock<HashMap<String, ScoringWeights>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// 校验设置中的权重：数据源必须已知，倍率在 0..=10 之间
+pub fn validate_weights(weights: &HashMap<String, ScoringWeights>) -> Result<(), String> {
+    for (provider, w) in weights {
+        if !PROVIDERS.contains(&provider.as_str()) {
+            return Err(format!("未知的搜索数据源: {}", provider));
+        }
+        for (field, value) in [
+            ("name", w.name),
+            ("pinyin", w.pinyin),
+            ("secondary", w.secondary),
+            ("path", w.path),
+            ("frecency", w.frecency),
+        ] {
+            if !value.is_finite() || !(0.0..=MAX_WEIGHT).contains(&value) {
+                return Err(format!("{} 的 {} 权重必须在 0 到 {} 之间", provider, field, MAX_WEIGHT));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 应用设置中的权重（启动和保存设置时调用）
+pub fn set_weights(weights: &HashMap<String, ScoringWeights>) {
+    if let Ok(mut current) = PROVIDER_WEIGHTS.write() {
+        *current = weights.clone();
+    }
+}
+
+fn scaled(score: i32, weight: f32) -> i32 {
+    if weight == 1.0 {
+        score
+    } else {
+        (score as f32 * weight).round() as i32
+    }
+}
+
+/// 预处理后的查询（每次搜索只计算一次）
+pub struct Query {
+    pub lower: String,
+    pub is_pinyin: bool, // 不含中文时才尝试拼音匹配
+    pub weights: ScoringWeights,
+}
+
+impl Query {
+    /// 使用默认权重
+    pub fn new(query: &str) -> Self {
+        let lower = query.trim().to_lowercase();
+        let is_pinyin = !pinyin_utils::contains_chinese(&lower);
+        Query { lower, is_pinyin, weights: ScoringWeights::default() }
+    }
+
+    /// 使用设置中该数据源的权重
+    pub fn for_provider(query: &str, provider: &str) -> Self {
+        let mut query = Query::new(query);
+        if let Some(weights) = PROVIDER_WEIGHTS.read().ok().and_then(|w| w.get(provider).copied()) {
+            query.weights = weights;
+        }
+        query
+    }
+}
+
+fn tiered(text: &str, query: &str, exact: i32, prefix: i32, contains: i32) -> i32 {
+    if text.is_empty() {
+        0
+    } else if text == query {
+        exact
+    } else if text.starts_with(query) {
+        prefix
+    } else if text.contains(query) {
+        contains
+    } else {
+        0
+    }
+}
+
+/// 文本匹配得分（text 需为小写）
+pub fn text_score(query: &Query, text_lower: &str) -> i32 {
+    scaled(tiered(text_lower, &query.lower, EXACT, PREFIX, CONTAINS), query.weights.name)
+}
+
+/// 拼音匹配得分（全拼 + 首字母），full / initials 为预先计算的小写拼音
+pub fn pinyin_score(query: &Query, full: &str, initials: &str) -> i32 {
+    if !query.is_pinyin {
+        return 0;
+    }
+    let score = tiered(full, &query.lower, PINYIN_EXACT, PINYIN_PREFIX, PINYIN_CONTAINS)
+        + tiered(initials, &query.lower, INITIALS_EXACT, INITIALS_PREFIX, INITIALS_CONTAINS);
+    scaled(score, query.weights.pinyin)
+}
+
+/// 使用缓存的拼音计算得分
+pub fn cached_pinyin_score(query: &Query, text: &str) -> i32 {
+    match pinyin_utils::names(text) {
+        Some(names) => pinyin_score(query, &names.full, &names.initials),
+        None => 0,
+    }
+}
+
+/// 按名称权重计算的模糊匹配得分
+pub fn weighted_fuzzy_score(query: &Query, text_lower: &str) -> i32 {
+    scaled(fuzzy_score(&query.lower, text_lower), query.weights.name)
+}
+
+/// 模糊子序列匹配：查询字符按顺序出现在文本中（如 "vsc" 匹配 "visual studio code"）
+/// 连续命中和单词开头命中加分，结果归一化到 0..=FUZZY_MAX
+pub fn fuzzy_score(query_lower: &str, text_lower: &str) -> i32 {
+    let query_chars: Vec<char> = query_lower.chars().filter(|c| !c.is_whitespace()).collect();
+    if query_chars.len() < 2 {
+        return 0;
+    }
+
+    let mut raw = 0;
+    let mut matched = 0;
+    let mut prev_matched = false;
+    let mut prev_char: Option<char> = None;
+    for c in text_lower.chars() {
+        if matched < query_chars.len() && c == query_chars[matched] {
+            raw += 1;
+            if prev_matched {
+                raw += 2;
+            }
+            let at_word_start = match prev_char {
+                None => true,
+                Some(p) => matches!(p, ' ' | '-' | '_' | '.' | '\\' | '/'),
+            };
+            if at_word_start {
+                raw += 3;
+            }
+            matched += 1;
+            prev_matched = true;
+        } else {
+            prev_matched = false;
+        }
+        prev_char = Some(c);
+    }
+
+    if matched < query_chars.len() {
+        return 0;
+    }
+    let max_raw = query_chars.len() as i32 * 6;
+    (raw * FUZZY_MAX / max_raw).max(1)
+}
+
+/// 名称综合得分：直接匹配 + 拼音匹配，都未命中时退回模糊匹配
+pub fn name_score(query: &Query, name: &str) -> i32 {
+    let name_lower = name.to_lowercase();
+    let score = text_score(query, &name_lower) + cached_pinyin_score(query, name);
+    if score > 0 {
+        score
+    } else {
+        weighted_fuzzy_score(query, &name_lower)
+    }
+}
+
+/// 次要文本（描述、显示名）得分：文本或拼音包含查询即命中
+pub fn secondary_score(query: &Query, text: &str) -> i32 {
+    secondary_score_lower(query, text, &text.to_lowercase())
+}
+
+/// 次要文本得分，text_lower 为预先计算的小写形式
+pub fn secondary_score_lower(query: &Query, text: &str, text_lower: &str) -> i32 {
+    if text_lower.contains(&query.lower) || cached_pinyin_score(query, text) > 0 {
+        scaled(SECONDARY, query.weights.secondary)
+    } else {
+        0
+    }
+}
+
+/// 路径得分
+pub fn path_score(query: &Query, path: &str) -> i32 {
+    path_score_lower(query, &path.to_lowercase())
+}
+
+/// 路径得分，path_lower 为预先计算的小写形式
+pub fn path_score_lower(query: &Query, path_lower: &str) -> i32 {
+    if path_lower.contains(&query.lower) {
+        scaled(PATH, query.weights.path)
+    } else {
+        0
+    }
+}
+
+/// 使用频率加成（仅在已命中的结果上叠加）
+pub fn frecency_bonus(query: &Query, use_count: u64) -> i32 {
+    scaled(use_count.min(USE_COUNT_MAX as u64) as i32, query.weights.frecency)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn direct_matches_outrank_pinyin_and_fuzzy() {
+        let query = Query::new("wx");
+        assert_eq!(name_score(&query, "wx"), EXACT);
+        assert_eq!(name_score(&query, "微信"), INITIALS_EXACT);
+        assert!(name_score(&Query::new("vsc"), "Visual Studio Code") > 0);
+        assert!(name_score(&Query::new("vsc"), "Visual Studio Code") <= FUZZY_MAX);
+        assert_eq!(name_score(&Query::new("xyz"), "Visual Studio Code"), 0);
+    }
+
+    #[test]
+    fn applies_provider_weights() {
+        let mut query = Query::new("code");
+        query.weights.name = 0.5;
+        query.weights.path = 2.0;
+        assert_eq!(text_score(&query, "code"), EXACT / 2);
+        assert_eq!(path_score(&query, "C:\\Code\\app.exe"), PATH * 2);
+
+        let invalid = HashMap::from([("apps".to_string(), ScoringWeights { path: -1.0, ..Default::default() })]);
+        assert!(validate_weights(&invalid).is_err());
+        let unknown = HashMap::from([("nope".to_string(), ScoringWeights::default())]);
+        assert!(validate_weights(&unknown).is_err());
+    }
+
+    #[test]
+    fn fuzzy_prefers_word_starts() {
+        assert!(fuzzy_score("vsc", "visual studio code") > fuzzy_score("vsc", "avastscan"));
+        assert_eq!(fuzzy_score("v", "visual"), 0);
+    }
+}
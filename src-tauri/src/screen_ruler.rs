@@ -0,0 +1,306 @@
+// 屏幕标尺与坐标检查：报告鼠标的屏幕坐标、在所在窗口（含客户区）和显示器内的相对坐标，以及显示器的 DPI 与缩放比例
+// 供设计测量浮层和宏录制坐标校准使用；开启实时检查后按固定间隔推送 cursor-inspector 事件，位置和窗口不变时不推送
+
+use crate::shutdown;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+pub const INSPECTOR_EVENT: &str = "cursor-inspector";
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(33);
+const MIN_INTERVAL_MS: u64 = 16;
+const MAX_INTERVAL_MS: u64 = 1000;
+const BASE_DPI: f64 = 96.0;
+
+// 每次开启检查递增，旧的检查线程发现代数变化后退出；0 表示未开启
+static INSPECTOR_GENERATION: AtomicU64 = AtomicU64::new(0);
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ScreenRect {
+    pub left: i32,
+    pub top: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MonitorMetrics {
+    pub id: String, // 设备名，如 \\.\DISPLAY1
+    pub primary: bool,
+    pub bounds: ScreenRect,
+    pub dpi: u32,
+    pub scale: f64, // dpi / 96
+    pub x: i32,     // 鼠标相对显示器左上角的坐标（物理像素）
+    pub y: i32,
+    pub logical_x: f64, // 按缩放比例换算的逻辑坐标
+    pub logical_y: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WindowMetrics {
+    pub title: String,
+    pub class_name: String,
+    pub process_id: u32,
+    pub bounds: ScreenRect, // 窗口可见边框（不含阴影）
+    pub client: ScreenRect, // 客户区的屏幕位置
+    pub x: i32,             // 鼠标相对窗口左上角的坐标
+    pub y: i32,
+    pub client_x: i32, // 鼠标相对客户区左上角的坐标
+    pub client_y: i32,
+}
+
+/// 鼠标位置信息，坐标均为物理像素
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CursorInfo {
+    pub x: i32,
+    pub y: i32,
+    pub monitor: Option<MonitorMetrics>,
+    pub window: Option<WindowMetrics>, // 鼠标下的顶层窗口（忽略鼠标事件的浮层不计）
+}
+
+/// 两点间的测量结果
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Measurement {
+    pub width: i32, // 水平距离（物理像素）
+    pub height: i32,
+    pub distance: f64,
+    pub angle: f64, // 从起点指向终点的角度，水平向右为 0，逆时针为正（屏幕 y 轴向下）
+    pub scale: f64, // 起点所在显示器的缩放比例
+    pub logical_width: f64,
+    pub logical_height: f64,
+    pub logical_distance: f64,
+}
+
+fn scale_for_dpi(dpi: u32) -> f64 {
+    if dpi == 0 { 1.0 } else { dpi as f64 / BASE_DPI }
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+/// 计算两点间的距离，逻辑尺寸按给定缩放比例换算
+pub fn measure_points(from: (i32, i32), to: (i32, i32), scale: f64) -> Measurement {
+    let dx = to.0 - from.0;
+    let dy = to.1 - from.1;
+    let distance = (dx as f64).hypot(dy as f64);
+    let scale = if scale > 0.0 { scale } else { 1.0 };
+    Measurement {
+        width: dx.abs(),
+        height: dy.abs(),
+        distance: round2(distance),
+        angle: round2((-dy as f64).atan2(dx as f64).to_degrees()),
+        scale,
+        logical_width: round2(dx.abs() as f64 / scale),
+        logical_height: round2(dy.abs() as f64 / scale),
+        logical_distance: round2(distance / scale),
+    }
+}
+
+/// 当前鼠标位置及所在窗口、显示器的信息
+pub fn cursor_info() -> Result<CursorInfo, String> {
+    platform::cursor_info()
+}
+
+/// 测量两点间距离，逻辑尺寸使用起点所在显示器的缩放比例
+pub fn measure(from: (i32, i32), to: (i32, i32)) -> Result<Measurement, String> {
+    let scale = platform::monitor_at(from.0, from.1).map(|m| m.scale).unwrap_or(1.0);
+    Ok(measure_points(from, to, scale))
+}
+
+/// 开启实时检查（已开启时按新的间隔重启），interval_ms 限制在 16~1000 之间
+pub fn start_inspector(app: AppHandle, interval_ms: Option<u64>) -> Result<(), String> {
+    // 先确认当前平台可用，避免启动一个只会报错的线程
+    platform::cursor_info()?;
+    let interval = interval_ms
+        .map(|ms| Duration::from_millis(ms.clamp(MIN_INTERVAL_MS, MAX_INTERVAL_MS)))
+        .unwrap_or(DEFAULT_INTERVAL);
+    let generation = NEXT_GENERATION.fetch_add(1, Ordering::SeqCst);
+    INSPECTOR_GENERATION.store(generation, Ordering::SeqCst);
+
+    shutdown::spawn("cursor-inspector", move || {
+        let mut last: Option<CursorInfo> = None;
+        while INSPECTOR_GENERATION.load(Ordering::SeqCst) == generation {
+            if let Ok(info) = platform::cursor_info() {
+                if last.as_ref() != Some(&info) {
+                    if let Err(e) = app.emit(INSPECTOR_EVENT, &info) {
+                        eprintln!("[ScreenRuler] Failed to emit cursor info: {}", e);
+                    }
+                    last = Some(info);
+                }
+            }
+            if shutdown::wait_timeout(interval) {
+                break;
+            }
+        }
+    })
+}
+
+/// 停止实时检查，未开启时返回 false
+pub fn stop_inspector() -> bool {
+    INSPECTOR_GENERATION.swap(0, Ordering::SeqCst) != 0
+}
+
+fn monitor_metrics(id: String, primary: bool, bounds: ScreenRect, dpi: u32, x: i32, y: i32) -> MonitorMetrics {
+    let scale = scale_for_dpi(dpi);
+    let (x, y) = (x - bounds.left, y - bounds.top);
+    MonitorMetrics {
+        id,
+        primary,
+        bounds,
+        dpi,
+        scale,
+        x,
+        y,
+        logical_x: round2(x as f64 / scale),
+        logical_y: round2(y as f64 / scale),
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::{monitor_metrics, CursorInfo, MonitorMetrics, ScreenRect, WindowMetrics};
+    use windows_sys::Win32::Foundation::{POINT, RECT};
+    use windows_sys::Win32::Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS};
+    use windows_sys::Win32::Graphics::Gdi::{
+        ClientToScreen, GetMonitorInfoW, MonitorFromPoint, MONITORINFO, MONITORINFOEXW, MONITOR_DEFAULTTONEAREST,
+    };
+    use windows_sys::Win32::UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI};
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        GetAncestor, GetClassNameW, GetClientRect, GetCursorPos, GetWindowRect, GetWindowTextW,
+        GetWindowThreadProcessId, WindowFromPoint, GA_ROOT, MONITORINFOF_PRIMARY,
+    };
+
+    fn from_wide(buf: &[u16]) -> String {
+        let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+        String::from_utf16_lossy(&buf[..len])
+    }
+
+    fn to_rect(rect: &RECT) -> ScreenRect {
+        ScreenRect { left: rect.left, top: rect.top, width: rect.right - rect.left, height: rect.bottom - rect.top }
+    }
+
+    pub fn cursor_info() -> Result<CursorInfo, String> {
+        let mut point = POINT { x: 0, y: 0 };
+        if unsafe { GetCursorPos(&mut point) } == 0 {
+            return Err("获取鼠标位置失败".to_string());
+        }
+        Ok(CursorInfo { x: point.x, y: point.y, monitor: monitor_at(point.x, point.y), window: window_at(point) })
+    }
+
+    pub fn monitor_at(x: i32, y: i32) -> Option<MonitorMetrics> {
+        let monitor = unsafe { MonitorFromPoint(POINT { x, y }, MONITOR_DEFAULTTONEAREST) };
+        if monitor == 0 {
+            return None;
+        }
+        let mut info: MONITORINFOEXW = unsafe { std::mem::zeroed() };
+        info.monitorInfo.cbSize = std::mem::size_of::<MONITORINFOEXW>() as u32;
+        if unsafe { GetMonitorInfoW(monitor, &mut info as *mut MONITORINFOEXW as *mut MONITORINFO) } == 0 {
+            return None;
+        }
+        let (mut dpi_x, mut dpi_y) = (0u32, 0u32);
+        // 失败时（如旧系统）按 96 DPI 处理
+        if unsafe { GetDpiForMonitor(monitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) } != 0 {
+            dpi_x = 96;
+        }
+        Some(monitor_metrics(
+            from_wide(&info.szDevice),
+            info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY != 0,
+            to_rect(&info.monitorInfo.rcMonitor),
+            dpi_x,
+            x,
+            y,
+        ))
+    }
+
+    fn window_at(point: POINT) -> Option<WindowMetrics> {
+        let hwnd = unsafe { GetAncestor(WindowFromPoint(point), GA_ROOT) };
+        if hwnd == 0 {
+            return None;
+        }
+        // 优先使用 DWM 的可见边框，GetWindowRect 在 Windows 10+ 上包含不可见的阴影边框
+        let mut frame: RECT = unsafe { std::mem::zeroed() };
+        let dwm_ok = unsafe {
+            DwmGetWindowAttribute(
+                hwnd,
+                DWMWA_EXTENDED_FRAME_BOUNDS as u32,
+                &mut frame as *mut RECT as *mut _,
+                std::mem::size_of::<RECT>() as u32,
+            )
+        } == 0;
+        if !dwm_ok && unsafe { GetWindowRect(hwnd, &mut frame) } == 0 {
+            return None;
+        }
+
+        let mut client: RECT = unsafe { std::mem::zeroed() };
+        let mut origin = POINT { x: 0, y: 0 };
+        unsafe {
+            GetClientRect(hwnd, &mut client);
+            ClientToScreen(hwnd, &mut origin);
+        }
+
+        let mut title = [0u16; 256];
+        let mut class_name = [0u16; 256];
+        let mut process_id = 0u32;
+        unsafe {
+            GetWindowTextW(hwnd, title.as_mut_ptr(), title.len() as i32);
+            GetClassNameW(hwnd, class_name.as_mut_ptr(), class_name.len() as i32);
+            GetWindowThreadProcessId(hwnd, &mut process_id);
+        }
+
+        Some(WindowMetrics {
+            title: from_wide(&title),
+            class_name: from_wide(&class_name),
+            process_id,
+            bounds: to_rect(&frame),
+            client: ScreenRect { left: origin.x, top: origin.y, width: client.right, height: client.bottom },
+            x: point.x - frame.left,
+            y: point.y - frame.top,
+            client_x: point.x - origin.x,
+            client_y: point.y - origin.y,
+        })
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    use super::{CursorInfo, MonitorMetrics};
+
+    pub fn cursor_info() -> Result<CursorInfo, String> {
+        Err("当前平台不支持坐标检查".to_string())
+    }
+
+    pub fn monitor_at(_x: i32, _y: i32) -> Option<MonitorMetrics> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_points_with_scale() {
+        let m = measure_points((100, 200), (400, 600), 1.5);
+        assert_eq!((m.width, m.height), (300, 400));
+        assert_eq!(m.distance, 500.0);
+        assert_eq!(m.logical_distance, 333.33);
+        assert_eq!((m.logical_width, m.logical_height), (200.0, 266.67));
+        assert_eq!(m.angle, -53.13);
+        assert_eq!(measure_points((0, 0), (10, -10), 0.0).angle, 45.0);
+
+        let monitor = monitor_metrics(
+            "\\\\.\\DISPLAY2".into(),
+            false,
+            ScreenRect { left: 1920, top: -200, width: 2560, height: 1440 },
+            144,
+            2070,
+            100,
+        );
+        assert_eq!((monitor.x, monitor.y), (150, 300));
+        assert_eq!((monitor.logical_x, monitor.logical_y), (100.0, 200.0));
+        assert_eq!(monitor.scale, 1.5);
+    }
+}
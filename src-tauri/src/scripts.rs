@@ -0,0 +1,544 @@
+// 自动化脚本：用户在 scripts 文件夹中编写 rhai 脚本，通过启动器关键字或全局快捷键触发
+// 脚本只能调用这里注册的函数（剪贴板、搜索、启动、通知、白名单内的 HTTP），文件夹内容变化时自动重新加载
+// HTTP 白名单保存在设置中，由用户确认脚本 @allow_http 申请的主机后写入，脚本自己无法扩大可访问范围
+
+use crate::api_policy::{self, Policy};
+use crate::settings::HotkeyConfig;
+use crate::{clipboard, file_history, focus_mode, http_client, memos, settings, shutdown};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use rhai::module_resolvers::DummyModuleResolver;
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Map, Scope};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+pub const CHANGED_EVENT: &str = "scripts://changed";
+pub const HOTKEY_PREFIX: &str = "script:";
+const SCRIPTS_DIR: &str = "scripts";
+const SCRIPT_EXTENSION: &str = "rhai";
+/// 文件保存时编辑器常连续写入多次，合并后再重新加载
+const RELOAD_DEBOUNCE: Duration = Duration::from_millis(500);
+// 运行限制：超过操作数或运行时间即中止，防止死循环卡住后台线程
+const MAX_OPERATIONS: u64 = 10_000_000;
+const TIMEOUT: Duration = Duration::from_secs(15);
+const MAX_CALL_LEVELS: usize = 64;
+const MAX_STRING_SIZE: usize = 4 * 1024 * 1024;
+const MAX_COLLECTION_SIZE: usize = 100_000;
+const MAX_LOGS: usize = 200;
+const HTTP_TIMEOUT: Duration = Duration::from_secs(20);
+const SEARCH_LIMIT: usize = 10;
+
+/// 脚本开头注释中的元数据，如 `// @keyword up`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ScriptMeta {
+    pub name: String,
+    pub description: String,
+    pub keyword: Option<String>,
+    pub hotkey: Option<HotkeyConfig>,
+    pub allow_http: Vec<String>, // 申请访问的主机（同时包括其子域名），用户在设置中确认后才生效
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScriptInfo {
+    pub id: String, // 文件名（不含扩展名）
+    pub path: String,
+    #[serde(flatten)]
+    pub meta: ScriptMeta,
+    pub error: Option<String>, // 语法错误或元数据错误，有错误的脚本不会绑定关键字和快捷键
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScriptRunResult {
+    pub output: String, // 脚本最后一个表达式的值，没有返回值时为空
+    pub logs: Vec<String>, // print / debug 输出
+    pub duration_ms: u64,
+}
+
+#[derive(Default)]
+struct ScriptState {
+    app_data_dir: Option<PathBuf>,
+    scripts: Vec<ScriptInfo>,
+    watcher: Option<RecommendedWatcher>,
+    hotkey_ids: Vec<String>,
+}
+
+static STATE: LazyLock<Mutex<ScriptState>> = LazyLock::new(|| Mutex::new(ScriptState::default()));
+static APP: OnceLock<AppHandle> = OnceLock::new();
+static DIRTY: AtomicBool = AtomicBool::new(false);
+
+pub fn scripts_dir(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join(SCRIPTS_DIR)
+}
+
+// ---------- 元数据 ----------
+
+/// 解析快捷键，如 "Ctrl+Alt+T"、"win+shift+space"
+pub fn parse_hotkey(value: &str) -> Result<HotkeyConfig, String> {
+    let parts: Vec<&str> = value.split('+').map(str::trim).filter(|p| !p.is_empty()).collect();
+    let Some((key, modifiers)) = parts.split_last() else {
+        return Err("快捷键不能为空".to_string());
+    };
+    let modifiers = modifiers
+        .iter()
+        .map(|m| match m.to_lowercase().as_str() {
+            "ctrl" | "control" => Ok("Ctrl".to_string()),
+            "alt" => Ok("Alt".to_string()),
+            "shift" => Ok("Shift".to_string()),
+            "win" | "meta" | "super" => Ok("Meta".to_string()),
+            other => Err(format!("无法识别的修饰键: {}", other)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if modifiers.is_empty() {
+        return Err("快捷键至少需要一个修饰键".to_string());
+    }
+    let key = if key.chars().count() == 1 {
+        key.to_uppercase()
+    } else {
+        let mut chars = key.chars();
+        chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+    };
+    Ok(HotkeyConfig { modifiers, key, apps: Vec::new() })
+}
+
+/// 读取脚本开头连续的注释行中的 `@字段 值`，遇到第一行代码即停止
+pub fn parse_header(source: &str, default_name: &str) -> Result<ScriptMeta, String> {
+    let mut meta = ScriptMeta { name: default_name.to_string(), ..Default::default() };
+    let mut apps = Vec::new();
+    for line in source.lines().map(str::trim) {
+        if line.is_empty() {
+            continue;
+        }
+        let Some(comment) = line.strip_prefix("//") else {
+            break;
+        };
+        let Some(field) = comment.trim().strip_prefix('@') else {
+            continue;
+        };
+        let (key, value) = field.split_once(char::is_whitespace).unwrap_or((field, ""));
+        let value = value.trim();
+        match key {
+            "name" if !value.is_empty() => meta.name = value.to_string(),
+            "description" => meta.description = value.to_string(),
+            "keyword" => {
+                if value.is_empty() || value.contains(char::is_whitespace) {
+                    return Err("@keyword 必须是不含空格的单个词".to_string());
+                }
+                meta.keyword = Some(value.to_lowercase());
+            }
+            "hotkey" => meta.hotkey = Some(parse_hotkey(value)?),
+            "apps" => {
+                let rules: Vec<String> = value.split(',').map(str::to_string).collect();
+                apps.extend(crate::app_scope::normalize(&rules)?);
+            }
+            "allow_http" => meta.allow_http.extend(normalize_hosts(value.split([',', ' ']))),
+            _ => {}
+        }
+    }
+    if let Some(hotkey) = meta.hotkey.as_mut() {
+        hotkey.apps = apps;
+    }
+    Ok(meta)
+}
+
+/// 主机统一为小写，去掉 *. 前缀（白名单本身就包括子域名）
+pub fn normalize_hosts<'a>(hosts: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for host in hosts.into_iter().map(|host| host.trim().trim_start_matches("*.").to_lowercase()) {
+        if !host.is_empty() && !normalized.contains(&host) {
+            normalized.push(host);
+        }
+    }
+    normalized
+}
+
+/// 只允许 http/https，主机与白名单中的某项相同或是其子域名
+pub fn host_allowed(url: &str, allow: &[String]) -> Result<(), String> {
+    let url = reqwest::Url::parse(url).map_err(|e| format!("无效的地址 {}: {}", url, e))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err("只能访问 http 或 https 地址".to_string());
+    }
+    let host = url.host_str().unwrap_or_default().to_lowercase();
+    if allow.iter().any(|allowed| host == *allowed || host.ends_with(&format!(".{}", allowed))) {
+        Ok(())
+    } else {
+        Err(format!("{} 不在已允许的主机中，请在设置的自动化脚本中确认", host))
+    }
+}
+
+// ---------- 加载与热更新 ----------
+
+fn load_scripts(dir: &Path) -> Vec<ScriptInfo> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == SCRIPT_EXTENSION))
+        .collect();
+    paths.sort();
+
+    let engine = Engine::new_raw();
+    let mut scripts: Vec<ScriptInfo> = Vec::new();
+    for path in paths {
+        let id = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+        let (meta, error) = match fs::read_to_string(&path) {
+            Ok(source) => match parse_header(&source, &id) {
+                Ok(meta) => {
+                    let error = engine.compile(&source).err().map(|e| format!("语法错误: {}", e));
+                    (meta, error)
+                }
+                Err(e) => (ScriptMeta { name: id.clone(), ..Default::default() }, Some(e)),
+            },
+            Err(e) => (ScriptMeta { name: id.clone(), ..Default::default() }, Some(format!("读取失败: {}", e))),
+        };
+        let duplicate = meta.keyword.as_ref().and_then(|keyword| {
+            scripts
+                .iter()
+                .find(|s| s.error.is_none() && s.meta.keyword.as_ref() == Some(keyword))
+                .map(|s| format!("关键字 {} 与脚本 {} 重复", keyword, s.id))
+        });
+        scripts.push(ScriptInfo {
+            id,
+            path: path.to_string_lossy().to_string(),
+            meta,
+            error: error.or(duplicate),
+        });
+    }
+    scripts
+}
+
+/// 重新读取脚本文件夹，更新快捷键绑定并通知前端
+fn reload() {
+    let Some(app_data_dir) = STATE.lock().ok().and_then(|state| state.app_data_dir.clone()) else {
+        return;
+    };
+    let scripts = load_scripts(&scripts_dir(&app_data_dir));
+    let Ok(mut state) = STATE.lock() else {
+        return;
+    };
+    for id in state.hotkey_ids.drain(..) {
+        let _ = crate::hotkey_handler::windows::unregister_plugin_hotkey(&id);
+    }
+    for script in scripts.iter().filter(|s| s.error.is_none()) {
+        if let Some(hotkey) = &script.meta.hotkey {
+            let id = format!("{}{}", HOTKEY_PREFIX, script.id);
+            match crate::hotkey_handler::windows::register_plugin_hotkey(id.clone(), hotkey.clone()) {
+                Ok(()) => state.hotkey_ids.push(id),
+                Err(e) => eprintln!("[Scripts] Failed to register hotkey for {}: {}", script.id, e),
+            }
+        }
+    }
+    state.scripts = scripts.clone();
+    drop(state);
+    if let Some(app) = APP.get() {
+        if let Err(e) = app.emit(CHANGED_EVENT, &scripts) {
+            eprintln!("[Scripts] Failed to emit {}: {}", CHANGED_EVENT, e);
+        }
+    }
+}
+
+/// 加载脚本并监听脚本文件夹，文件变化时自动重新加载
+pub fn start(app: AppHandle, app_data_dir: PathBuf) -> Result<(), String> {
+    let dir = scripts_dir(&app_data_dir);
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create scripts dir: {}", e))?;
+    let _ = APP.set(app);
+    if let Ok(mut state) = STATE.lock() {
+        state.app_data_dir = Some(app_data_dir);
+    }
+    reload();
+
+    let mut watcher = RecommendedWatcher::new(
+        |result: Result<Event, notify::Error>| match result {
+            Ok(event) => {
+                if event.paths.iter().any(|p| p.extension().is_some_and(|ext| ext == SCRIPT_EXTENSION)) {
+                    DIRTY.store(true, Ordering::Relaxed);
+                }
+            }
+            Err(e) => eprintln!("[Scripts] Watch error: {}", e),
+        },
+        notify::Config::default(),
+    )
+    .map_err(|e| format!("Failed to create scripts watcher: {}", e))?;
+    watcher
+        .watch(&dir, RecursiveMode::NonRecursive)
+        .map_err(|e| format!("Failed to watch {}: {}", dir.display(), e))?;
+    if let Ok(mut state) = STATE.lock() {
+        state.watcher = Some(watcher);
+    }
+
+    shutdown::spawn("scripts-reload", || loop {
+        if shutdown::wait_timeout(RELOAD_DEBOUNCE) {
+            break;
+        }
+        if DIRTY.swap(false, Ordering::Relaxed) {
+            reload();
+        }
+    })
+}
+
+pub fn list_scripts() -> Vec<ScriptInfo> {
+    STATE.lock().map(|state| state.scripts.clone()).unwrap_or_default()
+}
+
+/// 新建脚本模板，文件名取自名称（去掉文件名中不允许的字符），同名时追加序号
+pub fn create_script(app_data_dir: &Path, name: &str, keyword: Option<&str>) -> Result<PathBuf, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("脚本名称不能为空".to_string());
+    }
+    let stem: String = name
+        .chars()
+        .map(|c| if c.is_control() || r#"\/:*?"<>|"#.contains(c) { '_' } else { c })
+        .collect();
+    let dir = scripts_dir(app_data_dir);
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create scripts dir: {}", e))?;
+    let mut path = dir.join(format!("{}.{}", stem, SCRIPT_EXTENSION));
+    let mut index = 2;
+    while path.exists() {
+        path = dir.join(format!("{}-{}.{}", stem, index, SCRIPT_EXTENSION));
+        index += 1;
+    }
+    let keyword_line = match keyword.map(str::trim).filter(|k| !k.is_empty()) {
+        Some(keyword) => format!("// @keyword {}\n", keyword),
+        None => String::new(),
+    };
+    let template = format!(
+        "// @name {name}\n\
+         // @description 把输入或剪贴板中的文本转为大写并复制\n\
+         {keyword_line}\
+         //\n\
+         // 可选字段（各占一行，写成 \"// @字段 值\"）：@keyword 启动器关键字，@hotkey 全局快捷键（如 Ctrl+Alt+U），\n\
+         // @apps 快捷键只在这些程序中生效（如 code.exe, class:Notepad，加 ! 表示排除），\n\
+         // @allow_http 申请访问的主机（如 api.example.com，多个用逗号分隔），需在设置中确认后才能访问\n\
+         // 可用函数：clipboard_get()、clipboard_set(text)、search(query)、launch(target)、\n\
+         // notify(title, body)、http_get(url)、http_post(url, body)\n\
+         // input 为启动器中关键字之后的文本（快捷键触发时为空）；返回值显示在启动器中，快捷键触发时以通知显示\n\
+         \n\
+         let text = if input != \"\" {{ input }} else {{ clipboard_get() }};\n\
+         let upper = text.to_upper();\n\
+         clipboard_set(upper);\n\
+         upper\n"
+    );
+    fs::write(&path, template).map_err(|e| format!("Failed to write script: {}", e))?;
+    DIRTY.store(true, Ordering::Relaxed);
+    Ok(path)
+}
+
+// ---------- 运行 ----------
+
+/// 带运行限制的引擎：禁止 import 外部模块，print / debug 输出收集到 logs
+fn sandboxed_engine(logs: Arc<Mutex<Vec<String>>>) -> Engine {
+    let mut engine = Engine::new();
+    engine.set_module_resolver(DummyModuleResolver::new());
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.set_max_call_levels(MAX_CALL_LEVELS);
+    engine.set_max_string_size(MAX_STRING_SIZE);
+    engine.set_max_array_size(MAX_COLLECTION_SIZE);
+    engine.set_max_map_size(MAX_COLLECTION_SIZE);
+    let started = Instant::now();
+    engine.on_progress(move |_| (started.elapsed() > TIMEOUT).then(|| Dynamic::from("运行超时")));
+    let print_logs = logs.clone();
+    engine.on_print(move |text| push_log(&print_logs, text.to_string()));
+    engine.on_debug(move |text, _, pos| push_log(&logs, format!("[{}] {}", pos, text)));
+    engine
+}
+
+fn push_log(logs: &Mutex<Vec<String>>, line: String) {
+    if let Ok(mut logs) = logs.lock() {
+        if logs.len() < MAX_LOGS {
+            logs.push(line);
+        }
+    }
+}
+
+fn runtime_error(message: String) -> Box<EvalAltResult> {
+    message.into()
+}
+
+/// 搜索结果：应用的 target 为路径，备忘录和剪切板为内容
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub kind: &'static str,
+    pub title: String,
+    pub target: String,
+}
+
+/// 依次在应用、备忘录和剪切板历史（仅文本）中搜索
+pub fn search_items(query: &str, app_data_dir: &Path) -> Result<Vec<SearchHit>, String> {
+    let mut hits = Vec::new();
+    #[cfg(target_os = "windows")]
+    if let Ok(apps) = crate::app_search::windows::load_cache(app_data_dir) {
+        for app in crate::app_search::windows::search_apps(query, &apps).into_iter().take(SEARCH_LIMIT) {
+            hits.push(SearchHit { kind: "app", title: app.name, target: app.path });
+        }
+    }
+    for memo in memos::search_memos(query, &app_data_dir.to_path_buf())?.into_iter().take(SEARCH_LIMIT) {
+        hits.push(SearchHit { kind: "memo", title: memo.title, target: memo.content });
+    }
+    let clips = clipboard::search_clipboard_items(query, &app_data_dir.to_path_buf())?;
+    for item in clips.into_iter().filter(|item| item.content_type == "text").take(SEARCH_LIMIT) {
+        hits.push(SearchHit { kind: "clipboard", title: item.content.chars().take(80).collect(), target: item.content });
+    }
+    Ok(hits)
+}
+
+/// 脚本中的搜索结果为 #{kind, title, target}
+fn search(query: &str, app_data_dir: &Path) -> Result<Array, String> {
+    let hits = search_items(query, app_data_dir)?;
+    Ok(hits
+        .into_iter()
+        .map(|hit| {
+            let mut map = Map::new();
+            map.insert("kind".into(), hit.kind.into());
+            map.insert("title".into(), hit.title.into());
+            map.insert("target".into(), hit.target.into());
+            Dynamic::from(map)
+        })
+        .collect())
+}
+
+fn http_request(url: &str, body: Option<String>, allow: &[String]) -> Result<String, String> {
+    host_allowed(url, allow)?;
+    let client = http_client::client()?;
+    let provider = api_policy::provider_of(url);
+    tauri::async_runtime::block_on(async {
+        let response = api_policy::send(&provider, &Policy::SCRIPTS, || {
+            let request = match &body {
+                Some(body) => {
+                    let trimmed = body.trim_start();
+                    let content_type = if trimmed.starts_with('{') || trimmed.starts_with('[') {
+                        "application/json"
+                    } else {
+                        "text/plain; charset=utf-8"
+                    };
+                    client.post(url).header("Content-Type", content_type).body(body.clone())
+                }
+                None => client.get(url),
+            };
+            request.timeout(HTTP_TIMEOUT)
+        })
+        .await
+        .map_err(|e| e.to_string())?;
+        response.text().await.map_err(|e| format!("读取响应失败: {}", e))
+    })
+}
+
+/// 注册脚本可调用的函数
+fn register_api(engine: &mut Engine, app: Option<AppHandle>, app_data_dir: PathBuf, allow_http: Vec<String>) {
+    engine.register_fn("clipboard_get", || clipboard::read_text().map_err(runtime_error));
+    engine.register_fn("clipboard_set", |text: &str| clipboard::write_text(text).map_err(runtime_error));
+    engine.register_fn("search", move |query: &str| search(query, &app_data_dir).map_err(runtime_error));
+    engine.register_fn("launch", |target: &str| file_history::launch_file(target).map_err(runtime_error));
+    let notify_app = app.clone();
+    engine.register_fn("notify", move |title: &str, body: &str| show_notification(notify_app.as_ref(), title, body));
+    engine.register_fn("notify", move |body: &str| show_notification(app.as_ref(), "IMiss", body));
+    let allow = Arc::new(allow_http);
+    let get_allow = allow.clone();
+    engine.register_fn("http_get", move |url: &str| http_request(url, None, &get_allow).map_err(runtime_error));
+    engine.register_fn("http_post", move |url: &str, body: &str| {
+        http_request(url, Some(body.to_string()), &allow).map_err(runtime_error)
+    });
+}
+
+fn show_notification(app: Option<&AppHandle>, title: &str, body: &str) -> Result<(), Box<EvalAltResult>> {
+    let app = app.ok_or_else(|| runtime_error("通知不可用".to_string()))?;
+    focus_mode::show_notification(app, title, body, None).map_err(runtime_error)
+}
+
+/// 运行脚本，input 作为常量 input 传入
+pub fn run_script(id: &str, input: &str) -> Result<ScriptRunResult, String> {
+    let (script, app_data_dir) = {
+        let state = STATE.lock().map_err(|e| format!("Failed to lock scripts: {}", e))?;
+        let script = state.scripts.iter().find(|s| s.id == id).cloned();
+        (script, state.app_data_dir.clone())
+    };
+    let script = script.ok_or_else(|| format!("脚本 {} 不存在", id))?;
+    if let Some(error) = &script.error {
+        return Err(error.clone());
+    }
+    let app_data_dir = app_data_dir.ok_or_else(|| "脚本尚未加载".to_string())?;
+    let allow_http = settings::load_settings(&app_data_dir)?.script_http_allow.remove(id).unwrap_or_default();
+    let source = fs::read_to_string(&script.path).map_err(|e| format!("读取脚本失败: {}", e))?;
+
+    let logs = Arc::new(Mutex::new(Vec::new()));
+    let mut engine = sandboxed_engine(logs.clone());
+    register_api(&mut engine, APP.get().cloned(), app_data_dir, allow_http);
+    let ast = engine.compile(&source).map_err(|e| format!("语法错误: {}", e))?;
+    let mut scope = Scope::new();
+    scope.push_constant("input", input.to_string());
+
+    let started = Instant::now();
+    let value = engine
+        .eval_ast_with_scope::<Dynamic>(&mut scope, &ast)
+        .map_err(|e| format!("运行失败: {}", e))?;
+    let logs = logs.lock().map(|logs| logs.clone()).unwrap_or_default();
+    Ok(ScriptRunResult {
+        output: if value.is_unit() { String::new() } else { value.to_string() },
+        logs,
+        duration_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+/// 快捷键触发：在后台运行，输出或错误以通知显示
+pub fn run_from_hotkey(id: &str) {
+    let id = id.to_string();
+    std::thread::spawn(move || {
+        let name = list_scripts().into_iter().find(|s| s.id == id).map(|s| s.meta.name).unwrap_or_else(|| id.clone());
+        let (title, body) = match run_script(&id, "") {
+            Ok(result) if result.output.is_empty() => return,
+            Ok(result) => (name, result.output),
+            Err(e) => (format!("{} 运行失败", name), e),
+        };
+        let body: String = body.chars().take(200).collect();
+        if let Err(e) = show_notification(APP.get(), &title, &body) {
+            eprintln!("[Scripts] Failed to show notification: {}", e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_script_header() {
+        let source = "// @name 大写\n// 说明文字\n// @keyword UP\n// @apps code, !class:Notepad\n// @hotkey ctrl+alt+u\n// @allow_http api.example.com, *.github.com\n\nlet x = 1;\n// @keyword ignored";
+        let meta = parse_header(source, "upper").unwrap();
+        assert_eq!(meta.name, "大写");
+        assert_eq!(meta.keyword.as_deref(), Some("up"));
+        assert_eq!(
+            meta.hotkey,
+            Some(HotkeyConfig {
+                modifiers: vec!["Ctrl".to_string(), "Alt".to_string()],
+                key: "U".to_string(),
+                apps: vec!["code".to_string(), "!class:Notepad".to_string()],
+            })
+        );
+        assert_eq!(meta.allow_http, vec!["api.example.com", "github.com"]);
+        assert_eq!(parse_header("x", "id").unwrap().name, "id");
+        assert!(parse_header("// @keyword two words", "id").is_err());
+        assert!(parse_hotkey("T").is_err());
+        assert_eq!(parse_hotkey("Win+Shift+space").unwrap().key, "Space");
+
+        let allow = vec!["example.com".to_string()];
+        assert!(host_allowed("https://api.example.com/x", &allow).is_ok());
+        assert!(host_allowed("https://example.com.evil.net/", &allow).is_err());
+        assert!(host_allowed("file:///c:/secret", &allow).is_err());
+        assert_eq!(normalize_hosts(["*.Example.com", " example.com", ""]), vec!["example.com"]);
+    }
+
+    #[test]
+    fn sandbox_limits_scripts() {
+        let logs = Arc::new(Mutex::new(Vec::new()));
+        let engine = sandboxed_engine(logs.clone());
+        assert_eq!(engine.eval::<i64>("print(\"hi\"); 40 + 2").unwrap(), 42);
+        assert_eq!(*logs.lock().unwrap(), vec!["hi".to_string()]);
+        assert!(engine.eval::<Dynamic>("loop {}").is_err());
+        assert!(engine.eval::<Dynamic>("import \"other\" as o; 1").is_err());
+    }
+}
@@ -0,0 +1,159 @@
+// 搜索数据源开关与排序：控制启动器运行哪些数据源、各数据源的优先级偏置和结果数量上限
+// 设置保存后通过事件总线的 settings-changed 事件热更新，并以 providers://changed 通知前端
+
+use crate::event_bus::{self, BusEvent};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast;
+
+/// 启动器的数据源，与前端 combineResultsUtils 中的来源标记保持一致；
+/// network_tools 之后为按关键字触发的面板（ip、cpu、audio、rss、timer 等）
+pub const PROVIDERS: &[&str] = &[
+    "apps",
+    "system_folders",
+    "file_history",
+    "everything",
+    "url_history",
+    "browser_history",
+    "memos",
+    "plugins",
+    "hot_folders",
+    "ssh_hosts",
+    "git_repos",
+    "recent_projects",
+    "games",
+    "aliases",
+    "network_tools",
+    "system_monitor",
+    "audio_devices",
+    "display_control",
+    "radios",
+    "window_actions",
+    "virtual_desktops",
+    "keep_awake",
+    "text_transform",
+    "password_gen",
+    "totp",
+    "world_clock",
+    "quotes",
+    "rss",
+    "timers",
+    "calendar",
+    "habits",
+    "scripts",
+    "workflows",
+];
+pub const CHANGED_EVENT: &str = "providers://changed";
+const MAX_PRIORITY: i32 = 100;
+const MAX_RESULTS_LIMIT: u32 = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProviderSettings {
+    pub enabled: bool,
+    pub priority: i32,    // 排序偏置，-100..=100，越大越靠前，0 为默认
+    pub max_results: u32, // 结果数量上限，0 表示不限制
+}
+
+impl Default for ProviderSettings {
+    fn default() -> Self {
+        ProviderSettings { enabled: true, priority: 0, max_results: 0 }
+    }
+}
+
+static PROVIDER_SETTINGS: LazyLock<RwLock<HashMap<String, ProviderSettings>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// 校验设置中的数据源配置：数据源必须已知，优先级和数量上限在允许范围内
+pub fn validate(providers: &HashMap<String, ProviderSettings>) -> Result<(), String> {
+    for (provider, p) in providers {
+        if !PROVIDERS.contains(&provider.as_str()) {
+            return Err(format!("未知的搜索数据源: {}", provider));
+        }
+        if !(-MAX_PRIORITY..=MAX_PRIORITY).contains(&p.priority) {
+            return Err(format!("{} 的优先级必须在 -{} 到 {} 之间", provider, MAX_PRIORITY, MAX_PRIORITY));
+        }
+        if p.max_results > MAX_RESULTS_LIMIT {
+            return Err(format!("{} 的结果数量上限不能超过 {}", provider, MAX_RESULTS_LIMIT));
+        }
+    }
+    Ok(())
+}
+
+/// 应用数据源配置，返回配置是否发生变化
+pub fn apply(providers: &HashMap<String, ProviderSettings>) -> bool {
+    match PROVIDER_SETTINGS.write() {
+        Ok(mut current) if *current != *providers => {
+            *current = providers.clone();
+            true
+        }
+        _ => false,
+    }
+}
+
+/// 所有数据源的生效配置（未配置的数据源使用默认值）
+pub fn effective() -> HashMap<String, ProviderSettings> {
+    let current = PROVIDER_SETTINGS.read().map(|p| p.clone()).unwrap_or_default();
+    PROVIDERS
+        .iter()
+        .map(|provider| (provider.to_string(), current.get(*provider).copied().unwrap_or_default()))
+        .collect()
+}
+
+pub fn is_enabled(provider: &str) -> bool {
+    PROVIDER_SETTINGS
+        .read()
+        .ok()
+        .and_then(|p| p.get(provider).map(|s| s.enabled))
+        .unwrap_or(true)
+}
+
+/// 订阅 settings-changed，数据源配置变化时热更新并通知前端
+pub fn start_settings_listener(app: AppHandle) {
+    let mut receiver = event_bus::subscribe();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(BusEvent::SettingsChanged(settings)) => {
+                    if apply(&settings.providers) {
+                        if let Err(e) = app.emit(CHANGED_EVENT, effective()) {
+                            eprintln!("[SearchProviders] Failed to emit {}: {}", CHANGED_EVENT, e);
+                        }
+                    }
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    eprintln!("[SearchProviders] Listener lagged, skipped {} events", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_provider_settings() {
+        let mut providers = HashMap::new();
+        providers.insert("everything".to_string(), ProviderSettings { enabled: false, priority: -20, max_results: 50 });
+        assert!(validate(&providers).is_ok());
+
+        providers.insert("browser_history".to_string(), ProviderSettings { enabled: false, ..Default::default() });
+        providers.insert("rss".to_string(), ProviderSettings { enabled: false, ..Default::default() });
+        assert!(validate(&providers).is_ok());
+
+        providers.insert("not_a_provider".to_string(), ProviderSettings::default());
+        assert!(validate(&providers).is_err());
+        providers.remove("not_a_provider");
+
+        providers.insert("apps".to_string(), ProviderSettings { priority: 101, ..Default::default() });
+        assert!(validate(&providers).is_err());
+        providers.insert("apps".to_string(), ProviderSettings { max_results: 501, ..Default::default() });
+        assert!(validate(&providers).is_err());
+    }
+}
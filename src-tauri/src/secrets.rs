@@ -0,0 +1,74 @@
+// 敏感数据加密存储：使用 Windows DPAPI 按当前用户加密（TOTP 密钥等），密文以 base64 保存在数据库中
+// 密文只能由同一 Windows 用户解密，数据库文件被拷走也无法还原明文
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::ptr;
+    use windows_sys::Win32::Foundation::LocalFree;
+    use windows_sys::Win32::Security::Cryptography::{
+        CryptProtectData, CryptUnprotectData, CRYPTPROTECT_UI_FORBIDDEN, CRYPT_INTEGER_BLOB,
+    };
+
+    // 附加熵：其他程序即使以同一用户身份调用 DPAPI，不知道该值也无法解密
+    const ENTROPY: &[u8] = b"IMiss-secrets-v1";
+
+    fn blob(data: &[u8]) -> CRYPT_INTEGER_BLOB {
+        CRYPT_INTEGER_BLOB { cbData: data.len() as u32, pbData: data.as_ptr() as *mut u8 }
+    }
+
+    /// 取出 DPAPI 分配的输出缓冲区并释放
+    unsafe fn take(output: CRYPT_INTEGER_BLOB) -> Vec<u8> {
+        let data = std::slice::from_raw_parts(output.pbData, output.cbData as usize).to_vec();
+        LocalFree(output.pbData as _);
+        data
+    }
+
+    pub fn protect(plain: &[u8]) -> Result<Vec<u8>, String> {
+        let input = blob(plain);
+        let entropy = blob(ENTROPY);
+        let mut output = CRYPT_INTEGER_BLOB { cbData: 0, pbData: ptr::null_mut() };
+        unsafe {
+            if CryptProtectData(&input, ptr::null(), &entropy, ptr::null(), ptr::null(), CRYPTPROTECT_UI_FORBIDDEN, &mut output) == 0 {
+                return Err(format!("加密失败: {}", std::io::Error::last_os_error()));
+            }
+            Ok(take(output))
+        }
+    }
+
+    pub fn unprotect(cipher: &[u8]) -> Result<Vec<u8>, String> {
+        let input = blob(cipher);
+        let entropy = blob(ENTROPY);
+        let mut output = CRYPT_INTEGER_BLOB { cbData: 0, pbData: ptr::null_mut() };
+        unsafe {
+            if CryptUnprotectData(&input, ptr::null_mut(), &entropy, ptr::null(), ptr::null(), CRYPTPROTECT_UI_FORBIDDEN, &mut output) == 0 {
+                return Err(format!("解密失败（数据可能来自其他用户或其他电脑）: {}", std::io::Error::last_os_error()));
+            }
+            Ok(take(output))
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    pub fn protect(_plain: &[u8]) -> Result<Vec<u8>, String> {
+        Err("当前平台不支持加密存储".to_string())
+    }
+
+    pub fn unprotect(_cipher: &[u8]) -> Result<Vec<u8>, String> {
+        Err("当前平台不支持加密存储".to_string())
+    }
+}
+
+/// 加密字符串，返回可直接存入数据库的 base64 密文
+pub fn encrypt(plain: &str) -> Result<String, String> {
+    platform::protect(plain.as_bytes()).map(|cipher| STANDARD.encode(cipher))
+}
+
+/// 解密 encrypt 生成的密文
+pub fn decrypt(cipher: &str) -> Result<String, String> {
+    let bytes = STANDARD.decode(cipher).map_err(|e| format!("密文格式错误: {}", e))?;
+    let plain = platform::unprotect(&bytes)?;
+    String::from_utf8(plain).map_err(|e| format!("解密结果不是有效文本: {}", e))
+}
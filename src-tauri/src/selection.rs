@@ -0,0 +1,123 @@
+// 读取前台程序中选中的文本：优先通过 UI Automation 的 TextPattern 直接读取焦点控件的选区，不经过剪贴板；
+// 控件不支持时退回到模拟 Ctrl+C，复制前保存剪贴板原有内容，读取后恢复，复制过程中的剪贴板变化不记入剪切板历史
+
+/// 读取选中的文本；没有选中内容时返回错误
+pub fn read_selection() -> Result<String, String> {
+    match platform::uia_selection().map(|ranges| ranges.map(join_ranges)) {
+        Ok(Some(text)) if !text.is_empty() => return Ok(text),
+        Ok(_) => {}
+        Err(e) => eprintln!("[Selection] UI Automation unavailable: {}", e),
+    }
+    platform::copy_selection()
+}
+
+/// 合并多个选区的文本（多光标、表格多选等），各段之间换行
+fn join_ranges(ranges: Vec<String>) -> String {
+    ranges.into_iter().filter(|text| !text.is_empty()).collect::<Vec<_>>().join("\n")
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use crate::clipboard;
+    use crate::key_injection::{self, Injected};
+    use ::windows::core::Interface;
+    use ::windows::Win32::System::Com::{
+        CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_INPROC_SERVER, COINIT_MULTITHREADED,
+    };
+    use ::windows::Win32::UI::Accessibility::{
+        CUIAutomation, IUIAutomation, IUIAutomation2, IUIAutomationTextPattern, UIA_TextPatternId,
+    };
+    use std::time::{Duration, Instant};
+    use windows_sys::Win32::System::DataExchange::GetClipboardSequenceNumber;
+
+    /// 目标程序无响应时不要长时间阻塞
+    const UIA_TIMEOUT_MS: u32 = 500;
+    /// 等待目标程序响应 Ctrl+C 的最长时间
+    const COPY_TIMEOUT: Duration = Duration::from_millis(800);
+    const VK_CONTROL: u16 = 0x11;
+    const VK_C: u16 = 0x43;
+
+    /// 通过 UI Automation 读取焦点控件各选区的文本；控件不支持 TextPattern 时返回 None
+    pub fn uia_selection() -> Result<Option<Vec<String>>, String> {
+        let com_initialized = unsafe { CoInitializeEx(None, COINIT_MULTITHREADED).is_ok() };
+        let run = || -> ::windows::core::Result<Option<Vec<String>>> {
+            let automation: IUIAutomation = unsafe { CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER)? };
+            if let Ok(automation) = automation.cast::<IUIAutomation2>() {
+                unsafe {
+                    let _ = automation.SetConnectionTimeout(UIA_TIMEOUT_MS);
+                    let _ = automation.SetTransactionTimeout(UIA_TIMEOUT_MS);
+                }
+            }
+            let element = unsafe { automation.GetFocusedElement()? };
+            let Ok(pattern) = (unsafe { element.GetCurrentPatternAs::<IUIAutomationTextPattern>(UIA_TextPatternId) })
+            else {
+                return Ok(None);
+            };
+            let ranges = unsafe { pattern.GetSelection()? };
+            let mut texts = Vec::new();
+            for index in 0..unsafe { ranges.Length()? } {
+                let range = unsafe { ranges.GetElement(index)? };
+                texts.push(unsafe { range.GetText(-1)? }.to_string());
+            }
+            Ok(Some(texts))
+        };
+        let result = run().map_err(|e| e.to_string());
+        if com_initialized {
+            unsafe { CoUninitialize() };
+        }
+        result
+    }
+
+    fn copy_and_read(before: u32) -> Result<String, String> {
+        key_injection::platform::send_injected(&[
+            Injected::Key { vk: VK_CONTROL, up: false },
+            Injected::Key { vk: VK_C, up: false },
+            Injected::Key { vk: VK_C, up: true },
+            Injected::Key { vk: VK_CONTROL, up: true },
+        ])?;
+        let start = Instant::now();
+        while unsafe { GetClipboardSequenceNumber() } == before {
+            if start.elapsed() > COPY_TIMEOUT {
+                return Err("没有选中的文本".to_string());
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        // 部分程序分多次写入剪贴板，稍等再读取
+        std::thread::sleep(Duration::from_millis(50));
+        let text = clipboard::read_text()?;
+        if text.is_empty() {
+            return Err("没有选中的文本".to_string());
+        }
+        Ok(text)
+    }
+
+    /// 模拟 Ctrl+C 复制选中的文本，读取后恢复剪贴板原有内容
+    pub fn copy_selection() -> Result<String, String> {
+        let _guard = clipboard::ClipboardGuard::save();
+        copy_and_read(unsafe { GetClipboardSequenceNumber() })
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    const UNSUPPORTED: &str = "当前平台不支持读取选中文本";
+
+    pub fn uia_selection() -> Result<Option<Vec<String>>, String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn copy_selection() -> Result<String, String> {
+        Err(UNSUPPORTED.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joins_non_empty_ranges() {
+        assert_eq!(join_ranges(vec!["a".to_string(), String::new(), "b c".to_string()]), "a\nb c");
+        assert_eq!(join_ranges(Vec::new()), "");
+    }
+}
@@ -1,4 +1,9 @@
 use crate::db;
+use crate::display_control;
+use crate::event_bus::{self, BusEvent};
+use crate::scoring;
+use crate::search_providers;
+use crate::secrets;
 use rusqlite::{params, OptionalExtension};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -19,6 +24,8 @@ pub struct Settings {
     pub plugin_hotkeys: HashMap<String, HotkeyConfig>,
     #[serde(default)]
     pub app_hotkeys: HashMap<String, HotkeyConfig>,
+    #[serde(default)]
+    pub window_hotkeys: HashMap<String, HotkeyConfig>, // 窗口管理动作 id（如 "left_half"）-> 快捷键
     #[serde(default = "default_close_on_blur")]
     pub close_on_blur: bool,
     #[serde(default = "default_result_style")]
@@ -31,16 +38,68 @@ pub struct Settings {
     pub ignored_update_version: Option<String>,
     #[serde(default = "default_clipboard_max_items")]
     pub clipboard_max_items: u32,
+    #[serde(default)]
+    pub clipboard_max_age_days: u32, // 剪切板历史保留天数（收藏、置顶除外），0 表示不按时间清理
+    #[serde(default = "default_clipboard_mask_sensitive")]
+    pub clipboard_mask_sensitive: bool, // 列表中对看起来像密码、银行卡号、API 密钥的内容打码
+    #[serde(default)]
+    pub clipboard_sensitive_expire_minutes: u32, // 敏感内容在剪切板历史中保留的分钟数（收藏、置顶除外），0 表示不单独清理
+    #[serde(default)]
+    pub clipboard_dedup_collapse_whitespace: bool, // 剪切板去重时把连续空白视为相同
     #[serde(default = "default_translation_tab_order")]
     pub translation_tab_order: Vec<String>,
     #[serde(default = "default_search_engines")]
     pub search_engines: Vec<SearchEngineConfig>,
+    #[serde(default = "default_open_with_actions")]
+    pub open_with_actions: Vec<OpenWithAction>,
+    #[serde(default)]
+    pub git_repo_roots: Vec<String>,
+    #[serde(default)]
+    pub games_provider_enabled: bool,
+    #[serde(default)]
+    pub aliases: Vec<AliasConfig>,
+    #[serde(default = "default_analytics_enabled")]
+    pub analytics_enabled: bool,
+    #[serde(default = "default_analytics_retention_days")]
+    pub analytics_retention_days: u32,
+    #[serde(default)]
+    pub web_suggestions_enabled: bool, // 联想词会把输入发给搜索引擎，默认关闭
+    #[serde(default = "default_theme")]
+    pub theme: String, // 主题 id，"system" 表示跟随系统深色模式
+    #[serde(default = "default_locale")]
+    pub locale: String, // 后端显示文案的语言："zh-CN" | "en-US"
+    #[serde(default)]
+    pub dnd_auto_detect: bool, // 前台窗口全屏时自动进入勿扰模式
+    #[serde(default)]
+    pub proxy: ProxySettings,
+    #[serde(default)]
+    pub scoring_weights: HashMap<String, scoring::ScoringWeights>, // 按数据源调整搜索得分倍率，未配置的数据源使用默认权重
+    #[serde(default)]
+    pub providers: HashMap<String, search_providers::ProviderSettings>, // 数据源开关、优先级和结果数量上限，未配置的数据源使用默认值
+    #[serde(default)]
+    pub hot_folders: Vec<String>, // 热点文件夹（如下载、桌面），监听其中最近新建/修改的文件，支持 ~ 与环境变量
+    #[serde(default)]
+    pub display_presets: Vec<display_control::DisplayPreset>, // 显示器分辨率/刷新率预设，在启动器中输入 display 应用
+    #[serde(default = "default_world_clock_cities")]
+    pub world_clock_cities: Vec<String>, // 世界时钟常用城市（城市名、时区缩写或 IANA 时区名），输入 time 时显示
+    #[serde(default = "default_quote_watchlist")]
+    pub quote_watchlist: Vec<String>, // 行情关注列表（股票、外汇对或加密货币代码），输入 "行情" 时显示
+    #[serde(default = "default_quote_refresh_secs")]
+    pub quote_refresh_secs: u64, // 行情刷新间隔（秒），间隔内重复查询使用缓存
+    #[serde(default)]
+    pub text_variables: Vec<TextVariableConfig>, // 自定义文本扩展变量，在片段和快捷链接中以 {名称} 引用
+    #[serde(default)]
+    pub script_http_allow: HashMap<String, Vec<String>>, // 脚本 id -> 用户确认允许访问的主机，脚本的 @allow_http 只是申请
 }
 
 fn default_clipboard_max_items() -> u32 {
     100
 }
 
+fn default_clipboard_mask_sensitive() -> bool {
+    true
+}
+
 fn default_result_style() -> String {
     "skeuomorphic".to_string()
 }
@@ -57,6 +116,34 @@ fn default_translation_tab_order() -> Vec<String> {
     vec!["translation".to_string(), "wordbook".to_string()]
 }
 
+fn default_analytics_enabled() -> bool {
+    true
+}
+
+fn default_analytics_retention_days() -> u32 {
+    90
+}
+
+fn default_theme() -> String {
+    "system".to_string()
+}
+
+fn default_locale() -> String {
+    "zh-CN".to_string()
+}
+
+fn default_world_clock_cities() -> Vec<String> {
+    ["北京", "伦敦", "纽约", "洛杉矶"].iter().map(|s| s.to_string()).collect()
+}
+
+fn default_quote_watchlist() -> Vec<String> {
+    ["BTC", "USD/CNY", "AAPL"].iter().map(|s| s.to_string()).collect()
+}
+
+fn default_quote_refresh_secs() -> u64 {
+    60
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self {
@@ -66,22 +153,50 @@ impl Default for Settings {
             app_center_hotkey: None,
             plugin_hotkeys: HashMap::new(),
             app_hotkeys: HashMap::new(),
+            window_hotkeys: HashMap::new(),
             close_on_blur: default_close_on_blur(),
             result_style: default_result_style(),
             auto_check_update: default_auto_check_update(),
             last_update_check_time: None,
             ignored_update_version: None,
             clipboard_max_items: default_clipboard_max_items(),
+            clipboard_max_age_days: 0,
+            clipboard_mask_sensitive: default_clipboard_mask_sensitive(),
+            clipboard_sensitive_expire_minutes: 0,
+            clipboard_dedup_collapse_whitespace: false,
             translation_tab_order: default_translation_tab_order(),
             search_engines: default_search_engines(),
+            open_with_actions: default_open_with_actions(),
+            git_repo_roots: Vec::new(),
+            games_provider_enabled: false,
+            aliases: Vec::new(),
+            analytics_enabled: default_analytics_enabled(),
+            analytics_retention_days: default_analytics_retention_days(),
+            web_suggestions_enabled: false,
+            theme: default_theme(),
+            locale: default_locale(),
+            dnd_auto_detect: false,
+            proxy: ProxySettings::default(),
+            scoring_weights: HashMap::new(),
+            providers: HashMap::new(),
+            hot_folders: Vec::new(),
+            display_presets: Vec::new(),
+            world_clock_cities: default_world_clock_cities(),
+            quote_watchlist: default_quote_watchlist(),
+            quote_refresh_secs: default_quote_refresh_secs(),
+            text_variables: Vec::new(),
+            script_http_allow: HashMap::new(),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct HotkeyConfig {
     pub modifiers: Vec<String>,
     pub key: String,
+    /// 限定生效的程序（规则见 app_scope），为空时全局生效
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub apps: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -92,6 +207,12 @@ pub struct LlmSettings {
     pub base_url: String,
     #[serde(default)]
     pub api_key: Option<String>,
+    #[serde(default)]
+    pub monthly_budget: Option<f64>, // 每月费用软预算（美元），超过 80% 和 100% 时提醒
+    #[serde(default)]
+    pub embedding_model: Option<String>, // 剪切板语义搜索使用的向量模型，为空时不启用
+    #[serde(default = "default_chat_retention_days")]
+    pub chat_retention_days: u32, // 对话记录保留天数（置顶的对话除外），0 表示永久保留
 }
 
 fn default_llm_model() -> String {
@@ -102,21 +223,91 @@ fn default_llm_base_url() -> String {
     "https://api.openai.com/v1".to_string()
 }
 
+fn default_chat_retention_days() -> u32 {
+    90
+}
+
 impl Default for LlmSettings {
     fn default() -> Self {
         Self {
             model: default_llm_model(),
             base_url: default_llm_base_url(),
             api_key: None,
+            monthly_budget: None,
+            embedding_model: None,
+            chat_retention_days: default_chat_retention_days(),
         }
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProxySettings {
+    #[serde(default = "default_proxy_mode")]
+    pub mode: String, // "system" | "none" | "manual"
+    #[serde(default)]
+    pub url: String, // 手动代理地址，如 http://127.0.0.1:7890、socks5://127.0.0.1:1080
+    #[serde(default)]
+    pub username: Option<String>,
+    // 前端提交的新密码：None 表示不修改，空字符串表示清除；只在保存时读取，加密后丢弃，不写入数据库也不返回前端
+    #[serde(default, skip_serializing)]
+    pub password: Option<String>,
+    #[serde(default)]
+    pub encrypted_password: Option<String>, // secrets::encrypt 加密后的密码
+    #[serde(default, skip_deserializing)]
+    pub has_password: bool, // 返回前端时表示已设置密码
+}
+
+fn default_proxy_mode() -> String {
+    "system".to_string()
+}
+
+impl Default for ProxySettings {
+    fn default() -> Self {
+        Self {
+            mode: default_proxy_mode(),
+            url: String::new(),
+            username: None,
+            password: None,
+            encrypted_password: None,
+            has_password: false,
+        }
+    }
+}
+
+impl ProxySettings {
+    /// 加密前端提交的新密码；没有提交时沿用 previous 中已保存的密码
+    pub fn seal(&mut self, previous: &ProxySettings) -> Result<(), String> {
+        self.encrypted_password = match self.password.take() {
+            None => previous.encrypted_password.clone(),
+            Some(password) if password.is_empty() => None,
+            Some(password) => Some(secrets::encrypt(&password)?),
+        };
+        self.has_password = self.encrypted_password.is_some();
+        Ok(())
+    }
+
+    /// 返回前端的副本：去掉密文，只保留是否已设置密码
+    pub fn redacted(mut self) -> Self {
+        self.has_password = self.encrypted_password.take().is_some();
+        self
+    }
+
+    /// 解密已保存的密码，解密失败（如数据来自其他电脑）时视为未设置
+    pub fn decrypted_password(&self) -> Option<String> {
+        let encrypted = self.encrypted_password.as_deref()?;
+        secrets::decrypt(encrypted)
+            .map_err(|e| eprintln!("[Settings] Failed to decrypt proxy password: {}", e))
+            .ok()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SearchEngineConfig {
     pub prefix: String,  // 触发前缀，如 "s ", "g "
     pub url: String,     // URL 模板，使用 {query} 作为占位符
     pub name: String,    // 显示名称，如 "Google", "百度"
+    #[serde(default)]
+    pub suggest_source: Option<String>, // 联想词来源："google" | "baidu" | "bing" | "none"，为空时根据 URL 自动识别
 }
 
 fn default_search_engines() -> Vec<SearchEngineConfig> {
@@ -125,20 +316,63 @@ fn default_search_engines() -> Vec<SearchEngineConfig> {
             prefix: "s ".to_string(),
             url: "https://www.google.com/search?q={query}".to_string(),
             name: "Google".to_string(),
+            suggest_source: None,
         },
         SearchEngineConfig {
             prefix: "bd ".to_string(),
             url: "https://www.baidu.com/s?wd={query}".to_string(),
             name: "百度".to_string(),
+            suggest_source: None,
         },
         SearchEngineConfig {
             prefix: "b ".to_string(),
             url: "https://www.bing.com/search?q={query}".to_string(),
             name: "必应".to_string(),
+            suggest_source: None,
         },
     ]
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OpenWithAction {
+    pub id: String,       // 唯一标识，如 "terminal", "vscode"
+    pub name: String,     // 显示名称
+    pub command: String,  // 命令模板，使用 {path} 作为文件夹路径占位符
+}
+
+fn default_open_with_actions() -> Vec<OpenWithAction> {
+    vec![
+        OpenWithAction {
+            id: "terminal".to_string(),
+            name: "在终端中打开".to_string(),
+            command: "wt.exe -d \"{path}\"".to_string(),
+        },
+        OpenWithAction {
+            id: "vscode".to_string(),
+            name: "在 VS Code 中打开".to_string(),
+            command: "code \"{path}\"".to_string(),
+        },
+        OpenWithAction {
+            id: "powershell".to_string(),
+            name: "在 PowerShell 中打开".to_string(),
+            command: "powershell.exe -NoExit -Command \"Set-Location -LiteralPath '{path}'\""
+                .to_string(),
+        },
+    ]
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AliasConfig {
+    pub alias: String,   // 输入的别名关键字
+    pub target: String,  // 展开后的目标（路径、命令或 URL）
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TextVariableConfig {
+    pub name: String,  // 变量名，引用时写作 {name}
+    pub value: String, // 值，可以包含内置变量，如 "{date:%Y}年 周报"
+}
+
 pub fn get_settings_file_path(app_data_dir: &Path) -> PathBuf {
     app_data_dir.join("settings.json")
 }
@@ -156,17 +390,27 @@ pub fn load_settings(app_data_dir: &Path) -> Result<Settings, String> {
         .optional()
         .map_err(|e| format!("Failed to load settings from database: {}", e))?;
 
-    if let Some(json) = value {
-        serde_json::from_str(&json)
-            .map_err(|e| format!("Failed to parse settings from database: {}", e))
-    } else {
-        Ok(Settings::default())
+    let Some(json) = value else {
+        return Ok(Settings::default());
+    };
+    let mut settings: Settings = serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to parse settings from database: {}", e))?;
+    // 旧版本明文保存的代理密码：加密后写回
+    if settings.proxy.password.is_some() {
+        let previous = settings.proxy.clone();
+        match settings.proxy.seal(&previous) {
+            Ok(()) => save_settings_with_conn(&conn, &settings)?,
+            Err(e) => eprintln!("[Settings] Failed to encrypt proxy password: {}", e),
+        }
     }
+    Ok(settings)
 }
 
 pub fn save_settings(app_data_dir: &Path, settings: &Settings) -> Result<(), String> {
     let conn = db::get_connection(app_data_dir)?;
-    save_settings_with_conn(&conn, settings)
+    save_settings_with_conn(&conn, settings)?;
+    event_bus::publish(BusEvent::SettingsChanged(settings.clone()));
+    Ok(())
 }
 
 fn save_settings_with_conn(conn: &rusqlite::Connection, settings: &Settings) -> Result<(), String> {
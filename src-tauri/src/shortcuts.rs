@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, LazyLock, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ShortcutItem {
@@ -23,9 +24,24 @@ pub fn get_shortcuts_file_path(app_data_dir: &Path) -> PathBuf {
     app_data_dir.join("shortcuts.json")
 }
 
+// 是否已从数据库加载；启动时在后台加载，未完成前的读写会先按需加载，避免读到空列表或覆盖已有数据
+static LOADED: AtomicBool = AtomicBool::new(false);
+
 pub fn load_shortcuts(app_data_dir: &Path) -> Result<(), String> {
     let mut state = SHORTCUTS.lock().map_err(|e| e.to_string())?;
-    load_shortcuts_into(&mut state, app_data_dir)
+    load_shortcuts_into(&mut state, app_data_dir)?;
+    LOADED.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+/// 获取已加载的快捷方式表，尚未加载时先从数据库加载
+fn lock_loaded(app_data_dir: &Path) -> Result<MutexGuard<'static, HashMap<String, ShortcutItem>>, String> {
+    let mut state = SHORTCUTS.lock().map_err(|e| e.to_string())?;
+    if !LOADED.load(Ordering::Relaxed) {
+        load_shortcuts_into(&mut state, app_data_dir)?;
+        LOADED.store(true, Ordering::Relaxed);
+    }
+    Ok(state)
 }
 
 pub fn save_shortcuts(app_data_dir: &Path) -> Result<(), String> {
@@ -33,9 +49,9 @@ pub fn save_shortcuts(app_data_dir: &Path) -> Result<(), String> {
     save_shortcuts_internal(&state, app_data_dir)
 }
 
-pub fn get_all_shortcuts() -> Vec<ShortcutItem> {
-    let state = SHORTCUTS.lock().unwrap();
-    state.values().cloned().collect()
+pub fn get_all_shortcuts(app_data_dir: &Path) -> Result<Vec<ShortcutItem>, String> {
+    let state = lock_loaded(app_data_dir)?;
+    Ok(state.values().cloned().collect())
 }
 
 pub fn add_shortcut(
@@ -63,7 +79,7 @@ pub fn add_shortcut(
         updated_at: timestamp,
     };
 
-    let mut state = SHORTCUTS.lock().map_err(|e| e.to_string())?;
+    let mut state = lock_loaded(app_data_dir)?;
     state.insert(id.clone(), shortcut.clone());
     drop(state);
 
@@ -86,7 +102,7 @@ pub fn update_shortcut(
         .map_err(|e| format!("Failed to get timestamp: {}", e))?
         .as_secs();
 
-    let mut state = SHORTCUTS.lock().map_err(|e| e.to_string())?;
+    let mut state = lock_loaded(app_data_dir)?;
 
     let shortcut = state
         .get_mut(&id)
@@ -111,8 +127,22 @@ pub fn update_shortcut(
     Ok(shortcut_clone)
 }
 
+/// 按原样写入快捷方式（保留 id 与时间戳），已存在时覆盖；用于从 git 仓库导入配置
+pub fn upsert_shortcuts(items: Vec<ShortcutItem>, app_data_dir: &Path) -> Result<(), String> {
+    if items.is_empty() {
+        return Ok(());
+    }
+    let mut state = lock_loaded(app_data_dir)?;
+    for item in items {
+        state.insert(item.id.clone(), item);
+    }
+    drop(state);
+
+    save_shortcuts(app_data_dir)
+}
+
 pub fn delete_shortcut(id: String, app_data_dir: &Path) -> Result<(), String> {
-    let mut state = SHORTCUTS.lock().map_err(|e| e.to_string())?;
+    let mut state = lock_loaded(app_data_dir)?;
 
     state
         .remove(&id)
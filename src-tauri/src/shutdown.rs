@@ -0,0 +1,107 @@
+// 退出协调：后台线程通过这里感知应用退出
+// 子系统注册退出回调（停止消息循环、卸载钩子等），应用退出前统一执行并等待登记的线程结束，避免写入中途被打断
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, LazyLock, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+type ShutdownHook = Box<dyn FnOnce() + Send>;
+
+static REQUESTED: AtomicBool = AtomicBool::new(false);
+// 用于唤醒正在 wait_timeout 中等待的线程
+static SIGNAL: LazyLock<(Mutex<()>, Condvar)> = LazyLock::new(|| (Mutex::new(()), Condvar::new()));
+static HOOKS: LazyLock<Mutex<Vec<(String, ShutdownHook)>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+static THREADS: LazyLock<Mutex<Vec<(String, JoinHandle<()>)>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// 是否已开始退出
+pub fn is_requested() -> bool {
+    REQUESTED.load(Ordering::SeqCst)
+}
+
+/// 等待一段时间，期间开始退出时提前返回；返回 true 表示已开始退出
+/// 后台循环用它代替 thread::sleep
+pub fn wait_timeout(timeout: Duration) -> bool {
+    let (lock, cvar) = &*SIGNAL;
+    let deadline = Instant::now() + timeout;
+    let mut guard = match lock.lock() {
+        Ok(guard) => guard,
+        Err(_) => return is_requested(),
+    };
+    while !is_requested() {
+        let now = Instant::now();
+        if now >= deadline {
+            break;
+        }
+        guard = match cvar.wait_timeout(guard, deadline - now) {
+            Ok((guard, _)) => guard,
+            Err(_) => break,
+        };
+    }
+    is_requested()
+}
+
+/// 注册退出回调（按注册的相反顺序执行）；已开始退出时立即执行
+pub fn on_shutdown(name: &str, hook: impl FnOnce() + Send + 'static) {
+    if is_requested() {
+        hook();
+        return;
+    }
+    if let Ok(mut hooks) = HOOKS.lock() {
+        hooks.push((name.to_string(), Box::new(hook)));
+    }
+}
+
+/// 启动一个登记的后台线程，退出时会等待它结束（线程应通过 is_requested / wait_timeout 感知退出）
+pub fn spawn(name: &str, f: impl FnOnce() + Send + 'static) -> Result<(), String> {
+    let handle = thread::Builder::new()
+        .name(name.to_string())
+        .spawn(f)
+        .map_err(|e| format!("Failed to spawn {}: {}", name, e))?;
+    if let Ok(mut threads) = THREADS.lock() {
+        threads.retain(|(_, handle)| !handle.is_finished());
+        threads.push((name.to_string(), handle));
+    }
+    Ok(())
+}
+
+/// 开始退出：通知所有后台线程，执行退出回调，并在 timeout 内等待登记的线程结束
+/// 可重复调用，只有第一次生效
+pub fn shutdown(timeout: Duration) {
+    if REQUESTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    eprintln!("[Shutdown] Shutting down background tasks");
+
+    {
+        let (lock, cvar) = &*SIGNAL;
+        let _guard = lock.lock();
+        cvar.notify_all();
+    }
+
+    let hooks: Vec<(String, ShutdownHook)> = HOOKS
+        .lock()
+        .map(|mut hooks| hooks.drain(..).collect())
+        .unwrap_or_default();
+    for (name, hook) in hooks.into_iter().rev() {
+        if std::panic::catch_unwind(std::panic::AssertUnwindSafe(hook)).is_err() {
+            eprintln!("[Shutdown] Shutdown hook '{}' panicked", name);
+        }
+    }
+
+    let threads: Vec<(String, JoinHandle<()>)> = THREADS
+        .lock()
+        .map(|mut threads| threads.drain(..).collect())
+        .unwrap_or_default();
+    let deadline = Instant::now() + timeout;
+    for (name, handle) in threads {
+        while !handle.is_finished() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(20));
+        }
+        if handle.is_finished() {
+            let _ = handle.join();
+        } else {
+            eprintln!("[Shutdown] Background task '{}' did not finish in time", name);
+        }
+    }
+}
@@ -0,0 +1,352 @@
+// SSH 主机搜索：解析 ~/.ssh/config 与 ~/.ssh/known_hosts，提供可直接连接的主机列表
+
+use crate::db;
+use crate::open_with;
+use crate::path_expand;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Include 嵌套层数上限，防止循环引用
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SshHost {
+    pub host: String,              // ssh 命令中使用的名称（config 中的 Host 别名或 known_hosts 中的主机）
+    pub hostname: Option<String>,  // 实际连接地址（config 中的 HostName）
+    pub user: Option<String>,
+    pub port: Option<u16>,
+    pub source: String,            // "config" | "known_hosts"
+    pub use_count: u32,
+    pub last_used: Option<i64>,
+}
+
+impl SshHost {
+    fn new(host: String, source: &str) -> Self {
+        Self {
+            host,
+            hostname: None,
+            user: None,
+            port: None,
+            source: source.to_string(),
+            use_count: 0,
+            last_used: None,
+        }
+    }
+}
+
+fn ssh_dir() -> Option<PathBuf> {
+    path_expand::home_dir().map(|home| home.join(".ssh"))
+}
+
+/// 解析 ssh config 内容，忽略通配符与否定模式的 Host
+pub fn parse_ssh_config(content: &str) -> Vec<SshHost> {
+    let mut hosts = Vec::new();
+    collect_config_hosts(content, &mut hosts, 0);
+    hosts
+}
+
+fn collect_config_hosts(content: &str, hosts: &mut Vec<SshHost>, depth: usize) {
+    // 当前 Host 块对应的条目在 hosts 中的下标
+    let mut current: Vec<usize> = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (keyword, value) = match split_config_line(line) {
+            Some(kv) => kv,
+            None => continue,
+        };
+
+        match keyword.to_ascii_lowercase().as_str() {
+            "host" => {
+                current.clear();
+                for pattern in value.split_whitespace() {
+                    if pattern.contains(['*', '?', '!']) {
+                        continue;
+                    }
+                    current.push(hosts.len());
+                    hosts.push(SshHost::new(pattern.to_string(), "config"));
+                }
+            }
+            "match" => current.clear(),
+            "hostname" => {
+                for &i in &current {
+                    hosts[i].hostname = Some(value.to_string());
+                }
+            }
+            "user" => {
+                for &i in &current {
+                    hosts[i].user = Some(value.to_string());
+                }
+            }
+            "port" => {
+                if let Ok(port) = value.parse::<u16>() {
+                    for &i in &current {
+                        hosts[i].port = Some(port);
+                    }
+                }
+            }
+            // 仅支持不含通配符的 Include，相对路径相对于 ~/.ssh
+            "include" if depth < MAX_INCLUDE_DEPTH => {
+                for include in value.split_whitespace() {
+                    if include.contains(['*', '?']) {
+                        continue;
+                    }
+                    let expanded = PathBuf::from(path_expand::expand_path(include));
+                    let path = if expanded.is_absolute() {
+                        expanded
+                    } else if let Some(dir) = ssh_dir() {
+                        dir.join(expanded)
+                    } else {
+                        continue;
+                    };
+                    if let Ok(included) = fs::read_to_string(&path) {
+                        collect_config_hosts(&included, hosts, depth + 1);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 拆分 "Keyword value" 或 "Keyword=value"
+fn split_config_line(line: &str) -> Option<(&str, &str)> {
+    let idx = line.find(|c: char| c.is_whitespace() || c == '=')?;
+    let keyword = &line[..idx];
+    let value = line[idx..]
+        .trim_start_matches(|c: char| c.is_whitespace() || c == '=')
+        .trim()
+        .trim_matches('"');
+    if keyword.is_empty() || value.is_empty() {
+        None
+    } else {
+        Some((keyword, value))
+    }
+}
+
+/// 解析 known_hosts 内容，跳过哈希化的主机名与 @cert-authority / @revoked 行
+pub fn parse_known_hosts(content: &str) -> Vec<SshHost> {
+    let mut hosts = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('@') {
+            continue;
+        }
+        let field = match line.split_whitespace().next() {
+            Some(f) => f,
+            None => continue,
+        };
+
+        for entry in field.split(',') {
+            if entry.starts_with('|') || entry.contains(['*', '?', '!']) {
+                continue;
+            }
+            // [host]:port 形式
+            let mut host = SshHost::new(entry.to_string(), "known_hosts");
+            if let Some(rest) = entry.strip_prefix('[') {
+                if let Some((name, port)) = rest.split_once("]:") {
+                    host.host = name.to_string();
+                    host.port = port.parse().ok();
+                }
+            }
+            if !host.host.is_empty() {
+                hosts.push(host);
+            }
+        }
+    }
+
+    hosts
+}
+
+fn load_usage(app_data_dir: &Path) -> Result<HashMap<String, (u32, i64)>, String> {
+    let conn = db::get_connection(app_data_dir)?;
+    let mut stmt = conn
+        .prepare("SELECT host, use_count, last_used FROM ssh_host_usage")
+        .map_err(|e| format!("Failed to prepare ssh host usage query: {}", e))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, i64>(1)? as u32,
+                row.get::<_, i64>(2)?,
+            ))
+        })
+        .map_err(|e| format!("Failed to iterate ssh host usage rows: {}", e))?;
+
+    let mut usage = HashMap::new();
+    for row in rows {
+        let (host, count, last_used) =
+            row.map_err(|e| format!("Failed to read ssh host usage row: {}", e))?;
+        usage.insert(host, (count, last_used));
+    }
+    Ok(usage)
+}
+
+/// 获取所有 SSH 主机（config 优先于 known_hosts），按使用次数与最近使用排序
+pub fn get_ssh_hosts(app_data_dir: &Path) -> Result<Vec<SshHost>, String> {
+    let mut hosts: Vec<SshHost> = Vec::new();
+
+    if let Some(dir) = ssh_dir() {
+        if let Ok(content) = fs::read_to_string(dir.join("config")) {
+            hosts.extend(parse_ssh_config(&content));
+        }
+        if let Ok(content) = fs::read_to_string(dir.join("known_hosts")) {
+            hosts.extend(parse_known_hosts(&content));
+        }
+    }
+
+    // 去重：同名主机保留第一次出现的（config 中的配置信息更完整）
+    let mut seen = HashSet::new();
+    hosts.retain(|h| seen.insert(h.host.to_lowercase()));
+
+    let usage = load_usage(app_data_dir)?;
+    for host in hosts.iter_mut() {
+        if let Some(&(count, last_used)) = usage.get(&host.host) {
+            host.use_count = count;
+            host.last_used = Some(last_used);
+        }
+    }
+
+    hosts.sort_by(|a, b| {
+        b.use_count
+            .cmp(&a.use_count)
+            .then_with(|| b.last_used.cmp(&a.last_used))
+            .then_with(|| a.host.to_lowercase().cmp(&b.host.to_lowercase()))
+    });
+
+    Ok(hosts)
+}
+
+/// 按主机名、地址或用户名搜索 SSH 主机
+pub fn search_ssh_hosts(query: &str, app_data_dir: &Path) -> Result<Vec<SshHost>, String> {
+    let hosts = get_ssh_hosts(app_data_dir)?;
+    let query_lower = query.trim().to_lowercase();
+    if query_lower.is_empty() {
+        return Ok(hosts);
+    }
+
+    Ok(hosts
+        .into_iter()
+        .filter(|h| {
+            h.host.to_lowercase().contains(&query_lower)
+                || h.hostname
+                    .as_ref()
+                    .map(|n| n.to_lowercase().contains(&query_lower))
+                    .unwrap_or(false)
+                || h.user
+                    .as_ref()
+                    .map(|u| u.to_lowercase().contains(&query_lower))
+                    .unwrap_or(false)
+        })
+        .collect())
+}
+
+fn record_usage(host: &str, app_data_dir: &Path) -> Result<(), String> {
+    let conn = db::get_connection(app_data_dir)?;
+    let ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+
+    conn.execute(
+        r#"
+        INSERT INTO ssh_host_usage (host, use_count, last_used)
+        VALUES (?1, 1, ?2)
+        ON CONFLICT(host) DO UPDATE SET
+            use_count = ssh_host_usage.use_count + 1,
+            last_used = excluded.last_used
+        "#,
+        params![host, ts],
+    )
+    .map_err(|e| format!("Failed to record ssh host usage: {}", e))?;
+
+    Ok(())
+}
+
+/// ssh 命令行参数：解析到端口时（如 known_hosts 中的 [host]:port）通过 -p 指定
+fn ssh_command(host: &str, port: Option<u16>) -> Vec<String> {
+    let mut args = vec!["ssh".to_string()];
+    if let Some(port) = port {
+        args.push("-p".to_string());
+        args.push(port.to_string());
+    }
+    args.push(host.to_string());
+    args
+}
+
+/// 在新终端中执行 ssh [-p <port>] <host>，并记录使用次数
+pub fn connect_ssh_host(host: &str, app_data_dir: &Path) -> Result<(), String> {
+    let host = host.trim();
+    // 拒绝以 - 开头或包含空白的主机名，避免被当作 ssh 参数
+    if host.is_empty() || host.starts_with('-') || host.contains(char::is_whitespace) {
+        return Err(format!("Invalid ssh host: {}", host));
+    }
+
+    let port = get_ssh_hosts(app_data_dir)?
+        .into_iter()
+        .find(|h| h.host == host)
+        .and_then(|h| h.port);
+    open_with::spawn_in_terminal(&ssh_command(host, port))?;
+    record_usage(host, app_data_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ssh_config() {
+        let content = r#"
+# comment
+Host web1 web2
+    HostName 10.0.0.1
+    User deploy
+    Port=2222
+
+Host *.internal !bastion
+    User ops
+
+Host db
+  hostname db.example.com
+"#;
+        let hosts = parse_ssh_config(content);
+        assert_eq!(hosts.len(), 3);
+        assert_eq!(hosts[0].host, "web1");
+        assert_eq!(hosts[1].hostname.as_deref(), Some("10.0.0.1"));
+        assert_eq!(hosts[1].user.as_deref(), Some("deploy"));
+        assert_eq!(hosts[1].port, Some(2222));
+        assert_eq!(hosts[2].host, "db");
+        assert_eq!(hosts[2].hostname.as_deref(), Some("db.example.com"));
+        assert_eq!(hosts[2].user, None);
+    }
+
+    #[test]
+    fn test_parse_known_hosts() {
+        let content = "\
+github.com,140.82.112.3 ssh-ed25519 AAAA
+[gitlab.local]:2200 ssh-rsa AAAA
+|1|abc=|def= ssh-rsa AAAA
+@cert-authority *.example.com ssh-rsa AAAA
+";
+        let hosts = parse_known_hosts(content);
+        let names: Vec<&str> = hosts.iter().map(|h| h.host.as_str()).collect();
+        assert_eq!(names, vec!["github.com", "140.82.112.3", "gitlab.local"]);
+        assert_eq!(hosts[2].port, Some(2200));
+    }
+
+    #[test]
+    fn test_ssh_command_passes_port() {
+        assert_eq!(ssh_command("gitlab.local", Some(2200)), vec!["ssh", "-p", "2200", "gitlab.local"]);
+        assert_eq!(ssh_command("github.com", None), vec!["ssh", "github.com"]);
+    }
+}
@@ -0,0 +1,195 @@
+// 系统监控：CPU、内存、磁盘与电池状态，供启动器的 "cpu"、"ram" 等查询展示
+// 采样线程只在前端查询时运行，保留最近一段时间的 CPU/内存历史用于绘制走势图，一段时间无人查询后自动停止
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use sysinfo::{Disks, System, MINIMUM_CPU_UPDATE_INTERVAL};
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+const HISTORY_LEN: usize = 60;
+/// 超过这段时间没有查询时停止采样线程
+const IDLE_STOP_AFTER: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SamplePoint {
+    pub timestamp: u64, // 毫秒时间戳
+    pub cpu: f32,       // CPU 占用百分比
+    pub memory: f32,    // 内存占用百分比
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskUsage {
+    pub name: String,
+    pub mount_point: String,
+    pub total: u64,
+    pub available: u64,
+    pub removable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BatteryStatus {
+    pub percent: Option<u8>,
+    pub charging: bool,
+    pub plugged_in: bool,
+    pub seconds_remaining: Option<u32>, // 使用电池时的预计剩余时间
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemStats {
+    pub cpu: Option<f32>, // 采样线程刚启动、还没有两次采样时为 None
+    pub cpu_count: usize,
+    pub memory_used: u64,
+    pub memory_total: u64,
+    pub disks: Vec<DiskUsage>,
+    pub battery: Option<BatteryStatus>, // 没有电池的台式机为 None
+    pub history: Vec<SamplePoint>,
+}
+
+struct Sampler {
+    running: bool,
+    last_request: Instant,
+    cpu_count: usize,
+    memory_used: u64,
+    memory_total: u64,
+    history: VecDeque<SamplePoint>,
+}
+
+static SAMPLER: LazyLock<Mutex<Sampler>> = LazyLock::new(|| {
+    Mutex::new(Sampler {
+        running: false,
+        last_request: Instant::now(),
+        cpu_count: 0,
+        memory_used: 0,
+        memory_total: 0,
+        history: VecDeque::with_capacity(HISTORY_LEN),
+    })
+});
+
+fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+fn percent(used: u64, total: u64) -> f32 {
+    if total == 0 {
+        0.0
+    } else {
+        (used as f64 / total as f64 * 100.0) as f32
+    }
+}
+
+fn push_sample(history: &mut VecDeque<SamplePoint>, point: SamplePoint) {
+    if history.len() >= HISTORY_LEN {
+        history.pop_front();
+    }
+    history.push_back(point);
+}
+
+fn run_sampler() {
+    let mut system = System::new();
+    // CPU 占用需要两次刷新之间的差值
+    system.refresh_cpu_usage();
+    std::thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+    loop {
+        system.refresh_cpu_usage();
+        system.refresh_memory();
+        {
+            let Ok(mut sampler) = SAMPLER.lock() else {
+                return;
+            };
+            if sampler.last_request.elapsed() > IDLE_STOP_AFTER {
+                sampler.running = false;
+                sampler.history.clear();
+                return;
+            }
+            sampler.cpu_count = system.cpus().len();
+            sampler.memory_used = system.used_memory();
+            sampler.memory_total = system.total_memory();
+            let point = SamplePoint {
+                timestamp: now_ms(),
+                cpu: system.global_cpu_info().cpu_usage(),
+                memory: percent(system.used_memory(), system.total_memory()),
+            };
+            push_sample(&mut sampler.history, point);
+        }
+        std::thread::sleep(SAMPLE_INTERVAL);
+    }
+}
+
+fn disks() -> Vec<DiskUsage> {
+    Disks::new_with_refreshed_list()
+        .iter()
+        .map(|disk| DiskUsage {
+            name: disk.name().to_string_lossy().to_string(),
+            mount_point: disk.mount_point().to_string_lossy().to_string(),
+            total: disk.total_space(),
+            available: disk.available_space(),
+            removable: disk.is_removable(),
+        })
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn battery() -> Option<BatteryStatus> {
+    use windows_sys::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    let mut status: SYSTEM_POWER_STATUS = unsafe { std::mem::zeroed() };
+    if unsafe { GetSystemPowerStatus(&mut status) } == 0 {
+        return None;
+    }
+    // BatteryFlag：128 表示没有电池，255 表示状态未知；8 表示正在充电
+    if status.BatteryFlag == 128 || status.BatteryFlag == 255 {
+        return None;
+    }
+    Some(BatteryStatus {
+        percent: (status.BatteryLifePercent <= 100).then_some(status.BatteryLifePercent),
+        charging: status.BatteryFlag & 8 != 0,
+        plugged_in: status.ACLineStatus == 1,
+        seconds_remaining: (status.BatteryLifeTime != u32::MAX).then_some(status.BatteryLifeTime),
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+fn battery() -> Option<BatteryStatus> {
+    None
+}
+
+/// 当前系统状态与最近的 CPU/内存历史；采样线程未运行时启动它
+pub fn stats() -> SystemStats {
+    let (cpu, cpu_count, memory_used, memory_total, history) = match SAMPLER.lock() {
+        Ok(mut sampler) => {
+            sampler.last_request = Instant::now();
+            if !sampler.running {
+                sampler.running = true;
+                std::thread::spawn(run_sampler);
+            }
+            (
+                sampler.history.back().map(|p| p.cpu),
+                sampler.cpu_count,
+                sampler.memory_used,
+                sampler.memory_total,
+                sampler.history.iter().copied().collect(),
+            )
+        }
+        Err(_) => (None, 0, 0, 0, Vec::new()),
+    };
+    SystemStats { cpu, cpu_count, memory_used, memory_total, disks: disks(), battery: battery(), history }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_bounded_history() {
+        let mut history = VecDeque::new();
+        for i in 0..HISTORY_LEN as u64 + 5 {
+            push_sample(&mut history, SamplePoint { timestamp: i, cpu: 1.0, memory: 2.0 });
+        }
+        assert_eq!(history.len(), HISTORY_LEN);
+        assert_eq!(history.front().unwrap().timestamp, 5);
+        assert_eq!(percent(1, 4), 25.0);
+        assert_eq!(percent(1, 0), 0.0);
+    }
+}
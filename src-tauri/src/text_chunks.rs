@@ -0,0 +1,86 @@
+// 长文本切分：把整篇文章拆成适合单次 AI 请求的片段，用于长文本的分段翻译
+// 优先在段落边界切分，段落过长时在句末切分，仍然过长时按字符数硬切
+
+const SENTENCE_ENDS: &[char] = &['.', '!', '?', '。', '！', '？', '；', ';', '\n'];
+
+/// 在句末切分，单句超过 max_chars 时按字符数硬切
+fn split_sentences(paragraph: &str, max_chars: usize) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+    for c in paragraph.chars() {
+        current.push(c);
+        if SENTENCE_ENDS.contains(&c) {
+            pieces.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        pieces.push(current);
+    }
+
+    let mut result = Vec::new();
+    for piece in pieces {
+        let chars: Vec<char> = piece.chars().collect();
+        for part in chars.chunks(max_chars.max(1)) {
+            result.push(part.iter().collect());
+        }
+    }
+    result
+}
+
+/// 把文本切分为不超过 max_chars 个字符的片段（空白段落会被忽略）；
+/// 片段之间原本是段落分隔，同一段落被切开时片段末尾不再保留空行
+pub fn split_chunks(text: &str, max_chars: usize) -> Vec<String> {
+    let text = text.replace("\r\n", "\n");
+    let mut chunks: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut current_chars = 0;
+
+    let mut push_piece = |piece: &str, separator: &str, chunks: &mut Vec<String>| {
+        let piece_chars = piece.chars().count();
+        let separator_chars = if current.is_empty() { 0 } else { separator.chars().count() };
+        if !current.is_empty() && current_chars + separator_chars + piece_chars > max_chars {
+            chunks.push(std::mem::take(&mut current));
+            current_chars = 0;
+        }
+        if !current.is_empty() {
+            current.push_str(separator);
+            current_chars += separator.chars().count();
+        }
+        current.push_str(piece);
+        current_chars += piece_chars;
+    };
+
+    for paragraph in text.split("\n\n").map(str::trim).filter(|p| !p.is_empty()) {
+        if paragraph.chars().count() <= max_chars {
+            push_piece(paragraph, "\n\n", &mut chunks);
+            continue;
+        }
+        for (i, sentence) in split_sentences(paragraph, max_chars).iter().enumerate() {
+            push_piece(sentence, if i == 0 { "\n\n" } else { "" }, &mut chunks);
+        }
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_at_paragraphs_then_sentences() {
+        let text = "第一段。\r\n\r\n第二段。\n\n\n\nA long paragraph. It has three sentences! And ends here?";
+        assert_eq!(split_chunks(text, 1000), vec![
+            "第一段。\n\n第二段。\n\nA long paragraph. It has three sentences! And ends here?"
+        ]);
+
+        let chunks = split_chunks(text, 20);
+        assert_eq!(chunks[0], "第一段。\n\n第二段。");
+        assert!(chunks.iter().all(|c| c.chars().count() <= 20));
+        assert_eq!(chunks[1..].concat(), "A long paragraph. It has three sentences! And ends here?");
+
+        assert_eq!(split_chunks(&"字".repeat(25), 10).len(), 3);
+    }
+}
@@ -0,0 +1,405 @@
+// 文本转换：大小写与命名风格转换、JSON 格式化/压缩、行排序与去重、去除空白
+// 可作用于当前剪贴板文本，或前台窗口中选中的文本（读取选区 → 转换 → 粘贴回去）
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextTransform {
+    Upper,
+    Lower,
+    Title,
+    Camel,
+    Snake,
+    JsonPretty,
+    JsonMinify,
+    SortLines,
+    DedupeLines,
+    StripWhitespace,
+}
+
+impl TextTransform {
+    pub const ALL: [TextTransform; 10] = [
+        TextTransform::Upper,
+        TextTransform::Lower,
+        TextTransform::Title,
+        TextTransform::Camel,
+        TextTransform::Snake,
+        TextTransform::JsonPretty,
+        TextTransform::JsonMinify,
+        TextTransform::SortLines,
+        TextTransform::DedupeLines,
+        TextTransform::StripWhitespace,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            TextTransform::Upper => "upper",
+            TextTransform::Lower => "lower",
+            TextTransform::Title => "title",
+            TextTransform::Camel => "camel",
+            TextTransform::Snake => "snake",
+            TextTransform::JsonPretty => "json_pretty",
+            TextTransform::JsonMinify => "json_minify",
+            TextTransform::SortLines => "sort_lines",
+            TextTransform::DedupeLines => "dedupe_lines",
+            TextTransform::StripWhitespace => "strip_whitespace",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<TextTransform> {
+        Self::ALL.into_iter().find(|t| t.id() == id)
+    }
+}
+
+/// 保持原文的换行风格
+fn line_ending(text: &str) -> &'static str {
+    if text.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    }
+}
+
+fn map_lines(text: &str, f: impl Fn(&str) -> String) -> String {
+    text.lines().map(f).collect::<Vec<_>>().join(line_ending(text))
+}
+
+/// 按空白、标点与大小写边界拆分单词："parseHTTPResponse" -> ["parse", "HTTP", "Response"]
+fn split_words(line: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let chars: Vec<char> = line.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            // 小写/数字后的大写开始新词；连续大写中，后跟小写的最后一个大写开始新词
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars.flat_map(|c| c.to_lowercase())).collect(),
+        None => String::new(),
+    }
+}
+
+/// 每个单词首字母大写，保留原有的空白与标点
+fn title_case(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut at_word_start = true;
+    for c in text.chars() {
+        if c.is_alphanumeric() || c == '\'' {
+            if at_word_start {
+                result.extend(c.to_uppercase());
+            } else {
+                result.extend(c.to_lowercase());
+            }
+            at_word_start = false;
+        } else {
+            result.push(c);
+            at_word_start = true;
+        }
+    }
+    result
+}
+
+fn camel_case(line: &str) -> String {
+    split_words(line)
+        .iter()
+        .enumerate()
+        .map(|(i, w)| if i == 0 { w.to_lowercase() } else { capitalize(w) })
+        .collect()
+}
+
+fn snake_case(line: &str) -> String {
+    split_words(line).iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("_")
+}
+
+/// 按原有的键顺序重新排版 JSON；indent 为 None 时压缩为一行
+fn reformat_json(text: &str, indent: Option<&str>) -> Result<String, String> {
+    serde_json::from_str::<serde_json::Value>(text.trim()).map_err(|e| format!("不是有效的 JSON: {}", e))?;
+
+    let chars: Vec<char> = text.trim().chars().collect();
+    let mut out = String::with_capacity(chars.len());
+    let mut depth = 0usize;
+    let newline = |out: &mut String, depth: usize| {
+        if let Some(indent) = indent {
+            out.push('\n');
+            out.push_str(&indent.repeat(depth));
+        }
+    };
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '"' => {
+                // 字符串原样复制，注意转义的引号
+                out.push(c);
+                i += 1;
+                while i < chars.len() {
+                    out.push(chars[i]);
+                    if chars[i] == '\\' && i + 1 < chars.len() {
+                        out.push(chars[i + 1]);
+                        i += 1;
+                    } else if chars[i] == '"' {
+                        break;
+                    }
+                    i += 1;
+                }
+            }
+            '{' | '[' => {
+                out.push(c);
+                let close = if c == '{' { '}' } else { ']' };
+                let next = chars[i + 1..].iter().position(|n| !n.is_whitespace()).map(|p| i + 1 + p);
+                if next.is_some_and(|n| chars[n] == close) {
+                    // 空对象/数组保持在一行
+                    out.push(close);
+                    i = next.unwrap_or(i);
+                } else {
+                    depth += 1;
+                    newline(&mut out, depth);
+                }
+            }
+            '}' | ']' => {
+                depth = depth.saturating_sub(1);
+                newline(&mut out, depth);
+                out.push(c);
+            }
+            ',' => {
+                out.push(c);
+                newline(&mut out, depth);
+            }
+            ':' => {
+                out.push(c);
+                if indent.is_some() {
+                    out.push(' ');
+                }
+            }
+            c if c.is_whitespace() => {}
+            _ => out.push(c),
+        }
+        i += 1;
+    }
+    Ok(out)
+}
+
+/// 对文本执行转换；JSON 转换在文本不是有效 JSON 时返回错误
+pub fn apply(transform: TextTransform, text: &str) -> Result<String, String> {
+    let result = match transform {
+        TextTransform::Upper => text.to_uppercase(),
+        TextTransform::Lower => text.to_lowercase(),
+        TextTransform::Title => title_case(text),
+        TextTransform::Camel => map_lines(text, camel_case),
+        TextTransform::Snake => map_lines(text, snake_case),
+        TextTransform::JsonPretty => reformat_json(text, Some("  "))?,
+        TextTransform::JsonMinify => reformat_json(text, None)?,
+        TextTransform::SortLines => {
+            let mut lines: Vec<&str> = text.lines().collect();
+            lines.sort_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()).then_with(|| a.cmp(b)));
+            lines.join(line_ending(text))
+        }
+        TextTransform::DedupeLines => {
+            let mut seen = std::collections::HashSet::new();
+            let lines: Vec<&str> = text.lines().filter(|line| seen.insert(*line)).collect();
+            lines.join(line_ending(text))
+        }
+        TextTransform::StripWhitespace => {
+            // 去掉每行首尾空白以及开头、结尾的空行
+            map_lines(text, |line| line.trim().to_string()).trim_matches(['\r', '\n']).to_string()
+        }
+    };
+    Ok(result)
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use windows_sys::Win32::System::DataExchange::{
+        CloseClipboard, EmptyClipboard, GetClipboardData, OpenClipboard, SetClipboardData,
+    };
+    use windows_sys::Win32::System::Memory::{GlobalAlloc, GlobalLock, GlobalUnlock, GMEM_MOVEABLE};
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
+        SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, VK_CONTROL, VK_V,
+    };
+
+    const CF_UNICODETEXT: u32 = 13;
+
+    pub fn read_clipboard() -> Result<Option<String>, String> {
+        unsafe {
+            if OpenClipboard(0) == 0 {
+                return Err("打开剪贴板失败".to_string());
+            }
+            let handle = GetClipboardData(CF_UNICODETEXT);
+            let mut text = None;
+            if handle != 0 {
+                let ptr = GlobalLock(handle as *mut _) as *const u16;
+                if !ptr.is_null() {
+                    let mut len = 0;
+                    while *ptr.add(len) != 0 {
+                        len += 1;
+                    }
+                    text = Some(String::from_utf16_lossy(std::slice::from_raw_parts(ptr, len)));
+                    GlobalUnlock(handle as *mut _);
+                }
+            }
+            CloseClipboard();
+            Ok(text)
+        }
+    }
+
+    pub fn write_clipboard(text: &str) -> Result<(), String> {
+        let wide: Vec<u16> = text.encode_utf16().chain(std::iter::once(0)).collect();
+        unsafe {
+            if OpenClipboard(0) == 0 {
+                return Err("打开剪贴板失败".to_string());
+            }
+            EmptyClipboard();
+            let h_mem = GlobalAlloc(GMEM_MOVEABLE, wide.len() * 2);
+            let mut ok = false;
+            if !h_mem.is_null() {
+                let p_mem = GlobalLock(h_mem) as *mut u16;
+                if !p_mem.is_null() {
+                    std::ptr::copy_nonoverlapping(wide.as_ptr(), p_mem, wide.len());
+                    GlobalUnlock(h_mem);
+                    ok = SetClipboardData(CF_UNICODETEXT, h_mem as isize) != 0;
+                }
+            }
+            CloseClipboard();
+            if ok {
+                Ok(())
+            } else {
+                Err("写入剪贴板失败".to_string())
+            }
+        }
+    }
+
+    fn key_input(vk: u16, up: bool) -> INPUT {
+        INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT { wVk: vk, wScan: 0, dwFlags: if up { KEYEVENTF_KEYUP } else { 0 }, time: 0, dwExtraInfo: 0 },
+            },
+        }
+    }
+
+    fn send_ctrl(vk: u16) -> Result<(), String> {
+        let inputs = [key_input(VK_CONTROL, false), key_input(vk, false), key_input(vk, true), key_input(VK_CONTROL, true)];
+        let sent = unsafe { SendInput(inputs.len() as u32, inputs.as_ptr(), std::mem::size_of::<INPUT>() as i32) };
+        if sent != inputs.len() as u32 {
+            return Err("模拟按键失败".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn paste() -> Result<(), String> {
+        send_ctrl(VK_V)
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    const UNSUPPORTED: &str = "当前平台不支持文本转换";
+
+    pub fn read_clipboard() -> Result<Option<String>, String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn write_clipboard(_text: &str) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn paste() -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+}
+
+// 模拟粘贴后等待目标程序读取剪贴板，再恢复剪贴板原有内容
+const PASTE_SETTLE: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// 经剪贴板把文本粘贴到前台窗口，粘贴后恢复剪贴板原有内容；工作流中的“粘贴”步骤也使用它
+pub fn paste_text(text: &str) -> Result<(), String> {
+    let mut guard = crate::clipboard::ClipboardGuard::save();
+    platform::write_clipboard(text)?;
+    platform::paste()?;
+    guard.delay_restore(PASTE_SETTLE);
+    Ok(())
+}
+
+fn parse_transform(id: &str) -> Result<TextTransform, String> {
+    TextTransform::from_id(id).ok_or_else(|| format!("未知的文本转换: {}", id))
+}
+
+/// 转换剪贴板中的文本并写回剪贴板，返回转换结果
+pub fn transform_clipboard(id: &str) -> Result<String, String> {
+    let transform = parse_transform(id)?;
+    let text = platform::read_clipboard()?
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| "剪贴板中没有文本".to_string())?;
+    let result = apply(transform, &text)?;
+    platform::write_clipboard(&result)?;
+    Ok(result)
+}
+
+/// 读取前台窗口中选中的文本，转换后粘贴替换选区；剪贴板内容保持不变
+pub fn transform_selection(id: &str) -> Result<String, String> {
+    let transform = parse_transform(id)?;
+    let text = crate::selection::read_selection()?;
+    let result = apply(transform, &text)?;
+    paste_text(&result)?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(transform: TextTransform, text: &str) -> String {
+        apply(transform, text).unwrap()
+    }
+
+    #[test]
+    fn converts_case_and_naming_styles() {
+        assert_eq!(run(TextTransform::Title, "hello wORLD-wide it's"), "Hello World-Wide It's");
+        assert_eq!(run(TextTransform::Camel, "parse HTTP response"), "parseHttpResponse");
+        assert_eq!(run(TextTransform::Camel, "user_id\r\nfirst-name"), "userId\r\nfirstName");
+        assert_eq!(run(TextTransform::Snake, "parseHTTPResponse"), "parse_http_response");
+        assert_eq!(run(TextTransform::Snake, "Version2Name already_snake"), "version2_name_already_snake");
+        assert_eq!(run(TextTransform::Upper, "ab"), "AB");
+    }
+
+    #[test]
+    fn reformats_json_keeping_key_order() {
+        let text = r#"{"b": 1, "a": [1, 2, {}], "s": "x, \"y\": {z}", "e": []}"#;
+        assert_eq!(
+            run(TextTransform::JsonMinify, text),
+            r#"{"b":1,"a":[1,2,{}],"s":"x, \"y\": {z}","e":[]}"#
+        );
+        assert_eq!(
+            run(TextTransform::JsonPretty, r#"{"b":1,"a":[1,{}]}"#),
+            "{\n  \"b\": 1,\n  \"a\": [\n    1,\n    {}\n  ]\n}"
+        );
+        assert!(apply(TextTransform::JsonPretty, "{not json}").is_err());
+    }
+
+    #[test]
+    fn processes_lines() {
+        assert_eq!(run(TextTransform::SortLines, "b\nA\na\nc"), "A\na\nb\nc");
+        assert_eq!(run(TextTransform::DedupeLines, "x\ny\nx\nz\ny"), "x\ny\nz");
+        assert_eq!(run(TextTransform::StripWhitespace, "\n  a  \n\tb\n\n"), "a\nb");
+        assert_eq!(TextTransform::from_id("json_pretty"), Some(TextTransform::JsonPretty));
+    }
+}
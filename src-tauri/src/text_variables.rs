@@ -0,0 +1,220 @@
+// 文本扩展变量：{date:%Y-%m-%d}、{time}、{clip}、{uuid}、{random:int} 等内置变量，以及设置中的自定义变量
+// 片段插入、快捷链接打开等处共用 render；未知的占位符原样保留（如搜索引擎 URL 中待替换的 {query}）
+
+use crate::clipboard;
+use crate::settings::{self, TextVariableConfig};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Datelike, Local};
+use rand::Rng;
+use regex::{Captures, Regex};
+use serde::Serialize;
+use std::path::Path;
+use std::sync::LazyLock;
+
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+const DEFAULT_TIME_FORMAT: &str = "%H:%M:%S";
+const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const DEFAULT_RANDOM_MAX: i64 = 100;
+const WEEKDAY_CN: [&str; 7] = ["星期一", "星期二", "星期三", "星期四", "星期五", "星期六", "星期日"];
+
+/// 内置变量及说明
+pub const BUILTIN_VARIABLES: &[(&str, &str)] = &[
+    ("date", "当前日期，默认 %Y-%m-%d，可指定格式，如 {date:%Y年%m月%d日}"),
+    ("time", "当前时间，默认 %H:%M:%S，可指定格式，如 {time:%H:%M}"),
+    ("datetime", "当前日期和时间，默认 %Y-%m-%d %H:%M:%S"),
+    ("weekday", "今天星期几，如 星期一"),
+    ("timestamp", "Unix 时间戳（秒）"),
+    ("clip", "剪贴板中的文本"),
+    ("uuid", "随机 UUID（v4）"),
+    ("random", "随机数：{random:int} 为 0~100，{random:1-6} 指定范围，{random:hex} 为 8 位十六进制"),
+    ("query", "启动器中输入的内容（仅在调用方提供时替换）"),
+];
+
+// 变量名允许字母（含中文）、数字和下划线，冒号后为参数
+static VARIABLE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{([\p{L}_][\p{L}\p{N}_]*)(?::([^{}]*))?\}").unwrap());
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TextVariable {
+    pub name: String,
+    pub description: String,
+    pub custom: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    pub query: Option<String>,
+    pub url_encode: bool, // 替换的值做 URL 编码，用于快捷链接
+}
+
+/// 按 RFC 3986 编码，保留非保留字符
+pub fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// 格式无效时返回 None，避免 chrono 在格式化时 panic
+fn format_time(now: &DateTime<Local>, format: &str) -> Option<String> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return None;
+    }
+    Some(now.format(format).to_string())
+}
+
+fn random_value<R: Rng>(rng: &mut R, arg: Option<&str>) -> Option<String> {
+    match arg.map(str::trim).unwrap_or("int") {
+        "" | "int" => Some(rng.gen_range(0..=DEFAULT_RANDOM_MAX).to_string()),
+        "hex" => Some(format!("{:08x}", rng.gen::<u32>())),
+        range => {
+            let (min, max) = range.split_once('-').and_then(|(a, b)| Some((a.trim().parse::<i64>().ok()?, b.trim().parse::<i64>().ok()?)))?;
+            (min <= max).then(|| rng.gen_range(min..=max).to_string())
+        }
+    }
+}
+
+fn uuid_v4<R: Rng>(rng: &mut R) -> String {
+    let mut bytes: [u8; 16] = rng.gen();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32])
+}
+
+struct Renderer<'a, R: Rng, C: FnMut() -> Option<String>> {
+    options: &'a RenderOptions,
+    custom: &'a [TextVariableConfig],
+    now: DateTime<Local>,
+    rng: R,
+    read_clip: C,
+    clip: Option<Option<String>>, // 剪贴板只在用到时读取一次
+}
+
+impl<R: Rng, C: FnMut() -> Option<String>> Renderer<'_, R, C> {
+    fn builtin(&mut self, name: &str, arg: Option<&str>) -> Option<String> {
+        match name {
+            "date" => format_time(&self.now, arg.unwrap_or(DEFAULT_DATE_FORMAT)),
+            "time" => format_time(&self.now, arg.unwrap_or(DEFAULT_TIME_FORMAT)),
+            "datetime" => format_time(&self.now, arg.unwrap_or(DEFAULT_DATETIME_FORMAT)),
+            "weekday" => Some(WEEKDAY_CN[self.now.weekday().num_days_from_monday() as usize].to_string()),
+            "timestamp" => Some(self.now.timestamp().to_string()),
+            "clip" | "clipboard" => {
+                let read_clip = &mut self.read_clip;
+                self.clip.get_or_insert_with(read_clip).clone().or(Some(String::new()))
+            }
+            "uuid" => Some(uuid_v4(&mut self.rng)),
+            "random" => random_value(&mut self.rng, arg),
+            "query" => self.options.query.clone(),
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, template: &str, allow_custom: bool) -> String {
+        VARIABLE_RE
+            .replace_all(template, |caps: &Captures| {
+                let name = &caps[1];
+                let arg = caps.get(2).map(|m| m.as_str());
+                let value = self.builtin(name, arg).or_else(|| {
+                    // 自定义变量的值里只展开内置变量，避免互相引用造成循环
+                    let custom = self.custom.iter().find(|v| v.name == name).filter(|_| allow_custom)?;
+                    Some(self.render(&custom.value, false))
+                });
+                match value {
+                    Some(value) if self.options.url_encode => percent_encode(&value),
+                    Some(value) => value,
+                    None => caps[0].to_string(),
+                }
+            })
+            .into_owned()
+    }
+}
+
+/// 展开模板中的变量，时间、随机源与剪贴板读取由调用方提供
+pub fn render_with<R: Rng>(
+    template: &str,
+    options: &RenderOptions,
+    custom: &[TextVariableConfig],
+    now: DateTime<Local>,
+    rng: R,
+    read_clip: impl FnMut() -> Option<String>,
+) -> String {
+    if !template.contains('{') {
+        return template.to_string();
+    }
+    Renderer { options, custom, now, rng, read_clip, clip: None }.render(template, true)
+}
+
+/// 展开模板中的变量（含设置中的自定义变量）
+pub fn render(app_data_dir: &Path, template: &str, options: &RenderOptions) -> Result<String, String> {
+    if !template.contains('{') {
+        return Ok(template.to_string());
+    }
+    let settings = settings::load_settings(app_data_dir)?;
+    Ok(render_with(template, options, &settings.text_variables, Local::now(), rand::thread_rng(), || {
+        clipboard::read_text().ok()
+    }))
+}
+
+/// 所有可用变量：内置变量在前，其后为自定义变量
+pub fn list_variables(app_data_dir: &Path) -> Result<Vec<TextVariable>, String> {
+    let settings = settings::load_settings(app_data_dir)?;
+    let builtin = BUILTIN_VARIABLES.iter().map(|(name, description)| TextVariable {
+        name: name.to_string(),
+        description: description.to_string(),
+        custom: false,
+    });
+    let custom = settings
+        .text_variables
+        .into_iter()
+        .filter(|v| !BUILTIN_VARIABLES.iter().any(|(name, _)| *name == v.name))
+        .map(|v| TextVariable { name: v.name, description: v.value, custom: true });
+    Ok(builtin.chain(custom).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn render_at(template: &str, options: &RenderOptions, custom: &[TextVariableConfig]) -> String {
+        let now = Local.with_ymd_and_hms(2025, 3, 9, 14, 5, 30).unwrap();
+        render_with(template, options, custom, now, StdRng::seed_from_u64(7), || Some("剪贴 板".to_string()))
+    }
+
+    #[test]
+    fn renders_builtin_and_custom_variables() {
+        let options = RenderOptions::default();
+        assert_eq!(render_at("{date} {time:%H:%M} {weekday}", &options, &[]), "2025-03-09 14:05 星期日");
+        assert_eq!(render_at("{date:%Y年%m月}", &options, &[]), "2025年03月");
+        assert_eq!(render_at("[{clip}]", &options, &[]), "[剪贴 板]");
+        // 未知变量、无效格式和未提供的 {query} 原样保留
+        assert_eq!(render_at("{query} {unknown} {date:%Q}", &options, &[]), "{query} {unknown} {date:%Q}");
+
+        let dice: i64 = render_at("{random:1-6}", &options, &[]).parse().unwrap();
+        assert!((1..=6).contains(&dice));
+        assert_eq!(render_at("{random:hex}", &options, &[]).len(), 8);
+        let uuid = render_at("{uuid}", &options, &[]);
+        assert_eq!(uuid.len(), 36);
+        assert_eq!(&uuid[14..15], "4");
+
+        let custom = vec![
+            TextVariableConfig { name: "签名".to_string(), value: "张三 {date:%Y}".to_string() },
+            TextVariableConfig { name: "loop".to_string(), value: "{loop}".to_string() },
+        ];
+        assert_eq!(render_at("{签名} {loop}", &options, &custom), "张三 2025 {loop}");
+
+        let url = RenderOptions { query: Some("a b".to_string()), url_encode: true };
+        assert_eq!(
+            render_at("https://example.com/?q={query}&c={clip}", &url, &[]),
+            "https://example.com/?q=a%20b&c=%E5%89%AA%E8%B4%B4%20%E6%9D%BF"
+        );
+    }
+}
@@ -0,0 +1,264 @@
+// 主题与外观：内置 light / dark / acrylic 预设 + 用户自定义主题（JSON 保存在数据库）
+// 设置中的 theme 为 "system" 时跟随 Windows 深色模式自动切换，系统主题变化时发送事件
+
+use crate::db;
+use crate::settings;
+use crate::shutdown;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+pub const SYSTEM_THEME_ID: &str = "system";
+const SYSTEM_THEME_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Theme {
+    pub id: String,
+    pub name: String,
+    pub mode: String, // "light" | "dark"
+    #[serde(default)]
+    pub acrylic: bool, // 半透明背景
+    #[serde(default)]
+    pub builtin: bool,
+    #[serde(default)]
+    pub colors: HashMap<String, String>, // CSS 变量名（不含 --）-> 颜色值
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThemeState {
+    pub selected: String, // 设置中选择的主题 id（可能为 "system"）
+    pub system_dark: bool,
+    pub theme: Theme, // 实际生效的主题
+}
+
+fn now_ts() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+fn colors(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+/// 内置主题
+pub fn builtin_themes() -> Vec<Theme> {
+    vec![
+        Theme {
+            id: "light".to_string(),
+            name: "浅色".to_string(),
+            mode: "light".to_string(),
+            acrylic: false,
+            builtin: true,
+            colors: colors(&[
+                ("background", "#ffffff"),
+                ("surface", "#f9fafb"),
+                ("text", "#111827"),
+                ("text-muted", "#6b7280"),
+                ("border", "#e5e7eb"),
+                ("accent", "#6366f1"),
+            ]),
+        },
+        Theme {
+            id: "dark".to_string(),
+            name: "深色".to_string(),
+            mode: "dark".to_string(),
+            acrylic: false,
+            builtin: true,
+            colors: colors(&[
+                ("background", "#1f2937"),
+                ("surface", "#111827"),
+                ("text", "#f9fafb"),
+                ("text-muted", "#9ca3af"),
+                ("border", "#374151"),
+                ("accent", "#818cf8"),
+            ]),
+        },
+        Theme {
+            id: "acrylic".to_string(),
+            name: "亚克力".to_string(),
+            mode: "light".to_string(),
+            acrylic: true,
+            builtin: true,
+            colors: colors(&[
+                ("background", "rgba(255, 255, 255, 0.72)"),
+                ("surface", "rgba(249, 250, 251, 0.6)"),
+                ("text", "#111827"),
+                ("text-muted", "#4b5563"),
+                ("border", "rgba(255, 255, 255, 0.5)"),
+                ("accent", "#6366f1"),
+            ]),
+        },
+    ]
+}
+
+fn load_custom_themes(app_data_dir: &Path) -> Result<Vec<Theme>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached("SELECT data FROM themes ORDER BY created_at")
+            .map_err(|e| format!("Failed to prepare themes query: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| format!("Failed to iterate themes: {}", e))?;
+
+        let mut themes = Vec::new();
+        for row in rows {
+            let data = row.map_err(|e| format!("Failed to read theme row: {}", e))?;
+            match serde_json::from_str::<Theme>(&data) {
+                Ok(theme) => themes.push(theme),
+                Err(e) => eprintln!("[Themes] Skipping invalid theme: {}", e),
+            }
+        }
+        Ok(themes)
+    })
+}
+
+/// 获取所有主题（内置在前）
+pub fn list_themes(app_data_dir: &Path) -> Result<Vec<Theme>, String> {
+    let mut themes = builtin_themes();
+    themes.extend(load_custom_themes(app_data_dir)?);
+    Ok(themes)
+}
+
+/// 保存自定义主题（id 已存在时覆盖），json 为 Theme 的 JSON
+pub fn save_custom_theme(json: &str, app_data_dir: &Path) -> Result<Theme, String> {
+    let mut theme: Theme =
+        serde_json::from_str(json).map_err(|e| format!("Invalid theme JSON: {}", e))?;
+    theme.id = theme.id.trim().to_string();
+    if theme.id.is_empty() || theme.id == SYSTEM_THEME_ID {
+        return Err("主题 id 不能为空或为 system".to_string());
+    }
+    if builtin_themes().iter().any(|t| t.id == theme.id) {
+        return Err(format!("不能覆盖内置主题 {}", theme.id));
+    }
+    if theme.mode != "light" && theme.mode != "dark" {
+        return Err("主题 mode 必须为 light 或 dark".to_string());
+    }
+    theme.builtin = false;
+
+    let data = serde_json::to_string(&theme).map_err(|e| format!("Failed to serialize theme: {}", e))?;
+    let now = now_ts();
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "INSERT INTO themes (id, data, created_at, updated_at) VALUES (?1, ?2, ?3, ?3)
+             ON CONFLICT(id) DO UPDATE SET data = excluded.data, updated_at = excluded.updated_at",
+        )
+        .and_then(|mut stmt| stmt.execute(params![theme.id, data, now]))
+        .map_err(|e| format!("Failed to save theme: {}", e))
+    })?;
+    Ok(theme)
+}
+
+/// 删除自定义主题；正在使用时切回跟随系统
+pub fn delete_custom_theme(id: &str, app_data_dir: &Path) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached("DELETE FROM themes WHERE id = ?1")
+            .and_then(|mut stmt| stmt.execute(params![id]))
+            .map_err(|e| format!("Failed to delete theme: {}", e))
+    })?;
+
+    let mut settings = settings::load_settings(app_data_dir)?;
+    if settings.theme == id {
+        settings.theme = SYSTEM_THEME_ID.to_string();
+        settings::save_settings(app_data_dir, &settings)?;
+    }
+    Ok(())
+}
+
+/// 计算当前生效的主题
+pub fn get_theme(app_data_dir: &Path) -> Result<ThemeState, String> {
+    let selected = settings::load_settings(app_data_dir)?.theme;
+    let system_dark = is_system_dark();
+    let themes = list_themes(app_data_dir)?;
+
+    let wanted = if selected == SYSTEM_THEME_ID {
+        if system_dark { "dark" } else { "light" }
+    } else {
+        selected.as_str()
+    };
+    let theme = themes
+        .iter()
+        .find(|t| t.id == wanted)
+        .or_else(|| themes.first())
+        .cloned()
+        .ok_or_else(|| "No themes available".to_string())?;
+
+    Ok(ThemeState {
+        selected,
+        system_dark,
+        theme,
+    })
+}
+
+/// 选择主题（id 为 "system" 表示跟随系统）
+pub fn set_theme(id: &str, app_data_dir: &Path) -> Result<ThemeState, String> {
+    if id != SYSTEM_THEME_ID && !list_themes(app_data_dir)?.iter().any(|t| t.id == id) {
+        return Err(format!("Theme {} not found", id));
+    }
+    let mut settings = settings::load_settings(app_data_dir)?;
+    settings.theme = id.to_string();
+    settings::save_settings(app_data_dir, &settings)?;
+    get_theme(app_data_dir)
+}
+
+/// Windows 应用是否使用深色模式（读取 AppsUseLightTheme）
+#[cfg(target_os = "windows")]
+pub fn is_system_dark() -> bool {
+    use std::ffi::OsStr;
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD};
+
+    let to_wide = |s: &str| -> Vec<u16> { OsStr::new(s).encode_wide().chain(Some(0)).collect() };
+    let sub_key = to_wide("Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize");
+    let value_name = to_wide("AppsUseLightTheme");
+    let mut value: u32 = 1;
+    let mut size = std::mem::size_of::<u32>() as u32;
+
+    let result = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            sub_key.as_ptr(),
+            value_name.as_ptr(),
+            RRF_RT_REG_DWORD,
+            std::ptr::null_mut(),
+            &mut value as *mut u32 as *mut _,
+            &mut size,
+        )
+    };
+    result == 0 && value == 0
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn is_system_dark() -> bool {
+    false
+}
+
+/// 后台监听系统深色模式变化：发送 system-theme-changed，跟随系统时再发送 theme-changed
+pub fn start_system_theme_watcher(app_handle: AppHandle, app_data_dir: PathBuf) -> Result<(), String> {
+    shutdown::spawn("system-theme-watcher", move || {
+        let mut last_dark = is_system_dark();
+        while !shutdown::wait_timeout(SYSTEM_THEME_POLL_INTERVAL) {
+            let dark = is_system_dark();
+            if dark == last_dark {
+                continue;
+            }
+            last_dark = dark;
+            let _ = app_handle.emit("system-theme-changed", serde_json::json!({ "dark": dark }));
+
+            match get_theme(&app_data_dir) {
+                Ok(state) if state.selected == SYSTEM_THEME_ID => {
+                    let _ = app_handle.emit("theme-changed", state);
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("[Themes] Failed to resolve theme: {}", e),
+            }
+        }
+    })
+}
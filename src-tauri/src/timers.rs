@@ -0,0 +1,401 @@
+// 倒计时、闹钟与提醒：启动器输入 "timer 10m 泡茶"、"alarm 7:30 起床" 或自然语言提醒创建，保存在数据库中，重启后继续计时
+// 后台线程每秒检查一次最近的到期时间，到期时发送系统通知（可选提示音）并删除记录，循环提醒则排到下一次；应用关闭期间到期的在启动后补发
+
+use crate::reminders::{self, Recurrence};
+use crate::world_clock::parse_clock_time;
+use crate::{db, focus_mode, shutdown};
+use chrono::{Duration as ChronoDuration, Local, NaiveDateTime, NaiveTime, TimeZone};
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+const TICK: Duration = Duration::from_secs(1);
+const MAX_DURATION_SECS: i64 = 30 * 24 * 60 * 60;
+// 超过这个时间才到期的（应用未运行或系统睡眠）在通知中注明原定时间
+const LATE_GRACE_SECS: i64 = 60;
+// Windows 通知的提示音（Notification.Looping.Alarm，只播放一次）
+const ALARM_SOUND: &str = "Alarm";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimerKind {
+    Timer,
+    Alarm,
+    Reminder,
+}
+
+impl TimerKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            TimerKind::Timer => "timer",
+            TimerKind::Alarm => "alarm",
+            TimerKind::Reminder => "reminder",
+        }
+    }
+
+    fn from_str(value: &str) -> Self {
+        match value {
+            "alarm" => TimerKind::Alarm,
+            "reminder" => TimerKind::Reminder,
+            _ => TimerKind::Timer,
+        }
+    }
+
+    fn default_label(self) -> &'static str {
+        match self {
+            TimerKind::Timer => "计时器",
+            TimerKind::Alarm => "闹钟",
+            TimerKind::Reminder => "提醒",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Timer {
+    pub id: i64,
+    pub kind: TimerKind,
+    pub label: String,
+    pub fire_at: i64,       // 到期时间（Unix 秒）
+    pub duration_secs: i64, // 倒计时总时长；闹钟与提醒为创建时距到期的秒数
+    pub sound: bool,
+    pub created_at: i64,
+    pub recurrence: Option<String>, // 循环规则的描述，如 "每周一"
+}
+
+// 最近一次到期时间，None 表示没有待触发的计时；创建与重算时持锁，避免相互覆盖
+static NEXT_FIRE: Mutex<Option<i64>> = Mutex::new(None);
+
+fn now_secs() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+fn unit_secs(unit: &str) -> Option<f64> {
+    Some(match unit {
+        "" | "m" | "min" | "mins" | "minute" | "minutes" | "分" | "分钟" => 60.0,
+        "h" | "hr" | "hrs" | "hour" | "hours" | "时" | "小时" | "个小时" => 3600.0,
+        "s" | "sec" | "secs" | "second" | "seconds" | "秒" | "秒钟" => 1.0,
+        _ => return None,
+    })
+}
+
+/// 解析倒计时时长："10m"、"1h30m"、"90s"、"1.5h"、"10分钟"、"5:00"（分:秒）、"1:00:00"；只写数字按分钟
+pub fn parse_duration(input: &str) -> Result<i64, String> {
+    let invalid = || format!("无法识别的时长: {}", input.trim());
+    let text = input.trim().to_lowercase().replace(' ', "");
+    if text.is_empty() {
+        return Err(invalid());
+    }
+
+    let secs = if text.contains(':') {
+        let parts = text
+            .split(':')
+            .map(|p| p.parse::<u32>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+        match parts.as_slice() {
+            [m, s] if *s < 60 => (*m * 60 + *s) as f64,
+            [h, m, s] if *m < 60 && *s < 60 => (*h * 3600 + *m * 60 + *s) as f64,
+            _ => return Err(invalid()),
+        }
+    } else {
+        let mut total = 0.0;
+        let mut chars = text.chars().peekable();
+        while chars.peek().is_some() {
+            let mut number = String::new();
+            while let Some(&c) = chars.peek().filter(|c| c.is_ascii_digit() || **c == '.') {
+                number.push(c);
+                chars.next();
+            }
+            let mut unit = String::new();
+            while let Some(&c) = chars.peek().filter(|c| !c.is_ascii_digit() && **c != '.') {
+                unit.push(c);
+                chars.next();
+            }
+            let value: f64 = number.parse().map_err(|_| invalid())?;
+            total += value * unit_secs(&unit).ok_or_else(invalid)?;
+        }
+        total
+    };
+
+    let secs = secs.round() as i64;
+    if secs <= 0 {
+        return Err("时长必须大于 0".to_string());
+    }
+    if secs > MAX_DURATION_SECS {
+        return Err("时长不能超过 30 天".to_string());
+    }
+    Ok(secs)
+}
+
+/// 闹钟时间的下一次出现：今天已过则为明天
+fn next_occurrence(time: NaiveTime, now: NaiveDateTime) -> NaiveDateTime {
+    let today = now.date().and_time(time);
+    if today > now {
+        today
+    } else {
+        today + ChronoDuration::days(1)
+    }
+}
+
+/// 本地时间转为 Unix 秒：夏令时切换造成的重复时刻取较早的一个，不存在的时刻顺延一小时
+pub fn local_timestamp(time: NaiveDateTime) -> Option<i64> {
+    Local
+        .from_local_datetime(&time)
+        .earliest()
+        .or_else(|| Local.from_local_datetime(&(time + ChronoDuration::hours(1))).earliest())
+        .map(|dt| dt.timestamp())
+}
+
+/// 计算到期时间（Unix 秒）：倒计时按时长，闹钟按本地时间的下一次出现
+fn resolve_fire_at(kind: TimerKind, spec: &str) -> Result<i64, String> {
+    match kind {
+        TimerKind::Alarm => {
+            let time = parse_clock_time(spec)?;
+            local_timestamp(next_occurrence(time, Local::now().naive_local()))
+                .ok_or_else(|| format!("无法识别的时间: {}", spec.trim()))
+        }
+        _ => Ok(now_secs() + parse_duration(spec)?),
+    }
+}
+
+/// 时长的中文描述，如 "1 小时 30 分钟"、"45 秒"
+pub fn format_duration(secs: i64) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    let mut parts = Vec::new();
+    if hours > 0 {
+        parts.push(format!("{} 小时", hours));
+    }
+    if minutes > 0 {
+        parts.push(format!("{} 分钟", minutes));
+    }
+    if seconds > 0 || parts.is_empty() {
+        parts.push(format!("{} 秒", seconds));
+    }
+    parts.join(" ")
+}
+
+fn read_timer(row: &rusqlite::Row) -> rusqlite::Result<Timer> {
+    let kind: String = row.get(1)?;
+    Ok(Timer {
+        id: row.get(0)?,
+        kind: TimerKind::from_str(&kind),
+        label: row.get(2)?,
+        fire_at: row.get(3)?,
+        duration_secs: row.get(4)?,
+        sound: row.get::<_, i64>(5)? != 0,
+        created_at: row.get(6)?,
+        recurrence: row.get::<_, Option<String>>(7)?.as_deref().and_then(Recurrence::decode).map(Recurrence::describe),
+    })
+}
+
+const TIMER_COLUMNS: &str = "id, kind, label, fire_at, duration_secs, sound, created_at, recurrence";
+
+/// 所有待触发的计时，按到期时间排序
+pub fn list_timers(app_data_dir: &Path) -> Result<Vec<Timer>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(&format!("SELECT {} FROM timers ORDER BY fire_at", TIMER_COLUMNS))
+            .map_err(|e| format!("Failed to prepare timers query: {}", e))?;
+        let timers = stmt
+            .query_map([], read_timer)
+            .map_err(|e| format!("Failed to query timers: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read timers: {}", e))?;
+        Ok(timers)
+    })
+}
+
+/// 创建倒计时或闹钟；spec 为时长（倒计时）或时刻（闹钟），label 为空时使用默认名称
+pub fn create_timer(
+    app_handle: &AppHandle,
+    app_data_dir: &Path,
+    kind: TimerKind,
+    spec: &str,
+    label: Option<&str>,
+    sound: bool,
+) -> Result<Timer, String> {
+    let fire_at = resolve_fire_at(kind, spec)?;
+    schedule(app_handle, app_data_dir, kind, label.unwrap_or_default(), fire_at, sound, None)
+}
+
+/// 自然语言创建提醒，如 "明天上午9点提醒我交报告"、"every monday 9am team sync"
+pub fn create_reminder(app_handle: &AppHandle, app_data_dir: &Path, input: &str, sound: bool) -> Result<Timer, String> {
+    let parsed = reminders::parse_reminder(input, Local::now().naive_local())?;
+    let fire_at = local_timestamp(parsed.at).ok_or("提醒时间在本地时区不存在")?;
+    schedule(app_handle, app_data_dir, TimerKind::Reminder, &parsed.text, fire_at, sound, parsed.recurrence)
+}
+
+fn schedule(
+    app_handle: &AppHandle,
+    app_data_dir: &Path,
+    kind: TimerKind,
+    label: &str,
+    fire_at: i64,
+    sound: bool,
+    recurrence: Option<Recurrence>,
+) -> Result<Timer, String> {
+    let label = Some(label.trim()).filter(|l| !l.is_empty()).unwrap_or(kind.default_label()).to_string();
+    let created_at = now_secs();
+
+    let mut next = NEXT_FIRE.lock().map_err(|e| format!("Failed to lock timer schedule: {}", e))?;
+    let id = db::with_connection(app_data_dir, |conn| {
+        conn.execute(
+            "INSERT INTO timers (kind, label, fire_at, duration_secs, sound, created_at, recurrence) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![kind.as_str(), label, fire_at, fire_at - created_at, sound, created_at, recurrence.map(Recurrence::encode)],
+        )
+        .map_err(|e| format!("Failed to insert timer: {}", e))?;
+        Ok(conn.last_insert_rowid())
+    })?;
+    *next = Some(next.map_or(fire_at, |current| current.min(fire_at)));
+    drop(next);
+
+    let _ = app_handle.emit("timers-changed", ());
+    Ok(Timer {
+        id,
+        kind,
+        label,
+        fire_at,
+        duration_secs: fire_at - created_at,
+        sound,
+        created_at,
+        recurrence: recurrence.map(Recurrence::describe),
+    })
+}
+
+/// 取消计时；最近到期时间不必重算，多余的一次唤醒只会查到没有到期的记录
+pub fn cancel_timer(app_handle: &AppHandle, app_data_dir: &Path, id: i64) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute("DELETE FROM timers WHERE id = ?1", params![id])
+            .map_err(|e| format!("Failed to delete timer: {}", e))?;
+        Ok(())
+    })?;
+    let _ = app_handle.emit("timers-changed", ());
+    Ok(())
+}
+
+/// 从数据库重新读取最近的到期时间
+fn refresh_next_fire(app_data_dir: &Path) -> Result<(), String> {
+    let mut next = NEXT_FIRE.lock().map_err(|e| format!("Failed to lock timer schedule: {}", e))?;
+    *next = db::with_connection(app_data_dir, |conn| {
+        conn.query_row("SELECT MIN(fire_at) FROM timers", [], |row| row.get(0))
+            .map_err(|e| format!("Failed to query next timer: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 循环提醒的下一次到期时间（Unix 秒），按本地时间保持原来的时刻
+fn next_fire_at(recurrence: Recurrence, fire_at: i64, now: i64) -> Option<i64> {
+    let previous = Local.timestamp_opt(fire_at, 0).single()?.naive_local();
+    let now = Local.timestamp_opt(now, 0).single()?.naive_local();
+    local_timestamp(recurrence.next_after(previous, now))
+}
+
+/// 取出所有已到期的计时：一次性的删除，循环的排到下一次
+fn take_due(app_data_dir: &Path, now: i64) -> Result<Vec<Timer>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(&format!("SELECT {} FROM timers WHERE fire_at <= ?1 ORDER BY fire_at", TIMER_COLUMNS))
+            .map_err(|e| format!("Failed to prepare due timers query: {}", e))?;
+        let due = stmt
+            .query_map(params![now], |row| Ok((read_timer(row)?, row.get::<_, Option<String>>(7)?)))
+            .map_err(|e| format!("Failed to query due timers: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read due timers: {}", e))?;
+        for (timer, recurrence) in &due {
+            match recurrence.as_deref().and_then(Recurrence::decode).and_then(|r| next_fire_at(r, timer.fire_at, now)) {
+                Some(next) => conn.execute("UPDATE timers SET fire_at = ?1 WHERE id = ?2", params![next, timer.id]),
+                None => conn.execute("DELETE FROM timers WHERE id = ?1", params![timer.id]),
+            }
+            .map_err(|e| format!("Failed to update due timer: {}", e))?;
+        }
+        Ok(due.into_iter().map(|(timer, _)| timer).collect())
+    })
+}
+
+fn notify(app_handle: &AppHandle, timer: &Timer, now: i64) {
+    let title = match timer.kind {
+        TimerKind::Timer => "计时结束",
+        TimerKind::Alarm => "闹钟",
+        TimerKind::Reminder => "提醒",
+    };
+    let mut body = match timer.kind {
+        TimerKind::Timer => format!("{} · {}", timer.label, format_duration(timer.duration_secs)),
+        _ => timer.label.clone(),
+    };
+    if now - timer.fire_at > LATE_GRACE_SECS {
+        if let Some(at) = Local.timestamp_opt(timer.fire_at, 0).single() {
+            body.push_str(&format!("（原定 {}）", at.format("%m-%d %H:%M")));
+        }
+    }
+
+    let sound = timer.sound.then_some(ALARM_SOUND);
+    if let Err(e) = focus_mode::show_notification(app_handle, title, &body, sound) {
+        eprintln!("[Timers] Failed to show notification: {}", e);
+    }
+    let _ = app_handle.emit("timer-fired", timer);
+}
+
+/// 启动计时调度线程；应用关闭期间已到期的计时会在第一次检查时补发通知
+pub fn start_scheduler(app_handle: AppHandle, app_data_dir: PathBuf) -> Result<(), String> {
+    refresh_next_fire(&app_data_dir)?;
+    shutdown::spawn("timers", move || {
+        while !shutdown::wait_timeout(TICK) {
+            let now = now_secs();
+            let next = NEXT_FIRE.lock().ok().and_then(|next| *next);
+            if next.is_none_or(|at| at > now) {
+                continue;
+            }
+            match take_due(&app_data_dir, now) {
+                Ok(due) => {
+                    for timer in &due {
+                        notify(&app_handle, timer, now);
+                    }
+                    if !due.is_empty() {
+                        let _ = app_handle.emit("timers-changed", ());
+                    }
+                }
+                Err(e) => eprintln!("[Timers] Failed to fire timers: {}", e),
+            }
+            if let Err(e) = refresh_next_fire(&app_data_dir) {
+                eprintln!("[Timers] {}", e);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("10m"), Ok(600));
+        assert_eq!(parse_duration("25"), Ok(1500));
+        assert_eq!(parse_duration("1h30m"), Ok(5400));
+        assert_eq!(parse_duration("1h 30m 15s"), Ok(5415));
+        assert_eq!(parse_duration("1.5h"), Ok(5400));
+        assert_eq!(parse_duration("90s"), Ok(90));
+        assert_eq!(parse_duration("10分钟"), Ok(600));
+        assert_eq!(parse_duration("2小时"), Ok(7200));
+        assert_eq!(parse_duration("5:30"), Ok(330));
+        assert_eq!(parse_duration("1:00:00"), Ok(3600));
+        assert!(parse_duration("tea").is_err());
+        assert!(parse_duration("5:75").is_err());
+        assert!(parse_duration("0m").is_err());
+        assert!(parse_duration("31d").is_err());
+        assert_eq!(format_duration(5415), "1 小时 30 分钟 15 秒");
+        assert_eq!(format_duration(600), "10 分钟");
+    }
+
+    #[test]
+    fn alarm_rolls_over_to_tomorrow() {
+        let now = NaiveDateTime::parse_from_str("2025-06-10 08:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let later = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+        let earlier = NaiveTime::from_hms_opt(7, 30, 0).unwrap();
+        assert_eq!(next_occurrence(later, now).to_string(), "2025-06-10 09:30:00");
+        assert_eq!(next_occurrence(earlier, now).to_string(), "2025-06-11 07:30:00");
+        assert_eq!(next_occurrence(NaiveTime::from_hms_opt(8, 0, 0).unwrap(), now).to_string(), "2025-06-11 08:00:00");
+    }
+}
@@ -0,0 +1,384 @@
+// TOTP 两步验证码：账户密钥经 secrets 加密后保存在数据库，按 RFC 6238 计算当前验证码
+// 添加时可直接粘贴 Base32 密钥或 otpauth:// 链接（二维码内容），启动器中输入账户名即可查看和复制
+
+use crate::{db, pinyin_utils, secrets};
+use hmac::{Hmac, Mac};
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DEFAULT_DIGITS: u32 = 6;
+const DEFAULT_PERIOD: u64 = 30;
+const BASE32_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TotpAlgorithm {
+    #[default]
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+impl TotpAlgorithm {
+    pub fn name(self) -> &'static str {
+        match self {
+            TotpAlgorithm::Sha1 => "SHA1",
+            TotpAlgorithm::Sha256 => "SHA256",
+            TotpAlgorithm::Sha512 => "SHA512",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().replace('-', "").as_str() {
+            "SHA1" => Some(TotpAlgorithm::Sha1),
+            "SHA256" => Some(TotpAlgorithm::Sha256),
+            "SHA512" => Some(TotpAlgorithm::Sha512),
+            _ => None,
+        }
+    }
+}
+
+/// 账户信息（不含密钥）
+#[derive(Debug, Clone, Serialize)]
+pub struct TotpAccount {
+    pub id: String,
+    pub name: String,
+    pub issuer: String,
+    pub algorithm: TotpAlgorithm,
+    pub digits: u32,
+    pub period: u64,
+}
+
+/// 新增账户：secret 可以是 Base32 密钥或 otpauth:// 链接，链接中的参数用于补全未填写的字段
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct NewTotpAccount {
+    pub name: String,
+    pub issuer: String,
+    pub secret: String,
+    pub algorithm: Option<TotpAlgorithm>,
+    pub digits: Option<u32>,
+    pub period: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TotpCode {
+    pub id: String,
+    pub name: String,
+    pub issuer: String,
+    pub code: String,
+    pub period: u64,
+    pub remaining: u64, // 当前验证码剩余有效秒数
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// 解码 Base32 密钥：忽略大小写、空格、连字符和末尾的 '=' 填充
+pub fn decode_base32(input: &str) -> Result<Vec<u8>, String> {
+    let mut output = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u64 = 0;
+    let mut bits = 0;
+    for c in input.trim_end_matches('=').chars().filter(|c| !c.is_whitespace() && *c != '-') {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&a| a == c.to_ascii_uppercase() as u8)
+            .ok_or_else(|| format!("密钥包含无效字符 '{}'", c))?;
+        buffer = (buffer << 5) | value as u64;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    if output.is_empty() {
+        return Err("密钥不能为空".to_string());
+    }
+    Ok(output)
+}
+
+fn hmac_digest<M: Mac + hmac::digest::KeyInit>(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = <M as hmac::digest::KeyInit>::new_from_slice(key).expect("HMAC 接受任意长度的密钥");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// RFC 4226 HOTP：HMAC 后动态截断取指定位数
+fn hotp(key: &[u8], counter: u64, digits: u32, algorithm: TotpAlgorithm) -> String {
+    let message = counter.to_be_bytes();
+    let digest = match algorithm {
+        TotpAlgorithm::Sha1 => hmac_digest::<Hmac<sha1::Sha1>>(key, &message),
+        TotpAlgorithm::Sha256 => hmac_digest::<Hmac<sha2::Sha256>>(key, &message),
+        TotpAlgorithm::Sha512 => hmac_digest::<Hmac<sha2::Sha512>>(key, &message),
+    };
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([digest[offset] & 0x7f, digest[offset + 1], digest[offset + 2], digest[offset + 3]]);
+    format!("{:0width$}", binary as u64 % 10u64.pow(digits), width = digits as usize)
+}
+
+/// 计算 unix_time 时刻的验证码
+pub fn code_at(key: &[u8], algorithm: TotpAlgorithm, digits: u32, period: u64, unix_time: u64) -> String {
+    hotp(key, unix_time / period, digits, algorithm)
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let hex = |b: u8| (b as char).to_digit(16).map(|d| d as u8);
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = match bytes.get(i..i + 3) {
+            Some([b'%', high, low]) => hex(*high).zip(hex(*low)).map(|(h, l)| h << 4 | l),
+            _ => None,
+        };
+        match (escaped, bytes[i]) {
+            (Some(b), _) => {
+                output.push(b);
+                i += 3;
+                continue;
+            }
+            (None, b'+') => output.push(b' '),
+            (None, b) => output.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&output).into_owned()
+}
+
+/// 解析 otpauth://totp/Issuer:account?secret=...&issuer=...&algorithm=...&digits=...&period=...
+fn parse_otpauth(uri: &str) -> Result<NewTotpAccount, String> {
+    let rest = uri
+        .strip_prefix("otpauth://")
+        .ok_or_else(|| "不是 otpauth:// 链接".to_string())?;
+    let (kind, rest) = rest.split_once('/').ok_or_else(|| "otpauth 链接格式错误".to_string())?;
+    if !kind.eq_ignore_ascii_case("totp") {
+        return Err(format!("不支持 {} 类型的验证码，仅支持 TOTP", kind));
+    }
+    let (label, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let label = percent_decode(label);
+    let mut account = match label.split_once(':') {
+        Some((issuer, name)) => NewTotpAccount {
+            issuer: issuer.trim().to_string(),
+            name: name.trim().to_string(),
+            ..Default::default()
+        },
+        None => NewTotpAccount { name: label.trim().to_string(), ..Default::default() },
+    };
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = percent_decode(value);
+        match key.to_ascii_lowercase().as_str() {
+            "secret" => account.secret = value,
+            "issuer" => account.issuer = value,
+            "algorithm" => {
+                account.algorithm =
+                    Some(TotpAlgorithm::from_name(&value).ok_or_else(|| format!("不支持的算法: {}", value))?)
+            }
+            "digits" => account.digits = value.parse().ok(),
+            "period" => account.period = value.parse().ok(),
+            _ => {}
+        }
+    }
+    Ok(account)
+}
+
+fn matches_query(account: &TotpAccount, query: &str) -> bool {
+    if query.is_empty() {
+        return true;
+    }
+    [&account.name, &account.issuer].iter().any(|text| {
+        text.to_lowercase().contains(query)
+            || pinyin_utils::names(text).is_some_and(|names| names.full.contains(query) || names.initials.contains(query))
+    })
+}
+
+fn read_account(row: &rusqlite::Row) -> rusqlite::Result<(TotpAccount, String)> {
+    let algorithm: String = row.get(3)?;
+    Ok((
+        TotpAccount {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            issuer: row.get(2)?,
+            algorithm: TotpAlgorithm::from_name(&algorithm).unwrap_or_default(),
+            digits: row.get(4)?,
+            period: row.get::<_, i64>(5)? as u64,
+        },
+        row.get(6)?,
+    ))
+}
+
+/// 读取账户及其加密后的密钥
+fn load_accounts(app_data_dir: &Path) -> Result<Vec<(TotpAccount, String)>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT id, name, issuer, algorithm, digits, period, secret
+                 FROM totp_accounts ORDER BY issuer COLLATE NOCASE, name COLLATE NOCASE",
+            )
+            .map_err(|e| format!("Failed to prepare totp query: {}", e))?;
+        let rows = stmt
+            .query_map([], read_account)
+            .map_err(|e| format!("Failed to query totp accounts: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read totp accounts: {}", e))?;
+        Ok(rows)
+    })
+}
+
+/// 获取所有账户（不含密钥）
+pub fn list_accounts(app_data_dir: &Path) -> Result<Vec<TotpAccount>, String> {
+    Ok(load_accounts(app_data_dir)?.into_iter().map(|(account, _)| account).collect())
+}
+
+/// 新增账户：校验密钥后加密保存
+pub fn add_account(app_data_dir: &Path, input: NewTotpAccount) -> Result<TotpAccount, String> {
+    let mut input = input;
+    let secret = input.secret.trim().to_string();
+    if secret.to_ascii_lowercase().starts_with("otpauth://") {
+        let parsed = parse_otpauth(&secret)?;
+        if input.name.trim().is_empty() {
+            input.name = parsed.name;
+        }
+        if input.issuer.trim().is_empty() {
+            input.issuer = parsed.issuer;
+        }
+        input.secret = parsed.secret;
+        input.algorithm = input.algorithm.or(parsed.algorithm);
+        input.digits = input.digits.or(parsed.digits);
+        input.period = input.period.or(parsed.period);
+    }
+    let secret: String = input.secret.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_ascii_uppercase();
+    decode_base32(&secret)?;
+
+    let name = input.name.trim().to_string();
+    if name.is_empty() {
+        return Err("账户名称不能为空".to_string());
+    }
+    let digits = input.digits.unwrap_or(DEFAULT_DIGITS);
+    if !(6..=8).contains(&digits) {
+        return Err("验证码位数需在 6 到 8 之间".to_string());
+    }
+    let period = input.period.unwrap_or(DEFAULT_PERIOD);
+    if !(1..=300).contains(&period) {
+        return Err("刷新周期需在 1 到 300 秒之间".to_string());
+    }
+    let account = TotpAccount {
+        id: format!("totp-{}", SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos()),
+        name,
+        issuer: input.issuer.trim().to_string(),
+        algorithm: input.algorithm.unwrap_or_default(),
+        digits,
+        period,
+    };
+    let encrypted = secrets::encrypt(&secret)?;
+
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute(
+            "INSERT INTO totp_accounts (id, name, issuer, secret, algorithm, digits, period, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                account.id,
+                account.name,
+                account.issuer,
+                encrypted,
+                account.algorithm.name(),
+                account.digits,
+                account.period as i64,
+                now_secs() as i64
+            ],
+        )
+        .map_err(|e| format!("Failed to insert totp account: {}", e))
+    })?;
+    Ok(account)
+}
+
+/// 删除账户
+pub fn remove_account(app_data_dir: &Path, id: &str) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.execute("DELETE FROM totp_accounts WHERE id = ?1", params![id])
+            .map_err(|e| format!("Failed to delete totp account: {}", e))
+    })?;
+    Ok(())
+}
+
+fn current_code(account: TotpAccount, encrypted: &str, now: u64) -> Result<TotpCode, String> {
+    let key = decode_base32(&secrets::decrypt(encrypted)?)?;
+    Ok(TotpCode {
+        code: code_at(&key, account.algorithm, account.digits, account.period, now),
+        remaining: account.period - now % account.period,
+        period: account.period,
+        id: account.id,
+        name: account.name,
+        issuer: account.issuer,
+    })
+}
+
+/// 按名称或发行方（支持拼音）筛选账户并计算当前验证码，查询为空时返回全部
+pub fn current_codes(app_data_dir: &Path, query: &str) -> Result<Vec<TotpCode>, String> {
+    let query = query.trim().to_lowercase();
+    let now = now_secs();
+    load_accounts(app_data_dir)?
+        .into_iter()
+        .filter(|(account, _)| matches_query(account, &query))
+        .map(|(account, encrypted)| current_code(account, &encrypted, now))
+        .collect()
+}
+
+/// 计算单个账户的当前验证码
+pub fn code_for(app_data_dir: &Path, id: &str) -> Result<TotpCode, String> {
+    let row = db::with_connection(app_data_dir, |conn| {
+        conn.query_row(
+            "SELECT id, name, issuer, algorithm, digits, period, secret FROM totp_accounts WHERE id = ?1",
+            params![id],
+            read_account,
+        )
+        .optional()
+        .map_err(|e| format!("Failed to query totp account: {}", e))
+    })?;
+    let (account, encrypted) = row.ok_or_else(|| "账户不存在".to_string())?;
+    current_code(account, &encrypted, now_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc6238_vectors() {
+        let sha1 = b"12345678901234567890";
+        let sha256 = b"12345678901234567890123456789012";
+        let sha512 = b"1234567890123456789012345678901234567890123456789012345678901234";
+        assert_eq!(code_at(sha1, TotpAlgorithm::Sha1, 8, 30, 59), "94287082");
+        assert_eq!(code_at(sha1, TotpAlgorithm::Sha1, 8, 30, 1111111109), "07081804");
+        assert_eq!(code_at(sha256, TotpAlgorithm::Sha256, 8, 30, 59), "46119246");
+        assert_eq!(code_at(sha512, TotpAlgorithm::Sha512, 8, 30, 59), "90693936");
+        assert_eq!(code_at(sha1, TotpAlgorithm::Sha1, 6, 30, 1234567890), "005924");
+    }
+
+    #[test]
+    fn decodes_base32() {
+        assert_eq!(decode_base32("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ").unwrap(), b"12345678901234567890");
+        assert_eq!(decode_base32("mzxw 6ytb oi======").unwrap(), b"foobar");
+        assert!(decode_base32("ABC1").is_err());
+        assert!(decode_base32("").is_err());
+    }
+
+    #[test]
+    fn parses_otpauth_uri() {
+        let parsed = parse_otpauth(
+            "otpauth://totp/GitHub:alice%40example.com?secret=JBSWY3DPEHPK3PXP&issuer=GitHub&algorithm=SHA256&digits=8&period=60",
+        )
+        .unwrap();
+        assert_eq!(parsed.name, "alice@example.com");
+        assert_eq!(parsed.issuer, "GitHub");
+        assert_eq!(parsed.secret, "JBSWY3DPEHPK3PXP");
+        assert_eq!(parsed.algorithm, Some(TotpAlgorithm::Sha256));
+        assert_eq!(parsed.digits, Some(8));
+        assert_eq!(parsed.period, Some(60));
+        assert!(parse_otpauth("otpauth://hotp/x?secret=AA").is_err());
+    }
+}
@@ -0,0 +1,54 @@
+// 系统托盘菜单：菜单文字按当前语言生成，切换语言后重建菜单
+
+use crate::{i18n, keep_awake};
+use tauri::menu::{CheckMenuItem, Menu, MenuItem};
+use tauri::{AppHandle, Manager, Runtime};
+
+pub const TRAY_ID: &str = "main";
+pub const TOOLTIP: &str = "ReFast";
+
+/// 按当前语言构建托盘菜单
+pub fn build_menu<R: Runtime, M: Manager<R>>(manager: &M) -> tauri::Result<Menu<R>> {
+    let app_center = MenuItem::with_id(manager, "app_center", i18n::t("tray.app_center"), true, None::<&str>)?;
+    let keep_awake = CheckMenuItem::with_id(
+        manager,
+        "keep_awake",
+        i18n::t("tray.keep_awake"),
+        true,
+        keep_awake::is_active(),
+        None::<&str>,
+    )?;
+    let open_logs = MenuItem::with_id(manager, "open_logs", i18n::t("tray.open_logs"), true, None::<&str>)?;
+    let restart = MenuItem::with_id(manager, "restart", i18n::t("tray.restart"), true, None::<&str>)?;
+    let quit = MenuItem::with_id(manager, "quit", i18n::t("tray.quit"), true, None::<&str>)?;
+
+    Menu::with_items(manager, &[&app_center, &keep_awake, &open_logs, &restart, &quit])
+}
+
+/// 语言变化或保持唤醒状态变化后重建托盘菜单
+pub fn refresh_menu(app: &AppHandle) -> Result<(), String> {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return Ok(());
+    };
+    let menu = build_menu(app).map_err(|e| format!("Failed to build tray menu: {}", e))?;
+    tray.set_menu(Some(menu))
+        .map_err(|e| format!("Failed to update tray menu: {}", e))
+}
+
+/// 保持唤醒期间在托盘提示中标注（有时长时显示结束时间）
+pub fn refresh_tooltip(app: &AppHandle) -> Result<(), String> {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return Ok(());
+    };
+    let status = keep_awake::status();
+    let tooltip = if !status.active {
+        TOOLTIP.to_string()
+    } else if let Some(until) = status.until.and_then(chrono::DateTime::from_timestamp_millis) {
+        let until = until.with_timezone(&chrono::Local).format("%H:%M");
+        format!("{} - {}", TOOLTIP, i18n::tf("tray.keep_awake_until", &[&until]))
+    } else {
+        format!("{} - {}", TOOLTIP, i18n::t("tray.keep_awake_active"))
+    };
+    tray.set_tooltip(Some(tooltip))
+        .map_err(|e| format!("Failed to update tray tooltip: {}", e))
+}
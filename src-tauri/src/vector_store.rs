@@ -0,0 +1,315 @@
+// 本地向量库：按集合（如 clipboard、memos）保存向量，支持插入、删除和余弦相似度查询
+// 向量按固定长度的槽位写入 app_data_dir/vectors/<集合>.vec，查询时内存映射读取；键、模型、内容哈希和槽位记录在 SQLite
+
+use crate::db;
+use memmap2::Mmap;
+use rusqlite::params;
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MAGIC: &[u8; 4] = b"IMVS";
+const VERSION: u32 = 1;
+const HEADER_LEN: u64 = 16; // magic + version + 维度 + 保留
+
+// 向量文件只在持有该锁时读写，内存映射期间不会被修改
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+/// 已保存向量的元数据
+#[derive(Debug, Clone)]
+pub struct VectorEntry {
+    pub key: String,
+    pub model: String,
+    pub content_hash: String,
+}
+
+pub struct VectorStore {
+    app_data_dir: PathBuf,
+    collection: String,
+}
+
+fn now_ts() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+pub fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f32, 0.0f32, 0.0f32);
+    for (x, y) in a.iter().zip(b) {
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a.sqrt() * norm_b.sqrt())
+    }
+}
+
+fn decode(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+fn encode(vector: &[f32]) -> Vec<u8> {
+    vector.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+fn lock() -> std::sync::MutexGuard<'static, ()> {
+    STORE_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl VectorStore {
+    /// 打开集合，集合名只能包含小写字母、数字、- 和 _
+    pub fn open(app_data_dir: &Path, collection: &str) -> Result<Self, String> {
+        let valid = !collection.is_empty()
+            && collection
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if !valid {
+            return Err(format!("Invalid vector collection name: {}", collection));
+        }
+        Ok(Self {
+            app_data_dir: app_data_dir.to_path_buf(),
+            collection: collection.to_string(),
+        })
+    }
+
+    fn file_path(&self) -> PathBuf {
+        self.app_data_dir
+            .join("vectors")
+            .join(format!("{}.vec", self.collection))
+    }
+
+    /// 读取文件头中的维度，文件不存在或格式不对时返回 None
+    fn read_dim(&self) -> Option<usize> {
+        let mut header = [0u8; HEADER_LEN as usize];
+        File::open(self.file_path()).ok()?.read_exact(&mut header).ok()?;
+        let version = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+        let dim = u32::from_le_bytes([header[8], header[9], header[10], header[11]]) as usize;
+        (&header[..4] == MAGIC && version == VERSION && dim > 0).then_some(dim)
+    }
+
+    /// 清空集合并按新的维度重建文件
+    fn reset(&self, dim: usize) -> Result<(), String> {
+        self.delete_all_entries()?;
+        let path = self.file_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create vectors dir: {}", e))?;
+        }
+        let mut header = Vec::with_capacity(HEADER_LEN as usize);
+        header.extend_from_slice(MAGIC);
+        header.extend_from_slice(&VERSION.to_le_bytes());
+        header.extend_from_slice(&(dim as u32).to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes());
+        fs::write(&path, header).map_err(|e| format!("Failed to create vector file: {}", e))
+    }
+
+    fn delete_all_entries(&self) -> Result<(), String> {
+        db::with_connection(&self.app_data_dir, |conn| {
+            conn.prepare_cached("DELETE FROM vector_entries WHERE collection = ?1")
+                .and_then(|mut stmt| stmt.execute(params![self.collection]))
+                .map_err(|e| format!("Failed to clear vector entries: {}", e))
+        })?;
+        Ok(())
+    }
+
+    fn slots(&self) -> Result<Vec<(String, usize)>, String> {
+        db::with_connection(&self.app_data_dir, |conn| {
+            let mut stmt = conn
+                .prepare_cached("SELECT key, slot FROM vector_entries WHERE collection = ?1")
+                .map_err(|e| format!("Failed to prepare vector slots query: {}", e))?;
+            let rows = stmt
+                .query_map(params![self.collection], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as usize))
+                })
+                .map_err(|e| format!("Failed to query vector slots: {}", e))?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Failed to read vector slots: {}", e))?;
+            Ok(rows)
+        })
+    }
+
+    /// 集合中所有向量的元数据
+    pub fn entries(&self) -> Result<Vec<VectorEntry>, String> {
+        db::with_connection(&self.app_data_dir, |conn| {
+            let mut stmt = conn
+                .prepare_cached("SELECT key, model, content_hash FROM vector_entries WHERE collection = ?1")
+                .map_err(|e| format!("Failed to prepare vector entries query: {}", e))?;
+            let rows = stmt
+                .query_map(params![self.collection], |row| {
+                    Ok(VectorEntry {
+                        key: row.get(0)?,
+                        model: row.get(1)?,
+                        content_hash: row.get(2)?,
+                    })
+                })
+                .map_err(|e| format!("Failed to query vector entries: {}", e))?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Failed to read vector entries: {}", e))?;
+            Ok(rows)
+        })
+    }
+
+    /// 插入或更新向量；维度或模型与已有数据不同时先清空集合
+    pub fn upsert(&self, model: &str, items: &[(String, String, Vec<f32>)]) -> Result<(), String> {
+        let Some(dim) = items.first().map(|(_, _, v)| v.len()) else {
+            return Ok(());
+        };
+        if dim == 0 || items.iter().any(|(_, _, v)| v.len() != dim) {
+            return Err("向量维度不一致".to_string());
+        }
+
+        let _guard = lock();
+        let model_changed = self.entries()?.iter().any(|e| e.model != model);
+        if self.read_dim() != Some(dim) || model_changed {
+            self.reset(dim)?;
+        }
+
+        let path = self.file_path();
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&path)
+            .map_err(|e| format!("Failed to open vector file: {}", e))?;
+        let slot_len = (dim * 4) as u64;
+        let file_len = file.metadata().map(|m| m.len()).unwrap_or(HEADER_LEN);
+        let capacity = (file_len.saturating_sub(HEADER_LEN) / slot_len) as usize;
+
+        // 已有键复用原槽位，新键优先使用已删除条目留下的空槽位
+        let existing = self.slots()?;
+        let used: HashSet<usize> = existing.iter().map(|(_, slot)| *slot).collect();
+        let mut free = (0..capacity).filter(|slot| !used.contains(slot));
+        let mut next_slot = capacity;
+        let mut assigned = Vec::with_capacity(items.len());
+        for (key, hash, vector) in items {
+            let slot = match existing.iter().find(|(k, _)| k == key) {
+                Some((_, slot)) => *slot,
+                None => free.next().unwrap_or_else(|| {
+                    next_slot += 1;
+                    next_slot - 1
+                }),
+            };
+            file.seek(SeekFrom::Start(HEADER_LEN + slot as u64 * slot_len))
+                .and_then(|_| file.write_all(&encode(vector)))
+                .map_err(|e| format!("Failed to write vector: {}", e))?;
+            assigned.push((key, hash, slot));
+        }
+        file.flush().map_err(|e| format!("Failed to flush vector file: {}", e))?;
+
+        let now = now_ts();
+        db::with_connection(&self.app_data_dir, |conn| {
+            let tx = conn
+                .unchecked_transaction()
+                .map_err(|e| format!("Failed to begin vector entries update: {}", e))?;
+            {
+                let mut stmt = tx
+                    .prepare_cached(
+                        "INSERT INTO vector_entries (collection, key, slot, model, content_hash, updated_at)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                         ON CONFLICT(collection, key) DO UPDATE SET slot = excluded.slot, model = excluded.model,
+                            content_hash = excluded.content_hash, updated_at = excluded.updated_at",
+                    )
+                    .map_err(|e| format!("Failed to prepare vector entry insert: {}", e))?;
+                for (key, hash, slot) in &assigned {
+                    stmt.execute(params![self.collection, key, *slot as i64, model, hash, now])
+                        .map_err(|e| format!("Failed to save vector entry: {}", e))?;
+                }
+            }
+            tx.commit().map_err(|e| format!("Failed to commit vector entries: {}", e))
+        })
+    }
+
+    /// 删除指定键的向量（槽位留给之后插入的向量复用），返回删除的数量
+    pub fn delete(&self, keys: &[String]) -> Result<usize, String> {
+        if keys.is_empty() {
+            return Ok(0);
+        }
+        let _guard = lock();
+        db::with_connection(&self.app_data_dir, |conn| {
+            let mut stmt = conn
+                .prepare_cached("DELETE FROM vector_entries WHERE collection = ?1 AND key = ?2")
+                .map_err(|e| format!("Failed to prepare vector delete: {}", e))?;
+            let mut removed = 0;
+            for key in keys {
+                removed += stmt
+                    .execute(params![self.collection, key])
+                    .map_err(|e| format!("Failed to delete vector: {}", e))?;
+            }
+            Ok(removed)
+        })
+    }
+
+    /// 清空集合
+    pub fn clear(&self) -> Result<(), String> {
+        let _guard = lock();
+        self.delete_all_entries()?;
+        match fs::remove_file(self.file_path()) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(format!("Failed to remove vector file: {}", e)),
+        }
+    }
+
+    /// 按余弦相似度查询最相近的向量，返回 (键, 相似度)，只保留不低于 min_score 的结果
+    pub fn query(&self, vector: &[f32], limit: usize, min_score: f32) -> Result<Vec<(String, f32)>, String> {
+        let _guard = lock();
+        let Some(dim) = self.read_dim().filter(|dim| *dim == vector.len()) else {
+            return Ok(Vec::new());
+        };
+        let slots = self.slots()?;
+        if slots.is_empty() {
+            return Ok(Vec::new());
+        }
+        let file = File::open(self.file_path()).map_err(|e| format!("Failed to open vector file: {}", e))?;
+        // SAFETY: 向量文件只在持有 STORE_LOCK 时写入，映射期间不会被修改或截断
+        let mmap = unsafe { Mmap::map(&file) }.map_err(|e| format!("Failed to map vector file: {}", e))?;
+
+        let slot_len = dim * 4;
+        let mut results: Vec<(String, f32)> = slots
+            .into_iter()
+            .filter_map(|(key, slot)| {
+                let start = HEADER_LEN as usize + slot * slot_len;
+                let bytes = mmap.get(start..start + slot_len)?;
+                let score = cosine(vector, &decode(bytes));
+                (score >= min_score).then_some((key, score))
+            })
+            .collect();
+        results.sort_by(|a, b| b.1.total_cmp(&a.1));
+        results.truncate(limit);
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_vectors_and_scores_similarity() {
+        let vector = vec![0.5f32, -1.25, 3.0];
+        assert_eq!(decode(&encode(&vector)), vector);
+        assert!((cosine(&[1.0, 0.0], &[2.0, 0.0]) - 1.0).abs() < 1e-6);
+        assert!(cosine(&[1.0, 0.0], &[0.0, 1.0]).abs() < 1e-6);
+        assert_eq!(cosine(&[1.0], &[1.0, 2.0]), 0.0);
+    }
+
+    #[test]
+    fn validates_collection_names() {
+        let dir = Path::new(".");
+        assert!(VectorStore::open(dir, "clipboard").is_ok());
+        assert!(VectorStore::open(dir, "../memos").is_err());
+        assert!(VectorStore::open(dir, "").is_err());
+    }
+}
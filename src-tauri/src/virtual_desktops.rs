@@ -0,0 +1,321 @@
+// 虚拟桌面：列出桌面、切换桌面、把前台窗口移到指定桌面，并为启动器按桌面记住位置
+// 桌面列表与当前桌面读取自 Explorer 的注册表项；系统没有公开切换桌面的接口，切换通过模拟 Ctrl+Win+←/→ 完成
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VirtualDesktop {
+    pub id: String, // 形如 {XXXXXXXX-XXXX-...} 的 GUID
+    pub index: usize,
+    pub name: String,
+    pub current: bool,
+}
+
+/// 把注册表中按 GUID 内存布局保存的 16 字节转换为 {XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX}
+pub fn format_guid(bytes: &[u8]) -> Option<String> {
+    let bytes: &[u8; 16] = bytes.try_into().ok()?;
+    let data1 = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let data2 = u16::from_le_bytes([bytes[4], bytes[5]]);
+    let data3 = u16::from_le_bytes([bytes[6], bytes[7]]);
+    let tail: String = bytes[10..].iter().map(|b| format!("{:02X}", b)).collect();
+    Some(format!(
+        "{{{:08X}-{:04X}-{:04X}-{:02X}{:02X}-{}}}",
+        data1, data2, data3, bytes[8], bytes[9], tail
+    ))
+}
+
+/// VirtualDesktopIDs 是按顺序拼接的桌面 GUID
+pub fn parse_desktop_ids(data: &[u8]) -> Vec<String> {
+    data.chunks_exact(16).filter_map(format_guid).collect()
+}
+
+/// 未重命名的桌面使用与系统一致的默认名称
+fn default_name(index: usize) -> String {
+    format!("桌面 {}", index + 1)
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::{format_guid, parse_desktop_ids};
+    use ::windows::core::GUID;
+    use ::windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_ALL, COINIT_MULTITHREADED};
+    use ::windows::Win32::UI::Shell::{IVirtualDesktopManager, VirtualDesktopManager};
+    use std::ffi::OsStr;
+    use std::os::windows::ffi::OsStrExt;
+    use std::time::Duration;
+    use windows_sys::Win32::Foundation::HWND;
+    use windows_sys::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_BINARY, RRF_RT_REG_SZ};
+    use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
+        SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP, VK_CONTROL, VK_LEFT, VK_LWIN, VK_RIGHT,
+    };
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        GetForegroundWindow, GetShellWindow, SetForegroundWindow, ShowWindow, SW_HIDE, SW_SHOW,
+    };
+
+    const DESKTOPS_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Explorer\\VirtualDesktops";
+    /// 每次切换之间的间隔，过快时系统会丢弃部分按键
+    const SWITCH_STEP_DELAY: Duration = Duration::from_millis(80);
+    /// 等待切换动画结束
+    const SWITCH_SETTLE_DELAY: Duration = Duration::from_millis(300);
+
+    fn to_wide(s: &str) -> Vec<u16> {
+        OsStr::new(s).encode_wide().chain(Some(0)).collect()
+    }
+
+    fn read_value(sub_key: &str, value_name: &str, flags: u32) -> Option<Vec<u8>> {
+        let sub_key = to_wide(sub_key);
+        let value_name = to_wide(value_name);
+        let mut size = 0u32;
+        let result = unsafe {
+            RegGetValueW(HKEY_CURRENT_USER, sub_key.as_ptr(), value_name.as_ptr(), flags, std::ptr::null_mut(), std::ptr::null_mut(), &mut size)
+        };
+        if result != 0 || size == 0 {
+            return None;
+        }
+        let mut data = vec![0u8; size as usize];
+        let result = unsafe {
+            RegGetValueW(
+                HKEY_CURRENT_USER,
+                sub_key.as_ptr(),
+                value_name.as_ptr(),
+                flags,
+                std::ptr::null_mut(),
+                data.as_mut_ptr() as *mut _,
+                &mut size,
+            )
+        };
+        if result != 0 {
+            return None;
+        }
+        data.truncate(size as usize);
+        Some(data)
+    }
+
+    /// 在当前线程初始化 COM，离开作用域时释放
+    struct ComGuard(bool);
+
+    impl ComGuard {
+        fn new() -> Self {
+            ComGuard(unsafe { CoInitializeEx(None, COINIT_MULTITHREADED) }.is_ok())
+        }
+    }
+
+    impl Drop for ComGuard {
+        fn drop(&mut self) {
+            if self.0 {
+                unsafe { CoUninitialize() };
+            }
+        }
+    }
+
+    fn manager() -> Result<IVirtualDesktopManager, String> {
+        unsafe { CoCreateInstance(&VirtualDesktopManager, None, CLSCTX_ALL) }
+            .map_err(|e| format!("Failed to create virtual desktop manager: {}", e))
+    }
+
+    fn win_hwnd(hwnd: HWND) -> ::windows::Win32::Foundation::HWND {
+        ::windows::Win32::Foundation::HWND(hwnd as *mut std::ffi::c_void)
+    }
+
+    fn guid_string(guid: GUID) -> String {
+        format!("{{{:?}}}", guid).to_uppercase()
+    }
+
+    fn parse_guid(id: &str) -> Option<GUID> {
+        let hex: String = id.chars().filter(|c| c.is_ascii_hexdigit()).collect();
+        (hex.len() == 32).then(|| u128::from_str_radix(&hex, 16).ok().map(GUID::from_u128)).flatten()
+    }
+
+    pub fn desktop_ids() -> Vec<String> {
+        read_value(DESKTOPS_KEY, "VirtualDesktopIDs", RRF_RT_REG_BINARY)
+            .map(|data| parse_desktop_ids(&data))
+            .unwrap_or_default()
+    }
+
+    pub fn desktop_name(id: &str) -> Option<String> {
+        let data = read_value(&format!("{}\\Desktops\\{}", DESKTOPS_KEY, id), "Name", RRF_RT_REG_SZ)?;
+        let wide: Vec<u16> = data.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+        let name = String::from_utf16_lossy(&wide).trim_end_matches('\0').trim().to_string();
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// 当前桌面：Windows 11 记录在注册表中；Windows 10 通过前台窗口所在的桌面获取
+    pub fn current_desktop_id() -> Option<String> {
+        if let Some(id) = read_value(DESKTOPS_KEY, "CurrentVirtualDesktop", RRF_RT_REG_BINARY).and_then(|d| format_guid(&d)) {
+            return Some(id);
+        }
+        let _com = ComGuard::new();
+        let hwnd = unsafe { GetForegroundWindow() };
+        if hwnd == 0 {
+            return None;
+        }
+        let guid = unsafe { manager().ok()?.GetWindowDesktopId(win_hwnd(hwnd)) }.ok()?;
+        (guid != GUID::zeroed()).then(|| guid_string(guid))
+    }
+
+    fn key_input(vk: u16, up: bool) -> INPUT {
+        INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT { wVk: vk, wScan: 0, dwFlags: if up { KEYEVENTF_KEYUP } else { 0 }, time: 0, dwExtraInfo: 0 },
+            },
+        }
+    }
+
+    /// 模拟 Ctrl+Win+←/→，每次切换一个桌面
+    pub fn switch_by(delta: isize) -> Result<(), String> {
+        let arrow = if delta > 0 { VK_RIGHT } else { VK_LEFT };
+        for _ in 0..delta.unsigned_abs() {
+            let inputs = [
+                key_input(VK_CONTROL, false),
+                key_input(VK_LWIN, false),
+                key_input(arrow, false),
+                key_input(arrow, true),
+                key_input(VK_LWIN, true),
+                key_input(VK_CONTROL, true),
+            ];
+            let sent = unsafe { SendInput(inputs.len() as u32, inputs.as_ptr(), std::mem::size_of::<INPUT>() as i32) };
+            if sent != inputs.len() as u32 {
+                return Err("切换虚拟桌面失败".to_string());
+            }
+            std::thread::sleep(SWITCH_STEP_DELAY);
+        }
+        std::thread::sleep(SWITCH_SETTLE_DELAY);
+        Ok(())
+    }
+
+    /// 移动前台窗口；系统只允许直接移动本进程的窗口，其他窗口通过"隐藏 → 切换桌面 → 重新显示"移动
+    pub fn move_foreground_window(id: &str, delta: isize) -> Result<(), String> {
+        let hwnd = unsafe { GetForegroundWindow() };
+        if hwnd == 0 || hwnd == unsafe { GetShellWindow() } {
+            return Err("当前没有可移动的窗口".to_string());
+        }
+        let guid = parse_guid(id).ok_or_else(|| format!("无效的桌面 id: {}", id))?;
+        let moved = {
+            let _com = ComGuard::new();
+            manager().and_then(|m| {
+                unsafe { m.MoveWindowToDesktop(win_hwnd(hwnd), &guid) }.map_err(|e| e.to_string())
+            })
+        };
+        if moved.is_ok() {
+            switch_by(delta)?;
+        } else {
+            // 重新显示的窗口会出现在当前桌面上
+            unsafe { ShowWindow(hwnd, SW_HIDE) };
+            let result = switch_by(delta);
+            unsafe { ShowWindow(hwnd, SW_SHOW) };
+            result?;
+        }
+        unsafe { SetForegroundWindow(hwnd) };
+        Ok(())
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    const UNSUPPORTED: &str = "当前平台不支持虚拟桌面";
+
+    pub fn desktop_ids() -> Vec<String> {
+        Vec::new()
+    }
+
+    pub fn desktop_name(_id: &str) -> Option<String> {
+        None
+    }
+
+    pub fn current_desktop_id() -> Option<String> {
+        None
+    }
+
+    pub fn switch_by(_delta: isize) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+
+    pub fn move_foreground_window(_id: &str, _delta: isize) -> Result<(), String> {
+        Err(UNSUPPORTED.to_string())
+    }
+}
+
+pub use platform::current_desktop_id;
+
+/// 按顺序列出虚拟桌面；从未创建过额外桌面时注册表中没有列表，视为只有一个桌面
+pub fn list() -> Vec<VirtualDesktop> {
+    let current = current_desktop_id();
+    let ids = platform::desktop_ids();
+    if ids.is_empty() {
+        return vec![VirtualDesktop {
+            id: current.unwrap_or_default(),
+            index: 0,
+            name: default_name(0),
+            current: true,
+        }];
+    }
+    ids.into_iter()
+        .enumerate()
+        .map(|(index, id)| VirtualDesktop {
+            name: platform::desktop_name(&id).unwrap_or_else(|| default_name(index)),
+            current: current.as_deref() == Some(id.as_str()),
+            index,
+            id,
+        })
+        .collect()
+}
+
+/// 目标桌面与当前桌面的距离（向右为正）
+fn distance_to(desktops: &[VirtualDesktop], index: usize) -> Result<isize, String> {
+    if index >= desktops.len() {
+        return Err(format!("桌面 {} 不存在", index + 1));
+    }
+    let current = desktops.iter().position(|d| d.current).ok_or_else(|| "无法确定当前桌面".to_string())?;
+    Ok(index as isize - current as isize)
+}
+
+/// 切换到第 index 个桌面（从 0 开始）
+pub fn switch_to(index: usize) -> Result<(), String> {
+    let desktops = list();
+    let delta = distance_to(&desktops, index)?;
+    if delta == 0 {
+        return Ok(());
+    }
+    platform::switch_by(delta)
+}
+
+/// 把前台窗口移到第 index 个桌面并切换过去
+pub fn move_foreground_window(index: usize) -> Result<(), String> {
+    let desktops = list();
+    let delta = distance_to(&desktops, index)?;
+    if delta == 0 {
+        return Ok(());
+    }
+    platform::move_foreground_window(&desktops[index].id, delta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_desktop_guids() {
+        let guid = [
+            0x78, 0x56, 0x34, 0x12, 0xBC, 0x9A, 0xF0, 0xDE, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+        ];
+        assert_eq!(format_guid(&guid).unwrap(), "{12345678-9ABC-DEF0-0123-456789ABCDEF}");
+        let mut data = guid.to_vec();
+        data.extend_from_slice(&[0u8; 16]);
+        data.push(0xFF); // 不完整的尾部被忽略
+        let ids = parse_desktop_ids(&data);
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[1], "{00000000-0000-0000-0000-000000000000}");
+        assert!(format_guid(&guid[..8]).is_none());
+    }
+
+    #[test]
+    fn computes_switch_distance() {
+        let desktops: Vec<VirtualDesktop> = (0..3)
+            .map(|index| VirtualDesktop { id: index.to_string(), index, name: default_name(index), current: index == 1 })
+            .collect();
+        assert_eq!(distance_to(&desktops, 0), Ok(-1));
+        assert_eq!(distance_to(&desktops, 2), Ok(1));
+        assert!(distance_to(&desktops, 3).is_err());
+    }
+}
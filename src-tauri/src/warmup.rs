@@ -0,0 +1,101 @@
+// 启动编排：窗口先显示，各数据提供者（应用缓存、历史记录、数据库迁移等）在后台并行初始化
+// 每个提供者的状态可通过 get_startup_status 查询，状态变化时发送 startup-progress 事件
+
+use crate::shutdown;
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, Mutex, OnceLock};
+use std::time::Instant;
+use tauri::{AppHandle, Emitter};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderStatus {
+    pub name: String,
+    pub state: String, // "loading" | "ready" | "failed"
+    pub error: Option<String>,
+    pub duration_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartupStatus {
+    pub ready: bool, // 所有提供者都已结束（成功或失败）
+    pub elapsed_ms: u64,
+    pub providers: Vec<ProviderStatus>,
+}
+
+static STARTED_AT: LazyLock<Instant> = LazyLock::new(Instant::now);
+static PROVIDERS: LazyLock<Mutex<Vec<ProviderStatus>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+/// 记录启动时间并保存 AppHandle（用于发送进度事件）
+pub fn begin(app_handle: AppHandle) {
+    LazyLock::force(&STARTED_AT);
+    let _ = APP_HANDLE.set(app_handle);
+}
+
+/// 获取当前启动状态
+pub fn get_status() -> StartupStatus {
+    let providers = PROVIDERS.lock().map(|p| p.clone()).unwrap_or_default();
+    StartupStatus {
+        ready: providers.iter().all(|p| p.state != "loading"),
+        elapsed_ms: STARTED_AT.elapsed().as_millis() as u64,
+        providers,
+    }
+}
+
+fn set_status(status: ProviderStatus) {
+    if let Ok(mut providers) = PROVIDERS.lock() {
+        match providers.iter_mut().find(|p| p.name == status.name) {
+            Some(existing) => *existing = status,
+            None => providers.push(status),
+        }
+    }
+    if let Some(app_handle) = APP_HANDLE.get() {
+        let _ = app_handle.emit("startup-progress", get_status());
+    }
+}
+
+/// 在后台线程中初始化一个提供者，多个提供者并行执行
+pub fn provide(name: &str, init: impl FnOnce() -> Result<(), String> + Send + 'static) {
+    set_status(ProviderStatus {
+        name: name.to_string(),
+        state: "loading".to_string(),
+        error: None,
+        duration_ms: None,
+    });
+
+    let provider_name = name.to_string();
+    let spawned = shutdown::spawn(&format!("warmup-{}", name), move || {
+        let started = Instant::now();
+        let result = init();
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        match result {
+            Ok(()) => {
+                eprintln!("[Warmup] {} ready in {}ms", provider_name, elapsed_ms);
+                set_status(ProviderStatus {
+                    name: provider_name,
+                    state: "ready".to_string(),
+                    error: None,
+                    duration_ms: Some(elapsed_ms),
+                });
+            }
+            Err(e) => {
+                eprintln!("[Warmup] {} failed: {}", provider_name, e);
+                set_status(ProviderStatus {
+                    name: provider_name,
+                    state: "failed".to_string(),
+                    error: Some(e),
+                    duration_ms: Some(elapsed_ms),
+                });
+            }
+        }
+    });
+
+    if let Err(e) = spawned {
+        set_status(ProviderStatus {
+            name: name.to_string(),
+            state: "failed".to_string(),
+            error: Some(e),
+            duration_ms: None,
+        });
+    }
+}
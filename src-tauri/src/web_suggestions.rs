@@ -0,0 +1,140 @@
+// 搜索引擎前缀的联想词：输入 "s rust" 时向 Google / 百度 / 必应的联想接口请求候选词
+// 后端负责防抖（只处理最后一次请求）和短期缓存，避免每次按键都发起网络请求
+
+use crate::settings::SearchEngineConfig;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+const DEBOUNCE: Duration = Duration::from_millis(150);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(3);
+const CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+const CACHE_MAX_ENTRIES: usize = 200;
+const MAX_SUGGESTIONS: usize = 8;
+
+// 每次请求递增，防抖结束后编号已变化说明有更新的请求，直接放弃
+static REQUEST_SEQ: AtomicU64 = AtomicU64::new(0);
+// (来源, 关键词) -> (缓存时间, 联想词)
+static SUGGESTION_CACHE: LazyLock<Mutex<HashMap<(String, String), (Instant, Vec<String>)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// 确定搜索引擎使用的联想来源：优先使用配置的 suggest_source，否则根据 URL 自动识别
+/// 返回 None 表示不提供联想
+pub fn resolve_source(engine: &SearchEngineConfig) -> Option<&'static str> {
+    let source = match engine.suggest_source.as_deref() {
+        Some(source) => source.to_lowercase(),
+        None => {
+            let url = engine.url.to_lowercase();
+            if url.contains("google.") {
+                "google".to_string()
+            } else if url.contains("baidu.com") {
+                "baidu".to_string()
+            } else if url.contains("bing.com") {
+                "bing".to_string()
+            } else {
+                return None;
+            }
+        }
+    };
+    match source.as_str() {
+        "google" => Some("google"),
+        "baidu" => Some("baidu"),
+        "bing" => Some("bing"),
+        _ => None,
+    }
+}
+
+fn endpoint(source: &str, keyword: &str) -> Result<reqwest::Url, String> {
+    let (base, params): (&str, Vec<(&str, &str)>) = match source {
+        "google" => (
+            "https://suggestqueries.google.com/complete/search",
+            vec![("client", "firefox"), ("q", keyword)],
+        ),
+        "baidu" => (
+            "https://suggestion.baidu.com/su",
+            vec![("action", "opensearch"), ("ie", "utf-8"), ("wd", keyword)],
+        ),
+        "bing" => ("https://api.bing.com/osjson.aspx", vec![("query", keyword)]),
+        _ => return Err(format!("Unsupported suggestion source: {}", source)),
+    };
+    reqwest::Url::parse_with_params(base, &params)
+        .map_err(|e| format!("Failed to build suggestion url: {}", e))
+}
+
+/// 解析 OpenSearch 联想格式：["关键词", ["联想1", "联想2", ...], ...]
+pub fn parse_opensearch(body: &str) -> Vec<String> {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|value| value.get(1).and_then(|v| v.as_array()).cloned())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|v| v.as_str().map(|s| s.trim().to_string()))
+        .filter(|s| !s.is_empty())
+        .take(MAX_SUGGESTIONS)
+        .collect()
+}
+
+fn get_cached(key: &(String, String)) -> Option<Vec<String>> {
+    let cache = SUGGESTION_CACHE.lock().ok()?;
+    cache
+        .get(key)
+        .filter(|(cached_at, _)| cached_at.elapsed() < CACHE_TTL)
+        .map(|(_, suggestions)| suggestions.clone())
+}
+
+fn put_cached(key: (String, String), suggestions: Vec<String>) {
+    if let Ok(mut cache) = SUGGESTION_CACHE.lock() {
+        cache.retain(|_, (cached_at, _)| cached_at.elapsed() < CACHE_TTL);
+        if cache.len() >= CACHE_MAX_ENTRIES {
+            if let Some(oldest) = cache
+                .iter()
+                .min_by_key(|(_, (cached_at, _))| *cached_at)
+                .map(|(k, _)| k.clone())
+            {
+                cache.remove(&oldest);
+            }
+        }
+        cache.insert(key, (Instant::now(), suggestions));
+    }
+}
+
+/// 获取联想词；被更新的请求取代时返回空列表
+pub async fn fetch_suggestions(engine: &SearchEngineConfig, keyword: &str) -> Result<Vec<String>, String> {
+    let keyword = keyword.trim();
+    let source = match resolve_source(engine) {
+        Some(source) if !keyword.is_empty() => source,
+        _ => return Ok(Vec::new()),
+    };
+
+    let key = (source.to_string(), keyword.to_lowercase());
+    if let Some(cached) = get_cached(&key) {
+        return Ok(cached);
+    }
+    // 离线时不发请求，联想词只是辅助功能，直接返回空列表
+    if !crate::network::is_online() {
+        return Ok(Vec::new());
+    }
+
+    let seq = REQUEST_SEQ.fetch_add(1, Ordering::SeqCst) + 1;
+    tokio::time::sleep(DEBOUNCE).await;
+    if REQUEST_SEQ.load(Ordering::SeqCst) != seq {
+        return Ok(Vec::new());
+    }
+
+    let client = crate::http_client::builder()?
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("创建HTTP客户端失败: {}", e))?;
+    let url = endpoint(source, keyword)?;
+    let body = crate::api_policy::send(source, &crate::api_policy::Policy::SUGGESTIONS, || client.get(url.clone()))
+        .await
+        .map_err(|e| format!("联想词请求失败: {}", e))?
+        .text()
+        .await
+        .map_err(|e| format!("读取联想词失败: {}", e))?;
+
+    let suggestions = parse_opensearch(&body);
+    put_cached(key, suggestions.clone());
+    Ok(suggestions)
+}
@@ -1,4 +1,5 @@
 use crate::db;
+use crate::virtual_desktops;
 use rusqlite::{params, OptionalExtension};
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -80,15 +81,51 @@ pub fn save_launcher_position(
 ) -> Result<(), String> {
     let mut configs = load_window_config(app_data_dir).unwrap_or_default();
     configs.launcher.position = Some(WindowPosition { x, y });
-    save_window_config(app_data_dir, &configs)
+    save_window_config(app_data_dir, &configs)?;
+
+    // 同时记住在当前虚拟桌面上的位置
+    if let Some(desktop_id) = virtual_desktops::current_desktop_id() {
+        let conn = db::get_connection(app_data_dir)?;
+        conn.execute(
+            "INSERT INTO window_config (key, x, y) VALUES (?1, ?2, ?3)
+             ON CONFLICT(key) DO UPDATE SET x = excluded.x, y = excluded.y",
+            params![desktop_key(&desktop_id), x, y],
+        )
+        .map_err(|e| format!("Failed to save window config: {}", e))?;
+    }
+
+    Ok(())
 }
 
+/// 优先使用在当前虚拟桌面上记住的位置，没有时使用最后一次保存的位置
 pub fn get_launcher_position(app_data_dir: &Path) -> Option<WindowPosition> {
+    if let Some(desktop_id) = virtual_desktops::current_desktop_id() {
+        if let Some(position) = load_desktop_position(app_data_dir, &desktop_id) {
+            return Some(position);
+        }
+    }
     load_window_config(app_data_dir)
         .ok()
         .and_then(|configs| configs.launcher.position)
 }
 
+/// 按虚拟桌面保存的启动器位置使用 "launcher@{桌面 GUID}" 作为键
+fn desktop_key(desktop_id: &str) -> String {
+    format!("launcher@{}", desktop_id)
+}
+
+fn load_desktop_position(app_data_dir: &Path, desktop_id: &str) -> Option<WindowPosition> {
+    let conn = db::get_connection(app_data_dir).ok()?;
+    conn.query_row(
+        "SELECT x, y FROM window_config WHERE key = ?1 LIMIT 1",
+        params![desktop_key(desktop_id)],
+        |row| Ok(WindowPosition { x: row.get(0)?, y: row.get(1)? }),
+    )
+    .optional()
+    .ok()
+    .flatten()
+}
+
 fn maybe_migrate_from_json(
     conn: &rusqlite::Connection,
     app_data_dir: &Path,
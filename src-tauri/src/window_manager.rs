@@ -0,0 +1,385 @@
+// 窗口管理：对前台窗口执行半屏/四分之一屏贴靠、移动到下一个显示器、最大化切换、置顶切换、居中与最小化全部
+// 可在启动器中输入 "win left" 等执行，也可在快捷键设置中为每个动作绑定全局快捷键（以 "window:" 前缀注册）
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowAction {
+    LeftHalf,
+    RightHalf,
+    TopHalf,
+    BottomHalf,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    NextMonitor,
+    ToggleMaximize,
+    ToggleTopmost,
+    Center,
+    MinimizeAll,
+}
+
+impl WindowAction {
+    pub const ALL: [WindowAction; 13] = [
+        WindowAction::LeftHalf,
+        WindowAction::RightHalf,
+        WindowAction::TopHalf,
+        WindowAction::BottomHalf,
+        WindowAction::TopLeft,
+        WindowAction::TopRight,
+        WindowAction::BottomLeft,
+        WindowAction::BottomRight,
+        WindowAction::NextMonitor,
+        WindowAction::ToggleMaximize,
+        WindowAction::ToggleTopmost,
+        WindowAction::Center,
+        WindowAction::MinimizeAll,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            WindowAction::LeftHalf => "left_half",
+            WindowAction::RightHalf => "right_half",
+            WindowAction::TopHalf => "top_half",
+            WindowAction::BottomHalf => "bottom_half",
+            WindowAction::TopLeft => "top_left",
+            WindowAction::TopRight => "top_right",
+            WindowAction::BottomLeft => "bottom_left",
+            WindowAction::BottomRight => "bottom_right",
+            WindowAction::NextMonitor => "next_monitor",
+            WindowAction::ToggleMaximize => "toggle_maximize",
+            WindowAction::ToggleTopmost => "toggle_topmost",
+            WindowAction::Center => "center",
+            WindowAction::MinimizeAll => "minimize_all",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<WindowAction> {
+        Self::ALL.into_iter().find(|a| a.id() == id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl Rect {
+    pub fn width(&self) -> i32 {
+        self.right - self.left
+    }
+
+    pub fn height(&self) -> i32 {
+        self.bottom - self.top
+    }
+}
+
+/// 贴靠动作在工作区（不含任务栏）中的目标位置；非贴靠动作返回 None
+pub fn snap_rect(work: Rect, action: WindowAction) -> Option<Rect> {
+    let mid_x = work.left + work.width() / 2;
+    let mid_y = work.top + work.height() / 2;
+    let (left, top, right, bottom) = match action {
+        WindowAction::LeftHalf => (work.left, work.top, mid_x, work.bottom),
+        WindowAction::RightHalf => (mid_x, work.top, work.right, work.bottom),
+        WindowAction::TopHalf => (work.left, work.top, work.right, mid_y),
+        WindowAction::BottomHalf => (work.left, mid_y, work.right, work.bottom),
+        WindowAction::TopLeft => (work.left, work.top, mid_x, mid_y),
+        WindowAction::TopRight => (mid_x, work.top, work.right, mid_y),
+        WindowAction::BottomLeft => (work.left, mid_y, mid_x, work.bottom),
+        WindowAction::BottomRight => (mid_x, mid_y, work.right, work.bottom),
+        _ => return None,
+    };
+    Some(Rect { left, top, right, bottom })
+}
+
+/// 保持窗口大小（超出工作区时缩小）居中放置
+pub fn center_rect(work: Rect, window: Rect) -> Rect {
+    let width = window.width().min(work.width());
+    let height = window.height().min(work.height());
+    let left = work.left + (work.width() - width) / 2;
+    let top = work.top + (work.height() - height) / 2;
+    Rect { left, top, right: left + width, bottom: top + height }
+}
+
+/// 移动到另一个工作区：按比例保持相对位置，窗口大小超出目标工作区时缩小
+pub fn move_to_work_area(window: Rect, from: Rect, to: Rect) -> Rect {
+    let width = window.width().min(to.width());
+    let height = window.height().min(to.height());
+    let scale = |offset: i32, from_len: i32, to_len: i32| {
+        if from_len <= 0 {
+            0
+        } else {
+            (offset as i64 * to_len as i64 / from_len as i64) as i32
+        }
+    };
+    let left = (to.left + scale(window.left - from.left, from.width(), to.width())).clamp(to.left, to.right - width);
+    let top = (to.top + scale(window.top - from.top, from.height(), to.height())).clamp(to.top, to.bottom - height);
+    Rect { left, top, right: left + width, bottom: top + height }
+}
+
+/// 按从左到右、从上到下的顺序取下一个显示器
+pub fn next_work_area(areas: &[Rect], current: Rect) -> Option<Rect> {
+    if areas.len() < 2 {
+        return None;
+    }
+    let mut sorted = areas.to_vec();
+    sorted.sort_by_key(|r| (r.left, r.top));
+    let index = sorted.iter().position(|r| *r == current)?;
+    Some(sorted[(index + 1) % sorted.len()])
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::{center_rect, move_to_work_area, next_work_area, snap_rect, Rect, WindowAction};
+    use std::ffi::OsStr;
+    use std::collections::HashSet;
+    use std::os::windows::ffi::OsStrExt;
+    use std::sync::{LazyLock, Mutex};
+    use windows_sys::Win32::Foundation::{BOOL, HWND, LPARAM, RECT};
+    use windows_sys::Win32::Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_EXTENDED_FRAME_BOUNDS};
+    use windows_sys::Win32::Graphics::Gdi::{
+        EnumDisplayMonitors, GetMonitorInfoW, MonitorFromWindow, HDC, HMONITOR, MONITORINFO, MONITOR_DEFAULTTONEAREST,
+    };
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        FindWindowW, GetClassNameW, GetForegroundWindow, GetShellWindow, GetWindowLongPtrW, GetWindowRect, IsIconic,
+        IsWindow, IsZoomed, PostMessageW, SetWindowPos, ShowWindow, GWL_EXSTYLE, HWND_NOTOPMOST, HWND_TOPMOST,
+        SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER, SW_MAXIMIZE, SW_RESTORE, WM_COMMAND, WS_EX_TOPMOST,
+    };
+
+    // 任务栏"显示桌面"菜单里的"最小化所有窗口"命令
+    const MINIMIZE_ALL_COMMAND: usize = 419;
+    // 桌面和任务栏不参与窗口管理
+    const IGNORED_CLASSES: &[&str] = &["Progman", "WorkerW", "Shell_TrayWnd", "Shell_SecondaryTrayWnd"];
+
+    // 由本应用置顶的窗口，退出时统一取消置顶
+    static PINNED: LazyLock<Mutex<HashSet<isize>>> = LazyLock::new(|| Mutex::new(HashSet::new()));
+
+    fn to_wide(s: &str) -> Vec<u16> {
+        OsStr::new(s).encode_wide().chain(Some(0)).collect()
+    }
+
+    fn from_rect(rect: RECT) -> Rect {
+        Rect { left: rect.left, top: rect.top, right: rect.right, bottom: rect.bottom }
+    }
+
+    fn class_name(hwnd: HWND) -> String {
+        let mut buf = [0u16; 256];
+        let len = unsafe { GetClassNameW(hwnd, buf.as_mut_ptr(), buf.len() as i32) };
+        String::from_utf16_lossy(&buf[..len.max(0) as usize])
+    }
+
+    fn foreground_window() -> Result<HWND, String> {
+        let hwnd = unsafe { GetForegroundWindow() };
+        if hwnd == 0 || hwnd == unsafe { GetShellWindow() } || IGNORED_CLASSES.contains(&class_name(hwnd).as_str()) {
+            return Err("当前没有可操作的窗口".to_string());
+        }
+        Ok(hwnd)
+    }
+
+    fn window_rect(hwnd: HWND) -> Option<Rect> {
+        let mut rect: RECT = unsafe { std::mem::zeroed() };
+        (unsafe { GetWindowRect(hwnd, &mut rect) } != 0).then(|| from_rect(rect))
+    }
+
+    /// 可见边框（不含 Windows 10/11 的透明阴影边框）
+    fn visible_rect(hwnd: HWND) -> Option<Rect> {
+        let mut rect: RECT = unsafe { std::mem::zeroed() };
+        let result = unsafe {
+            DwmGetWindowAttribute(
+                hwnd,
+                DWMWA_EXTENDED_FRAME_BOUNDS as u32,
+                &mut rect as *mut RECT as *mut _,
+                std::mem::size_of::<RECT>() as u32,
+            )
+        };
+        if result == 0 {
+            Some(from_rect(rect))
+        } else {
+            window_rect(hwnd)
+        }
+    }
+
+    fn work_area(monitor: HMONITOR) -> Option<Rect> {
+        let mut info: MONITORINFO = unsafe { std::mem::zeroed() };
+        info.cbSize = std::mem::size_of::<MONITORINFO>() as u32;
+        (unsafe { GetMonitorInfoW(monitor, &mut info) } != 0).then(|| from_rect(info.rcWork))
+    }
+
+    unsafe extern "system" fn collect_work_area(monitor: HMONITOR, _: HDC, _: *mut RECT, data: LPARAM) -> BOOL {
+        let areas = &mut *(data as *mut Vec<Rect>);
+        if let Some(area) = work_area(monitor) {
+            areas.push(area);
+        }
+        1
+    }
+
+    fn all_work_areas() -> Vec<Rect> {
+        let mut areas: Vec<Rect> = Vec::new();
+        unsafe {
+            EnumDisplayMonitors(0, std::ptr::null(), Some(collect_work_area), &mut areas as *mut _ as LPARAM);
+        }
+        areas
+    }
+
+    /// 把窗口的可见边框放到 target；先补偿透明阴影边框，避免贴靠后出现缝隙
+    fn place(hwnd: HWND, target: Rect) -> Result<(), String> {
+        if unsafe { IsZoomed(hwnd) } != 0 || unsafe { IsIconic(hwnd) } != 0 {
+            unsafe { ShowWindow(hwnd, SW_RESTORE) };
+        }
+        let (outer, visible) = match (window_rect(hwnd), visible_rect(hwnd)) {
+            (Some(outer), Some(visible)) => (outer, visible),
+            _ => return Err("无法获取窗口位置".to_string()),
+        };
+        let left = target.left - (visible.left - outer.left);
+        let top = target.top - (visible.top - outer.top);
+        let right = target.right + (outer.right - visible.right);
+        let bottom = target.bottom + (outer.bottom - visible.bottom);
+        let ok = unsafe { SetWindowPos(hwnd, 0, left, top, right - left, bottom - top, SWP_NOZORDER | SWP_NOACTIVATE) };
+        if ok == 0 {
+            return Err("移动窗口失败（目标窗口可能以管理员身份运行）".to_string());
+        }
+        Ok(())
+    }
+
+    fn minimize_all() -> Result<(), String> {
+        let class = to_wide("Shell_TrayWnd");
+        let tray = unsafe { FindWindowW(class.as_ptr(), std::ptr::null()) };
+        if tray == 0 || unsafe { PostMessageW(tray, WM_COMMAND, MINIMIZE_ALL_COMMAND, 0) } == 0 {
+            return Err("最小化所有窗口失败".to_string());
+        }
+        Ok(())
+    }
+
+    fn is_topmost(hwnd: HWND) -> bool {
+        (unsafe { GetWindowLongPtrW(hwnd, GWL_EXSTYLE) } as u32 & WS_EX_TOPMOST) != 0
+    }
+
+    fn set_topmost(hwnd: HWND, topmost: bool) -> bool {
+        let insert_after = if topmost { HWND_TOPMOST } else { HWND_NOTOPMOST };
+        unsafe { SetWindowPos(hwnd, insert_after, 0, 0, 0, 0, SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE) != 0 }
+    }
+
+    fn toggle_topmost(hwnd: HWND) -> Result<(), String> {
+        let topmost = !is_topmost(hwnd);
+        if !set_topmost(hwnd, topmost) {
+            return Err("切换置顶失败（目标窗口可能以管理员身份运行）".to_string());
+        }
+        if let Ok(mut pinned) = PINNED.lock() {
+            if topmost {
+                pinned.insert(hwnd);
+            } else {
+                pinned.remove(&hwnd);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn clear_topmost() {
+        let pinned: Vec<isize> = PINNED.lock().map(|mut p| p.drain().collect()).unwrap_or_default();
+        for hwnd in pinned {
+            // 窗口可能已关闭，或句柄已被其他窗口复用
+            if unsafe { IsWindow(hwnd) } != 0 && is_topmost(hwnd) {
+                set_topmost(hwnd, false);
+            }
+        }
+    }
+
+    pub fn perform(action: WindowAction) -> Result<(), String> {
+        if action == WindowAction::MinimizeAll {
+            return minimize_all();
+        }
+        let hwnd = foreground_window()?;
+        if action == WindowAction::ToggleMaximize {
+            let command = if unsafe { IsZoomed(hwnd) } != 0 { SW_RESTORE } else { SW_MAXIMIZE };
+            unsafe { ShowWindow(hwnd, command) };
+            return Ok(());
+        }
+        if action == WindowAction::ToggleTopmost {
+            return toggle_topmost(hwnd);
+        }
+        let monitor = unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) };
+        let work = work_area(monitor).ok_or_else(|| "无法获取显示器工作区".to_string())?;
+        match action {
+            WindowAction::Center => {
+                let visible = visible_rect(hwnd).ok_or_else(|| "无法获取窗口位置".to_string())?;
+                place(hwnd, center_rect(work, visible))
+            }
+            WindowAction::NextMonitor => {
+                let target_work = next_work_area(&all_work_areas(), work).ok_or_else(|| "只有一个显示器".to_string())?;
+                // 最大化的窗口先还原再移动，移动后重新最大化
+                let maximized = unsafe { IsZoomed(hwnd) } != 0;
+                if maximized {
+                    unsafe { ShowWindow(hwnd, SW_RESTORE) };
+                }
+                let visible = visible_rect(hwnd).ok_or_else(|| "无法获取窗口位置".to_string())?;
+                place(hwnd, move_to_work_area(visible, work, target_work))?;
+                if maximized {
+                    unsafe { ShowWindow(hwnd, SW_MAXIMIZE) };
+                }
+                Ok(())
+            }
+            _ => match snap_rect(work, action) {
+                Some(target) => place(hwnd, target),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    use super::WindowAction;
+
+    pub fn perform(_action: WindowAction) -> Result<(), String> {
+        Err("当前平台不支持窗口管理".to_string())
+    }
+
+    pub fn clear_topmost() {}
+}
+
+/// 取消由本应用置顶的所有窗口（应用退出时调用）
+pub use platform::clear_topmost;
+
+/// 按动作 id（如 "left_half"）对前台窗口执行窗口管理动作
+pub fn perform(action: &str) -> Result<(), String> {
+    let action = WindowAction::from_id(action).ok_or_else(|| format!("未知的窗口动作: {}", action))?;
+    platform::perform(action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(left: i32, top: i32, right: i32, bottom: i32) -> Rect {
+        Rect { left, top, right, bottom }
+    }
+
+    #[test]
+    fn computes_snap_and_center_rects() {
+        let work = rect(0, 0, 1920, 1040);
+        assert_eq!(snap_rect(work, WindowAction::LeftHalf), Some(rect(0, 0, 960, 1040)));
+        assert_eq!(snap_rect(work, WindowAction::BottomRight), Some(rect(960, 520, 1920, 1040)));
+        assert_eq!(snap_rect(work, WindowAction::Center), None);
+        assert_eq!(center_rect(work, rect(10, 10, 810, 610)), rect(560, 220, 1360, 820));
+        assert_eq!(center_rect(work, rect(0, 0, 3000, 500)), rect(0, 270, 1920, 770));
+    }
+
+    #[test]
+    fn moves_to_next_monitor() {
+        let left = rect(0, 0, 1920, 1040);
+        let right = rect(1920, 0, 4480, 1400);
+        assert_eq!(next_work_area(&[right, left], left), Some(right));
+        assert_eq!(next_work_area(&[right, left], right), Some(left));
+        assert_eq!(next_work_area(&[left], left), None);
+
+        let moved = move_to_work_area(rect(960, 0, 1920, 1040), left, right);
+        assert_eq!(moved, rect(3200, 0, 4160, 1040));
+        // 大窗口移到小显示器时缩小并保持在工作区内
+        assert_eq!(move_to_work_area(rect(2000, 0, 4480, 1400), right, left), rect(0, 0, 1920, 1040));
+        assert_eq!(WindowAction::from_id("next_monitor"), Some(WindowAction::NextMonitor));
+        assert_eq!(WindowAction::from_id("toggle_topmost"), Some(WindowAction::ToggleTopmost));
+        assert_eq!(WindowAction::from_id("bogus"), None);
+    }
+}
@@ -1,8 +1,15 @@
 use crate::db;
+use crate::event_bus::{self, BusEvent};
 use rusqlite::{params, OptionalExtension};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+// 单词记录变更事件，载荷为受影响的记录；多个窗口据此同步单词列表
+pub const CREATED_EVENT: &str = "word-record://created";
+pub const UPDATED_EVENT: &str = "word-record://updated";
+pub const DELETED_EVENT: &str = "word-record://deleted";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -31,52 +38,56 @@ fn now_ts() -> u64 {
         .as_secs()
 }
 
-pub fn get_all_word_records(app_data_dir: &Path) -> Result<Vec<WordRecord>, String> {
-    let mut conn = db::get_connection(app_data_dir)?;
-
-    let mut stmt = conn
-        .prepare(
-            "SELECT id, word, translation, context, phonetic, 
+const RECORD_COLUMNS: &str = "id, word, translation, context, phonetic, 
                     example_sentence, tags, ai_explanation, mastery_level, review_count, last_reviewed, 
-                    created_at, updated_at, is_favorite, is_mastered 
-             FROM word_records ORDER BY mastery_level ASC",
-        )
-        .map_err(|e| format!("Failed to prepare word_records query: {}", e))?;
+                    created_at, updated_at, is_favorite, is_mastered";
 
-    let rows = stmt
-        .query_map([], |row| {
-            let tags_json: Option<String> = row.get(6)?;
-            let tags = if let Some(json) = tags_json {
-                serde_json::from_str(&json).unwrap_or_default()
-            } else {
-                Vec::new()
-            };
+fn row_to_record(row: &rusqlite::Row) -> rusqlite::Result<WordRecord> {
+    let tags_json: Option<String> = row.get(6)?;
+    let tags = if let Some(json) = tags_json {
+        serde_json::from_str(&json).unwrap_or_default()
+    } else {
+        Vec::new()
+    };
 
-            Ok(WordRecord {
-                id: row.get(0)?,
-                word: row.get(1)?,
-                translation: row.get(2)?,
-                context: row.get(3)?,
-                phonetic: row.get(4)?,
-                example_sentence: row.get(5)?,
-                tags,
-                ai_explanation: row.get(7)?,
-                mastery_level: row.get(8)?,
-                review_count: row.get(9)?,
-                last_reviewed: row.get::<_, Option<i64>>(10)?.map(|v| v as u64),
-                created_at: row.get::<_, i64>(11)? as u64,
-                updated_at: row.get::<_, i64>(12)? as u64,
-                is_favorite: row.get::<_, i32>(13)? != 0,
-                is_mastered: row.get::<_, i32>(14)? != 0,
-            })
-        })
-        .map_err(|e| format!("Failed to iterate word_records: {}", e))?;
+    Ok(WordRecord {
+        id: row.get(0)?,
+        word: row.get(1)?,
+        translation: row.get(2)?,
+        context: row.get(3)?,
+        phonetic: row.get(4)?,
+        example_sentence: row.get(5)?,
+        tags,
+        ai_explanation: row.get(7)?,
+        mastery_level: row.get(8)?,
+        review_count: row.get(9)?,
+        last_reviewed: row.get::<_, Option<i64>>(10)?.map(|v| v as u64),
+        created_at: row.get::<_, i64>(11)? as u64,
+        updated_at: row.get::<_, i64>(12)? as u64,
+        is_favorite: row.get::<_, i32>(13)? != 0,
+        is_mastered: row.get::<_, i32>(14)? != 0,
+    })
+}
 
-    let mut items = Vec::new();
-    for row in rows {
-        items.push(row.map_err(|e| format!("Failed to read word_record row: {}", e))?);
-    }
-    Ok(items)
+pub fn get_all_word_records(app_data_dir: &Path) -> Result<Vec<WordRecord>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(&format!(
+                "SELECT {} FROM word_records ORDER BY mastery_level ASC",
+                RECORD_COLUMNS
+            ))
+            .map_err(|e| format!("Failed to prepare word_records query: {}", e))?;
+
+        let rows = stmt
+            .query_map([], row_to_record)
+            .map_err(|e| format!("Failed to iterate word_records: {}", e))?;
+
+        let mut items = Vec::new();
+        for row in rows {
+            items.push(row.map_err(|e| format!("Failed to read word_record row: {}", e))?);
+        }
+        Ok(items)
+    })
 }
 
 pub fn add_word_record(
@@ -112,35 +123,124 @@ pub fn add_word_record(
         is_mastered: false,
     };
 
-    let mut conn = db::get_connection(app_data_dir)?;
-    conn.execute(
-        "INSERT INTO word_records (id, word, translation, context, 
-                                   phonetic, example_sentence, tags, ai_explanation, mastery_level, review_count, 
-                                   last_reviewed, created_at, updated_at, is_favorite, is_mastered)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
-        params![
-            item.id,
-            item.word,
-            item.translation,
-            item.context,
-            item.phonetic,
-            item.example_sentence,
-            tags_json,
-            item.ai_explanation,
-            item.mastery_level,
-            item.review_count,
-            item.last_reviewed.map(|v| v as i64),
-            item.created_at as i64,
-            item.updated_at as i64,
-            if item.is_favorite { 1 } else { 0 },
-            if item.is_mastered { 1 } else { 0 }
-        ],
-    )
-    .map_err(|e| format!("Failed to insert word_record: {}", e))?;
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "INSERT INTO word_records (id, word, translation, context, 
+                                       phonetic, example_sentence, tags, ai_explanation, mastery_level, review_count, 
+                                       last_reviewed, created_at, updated_at, is_favorite, is_mastered)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+        )
+        .and_then(|mut stmt| {
+            stmt.execute(params![
+                item.id,
+                item.word,
+                item.translation,
+                item.context,
+                item.phonetic,
+                item.example_sentence,
+                tags_json,
+                item.ai_explanation,
+                item.mastery_level,
+                item.review_count,
+                item.last_reviewed.map(|v| v as i64),
+                item.created_at as i64,
+                item.updated_at as i64,
+                if item.is_favorite { 1 } else { 0 },
+                if item.is_mastered { 1 } else { 0 }
+            ])
+        })
+        .map_err(|e| format!("Failed to insert word_record: {}", e))
+    })?;
 
+    event_bus::publish(BusEvent::DbWrite { table: "word_records" });
     Ok(item)
 }
 
+/// 批量导入单词记录（单个事务）。
+/// 相同 id 或相同单词（忽略大小写）的记录视为冲突，按 mode 处理
+pub fn insert_word_records(
+    records: &[WordRecord],
+    mode: db::ConflictMode,
+    app_data_dir: &Path,
+) -> Result<db::BatchInsertResult, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let tx = conn
+            .unchecked_transaction()
+            .map_err(|e| format!("Failed to begin word_records import: {}", e))?;
+        let mut result = db::BatchInsertResult::default();
+
+        for record in records {
+            let existing: Option<(String, i64)> = tx
+                .prepare_cached(
+                    "SELECT id, updated_at FROM word_records
+                     WHERE id = ?1 OR lower(word) = lower(?2) LIMIT 1",
+                )
+                .and_then(|mut stmt| {
+                    stmt.query_row(params![record.id, record.word], |row| Ok((row.get(0)?, row.get(1)?)))
+                        .optional()
+                })
+                .map_err(|e| format!("Failed to check existing word_record: {}", e))?;
+
+            let (id, is_new) = match existing {
+                None => (record.id.clone(), true),
+                Some((existing_id, existing_updated_at)) => {
+                    let overwrite = match mode {
+                        db::ConflictMode::Skip => false,
+                        db::ConflictMode::Replace => true,
+                        db::ConflictMode::KeepNewer => record.updated_at as i64 > existing_updated_at,
+                    };
+                    if !overwrite {
+                        result.skipped += 1;
+                        continue;
+                    }
+                    (existing_id, false)
+                }
+            };
+
+            let tags_json = serde_json::to_string(&record.tags)
+                .map_err(|e| format!("Failed to serialize tags: {}", e))?;
+            // 冲突时沿用现有记录的 id，INSERT OR REPLACE 覆盖整行
+            tx.prepare_cached(
+                "INSERT OR REPLACE INTO word_records (id, word, translation, context, 
+                                                     phonetic, example_sentence, tags, ai_explanation, mastery_level, review_count, 
+                                                     last_reviewed, created_at, updated_at, is_favorite, is_mastered)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+            )
+            .and_then(|mut stmt| {
+                stmt.execute(params![
+                    id,
+                    record.word,
+                    record.translation,
+                    record.context,
+                    record.phonetic,
+                    record.example_sentence,
+                    tags_json,
+                    record.ai_explanation,
+                    record.mastery_level,
+                    record.review_count,
+                    record.last_reviewed.map(|v| v as i64),
+                    record.created_at as i64,
+                    record.updated_at as i64,
+                    if record.is_favorite { 1 } else { 0 },
+                    if record.is_mastered { 1 } else { 0 }
+                ])
+            })
+            .map_err(|e| format!("Failed to import word_record {}: {}", record.word, e))?;
+
+            if is_new {
+                result.inserted += 1;
+            } else {
+                result.updated += 1;
+            }
+        }
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit word_records import: {}", e))?;
+        event_bus::publish(BusEvent::DbWrite { table: "word_records" });
+        Ok(result)
+    })
+}
+
 pub fn update_word_record(
     id: String,
     word: Option<String>,
@@ -155,166 +255,121 @@ pub fn update_word_record(
     is_mastered: Option<bool>,
     app_data_dir: &Path,
 ) -> Result<WordRecord, String> {
-    let mut conn = db::get_connection(app_data_dir)?;
+    db::with_connection(app_data_dir, |conn| {
+        let existing: Option<WordRecord> = conn
+            .prepare_cached(&format!("SELECT {} FROM word_records WHERE id = ?1", RECORD_COLUMNS))
+            .and_then(|mut stmt| stmt.query_row(params![id], row_to_record).optional())
+            .map_err(|e| format!("Failed to load word_record: {}", e))?;
 
-    let existing: Option<WordRecord> = conn
-        .query_row(
-            "SELECT id, word, translation, context, phonetic, 
-                    example_sentence, tags, ai_explanation, mastery_level, review_count, last_reviewed, 
-                    created_at, updated_at, is_favorite, is_mastered 
-             FROM word_records WHERE id = ?1",
-            params![id],
-            |row| {
-                let tags_json: Option<String> = row.get(6)?;
-                let tags = if let Some(json) = tags_json {
-                    serde_json::from_str(&json).unwrap_or_default()
-                } else {
-                    Vec::new()
-                };
-
-                Ok(WordRecord {
-                    id: row.get(0)?,
-                    word: row.get(1)?,
-                    translation: row.get(2)?,
-                    context: row.get(3)?,
-                    phonetic: row.get(4)?,
-                    example_sentence: row.get(5)?,
-                    tags,
-                    ai_explanation: row.get(7)?,
-                    mastery_level: row.get(8)?,
-                    review_count: row.get(9)?,
-                    last_reviewed: row.get::<_, Option<i64>>(10)?.map(|v| v as u64),
-                    created_at: row.get::<_, i64>(11)? as u64,
-                    updated_at: row.get::<_, i64>(12)? as u64,
-                    is_favorite: row.get::<_, i32>(13)? != 0,
-                    is_mastered: row.get::<_, i32>(14)? != 0,
-                })
-            },
-        )
-        .optional()
-        .map_err(|e| format!("Failed to load word_record: {}", e))?;
+        let mut record = existing.ok_or_else(|| format!("WordRecord {} not found", id))?;
 
-    let mut record = existing.ok_or_else(|| format!("WordRecord {} not found", id))?;
+        if let Some(w) = word {
+            record.word = w;
+        }
+        if let Some(t) = translation {
+            record.translation = t;
+        }
+        if let Some(c) = context {
+            record.context = Some(c);
+        }
+        if let Some(p) = phonetic {
+            record.phonetic = Some(p);
+        }
+        if let Some(e) = example_sentence {
+            record.example_sentence = Some(e);
+        }
+        if let Some(tags) = tags {
+            record.tags = tags;
+        }
+        if let Some(ai_exp) = ai_explanation {
+            record.ai_explanation = Some(ai_exp);
+        }
+        if let Some(ml) = mastery_level {
+            record.mastery_level = ml;
+        }
+        if let Some(fav) = is_favorite {
+            record.is_favorite = fav;
+        }
+        if let Some(mas) = is_mastered {
+            record.is_mastered = mas;
+        }
+        record.updated_at = now_ts();
 
-    if let Some(w) = word {
-        record.word = w;
-    }
-    if let Some(t) = translation {
-        record.translation = t;
-    }
-    if let Some(c) = context {
-        record.context = Some(c);
-    }
-    if let Some(p) = phonetic {
-        record.phonetic = Some(p);
-    }
-    if let Some(e) = example_sentence {
-        record.example_sentence = Some(e);
-    }
-    if let Some(tags) = tags {
-        record.tags = tags;
-    }
-    if let Some(ai_exp) = ai_explanation {
-        record.ai_explanation = Some(ai_exp);
-    }
-    if let Some(ml) = mastery_level {
-        record.mastery_level = ml;
-    }
-    if let Some(fav) = is_favorite {
-        record.is_favorite = fav;
-    }
-    if let Some(mas) = is_mastered {
-        record.is_mastered = mas;
-    }
-    record.updated_at = now_ts();
+        let tags_json = serde_json::to_string(&record.tags)
+            .map_err(|e| format!("Failed to serialize tags: {}", e))?;
 
-    let tags_json = serde_json::to_string(&record.tags)
-        .map_err(|e| format!("Failed to serialize tags: {}", e))?;
+        conn.prepare_cached(
+            "UPDATE word_records 
+             SET word = ?1, translation = ?2, context = ?3, phonetic = ?4, example_sentence = ?5, 
+                 tags = ?6, ai_explanation = ?7, mastery_level = ?8, updated_at = ?9, is_favorite = ?10, is_mastered = ?11 
+             WHERE id = ?12",
+        )
+        .and_then(|mut stmt| {
+            stmt.execute(params![
+                record.word,
+                record.translation,
+                record.context,
+                record.phonetic,
+                record.example_sentence,
+                tags_json,
+                record.ai_explanation,
+                record.mastery_level,
+                record.updated_at as i64,
+                if record.is_favorite { 1 } else { 0 },
+                if record.is_mastered { 1 } else { 0 },
+                record.id
+            ])
+        })
+        .map_err(|e| format!("Failed to update word_record: {}", e))?;
 
-    conn.execute(
-        "UPDATE word_records 
-         SET word = ?1, translation = ?2, context = ?3, phonetic = ?4, example_sentence = ?5, 
-             tags = ?6, ai_explanation = ?7, mastery_level = ?8, updated_at = ?9, is_favorite = ?10, is_mastered = ?11 
-         WHERE id = ?12",
-        params![
-            record.word,
-            record.translation,
-            record.context,
-            record.phonetic,
-            record.example_sentence,
-            tags_json,
-            record.ai_explanation,
-            record.mastery_level,
-            record.updated_at as i64,
-            if record.is_favorite { 1 } else { 0 },
-            if record.is_mastered { 1 } else { 0 },
-            record.id
-        ],
-    )
-    .map_err(|e| format!("Failed to update word_record: {}", e))?;
+        event_bus::publish(BusEvent::DbWrite { table: "word_records" });
+        Ok(record)
+    })
+}
 
-    Ok(record)
+/// 删除单词记录，返回被删除的记录
+pub fn delete_word_record(id: String, app_data_dir: &Path) -> Result<WordRecord, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let record = conn
+            .prepare_cached(&format!("SELECT {} FROM word_records WHERE id = ?1", RECORD_COLUMNS))
+            .and_then(|mut stmt| stmt.query_row(params![id], row_to_record).optional())
+            .map_err(|e| format!("Failed to load word_record: {}", e))?
+            .ok_or_else(|| "WordRecord not found".to_string())?;
+        conn.prepare_cached("DELETE FROM word_records WHERE id = ?1")
+            .and_then(|mut stmt| stmt.execute(params![id]))
+            .map_err(|e| format!("Failed to delete word_record: {}", e))?;
+        event_bus::publish(BusEvent::DbWrite { table: "word_records" });
+        Ok(record)
+    })
 }
 
-pub fn delete_word_record(id: String, app_data_dir: &Path) -> Result<(), String> {
-    let mut conn = db::get_connection(app_data_dir)?;
-    let affected = conn
-        .execute("DELETE FROM word_records WHERE id = ?1", params![id])
-        .map_err(|e| format!("Failed to delete word_record: {}", e))?;
-    if affected == 0 {
-        return Err("WordRecord not found".to_string());
+/// 通知所有窗口单词记录已变更（event 为 CREATED_EVENT / UPDATED_EVENT / DELETED_EVENT）
+pub fn notify_change(app: &AppHandle, event: &str, record: &WordRecord) {
+    if let Err(e) = app.emit(event, record) {
+        eprintln!("[WordRecords] Failed to emit {} event: {}", event, e);
     }
-    Ok(())
 }
 
 pub fn search_word_records(query: &str, app_data_dir: &Path) -> Result<Vec<WordRecord>, String> {
-    let mut conn = db::get_connection(app_data_dir)?;
-
     let like = format!("%{}%", query.to_lowercase());
-    let mut stmt = conn
-        .prepare(
-            "SELECT id, word, translation, context, phonetic, 
-                    example_sentence, tags, ai_explanation, mastery_level, review_count, last_reviewed, 
-                    created_at, updated_at, is_favorite, is_mastered 
-             FROM word_records
-             WHERE lower(word) LIKE ?1 OR lower(translation) LIKE ?1
-             ORDER BY mastery_level ASC",
-        )
-        .map_err(|e| format!("Failed to prepare word_record search: {}", e))?;
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(&format!(
+                "SELECT {} FROM word_records
+                 WHERE lower(word) LIKE ?1 OR lower(translation) LIKE ?1
+                 ORDER BY mastery_level ASC",
+                RECORD_COLUMNS
+            ))
+            .map_err(|e| format!("Failed to prepare word_record search: {}", e))?;
 
-    let rows = stmt
-        .query_map(params![like], |row| {
-            let tags_json: Option<String> = row.get(6)?;
-            let tags = if let Some(json) = tags_json {
-                serde_json::from_str(&json).unwrap_or_default()
-            } else {
-                Vec::new()
-            };
+        let rows = stmt
+            .query_map(params![like], row_to_record)
+            .map_err(|e| format!("Failed to iterate word_record search: {}", e))?;
 
-            Ok(WordRecord {
-                id: row.get(0)?,
-                word: row.get(1)?,
-                translation: row.get(2)?,
-                context: row.get(3)?,
-                phonetic: row.get(4)?,
-                example_sentence: row.get(5)?,
-                tags,
-                ai_explanation: row.get(7)?,
-                mastery_level: row.get(8)?,
-                review_count: row.get(9)?,
-                last_reviewed: row.get::<_, Option<i64>>(10)?.map(|v| v as u64),
-                created_at: row.get::<_, i64>(11)? as u64,
-                updated_at: row.get::<_, i64>(12)? as u64,
-                is_favorite: row.get::<_, i32>(13)? != 0,
-                is_mastered: row.get::<_, i32>(14)? != 0,
-            })
-        })
-        .map_err(|e| format!("Failed to iterate word_record search: {}", e))?;
-
-    let mut items = Vec::new();
-    for row in rows {
-        items.push(row.map_err(|e| format!("Failed to read word_record row: {}", e))?);
-    }
-    Ok(items)
+        let mut items = Vec::new();
+        for row in rows {
+            items.push(row.map_err(|e| format!("Failed to read word_record row: {}", e))?);
+        }
+        Ok(items)
+    })
 }
-
@@ -0,0 +1,421 @@
+// 工作流：在启动器中输入关键字，依次执行一串步骤（取得文本 → 处理 → 输出），类似 Alfred Workflows
+// 步骤之间传递一段文本，初始值为关键字之后输入的内容；步骤可以是内置动作、自动化脚本或录制的宏
+
+use crate::db;
+use crate::key_injection::InjectionMode;
+use crate::{clipboard, file_history, focus_mode, scripts, text_transform, text_variables};
+use regex::Regex;
+use rusqlite::{params, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::AppHandle;
+
+pub const CHANGED_EVENT: &str = "workflows://changed";
+const EXPORT_VERSION: u32 = 1;
+const MAX_STEPS: usize = 50;
+/// 宏回放最长等待时间，超时视为该步骤失败
+const MACRO_TIMEOUT: Duration = Duration::from_secs(600);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WorkflowStep {
+    // 取得文本
+    Clipboard,
+    Selection,
+    Text { template: String }, // 支持文本扩展变量，{query} 为当前文本
+    Search,                    // 用当前文本搜索，结果为第一个匹配项的 target
+    // 处理文本
+    Transform { transform: String }, // text_transform 中的转换 id
+    Replace { pattern: String, replacement: String }, // 正则替换
+    Script { script: String },       // 以当前文本作为 input 运行脚本，输出作为新的文本
+    // 输出
+    Copy,
+    Paste,
+    Launch, // 打开当前文本（路径或网址）
+    Notify { title: String },
+    // 回放 recordings 文件夹中的录制，injection 为按键的输入方式
+    Macro {
+        recording: String,
+        speed: f32,
+        #[serde(default)]
+        injection: InjectionMode,
+    },
+}
+
+impl WorkflowStep {
+    fn label(&self) -> &'static str {
+        match self {
+            WorkflowStep::Clipboard => "读取剪贴板",
+            WorkflowStep::Selection => "读取选中文本",
+            WorkflowStep::Text { .. } => "文本",
+            WorkflowStep::Search => "搜索",
+            WorkflowStep::Transform { .. } => "文本转换",
+            WorkflowStep::Replace { .. } => "替换",
+            WorkflowStep::Script { .. } => "脚本",
+            WorkflowStep::Copy => "复制",
+            WorkflowStep::Paste => "粘贴",
+            WorkflowStep::Launch => "打开",
+            WorkflowStep::Notify { .. } => "通知",
+            WorkflowStep::Macro { .. } => "宏",
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            WorkflowStep::Transform { transform } => text_transform::TextTransform::from_id(transform)
+                .map(|_| ())
+                .ok_or_else(|| format!("未知的文本转换: {}", transform)),
+            WorkflowStep::Replace { pattern, .. } => {
+                Regex::new(pattern).map(|_| ()).map_err(|e| format!("无效的正则表达式 {}: {}", pattern, e))
+            }
+            WorkflowStep::Script { script } if script.trim().is_empty() => Err("脚本步骤未选择脚本".to_string()),
+            WorkflowStep::Macro { recording, .. } if recording.trim().is_empty() => {
+                Err("宏步骤未选择录制".to_string())
+            }
+            WorkflowStep::Macro { speed, .. } if !(0.1..=10.0).contains(speed) => {
+                Err("宏回放速度必须在 0.1 到 10 之间".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workflow {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub keyword: String,
+    #[serde(default)]
+    pub description: String,
+    pub steps: Vec<WorkflowStep>,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowStepLog {
+    pub step: String,
+    pub output: String, // 该步骤之后的文本（截断）
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowRunResult {
+    pub output: String,
+    pub steps: Vec<WorkflowStepLog>,
+    pub duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WorkflowExport {
+    version: u32,
+    workflows: Vec<Workflow>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowImportResult {
+    pub imported: usize,
+    pub skipped: Vec<String>, // 跳过的工作流及原因
+}
+
+fn now_ts() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+fn new_id() -> String {
+    format!("workflow-{}", SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos())
+}
+
+fn row_to_workflow(row: &rusqlite::Row) -> rusqlite::Result<(Workflow, String)> {
+    Ok((
+        Workflow {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            keyword: row.get(2)?,
+            description: row.get(3)?,
+            steps: Vec::new(),
+            created_at: row.get(5)?,
+            updated_at: row.get(6)?,
+        },
+        row.get(4)?,
+    ))
+}
+
+pub fn list_workflows(app_data_dir: &Path) -> Result<Vec<Workflow>, String> {
+    db::with_connection(app_data_dir, |conn| {
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT id, name, keyword, description, steps, created_at, updated_at FROM workflows ORDER BY created_at",
+            )
+            .map_err(|e| format!("Failed to prepare workflows query: {}", e))?;
+        let rows = stmt
+            .query_map([], row_to_workflow)
+            .map_err(|e| format!("Failed to iterate workflows: {}", e))?;
+
+        let mut workflows = Vec::new();
+        for row in rows {
+            let (mut workflow, steps) = row.map_err(|e| format!("Failed to read workflow row: {}", e))?;
+            match serde_json::from_str(&steps) {
+                Ok(steps) => {
+                    workflow.steps = steps;
+                    workflows.push(workflow);
+                }
+                Err(e) => eprintln!("[Workflows] Skipping workflow {} with invalid steps: {}", workflow.id, e),
+            }
+        }
+        Ok(workflows)
+    })
+}
+
+fn get_workflow(app_data_dir: &Path, id: &str) -> Result<Workflow, String> {
+    let row = db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "SELECT id, name, keyword, description, steps, created_at, updated_at FROM workflows WHERE id = ?1",
+        )
+        .and_then(|mut stmt| stmt.query_row(params![id], row_to_workflow).optional())
+        .map_err(|e| format!("Failed to load workflow: {}", e))
+    })?;
+    let (mut workflow, steps) = row.ok_or_else(|| format!("工作流 {} 不存在", id))?;
+    workflow.steps = serde_json::from_str(&steps).map_err(|e| format!("工作流步骤无效: {}", e))?;
+    Ok(workflow)
+}
+
+/// 检查名称、关键字和步骤；关键字不能与其他工作流或脚本重复
+fn validate(workflow: &mut Workflow, existing: &[Workflow]) -> Result<(), String> {
+    workflow.name = workflow.name.trim().to_string();
+    workflow.keyword = workflow.keyword.trim().to_lowercase();
+    if workflow.name.is_empty() {
+        return Err("工作流名称不能为空".to_string());
+    }
+    if workflow.keyword.is_empty() || workflow.keyword.contains(char::is_whitespace) {
+        return Err("关键字必须是不含空格的单个词".to_string());
+    }
+    if workflow.steps.is_empty() {
+        return Err("工作流至少需要一个步骤".to_string());
+    }
+    if workflow.steps.len() > MAX_STEPS {
+        return Err(format!("工作流最多 {} 个步骤", MAX_STEPS));
+    }
+    for (index, step) in workflow.steps.iter().enumerate() {
+        step.validate().map_err(|e| format!("第 {} 步：{}", index + 1, e))?;
+    }
+    if let Some(other) = existing.iter().find(|w| w.id != workflow.id && w.keyword == workflow.keyword) {
+        return Err(format!("关键字 {} 已被工作流 {} 使用", workflow.keyword, other.name));
+    }
+    if let Some(script) = scripts::list_scripts()
+        .into_iter()
+        .find(|s| s.error.is_none() && s.meta.keyword.as_deref() == Some(workflow.keyword.as_str()))
+    {
+        return Err(format!("关键字 {} 已被脚本 {} 使用", workflow.keyword, script.id));
+    }
+    Ok(())
+}
+
+fn upsert(app_data_dir: &Path, workflow: &Workflow) -> Result<(), String> {
+    let steps = serde_json::to_string(&workflow.steps).map_err(|e| format!("Failed to serialize steps: {}", e))?;
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached(
+            "INSERT INTO workflows (id, name, keyword, description, steps, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT(id) DO UPDATE SET name = excluded.name, keyword = excluded.keyword,
+                 description = excluded.description, steps = excluded.steps, updated_at = excluded.updated_at",
+        )
+        .and_then(|mut stmt| {
+            stmt.execute(params![
+                workflow.id,
+                workflow.name,
+                workflow.keyword,
+                workflow.description,
+                steps,
+                workflow.created_at,
+                workflow.updated_at
+            ])
+        })
+        .map_err(|e| format!("Failed to save workflow: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 保存工作流，id 为空时新建
+pub fn save_workflow(app_data_dir: &Path, mut workflow: Workflow) -> Result<Workflow, String> {
+    let existing = list_workflows(app_data_dir)?;
+    validate(&mut workflow, &existing)?;
+    let now = now_ts();
+    match existing.iter().find(|w| w.id == workflow.id) {
+        Some(old) => workflow.created_at = old.created_at,
+        None => {
+            if workflow.id.trim().is_empty() {
+                workflow.id = new_id();
+            }
+            workflow.created_at = now;
+        }
+    }
+    workflow.updated_at = now;
+    upsert(app_data_dir, &workflow)?;
+    Ok(workflow)
+}
+
+pub fn delete_workflow(app_data_dir: &Path, id: &str) -> Result<(), String> {
+    db::with_connection(app_data_dir, |conn| {
+        conn.prepare_cached("DELETE FROM workflows WHERE id = ?1")
+            .and_then(|mut stmt| stmt.execute(params![id]))
+            .map_err(|e| format!("Failed to delete workflow: {}", e))
+    })?;
+    Ok(())
+}
+
+/// 导出为 JSON；ids 为空时导出全部
+pub fn export_workflows(app_data_dir: &Path, ids: &[String]) -> Result<String, String> {
+    let workflows = list_workflows(app_data_dir)?
+        .into_iter()
+        .filter(|w| ids.is_empty() || ids.contains(&w.id))
+        .collect();
+    serde_json::to_string_pretty(&WorkflowExport { version: EXPORT_VERSION, workflows })
+        .map_err(|e| format!("Failed to serialize workflows: {}", e))
+}
+
+/// 导入 export_workflows 的 JSON：相同 id 的工作流被覆盖，校验失败或关键字冲突的跳过
+pub fn import_workflows(app_data_dir: &Path, json: &str) -> Result<WorkflowImportResult, String> {
+    let data: WorkflowExport = serde_json::from_str(json).map_err(|e| format!("无效的工作流文件: {}", e))?;
+    if data.version > EXPORT_VERSION {
+        return Err(format!("不支持的工作流文件版本 {}", data.version));
+    }
+    let mut existing = list_workflows(app_data_dir)?;
+    let mut result = WorkflowImportResult { imported: 0, skipped: Vec::new() };
+    for mut workflow in data.workflows {
+        let name = workflow.name.clone();
+        if let Err(e) = validate(&mut workflow, &existing) {
+            result.skipped.push(format!("{}: {}", name, e));
+            continue;
+        }
+        let now = now_ts();
+        if workflow.id.trim().is_empty() {
+            workflow.id = new_id();
+        }
+        if workflow.created_at == 0 {
+            workflow.created_at = now;
+        }
+        workflow.updated_at = now;
+        upsert(app_data_dir, &workflow)?;
+        existing.retain(|w| w.id != workflow.id);
+        existing.push(workflow);
+        result.imported += 1;
+    }
+    Ok(result)
+}
+
+// ---------- 运行 ----------
+
+fn preview(text: &str) -> String {
+    let mut preview: String = text.chars().take(200).collect();
+    if preview.len() < text.len() {
+        preview.push('…');
+    }
+    preview
+}
+
+fn run_step(app: &AppHandle, app_data_dir: &Path, step: &WorkflowStep, text: String) -> Result<String, String> {
+    match step {
+        WorkflowStep::Clipboard => clipboard::read_text(),
+        WorkflowStep::Selection => crate::selection::read_selection(),
+        WorkflowStep::Text { template } => text_variables::render(
+            app_data_dir,
+            template,
+            &text_variables::RenderOptions { query: Some(text), url_encode: false },
+        ),
+        WorkflowStep::Search => scripts::search_items(&text, app_data_dir)?
+            .into_iter()
+            .next()
+            .map(|hit| hit.target)
+            .ok_or_else(|| format!("没有找到 {}", text)),
+        WorkflowStep::Transform { transform } => {
+            let transform = text_transform::TextTransform::from_id(transform)
+                .ok_or_else(|| format!("未知的文本转换: {}", transform))?;
+            text_transform::apply(transform, &text)
+        }
+        WorkflowStep::Replace { pattern, replacement } => {
+            let re = Regex::new(pattern).map_err(|e| format!("无效的正则表达式 {}: {}", pattern, e))?;
+            Ok(re.replace_all(&text, replacement.as_str()).into_owned())
+        }
+        WorkflowStep::Script { script } => {
+            let result = scripts::run_script(script, &text)?;
+            Ok(result.output)
+        }
+        WorkflowStep::Copy => clipboard::write_text(&text).map(|_| text),
+        WorkflowStep::Paste => text_transform::paste_text(&text).map(|_| text),
+        WorkflowStep::Launch => file_history::launch_file(text.trim()).map(|_| text),
+        WorkflowStep::Notify { title } => {
+            let title = if title.trim().is_empty() { "IMiss" } else { title.as_str() };
+            focus_mode::show_notification(app, title, &preview(&text), None)?;
+            Ok(text)
+        }
+        WorkflowStep::Macro { recording, speed, injection } => {
+            crate::commands::recording::play_recording(app.clone(), recording.clone(), *speed, Some(*injection))?;
+            // 等待回放结束再执行后续步骤
+            let started = Instant::now();
+            while crate::commands::recording::get_playback_status().unwrap_or(false) {
+                if started.elapsed() > MACRO_TIMEOUT {
+                    return Err("宏回放超时".to_string());
+                }
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            Ok(text)
+        }
+    }
+}
+
+/// 运行工作流，input 为启动器中关键字之后的文本；某一步失败时停止并返回错误
+pub fn run_workflow(app: &AppHandle, app_data_dir: &Path, id: &str, input: &str) -> Result<WorkflowRunResult, String> {
+    let workflow = get_workflow(app_data_dir, id)?;
+    let started = Instant::now();
+    let mut text = input.to_string();
+    let mut steps = Vec::with_capacity(workflow.steps.len());
+    for (index, step) in workflow.steps.iter().enumerate() {
+        text = run_step(app, app_data_dir, step, text)
+            .map_err(|e| format!("第 {} 步（{}）失败: {}", index + 1, step.label(), e))?;
+        steps.push(WorkflowStepLog { step: step.label().to_string(), output: preview(&text) });
+    }
+    Ok(WorkflowRunResult { output: text, steps, duration_ms: started.elapsed().as_millis() as u64 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_and_validates_steps() {
+        let json = r#"[{"type":"clipboard"},{"type":"transform","transform":"upper"},{"type":"replace","pattern":"\\s+","replacement":"-"},{"type":"copy"}]"#;
+        let steps: Vec<WorkflowStep> = serde_json::from_str(json).unwrap();
+        assert_eq!(steps[1], WorkflowStep::Transform { transform: "upper".to_string() });
+        assert!(steps.iter().all(|s| s.validate().is_ok()));
+
+        assert!(WorkflowStep::Transform { transform: "nope".to_string() }.validate().is_err());
+        assert!(WorkflowStep::Replace { pattern: "(".to_string(), replacement: String::new() }.validate().is_err());
+        // 旧的宏步骤没有 injection 字段
+        let step: WorkflowStep = serde_json::from_str(r#"{"type":"macro","recording":"a.json","speed":20.0}"#).unwrap();
+        assert!(matches!(step, WorkflowStep::Macro { injection: InjectionMode::Default, .. }));
+        assert!(step.validate().is_err());
+
+        let mut workflow = Workflow {
+            id: "a".to_string(),
+            name: " 大写 ".to_string(),
+            keyword: "UP".to_string(),
+            description: String::new(),
+            steps,
+            created_at: 0,
+            updated_at: 0,
+        };
+        let other = Workflow { id: "b".to_string(), keyword: "up".to_string(), ..workflow.clone() };
+        assert!(validate(&mut workflow, &[]).is_ok());
+        assert_eq!((workflow.name.as_str(), workflow.keyword.as_str()), ("大写", "up"));
+        assert!(validate(&mut workflow, &[other]).is_err());
+        workflow.keyword = "two words".to_string();
+        assert!(validate(&mut workflow, &[]).is_err());
+    }
+}
@@ -0,0 +1,262 @@
+// 世界时钟：按城市名（中英文）、时区缩写、UTC±N 或 IANA 时区名查询当地时间，并在时区间换算时间
+// 常用城市保存在设置 world_clock_cities 中，启动器输入 "time" 时显示
+
+use chrono::{DateTime, Local, NaiveTime, Offset, TimeZone, Utc};
+use chrono_tz::{OffsetName, Tz};
+use serde::Serialize;
+
+/// 城市显示名、IANA 时区及别名（小写匹配）
+const CITIES: &[(&str, &str, &[&str])] = &[
+    ("北京", "Asia/Shanghai", &["beijing", "peking", "china", "中国", "上海", "shanghai", "深圳", "shenzhen", "广州", "guangzhou"]),
+    ("香港", "Asia/Hong_Kong", &["hong kong", "hongkong", "hk"]),
+    ("台北", "Asia/Taipei", &["taipei", "taiwan", "台湾"]),
+    ("东京", "Asia/Tokyo", &["tokyo", "japan", "日本", "osaka", "大阪"]),
+    ("首尔", "Asia/Seoul", &["seoul", "korea", "韩国"]),
+    ("新加坡", "Asia/Singapore", &["singapore", "sg"]),
+    ("曼谷", "Asia/Bangkok", &["bangkok", "thailand", "泰国"]),
+    ("雅加达", "Asia/Jakarta", &["jakarta", "indonesia", "印尼"]),
+    ("马尼拉", "Asia/Manila", &["manila", "philippines", "菲律宾"]),
+    ("河内", "Asia/Ho_Chi_Minh", &["hanoi", "ho chi minh", "vietnam", "越南", "胡志明市"]),
+    ("新德里", "Asia/Kolkata", &["new delhi", "delhi", "mumbai", "孟买", "bangalore", "班加罗尔", "india", "印度"]),
+    ("迪拜", "Asia/Dubai", &["dubai", "uae", "阿联酋"]),
+    ("莫斯科", "Europe/Moscow", &["moscow", "russia", "俄罗斯"]),
+    ("伊斯坦布尔", "Europe/Istanbul", &["istanbul", "turkey", "土耳其"]),
+    ("伦敦", "Europe/London", &["london", "uk", "英国"]),
+    ("都柏林", "Europe/Dublin", &["dublin", "ireland", "爱尔兰"]),
+    ("巴黎", "Europe/Paris", &["paris", "france", "法国"]),
+    ("柏林", "Europe/Berlin", &["berlin", "germany", "德国", "munich", "慕尼黑", "frankfurt", "法兰克福"]),
+    ("阿姆斯特丹", "Europe/Amsterdam", &["amsterdam", "netherlands", "荷兰"]),
+    ("马德里", "Europe/Madrid", &["madrid", "spain", "西班牙"]),
+    ("罗马", "Europe/Rome", &["rome", "italy", "意大利", "milan", "米兰"]),
+    ("苏黎世", "Europe/Zurich", &["zurich", "switzerland", "瑞士"]),
+    ("斯德哥尔摩", "Europe/Stockholm", &["stockholm", "sweden", "瑞典"]),
+    ("开罗", "Africa/Cairo", &["cairo", "egypt", "埃及"]),
+    ("约翰内斯堡", "Africa/Johannesburg", &["johannesburg", "south africa", "南非"]),
+    ("纽约", "America/New_York", &["new york", "nyc", "ny", "washington", "华盛顿", "boston", "波士顿"]),
+    ("多伦多", "America/Toronto", &["toronto", "canada", "加拿大"]),
+    ("芝加哥", "America/Chicago", &["chicago", "dallas", "达拉斯", "houston", "休斯顿"]),
+    ("丹佛", "America/Denver", &["denver"]),
+    ("凤凰城", "America/Phoenix", &["phoenix"]),
+    ("洛杉矶", "America/Los_Angeles", &["los angeles", "la", "san francisco", "sf", "旧金山", "seattle", "西雅图", "硅谷"]),
+    ("温哥华", "America/Vancouver", &["vancouver"]),
+    ("檀香山", "Pacific/Honolulu", &["honolulu", "hawaii", "夏威夷"]),
+    ("墨西哥城", "America/Mexico_City", &["mexico city", "mexico", "墨西哥"]),
+    ("圣保罗", "America/Sao_Paulo", &["sao paulo", "são paulo", "brazil", "巴西"]),
+    ("布宜诺斯艾利斯", "America/Argentina/Buenos_Aires", &["buenos aires", "argentina", "阿根廷"]),
+    ("悉尼", "Australia/Sydney", &["sydney", "australia", "澳大利亚", "墨尔本", "melbourne"]),
+    ("奥克兰", "Pacific/Auckland", &["auckland", "new zealand", "新西兰"]),
+];
+
+/// 常见时区缩写；夏令时与标准时缩写都映射到对应地区的时区，按当天实际偏移计算
+/// CST 按美国中部时间处理，中国时间请使用 "北京" 或 "BJT"
+const ABBREVIATIONS: &[(&str, &str)] = &[
+    ("UTC", "UTC"),
+    ("GMT", "UTC"),
+    ("BJT", "Asia/Shanghai"),
+    ("HKT", "Asia/Hong_Kong"),
+    ("SGT", "Asia/Singapore"),
+    ("JST", "Asia/Tokyo"),
+    ("KST", "Asia/Seoul"),
+    ("IST", "Asia/Kolkata"),
+    ("MSK", "Europe/Moscow"),
+    ("BST", "Europe/London"),
+    ("WET", "Europe/Lisbon"),
+    ("CET", "Europe/Paris"),
+    ("CEST", "Europe/Paris"),
+    ("EET", "Europe/Athens"),
+    ("EST", "America/New_York"),
+    ("EDT", "America/New_York"),
+    ("ET", "America/New_York"),
+    ("CST", "America/Chicago"),
+    ("CDT", "America/Chicago"),
+    ("CT", "America/Chicago"),
+    ("MST", "America/Denver"),
+    ("MDT", "America/Denver"),
+    ("MT", "America/Denver"),
+    ("PST", "America/Los_Angeles"),
+    ("PDT", "America/Los_Angeles"),
+    ("PT", "America/Los_Angeles"),
+    ("AKST", "America/Anchorage"),
+    ("HST", "Pacific/Honolulu"),
+    ("AEST", "Australia/Sydney"),
+    ("AEDT", "Australia/Sydney"),
+    ("NZST", "Pacific/Auckland"),
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClockEntry {
+    pub query: String,                // 用户输入的城市或时区，用于前端收藏
+    pub label: String,                // 显示名称
+    pub zone: String,                 // IANA 时区名
+    pub date: String,                 // 当地日期 YYYY-MM-DD
+    pub time: String,                 // 当地时间 HH:MM
+    pub utc_offset: String,           // 如 "+09:00"
+    pub abbreviation: Option<String>, // 如 "JST"，没有字母缩写的时区为空
+    pub day_offset: i64,              // 相对参考日期（本地或换算的源时间）相差的天数
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeConversion {
+    pub source: ClockEntry,
+    pub targets: Vec<ClockEntry>,
+}
+
+/// 把城市名、时区缩写、UTC±N 或 IANA 时区名解析为 (显示名, 时区)
+pub fn resolve(place: &str) -> Result<(String, Tz), String> {
+    let trimmed = place.trim();
+    let lower = trimmed.to_lowercase();
+    if let Some((label, zone, _)) = CITIES
+        .iter()
+        .find(|(label, _, aliases)| *label == trimmed || aliases.contains(&lower.as_str()))
+    {
+        return Ok((label.to_string(), zone.parse().map_err(|e| format!("{}", e))?));
+    }
+    let upper = trimmed.to_ascii_uppercase();
+    if let Some((abbr, zone)) = ABBREVIATIONS.iter().find(|(abbr, _)| *abbr == upper) {
+        return Ok((abbr.to_string(), zone.parse().map_err(|e| format!("{}", e))?));
+    }
+    // UTC+8 / GMT-5：对应 IANA 的 Etc/GMT∓N（符号相反）
+    if let Some(offset) = upper.strip_prefix("UTC").or_else(|| upper.strip_prefix("GMT")) {
+        if let Ok(hours) = offset.parse::<i32>() {
+            if (-12..=14).contains(&hours) {
+                let zone = if hours == 0 { "UTC".to_string() } else { format!("Etc/GMT{:+}", -hours) };
+                return Ok((format!("UTC{:+}", hours), zone.parse().map_err(|e| format!("{}", e))?));
+            }
+        }
+    }
+    trimmed
+        .parse::<Tz>()
+        .map(|tz| (tz.name().rsplit('/').next().unwrap_or(tz.name()).replace('_', " "), tz))
+        .map_err(|_| format!("未知的城市或时区: {}", trimmed))
+}
+
+fn entry(query: &str, label: String, time: DateTime<Tz>, reference: chrono::NaiveDate) -> ClockEntry {
+    let seconds = time.offset().fix().local_minus_utc();
+    let abbreviation = time
+        .offset()
+        .abbreviation()
+        .filter(|a| a.starts_with(|c: char| c.is_ascii_alphabetic()))
+        .map(str::to_string);
+    ClockEntry {
+        query: query.trim().to_string(),
+        label,
+        zone: time.timezone().name().to_string(),
+        date: time.format("%Y-%m-%d").to_string(),
+        time: time.format("%H:%M").to_string(),
+        utc_offset: format!("{}{:02}:{:02}", if seconds < 0 { '-' } else { '+' }, seconds.abs() / 3600, seconds.abs() % 3600 / 60),
+        abbreviation,
+        day_offset: (time.date_naive() - reference).num_days(),
+    }
+}
+
+fn clocks_at(places: &[String], now: DateTime<Utc>, reference: chrono::NaiveDate) -> Result<Vec<ClockEntry>, String> {
+    places
+        .iter()
+        .map(|place| {
+            let (label, tz) = resolve(place)?;
+            Ok(entry(place, label, now.with_timezone(&tz), reference))
+        })
+        .collect()
+}
+
+/// 各城市的当前时间，day_offset 相对本地日期
+pub fn world_clocks(places: &[String]) -> Result<Vec<ClockEntry>, String> {
+    clocks_at(places, Utc::now(), Local::now().date_naive())
+}
+
+/// 解析 "3pm"、"3:30 pm"、"15:30"、"下午3点半"、"noon" 等时间
+pub fn parse_clock_time(input: &str) -> Result<NaiveTime, String> {
+    let invalid = || format!("无法识别的时间: {}", input.trim());
+    let mut text = input.trim().to_lowercase();
+    match text.as_str() {
+        "noon" | "中午" => return Ok(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+        "midnight" | "午夜" => return Ok(NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+        _ => {}
+    }
+    let mut meridiem = None;
+    for (prefix, pm) in [("上午", false), ("早上", false), ("凌晨", false), ("下午", true), ("晚上", true)] {
+        if let Some(rest) = text.strip_prefix(prefix) {
+            meridiem = Some(pm);
+            text = rest.to_string();
+        }
+    }
+    for (suffix, pm) in [("am", false), ("a.m.", false), ("pm", true), ("p.m.", true)] {
+        if let Some(rest) = text.strip_suffix(suffix) {
+            meridiem = Some(pm);
+            text = rest.trim().to_string();
+        }
+    }
+    let text = text.replace("点半", ":30").replace('点', ":").replace('分', "");
+    let (hour, minute) = match text.trim_end_matches(':').split_once(':') {
+        Some((h, m)) => (h.trim().parse::<u32>().map_err(|_| invalid())?, m.trim().parse::<u32>().map_err(|_| invalid())?),
+        None => (text.trim_end_matches(':').trim().parse::<u32>().map_err(|_| invalid())?, 0),
+    };
+    let hour = match meridiem {
+        Some(_) if !(1..=12).contains(&hour) => return Err(invalid()),
+        Some(pm) => hour % 12 + if pm { 12 } else { 0 },
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0).ok_or_else(invalid)
+}
+
+fn convert_on(time: &str, from: &str, to: &[String], now: DateTime<Utc>) -> Result<TimeConversion, String> {
+    let clock_time = parse_clock_time(time)?;
+    let (label, from_tz) = resolve(from)?;
+    // 以源时区的今天为日期；夏令时切换造成的重复时刻取较早的一个
+    let date = now.with_timezone(&from_tz).date_naive();
+    let source = from_tz
+        .from_local_datetime(&date.and_time(clock_time))
+        .earliest()
+        .ok_or_else(|| format!("{} 在 {} 不存在（夏令时切换）", time.trim(), label))?;
+    let targets = clocks_at(to, source.with_timezone(&Utc), date)?;
+    Ok(TimeConversion { source: entry(from, label, source, date), targets })
+}
+
+/// 把源时区今天的某个时间换算到目标时区，day_offset 相对源日期
+pub fn convert(time: &str, from: &str, to: &[String]) -> Result<TimeConversion, String> {
+    if to.is_empty() {
+        return Err("请指定目标城市或时区".to_string());
+    }
+    convert_on(time, from, to, Utc::now())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_places() {
+        assert_eq!(resolve("Tokyo").unwrap().1, chrono_tz::Asia::Tokyo);
+        assert_eq!(resolve("东京").unwrap().0, "东京");
+        assert_eq!(resolve("pst").unwrap().1, chrono_tz::America::Los_Angeles);
+        assert_eq!(resolve("UTC+8").unwrap().1, chrono_tz::Etc::GMTMinus8);
+        assert_eq!(resolve("Europe/Lisbon").unwrap().0, "Lisbon");
+        assert!(resolve("atlantis").is_err());
+    }
+
+    #[test]
+    fn parses_times() {
+        let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        assert_eq!(parse_clock_time("3pm").unwrap(), hm(15, 0));
+        assert_eq!(parse_clock_time("12 am").unwrap(), hm(0, 0));
+        assert_eq!(parse_clock_time("9:45").unwrap(), hm(9, 45));
+        assert_eq!(parse_clock_time("下午3点半").unwrap(), hm(15, 30));
+        assert_eq!(parse_clock_time("noon").unwrap(), hm(12, 0));
+        assert!(parse_clock_time("13pm").is_err());
+        assert!(parse_clock_time("25:00").is_err());
+    }
+
+    #[test]
+    fn converts_across_zones() {
+        // 2026-01-15 冬令时：PST = UTC-8，CST = UTC-6，东京 = UTC+9
+        let now = Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap();
+        let result = convert_on("3pm", "PST", &["CST".to_string(), "Tokyo".to_string()], now).unwrap();
+        assert_eq!(result.source.time, "15:00");
+        assert_eq!(result.source.utc_offset, "-08:00");
+        assert_eq!(result.targets[0].time, "17:00");
+        assert_eq!(result.targets[0].day_offset, 0);
+        assert_eq!(result.targets[1].time, "08:00");
+        assert_eq!(result.targets[1].day_offset, 1);
+        assert_eq!(result.targets[1].abbreviation.as_deref(), Some("JST"));
+    }
+}
@@ -1,10 +1,26 @@
 import { useState, useEffect } from "react";
+import { listen } from "@tauri-apps/api/event";
 import { RecordControls } from "./components/RecordControls";
 import { PlaybackControls } from "./components/PlaybackControls";
 import { RecordingList } from "./components/RecordingList";
 import { StatusBar } from "./components/StatusBar";
 import { tauriApi } from "./api/tauri";
-import type { AppStatus, RecordingMeta } from "./types";
+import type {
+  AppStatus,
+  InjectionMode,
+  RecordingMeta,
+  RecordingStoppedPayload,
+  ReplayCompletedPayload,
+  ReplayErrorPayload,
+  ReplayProgressPayload,
+} from "./types";
+
+const COMPLETED_MESSAGES: Record<ReplayCompletedPayload["reason"], string> = {
+  finished: "回放已完成",
+  stopped: "回放已停止",
+  escape: "回放已停止（按 Esc 键）",
+  limit: "回放已停止（超过事件数量上限）",
+};
 
 function App() {
   const [status, setStatus] = useState<AppStatus>("idle");
@@ -35,43 +51,35 @@ function App() {
     }
   };
 
-  // Poll playback progress when playing
+  // 监听后端推送的录制、回放状态事件
   useEffect(() => {
-    if (status !== "playing") {
-      return;
-    }
-
-    const interval = setInterval(async () => {
-      try {
-        // Check if playback is still active
-        const isPlaying = await tauriApi.getPlaybackStatus();
-        if (!isPlaying) {
-          // Playback has stopped
-          setStatus("idle");
-          setMessage("回放已停止");
-          setProgress(0);
-          return;
-        }
-
-        const progress = await tauriApi.getPlaybackProgress();
-        setProgress(progress);
-        
-        // If progress is 100%, playback is complete
-        if (progress >= 100) {
-          setStatus("idle");
-          setMessage("回放已完成");
-        }
-      } catch (error) {
-        // If getting status/progress fails, playback has likely stopped
-        console.error("Failed to get playback status/progress:", error);
+    const unlisteners = [
+      listen("recording://started", () => {
+        setStatus("recording");
+      }),
+      listen<RecordingStoppedPayload>("recording://stopped", (event) => {
         setStatus("idle");
-        setMessage("回放已停止");
+        setMessage(`录制已保存: ${event.payload.file_path}（${event.payload.event_count} 个事件）`);
+        loadRecordings();
+      }),
+      listen<ReplayProgressPayload>("replay://progress", (event) => {
+        setProgress(event.payload.progress);
+      }),
+      listen<ReplayCompletedPayload>("replay://completed", (event) => {
+        setStatus("idle");
+        setMessage(COMPLETED_MESSAGES[event.payload.reason] ?? "回放已停止");
         setProgress(0);
-      }
-    }, 100); // Update every 100ms
-
-    return () => clearInterval(interval);
-  }, [status]);
+      }),
+      listen<ReplayErrorPayload>("replay://error", (event) => {
+        console.error(`Failed to execute event #${event.payload.index}:`, event.payload.message);
+        setMessage(`回放第 ${event.payload.index + 1} 个事件失败: ${event.payload.message}`);
+      }),
+    ];
+
+    return () => {
+      unlisteners.forEach((unlisten) => unlisten.then((fn) => fn()));
+    };
+  }, []);
 
   // Listen for Esc key to stop playback
   useEffect(() => {
@@ -130,9 +138,9 @@ function App() {
     }
   };
 
-  const handlePlayRecording = async (path: string, speed: number) => {
+  const handlePlayRecording = async (path: string, speed: number, injection: InjectionMode) => {
     try {
-      await tauriApi.playRecording(path, speed);
+      await tauriApi.playRecording(path, speed, injection);
       setStatus("playing");
       setMessage(`正在回放: ${path} (${speed}x)`);
       setProgress(0);
@@ -92,6 +92,7 @@ export const tauriApi = {
   getAllMemos: async () => [] as MemoItem[],
   addMemo: async () => ({} as MemoItem),
   updateMemo: async () => ({} as MemoItem),
+  setMemoHotstring: async () => ({} as MemoItem),
   deleteMemo: async () => {},
   searchMemos: async () => [] as MemoItem[],
 
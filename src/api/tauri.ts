@@ -1,4 +1,4 @@
-import { invoke } from "@tauri-apps/api/core";
+import { Channel, invoke } from "@tauri-apps/api/core";
 import type {
   RecordingMeta,
   AppInfo,
@@ -8,6 +8,7 @@ import type {
   EverythingResult,
   ShortcutItem,
   MemoItem,
+  InjectionMode,
   IndexStatus,
   FilePreview,
   DatabaseBackupList,
@@ -15,9 +16,104 @@ import type {
   UpdateCheckResult,
   DatabaseHealthStatus,
   ClipboardItem,
+  ClipboardContent,
+  ClipboardCopyFormat,
+  ClipboardTag,
+  ClipboardMonitorStatus,
   OpenHistoryItem,
   WordRecord,
   SearchEngineConfig,
+  PathCompletion,
+  OpenWithAction,
+  SshHost,
+  GitRepo,
+  GitRepoRefreshResult,
+  RecentProject,
+  GameInfo,
+  BrowserHistoryItem,
+  AliasConfig,
+  LauncherImportSource,
+  LauncherImportPreview,
+  LauncherImportResult,
+  ActivityEvent,
+  ActivityStats,
+  RankingBoost,
+  QueryHistoryEntry,
+  ConflictMode,
+  BatchInsertResult,
+  StartupStatus,
+  Theme,
+  ThemeState,
+  CrashReportInfo,
+  FocusModeStatus,
+  KeepAwakeStatus,
+  PasswordOptions,
+  GeneratedPassword,
+  TotpAccount,
+  NewTotpAccount,
+  TotpCode,
+  ClockEntry,
+  TimeConversion,
+  QuoteResult,
+  Feed,
+  FeedItem,
+  Timer,
+  TimerKind,
+  ReminderPreview,
+  Calendar,
+  CalendarEvent,
+  Habit,
+  CursorInfo,
+  ScreenMeasurement,
+  TextVariable,
+  SyncConfig,
+  SyncConfigInput,
+  SyncSummary,
+  SyncStatus,
+  SyncConflict,
+  GitSyncConfig,
+  GitSyncStatus,
+  GitCommitResult,
+  GitPullResult,
+  BrowserBridgeStatus,
+  PhoneShare,
+  PasteConfigView,
+  PasteConfigInput,
+  PasteExpiry,
+  PasteLink,
+  ScriptInfo,
+  ScriptRunResult,
+  Workflow,
+  WorkflowRunResult,
+  WorkflowImportResult,
+  NetworkStatus,
+  PromptTemplate,
+  ClipboardAiAction,
+  SemanticMatch,
+  NotesAnswer,
+  ChatConversation,
+  ChatStoredMessage,
+  ChatMessageMatch,
+  ProxySettings,
+  ScoringWeights,
+  ProviderSettings,
+  ResultPreview,
+  ArchiveListing,
+  FolderSizeNode,
+  HotFolderFile,
+  NetworkInfo,
+  PingResult,
+  PortCheckResult,
+  SystemStats,
+  AudioDevice,
+  AudioVolumeState,
+  MonitorInfo,
+  DisplayMode,
+  DisplayPreset,
+  RadioStatus,
+  VirtualDesktop,
+  SystemProxy,
+  UsageSummary,
 } from "../types";
 
 export const tauriApi = {
@@ -41,8 +137,8 @@ export const tauriApi = {
     return invoke("delete_recording", { path });
   },
 
-  async playRecording(path: string, speed: number): Promise<void> {
-    return invoke("play_recording", { path, speed });
+  async playRecording(path: string, speed: number, injection?: InjectionMode): Promise<void> {
+    return invoke("play_recording", { path, speed, injection });
   },
 
   async stopPlayback(): Promise<void> {
@@ -65,6 +161,11 @@ export const tauriApi = {
     return invoke("rescan_applications");
   },
 
+  // 后台刷新应用索引（扫描期间保留旧索引），结果通过 app-index-* 事件通知
+  async refreshAppIndex(): Promise<void> {
+    return invoke("refresh_app_index");
+  },
+
   async testUwpAppsScan(): Promise<AppInfo[]> {
     return invoke("test_uwp_apps_scan");
   },
@@ -147,6 +248,138 @@ export const tauriApi = {
     return invoke("check_path_exists", { path });
   },
 
+  async completePath(partial: string): Promise<PathCompletion[]> {
+    return invoke("complete_path", { partial });
+  },
+
+  async expandPath(path: string): Promise<string> {
+    return invoke("expand_path", { path });
+  },
+
+  async getOpenWithActions(): Promise<OpenWithAction[]> {
+    return invoke("get_open_with_actions");
+  },
+
+  async saveOpenWithActions(actions: OpenWithAction[]): Promise<void> {
+    return invoke("save_open_with_actions", { actions });
+  },
+
+  async openFolderWith(actionId: string, path: string): Promise<void> {
+    return invoke("open_folder_with", { actionId, path });
+  },
+
+  async getSshHosts(): Promise<SshHost[]> {
+    return invoke("get_ssh_hosts");
+  },
+
+  async searchSshHosts(query: string): Promise<SshHost[]> {
+    return invoke("search_ssh_hosts", { query });
+  },
+
+  async connectSshHost(host: string): Promise<void> {
+    return invoke("connect_ssh_host", { host });
+  },
+
+  async getGitRepos(): Promise<GitRepo[]> {
+    return invoke("get_git_repos");
+  },
+
+  async searchGitRepos(query: string): Promise<GitRepo[]> {
+    return invoke("search_git_repos", { query });
+  },
+
+  async refreshGitRepos(): Promise<GitRepoRefreshResult> {
+    return invoke("refresh_git_repos");
+  },
+
+  async getRecentProjects(): Promise<RecentProject[]> {
+    return invoke("get_recent_projects");
+  },
+
+  async searchRecentProjects(query: string): Promise<RecentProject[]> {
+    return invoke("search_recent_projects", { query });
+  },
+
+  async openRecentProject(project: RecentProject): Promise<void> {
+    return invoke("open_recent_project", { project });
+  },
+
+  async searchBrowserHistory(query: string, limit?: number): Promise<BrowserHistoryItem[]> {
+    return invoke("search_browser_history", { query, limit });
+  },
+
+  async searchGames(query: string): Promise<GameInfo[]> {
+    return invoke("search_games", { query });
+  },
+
+  async refreshGames(): Promise<GameInfo[]> {
+    return invoke("refresh_games");
+  },
+
+  async launchGame(launchUri: string): Promise<void> {
+    return invoke("launch_game", { launchUri });
+  },
+
+  async previewLauncherImport(source: LauncherImportSource, path?: string): Promise<LauncherImportPreview> {
+    return invoke("preview_launcher_import", { source, path });
+  },
+
+  async applyLauncherImport(preview: LauncherImportPreview): Promise<LauncherImportResult> {
+    return invoke("apply_launcher_import", { preview });
+  },
+
+  async recordActivity(event: ActivityEvent): Promise<void> {
+    return invoke("record_activity", { event });
+  },
+
+  async getActivityStats(days?: number): Promise<ActivityStats> {
+    return invoke("get_activity_stats", { days });
+  },
+
+  async purgeActivityLog(): Promise<number> {
+    return invoke("purge_activity_log");
+  },
+
+  async getRankingBoosts(query: string): Promise<RankingBoost[]> {
+    return invoke("get_ranking_boosts", { query });
+  },
+
+  async addQueryHistory(query: string): Promise<void> {
+    return invoke("add_query_history", { query });
+  },
+
+  async getQueryHistory(limit?: number): Promise<QueryHistoryEntry[]> {
+    return invoke("get_query_history", { limit });
+  },
+
+  async navigateQueryHistory(prefix: string, offset: number): Promise<string | null> {
+    return invoke("navigate_query_history", { prefix, offset });
+  },
+
+  async suggestQueryCompletions(prefix: string, limit?: number): Promise<QueryHistoryEntry[]> {
+    return invoke("suggest_query_completions", { prefix, limit });
+  },
+
+  async deleteQueryHistory(query: string): Promise<void> {
+    return invoke("delete_query_history", { query });
+  },
+
+  async clearQueryHistory(): Promise<number> {
+    return invoke("clear_query_history");
+  },
+
+  async getWebSuggestions(prefix: string, keyword: string): Promise<string[]> {
+    return invoke("get_web_suggestions", { prefix, keyword });
+  },
+
+  async getFavicon(url: string): Promise<string | null> {
+    return invoke("get_favicon", { url });
+  },
+
+  async clearFaviconCache(): Promise<number> {
+    return invoke("clear_favicon_cache");
+  },
+
   async getClipboardFilePath(): Promise<string | null> {
     return invoke("get_clipboard_file_path");
   },
@@ -248,6 +481,487 @@ export const tauriApi = {
     return invoke("save_everything_custom_filters", { filters });
   },
 
+  async getStartupStatus(): Promise<StartupStatus> {
+    return invoke("get_startup_status");
+  },
+
+  async getFocusModeStatus(): Promise<FocusModeStatus> {
+    return invoke("get_focus_mode_status");
+  },
+
+  async setFocusMode(enabled: boolean): Promise<FocusModeStatus> {
+    return invoke("set_focus_mode", { enabled });
+  },
+
+  async transformText(action: string, source: "clipboard" | "selection"): Promise<string> {
+    return invoke("transform_text", { action, source });
+  },
+
+  async generatePassword(options: PasswordOptions): Promise<GeneratedPassword> {
+    return invoke("generate_password", { options });
+  },
+
+  // 以敏感内容复制（不记入剪切板历史），返回自动清除前的秒数
+  async copyPassword(password: string): Promise<number> {
+    return invoke("copy_password", { password });
+  },
+
+  async listTotpAccounts(): Promise<TotpAccount[]> {
+    return invoke("list_totp_accounts");
+  },
+
+  async addTotpAccount(account: NewTotpAccount): Promise<TotpAccount> {
+    return invoke("add_totp_account", { account });
+  },
+
+  async removeTotpAccount(id: string): Promise<void> {
+    return invoke("remove_totp_account", { id });
+  },
+
+  async getTotpCodes(query?: string): Promise<TotpCode[]> {
+    return invoke("get_totp_codes", { query: query ?? null });
+  },
+
+  // 以敏感内容复制当前验证码，验证码过期时自动从剪贴板清除
+  async copyTotpCode(id: string): Promise<TotpCode> {
+    return invoke("copy_totp_code", { id });
+  },
+
+  // places 为空时返回设置中的常用城市
+  async getWorldClocks(places?: string[]): Promise<ClockEntry[]> {
+    return invoke("get_world_clocks", { places: places ?? null });
+  },
+
+  async convertWorldTime(time: string, from: string, to: string[]): Promise<TimeConversion> {
+    return invoke("convert_world_time", { time, from, to });
+  },
+
+  // symbols 为空时返回关注列表，force 为 true 时忽略缓存
+  async getQuotes(symbols?: string[], force = false): Promise<QuoteResult[]> {
+    return invoke("get_quotes", { symbols: symbols ?? null, force });
+  },
+
+  async listRssFeeds(): Promise<Feed[]> {
+    return invoke("list_rss_feeds");
+  },
+
+  async addRssFeed(url: string): Promise<Feed> {
+    return invoke("add_rss_feed", { url });
+  },
+
+  async removeRssFeed(id: number): Promise<void> {
+    return invoke("remove_rss_feed", { id });
+  },
+
+  async getRssItems(query?: string, unreadOnly = true, limit?: number): Promise<FeedItem[]> {
+    return invoke("get_rss_items", { query: query ?? null, unreadOnly, limit: limit ?? null });
+  },
+
+  async markRssItemsRead(ids: number[]): Promise<void> {
+    return invoke("mark_rss_items_read", { ids });
+  },
+
+  // 立即抓取所有订阅，返回新增条目数
+  async refreshRssFeeds(): Promise<number> {
+    return invoke("refresh_rss_feeds");
+  },
+
+  async listTimers(): Promise<Timer[]> {
+    return invoke("list_timers");
+  },
+
+  // spec 为倒计时时长（"10m"、"1h30m"、"5:00"）或闹钟时刻（"7:30"、"下午3点"）
+  async createTimer(kind: TimerKind, spec: string, label?: string, sound = true): Promise<Timer> {
+    return invoke("create_timer", { kind, spec, label: label ?? null, sound });
+  },
+
+  async cancelTimer(id: number): Promise<void> {
+    return invoke("cancel_timer", { id });
+  },
+
+  // 解析 "明天上午9点提醒我交报告"、"every monday 9am team sync" 等输入，不创建
+  async previewReminder(text: string): Promise<ReminderPreview> {
+    return invoke("preview_reminder", { text });
+  },
+
+  async createReminder(text: string, sound = true): Promise<Timer> {
+    return invoke("create_reminder", { text, sound });
+  },
+
+  async listCalendars(): Promise<Calendar[]> {
+    return invoke("list_calendars");
+  },
+
+  // source 为 ICS 地址（http/https/webcal）或本地 .ics 文件路径
+  async addCalendar(source: string): Promise<Calendar> {
+    return invoke("add_calendar", { source });
+  },
+
+  async removeCalendar(id: number): Promise<void> {
+    return invoke("remove_calendar", { id });
+  },
+
+  async getTodayEvents(): Promise<CalendarEvent[]> {
+    return invoke("get_today_events");
+  },
+
+  async getUpcomingEvents(limit?: number): Promise<CalendarEvent[]> {
+    return invoke("get_upcoming_events", { limit: limit ?? null });
+  },
+
+  // 立即刷新所有日历，返回刷新成功的数量
+  async refreshCalendars(): Promise<number> {
+    return invoke("refresh_calendars");
+  },
+
+  async listHabits(): Promise<Habit[]> {
+    return invoke("list_habits");
+  },
+
+  // input 为 "名称 [计划]"，计划如 "每天"、"工作日"、"每周3次"、"周一三五"
+  async addHabit(input: string): Promise<Habit> {
+    return invoke("add_habit", { input });
+  },
+
+  async removeHabit(id: number): Promise<void> {
+    return invoke("remove_habit", { id });
+  },
+
+  async checkInHabit(id: number): Promise<Habit> {
+    return invoke("check_in_habit", { id });
+  },
+
+  async undoHabitCheckIn(id: number): Promise<Habit> {
+    return invoke("undo_habit_check_in", { id });
+  },
+
+  async getCursorInfo(): Promise<CursorInfo> {
+    return invoke("get_cursor_info");
+  },
+
+  async measureScreenDistance(fromX: number, fromY: number, toX: number, toY: number): Promise<ScreenMeasurement> {
+    return invoke("measure_screen_distance", { fromX, fromY, toX, toY });
+  },
+
+  // 开启后通过 "cursor-inspector" 事件持续推送 CursorInfo
+  async startCursorInspector(intervalMs?: number): Promise<void> {
+    return invoke("start_cursor_inspector", { intervalMs: intervalMs ?? null });
+  },
+
+  async stopCursorInspector(): Promise<boolean> {
+    return invoke("stop_cursor_inspector");
+  },
+
+  // 展开 {date}、{clip}、{uuid} 等文本变量；urlEncode 时替换的值做 URL 编码
+  async renderTextVariables(text: string, options?: { query?: string; urlEncode?: boolean }): Promise<string> {
+    return invoke("render_text_variables", {
+      text,
+      query: options?.query ?? null,
+      urlEncode: options?.urlEncode ?? null,
+    });
+  },
+
+  async listTextVariables(): Promise<TextVariable[]> {
+    return invoke("list_text_variables");
+  },
+
+  async getSyncConfig(): Promise<SyncConfig> {
+    return invoke("get_sync_config");
+  },
+
+  async saveSyncConfig(config: SyncConfigInput): Promise<SyncConfig> {
+    return invoke("save_sync_config", { config });
+  },
+
+  async syncNow(): Promise<SyncSummary> {
+    return invoke("sync_now");
+  },
+
+  async getSyncStatus(): Promise<SyncStatus> {
+    return invoke("get_sync_status");
+  },
+
+  async listSyncConflicts(limit?: number): Promise<SyncConflict[]> {
+    return invoke("list_sync_conflicts", { limit });
+  },
+
+  async clearSyncConflicts(): Promise<void> {
+    return invoke("clear_sync_conflicts");
+  },
+
+  async getGitSyncStatus(): Promise<GitSyncStatus> {
+    return invoke("get_git_sync_status");
+  },
+
+  async saveGitSyncConfig(config: GitSyncConfig): Promise<GitSyncStatus> {
+    return invoke("save_git_sync_config", { config });
+  },
+
+  async gitSyncCommit(message: string | null, push: boolean): Promise<GitCommitResult> {
+    return invoke("git_sync_commit", { message, push });
+  },
+
+  async gitSyncPull(): Promise<GitPullResult> {
+    return invoke("git_sync_pull");
+  },
+
+  async getBrowserBridgeStatus(): Promise<BrowserBridgeStatus> {
+    return invoke("get_browser_bridge_status");
+  },
+
+  async registerBrowserBridge(extensionIds: string[]): Promise<BrowserBridgeStatus> {
+    return invoke("register_browser_bridge", { extensionIds });
+  },
+
+  async unregisterBrowserBridge(): Promise<BrowserBridgeStatus> {
+    return invoke("unregister_browser_bridge");
+  },
+
+  async createPhoneShare(itemId: string, expireMinutes?: number): Promise<PhoneShare> {
+    return invoke("create_phone_share", { itemId, expireMinutes });
+  },
+
+  async listPhoneShares(): Promise<PhoneShare[]> {
+    return invoke("list_phone_shares");
+  },
+
+  async revokePhoneShare(id: string): Promise<void> {
+    return invoke("revoke_phone_share", { id });
+  },
+
+  async getPasteConfig(): Promise<PasteConfigView> {
+    return invoke("get_paste_config");
+  },
+
+  async savePasteConfig(input: PasteConfigInput): Promise<PasteConfigView> {
+    return invoke("save_paste_config", { input });
+  },
+
+  async createPasteLink(itemId: string, expiry?: PasteExpiry): Promise<PasteLink> {
+    return invoke("create_paste_link", { itemId, expiry });
+  },
+
+  async listPasteLinks(): Promise<PasteLink[]> {
+    return invoke("list_paste_links");
+  },
+
+  async revokePasteLink(id: string): Promise<void> {
+    return invoke("revoke_paste_link", { id });
+  },
+
+  async clearPasteLinks(): Promise<number> {
+    return invoke("clear_paste_links");
+  },
+
+  async listScripts(): Promise<ScriptInfo[]> {
+    return invoke("list_scripts");
+  },
+
+  async runScript(id: string, input?: string): Promise<ScriptRunResult> {
+    return invoke("run_script", { id, input });
+  },
+
+  async createScript(name: string, keyword?: string): Promise<string> {
+    return invoke("create_script", { name, keyword });
+  },
+
+  async openScriptsFolder(): Promise<void> {
+    return invoke("open_scripts_folder");
+  },
+
+  async getScriptHttpAllow(): Promise<Record<string, string[]>> {
+    return invoke("get_script_http_allow");
+  },
+
+  async setScriptHttpAllow(id: string, hosts: string[]): Promise<void> {
+    return invoke("set_script_http_allow", { id, hosts });
+  },
+
+  async listWorkflows(): Promise<Workflow[]> {
+    return invoke("list_workflows");
+  },
+
+  async saveWorkflow(workflow: Workflow): Promise<Workflow> {
+    return invoke("save_workflow", { workflow });
+  },
+
+  async deleteWorkflow(id: string): Promise<void> {
+    return invoke("delete_workflow", { id });
+  },
+
+  async runWorkflow(id: string, input?: string): Promise<WorkflowRunResult> {
+    return invoke("run_workflow", { id, input });
+  },
+
+  async exportWorkflows(path: string, ids?: string[]): Promise<void> {
+    return invoke("export_workflows", { path, ids });
+  },
+
+  async importWorkflows(path: string): Promise<WorkflowImportResult> {
+    return invoke("import_workflows", { path });
+  },
+
+  async getKeepAwakeStatus(): Promise<KeepAwakeStatus> {
+    return invoke("get_keep_awake_status");
+  },
+
+  async startKeepAwake(minutes: number | null, keepDisplay = true): Promise<KeepAwakeStatus> {
+    return invoke("start_keep_awake", { minutes, keepDisplay });
+  },
+
+  async stopKeepAwake(): Promise<KeepAwakeStatus> {
+    return invoke("stop_keep_awake");
+  },
+
+  async getNetworkStatus(): Promise<NetworkStatus> {
+    return invoke("get_network_status");
+  },
+
+  async checkNetworkStatus(): Promise<NetworkStatus> {
+    return invoke("check_network_status");
+  },
+
+  async getSystemProxy(): Promise<SystemProxy | null> {
+    return invoke("get_system_proxy");
+  },
+
+  async llmChat(
+    request: {
+      base_url: string;
+      api_key?: string;
+      model: string;
+      messages: Array<{ role: string; content: string }>;
+      stream?: boolean;
+      max_tokens?: number;
+      // 回答缓存：use 优先返回缓存，refresh 重新请求并更新缓存
+      cache?: "none" | "use" | "refresh";
+      // 发起请求的功能，用于用量统计
+      feature?: string;
+      // 使用提示词模板生成消息：按 feature 取当前模板，用 variables 渲染
+      prompt?: { feature: string; variables: Record<string, string> };
+      // 保存到对话记录
+      save_history?: boolean;
+    },
+    onDelta?: (delta: string) => void
+  ): Promise<string> {
+    const channel = new Channel<string>();
+    channel.onmessage = (delta) => onDelta?.(delta);
+    return invoke("llm_chat", { request, onDelta: channel });
+  },
+
+  /**
+   * 对剪切板文本执行 AI 操作，输出逐段回调 onDelta，完成后返回保存为新剪切板项的结果
+   * source.itemId 为剪切板项 id，或通过 source.text 直接传入文本
+   */
+  async clipboardAiAction(
+    action: ClipboardAiAction,
+    source: { itemId?: string; text?: string; targetLang?: string },
+    onDelta?: (delta: string) => void
+  ): Promise<ClipboardItem> {
+    const channel = new Channel<string>();
+    channel.onmessage = (delta) => onDelta?.(delta);
+    return invoke("clipboard_ai_action", {
+      action,
+      itemId: source.itemId ?? null,
+      text: source.text ?? null,
+      targetLang: source.targetLang ?? null,
+      onDelta: channel,
+    });
+  },
+
+  /**
+   * 问笔记：根据备忘录和单词记录回答问题，回答逐段回调 onDelta，完成后返回回答和引用的资料
+   */
+  async askNotes(question: string, onDelta?: (delta: string) => void): Promise<NotesAnswer> {
+    const channel = new Channel<string>();
+    channel.onmessage = (delta) => onDelta?.(delta);
+    return invoke("ask_notes", { question, onDelta: channel });
+  },
+
+  async listChatConversations(): Promise<ChatConversation[]> {
+    return invoke("list_chat_conversations");
+  },
+
+  async getChatMessages(id: string): Promise<ChatStoredMessage[]> {
+    return invoke("get_chat_messages", { id });
+  },
+
+  async searchChatMessages(query: string, limit?: number): Promise<ChatMessageMatch[]> {
+    return invoke("search_chat_messages", { query, limit: limit ?? null });
+  },
+
+  async setChatConversationPinned(id: string, pinned: boolean): Promise<void> {
+    return invoke("set_chat_conversation_pinned", { id, pinned });
+  },
+
+  async deleteChatConversation(id: string): Promise<void> {
+    return invoke("delete_chat_conversation", { id });
+  },
+
+  async exportChatConversation(id: string, path: string): Promise<void> {
+    return invoke("export_chat_conversation", { id, path });
+  },
+
+  async semanticSearchClipboard(query: string, limit?: number): Promise<SemanticMatch[]> {
+    return invoke("semantic_search_clipboard", { query, limit: limit ?? null });
+  },
+
+  async semanticSearchMemos(query: string, limit?: number): Promise<SemanticMatch<MemoItem>[]> {
+    return invoke("semantic_search_memos", { query, limit: limit ?? null });
+  },
+
+  async clearLlmCache(): Promise<number> {
+    return invoke("clear_llm_cache");
+  },
+
+  async getLlmUsageSummary(month?: string): Promise<UsageSummary> {
+    return invoke("get_llm_usage_summary", { month: month ?? null });
+  },
+
+  async listPromptTemplates(): Promise<PromptTemplate[]> {
+    return invoke("list_prompt_templates");
+  },
+
+  async savePromptTemplate(template: PromptTemplate): Promise<PromptTemplate> {
+    return invoke("save_prompt_template", { template });
+  },
+
+  async deletePromptTemplate(id: string): Promise<void> {
+    return invoke("delete_prompt_template", { id });
+  },
+
+  async setActivePromptTemplate(feature: string, id: string): Promise<void> {
+    return invoke("set_active_prompt_template", { feature, id });
+  },
+
+  async listCrashReports(): Promise<CrashReportInfo[]> {
+    return invoke("list_crash_reports");
+  },
+
+  async createBugReport(): Promise<string> {
+    return invoke("create_bug_report");
+  },
+
+  async listThemes(): Promise<Theme[]> {
+    return invoke("list_themes");
+  },
+
+  async getTheme(): Promise<ThemeState> {
+    return invoke("get_theme");
+  },
+
+  async setTheme(id: string): Promise<ThemeState> {
+    return invoke("set_theme", { id });
+  },
+
+  async saveCustomTheme(json: string): Promise<Theme> {
+    return invoke("save_custom_theme", { json });
+  },
+
+  async deleteCustomTheme(id: string): Promise<void> {
+    return invoke("delete_custom_theme", { id });
+  },
+
   async getIndexStatus(): Promise<IndexStatus> {
     return invoke("get_index_status");
   },
@@ -351,6 +1065,16 @@ export const tauriApi = {
     return invoke("update_memo", { id, title, content });
   },
 
+  async setMemoHotstring(
+    id: string,
+    hotstring: string | null,
+    caseSensitive: boolean,
+    apps?: string[],
+    injection?: InjectionMode
+  ): Promise<MemoItem> {
+    return invoke("set_memo_hotstring", { id, hotstring, caseSensitive, apps, injection });
+  },
+
   async deleteMemo(id: string): Promise<void> {
     return invoke("delete_memo", { id });
   },
@@ -541,14 +1265,138 @@ export const tauriApi = {
 
 
   // Settings APIs
-  async getSettings(): Promise<{ llm: { model: string; base_url: string; api_key?: string }; startup_enabled?: boolean; result_style?: "compact" | "soft" | "skeuomorphic" | "m3"; close_on_blur?: boolean; auto_check_update?: boolean; clipboard_max_items?: number; translation_tab_order?: string[]; search_engines?: SearchEngineConfig[] }> {
+  async getSettings(): Promise<{ llm: { model: string; base_url: string; api_key?: string; monthly_budget?: number | null; embedding_model?: string | null; chat_retention_days?: number }; startup_enabled?: boolean; result_style?: "compact" | "soft" | "skeuomorphic" | "m3"; close_on_blur?: boolean; auto_check_update?: boolean; clipboard_max_items?: number; clipboard_max_age_days?: number; clipboard_mask_sensitive?: boolean; clipboard_sensitive_expire_minutes?: number; clipboard_dedup_collapse_whitespace?: boolean; translation_tab_order?: string[]; search_engines?: SearchEngineConfig[]; open_with_actions?: OpenWithAction[]; git_repo_roots?: string[]; games_provider_enabled?: boolean; aliases?: AliasConfig[]; analytics_enabled?: boolean; analytics_retention_days?: number; web_suggestions_enabled?: boolean; theme?: string; locale?: string; dnd_auto_detect?: boolean; proxy?: ProxySettings; scoring_weights?: Record<string, ScoringWeights>; providers?: Record<string, ProviderSettings>; hot_folders?: string[]; display_presets?: DisplayPreset[]; world_clock_cities?: string[]; quote_watchlist?: string[]; quote_refresh_secs?: number }> {
     return invoke("get_settings");
   },
 
-  async saveSettings(settings: { llm: { model: string; base_url: string; api_key?: string }; startup_enabled?: boolean; result_style?: "compact" | "soft" | "skeuomorphic" | "m3"; close_on_blur?: boolean; clipboard_max_items?: number; translation_tab_order?: string[]; search_engines?: SearchEngineConfig[] }): Promise<void> {
+  async saveSettings(settings: { llm: { model: string; base_url: string; api_key?: string; monthly_budget?: number | null; embedding_model?: string | null; chat_retention_days?: number }; startup_enabled?: boolean; result_style?: "compact" | "soft" | "skeuomorphic" | "m3"; close_on_blur?: boolean; clipboard_max_items?: number; clipboard_max_age_days?: number; clipboard_mask_sensitive?: boolean; clipboard_sensitive_expire_minutes?: number; clipboard_dedup_collapse_whitespace?: boolean; translation_tab_order?: string[]; search_engines?: SearchEngineConfig[]; open_with_actions?: OpenWithAction[]; git_repo_roots?: string[]; games_provider_enabled?: boolean; aliases?: AliasConfig[]; analytics_enabled?: boolean; analytics_retention_days?: number; web_suggestions_enabled?: boolean; theme?: string; locale?: string; dnd_auto_detect?: boolean; proxy?: ProxySettings; scoring_weights?: Record<string, ScoringWeights>; providers?: Record<string, ProviderSettings>; hot_folders?: string[]; display_presets?: DisplayPreset[]; world_clock_cities?: string[]; quote_watchlist?: string[]; quote_refresh_secs?: number }): Promise<void> {
     return invoke("save_settings", { settings });
   },
 
+  async getSearchProviders(): Promise<Record<string, ProviderSettings>> {
+    return invoke("get_search_providers");
+  },
+
+  async getResultPreview(kind: "path" | "word", target: string): Promise<ResultPreview | null> {
+    return invoke("get_result_preview", { kind, target });
+  },
+
+  async listArchiveEntries(path: string): Promise<ArchiveListing> {
+    return invoke("list_archive_entries", { path });
+  },
+
+  async openArchiveEntry(path: string, entry: string): Promise<string> {
+    return invoke("open_archive_entry", { path, entry });
+  },
+
+  async scanFolderSizes(path: string): Promise<FolderSizeNode> {
+    return invoke("scan_folder_sizes", { path });
+  },
+
+  async cancelFolderScan(): Promise<boolean> {
+    return invoke("cancel_folder_scan");
+  },
+
+  async getHotFolderRecentFiles(limit?: number): Promise<HotFolderFile[]> {
+    return invoke("get_hot_folder_recent_files", { limit });
+  },
+
+  async getNetworkInfo(): Promise<NetworkInfo> {
+    return invoke("get_network_info");
+  },
+
+  async getPublicIp(): Promise<string> {
+    return invoke("get_public_ip");
+  },
+
+  async pingHost(host: string): Promise<PingResult> {
+    return invoke("ping_host", { host });
+  },
+
+  async checkPort(host: string, port: number): Promise<PortCheckResult> {
+    return invoke("check_port", { host, port });
+  },
+
+  async getSystemStats(): Promise<SystemStats> {
+    return invoke("get_system_stats");
+  },
+
+  async listAudioDevices(): Promise<AudioDevice[]> {
+    return invoke("list_audio_devices");
+  },
+
+  async switchAudioDevice(target: string): Promise<AudioDevice> {
+    return invoke("switch_audio_device", { target });
+  },
+
+  async getAudioVolume(): Promise<AudioVolumeState> {
+    return invoke("get_audio_volume");
+  },
+
+  async setAudioVolume(volume: number): Promise<void> {
+    return invoke("set_audio_volume", { volume });
+  },
+
+  async setAudioMute(muted: boolean): Promise<void> {
+    return invoke("set_audio_mute", { muted });
+  },
+
+  async listMonitors(): Promise<MonitorInfo[]> {
+    return invoke("list_monitors");
+  },
+
+  async setMonitorBrightness(monitor: string, brightness: number): Promise<void> {
+    return invoke("set_monitor_brightness", { monitor, brightness });
+  },
+
+  async listDisplayModes(monitor: string): Promise<DisplayMode[]> {
+    return invoke("list_display_modes", { monitor });
+  },
+
+  async setDisplayMode(monitor: string, width: number, height: number, frequency?: number | null): Promise<DisplayMode> {
+    return invoke("set_display_mode", { monitor, width, height, frequency: frequency ?? null });
+  },
+
+  async applyDisplayPreset(name: string): Promise<DisplayMode> {
+    return invoke("apply_display_preset", { name });
+  },
+
+  async getNightLight(): Promise<boolean> {
+    return invoke("get_night_light");
+  },
+
+  async setNightLight(enabled: boolean): Promise<boolean> {
+    return invoke("set_night_light", { enabled });
+  },
+
+  async getRadioStatus(): Promise<RadioStatus> {
+    return invoke("get_radio_status");
+  },
+
+  async setRadioState(kind: "wifi" | "bluetooth", enabled: boolean): Promise<void> {
+    return invoke("set_radio_state", { kind, enabled });
+  },
+
+  async setAirplaneMode(enabled: boolean): Promise<void> {
+    return invoke("set_airplane_mode", { enabled });
+  },
+
+  async runWindowAction(action: string): Promise<void> {
+    return invoke("run_window_action", { action });
+  },
+
+  async listVirtualDesktops(): Promise<VirtualDesktop[]> {
+    return invoke("list_virtual_desktops");
+  },
+
+  async switchVirtualDesktop(index: number): Promise<void> {
+    return invoke("switch_virtual_desktop", { index });
+  },
+
+  async moveWindowToVirtualDesktop(index: number): Promise<void> {
+    return invoke("move_window_to_virtual_desktop", { index });
+  },
+
 
   // Startup APIs
   async isStartupEnabled(): Promise<boolean> {
@@ -585,7 +1433,10 @@ export const tauriApi = {
     return invoke("save_plugin_hotkeys", { pluginHotkeys });
   },
 
-  async savePluginHotkey(pluginId: string, config: { modifiers: string[]; key: string } | null): Promise<void> {
+  async savePluginHotkey(
+    pluginId: string,
+    config: { modifiers: string[]; key: string; apps?: string[] } | null
+  ): Promise<void> {
     return invoke("save_plugin_hotkey", { pluginId, config });
   },
 
@@ -594,10 +1445,22 @@ export const tauriApi = {
     return invoke("get_app_hotkeys");
   },
 
-  async saveAppHotkey(appPath: string, config: { modifiers: string[]; key: string } | null): Promise<void> {
+  async saveAppHotkey(
+    appPath: string,
+    config: { modifiers: string[]; key: string; apps?: string[] } | null
+  ): Promise<void> {
     return invoke("save_app_hotkey", { appPath, config });
   },
 
+  // Window management hotkey APIs
+  async getWindowHotkeys(): Promise<Record<string, { modifiers: string[]; key: string }>> {
+    return invoke("get_window_hotkeys");
+  },
+
+  async saveWindowHotkey(action: string, config: { modifiers: string[]; key: string } | null): Promise<void> {
+    return invoke("save_window_hotkey", { action, config });
+  },
+
   // App center hotkey APIs
   async getAppCenterHotkey(): Promise<{ modifiers: string[]; key: string } | null> {
     return invoke("get_app_center_hotkey");
@@ -633,6 +1496,10 @@ export const tauriApi = {
   },
 
   // Clipboard APIs
+  async revealClipboardItem(id: string): Promise<ClipboardItem> {
+    return invoke("reveal_clipboard_item", { id });
+  },
+
   async getAllClipboardItems(): Promise<ClipboardItem[]> {
     return invoke("get_all_clipboard_items");
   },
@@ -649,6 +1516,45 @@ export const tauriApi = {
     return invoke("toggle_favorite_clipboard_item", { id });
   },
 
+  // 收藏的剪切板项，按手动排序排列
+  async getFavoriteClipboardItems(): Promise<ClipboardItem[]> {
+    return invoke("get_favorite_clipboard_items");
+  },
+
+  async reorderFavoriteClipboardItems(ids: string[]): Promise<ClipboardItem[]> {
+    return invoke("reorder_favorite_clipboard_items", { ids });
+  },
+
+  // 置顶的项在列表最前面按手动顺序排列，与收藏相互独立
+  async pinClipboardItem(id: string, pinned: boolean): Promise<ClipboardItem> {
+    return invoke("pin_clipboard_item", { id, pinned });
+  },
+
+  async reorderPinnedClipboardItems(ids: string[]): Promise<ClipboardItem[]> {
+    return invoke("reorder_pinned_clipboard_items", { ids });
+  },
+
+  // 批量操作（单个事务），返回受影响的条数
+  async deleteClipboardItems(ids: string[]): Promise<number> {
+    return invoke("delete_clipboard_items", { ids });
+  },
+
+  async setClipboardItemsFavorite(ids: string[], favorite: boolean): Promise<number> {
+    return invoke("set_clipboard_items_favorite", { ids, favorite });
+  },
+
+  async tagClipboardItems(ids: string[], tags: string[], replace = false): Promise<number> {
+    return invoke("tag_clipboard_items", { ids, tags, replace });
+  },
+
+  async setClipboardItemTags(id: string, tags: string[]): Promise<ClipboardItem> {
+    return invoke("set_clipboard_item_tags", { id, tags });
+  },
+
+  async listClipboardTags(): Promise<ClipboardTag[]> {
+    return invoke("list_clipboard_tags");
+  },
+
   async deleteClipboardItem(id: string): Promise<void> {
     return invoke("delete_clipboard_item", { id });
   },
@@ -661,6 +1567,18 @@ export const tauriApi = {
     return invoke("search_clipboard_items", { query });
   },
 
+  async importClipboardItems(items: ClipboardItem[], mode?: ConflictMode): Promise<BatchInsertResult> {
+    return invoke("import_clipboard_items", { items, mode });
+  },
+
+  async getMonitorStatus(): Promise<ClipboardMonitorStatus> {
+    return invoke("get_monitor_status");
+  },
+
+  async restartMonitor(): Promise<void> {
+    return invoke("restart_monitor");
+  },
+
   async showClipboardWindow(): Promise<void> {
     return invoke("show_clipboard_window");
   },
@@ -673,6 +1591,22 @@ export const tauriApi = {
     return invoke("copy_image_to_clipboard", { imagePath });
   },
 
+  async copyFilesToClipboard(paths: string[]): Promise<void> {
+    return invoke("copy_files_to_clipboard", { paths });
+  },
+
+  async copyRichTextToClipboard(itemId: string): Promise<void> {
+    return invoke("copy_rich_text_to_clipboard", { itemId });
+  },
+
+  async setClipboardMulti(content: ClipboardContent): Promise<void> {
+    return invoke("set_clipboard_multi", { content });
+  },
+
+  async copyClipboardItemAs(itemId: string, format: ClipboardCopyFormat): Promise<void> {
+    return invoke("copy_clipboard_item_as", { itemId, format });
+  },
+
   // Word Record APIs
   async getAllWordRecords(): Promise<WordRecord[]> {
     return invoke("get_all_word_records");
@@ -728,6 +1662,10 @@ export const tauriApi = {
     return invoke("delete_word_record", { id });
   },
 
+  async importWordRecords(records: WordRecord[], mode?: ConflictMode): Promise<BatchInsertResult> {
+    return invoke("import_word_records", { records, mode });
+  },
+
   async searchWordRecords(query: string): Promise<WordRecord[]> {
     return invoke("search_word_records", { query });
   },
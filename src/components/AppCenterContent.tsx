@@ -1,6 +1,6 @@
 import { useState, useMemo, useEffect, useRef, useCallback } from "react";
 import { plugins, executePlugin } from "../plugins";
-import type { PluginContext, IndexStatus, DatabaseBackupInfo, PluginUsage } from "../types";
+import type { PluginContext, IndexStatus, DatabaseBackupInfo, PluginUsage, ProxySettings, ScoringWeights, ProviderSettings, DisplayPreset } from "../types";
 import { tauriApi } from "../api/tauri";
 import { listen, emit } from "@tauri-apps/api/event";
 import { AiSettingsPage, SystemSettingsPage, AboutSettingsPage, LauncherSettingsPage } from "./SettingsPages";
@@ -10,6 +10,7 @@ import { AppIndexList } from "./AppIndexList";
 import { FileHistoryPanel } from "./FileHistoryPanel";
 import { formatSimpleDateTime } from "../utils/dateUtils";
 import { formatBytes, withTimeout } from "../utils/formatUtils";
+import { describeApiError } from "../utils/apiError";
 import { PluginsTab } from "./AppCenterContent/PluginsTab";
 import {
   Chart as ChartJS,
@@ -50,10 +51,25 @@ interface Settings {
     model: string;
     base_url: string;
     api_key?: string;
+    monthly_budget?: number | null;
+    embedding_model?: string | null;
+    chat_retention_days?: number;
   };
   startup_enabled?: boolean;
   result_style?: "compact" | "soft" | "skeuomorphic" | "m3";
   close_on_blur?: boolean;
+  theme?: string;
+  locale?: string;
+  dnd_auto_detect?: boolean;
+  proxy?: ProxySettings;
+  scoring_weights?: Record<string, ScoringWeights>;
+  providers?: Record<string, ProviderSettings>;
+  hot_folders?: string[];
+  display_presets?: DisplayPreset[];
+  world_clock_cities?: string[];
+  quote_watchlist?: string[];
+  quote_refresh_secs?: number;
+  web_suggestions_enabled?: boolean;
   search_engines?: Array<{
     prefix: string;
     url: string;
@@ -690,11 +706,8 @@ export function AppCenterContent({ onPluginClick, onClose: _onClose }: AppCenter
     try {
       setIsSaving(true);
       setSaveMessage("正在保存...");
+      // 后端保存时会在开机启动设置变化后同步注册表
       await tauriApi.saveSettings(settings);
-      // 保存开机启动设置
-      if (settings.startup_enabled !== undefined) {
-        await tauriApi.setStartupEnabled(settings.startup_enabled);
-      }
       // 本地缓存样式，避免后端旧版本未持久化时丢失
       if (settings.result_style) {
         localStorage.setItem("result-style", settings.result_style);
@@ -755,43 +768,29 @@ export function AppCenterContent({ onPluginClick, onClose: _onClose }: AppCenter
     try {
       const baseUrl = (settings.llm.base_url || 'https://api.openai.com/v1').replace(/\/+$/, '');
       const model = settings.llm.model || 'gpt-3.5-turbo';
-      
-      const headers: Record<string, string> = {
-        'Content-Type': 'application/json',
-      };
-      if (settings.llm.api_key) {
-        headers['Authorization'] = `Bearer ${settings.llm.api_key}`;
-      }
 
-      const response = await fetch(`${baseUrl}/chat/completions`, {
-        method: 'POST',
-        headers,
-        body: JSON.stringify({
-          model: model,
-          messages: [
-            {
-              role: 'user',
-              content: 'Hi',
-            },
-          ],
-          max_tokens: 5,
-          stream: false,
-        }),
+      // 经由后端发送，与实际对话使用相同的代理设置
+      await tauriApi.llmChat({
+        base_url: baseUrl,
+        api_key: settings.llm.api_key,
+        model: model,
+        messages: [
+          {
+            role: 'user',
+            content: 'Hi',
+          },
+        ],
+        max_tokens: 5,
+        stream: false,
+        feature: 'connection_test',
       });
-
-      if (!response.ok) {
-        const errorBody = await response.text().catch(() => '');
-        throw new Error(`API 请求失败 (${response.status}): ${errorBody || response.statusText}`);
-      }
-
-      await response.json();
       setTestResult({
         success: true,
         message: `连接成功！模型 "${model}" 可用。`,
       });
     } catch (error: any) {
       console.error('测试连接失败:', error);
-      const errorMessage = error.message || '未知错误';
+      const errorMessage = describeApiError(error);
       setTestResult({
         success: false,
         message: `连接失败: ${errorMessage}`,
@@ -31,6 +31,8 @@ export function AppIndexList({ isOpen, onClose, appHotkeys, onHotkeysChange }: A
   const [appIndexSearch, setAppIndexSearch] = useState("");
   const [appIndexProgress, setAppIndexProgress] = useState<{ progress: number; message: string } | null>(null);
   const [extractingIcons, setExtractingIcons] = useState<Set<string>>(new Set());
+  const [isRefreshingIndex, setIsRefreshingIndex] = useState(false);
+  const [appIndexSummary, setAppIndexSummary] = useState<string | null>(null);
   
   // 图标筛选类型：'all' | 'withIcon' | 'withoutIcon'
   const [iconFilter, setIconFilter] = useState<'all' | 'withIcon' | 'withoutIcon'>('all');
@@ -103,6 +105,21 @@ export function AppIndexList({ isOpen, onClose, appHotkeys, onHotkeysChange }: A
     }
   };
 
+  // 增量刷新：后台扫描并整体替换索引，扫描期间列表保持可用
+  const handleRefreshIndex = async () => {
+    setIsRefreshingIndex(true);
+    setAppIndexError(null);
+    setAppIndexSummary(null);
+    setAppIndexProgress({ progress: 0, message: "准备开始扫描..." });
+    try {
+      await tauriApi.refreshAppIndex();
+    } catch (error: any) {
+      setAppIndexError(error?.message || String(error));
+      setAppIndexProgress(null);
+      setIsRefreshingIndex(false);
+    }
+  };
+
   // 当模态框打开时，如果没有数据则加载
   useEffect(() => {
     if (isOpen && appIndexList.length === 0 && !appIndexLoading) {
@@ -118,6 +135,9 @@ export function AppIndexList({ isOpen, onClose, appHotkeys, onHotkeysChange }: A
     let unlistenComplete: (() => void) | undefined;
     let unlistenError: (() => void) | undefined;
     let unlistenIconsUpdated: (() => void) | undefined;
+    let unlistenIndexProgress: (() => void) | undefined;
+    let unlistenIndexRefreshed: (() => void) | undefined;
+    let unlistenIndexError: (() => void) | undefined;
 
     const setupListeners = async () => {
       // 监听扫描进度
@@ -143,6 +163,34 @@ export function AppIndexList({ isOpen, onClose, appHotkeys, onHotkeysChange }: A
         setAppIndexProgress(null);
       });
 
+      // 监听增量刷新进度、结果和错误
+      unlistenIndexProgress = await listen<{ progress: number; message: string }>("app-index-progress", (event) => {
+        setAppIndexProgress(event.payload);
+      });
+
+      unlistenIndexRefreshed = await listen<{ added: number; removed: number; total: number }>(
+        "app-index-refreshed",
+        async (event) => {
+          const { added, removed } = event.payload;
+          try {
+            // 索引已替换，这里直接读取内存缓存
+            setAppIndexList(await tauriApi.scanApplications());
+          } catch (error) {
+            console.error("获取应用索引列表失败:", error);
+          }
+          setAppIndexSummary(`索引已更新：新增 ${added} 个，移除 ${removed} 个`);
+          setAppIndexProgress(null);
+          setIsRefreshingIndex(false);
+        }
+      );
+
+      unlistenIndexError = await listen<{ error: string }>("app-index-error", (event) => {
+        console.error("刷新应用索引失败:", event.payload.error);
+        setAppIndexError(event.payload.error);
+        setAppIndexProgress(null);
+        setIsRefreshingIndex(false);
+      });
+
       // 监听图标更新事件
       unlistenIconsUpdated = await listen<Array<[string, string]>>("app-icons-updated", (event) => {
         const iconUpdates = event.payload;
@@ -183,6 +231,9 @@ export function AppIndexList({ isOpen, onClose, appHotkeys, onHotkeysChange }: A
       unlistenComplete?.();
       unlistenError?.();
       unlistenIconsUpdated?.();
+      unlistenIndexProgress?.();
+      unlistenIndexRefreshed?.();
+      unlistenIndexError?.();
     };
   }, [isOpen]);
 
@@ -696,6 +747,7 @@ export function AppIndexList({ isOpen, onClose, appHotkeys, onHotkeysChange }: A
             <div className="text-lg font-semibold text-gray-900">应用索引列表</div>
             <div className="text-sm text-gray-500">
               共 {appIndexList.length} 条{appIndexSearch ? `，筛选后 ${filteredAppIndexList.length} 条` : ""}
+              {appIndexSummary && <span className="ml-2 text-green-600">{appIndexSummary}</span>}
               {filteredAppIndexList.length > 0 && (
                 <span className="ml-2">
                   · 有图标: <span className="text-green-600 font-medium">{iconStats.withIcon}</span>
@@ -713,10 +765,18 @@ export function AppIndexList({ isOpen, onClose, appHotkeys, onHotkeysChange }: A
             >
               {isBatchExtracting ? "批量提取中..." : "批量提取图标"}
             </button>
+            <button
+              onClick={handleRefreshIndex}
+              className="px-3 py-2 text-xs rounded-lg bg-blue-50 text-blue-700 border border-blue-200 hover:border-blue-300 hover:shadow-sm transition"
+              disabled={appIndexLoading || isBatchExtracting || isRefreshingIndex}
+              title="后台扫描后替换索引，扫描期间列表和搜索保持可用"
+            >
+              {isRefreshingIndex ? "刷新中..." : "增量刷新"}
+            </button>
             <button
               onClick={() => loadAppIndexList(true)}
               className="px-3 py-2 text-xs rounded-lg bg-green-50 text-green-700 border border-green-200 hover:border-green-300 hover:shadow-sm transition"
-              disabled={appIndexLoading || isBatchExtracting}
+              disabled={appIndexLoading || isBatchExtracting || isRefreshingIndex}
             >
               {appIndexLoading ? "扫描中..." : "重新扫描"}
             </button>
@@ -0,0 +1,114 @@
+import { useEffect, useMemo, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { ArchiveListing } from "../types";
+import { formatBytes } from "../utils/formatUtils";
+
+interface ArchivePeekModalProps {
+  archivePath: string | null;
+  onClose: () => void;
+}
+
+/**
+ * 压缩包内容弹窗：列出 zip 中的条目，点击文件时只解压该文件到临时目录并打开
+ */
+export function ArchivePeekModal({ archivePath, onClose }: ArchivePeekModalProps) {
+  const [listing, setListing] = useState<ArchiveListing | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [filter, setFilter] = useState("");
+  const [openingEntry, setOpeningEntry] = useState<string | null>(null);
+
+  useEffect(() => {
+    setListing(null);
+    setError(null);
+    setFilter("");
+    if (!archivePath) return;
+    let cancelled = false;
+    tauriApi
+      .listArchiveEntries(archivePath)
+      .then((result) => {
+        if (!cancelled) setListing(result);
+      })
+      .catch((e) => {
+        if (!cancelled) setError(String(e));
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [archivePath]);
+
+  const visibleEntries = useMemo(() => {
+    const entries = listing?.entries ?? [];
+    const keyword = filter.trim().toLowerCase();
+    return keyword ? entries.filter((entry) => entry.name.toLowerCase().includes(keyword)) : entries;
+  }, [listing, filter]);
+
+  if (!archivePath) return null;
+
+  const handleOpen = async (name: string) => {
+    setOpeningEntry(name);
+    setError(null);
+    try {
+      await tauriApi.openArchiveEntry(archivePath, name);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setOpeningEntry(null);
+    }
+  };
+
+  const fileName = archivePath.split(/[\\/]/).pop() || archivePath;
+
+  return (
+    <div className="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50" onClick={onClose}>
+      <div
+        className="bg-white rounded-lg shadow-xl p-4 max-w-lg w-full mx-4 flex flex-col"
+        style={{ maxHeight: "80vh" }}
+        onClick={(e) => e.stopPropagation()}
+      >
+        <h2 className="text-base font-semibold mb-1 truncate" title={archivePath}>
+          {fileName}
+        </h2>
+        <div className="text-xs text-gray-500 mb-3">
+          {listing
+            ? listing.total > listing.entries.length
+              ? `共 ${listing.total} 项，仅显示前 ${listing.entries.length} 项`
+              : `共 ${listing.total} 项`
+            : error
+            ? ""
+            : "正在读取..."}
+        </div>
+        <input
+          value={filter}
+          onChange={(e) => setFilter(e.target.value)}
+          placeholder="筛选文件名..."
+          autoFocus
+          className="w-full px-2 py-1.5 mb-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent text-sm"
+        />
+        {error && <div className="text-xs text-red-600 mb-2 break-all">{error}</div>}
+        <div className="flex-1 overflow-y-auto border border-gray-100 rounded-md">
+          {visibleEntries.map((entry) => (
+            <button
+              key={entry.name}
+              disabled={entry.is_dir || openingEntry !== null}
+              onClick={() => handleOpen(entry.name)}
+              className="w-full flex items-center justify-between gap-3 px-3 py-1.5 text-left text-sm hover:bg-gray-100 disabled:hover:bg-transparent disabled:cursor-default transition-colors"
+            >
+              <span className={`truncate ${entry.is_dir ? "text-gray-400" : "text-gray-800"}`}>{entry.name}</span>
+              <span className="text-xs text-gray-500 shrink-0">
+                {openingEntry === entry.name ? "正在打开..." : entry.is_dir ? "" : formatBytes(entry.size)}
+              </span>
+            </button>
+          ))}
+        </div>
+        <div className="flex justify-end mt-3">
+          <button
+            onClick={onClose}
+            className="px-3 py-1.5 text-xs text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200 transition-colors"
+          >
+            关闭
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
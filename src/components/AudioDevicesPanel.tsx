@@ -0,0 +1,121 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { AudioDevice, AudioVolumeState } from "../types";
+import { findAudioDevice } from "../utils/searchUtils";
+
+interface AudioDevicesPanelProps {
+  keyword: string;
+}
+
+/**
+ * 音频设备面板：输入 "audio" 列出输出设备，点击或按回车切换默认设备；同时可调节音量和静音
+ */
+export function AudioDevicesPanel({ keyword }: AudioDevicesPanelProps) {
+  const [devices, setDevices] = useState<AudioDevice[]>([]);
+  const [volume, setVolume] = useState<AudioVolumeState | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [switchingId, setSwitchingId] = useState<string | null>(null);
+
+  const refresh = () => {
+    tauriApi
+      .listAudioDevices()
+      .then(setDevices)
+      .catch((e) => setError(String(e)));
+    tauriApi
+      .getAudioVolume()
+      .then(setVolume)
+      .catch(() => setVolume(null));
+  };
+
+  useEffect(refresh, []);
+
+  const filtered = keyword
+    ? devices.filter((d) => d.name.toLowerCase().includes(keyword.toLowerCase()))
+    : devices;
+  const bestMatch = findAudioDevice(devices, keyword);
+
+  const handleSwitch = async (device: AudioDevice) => {
+    setSwitchingId(device.id);
+    setError(null);
+    try {
+      await tauriApi.switchAudioDevice(device.id);
+      refresh();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setSwitchingId(null);
+    }
+  };
+
+  // 输入了关键字且有匹配的设备时，回车直接切换（在捕获阶段拦截，避免启动器打开其他结果）
+  useEffect(() => {
+    if (!bestMatch || bestMatch.is_default) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      handleSwitch(bestMatch);
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [bestMatch]);
+
+  const handleVolumeChange = (value: number) => {
+    setVolume((current) => (current ? { ...current, volume: value } : current));
+    tauriApi.setAudioVolume(value).catch((e) => setError(String(e)));
+  };
+
+  const handleToggleMute = () => {
+    if (!volume) return;
+    const muted = !volume.muted;
+    setVolume({ ...volume, muted });
+    tauriApi.setAudioMute(muted).catch((e) => setError(String(e)));
+  };
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {volume && (
+        <div className="flex items-center gap-3 mb-2">
+          <button
+            onClick={handleToggleMute}
+            className={`w-14 px-2 py-1 text-xs rounded ${volume.muted ? "bg-red-50 text-red-600" : "bg-gray-100 text-gray-700"}`}
+          >
+            {volume.muted ? "已静音" : "静音"}
+          </button>
+          <input
+            type="range"
+            min={0}
+            max={100}
+            value={volume.volume}
+            onChange={(e) => handleVolumeChange(Number(e.target.value))}
+            className="flex-1"
+          />
+          <span className="w-10 text-right text-xs text-gray-500">{volume.volume}%</span>
+        </div>
+      )}
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {filtered.length === 0 && !error && (
+        <div className="text-xs text-gray-400">{devices.length === 0 ? "正在读取音频设备..." : "没有匹配的音频设备"}</div>
+      )}
+      {filtered.map((device) => (
+        <button
+          key={device.id}
+          onClick={() => handleSwitch(device)}
+          disabled={device.is_default || switchingId !== null}
+          className={`w-full flex items-center gap-2 px-2 py-1.5 text-left rounded ${
+            device.id === bestMatch?.id ? "bg-blue-50" : "hover:bg-gray-100"
+          }`}
+        >
+          <span className="flex-1 truncate text-gray-800">{device.name}</span>
+          {device.is_default ? (
+            <span className="text-xs text-green-600">当前输出</span>
+          ) : (
+            <span className="text-xs text-gray-400">
+              {switchingId === device.id ? "切换中..." : device.id === bestMatch?.id ? "回车切换" : "切换"}
+            </span>
+          )}
+        </button>
+      ))}
+    </div>
+  );
+}
@@ -0,0 +1,99 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { BrowserBridgeStatus } from "../types";
+
+const BROWSER_NAMES: Record<string, string> = {
+  chrome: "Chrome",
+  edge: "Edge",
+  firefox: "Firefox",
+};
+
+/**
+ * 浏览器扩展桥接：为扩展 ID 注册 native messaging 宿主，扩展即可把页面链接和选中文本发送到剪切板历史或单词本
+ */
+export function BrowserBridgeSection() {
+  const [status, setStatus] = useState<BrowserBridgeStatus | null>(null);
+  const [ids, setIds] = useState("");
+  const [busy, setBusy] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const applyStatus = (next: BrowserBridgeStatus) => {
+    setStatus(next);
+    setIds(next.extension_ids.join("\n"));
+  };
+
+  useEffect(() => {
+    tauriApi
+      .getBrowserBridgeStatus()
+      .then(applyStatus)
+      .catch((e) => setError(String(e)));
+  }, []);
+
+  const run = async (action: () => Promise<BrowserBridgeStatus>) => {
+    setBusy(true);
+    setError(null);
+    try {
+      applyStatus(await action());
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const register = () =>
+    run(() =>
+      tauriApi.registerBrowserBridge(
+        ids
+          .split(/[\s,]+/)
+          .map((id) => id.trim())
+          .filter(Boolean)
+      )
+    );
+
+  const registered = status?.registered_browsers ?? [];
+
+  return (
+    <div className="bg-white rounded-lg shadow-sm border border-gray-200 p-6">
+      <h3 className="text-lg font-medium text-gray-700">浏览器扩展</h3>
+      <p className="text-sm text-gray-500 mt-1 mb-4">
+        填写扩展 ID（每行一个，Chrome/Edge 为 32 位字母，Firefox 为 name@domain 或 {"{GUID}"}）后注册，扩展即可把当前页面链接或选中文本加入剪切板历史、单词本，或搜索本地数据
+      </p>
+      <textarea
+        value={ids}
+        onChange={(e) => setIds(e.target.value)}
+        rows={3}
+        placeholder="abcdefghijklmnopabcdefghijklmnop"
+        className="w-full px-3 py-1.5 text-sm font-mono border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+      />
+      {status && (
+        <div className="text-xs text-gray-500 mt-2">
+          桥接服务{status.running ? "运行中" : "未运行"} ·{" "}
+          {registered.length > 0
+            ? `已注册到 ${registered.map((b) => BROWSER_NAMES[b] ?? b).join("、")}`
+            : "尚未注册"}
+          {registered.length > 0 && !status.exe_matches && (
+            <span className="text-amber-600"> · 程序位置已变化，请重新注册</span>
+          )}
+        </div>
+      )}
+      <div className="flex gap-2 mt-3">
+        <button
+          onClick={register}
+          disabled={busy}
+          className="px-3 py-1.5 text-sm bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50"
+        >
+          注册
+        </button>
+        <button
+          onClick={() => run(() => tauriApi.unregisterBrowserBridge())}
+          disabled={busy || registered.length === 0}
+          className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200 disabled:opacity-50"
+        >
+          取消注册
+        </button>
+      </div>
+      {error && <div className="text-xs text-red-500 mt-2 break-all">{error}</div>}
+    </div>
+  );
+}
@@ -0,0 +1,207 @@
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { Calendar, CalendarEvent } from "../types";
+
+interface CalendarPanelProps {
+  mode: "today" | "next";
+  addSource: string | null;
+}
+
+function formatTime(timestamp: number): string {
+  return new Date(timestamp * 1000).toLocaleTimeString("zh-CN", { hour: "2-digit", minute: "2-digit" });
+}
+
+function formatRange(event: CalendarEvent): string {
+  if (event.all_day) return "全天";
+  const start = new Date(event.start_at * 1000);
+  const sameDay = start.toDateString() === new Date().toDateString();
+  const day = sameDay ? "" : `${start.getMonth() + 1}月${start.getDate()}日 `;
+  return `${day}${formatTime(event.start_at)}–${formatTime(event.end_at)}`;
+}
+
+// 开始前一小时内显示倒计时，进行中的标注出来
+function formatStatus(event: CalendarEvent, now: number): string | null {
+  if (event.all_day) return null;
+  if (event.end_at <= now) return "已结束";
+  if (event.start_at <= now) return "进行中";
+  const minutes = Math.ceil((event.start_at - now) / 60);
+  return minutes <= 60 ? `${minutes} 分钟后` : null;
+}
+
+/**
+ * 日历面板：今天的日程或接下来的会议，有会议链接时可一键加入（回车加入最近一个未结束的会议）；
+ * 输入 "cal add <ICS 地址或 .ics 路径>" 回车添加订阅，也可在订阅管理中删除订阅或立即刷新
+ */
+export function CalendarPanel({ mode, addSource }: CalendarPanelProps) {
+  const [events, setEvents] = useState<CalendarEvent[] | null>(null);
+  const [calendars, setCalendars] = useState<Calendar[]>([]);
+  const [showCalendars, setShowCalendars] = useState(false);
+  const [busy, setBusy] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+  const [now, setNow] = useState(() => Math.floor(Date.now() / 1000));
+
+  const refresh = () => {
+    (mode === "next" ? tauriApi.getUpcomingEvents() : tauriApi.getTodayEvents())
+      .then(setEvents)
+      .catch((e) => setError(String(e)));
+    tauriApi
+      .listCalendars()
+      .then(setCalendars)
+      .catch(() => {});
+  };
+
+  useEffect(refresh, [mode]);
+
+  useEffect(() => {
+    const unlisten = listen("calendar-updated", refresh);
+    const tick = window.setInterval(() => setNow(Math.floor(Date.now() / 1000)), 30_000);
+    return () => {
+      unlisten.then((fn) => fn());
+      window.clearInterval(tick);
+    };
+  }, [mode]);
+
+  const run = async (action: () => Promise<string | void>) => {
+    setBusy(true);
+    setError(null);
+    try {
+      const result = await action();
+      if (result) setMessage(result);
+      refresh();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const join = (event: CalendarEvent) => {
+    if (event.join_url) run(() => tauriApi.openUrl(event.join_url!));
+  };
+
+  const addCalendar = (source: string) =>
+    run(async () => {
+      const calendar = await tauriApi.addCalendar(source);
+      return `已添加日历 ${calendar.name}（${calendar.upcoming} 个日程）`;
+    });
+
+  const nextJoinable = events?.find((e) => !e.all_day && e.end_at > now && e.join_url);
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    if (busy || (!addSource && !nextJoinable)) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      if (addSource) addCalendar(addSource);
+      else if (nextJoinable) join(nextJoinable);
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [addSource, nextJoinable, busy]);
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {message && <div className="text-xs text-blue-600 mb-1">{message}</div>}
+      {addSource && (
+        <div className="px-2 py-1.5 mb-1 rounded bg-blue-50 text-gray-700 truncate">
+          {busy ? "正在读取日历..." : `回车添加日历 ${addSource}`}
+        </div>
+      )}
+      {!addSource && events && events.length === 0 && (
+        <div className="text-xs text-gray-400">
+          {calendars.length === 0
+            ? '还没有日历，输入 "cal add ICS 地址或 .ics 文件路径" 添加'
+            : mode === "next"
+              ? "接下来没有会议"
+              : "今天没有日程"}
+        </div>
+      )}
+      {!addSource &&
+        events?.map((event) => {
+          const status = formatStatus(event, now);
+          const highlighted = event === nextJoinable;
+          return (
+            <div
+              key={event.id}
+              className={`flex items-center gap-2 px-2 py-1.5 rounded ${highlighted ? "bg-blue-50" : ""} ${
+                status === "已结束" ? "opacity-50" : ""
+              }`}
+            >
+              <span className="w-28 shrink-0 text-xs text-gray-500 font-mono">{formatRange(event)}</span>
+              <span className="flex-1 min-w-0">
+                <span className="block truncate text-gray-800">{event.title}</span>
+                <span className="block truncate text-xs text-gray-400">
+                  {event.calendar_name}
+                  {event.location ? ` · ${event.location}` : ""}
+                </span>
+              </span>
+              {status && (
+                <span className={`text-xs shrink-0 ${status === "进行中" ? "text-green-600" : "text-gray-400"}`}>
+                  {status}
+                </span>
+              )}
+              {event.join_url && status !== "已结束" && (
+                <button
+                  onClick={() => join(event)}
+                  className="px-2 py-0.5 text-xs text-blue-600 hover:bg-blue-100 rounded shrink-0"
+                  title={event.join_url}
+                >
+                  加入
+                </button>
+              )}
+            </div>
+          );
+        })}
+      {showCalendars && (
+        <div className="mt-2 border-t border-gray-100 pt-2 space-y-1">
+          {calendars.map((calendar) => (
+            <div key={calendar.id} className="flex items-center gap-2 text-xs">
+              <span className="flex-1 truncate text-gray-700" title={calendar.source}>
+                {calendar.name}
+                <span className="ml-1 text-gray-400">{calendar.upcoming} 个日程</span>
+              </span>
+              {calendar.last_error && (
+                <span className="text-red-500 truncate max-w-[40%]" title={calendar.last_error}>
+                  {calendar.last_error}
+                </span>
+              )}
+              <button
+                onClick={() => run(() => tauriApi.removeCalendar(calendar.id))}
+                className="px-2 py-0.5 text-red-600 hover:bg-red-50 rounded"
+              >
+                删除
+              </button>
+            </div>
+          ))}
+        </div>
+      )}
+      <div className="mt-1 flex items-center gap-3 text-xs text-gray-500">
+        {nextJoinable && !addSource ? (
+          <span className="flex-1 truncate text-blue-600">回车加入 {nextJoinable.title}</span>
+        ) : (
+          <span className="flex-1" />
+        )}
+        <button
+          onClick={() =>
+            run(async () => {
+              const refreshed = await tauriApi.refreshCalendars();
+              return `已刷新 ${refreshed} 个日历`;
+            })
+          }
+          disabled={busy || calendars.length === 0}
+          className="hover:underline"
+        >
+          刷新
+        </button>
+        <button onClick={() => setShowCalendars(!showCalendars)} className="hover:underline">
+          日历管理（{calendars.length}）
+        </button>
+      </div>
+    </div>
+  );
+}
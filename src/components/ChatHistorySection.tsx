@@ -0,0 +1,220 @@
+import { useEffect, useState } from "react";
+import { confirm, save } from "@tauri-apps/plugin-dialog";
+import { tauriApi } from "../api/tauri";
+import type { ChatConversation, ChatMessageMatch, ChatStoredMessage } from "../types";
+import { formatFullDateTime } from "../utils/dateUtils";
+
+const inputClassName =
+  "px-3 py-2 border border-[var(--md-sys-color-outline-variant)]/40 rounded-[var(--md-sys-shape-corner-medium)] focus:outline-none focus:ring-2 focus:ring-[var(--md-sys-color-primary)]/40 focus:border-[var(--md-sys-color-primary)] bg-[var(--md-sys-color-surface)] text-[var(--md-sys-color-on-surface)] placeholder-[var(--md-sys-color-outline)] text-sm";
+
+interface ChatHistorySectionProps {
+  retentionDays: number;
+  onRetentionDaysChange: (days: number) => void;
+}
+
+export function ChatHistorySection({ retentionDays, onRetentionDaysChange }: ChatHistorySectionProps) {
+  const [conversations, setConversations] = useState<ChatConversation[]>([]);
+  const [query, setQuery] = useState("");
+  const [matches, setMatches] = useState<ChatMessageMatch[]>([]);
+  const [selectedId, setSelectedId] = useState<string | null>(null);
+  const [messages, setMessages] = useState<ChatStoredMessage[]>([]);
+  const [error, setError] = useState<string | null>(null);
+
+  const loadConversations = async () => {
+    try {
+      setConversations(await tauriApi.listChatConversations());
+    } catch (error) {
+      console.error("获取对话记录失败:", error);
+    }
+  };
+
+  useEffect(() => {
+    loadConversations();
+  }, []);
+
+  // 搜索消息（300ms 防抖）
+  useEffect(() => {
+    if (!query.trim()) {
+      setMatches([]);
+      return;
+    }
+    const timer = setTimeout(() => {
+      tauriApi
+        .searchChatMessages(query)
+        .then(setMatches)
+        .catch((error) => console.error("搜索对话记录失败:", error));
+    }, 300);
+    return () => clearTimeout(timer);
+  }, [query]);
+
+  const handleOpen = async (id: string) => {
+    if (selectedId === id) {
+      setSelectedId(null);
+      return;
+    }
+    try {
+      setMessages(await tauriApi.getChatMessages(id));
+      setSelectedId(id);
+    } catch (error) {
+      console.error("获取对话消息失败:", error);
+    }
+  };
+
+  const handleTogglePin = async (conversation: ChatConversation) => {
+    try {
+      await tauriApi.setChatConversationPinned(conversation.id, !conversation.pinned);
+      await loadConversations();
+    } catch (error) {
+      console.error("置顶对话失败:", error);
+    }
+  };
+
+  const handleExport = async (conversation: ChatConversation) => {
+    const path = await save({
+      defaultPath: `${conversation.title.replace(/[\\/:*?"<>|]/g, "_")}.md`,
+      filters: [{ name: "Markdown", extensions: ["md"] }],
+    });
+    if (!path) return;
+    try {
+      await tauriApi.exportChatConversation(conversation.id, path);
+      setError(null);
+    } catch (error) {
+      setError(`导出失败: ${error}`);
+    }
+  };
+
+  const handleDelete = async (conversation: ChatConversation) => {
+    const confirmed = await confirm(`确定要删除对话「${conversation.title}」吗？`, {
+      title: "删除对话",
+      kind: "warning",
+    });
+    if (!confirmed) return;
+    try {
+      await tauriApi.deleteChatConversation(conversation.id);
+      if (selectedId === conversation.id) {
+        setSelectedId(null);
+      }
+      await loadConversations();
+    } catch (error) {
+      console.error("删除对话失败:", error);
+    }
+  };
+
+  const renderMessages = () => (
+    <div className="mt-2 space-y-2 max-h-80 overflow-y-auto">
+      {messages.map((message, index) => (
+        <div
+          key={index}
+          className={`p-2 rounded-[var(--md-sys-shape-corner-medium)] text-sm whitespace-pre-wrap break-words ${
+            message.role === "user"
+              ? "bg-[var(--md-sys-color-primary)]/10 text-[var(--md-sys-color-on-surface)]"
+              : "bg-[var(--md-sys-color-surface-container)] text-[var(--md-sys-color-on-surface-variant)]"
+          }`}
+        >
+          {message.content}
+        </div>
+      ))}
+    </div>
+  );
+
+  return (
+    <div className="bg-[var(--md-sys-color-surface-container-lowest)] rounded-[var(--md-sys-shape-corner-large)] border border-[var(--md-sys-color-outline-variant)]/30 p-6">
+      <div className="mb-4 flex items-start justify-between gap-4">
+        <div>
+          <h3 className="text-base font-semibold text-[var(--md-sys-color-on-surface)]">对话记录</h3>
+          <p className="mt-1 text-xs text-[var(--md-sys-color-outline)]">
+            启动器中的 AI 问答会保存在这里，可以搜索、置顶或导出为 Markdown
+          </p>
+        </div>
+        <div className="flex items-center gap-2 text-sm text-[var(--md-sys-color-on-surface-variant)] shrink-0">
+          <span>保留</span>
+          <input
+            type="number"
+            min={0}
+            value={retentionDays}
+            onChange={(e) => onRetentionDaysChange(Math.max(0, Math.floor(Number(e.target.value) || 0)))}
+            className={`${inputClassName} w-20 py-1`}
+          />
+          <span>天</span>
+        </div>
+      </div>
+      <p className="-mt-2 mb-3 text-xs text-[var(--md-sys-color-outline)]">
+        超过保留天数的对话会自动删除，置顶的对话除外；0 表示永久保留
+      </p>
+
+      <input
+        type="text"
+        value={query}
+        onChange={(e) => setQuery(e.target.value)}
+        className={`${inputClassName} w-full mb-3`}
+        placeholder="搜索对话内容..."
+      />
+
+      {error && <p className="mb-2 text-xs text-red-600">{error}</p>}
+
+      {query.trim() ? (
+        <ul className="space-y-2">
+          {matches.length === 0 && (
+            <li className="text-sm text-[var(--md-sys-color-outline)]">没有找到匹配的消息</li>
+          )}
+          {matches.map((match, index) => (
+            <li key={`${match.conversation_id}-${index}`}>
+              <button
+                onClick={() => handleOpen(match.conversation_id)}
+                className="w-full text-left p-2 rounded-[var(--md-sys-shape-corner-medium)] border border-[var(--md-sys-color-outline-variant)]/30 hover:bg-[var(--md-sys-color-surface-container)]"
+              >
+                <div className="text-sm text-[var(--md-sys-color-on-surface)]">{match.title}</div>
+                <div className="mt-1 text-xs text-[var(--md-sys-color-on-surface-variant)]">
+                  {match.role === "user" ? "我" : "AI"}：{match.snippet}
+                </div>
+              </button>
+              {selectedId === match.conversation_id && renderMessages()}
+            </li>
+          ))}
+        </ul>
+      ) : (
+        <ul className="space-y-2">
+          {conversations.length === 0 && (
+            <li className="text-sm text-[var(--md-sys-color-outline)]">暂无对话记录</li>
+          )}
+          {conversations.map((conversation) => (
+            <li
+              key={conversation.id}
+              className="p-2 rounded-[var(--md-sys-shape-corner-medium)] border border-[var(--md-sys-color-outline-variant)]/30"
+            >
+              <div className="flex items-center justify-between gap-2">
+                <button onClick={() => handleOpen(conversation.id)} className="min-w-0 text-left">
+                  <span className="text-sm text-[var(--md-sys-color-on-surface)]">{conversation.title}</span>
+                  {conversation.pinned && (
+                    <span className="ml-2 text-xs text-[var(--md-sys-color-primary)]">已置顶</span>
+                  )}
+                  <div className="text-xs text-[var(--md-sys-color-outline)]">
+                    {formatFullDateTime(conversation.updated_at)} · {conversation.message_count} 条消息
+                  </div>
+                </button>
+                <div className="flex gap-2 text-xs shrink-0">
+                  <button
+                    onClick={() => handleTogglePin(conversation)}
+                    className="text-[var(--md-sys-color-primary)] hover:underline"
+                  >
+                    {conversation.pinned ? "取消置顶" : "置顶"}
+                  </button>
+                  <button
+                    onClick={() => handleExport(conversation)}
+                    className="text-[var(--md-sys-color-on-surface-variant)] hover:underline"
+                  >
+                    导出
+                  </button>
+                  <button onClick={() => handleDelete(conversation)} className="text-red-600 hover:underline">
+                    删除
+                  </button>
+                </div>
+              </div>
+              {selectedId === conversation.id && renderMessages()}
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
@@ -1,15 +1,65 @@
 import { useState, useEffect, useRef } from "react";
 import { confirm } from "@tauri-apps/plugin-dialog";
+import { listen } from "@tauri-apps/api/event";
 import { tauriApi } from "../api/tauri";
-import type { ClipboardItem } from "../types";
+import type { ClipboardAiAction, ClipboardCopyFormat, ClipboardItem, ClipboardTag } from "../types";
 import { formatRelativeTime, formatFullDateTime } from "../utils/dateUtils";
 import { useWindowClose } from "../hooks/useWindowClose";
+import { describeApiError } from "../utils/apiError";
+import { splitSnippet } from "../utils/launcherUtils";
+import { splitTagFilter } from "../utils/searchUtils";
+import { PhoneShareModal } from "./PhoneShareModal";
+
+const AI_ACTIONS: Array<{ action: ClipboardAiAction; label: string }> = [
+  { action: "summarize", label: "总结" },
+  { action: "rewrite_formal", label: "正式改写" },
+  { action: "fix_grammar", label: "语法修正" },
+  { action: "translate", label: "翻译成中文" },
+];
+
+// 各类型可用的“复制为”格式
+const COPY_AS_FORMATS: Record<string, Array<{ format: ClipboardCopyFormat; label: string }>> = {
+  text: [
+    { format: "code_block", label: "Markdown 代码块" },
+    { format: "csv", label: "CSV" },
+    { format: "markdown_table", label: "Markdown 表格" },
+    { format: "file_url", label: "file:// 链接" },
+  ],
+  image: [
+    { format: "data_uri", label: "Base64 Data URI" },
+    { format: "image_file", label: "图片文件" },
+    { format: "file_url", label: "file:// 链接" },
+  ],
+  file: [{ format: "file_url", label: "file:// 链接" }],
+};
+
+const TYPE_BADGES: Record<string, { label: string; className: string }> = {
+  text: { label: "📝 文字", className: "bg-blue-100 text-blue-700" },
+  image: { label: "🖼️ 图片", className: "bg-purple-100 text-purple-700" },
+  file: { label: "📁 文件", className: "bg-amber-100 text-amber-700" },
+};
+
+const typeBadge = (contentType: string) => TYPE_BADGES[contentType] ?? TYPE_BADGES.text;
+
+// 文件项的内容为每行一个路径
+const filePaths = (content: string) =>
+  content
+    .split(/\r?\n/)
+    .map((path) => path.trim())
+    .filter(Boolean);
+
+const fileName = (path: string) => path.split(/[\\/]/).pop() || path;
 
 export function ClipboardWindow() {
   const [clipboardItems, setClipboardItems] = useState<ClipboardItem[]>([]);
   const [filteredItems, setFilteredItems] = useState<ClipboardItem[]>([]);
   const [searchQuery, setSearchQuery] = useState("");
-  const [contentTypeFilter, setContentTypeFilter] = useState<"all" | "text" | "image">("all");
+  // 语义搜索：结果按相似度排序，为 null 时使用关键词筛选
+  const [semanticMode, setSemanticMode] = useState(false);
+  const [semanticResults, setSemanticResults] = useState<ClipboardItem[] | null>(null);
+  const [semanticError, setSemanticError] = useState<string | null>(null);
+  const [searchResults, setSearchResults] = useState<ClipboardItem[] | null>(null);
+  const [contentTypeFilter, setContentTypeFilter] = useState<"all" | "text" | "image" | "file">("all");
   const [selectedItem, setSelectedItem] = useState<ClipboardItem | null>(null);
   const [isEditing, setIsEditing] = useState(false);
   const [editContent, setEditContent] = useState("");
@@ -17,6 +67,27 @@ export function ClipboardWindow() {
   const [showSettings, setShowSettings] = useState(false);
   const [maxItems, setMaxItems] = useState<number>(100);
   const [tempMaxItems, setTempMaxItems] = useState<number>(100);
+  const [maxAgeDays, setMaxAgeDays] = useState<number>(0);
+  const [tempMaxAgeDays, setTempMaxAgeDays] = useState<number>(0);
+  const [dedupCollapseWhitespace, setDedupCollapseWhitespace] = useState(false);
+  const [maskSensitive, setMaskSensitive] = useState(true);
+  const [sensitiveExpireMinutes, setSensitiveExpireMinutes] = useState(0);
+  // 详情中临时显示的敏感内容原文
+  const [revealed, setRevealed] = useState<{ id: string; content: string } | null>(null);
+  // 正在执行的 AI 操作：来源剪切板项 id 及流式输出
+  const [aiTask, setAiTask] = useState<{ sourceId: string; label: string; output: string; error?: string } | null>(null);
+  // 多选模式：勾选的剪切板项用于批量删除、收藏、添加标签
+  const [multiSelect, setMultiSelect] = useState(false);
+  const [checkedIds, setCheckedIds] = useState<Set<string>>(new Set());
+  const [batchTags, setBatchTags] = useState("");
+  // 所有用到的标签，点击后在搜索框中加入 #标签 过滤
+  const [allTags, setAllTags] = useState<ClipboardTag[]>([]);
+  // 正在发送到手机的剪切板项
+  const [phoneShareItemId, setPhoneShareItemId] = useState<string | null>(null);
+  const [pasteLink, setPasteLink] = useState<{ itemId: string; url?: string; error?: string } | null>(null);
+  const [copyAsResult, setCopyAsResult] = useState<{ itemId: string; error?: string } | null>(null);
+  // 清空历史时删除图片文件的进度，null 表示没有在清空
+  const [clearProgress, setClearProgress] = useState<{ progress: number; message: string } | null>(null);
   const itemRefs = useRef<Map<string, HTMLDivElement>>(new Map());
   const imageDataUrlsRef = useRef<Map<string, string>>(new Map());
 
@@ -76,17 +147,57 @@ export function ClipboardWindow() {
     };
   }, []);
 
+  // 监控到新的剪切板内容时立即更新列表（重复内容会移到最前）
+  useEffect(() => {
+    const unlisten = listen<ClipboardItem>("clipboard://new-item", (event) => {
+      const item = event.payload;
+      setClipboardItems((items) => [item, ...items.filter((i) => i.id !== item.id)]);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 清空历史时后端逐个删除图片文件并报告进度
+  useEffect(() => {
+    const unlisten = listen<{ progress: number; message: string }>("clipboard-clear-progress", (event) => {
+      setClearProgress(event.payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   const loadSettings = async () => {
     try {
       const settings = await tauriApi.getSettings();
       const value = settings.clipboard_max_items ?? 100;
       setMaxItems(value);
       setTempMaxItems(value);
+      const days = settings.clipboard_max_age_days ?? 0;
+      setMaxAgeDays(days);
+      setTempMaxAgeDays(days);
+      setDedupCollapseWhitespace(settings.clipboard_dedup_collapse_whitespace ?? false);
+      setMaskSensitive(settings.clipboard_mask_sensitive ?? true);
+      setSensitiveExpireMinutes(settings.clipboard_sensitive_expire_minutes ?? 0);
     } catch (error) {
       console.error("Failed to load settings:", error);
     }
   };
 
+  const saveDedupCollapseWhitespace = async (enabled: boolean) => {
+    try {
+      const settings = await tauriApi.getSettings();
+      await tauriApi.saveSettings({
+        ...settings,
+        clipboard_dedup_collapse_whitespace: enabled,
+      });
+      setDedupCollapseWhitespace(enabled);
+    } catch (error) {
+      console.error("Failed to save settings:", error);
+    }
+  };
+
   const saveMaxItems = async () => {
     try {
       const settings = await tauriApi.getSettings();
@@ -100,10 +211,42 @@ export function ClipboardWindow() {
     }
   };
 
+  const saveSensitiveSettings = async (mask: boolean, expireMinutes: number) => {
+    try {
+      const settings = await tauriApi.getSettings();
+      await tauriApi.saveSettings({
+        ...settings,
+        clipboard_mask_sensitive: mask,
+        clipboard_sensitive_expire_minutes: expireMinutes,
+      });
+      setMaskSensitive(mask);
+      setSensitiveExpireMinutes(expireMinutes);
+      await loadClipboardItems();
+    } catch (error) {
+      console.error("Failed to save settings:", error);
+    }
+  };
+
+  const saveMaxAgeDays = async () => {
+    try {
+      const settings = await tauriApi.getSettings();
+      await tauriApi.saveSettings({
+        ...settings,
+        clipboard_max_age_days: tempMaxAgeDays,
+      });
+      setMaxAgeDays(tempMaxAgeDays);
+      // 保存后后端会立即删除过期记录
+      await loadClipboardItems();
+    } catch (error) {
+      console.error("Failed to save settings:", error);
+    }
+  };
+
   // 当打开设置面板时，同步临时值
   useEffect(() => {
     if (showSettings) {
       setTempMaxItems(maxItems);
+      setTempMaxAgeDays(maxAgeDays);
     }
     // eslint-disable-next-line react-hooks/exhaustive-deps
   }, [showSettings]);
@@ -115,6 +258,28 @@ export function ClipboardWindow() {
     }
   }, [selectedItem]);
 
+  // 切换选中项时重新隐藏敏感内容
+  useEffect(() => {
+    setRevealed(null);
+  }, [selectedItem?.id]);
+
+  // 敏感内容在列表中已打码，复制、编辑前读取原文
+  const withContent = async (item: ClipboardItem): Promise<ClipboardItem> =>
+    item.is_sensitive ? tauriApi.revealClipboardItem(item.id) : item;
+
+  const handleToggleReveal = async (item: ClipboardItem) => {
+    if (revealed?.id === item.id) {
+      setRevealed(null);
+      return;
+    }
+    try {
+      const original = await tauriApi.revealClipboardItem(item.id);
+      setRevealed({ id: item.id, content: original.content });
+    } catch (error) {
+      console.error("Failed to reveal clipboard item:", error);
+    }
+  };
+
   // 使用 Intersection Observer 实现视口内图片懒加载
   useEffect(() => {
     const imageItems = filteredItems.filter(item => item.content_type === "image");
@@ -161,45 +326,129 @@ export function ClipboardWindow() {
     };
   }, [filteredItems]);
 
+  // 语义搜索需要请求向量接口，输入停止一段时间后再搜索
+  useEffect(() => {
+    if (!semanticMode || searchQuery.trim() === "") {
+      setSemanticResults(null);
+      setSemanticError(null);
+      return;
+    }
+    let cancelled = false;
+    const timer = setTimeout(async () => {
+      try {
+        const matches = await tauriApi.semanticSearchClipboard(searchQuery.trim());
+        if (!cancelled) {
+          setSemanticResults(matches.map((m) => m.item));
+          setSemanticError(null);
+        }
+      } catch (error) {
+        console.error("Failed to run semantic search:", error);
+        if (!cancelled) {
+          setSemanticResults(null);
+          setSemanticError(describeApiError(error));
+        }
+      }
+    }, 400);
+    return () => {
+      cancelled = true;
+      clearTimeout(timer);
+    };
+  }, [semanticMode, searchQuery]);
+
+  // 关键词搜索走后端全文索引（覆盖全部历史，而不只是已加载的条目）
+  useEffect(() => {
+    if (semanticMode || searchQuery.trim() === "") {
+      setSearchResults(null);
+      return;
+    }
+    let cancelled = false;
+    const timer = setTimeout(async () => {
+      try {
+        const items = await tauriApi.searchClipboardItems(searchQuery.trim());
+        if (!cancelled) setSearchResults(items);
+      } catch (error) {
+        console.error("Failed to search clipboard:", error);
+        if (!cancelled) setSearchResults(null);
+      }
+    }, 200);
+    return () => {
+      cancelled = true;
+      clearTimeout(timer);
+    };
+  }, [semanticMode, searchQuery, clipboardItems]);
+
   useEffect(() => {
-    let filtered = clipboardItems;
+    let filtered = semanticResults ?? searchResults ?? clipboardItems;
 
     // 按内容类型筛选
     if (contentTypeFilter !== "all") {
       filtered = filtered.filter((item) => item.content_type === contentTypeFilter);
     }
 
-    // 按搜索关键词筛选
-    if (searchQuery.trim() !== "") {
-      const query = searchQuery.toLowerCase();
-      filtered = filtered.filter((item) =>
-        item.content.toLowerCase().includes(query)
+    // 后端结果返回前先在已加载的条目中筛选（语义搜索结果已包含关键词匹配）
+    if (semanticResults === null && searchResults === null && searchQuery.trim() !== "") {
+      const { tags, text } = splitTagFilter(searchQuery);
+      const query = text.toLowerCase();
+      filtered = filtered.filter(
+        (item) =>
+          item.content.toLowerCase().includes(query) &&
+          tags.every((tag) => item.tags?.some((t) => t.toLowerCase() === tag))
       );
     }
 
     setFilteredItems(filtered);
-  }, [searchQuery, contentTypeFilter, clipboardItems]);
+  }, [searchQuery, contentTypeFilter, clipboardItems, semanticResults, searchResults]);
 
   const handleClose = useWindowClose();
 
-  const handleCopyToClipboard = async (item: ClipboardItem) => {
+  const handleCopyToClipboard = async (item: ClipboardItem, plainOnly = false) => {
     try {
       if (item.content_type === "image") {
         // 复制图片到剪切板
         await tauriApi.copyImageToClipboard(item.content);
+      } else if (item.content_type === "file") {
+        // 重新复制文件，可在资源管理器中粘贴
+        await tauriApi.copyFilesToClipboard(filePaths(item.content));
+      } else if (item.has_html && !plainOnly) {
+        // 同时复制纯文本与 HTML，粘贴到 Word、邮件等程序时保留格式
+        await tauriApi.copyRichTextToClipboard(item.id);
       } else {
         // 复制文本到剪切板
-        await navigator.clipboard.writeText(item.content);
+        await navigator.clipboard.writeText((await withContent(item)).content);
       }
     } catch (error) {
       console.error("Failed to copy to clipboard:", error);
     }
   };
 
-  const handleEdit = (item: ClipboardItem) => {
+  const handleCreatePasteLink = async (item: ClipboardItem) => {
+    setPasteLink({ itemId: item.id });
+    try {
+      const link = await tauriApi.createPasteLink(item.id);
+      await navigator.clipboard.writeText(link.url);
+      setPasteLink({ itemId: item.id, url: link.url });
+    } catch (error) {
+      setPasteLink({ itemId: item.id, error: String(error) });
+    }
+  };
+
+  const handleCopyAs = async (item: ClipboardItem, format: ClipboardCopyFormat) => {
+    try {
+      await tauriApi.copyClipboardItemAs(item.id, format);
+      setCopyAsResult({ itemId: item.id });
+    } catch (error) {
+      setCopyAsResult({ itemId: item.id, error: describeApiError(error) });
+    }
+  };
+
+  const handleEdit = async (item: ClipboardItem) => {
     setSelectedItem(item);
-    setEditContent(item.content);
-    setIsEditing(true);
+    try {
+      setEditContent((await withContent(item)).content);
+      setIsEditing(true);
+    } catch (error) {
+      console.error("Failed to load clipboard item:", error);
+    }
   };
 
   const handleSaveEdit = async () => {
@@ -227,6 +476,60 @@ export function ClipboardWindow() {
     setEditContent("");
   };
 
+  const handleAiAction = async (item: ClipboardItem, action: ClipboardAiAction) => {
+    const label = AI_ACTIONS.find((a) => a.action === action)?.label ?? action;
+    setAiTask({ sourceId: item.id, label, output: "" });
+    try {
+      const created = await tauriApi.clipboardAiAction(action, { itemId: item.id }, (delta) => {
+        setAiTask((task) => (task && task.sourceId === item.id ? { ...task, output: task.output + delta } : task));
+      });
+      // 结果已保存为新的剪切板项，刷新列表并选中
+      await loadClipboardItems();
+      setAiTask(null);
+      setSelectedItem(created);
+    } catch (error) {
+      console.error("Failed to run clipboard AI action:", error);
+      setAiTask((task) => (task && task.sourceId === item.id ? { ...task, error: describeApiError(error) } : task));
+    }
+  };
+
+  useEffect(() => {
+    tauriApi
+      .listClipboardTags()
+      .then(setAllTags)
+      .catch((error) => console.error("Failed to load clipboard tags:", error));
+  }, [clipboardItems]);
+
+  // 在搜索框中加入或移除 #标签
+  const toggleTagFilter = (tag: string) => {
+    const { tags } = splitTagFilter(searchQuery);
+    const lower = tag.toLowerCase();
+    if (tags.includes(lower)) {
+      setSearchQuery(
+        searchQuery
+          .split(/\s+/)
+          .filter((term) => term && term.toLowerCase() !== `#${lower}`)
+          .join(" ")
+      );
+    } else {
+      setSearchQuery(`${searchQuery.trim()} #${tag}`.trim());
+    }
+  };
+
+  const handleSaveTags = async (item: ClipboardItem, value: string) => {
+    const tags = value.split(/[,，]/).map((tag) => tag.trim()).filter(Boolean);
+    if (tags.join(",") === (item.tags ?? []).join(",")) return;
+    try {
+      const updated = await tauriApi.setClipboardItemTags(item.id, tags);
+      setClipboardItems((items) => items.map((i) => (i.id === updated.id ? updated : i)));
+      if (selectedItem?.id === updated.id) {
+        setSelectedItem(updated);
+      }
+    } catch (error) {
+      console.error("Failed to set clipboard tags:", error);
+    }
+  };
+
   const handleToggleFavorite = async (item: ClipboardItem) => {
     try {
       const updated = await tauriApi.toggleFavoriteClipboardItem(item.id);
@@ -242,6 +545,32 @@ export function ClipboardWindow() {
     }
   };
 
+  const handleTogglePin = async (item: ClipboardItem) => {
+    try {
+      const updated = await tauriApi.pinClipboardItem(item.id, !item.pinned);
+      setSelectedItem((selected) => (selected?.id === updated.id ? updated : selected));
+      // 置顶会改变列表顺序，重新加载
+      await loadClipboardItems();
+    } catch (error) {
+      console.error("Failed to pin clipboard item:", error);
+    }
+  };
+
+  // 在置顶项之间上移（-1）或下移（1）
+  const handleMovePinned = async (item: ClipboardItem, offset: number) => {
+    const ids = clipboardItems.filter((i) => i.pinned).map((i) => i.id);
+    const index = ids.indexOf(item.id);
+    const target = index + offset;
+    if (index < 0 || target < 0 || target >= ids.length) return;
+    [ids[index], ids[target]] = [ids[target], ids[index]];
+    try {
+      await tauriApi.reorderPinnedClipboardItems(ids);
+      await loadClipboardItems();
+    } catch (error) {
+      console.error("Failed to reorder pinned clipboard items:", error);
+    }
+  };
+
   const handleDelete = async (item: ClipboardItem) => {
     const confirmed = await confirm(`确定要删除这条剪切板记录吗？`, {
       title: "确认删除",
@@ -262,9 +591,68 @@ export function ClipboardWindow() {
     }
   };
 
+  const toggleChecked = (id: string) => {
+    setCheckedIds((prev) => {
+      const next = new Set(prev);
+      if (next.has(id)) {
+        next.delete(id);
+      } else {
+        next.add(id);
+      }
+      return next;
+    });
+  };
+
+  const exitMultiSelect = () => {
+    setMultiSelect(false);
+    setCheckedIds(new Set());
+    setBatchTags("");
+  };
+
+  const handleBatchDelete = async () => {
+    const confirmed = await confirm(`确定要删除选中的 ${checkedIds.size} 条剪切板记录吗？`, {
+      title: "确认删除",
+      kind: "warning",
+    });
+    if (!confirmed) return;
+
+    try {
+      await tauriApi.deleteClipboardItems([...checkedIds]);
+      if (selectedItem && checkedIds.has(selectedItem.id)) {
+        setSelectedItem(null);
+        setIsEditing(false);
+      }
+      setCheckedIds(new Set());
+      await loadClipboardItems();
+    } catch (error) {
+      console.error("Failed to delete clipboard items:", error);
+    }
+  };
+
+  const handleBatchFavorite = async (favorite: boolean) => {
+    try {
+      await tauriApi.setClipboardItemsFavorite([...checkedIds], favorite);
+      await loadClipboardItems();
+    } catch (error) {
+      console.error("Failed to update favorites:", error);
+    }
+  };
+
+  const handleBatchTag = async () => {
+    const tags = batchTags.split(/[,，]/).map((tag) => tag.trim()).filter(Boolean);
+    if (tags.length === 0) return;
+    try {
+      await tauriApi.tagClipboardItems([...checkedIds], tags);
+      setBatchTags("");
+      await loadClipboardItems();
+    } catch (error) {
+      console.error("Failed to tag clipboard items:", error);
+    }
+  };
+
   const handleClearHistory = async () => {
     const confirmed = await confirm(
-      "确定要清空所有非收藏的剪切板历史吗？此操作不可恢复。",
+      "确定要清空所有非收藏、非置顶的剪切板历史吗？此操作不可恢复。",
       {
         title: "确认清空",
         kind: "warning",
@@ -273,6 +661,7 @@ export function ClipboardWindow() {
 
     if (!confirmed) return;
 
+    setClearProgress({ progress: 0, message: "正在清空..." });
     try {
       await tauriApi.clearClipboardHistory();
       await loadClipboardItems();
@@ -280,6 +669,8 @@ export function ClipboardWindow() {
       setIsEditing(false);
     } catch (error) {
       console.error("Failed to clear clipboard history:", error);
+    } finally {
+      setClearProgress(null);
     }
   };
 
@@ -290,7 +681,9 @@ export function ClipboardWindow() {
         e.preventDefault();
         e.stopPropagation();
         
-        if (isEditing) {
+        if (phoneShareItemId) {
+          setPhoneShareItemId(null);
+        } else if (isEditing) {
           handleCancelEdit();
         } else if (selectedItem) {
           setSelectedItem(null);
@@ -304,7 +697,7 @@ export function ClipboardWindow() {
     return () => {
       document.removeEventListener("keydown", handleKeyDown, true);
     };
-  }, [isEditing, selectedItem, handleClose]);
+  }, [isEditing, selectedItem, handleClose, phoneShareItemId]);
 
   return (
     <div className="h-screen w-screen flex bg-gradient-to-br from-gray-50 via-white to-gray-50">
@@ -339,11 +732,25 @@ export function ClipboardWindow() {
                 type="text"
                 value={searchQuery}
                 onChange={(e) => setSearchQuery(e.target.value)}
-                placeholder="搜索剪切板内容..."
-                className="w-full px-4 py-2.5 pl-10 border border-gray-200 rounded-xl focus:outline-none focus:ring-2 focus:ring-blue-500/50 focus:border-blue-400 text-sm bg-white/80 shadow-sm transition-all duration-200 placeholder:text-gray-400"
+                placeholder={semanticMode ? "按意思搜索剪切板内容..." : "搜索剪切板内容，#标签 按标签筛选..."}
+                className="w-full px-4 py-2.5 pl-10 pr-16 border border-gray-200 rounded-xl focus:outline-none focus:ring-2 focus:ring-blue-500/50 focus:border-blue-400 text-sm bg-white/80 shadow-sm transition-all duration-200 placeholder:text-gray-400"
               />
               <span className="absolute left-3 top-1/2 transform -translate-y-1/2 text-gray-400 text-sm">🔍</span>
+              <button
+                onClick={() => setSemanticMode((mode) => !mode)}
+                className={`absolute right-2 top-1/2 transform -translate-y-1/2 px-2 py-0.5 text-xs rounded-md border transition-all duration-200 ${
+                  semanticMode
+                    ? "bg-purple-50 text-purple-700 border-purple-200"
+                    : "text-gray-500 border-gray-200 hover:border-gray-300"
+                }`}
+                title="语义搜索：按意思查找相似内容（需在 AI 设置中填写向量模型）"
+              >
+                语义
+              </button>
             </div>
+            {semanticError && (
+              <p className="mt-1 text-xs text-red-600">语义搜索失败：{semanticError}</p>
+            )}
           </div>
 
           {/* Settings Panel */}
@@ -382,6 +789,76 @@ export function ClipboardWindow() {
                 <p className="text-xs text-gray-500 mt-2 leading-relaxed">
                   超过此数量时自动删除最旧记录（0=不限制，收藏不受影响）
                 </p>
+                <label className="block text-sm font-semibold text-gray-700 mt-3 mb-2">
+                  保留天数
+                </label>
+                <div className="flex items-center gap-2">
+                  <input
+                    type="number"
+                    min="0"
+                    max="3650"
+                    value={tempMaxAgeDays}
+                    onChange={(e) => {
+                      const value = parseInt(e.target.value, 10) || 0;
+                      setTempMaxAgeDays(value);
+                    }}
+                    onKeyDown={(e) => {
+                      if (e.key === "Enter") {
+                        saveMaxAgeDays();
+                      }
+                    }}
+                    className="flex-1 px-3 py-2 text-sm border border-gray-300 rounded-lg focus:outline-none focus:ring-2 focus:ring-blue-500/50 focus:border-blue-400 bg-white shadow-sm"
+                  />
+                  <span className="text-sm text-gray-600 font-medium">天</span>
+                  <button
+                    onClick={saveMaxAgeDays}
+                    disabled={tempMaxAgeDays === maxAgeDays}
+                    className="px-3 py-2 text-xs font-medium bg-gradient-to-r from-blue-500 to-indigo-600 text-white rounded-lg hover:from-blue-600 hover:to-indigo-700 transition-all duration-200 shadow-md shadow-blue-500/30 hover:shadow-lg hover:shadow-blue-500/40 disabled:opacity-50 disabled:cursor-not-allowed disabled:hover:shadow-md whitespace-nowrap"
+                  >
+                    💾 保存
+                  </button>
+                </div>
+                <p className="text-xs text-gray-500 mt-2 leading-relaxed">
+                  自动删除早于此天数的记录及其图片（0=永久保留，收藏和置顶不受影响）
+                </p>
+                <label className="flex items-center gap-2 mt-3 text-sm text-gray-700 cursor-pointer">
+                  <input
+                    type="checkbox"
+                    checked={dedupCollapseWhitespace}
+                    onChange={(e) => saveDedupCollapseWhitespace(e.target.checked)}
+                    className="rounded border-gray-300"
+                  />
+                  去重时忽略空白差异
+                </label>
+                <p className="text-xs text-gray-500 mt-1 leading-relaxed">
+                  首尾空白和换行不同的文本始终视为重复；开启后缩进、空格数量不同的文本也视为重复（保存的内容不变）
+                </p>
+                <label className="flex items-center gap-2 mt-3 text-sm text-gray-700 cursor-pointer">
+                  <input
+                    type="checkbox"
+                    checked={maskSensitive}
+                    onChange={(e) => saveSensitiveSettings(e.target.checked, sensitiveExpireMinutes)}
+                    className="rounded border-gray-300"
+                  />
+                  列表中隐藏敏感内容
+                </label>
+                <div className="flex items-center gap-2 mt-2 text-sm text-gray-700">
+                  <span>敏感内容保留</span>
+                  <select
+                    value={sensitiveExpireMinutes}
+                    onChange={(e) => saveSensitiveSettings(maskSensitive, parseInt(e.target.value, 10))}
+                    className="px-2 py-1 text-sm border border-gray-300 rounded-lg bg-white"
+                  >
+                    <option value={0}>不单独清理</option>
+                    <option value={1}>1 分钟</option>
+                    <option value={5}>5 分钟</option>
+                    <option value={15}>15 分钟</option>
+                    <option value={60}>1 小时</option>
+                  </select>
+                </div>
+                <p className="text-xs text-gray-500 mt-1 leading-relaxed">
+                  看起来像密码、银行卡号、API 密钥的内容标记为 🔒，打码显示，复制时仍为原文；到期自动删除（收藏和置顶不受影响）
+                </p>
               </div>
             </div>
           )}
@@ -419,8 +896,38 @@ export function ClipboardWindow() {
           >
             图片
           </button>
+          <button
+            onClick={() => setContentTypeFilter("file")}
+            className={`flex-1 px-4 py-2 text-sm font-medium rounded-lg transition-all duration-200 ${
+              contentTypeFilter === "file"
+                ? "bg-gradient-to-r from-blue-500 to-indigo-600 text-white shadow-md shadow-blue-500/30 scale-105"
+                : "text-gray-600 hover:bg-gray-50 border border-gray-200 hover:border-gray-300 hover:shadow-sm"
+            }`}
+          >
+            文件
+          </button>
         </div>
 
+        {/* Tag Filter */}
+        {allTags.length > 0 && (
+          <div className="px-3 py-2 border-b border-gray-200/60 bg-white/50 flex flex-wrap gap-1.5 max-h-20 overflow-y-auto">
+            {allTags.map((tag) => {
+              const active = splitTagFilter(searchQuery).tags.includes(tag.name.toLowerCase());
+              return (
+                <button
+                  key={tag.name}
+                  onClick={() => toggleTagFilter(tag.name)}
+                  className={`text-xs px-1.5 py-0.5 rounded transition-colors ${
+                    active ? "bg-emerald-600 text-white" : "text-emerald-700 bg-emerald-50 hover:bg-emerald-100"
+                  }`}
+                >
+                  #{tag.name} <span className="opacity-60">{tag.count}</span>
+                </button>
+              );
+            })}
+          </div>
+        )}
+
         {/* Actions */}
         <div className="p-3 border-b border-gray-200/60 bg-white/50 flex gap-2">
           <button
@@ -431,12 +938,99 @@ export function ClipboardWindow() {
           </button>
           <button
             onClick={handleClearHistory}
-            className="flex-1 px-4 py-2 text-sm font-medium text-red-600 hover:bg-gradient-to-r hover:from-red-50 hover:to-pink-50 rounded-lg transition-all duration-200 border border-red-200 hover:border-red-300 hover:shadow-sm"
+            disabled={clearProgress !== null}
+            className="flex-1 px-4 py-2 text-sm font-medium text-red-600 hover:bg-gradient-to-r hover:from-red-50 hover:to-pink-50 rounded-lg transition-all duration-200 border border-red-200 hover:border-red-300 hover:shadow-sm disabled:opacity-50"
           >
             🗑️ 清空历史
           </button>
+          <button
+            onClick={() => (multiSelect ? exitMultiSelect() : setMultiSelect(true))}
+            className={`flex-1 px-4 py-2 text-sm font-medium rounded-lg transition-all duration-200 border ${
+              multiSelect
+                ? "bg-blue-50 text-blue-600 border-blue-300"
+                : "text-gray-600 border-gray-200 hover:border-gray-300 hover:shadow-sm"
+            }`}
+          >
+            ☑️ 多选
+          </button>
         </div>
 
+        {clearProgress && (
+          <div className="px-3 py-2 border-b border-gray-200/60 bg-white/50">
+            <div className="flex items-center justify-between text-xs text-gray-500 mb-1">
+              <span className="truncate">{clearProgress.message}</span>
+              <span>{clearProgress.progress}%</span>
+            </div>
+            <div className="h-1.5 bg-gray-100 rounded-full overflow-hidden">
+              <div
+                className="h-full bg-red-400 transition-all duration-200"
+                style={{ width: `${clearProgress.progress}%` }}
+              />
+            </div>
+          </div>
+        )}
+
+        {/* Batch Actions */}
+        {multiSelect && (
+          <div className="p-3 border-b border-gray-200/60 bg-blue-50/40 space-y-2">
+            <div className="flex items-center gap-2 text-xs">
+              <span className="text-gray-600">已选 {checkedIds.size} 项</span>
+              <button
+                onClick={() => setCheckedIds(new Set(filteredItems.map((item) => item.id)))}
+                className="text-blue-600 hover:underline"
+              >
+                全选
+              </button>
+              <button onClick={() => setCheckedIds(new Set())} className="text-gray-500 hover:underline">
+                清除
+              </button>
+              <div className="flex-1" />
+              <button
+                onClick={() => handleBatchFavorite(true)}
+                disabled={checkedIds.size === 0}
+                className="px-2 py-1 rounded-md border border-yellow-200 text-yellow-700 hover:bg-yellow-50 disabled:opacity-50"
+              >
+                ⭐ 收藏
+              </button>
+              <button
+                onClick={() => handleBatchFavorite(false)}
+                disabled={checkedIds.size === 0}
+                className="px-2 py-1 rounded-md border border-gray-200 text-gray-600 hover:bg-gray-50 disabled:opacity-50"
+              >
+                取消收藏
+              </button>
+              <button
+                onClick={handleBatchDelete}
+                disabled={checkedIds.size === 0}
+                className="px-2 py-1 rounded-md border border-red-200 text-red-600 hover:bg-red-50 disabled:opacity-50"
+              >
+                删除
+              </button>
+            </div>
+            <div className="flex gap-2">
+              <input
+                type="text"
+                value={batchTags}
+                onChange={(e) => setBatchTags(e.target.value)}
+                onKeyDown={(e) => {
+                  if (e.key === "Enter") {
+                    handleBatchTag();
+                  }
+                }}
+                placeholder="标签，多个用逗号分隔"
+                className="flex-1 px-2 py-1 text-xs border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500/50 bg-white"
+              />
+              <button
+                onClick={handleBatchTag}
+                disabled={checkedIds.size === 0 || !batchTags.trim()}
+                className="px-2 py-1 text-xs rounded-md border border-blue-200 text-blue-600 hover:bg-blue-50 disabled:opacity-50"
+              >
+                添加标签
+              </button>
+            </div>
+          </div>
+        )}
+
         {/* List */}
         <div className="flex-1 overflow-y-auto p-2 bg-gradient-to-b from-white to-gray-50/30">
           {filteredItems.length === 0 ? (
@@ -470,33 +1064,52 @@ export function ClipboardWindow() {
                     }
                   }}
                   data-item-id={item.id}
-                  onClick={() => setSelectedItem(item)}
+                  onClick={() => (multiSelect ? toggleChecked(item.id) : setSelectedItem(item))}
                   className={`group p-3 cursor-pointer transition-all duration-200 rounded-xl border ${
-                    selectedItem?.id === item.id
+                    (multiSelect ? checkedIds.has(item.id) : selectedItem?.id === item.id)
                       ? "bg-gradient-to-r from-blue-50 to-indigo-50 border-blue-300 shadow-md shadow-blue-200/30 scale-[1.02]"
                       : "bg-white/80 border-gray-200/60 hover:border-blue-200/60 hover:bg-gradient-to-r hover:from-gray-50/80 hover:to-blue-50/30 hover:shadow-sm"
                   }`}
                 >
                   <div className="flex items-start justify-between gap-2 mb-2">
                     <div className="flex items-center gap-2 flex-1 min-w-0">
+                      {multiSelect && (
+                        <input
+                          type="checkbox"
+                          checked={checkedIds.has(item.id)}
+                          readOnly
+                          className="rounded border-gray-300 pointer-events-none"
+                        />
+                      )}
                       <span className="text-xs font-bold text-gray-400 bg-gray-200/60 px-1.5 py-0.5 rounded min-w-[24px] text-center flex-shrink-0">
                         {index + 1}
                       </span>
                       <span className="text-xs font-medium text-gray-500 bg-gray-100/80 px-2 py-0.5 rounded-md">
                         {formatRelativeTime(item.created_at)}
                       </span>
+                      {item.pinned && (
+                        <span className="text-base" title="置顶">
+                          📌
+                        </span>
+                      )}
+                      {item.is_sensitive && (
+                        <span className="text-base" title="敏感内容">
+                          🔒
+                        </span>
+                      )}
                       {item.is_favorite && (
                         <span className="text-yellow-500 text-base drop-shadow-sm" title="收藏">
                           ⭐
                         </span>
                       )}
+                      {item.tags?.map((tag) => (
+                        <span key={tag} className="text-xs text-emerald-700 bg-emerald-50 px-1.5 py-0.5 rounded truncate">
+                          #{tag}
+                        </span>
+                      ))}
                     </div>
-                    <span className={`text-xs px-2.5 py-1 rounded-md font-medium flex-shrink-0 ${
-                      item.content_type === "image"
-                        ? "bg-purple-100 text-purple-700"
-                        : "bg-blue-100 text-blue-700"
-                    }`}>
-                      {item.content_type === "image" ? "🖼️ 图片" : "📝 文字"}
+                    <span className={`text-xs px-2.5 py-1 rounded-md font-medium flex-shrink-0 ${typeBadge(item.content_type).className}`}>
+                      {typeBadge(item.content_type).label}
                     </span>
                   </div>
                   {item.content_type === "image" ? (
@@ -515,11 +1128,44 @@ export function ClipboardWindow() {
                           📷
                         </div>
                       )}
-                      <span className="text-sm text-gray-600 font-medium">图片内容</span>
+                      {item.snippet ? (
+                        <span className="text-sm text-gray-600 line-clamp-2">
+                          {splitSnippet(item.snippet).map((part, index) =>
+                            part.match ? (
+                              <mark key={index} className="bg-yellow-200 text-gray-900 rounded-sm">
+                                {part.text}
+                              </mark>
+                            ) : (
+                              <span key={index}>{part.text}</span>
+                            )
+                          )}
+                        </span>
+                      ) : (
+                        <span className="text-sm text-gray-600 font-medium">图片内容</span>
+                      )}
+                    </div>
+                  ) : item.content_type === "file" ? (
+                    <div className="text-sm text-gray-800 leading-relaxed bg-gray-50/50 rounded-md p-2 border border-gray-100 truncate">
+                      {filePaths(item.content).map(fileName).join("、")}
+                      {filePaths(item.content).length > 1 && (
+                        <span className="text-gray-400 ml-1">（{filePaths(item.content).length} 个文件）</span>
+                      )}
                     </div>
                   ) : (
                     <div className="text-sm text-gray-800 line-clamp-2 leading-relaxed bg-gray-50/50 rounded-md p-2 border border-gray-100">
-                      {item.content || <span className="text-gray-400 italic">(空内容)</span>}
+                      {item.snippet ? (
+                        splitSnippet(item.snippet).map((part, index) =>
+                          part.match ? (
+                            <mark key={index} className="bg-yellow-200 text-gray-900 rounded-sm">
+                              {part.text}
+                            </mark>
+                          ) : (
+                            <span key={index}>{part.text}</span>
+                          )
+                        )
+                      ) : (
+                        item.content || <span className="text-gray-400 italic">(空内容)</span>
+                      )}
                     </div>
                   )}
                 </div>
@@ -540,13 +1186,14 @@ export function ClipboardWindow() {
                   <h3 className="text-base sm:text-lg font-bold bg-gradient-to-r from-gray-800 to-gray-600 bg-clip-text text-transparent whitespace-nowrap">
                     {isEditing ? "✏️ 编辑内容" : "📄 详细内容"}
                   </h3>
-                  <span className={`text-xs px-2.5 sm:px-3 py-1 sm:py-1.5 rounded-lg font-medium whitespace-nowrap flex-shrink-0 ${
-                    selectedItem.content_type === "image"
-                      ? "bg-purple-100 text-purple-700"
-                      : "bg-blue-100 text-blue-700"
-                  }`}>
-                    {selectedItem.content_type === "image" ? "🖼️ 图片" : "📝 文字"}
+                  <span className={`text-xs px-2.5 sm:px-3 py-1 sm:py-1.5 rounded-lg font-medium whitespace-nowrap flex-shrink-0 ${typeBadge(selectedItem.content_type).className}`}>
+                    {typeBadge(selectedItem.content_type).label}
                   </span>
+                  {selectedItem.has_html && (
+                    <span className="text-xs px-2 py-1 rounded-md font-medium whitespace-nowrap flex-shrink-0 bg-rose-100 text-rose-700">
+                      富文本
+                    </span>
+                  )}
                 </div>
                 <div className="flex gap-1.5 flex-wrap min-w-0">
                 {isEditing ? (
@@ -574,6 +1221,25 @@ export function ClipboardWindow() {
                       ✏️ 编辑
                     </button>
                     )}
+                    {selectedItem.content_type === "text" && (
+                    <select
+                      value=""
+                      disabled={!!aiTask && !aiTask.error}
+                      onChange={(e) => {
+                        if (e.target.value) {
+                          handleAiAction(selectedItem, e.target.value as ClipboardAiAction);
+                        }
+                      }}
+                      className="px-2 py-1 text-xs font-medium text-purple-600 rounded-lg border border-purple-200 hover:border-purple-300 bg-white disabled:opacity-50 whitespace-nowrap flex-shrink-0"
+                    >
+                      <option value="">✨ AI 处理</option>
+                      {AI_ACTIONS.map((a) => (
+                        <option key={a.action} value={a.action}>
+                          {a.label}
+                        </option>
+                      ))}
+                    </select>
+                    )}
                     <button
                       onClick={() => handleToggleFavorite(selectedItem)}
                       className={`px-2.5 py-1 text-xs font-medium rounded-lg transition-all duration-200 border whitespace-nowrap flex-shrink-0 ${
@@ -584,12 +1250,95 @@ export function ClipboardWindow() {
                     >
                       {selectedItem.is_favorite ? "⭐ 取消收藏" : "☆ 收藏"}
                     </button>
+                    <button
+                      onClick={() => handleTogglePin(selectedItem)}
+                      className={`px-2.5 py-1 text-xs font-medium rounded-lg transition-all duration-200 border whitespace-nowrap flex-shrink-0 ${
+                        selectedItem.pinned
+                          ? "text-red-700 bg-red-50 border-red-200 hover:bg-red-100 shadow-sm"
+                          : "text-gray-600 hover:bg-gray-50 border-gray-200 hover:border-gray-300"
+                      }`}
+                    >
+                      {selectedItem.pinned ? "📌 取消置顶" : "📌 置顶"}
+                    </button>
+                    {selectedItem.pinned && (
+                      <>
+                        <button
+                          onClick={() => handleMovePinned(selectedItem, -1)}
+                          className="px-2 py-1 text-xs font-medium text-gray-600 hover:bg-gray-50 rounded-lg border border-gray-200 hover:border-gray-300 flex-shrink-0"
+                          title="在置顶项中上移"
+                        >
+                          ↑
+                        </button>
+                        <button
+                          onClick={() => handleMovePinned(selectedItem, 1)}
+                          className="px-2 py-1 text-xs font-medium text-gray-600 hover:bg-gray-50 rounded-lg border border-gray-200 hover:border-gray-300 flex-shrink-0"
+                          title="在置顶项中下移"
+                        >
+                          ↓
+                        </button>
+                      </>
+                    )}
                     <button
                       onClick={() => handleCopyToClipboard(selectedItem)}
                       className="px-2.5 py-1 text-xs font-medium text-green-600 hover:bg-gradient-to-r hover:from-green-50 hover:to-emerald-50 rounded-lg transition-all duration-200 border border-green-200 hover:border-green-300 hover:shadow-sm whitespace-nowrap flex-shrink-0"
                     >
                       📋 复制
                     </button>
+                    {selectedItem.has_html && (
+                      <button
+                        onClick={() => handleCopyToClipboard(selectedItem, true)}
+                        className="px-2.5 py-1 text-xs font-medium text-gray-600 hover:bg-gray-50 rounded-lg transition-all duration-200 border border-gray-200 hover:border-gray-300 hover:shadow-sm whitespace-nowrap flex-shrink-0"
+                      >
+                        复制纯文本
+                      </button>
+                    )}
+                    {COPY_AS_FORMATS[selectedItem.content_type] && (
+                      <select
+                        value=""
+                        onChange={(e) => {
+                          if (e.target.value) {
+                            handleCopyAs(selectedItem, e.target.value as ClipboardCopyFormat);
+                          }
+                        }}
+                        title={copyAsResult?.itemId === selectedItem.id ? copyAsResult.error ?? "已复制" : "以另一种形式复制"}
+                        className="px-2 py-1 text-xs font-medium text-green-600 rounded-lg border border-green-200 hover:border-green-300 bg-white whitespace-nowrap flex-shrink-0"
+                      >
+                        <option value="">
+                          {copyAsResult?.itemId !== selectedItem.id
+                            ? "复制为…"
+                            : copyAsResult.error
+                              ? "⚠️ 复制失败"
+                              : "✅ 已复制"}
+                        </option>
+                        {COPY_AS_FORMATS[selectedItem.content_type].map((f) => (
+                          <option key={f.format} value={f.format}>
+                            {f.label}
+                          </option>
+                        ))}
+                      </select>
+                    )}
+                    <button
+                      onClick={() => setPhoneShareItemId(selectedItem.id)}
+                      className="px-2.5 py-1 text-xs font-medium text-indigo-600 hover:bg-gradient-to-r hover:from-indigo-50 hover:to-blue-50 rounded-lg transition-all duration-200 border border-indigo-200 hover:border-indigo-300 hover:shadow-sm whitespace-nowrap flex-shrink-0"
+                    >
+                      📱 发送到手机
+                    </button>
+                    {selectedItem.content_type === "text" && (
+                      <button
+                        onClick={() => handleCreatePasteLink(selectedItem)}
+                        disabled={pasteLink?.itemId === selectedItem.id && !pasteLink.url && !pasteLink.error}
+                        title={pasteLink?.itemId === selectedItem.id ? pasteLink.url ?? pasteLink.error : "上传到粘贴服务并复制链接"}
+                        className="px-2.5 py-1 text-xs font-medium text-teal-600 hover:bg-gradient-to-r hover:from-teal-50 hover:to-cyan-50 rounded-lg transition-all duration-200 border border-teal-200 hover:border-teal-300 hover:shadow-sm whitespace-nowrap flex-shrink-0 disabled:opacity-50"
+                      >
+                        {pasteLink?.itemId !== selectedItem.id
+                          ? "🔗 粘贴链接"
+                          : pasteLink.url
+                            ? "✅ 链接已复制"
+                            : pasteLink.error
+                              ? "⚠️ 分享失败"
+                              : "⏳ 上传中..."}
+                      </button>
+                    )}
                     <button
                       onClick={() => handleDelete(selectedItem)}
                       className="px-2.5 py-1 text-xs font-medium text-red-600 hover:bg-gradient-to-r hover:from-red-50 hover:to-pink-50 rounded-lg transition-all duration-200 border border-red-200 hover:border-red-300 hover:shadow-sm whitespace-nowrap flex-shrink-0"
@@ -628,6 +1377,41 @@ export function ClipboardWindow() {
                       })}
                     </span>
                   </div>
+                  <div className="mb-4 px-4 py-2 text-sm text-gray-600 bg-gradient-to-r from-gray-50 to-emerald-50/50 rounded-lg border border-gray-200/60 flex-shrink-0 flex items-center gap-2">
+                    <span className="font-medium whitespace-nowrap">🏷️ 标签:</span>
+                    <input
+                      key={`${selectedItem.id}:${(selectedItem.tags ?? []).join(",")}`}
+                      type="text"
+                      defaultValue={(selectedItem.tags ?? []).join(", ")}
+                      onBlur={(e) => handleSaveTags(selectedItem, e.target.value)}
+                      onKeyDown={(e) => {
+                        if (e.key === "Enter" && !e.nativeEvent.isComposing) {
+                          handleSaveTags(selectedItem, e.currentTarget.value);
+                        }
+                      }}
+                      placeholder="多个标签用逗号分隔，如 项目A, sql"
+                      className="flex-1 min-w-0 px-2 py-1 text-sm bg-white border border-gray-200 rounded focus:outline-none focus:ring-1 focus:ring-emerald-400"
+                    />
+                  </div>
+                  {aiTask && aiTask.sourceId === selectedItem.id && (
+                    <div className="mb-4 px-4 py-3 text-sm bg-purple-50/60 rounded-lg border border-purple-200/60 flex-shrink-0 max-h-60 overflow-y-auto">
+                      <div className="flex items-center justify-between mb-1">
+                        <span className="font-medium text-purple-700">
+                          ✨ {aiTask.label}{aiTask.error ? "失败" : aiTask.output ? "" : "中..."}
+                        </span>
+                        {aiTask.error && (
+                          <button onClick={() => setAiTask(null)} className="text-xs text-gray-500 hover:text-gray-700">
+                            关闭
+                          </button>
+                        )}
+                      </div>
+                      {aiTask.error ? (
+                        <div className="text-red-600">{aiTask.error}</div>
+                      ) : (
+                        <pre className="whitespace-pre-wrap break-words font-mono text-sm text-gray-800 m-0">{aiTask.output}</pre>
+                      )}
+                    </div>
+                  )}
                   {selectedItem.content_type === "image" ? (
                     <div className="flex-1 flex items-center justify-center min-h-0 bg-gradient-to-br from-gray-50/50 to-blue-50/30 rounded-xl border-2 border-dashed border-gray-200 p-8">
                       {imageDataUrls.has(selectedItem.content) ? (
@@ -646,11 +1430,28 @@ export function ClipboardWindow() {
                         </div>
                       )}
                     </div>
+                  ) : selectedItem.content_type === "file" ? (
+                    <div className="flex-1 min-h-0 bg-white rounded-xl border-2 border-gray-200/60 shadow-inner overflow-y-auto p-3">
+                      {filePaths(selectedItem.content).map((path) => (
+                        <div key={path} className="px-2 py-1.5 rounded-md hover:bg-gray-50" title={path}>
+                          <div className="text-sm text-gray-800 truncate">📄 {fileName(path)}</div>
+                          <div className="text-xs text-gray-400 truncate">{path}</div>
+                        </div>
+                      ))}
+                    </div>
                   ) : (
                     <div className="flex-1 min-h-0 bg-white rounded-xl border-2 border-gray-200/60 shadow-inner overflow-hidden">
                       <div className="h-full overflow-y-auto p-5">
+                        {selectedItem.is_sensitive && (
+                          <button
+                            onClick={() => handleToggleReveal(selectedItem)}
+                            className="mb-3 px-2.5 py-1 text-xs font-medium text-gray-600 rounded-lg border border-gray-200 hover:border-gray-300 hover:bg-gray-50"
+                          >
+                            {revealed?.id === selectedItem.id ? "🙈 隐藏" : "👁 显示原文"}
+                          </button>
+                        )}
                         <pre className="whitespace-pre-wrap break-words font-mono text-sm text-gray-800 leading-relaxed m-0">
-                          {selectedItem.content || (
+                          {(revealed?.id === selectedItem.id ? revealed.content : selectedItem.content) || (
                             <span className="text-gray-400 italic">(空内容)</span>
                           )}
                         </pre>
@@ -675,6 +1476,8 @@ export function ClipboardWindow() {
           </div>
         )}
       </div>
+
+      <PhoneShareModal itemId={phoneShareItemId} onClose={() => setPhoneShareItemId(null)} />
     </div>
   );
 }
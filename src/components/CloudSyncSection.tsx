@@ -0,0 +1,267 @@
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { SyncConfig, SyncConflict, SyncStatus, SyncSummary } from "../types";
+import { formatFullDateTime } from "../utils/dateUtils";
+
+const DATASETS: { id: string; label: string }[] = [
+  { id: "settings", label: "设置" },
+  { id: "snippets", label: "片段（备忘录）" },
+  { id: "wordbook", label: "单词本" },
+];
+
+const inputClassName =
+  "w-full px-3 py-1.5 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500";
+
+function formatSummary(summary: SyncSummary): string {
+  return `拉取 ${summary.pulled} 条，推送 ${summary.pushed} 条${summary.conflicts ? `，冲突 ${summary.conflicts} 条` : ""}`;
+}
+
+/**
+ * 云同步设置：配置 WebDAV / S3 存储和同步口令，选择要同步的数据，查看同步状态与冲突日志
+ */
+export function CloudSyncSection() {
+  const [config, setConfig] = useState<SyncConfig | null>(null);
+  const [secret, setSecret] = useState("");
+  const [passphrase, setPassphrase] = useState("");
+  const [status, setStatus] = useState<SyncStatus | null>(null);
+  const [conflicts, setConflicts] = useState<SyncConflict[] | null>(null);
+  const [busy, setBusy] = useState(false);
+  const [message, setMessage] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const refreshStatus = () => {
+    tauriApi
+      .getSyncStatus()
+      .then(setStatus)
+      .catch((e) => console.error("获取同步状态失败:", e));
+  };
+
+  useEffect(() => {
+    tauriApi
+      .getSyncConfig()
+      .then(setConfig)
+      .catch((e) => setError(String(e)));
+    refreshStatus();
+    const unlisten = listen<SyncSummary>("cloud-sync-completed", refreshStatus);
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  if (!config) return null;
+
+  const update = (changes: Partial<SyncConfig>) => setConfig({ ...config, ...changes });
+
+  const toggleDataset = (id: string) =>
+    update({
+      datasets: config.datasets.includes(id) ? config.datasets.filter((d) => d !== id) : [...config.datasets, id],
+    });
+
+  const run = async (action: () => Promise<string>) => {
+    setBusy(true);
+    setError(null);
+    setMessage(null);
+    try {
+      setMessage(await action());
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBusy(false);
+      refreshStatus();
+    }
+  };
+
+  const saveConfig = () =>
+    run(async () => {
+      const { has_secret, has_passphrase, ...rest } = config;
+      const saved = await tauriApi.saveSyncConfig({
+        ...rest,
+        secret: secret || null,
+        passphrase: passphrase || null,
+      });
+      setConfig(saved);
+      setSecret("");
+      setPassphrase("");
+      return "同步设置已保存";
+    });
+
+  const syncNow = () => run(async () => `同步完成：${formatSummary(await tauriApi.syncNow())}`);
+
+  const toggleConflicts = async () => {
+    if (conflicts) {
+      setConflicts(null);
+      return;
+    }
+    try {
+      setConflicts(await tauriApi.listSyncConflicts());
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const isS3 = config.backend === "s3";
+
+  return (
+    <div className="bg-white rounded-lg shadow-sm border border-gray-200 p-6">
+      <div className="flex items-center justify-between">
+        <div>
+          <h3 className="text-lg font-medium text-gray-700">云同步</h3>
+          <p className="text-sm text-gray-500 mt-1">
+            通过你自己的 WebDAV 或 S3 存储在多台设备间同步，数据在本机用同步口令加密后再上传
+          </p>
+        </div>
+        <label className="flex items-center gap-2 text-sm text-gray-700 shrink-0">
+          <input type="checkbox" checked={config.enabled} onChange={(e) => update({ enabled: e.target.checked })} />
+          启用
+        </label>
+      </div>
+
+      <div className="grid grid-cols-2 gap-3 mt-4 text-sm">
+        <label className="col-span-2 flex items-center gap-4">
+          <span className="w-20 text-gray-600">存储类型</span>
+          <select
+            value={config.backend}
+            onChange={(e) => update({ backend: e.target.value as SyncConfig["backend"] })}
+            className="px-2 py-1.5 text-sm border border-gray-300 rounded-md"
+          >
+            <option value="webdav">WebDAV</option>
+            <option value="s3">S3 兼容存储</option>
+          </select>
+        </label>
+        <input
+          type="text"
+          value={config.endpoint}
+          onChange={(e) => update({ endpoint: e.target.value })}
+          placeholder={isS3 ? "服务地址，如 https://s3.us-east-1.amazonaws.com" : "WebDAV 目录地址，如 https://dav.example.com/imiss"}
+          className={`${inputClassName} col-span-2`}
+        />
+        {isS3 && (
+          <>
+            <input
+              type="text"
+              value={config.bucket}
+              onChange={(e) => update({ bucket: e.target.value })}
+              placeholder="存储桶"
+              className={inputClassName}
+            />
+            <input
+              type="text"
+              value={config.region}
+              onChange={(e) => update({ region: e.target.value })}
+              placeholder="区域，默认 us-east-1"
+              className={inputClassName}
+            />
+          </>
+        )}
+        <input
+          type="text"
+          value={config.username}
+          onChange={(e) => update({ username: e.target.value })}
+          placeholder={isS3 ? "Access Key" : "用户名"}
+          className={inputClassName}
+        />
+        <input
+          type="password"
+          value={secret}
+          onChange={(e) => setSecret(e.target.value)}
+          placeholder={config.has_secret ? "已保存，留空则不修改" : isS3 ? "Secret Key" : "密码"}
+          className={inputClassName}
+        />
+        <input
+          type="password"
+          value={passphrase}
+          onChange={(e) => setPassphrase(e.target.value)}
+          placeholder={config.has_passphrase ? "同步口令已设置，留空则不修改" : "同步口令（所有设备需一致，遗忘后无法解密）"}
+          className={`${inputClassName} col-span-2`}
+        />
+      </div>
+
+      <div className="flex flex-wrap items-center gap-4 mt-4 text-sm text-gray-700">
+        {DATASETS.map((dataset) => (
+          <label key={dataset.id} className="flex items-center gap-1.5">
+            <input
+              type="checkbox"
+              checked={config.datasets.includes(dataset.id)}
+              onChange={() => toggleDataset(dataset.id)}
+            />
+            {dataset.label}
+          </label>
+        ))}
+        <label className="flex items-center gap-1.5 ml-auto">
+          每
+          <input
+            type="number"
+            min={0}
+            value={config.interval_minutes}
+            onChange={(e) => update({ interval_minutes: Math.max(0, parseInt(e.target.value) || 0) })}
+            className="w-16 px-2 py-1 text-sm border border-gray-300 rounded-md"
+          />
+          分钟自动同步（0 为仅手动）
+        </label>
+      </div>
+      <p className="text-xs text-gray-400 mt-2">API Key、代理和本机路径等设置不会同步</p>
+
+      <div className="flex items-center gap-2 mt-4">
+        <button
+          onClick={saveConfig}
+          disabled={busy}
+          className="px-4 py-1.5 text-sm bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50"
+        >
+          保存
+        </button>
+        <button
+          onClick={syncNow}
+          disabled={busy || status?.running || !config.enabled}
+          className="px-4 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200 disabled:opacity-50"
+        >
+          {busy || status?.running ? "同步中..." : "立即同步"}
+        </button>
+        <button onClick={toggleConflicts} className="px-3 py-1.5 text-sm text-gray-600 hover:bg-gray-100 rounded-md">
+          {conflicts ? "隐藏冲突日志" : "冲突日志"}
+        </button>
+      </div>
+
+      {message && <div className="text-xs text-blue-600 mt-2">{message}</div>}
+      {error && <div className="text-xs text-red-500 mt-2 break-all">{error}</div>}
+      {status?.last_sync_at && (
+        <div className="text-xs text-gray-500 mt-2">
+          上次同步：{formatFullDateTime(status.last_sync_at)}
+          {status.last_summary && `，${formatSummary(status.last_summary)}`}
+        </div>
+      )}
+      {status?.last_error && <div className="text-xs text-gray-400 mt-1 break-all">最近错误：{status.last_error}</div>}
+
+      {conflicts && (
+        <div className="mt-3 border-t border-gray-100 pt-3">
+          {conflicts.length === 0 ? (
+            <div className="text-xs text-gray-400">没有冲突记录</div>
+          ) : (
+            <>
+              <div className="max-h-60 overflow-y-auto space-y-1">
+                {conflicts.map((conflict) => (
+                  <details key={conflict.id} className="text-xs text-gray-600">
+                    <summary className="cursor-pointer">
+                      {formatFullDateTime(conflict.created_at)} ·{" "}
+                      {DATASETS.find((d) => d.id === conflict.dataset)?.label ?? conflict.dataset} · {conflict.record_id} ·{" "}
+                      {conflict.winner === "remote" ? "采用了其他设备的版本" : "保留了本机的版本"}
+                    </summary>
+                    <pre className="mt-1 p-2 bg-gray-50 rounded whitespace-pre-wrap break-all">
+                      {`本机：${JSON.stringify(conflict.local_data, null, 2)}\n其他设备：${JSON.stringify(conflict.remote_data, null, 2)}`}
+                    </pre>
+                  </details>
+                ))}
+              </div>
+              <button
+                onClick={() => tauriApi.clearSyncConflicts().then(() => setConflicts([]))}
+                className="mt-2 px-2 py-1 text-xs text-red-600 hover:bg-red-50 rounded"
+              >
+                清空冲突日志
+              </button>
+            </>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
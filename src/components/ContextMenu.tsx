@@ -1,13 +1,18 @@
-import { useRef, useEffect } from "react";
+import { useRef, useEffect, useState } from "react";
 import type { SearchResult } from "../utils/resultUtils";
+import type { OpenWithAction } from "../types";
+import { tauriApi } from "../api/tauri";
 
 interface ContextMenuProps {
   menu: { x: number; y: number; result: SearchResult } | null;
   onClose: () => void;
   onRevealInFolder: () => Promise<void>;
+  onPeekArchive?: (path: string) => void;
+  onAnalyzeFolder?: (path: string) => void;
   onEditMemo: () => void;
   onDeleteMemo: (memoId: string) => Promise<void>;
   onOpenUrl: (url: string) => Promise<void>;
+  onOpenFolderWith?: (actionId: string, path: string) => Promise<void>;
   onDeleteHistory?: (key: string) => Promise<void>;
   onEditRemark?: (url: string) => Promise<void>;
   onCopyJson: (json: string) => Promise<void>;
@@ -22,9 +27,12 @@ export function ContextMenu({
   menu,
   onClose,
   onRevealInFolder,
+  onPeekArchive,
+  onAnalyzeFolder,
   onEditMemo,
   onDeleteMemo,
   onOpenUrl,
+  onOpenFolderWith,
   onDeleteHistory,
   onEditRemark,
   onCopyJson,
@@ -35,6 +43,27 @@ export function ContextMenu({
   onCloseMemoModal,
 }: ContextMenuProps) {
   const contextMenuRef = useRef<HTMLDivElement>(null);
+  const [openWithActions, setOpenWithActions] = useState<OpenWithAction[]>([]);
+  const isGitRepo = menu?.result.type === "git_repo";
+
+  // Git 仓库结果提供设置中的"打开方式"动作（终端、VS Code 等）
+  useEffect(() => {
+    if (!isGitRepo || !onOpenFolderWith) {
+      return;
+    }
+    let cancelled = false;
+    tauriApi
+      .getOpenWithActions()
+      .then((actions) => {
+        if (!cancelled) {
+          setOpenWithActions(actions);
+        }
+      })
+      .catch((error) => console.warn("[打开方式] 获取动作失败:", error));
+    return () => {
+      cancelled = true;
+    };
+  }, [isGitRepo, onOpenFolderWith]);
 
   // Close context menu when clicking outside
   useEffect(() => {
@@ -71,14 +100,21 @@ export function ContextMenu({
   // 检查是否是 UWP 应用（shell:AppsFolder 路径），UWP 应用没有传统意义上的所在文件夹
   const isUwpApp = menu.result.path.toLowerCase().startsWith("shell:appsfolder");
   const canRevealInFolder = hasFileMenu && !isUwpApp;
+  const canPeekArchive =
+    !!onPeekArchive && canRevealInFolder && menu.result.path.toLowerCase().endsWith(".zip");
+  const canAnalyzeFolder =
+    !!onAnalyzeFolder &&
+    canRevealInFolder &&
+    !!(menu.result.file?.is_folder || menu.result.everything?.is_folder);
   
   const hasMemoMenu = menu.result.type === "memo" && menu.result.memo;
   const hasUrlMenu = menu.result.type === "url" && menu.result.url;
   const hasJsonMenu = menu.result.type === "json_formatter" && menu.result.jsonContent;
   const hasAiMenu = menu.result.type === "ai" && menu.result.aiAnswer;
+  const hasOpenWithMenu = isGitRepo && !!onOpenFolderWith && openWithActions.length > 0;
 
   // 如果没有菜单项，不显示菜单
-  if (!canRevealInFolder && !hasMemoMenu && !hasUrlMenu && !hasJsonMenu && !hasAiMenu) {
+  if (!canRevealInFolder && !hasMemoMenu && !hasUrlMenu && !hasJsonMenu && !hasAiMenu && !hasOpenWithMenu) {
     return null;
   }
 
@@ -128,8 +164,66 @@ export function ContextMenu({
           >
             打开所在文件夹
           </button>
+          {canPeekArchive && (
+            <button
+              onClick={(e) => {
+                e.preventDefault();
+                e.stopPropagation();
+                onPeekArchive?.(menu.result.path);
+                onClose();
+              }}
+              onMouseDown={(e) => {
+                e.preventDefault();
+                e.stopPropagation();
+              }}
+              className="w-full text-left px-4 py-2 text-sm hover:bg-gray-100 transition-colors"
+            >
+              查看压缩包内容
+            </button>
+          )}
+          {canAnalyzeFolder && (
+            <button
+              onClick={(e) => {
+                e.preventDefault();
+                e.stopPropagation();
+                onAnalyzeFolder?.(menu.result.path);
+                onClose();
+              }}
+              onMouseDown={(e) => {
+                e.preventDefault();
+                e.stopPropagation();
+              }}
+              className="w-full text-left px-4 py-2 text-sm hover:bg-gray-100 transition-colors"
+            >
+              分析文件夹大小
+            </button>
+          )}
         </>
       )}
+      {hasOpenWithMenu &&
+        openWithActions.map((action) => (
+          <button
+            key={action.id}
+            onClick={async (e) => {
+              e.preventDefault();
+              e.stopPropagation();
+              try {
+                await onOpenFolderWith!(action.id, menu.result.path);
+              } catch (error) {
+                console.error("Failed to open folder with action:", error);
+                alert(`${action.name}失败: ${error}`);
+              }
+              onClose();
+            }}
+            onMouseDown={(e) => {
+              e.preventDefault();
+              e.stopPropagation();
+            }}
+            className="w-full text-left px-4 py-2 text-sm hover:bg-gray-100 transition-colors"
+          >
+            {action.name}
+          </button>
+        ))}
       {hasMemoMenu && (
         <>
           <button
@@ -0,0 +1,272 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { DisplayMode, DisplayPreset, MonitorInfo } from "../types";
+
+interface DisplayControlPanelProps {
+  keyword: string;
+  brightness: number | null;
+  nightLight: boolean;
+}
+
+const PRESET_NAME_INPUT = "display-preset-name";
+
+const modeKey = (mode: DisplayMode) => `${mode.width}x${mode.height}@${mode.frequency}`;
+
+/**
+ * 显示器控制面板：调节各显示器亮度、切换分辨率与刷新率、开关夜间模式、应用或保存显示预设
+ * 回车行为由查询决定："亮度 50" 调亮度、"夜间模式" 开关夜间模式、"display 预设名" 应用预设
+ */
+export function DisplayControlPanel({ keyword, brightness, nightLight }: DisplayControlPanelProps) {
+  const [monitors, setMonitors] = useState<MonitorInfo[]>([]);
+  const [modes, setModes] = useState<Record<string, DisplayMode[]>>({});
+  const [nightLightOn, setNightLightOn] = useState<boolean | null>(null);
+  const [presets, setPresets] = useState<DisplayPreset[]>([]);
+  const [presetName, setPresetName] = useState("");
+  const [error, setError] = useState<string | null>(null);
+  const [busy, setBusy] = useState(false);
+
+  const refresh = () => {
+    tauriApi
+      .listMonitors()
+      .then((list) => {
+        setMonitors(list);
+        list.forEach((monitor) => {
+          tauriApi
+            .listDisplayModes(monitor.id)
+            .then((items) => setModes((current) => ({ ...current, [monitor.id]: items })))
+            .catch(() => {});
+        });
+      })
+      .catch((e) => setError(String(e)));
+    tauriApi
+      .getNightLight()
+      .then(setNightLightOn)
+      .catch(() => setNightLightOn(null));
+    tauriApi
+      .getSettings()
+      .then((settings) => setPresets(settings.display_presets ?? []))
+      .catch(() => {});
+  };
+
+  useEffect(refresh, []);
+
+  const filteredPresets = keyword
+    ? presets.filter((p) => p.name.toLowerCase().includes(keyword.toLowerCase()))
+    : presets;
+  const bestPreset = keyword ? filteredPresets[0] ?? null : null;
+
+  const run = async (action: () => Promise<unknown>) => {
+    setBusy(true);
+    setError(null);
+    try {
+      await action();
+      refresh();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleBrightnessChange = (monitor: MonitorInfo, value: number) => {
+    setMonitors((current) => current.map((m) => (m.id === monitor.id ? { ...m, brightness: value } : m)));
+  };
+
+  // 松开滑块时才写入：笔记本屏幕经 WMI 调节，每次调用都要启动 PowerShell
+  const handleBrightnessCommit = (monitor: MonitorInfo) => {
+    if (monitor.brightness == null) return;
+    tauriApi.setMonitorBrightness(monitor.id, monitor.brightness).catch((e) => setError(String(e)));
+  };
+
+  const handleModeChange = (monitor: MonitorInfo, key: string) => {
+    const mode = modes[monitor.id]?.find((m) => modeKey(m) === key);
+    if (!mode) return;
+    run(() => tauriApi.setDisplayMode(monitor.id, mode.width, mode.height, mode.frequency));
+  };
+
+  const handleToggleNightLight = () => {
+    if (nightLightOn === null) return;
+    run(() => tauriApi.setNightLight(!nightLightOn));
+  };
+
+  const handleSavePreset = async () => {
+    const name = presetName.trim();
+    const primary = monitors.find((m) => m.primary) ?? monitors[0];
+    if (!name || !primary) return;
+    await run(async () => {
+      const settings = await tauriApi.getSettings();
+      const preset: DisplayPreset = {
+        name,
+        monitor: primary.id,
+        width: primary.width,
+        height: primary.height,
+        frequency: primary.frequency,
+      };
+      const others = (settings.display_presets ?? []).filter((p) => p.name !== name);
+      await tauriApi.saveSettings({ ...settings, display_presets: [...others, preset] });
+      setPresetName("");
+    });
+  };
+
+  const handleDeletePreset = (name: string) => {
+    run(async () => {
+      const settings = await tauriApi.getSettings();
+      const display_presets = (settings.display_presets ?? []).filter((p) => p.name !== name);
+      await tauriApi.saveSettings({ ...settings, display_presets });
+    });
+  };
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    let action: (() => Promise<unknown>) | null = null;
+    if (brightness !== null) {
+      const adjustable = monitors.filter((m) => m.brightness != null);
+      if (adjustable.length > 0) {
+        action = () => Promise.all(adjustable.map((m) => tauriApi.setMonitorBrightness(m.id, brightness)));
+      }
+    } else if (nightLight && nightLightOn !== null) {
+      action = () => tauriApi.setNightLight(!nightLightOn);
+    } else if (bestPreset) {
+      action = () => tauriApi.applyDisplayPreset(bestPreset.name);
+    }
+    if (!action || busy) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      // 预设名称输入框中的回车用于保存预设
+      if (e.target instanceof HTMLInputElement && e.target.name === PRESET_NAME_INPUT) return;
+      e.preventDefault();
+      e.stopPropagation();
+      run(action!);
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [brightness, nightLight, nightLightOn, bestPreset, monitors, busy]);
+
+  const enterHint =
+    brightness !== null
+      ? `回车将亮度调整为 ${brightness}%`
+      : nightLight && nightLightOn !== null
+        ? `回车${nightLightOn ? "关闭" : "开启"}夜间模式`
+        : bestPreset
+          ? `回车应用预设「${bestPreset.name}」`
+          : null;
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {enterHint && <div className="text-xs text-blue-600 mb-2">{enterHint}</div>}
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {monitors.length === 0 && !error && <div className="text-xs text-gray-400">正在读取显示器...</div>}
+      {monitors.map((monitor) => (
+        <div key={monitor.id} className="mb-2">
+          <div className="flex items-center gap-2">
+            <span className="flex-1 truncate text-gray-800">
+              {monitor.name}
+              {monitor.primary && <span className="ml-1 text-xs text-gray-400">主显示器</span>}
+            </span>
+            <select
+              value={modeKey(monitor)}
+              onChange={(e) => handleModeChange(monitor, e.target.value)}
+              disabled={busy || !modes[monitor.id]}
+              className="text-xs border border-gray-200 rounded px-1 py-0.5"
+            >
+              {!modes[monitor.id]?.some((m) => modeKey(m) === modeKey(monitor)) && (
+                <option value={modeKey(monitor)}>
+                  {monitor.width}x{monitor.height} @ {monitor.frequency}Hz
+                </option>
+              )}
+              {(modes[monitor.id] ?? []).map((mode) => (
+                <option key={modeKey(mode)} value={modeKey(mode)}>
+                  {mode.width}x{mode.height} @ {mode.frequency}Hz
+                </option>
+              ))}
+            </select>
+          </div>
+          {monitor.brightness != null ? (
+            <div className="flex items-center gap-3 mt-1">
+              <span className="w-10 text-xs text-gray-500">亮度</span>
+              <input
+                type="range"
+                min={0}
+                max={100}
+                value={monitor.brightness}
+                onChange={(e) => handleBrightnessChange(monitor, Number(e.target.value))}
+                onPointerUp={() => handleBrightnessCommit(monitor)}
+                onKeyUp={() => handleBrightnessCommit(monitor)}
+                className="flex-1"
+              />
+              <span className="w-10 text-right text-xs text-gray-500">{monitor.brightness}%</span>
+            </div>
+          ) : (
+            <div className="mt-1 text-xs text-gray-400">该显示器不支持调节亮度（需开启 DDC/CI）</div>
+          )}
+        </div>
+      ))}
+      {nightLightOn !== null && (
+        <div className="flex items-center gap-2 py-1">
+          <span className="flex-1 text-gray-800">夜间模式</span>
+          <button
+            onClick={handleToggleNightLight}
+            disabled={busy}
+            className={`px-2 py-1 text-xs rounded ${nightLightOn ? "bg-amber-50 text-amber-700" : "bg-gray-100 text-gray-700"}`}
+          >
+            {nightLightOn ? "已开启" : "已关闭"}
+          </button>
+        </div>
+      )}
+      <div className="mt-2 pt-2 border-t border-gray-100">
+        <div className="text-xs text-gray-500 mb-1">显示预设</div>
+        {filteredPresets.map((preset) => (
+          <div
+            key={preset.name}
+            className={`flex items-center gap-2 px-2 py-1 rounded ${preset.name === bestPreset?.name ? "bg-blue-50" : "hover:bg-gray-100"}`}
+          >
+            <span className="flex-1 truncate text-gray-800">{preset.name}</span>
+            <span className="text-xs text-gray-400">
+              {preset.width}x{preset.height}
+              {preset.frequency ? ` @ ${preset.frequency}Hz` : ""}
+            </span>
+            <button
+              onClick={() => run(() => tauriApi.applyDisplayPreset(preset.name))}
+              disabled={busy}
+              className="text-xs text-blue-600 hover:underline"
+            >
+              应用
+            </button>
+            <button
+              onClick={() => handleDeletePreset(preset.name)}
+              disabled={busy}
+              className="text-xs text-gray-400 hover:text-red-500"
+            >
+              删除
+            </button>
+          </div>
+        ))}
+        {filteredPresets.length === 0 && (
+          <div className="text-xs text-gray-400">{presets.length === 0 ? "暂无预设" : "没有匹配的预设"}</div>
+        )}
+        <div className="flex items-center gap-2 mt-1">
+          <input
+            name={PRESET_NAME_INPUT}
+            value={presetName}
+            onChange={(e) => setPresetName(e.target.value)}
+            onKeyDown={(e) => {
+              if (e.key === "Enter" && !e.nativeEvent.isComposing) {
+                e.stopPropagation();
+                handleSavePreset();
+              }
+            }}
+            placeholder="预设名称"
+            className="flex-1 text-xs border border-gray-200 rounded px-2 py-1"
+          />
+          <button
+            onClick={handleSavePreset}
+            disabled={busy || !presetName.trim() || monitors.length === 0}
+            className="px-2 py-1 text-xs rounded bg-gray-100 text-gray-700 disabled:opacity-50"
+          >
+            保存主显示器当前模式
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,144 @@
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { FolderScanProgress, FolderSizeNode } from "../types";
+import { formatBytes } from "../utils/formatUtils";
+
+interface FolderSizeModalProps {
+  folderPath: string | null;
+  onClose: () => void;
+}
+
+function FolderSizeRow({ node, parentSize, depth }: { node: FolderSizeNode; parentSize: number; depth: number }) {
+  const [expanded, setExpanded] = useState(depth === 0);
+  const percent = parentSize > 0 ? (node.size / parentSize) * 100 : 0;
+  const expandable = node.is_dir && (node.children.length > 0 || node.other_size > 0);
+
+  return (
+    <div>
+      <button
+        onClick={() => expandable && setExpanded(!expanded)}
+        onDoubleClick={() => tauriApi.revealInFolder(node.path)}
+        className="w-full flex items-center gap-2 py-1 pr-2 text-left text-sm hover:bg-gray-100 rounded"
+        style={{ paddingLeft: `${depth * 16 + 4}px` }}
+        title={node.path}
+      >
+        <span className="w-3 text-xs text-gray-400">{expandable ? (expanded ? "▾" : "▸") : ""}</span>
+        <span className={`flex-1 truncate ${node.is_dir ? "text-gray-800" : "text-gray-600"}`}>{node.name}</span>
+        <span className="w-24 h-1.5 bg-gray-100 rounded overflow-hidden shrink-0">
+          <span className="block h-full bg-blue-500" style={{ width: `${percent}%` }} />
+        </span>
+        <span className="w-20 text-right text-xs text-gray-500 shrink-0">{formatBytes(node.size)}</span>
+      </button>
+      {expanded && (
+        <>
+          {node.children.map((child) => (
+            <FolderSizeRow key={child.path} node={child} parentSize={node.size} depth={depth + 1} />
+          ))}
+          {node.other_size > 0 && (
+            <div
+              className="flex items-center gap-2 py-1 pr-2 text-xs text-gray-400"
+              style={{ paddingLeft: `${(depth + 1) * 16 + 24}px` }}
+            >
+              <span className="flex-1">{node.children.length > 0 ? "其他较小的项目" : "子项目"}</span>
+              <span className="w-20 text-right shrink-0">{formatBytes(node.other_size)}</span>
+            </div>
+          )}
+        </>
+      )}
+    </div>
+  );
+}
+
+/**
+ * 文件夹大小分析弹窗：扫描时显示进度并可取消，完成后以可展开的树显示各项占用空间
+ */
+export function FolderSizeModal({ folderPath, onClose }: FolderSizeModalProps) {
+  const [tree, setTree] = useState<FolderSizeNode | null>(null);
+  const [progress, setProgress] = useState<FolderScanProgress | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [isScanning, setIsScanning] = useState(false);
+
+  useEffect(() => {
+    setTree(null);
+    setProgress(null);
+    setError(null);
+    if (!folderPath) return;
+
+    let cancelled = false;
+    const unlisten = listen<FolderScanProgress>("folder-scan://progress", (event) => {
+      if (!cancelled) setProgress(event.payload);
+    });
+    setIsScanning(true);
+    tauriApi
+      .scanFolderSizes(folderPath)
+      .then((result) => {
+        if (!cancelled) setTree(result);
+      })
+      .catch((e) => {
+        if (!cancelled) setError(String(e));
+      })
+      .finally(() => {
+        if (!cancelled) setIsScanning(false);
+      });
+
+    return () => {
+      cancelled = true;
+      unlisten.then((fn) => fn());
+      // 关闭弹窗时停止仍在进行的扫描
+      tauriApi.cancelFolderScan().catch(() => {});
+    };
+  }, [folderPath]);
+
+  if (!folderPath) return null;
+
+  return (
+    <div className="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50" onClick={onClose}>
+      <div
+        className="bg-white rounded-lg shadow-xl p-4 max-w-2xl w-full mx-4 flex flex-col"
+        style={{ maxHeight: "80vh" }}
+        onClick={(e) => e.stopPropagation()}
+      >
+        <h2 className="text-base font-semibold mb-1">文件夹大小分析</h2>
+        <div className="text-xs text-gray-500 mb-3 truncate" title={folderPath}>
+          {folderPath}
+        </div>
+        {isScanning && (
+          <div className="text-xs text-gray-600 mb-3">
+            <div>
+              正在扫描：已统计 {progress?.files_scanned ?? 0} 个文件，共 {formatBytes(progress?.bytes_scanned ?? 0)}
+            </div>
+            {progress?.current_path && <div className="truncate text-gray-400">{progress.current_path}</div>}
+          </div>
+        )}
+        {error && <div className="text-xs text-red-600 mb-2 break-all">{error}</div>}
+        {tree && (
+          <>
+            <div className="text-xs text-gray-500 mb-2">
+              共 {formatBytes(tree.size)}，{tree.file_count} 个文件（双击打开所在位置）
+            </div>
+            <div className="flex-1 overflow-y-auto border border-gray-100 rounded-md p-1">
+              <FolderSizeRow node={tree} parentSize={tree.size} depth={0} />
+            </div>
+          </>
+        )}
+        <div className="flex justify-end gap-2 mt-3">
+          {isScanning && (
+            <button
+              onClick={() => tauriApi.cancelFolderScan()}
+              className="px-3 py-1.5 text-xs text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200 transition-colors"
+            >
+              取消扫描
+            </button>
+          )}
+          <button
+            onClick={onClose}
+            className="px-3 py-1.5 text-xs text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200 transition-colors"
+          >
+            关闭
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,161 @@
+import { useEffect, useState } from "react";
+import { open } from "@tauri-apps/plugin-dialog";
+import { tauriApi } from "../api/tauri";
+import type { GitPullResult, GitSyncStatus } from "../types";
+import { formatFullDateTime } from "../utils/dateUtils";
+
+const inputClassName =
+  "px-3 py-1.5 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500";
+
+function formatPullResult(result: GitPullResult): string {
+  const parts = [
+    result.settings_updated && "设置已更新",
+    (result.snippets_updated || result.snippets_removed) &&
+      `片段更新 ${result.snippets_updated} 条、删除 ${result.snippets_removed} 条`,
+    (result.quicklinks_updated || result.quicklinks_removed) &&
+      `快捷链接更新 ${result.quicklinks_updated} 条、删除 ${result.quicklinks_removed} 条`,
+  ].filter(Boolean);
+  if (parts.length === 0) return result.merged ? "已合并远端提交，配置没有变化" : "已是最新";
+  return `拉取完成：${parts.join("，")}`;
+}
+
+/**
+ * Git 配置同步：把设置、片段和快捷链接导出为 git 仓库中的 JSON 文件，提交、推送与拉取合并
+ */
+export function GitSyncSection() {
+  const [status, setStatus] = useState<GitSyncStatus | null>(null);
+  const [repoPath, setRepoPath] = useState("");
+  const [subdir, setSubdir] = useState("");
+  const [commitMessage, setCommitMessage] = useState("");
+  const [push, setPush] = useState(true);
+  const [busy, setBusy] = useState(false);
+  const [message, setMessage] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const applyStatus = (next: GitSyncStatus) => {
+    setStatus(next);
+    setRepoPath(next.repo_path);
+    setSubdir(next.subdir);
+  };
+
+  useEffect(() => {
+    tauriApi
+      .getGitSyncStatus()
+      .then(applyStatus)
+      .catch((e) => setError(String(e)));
+  }, []);
+
+  const run = async (action: () => Promise<string>) => {
+    setBusy(true);
+    setError(null);
+    setMessage(null);
+    try {
+      setMessage(await action());
+      applyStatus(await tauriApi.getGitSyncStatus());
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleBrowse = async () => {
+    const selected = await open({ directory: true, multiple: false });
+    if (typeof selected === "string") setRepoPath(selected);
+  };
+
+  const saveConfig = () =>
+    run(async () => {
+      applyStatus(await tauriApi.saveGitSyncConfig({ repo_path: repoPath, subdir }));
+      return "仓库设置已保存";
+    });
+
+  const commit = () =>
+    run(async () => {
+      const result = await tauriApi.gitSyncCommit(commitMessage.trim() || null, push && !!status?.remote);
+      setCommitMessage("");
+      if (!result.committed) return result.pushed ? "没有新的修改，已推送" : "没有需要提交的修改";
+      return `已提交 ${result.commit?.hash ?? ""}${result.pushed ? " 并推送" : ""}`;
+    });
+
+  const pull = () => run(async () => formatPullResult(await tauriApi.gitSyncPull()));
+
+  const configChanged = !!status && (repoPath !== status.repo_path || subdir !== status.subdir);
+
+  return (
+    <div className="bg-white rounded-lg shadow-sm border border-gray-200 p-6">
+      <h3 className="text-lg font-medium text-gray-700">Git 配置同步</h3>
+      <p className="text-sm text-gray-500 mt-1 mb-4">
+        把设置、片段和快捷链接导出为仓库中的 JSON 文件，提交后可推送到远端；拉取时先提交本机修改再合并远端，合并结果导入本机
+      </p>
+      <div className="flex gap-2 text-sm">
+        <input
+          type="text"
+          value={repoPath}
+          onChange={(e) => setRepoPath(e.target.value)}
+          placeholder="仓库目录，不是 git 仓库时自动初始化"
+          className={`${inputClassName} flex-1`}
+        />
+        <input
+          type="text"
+          value={subdir}
+          onChange={(e) => setSubdir(e.target.value)}
+          placeholder="子目录，默认 imiss"
+          className={`${inputClassName} w-32`}
+        />
+        <button onClick={handleBrowse} className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200">
+          浏览
+        </button>
+        <button
+          onClick={saveConfig}
+          disabled={busy || !configChanged}
+          className="px-3 py-1.5 text-sm bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50"
+        >
+          保存
+        </button>
+      </div>
+
+      {status?.is_repo && (
+        <>
+          <div className="text-xs text-gray-500 mt-3">
+            分支 {status.branch ?? "（尚无提交）"} · {status.remote ? `远端 ${status.remote}` : "未配置远端"}
+            {status.last_commit &&
+              ` · 最近提交 ${status.last_commit.hash} ${status.last_commit.message}（${status.last_commit.author}，${formatFullDateTime(status.last_commit.time)}）`}
+            {status.has_changes && <span className="text-amber-600"> · 本机有未提交的修改</span>}
+          </div>
+          <div className="flex items-center gap-2 mt-3 text-sm">
+            <input
+              type="text"
+              value={commitMessage}
+              onChange={(e) => setCommitMessage(e.target.value)}
+              placeholder="提交说明（可选）"
+              className={`${inputClassName} flex-1`}
+            />
+            {status.remote && (
+              <label className="flex items-center gap-1.5 text-gray-700 shrink-0">
+                <input type="checkbox" checked={push} onChange={(e) => setPush(e.target.checked)} />
+                推送
+              </label>
+            )}
+            <button
+              onClick={commit}
+              disabled={busy}
+              className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200 disabled:opacity-50"
+            >
+              提交
+            </button>
+            <button
+              onClick={pull}
+              disabled={busy || !status.remote}
+              className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200 disabled:opacity-50"
+            >
+              拉取
+            </button>
+          </div>
+        </>
+      )}
+      {message && <div className="text-xs text-blue-600 mt-2">{message}</div>}
+      {error && <div className="text-xs text-red-500 mt-2 break-all">{error}</div>}
+    </div>
+  );
+}
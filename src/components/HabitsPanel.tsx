@@ -0,0 +1,139 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { Habit } from "../types";
+
+interface HabitsPanelProps {
+  keyword: string;
+  addInput: string | null;
+}
+
+function formatStreak(habit: Habit): string {
+  const unit = habit.streak_unit === "week" ? "周" : "天";
+  return `连续 ${habit.current_streak} ${unit} · 最长 ${habit.best_streak} ${unit}`;
+}
+
+/**
+ * 习惯面板：列出今天的习惯（待打卡的在前），回车为第一个匹配且未完成的习惯打卡，点击可打卡或撤销；
+ * 输入 "habit add <名称> [计划]" 回车添加习惯
+ */
+export function HabitsPanel({ keyword, addInput }: HabitsPanelProps) {
+  const [habits, setHabits] = useState<Habit[] | null>(null);
+  const [busy, setBusy] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+
+  const refresh = () => {
+    tauriApi
+      .listHabits()
+      .then(setHabits)
+      .catch((e) => setError(String(e)));
+  };
+
+  useEffect(refresh, []);
+
+  const run = async (action: () => Promise<string | void>) => {
+    setBusy(true);
+    setError(null);
+    try {
+      const result = await action();
+      if (result) setMessage(result);
+      refresh();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const toggle = (habit: Habit) =>
+    run(async () => {
+      if (habit.done_today) {
+        await tauriApi.undoHabitCheckIn(habit.id);
+        return `已撤销 ${habit.name} 的打卡`;
+      }
+      const updated = await tauriApi.checkInHabit(habit.id);
+      const unit = updated.streak_unit === "week" ? "周" : "天";
+      return `${updated.name} 已打卡，连续 ${updated.current_streak} ${unit}`;
+    });
+
+  const addHabit = (input: string) =>
+    run(async () => {
+      const habit = await tauriApi.addHabit(input);
+      return `已添加习惯 ${habit.name}（${habit.schedule}）`;
+    });
+
+  const lower = keyword.toLowerCase();
+  const visible = habits?.filter((h) => !lower || h.name.toLowerCase().includes(lower)) ?? [];
+  const nextDue = visible.find((h) => h.due_today && !h.done_today);
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    if (busy || (!addInput && !nextDue)) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      if (addInput) addHabit(addInput);
+      else if (nextDue) toggle(nextDue);
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [addInput, nextDue, busy]);
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {message && <div className="text-xs text-blue-600 mb-1">{message}</div>}
+      {addInput && (
+        <div className="px-2 py-1.5 mb-1 rounded bg-blue-50 text-gray-700 truncate">回车添加习惯 {addInput}</div>
+      )}
+      {!addInput && habits && visible.length === 0 && (
+        <div className="text-xs text-gray-400">
+          {habits.length === 0 ? '还没有习惯，输入 "habit add 名称 [每天/工作日/每周3次/周一三五]" 添加' : "没有匹配的习惯"}
+        </div>
+      )}
+      {!addInput &&
+        visible.map((habit) => (
+          <div
+            key={habit.id}
+            className={`group flex items-center gap-2 px-2 py-1.5 rounded ${habit === nextDue ? "bg-blue-50" : ""} ${
+              !habit.due_today && !habit.done_today ? "opacity-60" : ""
+            }`}
+          >
+            <button
+              onClick={() => toggle(habit)}
+              disabled={busy}
+              className={`w-4 h-4 shrink-0 rounded border ${
+                habit.done_today ? "bg-green-500 border-green-500" : "border-gray-300 hover:border-green-500"
+              }`}
+              title={habit.done_today ? "撤销今天的打卡" : "打卡"}
+            />
+            <span className="flex-1 min-w-0">
+              <span className={`block truncate ${habit.done_today ? "text-gray-400 line-through" : "text-gray-800"}`}>
+                {habit.name}
+              </span>
+              <span className="block truncate text-xs text-gray-400">
+                {habit.schedule}
+                {habit.week_progress ? `（本周 ${habit.week_progress[0]}/${habit.week_progress[1]}）` : ""} ·{" "}
+                {formatStreak(habit)} · 近 30 天 {Math.round(habit.completion_rate * 100)}%
+              </span>
+            </span>
+            <span className="flex gap-0.5 shrink-0" title="最近 7 天">
+              {habit.recent.map((done, i) => (
+                <span key={i} className={`w-1.5 h-3 rounded-sm ${done ? "bg-green-500" : "bg-gray-200"}`} />
+              ))}
+            </span>
+            <button
+              onClick={() => run(() => tauriApi.removeHabit(habit.id))}
+              className="px-2 py-0.5 text-xs text-red-600 hover:bg-red-50 rounded shrink-0 opacity-0 group-hover:opacity-100"
+            >
+              删除
+            </button>
+          </div>
+        ))}
+      {!addInput && nextDue && (
+        <div className="mt-1 text-xs text-blue-600 truncate">回车为 {nextDue.name} 打卡</div>
+      )}
+    </div>
+  );
+}
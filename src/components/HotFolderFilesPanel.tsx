@@ -0,0 +1,55 @@
+import { tauriApi } from "../api/tauri";
+import type { HotFolderFile } from "../types";
+import { formatBytes } from "../utils/formatUtils";
+import { formatRelativeTime } from "../utils/dateUtils";
+
+interface HotFolderFilesPanelProps {
+  files: HotFolderFile[];
+  onOpened: () => void;
+}
+
+function folderName(folder: string): string {
+  return folder.split(/[\\/]/).filter(Boolean).pop() || folder;
+}
+
+/**
+ * 启动器空查询时展示的热点文件夹最近文件（如刚下载完成的文件）
+ */
+export function HotFolderFilesPanel({ files, onOpened }: HotFolderFilesPanelProps) {
+  if (files.length === 0) return null;
+
+  const handleOpen = async (file: HotFolderFile) => {
+    try {
+      await tauriApi.launchFile(file.path);
+      onOpened();
+    } catch (error) {
+      console.error("[热点文件夹] 打开文件失败:", error);
+    }
+  };
+
+  return (
+    <div className="px-3 py-2 border-t border-gray-100">
+      <div className="text-xs text-gray-400 mb-1">最近新增的文件</div>
+      {files.map((file) => (
+        <button
+          key={file.path}
+          onClick={() => handleOpen(file)}
+          onContextMenu={(e) => {
+            e.preventDefault();
+            tauriApi.revealInFolder(file.path);
+          }}
+          className="w-full flex items-center gap-2 px-2 py-1.5 text-left text-sm rounded hover:bg-gray-100"
+          title={`${file.path}\n右键打开所在文件夹`}
+        >
+          <span className="flex-1 truncate text-gray-800">{file.name}</span>
+          {file.created && <span className="text-xs text-green-600 shrink-0">新</span>}
+          <span className="text-xs text-gray-400 shrink-0">{folderName(file.folder)}</span>
+          <span className="w-16 text-right text-xs text-gray-400 shrink-0">{formatBytes(file.size)}</span>
+          <span className="w-16 text-right text-xs text-gray-400 shrink-0">
+            {formatRelativeTime(Math.floor(file.modified / 1000))}
+          </span>
+        </button>
+      ))}
+    </div>
+  );
+}
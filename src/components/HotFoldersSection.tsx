@@ -0,0 +1,82 @@
+import { useState } from "react";
+import { open } from "@tauri-apps/plugin-dialog";
+
+interface HotFoldersSectionProps {
+  folders: string[];
+  onChange: (folders: string[]) => void;
+}
+
+/**
+ * 热点文件夹设置：登记需要监听的文件夹，启动器空查询时展示其中最近新建/修改的文件
+ */
+export function HotFoldersSection({ folders, onChange }: HotFoldersSectionProps) {
+  const [input, setInput] = useState("");
+
+  const addFolder = (folder: string) => {
+    const trimmed = folder.trim();
+    if (!trimmed || folders.includes(trimmed)) return;
+    onChange([...folders, trimmed]);
+  };
+
+  const handleBrowse = async () => {
+    const selected = await open({ directory: true, multiple: false });
+    if (typeof selected === "string") {
+      addFolder(selected);
+    }
+  };
+
+  return (
+    <div className="bg-white rounded-lg shadow-sm border border-gray-200 p-6">
+      <h3 className="text-lg font-medium text-gray-700">热点文件夹</h3>
+      <p className="text-sm text-gray-500 mt-1 mb-4">
+        监听这些文件夹中新建或修改的文件，启动器未输入内容时显示最近 7 天内的文件；支持 ~ 与 %USERPROFILE% 等环境变量
+      </p>
+      <div className="space-y-2 mb-3">
+        {folders.length === 0 && <div className="text-sm text-gray-400">尚未添加文件夹</div>}
+        {folders.map((folder) => (
+          <div key={folder} className="flex items-center gap-2 text-sm">
+            <span className="flex-1 truncate text-gray-700" title={folder}>
+              {folder}
+            </span>
+            <button
+              onClick={() => onChange(folders.filter((f) => f !== folder))}
+              className="px-2 py-1 text-xs text-red-600 hover:bg-red-50 rounded"
+            >
+              移除
+            </button>
+          </div>
+        ))}
+      </div>
+      <div className="flex gap-2">
+        <input
+          type="text"
+          value={input}
+          onChange={(e) => setInput(e.target.value)}
+          onKeyDown={(e) => {
+            if (e.key === "Enter") {
+              addFolder(input);
+              setInput("");
+            }
+          }}
+          placeholder="例如 ~/Downloads"
+          className="flex-1 px-3 py-1.5 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+        />
+        <button
+          onClick={() => {
+            addFolder(input);
+            setInput("");
+          }}
+          className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200"
+        >
+          添加
+        </button>
+        <button
+          onClick={handleBrowse}
+          className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200"
+        >
+          选择文件夹
+        </button>
+      </div>
+    </div>
+  );
+}
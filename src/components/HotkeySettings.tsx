@@ -1,6 +1,8 @@
 import { useState, useEffect, useRef } from "react";
 import { tauriApi } from "../api/tauri";
 import { plugins, pluginRegistry } from "../plugins";
+import { WindowHotkeysSection } from "./WindowHotkeysSection";
+import { formatAppRules, parseAppRules, scopesOverlap } from "../utils/appScope";
 
 interface HotkeySettingsProps {
   onClose: () => void;
@@ -9,6 +11,7 @@ interface HotkeySettingsProps {
 interface HotkeyConfig {
   modifiers: string[];
   key: string;
+  apps?: string[]; // 限定生效的程序，为空时全局生效
 }
 
 export function HotkeySettings({ onClose }: HotkeySettingsProps) {
@@ -131,7 +134,12 @@ export function HotkeySettings({ onClose }: HotkeySettingsProps) {
     }
   };
 
-  const savePluginHotkey = async (pluginId: string, config: HotkeyConfig | null) => {
+  const savePluginHotkey = async (pluginId: string, newConfig: HotkeyConfig | null) => {
+    // 重新录制按键时保留原来限定的程序
+    const config =
+      newConfig && newConfig.apps === undefined
+        ? { ...newConfig, apps: pluginHotkeys[pluginId]?.apps }
+        : newConfig;
     // 如果设置了快捷键，检查冲突
     if (config) {
       const conflict = checkHotkeyConflict(config, "plugin", pluginId);
@@ -188,13 +196,14 @@ export function HotkeySettings({ onClose }: HotkeySettingsProps) {
     // 对修饰键数组进行排序后比较
     const aMods = [...a.modifiers].sort().join(",");
     const bMods = [...b.modifiers].sort().join(",");
-    return aMods === bMods && a.key === b.key;
+    // 限定在不同程序中的相同按键互不冲突
+    return aMods === bMods && a.key === b.key && scopesOverlap(a.apps, b.apps);
   };
 
   // 检查快捷键冲突
   const checkHotkeyConflict = (
     newHotkey: HotkeyConfig,
-    excludeType?: "launcher" | "appCenter" | "plugin" | "app",
+    excludeType?: "launcher" | "appCenter" | "plugin" | "app" | "window",
     excludeId?: string
   ): string | null => {
     // 检查与启动器快捷键冲突
@@ -964,9 +973,24 @@ export function HotkeySettings({ onClose }: HotkeySettingsProps) {
                           <div className="text-xs text-gray-500 mt-1">{plugin.description}</div>
                         )}
                         {pluginHotkey ? (
-                          <div className="text-sm font-mono text-gray-600 mt-2">
-                            {formatHotkey(pluginHotkey)}
-                          </div>
+                          <>
+                            <div className="text-sm font-mono text-gray-600 mt-2">
+                              {formatHotkey(pluginHotkey)}
+                            </div>
+                            <input
+                              key={formatAppRules(pluginHotkey.apps)}
+                              type="text"
+                              defaultValue={formatAppRules(pluginHotkey.apps)}
+                              onBlur={(e) => {
+                                const apps = parseAppRules(e.target.value);
+                                if (formatAppRules(apps) !== formatAppRules(pluginHotkey.apps)) {
+                                  savePluginHotkey(plugin.id, { ...pluginHotkey, apps });
+                                }
+                              }}
+                              className="w-full mt-2 px-2 py-1 text-xs border border-gray-300 rounded focus:outline-none focus:ring-1 focus:ring-blue-500"
+                              placeholder="限定程序，如 code.exe, class:Notepad，加 ! 表示排除；留空为全局"
+                            />
+                          </>
                         ) : (
                           <div className="text-sm text-gray-400 mt-2">未设置</div>
                         )}
@@ -1029,6 +1053,16 @@ export function HotkeySettings({ onClose }: HotkeySettingsProps) {
               })}
             </div>
           </div>
+
+          {/* 窗口管理快捷键配置 */}
+          <WindowHotkeysSection
+            formatHotkey={formatHotkey}
+            checkConflict={(config) => checkHotkeyConflict(config, "window")}
+            onMessage={(message) => {
+              setSaveMessage(message);
+              setTimeout(() => setSaveMessage(null), message.startsWith("⚠️") ? 5000 : 2000);
+            }}
+          />
         </div>
       </div>
 
@@ -0,0 +1,124 @@
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { KeepAwakeStatus } from "../types";
+
+interface KeepAwakePanelProps {
+  minutes: number | null;
+  off: boolean;
+}
+
+const DURATIONS: Array<{ minutes: number | null; label: string }> = [
+  { minutes: 30, label: "30 分钟" },
+  { minutes: 60, label: "1 小时" },
+  { minutes: 120, label: "2 小时" },
+  { minutes: null, label: "一直保持" },
+];
+
+const formatDuration = (minutes: number | null) =>
+  minutes === null ? "一直" : minutes % 60 === 0 ? `${minutes / 60} 小时` : `${minutes} 分钟`;
+
+const formatTime = (millis: number) =>
+  new Date(millis).toLocaleTimeString([], { hour: "2-digit", minute: "2-digit" });
+
+/**
+ * 保持唤醒面板：显示当前状态与结束时间，可按预设时长开启或关闭；
+ * 输入 "awake 2h" 时回车按该时长开启，"awake off" 时回车关闭
+ */
+export function KeepAwakePanel({ minutes, off }: KeepAwakePanelProps) {
+  const [status, setStatus] = useState<KeepAwakeStatus | null>(null);
+  const [keepDisplay, setKeepDisplay] = useState(true);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    tauriApi
+      .getKeepAwakeStatus()
+      .then((s) => {
+        setStatus(s);
+        if (s.active) setKeepDisplay(s.keep_display);
+      })
+      .catch((e) => setError(String(e)));
+    // 到期结束或从托盘切换时同步状态
+    const unlisten = listen<KeepAwakeStatus>("keep-awake-changed", (event) => setStatus(event.payload));
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const run = async (action: () => Promise<KeepAwakeStatus>) => {
+    setError(null);
+    try {
+      setStatus(await action());
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const handleStart = (value: number | null) => run(() => tauriApi.startKeepAwake(value, keepDisplay));
+  const handleStop = () => run(() => tauriApi.stopKeepAwake());
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    if (!status || (off && !status.active)) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      if (off) {
+        handleStop();
+      } else {
+        handleStart(minutes);
+      }
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [status, minutes, off, keepDisplay]);
+
+  const enterHint = !status
+    ? null
+    : off
+      ? status.active
+        ? "回车关闭保持唤醒"
+        : null
+      : `回车保持唤醒（${formatDuration(minutes)}）`;
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {enterHint && <div className="text-xs text-blue-600 mb-2">{enterHint}</div>}
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {status && (
+        <>
+          <div className="flex items-center gap-2 mb-2">
+            <span className="flex-1 text-gray-800">
+              {status.active
+                ? status.until
+                  ? `保持唤醒至 ${formatTime(status.until)}`
+                  : "保持唤醒中"
+                : "未开启保持唤醒"}
+            </span>
+            {status.active && (
+              <button onClick={handleStop} className="px-2 py-1 text-xs rounded bg-gray-100 text-gray-700">
+                关闭
+              </button>
+            )}
+          </div>
+          <div className="flex items-center gap-2">
+            {DURATIONS.map((item) => (
+              <button
+                key={item.label}
+                onClick={() => handleStart(item.minutes)}
+                className="px-2 py-1 text-xs rounded bg-amber-50 text-amber-700 hover:bg-amber-100"
+              >
+                {item.label}
+              </button>
+            ))}
+            <label className="ml-auto flex items-center gap-1 text-xs text-gray-500">
+              <input type="checkbox" checked={keepDisplay} onChange={(e) => setKeepDisplay(e.target.checked)} />
+              同时阻止息屏
+            </label>
+          </div>
+        </>
+      )}
+    </div>
+  );
+}
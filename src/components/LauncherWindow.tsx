@@ -1,11 +1,34 @@
 import { useState, useEffect, useRef, useMemo, useCallback, startTransition } from "react";
 import { tauriApi } from "../api/tauri";
-import type { AppInfo, FileHistoryItem, EverythingResult, MemoItem, PluginContext, UpdateCheckResult, SearchEngineConfig } from "../types";
+import type { AliasConfig, AppInfo, BrowserHistoryItem, FileHistoryItem, EverythingResult, GameInfo, MemoItem, PluginContext, UpdateCheckResult, GitRepo, RecentProject, SearchEngineConfig, ScriptInfo, SshHost, StartupStatus, Workflow } from "../types";
 import { getCurrentWindow } from "@tauri-apps/api/window";
+import { listen } from "@tauri-apps/api/event";
 import { LogicalSize } from "@tauri-apps/api/window";
 import { plugins, executePlugin } from "../plugins";
 import { MemoModal } from "./MemoModal";
 import { RemarkEditModal } from "./RemarkEditModal";
+import { ArchivePeekModal } from "./ArchivePeekModal";
+import { FolderSizeModal } from "./FolderSizeModal";
+import { HotFolderFilesPanel } from "./HotFolderFilesPanel";
+import { NetworkToolsPanel } from "./NetworkToolsPanel";
+import { SystemMonitorPanel } from "./SystemMonitorPanel";
+import { AudioDevicesPanel } from "./AudioDevicesPanel";
+import { DisplayControlPanel } from "./DisplayControlPanel";
+import { RadioTogglesPanel } from "./RadioTogglesPanel";
+import { WindowActionsPanel } from "./WindowActionsPanel";
+import { VirtualDesktopsPanel } from "./VirtualDesktopsPanel";
+import { KeepAwakePanel } from "./KeepAwakePanel";
+import { TextTransformPanel } from "./TextTransformPanel";
+import { PasswordGeneratorPanel } from "./PasswordGeneratorPanel";
+import { TotpPanel } from "./TotpPanel";
+import { WorldClockPanel } from "./WorldClockPanel";
+import { QuotesPanel } from "./QuotesPanel";
+import { RssPanel } from "./RssPanel";
+import { TimersPanel } from "./TimersPanel";
+import { CalendarPanel } from "./CalendarPanel";
+import { HabitsPanel } from "./HabitsPanel";
+import { ScriptPanel } from "./ScriptPanel";
+import { WorkflowPanel } from "./WorkflowPanel";
 import { PluginListModal } from "./PluginListModal";
 import { ContextMenu } from "./ContextMenu";
 import { ErrorDialog } from "./ErrorDialog";
@@ -19,6 +42,7 @@ import { getMainContainer as getMainContainerUtil } from "../utils/windowUtils";
 import type { SearchResult } from "../utils/resultUtils";
 import { askAi } from "../utils/ollamaUtils";
 import { handleLaunch as handleLaunchUtil } from "../utils/launchUtils";
+import { detectSearchIntent, parseNetworkQuery, isSystemMonitorQuery, parseAudioQuery, parseDisplayQuery, parseRadioQuery, parseWindowQuery, parseDesktopQuery, parseKeepAwakeQuery, parseTextTransformQuery, parsePasswordQuery, parseTotpQuery, parseWorldClockQuery, parseQuoteQuery, parseRssQuery, parseTimerQuery, parseCalendarQuery, parseHabitQuery, parseScriptQuery, parseWorkflowQuery } from "../utils/searchUtils";
 import {
   startEverythingSearchSession,
   closeEverythingSession,
@@ -34,6 +58,8 @@ import { useSearchWrappers } from "../hooks/useSearchWrappers";
 import { useCombinedResults } from "../hooks/useCombinedResults";
 import { useSearch } from "../hooks/useSearch";
 import { useScrollbarStyle } from "../hooks/useScrollbarStyle";
+import { useSearchProviders, isProviderEnabled } from "../hooks/useSearchProviders";
+import { useHotFolderFiles } from "../hooks/useHotFolderFiles";
 import {
   processPastedPath as processPastedPathUtil,
   handlePaste as handlePasteUtil,
@@ -108,8 +134,51 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
   const [isPluginListModalOpen, setIsPluginListModalOpen] = useState(false);
   const [openHistory, setOpenHistory] = useState<Record<string, number>>({});
   const [isRemarkModalOpen, setIsRemarkModalOpen] = useState(false);
+  const [archivePeekPath, setArchivePeekPath] = useState<string | null>(null); // 正在查看内容的压缩包
+  const [folderSizePath, setFolderSizePath] = useState<string | null>(null); // 正在分析大小的文件夹
   const [editingRemarkUrl, setEditingRemarkUrl] = useState<string | null>(null);
   const [searchEngines, setSearchEngines] = useState<SearchEngineConfig[]>([]);
+  const [quoteWatchlist, setQuoteWatchlist] = useState<string[]>([]);
+  const [aliases, setAliases] = useState<AliasConfig[]>([]); // 设置中的别名
+  const [scripts, setScripts] = useState<ScriptInfo[]>([]);
+  const [workflows, setWorkflows] = useState<Workflow[]>([]);
+  const [rankingBoosts, setRankingBoosts] = useState<Record<string, number>>({});
+  const { providers: searchProviders, providersRef: searchProvidersRef } = useSearchProviders();
+  // 按关键字触发的面板，设置中停用的数据源不解析查询
+  const enabled = (provider: string) => isProviderEnabled(searchProviders, provider);
+  const networkQuery = useMemo(() => (enabled("network_tools") ? parseNetworkQuery(query) : null), [query, searchProviders]);
+  const showSystemMonitor = enabled("system_monitor") && isSystemMonitorQuery(query);
+  const audioQuery = useMemo(() => (enabled("audio_devices") ? parseAudioQuery(query) : null), [query, searchProviders]);
+  const displayQuery = useMemo(() => (enabled("display_control") ? parseDisplayQuery(query) : null), [query, searchProviders]);
+  const radioQuery = useMemo(() => (enabled("radios") ? parseRadioQuery(query) : null), [query, searchProviders]);
+  const windowQuery = useMemo(() => (enabled("window_actions") ? parseWindowQuery(query) : null), [query, searchProviders]);
+  const desktopQuery = useMemo(() => (enabled("virtual_desktops") ? parseDesktopQuery(query) : null), [query, searchProviders]);
+  const keepAwakeQuery = useMemo(() => (enabled("keep_awake") ? parseKeepAwakeQuery(query) : null), [query, searchProviders]);
+  const textTransformQuery = useMemo(() => (enabled("text_transform") ? parseTextTransformQuery(query) : null), [query, searchProviders]);
+  const passwordQuery = useMemo(() => (enabled("password_gen") ? parsePasswordQuery(query) : null), [query, searchProviders]);
+  const totpQuery = useMemo(() => (enabled("totp") ? parseTotpQuery(query) : null), [query, searchProviders]);
+  const worldClockQuery = useMemo(() => (enabled("world_clock") ? parseWorldClockQuery(query) : null), [query, searchProviders]);
+  const quoteQuery = useMemo(() => (enabled("quotes") ? parseQuoteQuery(query, quoteWatchlist) : null), [query, quoteWatchlist, searchProviders]);
+  const rssQuery = useMemo(() => (enabled("rss") ? parseRssQuery(query) : null), [query, searchProviders]);
+  const timerQuery = useMemo(() => (enabled("timers") ? parseTimerQuery(query) : null), [query, searchProviders]);
+  const calendarQuery = useMemo(() => (enabled("calendar") ? parseCalendarQuery(query) : null), [query, searchProviders]);
+  const habitQuery = useMemo(() => (enabled("habits") ? parseHabitQuery(query) : null), [query, searchProviders]);
+  const scriptQuery = useMemo(() => (enabled("scripts") ? parseScriptQuery(query, scripts) : null), [query, scripts, searchProviders]);
+  const workflowQuery = useMemo(() => (enabled("workflows") ? parseWorkflowQuery(query, workflows) : null), [query, workflows, searchProviders]);
+  const hotFolderFiles = useHotFolderFiles(8);
+  const [webSuggestions, setWebSuggestions] = useState<string[]>([]); // 搜索引擎前缀查询的联想词
+  const [sshHosts, setSshHosts] = useState<SshHost[]>([]); // 匹配查询的 SSH 主机
+  const [gitRepos, setGitRepos] = useState<GitRepo[]>([]); // 匹配查询的 Git 仓库
+  const [recentProjects, setRecentProjects] = useState<RecentProject[]>([]); // 匹配查询的 IDE 最近项目
+  const [games, setGames] = useState<GameInfo[]>([]); // 匹配查询的已安装游戏
+  const [browserHistory, setBrowserHistory] = useState<BrowserHistoryItem[]>([]); // 匹配查询的浏览器历史
+  const [queryCompletion, setQueryCompletion] = useState<string | null>(null); // 查询历史补全建议
+  // 查询历史回溯状态：prefix 为开始回溯时的输入，offset 为当前历史位置（-1 表示未在回溯）
+  const queryHistoryNavRef = useRef<{ prefix: string; offset: number; applied: string | null }>({
+    prefix: "",
+    offset: -1,
+    applied: null,
+  });
   const [remarkText, setRemarkText] = useState<string>("");
   const [urlRemarks, setUrlRemarks] = useState<Record<string, string>>({});
   const [launchingAppPath, setLaunchingAppPath] = useState<string | null>(null); // 正在启动的应用路径
@@ -143,6 +212,8 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
   const isPluginListModalOpenRef = useRef(false);
   // 记录备注弹窗是否打开，用于全局 ESC 处理时优先关闭备注弹窗，而不是隐藏整个窗口
   const isRemarkModalOpenRef = useRef(false);
+  const archivePeekPathRef = useRef<string | null>(null);
+  const folderSizePathRef = useRef<string | null>(null);
   // 记录右键菜单是否打开，用于全局 ESC 处理时优先关闭右键菜单，而不是隐藏整个窗口
   const contextMenuRef = useRef<{ x: number; y: number; result: SearchResult } | null>(null);
   const shouldPreserveScrollRef = useRef(false); // 标记是否需要保持滚动位置
@@ -185,6 +256,14 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
     isRemarkModalOpenRef.current = isRemarkModalOpen;
   }, [isRemarkModalOpen]);
 
+  useEffect(() => {
+    archivePeekPathRef.current = archivePeekPath;
+  }, [archivePeekPath]);
+
+  useEffect(() => {
+    folderSizePathRef.current = folderSizePath;
+  }, [folderSizePath]);
+
   useEffect(() => {
     contextMenuRef.current = contextMenu;
   }, [contextMenu]);
@@ -410,6 +489,22 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
         setRemarkText("");
         return;
       }
+
+      // 如果压缩包内容弹窗已打开，只关闭弹窗
+      if ((e.key === "Escape" || e.keyCode === 27) && archivePeekPathRef.current) {
+        e.preventDefault();
+        e.stopPropagation();
+        setArchivePeekPath(null);
+        return;
+      }
+
+      // 如果文件夹大小分析弹窗已打开，只关闭弹窗（关闭时会取消扫描）
+      if ((e.key === "Escape" || e.keyCode === 27) && folderSizePathRef.current) {
+        e.preventDefault();
+        e.stopPropagation();
+        setFolderSizePath(null);
+        return;
+      }
       
       if (handleEscapeKey(e, {
         isPluginListModalOpen: () => isPluginListModalOpenRef.current,
@@ -560,6 +655,41 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
     };
   }, []);
 
+  // 自动化脚本的关键字，脚本文件夹变化时后端重新加载并推送
+  useEffect(() => {
+    tauriApi
+      .listScripts()
+      .then(setScripts)
+      .catch(() => setScripts([]));
+    const unlisten = listen<ScriptInfo[]>("scripts://changed", (event) => setScripts(event.payload));
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 工作流关键字，设置中保存或导入后后端推送最新列表
+  useEffect(() => {
+    tauriApi
+      .listWorkflows()
+      .then(setWorkflows)
+      .catch(() => setWorkflows([]));
+    const unlisten = listen<Workflow[]>("workflows://changed", (event) => setWorkflows(event.payload));
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 浏览器扩展唤起启动器时带入的查询内容
+  useEffect(() => {
+    const unlisten = listen<string>("launcher://set-query", (event) => {
+      setQuery(event.payload);
+      inputRef.current?.focus();
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
 
 
   // 统一处理窗口拖动，避免拖动过程中触发失焦自动关闭
@@ -599,6 +729,183 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
   }, [filteredApps, filteredFiles, filteredMemos, filteredPlugins, everythingResults]);
 
 
+  // 根据当前输入获取个性化排序加权（防抖，避免每次按键都查询数据库）
+  useEffect(() => {
+    const trimmed = query.trim();
+    if (trimmed === "") {
+      setRankingBoosts({});
+      return;
+    }
+    const timer = window.setTimeout(() => {
+      tauriApi
+        .getRankingBoosts(trimmed)
+        .then((boosts) => {
+          const map: Record<string, number> = {};
+          for (const item of boosts) {
+            map[item.result_id] = item.boost;
+          }
+          setRankingBoosts(map);
+        })
+        .catch((error) => console.warn("[个性化排序] 获取加权失败:", error));
+    }, 120);
+    return () => window.clearTimeout(timer);
+  }, [query]);
+
+  // 输入匹配搜索引擎前缀时获取联想词（后端负责防抖和缓存）
+  useEffect(() => {
+    const intent = detectSearchIntent(query, searchEngines);
+    if (!intent || intent.keyword === "") {
+      setWebSuggestions([]);
+      return;
+    }
+    let cancelled = false;
+    tauriApi
+      .getWebSuggestions(intent.engine.prefix, intent.keyword)
+      .then((suggestions) => {
+        if (!cancelled) {
+          setWebSuggestions(suggestions);
+        }
+      })
+      .catch((error) => console.warn("[搜索联想] 获取联想词失败:", error));
+    return () => {
+      cancelled = true;
+    };
+  }, [query, searchEngines]);
+
+  // 搜索 ~/.ssh/config 与 known_hosts 中的主机（数据源停用时后端返回空列表）
+  useEffect(() => {
+    if (query.trim() === "") {
+      setSshHosts([]);
+      return;
+    }
+    let cancelled = false;
+    const timer = window.setTimeout(() => {
+      tauriApi
+        .searchSshHosts(query.trim())
+        .then((hosts) => {
+          if (!cancelled) {
+            setSshHosts(hosts);
+          }
+        })
+        .catch((error) => console.warn("[SSH 主机] 搜索失败:", error));
+    }, 120);
+    return () => {
+      cancelled = true;
+      window.clearTimeout(timer);
+    };
+  }, [query]);
+
+  // 搜索已索引的 Git 仓库（数据源停用时后端返回空列表）
+  useEffect(() => {
+    if (query.trim() === "") {
+      setGitRepos([]);
+      return;
+    }
+    let cancelled = false;
+    const timer = window.setTimeout(() => {
+      tauriApi
+        .searchGitRepos(query.trim())
+        .then((repos) => {
+          if (!cancelled) {
+            setGitRepos(repos);
+          }
+        })
+        .catch((error) => console.warn("[Git 仓库] 搜索失败:", error));
+    }, 120);
+    return () => {
+      cancelled = true;
+      window.clearTimeout(timer);
+    };
+  }, [query]);
+
+  // 搜索 VS Code / JetBrains 最近项目（数据源停用时后端返回空列表）
+  useEffect(() => {
+    if (query.trim() === "") {
+      setRecentProjects([]);
+      return;
+    }
+    let cancelled = false;
+    const timer = window.setTimeout(() => {
+      tauriApi
+        .searchRecentProjects(query.trim())
+        .then((projects) => {
+          if (!cancelled) {
+            setRecentProjects(projects);
+          }
+        })
+        .catch((error) => console.warn("[最近项目] 搜索失败:", error));
+    }, 120);
+    return () => {
+      cancelled = true;
+      window.clearTimeout(timer);
+    };
+  }, [query]);
+
+  // 搜索 Steam / Epic 已安装的游戏（未在设置中启用或数据源停用时后端返回空列表）
+  useEffect(() => {
+    if (query.trim() === "") {
+      setGames([]);
+      return;
+    }
+    let cancelled = false;
+    const timer = window.setTimeout(() => {
+      tauriApi
+        .searchGames(query.trim())
+        .then((items) => {
+          if (!cancelled) {
+            setGames(items);
+          }
+        })
+        .catch((error) => console.warn("[游戏] 搜索失败:", error));
+    }, 120);
+    return () => {
+      cancelled = true;
+      window.clearTimeout(timer);
+    };
+  }, [query]);
+
+  // 搜索 Chrome / Edge / Brave 浏览历史（数据源停用时后端返回空列表）
+  useEffect(() => {
+    if (query.trim() === "") {
+      setBrowserHistory([]);
+      return;
+    }
+    let cancelled = false;
+    const timer = window.setTimeout(() => {
+      tauriApi
+        .searchBrowserHistory(query.trim())
+        .then((items) => {
+          if (!cancelled) {
+            setBrowserHistory(items);
+          }
+        })
+        .catch((error) => console.warn("[浏览器历史] 搜索失败:", error));
+    }, 120);
+    return () => {
+      cancelled = true;
+      window.clearTimeout(timer);
+    };
+  }, [query]);
+
+  // 用户修改输入后退出历史回溯，并获取查询历史补全建议
+  useEffect(() => {
+    const nav = queryHistoryNavRef.current;
+    if (nav.offset >= 0 && query !== nav.applied) {
+      queryHistoryNavRef.current = { prefix: "", offset: -1, applied: null };
+    }
+    if (query.trim() === "" || queryHistoryNavRef.current.offset >= 0) {
+      setQueryCompletion(null);
+      return;
+    }
+    const timer = window.setTimeout(() => {
+      tauriApi
+        .suggestQueryCompletions(query, 1)
+        .then((items) => setQueryCompletion(items.length > 0 ? items[0].query : null))
+        .catch((error) => console.warn("[查询历史] 获取补全失败:", error));
+    }, 120);
+    return () => window.clearTimeout(timer);
+  }, [query]);
+
   // 使用自定义 Hook 合并搜索结果
   const { combinedResults: debouncedCombinedResults, queryRef, debouncedResultsQueryRef } = useCombinedResults({
     query,
@@ -618,6 +925,15 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
     searchEngines,
     apps,
     extractedFileIconsRef,
+    rankingBoosts,
+    webSuggestions,
+    sshHosts,
+    gitRepos,
+    recentProjects,
+    games,
+    browserHistory,
+    aliases,
+    providerSettings: searchProviders,
   });
   
   // 跟踪 horizontalResults 的稳定性
@@ -1168,6 +1484,8 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
     setResultStyle,
     setCloseOnBlur,
     setSearchEngines,
+    setQuoteWatchlist,
+    setAliases,
     setIsEverythingAvailable,
     setEverythingError,
     setEverythingPath,
@@ -1231,6 +1549,40 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
     setDirectPathResult,
   });
 
+  // 后台初始化（应用缓存、打开历史等）全部完成前的搜索结果可能不完整，就绪后刷新缓存并重新搜索当前查询
+  const startupSearchRef = useRef({ query, searchApplicationsWrapper, searchFileHistoryWrapper });
+  startupSearchRef.current = { query, searchApplicationsWrapper, searchFileHistoryWrapper };
+  useEffect(() => {
+    let refreshed = false;
+    const refreshAfterStartup = async () => {
+      if (refreshed) return;
+      refreshed = true;
+      allAppsCacheLoadedRef.current = false;
+      await refreshFileHistoryCache();
+      const { query: currentQuery, searchApplicationsWrapper: searchApps, searchFileHistoryWrapper: searchFiles } =
+        startupSearchRef.current;
+      if (currentQuery.trim()) {
+        searchApps(currentQuery);
+        searchFiles(currentQuery);
+      }
+    };
+    const unlisten = listen<StartupStatus>("startup-progress", (event) => {
+      if (event.payload.ready) {
+        refreshAfterStartup();
+      }
+    });
+    tauriApi
+      .getStartupStatus()
+      .then((status) => {
+        // 页面加载时已经全部就绪则无需刷新
+        if (status.ready) refreshed = true;
+      })
+      .catch(() => {});
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [refreshFileHistoryCache]);
+
   // 使用自定义 hook 监听图标更新事件
   useAppIconsListener({
     setFilteredApps,
@@ -1335,6 +1687,7 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
     pendingSessionIdRef,
     currentSearchQueryRef,
     displayedSearchQueryRef,
+    providersRef: searchProvidersRef,
     searchSystemFoldersWrapper,
     searchFileHistoryWrapper,
     searchApplicationsWrapper,
@@ -1562,6 +1915,59 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
   );
 
 
+  // 类似 shell 的查询历史回溯：older 回到更早的查询，newer 回到较新的查询 / 原始输入
+  const navigateQueryHistory = useCallback(
+    async (direction: "older" | "newer"): Promise<boolean> => {
+      const nav = queryHistoryNavRef.current;
+      try {
+        if (direction === "newer") {
+          if (nav.offset < 0) {
+            return false;
+          }
+          if (nav.offset === 0) {
+            queryHistoryNavRef.current = { prefix: "", offset: -1, applied: null };
+            setQuery(nav.prefix);
+            return true;
+          }
+          const entry = await tauriApi.navigateQueryHistory(nav.prefix, nav.offset - 1);
+          if (entry !== null) {
+            queryHistoryNavRef.current = { ...nav, offset: nav.offset - 1, applied: entry };
+            setQuery(entry);
+          }
+          return true;
+        }
+
+        const prefix = nav.offset < 0 ? query : nav.prefix;
+        let offset = nav.offset + 1;
+        let entry = await tauriApi.navigateQueryHistory(prefix, offset);
+        // 开始回溯时跳过与当前输入相同的历史
+        if (entry !== null && nav.offset < 0 && entry === query) {
+          offset += 1;
+          entry = await tauriApi.navigateQueryHistory(prefix, offset);
+        }
+        if (entry === null) {
+          return false;
+        }
+        queryHistoryNavRef.current = { prefix, offset, applied: entry };
+        setQuery(entry);
+        return true;
+      } catch (error) {
+        console.warn("[查询历史] 回溯失败:", error);
+        return false;
+      }
+    },
+    [query]
+  );
+
+  const acceptQueryCompletion = useCallback((): boolean => {
+    if (!queryCompletion || document.activeElement !== inputRef.current) {
+      return false;
+    }
+    setQuery(queryCompletion);
+    setQueryCompletion(null);
+    return true;
+  }, [queryCompletion]);
+
   const handleKeyDown = useCallback(
     async (e: React.KeyboardEvent) => {
       await handleKeyDownUtil({
@@ -1599,6 +2005,8 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
         hideLauncherAndResetState,
         resetMemoState,
         handleLaunch,
+        navigateQueryHistory,
+        acceptQueryCompletion,
       });
     },
     [
@@ -1630,6 +2038,8 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
       hideLauncherAndResetState,
       resetMemoState,
       handleLaunch,
+      navigateQueryHistory,
+      acceptQueryCompletion,
     ]
   );
 
@@ -1742,6 +2152,7 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
             onStartWindowDragging={startWindowDragging}
             contextMenu={contextMenu}
             setContextMenu={setContextMenu}
+            queryCompletion={queryCompletion}
           />
 
           {/* Results List or AI Answer */}
@@ -1779,6 +2190,60 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
             isHorizontalResultsStable={isHorizontalResultsStable}
           />
 
+          {/* 空查询时展示热点文件夹中的最近文件 */}
+          {query.trim() === "" && !showAiAnswer && (
+            <HotFolderFilesPanel files={hotFolderFiles} onOpened={() => hideLauncherAndResetState()} />
+          )}
+
+          {/* "ip"、"ping 主机" 查询时展示网络信息与诊断结果 */}
+          {networkQuery && !showAiAnswer && <NetworkToolsPanel networkQuery={networkQuery} />}
+
+          {/* "cpu"、"ram" 等查询时展示系统状态 */}
+          {showSystemMonitor && !showAiAnswer && <SystemMonitorPanel />}
+
+          {/* "audio 关键字" 查询时展示音频输出设备与音量 */}
+          {audioQuery && !showAiAnswer && <AudioDevicesPanel keyword={audioQuery.keyword} />}
+          {/* "亮度 50"、"夜间模式"、"display 预设名" 查询时展示显示器控制 */}
+          {displayQuery && !showAiAnswer && (
+            <DisplayControlPanel
+              keyword={displayQuery.keyword}
+              brightness={displayQuery.brightness}
+              nightLight={displayQuery.nightLight}
+            />
+          )}
+          {/* "wifi off"、"蓝牙"、"飞行模式" 查询时展示无线开关 */}
+          {radioQuery && !showAiAnswer && <RadioTogglesPanel target={radioQuery.target} enabled={radioQuery.enabled} />}
+          {/* "win left"、"窗口 最大化" 查询时展示窗口管理动作 */}
+          {windowQuery && !showAiAnswer && <WindowActionsPanel actions={windowQuery.actions} />}
+          {desktopQuery && !showAiAnswer && (
+            <VirtualDesktopsPanel keyword={desktopQuery.keyword} index={desktopQuery.index} move={desktopQuery.move} />
+          )}
+          {keepAwakeQuery && !showAiAnswer && <KeepAwakePanel minutes={keepAwakeQuery.minutes} off={keepAwakeQuery.off} />}
+          {textTransformQuery && !showAiAnswer && <TextTransformPanel transforms={textTransformQuery.transforms} />}
+          {passwordQuery && !showAiAnswer && (
+            <PasswordGeneratorPanel
+              key={`${passwordQuery.mode}-${passwordQuery.count}-${passwordQuery.symbols}`}
+              mode={passwordQuery.mode}
+              count={passwordQuery.count}
+              symbols={passwordQuery.symbols}
+            />
+          )}
+          {totpQuery && !showAiAnswer && <TotpPanel account={totpQuery.account} />}
+          {worldClockQuery && !showAiAnswer && <WorldClockPanel query={worldClockQuery} />}
+          {quoteQuery && !showAiAnswer && <QuotesPanel symbols={quoteQuery.symbols} />}
+          {rssQuery && !showAiAnswer && <RssPanel keyword={rssQuery.keyword} addUrl={rssQuery.addUrl} />}
+          {timerQuery && !showAiAnswer && (
+            <TimersPanel kind={timerQuery.kind} spec={timerQuery.spec} label={timerQuery.label} />
+          )}
+          {calendarQuery && !showAiAnswer && (
+            <CalendarPanel mode={calendarQuery.mode} addSource={calendarQuery.addSource} />
+          )}
+          {habitQuery && !showAiAnswer && <HabitsPanel keyword={habitQuery.keyword} addInput={habitQuery.addInput} />}
+          {/* 输入脚本关键字时展示脚本，回车运行 */}
+          {scriptQuery && !showAiAnswer && <ScriptPanel script={scriptQuery.script} input={scriptQuery.input} />}
+          {/* 输入工作流关键字时展示步骤，回车运行 */}
+          {workflowQuery && !showAiAnswer && <WorkflowPanel workflow={workflowQuery.workflow} input={workflowQuery.input} />}
+
           {/* Footer */}
           <LauncherStatusBar
             resultsCount={results.length}
@@ -1823,6 +2288,8 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
         menu={contextMenu}
         onClose={() => setContextMenu(null)}
         onRevealInFolder={handleRevealInFolder}
+        onPeekArchive={setArchivePeekPath}
+        onAnalyzeFolder={setFolderSizePath}
         onEditMemo={() => {
           if (!contextMenu?.result.memo) return;
           setSelectedMemo(contextMenu.result.memo);
@@ -1837,6 +2304,10 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
         onOpenUrl={async (url: string) => {
           await tauriApi.openUrl(url);
         }}
+        onOpenFolderWith={async (actionId: string, path: string) => {
+          await tauriApi.openFolderWith(actionId, path);
+          await hideLauncherAndResetState();
+        }}
         onDeleteHistory={handleDeleteHistory}
         onEditRemark={handleEditRemark}
         onCopyJson={async (json: string) => {
@@ -1873,6 +2344,9 @@ export function LauncherWindow({ updateInfo }: LauncherWindowProps) {
         onSave={handleSaveRemark}
       />
 
+      <ArchivePeekModal archivePath={archivePeekPath} onClose={() => setArchivePeekPath(null)} />
+      <FolderSizeModal folderPath={folderSizePath} onClose={() => setFolderSizePath(null)} />
+
       {/* Memo Detail Modal */}
       <MemoModal
         isOpen={isMemoModalOpen}
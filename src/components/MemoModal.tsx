@@ -235,8 +235,10 @@ export function MemoModal({
                             const textToPaste = memo.title 
                               ? (memo.content ? `${memo.title}\n${memo.content}` : memo.title)
                               : (memo.content || "(无内容)");
+                            // 展开片段中的文本变量（如 {date}、{clip}）
+                            const rendered = await tauriApi.renderTextVariables(textToPaste);
                             // 使用 navigator.clipboard.writeText 设置剪贴板（和复制按钮一样的方式）
-                            await navigator.clipboard.writeText(textToPaste);
+                            await navigator.clipboard.writeText(rendered);
                             // 先隐藏窗口，让用户有时间切换到目标窗口
                             await onHideLauncher();
                             // 然后只模拟按键（剪贴板已经通过 navigator.clipboard.writeText 设置好了）
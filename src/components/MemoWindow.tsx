@@ -2,9 +2,11 @@ import { useState, useEffect, useCallback } from "react";
 import { getCurrentWindow } from "@tauri-apps/api/window";
 import { confirm } from "@tauri-apps/plugin-dialog";
 import { tauriApi } from "../api/tauri";
-import type { MemoItem } from "../types";
+import type { InjectionMode, MemoItem, NoteSource } from "../types";
 import { formatFullDateTime } from "../utils/dateUtils";
 import { useWindowClose } from "../hooks/useWindowClose";
+import { describeApiError } from "../utils/apiError";
+import { formatAppRules, parseAppRules } from "../utils/appScope";
 
 export function MemoWindow() {
   const [memos, setMemos] = useState<MemoItem[]>([]);
@@ -12,7 +14,16 @@ export function MemoWindow() {
   const [memoEditTitle, setMemoEditTitle] = useState("");
   const [memoEditContent, setMemoEditContent] = useState("");
   const [isEditingMemo, setIsEditingMemo] = useState(false);
+  const [memoEditHotstring, setMemoEditHotstring] = useState("");
+  const [memoEditCaseSensitive, setMemoEditCaseSensitive] = useState(false);
+  const [memoEditApps, setMemoEditApps] = useState("");
+  const [memoEditInjection, setMemoEditInjection] = useState<InjectionMode>("default");
   const [isMemoListMode, setIsMemoListMode] = useState(true);
+  // 问笔记：根据备忘录和单词记录回答问题
+  const [question, setQuestion] = useState("");
+  const [notesAnswer, setNotesAnswer] = useState("");
+  const [notesSources, setNotesSources] = useState<NoteSource[]>([]);
+  const [isAsking, setIsAsking] = useState(false);
 
   const loadMemos = async () => {
     try {
@@ -34,6 +45,30 @@ export function MemoWindow() {
     loadMemos();
   }, []);
 
+  // 进入编辑时按当前备忘录初始化热字串设置
+  useEffect(() => {
+    if (!isEditingMemo) return;
+    setMemoEditHotstring(selectedMemo?.hotstring ?? "");
+    setMemoEditCaseSensitive(selectedMemo?.case_sensitive ?? false);
+    setMemoEditApps(formatAppRules(selectedMemo?.apps));
+    setMemoEditInjection(selectedMemo?.injection ?? "default");
+  }, [isEditingMemo, selectedMemo?.id]);
+
+  // 热字串有变化时单独保存，返回最新的备忘录
+  const saveHotstring = async (memo: MemoItem): Promise<MemoItem> => {
+    const hotstring = memoEditHotstring.trim();
+    const apps = parseAppRules(memoEditApps);
+    if (
+      hotstring === (memo.hotstring ?? "") &&
+      memoEditCaseSensitive === memo.case_sensitive &&
+      formatAppRules(apps) === formatAppRules(memo.apps) &&
+      memoEditInjection === (memo.injection ?? "default")
+    ) {
+      return memo;
+    }
+    return tauriApi.setMemoHotstring(memo.id, hotstring || null, memoEditCaseSensitive, apps, memoEditInjection);
+  };
+
   // ESC 键处理
   useEffect(() => {
     const handleKeyDown = async (e: KeyboardEvent) => {
@@ -71,6 +106,43 @@ export function MemoWindow() {
     };
   }, [isMemoListMode, isEditingMemo, selectedMemo, handleClose]);
 
+  const handleAskNotes = async () => {
+    if (!question.trim() || isAsking) return;
+    setIsAsking(true);
+    setNotesAnswer("");
+    setNotesSources([]);
+    let accumulated = "";
+    try {
+      const result = await tauriApi.askNotes(question, (delta) => {
+        accumulated += delta;
+        setNotesAnswer(accumulated);
+      });
+      setNotesAnswer(result.answer);
+      setNotesSources(result.sources);
+    } catch (error) {
+      console.error("Failed to ask notes:", error);
+      setNotesAnswer(`提问失败: ${describeApiError(error)}`);
+    } finally {
+      setIsAsking(false);
+    }
+  };
+
+  // 打开引用的资料：备忘录打开详情，单词记录打开单词本所在的翻译窗口
+  const handleOpenSource = async (source: NoteSource) => {
+    if (source.kind === "word") {
+      await tauriApi.showTranslationWindow();
+      return;
+    }
+    const memo = memos.find((m) => m.id === source.id);
+    if (memo) {
+      setIsMemoListMode(false);
+      setSelectedMemo(memo);
+      setMemoEditTitle(memo.title);
+      setMemoEditContent(memo.content);
+      setIsEditingMemo(false);
+    }
+  };
+
   const resetMemoState = () => {
     setIsMemoListMode(true);
     setSelectedMemo(null);
@@ -144,6 +216,46 @@ export function MemoWindow() {
       <div className="flex-1 overflow-y-auto p-4">
         {isMemoListMode ? (
           <div className="space-y-2">
+            <div className="p-3 border border-gray-200 rounded bg-white">
+              <div className="flex gap-2">
+                <input
+                  type="text"
+                  value={question}
+                  onChange={(e) => setQuestion(e.target.value)}
+                  onKeyDown={(e) => {
+                    if (e.key === "Enter") {
+                      handleAskNotes();
+                    }
+                  }}
+                  placeholder="问笔记：根据备忘录和单词本回答问题..."
+                  className="flex-1 px-3 py-1.5 text-sm border border-gray-300 rounded focus:outline-none focus:ring-2 focus:ring-blue-500"
+                />
+                <button
+                  onClick={handleAskNotes}
+                  disabled={isAsking || !question.trim()}
+                  className="px-3 py-1.5 text-sm bg-blue-500 text-white rounded hover:bg-blue-600 disabled:opacity-50 transition-colors"
+                >
+                  {isAsking ? "回答中..." : "提问"}
+                </button>
+              </div>
+              {notesAnswer && (
+                <div className="mt-3 text-sm text-gray-700 whitespace-pre-wrap break-words">{notesAnswer}</div>
+              )}
+              {notesSources.length > 0 && (
+                <div className="mt-2 flex flex-wrap gap-1.5">
+                  {notesSources.map((source, index) => (
+                    <button
+                      key={`${source.kind}-${source.id}`}
+                      onClick={() => handleOpenSource(source)}
+                      className="px-2 py-0.5 text-xs text-blue-600 bg-blue-50 border border-blue-200 rounded hover:bg-blue-100 transition-colors"
+                      title={source.content}
+                    >
+                      [{index + 1}] {source.kind === "word" ? "单词" : "备忘录"}：{source.title || "(无标题)"}
+                    </button>
+                  ))}
+                </div>
+              )}
+            </div>
             {memos.length === 0 ? (
               <div className="text-sm text-gray-500">还没有任何备忘录</div>
             ) : (
@@ -187,8 +299,10 @@ export function MemoWindow() {
                           const textToPaste = memo.title 
                             ? (memo.content ? `${memo.title}\n${memo.content}` : memo.title)
                             : (memo.content || "(无内容)");
+                          // 展开片段中的文本变量（如 {date}、{clip}）
+                          const rendered = await tauriApi.renderTextVariables(textToPaste);
                           // 使用 navigator.clipboard.writeText 设置剪贴板（和复制按钮一样的方式）
-                          await navigator.clipboard.writeText(textToPaste);
+                          await navigator.clipboard.writeText(rendered);
                           // 先隐藏窗口（而不是关闭），让用户有时间切换到目标窗口，同时保持 JavaScript 上下文
                           await handleHide();
                           // 然后只模拟按键（剪贴板已经通过 navigator.clipboard.writeText 设置好了）
@@ -301,6 +415,47 @@ export function MemoWindow() {
                 rows={12}
               />
             </div>
+            <div>
+              <label className="block text-sm font-medium text-gray-700 mb-1">
+                热字串
+              </label>
+              <div className="flex items-center gap-3">
+                <input
+                  type="text"
+                  value={memoEditHotstring}
+                  onChange={(e) => setMemoEditHotstring(e.target.value)}
+                  className="w-48 px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+                  placeholder="如 ;addr，留空不启用"
+                />
+                <label className="flex items-center gap-1 text-sm text-gray-600">
+                  <input
+                    type="checkbox"
+                    checked={memoEditCaseSensitive}
+                    onChange={(e) => setMemoEditCaseSensitive(e.target.checked)}
+                  />
+                  区分大小写
+                </label>
+                <select
+                  value={memoEditInjection}
+                  onChange={(e) => setMemoEditInjection(e.target.value as InjectionMode)}
+                  className="px-2 py-2 border border-gray-300 rounded-md text-sm text-gray-600 focus:outline-none focus:ring-2 focus:ring-blue-500"
+                  title="中文输入法下展开出现乱码时，可改为输入期间切换到英文布局"
+                >
+                  <option value="default">Unicode 输入</option>
+                  <option value="english_layout">切换英文布局</option>
+                </select>
+              </div>
+              <input
+                type="text"
+                value={memoEditApps}
+                onChange={(e) => setMemoEditApps(e.target.value)}
+                className="w-full mt-2 px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+                placeholder="只在这些程序中展开，如 code.exe, class:Notepad，加 ! 表示排除；留空为所有程序"
+              />
+              <div className="text-xs text-gray-400 mt-1">
+                在任意程序中输入缩写后按空格、回车或 Tab 展开为内容，展开后立即按退格可撤销
+              </div>
+            </div>
           </div>
         ) : selectedMemo ? (
           <div className="space-y-4 max-w-2xl mx-auto">
@@ -316,6 +471,25 @@ export function MemoWindow() {
                 {selectedMemo.content || "(无内容)"}
               </div>
             </div>
+            {selectedMemo.hotstring && (
+              <div>
+                <div className="text-sm font-medium text-gray-500 mb-1">热字串</div>
+                <div className="text-gray-700 font-mono">
+                  {selectedMemo.hotstring}
+                  {selectedMemo.case_sensitive && (
+                    <span className="ml-2 text-xs text-gray-400 font-sans">区分大小写</span>
+                  )}
+                  {selectedMemo.apps && selectedMemo.apps.length > 0 && (
+                    <span className="ml-2 text-xs text-gray-400 font-sans">
+                      仅限 {formatAppRules(selectedMemo.apps)}
+                    </span>
+                  )}
+                  {selectedMemo.injection === "english_layout" && (
+                    <span className="ml-2 text-xs text-gray-400 font-sans">切换英文布局</span>
+                  )}
+                </div>
+              </div>
+            )}
             <div className="pt-4 border-t border-gray-200">
               <div className="text-xs text-gray-500">
                 <div>
@@ -339,26 +513,26 @@ export function MemoWindow() {
             onClick={async () => {
               try {
                 if (selectedMemo) {
-                  await tauriApi.updateMemo(
-                    selectedMemo.id,
-                    memoEditTitle,
-                    memoEditContent
+                  const updated = await saveHotstring(
+                    await tauriApi.updateMemo(
+                      selectedMemo.id,
+                      memoEditTitle,
+                      memoEditContent
+                    )
                   );
                   await loadMemos();
-                  const list = await tauriApi.getAllMemos();
-                  const updated = list.find((m) => m.id === selectedMemo.id);
-                  if (updated) {
-                    setSelectedMemo(updated);
-                  }
+                  setSelectedMemo(updated);
                   setIsEditingMemo(false);
                 } else {
                   if (!memoEditTitle.trim() && !memoEditContent.trim()) {
                     alert("请输入标题或内容");
                     return;
                   }
-                  const newMemo = await tauriApi.addMemo(
-                    memoEditTitle.trim() || "无标题",
-                    memoEditContent.trim()
+                  const newMemo = await saveHotstring(
+                    await tauriApi.addMemo(
+                      memoEditTitle.trim() || "无标题",
+                      memoEditContent.trim()
+                    )
                   );
                   await loadMemos();
                   setSelectedMemo(newMemo);
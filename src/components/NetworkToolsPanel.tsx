@@ -0,0 +1,168 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { NetworkInfo, PingResult, PortCheckResult } from "../types";
+import type { NetworkQuery } from "../utils/searchUtils";
+
+interface NetworkToolsPanelProps {
+  networkQuery: NetworkQuery;
+}
+
+// 输入主机名时等待停止输入后再 ping，避免每个字符都发起一次
+const PING_DEBOUNCE_MS = 500;
+
+function CopyableValue({ value }: { value: string }) {
+  const [copied, setCopied] = useState(false);
+  return (
+    <button
+      onClick={async () => {
+        await navigator.clipboard.writeText(value);
+        setCopied(true);
+        setTimeout(() => setCopied(false), 1500);
+      }}
+      className="font-mono text-gray-800 hover:text-blue-600"
+      title="点击复制"
+    >
+      {value}
+      {copied && <span className="ml-2 text-xs text-green-600">已复制</span>}
+    </button>
+  );
+}
+
+function NetworkInfoView() {
+  const [info, setInfo] = useState<NetworkInfo | null>(null);
+  const [publicIp, setPublicIp] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [publicIpError, setPublicIpError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    tauriApi
+      .getNetworkInfo()
+      .then((result) => !cancelled && setInfo(result))
+      .catch((e) => !cancelled && setError(String(e)));
+    tauriApi
+      .getPublicIp()
+      .then((ip) => !cancelled && setPublicIp(ip))
+      .catch((e) => !cancelled && setPublicIpError(String(e)));
+    return () => {
+      cancelled = true;
+    };
+  }, []);
+
+  return (
+    <div className="space-y-1 text-sm">
+      <div className="flex gap-2">
+        <span className="w-20 text-gray-500">本机 IP</span>
+        {info?.local_ip ? <CopyableValue value={info.local_ip} /> : <span className="text-gray-400">{info ? "未连接" : "获取中..."}</span>}
+      </div>
+      <div className="flex gap-2">
+        <span className="w-20 text-gray-500">公网 IP</span>
+        {publicIp ? (
+          <CopyableValue value={publicIp} />
+        ) : (
+          <span className={publicIpError ? "text-red-500 text-xs" : "text-gray-400"}>{publicIpError ?? "获取中..."}</span>
+        )}
+      </div>
+      {info?.wifi && (
+        <div className="flex gap-2">
+          <span className="w-20 text-gray-500">Wi-Fi</span>
+          <span className="text-gray-800">
+            {info.wifi.ssid}
+            {info.wifi.signal != null && <span className="ml-2 text-xs text-gray-500">信号 {info.wifi.signal}%</span>}
+          </span>
+        </div>
+      )}
+      {error && <div className="text-xs text-red-500">{error}</div>}
+      {info && info.adapters.length > 0 && (
+        <div className="pt-2">
+          <div className="text-xs text-gray-400 mb-1">网卡</div>
+          {info.adapters.map((adapter) => (
+            <div key={adapter.name} className="flex items-start gap-2 py-0.5 text-xs">
+              <span className={`w-2 h-2 mt-1 rounded-full shrink-0 ${adapter.status === "Up" ? "bg-green-500" : "bg-gray-300"}`} />
+              <span className="w-32 truncate text-gray-700" title={adapter.description}>
+                {adapter.name}
+              </span>
+              <span className="flex-1 text-gray-500 break-all">{adapter.addresses.join("  ") || adapter.status}</span>
+              <span className="text-gray-400 shrink-0">{adapter.link_speed}</span>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
+
+function PingView({ host, port }: { host: string; port?: number }) {
+  const [ping, setPing] = useState<PingResult | null>(null);
+  const [portCheck, setPortCheck] = useState<PortCheckResult | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [isRunning, setIsRunning] = useState(false);
+
+  useEffect(() => {
+    setPing(null);
+    setPortCheck(null);
+    setError(null);
+    let cancelled = false;
+    const timer = setTimeout(() => {
+      setIsRunning(true);
+      const task =
+        port !== undefined
+          ? tauriApi.checkPort(host, port).then((result) => !cancelled && setPortCheck(result))
+          : tauriApi.pingHost(host).then((result) => !cancelled && setPing(result));
+      task
+        .catch((e) => !cancelled && setError(String(e)))
+        .finally(() => !cancelled && setIsRunning(false));
+    }, PING_DEBOUNCE_MS);
+    return () => {
+      cancelled = true;
+      clearTimeout(timer);
+    };
+  }, [host, port]);
+
+  const target = port !== undefined ? `${host}:${port}` : host;
+  return (
+    <div className="text-sm">
+      <div className="text-gray-500 mb-1">
+        {port !== undefined ? "检测端口" : "Ping"} <span className="font-mono text-gray-800">{target}</span>
+        {isRunning && <span className="ml-2 text-xs text-gray-400">检测中...</span>}
+      </div>
+      {error && <div className="text-xs text-red-500">{error}</div>}
+      {ping && (
+        <div className="space-y-0.5">
+          <div className="text-xs text-gray-500">{ping.address}</div>
+          <div className={ping.received > 0 ? "text-green-700" : "text-red-600"}>
+            已接收 {ping.received}/{ping.sent}
+            {ping.avg_ms != null && `，平均 ${Math.round(ping.avg_ms)} ms`}
+          </div>
+          {ping.times_ms.length > 0 && (
+            <div className="text-xs text-gray-400">{ping.times_ms.map((t) => `${t} ms`).join("  ")}</div>
+          )}
+        </div>
+      )}
+      {portCheck && (
+        <div className="space-y-0.5">
+          <div className="text-xs text-gray-500">{portCheck.address}</div>
+          <div className={portCheck.open ? "text-green-700" : "text-red-600"}>
+            {portCheck.open ? `端口开放（${portCheck.elapsed_ms} ms）` : "无法连接"}
+          </div>
+          {portCheck.error && <div className="text-xs text-gray-400">{portCheck.error}</div>}
+        </div>
+      )}
+    </div>
+  );
+}
+
+/**
+ * 网络工具面板：输入 "ip" 显示本机网络信息，"ping 主机" 测试连通性，"ping 主机:端口" 检测 TCP 端口
+ */
+export function NetworkToolsPanel({ networkQuery }: NetworkToolsPanelProps) {
+  return (
+    <div className="px-4 py-3 border-t border-gray-100">
+      {networkQuery.kind === "info" ? (
+        <NetworkInfoView />
+      ) : (
+        <PingView host={networkQuery.host} port={networkQuery.kind === "port" ? networkQuery.port : undefined} />
+      )}
+    </div>
+  );
+}
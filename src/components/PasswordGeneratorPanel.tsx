@@ -0,0 +1,197 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { GeneratedPassword, PasswordMode, PasswordOptions } from "../types";
+
+interface PasswordGeneratorPanelProps {
+  mode: PasswordMode;
+  count: number | null;
+  symbols: boolean;
+}
+
+const OPTIONS_STORAGE_KEY = "password-generator-options";
+
+const DEFAULT_OPTIONS: PasswordOptions = {
+  mode: "random",
+  length: 20,
+  lowercase: true,
+  uppercase: true,
+  digits: true,
+  symbols: true,
+  exclude_ambiguous: false,
+  words: 6,
+  separator: "-",
+  capitalize: false,
+  include_number: false,
+};
+
+const MODE_LABELS: Record<PasswordMode, string> = {
+  random: "随机字符",
+  passphrase: "单词口令",
+  pronounceable: "易读密码",
+};
+
+const loadOptions = (): PasswordOptions => {
+  try {
+    const stored = localStorage.getItem(OPTIONS_STORAGE_KEY);
+    return stored ? { ...DEFAULT_OPTIONS, ...JSON.parse(stored) } : DEFAULT_OPTIONS;
+  } catch {
+    return DEFAULT_OPTIONS;
+  }
+};
+
+const strengthOf = (bits: number) =>
+  bits >= 100 ? { label: "极强", color: "text-green-700" }
+  : bits >= 70 ? { label: "强", color: "text-green-600" }
+  : bits >= 50 ? { label: "中等", color: "text-amber-600" }
+  : { label: "弱", color: "text-red-500" };
+
+/**
+ * 密码生成面板：随机字符、单词口令与易读密码三种模式，选项保存在本地；
+ * 回车把当前密码以敏感内容复制到剪贴板（不记入历史，到期自动清除）
+ */
+export function PasswordGeneratorPanel({ mode, count, symbols }: PasswordGeneratorPanelProps) {
+  const [options, setOptions] = useState<PasswordOptions>(() => {
+    const stored = loadOptions();
+    // 查询中指定的模式、长度和符号开关优先于保存的选项
+    const fromQuery: Partial<PasswordOptions> = { mode, symbols: symbols && stored.symbols };
+    if (count !== null) {
+      if (mode === "passphrase") fromQuery.words = count;
+      else fromQuery.length = count;
+    }
+    return { ...stored, ...fromQuery };
+  });
+  const [generated, setGenerated] = useState<GeneratedPassword | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const regenerate = () => {
+    setMessage(null);
+    tauriApi
+      .generatePassword(options)
+      .then((result) => {
+        setGenerated(result);
+        setError(null);
+      })
+      .catch((e) => {
+        setGenerated(null);
+        setError(String(e));
+      });
+  };
+
+  useEffect(() => {
+    regenerate();
+    localStorage.setItem(OPTIONS_STORAGE_KEY, JSON.stringify(options));
+  }, [options]);
+
+  const update = (patch: Partial<PasswordOptions>) => setOptions((current) => ({ ...current, ...patch }));
+
+  const handleCopy = async () => {
+    if (!generated) return;
+    try {
+      const seconds = await tauriApi.copyPassword(generated.password);
+      setMessage(`已复制，${seconds} 秒后自动从剪贴板清除`);
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    if (!generated) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      handleCopy();
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [generated]);
+
+  const strength = generated ? strengthOf(generated.entropy_bits) : null;
+  const checkbox = (key: keyof PasswordOptions, label: string) => (
+    <label className="flex items-center gap-1 text-xs text-gray-600">
+      <input type="checkbox" checked={options[key] as boolean} onChange={(e) => update({ [key]: e.target.checked })} />
+      {label}
+    </label>
+  );
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      <div className="flex items-center gap-1 mb-2">
+        {(Object.keys(MODE_LABELS) as PasswordMode[]).map((item) => (
+          <button
+            key={item}
+            onClick={() => update({ mode: item })}
+            className={`px-2 py-1 text-xs rounded ${options.mode === item ? "bg-blue-50 text-blue-700" : "text-gray-600 hover:bg-gray-100"}`}
+          >
+            {MODE_LABELS[item]}
+          </button>
+        ))}
+      </div>
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {generated && (
+        <div className="flex items-center gap-2 mb-2">
+          <span className="flex-1 font-mono text-gray-900 break-all select-all">{generated.password}</span>
+          {strength && (
+            <span className={`text-xs ${strength.color}`}>
+              {strength.label} · {Math.round(generated.entropy_bits)} 位
+            </span>
+          )}
+          <button onClick={regenerate} className="text-xs text-gray-500 hover:underline">
+            重新生成
+          </button>
+          <button onClick={handleCopy} className="text-xs text-blue-600 hover:underline">
+            复制
+          </button>
+        </div>
+      )}
+      <div className="text-xs text-blue-600 mb-2">{message ?? "回车复制（不记入剪切板历史）"}</div>
+      {options.mode === "passphrase" ? (
+        <div className="flex flex-wrap items-center gap-3">
+          <label className="flex items-center gap-1 text-xs text-gray-600">
+            单词数
+            <input
+              type="number"
+              min={3}
+              max={16}
+              value={options.words}
+              onChange={(e) => update({ words: Number(e.target.value) })}
+              className="w-12 border border-gray-200 rounded px-1"
+            />
+          </label>
+          <label className="flex items-center gap-1 text-xs text-gray-600">
+            分隔符
+            <input
+              value={options.separator}
+              maxLength={3}
+              onChange={(e) => update({ separator: e.target.value })}
+              className="w-10 border border-gray-200 rounded px-1"
+            />
+          </label>
+          {checkbox("capitalize", "首字母大写")}
+          {checkbox("include_number", "包含数字")}
+        </div>
+      ) : (
+        <div className="flex flex-wrap items-center gap-3">
+          <label className="flex items-center gap-1 text-xs text-gray-600">
+            长度
+            <input
+              type="range"
+              min={4}
+              max={64}
+              value={options.length}
+              onChange={(e) => update({ length: Number(e.target.value) })}
+            />
+            <span className="w-6">{options.length}</span>
+          </label>
+          {options.mode === "random" && checkbox("lowercase", "小写")}
+          {checkbox("uppercase", options.mode === "random" ? "大写" : "首字母大写")}
+          {checkbox("digits", "数字")}
+          {checkbox("symbols", "符号")}
+          {options.mode === "random" && checkbox("exclude_ambiguous", "排除易混淆字符")}
+        </div>
+      )}
+    </div>
+  );
+}
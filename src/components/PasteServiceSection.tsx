@@ -0,0 +1,236 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { PasteConfig, PasteExpiry, PasteLink, PasteProvider } from "../types";
+import { formatFullDateTime } from "../utils/dateUtils";
+
+const PROVIDERS: { id: PasteProvider; label: string }[] = [
+  { id: "paste_rs", label: "paste.rs" },
+  { id: "dpaste", label: "dpaste.com" },
+  { id: "custom", label: "自建服务" },
+];
+
+const EXPIRY_OPTIONS: { id: PasteExpiry; label: string }[] = [
+  { id: "1h", label: "1 小时" },
+  { id: "1d", label: "1 天" },
+  { id: "7d", label: "7 天" },
+  { id: "30d", label: "30 天" },
+  { id: "never", label: "永久" },
+];
+
+const DEFAULT_CONFIG: PasteConfig = {
+  enabled: false,
+  provider: "paste_rs",
+  endpoint: "",
+  default_expiry: "1d",
+};
+
+function linkStatus(link: PasteLink, now: number): string {
+  if (link.revoked_at) return "已撤销";
+  if (link.expires_at && link.expires_at <= now) return "已过期";
+  if (!link.expires_at) return "永久有效";
+  return `${formatFullDateTime(link.expires_at)} 过期`;
+}
+
+/**
+ * 粘贴链接分享：把剪切板文本上传到粘贴服务生成链接，可查看和撤销已创建的链接
+ */
+export function PasteServiceSection() {
+  const [config, setConfig] = useState<PasteConfig>(DEFAULT_CONFIG);
+  const [hasToken, setHasToken] = useState(false);
+  const [token, setToken] = useState("");
+  const [links, setLinks] = useState<PasteLink[]>([]);
+  const [busy, setBusy] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [saved, setSaved] = useState(false);
+
+  const loadLinks = () => {
+    tauriApi
+      .listPasteLinks()
+      .then(setLinks)
+      .catch((e) => setError(String(e)));
+  };
+
+  useEffect(() => {
+    tauriApi
+      .getPasteConfig()
+      .then(({ has_token, ...rest }) => {
+        setConfig(rest);
+        setHasToken(has_token);
+      })
+      .catch((e) => setError(String(e)));
+    loadLinks();
+  }, []);
+
+  const update = (patch: Partial<PasteConfig>) => {
+    setConfig((prev) => ({ ...prev, ...patch }));
+    setSaved(false);
+  };
+
+  const save = async (clearToken = false) => {
+    setBusy(true);
+    setError(null);
+    try {
+      const view = await tauriApi.savePasteConfig({
+        ...config,
+        token: clearToken ? "" : token.trim() || undefined,
+      });
+      setHasToken(view.has_token);
+      setToken("");
+      setSaved(true);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const revoke = async (id: string) => {
+    setError(null);
+    try {
+      await tauriApi.revokePasteLink(id);
+    } catch (e) {
+      setError(String(e));
+    }
+    loadLinks();
+  };
+
+  const clearInactive = async () => {
+    await tauriApi.clearPasteLinks().catch((e) => setError(String(e)));
+    loadLinks();
+  };
+
+  const now = Math.floor(Date.now() / 1000);
+
+  return (
+    <div className="bg-white rounded-lg shadow-sm border border-gray-200 p-6">
+      <h3 className="text-lg font-medium text-gray-700">粘贴链接分享</h3>
+      <p className="text-sm text-gray-500 mt-1 mb-4">
+        开启后可在剪切板历史中把文本上传到粘贴服务并复制链接。内容会发送到第三方服务器，请勿分享敏感信息
+      </p>
+      <label className="flex items-center gap-2 text-sm text-gray-700 mb-3">
+        <input type="checkbox" checked={config.enabled} onChange={(e) => update({ enabled: e.target.checked })} />
+        启用粘贴链接分享
+      </label>
+      <div className="grid grid-cols-2 gap-3 text-sm">
+        <label className="flex flex-col gap-1">
+          <span className="text-gray-600">服务</span>
+          <select
+            value={config.provider}
+            onChange={(e) => update({ provider: e.target.value as PasteProvider })}
+            className="px-2 py-1.5 border border-gray-300 rounded-md"
+          >
+            {PROVIDERS.map((p) => (
+              <option key={p.id} value={p.id}>
+                {p.label}
+              </option>
+            ))}
+          </select>
+        </label>
+        <label className="flex flex-col gap-1">
+          <span className="text-gray-600">默认有效期</span>
+          <select
+            value={config.default_expiry}
+            onChange={(e) => update({ default_expiry: e.target.value as PasteExpiry })}
+            className="px-2 py-1.5 border border-gray-300 rounded-md"
+          >
+            {EXPIRY_OPTIONS.map((o) => (
+              <option key={o.id} value={o.id}>
+                {o.label}
+              </option>
+            ))}
+          </select>
+        </label>
+        {config.provider === "custom" && (
+          <label className="flex flex-col gap-1 col-span-2">
+            <span className="text-gray-600">上传地址（POST JSON {"{content, expires_in}"}，返回链接或 {"{url, delete_url}"}）</span>
+            <input
+              value={config.endpoint}
+              onChange={(e) => update({ endpoint: e.target.value })}
+              placeholder="https://paste.example.com/api/upload"
+              className="px-3 py-1.5 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+            />
+          </label>
+        )}
+        {config.provider !== "paste_rs" && (
+          <label className="flex flex-col gap-1 col-span-2">
+            <span className="text-gray-600">访问令牌（可选）</span>
+            <input
+              type="password"
+              value={token}
+              onChange={(e) => setToken(e.target.value)}
+              placeholder={hasToken ? "已保存，留空则不修改" : "Bearer token"}
+              className="px-3 py-1.5 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+            />
+          </label>
+        )}
+      </div>
+      <div className="flex items-center gap-2 mt-3">
+        <button
+          onClick={() => save()}
+          disabled={busy}
+          className="px-3 py-1.5 text-sm bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50"
+        >
+          保存
+        </button>
+        {hasToken && config.provider !== "paste_rs" && (
+          <button
+            onClick={() => save(true)}
+            disabled={busy}
+            className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200 disabled:opacity-50"
+          >
+            清除令牌
+          </button>
+        )}
+        {saved && <span className="text-xs text-green-600">已保存</span>}
+      </div>
+      {error && <div className="text-xs text-red-500 mt-2 break-all">{error}</div>}
+
+      <div className="flex items-center justify-between mt-5 mb-2">
+        <h4 className="text-sm font-medium text-gray-700">已创建的链接</h4>
+        {links.length > 0 && (
+          <button onClick={clearInactive} className="text-xs text-gray-500 hover:text-gray-700">
+            清除已失效
+          </button>
+        )}
+      </div>
+      {links.length === 0 ? (
+        <div className="text-xs text-gray-400">暂无</div>
+      ) : (
+        <div className="divide-y divide-gray-100 max-h-64 overflow-y-auto">
+          {links.map((link) => {
+            const active = !link.revoked_at && !(link.expires_at && link.expires_at <= now);
+            return (
+              <div key={link.id} className="flex items-center gap-2 py-1.5 text-xs">
+                <div className="flex-1 min-w-0">
+                  <div className={`truncate ${active ? "text-blue-600" : "text-gray-400 line-through"}`} title={link.url}>
+                    {link.url}
+                  </div>
+                  <div className="truncate text-gray-400" title={link.preview}>
+                    {link.preview} · {linkStatus(link, now)}
+                  </div>
+                </div>
+                {active && (
+                  <>
+                    <button
+                      onClick={() => navigator.clipboard.writeText(link.url)}
+                      className="px-2 py-0.5 text-gray-600 bg-gray-100 rounded hover:bg-gray-200"
+                    >
+                      复制
+                    </button>
+                    <button
+                      onClick={() => revoke(link.id)}
+                      title={link.can_delete ? "在服务端删除" : "该服务不支持删除，只从列表中标记为撤销"}
+                      className="px-2 py-0.5 text-red-600 bg-red-50 rounded hover:bg-red-100"
+                    >
+                      撤销
+                    </button>
+                  </>
+                )}
+              </div>
+            );
+          })}
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,118 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { PhoneShare } from "../types";
+import { formatBytes } from "../utils/formatUtils";
+
+interface PhoneShareModalProps {
+  itemId: string | null;
+  onClose: () => void;
+}
+
+const EXPIRE_OPTIONS = [5, 10, 30, 60];
+
+function formatRemaining(seconds: number): string {
+  const minutes = Math.floor(seconds / 60);
+  return `${minutes}:${String(seconds % 60).padStart(2, "0")}`;
+}
+
+/**
+ * 发送到手机弹窗：为剪切板项生成局域网临时链接和二维码，到期或停止分享后链接失效
+ */
+export function PhoneShareModal({ itemId, onClose }: PhoneShareModalProps) {
+  const [expireMinutes, setExpireMinutes] = useState(10);
+  const [share, setShare] = useState<PhoneShare | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [now, setNow] = useState(() => Math.floor(Date.now() / 1000));
+
+  useEffect(() => {
+    setShare(null);
+    setError(null);
+    if (!itemId) return;
+    let cancelled = false;
+    tauriApi
+      .createPhoneShare(itemId, expireMinutes)
+      .then((result) => {
+        if (!cancelled) setShare(result);
+      })
+      .catch((e) => {
+        if (!cancelled) setError(String(e));
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [itemId, expireMinutes]);
+
+  useEffect(() => {
+    if (!share) return;
+    const timer = setInterval(() => setNow(Math.floor(Date.now() / 1000)), 1000);
+    return () => clearInterval(timer);
+  }, [share]);
+
+  if (!itemId) return null;
+
+  const remaining = share ? Math.max(0, share.expires_at - now) : 0;
+
+  const handleStop = async () => {
+    if (share) {
+      await tauriApi.revokePhoneShare(share.id).catch(() => {});
+    }
+    onClose();
+  };
+
+  const handleChangeExpire = async (minutes: number) => {
+    // 旧链接立即失效，按新的有效期重新生成
+    if (share) {
+      await tauriApi.revokePhoneShare(share.id).catch(() => {});
+    }
+    setExpireMinutes(minutes);
+  };
+
+  return (
+    <div className="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50" onClick={onClose}>
+      <div className="bg-white rounded-lg shadow-xl p-4 w-80 mx-4 flex flex-col items-center" onClick={(e) => e.stopPropagation()}>
+        <h2 className="text-base font-semibold mb-1">发送到手机</h2>
+        <div className="text-xs text-gray-500 mb-3 text-center">手机与电脑连接同一局域网后扫码打开</div>
+        {error ? (
+          <div className="text-sm text-red-500 break-all py-8">{error}</div>
+        ) : share ? (
+          <>
+            <img
+              src={`data:image/svg+xml;utf8,${encodeURIComponent(share.qr_svg)}`}
+              alt="QR code"
+              className={`w-52 h-52 ${remaining === 0 ? "opacity-20" : ""}`}
+            />
+            <div className="text-xs text-gray-700 mt-2 truncate max-w-full" title={share.name}>
+              {share.name || "(空内容)"} · {formatBytes(share.size)}
+            </div>
+            <div className="text-xs text-gray-400 mt-1 break-all select-all text-center">{share.url}</div>
+            <div className={`text-xs mt-2 ${remaining === 0 ? "text-red-500" : "text-gray-500"}`}>
+              {remaining === 0 ? "链接已过期" : `${formatRemaining(remaining)} 后失效`}
+            </div>
+          </>
+        ) : (
+          <div className="text-sm text-gray-500 py-8">正在生成链接...</div>
+        )}
+        <div className="flex items-center gap-2 mt-4 w-full">
+          <select
+            value={expireMinutes}
+            onChange={(e) => handleChangeExpire(Number(e.target.value))}
+            className="px-2 py-1 text-xs border border-gray-300 rounded-md"
+          >
+            {EXPIRE_OPTIONS.map((minutes) => (
+              <option key={minutes} value={minutes}>
+                {minutes} 分钟有效
+              </option>
+            ))}
+          </select>
+          <div className="flex-1" />
+          <button onClick={onClose} className="px-3 py-1 text-xs text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200">
+            关闭
+          </button>
+          <button onClick={handleStop} className="px-3 py-1 text-xs text-white bg-red-500 rounded-md hover:bg-red-600">
+            停止分享
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -1,11 +1,12 @@
 import { useState, useEffect } from "react";
+import type { InjectionMode } from "../types";
 
 interface PlaybackControlsProps {
   isPlaying: boolean;
   recordings: Array<{ file_path: string; file_name: string }>;
   selectedPath?: string;
   onSelectPath?: (path: string) => void;
-  onPlay: (path: string, speed: number) => void;
+  onPlay: (path: string, speed: number, injection: InjectionMode) => void;
   onStop: () => void;
 }
 
@@ -19,6 +20,7 @@ export const PlaybackControls: React.FC<PlaybackControlsProps> = ({
 }) => {
   const [selectedPath, setSelectedPath] = useState<string>(externalSelectedPath);
   const [speed, setSpeed] = useState<number>(1.0);
+  const [injection, setInjection] = useState<InjectionMode>("default");
 
   // Sync external selectedPath with internal state
   useEffect(() => {
@@ -32,7 +34,7 @@ export const PlaybackControls: React.FC<PlaybackControlsProps> = ({
 
   const handlePlay = () => {
     if (selectedPath) {
-      onPlay(selectedPath, speed);
+      onPlay(selectedPath, speed, injection);
     }
   };
 
@@ -67,6 +69,20 @@ export const PlaybackControls: React.FC<PlaybackControlsProps> = ({
             <option value="2.0">2x</option>
           </select>
         </div>
+        <div className="flex items-center gap-2">
+          <label className="text-sm">按键输入:</label>
+          <select
+            value={injection}
+            onChange={(e) => setInjection(e.target.value as InjectionMode)}
+            disabled={isPlaying}
+            className="px-3 py-2 border border-gray-300 rounded disabled:bg-gray-100"
+            title="中文输入法下回放出现乱码时，可改为 Unicode 字符或切换英文布局"
+          >
+            <option value="default">按录制的按键</option>
+            <option value="unicode">Unicode 字符</option>
+            <option value="english_layout">切换英文布局</option>
+          </select>
+        </div>
         <div className="flex gap-2">
           <button
             onClick={handlePlay}
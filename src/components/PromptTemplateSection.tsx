@@ -0,0 +1,194 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { PromptTemplate } from "../types";
+
+// 功能名称及可用变量，与后端 prompt_templates::FEATURES 保持一致
+const PROMPT_FEATURES: Array<{ id: string; label: string; variables: string[] }> = [
+  { id: "word_explain", label: "单词本 AI 解释", variables: ["word", "translation"] },
+  { id: "word_lookup", label: "AI 查词", variables: ["word"] },
+  { id: "translate", label: "翻译", variables: ["text", "source_lang", "target_lang"] },
+  { id: "summarize", label: "总结", variables: ["text"] },
+  { id: "rewrite_formal", label: "正式改写", variables: ["text"] },
+  { id: "fix_grammar", label: "语法修正", variables: ["text"] },
+  { id: "ask_notes", label: "问笔记", variables: ["question", "context"] },
+];
+
+const inputClassName =
+  "w-full px-3 py-2 border border-[var(--md-sys-color-outline-variant)]/40 rounded-[var(--md-sys-shape-corner-medium)] focus:outline-none focus:ring-2 focus:ring-[var(--md-sys-color-primary)]/40 focus:border-[var(--md-sys-color-primary)] bg-[var(--md-sys-color-surface)] text-[var(--md-sys-color-on-surface)] placeholder-[var(--md-sys-color-outline)] text-sm";
+
+export function PromptTemplateSection() {
+  const [templates, setTemplates] = useState<PromptTemplate[]>([]);
+  const [feature, setFeature] = useState(PROMPT_FEATURES[0].id);
+  const [editing, setEditing] = useState<PromptTemplate | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const loadTemplates = async () => {
+    try {
+      setTemplates(await tauriApi.listPromptTemplates());
+    } catch (error) {
+      console.error("获取提示词模板失败:", error);
+    }
+  };
+
+  useEffect(() => {
+    loadTemplates();
+  }, []);
+
+  const featureInfo = PROMPT_FEATURES.find((f) => f.id === feature) ?? PROMPT_FEATURES[0];
+  const featureTemplates = templates.filter((t) => t.feature === feature);
+
+  const handleSelectFeature = (id: string) => {
+    setFeature(id);
+    setEditing(null);
+    setError(null);
+  };
+
+  // 基于现有模板新建（内置模板不能直接修改）
+  const handleCopy = (template: PromptTemplate) => {
+    setEditing({ ...template, id: "", name: `${template.name} 副本`, builtin: false, active: false });
+    setError(null);
+  };
+
+  const handleSave = async () => {
+    if (!editing) return;
+    try {
+      await tauriApi.savePromptTemplate(editing);
+      setEditing(null);
+      setError(null);
+      await loadTemplates();
+    } catch (error) {
+      setError(String(error));
+    }
+  };
+
+  const handleDelete = async (template: PromptTemplate) => {
+    try {
+      await tauriApi.deletePromptTemplate(template.id);
+      if (editing?.id === template.id) {
+        setEditing(null);
+      }
+      await loadTemplates();
+    } catch (error) {
+      console.error("删除提示词模板失败:", error);
+    }
+  };
+
+  const handleActivate = async (template: PromptTemplate) => {
+    try {
+      await tauriApi.setActivePromptTemplate(template.feature, template.id);
+      await loadTemplates();
+    } catch (error) {
+      console.error("切换提示词模板失败:", error);
+    }
+  };
+
+  return (
+    <div className="bg-[var(--md-sys-color-surface-container-lowest)] rounded-[var(--md-sys-shape-corner-large)] border border-[var(--md-sys-color-outline-variant)]/30 p-6">
+      <div className="mb-4">
+        <h3 className="text-base font-semibold text-[var(--md-sys-color-on-surface)]">提示词模板</h3>
+        <p className="mt-1 text-xs text-[var(--md-sys-color-outline)]">
+          自定义各 AI 功能使用的提示词，模板中可以使用 {"{{变量}}"} 占位符
+        </p>
+      </div>
+
+      <div className="flex flex-wrap gap-2 mb-4">
+        {PROMPT_FEATURES.map((f) => (
+          <button
+            key={f.id}
+            onClick={() => handleSelectFeature(f.id)}
+            className={`px-3 py-1 rounded-full text-sm transition-all ${
+              f.id === feature
+                ? "bg-[var(--md-sys-color-primary)] text-[var(--md-sys-color-on-primary)]"
+                : "border border-[var(--md-sys-color-outline-variant)]/40 text-[var(--md-sys-color-on-surface)] hover:opacity-90"
+            }`}
+          >
+            {f.label}
+          </button>
+        ))}
+      </div>
+
+      <ul className="space-y-2">
+        {featureTemplates.map((template) => (
+          <li
+            key={template.id}
+            className="flex items-center justify-between p-2 rounded-[var(--md-sys-shape-corner-medium)] border border-[var(--md-sys-color-outline-variant)]/30"
+          >
+            <div className="min-w-0">
+              <span className="text-sm text-[var(--md-sys-color-on-surface)]">{template.name}</span>
+              {template.active && (
+                <span className="ml-2 text-xs text-[var(--md-sys-color-primary)]">使用中</span>
+              )}
+            </div>
+            <div className="flex gap-2 text-xs shrink-0">
+              {!template.active && (
+                <button onClick={() => handleActivate(template)} className="text-[var(--md-sys-color-primary)] hover:underline">
+                  使用
+                </button>
+              )}
+              {!template.builtin && (
+                <button
+                  onClick={() => { setEditing(template); setError(null); }}
+                  className="text-[var(--md-sys-color-on-surface-variant)] hover:underline"
+                >
+                  编辑
+                </button>
+              )}
+              <button onClick={() => handleCopy(template)} className="text-[var(--md-sys-color-on-surface-variant)] hover:underline">
+                复制
+              </button>
+              {!template.builtin && (
+                <button onClick={() => handleDelete(template)} className="text-red-600 hover:underline">
+                  删除
+                </button>
+              )}
+            </div>
+          </li>
+        ))}
+      </ul>
+
+      {editing && (
+        <div className="mt-4 space-y-3">
+          <input
+            type="text"
+            value={editing.name}
+            onChange={(e) => setEditing({ ...editing, name: e.target.value })}
+            className={inputClassName}
+            placeholder="模板名称"
+          />
+          <textarea
+            value={editing.system ?? ""}
+            onChange={(e) => setEditing({ ...editing, system: e.target.value || null })}
+            rows={2}
+            className={inputClassName}
+            placeholder="系统提示词（可选）"
+          />
+          <textarea
+            value={editing.template}
+            onChange={(e) => setEditing({ ...editing, template: e.target.value })}
+            rows={8}
+            className={`${inputClassName} font-mono`}
+            placeholder="提示词内容"
+          />
+          <p className="text-xs text-[var(--md-sys-color-outline)]">
+            可用变量：{featureInfo.variables.map((v) => `{{${v}}}`).join("、")}
+          </p>
+          {error && <p className="text-xs text-red-600">{error}</p>}
+          <div className="flex gap-2">
+            <button
+              onClick={handleSave}
+              className="px-4 py-2 bg-[var(--md-sys-color-primary)] text-[var(--md-sys-color-on-primary)] rounded-full hover:opacity-90 transition-all text-sm"
+            >
+              保存
+            </button>
+            <button
+              onClick={() => { setEditing(null); setError(null); }}
+              className="px-4 py-2 border border-[var(--md-sys-color-outline-variant)]/40 text-[var(--md-sys-color-on-surface)] rounded-full hover:opacity-90 transition-all text-sm"
+            >
+              取消
+            </button>
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
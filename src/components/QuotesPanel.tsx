@@ -0,0 +1,154 @@
+import { useEffect, useState } from "react";
+import { emit } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { Quote, QuoteResult } from "../types";
+
+interface QuotesPanelProps {
+  symbols: string[] | null; // 为空时显示关注列表
+}
+
+const formatPrice = (value: number) =>
+  value.toLocaleString(undefined, { maximumFractionDigits: value >= 1000 ? 2 : value >= 1 ? 4 : 6 });
+
+const formatChange = (quote: Quote) => {
+  if (quote.change == null || quote.change_percent == null) return null;
+  const sign = quote.change >= 0 ? "+" : "";
+  return `${sign}${formatPrice(quote.change)} (${sign}${quote.change_percent.toFixed(2)}%)`;
+};
+
+const describe = (quote: Quote) =>
+  `${quote.symbol} ${formatPrice(quote.price)}${quote.currency ? ` ${quote.currency}` : ""}${
+    quote.change_percent != null ? ` (${quote.change_percent >= 0 ? "+" : ""}${quote.change_percent.toFixed(2)}%)` : ""
+  }`;
+
+/**
+ * 行情面板：显示指定代码或关注列表的最新价与当日涨跌（红涨绿跌），按设置的刷新间隔自动刷新；
+ * 可将代码加入或移出关注列表，回车复制第一条报价
+ */
+export function QuotesPanel({ symbols }: QuotesPanelProps) {
+  const [results, setResults] = useState<QuoteResult[] | null>(null);
+  const [watchlist, setWatchlist] = useState<string[]>([]);
+  const [refreshSecs, setRefreshSecs] = useState(60);
+  const [loading, setLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+  const symbolsKey = symbols?.join(",") ?? "";
+
+  const refresh = (force = false) => {
+    setLoading(true);
+    tauriApi
+      .getQuotes(symbols ?? undefined, force)
+      .then((result) => {
+        setResults(result);
+        setError(null);
+      })
+      .catch((e) => setError(String(e)))
+      .finally(() => setLoading(false));
+  };
+
+  useEffect(() => {
+    tauriApi
+      .getSettings()
+      .then((settings) => {
+        setWatchlist(settings.quote_watchlist ?? []);
+        setRefreshSecs(Math.max(settings.quote_refresh_secs ?? 60, 15));
+      })
+      .catch(() => {});
+  }, []);
+
+  useEffect(() => {
+    setMessage(null);
+    refresh();
+    const timer = window.setInterval(() => refresh(), refreshSecs * 1000);
+    return () => window.clearInterval(timer);
+  }, [symbolsKey, refreshSecs]);
+
+  const inWatchlist = (input: string) => watchlist.some((s) => s.toLowerCase() === input.toLowerCase());
+
+  const toggleWatchlist = async (input: string) => {
+    try {
+      const settings = await tauriApi.getSettings();
+      const current = settings.quote_watchlist ?? [];
+      const quote_watchlist = inWatchlist(input)
+        ? current.filter((s) => s.toLowerCase() !== input.toLowerCase())
+        : [...current, input.toUpperCase()];
+      await tauriApi.saveSettings({ ...settings, quote_watchlist });
+      setWatchlist(quote_watchlist);
+      await emit("settings:updated", {});
+      if (symbols === null) refresh();
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const first = results?.find((r) => r.quote)?.quote ?? null;
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    if (!first) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      navigator.clipboard
+        .writeText(describe(first))
+        .then(() => setMessage(`已复制 ${first.symbol} 报价`))
+        .catch((err) => setError(String(err)));
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [first]);
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {!results && !error && <div className="text-xs text-gray-400">正在获取行情...</div>}
+      {results && results.length === 0 && <div className="text-xs text-gray-400">关注列表为空，输入 "quote 代码" 后点击 ☆ 添加</div>}
+      {results?.map((result, index) => {
+        const quote = result.quote;
+        const change = quote ? formatChange(quote) : null;
+        const up = (quote?.change ?? 0) >= 0;
+        return (
+          <div
+            key={`${result.input}-${index}`}
+            className={`flex items-center gap-2 px-2 py-1.5 rounded ${quote === first ? "bg-blue-50" : ""}`}
+          >
+            <div className="flex-1 min-w-0">
+              <div className="text-gray-800 truncate">
+                {quote?.symbol ?? result.input}
+                {quote?.name && <span className="ml-2 text-xs text-gray-400">{quote.name}</span>}
+              </div>
+              {result.error && (
+                <div className="text-xs text-red-500 truncate" title={result.error}>
+                  {result.stale ? `使用缓存：${result.error}` : result.error}
+                </div>
+              )}
+            </div>
+            {quote && (
+              <>
+                <span className="font-mono text-gray-900">
+                  {formatPrice(quote.price)}
+                  {quote.currency && <span className="ml-1 text-xs text-gray-400">{quote.currency}</span>}
+                </span>
+                {change && <span className={`w-36 text-right text-xs ${up ? "text-red-600" : "text-green-600"}`}>{change}</span>}
+              </>
+            )}
+            <button
+              onClick={() => toggleWatchlist(result.input)}
+              className={`text-sm ${inWatchlist(result.input) ? "text-amber-500" : "text-gray-300 hover:text-amber-500"}`}
+              title={inWatchlist(result.input) ? "移出关注列表" : "加入关注列表"}
+            >
+              {inWatchlist(result.input) ? "★" : "☆"}
+            </button>
+          </div>
+        );
+      })}
+      <div className="mt-1 flex items-center justify-between text-xs">
+        <span className="text-blue-600">{message ?? (first ? "回车复制报价" : "")}</span>
+        <button onClick={() => refresh(true)} disabled={loading} className="text-gray-500 hover:underline">
+          {loading ? "刷新中..." : `刷新（每 ${refreshSecs} 秒自动）`}
+        </button>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,78 @@
+import { useState } from "react";
+
+interface QuotesSectionProps {
+  watchlist: string[];
+  refreshSecs: number;
+  onChange: (watchlist: string[], refreshSecs: number) => void;
+}
+
+/**
+ * 行情设置：关注列表与刷新间隔，启动器输入 "行情" 时显示关注列表的报价
+ */
+export function QuotesSection({ watchlist, refreshSecs, onChange }: QuotesSectionProps) {
+  const [input, setInput] = useState("");
+
+  const addSymbol = (symbol: string) => {
+    const trimmed = symbol.trim().toUpperCase();
+    if (!trimmed || watchlist.includes(trimmed)) return;
+    onChange([...watchlist, trimmed], refreshSecs);
+  };
+
+  return (
+    <div className="bg-white rounded-lg shadow-sm border border-gray-200 p-6">
+      <h3 className="text-lg font-medium text-gray-700">行情</h3>
+      <p className="text-sm text-gray-500 mt-1 mb-4">
+        支持股票（AAPL、600519、00700）、外汇对（USD/JPY）和加密货币（BTC、ETH/EUR）；关注列表中的代码可直接在启动器中输入查询
+      </p>
+      <div className="space-y-2 mb-3">
+        {watchlist.length === 0 && <div className="text-sm text-gray-400">关注列表为空</div>}
+        {watchlist.map((symbol) => (
+          <div key={symbol} className="flex items-center gap-2 text-sm">
+            <span className="flex-1 font-mono text-gray-700">{symbol}</span>
+            <button
+              onClick={() => onChange(watchlist.filter((s) => s !== symbol), refreshSecs)}
+              className="px-2 py-1 text-xs text-red-600 hover:bg-red-50 rounded"
+            >
+              移除
+            </button>
+          </div>
+        ))}
+      </div>
+      <div className="flex gap-2 mb-4">
+        <input
+          type="text"
+          value={input}
+          onChange={(e) => setInput(e.target.value)}
+          onKeyDown={(e) => {
+            if (e.key === "Enter") {
+              addSymbol(input);
+              setInput("");
+            }
+          }}
+          placeholder="例如 MSFT 或 EUR/USD"
+          className="flex-1 px-3 py-1.5 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+        />
+        <button
+          onClick={() => {
+            addSymbol(input);
+            setInput("");
+          }}
+          className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200"
+        >
+          添加
+        </button>
+      </div>
+      <label className="flex items-center gap-2 text-sm text-gray-700">
+        刷新间隔
+        <input
+          type="number"
+          min={15}
+          value={refreshSecs}
+          onChange={(e) => onChange(watchlist, Math.max(Number(e.target.value) || 15, 15))}
+          className="w-20 px-2 py-1 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+        />
+        秒（间隔内重复查询使用缓存）
+      </label>
+    </div>
+  );
+}
@@ -0,0 +1,115 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { RadioStatus } from "../types";
+
+type RadioTarget = "wifi" | "bluetooth" | "airplane";
+
+interface RadioTogglesPanelProps {
+  target: RadioTarget | null;
+  enabled: boolean | null;
+}
+
+const LABELS: Record<RadioTarget, string> = {
+  wifi: "Wi-Fi",
+  bluetooth: "蓝牙",
+  airplane: "飞行模式",
+};
+
+/**
+ * 无线开关面板：展示 Wi-Fi、蓝牙与飞行模式的当前状态，点击切换；
+ * 输入 "wifi off"、"蓝牙"、"飞行模式 开" 等时回车直接执行
+ */
+export function RadioTogglesPanel({ target, enabled }: RadioTogglesPanelProps) {
+  const [status, setStatus] = useState<RadioStatus | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [pending, setPending] = useState<RadioTarget | null>(null);
+
+  const refresh = () => {
+    tauriApi
+      .getRadioStatus()
+      .then(setStatus)
+      .catch((e) => setError(String(e)));
+  };
+
+  useEffect(refresh, []);
+
+  // 同类设备有多个（如两块无线网卡）时，任一打开即视为打开
+  const stateOf = (item: RadioTarget): boolean | null => {
+    if (!status) return null;
+    if (item === "airplane") return status.airplane_mode ?? null;
+    const radios = status.radios.filter((r) => r.kind === item);
+    return radios.length === 0 ? null : radios.some((r) => r.on);
+  };
+
+  const isDisabled = (item: RadioTarget) =>
+    item !== "airplane" && !!status?.radios.some((r) => r.kind === item && r.disabled);
+
+  const handleSet = async (item: RadioTarget, value: boolean) => {
+    setPending(item);
+    setError(null);
+    try {
+      if (item === "airplane") {
+        await tauriApi.setAirplaneMode(value);
+      } else {
+        await tauriApi.setRadioState(item, value);
+      }
+      refresh();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setPending(null);
+    }
+  };
+
+  const targetState = target ? stateOf(target) : null;
+  const nextValue = target && targetState !== null ? enabled ?? !targetState : null;
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    if (!target || nextValue === null || nextValue === targetState || pending) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      handleSet(target, nextValue);
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [target, nextValue, targetState, pending]);
+
+  const items: RadioTarget[] = ["wifi", "bluetooth", "airplane"];
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {!status && !error && <div className="text-xs text-gray-400">正在读取无线状态...</div>}
+      {status &&
+        items.map((item) => {
+          const on = stateOf(item);
+          const highlighted = item === target;
+          return (
+            <div
+              key={item}
+              className={`flex items-center gap-2 px-2 py-1.5 rounded ${highlighted ? "bg-blue-50" : ""}`}
+            >
+              <span className="flex-1 text-gray-800">{LABELS[item]}</span>
+              {highlighted && nextValue !== null && nextValue !== on && (
+                <span className="text-xs text-gray-400">回车{nextValue ? "打开" : "关闭"}</span>
+              )}
+              {on === null ? (
+                <span className="text-xs text-gray-400">不可用</span>
+              ) : (
+                <button
+                  onClick={() => handleSet(item, !on)}
+                  disabled={pending !== null || (isDisabled(item) && !on)}
+                  className={`w-16 px-2 py-1 text-xs rounded ${on ? "bg-green-50 text-green-700" : "bg-gray-100 text-gray-700"}`}
+                >
+                  {pending === item ? "切换中..." : on ? "已打开" : isDisabled(item) ? "已禁用" : "已关闭"}
+                </button>
+              )}
+            </div>
+          );
+        })}
+    </div>
+  );
+}
@@ -2,7 +2,7 @@ import { useState, useEffect, useRef } from "react";
 import type { AppInfo } from "../types";
 import type { ThemeConfig, ResultStyle } from "../utils/themeConfig";
 import { isFolderLikePath } from "../utils/launcherUtils";
-// import { tauriApi } from "../api/tauri"; // 已禁用前端图标提取
+import { tauriApi } from "../api/tauri"; // 仅用于获取网站图标，前端文件图标提取已禁用
 
 // Icon extraction failure marker (must match backend constant)
 const ICON_EXTRACTION_FAILED_MARKER = "__ICON_EXTRACTION_FAILED__";
@@ -12,6 +12,17 @@ const isIconExtractionFailed = (icon: string | null | undefined): boolean => {
   return icon === ICON_EXTRACTION_FAILED_MARKER;
 };
 
+// 网站图标内存缓存（按站点 origin），后端另有磁盘缓存
+const faviconCache = new Map<string, string | null>();
+
+const getUrlOrigin = (url: string): string | null => {
+  try {
+    return new URL(url).origin;
+  } catch {
+    return null;
+  }
+};
+
 // 规范化路径用于比较（大小写不敏感，统一路径分隔符）
 const normalizePathForComparison = (path: string): string => {
   return path.toLowerCase().replace(/\\/g, "/");
@@ -73,6 +84,38 @@ export function ResultIcon({
     }
   }, [filePath]);
   
+  // URL 结果显示站点的 favicon（后端获取并缓存到磁盘）
+  const urlOrigin = result.type === "url" ? getUrlOrigin(result.url || result.path) : null;
+  const [favicon, setFavicon] = useState<string | null>(() =>
+    urlOrigin ? faviconCache.get(urlOrigin) ?? null : null
+  );
+
+  useEffect(() => {
+    if (!urlOrigin) {
+      setFavicon(null);
+      return;
+    }
+    if (faviconCache.has(urlOrigin)) {
+      setFavicon(faviconCache.get(urlOrigin) ?? null);
+      return;
+    }
+    let cancelled = false;
+    tauriApi
+      .getFavicon(urlOrigin)
+      .then((icon) => {
+        faviconCache.set(urlOrigin, icon);
+        if (!cancelled) {
+          setFavicon(icon);
+        }
+      })
+      .catch(() => {
+        // 忽略错误，使用默认图标
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [urlOrigin]);
+
   // 对于 file、everything 类型的 .lnk/.exe 文件，如果没有图标，尝试动态提取
   // 已禁用：后端已经在后台处理图标提取，避免重复提取
   // useEffect(() => {
@@ -304,6 +347,9 @@ export function ResultIcon({
 
   // 处理 URL 图标
   if (result.type === "url") {
+    if (favicon) {
+      return <img src={favicon} alt="" className="w-5 h-5 object-contain" draggable={false} />;
+    }
     return (
       <svg className={`w-5 h-5 ${theme.iconColor(isSelected, "text-blue-500")}`} fill="none" stroke="currentColor" viewBox="0 0 24 24">
         <path
@@ -344,6 +390,83 @@ export function ResultIcon({
     );
   }
 
+  // 处理 SSH 主机图标（终端）
+  if (result.type === "ssh_host") {
+    return (
+      <svg className={`w-5 h-5 ${theme.iconColor(isSelected, "text-gray-600")}`} fill="none" stroke="currentColor" viewBox="0 0 24 24">
+        <path
+          strokeLinecap="round"
+          strokeLinejoin="round"
+          strokeWidth={2}
+          d="M8 9l3 3-3 3m5 0h3M5 20h14a2 2 0 002-2V6a2 2 0 00-2-2H5a2 2 0 00-2 2v12a2 2 0 002 2z"
+        />
+      </svg>
+    );
+  }
+
+  // 处理别名图标
+  if (result.type === "alias") {
+    return (
+      <svg className={`w-5 h-5 ${theme.iconColor(isSelected, "text-sky-500")}`} fill="none" stroke="currentColor" viewBox="0 0 24 24">
+        <path
+          strokeLinecap="round"
+          strokeLinejoin="round"
+          strokeWidth={2}
+          d="M13 7l5 5m0 0l-5 5m5-5H6"
+        />
+      </svg>
+    );
+  }
+
+  // 处理游戏图标：优先使用扫描到的游戏图标
+  if (result.type === "game") {
+    if (result.game?.icon) {
+      return (
+        <img
+          src={`data:image/png;base64,${result.game.icon}`}
+          alt={result.displayName}
+          className={`${iconSize} object-contain`}
+        />
+      );
+    }
+    return (
+      <svg className={`w-5 h-5 ${theme.iconColor(isSelected, "text-emerald-500")}`} fill="none" stroke="currentColor" viewBox="0 0 24 24">
+        <path
+          strokeLinecap="round"
+          strokeLinejoin="round"
+          strokeWidth={2}
+          d="M6 11h4m-2-2v4m7-1h.01M18 10h.01M7 6h10a4 4 0 014 4v4a4 4 0 01-4 4H7a4 4 0 01-4-4v-4a4 4 0 014-4z"
+        />
+      </svg>
+    );
+  }
+
+  // 处理 IDE 最近项目图标（代码）
+  if (result.type === "recent_project") {
+    return (
+      <svg className={`w-5 h-5 ${theme.iconColor(isSelected, "text-blue-500")}`} fill="none" stroke="currentColor" viewBox="0 0 24 24">
+        <path
+          strokeLinecap="round"
+          strokeLinejoin="round"
+          strokeWidth={2}
+          d="M10 20l4-16m4 4l4 4-4 4M6 16l-4-4 4-4"
+        />
+      </svg>
+    );
+  }
+
+  // 处理 Git 仓库图标（分支）
+  if (result.type === "git_repo") {
+    return (
+      <svg className={`w-5 h-5 ${theme.iconColor(isSelected, "text-orange-600")}`} fill="none" stroke="currentColor" viewBox="0 0 24 24">
+        <circle cx="6" cy="6" r="2" strokeWidth={2} />
+        <circle cx="6" cy="18" r="2" strokeWidth={2} />
+        <circle cx="18" cy="8" r="2" strokeWidth={2} />
+        <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M6 8v8m12-6c0 4-6 3-10 6" />
+      </svg>
+    );
+  }
+
   // 处理历史记录图标
   if (result.type === "history") {
     return (
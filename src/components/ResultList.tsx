@@ -5,6 +5,7 @@
 
 import React from "react";
 import { ResultIcon } from "./ResultIcon";
+import { ResultPreviewPanel } from "./ResultPreviewPanel";
 import { highlightText, formatLastUsedTime } from "../utils/launcherUtils";
 import type { SearchResult } from "../utils/resultUtils";
 import type { AppInfo } from "../types";
@@ -337,6 +338,7 @@ const VerticalResultItem = React.memo<{
               </button>
             </div>
           )}
+          {isSelected && <ResultPreviewPanel result={result} isSelected={isSelected} theme={theme} />}
           {result.type === "url" && (
             <div className="flex items-center gap-2 mt-1.5 flex-wrap">
               <span
@@ -396,6 +398,68 @@ const VerticalResultItem = React.memo<{
               )}
             </div>
           )}
+          {result.type === "ssh_host" && result.sshHost && (
+            <div className="flex items-center gap-2 mt-1.5">
+              <span
+                className={`text-xs px-2.5 py-1 rounded-md font-medium transition-all ${theme.tag("ssh_host", isSelected)}`}
+                title={result.sshHost.source === "config" ? "来自 ~/.ssh/config" : "来自 ~/.ssh/known_hosts"}
+              >
+                SSH
+              </span>
+            </div>
+          )}
+          {result.type === "git_repo" && result.gitRepo && (
+            <div className="flex items-center gap-2 mt-1.5">
+              <span
+                className={`text-xs px-2.5 py-1 rounded-md font-medium transition-all ${theme.tag("git_repo", isSelected)}`}
+                title="Git 仓库"
+              >
+                Git
+              </span>
+              {result.gitRepo.branch && (
+                <span className={`text-xs truncate ${theme.metaText(isSelected)}`}>{result.gitRepo.branch}</span>
+              )}
+              {result.gitRepo.last_commit_time && (
+                <span className={`text-xs ${theme.usageText(isSelected)}`}>
+                  {formatLastUsedTime(result.gitRepo.last_commit_time * 1000)}
+                </span>
+              )}
+            </div>
+          )}
+          {result.type === "recent_project" && result.recentProject && (
+            <div className="flex items-center gap-2 mt-1.5">
+              <span
+                className={`text-xs px-2.5 py-1 rounded-md font-medium transition-all ${theme.tag("recent_project", isSelected)}`}
+                title="IDE 最近打开的项目"
+              >
+                {result.recentProject.ide_name}
+              </span>
+              {!result.recentProject.exists && (
+                <span className={`text-xs ${theme.metaText(isSelected)}`}>路径已不存在</span>
+              )}
+            </div>
+          )}
+          {result.type === "alias" && result.alias && (
+            <div className="flex items-center gap-2 mt-1.5">
+              <span
+                className={`text-xs px-2.5 py-1 rounded-md font-medium transition-all ${theme.tag("alias", isSelected)}`}
+                title="设置中的别名"
+              >
+                别名
+              </span>
+              <span className={`text-xs truncate ${theme.metaText(isSelected)}`}>{result.alias.target}</span>
+            </div>
+          )}
+          {result.type === "game" && result.game && (
+            <div className="flex items-center gap-2 mt-1.5">
+              <span
+                className={`text-xs px-2.5 py-1 rounded-md font-medium transition-all ${theme.tag("game", isSelected)}`}
+                title="已安装的游戏"
+              >
+                {result.game.platform === "steam" ? "Steam" : "Epic"}
+              </span>
+            </div>
+          )}
           {result.type === "everything" && (
             <div className="flex items-center gap-2 mt-1.5">
               <span
@@ -0,0 +1,79 @@
+import type { SearchResult } from "../utils/resultUtils";
+import type { getThemeConfig } from "../utils/themeConfig";
+import { useResultPreview } from "../hooks/useResultPreview";
+import { formatBytes } from "../utils/formatUtils";
+import { formatDateTime } from "../utils/dateUtils";
+
+interface ResultPreviewPanelProps {
+  result: SearchResult;
+  isSelected: boolean;
+  theme: ReturnType<typeof getThemeConfig>;
+}
+
+/**
+ * 选中结果下方的预览：文件大小与修改时间、文本前几行、图片尺寸与缩略图、单词释义
+ */
+export function ResultPreviewPanel({ result, isSelected, theme }: ResultPreviewPanelProps) {
+  const preview = useResultPreview(result, isSelected);
+  if (!isSelected || !preview) {
+    return null;
+  }
+
+  const metaClass = `text-xs mt-1 ${theme.metaText(isSelected)}`;
+
+  switch (preview.kind) {
+    case "file":
+      return (
+        <div className="mt-1">
+          <div className={metaClass}>
+            {formatBytes(preview.size)}
+            {preview.modified ? <span className="ml-2">修改于 {formatDateTime(preview.modified)}</span> : null}
+          </div>
+          {preview.lines.length > 0 && (
+            <pre
+              className={`text-xs mt-1 p-2 rounded-md bg-black/5 overflow-hidden ${theme.descText(isSelected)}`}
+              style={{ whiteSpace: "pre-wrap", wordBreak: "break-all", maxHeight: "120px" }}
+            >
+              {preview.lines.join("\n")}
+            </pre>
+          )}
+        </div>
+      );
+    case "folder":
+      return (
+        <div className={metaClass}>
+          {preview.item_count != null ? `${preview.item_count} 项` : "项目较多"}
+          {preview.modified ? <span className="ml-2">修改于 {formatDateTime(preview.modified)}</span> : null}
+        </div>
+      );
+    case "image":
+      return (
+        <div className="flex items-center gap-3 mt-1">
+          {preview.thumbnail && (
+            <img
+              src={preview.thumbnail}
+              alt={result.displayName}
+              className="max-h-20 max-w-[120px] rounded-md object-contain bg-black/5"
+            />
+          )}
+          <div className={metaClass}>
+            <div>
+              {preview.format.toUpperCase()} · {preview.width} × {preview.height}
+            </div>
+            <div>{formatBytes(preview.size)}</div>
+          </div>
+        </div>
+      );
+    case "word":
+      return (
+        <div className={`text-xs mt-1 leading-relaxed ${theme.descText(isSelected)}`}>
+          <div>
+            <span className="font-medium">{preview.word}</span>
+            {preview.phonetic && <span className="ml-2">{preview.phonetic}</span>}
+          </div>
+          <div>{preview.translation}</div>
+          {preview.example_sentence && <div className={theme.metaText(isSelected)}>{preview.example_sentence}</div>}
+        </div>
+      );
+  }
+}
@@ -0,0 +1,169 @@
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { Feed, FeedItem } from "../types";
+import { formatRelativeTime } from "../utils/dateUtils";
+
+interface RssPanelProps {
+  keyword: string;
+  addUrl: string | null;
+}
+
+/**
+ * RSS 面板：列出未读标题（可按关键词筛选），回车或点击在浏览器中打开并标记已读；
+ * 输入 "rss add 地址" 回车添加订阅，也可在订阅管理中删除订阅或立即刷新
+ */
+export function RssPanel({ keyword, addUrl }: RssPanelProps) {
+  const [items, setItems] = useState<FeedItem[] | null>(null);
+  const [feeds, setFeeds] = useState<Feed[]>([]);
+  const [showFeeds, setShowFeeds] = useState(false);
+  const [busy, setBusy] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+
+  const refresh = () => {
+    tauriApi
+      .getRssItems(keyword)
+      .then(setItems)
+      .catch((e) => setError(String(e)));
+    tauriApi
+      .listRssFeeds()
+      .then(setFeeds)
+      .catch(() => {});
+  };
+
+  useEffect(refresh, [keyword]);
+
+  useEffect(() => {
+    const unlisten = listen("rss-updated", refresh);
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [keyword]);
+
+  const run = async (action: () => Promise<string | void>) => {
+    setBusy(true);
+    setError(null);
+    try {
+      const result = await action();
+      if (result) setMessage(result);
+      refresh();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const openItem = (item: FeedItem) =>
+    run(async () => {
+      await tauriApi.openUrl(item.link);
+      await tauriApi.markRssItemsRead([item.id]);
+    });
+
+  const addFeed = (url: string) =>
+    run(async () => {
+      const feed = await tauriApi.addRssFeed(url);
+      return `已订阅 ${feed.title}`;
+    });
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    const first = items?.[0];
+    if (busy || (!addUrl && !first)) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      if (addUrl) addFeed(addUrl);
+      else if (first) openItem(first);
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [items, addUrl, busy]);
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {message && <div className="text-xs text-blue-600 mb-1">{message}</div>}
+      {addUrl && (
+        <div className="px-2 py-1.5 mb-1 rounded bg-blue-50 text-gray-700">
+          {busy ? "正在抓取订阅..." : `回车订阅 ${addUrl}`}
+        </div>
+      )}
+      {!addUrl && items && items.length === 0 && (
+        <div className="text-xs text-gray-400">
+          {feeds.length === 0 ? '还没有订阅，输入 "rss add 订阅地址" 添加' : keyword ? `没有匹配 "${keyword}" 的未读条目` : "没有未读条目"}
+        </div>
+      )}
+      {!addUrl &&
+        items?.slice(0, 12).map((item, index) => (
+          <div
+            key={item.id}
+            onClick={() => openItem(item)}
+            className={`px-2 py-1.5 rounded cursor-pointer hover:bg-gray-50 ${index === 0 ? "bg-blue-50" : ""}`}
+          >
+            <div className="flex items-center gap-2">
+              <span className="flex-1 text-gray-800 truncate">{item.title}</span>
+              <span className="text-xs text-gray-400 shrink-0">
+                {item.feed_title}
+                {item.published_at ? ` · ${formatRelativeTime(item.published_at)}` : ""}
+              </span>
+            </div>
+            {item.summary && <div className="text-xs text-gray-400 truncate">{item.summary}</div>}
+          </div>
+        ))}
+      {showFeeds && (
+        <div className="mt-2 border-t border-gray-100 pt-2 space-y-1">
+          {feeds.map((feed) => (
+            <div key={feed.id} className="flex items-center gap-2 text-xs">
+              <span className="flex-1 truncate text-gray-700" title={feed.url}>
+                {feed.title}
+                <span className="ml-1 text-gray-400">{feed.unread} 未读</span>
+              </span>
+              {feed.last_error && (
+                <span className="text-red-500 truncate max-w-[40%]" title={feed.last_error}>
+                  {feed.last_error}
+                </span>
+              )}
+              <button
+                onClick={() => run(() => tauriApi.removeRssFeed(feed.id))}
+                className="px-2 py-0.5 text-red-600 hover:bg-red-50 rounded"
+              >
+                删除
+              </button>
+            </div>
+          ))}
+        </div>
+      )}
+      <div className="mt-1 flex items-center gap-3 text-xs text-gray-500">
+        {items && items.length > 0 && !addUrl && <span className="flex-1 text-blue-600">回车打开第一条</span>}
+        <span className="flex-1" />
+        {items && items.length > 0 && (
+          <button
+            onClick={() => run(() => tauriApi.markRssItemsRead(items.map((i) => i.id)))}
+            disabled={busy}
+            className="hover:underline"
+          >
+            全部已读
+          </button>
+        )}
+        <button
+          onClick={() =>
+            run(async () => {
+              const inserted = await tauriApi.refreshRssFeeds();
+              return inserted > 0 ? `新增 ${inserted} 条` : "没有新条目";
+            })
+          }
+          disabled={busy || feeds.length === 0}
+          className="hover:underline"
+        >
+          刷新
+        </button>
+        <button onClick={() => setShowFeeds(!showFeeds)} className="hover:underline">
+          订阅管理（{feeds.length}）
+        </button>
+      </div>
+    </div>
+  );
+}
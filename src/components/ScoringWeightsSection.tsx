@@ -0,0 +1,97 @@
+import type { ScoringWeights } from "../types";
+
+// 数据源与得分类型，与后端 scoring::PROVIDERS / ScoringWeights 保持一致
+const PROVIDERS: Array<{ id: string; label: string }> = [
+  { id: "apps", label: "应用" },
+  { id: "system_folders", label: "系统文件夹" },
+  { id: "file_history", label: "文件历史" },
+  { id: "open_history", label: "打开历史" },
+];
+
+const FIELDS: Array<{ key: keyof ScoringWeights; label: string }> = [
+  { key: "name", label: "名称" },
+  { key: "pinyin", label: "拼音" },
+  { key: "secondary", label: "描述" },
+  { key: "path", label: "路径" },
+  { key: "frecency", label: "使用频率" },
+];
+
+const DEFAULT_WEIGHTS: ScoringWeights = { name: 1, pinyin: 1, secondary: 1, path: 1, frecency: 1 };
+const MAX_WEIGHT = 10;
+
+interface ScoringWeightsSectionProps {
+  weights: Record<string, ScoringWeights>;
+  onChange: (weights: Record<string, ScoringWeights>) => void;
+}
+
+export function ScoringWeightsSection({ weights, onChange }: ScoringWeightsSectionProps) {
+  const handleChange = (provider: string, key: keyof ScoringWeights, value: string) => {
+    const parsed = Number(value);
+    if (!Number.isFinite(parsed)) return;
+    const current = weights[provider] ?? DEFAULT_WEIGHTS;
+    onChange({
+      ...weights,
+      [provider]: { ...current, [key]: Math.min(MAX_WEIGHT, Math.max(0, parsed)) },
+    });
+  };
+
+  const handleReset = (provider: string) => {
+    const { [provider]: _removed, ...rest } = weights;
+    onChange(rest);
+  };
+
+  return (
+    <div className="bg-white rounded-lg shadow-sm border border-gray-200 p-6">
+      <h3 className="text-lg font-medium text-gray-700">搜索排序权重（高级）</h3>
+      <p className="text-sm text-gray-500 mt-1 mb-4">
+        按数据源调整各类匹配的得分倍率，1 为默认值，0 表示忽略该类匹配，最大为 {MAX_WEIGHT}
+      </p>
+      <table className="w-full text-sm">
+        <thead>
+          <tr className="text-left text-gray-500">
+            <th className="py-2 font-normal">数据源</th>
+            {FIELDS.map((field) => (
+              <th key={field.key} className="py-2 font-normal">
+                {field.label}
+              </th>
+            ))}
+            <th />
+          </tr>
+        </thead>
+        <tbody>
+          {PROVIDERS.map((provider) => {
+            const current = weights[provider.id] ?? DEFAULT_WEIGHTS;
+            return (
+              <tr key={provider.id} className="border-t border-gray-100">
+                <td className="py-2 text-gray-700">{provider.label}</td>
+                {FIELDS.map((field) => (
+                  <td key={field.key} className="py-2 pr-2">
+                    <input
+                      type="number"
+                      min={0}
+                      max={MAX_WEIGHT}
+                      step={0.1}
+                      value={current[field.key]}
+                      onChange={(e) => handleChange(provider.id, field.key, e.target.value)}
+                      className="w-16 px-2 py-1 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent text-sm"
+                    />
+                  </td>
+                ))}
+                <td className="py-2 text-right">
+                  {weights[provider.id] && (
+                    <button
+                      onClick={() => handleReset(provider.id)}
+                      className="text-xs text-gray-500 hover:text-gray-700"
+                    >
+                      恢复默认
+                    </button>
+                  )}
+                </td>
+              </tr>
+            );
+          })}
+        </tbody>
+      </table>
+    </div>
+  );
+}
@@ -0,0 +1,90 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { ScriptInfo, ScriptRunResult } from "../types";
+
+interface ScriptPanelProps {
+  script: ScriptInfo;
+  input: string;
+}
+
+/**
+ * 自动化脚本面板：输入脚本关键字后展示脚本说明，回车运行，关键字之后的文本作为 input 传入；
+ * 显示脚本返回值与 print 输出
+ */
+export function ScriptPanel({ script, input }: ScriptPanelProps) {
+  const [running, setRunning] = useState(false);
+  const [result, setResult] = useState<ScriptRunResult | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [copied, setCopied] = useState(false);
+
+  useEffect(() => {
+    setResult(null);
+    setError(null);
+  }, [script.id]);
+
+  const handleRun = async () => {
+    setRunning(true);
+    setError(null);
+    setCopied(false);
+    try {
+      setResult(await tauriApi.runScript(script.id, input));
+    } catch (e) {
+      setResult(null);
+      setError(String(e));
+    } finally {
+      setRunning(false);
+    }
+  };
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      if (!running) handleRun();
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [script.id, input, running]);
+
+  const handleCopy = async () => {
+    if (!result?.output) return;
+    await navigator.clipboard.writeText(result.output);
+    setCopied(true);
+  };
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      <div className="flex items-center gap-2">
+        <span className="font-medium text-gray-800 truncate">⚙️ {script.name}</span>
+        {script.description && <span className="text-xs text-gray-400 truncate">{script.description}</span>}
+        <div className="flex-1" />
+        <span className="text-xs text-gray-400">{running ? "运行中..." : "回车运行"}</span>
+      </div>
+      {input && <div className="text-xs text-gray-500 mt-1 truncate">输入：{input}</div>}
+      {error && <div className="text-xs text-red-500 mt-2 whitespace-pre-wrap break-all">{error}</div>}
+      {result && (
+        <div className="mt-2">
+          {result.output && (
+            <div
+              onClick={handleCopy}
+              title="点击复制"
+              className="px-2 py-1.5 bg-gray-50 rounded text-gray-800 whitespace-pre-wrap break-all max-h-40 overflow-y-auto cursor-pointer hover:bg-gray-100"
+            >
+              {result.output}
+            </div>
+          )}
+          {result.logs.length > 0 && (
+            <div className="mt-1 px-2 py-1 bg-gray-900 text-gray-100 rounded font-mono text-xs whitespace-pre-wrap max-h-32 overflow-y-auto">
+              {result.logs.join("\n")}
+            </div>
+          )}
+          <div className="text-xs text-gray-400 mt-1">
+            {copied ? "已复制 · " : ""}用时 {result.duration_ms} ms
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
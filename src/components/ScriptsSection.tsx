@@ -0,0 +1,139 @@
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { ScriptInfo } from "../types";
+
+const formatHotkey = (config: { modifiers: string[]; key: string }) => [...config.modifiers, config.key].join(" + ");
+
+/**
+ * 自动化脚本：scripts 文件夹中的 .rhai 脚本，修改后自动重新加载；
+ * 通过文件头的 @keyword / @hotkey 在启动器或全局快捷键中触发
+ */
+export function ScriptsSection() {
+  const [scripts, setScripts] = useState<ScriptInfo[]>([]);
+  const [name, setName] = useState("");
+  const [keyword, setKeyword] = useState("");
+  const [error, setError] = useState<string | null>(null);
+  const [createdPath, setCreatedPath] = useState<string | null>(null);
+  // 用户已确认的网络白名单，脚本 id -> 主机
+  const [httpAllow, setHttpAllow] = useState<Record<string, string[]>>({});
+
+  useEffect(() => {
+    tauriApi
+      .listScripts()
+      .then(setScripts)
+      .catch((e) => setError(String(e)));
+    tauriApi
+      .getScriptHttpAllow()
+      .then(setHttpAllow)
+      .catch((e) => setError(String(e)));
+    const unlisten = listen<ScriptInfo[]>("scripts://changed", (event) => setScripts(event.payload));
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const setAllowedHosts = async (id: string, hosts: string[]) => {
+    setError(null);
+    try {
+      await tauriApi.setScriptHttpAllow(id, hosts);
+      setHttpAllow(await tauriApi.getScriptHttpAllow());
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const create = async () => {
+    if (!name.trim()) return;
+    setError(null);
+    try {
+      setCreatedPath(await tauriApi.createScript(name.trim(), keyword.trim() || undefined));
+      setName("");
+      setKeyword("");
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  return (
+    <div className="bg-white rounded-lg shadow-sm border border-gray-200 p-6">
+      <div className="flex items-center justify-between">
+        <h3 className="text-lg font-medium text-gray-700">自动化脚本</h3>
+        <button
+          onClick={() => tauriApi.openScriptsFolder().catch((e) => setError(String(e)))}
+          className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200"
+        >
+          打开脚本文件夹
+        </button>
+      </div>
+      <p className="text-sm text-gray-500 mt-1 mb-4">
+        使用 Rhai 编写脚本，可读写剪切板、搜索、启动程序、发送通知，并访问 @allow_http 申请且经你确认的域名。保存后自动生效
+      </p>
+      {scripts.length === 0 ? (
+        <div className="text-xs text-gray-400">暂无脚本</div>
+      ) : (
+        <div className="divide-y divide-gray-100">
+          {scripts.map((script) => (
+            <div key={script.id} className="py-2 text-sm">
+              <div className="flex items-center gap-2">
+                <span className="font-medium text-gray-800">{script.name}</span>
+                {script.keyword && (
+                  <span className="px-1.5 py-0.5 text-xs font-mono text-blue-700 bg-blue-50 rounded">{script.keyword}</span>
+                )}
+                {script.hotkey && (
+                  <span className="text-xs font-mono text-gray-400">{formatHotkey(script.hotkey)}</span>
+                )}
+                <div className="flex-1" />
+                <span className="text-xs text-gray-400">{script.id}.rhai</span>
+              </div>
+              {script.description && <div className="text-xs text-gray-500 mt-0.5">{script.description}</div>}
+              {(script.allow_http.length > 0 || (httpAllow[script.id] ?? []).length > 0) && (
+                <div className="flex items-center gap-2 text-xs text-gray-400 mt-0.5">
+                  <span>网络：{(httpAllow[script.id] ?? []).join(", ") || "未允许"}</span>
+                  {script.allow_http.some((host) => !(httpAllow[script.id] ?? []).includes(host)) && (
+                    <button
+                      onClick={() => setAllowedHosts(script.id, script.allow_http)}
+                      className="text-blue-600 hover:underline"
+                      title={`允许访问 ${script.allow_http.join(", ")}`}
+                    >
+                      允许 {script.allow_http.join(", ")}
+                    </button>
+                  )}
+                  {(httpAllow[script.id] ?? []).length > 0 && (
+                    <button onClick={() => setAllowedHosts(script.id, [])} className="text-red-500 hover:underline">
+                      撤销
+                    </button>
+                  )}
+                </div>
+              )}
+              {script.error && <div className="text-xs text-red-500 mt-0.5 whitespace-pre-wrap break-all">{script.error}</div>}
+            </div>
+          ))}
+        </div>
+      )}
+      <div className="flex items-center gap-2 mt-4 text-sm">
+        <input
+          value={name}
+          onChange={(e) => setName(e.target.value)}
+          placeholder="脚本名称"
+          className="flex-1 px-3 py-1.5 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+        />
+        <input
+          value={keyword}
+          onChange={(e) => setKeyword(e.target.value)}
+          placeholder="触发关键字（可选）"
+          className="w-40 px-3 py-1.5 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+        />
+        <button
+          onClick={create}
+          disabled={!name.trim()}
+          className="px-3 py-1.5 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50"
+        >
+          新建
+        </button>
+      </div>
+      {createdPath && <div className="text-xs text-green-600 mt-2 break-all">已创建：{createdPath}</div>}
+      {error && <div className="text-xs text-red-500 mt-2 break-all">{error}</div>}
+    </div>
+  );
+}
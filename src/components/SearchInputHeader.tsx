@@ -22,6 +22,7 @@ interface SearchInputHeaderProps {
   onStartWindowDragging: () => void;
   contextMenu: any; // Using any for simplicity as it's just checking for null
   setContextMenu: (menu: any) => void;
+  queryCompletion?: string | null; // 查询历史补全建议，按 Tab 接受
 }
 
 export function SearchInputHeader({
@@ -38,6 +39,7 @@ export function SearchInputHeader({
   onStartWindowDragging,
   contextMenu,
   setContextMenu,
+  queryCompletion,
 }: SearchInputHeaderProps) {
   
   // 缓存输入框的 className 和 style，避免每次渲染都创建新对象
@@ -174,6 +176,14 @@ export function SearchInputHeader({
               e.stopPropagation();
             }}
           />
+          {queryCompletion && (
+            <span
+              className="text-xs text-gray-400 whitespace-nowrap flex-shrink-0 max-w-[40%] truncate"
+              title={queryCompletion}
+            >
+              Tab → {queryCompletion}
+            </span>
+          )}
         </div>
         {/* 应用中心按钮 */}
         <div
@@ -0,0 +1,147 @@
+import type { ProviderSettings } from "../types";
+
+// 启动器数据源，与后端 search_providers::PROVIDERS 保持一致；panel 为按关键字触发的面板，只能启用或停用
+const PROVIDERS: Array<{ id: string; label: string; panel?: boolean }> = [
+  { id: "apps", label: "应用" },
+  { id: "system_folders", label: "系统文件夹" },
+  { id: "file_history", label: "文件历史" },
+  { id: "everything", label: "Everything 文件搜索" },
+  { id: "url_history", label: "网址访问历史" },
+  { id: "browser_history", label: "浏览器历史（Chrome / Edge / Brave）" },
+  { id: "memos", label: "备忘录" },
+  { id: "plugins", label: "插件" },
+  { id: "hot_folders", label: "热点文件夹最近文件" },
+  { id: "ssh_hosts", label: "SSH 主机" },
+  { id: "git_repos", label: "Git 仓库" },
+  { id: "recent_projects", label: "IDE 最近项目" },
+  { id: "games", label: "游戏（Steam / Epic）" },
+  { id: "aliases", label: "别名" },
+  { id: "network_tools", label: "网络工具（ip、ping）", panel: true },
+  { id: "system_monitor", label: "系统状态（cpu、ram）", panel: true },
+  { id: "audio_devices", label: "音频设备", panel: true },
+  { id: "display_control", label: "显示器亮度与夜间模式", panel: true },
+  { id: "radios", label: "无线开关（wifi、蓝牙）", panel: true },
+  { id: "window_actions", label: "窗口管理", panel: true },
+  { id: "virtual_desktops", label: "虚拟桌面", panel: true },
+  { id: "keep_awake", label: "保持唤醒", panel: true },
+  { id: "text_transform", label: "文本转换", panel: true },
+  { id: "password_gen", label: "密码生成", panel: true },
+  { id: "totp", label: "两步验证码", panel: true },
+  { id: "world_clock", label: "世界时钟", panel: true },
+  { id: "quotes", label: "行情", panel: true },
+  { id: "rss", label: "RSS 订阅", panel: true },
+  { id: "timers", label: "计时器与提醒", panel: true },
+  { id: "calendar", label: "日历", panel: true },
+  { id: "habits", label: "习惯打卡", panel: true },
+  { id: "scripts", label: "脚本", panel: true },
+  { id: "workflows", label: "工作流", panel: true },
+];
+
+const DEFAULT_PROVIDER: ProviderSettings = { enabled: true, priority: 0, max_results: 0 };
+const MAX_PRIORITY = 100;
+const MAX_RESULTS_LIMIT = 500;
+
+interface SearchProvidersSectionProps {
+  providers: Record<string, ProviderSettings>;
+  onChange: (providers: Record<string, ProviderSettings>) => void;
+}
+
+export function SearchProvidersSection({ providers, onChange }: SearchProvidersSectionProps) {
+  const update = (provider: string, patch: Partial<ProviderSettings>) => {
+    const current = providers[provider] ?? DEFAULT_PROVIDER;
+    onChange({ ...providers, [provider]: { ...current, ...patch } });
+  };
+
+  const handleNumberChange = (
+    provider: string,
+    key: "priority" | "max_results",
+    value: string,
+    min: number,
+    max: number
+  ) => {
+    const parsed = Math.round(Number(value));
+    if (!Number.isFinite(parsed)) return;
+    update(provider, { [key]: Math.min(max, Math.max(min, parsed)) });
+  };
+
+  const handleReset = (provider: string) => {
+    const { [provider]: _removed, ...rest } = providers;
+    onChange(rest);
+  };
+
+  return (
+    <div className="bg-white rounded-lg shadow-sm border border-gray-200 p-6">
+      <h3 className="text-lg font-medium text-gray-700">搜索数据源</h3>
+      <p className="text-sm text-gray-500 mt-1 mb-4">
+        停用的数据源不再参与搜索；优先级越大结果越靠前（-{MAX_PRIORITY} 到 {MAX_PRIORITY}），结果上限为 0 表示不限制
+      </p>
+      <table className="w-full text-sm">
+        <thead>
+          <tr className="text-left text-gray-500">
+            <th className="py-2 font-normal">数据源</th>
+            <th className="py-2 font-normal">启用</th>
+            <th className="py-2 font-normal">优先级</th>
+            <th className="py-2 font-normal">结果上限</th>
+            <th />
+          </tr>
+        </thead>
+        <tbody>
+          {PROVIDERS.map((provider) => {
+            const current = providers[provider.id] ?? DEFAULT_PROVIDER;
+            return (
+              <tr key={provider.id} className="border-t border-gray-100">
+                <td className="py-2 text-gray-700">{provider.label}</td>
+                <td className="py-2 pr-2">
+                  <input
+                    type="checkbox"
+                    checked={current.enabled}
+                    onChange={(e) => update(provider.id, { enabled: e.target.checked })}
+                    className="w-4 h-4 text-blue-600 border-gray-300 rounded focus:ring-blue-500"
+                  />
+                </td>
+                <td className="py-2 pr-2">
+                  <input
+                    type="number"
+                    min={-MAX_PRIORITY}
+                    max={MAX_PRIORITY}
+                    step={1}
+                    value={current.priority}
+                    disabled={!current.enabled || provider.panel}
+                    onChange={(e) =>
+                      handleNumberChange(provider.id, "priority", e.target.value, -MAX_PRIORITY, MAX_PRIORITY)
+                    }
+                    className="w-20 px-2 py-1 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent text-sm disabled:bg-gray-100"
+                  />
+                </td>
+                <td className="py-2 pr-2">
+                  <input
+                    type="number"
+                    min={0}
+                    max={MAX_RESULTS_LIMIT}
+                    step={1}
+                    value={current.max_results}
+                    disabled={!current.enabled || provider.panel}
+                    onChange={(e) =>
+                      handleNumberChange(provider.id, "max_results", e.target.value, 0, MAX_RESULTS_LIMIT)
+                    }
+                    className="w-20 px-2 py-1 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent text-sm disabled:bg-gray-100"
+                  />
+                </td>
+                <td className="py-2 text-right">
+                  {providers[provider.id] && (
+                    <button
+                      onClick={() => handleReset(provider.id)}
+                      className="text-xs text-gray-500 hover:text-gray-700"
+                    >
+                      恢复默认
+                    </button>
+                  )}
+                </td>
+              </tr>
+            );
+          })}
+        </tbody>
+      </table>
+    </div>
+  );
+}
@@ -1,8 +1,37 @@
 import { tauriApi } from "../api/tauri";
 import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
 import { UpdateSection } from "./UpdateSection";
+import { PromptTemplateSection } from "./PromptTemplateSection";
+import { ChatHistorySection } from "./ChatHistorySection";
+import { ScoringWeightsSection } from "./ScoringWeightsSection";
+import { SearchProvidersSection } from "./SearchProvidersSection";
+import { HotFoldersSection } from "./HotFoldersSection";
+import { QuotesSection } from "./QuotesSection";
+import { TextVariablesSection } from "./TextVariablesSection";
+import { CloudSyncSection } from "./CloudSyncSection";
+import { GitSyncSection } from "./GitSyncSection";
+import { BrowserBridgeSection } from "./BrowserBridgeSection";
+import { PasteServiceSection } from "./PasteServiceSection";
+import { ScriptsSection } from "./ScriptsSection";
+import { WorkflowsSection } from "./WorkflowsSection";
 import { ErrorDialog } from "./ErrorDialog";
-import type { SearchEngineConfig } from "../types";
+import type { SearchEngineConfig, ClipboardMonitorStatus, Theme, FocusModeStatus, ProxySettings, SystemProxy, UsageSummary, ScoringWeights, ProviderSettings, TextVariableConfig } from "../types";
+
+// AI 用量统计中的功能名称
+const LLM_FEATURE_LABELS: Record<string, string> = {
+  launcher_ask: "启动器 AI 问答",
+  wordbook_explain: "单词本 AI 解释",
+  wordbook_lookup: "AI 查词",
+  connection_test: "测试连接",
+  clipboard_summarize: "剪切板总结",
+  clipboard_rewrite_formal: "剪切板正式改写",
+  clipboard_fix_grammar: "剪切板语法修正",
+  clipboard_translate: "剪切板翻译",
+  clipboard_embedding: "剪切板语义索引",
+  ask_notes: "问笔记",
+  other: "其他",
+};
 
 interface AiSettingsProps {
   settings: {
@@ -10,6 +39,9 @@ interface AiSettingsProps {
       model: string;
       base_url: string;
       api_key?: string;
+      monthly_budget?: number | null;
+      embedding_model?: string | null;
+      chat_retention_days?: number;
     };
   };
   onSettingsChange: (settings: any) => void;
@@ -25,6 +57,34 @@ export function AiSettingsPage({
   testResult,
   onTestConnection,
 }: AiSettingsProps) {
+  const [cacheMessage, setCacheMessage] = useState<string | null>(null);
+  const [usage, setUsage] = useState<UsageSummary | null>(null);
+
+  // 加载本月用量；保存预算后、收到预算提醒时刷新
+  useEffect(() => {
+    const loadUsage = () => {
+      tauriApi
+        .getLlmUsageSummary()
+        .then(setUsage)
+        .catch((error) => console.error("获取 AI 用量失败:", error));
+    };
+    loadUsage();
+    const unlisten = listen<UsageSummary>("llm-budget-warning", (event) => setUsage(event.payload));
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [settings.llm.monthly_budget]);
+
+  const handleClearCache = async () => {
+    try {
+      const removed = await tauriApi.clearLlmCache();
+      setCacheMessage(`已清除 ${removed} 条缓存的 AI 回答`);
+    } catch (error) {
+      console.error("清除 AI 回答缓存失败:", error);
+      setCacheMessage(`清除失败: ${error}`);
+    }
+  };
+
   return (
     <div className="space-y-6">
       <div>
@@ -97,6 +157,27 @@ export function AiSettingsPage({
             </p>
           </div>
 
+          <div>
+            <label className="block text-sm font-medium text-[var(--md-sys-color-on-surface)] mb-2">
+              向量模型（可选）
+            </label>
+            <input
+              type="text"
+              value={settings.llm.embedding_model || ''}
+              onChange={(e) =>
+                onSettingsChange({
+                  ...settings,
+                  llm: { ...settings.llm, embedding_model: e.target.value || null },
+                })
+              }
+              className="w-full px-3 py-2 border border-[var(--md-sys-color-outline-variant)]/40 rounded-[var(--md-sys-shape-corner-medium)] focus:outline-none focus:ring-2 focus:ring-[var(--md-sys-color-primary)]/40 focus:border-[var(--md-sys-color-primary)] bg-[var(--md-sys-color-surface)] text-[var(--md-sys-color-on-surface)] placeholder-[var(--md-sys-color-outline)]"
+              placeholder="text-embedding-3-small / nomic-embed-text"
+            />
+            <p className="mt-1 text-xs text-[var(--md-sys-color-outline)]">
+              填写后启用剪切板语义搜索，文本记录会在空闲时计算向量；留空则不启用
+            </p>
+          </div>
+
           <div className="pt-2">
             <button
               onClick={onTestConnection}
@@ -105,6 +186,16 @@ export function AiSettingsPage({
             >
               {isTesting ? "测试中..." : "测试连接"}
             </button>
+            <button
+              onClick={handleClearCache}
+              className="ml-2 px-4 py-2 border border-[var(--md-sys-color-outline-variant)]/40 text-[var(--md-sys-color-on-surface)] rounded-full hover:opacity-90 transition-all text-sm"
+              title="单词解释、查词等相同请求会复用缓存的回答"
+            >
+              清除回答缓存
+            </button>
+            {cacheMessage && (
+              <p className="mt-2 text-xs text-[var(--md-sys-color-outline)]">{cacheMessage}</p>
+            )}
             {testResult && (
               <div className={`mt-2 p-2 rounded-[var(--md-sys-shape-corner-medium)] text-sm ${
                 testResult.success 
@@ -115,8 +206,75 @@ export function AiSettingsPage({
               </div>
             )}
           </div>
+
+          <div className="pt-4 border-t border-[var(--md-sys-color-outline-variant)]/30">
+            <div className="flex items-center justify-between mb-2">
+              <label className="block text-sm font-medium text-[var(--md-sys-color-on-surface)]">
+                本月用量{usage ? `（${usage.month}）` : ""}
+              </label>
+              <div className="flex items-center gap-2 text-sm text-[var(--md-sys-color-on-surface-variant)]">
+                <span>月度预算 $</span>
+                <input
+                  type="number"
+                  min={0}
+                  step={0.5}
+                  value={settings.llm.monthly_budget ?? ""}
+                  onChange={(e) =>
+                    onSettingsChange({
+                      ...settings,
+                      llm: {
+                        ...settings.llm,
+                        monthly_budget: e.target.value === "" ? null : Math.max(0, Number(e.target.value)),
+                      },
+                    })
+                  }
+                  placeholder="不限"
+                  className="w-24 px-2 py-1 border border-[var(--md-sys-color-outline-variant)]/40 rounded-[var(--md-sys-shape-corner-medium)] focus:outline-none focus:ring-2 focus:ring-[var(--md-sys-color-primary)]/40 bg-[var(--md-sys-color-surface)] text-[var(--md-sys-color-on-surface)]"
+                />
+              </div>
+            </div>
+            {usage && (
+              <div className="space-y-2 text-sm text-[var(--md-sys-color-on-surface-variant)]">
+                <p>
+                  {usage.calls} 次请求 · 输入 {usage.prompt_tokens.toLocaleString()} tokens · 输出{" "}
+                  {usage.completion_tokens.toLocaleString()} tokens · 估算费用 ${usage.estimated_cost.toFixed(4)}
+                  {usage.has_estimated_tokens ? "（部分 token 数为估算值）" : ""}
+                </p>
+                {usage.budget_used_ratio != null && usage.budget_used_ratio >= 0.8 && (
+                  <div className="p-2 rounded-[var(--md-sys-shape-corner-medium)] bg-amber-50 text-amber-700 border border-amber-200">
+                    本月估算费用已达预算的 {Math.round(usage.budget_used_ratio * 100)}%
+                  </div>
+                )}
+                {usage.by_feature.length > 0 && (
+                  <ul className="space-y-1 text-xs">
+                    {usage.by_feature.map((item) => (
+                      <li key={item.feature} className="flex justify-between">
+                        <span>{LLM_FEATURE_LABELS[item.feature] ?? item.feature}</span>
+                        <span>
+                          {item.calls} 次 · {(item.prompt_tokens + item.completion_tokens).toLocaleString()} tokens · $
+                          {item.estimated_cost.toFixed(4)}
+                        </span>
+                      </li>
+                    ))}
+                  </ul>
+                )}
+              </div>
+            )}
+          </div>
         </div>
       </div>
+
+      <PromptTemplateSection />
+
+      <ChatHistorySection
+        retentionDays={settings.llm.chat_retention_days ?? 90}
+        onRetentionDaysChange={(days) =>
+          onSettingsChange({
+            ...settings,
+            llm: { ...settings.llm, chat_retention_days: days },
+          })
+        }
+      />
     </div>
   );
 }
@@ -132,6 +290,10 @@ interface SystemSettingsProps {
     auto_check_update?: boolean;
     clipboard_max_items?: number;
     translation_tab_order?: string[];
+    theme?: string;
+    locale?: string;
+    dnd_auto_detect?: boolean;
+    proxy?: ProxySettings;
   };
   onSettingsChange: (settings: any) => void;
   onOpenHotkeySettings: () => void;
@@ -143,6 +305,88 @@ export function SystemSettingsPage({
   onOpenHotkeySettings,
 }: SystemSettingsProps) {
   const [nextCheckTime, setNextCheckTime] = useState<string>("");
+  const [monitorStatus, setMonitorStatus] = useState<ClipboardMonitorStatus | null>(null);
+  const [isRestartingMonitor, setIsRestartingMonitor] = useState(false);
+  const [themes, setThemes] = useState<Theme[]>([]);
+  const [focusMode, setFocusMode] = useState<FocusModeStatus | null>(null);
+  const [systemProxy, setSystemProxy] = useState<SystemProxy | null>(null);
+  const proxy: ProxySettings = settings.proxy ?? { mode: "system", url: "" };
+
+  useEffect(() => {
+    tauriApi
+      .getSystemProxy()
+      .then(setSystemProxy)
+      .catch((error) => console.error("检测系统代理失败:", error));
+  }, []);
+
+  const handleProxyChange = (changes: Partial<ProxySettings>) => {
+    onSettingsChange({ ...settings, proxy: { ...proxy, ...changes } });
+  };
+
+  useEffect(() => {
+    tauriApi
+      .getFocusModeStatus()
+      .then(setFocusMode)
+      .catch((error) => console.error("获取勿扰模式状态失败:", error));
+    const unlisten = listen<FocusModeStatus>("focus-mode-changed", (event) => setFocusMode(event.payload));
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const handleFocusModeToggle = async (enabled: boolean) => {
+    try {
+      setFocusMode(await tauriApi.setFocusMode(enabled));
+    } catch (error) {
+      console.error("切换勿扰模式失败:", error);
+    }
+  };
+
+  useEffect(() => {
+    tauriApi
+      .listThemes()
+      .then(setThemes)
+      .catch((error) => console.error("获取主题列表失败:", error));
+  }, []);
+
+  const handleThemeChange = async (id: string) => {
+    onSettingsChange({ ...settings, theme: id });
+    try {
+      await tauriApi.setTheme(id);
+    } catch (error) {
+      console.error("切换主题失败:", error);
+    }
+  };
+
+  // 加载剪切板监控状态，之后由事件总线推送状态变化
+  useEffect(() => {
+    tauriApi
+      .getMonitorStatus()
+      .then(setMonitorStatus)
+      .catch((error) => console.error("获取剪切板监控状态失败:", error));
+
+    const unlisten = listen<ClipboardMonitorStatus>("bus://monitor-status", (event) => {
+      setMonitorStatus(event.payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const handleRestartMonitor = async () => {
+    setIsRestartingMonitor(true);
+    try {
+      await tauriApi.restartMonitor();
+      // 等待守护线程重新启动监控后再刷新状态
+      setTimeout(() => {
+        tauriApi.getMonitorStatus().then(setMonitorStatus).catch(() => {});
+        setIsRestartingMonitor(false);
+      }, 800);
+    } catch (error) {
+      console.error("重启剪切板监控失败:", error);
+      setIsRestartingMonitor(false);
+    }
+  };
 
   // 计算下次检查更新的时间
   useEffect(() => {
@@ -224,6 +468,154 @@ export function SystemSettingsPage({
             </select>
           </div>
 
+          <div className="flex items-center justify-between">
+            <div className="flex-1">
+              <label className="block text-sm font-medium text-gray-700 mb-1">
+                主题
+              </label>
+              <p className="text-xs text-gray-500">
+                跟随系统时随 Windows 深色模式自动切换
+              </p>
+            </div>
+            <select
+              value={settings.theme || "system"}
+              onChange={(e) => handleThemeChange(e.target.value)}
+              className="px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent bg-white"
+            >
+              <option value="system">跟随系统</option>
+              {themes.map((theme) => (
+                <option key={theme.id} value={theme.id}>
+                  {theme.name}
+                </option>
+              ))}
+            </select>
+          </div>
+
+          <div className="flex items-center justify-between">
+            <div className="flex-1">
+              <label className="block text-sm font-medium text-gray-700 mb-1">
+                语言
+              </label>
+              <p className="text-xs text-gray-500">
+                托盘菜单、系统文件夹名称等由后端提供的文字所使用的语言
+              </p>
+            </div>
+            <select
+              value={settings.locale || "zh-CN"}
+              onChange={(e) => onSettingsChange({ ...settings, locale: e.target.value })}
+              className="px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent bg-white"
+            >
+              <option value="zh-CN">简体中文</option>
+              <option value="en-US">English</option>
+            </select>
+          </div>
+
+          <div className="flex items-center justify-between">
+            <div className="flex-1">
+              <label className="block text-sm font-medium text-gray-700 mb-1">
+                勿扰模式
+              </label>
+              <p className="text-xs text-gray-500">
+                开启后快捷键不再弹出启动器，回放中的录制会暂停
+                {focusMode?.active && !focusMode.manual ? "（已因全屏程序自动开启）" : ""}
+              </p>
+            </div>
+            <label className="relative inline-flex items-center cursor-pointer">
+              <input
+                type="checkbox"
+                checked={focusMode?.manual ?? false}
+                onChange={(e) => handleFocusModeToggle(e.target.checked)}
+                className="sr-only peer"
+              />
+              <div className="w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-blue-300 rounded-full peer peer-checked:after:translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:left-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all peer-checked:bg-blue-600"></div>
+            </label>
+          </div>
+
+          <div className="flex items-center justify-between">
+            <div className="flex-1">
+              <label className="block text-sm font-medium text-gray-700 mb-1">
+                全屏时自动勿扰
+              </label>
+              <p className="text-xs text-gray-500">
+                前台程序无边框全屏（演示、游戏、全屏视频）时自动进入勿扰模式，最大化窗口不算
+              </p>
+            </div>
+            <label className="relative inline-flex items-center cursor-pointer">
+              <input
+                type="checkbox"
+                checked={settings.dnd_auto_detect ?? false}
+                onChange={(e) =>
+                  onSettingsChange({
+                    ...settings,
+                    dnd_auto_detect: e.target.checked,
+                  })
+                }
+                className="sr-only peer"
+              />
+              <div className="w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-blue-300 rounded-full peer peer-checked:after:translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:left-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all peer-checked:bg-blue-600"></div>
+            </label>
+          </div>
+
+          <div className="space-y-2">
+            <div className="flex items-center justify-between">
+              <div className="flex-1">
+                <label className="block text-sm font-medium text-gray-700 mb-1">
+                  网络代理
+                </label>
+                <p className="text-xs text-gray-500">
+                  AI 对话、更新检查、搜索联想等网络请求使用的代理
+                  {proxy.mode === "system"
+                    ? systemProxy
+                      ? `（当前系统代理：${systemProxy.server}）`
+                      : "（未检测到系统代理，将直接连接）"
+                    : ""}
+                </p>
+              </div>
+              <select
+                value={proxy.mode}
+                onChange={(e) => handleProxyChange({ mode: e.target.value })}
+                className="px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent bg-white"
+              >
+                <option value="system">使用系统代理</option>
+                <option value="none">不使用代理</option>
+                <option value="manual">手动配置</option>
+              </select>
+            </div>
+            {proxy.mode === "manual" && (
+              <div className="grid grid-cols-3 gap-2">
+                <input
+                  type="text"
+                  value={proxy.url}
+                  onChange={(e) => handleProxyChange({ url: e.target.value })}
+                  placeholder="http://127.0.0.1:7890 或 socks5://127.0.0.1:1080"
+                  className="col-span-3 px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent"
+                />
+                <input
+                  type="text"
+                  value={proxy.username ?? ""}
+                  onChange={(e) => handleProxyChange({ username: e.target.value || null })}
+                  placeholder="用户名（可选）"
+                  className="px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent"
+                />
+                <input
+                  type="password"
+                  value={proxy.password ?? ""}
+                  onChange={(e) => handleProxyChange({ password: e.target.value || null })}
+                  placeholder={proxy.has_password ? "已设置密码，留空保持不变" : "密码（可选）"}
+                  className="px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-transparent"
+                />
+                {proxy.has_password && !proxy.password && (
+                  <button
+                    onClick={() => handleProxyChange({ password: "", has_password: false })}
+                    className="col-span-3 justify-self-start text-xs text-red-500 hover:underline"
+                  >
+                    清除已保存的密码
+                  </button>
+                )}
+              </div>
+            )}
+          </div>
+
           <div className="flex items-center justify-between">
             <div className="flex-1">
               <label className="block text-sm font-medium text-gray-700 mb-1">
@@ -326,8 +718,50 @@ export function SystemSettingsPage({
             </div>
           </div>
 
+          {monitorStatus?.supported && (
+            <div className="border-t border-gray-200 pt-6">
+              <div className="flex items-center justify-between">
+                <div className="flex-1">
+                  <label className="block text-sm font-medium text-gray-700 mb-1">
+                    剪切板监控
+                  </label>
+                  <p className="text-xs text-gray-500 mb-1">
+                    监控异常退出时会自动重启；如果剪切板历史不再更新，可以手动重启
+                  </p>
+                  <p className={`text-xs font-medium ${monitorStatus.running ? "text-green-600" : "text-red-600"}`}>
+                    {monitorStatus.running ? "运行中" : "已停止"}
+                    {monitorStatus.restart_count > 0 && `，已重启 ${monitorStatus.restart_count} 次`}
+                    {!monitorStatus.running && monitorStatus.next_retry_at &&
+                      `，将于 ${new Date(monitorStatus.next_retry_at * 1000).toLocaleTimeString("zh-CN")} 自动重试`}
+                  </p>
+                  {monitorStatus.last_error && (
+                    <p className="text-xs text-gray-400 mt-1 break-all">
+                      最近错误：{monitorStatus.last_error}
+                    </p>
+                  )}
+                </div>
+                <button
+                  onClick={handleRestartMonitor}
+                  disabled={isRestartingMonitor}
+                  className="px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 transition-colors text-sm disabled:opacity-50"
+                >
+                  {isRestartingMonitor ? "重启中..." : "重启监控"}
+                </button>
+              </div>
+            </div>
+          )}
+
         </div>
       </div>
+
+      <CloudSyncSection />
+
+      <GitSyncSection />
+
+      <BrowserBridgeSection />
+      <PasteServiceSection />
+      <ScriptsSection />
+      <WorkflowsSection />
     </div>
   );
 }
@@ -335,6 +769,13 @@ export function SystemSettingsPage({
 interface LauncherSettingsProps {
   settings: {
     search_engines?: SearchEngineConfig[];
+    web_suggestions_enabled?: boolean;
+    scoring_weights?: Record<string, ScoringWeights>;
+    providers?: Record<string, ProviderSettings>;
+    hot_folders?: string[];
+    quote_watchlist?: string[];
+    quote_refresh_secs?: number;
+    text_variables?: TextVariableConfig[];
   };
   onSettingsChange: (settings: any) => void;
 }
@@ -507,6 +948,31 @@ export function LauncherSettingsPage({
               配置搜索引擎前缀，输入特定前缀时可在浏览器中快速搜索。URL 模板中使用 <code className="bg-gray-100 px-1 rounded">{`{query}`}</code> 作为搜索关键词的占位符。
             </p>
 
+            <div className="flex items-center justify-between mb-4">
+              <div className="flex-1">
+                <label className="block text-sm font-medium text-gray-700 mb-1">
+                  搜索联想
+                </label>
+                <p className="text-xs text-gray-500">
+                  输入搜索引擎前缀后显示联想词，输入的内容会发送给对应的搜索引擎
+                </p>
+              </div>
+              <label className="relative inline-flex items-center cursor-pointer">
+                <input
+                  type="checkbox"
+                  checked={settings.web_suggestions_enabled ?? false}
+                  onChange={(e) =>
+                    onSettingsChange({
+                      ...settings,
+                      web_suggestions_enabled: e.target.checked,
+                    })
+                  }
+                  className="sr-only peer"
+                />
+                <div className="w-11 h-6 bg-gray-200 peer-focus:outline-none peer-focus:ring-4 peer-focus:ring-blue-300 rounded-full peer peer-checked:after:translate-x-full peer-checked:after:border-white after:content-[''] after:absolute after:top-[2px] after:left-[2px] after:bg-white after:border-gray-300 after:border after:rounded-full after:h-5 after:w-5 after:transition-all peer-checked:bg-blue-600"></div>
+              </label>
+            </div>
+
             {/* 预设搜索引擎 */}
             <div className="mb-4">
               <p className="text-sm font-medium text-gray-700 mb-2">快速添加预设：</p>
@@ -610,6 +1076,34 @@ export function LauncherSettingsPage({
         </div>
       </div>
 
+      <SearchProvidersSection
+        providers={settings.providers ?? {}}
+        onChange={(providers) => onSettingsChange({ ...settings, providers })}
+      />
+
+      <HotFoldersSection
+        folders={settings.hot_folders ?? []}
+        onChange={(hot_folders) => onSettingsChange({ ...settings, hot_folders })}
+      />
+
+      <QuotesSection
+        watchlist={settings.quote_watchlist ?? []}
+        refreshSecs={settings.quote_refresh_secs ?? 60}
+        onChange={(quote_watchlist, quote_refresh_secs) =>
+          onSettingsChange({ ...settings, quote_watchlist, quote_refresh_secs })
+        }
+      />
+
+      <TextVariablesSection
+        variables={settings.text_variables ?? []}
+        onChange={(text_variables) => onSettingsChange({ ...settings, text_variables })}
+      />
+
+      <ScoringWeightsSection
+        weights={settings.scoring_weights ?? {}}
+        onChange={(scoring_weights) => onSettingsChange({ ...settings, scoring_weights })}
+      />
+
       <ErrorDialog
         isOpen={errorDialog.isOpen}
         type={errorDialog.type}
@@ -625,6 +1119,28 @@ interface AboutSettingsProps {}
 
 export function AboutSettingsPage({}: AboutSettingsProps) {
   const [version, setVersion] = useState<string>("");
+  const [crashCount, setCrashCount] = useState(0);
+  const [isCreatingReport, setIsCreatingReport] = useState(false);
+
+  useEffect(() => {
+    tauriApi
+      .listCrashReports()
+      .then((reports) => setCrashCount(reports.length))
+      .catch((error) => console.error("获取崩溃报告失败:", error));
+  }, []);
+
+  const handleCreateBugReport = async () => {
+    setIsCreatingReport(true);
+    try {
+      const path = await tauriApi.createBugReport();
+      await tauriApi.revealInFolder(path);
+    } catch (error) {
+      console.error("Failed to create bug report:", error);
+      alert("创建问题报告失败");
+    } finally {
+      setIsCreatingReport(false);
+    }
+  };
 
   useEffect(() => {
     const loadVersion = async () => {
@@ -700,7 +1216,17 @@ export function AboutSettingsPage({}: AboutSettingsProps) {
                   >
                     加入产品交流群
                   </button>
+                  <button
+                    onClick={handleCreateBugReport}
+                    disabled={isCreatingReport}
+                    className="px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 transition-colors text-sm disabled:opacity-50"
+                  >
+                    {isCreatingReport ? "正在打包..." : "创建问题报告"}
+                  </button>
                 </div>
+                <p className="text-xs text-gray-500">
+                  "创建问题报告"会把崩溃报告{crashCount > 0 ? `（${crashCount} 份）` : ""}和最近日志打包为 zip，可附加到 GitHub issue
+                </p>
                 <p className="text-xs text-gray-500">
                   点击"加入产品交流群"可查看作者微信，加入产品交流群获取最新动态和反馈建议
                 </p>
@@ -0,0 +1,134 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { SystemSamplePoint, SystemStats } from "../types";
+import { formatBytes } from "../utils/formatUtils";
+
+const POLL_INTERVAL_MS = 1000;
+const SPARKLINE_WIDTH = 120;
+const SPARKLINE_HEIGHT = 28;
+
+function Sparkline({ points, color }: { points: number[]; color: string }) {
+  if (points.length < 2) {
+    return <svg width={SPARKLINE_WIDTH} height={SPARKLINE_HEIGHT} />;
+  }
+  const step = SPARKLINE_WIDTH / (points.length - 1);
+  const path = points
+    .map((value, i) => {
+      const y = SPARKLINE_HEIGHT - (Math.min(100, Math.max(0, value)) / 100) * SPARKLINE_HEIGHT;
+      return `${i === 0 ? "M" : "L"}${(i * step).toFixed(1)},${y.toFixed(1)}`;
+    })
+    .join(" ");
+  return (
+    <svg width={SPARKLINE_WIDTH} height={SPARKLINE_HEIGHT} className="shrink-0">
+      <path d={path} fill="none" stroke={color} strokeWidth={1.5} />
+    </svg>
+  );
+}
+
+function UsageBar({ percent }: { percent: number }) {
+  const color = percent >= 90 ? "bg-red-500" : percent >= 70 ? "bg-yellow-500" : "bg-blue-500";
+  return (
+    <span className="flex-1 h-1.5 bg-gray-100 rounded overflow-hidden">
+      <span className={`block h-full ${color}`} style={{ width: `${Math.min(100, percent)}%` }} />
+    </span>
+  );
+}
+
+function formatDuration(seconds: number): string {
+  const hours = Math.floor(seconds / 3600);
+  const minutes = Math.floor((seconds % 3600) / 60);
+  return hours > 0 ? `${hours}小时${minutes}分钟` : `${minutes}分钟`;
+}
+
+function series(history: SystemSamplePoint[], key: "cpu" | "memory"): number[] {
+  return history.map((point) => point[key]);
+}
+
+/**
+ * 系统监控面板：输入 "cpu"、"ram" 等时显示 CPU、内存、磁盘与电池状态，CPU/内存附带最近一分钟的走势
+ */
+export function SystemMonitorPanel() {
+  const [stats, setStats] = useState<SystemStats | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    const poll = () =>
+      tauriApi
+        .getSystemStats()
+        .then((result) => !cancelled && setStats(result))
+        .catch((e) => !cancelled && setError(String(e)));
+    poll();
+    const timer = setInterval(poll, POLL_INTERVAL_MS);
+    return () => {
+      cancelled = true;
+      clearInterval(timer);
+    };
+  }, []);
+
+  if (error) {
+    return <div className="px-4 py-3 text-xs text-red-500 border-t border-gray-100">{error}</div>;
+  }
+  if (!stats) {
+    return <div className="px-4 py-3 text-xs text-gray-400 border-t border-gray-100">正在读取系统状态...</div>;
+  }
+
+  const memoryPercent = stats.memory_total > 0 ? (stats.memory_used / stats.memory_total) * 100 : 0;
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 space-y-2 text-sm">
+      <div className="flex items-center gap-3">
+        <span className="w-12 text-gray-500">CPU</span>
+        <span className="w-28 text-gray-800">
+          {stats.cpu != null ? `${stats.cpu.toFixed(0)}%` : "采样中..."}
+          <span className="ml-1 text-xs text-gray-400">{stats.cpu_count} 核</span>
+        </span>
+        <UsageBar percent={stats.cpu ?? 0} />
+        <Sparkline points={series(stats.history, "cpu")} color="#3b82f6" />
+      </div>
+      <div className="flex items-center gap-3">
+        <span className="w-12 text-gray-500">内存</span>
+        <span className="w-28 text-gray-800 text-xs">
+          {formatBytes(stats.memory_used)} / {formatBytes(stats.memory_total)}
+        </span>
+        <UsageBar percent={memoryPercent} />
+        <Sparkline points={series(stats.history, "memory")} color="#8b5cf6" />
+      </div>
+      {stats.battery && (
+        <div className="flex items-center gap-3">
+          <span className="w-12 text-gray-500">电池</span>
+          <span className="text-gray-800">
+            {stats.battery.percent != null ? `${stats.battery.percent}%` : "未知"}
+            <span className="ml-2 text-xs text-gray-400">
+              {stats.battery.charging
+                ? "正在充电"
+                : stats.battery.plugged_in
+                  ? "已接通电源"
+                  : stats.battery.seconds_remaining != null
+                    ? `剩余约 ${formatDuration(stats.battery.seconds_remaining)}`
+                    : "使用电池"}
+            </span>
+          </span>
+        </div>
+      )}
+      {stats.disks.length > 0 && (
+        <div className="pt-1 space-y-1">
+          {stats.disks.map((disk) => {
+            const used = disk.total - disk.available;
+            return (
+              <div key={disk.mount_point} className="flex items-center gap-3 text-xs">
+                <span className="w-12 text-gray-500 truncate" title={disk.name}>
+                  {disk.mount_point}
+                </span>
+                <span className="w-28 text-gray-600">
+                  可用 {formatBytes(disk.available)}
+                </span>
+                <UsageBar percent={disk.total > 0 ? (used / disk.total) * 100 : 0} />
+                <span className="w-[120px] text-right text-gray-400">共 {formatBytes(disk.total)}</span>
+              </div>
+            );
+          })}
+        </div>
+      )}
+    </div>
+  );
+}
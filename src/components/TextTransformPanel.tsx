@@ -0,0 +1,79 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import { TEXT_TRANSFORMS } from "../utils/searchUtils";
+
+interface TextTransformPanelProps {
+  transforms: typeof TEXT_TRANSFORMS;
+}
+
+type TransformSource = "clipboard" | "selection";
+
+/**
+ * 文本转换面板：列出匹配的转换，可作用于剪贴板文本（结果写回剪贴板）或之前窗口中选中的文本（转换后粘贴替换）；
+ * 回车转换剪贴板，Shift+回车转换选中文本
+ */
+export function TextTransformPanel({ transforms }: TextTransformPanelProps) {
+  const [result, setResult] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleRun = async (id: string, source: TransformSource) => {
+    setError(null);
+    setResult(null);
+    try {
+      const text = await tauriApi.transformText(id, source);
+      if (source === "clipboard") setResult(text);
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    const first = transforms[0];
+    if (!first) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      handleRun(first.id, e.shiftKey ? "selection" : "clipboard");
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [transforms]);
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {result !== null && (
+        <div className="mb-2">
+          <div className="text-xs text-green-600 mb-1">已写入剪贴板</div>
+          <pre className="max-h-24 overflow-auto text-xs text-gray-600 bg-gray-50 rounded px-2 py-1 whitespace-pre-wrap break-all">
+            {result}
+          </pre>
+        </div>
+      )}
+      {transforms.length === 0 && <div className="text-xs text-gray-400">没有匹配的文本转换</div>}
+      {transforms.map((transform, index) => (
+        <div
+          key={transform.id}
+          className={`flex items-center gap-2 px-2 py-1.5 rounded ${index === 0 ? "bg-blue-50" : "hover:bg-gray-100"}`}
+        >
+          <span className="flex-1 truncate text-gray-800">{transform.label}</span>
+          {index === 0 && <span className="text-xs text-gray-400">回车 / Shift+回车</span>}
+          <button
+            onClick={() => handleRun(transform.id, "clipboard")}
+            className="text-xs text-blue-600 hover:underline"
+          >
+            剪贴板
+          </button>
+          <button
+            onClick={() => handleRun(transform.id, "selection")}
+            className="text-xs text-blue-600 hover:underline"
+          >
+            选中文本
+          </button>
+        </div>
+      ))}
+    </div>
+  );
+}
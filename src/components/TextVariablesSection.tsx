@@ -0,0 +1,104 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { TextVariable, TextVariableConfig } from "../types";
+
+interface TextVariablesSectionProps {
+  variables: TextVariableConfig[];
+  onChange: (variables: TextVariableConfig[]) => void;
+}
+
+const NAME_PATTERN = /^[\p{L}_][\p{L}\p{N}_]*$/u;
+
+/**
+ * 文本扩展变量设置：列出内置变量，编辑自定义变量；片段插入和快捷链接打开时以 {名称} 展开
+ */
+export function TextVariablesSection({ variables, onChange }: TextVariablesSectionProps) {
+  const [builtin, setBuiltin] = useState<TextVariable[]>([]);
+  const [name, setName] = useState("");
+  const [value, setValue] = useState("");
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    tauriApi
+      .listTextVariables()
+      .then((list) => setBuiltin(list.filter((v) => !v.custom)))
+      .catch(() => {});
+  }, []);
+
+  const addVariable = () => {
+    const trimmed = name.trim();
+    if (!NAME_PATTERN.test(trimmed)) {
+      setError("变量名只能包含字母、数字和下划线，且不能以数字开头");
+      return;
+    }
+    if (builtin.some((v) => v.name === trimmed) || variables.some((v) => v.name === trimmed)) {
+      setError(`变量 ${trimmed} 已存在`);
+      return;
+    }
+    setError(null);
+    onChange([...variables, { name: trimmed, value }]);
+    setName("");
+    setValue("");
+  };
+
+  return (
+    <div className="bg-white rounded-lg shadow-sm border border-gray-200 p-6">
+      <h3 className="text-lg font-medium text-gray-700">文本变量</h3>
+      <p className="text-sm text-gray-500 mt-1 mb-4">
+        在备忘录片段和快捷链接中写 {"{变量名}"} 即可在插入或打开时展开；自定义变量的值中可以使用内置变量
+      </p>
+      <div className="grid grid-cols-[auto_1fr] gap-x-4 gap-y-1 text-xs mb-4">
+        {builtin.map((v) => (
+          <div key={v.name} className="contents">
+            <code className="text-gray-700">{`{${v.name}}`}</code>
+            <span className="text-gray-500">{v.description}</span>
+          </div>
+        ))}
+      </div>
+      <div className="space-y-2 mb-3">
+        {variables.map((v, index) => (
+          <div key={v.name} className="flex items-center gap-2 text-sm">
+            <code className="w-32 shrink-0 truncate text-gray-700">{`{${v.name}}`}</code>
+            <input
+              type="text"
+              value={v.value}
+              onChange={(e) =>
+                onChange(variables.map((item, i) => (i === index ? { ...item, value: e.target.value } : item)))
+              }
+              className="flex-1 px-2 py-1 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+            />
+            <button
+              onClick={() => onChange(variables.filter((_, i) => i !== index))}
+              className="px-2 py-1 text-xs text-red-600 hover:bg-red-50 rounded"
+            >
+              删除
+            </button>
+          </div>
+        ))}
+      </div>
+      <div className="flex gap-2">
+        <input
+          type="text"
+          value={name}
+          onChange={(e) => setName(e.target.value)}
+          placeholder="变量名，如 签名"
+          className="w-32 px-3 py-1.5 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+        />
+        <input
+          type="text"
+          value={value}
+          onChange={(e) => setValue(e.target.value)}
+          onKeyDown={(e) => {
+            if (e.key === "Enter") addVariable();
+          }}
+          placeholder="值，如 张三 {date:%Y-%m-%d}"
+          className="flex-1 px-3 py-1.5 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+        />
+        <button onClick={addVariable} className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200">
+          添加
+        </button>
+      </div>
+      {error && <div className="text-xs text-red-500 mt-2">{error}</div>}
+    </div>
+  );
+}
@@ -0,0 +1,179 @@
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { ReminderPreview, Timer, TimerKind } from "../types";
+
+interface TimersPanelProps {
+  kind: TimerKind;
+  spec: string | null;
+  label: string;
+}
+
+const SOUND_STORAGE_KEY = "timer-sound";
+
+function formatRemaining(secs: number): string {
+  const s = Math.max(0, secs);
+  const hours = Math.floor(s / 3600);
+  const minutes = Math.floor((s % 3600) / 60);
+  const seconds = s % 60;
+  const mmss = `${String(minutes).padStart(2, "0")}:${String(seconds).padStart(2, "0")}`;
+  return hours > 0 ? `${hours}:${mmss}` : mmss;
+}
+
+function formatClock(timestamp: number): string {
+  const date = new Date(timestamp * 1000);
+  const time = date.toLocaleTimeString("zh-CN", { hour: "2-digit", minute: "2-digit" });
+  const tomorrow = new Date();
+  tomorrow.setDate(tomorrow.getDate() + 1);
+  if (date.toDateString() === new Date().toDateString()) return time;
+  if (date.toDateString() === tomorrow.toDateString()) return `明天 ${time}`;
+  return `${date.getMonth() + 1}月${date.getDate()}日 ${time}`;
+}
+
+const KIND_ICONS: Record<TimerKind, string> = { timer: "⏳", alarm: "⏰", reminder: "📌" };
+
+/**
+ * 计时面板：列出进行中的倒计时、闹钟和提醒（实时倒数，可取消）；
+ * 输入 "timer 10m 泡茶"、"alarm 7:30 起床" 或 "明天上午9点提醒我交报告" 时回车创建，提醒音开关记住上次的选择
+ */
+export function TimersPanel({ kind, spec, label }: TimersPanelProps) {
+  const [timers, setTimers] = useState<Timer[] | null>(null);
+  const [preview, setPreview] = useState<ReminderPreview | null>(null);
+  const [previewError, setPreviewError] = useState<string | null>(null);
+  const [now, setNow] = useState(() => Math.floor(Date.now() / 1000));
+  const [sound, setSound] = useState(() => localStorage.getItem(SOUND_STORAGE_KEY) !== "false");
+  const [busy, setBusy] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const refresh = () => {
+    tauriApi
+      .listTimers()
+      .then(setTimers)
+      .catch((e) => setError(String(e)));
+  };
+
+  useEffect(() => {
+    refresh();
+    const unlisten = listen("timers-changed", refresh);
+    const tick = window.setInterval(() => setNow(Math.floor(Date.now() / 1000)), 1000);
+    return () => {
+      unlisten.then((fn) => fn());
+      window.clearInterval(tick);
+    };
+  }, []);
+
+  // 提醒输入交给后端解析，停止输入后再预览
+  useEffect(() => {
+    setPreview(null);
+    setPreviewError(null);
+    if (kind !== "reminder" || !spec) return;
+    const timer = window.setTimeout(() => {
+      tauriApi
+        .previewReminder(spec)
+        .then(setPreview)
+        .catch((e) => setPreviewError(String(e)));
+    }, 200);
+    return () => window.clearTimeout(timer);
+  }, [kind, spec]);
+
+  const toggleSound = () => {
+    localStorage.setItem(SOUND_STORAGE_KEY, String(!sound));
+    setSound(!sound);
+  };
+
+  const handleCreate = async () => {
+    if (!spec) return;
+    setBusy(true);
+    setError(null);
+    try {
+      if (kind === "reminder") {
+        await tauriApi.createReminder(spec, sound);
+      } else {
+        await tauriApi.createTimer(kind, spec, label || undefined, sound);
+      }
+      refresh();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleCancel = async (id: number) => {
+    try {
+      await tauriApi.cancelTimer(id);
+      refresh();
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    if (!spec || busy || (kind === "reminder" && !preview)) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      handleCreate();
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [kind, spec, label, sound, busy, preview]);
+
+  const kindName = kind === "alarm" ? "闹钟" : "倒计时";
+  const hint = (() => {
+    if (kind === "reminder") {
+      if (!spec) return '输入 "明天上午9点提醒我交报告"、"remind me to stretch in 30 minutes" 创建提醒';
+      if (previewError) return previewError;
+      if (!preview) return "正在解析...";
+      return `回车创建提醒：${preview.text} · ${formatClock(preview.fire_at)}${preview.recurrence ? ` · ${preview.recurrence}` : ""}`;
+    }
+    if (spec) return `回车创建${kindName} ${spec}${label ? ` · ${label}` : ""}`;
+    return kind === "alarm" ? '输入 "alarm 7:30 起床" 创建闹钟' : '输入 "timer 10m 泡茶" 创建倒计时';
+  })();
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      <div className="flex items-center gap-2 px-2 py-1.5 mb-1 rounded bg-blue-50 text-gray-700">
+        <span className={`flex-1 truncate ${previewError ? "text-gray-400" : ""}`}>{hint}</span>
+        <button onClick={toggleSound} className="text-xs text-gray-500 hover:underline">
+          {sound ? "🔔 提示音" : "🔕 静音"}
+        </button>
+      </div>
+      {timers && timers.length === 0 && <div className="px-2 text-xs text-gray-400">没有进行中的计时</div>}
+      {timers?.map((timer) => {
+        const remaining = timer.fire_at - now;
+        const progress = timer.duration_secs > 0 ? 1 - remaining / timer.duration_secs : 1;
+        return (
+          <div key={timer.id} className="flex items-center gap-2 px-2 py-1.5 rounded hover:bg-gray-50">
+            <span className="w-4 text-center">{KIND_ICONS[timer.kind]}</span>
+            <span className="flex-1 truncate text-gray-800">
+              {timer.label}
+              {timer.recurrence && <span className="ml-1 text-xs text-blue-500">{timer.recurrence}</span>}
+              {!timer.sound && <span className="ml-1 text-xs text-gray-400">静音</span>}
+            </span>
+            {timer.kind === "timer" && (
+              <span className="w-16 h-1 bg-gray-100 rounded overflow-hidden">
+                <span
+                  className="block h-full bg-blue-400"
+                  style={{ width: `${Math.min(100, Math.max(0, progress * 100))}%` }}
+                />
+              </span>
+            )}
+            <span className="text-xs text-gray-500 font-mono" title={formatClock(timer.fire_at)}>
+              {timer.kind === "timer" ? formatRemaining(remaining) : formatClock(timer.fire_at)}
+            </span>
+            <button
+              onClick={() => handleCancel(timer.id)}
+              className="px-2 py-0.5 text-xs text-red-600 hover:bg-red-50 rounded"
+            >
+              取消
+            </button>
+          </div>
+        );
+      })}
+    </div>
+  );
+}
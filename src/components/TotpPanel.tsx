@@ -0,0 +1,175 @@
+import { useEffect, useState, type FormEvent } from "react";
+import { tauriApi } from "../api/tauri";
+import type { TotpCode } from "../types";
+
+interface TotpPanelProps {
+  account: string;
+}
+
+/**
+ * 两步验证码面板：按账户名筛选并显示当前验证码与剩余秒数，回车复制第一个；
+ * 复制以敏感内容写入剪贴板，验证码过期时自动清除。也可在此添加或删除账户
+ */
+export function TotpPanel({ account }: TotpPanelProps) {
+  const [codes, setCodes] = useState<TotpCode[] | null>(null);
+  const [fetchedAt, setFetchedAt] = useState(Date.now());
+  const [now, setNow] = useState(Date.now());
+  const [error, setError] = useState<string | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+  const [showForm, setShowForm] = useState(false);
+  const [form, setForm] = useState({ name: "", issuer: "", secret: "" });
+
+  const refresh = () => {
+    tauriApi
+      .getTotpCodes(account)
+      .then((result) => {
+        setCodes(result);
+        setFetchedAt(Date.now());
+        setError(null);
+      })
+      .catch((e) => setError(String(e)));
+  };
+
+  useEffect(refresh, [account]);
+
+  useEffect(() => {
+    const timer = window.setInterval(() => setNow(Date.now()), 1000);
+    return () => window.clearInterval(timer);
+  }, []);
+
+  const elapsed = Math.floor((now - fetchedAt) / 1000);
+  const remainingOf = (code: TotpCode) => code.remaining - elapsed;
+
+  // 任一验证码到期后重新获取
+  useEffect(() => {
+    if (codes?.some((code) => remainingOf(code) <= 0)) refresh();
+  }, [now]);
+
+  const handleCopy = async (id: string) => {
+    try {
+      const code = await tauriApi.copyTotpCode(id);
+      setMessage(`已复制 ${code.issuer || code.name} 的验证码，${code.remaining} 秒后自动清除`);
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const handleRemove = async (code: TotpCode) => {
+    if (!window.confirm(`删除账户 ${code.issuer ? `${code.issuer}: ` : ""}${code.name}？`)) return;
+    try {
+      await tauriApi.removeTotpAccount(code.id);
+      refresh();
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const handleAdd = async (e: FormEvent) => {
+    e.preventDefault();
+    try {
+      await tauriApi.addTotpAccount(form);
+      setForm({ name: "", issuer: "", secret: "" });
+      setShowForm(false);
+      refresh();
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果；添加表单打开时交给表单处理
+  useEffect(() => {
+    const first = codes?.[0];
+    if (!first || showForm) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      handleCopy(first.id);
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [codes, showForm]);
+
+  const formatCode = (code: string) => code.replace(/^(\d{3,4})(\d{3,4})$/, "$1 $2");
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {message && <div className="text-xs text-blue-600 mb-1">{message}</div>}
+      {codes && codes.length === 0 && !showForm && (
+        <div className="text-xs text-gray-400">{account ? `没有匹配 "${account}" 的账户` : "还没有添加两步验证账户"}</div>
+      )}
+      {codes?.map((code, index) => {
+        const remaining = Math.max(remainingOf(code), 0);
+        return (
+          <div
+            key={code.id}
+            className={`group flex items-center gap-2 px-2 py-1.5 rounded ${index === 0 ? "bg-blue-50" : ""}`}
+          >
+            <div className="flex-1 min-w-0">
+              <div className="text-gray-800 truncate">{code.issuer || code.name}</div>
+              {code.issuer && <div className="text-xs text-gray-400 truncate">{code.name}</div>}
+            </div>
+            <button
+              onClick={() => handleCopy(code.id)}
+              className="font-mono text-lg text-gray-900 hover:text-blue-600"
+              title="复制验证码"
+            >
+              {formatCode(code.code)}
+            </button>
+            <span className={`w-8 text-right text-xs ${remaining <= 5 ? "text-red-500" : "text-gray-400"}`}>
+              {remaining}s
+            </span>
+            <button
+              onClick={() => handleRemove(code)}
+              className="text-xs text-gray-300 hover:text-red-500 opacity-0 group-hover:opacity-100"
+              title="删除账户"
+            >
+              ×
+            </button>
+          </div>
+        );
+      })}
+      {showForm ? (
+        <form onSubmit={handleAdd} className="mt-2 flex flex-col gap-1.5">
+          <input
+            value={form.secret}
+            onChange={(e) => setForm({ ...form, secret: e.target.value })}
+            placeholder="Base32 密钥或 otpauth:// 链接"
+            className="border border-gray-200 rounded px-2 py-1 text-xs font-mono"
+            autoFocus
+          />
+          <div className="flex gap-1.5">
+            <input
+              value={form.name}
+              onChange={(e) => setForm({ ...form, name: e.target.value })}
+              placeholder="账户名（链接中已包含时可不填）"
+              className="flex-1 border border-gray-200 rounded px-2 py-1 text-xs"
+            />
+            <input
+              value={form.issuer}
+              onChange={(e) => setForm({ ...form, issuer: e.target.value })}
+              placeholder="服务商（可选）"
+              className="w-32 border border-gray-200 rounded px-2 py-1 text-xs"
+            />
+          </div>
+          <div className="flex gap-2 justify-end">
+            <button type="button" onClick={() => setShowForm(false)} className="text-xs text-gray-500 hover:underline">
+              取消
+            </button>
+            <button type="submit" disabled={!form.secret.trim()} className="text-xs text-blue-600 hover:underline">
+              添加
+            </button>
+          </div>
+        </form>
+      ) : (
+        <div className="mt-1 flex items-center justify-between text-xs text-gray-400">
+          <span>{codes && codes.length > 0 ? "回车复制第一个验证码（不记入剪切板历史）" : ""}</span>
+          <button onClick={() => setShowForm(true)} className="text-blue-600 hover:underline">
+            添加账户
+          </button>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,109 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { VirtualDesktop } from "../types";
+
+interface VirtualDesktopsPanelProps {
+  keyword: string;
+  index: number | null;
+  move: boolean;
+}
+
+/**
+ * 虚拟桌面面板：列出桌面并标记当前桌面，点击切换，或把启动器打开前的窗口移到某个桌面；
+ * 输入 "vd 2"、"vd 工作" 时回车切换到该桌面，"vd move 2" 时回车移动窗口并切换过去
+ */
+export function VirtualDesktopsPanel({ keyword, index, move }: VirtualDesktopsPanelProps) {
+  const [desktops, setDesktops] = useState<VirtualDesktop[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [busy, setBusy] = useState(false);
+
+  useEffect(() => {
+    tauriApi
+      .listVirtualDesktops()
+      .then(setDesktops)
+      .catch((e) => setError(String(e)));
+  }, []);
+
+  const target =
+    desktops === null
+      ? null
+      : index !== null
+        ? desktops[index] ?? null
+        : keyword
+          ? desktops.find((d) => d.name.toLowerCase().includes(keyword)) ?? null
+          : null;
+
+  // 切换或移动后启动器已隐藏，无需刷新列表
+  const run = async (action: () => Promise<void>) => {
+    setBusy(true);
+    setError(null);
+    try {
+      await action();
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleSwitch = (desktop: VirtualDesktop) => run(() => tauriApi.switchVirtualDesktop(desktop.index));
+  const handleMove = (desktop: VirtualDesktop) => run(() => tauriApi.moveWindowToVirtualDesktop(desktop.index));
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    if (!target || target.current || busy) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      if (move) {
+        handleMove(target);
+      } else {
+        handleSwitch(target);
+      }
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [target, move, busy]);
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {target && !target.current && (
+        <div className="text-xs text-blue-600 mb-2">
+          {move ? `回车把当前窗口移到「${target.name}」` : `回车切换到「${target.name}」`}
+        </div>
+      )}
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {!desktops && !error && <div className="text-xs text-gray-400">正在读取虚拟桌面...</div>}
+      {desktops?.map((desktop) => (
+        <div
+          key={desktop.id || desktop.index}
+          className={`flex items-center gap-2 px-2 py-1.5 rounded ${desktop.id === target?.id ? "bg-blue-50" : "hover:bg-gray-100"}`}
+        >
+          <span className="w-5 text-xs text-gray-400">{desktop.index + 1}</span>
+          <span className="flex-1 truncate text-gray-800">{desktop.name}</span>
+          {desktop.current ? (
+            <span className="text-xs text-green-600">当前桌面</span>
+          ) : (
+            <>
+              <button
+                onClick={() => handleMove(desktop)}
+                disabled={busy}
+                className="text-xs text-gray-500 hover:underline"
+              >
+                移动当前窗口到此桌面
+              </button>
+              <button
+                onClick={() => handleSwitch(desktop)}
+                disabled={busy}
+                className="text-xs text-blue-600 hover:underline"
+              >
+                切换
+              </button>
+            </>
+          )}
+        </div>
+      ))}
+    </div>
+  );
+}
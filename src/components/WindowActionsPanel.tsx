@@ -0,0 +1,66 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import { WINDOW_ACTIONS } from "../utils/searchUtils";
+
+interface WindowActionsPanelProps {
+  actions: typeof WINDOW_ACTIONS;
+}
+
+const formatHotkey = (config: { modifiers: string[]; key: string }) => [...config.modifiers, config.key].join(" + ");
+
+/**
+ * 窗口管理面板：输入 "win left" 等列出匹配的动作，回车执行第一个；
+ * 执行时先隐藏启动器，动作作用于之前的前台窗口
+ */
+export function WindowActionsPanel({ actions }: WindowActionsPanelProps) {
+  const [hotkeys, setHotkeys] = useState<Record<string, { modifiers: string[]; key: string }>>({});
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    tauriApi
+      .getWindowHotkeys()
+      .then(setHotkeys)
+      .catch(() => setHotkeys({}));
+  }, []);
+
+  const handleRun = (id: string) => {
+    setError(null);
+    tauriApi.runWindowAction(id).catch((e) => setError(String(e)));
+  };
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    const first = actions[0];
+    if (!first) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      handleRun(first.id);
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [actions]);
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {actions.length === 0 && <div className="text-xs text-gray-400">没有匹配的窗口动作</div>}
+      {actions.map((action, index) => (
+        <button
+          key={action.id}
+          onClick={() => handleRun(action.id)}
+          className={`w-full flex items-center gap-2 px-2 py-1.5 text-left rounded ${
+            index === 0 ? "bg-blue-50" : "hover:bg-gray-100"
+          }`}
+        >
+          <span className="flex-1 truncate text-gray-800">{action.label}</span>
+          {hotkeys[action.id] && (
+            <span className="text-xs font-mono text-gray-400">{formatHotkey(hotkeys[action.id])}</span>
+          )}
+          {index === 0 && <span className="text-xs text-gray-400">回车执行</span>}
+        </button>
+      ))}
+    </div>
+  );
+}
@@ -0,0 +1,171 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import { WINDOW_ACTIONS } from "../utils/searchUtils";
+
+interface HotkeyConfig {
+  modifiers: string[];
+  key: string;
+}
+
+interface WindowHotkeysSectionProps {
+  formatHotkey: (config: HotkeyConfig) => string;
+  checkConflict: (config: HotkeyConfig) => string | null;
+  onMessage: (message: string) => void;
+}
+
+const MODIFIER_KEYS: Record<string, string> = {
+  Control: "Ctrl",
+  Alt: "Alt",
+  Shift: "Shift",
+  Meta: "Meta",
+};
+
+/**
+ * 窗口管理快捷键：为每个窗口动作录制一个"修饰键 + 按键"组合，按下后作用于当前前台窗口
+ */
+export function WindowHotkeysSection({ formatHotkey, checkConflict, onMessage }: WindowHotkeysSectionProps) {
+  const [hotkeys, setHotkeys] = useState<Record<string, HotkeyConfig>>({});
+  const [recordingId, setRecordingId] = useState<string | null>(null);
+  const [recordingKeys, setRecordingKeys] = useState<string[]>([]);
+
+  useEffect(() => {
+    tauriApi
+      .getWindowHotkeys()
+      .then(setHotkeys)
+      .catch((error) => console.error("Failed to load window hotkeys:", error));
+  }, []);
+
+  const save = async (action: string, config: HotkeyConfig | null) => {
+    if (config) {
+      const conflict =
+        checkConflict(config) ??
+        (Object.entries(hotkeys).some(
+          ([other, hotkey]) => other !== action && formatHotkey(hotkey) === formatHotkey(config)
+        )
+          ? "与其他窗口动作的快捷键冲突"
+          : null);
+      if (conflict) {
+        onMessage(`⚠️ ${conflict}，请修改后再保存`);
+        return;
+      }
+    }
+    try {
+      await tauriApi.saveWindowHotkey(action, config);
+      setHotkeys((prev) => {
+        const next = { ...prev };
+        if (config) {
+          next[action] = config;
+        } else {
+          delete next[action];
+        }
+        return next;
+      });
+      onMessage("窗口管理快捷键已保存并生效");
+    } catch (error) {
+      onMessage(String(error) || "保存失败");
+    }
+  };
+
+  useEffect(() => {
+    if (!recordingId) return;
+
+    const handleKeyDown = (e: KeyboardEvent) => {
+      e.preventDefault();
+      e.stopPropagation();
+      if (e.repeat) return;
+      if (e.key === "Escape") {
+        setRecordingId(null);
+        return;
+      }
+      if (MODIFIER_KEYS[e.key]) {
+        setRecordingKeys([MODIFIER_KEYS[e.key]]);
+        return;
+      }
+
+      const modifiers: string[] = [];
+      if (e.ctrlKey) modifiers.push("Ctrl");
+      if (e.altKey) modifiers.push("Alt");
+      if (e.shiftKey) modifiers.push("Shift");
+      if (e.metaKey) modifiers.push("Meta");
+
+      let key = e.key;
+      if (key === " ") key = "Space";
+      if (key.length === 1) key = key.toUpperCase();
+
+      // 窗口动作需要至少一个修饰键，避免占用普通按键
+      if (modifiers.length === 0) {
+        setRecordingKeys([key]);
+        return;
+      }
+
+      setRecordingKeys([...modifiers, key]);
+      save(recordingId, { modifiers, key });
+      setRecordingId(null);
+    };
+
+    window.addEventListener("keydown", handleKeyDown, true);
+    return () => window.removeEventListener("keydown", handleKeyDown, true);
+  }, [recordingId, hotkeys]);
+
+  return (
+    <div className="bg-gray-50 rounded-lg p-6 border border-gray-200">
+      <h4 className="text-sm font-medium text-gray-700 mb-4">窗口管理快捷键</h4>
+      <p className="text-xs text-gray-500 mb-4">
+        为窗口贴靠、移动到下一个显示器等动作配置快捷键，作用于当前前台窗口；也可以在启动器中输入 "win left" 等执行
+      </p>
+
+      <div className="space-y-2">
+        {WINDOW_ACTIONS.map((action) => {
+          const hotkey = hotkeys[action.id];
+          const isRecordingThis = recordingId === action.id;
+          return (
+            <div key={action.id} className="bg-white rounded-md px-4 py-2 border border-gray-200">
+              <div className="flex items-center justify-between">
+                <div className="flex-1 text-sm text-gray-800">{action.label}</div>
+                <div className="text-sm font-mono text-gray-600 mr-4">
+                  {isRecordingThis
+                    ? recordingKeys.length > 0
+                      ? recordingKeys.join(" + ")
+                      : "请按下快捷键..."
+                    : hotkey
+                      ? formatHotkey(hotkey)
+                      : <span className="text-gray-400 font-sans">未设置</span>}
+                </div>
+                <div className="flex gap-2">
+                  {!isRecordingThis ? (
+                    <>
+                      <button
+                        onClick={() => {
+                          setRecordingId(action.id);
+                          setRecordingKeys([]);
+                        }}
+                        className="px-3 py-1 text-sm bg-blue-600 text-white rounded-md hover:bg-blue-700 transition-colors"
+                      >
+                        {hotkey ? "修改" : "设置"}
+                      </button>
+                      {hotkey && (
+                        <button
+                          onClick={() => save(action.id, null)}
+                          className="px-3 py-1 text-sm bg-gray-200 text-gray-700 rounded-md hover:bg-gray-300 transition-colors"
+                        >
+                          清除
+                        </button>
+                      )}
+                    </>
+                  ) : (
+                    <button
+                      onClick={() => setRecordingId(null)}
+                      className="px-3 py-1 text-sm bg-gray-500 text-white rounded-md hover:bg-gray-600 transition-colors"
+                    >
+                      取消
+                    </button>
+                  )}
+                </div>
+              </div>
+            </div>
+          );
+        })}
+      </div>
+    </div>
+  );
+}
@@ -4,9 +4,12 @@ import ReactMarkdown from "react-markdown";
 import remarkGfm from "remark-gfm";
 import rehypeRaw from "rehype-raw";
 import { confirm } from "@tauri-apps/plugin-dialog";
+import { listen } from "@tauri-apps/api/event";
 import { tauriApi } from "../api/tauri";
 import type { WordRecord } from "../types";
 import { formatDateTime } from "../utils/dateUtils";
+import { assertOnline, isLocalUrl, isOnline, OFFLINE_MESSAGE, queueWhenOnline } from "../utils/networkStatus";
+import { describeApiError } from "../utils/apiError";
 
 interface WordbookPanelProps {
   llmSettings: { model: string; base_url: string; api_key?: string };
@@ -72,6 +75,7 @@ export function WordbookPanel({
   const filterRef = useRef({ wordSearchQuery: "", masteryFilter: null as number | null });
   // 使用 ref 存储最新的单词列表，避免在回调中依赖这些值
   const allWordRecordsRef = useRef<WordRecord[]>([]);
+  const importInputRef = useRef<HTMLInputElement>(null);
 
 
   // 应用筛选条件
@@ -158,6 +162,38 @@ export function WordbookPanel({
     }
   }, [loadWordRecords, wordSearchQuery]);
 
+  // 其他窗口（如翻译窗口）增删改单词时同步列表，已存在的记录按 id 去重
+  useEffect(() => {
+    const updateRecords = (update: (records: WordRecord[]) => WordRecord[]) => {
+      setAllWordRecords((records) => {
+        const updated = update(records);
+        allWordRecordsRef.current = updated; // 更新 ref
+        return updated;
+      });
+    };
+
+    const unlisteners = [
+      listen<WordRecord>("word-record://created", (event) => {
+        const record = event.payload;
+        updateRecords((records) =>
+          records.some((r) => r.id === record.id) ? records : [record, ...records]
+        );
+      }),
+      listen<WordRecord>("word-record://updated", (event) => {
+        const record = event.payload;
+        updateRecords((records) => records.map((r) => (r.id === record.id ? record : r)));
+      }),
+      listen<WordRecord>("word-record://deleted", (event) => {
+        const id = event.payload.id;
+        updateRecords((records) => records.filter((r) => r.id !== id));
+      }),
+    ];
+
+    return () => {
+      unlisteners.forEach((unlisten) => unlisten.then((fn) => fn()));
+    };
+  }, []);
+
   const handleEditWord = useCallback((record: WordRecord) => {
     setEditingRecord(record);
     setEditWord(record.word);
@@ -231,6 +267,49 @@ export function WordbookPanel({
     }
   }, [loadWordRecords]);
 
+  // 导出单词本为 JSON
+  const handleExportWords = useCallback(() => {
+    if (allWordRecords.length === 0) {
+      alert("没有数据可导出");
+      return;
+    }
+    const exportData = {
+      version: "1.0",
+      export_date: new Date().toISOString(),
+      records: allWordRecords,
+    };
+    const blob = new Blob([JSON.stringify(exportData, null, 2)], { type: "application/json" });
+    const url = URL.createObjectURL(blob);
+    const link = document.createElement("a");
+    link.href = url;
+    link.download = `单词本_${new Date().toISOString().split("T")[0]}.json`;
+    document.body.appendChild(link);
+    link.click();
+    document.body.removeChild(link);
+    URL.revokeObjectURL(url);
+  }, [allWordRecords]);
+
+  // 从 JSON 导入单词本（批量写入，已存在的单词保留较新的一方）
+  const handleImportWords = useCallback(async (event: React.ChangeEvent<HTMLInputElement>) => {
+    const file = event.target.files?.[0];
+    event.target.value = "";
+    if (!file) return;
+    try {
+      const data = JSON.parse(await file.text());
+      const records: WordRecord[] = Array.isArray(data) ? data : data.records;
+      if (!Array.isArray(records)) {
+        alert("导入失败：文件格式不正确");
+        return;
+      }
+      const result = await tauriApi.importWordRecords(records, "keep_newer");
+      await loadWordRecords();
+      alert(`导入完成：新增 ${result.inserted} 条，更新 ${result.updated} 条，跳过 ${result.skipped} 条`);
+    } catch (error) {
+      console.error("Failed to import word records:", error);
+      alert("导入失败：" + (error instanceof Error ? error.message : String(error)));
+    }
+  }, [loadWordRecords]);
+
   // 快速更新熟练度
   const handleQuickUpdateMastery = useCallback(async (id: string, newLevel: number) => {
     if (newLevel < 0 || newLevel > 5) return;
@@ -324,6 +403,15 @@ export function WordbookPanel({
       return;
     }
     
+    // 离线时加入待生成队列，联网后自动生成（本地模型不受影响）
+    if (!isLocalUrl(llmSettings.base_url || 'https://api.openai.com/v1') && !(await isOnline())) {
+      console.log(`[AI解释] 当前离线，加入待生成队列，单词: ${record.word}`);
+      queueWhenOnline("wordbook-ai-explanation", () => handleAiExplanation(record, true));
+      setIsAiExplanationLoading(false);
+      setAiExplanationText(`${OFFLINE_MESSAGE}。\n\n已加入待生成队列，联网后将自动生成并保存解释。`);
+      return;
+    }
+
     console.log(`[AI解释] 开始请求AI生成解释，单词: ${record.word}`);
     
     setAiExplanationText("");
@@ -334,94 +422,33 @@ export function WordbookPanel({
     const wordText = record.word;
     
     let accumulatedAnswer = '';
-    let buffer = '';
 
     try {
       const baseUrl = (llmSettings.base_url || 'https://api.openai.com/v1').replace(/\/+$/, '');
       const model = llmSettings.model || 'gpt-3.5-turbo';
       
-      const prompt = `请详细解释英语单词 "${record.word}"（中文翻译：${record.translation}）。请提供：
-1. 单词的详细含义和用法
-2. 词性（如果是动词，说明及物/不及物）
-3. 常见搭配和短语
-4. 2-3个实用的例句（中英文对照）
-5. 记忆技巧或词根词缀分析（如果有）
-请用中文回答，内容要详细且实用。`;
-
-      const headers: Record<string, string> = { 'Content-Type': 'application/json' };
-      if (llmSettings.api_key) {
-        headers['Authorization'] = `Bearer ${llmSettings.api_key}`;
-      }
-
-      const response = await fetch(`${baseUrl}/chat/completions`, {
-        method: 'POST',
-        headers,
-        body: JSON.stringify({
+      // 请求经由后端发出，以便应用代理设置
+      accumulatedAnswer = await tauriApi.llmChat(
+        {
+          base_url: baseUrl,
+          api_key: llmSettings.api_key,
           model,
-          messages: [{ role: 'user', content: prompt }],
+          messages: [],
+          // 提示词由后端按当前使用的模板生成
+          prompt: {
+            feature: 'word_explain',
+            variables: { word: record.word, translation: record.translation },
+          },
           stream: true,
-        }),
-      });
-
-      if (!response.ok) {
-        const errorBody = await response.text().catch(() => '');
-        throw new Error(`API 请求失败 (${response.status}): ${errorBody || response.statusText}`);
-      }
-
-      const reader = response.body?.getReader();
-      const decoder = new TextDecoder();
-      if (!reader) throw new Error('无法读取响应流');
-
-      while (true) {
-        const { done, value } = await reader.read();
-        if (done) break;
-
-        const chunk = decoder.decode(value, { stream: true });
-        buffer += chunk;
-        const lines = buffer.split('\n');
-        buffer = lines.pop() || '';
-
-        let hasUpdate = false;
-        for (const line of lines) {
-          const trimmedLine = line.trim();
-          if (!trimmedLine) continue;
-          if (trimmedLine === 'data: [DONE]') {
-            flushSync(() => {
-              setIsAiExplanationLoading(false);
-              setAiExplanationText(accumulatedAnswer);
-            });
-            // 保存AI解释到数据库
-            if (accumulatedAnswer && wordId) {
-              console.log(`[AI解释] 开始保存解释到数据库，单词ID: ${wordId}, 单词: ${wordText}, 解释长度: ${accumulatedAnswer.length}`);
-              try {
-                const updated = await tauriApi.updateWordRecord(wordId, null, null, null, null, null, null, accumulatedAnswer, null, null, null);
-                console.log(`[AI解释] 保存成功，单词: ${wordText}, 已保存的解释长度: ${updated.aiExplanation?.length || 0}`);
-                setAllWordRecords((records) => records.map((r) => r.id === wordId ? { ...r, aiExplanation: accumulatedAnswer } : r));
-                setWordRecords((records) => records.map((r) => r.id === wordId ? { ...r, aiExplanation: accumulatedAnswer } : r));
-              } catch (error) {
-                console.error(`[AI解释] 保存失败，单词: ${wordText}`, error);
-              }
-            }
-            return;
-          }
-          const dataPrefix = 'data: ';
-          const jsonStr = trimmedLine.startsWith(dataPrefix) ? trimmedLine.slice(dataPrefix.length) : trimmedLine;
-          if (!jsonStr.trim()) continue;
-          try {
-            const data = JSON.parse(jsonStr);
-            const content = data.choices?.[0]?.delta?.content;
-            if (content) {
-              accumulatedAnswer += content;
-              hasUpdate = true;
-            }
-          } catch (e) {
-            console.warn('解析流式数据失败:', e, jsonStr);
-          }
-        }
-        if (hasUpdate) {
+          // 相同单词的解释复用缓存，重新生成时刷新缓存
+          cache: forceRegenerate ? 'refresh' : 'use',
+          feature: 'wordbook_explain',
+        },
+        (content) => {
+          accumulatedAnswer += content;
           flushSync(() => { setAiExplanationText(accumulatedAnswer); });
         }
-      }
+      );
       
       // 流结束，确保最终状态更新
       flushSync(() => {
@@ -445,7 +472,7 @@ export function WordbookPanel({
       console.error('AI解释失败:', error);
       flushSync(() => {
         setIsAiExplanationLoading(false);
-        setAiExplanationText(`获取AI解释失败: ${error.message || '未知错误'}\n\n请确保：\n1. API 服务可用\n2. 模型名称正确\n3. 设置中的 AI 配置正确`);
+        setAiExplanationText(`获取AI解释失败: ${describeApiError(error)}\n\n请确保：\n1. API 服务可用\n2. 模型名称正确\n3. 设置中的 AI 配置正确`);
       });
     }
   }, [llmSettings]);
@@ -581,85 +608,32 @@ export function WordbookPanel({
     setHasAutoSaved(false); // 重置自动保存标记
 
     let accumulatedAnswer = '';
-    let buffer = '';
 
     try {
       const baseUrl = (llmSettings.base_url || 'https://api.openai.com/v1').replace(/\/+$/, '');
       const model = llmSettings.model || 'gpt-3.5-turbo';
-      
-      const prompt = `请详细解释英语单词 "${word.trim()}"。请提供：
-1. 单词的详细含义和用法
-2. 词性（如果是动词，说明及物/不及物）
-3. 常见搭配和短语
-4. 2-3个实用的例句（中英文对照）
-5. 记忆技巧或词根词缀分析（如果有）
-请用中文回答，内容要详细且实用。`;
-
-      const headers: Record<string, string> = { 'Content-Type': 'application/json' };
-      if (llmSettings.api_key) {
-        headers['Authorization'] = `Bearer ${llmSettings.api_key}`;
+      // 离线时直接失败，不等待请求超时（本地模型不受影响）
+      if (!isLocalUrl(baseUrl)) {
+        await assertOnline();
       }
-
-      const response = await fetch(`${baseUrl}/chat/completions`, {
-        method: 'POST',
-        headers,
-        body: JSON.stringify({
+      
+      // 请求经由后端发出，以便应用代理设置
+      accumulatedAnswer = await tauriApi.llmChat(
+        {
+          base_url: baseUrl,
+          api_key: llmSettings.api_key,
           model,
-          messages: [{ role: 'user', content: prompt }],
+          messages: [],
+          prompt: { feature: 'word_lookup', variables: { word: word.trim() } },
           stream: true,
-        }),
-      });
-
-      if (!response.ok) {
-        const errorBody = await response.text().catch(() => '');
-        throw new Error(`API 请求失败 (${response.status}): ${errorBody || response.statusText}`);
-      }
-
-      const reader = response.body?.getReader();
-      const decoder = new TextDecoder();
-      if (!reader) throw new Error('无法读取响应流');
-
-      while (true) {
-        const { done, value } = await reader.read();
-        if (done) break;
-
-        const chunk = decoder.decode(value, { stream: true });
-        buffer += chunk;
-        const lines = buffer.split('\n');
-        buffer = lines.pop() || '';
-
-        let hasUpdate = false;
-        for (const line of lines) {
-          const trimmedLine = line.trim();
-          if (!trimmedLine) continue;
-          if (trimmedLine === 'data: [DONE]') {
-            flushSync(() => {
-              setIsAiExplanationLoading(false);
-              setAiExplanationText(accumulatedAnswer);
-            });
-            if (accumulatedAnswer && !hasAutoSaved) {
-              autoSaveWord(word.trim(), accumulatedAnswer);
-            }
-            return;
-          }
-          const dataPrefix = 'data: ';
-          const jsonStr = trimmedLine.startsWith(dataPrefix) ? trimmedLine.slice(dataPrefix.length) : trimmedLine;
-          if (!jsonStr.trim()) continue;
-          try {
-            const data = JSON.parse(jsonStr);
-            const content = data.choices?.[0]?.delta?.content;
-            if (content) {
-              accumulatedAnswer += content;
-              hasUpdate = true;
-            }
-          } catch (e) {
-            console.warn('解析流式数据失败:', e, jsonStr);
-          }
-        }
-        if (hasUpdate) {
+          cache: 'use',
+          feature: 'wordbook_lookup',
+        },
+        (content) => {
+          accumulatedAnswer += content;
           flushSync(() => { setAiExplanationText(accumulatedAnswer); });
         }
-      }
+      );
       
       // 流结束，确保最终状态更新
       flushSync(() => {
@@ -673,7 +647,7 @@ export function WordbookPanel({
       console.error('AI查词失败:', error);
       flushSync(() => {
         setIsAiExplanationLoading(false);
-        setAiExplanationText(`获取AI查词结果失败: ${error.message || '未知错误'}\n\n请确保：\n1. API 服务可用\n2. 模型名称正确\n3. 设置中的 AI 配置正确`);
+        setAiExplanationText(`获取AI查词结果失败: ${describeApiError(error)}\n\n请确保：\n1. API 服务可用\n2. 模型名称正确\n3. 设置中的 AI 配置正确`);
       });
     }
   }, [llmSettings, setShowAiExplanation, autoSaveWord, hasAutoSaved]);
@@ -735,6 +709,27 @@ export function WordbookPanel({
                 AI查词
               </button>
             )}
+            <button
+              onClick={() => importInputRef.current?.click()}
+              className="px-3 py-3 text-sm text-gray-600 border border-gray-300 hover:bg-gray-50 rounded-xl transition-all"
+              title="从 JSON 导入单词本"
+            >
+              导入
+            </button>
+            <button
+              onClick={handleExportWords}
+              className="px-3 py-3 text-sm text-gray-600 border border-gray-300 hover:bg-gray-50 rounded-xl transition-all"
+              title="导出单词本为 JSON"
+            >
+              导出
+            </button>
+            <input
+              ref={importInputRef}
+              type="file"
+              accept=".json,application/json"
+              className="hidden"
+              onChange={handleImportWords}
+            />
           </div>
           {/* 熟练度统计 */}
           {allWordRecords.length > 0 && (
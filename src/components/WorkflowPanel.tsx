@@ -0,0 +1,81 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { Workflow, WorkflowRunResult } from "../types";
+
+interface WorkflowPanelProps {
+  workflow: Workflow;
+  input: string;
+}
+
+/**
+ * 工作流面板：输入工作流关键字后展示步骤，回车依次执行，关键字之后的文本作为初始文本；
+ * 显示每一步之后的文本与最终结果
+ */
+export function WorkflowPanel({ workflow, input }: WorkflowPanelProps) {
+  const [running, setRunning] = useState(false);
+  const [result, setResult] = useState<WorkflowRunResult | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    setResult(null);
+    setError(null);
+  }, [workflow.id]);
+
+  const handleRun = async () => {
+    setRunning(true);
+    setError(null);
+    try {
+      setResult(await tauriApi.runWorkflow(workflow.id, input));
+    } catch (e) {
+      setResult(null);
+      setError(String(e));
+    } finally {
+      setRunning(false);
+    }
+  };
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      if (!running) handleRun();
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [workflow.id, input, running]);
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      <div className="flex items-center gap-2">
+        <span className="font-medium text-gray-800 truncate">🔗 {workflow.name}</span>
+        {workflow.description && <span className="text-xs text-gray-400 truncate">{workflow.description}</span>}
+        <div className="flex-1" />
+        <span className="text-xs text-gray-400">{running ? "运行中..." : "回车运行"}</span>
+      </div>
+      {input && <div className="text-xs text-gray-500 mt-1 truncate">输入：{input}</div>}
+      {!result && (
+        <div className="text-xs text-gray-400 mt-1 truncate">
+          {workflow.steps.map((step) => step.type).join(" → ")}
+        </div>
+      )}
+      {error && <div className="text-xs text-red-500 mt-2 whitespace-pre-wrap break-all">{error}</div>}
+      {result && (
+        <div className="mt-2">
+          <div className="space-y-0.5">
+            {result.steps.map((step, index) => (
+              <div key={index} className="flex gap-2 text-xs">
+                <span className="w-20 shrink-0 text-gray-400">
+                  {index + 1}. {step.step}
+                </span>
+                <span className="text-gray-600 truncate">{step.output}</span>
+              </div>
+            ))}
+          </div>
+          <div className="text-xs text-gray-400 mt-1">用时 {result.duration_ms} ms</div>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,367 @@
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { open, save } from "@tauri-apps/plugin-dialog";
+import { tauriApi } from "../api/tauri";
+import { TEXT_TRANSFORMS } from "../utils/searchUtils";
+import type { InjectionMode, RecordingMeta, ScriptInfo, Workflow, WorkflowStep } from "../types";
+
+const STEP_TYPES: Array<{ type: WorkflowStep["type"]; label: string; create: () => WorkflowStep }> = [
+  { type: "clipboard", label: "读取剪贴板", create: () => ({ type: "clipboard" }) },
+  { type: "selection", label: "读取选中文本", create: () => ({ type: "selection" }) },
+  { type: "text", label: "文本模板", create: () => ({ type: "text", template: "{query}" }) },
+  { type: "search", label: "搜索（取第一个结果）", create: () => ({ type: "search" }) },
+  { type: "transform", label: "文本转换", create: () => ({ type: "transform", transform: TEXT_TRANSFORMS[0].id }) },
+  { type: "replace", label: "正则替换", create: () => ({ type: "replace", pattern: "", replacement: "" }) },
+  { type: "script", label: "运行脚本", create: () => ({ type: "script", script: "" }) },
+  { type: "copy", label: "复制到剪贴板", create: () => ({ type: "copy" }) },
+  { type: "paste", label: "粘贴到前台窗口", create: () => ({ type: "paste" }) },
+  { type: "launch", label: "打开路径或网址", create: () => ({ type: "launch" }) },
+  { type: "notify", label: "发送通知", create: () => ({ type: "notify", title: "" }) },
+  { type: "macro", label: "回放宏", create: () => ({ type: "macro", recording: "", speed: 1 }) },
+];
+
+const emptyWorkflow = (): Workflow => ({
+  id: "",
+  name: "",
+  keyword: "",
+  description: "",
+  steps: [{ type: "clipboard" }, { type: "copy" }],
+  created_at: 0,
+  updated_at: 0,
+});
+
+const inputClass =
+  "px-2 py-1 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500";
+
+/**
+ * 工作流：关键字触发的一串步骤（取得文本 → 处理 → 输出），步骤可使用内置动作、自动化脚本和录制的宏；
+ * 支持导出为 JSON 文件并在其他设备上导入
+ */
+export function WorkflowsSection() {
+  const [workflows, setWorkflows] = useState<Workflow[]>([]);
+  const [scripts, setScripts] = useState<ScriptInfo[]>([]);
+  const [recordings, setRecordings] = useState<RecordingMeta[]>([]);
+  const [editing, setEditing] = useState<Workflow | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [notice, setNotice] = useState<string | null>(null);
+
+  useEffect(() => {
+    tauriApi
+      .listWorkflows()
+      .then(setWorkflows)
+      .catch((e) => setError(String(e)));
+    const unlisten = listen<Workflow[]>("workflows://changed", (event) => setWorkflows(event.payload));
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 编辑时才加载脚本与录制列表，供步骤下拉选择
+  useEffect(() => {
+    if (!editing) return;
+    tauriApi.listScripts().then(setScripts).catch(() => setScripts([]));
+    tauriApi.listRecordings().then(setRecordings).catch(() => setRecordings([]));
+  }, [editing !== null]);
+
+  const updateStep = (index: number, step: WorkflowStep) => {
+    if (!editing) return;
+    setEditing({ ...editing, steps: editing.steps.map((s, i) => (i === index ? step : s)) });
+  };
+
+  const moveStep = (index: number, offset: number) => {
+    if (!editing) return;
+    const target = index + offset;
+    if (target < 0 || target >= editing.steps.length) return;
+    const steps = [...editing.steps];
+    [steps[index], steps[target]] = [steps[target], steps[index]];
+    setEditing({ ...editing, steps });
+  };
+
+  const handleSave = async () => {
+    if (!editing) return;
+    setError(null);
+    try {
+      await tauriApi.saveWorkflow(editing);
+      setEditing(null);
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const handleDelete = async (workflow: Workflow) => {
+    setError(null);
+    try {
+      await tauriApi.deleteWorkflow(workflow.id);
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const handleExport = async () => {
+    const path = await save({ defaultPath: "workflows.json", filters: [{ name: "JSON", extensions: ["json"] }] });
+    if (!path) return;
+    try {
+      await tauriApi.exportWorkflows(path);
+      setNotice(`已导出 ${workflows.length} 个工作流`);
+      setError(null);
+    } catch (e) {
+      setError(`导出失败: ${e}`);
+    }
+  };
+
+  const handleImport = async () => {
+    const path = await open({ multiple: false, filters: [{ name: "JSON", extensions: ["json"] }] });
+    if (!path || Array.isArray(path)) return;
+    try {
+      const result = await tauriApi.importWorkflows(path);
+      setNotice(`已导入 ${result.imported} 个工作流`);
+      setError(result.skipped.length > 0 ? `跳过：\n${result.skipped.join("\n")}` : null);
+    } catch (e) {
+      setError(`导入失败: ${e}`);
+    }
+  };
+
+  const renderStepFields = (step: WorkflowStep, index: number) => {
+    switch (step.type) {
+      case "text":
+        return (
+          <input
+            value={step.template}
+            onChange={(e) => updateStep(index, { ...step, template: e.target.value })}
+            placeholder="如 https://github.com/search?q={query}"
+            className={`${inputClass} flex-1`}
+          />
+        );
+      case "transform":
+        return (
+          <select
+            value={step.transform}
+            onChange={(e) => updateStep(index, { ...step, transform: e.target.value })}
+            className={inputClass}
+          >
+            {TEXT_TRANSFORMS.map((t) => (
+              <option key={t.id} value={t.id}>
+                {t.label}
+              </option>
+            ))}
+          </select>
+        );
+      case "replace":
+        return (
+          <>
+            <input
+              value={step.pattern}
+              onChange={(e) => updateStep(index, { ...step, pattern: e.target.value })}
+              placeholder="正则表达式"
+              className={`${inputClass} flex-1 font-mono`}
+            />
+            <input
+              value={step.replacement}
+              onChange={(e) => updateStep(index, { ...step, replacement: e.target.value })}
+              placeholder="替换为（$1 引用分组）"
+              className={`${inputClass} flex-1 font-mono`}
+            />
+          </>
+        );
+      case "script":
+        return (
+          <select
+            value={step.script}
+            onChange={(e) => updateStep(index, { ...step, script: e.target.value })}
+            className={`${inputClass} flex-1`}
+          >
+            <option value="">选择脚本</option>
+            {scripts.map((s) => (
+              <option key={s.id} value={s.id}>
+                {s.name}
+              </option>
+            ))}
+          </select>
+        );
+      case "notify":
+        return (
+          <input
+            value={step.title}
+            onChange={(e) => updateStep(index, { ...step, title: e.target.value })}
+            placeholder="通知标题"
+            className={`${inputClass} flex-1`}
+          />
+        );
+      case "macro":
+        return (
+          <>
+            <select
+              value={step.recording}
+              onChange={(e) => updateStep(index, { ...step, recording: e.target.value })}
+              className={`${inputClass} flex-1`}
+            >
+              <option value="">选择录制</option>
+              {recordings.map((r) => (
+                <option key={r.file_path} value={r.file_path}>
+                  {r.file_name}
+                </option>
+              ))}
+            </select>
+            <input
+              type="number"
+              min={0.1}
+              max={10}
+              step={0.1}
+              value={step.speed}
+              onChange={(e) => updateStep(index, { ...step, speed: Number(e.target.value) })}
+              className={`${inputClass} w-20`}
+              title="回放速度"
+            />
+            <select
+              value={step.injection ?? "default"}
+              onChange={(e) => updateStep(index, { ...step, injection: e.target.value as InjectionMode })}
+              className={inputClass}
+              title="按键输入方式，中文输入法下出现乱码时可改为 Unicode 或切换英文布局"
+            >
+              <option value="default">按键</option>
+              <option value="unicode">Unicode</option>
+              <option value="english_layout">英文布局</option>
+            </select>
+          </>
+        );
+      default:
+        return <div className="flex-1" />;
+    }
+  };
+
+  return (
+    <div className="bg-white rounded-lg shadow-sm border border-gray-200 p-6">
+      <div className="flex items-center justify-between">
+        <h3 className="text-lg font-medium text-gray-700">工作流</h3>
+        <div className="flex gap-2">
+          <button onClick={handleImport} className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200">
+            导入
+          </button>
+          <button
+            onClick={handleExport}
+            disabled={workflows.length === 0}
+            className="px-3 py-1.5 text-sm text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200 disabled:opacity-50"
+          >
+            导出
+          </button>
+          <button
+            onClick={() => setEditing(emptyWorkflow())}
+            className="px-3 py-1.5 text-sm bg-blue-600 text-white rounded-md hover:bg-blue-700"
+          >
+            新建
+          </button>
+        </div>
+      </div>
+      <p className="text-sm text-gray-500 mt-1 mb-4">
+        在启动器中输入关键字运行，关键字之后的内容作为初始文本，依次经过每个步骤处理
+      </p>
+      {workflows.length === 0 ? (
+        <div className="text-xs text-gray-400">暂无工作流</div>
+      ) : (
+        <div className="divide-y divide-gray-100">
+          {workflows.map((workflow) => (
+            <div key={workflow.id} className="py-2 text-sm">
+              <div className="flex items-center gap-2">
+                <span className="font-medium text-gray-800">{workflow.name}</span>
+                <span className="px-1.5 py-0.5 text-xs font-mono text-blue-700 bg-blue-50 rounded">{workflow.keyword}</span>
+                <div className="flex-1" />
+                <button onClick={() => setEditing(workflow)} className="text-xs text-blue-600 hover:underline">
+                  编辑
+                </button>
+                <button onClick={() => handleDelete(workflow)} className="text-xs text-red-500 hover:underline">
+                  删除
+                </button>
+              </div>
+              {workflow.description && <div className="text-xs text-gray-500 mt-0.5">{workflow.description}</div>}
+              <div className="text-xs text-gray-400 mt-0.5">
+                {workflow.steps
+                  .map((step) => STEP_TYPES.find((t) => t.type === step.type)?.label ?? step.type)
+                  .join(" → ")}
+              </div>
+            </div>
+          ))}
+        </div>
+      )}
+
+      {editing && (
+        <div className="mt-4 p-4 border border-gray-200 rounded-md space-y-3 text-sm">
+          <div className="flex gap-2">
+            <input
+              value={editing.name}
+              onChange={(e) => setEditing({ ...editing, name: e.target.value })}
+              placeholder="名称"
+              className={`${inputClass} flex-1`}
+            />
+            <input
+              value={editing.keyword}
+              onChange={(e) => setEditing({ ...editing, keyword: e.target.value })}
+              placeholder="关键字"
+              className={`${inputClass} w-32 font-mono`}
+            />
+          </div>
+          <input
+            value={editing.description}
+            onChange={(e) => setEditing({ ...editing, description: e.target.value })}
+            placeholder="说明（可选）"
+            className={`${inputClass} w-full`}
+          />
+          <div className="space-y-2">
+            {editing.steps.map((step, index) => (
+              <div key={index} className="flex items-center gap-2">
+                <span className="w-5 text-xs text-gray-400">{index + 1}</span>
+                <select
+                  value={step.type}
+                  onChange={(e) =>
+                    updateStep(index, STEP_TYPES.find((t) => t.type === e.target.value)!.create())
+                  }
+                  className={`${inputClass} w-40`}
+                >
+                  {STEP_TYPES.map((t) => (
+                    <option key={t.type} value={t.type}>
+                      {t.label}
+                    </option>
+                  ))}
+                </select>
+                {renderStepFields(step, index)}
+                <button onClick={() => moveStep(index, -1)} className="text-xs text-gray-500 hover:text-gray-700" title="上移">
+                  ↑
+                </button>
+                <button onClick={() => moveStep(index, 1)} className="text-xs text-gray-500 hover:text-gray-700" title="下移">
+                  ↓
+                </button>
+                <button
+                  onClick={() => setEditing({ ...editing, steps: editing.steps.filter((_, i) => i !== index) })}
+                  className="text-xs text-red-500 hover:text-red-700"
+                  title="删除步骤"
+                >
+                  ✕
+                </button>
+              </div>
+            ))}
+          </div>
+          <div className="flex items-center gap-2">
+            <button
+              onClick={() => setEditing({ ...editing, steps: [...editing.steps, { type: "copy" }] })}
+              className="px-3 py-1.5 text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200"
+            >
+              添加步骤
+            </button>
+            <div className="flex-1" />
+            <button onClick={() => setEditing(null)} className="px-3 py-1.5 text-gray-700 bg-gray-100 rounded-md hover:bg-gray-200">
+              取消
+            </button>
+            <button
+              onClick={handleSave}
+              disabled={!editing.name.trim() || !editing.keyword.trim() || editing.steps.length === 0}
+              className="px-3 py-1.5 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50"
+            >
+              保存
+            </button>
+          </div>
+        </div>
+      )}
+      {notice && <div className="text-xs text-green-600 mt-2">{notice}</div>}
+      {error && <div className="text-xs text-red-500 mt-2 whitespace-pre-wrap break-all">{error}</div>}
+    </div>
+  );
+}
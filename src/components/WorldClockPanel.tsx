@@ -0,0 +1,140 @@
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { ClockEntry, TimeConversion } from "../types";
+
+type WorldClockQuery =
+  | { mode: "favorites" }
+  | { mode: "place"; place: string }
+  | { mode: "convert"; time: string; from: string; to: string[] };
+
+interface WorldClockPanelProps {
+  query: WorldClockQuery;
+}
+
+const dayLabel = (offset: number) => (offset === 0 ? "" : offset === 1 ? "明天" : offset === -1 ? "昨天" : `${offset > 0 ? "+" : ""}${offset} 天`);
+
+const describe = (entry: ClockEntry) => `${entry.label} ${entry.time}${entry.day_offset ? ` (${dayLabel(entry.day_offset)})` : ""}`;
+
+/**
+ * 世界时钟面板：显示常用城市或指定城市的当前时间，或时区换算结果；
+ * 城市可收藏到常用列表（保存在设置中），回车复制显示的时间
+ */
+export function WorldClockPanel({ query }: WorldClockPanelProps) {
+  const [clocks, setClocks] = useState<ClockEntry[]>([]);
+  const [conversion, setConversion] = useState<TimeConversion | null>(null);
+  const [favorites, setFavorites] = useState<string[]>([]);
+  const [error, setError] = useState<string | null>(null);
+  const [message, setMessage] = useState<string | null>(null);
+  const queryKey = JSON.stringify(query);
+
+  const refresh = () => {
+    const request =
+      query.mode === "convert"
+        ? tauriApi.convertWorldTime(query.time, query.from, query.to).then((result) => {
+            setConversion(result);
+            setClocks([]);
+          })
+        : tauriApi.getWorldClocks(query.mode === "place" ? [query.place] : undefined).then((result) => {
+            setClocks(result);
+            setConversion(null);
+          });
+    request.then(() => setError(null)).catch((e) => setError(String(e)));
+  };
+
+  useEffect(() => {
+    setMessage(null);
+    refresh();
+    tauriApi
+      .getSettings()
+      .then((settings) => setFavorites(settings.world_clock_cities ?? []))
+      .catch(() => {});
+    // 换算结果固定不变，当前时间定时刷新
+    if (query.mode === "convert") return;
+    const timer = window.setInterval(refresh, 15_000);
+    return () => window.clearInterval(timer);
+  }, [queryKey]);
+
+  const isFavorite = (entry: ClockEntry) => favorites.some((f) => f === entry.query || f === entry.label);
+
+  const toggleFavorite = async (entry: ClockEntry) => {
+    try {
+      const settings = await tauriApi.getSettings();
+      const current = settings.world_clock_cities ?? [];
+      const world_clock_cities = isFavorite(entry)
+        ? current.filter((f) => f !== entry.query && f !== entry.label)
+        : [...current, entry.label];
+      await tauriApi.saveSettings({ ...settings, world_clock_cities });
+      setFavorites(world_clock_cities);
+      if (query.mode === "favorites") refresh();
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const copyText = conversion
+    ? [describe(conversion.source), ...conversion.targets.map(describe)].join(" = ")
+    : clocks.length > 0
+    ? clocks.map(describe).join("\n")
+    : null;
+
+  // 在捕获阶段拦截回车，避免启动器打开其他结果
+  useEffect(() => {
+    if (!copyText) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== "Enter" || e.isComposing) return;
+      e.preventDefault();
+      e.stopPropagation();
+      navigator.clipboard
+        .writeText(copyText)
+        .then(() => setMessage("已复制"))
+        .catch((err) => setError(String(err)));
+    };
+    window.addEventListener("keydown", onKeyDown, true);
+    return () => window.removeEventListener("keydown", onKeyDown, true);
+  }, [copyText]);
+
+  // 单个城市查询无法识别时不显示面板（如 "time machine"），避免干扰普通搜索
+  if (error && query.mode === "place") return null;
+
+  const row = (entry: ClockEntry, highlighted: boolean, favoritable: boolean) => (
+    <div
+      key={`${entry.query}-${entry.zone}`}
+      className={`flex items-center gap-2 px-2 py-1.5 rounded ${highlighted ? "bg-blue-50" : ""}`}
+    >
+      <span className="flex-1 text-gray-800">
+        {entry.label}
+        <span className="ml-2 text-xs text-gray-400">
+          {entry.abbreviation ? `${entry.abbreviation} · ` : ""}UTC{entry.utc_offset}
+        </span>
+      </span>
+      {entry.day_offset !== 0 && <span className="text-xs text-amber-600">{dayLabel(entry.day_offset)}</span>}
+      <span className="font-mono text-lg text-gray-900">{entry.time}</span>
+      {favoritable && (
+        <button
+          onClick={() => toggleFavorite(entry)}
+          className={`text-sm ${isFavorite(entry) ? "text-amber-500" : "text-gray-300 hover:text-amber-500"}`}
+          title={isFavorite(entry) ? "从常用城市移除" : "添加到常用城市"}
+        >
+          {isFavorite(entry) ? "★" : "☆"}
+        </button>
+      )}
+    </div>
+  );
+
+  return (
+    <div className="px-4 py-3 border-t border-gray-100 text-sm">
+      {error && <div className="text-xs text-red-500 mb-1">{error}</div>}
+      {conversion && (
+        <>
+          {row(conversion.source, false, false)}
+          {conversion.targets.map((entry) => row(entry, true, false))}
+        </>
+      )}
+      {clocks.map((entry) => row(entry, query.mode === "place", true))}
+      {query.mode === "favorites" && clocks.length === 0 && !error && (
+        <div className="text-xs text-gray-400">还没有常用城市，输入 "time 东京" 后点击 ☆ 添加</div>
+      )}
+      {copyText && <div className="mt-1 text-xs text-blue-600">{message ?? "回车复制时间"}</div>}
+    </div>
+  );
+}
@@ -7,7 +7,7 @@ import { useState, useEffect, useRef, useDeferredValue, useMemo } from "react";
 import { startTransition } from "react";
 import { computeCombinedResults } from "../utils/combineResultsUtils";
 import type { SearchResult } from "../utils/resultUtils";
-import type { AppInfo, FileHistoryItem, MemoItem, SearchEngineConfig } from "../types";
+import type { AppInfo, FileHistoryItem, MemoItem, SearchEngineConfig, ProviderSettings, SshHost, GitRepo, RecentProject, GameInfo, BrowserHistoryItem, AliasConfig } from "../types";
 import type { EverythingResult } from "../types";
 
 export interface UseCombinedResultsOptions {
@@ -28,6 +28,15 @@ export interface UseCombinedResultsOptions {
   searchEngines: SearchEngineConfig[];
   apps: AppInfo[];
   extractedFileIconsRef: React.MutableRefObject<Map<string, string>>;
+  rankingBoosts?: Record<string, number>;
+  webSuggestions?: string[];
+  sshHosts?: SshHost[];
+  gitRepos?: GitRepo[];
+  recentProjects?: RecentProject[];
+  games?: GameInfo[];
+  browserHistory?: BrowserHistoryItem[];
+  aliases?: AliasConfig[];
+  providerSettings?: Record<string, ProviderSettings>;
 }
 
 /**
@@ -53,6 +62,15 @@ export function useCombinedResults(options: UseCombinedResultsOptions) {
     searchEngines,
     apps,
     extractedFileIconsRef,
+    rankingBoosts,
+    webSuggestions,
+    sshHosts,
+    gitRepos,
+    recentProjects,
+    games,
+    browserHistory,
+    aliases,
+    providerSettings,
   } = options;
 
   // 使用 useState + useEffect 替代 useMemo，在 useEffect 中使用 startTransition 异步计算
@@ -83,6 +101,15 @@ export function useCombinedResults(options: UseCombinedResultsOptions) {
           searchEngines,
           apps,
           extractedFileIconsRef,
+          rankingBoosts,
+          webSuggestions,
+          sshHosts,
+          gitRepos,
+          recentProjects,
+          games,
+          browserHistory,
+          aliases,
+          providerSettings,
         });
         setCombinedResultsRaw(results);
       });
@@ -94,7 +121,7 @@ export function useCombinedResults(options: UseCombinedResultsOptions) {
     } else {
       setTimeout(scheduleCompute, 0);
     }
-  }, [filteredApps, filteredFiles, filteredMemos, filteredPlugins, everythingResults, detectedUrls, detectedEmails, detectedJson, openHistory, urlRemarks, query, aiAnswer, searchEngines, systemFolders, directPathResult, apps, extractedFileIconsRef]);
+  }, [filteredApps, filteredFiles, filteredMemos, filteredPlugins, everythingResults, detectedUrls, detectedEmails, detectedJson, openHistory, urlRemarks, query, aiAnswer, searchEngines, systemFolders, directPathResult, apps, extractedFileIconsRef, rankingBoosts, webSuggestions, sshHosts, gitRepos, recentProjects, games, browserHistory, aliases, providerSettings]);
 
   // 使用 useDeferredValue 延迟 combinedResults 的更新，让输入框保持响应
   // 当用户快速输入时，React 会延迟更新 combinedResults，优先处理输入事件
@@ -0,0 +1,36 @@
+/**
+ * 热点文件夹最近文件相关的自定义 Hook
+ * 启动时读取最近新建/修改的文件，后端索引变化后通过 hot-folders://updated 事件刷新
+ */
+
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { HotFolderFile } from "../types";
+
+/**
+ * 热点文件夹最近文件 Hook
+ * @param limit 最多返回的文件数量
+ */
+export function useHotFolderFiles(limit: number) {
+  const [files, setFiles] = useState<HotFolderFile[]>([]);
+
+  useEffect(() => {
+    const refresh = () =>
+      tauriApi
+        .getHotFolderRecentFiles(limit)
+        .then(setFiles)
+        .catch((error) => console.warn("[热点文件夹] 获取最近文件失败:", error));
+    refresh();
+
+    // 数据源开关变化时也需要重新获取（停用后返回空列表）
+    const unlistenUpdated = listen("hot-folders://updated", refresh);
+    const unlistenProviders = listen("providers://changed", refresh);
+    return () => {
+      unlistenUpdated.then((fn) => fn());
+      unlistenProviders.then((fn) => fn());
+    };
+  }, [limit]);
+
+  return files;
+}
@@ -7,7 +7,7 @@ import type React from "react";
 import { useEffect, useRef } from "react";
 import { listen } from "@tauri-apps/api/event";
 import { tauriApi } from "../api/tauri";
-import type { AppInfo, FileHistoryItem, MemoItem, SearchEngineConfig, PluginContext } from "../types";
+import type { AliasConfig, AppInfo, FileHistoryItem, MemoItem, SearchEngineConfig, PluginContext } from "../types";
 import type { ResultStyle } from "../utils/themeConfig";
 
 // 全局标志，确保整个应用只有一个插件快捷键监听器
@@ -30,6 +30,8 @@ export interface LauncherInitializationOptions {
   setResultStyle: (style: ResultStyle) => void;
   setCloseOnBlur: (close: boolean) => void;
   setSearchEngines: (engines: SearchEngineConfig[]) => void;
+  setQuoteWatchlist: (symbols: string[]) => void;
+  setAliases: (aliases: AliasConfig[]) => void;
   setIsEverythingAvailable: (available: boolean) => void;
   setEverythingError: (error: string | null) => void;
   setEverythingPath: (path: string | null) => void;
@@ -70,6 +72,8 @@ export function useLauncherInitialization(
     setResultStyle,
     setCloseOnBlur,
     setSearchEngines,
+    setQuoteWatchlist,
+    setAliases,
     setIsEverythingAvailable,
     setEverythingError,
     setEverythingPath,
@@ -115,6 +119,8 @@ export function useLauncherInitialization(
         if (settings.search_engines) {
           setSearchEngines(settings.search_engines);
         }
+        setQuoteWatchlist(settings.quote_watchlist ?? []);
+        setAliases(settings.aliases ?? []);
       } catch (error) {
         console.error("Failed to load settings:", error);
       }
@@ -129,7 +135,7 @@ export function useLauncherInitialization(
     return () => {
       unlisten.then((fn) => fn());
     };
-  }, [setLlmSettings, setResultStyle, setCloseOnBlur, setSearchEngines, closeOnBlurRef]);
+  }, [setLlmSettings, setResultStyle, setCloseOnBlur, setSearchEngines, setQuoteWatchlist, setAliases, closeOnBlurRef]);
 
   // 监听 JSON 查看器窗口准备好事件，发送待处理的内容
   useEffect(() => {
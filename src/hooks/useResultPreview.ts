@@ -0,0 +1,64 @@
+/**
+ * 搜索结果预览相关的自定义 Hook
+ * 选中结果后按需获取预览信息，并缓存最近获取过的预览，避免上下移动选中项时重复读取文件
+ */
+
+import { useEffect, useState } from "react";
+import { tauriApi } from "../api/tauri";
+import type { ResultPreview } from "../types";
+import type { SearchResult } from "../utils/resultUtils";
+
+const MAX_CACHED_PREVIEWS = 100;
+const previewCache = new Map<string, ResultPreview | null>();
+
+/**
+ * 结果对应的预览请求，不支持预览的结果返回 null
+ */
+export function getPreviewRequest(
+  result: SearchResult
+): { kind: "path" | "word"; target: string } | null {
+  if ((result.type === "file" || result.type === "everything") && result.path) {
+    return { kind: "path", target: result.path };
+  }
+  return null;
+}
+
+/**
+ * 结果预览 Hook：enabled 为 true 时（通常是结果被选中时）才发起请求
+ */
+export function useResultPreview(result: SearchResult, enabled: boolean): ResultPreview | null {
+  const request = result.preview === undefined ? getPreviewRequest(result) : null;
+  const cacheKey = request ? `${request.kind}:${request.target}` : null;
+  const [preview, setPreview] = useState<ResultPreview | null>(
+    cacheKey ? previewCache.get(cacheKey) ?? null : null
+  );
+
+  useEffect(() => {
+    if (!enabled || !request || !cacheKey) {
+      return;
+    }
+    if (previewCache.has(cacheKey)) {
+      setPreview(previewCache.get(cacheKey) ?? null);
+      return;
+    }
+    let cancelled = false;
+    tauriApi
+      .getResultPreview(request.kind, request.target)
+      .then((value) => {
+        if (previewCache.size >= MAX_CACHED_PREVIEWS) {
+          // Map 按插入顺序遍历，删除最早缓存的一项
+          const oldest = previewCache.keys().next().value;
+          if (oldest !== undefined) previewCache.delete(oldest);
+        }
+        previewCache.set(cacheKey, value);
+        if (!cancelled) setPreview(value);
+      })
+      .catch((error) => console.warn("[结果预览] 获取失败:", error));
+    return () => {
+      cancelled = true;
+    };
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [enabled, cacheKey]);
+
+  return result.preview ?? preview;
+}
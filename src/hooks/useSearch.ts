@@ -7,11 +7,14 @@ import { useEffect } from "react";
 import { startTransition } from "react";
 import {
   extractUrls,
+  detectBareUrl,
   extractEmails,
   isValidJson,
   isLikelyAbsolutePath,
 } from "../utils/launcherUtils";
-import type { AppInfo, FileHistoryItem, MemoItem, EverythingResult } from "../types";
+import { isProviderEnabled } from "./useSearchProviders";
+import { tauriApi } from "../api/tauri";
+import type { AppInfo, FileHistoryItem, MemoItem, EverythingResult, ProviderSettings } from "../types";
 
 export interface UseSearchOptions {
   // 查询状态
@@ -47,6 +50,7 @@ export interface UseSearchOptions {
   pendingSessionIdRef: React.MutableRefObject<string | null>;
   currentSearchQueryRef: React.MutableRefObject<string>;
   displayedSearchQueryRef: React.MutableRefObject<string>;
+  providersRef: React.MutableRefObject<Record<string, ProviderSettings>>; // 数据源开关，停用的数据源不发起搜索
   
   // 搜索函数
   searchSystemFoldersWrapper: (query: string) => Promise<void>;
@@ -91,6 +95,7 @@ export function useSearch(options: UseSearchOptions): void {
     pendingSessionIdRef,
     currentSearchQueryRef,
     displayedSearchQueryRef,
+    providersRef,
     searchSystemFoldersWrapper,
     searchFileHistoryWrapper,
     searchApplicationsWrapper,
@@ -204,7 +209,9 @@ export function useSearch(options: UseSearchOptions): void {
       startTransition(() => {
         try {
           const urls = extractUrls(query);
-          setDetectedUrls(urls);
+          // 输入本身是不带协议的网址 / 域名时，提供直接打开的结果而不是只能网页搜索
+          const bareUrl = urls.length === 0 ? detectBareUrl(query) : null;
+          setDetectedUrls(bareUrl ? [bareUrl] : urls);
           
           // Extract email addresses from query（移到防抖内部）
           const emails = extractEmails(query);
@@ -299,7 +306,7 @@ export function useSearch(options: UseSearchOptions): void {
         });
         
         // Everything 搜索立即执行，不延迟
-        if (isEverythingAvailable) {
+        if (isEverythingAvailable && isProviderEnabled(providersRef.current, "everything")) {
           startSearchSession(trimmedQuery).catch(() => {
             // 静默处理错误
           });
@@ -310,22 +317,50 @@ export function useSearch(options: UseSearchOptions): void {
       // 使用 setTimeout(0) 将搜索操作推迟到下一个事件循环，避免阻塞防抖定时器
       // 这样可以让输入框更快响应，即使搜索函数正在执行
       setTimeout(() => {
+        // 设置中停用的数据源不发起搜索
+        const providers = providersRef.current;
+
+        // 记录各数据源的查询耗时到本地使用统计（统计关闭时后端直接忽略）
+        const timed = (provider: string, search: (q: string) => Promise<void>) => {
+          const start = performance.now();
+          return search(trimmedQuery).finally(() => {
+            void tauriApi
+              .recordActivity({
+                event_type: "query",
+                provider,
+                query: trimmedQuery,
+                duration_ms: Math.round(performance.now() - start),
+              })
+              .catch((error) => console.warn("[使用统计] 记录查询耗时失败:", error));
+          });
+        };
+
         // 系统文件夹和文件历史搜索立即执行
         Promise.all([
-          searchSystemFoldersWrapper(trimmedQuery),
-          searchFileHistoryWrapper(trimmedQuery),
+          isProviderEnabled(providers, "system_folders")
+            ? timed("system_folders", searchSystemFoldersWrapper)
+            : Promise.resolve(),
+          isProviderEnabled(providers, "file_history")
+            ? timed("file_history", searchFileHistoryWrapper)
+            : Promise.resolve(),
         ]).catch((error) => {
           console.error("[搜索错误] 并行搜索失败:", error);
         });
         
-        console.log(`[搜索流程] 准备调用 searchApplications: query="${trimmedQuery}"`);
-        searchApplicationsWrapper(trimmedQuery).catch((error) => {
-          console.error("[搜索错误] searchApplications 调用失败:", error);
-        });
+        if (isProviderEnabled(providers, "apps")) {
+          console.log(`[搜索流程] 准备调用 searchApplications: query="${trimmedQuery}"`);
+          timed("apps", searchApplicationsWrapper).catch((error) => {
+            console.error("[搜索错误] searchApplications 调用失败:", error);
+          });
+        }
         
         // 备忘录和插件搜索是纯前端过滤，立即执行（不会阻塞）
-        searchMemosWrapper(trimmedQuery);
-        handleSearchPlugins(trimmedQuery);
+        if (isProviderEnabled(providers, "memos")) {
+          searchMemosWrapper(trimmedQuery);
+        }
+        if (isProviderEnabled(providers, "plugins")) {
+          handleSearchPlugins(trimmedQuery);
+        }
       }, 0);
     }, debounceTime) as unknown as number;
     
@@ -0,0 +1,48 @@
+/**
+ * 搜索数据源配置相关的自定义 Hook
+ * 启动时读取各数据源的开关、优先级和结果数量上限，设置保存后通过 providers://changed 事件热更新
+ */
+
+import { useEffect, useRef, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { ProviderSettings } from "../types";
+
+/**
+ * 判断数据源是否启用（未配置时默认启用）
+ */
+export function isProviderEnabled(
+  providers: Record<string, ProviderSettings>,
+  provider: string
+): boolean {
+  return providers[provider]?.enabled ?? true;
+}
+
+/**
+ * 搜索数据源配置 Hook
+ * 返回当前配置以及始终指向最新配置的 ref（供防抖回调中读取）
+ */
+export function useSearchProviders() {
+  const [providers, setProviders] = useState<Record<string, ProviderSettings>>({});
+  const providersRef = useRef<Record<string, ProviderSettings>>({});
+
+  useEffect(() => {
+    providersRef.current = providers;
+  }, [providers]);
+
+  useEffect(() => {
+    tauriApi
+      .getSearchProviders()
+      .then(setProviders)
+      .catch((error) => console.warn("[搜索数据源] 获取配置失败:", error));
+
+    const unlisten = listen<Record<string, ProviderSettings>>("providers://changed", (event) => {
+      setProviders(event.payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  return { providers, providersRef };
+}
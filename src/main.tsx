@@ -18,6 +18,7 @@ import ClipboardApp from "./ClipboardApp";
 import MarkdownEditorApp from "./MarkdownEditorApp";
 import { initializePlugins } from "./plugins";
 import { trackEvent } from "./api/events";
+import { initAppTheme } from "./utils/appTheme";
 import "./styles.css";
 
 // Determine which app to render based on window label
@@ -44,6 +45,11 @@ async function initApp() {
   }
 
   trackEvent("app_started", { window_label: label });
+
+  // 应用主题（不阻塞渲染）
+  initAppTheme().catch((error) => {
+    console.error("[初始化] 主题初始化失败:", error);
+  });
   
   // 初始化插件系统（仅在 launcher 窗口初始化）
   // 注意：不等待插件初始化完成，避免阻塞渲染
@@ -30,6 +30,30 @@ export interface RecordingMeta {
 
 export type AppStatus = "idle" | "recording" | "playing";
 
+// 录制、回放状态事件的载荷，与后端 commands::recording 保持一致
+export interface RecordingStoppedPayload {
+  file_path: string;
+  event_count: number;
+  duration_ms: number;
+}
+
+export interface ReplayProgressPayload {
+  progress: number;
+  current: number;
+  total: number;
+}
+
+export interface ReplayCompletedPayload {
+  reason: "finished" | "stopped" | "escape" | "limit";
+  executed: number;
+  total: number;
+}
+
+export interface ReplayErrorPayload {
+  index: number;
+  message: string;
+}
+
 export interface AppInfo {
   name: string;
   path: string;
@@ -91,6 +115,763 @@ export interface IndexFileHistoryStatus {
   mtime?: number | null;
 }
 
+// 启动预热中单个数据提供者的状态
+export interface StartupProviderStatus {
+  name: string;
+  state: "loading" | "ready" | "failed";
+  error?: string | null;
+  duration_ms?: number | null;
+}
+
+export interface StartupStatus {
+  ready: boolean;
+  elapsed_ms: number;
+  providers: StartupProviderStatus[];
+}
+
+// 勿扰模式状态
+export interface FocusModeStatus {
+  active: boolean;
+  manual: boolean;
+  auto_detect: boolean;
+  fullscreen: boolean;
+}
+
+// 密码生成选项：length 用于随机与易读模式，words/separator/capitalize/include_number 用于口令模式
+export type PasswordMode = "random" | "passphrase" | "pronounceable";
+
+export interface PasswordOptions {
+  mode: PasswordMode;
+  length: number;
+  lowercase: boolean;
+  uppercase: boolean;
+  digits: boolean;
+  symbols: boolean;
+  exclude_ambiguous: boolean;
+  words: number;
+  separator: string;
+  capitalize: boolean;
+  include_number: boolean;
+}
+
+export interface GeneratedPassword {
+  password: string;
+  entropy_bits: number;
+}
+
+// 保持唤醒状态；until 为结束时间（Unix 毫秒），为空表示一直保持
+export interface KeepAwakeStatus {
+  active: boolean;
+  keep_display: boolean;
+  until?: number | null;
+}
+
+export type TotpAlgorithm = "SHA1" | "SHA256" | "SHA512";
+
+// TOTP 账户（不含密钥）
+export interface TotpAccount {
+  id: string;
+  name: string;
+  issuer: string;
+  algorithm: TotpAlgorithm;
+  digits: number;
+  period: number;
+}
+
+// 新增 TOTP 账户；secret 可以是 Base32 密钥或 otpauth:// 链接
+export interface NewTotpAccount {
+  name: string;
+  issuer?: string;
+  secret: string;
+  algorithm?: TotpAlgorithm | null;
+  digits?: number | null;
+  period?: number | null;
+}
+
+// 当前验证码；remaining 为剩余有效秒数
+export interface TotpCode {
+  id: string;
+  name: string;
+  issuer: string;
+  code: string;
+  period: number;
+  remaining: number;
+}
+
+// 世界时钟条目；query 为用户输入的城市或时区，day_offset 为相对参考日期相差的天数
+export interface ClockEntry {
+  query: string;
+  label: string;
+  zone: string;
+  date: string;
+  time: string;
+  utc_offset: string;
+  abbreviation?: string | null;
+  day_offset: number;
+}
+
+export interface TimeConversion {
+  source: ClockEntry;
+  targets: ClockEntry[];
+}
+
+export type QuoteKind = "stock" | "forex" | "crypto";
+
+// 报价；change / change_percent 为相对前一交易日收盘的涨跌
+export interface Quote {
+  symbol: string;
+  source_symbol: string;
+  kind: QuoteKind;
+  name?: string | null;
+  price: number;
+  previous_close?: number | null;
+  change?: number | null;
+  change_percent?: number | null;
+  currency?: string | null;
+  market_time?: number | null;
+  fetched_at: number;
+}
+
+// 单个代码的查询结果；请求失败时 quote 为上次缓存（stale 为 true）
+export interface QuoteResult {
+  input: string;
+  quote?: Quote | null;
+  stale: boolean;
+  error?: string | null;
+}
+
+// RSS 订阅；last_error 为最近一次抓取失败的原因
+export interface Feed {
+  id: number;
+  url: string;
+  title: string;
+  site_url?: string | null;
+  last_fetched?: number | null;
+  last_error?: string | null;
+  unread: number;
+}
+
+// RSS 条目；published_at 为发布时间（Unix 秒）
+export interface FeedItem {
+  id: number;
+  feed_id: number;
+  feed_title: string;
+  title: string;
+  link: string;
+  summary?: string | null;
+  published_at?: number | null;
+  read: boolean;
+}
+
+export type TimerKind = "timer" | "alarm" | "reminder";
+
+// 倒计时、闹钟与提醒，fire_at 为到期时间（Unix 秒），recurrence 为循环规则描述（如 "每周一"）
+export interface Timer {
+  id: number;
+  kind: TimerKind;
+  label: string;
+  fire_at: number;
+  duration_secs: number;
+  sound: boolean;
+  created_at: number;
+  recurrence?: string | null;
+}
+
+// 自然语言提醒的解析结果
+export interface ReminderPreview {
+  text: string;
+  fire_at: number;
+  recurrence?: string | null;
+}
+
+// 日历订阅，source 为 ICS 地址或本地文件路径
+export interface Calendar {
+  id: number;
+  source: string;
+  name: string;
+  last_fetched?: number | null;
+  last_error?: string | null;
+  upcoming: number;
+}
+
+// 日历日程，时间为 Unix 秒；join_url 为识别到的会议链接
+export interface CalendarEvent {
+  id: number;
+  calendar_id: number;
+  calendar_name: string;
+  title: string;
+  location?: string | null;
+  start_at: number;
+  end_at: number;
+  all_day: boolean;
+  join_url?: string | null;
+}
+
+// 习惯及统计；streak_unit 为 "week" 时连续记录按周计（每周几次的习惯）
+export interface Habit {
+  id: number;
+  name: string;
+  schedule: string;
+  due_today: boolean;
+  done_today: boolean;
+  current_streak: number;
+  best_streak: number;
+  streak_unit: "day" | "week";
+  total_checkins: number;
+  completion_rate: number;
+  week_progress?: [number, number] | null;
+  recent: boolean[];
+}
+
+// 屏幕坐标检查，坐标均为物理像素
+export interface ScreenRect {
+  left: number;
+  top: number;
+  width: number;
+  height: number;
+}
+
+export interface CursorInfo {
+  x: number;
+  y: number;
+  monitor?: {
+    id: string;
+    primary: boolean;
+    bounds: ScreenRect;
+    dpi: number;
+    scale: number;
+    x: number;
+    y: number;
+    logical_x: number;
+    logical_y: number;
+  } | null;
+  window?: {
+    title: string;
+    class_name: string;
+    process_id: number;
+    bounds: ScreenRect;
+    client: ScreenRect;
+    x: number;
+    y: number;
+    client_x: number;
+    client_y: number;
+  } | null;
+}
+
+// 两点测量结果，逻辑尺寸按起点所在显示器的缩放比例换算
+export interface ScreenMeasurement {
+  width: number;
+  height: number;
+  distance: number;
+  angle: number;
+  scale: number;
+  logical_width: number;
+  logical_height: number;
+  logical_distance: number;
+}
+
+// 文本扩展变量，custom 为 true 时 description 为自定义变量的值
+export interface TextVariable {
+  name: string;
+  description: string;
+  custom: boolean;
+}
+
+// 设置中的自定义文本变量
+export interface TextVariableConfig {
+  name: string;
+  value: string;
+}
+
+// 云同步：backend 为 "webdav" | "s3"，datasets 为 "settings" | "snippets" | "wordbook"
+export interface SyncConfig {
+  enabled: boolean;
+  backend: "webdav" | "s3";
+  endpoint: string;
+  username: string;
+  region: string;
+  bucket: string;
+  datasets: string[];
+  interval_minutes: number;
+  has_secret: boolean;
+  has_passphrase: boolean;
+}
+
+// 保存同步配置：secret / passphrase 为 null 时保留原值，为空字符串时清除
+export interface SyncConfigInput extends Omit<SyncConfig, "has_secret" | "has_passphrase"> {
+  secret: string | null;
+  passphrase: string | null;
+}
+
+export interface SyncSummary {
+  pulled: number;
+  pushed: number;
+  conflicts: number;
+}
+
+export interface SyncStatus {
+  device_id: string;
+  last_seq: number;
+  last_sync_at: number | null;
+  last_attempt_at: number | null;
+  last_error: string | null;
+  last_summary: SyncSummary | null;
+  running: boolean;
+}
+
+export interface SyncConflict {
+  id: number;
+  dataset: string;
+  record_id: string;
+  winner: "local" | "remote";
+  local_data: unknown;
+  remote_data: unknown;
+  local_updated_at: number;
+  remote_updated_at: number;
+  remote_device: string;
+  created_at: number;
+}
+
+export interface GitSyncConfig {
+  repo_path: string;
+  subdir: string;
+}
+
+export interface GitCommitInfo {
+  hash: string;
+  message: string;
+  author: string;
+  time: number;
+}
+
+export interface GitSyncStatus extends GitSyncConfig {
+  is_repo: boolean;
+  branch: string | null;
+  remote: string | null;
+  has_changes: boolean;
+  last_commit: GitCommitInfo | null;
+}
+
+export interface GitCommitResult {
+  committed: boolean;
+  commit: GitCommitInfo | null;
+  pushed: boolean;
+}
+
+export interface GitPullResult {
+  merged: boolean;
+  settings_updated: boolean;
+  snippets_updated: number;
+  snippets_removed: number;
+  quicklinks_updated: number;
+  quicklinks_removed: number;
+}
+
+// 浏览器扩展桥接状态；registered_browsers 为 chrome / edge / firefox
+export interface BrowserBridgeStatus {
+  running: boolean;
+  extension_ids: string[];
+  registered_browsers: string[];
+  manifest_dir: string;
+  exe_matches: boolean;
+}
+
+// 发送到手机的局域网分享；qr_svg 为链接的二维码，时间为秒级时间戳
+export interface PhoneShare {
+  id: string;
+  kind: "text" | "image" | "file";
+  name: string;
+  size: number;
+  url: string;
+  qr_svg: string;
+  created_at: number;
+  expires_at: number;
+  downloads: number;
+}
+
+export type PasteProvider = "paste_rs" | "dpaste" | "custom";
+
+export type PasteExpiry = "1h" | "1d" | "7d" | "30d" | "never";
+
+export interface PasteConfig {
+  enabled: boolean;
+  provider: PasteProvider;
+  endpoint: string;
+  default_expiry: PasteExpiry;
+}
+
+export interface PasteConfigView extends PasteConfig {
+  has_token: boolean;
+}
+
+export interface PasteConfigInput extends PasteConfig {
+  /** 不传保留原值，空字符串清除 */
+  token?: string;
+}
+
+export interface PasteLink {
+  id: string;
+  clipboard_item_id: string | null;
+  provider: PasteProvider;
+  url: string;
+  preview: string;
+  created_at: number;
+  expires_at: number | null;
+  revoked_at: number | null;
+  can_delete: boolean;
+}
+
+export interface ScriptInfo {
+  id: string;
+  path: string;
+  name: string;
+  description: string;
+  keyword: string | null;
+  hotkey: { modifiers: string[]; key: string } | null;
+  allow_http: string[]; // 脚本申请访问的主机，需用户确认
+  error: string | null;
+}
+
+export interface ScriptRunResult {
+  output: string;
+  logs: string[];
+  duration_ms: number;
+}
+
+// 模拟输入的方式：default 文本按 Unicode 输入、宏按录制的键码回放；unicode 宏中的字符键也按 Unicode 输入；
+// english_layout 输入期间切换到英文键盘布局，避免中文输入法拦截
+export type InjectionMode = "default" | "unicode" | "english_layout";
+
+// 工作流步骤，type 之外的字段随步骤类型不同
+export type WorkflowStep =
+  | { type: "clipboard" }
+  | { type: "selection" }
+  | { type: "text"; template: string }
+  | { type: "search" }
+  | { type: "transform"; transform: string }
+  | { type: "replace"; pattern: string; replacement: string }
+  | { type: "script"; script: string }
+  | { type: "copy" }
+  | { type: "paste" }
+  | { type: "launch" }
+  | { type: "notify"; title: string }
+  | { type: "macro"; recording: string; speed: number; injection?: InjectionMode };
+
+export interface Workflow {
+  id: string;
+  name: string;
+  keyword: string;
+  description: string;
+  steps: WorkflowStep[];
+  created_at: number;
+  updated_at: number;
+}
+
+export interface WorkflowRunResult {
+  output: string;
+  steps: Array<{ step: string; output: string }>;
+  duration_ms: number;
+}
+
+export interface WorkflowImportResult {
+  imported: number;
+  skipped: string[];
+}
+
+// 代理设置：mode 为 "system" | "none" | "manual"
+export interface ProxySettings {
+  mode: string;
+  url: string;
+  username?: string | null;
+  password?: string | null; // 新密码，只在保存时提交；留空表示不修改，空字符串表示清除
+  has_password?: boolean; // 是否已保存密码（密码不会返回前端）
+}
+
+// 搜索得分倍率（按数据源配置），1 为默认权重，0 表示忽略该类匹配
+export interface ScoringWeights {
+  name: number;
+  pinyin: number;
+  secondary: number;
+  path: number;
+  frecency: number;
+}
+
+// 搜索数据源配置：enabled 控制是否运行，priority 为排序偏置（越大越靠前），max_results 为结果数量上限（0 表示不限制）
+export interface ProviderSettings {
+  enabled: boolean;
+  priority: number;
+  max_results: number;
+}
+
+// 热点文件夹中最近新建/修改的文件，modified 为毫秒时间戳，created 表示监听期间新建
+export interface HotFolderFile {
+  path: string;
+  name: string;
+  folder: string;
+  size: number;
+  modified: number;
+  created: boolean;
+}
+
+// 网卡信息（get_network_info），status 为 "Up" | "Disconnected" 等
+export interface NetworkAdapter {
+  name: string;
+  description: string;
+  status: string;
+  mac: string;
+  link_speed: string;
+  addresses: string[];
+}
+
+// 本机网络信息：默认路由使用的本机地址、当前 Wi-Fi（signal 为信号强度百分比）和网卡列表
+export interface NetworkInfo {
+  local_ip?: string | null;
+  wifi?: { ssid: string; signal?: number | null } | null;
+  adapters: NetworkAdapter[];
+}
+
+// ping 结果，times_ms 为每次回复的耗时
+export interface PingResult {
+  host: string;
+  address?: string | null;
+  sent: number;
+  received: number;
+  times_ms: number[];
+  avg_ms?: number | null;
+}
+
+// TCP 端口检测结果
+export interface PortCheckResult {
+  host: string;
+  port: number;
+  address?: string | null;
+  open: boolean;
+  elapsed_ms?: number | null;
+  error?: string | null;
+}
+
+// 系统监控采样点，cpu / memory 为占用百分比
+export interface SystemSamplePoint {
+  timestamp: number;
+  cpu: number;
+  memory: number;
+}
+
+// 系统状态（get_system_stats），cpu 在采样刚开始时为空，battery 在没有电池的设备上为空
+export interface SystemStats {
+  cpu?: number | null;
+  cpu_count: number;
+  memory_used: number;
+  memory_total: number;
+  disks: Array<{ name: string; mount_point: string; total: number; available: number; removable: boolean }>;
+  battery?: { percent?: number | null; charging: boolean; plugged_in: boolean; seconds_remaining?: number | null } | null;
+  history: SystemSamplePoint[];
+}
+
+// 音频输出设备
+export interface AudioDevice {
+  id: string;
+  name: string;
+  is_default: boolean;
+}
+
+// 默认输出设备的音量（0-100）与静音状态
+export interface AudioVolumeState {
+  volume: number;
+  muted: boolean;
+}
+
+// 显示器（id 为设备名，如 \\.\DISPLAY1）；brightness 为 null 表示不支持调节亮度
+export interface MonitorInfo {
+  id: string;
+  name: string;
+  primary: boolean;
+  width: number;
+  height: number;
+  frequency: number;
+  brightness?: number | null;
+  brightness_method?: "ddc" | "wmi" | null;
+}
+
+export interface DisplayMode {
+  width: number;
+  height: number;
+  frequency: number;
+}
+
+// 无线设备（disabled 表示被硬件开关或驱动禁用）
+export interface RadioInfo {
+  kind: "wifi" | "bluetooth" | "mobile_broadband" | "other";
+  name: string;
+  on: boolean;
+  disabled: boolean;
+}
+
+// 无线状态；airplane_mode 为 null 表示系统不支持飞行模式
+export interface RadioStatus {
+  radios: RadioInfo[];
+  airplane_mode?: boolean | null;
+}
+
+// 虚拟桌面（index 从 0 开始，id 为桌面 GUID）
+export interface VirtualDesktop {
+  id: string;
+  index: number;
+  name: string;
+  current: boolean;
+}
+
+// 显示预设：monitor 为空时作用于主显示器，frequency 为空时保持当前刷新率
+export interface DisplayPreset {
+  name: string;
+  monitor?: string | null;
+  width: number;
+  height: number;
+  frequency?: number | null;
+}
+
+// 检测到的系统代理
+export interface SystemProxy {
+  server: string;
+  bypass?: string | null;
+}
+
+// 外部 API 的结构化错误（AI 对话等），kind 区分离线、限流、额度用尽等情况
+export type ApiErrorKind =
+  | "offline"
+  | "network"
+  | "timeout"
+  | "rate_limited"
+  | "quota_exceeded"
+  | "unauthorized"
+  | "circuit_open"
+  | "http"
+  | "invalid";
+
+export interface ApiError {
+  kind: ApiErrorKind;
+  message: string;
+  status?: number | null;
+  retry_after_secs?: number | null;
+}
+
+// AI 用量：按功能的明细
+export interface FeatureUsage {
+  feature: string;
+  calls: number;
+  prompt_tokens: number;
+  completion_tokens: number;
+  estimated_cost: number;
+}
+
+// AI 用量：月度汇总（费用单位为美元，按模型单价估算）
+export interface UsageSummary {
+  month: string;
+  calls: number;
+  prompt_tokens: number;
+  completion_tokens: number;
+  estimated_cost: number;
+  has_estimated_tokens: boolean;
+  by_feature: FeatureUsage[];
+  budget?: number | null;
+  budget_used_ratio?: number | null;
+}
+
+// AI 提示词模板，template / system 中可使用 {{变量}} 占位符
+// feature 为 "word_explain" | "word_lookup" | "translate" | "summarize"
+export interface PromptTemplate {
+  id: string;
+  name: string;
+  feature: string;
+  system?: string | null;
+  template: string;
+  variables: string[];
+  builtin: boolean;
+  active: boolean;
+}
+
+// 语义搜索结果（剪切板、备忘录），keyword 表示同时命中关键词
+export interface SemanticMatch<T = ClipboardItem> {
+  item: T;
+  score: number;
+  keyword: boolean;
+}
+
+// AI 对话记录
+export interface ChatConversation {
+  id: string;
+  title: string;
+  feature: string;
+  model: string;
+  pinned: boolean;
+  message_count: number;
+  created_at: number;
+  updated_at: number;
+}
+
+export interface ChatStoredMessage {
+  role: string;
+  content: string;
+  created_at: number;
+}
+
+// 对话记录搜索结果，snippet 为匹配位置附近的片段
+export interface ChatMessageMatch {
+  conversation_id: string;
+  title: string;
+  role: string;
+  snippet: string;
+  created_at: number;
+}
+
+// 问笔记检索到的资料，kind 为 "memo" | "word"
+export interface NoteSource {
+  kind: "memo" | "word";
+  id: string;
+  title: string;
+  content: string;
+  score: number;
+}
+
+// 问笔记的回答，sources 按回答中 [编号] 的顺序排列
+export interface NotesAnswer {
+  answer: string;
+  sources: NoteSource[];
+}
+
+// 剪切板文本的 AI 操作
+export type ClipboardAiAction = "summarize" | "rewrite_formal" | "fix_grammar" | "translate";
+
+// 网络状态
+export interface NetworkStatus {
+  online: boolean;
+  last_checked: number;
+}
+
+// 崩溃报告
+export interface CrashReportInfo {
+  name: string;
+  path: string;
+  created_at: number;
+  has_minidump: boolean;
+}
+
+// 主题（内置或用户自定义）
+export interface Theme {
+  id: string;
+  name: string;
+  mode: "light" | "dark";
+  acrylic: boolean;
+  builtin: boolean;
+  colors: Record<string, string>; // CSS 变量名（不含 --）-> 颜色值
+}
+
+export interface ThemeState {
+  selected: string; // 设置中选择的主题 id，"system" 表示跟随系统
+  system_dark: boolean;
+  theme: Theme; // 实际生效的主题
+}
+
 export interface IndexStatus {
   everything: IndexEverythingStatus;
   applications: IndexApplicationsStatus;
@@ -153,6 +934,66 @@ export interface MemoItem {
   content: string;
   created_at: number;
   updated_at: number;
+  // 热字串缩写，输入后按空格/回车/Tab 展开为内容
+  hotstring?: string | null;
+  case_sensitive: boolean;
+  // 热字串只在这些程序中展开，为空时所有程序
+  apps?: string[];
+  // 热字串展开时的输入方式
+  injection?: InjectionMode;
+}
+
+// 搜索结果预览（get_result_preview），kind 区分文件、文件夹、图片和单词本释义
+export type ResultPreview =
+  | { kind: "file"; size: number; modified?: number | null; lines: string[] }
+  | { kind: "folder"; modified?: number | null; item_count?: number | null }
+  | {
+      kind: "image";
+      size: number;
+      modified?: number | null;
+      format: string;
+      width: number;
+      height: number;
+      thumbnail?: string | null;
+    }
+  | {
+      kind: "word";
+      word: string;
+      phonetic?: string | null;
+      translation: string;
+      example_sentence?: string | null;
+      mastery_level: number;
+    };
+
+// 压缩包条目（list_archive_entries），total 超过 entries 长度时表示列表被截断
+export interface ArchiveEntry {
+  name: string;
+  size: number;
+  compressed_size: number;
+  is_dir: boolean;
+}
+
+export interface ArchiveListing {
+  entries: ArchiveEntry[];
+  total: number;
+}
+
+// 文件夹大小分析结果（scan_folder_sizes），other_size 为未展开的较小子项总大小
+export interface FolderSizeNode {
+  name: string;
+  path: string;
+  size: number;
+  file_count: number;
+  is_dir: boolean;
+  children: FolderSizeNode[];
+  other_size: number;
+}
+
+// 文件夹扫描进度（folder-scan://progress 事件）
+export interface FolderScanProgress {
+  files_scanned: number;
+  bytes_scanned: number;
+  current_path: string;
 }
 
 export interface WordRecord {
@@ -244,6 +1085,38 @@ export interface ClipboardItem {
   content_type: string; // "text", "image", "file"
   created_at: number;
   is_favorite: boolean;
+  tags?: string[];
+  has_html?: boolean; // 复制时同时保存了 HTML 格式，重新复制时保留富文本
+  ocr_text?: string; // 图片中识别出的文字，图片也可以被搜索到
+  snippet?: string; // 全文搜索命中位置附近的片段，命中部分以 \u0002 与 \u0003 包围
+  pinned?: boolean; // 置顶，在列表最前面按手动顺序排列
+  is_sensitive?: boolean; // 像密码、银行卡号或 API 密钥；列表接口返回的内容已打码，原文用 revealClipboardItem 读取
+}
+
+// 同时写入剪贴板的多种格式，未提供的格式不写入
+export interface ClipboardContent {
+  text?: string;
+  html?: string;
+  image_path?: string; // PNG 图片文件
+  files?: string[];
+}
+
+// 以另一种形式复制剪切板项：图片转 data URI 或图片文件，路径转 file:// 链接，文本转 Markdown 代码块，表格文本转 CSV 或 Markdown 表格
+export type ClipboardCopyFormat = "data_uri" | "image_file" | "file_url" | "code_block" | "csv" | "markdown_table";
+
+// 剪切板标签及使用它的条目数量
+export interface ClipboardTag {
+  name: string;
+  count: number;
+}
+
+// 批量导入时已存在记录的处理方式
+export type ConflictMode = "skip" | "replace" | "keep_newer";
+
+export interface BatchInsertResult {
+  inserted: number;
+  updated: number;
+  skipped: number;
 }
 
 export interface OpenHistoryItem {
@@ -258,5 +1131,146 @@ export interface SearchEngineConfig {
   prefix: string;
   url: string;
   name: string;
+  suggest_source?: string | null; // "google" | "baidu" | "bing" | "none"，为空时根据 URL 自动识别
+}
+
+export interface OpenWithAction {
+  id: string;
+  name: string;
+  command: string; // 命令模板，使用 {path} 作为文件夹路径占位符
+}
+
+export interface SshHost {
+  host: string; // ssh 命令中使用的名称
+  hostname?: string | null; // 实际连接地址（config 中的 HostName）
+  user?: string | null;
+  port?: number | null;
+  source: "config" | "known_hosts";
+  use_count: number;
+  last_used?: number | null;
+}
+
+export interface GitRepo {
+  path: string;
+  name: string;
+  branch?: string | null; // 当前分支；分离 HEAD 时为提交哈希前 7 位
+  last_commit_time?: number | null;
+  head_mtime: number;
+}
+
+export interface GitRepoRefreshResult {
+  total: number;
+  added: number;
+  updated: number;
+  removed: number;
+}
+
+export interface RecentProject {
+  name: string;
+  path: string;
+  kind: "folder" | "workspace" | "file";
+  ide: "vscode" | "jetbrains";
+  ide_name: string;
+  product: string; // 产品标识，打开时用于查找可执行文件
+  last_opened?: number | null; // 毫秒时间戳（VS Code 不记录）
+  exists: boolean;
+}
+
+export interface GameInfo {
+  id: string; // "steam:<appid>" | "epic:<AppName>"
+  name: string;
+  platform: "steam" | "epic";
+  install_dir?: string | null;
+  launch_uri: string;
+  icon?: string | null; // base64 图片（不带 data: 前缀）
+}
+
+export interface BrowserHistoryItem {
+  url: string;
+  title: string;
+  browser: string; // "Chrome" | "Edge" | "Brave"
+  visit_count: number;
+  last_visit: number; // Unix 时间戳（秒）
+}
+
+export interface AliasConfig {
+  alias: string;
+  target: string;
+}
+
+export interface ImportedQuicklink {
+  name: string;
+  url: string;
 }
 
+export type LauncherImportSource = "wox" | "powertoys" | "utools";
+
+export interface LauncherImportPreview {
+  source: LauncherImportSource;
+  search_engines: SearchEngineConfig[];
+  quicklinks: ImportedQuicklink[];
+  aliases: AliasConfig[];
+}
+
+export interface LauncherImportResult {
+  search_engines_added: number;
+  quicklinks_added: number;
+  aliases_added: number;
+  skipped: number;
+}
+
+export interface ActivityEvent {
+  event_type: "launch" | "query";
+  provider: string; // 结果来源，如 "app" | "file" | "everything" | "plugin" | "url"
+  result_type?: string | null;
+  result_id?: string | null;
+  result_name?: string | null;
+  query?: string | null;
+  duration_ms?: number | null; // 查询耗时（仅 query 事件）
+}
+
+export interface ActivityCountEntry {
+  key: string;
+  name?: string | null;
+  count: number;
+}
+
+export interface ActivityStats {
+  days: number;
+  total_launches: number;
+  total_queries: number;
+  launches_by_provider: ActivityCountEntry[];
+  launches_by_result_type: ActivityCountEntry[];
+  top_results: ActivityCountEntry[];
+  query_latency: { provider: string; avg_ms: number; max_ms: number; query_count: number }[];
+  daily: { day: string; launches: number; queries: number }[];
+}
+
+export interface RankingBoost {
+  result_id: string; // 与 normalizePathForHistory(result.path) 对应
+  score: number;
+  boost: number; // 归一化到 0~1
+  launch_count: number;
+}
+
+export interface QueryHistoryEntry {
+  query: string;
+  use_count: number;
+  last_used: number;
+}
+
+export interface ClipboardMonitorStatus {
+  supported: boolean;
+  running: boolean;
+  restart_count: number;
+  last_error?: string | null;
+  last_started_at?: number | null;
+  last_event_at?: number | null; // 最近一次收到剪切板更新通知的时间（秒）
+  next_retry_at?: number | null; // 异常退出后下次重启的时间（秒）
+}
+
+export interface PathCompletion {
+  path: string;
+  name: string;
+  is_folder: boolean;
+}
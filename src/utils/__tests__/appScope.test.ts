@@ -0,0 +1,18 @@
+import { describe, it, expect } from "vitest";
+import { formatAppRules, parseAppRules, scopesOverlap } from "../appScope";
+
+describe("appScope", () => {
+  it("应该解析逗号分隔的程序规则", () => {
+    expect(parseAppRules(" code.exe，class:Notepad, ,!word ")).toEqual(["code.exe", "class:Notepad", "!word"]);
+    expect(parseAppRules("")).toEqual([]);
+    expect(formatAppRules(["code.exe", "!word"])).toBe("code.exe, !word");
+    expect(formatAppRules(undefined)).toBe("");
+  });
+
+  it("限定在不同程序中的范围互不冲突", () => {
+    expect(scopesOverlap([], ["code"])).toBe(true);
+    expect(scopesOverlap(["code.exe"], ["Code", "word"])).toBe(true);
+    expect(scopesOverlap(["code"], ["class:Notepad"])).toBe(false);
+    expect(scopesOverlap(["!code"], ["code"])).toBe(true);
+  });
+});
@@ -0,0 +1,68 @@
+import { describe, it, expect } from "vitest";
+import {
+  canonicalResultKey,
+  mergeDuplicateResults,
+  normalizeUrlForDedup,
+} from "../combineResultsUtils";
+import type { SearchResult } from "../resultUtils";
+
+describe("combineResultsUtils", () => {
+  describe("canonicalResultKey", () => {
+    it("路径忽略大小写、斜杠方向和末尾分隔符", () => {
+      const a: SearchResult = { type: "file", displayName: "Docs", path: "C:\\Users\\Me\\Docs\\" };
+      const b: SearchResult = { type: "everything", displayName: "Docs", path: "c:/users/me/docs" };
+      expect(canonicalResultKey(a)).toBe(canonicalResultKey(b));
+    });
+
+    it("UWP 应用按 AUMID 比较", () => {
+      const result: SearchResult = {
+        type: "app",
+        displayName: "计算器",
+        path: "shell:AppsFolder\\Microsoft.WindowsCalculator_8wekyb3d8bbwe!App",
+      };
+      expect(canonicalResultKey(result)).toBe("aumid:microsoft.windowscalculator_8wekyb3d8bbwe!app");
+    });
+
+    it("特殊结果不参与去重", () => {
+      expect(canonicalResultKey({ type: "ai", displayName: "AI 回答", path: "ai://answer" })).toBeNull();
+    });
+  });
+
+  it("normalizeUrlForDedup 忽略主机名大小写和末尾斜杠", () => {
+    expect(normalizeUrlForDedup("HTTPS://Example.com/")).toBe(normalizeUrlForDedup("https://example.com"));
+    expect(normalizeUrlForDedup("https://example.com/A/")).not.toBe(normalizeUrlForDedup("https://example.com/a"));
+  });
+
+  describe("mergeDuplicateResults", () => {
+    it("合并重复结果，保留更完整的元数据和较大的使用记录", () => {
+      const results: SearchResult[] = [
+        {
+          type: "everything",
+          everything: { path: "D:\\Work", name: "Work" },
+          displayName: "Work",
+          path: "D:\\Work",
+        },
+        { type: "ai", displayName: "AI 回答", path: "ai://answer" },
+        {
+          type: "file",
+          file: { path: "d:/work/", name: "Work", use_count: 5, last_used: 100 },
+          displayName: "Work",
+          path: "d:/work/",
+        },
+        {
+          type: "file",
+          file: { path: "D:\\Work", name: "Work", use_count: 2, last_used: 300 },
+          displayName: "Work",
+          path: "D:\\Work",
+        },
+      ];
+
+      const merged = mergeDuplicateResults(results);
+      expect(merged).toHaveLength(2);
+      expect(merged[0].type).toBe("file");
+      expect(merged[0].file.use_count).toBe(5);
+      expect(merged[0].file.last_used).toBe(300);
+      expect(merged[1].type).toBe("ai");
+    });
+  });
+});
@@ -1,6 +1,7 @@
 import { describe, it, expect } from "vitest";
 import {
   extractUrls,
+  detectBareUrl,
   extractEmails,
   isValidJson,
   highlightText,
@@ -19,6 +20,7 @@ import {
   isValidIcon,
   isIconExtractionFailed,
   ICON_EXTRACTION_FAILED_MARKER,
+  splitSnippet,
 } from "../launcherUtils";
 
 describe("launcherUtils", () => {
@@ -47,6 +49,23 @@ describe("launcherUtils", () => {
     });
   });
 
+  describe("detectBareUrl", () => {
+    it("应该识别不带协议的域名和网址", () => {
+      expect(detectBareUrl("github.com")).toBe("https://github.com");
+      expect(detectBareUrl("docs.rs/tauri")).toBe("https://docs.rs/tauri");
+      expect(detectBareUrl("localhost:3000")).toBe("http://localhost:3000");
+      expect(detectBareUrl("192.168.1.1/admin")).toBe("http://192.168.1.1/admin");
+    });
+
+    it("不应该把文件名或普通文本当成网址", () => {
+      expect(detectBareUrl("readme.md")).toBeNull();
+      expect(detectBareUrl("1.5")).toBeNull();
+      expect(detectBareUrl("hello world.com")).toBeNull();
+      expect(detectBareUrl("https://example.com")).toBeNull();
+      expect(detectBareUrl("999.1.1.1")).toBeNull();
+    });
+  });
+
   describe("extractEmails", () => {
     it("应该从文本中提取邮箱地址", () => {
       const text = "联系我 test@example.com 或 admin@test.org";
@@ -411,5 +430,25 @@ describe("launcherUtils", () => {
       expect(isIconExtractionFailed(null)).toBe(false);
     });
   });
+
+  describe("splitSnippet", () => {
+    it("应该拆分出命中部分", () => {
+      expect(splitSnippet("…讨论\u0002全文搜索\u0003的\u0002方案\u0003")).toEqual([
+        { text: "…讨论", match: false },
+        { text: "全文搜索", match: true },
+        { text: "的", match: false },
+        { text: "方案", match: true },
+      ]);
+    });
+
+    it("应该处理没有标记或标记不完整的片段", () => {
+      expect(splitSnippet("plain")).toEqual([{ text: "plain", match: false }]);
+      expect(splitSnippet("a\u0002b")).toEqual([
+        { text: "a", match: false },
+        { text: "b", match: true },
+      ]);
+      expect(splitSnippet("")).toEqual([]);
+    });
+  });
 });
 
@@ -3,6 +3,28 @@ import {
   detectSearchIntent,
   buildSearchUrl,
   getSearchResultItem,
+  parseNetworkQuery,
+  isSystemMonitorQuery,
+  parseAudioQuery,
+  findAudioDevice,
+  parseDisplayQuery,
+  parseRadioQuery,
+  parseWindowQuery,
+  parseDesktopQuery,
+  parseKeepAwakeQuery,
+  parseTextTransformQuery,
+  parsePasswordQuery,
+  parseTotpQuery,
+  parseWorldClockQuery,
+  parseQuoteQuery,
+  parseRssQuery,
+  parseTimerQuery,
+  parseCalendarQuery,
+  parseHabitQuery,
+  parseScriptQuery,
+  parseWorkflowQuery,
+  splitTagFilter,
+  matchAliases,
   searchApplicationsFrontend,
   searchFileHistoryFrontend,
 } from "../searchUtils";
@@ -205,5 +227,284 @@ describe("searchUtils", () => {
       expect(results.length).toBeLessThanOrEqual(100);
     });
   });
-});
 
+  describe("parseNetworkQuery", () => {
+    it("应该识别 ip 查询", () => {
+      expect(parseNetworkQuery("ip")).toEqual({ kind: "info" });
+      expect(parseNetworkQuery(" IP ")).toEqual({ kind: "info" });
+      expect(parseNetworkQuery("ipconfig")).toBeNull();
+    });
+
+    it("应该识别 ping 主机", () => {
+      expect(parseNetworkQuery("ping example.com")).toEqual({ kind: "ping", host: "example.com" });
+      expect(parseNetworkQuery("ping")).toBeNull();
+      expect(parseNetworkQuery("ping -t example.com")).toBeNull();
+    });
+
+    it("应该识别端口检测", () => {
+      expect(parseNetworkQuery("ping example.com:443")).toEqual({ kind: "port", host: "example.com", port: 443 });
+      expect(parseNetworkQuery("ping 10.0.0.1 22")).toEqual({ kind: "port", host: "10.0.0.1", port: 22 });
+      expect(parseNetworkQuery("ping [::1]:8080")).toEqual({ kind: "port", host: "[::1]", port: 8080 });
+      expect(parseNetworkQuery("ping example.com:70000")).toBeNull();
+    });
+  });
+
+  describe("isSystemMonitorQuery", () => {
+    it("应该识别系统监控关键字", () => {
+      expect(isSystemMonitorQuery("cpu")).toBe(true);
+      expect(isSystemMonitorQuery(" RAM ")).toBe(true);
+      expect(isSystemMonitorQuery("内存")).toBe(true);
+      expect(isSystemMonitorQuery("cpu-z")).toBe(false);
+    });
+  });
+
+  describe("parseAudioQuery", () => {
+    it("应该解析音频设备查询", () => {
+      expect(parseAudioQuery("audio")).toEqual({ keyword: "" });
+      expect(parseAudioQuery("Audio headphones ")).toEqual({ keyword: "headphones" });
+      expect(parseAudioQuery("音频 耳机")).toEqual({ keyword: "耳机" });
+      expect(parseAudioQuery("audiobook")).toBeNull();
+    });
+
+    it("应该优先完全匹配设备名称", () => {
+      const devices = [{ name: "Headphones (WH-1000XM4)" }, { name: "Headphones" }, { name: "扬声器 (Realtek Audio)" }];
+      expect(findAudioDevice(devices, "headphones")?.name).toBe("Headphones");
+      expect(findAudioDevice(devices, "realtek")?.name).toBe("扬声器 (Realtek Audio)");
+      expect(findAudioDevice(devices, "hdmi")).toBeNull();
+      expect(findAudioDevice(devices, "")).toBeNull();
+    });
+  });
+
+  describe("parseDisplayQuery", () => {
+    it("应该解析显示器、亮度与夜间模式查询", () => {
+      expect(parseDisplayQuery("display")).toEqual({ keyword: "", brightness: null, nightLight: false });
+      expect(parseDisplayQuery("显示器 游戏")).toEqual({ keyword: "游戏", brightness: null, nightLight: false });
+      expect(parseDisplayQuery("亮度 40")).toEqual({ keyword: "", brightness: 40, nightLight: false });
+      expect(parseDisplayQuery("brightness 150%")).toEqual({ keyword: "", brightness: 100, nightLight: false });
+      expect(parseDisplayQuery("brightness")).toEqual({ keyword: "", brightness: null, nightLight: false });
+      expect(parseDisplayQuery("Night Light")).toEqual({ keyword: "", brightness: null, nightLight: true });
+      expect(parseDisplayQuery("夜间模式")).toEqual({ keyword: "", brightness: null, nightLight: true });
+      expect(parseDisplayQuery("displayport")).toBeNull();
+      expect(parseDisplayQuery("亮度 高")).toBeNull();
+    });
+  });
+
+  describe("parseRadioQuery", () => {
+    it("应该解析无线开关查询", () => {
+      expect(parseRadioQuery("wifi")).toEqual({ target: "wifi", enabled: null });
+      expect(parseRadioQuery("Wi-Fi off")).toEqual({ target: "wifi", enabled: false });
+      expect(parseRadioQuery("蓝牙 开")).toEqual({ target: "bluetooth", enabled: true });
+      expect(parseRadioQuery("airplane mode on")).toEqual({ target: "airplane", enabled: true });
+      expect(parseRadioQuery("飞行模式 关闭")).toEqual({ target: "airplane", enabled: false });
+      expect(parseRadioQuery("无线")).toEqual({ target: null, enabled: null });
+      expect(parseRadioQuery("wifi password")).toBeNull();
+    });
+  });
+
+  describe("parseWindowQuery", () => {
+    it("应该按别名匹配窗口管理动作", () => {
+      expect(parseWindowQuery("win")?.actions).toHaveLength(13);
+      expect(parseWindowQuery("win left")?.actions[0].id).toBe("left_half");
+      expect(parseWindowQuery("窗口 最大化")?.actions[0].id).toBe("toggle_maximize");
+      expect(parseWindowQuery("win pin")?.actions[0].id).toBe("toggle_topmost");
+      expect(parseWindowQuery("win top")?.actions.map((a) => a.id)).toEqual(["top_half", "top_left", "top_right"]);
+      expect(parseWindowQuery("win xyz")?.actions).toEqual([]);
+      expect(parseWindowQuery("windows")).toBeNull();
+    });
+  });
+
+  describe("parseDesktopQuery", () => {
+    it("应该解析虚拟桌面查询", () => {
+      expect(parseDesktopQuery("vd")).toEqual({ keyword: "", index: null, move: false });
+      expect(parseDesktopQuery("desktop 2")).toEqual({ keyword: "", index: 1, move: false });
+      expect(parseDesktopQuery("虚拟桌面 工作")).toEqual({ keyword: "工作", index: null, move: false });
+      expect(parseDesktopQuery("vd move 3")).toEqual({ keyword: "", index: 2, move: true });
+      expect(parseDesktopQuery("vd 移动")).toEqual({ keyword: "", index: null, move: true });
+      expect(parseDesktopQuery("vd 0")).toEqual({ keyword: "", index: null, move: false });
+      expect(parseDesktopQuery("vdesktop")).toBeNull();
+    });
+  });
+
+  describe("parseKeepAwakeQuery", () => {
+    it("应该解析保持唤醒时长", () => {
+      expect(parseKeepAwakeQuery("awake")).toEqual({ minutes: null, off: false });
+      expect(parseKeepAwakeQuery("caffeine 2h")).toEqual({ minutes: 120, off: false });
+      expect(parseKeepAwakeQuery("保持唤醒 30分钟")).toEqual({ minutes: 30, off: false });
+      expect(parseKeepAwakeQuery("awake 1.5小时")).toEqual({ minutes: 90, off: false });
+      expect(parseKeepAwakeQuery("awake 45")).toEqual({ minutes: 45, off: false });
+      expect(parseKeepAwakeQuery("awake off")).toEqual({ minutes: null, off: true });
+      expect(parseKeepAwakeQuery("awake 0")).toBeNull();
+      expect(parseKeepAwakeQuery("awakening")).toBeNull();
+    });
+  });
+
+  describe("parseTextTransformQuery", () => {
+    it("应该按前缀或完整别名匹配文本转换", () => {
+      expect(parseTextTransformQuery("text")?.transforms).toHaveLength(10);
+      expect(parseTextTransformQuery("uppercase")?.transforms.map((t) => t.id)).toEqual(["upper"]);
+      expect(parseTextTransformQuery("驼峰")?.transforms.map((t) => t.id)).toEqual(["camel"]);
+      expect(parseTextTransformQuery("text json")?.transforms.map((t) => t.id)).toEqual(["json_pretty", "json_minify"]);
+      expect(parseTextTransformQuery("文本 排序")?.transforms.map((t) => t.id)).toEqual(["sort_lines"]);
+      expect(parseTextTransformQuery("upper case")).toBeNull();
+      expect(parseTextTransformQuery("textual")).toBeNull();
+    });
+  });
+
+  describe("parsePasswordQuery", () => {
+    it("应该解析密码生成的模式与长度", () => {
+      expect(parsePasswordQuery("pwd")).toEqual({ mode: "random", count: null, symbols: true });
+      expect(parsePasswordQuery("pwd 32 nosym")).toEqual({ mode: "random", count: 32, symbols: false });
+      expect(parsePasswordQuery("pwgen phrase 5")).toEqual({ mode: "passphrase", count: 5, symbols: true });
+      expect(parsePasswordQuery("生成密码 易读")).toEqual({ mode: "pronounceable", count: null, symbols: true });
+      expect(parsePasswordQuery("pwd reset")).toBeNull();
+      expect(parsePasswordQuery("pwdx")).toBeNull();
+    });
+  });
+
+  describe("parseTotpQuery", () => {
+    it("应该解析验证码查询中的账户名", () => {
+      expect(parseTotpQuery("otp")).toEqual({ account: "" });
+      expect(parseTotpQuery("2fa  github ")).toEqual({ account: "github" });
+      expect(parseTotpQuery("验证码 阿里云")).toEqual({ account: "阿里云" });
+      expect(parseTotpQuery("otpx")).toBeNull();
+    });
+  });
+
+  describe("parseWorldClockQuery", () => {
+    it("应该解析城市时间与时区换算", () => {
+      expect(parseWorldClockQuery("time")).toEqual({ mode: "favorites" });
+      expect(parseWorldClockQuery("time in Tokyo")).toEqual({ mode: "place", place: "Tokyo" });
+      expect(parseWorldClockQuery("东京时间")).toEqual({ mode: "place", place: "东京" });
+      expect(parseWorldClockQuery("convert 3pm PST to CST")).toEqual({
+        mode: "convert",
+        time: "3pm",
+        from: "PST",
+        to: ["CST"],
+      });
+      expect(parseWorldClockQuery("15:30 北京 到 纽约, 伦敦")).toEqual({
+        mode: "convert",
+        time: "15:30",
+        from: "北京",
+        to: ["纽约", "伦敦"],
+      });
+      expect(parseWorldClockQuery("timer 5m")).toBeNull();
+    });
+  });
+
+  describe("parseQuoteQuery", () => {
+    it("应该识别行情代码与关注列表", () => {
+      expect(parseQuoteQuery("行情")).toEqual({ symbols: null });
+      expect(parseQuoteQuery("quote aapl, msft")).toEqual({ symbols: ["aapl", "msft"] });
+      expect(parseQuoteQuery("$tsla")).toEqual({ symbols: ["tsla"] });
+      expect(parseQuoteQuery("usd/jpy")).toEqual({ symbols: ["usd/jpy"] });
+      expect(parseQuoteQuery("BTC")).toEqual({ symbols: ["BTC"] });
+      expect(parseQuoteQuery("aapl", ["AAPL"])).toEqual({ symbols: ["aapl"] });
+      expect(parseQuoteQuery("aapl")).toBeNull();
+      expect(parseQuoteQuery("abc/def")).toBeNull();
+    });
+  });
+
+  describe("parseRssQuery", () => {
+    it("应该解析 RSS 关键词与添加订阅", () => {
+      expect(parseRssQuery("rss")).toEqual({ keyword: "", addUrl: null });
+      expect(parseRssQuery("订阅 rust")).toEqual({ keyword: "rust", addUrl: null });
+      expect(parseRssQuery("rss add https://example.com/feed.xml")).toEqual({
+        keyword: "",
+        addUrl: "https://example.com/feed.xml",
+      });
+      expect(parseRssQuery("rsshub")).toBeNull();
+    });
+  });
+
+  describe("parseTimerQuery", () => {
+    it("应该解析倒计时与闹钟", () => {
+      expect(parseTimerQuery("timer")).toEqual({ kind: "timer", spec: null, label: "" });
+      expect(parseTimerQuery("timer 10m tea")).toEqual({ kind: "timer", spec: "10m", label: "tea" });
+      expect(parseTimerQuery("计时 25 番茄 钟")).toEqual({ kind: "timer", spec: "25", label: "番茄 钟" });
+      expect(parseTimerQuery("闹钟 7:30 起床")).toEqual({ kind: "alarm", spec: "7:30", label: "起床" });
+      expect(parseTimerQuery("Alarms")).toEqual({ kind: "alarm", spec: null, label: "" });
+      expect(parseTimerQuery("timeline")).toBeNull();
+    });
+
+    it("应该把提醒输入整体交给后端解析", () => {
+      expect(parseTimerQuery("remind me to submit report tomorrow 9am")).toEqual({
+        kind: "reminder",
+        spec: "remind me to submit report tomorrow 9am",
+        label: "",
+      });
+      expect(parseTimerQuery("明天上午9点提醒我交报告")?.kind).toBe("reminder");
+      expect(parseTimerQuery("reminders")).toEqual({ kind: "reminder", spec: null, label: "" });
+      expect(parseTimerQuery("reminder")).toEqual({ kind: "reminder", spec: null, label: "" });
+      expect(parseTimerQuery("remindful")).toBeNull();
+    });
+  });
+
+  describe("parseCalendarQuery", () => {
+    it("应该解析今天日程、下个会议与添加日历", () => {
+      expect(parseCalendarQuery("today")).toEqual({ mode: "today", addSource: null });
+      expect(parseCalendarQuery("今天日程")).toEqual({ mode: "today", addSource: null });
+      expect(parseCalendarQuery("Next Meeting")).toEqual({ mode: "next", addSource: null });
+      expect(parseCalendarQuery("下个会议")).toEqual({ mode: "next", addSource: null });
+      expect(parseCalendarQuery("cal add webcal://example.com/work.ics")).toEqual({
+        mode: "today",
+        addSource: "webcal://example.com/work.ics",
+      });
+      expect(parseCalendarQuery("日历 添加 D:\\cal\\my calendar.ics")?.addSource).toBe("D:\\cal\\my calendar.ics");
+      expect(parseCalendarQuery("todays")).toBeNull();
+    });
+  });
+
+  describe("parseHabitQuery", () => {
+    it("应该解析习惯列表、过滤关键字与添加习惯", () => {
+      expect(parseHabitQuery("habit")).toEqual({ keyword: "", addInput: null });
+      expect(parseHabitQuery("打卡 跑步")).toEqual({ keyword: "跑步", addInput: null });
+      expect(parseHabitQuery("habit add 跑步 每周3次")).toEqual({ keyword: "", addInput: "跑步 每周3次" });
+      expect(parseHabitQuery("习惯 添加 read weekdays")).toEqual({ keyword: "", addInput: "read weekdays" });
+      expect(parseHabitQuery("habitual")).toBeNull();
+    });
+  });
+
+  describe("parseScriptQuery", () => {
+    it("应该按脚本关键字匹配并把其余内容作为输入", () => {
+      const scripts = [
+        { id: "upper", keyword: "up", error: null },
+        { id: "broken", keyword: "bad", error: "语法错误" },
+      ];
+      expect(parseScriptQuery("UP hello world", scripts)).toEqual({ script: scripts[0], input: "hello world" });
+      expect(parseScriptQuery("up", scripts)).toEqual({ script: scripts[0], input: "" });
+      expect(parseScriptQuery("bad x", scripts)).toBeNull();
+      expect(parseScriptQuery("upper", scripts)).toBeNull();
+    });
+  });
+
+  describe("parseWorkflowQuery", () => {
+    it("应该按工作流关键字匹配并把其余内容作为初始文本", () => {
+      const workflows = [{ id: "a", keyword: "gh" }];
+      expect(parseWorkflowQuery("GH  tauri apps ", workflows)).toEqual({ workflow: workflows[0], input: "tauri apps" });
+      expect(parseWorkflowQuery("gh", workflows)).toEqual({ workflow: workflows[0], input: "" });
+      expect(parseWorkflowQuery("ghx", workflows)).toBeNull();
+    });
+  });
+
+  describe("splitTagFilter", () => {
+    it("应该拆出 #标签 并保留其余查询文本", () => {
+      expect(splitTagFilter("#Work  select #sql")).toEqual({ tags: ["work", "sql"], text: "select" });
+      expect(splitTagFilter("# c#")).toEqual({ tags: [], text: "# c#" });
+      expect(splitTagFilter("#项目")).toEqual({ tags: ["项目"], text: "" });
+    });
+  });
+
+  describe("matchAliases", () => {
+    it("应该优先返回完全匹配的别名，其次是前缀匹配", () => {
+      const aliases = [
+        { alias: "docs", target: "C:\\Users\\me\\Documents" },
+        { alias: "Doc", target: "https://docs.example.com" },
+        { alias: "empty", target: " " },
+      ];
+      expect(matchAliases("doc", aliases)).toEqual([aliases[1], aliases[0]]);
+      expect(matchAliases("DOCS ", aliases)).toEqual([aliases[0]]);
+      expect(matchAliases("empty", aliases)).toEqual([]);
+      expect(matchAliases("  ", aliases)).toEqual([]);
+    });
+  });
+});
@@ -0,0 +1,38 @@
+/**
+ * 外部 API 错误提示
+ * 后端返回结构化错误（kind 区分离线、限流、额度用尽等），这里转换为面向用户的提示文字
+ */
+
+import type { ApiError } from "../types";
+
+export function isApiError(error: unknown): error is ApiError {
+  return typeof error === "object" && error !== null && "kind" in error && "message" in error;
+}
+
+/**
+ * 生成错误提示；非结构化错误（如前端抛出的 Error、字符串）原样返回其内容
+ */
+export function describeApiError(error: unknown): string {
+  if (!isApiError(error)) {
+    if (error instanceof Error) return error.message || "未知错误";
+    return String(error ?? "") || "未知错误";
+  }
+  const wait = error.retry_after_secs ? `，请 ${error.retry_after_secs} 秒后重试` : "，请稍后重试";
+  switch (error.kind) {
+    case "offline":
+      return "网络不可用，请检查网络连接";
+    case "network":
+    case "timeout":
+      return `无法连接到服务（${error.message}），请检查网络或代理设置`;
+    case "rate_limited":
+      return `请求过于频繁${wait}`;
+    case "quota_exceeded":
+      return `API 额度已用尽，请检查账户余额或套餐（${error.message}）`;
+    case "unauthorized":
+      return "API Key 无效或没有访问权限，请检查设置";
+    case "circuit_open":
+      return `服务连续请求失败，已暂停访问${wait}`;
+    default:
+      return error.message || "未知错误";
+  }
+}
@@ -0,0 +1,28 @@
+/**
+ * 程序作用范围：热字串和快捷键可以限定在指定程序中生效。
+ * 规则为进程名（如 code.exe，可省略 .exe）或 class:窗口类名，前面加 ! 表示排除，界面中用逗号分隔
+ */
+
+export function parseAppRules(text: string): string[] {
+  return text
+    .split(/[,，]/)
+    .map((rule) => rule.trim())
+    .filter(Boolean);
+}
+
+export function formatAppRules(rules?: string[]): string {
+  return (rules ?? []).join(", ");
+}
+
+function includeKeys(rules?: string[]): string[] {
+  return (rules ?? [])
+    .filter((rule) => !rule.startsWith("!"))
+    .map((rule) => rule.trim().toLowerCase().replace(/\.exe$/, "").replace(/^class:\s*/, "class:"));
+}
+
+/** 两个作用范围是否可能同时生效（与后端 app_scope::overlaps 一致），用于快捷键冲突检查 */
+export function scopesOverlap(a?: string[], b?: string[]): boolean {
+  const aKeys = includeKeys(a);
+  const bKeys = includeKeys(b);
+  return aKeys.length === 0 || bKeys.length === 0 || aKeys.some((key) => bKeys.includes(key));
+}
@@ -0,0 +1,37 @@
+/**
+ * 应用主题
+ * 从后端读取当前生效的主题，写入根元素的 CSS 变量和 data-theme，并跟随 theme-changed 事件切换
+ */
+
+import { listen } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { ThemeState } from "../types";
+
+// 上一个主题写入的变量，切换时先清除，避免自定义主题残留颜色
+let appliedColorNames: string[] = [];
+
+export function applyTheme(state: ThemeState) {
+  const root = document.documentElement;
+  const { theme } = state;
+  root.dataset.theme = theme.id;
+  root.dataset.themeMode = theme.mode;
+  root.classList.toggle("dark", theme.mode === "dark");
+  root.classList.toggle("acrylic", theme.acrylic);
+  root.style.colorScheme = theme.mode;
+  for (const name of appliedColorNames) {
+    root.style.removeProperty(`--theme-${name}`);
+  }
+  for (const [name, value] of Object.entries(theme.colors)) {
+    root.style.setProperty(`--theme-${name}`, value);
+  }
+  appliedColorNames = Object.keys(theme.colors);
+}
+
+export async function initAppTheme() {
+  try {
+    applyTheme(await tauriApi.getTheme());
+  } catch (error) {
+    console.error("加载主题失败:", error);
+  }
+  await listen<ThemeState>("theme-changed", (event) => applyTheme(event.payload));
+}
@@ -5,11 +5,18 @@
 
 import type React from "react";
 import type {
+  AliasConfig,
   AppInfo,
+  BrowserHistoryItem,
   FileHistoryItem,
   EverythingResult,
+  GameInfo,
+  GitRepo,
   MemoItem,
+  ProviderSettings,
+  RecentProject,
   SearchEngineConfig,
+  SshHost,
 } from "../types";
 import type { SearchResult } from "./resultUtils";
 import {
@@ -20,7 +27,7 @@ import {
   getResultUsageInfo,
   isLnkPath,
 } from "./launcherUtils";
-import { detectSearchIntent, getSearchResultItem } from "./searchUtils";
+import { detectSearchIntent, getSearchResultItem, matchAliases } from "./searchUtils";
 
 /**
  * 组合搜索结果的选项接口
@@ -51,6 +58,160 @@ export interface CombineResultsOptions {
   searchEngines: SearchEngineConfig[];
   apps: AppInfo[];
   extractedFileIconsRef: React.MutableRefObject<Map<string, string>>;
+  // 个性化排序加权：normalizePathForHistory(path) -> 0~1，来自历史启动记录
+  rankingBoosts?: Record<string, number>;
+  // 搜索引擎前缀查询的联想词（作为次要搜索结果显示在主搜索结果之后）
+  webSuggestions?: string[];
+  // ~/.ssh/config 与 known_hosts 中匹配查询的主机
+  sshHosts?: SshHost[];
+  // 设置中的根目录下索引到的 Git 仓库
+  gitRepos?: GitRepo[];
+  // VS Code / JetBrains 最近打开的项目
+  recentProjects?: RecentProject[];
+  // Steam / Epic 已安装的游戏（需在设置中启用）
+  games?: GameInfo[];
+  // Chrome / Edge / Brave 浏览历史中匹配查询的网址
+  browserHistory?: BrowserHistoryItem[];
+  // 设置中的别名（从其他启动器导入或手动添加），匹配时排在最前面
+  aliases?: AliasConfig[];
+  // 数据源开关、优先级和结果数量上限（设置中的 providers），未配置的数据源使用默认值
+  providerSettings?: Record<string, ProviderSettings>;
+}
+
+/**
+ * 按数据源配置过滤结果：停用的数据源返回空列表，超过数量上限的部分截断
+ */
+export function limitProviderResults<T>(
+  providerSettings: Record<string, ProviderSettings>,
+  provider: string,
+  items: T[]
+): T[] {
+  const settings = providerSettings[provider];
+  if (!settings) {
+    return items;
+  }
+  if (!settings.enabled) {
+    return [];
+  }
+  return settings.max_results > 0 ? items.slice(0, settings.max_results) : items;
+}
+
+/**
+ * 规范化 URL：协议和主机名忽略大小写，去掉默认端口和末尾的斜杠
+ */
+export function normalizeUrlForDedup(url: string): string {
+  try {
+    const parsed = new URL(url.trim());
+    const pathname = parsed.pathname.replace(/\/+$/, "");
+    return `${parsed.protocol}//${parsed.host}${pathname}${parsed.search}${parsed.hash}`;
+  } catch {
+    return url.trim().toLowerCase().replace(/\/+$/, "");
+  }
+}
+
+/**
+ * 计算结果的规范化去重键，不同来源指向同一目标的结果得到相同的键
+ * - 文件/文件夹路径：忽略大小写、斜杠方向和末尾的分隔符
+ * - UWP 应用：shell:AppsFolder\ 后的 AUMID
+ * - 网址：见 normalizeUrlForDedup
+ * 返回 null 表示该结果不参与去重（AI 回答、插件、备忘录等）
+ */
+export function canonicalResultKey(result: SearchResult): string | null {
+  if (result.type === "url") {
+    return `url:${normalizeUrlForDedup(result.url || result.path)}`;
+  }
+  if (result.type !== "app" && result.type !== "file" && result.type !== "everything") {
+    return null;
+  }
+  const target = result.path.trim();
+  if (!target) {
+    return null;
+  }
+  const targetLower = target.toLowerCase();
+  if (targetLower.startsWith("shell:appsfolder\\")) {
+    return `aumid:${targetLower.slice("shell:appsfolder\\".length)}`;
+  }
+  if (targetLower.startsWith("http://") || targetLower.startsWith("https://")) {
+    return `url:${normalizeUrlForDedup(target)}`;
+  }
+  const normalizedPath = normalizePathForHistory(target);
+  // 保留根目录的斜杠（"/"），其余情况去掉末尾的分隔符，"C:\\Users\\" 与 "c:/users" 视为同一路径
+  return `path:${normalizedPath.replace(/(.)\/+$/, "$1")}`;
+}
+
+/**
+ * 结果携带的元数据丰富程度：图标、描述、拼音、使用记录各计一分
+ */
+function metadataRichness(result: SearchResult): number {
+  let richness = 0;
+  if (result.app) {
+    if (isValidIcon(result.app.icon)) richness++;
+    if (result.app.description) richness++;
+    if (result.app.name_pinyin) richness++;
+  }
+  if (result.file) {
+    richness++;
+    if ((result.file.use_count || 0) > 0) richness++;
+  }
+  return richness;
+}
+
+/**
+ * 合并两个指向同一目标的结果：保留元数据更丰富的一个（相同时保留先出现的），
+ * 使用次数与最近使用时间取两者较大值，缺失的图标、描述、拼音从另一个补齐
+ */
+function mergeDuplicateResult(existing: SearchResult, incoming: SearchResult): SearchResult {
+  const [primary, secondary] =
+    metadataRichness(incoming) > metadataRichness(existing)
+      ? [incoming, existing]
+      : [existing, incoming];
+  const merged: SearchResult = { ...primary };
+
+  if (primary.app && secondary.app) {
+    merged.app = {
+      ...primary.app,
+      icon: isValidIcon(primary.app.icon) ? primary.app.icon : secondary.app.icon ?? primary.app.icon,
+      description: primary.app.description ?? secondary.app.description,
+      name_pinyin: primary.app.name_pinyin ?? secondary.app.name_pinyin,
+      name_pinyin_initials: primary.app.name_pinyin_initials ?? secondary.app.name_pinyin_initials,
+    };
+  }
+
+  if (primary.file && secondary.file) {
+    merged.file = {
+      ...primary.file,
+      use_count: Math.max(primary.file.use_count || 0, secondary.file.use_count || 0),
+      last_used: Math.max(primary.file.last_used || 0, secondary.file.last_used || 0),
+    };
+  } else if (!primary.file && secondary.file && primary.type !== "everything") {
+    // 让应用、网址等结果也能按历史使用频率排序
+    merged.file = secondary.file;
+  }
+
+  return merged;
+}
+
+/**
+ * 按规范化键合并来自不同来源的重复结果，合并后的结果占据第一次出现的位置
+ */
+export function mergeDuplicateResults(results: SearchResult[]): SearchResult[] {
+  const merged: SearchResult[] = [];
+  const indexByKey = new Map<string, number>();
+  for (const result of results) {
+    const key = canonicalResultKey(result);
+    if (key === null) {
+      merged.push(result);
+      continue;
+    }
+    const existingIndex = indexByKey.get(key);
+    if (existingIndex === undefined) {
+      indexByKey.set(key, merged.length);
+      merged.push(result);
+    } else {
+      merged[existingIndex] = mergeDuplicateResult(merged[existingIndex], result);
+    }
+  }
+  return merged;
 }
 
 /**
@@ -60,12 +221,12 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
   const {
     query,
     aiAnswer,
-    filteredApps,
-    filteredFiles,
-    systemFolders,
-    everythingResults,
-    filteredMemos,
-    filteredPlugins,
+    filteredApps: rawFilteredApps,
+    filteredFiles: rawFilteredFiles,
+    systemFolders: rawSystemFolders,
+    everythingResults: rawEverythingResults,
+    filteredMemos: rawFilteredMemos,
+    filteredPlugins: rawFilteredPlugins,
     detectedUrls,
     detectedEmails,
     detectedJson,
@@ -75,8 +236,39 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
     searchEngines,
     apps,
     extractedFileIconsRef,
+    rankingBoosts = {},
+    webSuggestions = [],
+    sshHosts: rawSshHosts = [],
+    gitRepos: rawGitRepos = [],
+    recentProjects: rawRecentProjects = [],
+    games: rawGames = [],
+    browserHistory: rawBrowserHistory = [],
+    aliases = [],
+    providerSettings = {},
   } = options;
 
+  // 停用的数据源不参与合并，各数据源的结果数量不超过设置的上限
+  const filteredApps = limitProviderResults(providerSettings, "apps", rawFilteredApps);
+  const filteredFiles = limitProviderResults(providerSettings, "file_history", rawFilteredFiles);
+  const systemFolders = limitProviderResults(providerSettings, "system_folders", rawSystemFolders);
+  const everythingResults = limitProviderResults(providerSettings, "everything", rawEverythingResults);
+  const filteredMemos = limitProviderResults(providerSettings, "memos", rawFilteredMemos);
+  const filteredPlugins = limitProviderResults(providerSettings, "plugins", rawFilteredPlugins);
+  const sshHosts = limitProviderResults(providerSettings, "ssh_hosts", rawSshHosts);
+  const gitRepos = limitProviderResults(providerSettings, "git_repos", rawGitRepos);
+  const recentProjects = limitProviderResults(providerSettings, "recent_projects", rawRecentProjects);
+  const games = limitProviderResults(providerSettings, "games", rawGames);
+  const browserHistory = limitProviderResults(providerSettings, "browser_history", rawBrowserHistory);
+  const aliasResults: SearchResult[] = limitProviderResults(providerSettings, "aliases", matchAliases(query, aliases)).map(
+    (alias) => ({
+      type: "alias" as const,
+      alias,
+      displayName: alias.alias,
+      path: alias.target,
+      provider: "aliases",
+    })
+  );
+
   // 如果查询为空且没有 AI 回答，直接返回空数组，不显示任何结果
   // 如果有 AI 回答，即使查询为空也要显示
   if (query.trim() === "" && !aiAnswer) {
@@ -180,6 +372,7 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
           },
           displayName: everything.name,
           path: everything.path,
+          provider: "everything",
         };
       }
     );
@@ -205,6 +398,7 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
       everything,
       displayName: everything.name,
       path: everything.path,
+      provider: "everything",
     }));
 
   // 从 openHistory 中提取 URL 历史记录（仅在查询不为空时）
@@ -213,7 +407,7 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
   const detectedUrlsSet = new Set(detectedUrls.map((url) => url.toLowerCase()));
 
   // 只在有查询时才从历史记录中搜索 URL
-  if (queryLower) {
+  if (queryLower && (providerSettings.url_history?.enabled ?? true)) {
     // 遍历 openHistory，提取所有 URL（以 http:// 或 https:// 开头）
     for (const [key, timestamp] of Object.entries(openHistory)) {
       const keyLower = key.toLowerCase();
@@ -234,6 +428,10 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
 
     // 按最近使用时间排序（最新的在前）
     historyUrls.sort((a, b) => b.timestamp - a.timestamp);
+    const maxHistoryUrls = providerSettings.url_history?.max_results ?? 0;
+    if (maxHistoryUrls > 0) {
+      historyUrls.splice(maxHistoryUrls);
+    }
   }
 
   // 合并检测到的 URL 和历史 URL，去重（检测到的 URL 优先）
@@ -248,11 +446,27 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
     }
   }
 
+  // 浏览器历史排在启动器自身的网址历史之后，网页标题作为显示名称
+  const browserHistoryTitles = new Map<string, string>();
+  for (const item of browserHistory) {
+    if (!allUrlsSet.has(item.url.toLowerCase())) {
+      allUrls.push(item.url);
+      allUrlsSet.add(item.url.toLowerCase());
+      browserHistoryTitles.set(item.url, item.title);
+    }
+  }
+
+  const historyUrlSet = new Set(historyUrls.map(({ url }) => url));
   const urlResults: SearchResult[] = allUrls.map((url) => ({
     type: "url" as const,
     url,
-    displayName: url,
+    displayName: browserHistoryTitles.get(url) || url,
     path: url,
+    ...(historyUrlSet.has(url)
+      ? { provider: "url_history" }
+      : browserHistoryTitles.has(url)
+        ? { provider: "browser_history" }
+        : {}),
   }));
 
   // 邮箱结果
@@ -325,7 +539,13 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
       ...searchResultItem,
       type: "search" as const,
     };
-    return [searchResult];
+    const suggestionResults: SearchResult[] = webSuggestions
+      .filter((suggestion) => suggestion !== searchIntent.keyword)
+      .map((suggestion) => ({
+        ...getSearchResultItem(searchIntent.engine, suggestion),
+        type: "search" as const,
+      }));
+    return [searchResult, ...suggestionResults];
   }
 
   let otherResults: SearchResult[] = [
@@ -392,6 +612,7 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
         file: fileData,
         displayName: folder.name,
         path: folder.path,
+        provider: "system_folders",
       };
     }),
     // 如果查询匹配设置关键词，优先显示 Windows 设置应用（通过提高其优先级实现）
@@ -420,6 +641,7 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
         file: fileHistory,
         displayName: app.name,
         path: app.path,
+        provider: "apps",
       };
     }),
     // 从文件历史记录中分离可执行文件
@@ -610,6 +832,7 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
             },
             displayName: file.name,
             path: file.path,
+            provider: "file_history",
           };
         }
       ),
@@ -631,6 +854,7 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
             file,
             displayName: file.name,
             path: file.path,
+            provider: "file_history",
           };
         }
 
@@ -639,6 +863,7 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
           file,
           displayName: file.name,
           path: file.path,
+          provider: "file_history",
         };
       }),
     ...filteredMemos.map((memo) => ({
@@ -646,6 +871,35 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
       memo,
       displayName: memo.title || memo.content.slice(0, 50),
       path: memo.id,
+      provider: "memos",
+    })),
+    ...sshHosts.map((host) => ({
+      type: "ssh_host" as const,
+      sshHost: host,
+      displayName: host.host,
+      path: `ssh://${host.user ? `${host.user}@` : ""}${host.hostname || host.host}${host.port ? `:${host.port}` : ""}`,
+      provider: "ssh_hosts",
+    })),
+    ...gitRepos.map((repo) => ({
+      type: "git_repo" as const,
+      gitRepo: repo,
+      displayName: repo.name,
+      path: repo.path,
+      provider: "git_repos",
+    })),
+    ...recentProjects.map((project) => ({
+      type: "recent_project" as const,
+      recentProject: project,
+      displayName: project.name,
+      path: project.path,
+      provider: "recent_projects",
+    })),
+    ...games.map((game) => ({
+      type: "game" as const,
+      game,
+      displayName: game.name,
+      path: game.launch_uri,
+      provider: "games",
     })),
     // 将文件工具箱插件单独提取，优先显示
     ...filteredPlugins
@@ -655,6 +909,7 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
         plugin,
         displayName: plugin.name,
         path: plugin.id,
+        provider: "plugins",
       })),
     // 其他插件
     ...filteredPlugins
@@ -664,6 +919,7 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
         plugin,
         displayName: plugin.name,
         path: plugin.id,
+        provider: "plugins",
       })),
     // 从 Everything 结果中分离可执行文件（已在数组外预处理）
     ...filteredExecutableEverything,
@@ -671,6 +927,9 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
     ...filteredNonExecutableEverything,
   ];
 
+  // 先按规范化键合并跨来源的重复结果（如既在文件历史中又是书签的文件夹），合并使用记录并保留最完整的元数据
+  otherResults = mergeDuplicateResults(otherResults);
+
   // 对结果进行去重：如果同一个路径出现在多个结果源中，只保留一个
   // 优先保留历史文件结果（因为历史记录包含使用频率和最近使用时间，排序更准确）
   // 先收集历史文件结果的路径集合
@@ -1120,8 +1379,26 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
     ...jsonFormatterResult,
   ];
 
-  // 对所有结果统一排序，确保最近使用时间优先
+  // 数据源优先级（设置中的 providers.priority），没有来源的结果按 0 处理
+  const providerPriority = (result: SearchResult): number =>
+    (result.provider && providerSettings[result.provider]?.priority) || 0;
+
+  // 对所有结果统一排序：数据源优先级和个性化加权优先，其次最近使用时间
   allResultsToSort.sort((a, b) => {
+    // 设置中调高优先级的数据源整体排在前面
+    const aPriority = providerPriority(a);
+    const bPriority = providerPriority(b);
+    if (aPriority !== bPriority) {
+      return bPriority - aPriority;
+    }
+
+    // 第零优先级：在当前输入前缀下经常选择的结果排在前面
+    const aBoost = rankingBoosts[normalizePathForHistory(a.path)] ?? 0;
+    const bBoost = rankingBoosts[normalizePathForHistory(b.path)] ?? 0;
+    if (aBoost !== bBoost) {
+      return bBoost - aBoost;
+    }
+
     const aUsage = getResultUsageInfo(a, openHistory);
     const bUsage = getResultUsageInfo(b, openHistory);
     const aUseCount = aUsage.useCount;
@@ -1192,6 +1469,11 @@ export function computeCombinedResults(options: CombineResultsOptions): SearchRe
     return a.displayName.localeCompare(b.displayName);
   });
 
-  return allResultsToSort;
+  // 别名是用户明确指定的目标，排在其他结果之前，同一目标的其他结果不再重复显示
+  const aliasTargets = new Set(aliasResults.map((result) => normalizePathForHistory(result.path)));
+  return [
+    ...aliasResults,
+    ...allResultsToSort.filter((result) => !aliasTargets.has(normalizePathForHistory(result.path))),
+  ];
 }
 
@@ -65,6 +65,12 @@ export interface HandleKeyDownOptions {
   }) => Promise<void>;
   resetMemoState: () => void;
   handleLaunch: (result: SearchResult) => Promise<void>;
+
+  // 查询历史（可选）
+  // 返回 true 表示已处理（回溯到了一条历史 / 回到了原始输入）
+  navigateQueryHistory?: (direction: "older" | "newer") => Promise<boolean>;
+  // 接受当前的补全建议，返回 true 表示已处理
+  acceptQueryCompletion?: () => boolean;
 }
 
 /**
@@ -107,6 +113,8 @@ export async function handleKeyDown(
     hideLauncherAndResetState,
     resetMemoState,
     handleLaunch,
+    navigateQueryHistory,
+    acceptQueryCompletion,
   } = options;
 
   if (e.key === "Escape" || e.keyCode === 27) {
@@ -178,6 +186,17 @@ export async function handleKeyDown(
     // 检查当前焦点是否在输入框
     const isInputFocused = document.activeElement === inputRef.current;
 
+    // 正在回溯查询历史时，ArrowDown 回到较新的历史
+    if (
+      isInputFocused &&
+      selectedHorizontalIndex === null &&
+      selectedVerticalIndex === null &&
+      navigateQueryHistory &&
+      (await navigateQueryHistory("newer"))
+    ) {
+      return;
+    }
+
     // 如果当前选中的是横向结果，按ArrowDown应该跳转到第一个纵向结果
     if (selectedHorizontalIndex !== null) {
       if (verticalResults.length > 0) {
@@ -270,6 +289,21 @@ export async function handleKeyDown(
       return;
     }
 
+    // 没有选中任何结果时，ArrowUp 回溯较早的查询历史（类似 shell）
+    if (
+      selectedHorizontalIndex === null &&
+      selectedVerticalIndex === null &&
+      navigateQueryHistory
+    ) {
+      await navigateQueryHistory("older");
+    }
+
+    return;
+  }
+
+  // Tab 接受查询历史的补全建议
+  if (e.key === "Tab" && acceptQueryCompletion && acceptQueryCompletion()) {
+    e.preventDefault();
     return;
   }
 
@@ -87,6 +87,26 @@ export async function handleLaunch(options: LaunchOptions): Promise<void> {
   } = options;
 
   try {
+    // 记录到本地使用统计（用于统计面板和个性化排序），失败不影响启动
+    if (result.path && query.trim() !== "") {
+      void tauriApi
+        .recordActivity({
+          event_type: "launch",
+          provider: result.type,
+          result_id: normalizePathForHistory(result.path),
+          result_name: result.displayName,
+          query: query.trim(),
+        })
+        .catch((error) => console.warn("[使用统计] 记录启动失败:", error));
+    }
+
+    // 记录查询历史（用于上下键回溯和输入补全）
+    if (query.trim() !== "") {
+      void tauriApi
+        .addQueryHistory(query.trim())
+        .catch((error) => console.warn("[查询历史] 记录失败:", error));
+    }
+
     // 统一更新使用历史记录（所有类型统一处理）
     const pathToUpdate = result.path;
     const timestampToUpdate = Date.now() / 1000;
@@ -105,6 +125,11 @@ export async function handleLaunch(options: LaunchOptions): Promise<void> {
         result.type === "history" ||
         result.type === "settings" ||
         result.type === "memo" ||
+        result.type === "ssh_host" ||
+        result.type === "git_repo" ||
+        result.type === "recent_project" ||
+        result.type === "game" ||
+        result.type === "alias" ||
         result.type === "plugin"
       ) {
         return false;
@@ -232,7 +257,11 @@ export async function handleLaunch(options: LaunchOptions): Promise<void> {
       // 这里暂时不做任何操作，只是显示结果
       return;
     } else if (result.type === "url" && result.url) {
-      await tauriApi.openUrl(result.url);
+      // 快捷链接中的文本变量（如 {clip}、{date}）按 URL 编码展开
+      const url = result.url.includes("{")
+        ? await tauriApi.renderTextVariables(result.url, { urlEncode: true })
+        : result.url;
+      await tauriApi.openUrl(url);
       // 注意：历史记录的更新已在开头统一处理
       await hideLauncherAndResetState();
       return;
@@ -241,6 +270,37 @@ export async function handleLaunch(options: LaunchOptions): Promise<void> {
       await tauriApi.openUrl(result.path);
       await hideLauncherAndResetState();
       return;
+    } else if (result.type === "ssh_host" && result.sshHost) {
+      // 在新终端中连接 SSH 主机（后端记录使用次数）
+      await tauriApi.connectSshHost(result.sshHost.host);
+      await hideLauncherAndResetState();
+      return;
+    } else if (result.type === "alias" && result.alias) {
+      // 别名目标可以是路径、命令或网址，交给系统按类型打开
+      await tauriApi.launchFile(result.alias.target);
+      await hideLauncherAndResetState();
+      return;
+    } else if (result.type === "game" && result.game) {
+      // 通过 steam:// 或 com.epicgames.launcher:// 协议启动游戏
+      await tauriApi.launchGame(result.game.launch_uri);
+      await hideLauncherAndResetState();
+      return;
+    } else if (result.type === "recent_project" && result.recentProject) {
+      // 使用对应 IDE 打开最近项目（可执行文件由后端从应用索引中查找）
+      await tauriApi.openRecentProject(result.recentProject);
+      await hideLauncherAndResetState();
+      return;
+    } else if (result.type === "git_repo" && result.gitRepo) {
+      // 优先在编辑器（VS Code 动作）中打开仓库，没有配置打开方式时用资源管理器打开
+      const actions = await tauriApi.getOpenWithActions();
+      const action = actions.find((a) => a.id === "vscode") ?? actions[0];
+      if (action) {
+        await tauriApi.openFolderWith(action.id, result.gitRepo.path);
+      } else {
+        await tauriApi.launchFile(result.gitRepo.path);
+      }
+      await hideLauncherAndResetState();
+      return;
     } else if (result.type === "email" && result.email) {
       // 复制邮箱地址到剪贴板
       try {
@@ -19,6 +19,42 @@ export function extractUrls(text: string): string[] {
     .filter((url, index, self) => self.indexOf(url) === index); // Remove duplicates
 }
 
+// 常见顶级域名：不带协议和路径的输入只有以这些结尾时才视为网址，避免把 readme.md、main.rs 之类的文件名当成网址
+const COMMON_TLDS = new Set([
+  "com", "net", "org", "io", "dev", "app", "ai", "co", "me", "cn", "edu", "gov", "info", "xyz",
+  "top", "tech", "site", "cc", "tv", "uk", "de", "jp", "us", "fr", "ru", "hk", "tw", "so", "gg",
+]);
+
+// 检测不带协议的网址或域名（如 github.com、localhost:3000、192.168.1.1/admin），返回补全协议后的 URL
+export function detectBareUrl(text: string): string | null {
+  const trimmed = text.trim();
+  if (!trimmed || /\s/.test(trimmed) || /^[a-z][a-z0-9+.-]*:\/\//i.test(trimmed)) return null;
+
+  const match = trimmed.match(/^([a-z0-9.-]+)(:\d{1,5})?([/?#].*)?$/i);
+  if (!match) return null;
+  const [, host, port, rest] = match;
+  const hostLower = host.toLowerCase();
+
+  // 本机地址和 IPv4 默认使用 http
+  if (hostLower === "localhost" || /^\d{1,3}(\.\d{1,3}){3}$/.test(hostLower)) {
+    if (hostLower !== "localhost" && hostLower.split(".").some((part) => Number(part) > 255)) {
+      return null;
+    }
+    return hostLower === "localhost" && !port && !rest ? null : `http://${trimmed}`;
+  }
+
+  const labels = hostLower.split(".");
+  if (labels.length < 2 || labels.some((label) => !label || label.startsWith("-") || label.endsWith("-"))) {
+    return null;
+  }
+  const tld = labels[labels.length - 1];
+  if (!/^[a-z]{2,}$/.test(tld)) return null;
+  // 带端口或路径时更可能是网址，放宽顶级域名限制
+  if (!port && !rest && !COMMON_TLDS.has(tld)) return null;
+
+  return `https://${trimmed}`;
+}
+
 // Extract email addresses from text
 export function extractEmails(text: string): string[] {
   if (!text || text.trim().length === 0) return [];
@@ -141,6 +177,29 @@ export function highlightText(text: string, query: string): string {
   }
 }
 
+// 剪切板全文搜索片段中命中部分的起止标记（与后端 clipboard::SNIPPET_MATCH_START/END 一致）
+const SNIPPET_MATCH_START = "\u0002";
+const SNIPPET_MATCH_END = "\u0003";
+
+// 把搜索片段拆分为普通文本与命中文本，供渲染高亮
+export function splitSnippet(snippet: string): Array<{ text: string; match: boolean }> {
+  const parts: Array<{ text: string; match: boolean }> = [];
+  let rest = snippet;
+  while (rest) {
+    const start = rest.indexOf(SNIPPET_MATCH_START);
+    if (start < 0) {
+      parts.push({ text: rest, match: false });
+      break;
+    }
+    if (start > 0) parts.push({ text: rest.slice(0, start), match: false });
+    const end = rest.indexOf(SNIPPET_MATCH_END, start + 1);
+    const stop = end < 0 ? rest.length : end;
+    parts.push({ text: rest.slice(start + 1, stop), match: true });
+    rest = rest.slice(stop + 1);
+  }
+  return parts.filter((part) => part.text);
+}
+
 // 判断字符串是否包含中文字符
 export function containsChinese(text: string): boolean {
   return /[\u4E00-\u9FFF]/.test(text);
@@ -0,0 +1,87 @@
+/**
+ * 网络状态
+ * 跟随后端 network-status-changed 事件维护在线状态；离线时在线功能可直接失败，或把任务排队到联网后执行
+ */
+
+import { listen } from "@tauri-apps/api/event";
+import { tauriApi } from "../api/tauri";
+import type { NetworkStatus } from "../types";
+
+export const OFFLINE_MESSAGE = "网络不可用，请检查网络连接";
+
+// 启动时假定在线，与后端保持一致
+let online = true;
+let initPromise: Promise<void> | null = null;
+// 同一 key 只保留最后一次排队的任务
+const pendingTasks = new Map<string, () => void | Promise<void>>();
+
+function flushPendingTasks() {
+  const tasks = Array.from(pendingTasks.values());
+  pendingTasks.clear();
+  for (const task of tasks) {
+    Promise.resolve()
+      .then(task)
+      .catch((error) => console.error("执行排队任务失败:", error));
+  }
+}
+
+function ensureInit(): Promise<void> {
+  if (!initPromise) {
+    initPromise = (async () => {
+      try {
+        online = (await tauriApi.getNetworkStatus()).online;
+      } catch (error) {
+        console.error("获取网络状态失败:", error);
+      }
+      await listen<NetworkStatus>("network-status-changed", (event) => {
+        online = event.payload.online;
+      });
+      await listen<NetworkStatus>("network-online", () => {
+        online = true;
+        flushPendingTasks();
+      });
+    })();
+  }
+  return initPromise;
+}
+
+export async function isOnline(): Promise<boolean> {
+  await ensureInit();
+  return online;
+}
+
+/**
+ * 是否为本机或局域网地址（如本地 Ollama），这类服务离线时仍可访问
+ */
+export function isLocalUrl(url: string): boolean {
+  try {
+    const host = new URL(url).hostname.replace(/^\[|\]$/g, "");
+    return (
+      host === "localhost" ||
+      host === "::1" ||
+      /^127\./.test(host) ||
+      /^10\./.test(host) ||
+      /^192\.168\./.test(host) ||
+      /^172\.(1[6-9]|2\d|3[01])\./.test(host)
+    );
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * 离线时抛出错误，供在线请求发起前调用
+ */
+export async function assertOnline(): Promise<void> {
+  if (!(await isOnline())) {
+    throw new Error(OFFLINE_MESSAGE);
+  }
+}
+
+/**
+ * 把任务排队到联网后执行；同一 key 重复排队时只保留最后一次
+ */
+export function queueWhenOnline(key: string, task: () => void | Promise<void>) {
+  pendingTasks.set(key, task);
+  void ensureInit();
+}
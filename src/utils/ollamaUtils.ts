@@ -4,6 +4,9 @@
  */
 
 import { flushSync } from "react-dom";
+import { tauriApi } from "../api/tauri";
+import { assertOnline, isLocalUrl } from "./networkStatus";
+import { describeApiError } from "./apiError";
 
 /**
  * LLM 设置接口
@@ -53,24 +56,21 @@ export async function askAi(
   setIsAiLoading(true);
   
   let accumulatedAnswer = '';
-  let buffer = '';
   
   try {
     const baseUrl = (settings.base_url || 'https://api.openai.com/v1').replace(/\/+$/, '');
     const model = settings.model || 'gpt-3.5-turbo';
-    
-    const headers: Record<string, string> = {
-      'Content-Type': 'application/json',
-    };
-    if (settings.api_key) {
-      headers['Authorization'] = `Bearer ${settings.api_key}`;
+    // 离线时直接失败，不等待请求超时（本地模型不受影响）
+    if (!isLocalUrl(baseUrl)) {
+      await assertOnline();
     }
 
-    const response = await fetch(`${baseUrl}/chat/completions`, {
-      method: 'POST',
-      headers,
-      body: JSON.stringify({
-        model: model,
+    // 请求经由后端发出，以便应用代理设置
+    const answer = await tauriApi.llmChat(
+      {
+        base_url: baseUrl,
+        api_key: settings.api_key,
+        model,
         messages: [
           {
             role: 'user',
@@ -78,64 +78,21 @@ export async function askAi(
           },
         ],
         stream: true,
-      }),
-    });
-
-    if (!response.ok) {
-      const errorBody = await response.text().catch(() => '');
-      throw new Error(`API 请求失败 (${response.status}): ${errorBody || response.statusText}`);
-    }
-
-    const reader = response.body?.getReader();
-    const decoder = new TextDecoder();
-    
-    if (!reader) {
-      throw new Error('无法读取响应流');
-    }
-
-    while (true) {
-      const { done, value } = await reader.read();
-      if (done) {
-        // 处理剩余的 buffer
-        if (buffer.trim()) {
-          processSSELine(buffer, (content) => {
-            accumulatedAnswer += content;
-            flushSync(() => { setAiAnswer(accumulatedAnswer); });
-          });
-        }
-        break;
-      }
-
-      const chunk = decoder.decode(value, { stream: true });
-      buffer += chunk;
-      const lines = buffer.split('\n');
-      
-      // 保留最后一个不完整的行
-      buffer = lines.pop() || '';
-
-      for (const line of lines) {
-        const trimmedLine = line.trim();
-        if (!trimmedLine) continue;
-        
-        if (trimmedLine === 'data: [DONE]') {
-          setIsAiLoading(false);
-          flushSync(() => { setAiAnswer(accumulatedAnswer); });
-          return;
-        }
-
-        processSSELine(trimmedLine, (content) => {
-          accumulatedAnswer += content;
-          flushSync(() => { setAiAnswer(accumulatedAnswer); });
-        });
+        feature: 'launcher_ask',
+        save_history: true,
+      },
+      (content) => {
+        accumulatedAnswer += content;
+        flushSync(() => { setAiAnswer(accumulatedAnswer); });
       }
-    }
+    );
     
     setIsAiLoading(false);
-    setAiAnswer(accumulatedAnswer);
+    setAiAnswer(answer);
   } catch (error: any) {
     console.error('调用 AI API 失败:', error);
     setIsAiLoading(false);
-    const errorMessage = error.message || '未知错误';
+    const errorMessage = describeApiError(error);
     const baseUrl = settings.base_url || 'https://api.openai.com/v1';
     alert(`调用AI失败: ${errorMessage}\n\n请确保:\n1. API 服务可用\n2. 模型名称正确 (${settings.model})\n3. API 地址正确 (${baseUrl})\n4. API Key 已正确配置`);
   }
@@ -143,26 +100,3 @@ export async function askAi(
 
 // 向后兼容别名
 export const askOllama = askAi;
-
-/**
- * 处理 SSE 行，提取 content delta
- */
-function processSSELine(line: string, onContent: (content: string) => void): void {
-  const dataPrefix = 'data: ';
-  const jsonStr = line.startsWith(dataPrefix) ? line.slice(dataPrefix.length) : line;
-  
-  if (!jsonStr.trim() || jsonStr.trim() === '[DONE]') return;
-  
-  try {
-    const data = JSON.parse(jsonStr);
-    // OpenAI format: data.choices[0].delta.content
-    const content = data.choices?.[0]?.delta?.content;
-    if (content) {
-      onContent(content);
-    }
-  } catch (e) {
-    // 忽略解析错误
-    console.warn('解析流式数据失败:', e, jsonStr);
-  }
-}
-
@@ -11,21 +11,29 @@ import {
   getResultUsageInfo,
   calculateRelevanceScore,
 } from "./launcherUtils";
+import type { AliasConfig, GameInfo, GitRepo, RecentProject, ResultPreview, SshHost } from "../types";
 
 // SearchResult 类型定义（与 LauncherWindow.tsx 中的定义保持一致）
 export type SearchResult = {
-  type: "app" | "file" | "everything" | "url" | "email" | "memo" | "plugin" | "history" | "ai" | "json_formatter" | "settings" | "search";
+  type: "app" | "file" | "everything" | "url" | "email" | "memo" | "plugin" | "history" | "ai" | "json_formatter" | "settings" | "search" | "ssh_host" | "git_repo" | "recent_project" | "game" | "alias";
   app?: any;
   file?: any;
   everything?: any;
   url?: string;
   email?: string;
   memo?: any;
+  sshHost?: SshHost;
+  gitRepo?: GitRepo;
+  recentProject?: RecentProject;
+  game?: GameInfo;
+  alias?: AliasConfig;
   plugin?: { id: string; name: string; description?: string };
   aiAnswer?: string;
   jsonContent?: string;
   displayName: string;
   path: string;
+  provider?: string; // 结果来源的数据源，对应设置中的 providers
+  preview?: ResultPreview | null; // 预览信息，数据源未提供时在选中后通过 get_result_preview 按需获取
 };
 
 /**
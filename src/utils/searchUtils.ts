@@ -4,7 +4,7 @@
  */
 
 import type React from "react";
-import type { SearchEngineConfig, AppInfo, FileHistoryItem, MemoItem } from "../types";
+import type { SearchEngineConfig, AppInfo, FileHistoryItem, MemoItem, TimerKind, AliasConfig } from "../types";
 import { containsChinese, processBatchAsync, isValidIcon, normalizePathForHistory } from "./launcherUtils";
 import { tauriApi } from "../api/tauri";
 
@@ -60,6 +60,437 @@ export function buildSearchUrl(urlTemplate: string, keyword: string): string {
   return urlTemplate.replace(/{query}/g, encodedKeyword);
 }
 
+/**
+ * 网络工具查询："ip" 查看本机网络信息，"ping 主机" 测试连通性，"ping 主机:端口" 或 "ping 主机 端口" 检测 TCP 端口
+ */
+export type NetworkQuery =
+  | { kind: "info" }
+  | { kind: "ping"; host: string }
+  | { kind: "port"; host: string; port: number };
+
+/**
+ * 解析网络工具查询，不匹配时返回 null
+ */
+export function parseNetworkQuery(query: string): NetworkQuery | null {
+  const trimmed = query.trim();
+  if (/^ip$/i.test(trimmed)) {
+    return { kind: "info" };
+  }
+  const match = trimmed.match(/^ping\s+(\S+)(?:\s+(\d+))?$/i);
+  if (!match) return null;
+
+  let host = match[1];
+  let portText = match[2];
+  // host:port（IPv6 需写成 [::1]:443）
+  const hostPort = host.match(/^(\[[^\]]+\]|[^:]+):(\d+)$/);
+  if (!portText && hostPort) {
+    host = hostPort[1];
+    portText = hostPort[2];
+  }
+  if (!/^[\w.\-:\[\]]+$/.test(host) || host.startsWith("-")) return null;
+  if (!portText) return { kind: "ping", host };
+  const port = Number(portText);
+  if (!Number.isInteger(port) || port < 1 || port > 65535) return null;
+  return { kind: "port", host, port };
+}
+
+/**
+ * 是否为系统监控查询（cpu、ram、内存、磁盘、电池等）
+ */
+export function isSystemMonitorQuery(query: string): boolean {
+  return /^(cpu|ram|mem|memory|disk|battery|sys|系统|内存|磁盘|电池)$/i.test(query.trim());
+}
+
+/**
+ * 解析音频设备查询："audio" 列出输出设备，"audio 关键字" 按名称筛选设备（如 "audio headphones"）
+ * 不匹配时返回 null
+ */
+export function parseAudioQuery(query: string): { keyword: string } | null {
+  const match = query.trim().match(/^(?:audio|音频|声音)(?:\s+(.*))?$/i);
+  return match ? { keyword: (match[1] ?? "").trim() } : null;
+}
+
+/**
+ * 按名称查找音频设备：优先完全匹配，其次名称包含关键字（不区分大小写），与后端 find_device 一致
+ */
+export function findAudioDevice<T extends { name: string }>(devices: T[], keyword: string): T | null {
+  const lower = keyword.trim().toLowerCase();
+  if (!lower) return null;
+  return (
+    devices.find((d) => d.name.toLowerCase() === lower) ??
+    devices.find((d) => d.name.toLowerCase().includes(lower)) ??
+    null
+  );
+}
+
+/**
+ * 解析显示器控制查询，不匹配时返回 null：
+ * - "display"/"显示器" [关键字]：显示器与预设，关键字匹配预设名称时回车应用
+ * - "亮度"/"brightness" [0-100]：带数值时回车把所有显示器调到该亮度
+ * - "夜间模式"/"night light"：回车开关夜间模式
+ */
+export function parseDisplayQuery(
+  query: string
+): { keyword: string; brightness: number | null; nightLight: boolean } | null {
+  const q = query.trim();
+  if (/^(?:night\s*light|夜间模式|护眼模式)$/i.test(q)) {
+    return { keyword: "", brightness: null, nightLight: true };
+  }
+  const brightness = q.match(/^(?:brightness|亮度)(?:\s+(\d{1,3})%?)?$/i);
+  if (brightness) {
+    const value = brightness[1] === undefined ? null : Math.min(100, Number(brightness[1]));
+    return { keyword: "", brightness: value, nightLight: false };
+  }
+  const display = q.match(/^(?:display|monitor|显示器|屏幕)(?:\s+(.*))?$/i);
+  return display ? { keyword: (display[1] ?? "").trim(), brightness: null, nightLight: false } : null;
+}
+
+/**
+ * 解析无线开关查询："wifi"、"蓝牙"、"飞行模式" 可加 on/off（开/关），"radio"/"无线" 只展示状态
+ * enabled 为 null 表示回车时切换当前状态；不匹配时返回 null
+ */
+export function parseRadioQuery(
+  query: string
+): { target: "wifi" | "bluetooth" | "airplane" | null; enabled: boolean | null } | null {
+  const match = query
+    .trim()
+    .match(/^(wi-?fi|wlan|bluetooth|蓝牙|airplane(?:\s*mode)?|飞行模式|radios?|无线)(?:\s+(on|off|开|关|打开|关闭))?$/i);
+  if (!match) return null;
+  const name = match[1].toLowerCase();
+  const target = /^(wi-?fi|wlan)$/.test(name)
+    ? "wifi"
+    : /^(bluetooth|蓝牙)$/.test(name)
+      ? "bluetooth"
+      : /^(airplane|飞行模式)/.test(name)
+        ? "airplane"
+        : null;
+  const state = match[2]?.toLowerCase();
+  const enabled = state === undefined ? null : ["on", "开", "打开"].includes(state);
+  return { target, enabled };
+}
+
+/**
+ * 窗口管理动作，id 与后端 window_manager::WindowAction 一致；aliases 用于启动器中的 "win 关键字" 匹配
+ */
+export const WINDOW_ACTIONS: Array<{ id: string; label: string; aliases: string[] }> = [
+  { id: "left_half", label: "左半屏", aliases: ["left", "左"] },
+  { id: "right_half", label: "右半屏", aliases: ["right", "右"] },
+  { id: "top_half", label: "上半屏", aliases: ["top", "up", "上"] },
+  { id: "bottom_half", label: "下半屏", aliases: ["bottom", "down", "下"] },
+  { id: "top_left", label: "左上角", aliases: ["top left", "tl"] },
+  { id: "top_right", label: "右上角", aliases: ["top right", "tr"] },
+  { id: "bottom_left", label: "左下角", aliases: ["bottom left", "bl"] },
+  { id: "bottom_right", label: "右下角", aliases: ["bottom right", "br"] },
+  { id: "next_monitor", label: "移到下一个显示器", aliases: ["next", "monitor", "下一个显示器"] },
+  { id: "toggle_maximize", label: "最大化/还原", aliases: ["max", "maximize", "最大化"] },
+  { id: "toggle_topmost", label: "置顶/取消置顶", aliases: ["pin", "ontop", "置顶"] },
+  { id: "center", label: "居中", aliases: ["center", "middle"] },
+  { id: "minimize_all", label: "最小化所有窗口", aliases: ["min", "minimize", "最小化", "desktop"] },
+];
+
+/**
+ * 解析窗口管理查询："win"/"window"/"窗口" 后跟关键字，返回匹配的动作（完全匹配别名的排在最前）
+ * 不匹配时返回 null
+ */
+export function parseWindowQuery(query: string): { keyword: string; actions: typeof WINDOW_ACTIONS } | null {
+  const match = query.trim().match(/^(?:win|window|窗口)(?:\s+(.*))?$/i);
+  if (!match) return null;
+  const keyword = (match[1] ?? "").trim().toLowerCase();
+  if (!keyword) return { keyword, actions: WINDOW_ACTIONS };
+  const exact = WINDOW_ACTIONS.filter((a) => a.aliases.includes(keyword) || a.label === keyword);
+  const partial = WINDOW_ACTIONS.filter(
+    (a) => !exact.includes(a) && (a.label.includes(keyword) || a.aliases.some((alias) => alias.startsWith(keyword)))
+  );
+  return { keyword, actions: [...exact, ...partial] };
+}
+
+/**
+ * 解析自动化脚本查询：第一个词与脚本的 @keyword 相同（不区分大小写）时匹配，其后的内容作为脚本的 input
+ * 有错误的脚本不参与匹配；不匹配时返回 null
+ */
+export function parseScriptQuery<T extends { keyword: string | null; error: string | null }>(
+  query: string,
+  scripts: T[]
+): { script: T; input: string } | null {
+  const match = query.match(/^\s*(\S+)(?:\s+([\s\S]*))?$/);
+  if (!match) return null;
+  const keyword = match[1].toLowerCase();
+  const script = scripts.find((s) => !s.error && s.keyword === keyword);
+  return script ? { script, input: (match[2] ?? "").trim() } : null;
+}
+
+/**
+ * 解析工作流查询：第一个词与工作流关键字相同（不区分大小写）时匹配，其后的内容作为工作流的初始文本
+ */
+export function parseWorkflowQuery<T extends { keyword: string }>(
+  query: string,
+  workflows: T[]
+): { workflow: T; input: string } | null {
+  const match = query.match(/^\s*(\S+)(?:\s+([\s\S]*))?$/);
+  if (!match) return null;
+  const keyword = match[1].toLowerCase();
+  const workflow = workflows.find((w) => w.keyword === keyword);
+  return workflow ? { workflow, input: (match[2] ?? "").trim() } : null;
+}
+
+/**
+ * 匹配设置中的别名（忽略大小写）：完全相同的排在前面，其次是以输入开头的别名
+ */
+export function matchAliases(query: string, aliases: AliasConfig[]): AliasConfig[] {
+  const lowerQuery = query.trim().toLowerCase();
+  if (!lowerQuery) return [];
+  const valid = aliases.filter((a) => a.alias.trim() && a.target.trim());
+  const exact = valid.filter((a) => a.alias.trim().toLowerCase() === lowerQuery);
+  const prefix = valid.filter((a) => {
+    const alias = a.alias.trim().toLowerCase();
+    return alias !== lowerQuery && alias.startsWith(lowerQuery);
+  });
+  return [...exact, ...prefix];
+}
+
+/**
+ * 拆出剪切板搜索中的 #标签 过滤条件（小写，与后端一致），返回标签和其余的查询文本
+ */
+export function splitTagFilter(query: string): { tags: string[]; text: string } {
+  const tags: string[] = [];
+  const terms: string[] = [];
+  for (const term of query.split(/\s+/).filter(Boolean)) {
+    if (term.startsWith("#") && term.length > 1) {
+      tags.push(term.slice(1).toLowerCase());
+    } else {
+      terms.push(term);
+    }
+  }
+  return { tags, text: terms.join(" ") };
+}
+
+/**
+ * 解析虚拟桌面查询："vd"/"desktop"/"虚拟桌面" 后跟桌面序号或名称，
+ * 前面加 "move"/"mv"/"移动" 表示把当前窗口移到该桌面；index 为从 0 开始的序号，未给出序号时为 null
+ */
+export function parseDesktopQuery(query: string): { keyword: string; index: number | null; move: boolean } | null {
+  const match = query.trim().match(/^(?:vd|desktops?|虚拟桌面)(?:\s+(.*))?$/i);
+  if (!match) return null;
+  let rest = (match[1] ?? "").trim();
+  const moveMatch = rest.match(/^(?:move|mv|移动)(?:\s+|$)(.*)$/i);
+  const move = !!moveMatch;
+  if (moveMatch) rest = moveMatch[1].trim();
+  const number = rest.match(/^\d+$/) ? parseInt(rest, 10) : null;
+  return {
+    keyword: number === null ? rest.toLowerCase() : "",
+    index: number !== null && number > 0 ? number - 1 : null,
+    move,
+  };
+}
+
+/**
+ * 解析保持唤醒查询："awake"/"caffeine"/"保持唤醒" 后可跟时长（"2h"、"30m"、"90"、"1.5小时"，无单位按分钟）
+ * 或 "off"/"关闭"；minutes 为空表示一直保持，后续内容无法识别时返回 null
+ */
+export function parseKeepAwakeQuery(query: string): { minutes: number | null; off: boolean } | null {
+  const match = query.trim().match(/^(?:awake|caffeine|保持唤醒|防休眠)(?:\s+(.*))?$/i);
+  if (!match) return null;
+  const rest = (match[1] ?? "").trim().toLowerCase();
+  if (!rest) return { minutes: null, off: false };
+  if (/^(?:off|stop|关|关闭|停止)$/.test(rest)) return { minutes: null, off: true };
+  const duration = rest.match(/^(\d+(?:\.\d+)?)\s*(h|hr|hours?|小时|m|min|mins|minutes?|分|分钟)?$/);
+  if (!duration) return null;
+  const value = parseFloat(duration[1]);
+  const hours = !!duration[2] && /^(?:h|hr|hour|hours|小时)$/.test(duration[2]);
+  const minutes = Math.round(hours ? value * 60 : value);
+  return minutes > 0 ? { minutes, off: false } : null;
+}
+
+export const TEXT_TRANSFORMS: Array<{ id: string; label: string; aliases: string[] }> = [
+  { id: "upper", label: "转为大写", aliases: ["upper", "uppercase", "大写"] },
+  { id: "lower", label: "转为小写", aliases: ["lower", "lowercase", "小写"] },
+  { id: "title", label: "单词首字母大写", aliases: ["title", "title case", "首字母大写"] },
+  { id: "camel", label: "驼峰命名 camelCase", aliases: ["camel", "camelcase", "驼峰"] },
+  { id: "snake", label: "下划线命名 snake_case", aliases: ["snake", "snake_case", "snakecase", "下划线"] },
+  { id: "json_pretty", label: "JSON 美化", aliases: ["json pretty", "pretty json", "json美化"] },
+  { id: "json_minify", label: "JSON 压缩为一行", aliases: ["json minify", "minify json", "压缩json"] },
+  { id: "sort_lines", label: "按行排序", aliases: ["sort lines", "排序行", "行排序"] },
+  { id: "dedupe_lines", label: "删除重复行", aliases: ["dedupe", "dedupe lines", "去重", "删除重复行"] },
+  { id: "strip_whitespace", label: "去除首尾空白", aliases: ["trim", "strip whitespace", "去空白"] },
+];
+
+/**
+ * 解析文本转换查询："text"/"文本" 后跟关键字时列出匹配的转换（完全匹配别名的排在最前）；
+ * 直接输入某个转换的完整别名（如 "uppercase"、"驼峰"）时只返回该转换；不匹配时返回 null
+ */
+export function parseTextTransformQuery(query: string): { keyword: string; transforms: typeof TEXT_TRANSFORMS } | null {
+  const trimmed = query.trim().toLowerCase();
+  const direct = TEXT_TRANSFORMS.filter((t) => t.aliases.includes(trimmed));
+  if (direct.length > 0) return { keyword: trimmed, transforms: direct };
+  const match = trimmed.match(/^(?:text|文本)(?:\s+(.*))?$/);
+  if (!match) return null;
+  const keyword = (match[1] ?? "").trim();
+  if (!keyword) return { keyword, transforms: TEXT_TRANSFORMS };
+  const exact = TEXT_TRANSFORMS.filter((t) => t.aliases.includes(keyword));
+  const partial = TEXT_TRANSFORMS.filter(
+    (t) => !exact.includes(t) && (t.label.toLowerCase().includes(keyword) || t.aliases.some((alias) => alias.startsWith(keyword)))
+  );
+  return { keyword, transforms: [...exact, ...partial] };
+}
+
+/**
+ * 解析密码生成查询："pwd"/"pwgen"/"生成密码" 后可跟模式（"phrase"/"口令"、"pron"/"易读"）、
+ * 数字（随机与易读模式为长度，口令模式为单词数）和 "nosym"/"无符号"；含无法识别的内容时返回 null
+ */
+export function parsePasswordQuery(
+  query: string
+): { mode: "random" | "passphrase" | "pronounceable"; count: number | null; symbols: boolean } | null {
+  const match = query.trim().match(/^(?:pwd|pwgen|生成密码)(?:\s+(.*))?$/i);
+  if (!match) return null;
+  const result: { mode: "random" | "passphrase" | "pronounceable"; count: number | null; symbols: boolean } = {
+    mode: "random",
+    count: null,
+    symbols: true,
+  };
+  for (const token of (match[1] ?? "").trim().toLowerCase().split(/\s+/).filter(Boolean)) {
+    if (/^\d+$/.test(token)) {
+      result.count = parseInt(token, 10);
+    } else if (["phrase", "passphrase", "diceware", "口令"].includes(token)) {
+      result.mode = "passphrase";
+    } else if (["pron", "pronounceable", "易读"].includes(token)) {
+      result.mode = "pronounceable";
+    } else if (["nosym", "无符号"].includes(token)) {
+      result.symbols = false;
+    } else {
+      return null;
+    }
+  }
+  return result;
+}
+
+/**
+ * 解析两步验证码查询："otp"/"totp"/"2fa"/"验证码" 后可跟账户名（用于筛选）
+ */
+export function parseTotpQuery(query: string): { account: string } | null {
+  const match = query.trim().match(/^(?:otp|totp|2fa|验证码)(?:\s+(.*))?$/i);
+  if (!match) return null;
+  return { account: (match[1] ?? "").trim() };
+}
+
+/**
+ * 解析世界时钟查询：
+ * - "time"/"时间"/"世界时间" 显示常用城市
+ * - "time in Tokyo"、"time 东京"、"东京时间" 查询单个城市
+ * - "convert 3pm PST to CST"、"3pm PST to CST, JST"、"下午3点 北京 到 纽约" 换算时间
+ */
+export function parseWorldClockQuery(
+  query: string
+):
+  | { mode: "favorites" }
+  | { mode: "place"; place: string }
+  | { mode: "convert"; time: string; from: string; to: string[] }
+  | null {
+  const trimmed = query.trim();
+  if (/^(?:time|时间|世界时间|world\s*clock)$/i.test(trimmed)) return { mode: "favorites" };
+  const convert = trimmed.match(
+    /^(?:convert\s+|换算\s*)?((?:上午|下午|早上|晚上|凌晨)?\d{1,2}(?:[:：]\d{2}|点半|点(?:\d{1,2}分?)?)?\s*(?:am|pm|a\.m\.|p\.m\.)?)\s+(\S+)\s+(?:to|in|->|到|转)\s+(.+)$/i
+  );
+  if (convert) {
+    const to = convert[3].split(/\s*[,，、]\s*/).filter(Boolean);
+    return { mode: "convert", time: convert[1].replace("：", ":"), from: convert[2], to };
+  }
+  const place = trimmed.match(/^(?:time|时间)\s+(?:in\s+)?(.+)$/i) ?? trimmed.match(/^(\S+?)\s*(?:时间|现在几点)$/);
+  return place ? { mode: "place", place: place[1].trim() } : null;
+}
+
+const QUOTE_CURRENCIES = [
+  "usd", "eur", "jpy", "gbp", "cny", "cnh", "hkd", "twd", "krw", "sgd", "aud", "nzd", "cad", "chf", "sek", "nok",
+  "dkk", "rub", "inr", "thb", "myr", "idr", "php", "vnd", "brl", "mxn", "zar", "try", "aed", "sar",
+];
+const QUOTE_CRYPTOS = [
+  "btc", "eth", "sol", "bnb", "xrp", "doge", "ada", "trx", "ton", "avax", "dot", "link", "ltc", "bch", "shib",
+];
+
+/**
+ * 解析行情查询：
+ * - "行情"/"quotes"/"自选" 显示关注列表（symbols 为 null）
+ * - "quote aapl msft"、"股价 600519"、"$tsla" 查询指定代码
+ * - 直接输入外汇对（"usd/jpy"）、常见加密货币（"btc"）或关注列表中的代码
+ */
+export function parseQuoteQuery(query: string, watchlist: string[] = []): { symbols: string[] | null } | null {
+  const trimmed = query.trim();
+  if (/^(?:quotes?|stocks?|行情|自选)$/i.test(trimmed)) return { symbols: null };
+  const explicit = trimmed.match(/^(?:quote|stock|行情|股价)\s+(.+)$/i);
+  if (explicit) return { symbols: explicit[1].split(/[\s,，]+/).filter(Boolean) };
+  const dollar = trimmed.match(/^\$([\w.^=/-]+)$/);
+  if (dollar) return { symbols: [dollar[1]] };
+
+  const lower = trimmed.toLowerCase();
+  const pair = lower.match(/^([a-z]{3,4})[/-]([a-z]{3,4})$/);
+  const isPair =
+    !!pair &&
+    (QUOTE_CURRENCIES.includes(pair[1]) || QUOTE_CRYPTOS.includes(pair[1])) &&
+    (QUOTE_CURRENCIES.includes(pair[2]) || ["usdt", "usdc"].includes(pair[2]));
+  if (isPair || QUOTE_CRYPTOS.includes(lower) || watchlist.some((s) => s.toLowerCase() === lower)) {
+    return { symbols: [trimmed] };
+  }
+  return null;
+}
+
+/**
+ * 解析 RSS 查询："rss"/"订阅" 后可跟关键词筛选标题，"rss add <地址>" 添加订阅
+ */
+export function parseRssQuery(query: string): { keyword: string; addUrl: string | null } | null {
+  const match = query.trim().match(/^(?:rss|订阅)(?:\s+(.*))?$/i);
+  if (!match) return null;
+  const rest = (match[1] ?? "").trim();
+  const add = rest.match(/^(?:add|添加)\s+(\S+)$/i);
+  if (add) return { keyword: "", addUrl: add[1] };
+  return { keyword: rest, addUrl: null };
+}
+
+/**
+ * 解析计时查询："timer 10m 泡茶"/"计时 25 番茄钟" 创建倒计时，"alarm 7:30 起床"/"闹钟 下午3点" 创建闹钟；
+ * 关键词后第一段为时长或时刻，其余为名称，只输入关键词时列出现有计时。
+ * 以 "remind"/"提醒" 开头或含 "提醒我" 的输入整体作为自然语言提醒交给后端解析
+ */
+export function parseTimerQuery(query: string): { kind: TimerKind; spec: string | null; label: string } | null {
+  const trimmed = query.trim();
+  if (/^(?:remind(?:ers?)?(?:\s|$)|提醒)/i.test(trimmed) || trimmed.includes("提醒我")) {
+    const bare = /^(?:remind(?:ers?)?|提醒)$/i.test(trimmed);
+    return { kind: "reminder", spec: bare ? null : trimmed, label: "" };
+  }
+  const match = trimmed.match(/^(timers?|计时|倒计时|alarms?|闹钟)(?:\s+(\S+)(?:\s+(.*))?)?$/i);
+  if (!match) return null;
+  const kind: TimerKind = /^(alarms?|闹钟)$/i.test(match[1]) ? "alarm" : "timer";
+  return { kind, spec: match[2] ?? null, label: (match[3] ?? "").trim() };
+}
+
+/**
+ * 解析日历查询："today"/"日程" 查看今天的日程，"next meeting"/"下个会议" 查看接下来的会议，
+ * "cal add <ICS 地址或 .ics 路径>" 添加日历订阅
+ */
+export function parseCalendarQuery(query: string): { mode: "today" | "next"; addSource: string | null } | null {
+  const trimmed = query.trim();
+  const add = trimmed.match(/^(?:cal|calendar|日历)\s+(?:add|添加)\s+(.+)$/i);
+  if (add) return { mode: "today", addSource: add[1].trim() };
+  if (/^(?:today|agenda|cal|calendar|日历|日程|今天|今日|(?:今天|今日)日程)$/i.test(trimmed)) {
+    return { mode: "today", addSource: null };
+  }
+  if (/^(?:next\s+meeting|meetings?|下一?个会议?|会议)$/i.test(trimmed)) {
+    return { mode: "next", addSource: null };
+  }
+  return null;
+}
+
+/**
+ * 解析习惯打卡查询："habit"/"习惯"/"打卡" 列出今天的习惯，后跟关键字时过滤；
+ * "habit add <名称> [计划]" 添加习惯，如 "habit add 跑步 每周3次"
+ */
+export function parseHabitQuery(query: string): { keyword: string; addInput: string | null } | null {
+  const trimmed = query.trim();
+  const add = trimmed.match(/^(?:habits?|习惯)\s+(?:add|添加)\s+(.+)$/i);
+  if (add) return { keyword: "", addInput: add[1].trim() };
+  const match = trimmed.match(/^(habits?|习惯|打卡)(?:\s+(.*))?$/i);
+  if (!match) return null;
+  return { keyword: (match[2] ?? "").trim(), addInput: null };
+}
+
 /**
  * 生成搜索结果项
  */